@@ -5,7 +5,9 @@
  * // license that can be found in the LICENSE file.
  */
 use criterion::{criterion_group, criterion_main, Criterion};
-use erydanos::{ArcSin, ArcTan, CubeRoot, Power, Sine, Tangent};
+use erydanos::{
+    eerff, ArcSin, ArcTan, CubeRoot, Exponential, Hyperbolic, Logarithmic, Power, Sine, Tangent,
+};
 use rand::Rng;
 
 pub fn criterion_benchmark(c: &mut Criterion) {
@@ -158,6 +160,121 @@ pub fn criterion_benchmark(c: &mut Criterion) {
             libm::atanf(tan_value);
         })
     });
+
+    c.bench_function("Exp Erydanos", |b| {
+        b.iter(|| {
+            let value: f32 = rng.gen_range(-10f32..10f32);
+            _ = value.eexp();
+            _ = value.eexp();
+            _ = value.eexp();
+            _ = value.eexp();
+            _ = value.eexp();
+            _ = value.eexp();
+            _ = value.eexp();
+        })
+    });
+    c.bench_function("Exp libm", |b| {
+        b.iter(|| {
+            let value: f32 = rng.gen_range(-10f32..10f32);
+            libm::expf(value);
+            libm::expf(value);
+            libm::expf(value);
+            libm::expf(value);
+            libm::expf(value);
+            libm::expf(value);
+            libm::expf(value);
+        })
+    });
+
+    c.bench_function("Ln Erydanos", |b| {
+        b.iter(|| {
+            let value: f32 = rng.gen_range(0f32..100f32);
+            _ = value.eln();
+            _ = value.eln();
+            _ = value.eln();
+            _ = value.eln();
+            _ = value.eln();
+            _ = value.eln();
+            _ = value.eln();
+        })
+    });
+    c.bench_function("Ln libm", |b| {
+        b.iter(|| {
+            let value: f32 = rng.gen_range(0f32..100f32);
+            libm::logf(value);
+            libm::logf(value);
+            libm::logf(value);
+            libm::logf(value);
+            libm::logf(value);
+            libm::logf(value);
+            libm::logf(value);
+        })
+    });
+
+    c.bench_function("Sinh Erydanos", |b| {
+        b.iter(|| {
+            let value: f32 = rng.gen_range(-10f32..10f32);
+            _ = value.esinh();
+            _ = value.esinh();
+            _ = value.esinh();
+            _ = value.esinh();
+            _ = value.esinh();
+            _ = value.esinh();
+            _ = value.esinh();
+        })
+    });
+    c.bench_function("Sinh libm", |b| {
+        b.iter(|| {
+            let value: f32 = rng.gen_range(-10f32..10f32);
+            libm::sinhf(value);
+            libm::sinhf(value);
+            libm::sinhf(value);
+            libm::sinhf(value);
+            libm::sinhf(value);
+            libm::sinhf(value);
+            libm::sinhf(value);
+        })
+    });
+
+    c.bench_function("Erf Erydanos", |b| {
+        b.iter(|| {
+            let value: f32 = rng.gen_range(-4f32..4f32);
+            _ = eerff(value);
+            _ = eerff(value);
+            _ = eerff(value);
+            _ = eerff(value);
+            _ = eerff(value);
+            _ = eerff(value);
+            _ = eerff(value);
+        })
+    });
+    c.bench_function("Erf libm", |b| {
+        b.iter(|| {
+            let value: f32 = rng.gen_range(-4f32..4f32);
+            libm::erff(value);
+            libm::erff(value);
+            libm::erff(value);
+            libm::erff(value);
+            libm::erff(value);
+            libm::erff(value);
+            libm::erff(value);
+        })
+    });
+
+    let batch_values: Vec<f32> = (0..1024).map(|_| rng.gen_range(-10f32..10f32)).collect();
+    let mut batch_dst = vec![0f32; batch_values.len()];
+    c.bench_function("Sine batch Erydanos", |b| {
+        b.iter(|| {
+            erydanos::batch::sin_f32(&batch_values, &mut batch_dst);
+        })
+    });
+    c.bench_function("Sine loop Erydanos", |b| {
+        b.iter(|| {
+            for (dst, &value) in batch_dst.iter_mut().zip(batch_values.iter()) {
+                *dst = value.esin();
+            }
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
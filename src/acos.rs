@@ -6,22 +6,27 @@
  */
 use crate::abs::eabs;
 use crate::asin::easin;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_acos;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::vacosq_f64;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::{_mm_acos_pd, _mm_extract_pd};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm256_acos_pd, _mm_acos_pd, _mm_extract_pd};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::*;
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 #[inline]
-fn do_acos(x: f64) -> f64 {
+pub(crate) fn do_acos(x: f64) -> f64 {
     if x > 0f64 {
         std::f64::consts::FRAC_PI_2 - easin(x)
     } else {
@@ -39,16 +44,39 @@ fn do_acos_neon(d: f64) -> f64 {
     }
 }
 
-#[inline]
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
-fn do_acos_sse(d: f64) -> f64 {
-    unsafe {
-        let ld = _mm_set1_pd(d);
-        _mm_extract_pd::<0>(_mm_acos_pd(ld))
-    }
+#[inline(always)]
+unsafe fn do_acos_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_acos_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_acos_sse41(d: f64) -> f64 {
+    do_acos_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_acos_avx(d: f64) -> f64 {
+    let ld = _mm256_set1_pd(d);
+    _mm256_cvtsd_f64(_mm256_acos_pd(ld))
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_acos_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_acos(f64x2_splat(d)))
 }
 
 #[inline]
@@ -59,12 +87,25 @@ pub fn eacos(d: f64) -> f64 {
     {
         _dispatcher = do_acos_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_acos_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_acos_sse;
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_acos_avx(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_acos_sse41(d) }
+            } else {
+                |d| unsafe { do_acos_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
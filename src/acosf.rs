@@ -7,19 +7,24 @@
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::*;
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_acos_ps;
 use crate::abs::eabsf;
 use crate::asinf::easinf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_acos;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::vacosq_f32;
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm256_acos_ps, _mm_acos_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 #[inline]
 fn do_acosf(x: f32) -> f32 {
@@ -40,16 +45,39 @@ fn do_acosf_neon(d: f32) -> f32 {
     }
 }
 
-#[inline]
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
-fn do_acosf_sse(d: f32) -> f32 {
-    unsafe {
-        let ld = _mm_set1_ps(d);
-        f32::from_bits(_mm_extract_ps::<0>(_mm_acos_ps(ld)) as u32)
-    }
+#[inline(always)]
+unsafe fn do_acosf_sse(d: f32) -> f32 {
+    let ld = _mm_set1_ps(d);
+    f32::from_bits(_mm_extract_psx::<0>(_mm_acos_ps(ld)) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_acosf_sse41(d: f32) -> f32 {
+    do_acosf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_acosf_avx(d: f32) -> f32 {
+    let ld = _mm256_set1_ps(d);
+    _mm256_cvtss_f32(_mm256_acos_ps(ld))
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_acosf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_acos(f32x4_splat(d)))
 }
 
 /// Computes acos for an argument, *ULP 2.0*
@@ -60,12 +88,25 @@ pub fn eacosf(d: f32) -> f32 {
     {
         _dispatcher = do_acosf_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_acosf_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_acosf_sse;
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_acosf_avx(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_acosf_sse41(d) }
+            } else {
+                |d| unsafe { do_acosf_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
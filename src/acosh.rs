@@ -0,0 +1,121 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_acosh;
+use crate::log1p::do_log1p;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vacoshq_f64;
+use crate::sqrt::esqrt;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm256_acosh_pd, _mm_acosh_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn do_acosh(d: f64) -> f64 {
+    if d < 2. {
+        // t = d - 1 is exact here
+        let t = d - 1.;
+        let r = do_log1p(t + esqrt(t * (t + 2.)));
+        if d < 1. {
+            f64::NAN
+        } else {
+            r
+        }
+    } else if d > 268_435_456f64 {
+        // sqrt(d^2 - 1) == d here, so acosh(d) = ln(d) + ln(2)
+        do_log1p(d - 1.) + std::f64::consts::LN_2
+    } else {
+        do_log1p((d + d - 1.) - 1. / (d + esqrt(d * d - 1.)))
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_acosh_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(vacoshq_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_acosh_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_acosh_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_acosh_sse41(d: f64) -> f64 {
+    do_acosh_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_acosh_avx(d: f64) -> f64 {
+    let ld = _mm256_set1_pd(d);
+    _mm256_cvtsd_f64(_mm256_acosh_pd(ld))
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_acosh_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_acosh(f64x2_splat(d)))
+}
+
+/// Computes inverse hyperbolic cosine, error bound *ULP 4.0*
+#[inline]
+pub fn eacosh(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_acosh;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_acosh_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_acosh_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_acosh_avx(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_acosh_sse41(d) }
+            } else {
+                |d| unsafe { do_acosh_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,123 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_acosh;
+use crate::log1pf::do_log1pf;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vacoshq_f32;
+use crate::sqrtf::esqrtf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm256_acosh_ps, _mm_acosh_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn do_acoshf(d: f32) -> f32 {
+    if d < 2. {
+        // t = d - 1 is exact here
+        let t = d - 1.;
+        let r = do_log1pf(t + esqrtf(t * (t + 2.)));
+        if d < 1. {
+            f32::NAN
+        } else {
+            r
+        }
+    } else if d > 4096f32 {
+        // sqrt(d^2 - 1) == d here, so acosh(d) = ln(d) + ln(2)
+        do_log1pf(d - 1.) + std::f32::consts::LN_2
+    } else {
+        do_log1pf((d + d - 1.) - 1. / (d + esqrtf(d * d - 1.)))
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_acoshf_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vacoshq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_acoshf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_acosh_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_acoshf_sse41(d: f32) -> f32 {
+    do_acoshf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_acoshf_avx(d: f32) -> f32 {
+    let v = _mm256_set1_ps(d);
+    let value = _mm256_acosh_ps(v);
+    _mm256_cvtss_f32(value)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_acoshf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_acosh(f32x4_splat(d)))
+}
+
+/// Computes inverse hyperbolic cosine, error bound *ULP 4.5*
+#[inline]
+pub fn eacoshf(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_acoshf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_acoshf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_acoshf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_acoshf_avx(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_acoshf_sse41(d) }
+            } else {
+                |d| unsafe { do_acoshf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -9,19 +9,24 @@
 use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
 
 use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_asin;
 use crate::generalf::{copysignk, mlaf};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vasinq_f64;
 use crate::sqrt::esqrt;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::{_mm_asin_pd, _mm_extract_pd};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm256_asin_pd, _mm_asin_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub(crate) const ASIN_POLY_1_D: f64 = 1.000000000000000023366;
 pub(crate) const ASIN_POLY_2_D: f64 = -3.176610416071895664242e-14;
@@ -44,7 +49,7 @@ pub(crate) const ASIN_POLY_18_D: f64 = -4.795580928637641353576;
 pub(crate) const ASIN_POLY_19_D: f64 = 1.287092068615565929449;
 
 #[inline]
-fn do_asin(c: f64) -> f64 {
+pub(crate) fn do_asin(c: f64) -> f64 {
     if eabs(c) > 1f64 {
         return f64::NAN;
     }
@@ -94,16 +99,39 @@ fn do_asin_neon(d: f64) -> f64 {
     }
 }
 
-#[inline]
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
-fn do_asin_sse(d: f64) -> f64 {
-    unsafe {
-        let ld = _mm_set1_pd(d);
-        _mm_extract_pd::<0>(_mm_asin_pd(ld))
-    }
+#[inline(always)]
+unsafe fn do_asin_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_asin_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_asin_sse41(d: f64) -> f64 {
+    do_asin_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_asin_avx(d: f64) -> f64 {
+    let ld = _mm256_set1_pd(d);
+    _mm256_cvtsd_f64(_mm256_asin_pd(ld))
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_asin_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_asin(f64x2_splat(d)))
 }
 
 #[inline]
@@ -114,12 +142,25 @@ pub fn easin(d: f64) -> f64 {
     {
         _dispatcher = do_asin_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_asin_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_asin_sse;
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_asin_avx(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_asin_sse41(d) }
+            } else {
+                |d| unsafe { do_asin_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
@@ -5,22 +5,27 @@
  * // license that can be found in the LICENSE file.
  */
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_asin_ps;
 use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_asin;
 use crate::generalf::{copysignfk, mlaf};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vasinq_f32;
 use crate::sqrtf::esqrtf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm256_asin_ps, _mm_asin_ps, _mm_extract_psx};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub(crate) const ASIN_POLY_2_F: u32 = 0x3f7ffffe; // 0.99999996772155
 pub(crate) const ASIN_POLY_3_F: u32 = 0x36f8402e; // 7.398621160063735e-6
@@ -42,7 +47,7 @@ fn do_asinf(c: f32) -> f32 {
         return f32::NAN;
     }
     if c == 0. {
-        return 0.;
+        return c;
     }
     let mut q = 0;
     let ca = eabsf(c);
@@ -82,16 +87,39 @@ fn do_asinf_neon(d: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_asinf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_asin_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_asinf_sse41(d: f32) -> f32 {
+    do_asinf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_asinf_avx(d: f32) -> f32 {
+    let v = _mm256_set1_ps(d);
+    let value = _mm256_asin_ps(v);
+    _mm256_cvtss_f32(value)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_asinf_sse(d: f32) -> f32 {
-    unsafe {
-        let v = _mm_set1_ps(d);
-        let value = _mm_asin_ps(v);
-        let ex = f32::from_bits(_mm_extract_ps::<0>(value) as u32);
-        ex
-    }
+fn do_asinf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_asin(f32x4_splat(d)))
 }
 
 /// Computes arcsin, error bound *ULP 2.0*
@@ -102,12 +130,25 @@ pub fn easinf(d: f32) -> f32 {
     {
         _dispatcher = do_asinf_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_asinf_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_asinf_sse;
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_asinf_avx(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_asinf_sse41(d) }
+            } else {
+                |d| unsafe { do_asinf_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
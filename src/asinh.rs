@@ -0,0 +1,120 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_asinh;
+use crate::generalf::copysignk;
+use crate::log1p::do_log1p;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vasinhq_f64;
+use crate::sqrt::esqrt;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm256_asinh_pd, _mm_asinh_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn do_asinh(d: f64) -> f64 {
+    let a = eabs(d);
+    let r = if a < 2. {
+        let a2 = a * a;
+        do_log1p(a + a2 / (1. + esqrt(a2 + 1.)))
+    } else if a > 268_435_456f64 {
+        // sqrt(a^2 + 1) == a here, so asinh(a) = ln(a) + ln(2)
+        do_log1p(a - 1.) + std::f64::consts::LN_2
+    } else {
+        // 2a - 1 is exact for a >= 2
+        do_log1p((a + a - 1.) + 1. / (a + esqrt(a * a + 1.)))
+    };
+    copysignk(r, d)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_asinh_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(vasinhq_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_asinh_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_asinh_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_asinh_sse41(d: f64) -> f64 {
+    do_asinh_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_asinh_avx(d: f64) -> f64 {
+    let ld = _mm256_set1_pd(d);
+    _mm256_cvtsd_f64(_mm256_asinh_pd(ld))
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_asinh_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_asinh(f64x2_splat(d)))
+}
+
+/// Computes inverse hyperbolic sine, error bound *ULP 3.5*
+#[inline]
+pub fn easinh(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_asinh;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_asinh_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_asinh_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_asinh_avx(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_asinh_sse41(d) }
+            } else {
+                |d| unsafe { do_asinh_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,122 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_asinh;
+use crate::generalf::copysignfk;
+use crate::log1pf::do_log1pf;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vasinhq_f32;
+use crate::sqrtf::esqrtf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm256_asinh_ps, _mm_asinh_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn do_asinhf(d: f32) -> f32 {
+    let a = eabsf(d);
+    let r = if a < 2. {
+        let a2 = a * a;
+        do_log1pf(a + a2 / (1. + esqrtf(a2 + 1.)))
+    } else if a > 4096f32 {
+        // sqrt(a^2 + 1) == a here, so asinh(a) = ln(a) + ln(2)
+        do_log1pf(a - 1.) + std::f32::consts::LN_2
+    } else {
+        // 2a - 1 is exact for a >= 2
+        do_log1pf((a + a - 1.) + 1. / (a + esqrtf(a * a + 1.)))
+    };
+    copysignfk(r, d)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_asinhf_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vasinhq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_asinhf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_asinh_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_asinhf_sse41(d: f32) -> f32 {
+    do_asinhf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_asinhf_avx(d: f32) -> f32 {
+    let v = _mm256_set1_ps(d);
+    let value = _mm256_asinh_ps(v);
+    _mm256_cvtss_f32(value)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_asinhf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_asinh(f32x4_splat(d)))
+}
+
+/// Computes inverse hyperbolic sine, error bound *ULP 4.5*
+#[inline]
+pub fn easinhf(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_asinhf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_asinhf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_asinhf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_asinhf_avx(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_asinhf_sse41(d) }
+            } else {
+                |d| unsafe { do_asinhf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -5,17 +5,26 @@
  * // license that can be found in the LICENSE file.
  */
 
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_atan;
 use crate::generalf::mlaf;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vatanq_f64;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_atan_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
 
 // Chebyshev
 pub const ATAN_POLY_1_D: f64 = 0.9999999999999999f64;
@@ -41,7 +50,7 @@ pub const ATAN_POLY_20_D: f64 = -0.0001278956066478230268751;
 pub const ATAN_POLY_21_D: f64 = 0.00001085532590549307282752;
 
 #[inline]
-fn do_atan(d: f64) -> f64 {
+pub(crate) fn do_atan(d: f64) -> f64 {
     let mut x = d;
     let q = if x < 0f64 {
         x = -x;
@@ -88,28 +97,76 @@ fn do_atan(d: f64) -> f64 {
     u
 }
 
+#[inline]
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+fn do_atan_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(vatanq_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_atan_sse(d: f64) -> f64 {
+    let j = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_atan_pd(j))
+}
+
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_atan_sse41(d: f64) -> f64 {
+    do_atan_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_atan_fma(d: f64) -> f64 {
+    do_atan(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_atan_sse(d: f64) -> f64 {
-    unsafe {
-        let j = _mm_set1_pd(d);
-        _mm_extract_pd::<0>(_mm_atan_pd(j))
-    }
+fn do_atan_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_atan(f64x2_splat(d)))
 }
 
 #[inline]
 /// Computes atan for f64 with error bound *ULP 2.0*
 pub fn eatan(d: f64) -> f64 {
     let mut _dispatcher: fn(f64) -> f64 = do_atan;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_atan_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_atan_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_atan_sse;
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_atan_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_atan_sse41(d) }
+            } else {
+                |d| unsafe { do_atan_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
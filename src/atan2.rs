@@ -5,64 +5,119 @@
  * // license that can be found in the LICENSE file.
  */
 
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
+use crate::abs::eabs;
 use crate::atan::eatan;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_atan2;
+use crate::generalf::copysignk;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vatan2q_f64;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_atan2_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
 
 #[inline]
 fn do_atan2(y: f64, x: f64) -> f64 {
-    if x == 0. {
-        if y > 0. {
-            return std::f64::consts::FRAC_PI_2;
-        }
-        if y < 0. {
-            return -std::f64::consts::FRAC_PI_2;
-        }
-        if y == 0. {
-            return 0f64;
-        }
-    }
-    let rad = eatan(y / x);
-    return if x > 0f64 {
-        rad
-    } else if x < 0f64 && y >= 0f64 {
-        std::f64::consts::PI + rad
+    let ay = eabs(y);
+    let ax = eabs(x);
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    let mut r = if ay == 0. && ax == 0. {
+        0.
+    } else if ay.is_infinite() && ax.is_infinite() {
+        std::f64::consts::FRAC_PI_4
     } else {
-        // if x < 0. && y < 0.
-        -std::f64::consts::PI + rad
+        eatan(ay / ax)
     };
+    // Negative x, including -0, reflects the angle to the left half plane
+    if x.is_sign_negative() {
+        r = std::f64::consts::PI - r;
+    }
+    copysignk(r, y)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_atan2_neon(y: f64, x: f64) -> f64 {
+    unsafe {
+        let vx = vdupq_n_f64(x);
+        let vy = vdupq_n_f64(y);
+        vgetq_lane_f64::<0>(vatan2q_f64(vy, vx))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_atan2_sse(y: f64, x: f64) -> f64 {
+    let vx = _mm_set1_pd(x);
+    let vy = _mm_set1_pd(y);
+    _mm_extract_pd::<0>(_mm_atan2_pd(vy, vx))
 }
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_atan2_sse41(y: f64, x: f64) -> f64 {
+    do_atan2_sse(y, x)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_atan2_fma(y: f64, x: f64) -> f64 {
+    do_atan2(y, x)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_atan2_sse(y: f64, x: f64) -> f64 {
-    unsafe {
-        let vx = _mm_set1_pd(x);
-        let vy = _mm_set1_pd(y);
-        _mm_extract_pd::<0>(_mm_atan2_pd(vy, vx))
-    }
+fn do_atan2_wasm(y: f64, x: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_atan2(f64x2_splat(y), f64x2_splat(x)))
 }
 
 #[inline]
 pub fn eatan2(y: f64, x: f64) -> f64 {
     let mut _dispatcher: fn(f64, f64) -> f64 = do_atan2;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_atan2_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_atan2_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_atan2_sse;
+        static DISPATCHER: OnceLock<fn(f64, f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |y, x| unsafe { do_atan2_fma(y, x) }
+            } else if is_sse41_available() {
+                |y, x| unsafe { do_atan2_sse41(y, x) }
+            } else {
+                |y, x| unsafe { do_atan2_sse(y, x) }
+            }
+        });
     }
     _dispatcher(y, x)
 }
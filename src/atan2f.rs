@@ -5,42 +5,44 @@
  * // license that can be found in the LICENSE file.
  */
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_atan2_ps;
+use crate::abs::eabsf;
 use crate::atanf::eatanf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_atan2;
+use crate::generalf::copysignfk;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vatan2q_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_atan2_ps, _mm_extract_psx};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 fn do_atan2f(y: f32, x: f32) -> f32 {
-    if x == 0. {
-        if y > 0. {
-            return std::f32::consts::FRAC_PI_2;
-        }
-        if y < 0. {
-            return -std::f32::consts::FRAC_PI_2;
-        }
-        if y == 0. {
-            return 0f32;
-        }
-    }
-    let rad = eatanf(y / x);
-    return if x > 0f32 {
-        rad
-    } else if x < 0f32 && y >= 0f32 {
-        std::f32::consts::PI + rad
+    let ay = eabsf(y);
+    let ax = eabsf(x);
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    let mut r = if ay == 0. && ax == 0. {
+        0.
+    } else if ay.is_infinite() && ax.is_infinite() {
+        std::f32::consts::FRAC_PI_4
     } else {
-        // if x < 0. && y < 0.
-        -std::f32::consts::PI + rad
+        eatanf(ay / ax)
     };
+    // Negative x, including -0, reflects the angle to the left half plane
+    if x.is_sign_negative() {
+        r = std::f32::consts::PI - r;
+    }
+    copysignfk(r, y)
 }
 
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
@@ -55,15 +57,37 @@ fn do_atan2f_neon(y: f32, x: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
 #[inline(always)]
-fn do_atan2f_sse(y: f32, x: f32) -> f32 {
-    unsafe {
-        let vy = _mm_set1_ps(y);
-        let vx = _mm_set1_ps(x);
-        f32::from_bits(_mm_extract_ps::<0>(_mm_atan2_ps(vy, vx)) as u32)
-    }
+unsafe fn do_atan2f_sse(y: f32, x: f32) -> f32 {
+    let vy = _mm_set1_ps(y);
+    let vx = _mm_set1_ps(x);
+    f32::from_bits(_mm_extract_psx::<0>(_mm_atan2_ps(vy, vx)) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_atan2f_sse41(y: f32, x: f32) -> f32 {
+    do_atan2f_sse(y, x)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_atan2f_fma(y: f32, x: f32) -> f32 {
+    do_atan2f(y, x)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_atan2f_wasm(y: f32, x: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_atan2(f32x4_splat(y), f32x4_splat(x)))
 }
 
 /// Computes atan2 between vector, *ULP 1.0*
@@ -74,12 +98,25 @@ pub fn eatan2f(y: f32, x: f32) -> f32 {
     {
         _dispatcher = do_atan2f_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_atan2f_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_atan2f_sse;
+        static DISPATCHER: OnceLock<fn(f32, f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |y, x| unsafe { do_atan2f_fma(y, x) }
+            } else if is_sse41_available() {
+                |y, x| unsafe { do_atan2f_sse41(y, x) }
+            } else {
+                |y, x| unsafe { do_atan2f_sse(y, x) }
+            }
+        });
     }
     _dispatcher(y, x)
 }
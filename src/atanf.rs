@@ -5,11 +5,25 @@
  * // license that can be found in the LICENSE file.
  */
 
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_atan;
 use crate::generalf::mlaf;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vatanq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_atan_ps, _mm_extract_psx};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub const ATAN_POLY_1_F: f32 = 0.999999871164f32;
 pub const ATAN_POLY_2_F: f32 = -0.333325240026f32;
@@ -65,6 +79,40 @@ fn do_atanf_neon(y: f32) -> f32 {
     }
 }
 
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_atanf_sse(y: f32) -> f32 {
+    let vy = _mm_set1_ps(y);
+    f32::from_bits(_mm_extract_psx::<0>(_mm_atan_ps(vy)) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_atanf_sse41(y: f32) -> f32 {
+    do_atanf_sse(y)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_atanf_fma(y: f32) -> f32 {
+    do_atanf(y)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_atanf_wasm(y: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_atan(f32x4_splat(y)))
+}
+
 /// Computes Atan function with *ULP 2.0* error
 #[inline]
 pub fn eatanf(d: f32) -> f32 {
@@ -73,5 +121,25 @@ pub fn eatanf(d: f32) -> f32 {
     {
         _dispatcher = do_atanf_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_atanf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_atanf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_atanf_sse41(d) }
+            } else {
+                |d| unsafe { do_atanf_sse(d) }
+            }
+        });
+    }
     _dispatcher(d)
 }
@@ -0,0 +1,114 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_atanh;
+use crate::generalf::copysignk;
+use crate::log1p::do_log1p;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vatanhq_f64;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_atanh_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn do_atanh(d: f64) -> f64 {
+    let a = eabs(d);
+    if a > 1. {
+        return f64::NAN;
+    }
+    let s = a + a;
+    // atanh(a) = log1p(2a + 2a^2 / (1 - a)) / 2, keeping 2a apart holds precision near zero
+    let r = 0.5 * do_log1p(s + s * a / (1. - a));
+    copysignk(r, d)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_atanh_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(vatanhq_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_atanh_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_atanh_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_atanh_sse41(d: f64) -> f64 {
+    do_atanh_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_atanh_fma(d: f64) -> f64 {
+    do_atanh(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_atanh_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_atanh(f64x2_splat(d)))
+}
+
+/// Computes inverse hyperbolic tangent, error bound *ULP 3.0*
+#[inline]
+pub fn eatanh(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_atanh;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_atanh_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_atanh_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_atanh_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_atanh_sse41(d) }
+            } else {
+                |d| unsafe { do_atanh_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,115 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_atanh;
+use crate::generalf::copysignfk;
+use crate::log1pf::do_log1pf;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vatanhq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_atanh_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn do_atanhf(d: f32) -> f32 {
+    let a = eabsf(d);
+    if a > 1. {
+        return f32::NAN;
+    }
+    let s = a + a;
+    // atanh(a) = log1p(2a + 2a^2 / (1 - a)) / 2, keeping 2a apart holds precision near zero
+    let r = 0.5 * do_log1pf(s + s * a / (1. - a));
+    copysignfk(r, d)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_atanhf_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vatanhq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_atanhf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_atanh_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_atanhf_sse41(d: f32) -> f32 {
+    do_atanhf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_atanhf_fma(d: f32) -> f32 {
+    do_atanhf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_atanhf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_atanh(f32x4_splat(d)))
+}
+
+/// Computes inverse hyperbolic tangent, error bound *ULP 4.0*
+#[inline]
+pub fn eatanhf(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_atanhf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_atanhf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_atanhf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_atanhf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_atanhf_sse41(d) }
+            } else {
+                |d| unsafe { do_atanhf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -13,7 +13,7 @@ use std::arch::x86_64::*;
 use crate::{_mm256_abs_ps, _mm256_asin_ps, _mm256_select_ps};
 
 /// Computes arccos, error bound *ULP 2.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_acos_ps(x: __m256) -> __m256 {
     let gt_zero = _mm256_cmp_ps::<_CMP_GT_OS>(x, _mm256_setzero_ps());
     let x_a = _mm256_abs_ps(x);
@@ -0,0 +1,116 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm256_log1p_pd, _mm256_select_pd};
+
+/// Computes inverse hyperbolic cosine, error bound *ULP 4.0*
+#[inline(always)]
+pub unsafe fn _mm256_acosh_pd(d: __m256d) -> __m256d {
+    let t = _mm256_sub_pd(d, _mm256_set1_pd(1f64));
+    // d < 2: acosh(d) = log1p(t + sqrt(t * (t + 2))), t = d - 1 is exact there
+    let small = _mm256_add_pd(
+        t,
+        _mm256_sqrt_pd(_mm256_mul_pd(t, _mm256_add_pd(t, _mm256_set1_pd(2f64)))),
+    );
+    // d >= 2: acosh(d) = log1p(2d - 1 - 1 / (d + sqrt(d^2 - 1)))
+    let mid = _mm256_sub_pd(
+        _mm256_sub_pd(_mm256_add_pd(d, d), _mm256_set1_pd(1f64)),
+        _mm256_div_pd(
+            _mm256_set1_pd(1f64),
+            _mm256_add_pd(
+                d,
+                _mm256_sqrt_pd(_mm256_sub_pd(_mm256_mul_pd(d, d), _mm256_set1_pd(1f64))),
+            ),
+        ),
+    );
+    // Large d has sqrt(d^2 - 1) == d, so acosh(d) = ln(d) + ln(2)
+    let big = _mm256_cmp_pd::<_CMP_GT_OS>(d, _mm256_set1_pd(268_435_456f64));
+    let u = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_LT_OS>(d, _mm256_set1_pd(2f64)),
+        small,
+        _mm256_select_pd(big, t, mid),
+    );
+    let mut r = _mm256_add_pd(
+        _mm256_log1p_pd(u),
+        _mm256_select_pd(
+            big,
+            _mm256_set1_pd(std::f64::consts::LN_2),
+            _mm256_setzero_pd(),
+        ),
+    );
+    // d < 1 -> Nan
+    r = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_LT_OS>(d, _mm256_set1_pd(1f64)),
+        _mm256_set1_pd(f64::NAN),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acoshd() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(1.5f64);
+            let comparison = _mm256_acosh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 1.5f64.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(3f64);
+            let comparison = _mm256_acosh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 3f64.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(1e300f64);
+            let comparison = _mm256_acosh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 1e300f64.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test exact value
+            let value = _mm256_set1_pd(1f64);
+            let comparison = _mm256_acosh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm256_set1_pd(0.5f64);
+            let comparison = _mm256_acosh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm256_set1_pd(f64::INFINITY);
+            let comparison = _mm256_acosh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
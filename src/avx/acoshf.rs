@@ -0,0 +1,140 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm256_log1p_ps, _mm256_select_ps};
+
+/// Computes inverse hyperbolic cosine, error bound *ULP 4.5*
+#[inline(always)]
+pub unsafe fn _mm256_acosh_ps(d: __m256) -> __m256 {
+    let t = _mm256_sub_ps(d, _mm256_set1_ps(1f32));
+    // d < 2: acosh(d) = log1p(t + sqrt(t * (t + 2))), t = d - 1 is exact there
+    let small = _mm256_add_ps(
+        t,
+        _mm256_sqrt_ps(_mm256_mul_ps(t, _mm256_add_ps(t, _mm256_set1_ps(2f32)))),
+    );
+    // d >= 2: acosh(d) = log1p(2d - 1 - 1 / (d + sqrt(d^2 - 1)))
+    let mid = _mm256_sub_ps(
+        _mm256_sub_ps(_mm256_add_ps(d, d), _mm256_set1_ps(1f32)),
+        _mm256_div_ps(
+            _mm256_set1_ps(1f32),
+            _mm256_add_ps(
+                d,
+                _mm256_sqrt_ps(_mm256_sub_ps(_mm256_mul_ps(d, d), _mm256_set1_ps(1f32))),
+            ),
+        ),
+    );
+    // Large d has sqrt(d^2 - 1) == d, so acosh(d) = ln(d) + ln(2)
+    let big = _mm256_cmp_ps::<_CMP_GT_OS>(d, _mm256_set1_ps(4096f32));
+    let u = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_set1_ps(2f32)),
+        small,
+        _mm256_select_ps(big, t, mid),
+    );
+    let mut r = _mm256_add_ps(
+        _mm256_log1p_ps(u),
+        _mm256_select_ps(
+            big,
+            _mm256_set1_ps(std::f32::consts::LN_2),
+            _mm256_setzero_ps(),
+        ),
+    );
+    // d < 1 -> Nan
+    r = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_set1_ps(1f32)),
+        _mm256_set1_ps(f32::NAN),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acoshf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(1.5f32);
+            let comparison = _mm256_acosh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1.5f32.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(3f32);
+            let comparison = _mm256_acosh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 3f32.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(1e30f32);
+            let comparison = _mm256_acosh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1e30f32.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test exact value
+            let value = _mm256_set1_ps(1f32);
+            let comparison = _mm256_acosh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm256_set1_ps(0.5f32);
+            let comparison = _mm256_acosh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm256_set1_ps(f32::INFINITY);
+            let comparison = _mm256_acosh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+
+    #[test]
+    fn test_acoshf_ulp() {
+        // Distance to one is log-uniform from 1e-7 to 1e30
+        let mut state = 0x5851_f42d_4c95_7f2du64;
+        for _ in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let x = (1. + 10f64.powf(u * 37. - 7.)) as f32;
+            let control = (x as f64).acosh();
+            let value = unsafe { _mm256_cvtss_f32(_mm256_acosh_ps(_mm256_set1_ps(x))) };
+            assert!(
+                ulp(value, control) <= 4.5,
+                "acosh({x}) = {value}, expected {control}"
+            );
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
+}
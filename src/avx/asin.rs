@@ -19,7 +19,7 @@ use crate::asin::{
 use crate::{_mm256_abs_pd, _mm256_copysign_pd, _mm256_mlaf_pd, _mm256_select_pd};
 
 /// Computes arcsin, error bound *ULP 2.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_asin_pd(d: __m256d) -> __m256d {
     let ones = _mm256_set1_pd(1.);
     let ca = _mm256_abs_pd(d);
@@ -50,7 +50,7 @@ pub unsafe fn _mm256_asin_pd(d: __m256d) -> __m256d {
     u = _mm256_mlaf_pd(u, x, _mm256_set1_pd(ASIN_POLY_1_D));
     u = _mm256_mul_pd(u, x);
     let j = u;
-    let reconstruct_reversed = _mm256_select_pd(
+    let reconstruct_reversed = _mm256_mlaf_pd(
         _mm256_set1_pd(-2f64),
         j,
         _mm256_set1_pd(std::f64::consts::FRAC_PI_2),
@@ -69,6 +69,13 @@ mod tests {
 
     #[test]
     fn test_asind() {
+        unsafe {
+            let value = _mm256_set1_pd(0.7);
+            let comparison = _mm256_asin_pd(value);
+            let flag_1 = _mm256_extract_pd::<1>(comparison);
+            let control = 0.775397496610753f64;
+            assert!((flag_1 - control).abs() < 1e-15);
+        }
         unsafe {
             let value = _mm256_set1_pd(0.3);
             let comparison = _mm256_asin_pd(value);
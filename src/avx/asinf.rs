@@ -17,7 +17,7 @@ use crate::avx::generalf::_mm256_copysign_ps;
 use crate::{_mm256_abs_ps, _mm256_mlaf_ps, _mm256_select_ps};
 
 /// Computes arcsin, error bound *ULP 2.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_asin_ps(d: __m256) -> __m256 {
     let ones = _mm256_set1_ps(1f32);
     let ca = _mm256_abs_ps(d);
@@ -65,7 +65,7 @@ pub unsafe fn _mm256_asin_ps(d: __m256) -> __m256 {
     );
     u = _mm256_mul_ps(u, x);
     let j = u;
-    let reconstruct_reversed = _mm256_select_ps(
+    let reconstruct_reversed = _mm256_mlaf_ps(
         _mm256_set1_ps(-2f32),
         j,
         _mm256_set1_ps(std::f32::consts::FRAC_PI_2),
@@ -84,6 +84,13 @@ mod tests {
 
     #[test]
     fn test_asinf() {
+        unsafe {
+            let value = _mm256_set1_ps(0.7);
+            let comparison = _mm256_asin_ps(value);
+            let flag_1 = _mm256_extract_ps::<1>(comparison);
+            let control = 0.775397496610753f32;
+            assert!((flag_1 - control).abs() < 1e-6);
+        }
         unsafe {
             let value = _mm256_set1_ps(0.3);
             let comparison = _mm256_asin_ps(value);
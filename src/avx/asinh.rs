@@ -0,0 +1,107 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm256_abs_pd, _mm256_copysign_pd, _mm256_log1p_pd, _mm256_select_pd};
+
+/// Computes inverse hyperbolic sine, error bound *ULP 3.5*
+#[inline(always)]
+pub unsafe fn _mm256_asinh_pd(d: __m256d) -> __m256d {
+    let a = _mm256_abs_pd(d);
+    let a2 = _mm256_mul_pd(a, a);
+    // a < 2: asinh(a) = log1p(a + a^2 / (1 + sqrt(1 + a^2)))
+    let small = _mm256_add_pd(
+        a,
+        _mm256_div_pd(
+            a2,
+            _mm256_add_pd(
+                _mm256_set1_pd(1f64),
+                _mm256_sqrt_pd(_mm256_add_pd(a2, _mm256_set1_pd(1f64))),
+            ),
+        ),
+    );
+    // a >= 2: asinh(a) = log1p(2a - 1 + 1 / (a + sqrt(a^2 + 1))), 2a - 1 is exact there
+    let mid = _mm256_add_pd(
+        _mm256_sub_pd(_mm256_add_pd(a, a), _mm256_set1_pd(1f64)),
+        _mm256_div_pd(
+            _mm256_set1_pd(1f64),
+            _mm256_add_pd(a, _mm256_sqrt_pd(_mm256_add_pd(a2, _mm256_set1_pd(1f64)))),
+        ),
+    );
+    // Large a has sqrt(a^2 + 1) == a, so asinh(a) = ln(a) + ln(2)
+    let big = _mm256_cmp_pd::<_CMP_GT_OS>(a, _mm256_set1_pd(268_435_456f64));
+    let u = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_LT_OS>(a, _mm256_set1_pd(2f64)),
+        small,
+        _mm256_select_pd(big, _mm256_sub_pd(a, _mm256_set1_pd(1f64)), mid),
+    );
+    let r = _mm256_add_pd(
+        _mm256_log1p_pd(u),
+        _mm256_select_pd(
+            big,
+            _mm256_set1_pd(std::f64::consts::LN_2),
+            _mm256_setzero_pd(),
+        ),
+    );
+    _mm256_copysign_pd(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asinhd() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.5f64);
+            let comparison = _mm256_asinh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 0.5f64.asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(-1e-10f64);
+            let comparison = _mm256_asinh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = (-1e-10f64).asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(-3f64);
+            let comparison = _mm256_asinh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = (-3f64).asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(1e300f64);
+            let comparison = _mm256_asinh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 1e300f64.asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm256_set1_pd(f64::NEG_INFINITY);
+            let comparison = _mm256_asinh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::generalf::_mm256_copysign_ps;
+use crate::{_mm256_abs_ps, _mm256_log1p_ps, _mm256_select_ps};
+
+/// Computes inverse hyperbolic sine, error bound *ULP 4.5*
+#[inline(always)]
+pub unsafe fn _mm256_asinh_ps(d: __m256) -> __m256 {
+    let a = _mm256_abs_ps(d);
+    let a2 = _mm256_mul_ps(a, a);
+    // a < 2: asinh(a) = log1p(a + a^2 / (1 + sqrt(1 + a^2)))
+    let small = _mm256_add_ps(
+        a,
+        _mm256_div_ps(
+            a2,
+            _mm256_add_ps(
+                _mm256_set1_ps(1f32),
+                _mm256_sqrt_ps(_mm256_add_ps(a2, _mm256_set1_ps(1f32))),
+            ),
+        ),
+    );
+    // a >= 2: asinh(a) = log1p(2a - 1 + 1 / (a + sqrt(a^2 + 1))), 2a - 1 is exact there
+    let mid = _mm256_add_ps(
+        _mm256_sub_ps(_mm256_add_ps(a, a), _mm256_set1_ps(1f32)),
+        _mm256_div_ps(
+            _mm256_set1_ps(1f32),
+            _mm256_add_ps(a, _mm256_sqrt_ps(_mm256_add_ps(a2, _mm256_set1_ps(1f32)))),
+        ),
+    );
+    // Large a has sqrt(a^2 + 1) == a, so asinh(a) = ln(a) + ln(2)
+    let big = _mm256_cmp_ps::<_CMP_GT_OS>(a, _mm256_set1_ps(4096f32));
+    let u = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(a, _mm256_set1_ps(2f32)),
+        small,
+        _mm256_select_ps(big, _mm256_sub_ps(a, _mm256_set1_ps(1f32)), mid),
+    );
+    let r = _mm256_add_ps(
+        _mm256_log1p_ps(u),
+        _mm256_select_ps(
+            big,
+            _mm256_set1_ps(std::f32::consts::LN_2),
+            _mm256_setzero_ps(),
+        ),
+    );
+    _mm256_copysign_ps(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asinhf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.5f32);
+            let comparison = _mm256_asinh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 0.5f32.asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-1e-10f32);
+            let comparison = _mm256_asinh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = (-1e-10f32).asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-3f32);
+            let comparison = _mm256_asinh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = (-3f32).asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(1e30f32);
+            let comparison = _mm256_asinh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1e30f32.asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm256_set1_ps(f32::NEG_INFINITY);
+            let comparison = _mm256_asinh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+    }
+}
@@ -9,54 +9,37 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-use crate::{_mm256_atan_pd, _mm256_select_pd};
+use crate::{_mm256_abs_pd, _mm256_atan_pd, _mm256_copysign_pd, _mm256_isinf_pd, _mm256_select_pd};
 
 /// Computes atan for Y,X
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_atan2_pd(y: __m256d, x: __m256d) -> __m256d {
-    let zero_x_mask = _mm256_cmp_pd::<_CMP_EQ_OS>(x, _mm256_setzero_pd());
-    let yx = _mm256_atan_pd(_mm256_div_pd(y, x));
-    let mut rad = yx;
-    rad = _mm256_select_pd(
+    let ay = _mm256_abs_pd(y);
+    let ax = _mm256_abs_pd(x);
+    let mut r = _mm256_atan_pd(_mm256_div_pd(ay, ax));
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    r = _mm256_select_pd(
         _mm256_and_pd(
-            zero_x_mask,
-            _mm256_cmp_pd::<_CMP_GE_OS>(y, _mm256_setzero_pd()),
+            _mm256_cmp_pd::<_CMP_EQ_OQ>(ay, _mm256_setzero_pd()),
+            _mm256_cmp_pd::<_CMP_EQ_OQ>(ax, _mm256_setzero_pd()),
         ),
-        _mm256_set1_pd(std::f64::consts::FRAC_PI_2),
-        rad,
-    ); // x == 0 && y > 0.
-    rad = _mm256_select_pd(
-        _mm256_and_pd(
-            zero_x_mask,
-            _mm256_cmp_pd::<_CMP_LE_OS>(y, _mm256_setzero_pd()),
-        ),
-        _mm256_set1_pd(-std::f64::consts::FRAC_PI_2),
-        rad,
-    ); // x == 0 && y < 0.
-    rad = _mm256_select_pd(
-        _mm256_and_pd(
-            zero_x_mask,
-            _mm256_cmp_pd::<_CMP_LE_OS>(y, _mm256_setzero_pd()),
-        ),
-        _mm256_set1_pd(0.),
-        rad,
-    ); // x == 0 && y == 0.
-    let x_lower_than_0 = _mm256_cmp_pd::<_CMP_LT_OS>(x, _mm256_setzero_pd());
-    rad = _mm256_select_pd(
-        _mm256_and_pd(
-            x_lower_than_0,
-            _mm256_cmp_pd::<_CMP_GE_OS>(y, _mm256_setzero_pd()),
-        ),
-        _mm256_add_pd(yx, _mm256_set1_pd(std::f64::consts::PI)),
-        rad,
-    ); // x < 0 && y >= 0
-    rad = _mm256_select_pd(
-        _mm256_and_pd(
-            x_lower_than_0,
-            _mm256_cmp_pd::<_CMP_LT_OS>(y, _mm256_setzero_pd()),
-        ),
-        _mm256_add_pd(yx, _mm256_set1_pd(-std::f64::consts::PI)),
-        rad,
-    ); // x < 0 && y < 0
-    rad
+        _mm256_setzero_pd(),
+        r,
+    );
+    r = _mm256_select_pd(
+        _mm256_and_pd(_mm256_isinf_pd(ay), _mm256_isinf_pd(ax)),
+        _mm256_set1_pd(std::f64::consts::FRAC_PI_4),
+        r,
+    );
+    // Negative x, including -0, reflects the angle to the left half plane
+    let x_neg = _mm256_cmp_pd::<_CMP_LT_OQ>(
+        _mm256_copysign_pd(_mm256_set1_pd(1.), x),
+        _mm256_setzero_pd(),
+    );
+    r = _mm256_select_pd(
+        x_neg,
+        _mm256_sub_pd(_mm256_set1_pd(std::f64::consts::PI), r),
+        r,
+    );
+    _mm256_copysign_pd(r, y)
 }
@@ -10,51 +10,35 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-use crate::{_mm256_atan_ps, _mm256_eqzero_ps, _mm256_ltzero_ps, _mm256_select_ps};
+use crate::avx::generalf::_mm256_copysign_ps;
+use crate::{_mm256_abs_ps, _mm256_atan_ps, _mm256_eqzero_ps, _mm256_isinf_ps, _mm256_select_ps};
 
 /// Computes atan for Y,X
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_atan2_ps(y: __m256, x: __m256) -> __m256 {
-    let zero_x_mask = _mm256_eqzero_ps(x);
-    let yx = _mm256_atan_ps(_mm256_div_ps(y, x));
-    let mut rad = yx;
-    rad = _mm256_select_ps(
-        _mm256_and_ps(
-            zero_x_mask,
-            _mm256_cmp_ps::<_CMP_GE_OS>(y, _mm256_setzero_ps()),
-        ),
-        _mm256_set1_ps(std::f32::consts::FRAC_PI_2),
-        rad,
-    ); // x == 0 && y > 0.
-    rad = _mm256_select_ps(
-        _mm256_and_ps(
-            zero_x_mask,
-            _mm256_cmp_ps::<_CMP_LE_OS>(y, _mm256_setzero_ps()),
-        ),
-        _mm256_set1_ps(-std::f32::consts::FRAC_PI_2),
-        rad,
-    ); // x == 0 && y < 0.
-    rad = _mm256_select_ps(
-        _mm256_and_ps(
-            zero_x_mask,
-            _mm256_cmp_ps::<_CMP_LE_OS>(y, _mm256_setzero_ps()),
-        ),
-        _mm256_set1_ps(0f32),
-        rad,
-    ); // x == 0 && y == 0.
-    let x_lower_than_0 = _mm256_ltzero_ps(x);
-    rad = _mm256_select_ps(
-        _mm256_and_ps(
-            x_lower_than_0,
-            _mm256_cmp_ps::<_CMP_GE_OS>(y, _mm256_setzero_ps()),
-        ),
-        _mm256_add_ps(yx, _mm256_set1_ps(std::f32::consts::PI)),
-        rad,
-    ); // x < 0 && y >= 0
-    rad = _mm256_select_ps(
-        _mm256_and_ps(x_lower_than_0, _mm256_ltzero_ps(y)),
-        _mm256_add_ps(yx, _mm256_set1_ps(-std::f32::consts::PI)),
-        rad,
-    ); // x < 0 && y < 0
-    rad
+    let ay = _mm256_abs_ps(y);
+    let ax = _mm256_abs_ps(x);
+    let mut r = _mm256_atan_ps(_mm256_div_ps(ay, ax));
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    r = _mm256_select_ps(
+        _mm256_and_ps(_mm256_eqzero_ps(ay), _mm256_eqzero_ps(ax)),
+        _mm256_setzero_ps(),
+        r,
+    );
+    r = _mm256_select_ps(
+        _mm256_and_ps(_mm256_isinf_ps(ay), _mm256_isinf_ps(ax)),
+        _mm256_set1_ps(std::f32::consts::FRAC_PI_4),
+        r,
+    );
+    // Negative x, including -0, reflects the angle to the left half plane
+    let x_neg = _mm256_cmp_ps::<_CMP_LT_OQ>(
+        _mm256_copysign_ps(_mm256_set1_ps(1.), x),
+        _mm256_setzero_ps(),
+    );
+    r = _mm256_select_ps(
+        x_neg,
+        _mm256_sub_ps(_mm256_set1_ps(std::f32::consts::PI), r),
+        r,
+    );
+    _mm256_copysign_ps(r, y)
 }
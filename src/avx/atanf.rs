@@ -13,12 +13,13 @@ use crate::atanf::{
     ATAN_POLY_1_F, ATAN_POLY_2_F, ATAN_POLY_3_F, ATAN_POLY_4_F, ATAN_POLY_5_F, ATAN_POLY_6_F,
     ATAN_POLY_7_F, ATAN_POLY_8_F, ATAN_POLY_9_F,
 };
-use crate::{_mm256_abs_ps, _mm256_ltzero_ps, _mm256_mlaf_ps, _mm256_neg_ps, _mm256_select_ps};
+use crate::{_mm256_abs_ps, _mm256_mlaf_ps, _mm256_select_ps};
 
 /// Computes Atan function with *ULP 1.0* error
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_atan_ps(x: __m256) -> __m256 {
-    let negative_mask = _mm256_ltzero_ps(x);
+    // Sign bit is restored at the end so that atan(-0) = -0
+    let sign = _mm256_and_ps(x, _mm256_set1_ps(-0f32));
     let d = _mm256_abs_ps(x);
     let more_than_one_mask = _mm256_cmp_ps::<_CMP_GE_OS>(d, _mm256_set1_ps(1f32));
     let x = _mm256_select_ps(
@@ -42,7 +43,7 @@ pub unsafe fn _mm256_atan_ps(x: __m256) -> __m256 {
         _mm256_sub_ps(_mm256_set1_ps(std::f32::consts::FRAC_PI_2), u),
         u,
     );
-    u = _mm256_select_ps(negative_mask, _mm256_neg_ps(u), u);
+    u = _mm256_xor_ps(u, sign);
     u
 }
 
@@ -0,0 +1,83 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm256_abs_pd, _mm256_copysign_pd, _mm256_log1p_pd, _mm256_select_pd};
+
+/// Computes inverse hyperbolic tangent, error bound *ULP 3.0*
+#[inline(always)]
+pub unsafe fn _mm256_atanh_pd(d: __m256d) -> __m256d {
+    let a = _mm256_abs_pd(d);
+    let s = _mm256_add_pd(a, a);
+    // atanh(a) = log1p(2a + 2a^2 / (1 - a)) / 2, keeping 2a apart holds precision near zero
+    let u = _mm256_add_pd(
+        s,
+        _mm256_div_pd(_mm256_mul_pd(s, a), _mm256_sub_pd(_mm256_set1_pd(1f64), a)),
+    );
+    let mut r = _mm256_mul_pd(_mm256_set1_pd(0.5f64), _mm256_log1p_pd(u));
+    // |d| > 1 -> Nan
+    r = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_GT_OS>(a, _mm256_set1_pd(1f64)),
+        _mm256_set1_pd(f64::NAN),
+        r,
+    );
+    _mm256_copysign_pd(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atanhd() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.5f64);
+            let comparison = _mm256_atanh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 0.5f64.atanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(-1e-10f64);
+            let comparison = _mm256_atanh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = (-1e-10f64).atanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_pd(1f64);
+            let comparison = _mm256_atanh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_pd(-1f64);
+            let comparison = _mm256_atanh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm256_set1_pd(2f64);
+            let comparison = _mm256_atanh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
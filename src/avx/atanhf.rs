@@ -0,0 +1,84 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::generalf::_mm256_copysign_ps;
+use crate::{_mm256_abs_ps, _mm256_log1p_ps, _mm256_select_ps};
+
+/// Computes inverse hyperbolic tangent, error bound *ULP 4.0*
+#[inline(always)]
+pub unsafe fn _mm256_atanh_ps(d: __m256) -> __m256 {
+    let a = _mm256_abs_ps(d);
+    let s = _mm256_add_ps(a, a);
+    // atanh(a) = log1p(2a + 2a^2 / (1 - a)) / 2, keeping 2a apart holds precision near zero
+    let u = _mm256_add_ps(
+        s,
+        _mm256_div_ps(_mm256_mul_ps(s, a), _mm256_sub_ps(_mm256_set1_ps(1f32), a)),
+    );
+    let mut r = _mm256_mul_ps(_mm256_set1_ps(0.5f32), _mm256_log1p_ps(u));
+    // |d| > 1 -> Nan
+    r = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_GT_OS>(a, _mm256_set1_ps(1f32)),
+        _mm256_set1_ps(f32::NAN),
+        r,
+    );
+    _mm256_copysign_ps(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atanhf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.5f32);
+            let comparison = _mm256_atanh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 0.5f32.atanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-1e-10f32);
+            let comparison = _mm256_atanh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = (-1e-10f32).atanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_ps(1f32);
+            let comparison = _mm256_atanh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_ps(-1f32);
+            let comparison = _mm256_atanh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm256_set1_ps(2f32);
+            let comparison = _mm256_atanh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,182 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::cbrt::{
+    CBRT_HUGE_D, CBRT_HUGE_SCALE_D, CBRT_HUGE_SCALE_R_D, CBRT_SCALE_D, CBRT_SCALE_R_D,
+};
+use crate::{
+    _mm256_abs_pd, _mm256_eqzero_pd, _mm256_isinf_pd, _mm256_isneginf_pd, _mm256_mlaf_pd,
+    _mm256_select_pd,
+};
+
+#[inline(always)]
+unsafe fn halley_cbrt(x: __m256d, a: __m256d) -> __m256d {
+    let tx = _mm256_mul_pd(_mm256_mul_pd(x, x), x);
+    let twos = _mm256_set1_pd(2.);
+    let num = _mm256_mlaf_pd(twos, a, tx);
+    let den = _mm256_mlaf_pd(twos, tx, a);
+    let scale = _mm256_div_pd(num, den);
+    _mm256_mul_pd(x, scale)
+}
+
+/// Newton step from t rounded to 22 bits, t*t is exact so the result is within 0.67 ulp
+#[inline(always)]
+unsafe fn newton_round(t: __m256d, a: __m256d) -> __m256d {
+    #[allow(overflowing_literals)]
+    let t = _mm256_castsi256_pd(_mm256_and_si256(
+        _mm256_add_epi64(_mm256_castpd_si256(t), _mm256_set1_epi64x(0x8000_0000)),
+        _mm256_set1_epi64x(0xffff_ffff_c000_0000),
+    ));
+    let s = _mm256_mul_pd(t, t);
+    let r = _mm256_div_pd(a, s);
+    let w = _mm256_add_pd(t, t);
+    let r = _mm256_div_pd(_mm256_sub_pd(r, t), _mm256_add_pd(w, r));
+    _mm256_add_pd(t, _mm256_mul_pd(t, r))
+}
+
+#[inline(always)]
+unsafe fn integer_pow_1_3(hx: __m256i) -> __m256i {
+    // hx / 3 as (hx * 0xAAAAAAAB) >> 33, exact for every 31-bit high word
+    let third = _mm256_srli_epi64::<33>(_mm256_mul_epu32(hx, _mm256_set1_epi64x(0xAAAAAAAB)));
+    _mm256_add_epi64(third, _mm256_set1_epi64x(715094163))
+}
+
+/// Takes cube root from value *ULP 1.5*, Skipping NaN, Inf checks
+#[inline(always)]
+pub unsafe fn _mm256_cbrt_fast_pd(x: __m256d) -> __m256d {
+    let mut ui = _mm256_castpd_si256(x);
+    let hx = _mm256_and_si256(_mm256_srli_epi64::<32>(ui), _mm256_set1_epi64x(0x7fffffff));
+
+    let hx = integer_pow_1_3(hx);
+
+    #[allow(overflowing_literals)]
+    let m = _mm256_set1_epi64x(1 << 63);
+    ui = _mm256_and_si256(ui, m);
+    ui = _mm256_or_si256(ui, _mm256_slli_epi64::<32>(hx));
+
+    let t = _mm256_castsi256_pd(ui);
+
+    let c0 = halley_cbrt(t, x);
+    let c1 = halley_cbrt(c0, x);
+    let c2 = halley_cbrt(c1, x);
+    let c2 = newton_round(c2, x);
+    let v = _mm256_select_pd(_mm256_eqzero_pd(x), x, c2);
+    v
+}
+
+/// Takes cube root from value *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm256_cbrt_pd(x: __m256d) -> __m256d {
+    // Subnormals and the top binades are scaled by powers of 8, the initial guess
+    // and x^3 in Halley step are out of range for them
+    let ax = _mm256_abs_pd(x);
+    let tiny = _mm256_cmp_pd::<_CMP_LT_OQ>(ax, _mm256_set1_pd(f64::MIN_POSITIVE));
+    let huge = _mm256_cmp_pd::<_CMP_GT_OQ>(ax, _mm256_set1_pd(CBRT_HUGE_D));
+    let scale_in = _mm256_select_pd(
+        tiny,
+        _mm256_set1_pd(CBRT_SCALE_D),
+        _mm256_select_pd(huge, _mm256_set1_pd(CBRT_HUGE_SCALE_D), _mm256_set1_pd(1.)),
+    );
+    let scale_out = _mm256_select_pd(
+        tiny,
+        _mm256_set1_pd(1. / CBRT_SCALE_R_D),
+        _mm256_select_pd(
+            huge,
+            _mm256_set1_pd(CBRT_HUGE_SCALE_R_D),
+            _mm256_set1_pd(1.),
+        ),
+    );
+    let c1 = _mm256_mul_pd(_mm256_cbrt_fast_pd(_mm256_mul_pd(x, scale_in)), scale_out);
+    let mut v = _mm256_select_pd(_mm256_isinf_pd(x), _mm256_set1_pd(f64::INFINITY), c1);
+    v = _mm256_select_pd(_mm256_isneginf_pd(x), _mm256_set1_pd(f64::NEG_INFINITY), v);
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::_mm256_extract_pd;
+
+    use super::*;
+
+    #[test]
+    fn test_cbrtd() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.0201934222);
+            let comparison = _mm256_cbrt_pd(value);
+            let flag_1 = _mm256_extract_pd::<3>(comparison);
+            assert_eq!(flag_1, 0.27231400353631246186777917307287727f64);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.0201934222);
+            let comparison = _mm256_cbrt_fast_pd(value);
+            let flag_1 = _mm256_extract_pd::<3>(comparison);
+            assert_eq!(flag_1, 0.27231400353631246186777917307287727f64);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(27f64);
+            let comparison = _mm256_cbrt_pd(value);
+            let flag_1 = _mm256_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 3f64);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.5);
+            let comparison = _mm256_cbrt_pd(value);
+            let flag_1 = _mm256_extract_pd::<2>(comparison);
+            assert_eq!(flag_1, 0.793700525984099737375f64);
+        }
+
+        unsafe {
+            // Test big
+            let value = _mm256_set1_pd(150000000f64);
+            let comparison = _mm256_cbrt_pd(value);
+            let flag_1 = _mm256_extract_pd::<1>(comparison);
+            assert_eq!(flag_1, 531.32928459130553302387f64);
+        }
+
+        unsafe {
+            // Test subnormal
+            let value = _mm256_set1_pd(f64::MIN_POSITIVE / 8.);
+            let comparison = _mm256_cbrt_pd(value);
+            let flag_1 = _mm256_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, (f64::MIN_POSITIVE / 8.).cbrt());
+        }
+
+        unsafe {
+            // Test Infinity
+            let value = _mm256_set1_pd(f64::INFINITY);
+            let comparison = _mm256_cbrt_pd(value);
+            let flag_1 = _mm256_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        unsafe {
+            // Test Neg Infinity
+            let value = _mm256_set1_pd(-27f64);
+            let comparison = _mm256_cbrt_pd(value);
+            let flag_1 = _mm256_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, -3f64);
+        }
+
+        unsafe {
+            let value = _mm256_set1_pd(f64::NEG_INFINITY);
+            let comparison = _mm256_cbrt_pd(value);
+            let flag_1 = _mm256_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+    }
+}
@@ -9,9 +9,10 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use crate::cbrtf::{CBRT_HUGE_F, CBRT_SCALE_F, CBRT_SCALE_R_F};
 use crate::{
-    _mm256_eqzero_ps, _mm256_isinf_ps, _mm256_isneginf_ps, _mm256_mlaf_ps, _mm256_mul_epu64,
-    _mm256_packts_epi64, _mm256_select_ps,
+    _mm256_abs_ps, _mm256_eqzero_ps, _mm256_isinf_ps, _mm256_isneginf_ps, _mm256_mlaf_ps,
+    _mm256_select_ps,
 };
 
 #[inline(always)]
@@ -27,19 +28,14 @@ unsafe fn halley_cbrt(x: __m256, a: __m256) -> __m256 {
 #[inline(always)]
 unsafe fn integer_pow_1_3(hx: __m256i) -> __m256i {
     let scale = _mm256_set1_epi64x(341);
-    let hi = _mm256_srli_epi64::<10>(_mm256_mul_epu64(
-        _mm256_unpackhi_epi32(hx, _mm256_setzero_si256()),
-        scale,
-    ));
-    let lo = _mm256_srli_epi64::<10>(_mm256_mul_epu64(
-        _mm256_unpacklo_epi32(hx, _mm256_setzero_si256()),
-        scale,
-    ));
-    _mm256_packts_epi64(lo, hi)
+    // Even and odd lanes are widened separately, products fit into 32 bits after shift
+    let even = _mm256_srli_epi64::<10>(_mm256_mul_epu32(hx, scale));
+    let odd = _mm256_srli_epi64::<10>(_mm256_mul_epu32(_mm256_srli_epi64::<32>(hx), scale));
+    _mm256_or_si256(even, _mm256_slli_epi64::<32>(odd))
 }
 
 /// Takes cube root from value *ULP 1.5*, Skipping NaN, Inf checks
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_cbrt_fast_ps(x: __m256) -> __m256 {
     let mut ui = _mm256_castps_si256(x);
     let hx = _mm256_and_si256(ui, _mm256_set1_epi32(0x7fffffff));
@@ -55,14 +51,29 @@ pub unsafe fn _mm256_cbrt_fast_ps(x: __m256) -> __m256 {
 
     let c0 = halley_cbrt(t, x);
     let c1 = halley_cbrt(c0, x);
-    let v = _mm256_select_ps(_mm256_eqzero_ps(x), _mm256_set1_ps(0f32), c1);
+    let v = _mm256_select_ps(_mm256_eqzero_ps(x), x, c1);
     v
 }
 
 /// Takes cube root from value *ULP 1.5*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_cbrt_ps(x: __m256) -> __m256 {
-    let c1 = _mm256_cbrt_fast_ps(x);
+    // Subnormals and the top binades are scaled by powers of 8, the initial guess
+    // and x^3 in Halley step are out of range for them
+    let ax = _mm256_abs_ps(x);
+    let tiny = _mm256_cmp_ps::<_CMP_LT_OQ>(ax, _mm256_set1_ps(f32::MIN_POSITIVE));
+    let huge = _mm256_cmp_ps::<_CMP_GT_OQ>(ax, _mm256_set1_ps(CBRT_HUGE_F));
+    let scale_in = _mm256_select_ps(
+        tiny,
+        _mm256_set1_ps(CBRT_SCALE_F),
+        _mm256_select_ps(huge, _mm256_set1_ps(1. / CBRT_SCALE_F), _mm256_set1_ps(1.)),
+    );
+    let scale_out = _mm256_select_ps(
+        tiny,
+        _mm256_set1_ps(1. / CBRT_SCALE_R_F),
+        _mm256_select_ps(huge, _mm256_set1_ps(CBRT_SCALE_R_F), _mm256_set1_ps(1.)),
+    );
+    let c1 = _mm256_mul_ps(_mm256_cbrt_fast_ps(_mm256_mul_ps(x, scale_in)), scale_out);
     let mut v = _mm256_select_ps(_mm256_isinf_ps(x), _mm256_set1_ps(f32::INFINITY), c1);
     v = _mm256_select_ps(_mm256_isneginf_ps(x), _mm256_set1_ps(f32::NEG_INFINITY), v);
     v
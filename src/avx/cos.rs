@@ -10,6 +10,8 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use crate::avx::general::_mm256_rempi_pd;
+use crate::generalf::rempi2_odd;
 use crate::sin::{
     PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
     SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
@@ -19,8 +21,8 @@ use crate::{
     _mm256_selecti_pd,
 };
 
-#[inline]
-/// Computes cosine function with error bound *ULP 2.0*
+#[inline(always)]
+/// Computes cosine function with error bound *ULP 2.5*
 pub unsafe fn _mm256_cos_pd(d: __m256d) -> __m256d {
     let j = _mm256_rint_pd(_mm256_sub_pd(
         _mm256_mul_pd(d, _mm256_set1_pd(std::f64::consts::FRAC_1_PI)),
@@ -34,6 +36,7 @@ pub unsafe fn _mm256_cos_pd(d: __m256d) -> __m256d {
 
     let mut r = _mm256_mlaf_pd(qf, _mm256_set1_pd(-PI_A2 * 0.5), d);
     r = _mm256_mlaf_pd(qf, _mm256_set1_pd(-PI_B2 * 0.5), r);
+    let (q, mut r) = _mm256_rempi_pd(d, q, r, rempi2_odd);
 
     let x2 = _mm256_mul_pd(r, r);
 
@@ -89,4 +92,13 @@ mod tests {
             assert_eq!(flag_1, control);
         }
     }
+
+    #[test]
+    fn test_cosd_large() {
+        for x in [1e6f64, -1e22, 3.5e300] {
+            let value = unsafe { _mm256_cvtsd_f64(_mm256_cos_pd(_mm256_setr_pd(x, 2., -0.5, 1.))) };
+            let control = x.cos();
+            assert!((value - control).abs() <= 2. * f64::EPSILON * control.abs());
+        }
+    }
 }
@@ -9,12 +9,17 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use crate::avx::generalf::_mm256_rempi_ps;
 use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2_oddf;
 use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
-use crate::{_mm256_mlaf_ps, _mm256_neg_ps, _mm256_rint_ps, _mm256_selecti_ps};
+use crate::{
+    _mm256_eqzero_ps, _mm256_mlaf_ps, _mm256_neg_ps, _mm256_rint_ps, _mm256_select_ps,
+    _mm256_selecti_ps,
+};
 
-#[inline]
-/// Computes cosine function with error bound *ULP 1.5*
+#[inline(always)]
+/// Computes cosine function with error bound *ULP 2.5*
 pub unsafe fn _mm256_cos_ps(d: __m256) -> __m256 {
     let q = _mm256_add_epi32(
         _mm256_set1_epi32(1),
@@ -32,6 +37,7 @@ pub unsafe fn _mm256_cos_ps(d: __m256) -> __m256 {
     r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_B_F * 0.5), r);
     r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_C_F * 0.5), r);
     r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_D_F * 0.5), r);
+    let (q, mut r) = _mm256_rempi_ps(d, q, r, rempi2_oddf);
 
     let x2 = _mm256_mul_ps(r, r);
 
@@ -49,7 +55,8 @@ pub unsafe fn _mm256_cos_ps(d: __m256) -> __m256 {
     res = _mm256_mlaf_ps(res, x2, _mm256_set1_ps(SIN_POLY_2_S));
     res = _mm256_mlaf_ps(res, x2, _mm256_set1_ps(SIN_POLY_1_S));
     res = _mm256_mlaf_ps(res, _mm256_mul_ps(x2, r), r);
-    res
+    // cos(±0) = 1
+    _mm256_select_ps(_mm256_eqzero_ps(d), _mm256_set1_ps(1.), res)
 }
 
 #[cfg(test)]
@@ -83,4 +90,46 @@ mod tests {
             assert_eq!(flag_1, control);
         }
     }
+
+    #[test]
+    fn test_cosf_large() {
+        for x in [1e4f32, -1e22, 3.0e38] {
+            let value = unsafe {
+                _mm256_cvtss_f32(_mm256_cos_ps(_mm256_setr_ps(
+                    x, 2., -0.5, 1., 0.25, 3., -3., 5.,
+                )))
+            };
+            let control = (x as f64).cos() as f32;
+            assert!((value - control).abs() <= 2. * f32::EPSILON * control.abs());
+        }
+    }
+
+    #[test]
+    fn test_cosf_ulp() {
+        // Uniform on [-1e4, 1e4] and log-uniform up to 1e30
+        let mut state = 0x1405_7b7e_f767_814fu64;
+        for i in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let sign = if i % 4 == 1 { 1. } else { -1. };
+            let x = if i % 2 == 0 {
+                ((u * 2. - 1.) * 1e4) as f32
+            } else {
+                (sign * 10f64.powf(u * 33. - 3.)) as f32
+            };
+            let control = (x as f64).cos();
+            let value = unsafe { _mm256_cvtss_f32(_mm256_cos_ps(_mm256_set1_ps(x))) };
+            assert!(
+                ulp(value, control) <= 2.5,
+                "cos({x}) = {value}, expected {control}"
+            );
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
 }
@@ -0,0 +1,76 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm256_abs_pd, _mm256_exp_pd, _mm256_mlaf_pd, _mm256_select_pd};
+
+/// Computes hyperbolic cosine, error bound *ULP 3.5*
+#[inline(always)]
+pub unsafe fn _mm256_cosh_pd(d: __m256d) -> __m256d {
+    let a = _mm256_abs_pd(d);
+    let e = _mm256_exp_pd(a);
+    let mut r = _mm256_mlaf_pd(
+        _mm256_set1_pd(0.5f64),
+        e,
+        _mm256_div_pd(_mm256_set1_pd(0.5f64), e),
+    );
+    // exp(|d|) / 2 overflows before cosh does, split it as exp(|d|/2)^2 / 2
+    let h = _mm256_exp_pd(_mm256_mul_pd(a, _mm256_set1_pd(0.5f64)));
+    r = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_GT_OS>(a, _mm256_set1_pd(709f64)),
+        _mm256_mul_pd(_mm256_mul_pd(h, _mm256_set1_pd(0.5f64)), h),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coshd() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(1.5f64);
+            let comparison = _mm256_cosh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 1.5f64.cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(-0.3f64);
+            let comparison = _mm256_cosh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = (-0.3f64).cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(710f64);
+            let comparison = _mm256_cosh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 710f64.cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm256_set1_pd(-720f64);
+            let comparison = _mm256_cosh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
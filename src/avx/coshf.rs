@@ -0,0 +1,76 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm256_abs_ps, _mm256_exp_ps, _mm256_mlaf_ps, _mm256_select_ps};
+
+/// Computes hyperbolic cosine, error bound *ULP 3.5*
+#[inline(always)]
+pub unsafe fn _mm256_cosh_ps(d: __m256) -> __m256 {
+    let a = _mm256_abs_ps(d);
+    let e = _mm256_exp_ps(a);
+    let mut r = _mm256_mlaf_ps(
+        _mm256_set1_ps(0.5f32),
+        e,
+        _mm256_div_ps(_mm256_set1_ps(0.5f32), e),
+    );
+    // exp(|d|) / 2 overflows before cosh does, split it as exp(|d|/2)^2 / 2
+    let h = _mm256_exp_ps(_mm256_mul_ps(a, _mm256_set1_ps(0.5f32)));
+    r = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_GT_OS>(a, _mm256_set1_ps(88f32)),
+        _mm256_mul_ps(_mm256_mul_ps(h, _mm256_set1_ps(0.5f32)), h),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coshf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(1.5f32);
+            let comparison = _mm256_cosh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1.5f32.cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-0.3f32);
+            let comparison = _mm256_cosh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = (-0.3f32).cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(89f32);
+            let comparison = _mm256_cosh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 89f32.cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm256_set1_ps(-100f32);
+            let comparison = _mm256_cosh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,172 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::avx::general::_mm256_isnotintegral_pd;
+use crate::avx::sin::_mm256_sinpik_pd;
+use crate::digamma::{
+    DIGAMMA_ASYMPT_POLY_1_D, DIGAMMA_ASYMPT_POLY_2_D, DIGAMMA_ASYMPT_POLY_3_D,
+    DIGAMMA_ASYMPT_POLY_4_D, DIGAMMA_ASYMPT_POLY_5_D, DIGAMMA_ASYMPT_POLY_6_D, DIGAMMA_POLY_10_D,
+    DIGAMMA_POLY_11_D, DIGAMMA_POLY_12_D, DIGAMMA_POLY_13_D, DIGAMMA_POLY_14_D, DIGAMMA_POLY_15_D,
+    DIGAMMA_POLY_16_D, DIGAMMA_POLY_17_D, DIGAMMA_POLY_18_D, DIGAMMA_POLY_19_D, DIGAMMA_POLY_1_D,
+    DIGAMMA_POLY_20_D, DIGAMMA_POLY_21_D, DIGAMMA_POLY_22_D, DIGAMMA_POLY_23_D, DIGAMMA_POLY_2_D,
+    DIGAMMA_POLY_3_D, DIGAMMA_POLY_4_D, DIGAMMA_POLY_5_D, DIGAMMA_POLY_6_D, DIGAMMA_POLY_7_D,
+    DIGAMMA_POLY_8_D, DIGAMMA_POLY_9_D, DIGAMMA_ROOT_M1_D, DIGAMMA_ROOT_M1_LO_D,
+};
+use crate::{_mm256_abs_pd, _mm256_ln_pd, _mm256_mlaf_pd, _mm256_select_pd};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Computes digamma function, error bound *ULP 4.5* for positive arguments
+#[inline(always)]
+pub unsafe fn _mm256_digamma_pd(d: __m256d) -> __m256d {
+    let reflect = _mm256_cmp_pd::<_CMP_LT_OS>(d, _mm256_setzero_pd());
+    // ψ(d) = ψ(1 - d) - pi cot(pi d)
+    let x = _mm256_select_pd(reflect, _mm256_sub_pd(_mm256_set1_pd(1f64), d), d);
+    // ψ(x) = ln(x) - 1/2x - z D(z), z = 1/x^2 for x >= 10
+    let ix = _mm256_div_pd(_mm256_set1_pd(1f64), x);
+    let z = _mm256_mul_pd(ix, ix);
+    let mut p = _mm256_set1_pd(DIGAMMA_ASYMPT_POLY_6_D);
+    p = _mm256_mlaf_pd(p, z, _mm256_set1_pd(DIGAMMA_ASYMPT_POLY_5_D));
+    p = _mm256_mlaf_pd(p, z, _mm256_set1_pd(DIGAMMA_ASYMPT_POLY_4_D));
+    p = _mm256_mlaf_pd(p, z, _mm256_set1_pd(DIGAMMA_ASYMPT_POLY_3_D));
+    p = _mm256_mlaf_pd(p, z, _mm256_set1_pd(DIGAMMA_ASYMPT_POLY_2_D));
+    p = _mm256_mlaf_pd(p, z, _mm256_set1_pd(DIGAMMA_ASYMPT_POLY_1_D));
+    let big = _mm256_sub_pd(
+        _mm256_ln_pd(x),
+        _mm256_mlaf_pd(z, p, _mm256_mul_pd(_mm256_set1_pd(0.5f64), ix)),
+    );
+    // ψ(1 + t) = (t - t0) G(t) keeps the root t0 apart, so values around it stay accurate
+    let n = _mm256_floor_pd(x);
+    let t = _mm256_sub_pd(x, n);
+    let v = _mm256_sub_pd(t, _mm256_set1_pd(0.5f64));
+    let mut u = _mm256_set1_pd(DIGAMMA_POLY_23_D);
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_22_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_21_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_20_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_19_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_18_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_17_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_16_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_15_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_14_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_13_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_12_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_11_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_10_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_9_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_8_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_7_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_6_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_5_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_4_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_3_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_2_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(DIGAMMA_POLY_1_D));
+    let r = _mm256_mul_pd(
+        _mm256_sub_pd(
+            _mm256_sub_pd(t, _mm256_set1_pd(DIGAMMA_ROOT_M1_D)),
+            _mm256_set1_pd(DIGAMMA_ROOT_M1_LO_D),
+        ),
+        u,
+    );
+    // ψ(x) = ψ(1 + t) + 1/(x - 1) + ... + 1/(x - n + 1) summed as a single fraction, below 1 it is -1/x
+    let below = _mm256_cmp_pd::<_CMP_LT_OS>(x, _mm256_set1_pd(1f64));
+    let mut num = _mm256_select_pd(below, _mm256_set1_pd(-1f64), _mm256_setzero_pd());
+    let mut den = _mm256_select_pd(below, x, _mm256_set1_pd(1f64));
+    for k in 1..10 {
+        let kf = _mm256_set1_pd(k as f64);
+        let v = _mm256_sub_pd(x, kf);
+        let m = _mm256_cmp_pd::<_CMP_LT_OS>(kf, n);
+        num = _mm256_select_pd(m, _mm256_mlaf_pd(num, v, den), num);
+        den = _mm256_select_pd(m, _mm256_mul_pd(den, v), den);
+    }
+    let mut ret = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_LT_OS>(x, _mm256_set1_pd(10f64)),
+        _mm256_add_pd(r, _mm256_div_pd(num, den)),
+        big,
+    );
+    let rr = _mm256_sub_pd(d, _mm256_round_pd::<_MM_FROUND_TO_NEAREST_INT>(d));
+    let cs = _mm256_sinpik_pd(_mm256_sub_pd(_mm256_set1_pd(0.5f64), _mm256_abs_pd(rr)));
+    ret = _mm256_select_pd(
+        reflect,
+        _mm256_sub_pd(
+            ret,
+            _mm256_div_pd(
+                _mm256_mul_pd(_mm256_set1_pd(std::f64::consts::PI), cs),
+                _mm256_sinpik_pd(rr),
+            ),
+        ),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    _mm256_select_pd(
+        _mm256_andnot_pd(_mm256_isnotintegral_pd(d), reflect),
+        _mm256_set1_pd(f64::NAN),
+        ret,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digammad() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.5f64);
+            let comparison = _mm256_digamma_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = -1.9635100260214235f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(3.7f64);
+            let comparison = _mm256_digamma_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 1.1671535393615113f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(-1.5f64);
+            let comparison = _mm256_digamma_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 0.7031566406452432f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_pd(-1f64);
+            let comparison = _mm256_digamma_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm256_set1_pd(f64::INFINITY);
+            let comparison = _mm256_digamma_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        unsafe {
+            // Test NaN
+            let value = _mm256_set1_pd(f64::NAN);
+            let comparison = _mm256_digamma_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
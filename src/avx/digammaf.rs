@@ -0,0 +1,158 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::avx::sinf::_mm256_sinpik_ps;
+use crate::digammaf::{
+    DIGAMMA_ASYMPT_POLY_1_S, DIGAMMA_ASYMPT_POLY_2_S, DIGAMMA_ASYMPT_POLY_3_S,
+    DIGAMMA_ASYMPT_POLY_4_S, DIGAMMA_POLY_10_S, DIGAMMA_POLY_11_S, DIGAMMA_POLY_12_S,
+    DIGAMMA_POLY_1_S, DIGAMMA_POLY_2_S, DIGAMMA_POLY_3_S, DIGAMMA_POLY_4_S, DIGAMMA_POLY_5_S,
+    DIGAMMA_POLY_6_S, DIGAMMA_POLY_7_S, DIGAMMA_POLY_8_S, DIGAMMA_POLY_9_S, DIGAMMA_ROOT_M1_LO_S,
+    DIGAMMA_ROOT_M1_S,
+};
+use crate::{
+    _mm256_abs_ps, _mm256_isnotintegral_ps, _mm256_ln_ps, _mm256_mlaf_ps, _mm256_select_ps,
+};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Computes digamma function, error bound *ULP 3.5* for positive arguments
+#[inline(always)]
+pub unsafe fn _mm256_digamma_ps(d: __m256) -> __m256 {
+    let reflect = _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_setzero_ps());
+    // ψ(d) = ψ(1 - d) - pi cot(pi d)
+    let x = _mm256_select_ps(reflect, _mm256_sub_ps(_mm256_set1_ps(1f32), d), d);
+    // ψ(x) = ln(x) - 1/2x - z D(z), z = 1/x^2 for x >= 5
+    let ix = _mm256_div_ps(_mm256_set1_ps(1f32), x);
+    let z = _mm256_mul_ps(ix, ix);
+    let mut p = _mm256_set1_ps(DIGAMMA_ASYMPT_POLY_4_S);
+    p = _mm256_mlaf_ps(p, z, _mm256_set1_ps(DIGAMMA_ASYMPT_POLY_3_S));
+    p = _mm256_mlaf_ps(p, z, _mm256_set1_ps(DIGAMMA_ASYMPT_POLY_2_S));
+    p = _mm256_mlaf_ps(p, z, _mm256_set1_ps(DIGAMMA_ASYMPT_POLY_1_S));
+    let big = _mm256_sub_ps(
+        _mm256_ln_ps(x),
+        _mm256_mlaf_ps(z, p, _mm256_mul_ps(_mm256_set1_ps(0.5f32), ix)),
+    );
+    // ψ(1 + t) = (t - t0) G(t) keeps the root t0 apart, so values around it stay accurate
+    let n = _mm256_floor_ps(x);
+    let t = _mm256_sub_ps(x, n);
+    let v = _mm256_sub_ps(t, _mm256_set1_ps(0.5f32));
+    let mut u = _mm256_set1_ps(DIGAMMA_POLY_12_S);
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_11_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_10_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_9_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_8_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_7_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_6_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_5_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_4_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_3_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_2_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(DIGAMMA_POLY_1_S));
+    let r = _mm256_mul_ps(
+        _mm256_sub_ps(
+            _mm256_sub_ps(t, _mm256_set1_ps(DIGAMMA_ROOT_M1_S)),
+            _mm256_set1_ps(DIGAMMA_ROOT_M1_LO_S),
+        ),
+        u,
+    );
+    // ψ(x) = ψ(1 + t) + 1/(x - 1) + ... + 1/(x - n + 1) summed as a single fraction, below 1 it is -1/x
+    let below = _mm256_cmp_ps::<_CMP_LT_OS>(x, _mm256_set1_ps(1f32));
+    let mut num = _mm256_select_ps(below, _mm256_set1_ps(-1f32), _mm256_setzero_ps());
+    let mut den = _mm256_select_ps(below, x, _mm256_set1_ps(1f32));
+    for k in 1..5 {
+        let kf = _mm256_set1_ps(k as f32);
+        let v = _mm256_sub_ps(x, kf);
+        let m = _mm256_cmp_ps::<_CMP_LT_OS>(kf, n);
+        num = _mm256_select_ps(m, _mm256_mlaf_ps(num, v, den), num);
+        den = _mm256_select_ps(m, _mm256_mul_ps(den, v), den);
+    }
+    let mut ret = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(x, _mm256_set1_ps(5f32)),
+        _mm256_add_ps(r, _mm256_div_ps(num, den)),
+        big,
+    );
+    let rr = _mm256_sub_ps(d, _mm256_round_ps::<_MM_FROUND_TO_NEAREST_INT>(d));
+    let cs = _mm256_sinpik_ps(_mm256_sub_ps(_mm256_set1_ps(0.5f32), _mm256_abs_ps(rr)));
+    ret = _mm256_select_ps(
+        reflect,
+        _mm256_sub_ps(
+            ret,
+            _mm256_div_ps(
+                _mm256_mul_ps(_mm256_set1_ps(std::f32::consts::PI), cs),
+                _mm256_sinpik_ps(rr),
+            ),
+        ),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    _mm256_select_ps(
+        _mm256_andnot_ps(_mm256_isnotintegral_ps(d), reflect),
+        _mm256_set1_ps(f32::NAN),
+        ret,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digammaf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.5f32);
+            let comparison = _mm256_digamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = -1.96351f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(3.7f32);
+            let comparison = _mm256_digamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1.1671536f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-1.5f32);
+            let comparison = _mm256_digamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 0.70315665f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_ps(-1f32);
+            let comparison = _mm256_digamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm256_set1_ps(f32::INFINITY);
+            let comparison = _mm256_digamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        unsafe {
+            // Test NaN
+            let value = _mm256_set1_ps(f32::NAN);
+            let comparison = _mm256_digamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,228 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::divider::{DividerI32, DividerI64, DividerU32, DividerU64};
+use crate::{_mm256_mull_epi64, _mm256_mull_epu64};
+
+#[inline(always)]
+/// Takes upper half of u32 multiplication
+unsafe fn _mm256_mulhi_epu32(a: __m256i, b: __m256i) -> __m256i {
+    let even = _mm256_srli_epi64::<32>(_mm256_mul_epu32(a, b));
+    let odd = _mm256_mul_epu32(_mm256_srli_epi64::<32>(a), _mm256_srli_epi64::<32>(b));
+    _mm256_or_si256(
+        even,
+        _mm256_and_si256(odd, _mm256_set1_epi64x(0xFFFFFFFF00000000u64 as i64)),
+    )
+}
+
+#[inline(always)]
+/// Takes upper half of i32 multiplication
+unsafe fn _mm256_mulhi_epi32(a: __m256i, b: __m256i) -> __m256i {
+    let product = _mm256_mulhi_epu32(a, b);
+    let correction = _mm256_add_epi32(
+        _mm256_and_si256(_mm256_srai_epi32::<31>(a), b),
+        _mm256_and_si256(_mm256_srai_epi32::<31>(b), a),
+    );
+    _mm256_sub_epi32(product, correction)
+}
+
+#[inline(always)]
+/// Arithmetic shift i64 by the same runtime amount
+unsafe fn _mm256_sra_epi64x(a: __m256i, shift: u32) -> __m256i {
+    let sign = _mm256_cmpgt_epi64(_mm256_setzero_si256(), a);
+    _mm256_or_si256(
+        _mm256_srl_epi64(a, _mm_cvtsi32_si128(shift as i32)),
+        _mm256_sll_epi64(sign, _mm_cvtsi32_si128(64 - shift as i32)),
+    )
+}
+
+#[inline(always)]
+/// Divides u32 by precomputed divider
+pub unsafe fn _mm256_div_epu32(a: __m256i, divider: DividerU32) -> __m256i {
+    let shift = _mm_cvtsi32_si128(divider.shift as i32);
+    if divider.magic == 0 {
+        return _mm256_srl_epi32(a, shift);
+    }
+    let q = _mm256_mulhi_epu32(a, _mm256_set1_epi32(divider.magic as i32));
+    if divider.add {
+        let t = _mm256_add_epi32(_mm256_srli_epi32::<1>(_mm256_sub_epi32(a, q)), q);
+        _mm256_srl_epi32(t, shift)
+    } else {
+        _mm256_srl_epi32(q, shift)
+    }
+}
+
+#[inline(always)]
+/// Divides i32 by precomputed divider, rounds towards zero
+pub unsafe fn _mm256_div_epi32(a: __m256i, divider: DividerI32) -> __m256i {
+    let shift = _mm_cvtsi32_si128(divider.shift as i32);
+    let sign = _mm256_set1_epi32(if divider.negative { -1 } else { 0 });
+    if divider.magic == 0 {
+        let mask = _mm256_set1_epi32(((1u32 << divider.shift) - 1) as i32);
+        let rounding = _mm256_and_si256(_mm256_srai_epi32::<31>(a), mask);
+        let q = _mm256_sra_epi32(_mm256_add_epi32(a, rounding), shift);
+        return _mm256_sub_epi32(_mm256_xor_si256(q, sign), sign);
+    }
+    let mut q = _mm256_mulhi_epi32(a, _mm256_set1_epi32(divider.magic));
+    if divider.add {
+        q = _mm256_add_epi32(q, _mm256_sub_epi32(_mm256_xor_si256(a, sign), sign));
+    }
+    q = _mm256_sra_epi32(q, shift);
+    _mm256_add_epi32(q, _mm256_srli_epi32::<31>(q))
+}
+
+#[inline(always)]
+/// Divides u64 by precomputed divider
+pub unsafe fn _mm256_div_epu64(a: __m256i, divider: DividerU64) -> __m256i {
+    let shift = _mm_cvtsi32_si128(divider.shift as i32);
+    if divider.magic == 0 {
+        return _mm256_srl_epi64(a, shift);
+    }
+    let q = _mm256_mull_epu64(a, _mm256_set1_epi64x(divider.magic as i64)).1;
+    if divider.add {
+        let t = _mm256_add_epi64(_mm256_srli_epi64::<1>(_mm256_sub_epi64(a, q)), q);
+        _mm256_srl_epi64(t, shift)
+    } else {
+        _mm256_srl_epi64(q, shift)
+    }
+}
+
+#[inline(always)]
+/// Divides i64 by precomputed divider, rounds towards zero
+pub unsafe fn _mm256_div_epi64(a: __m256i, divider: DividerI64) -> __m256i {
+    let sign = _mm256_set1_epi64x(if divider.negative { -1 } else { 0 });
+    if divider.magic == 0 {
+        let mask = _mm256_set1_epi64x(((1u64 << divider.shift) - 1) as i64);
+        let rounding = _mm256_and_si256(_mm256_cmpgt_epi64(_mm256_setzero_si256(), a), mask);
+        let q = _mm256_sra_epi64x(_mm256_add_epi64(a, rounding), divider.shift);
+        return _mm256_sub_epi64(_mm256_xor_si256(q, sign), sign);
+    }
+    let mut q = _mm256_mull_epi64(a, _mm256_set1_epi64x(divider.magic)).1;
+    if divider.add {
+        q = _mm256_add_epi64(q, _mm256_sub_epi64(_mm256_xor_si256(a, sign), sign));
+    }
+    q = _mm256_sra_epi64x(q, divider.shift);
+    _mm256_add_epi64(q, _mm256_srli_epi64::<63>(q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm256_extract_epi64x;
+
+    const DIVISORS: [u64; 14] = [
+        1,
+        2,
+        3,
+        7,
+        10,
+        64,
+        641,
+        0x7fff_ffff,
+        0x8000_0001,
+        0xffff_ffff,
+        0x1_0000_0003,
+        i64::MAX as u64,
+        1 << 63,
+        u64::MAX,
+    ];
+
+    const NUMERATORS: [u64; 12] = [
+        0,
+        1,
+        6,
+        100,
+        0x7fff_ffff,
+        0x8000_0000,
+        0xffff_ffff,
+        0x1234_5678_9abc_def0,
+        i64::MAX as u64,
+        i64::MIN as u64,
+        -7i64 as u64,
+        u64::MAX,
+    ];
+
+    unsafe fn lanes64(v: __m256i) -> [u64; 4] {
+        [
+            _mm256_extract_epi64x::<0>(v) as u64,
+            _mm256_extract_epi64x::<1>(v) as u64,
+            _mm256_extract_epi64x::<2>(v) as u64,
+            _mm256_extract_epi64x::<3>(v) as u64,
+        ]
+    }
+
+    unsafe fn lanes32(v: __m256i) -> [u32; 8] {
+        let l = lanes64(v);
+        [0, 1, 2, 3, 4, 5, 6, 7].map(|i| (l[i / 2] >> (32 * (i % 2))) as u32)
+    }
+
+    #[test]
+    fn test_div_32() {
+        unsafe {
+            for &d in DIVISORS.iter() {
+                for d in [d as u32, (d >> 32) as u32] {
+                    if d == 0 {
+                        continue;
+                    }
+                    let n = NUMERATORS.map(|x| x as u32);
+                    for chunk in [&n[0..8], &n[4..12]] {
+                        let v = _mm256_setr_epi32(
+                            chunk[0] as i32,
+                            chunk[1] as i32,
+                            chunk[2] as i32,
+                            chunk[3] as i32,
+                            chunk[4] as i32,
+                            chunk[5] as i32,
+                            chunk[6] as i32,
+                            chunk[7] as i32,
+                        );
+                        let q = lanes32(_mm256_div_epu32(v, DividerU32::new(d)));
+                        for i in 0..8 {
+                            assert_eq!(q[i], chunk[i] / d);
+                        }
+                        for sd in [d as i32, (d as i32).wrapping_neg()] {
+                            let q = lanes32(_mm256_div_epi32(v, DividerI32::new(sd)));
+                            for i in 0..8 {
+                                assert_eq!(q[i] as i32, (chunk[i] as i32).wrapping_div(sd));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_div_64() {
+        unsafe {
+            for &d in DIVISORS.iter() {
+                for chunk in NUMERATORS.chunks(4) {
+                    let v = _mm256_setr_epi64x(
+                        chunk[0] as i64,
+                        chunk[1] as i64,
+                        chunk[2] as i64,
+                        chunk[3] as i64,
+                    );
+                    let q = lanes64(_mm256_div_epu64(v, DividerU64::new(d)));
+                    for i in 0..4 {
+                        assert_eq!(q[i], chunk[i] / d);
+                    }
+                    for sd in [d as i64, (d as i64).wrapping_neg()] {
+                        let q = lanes64(_mm256_div_epi64(v, DividerI64::new(sd)));
+                        for i in 0..4 {
+                            assert_eq!(q[i] as i64, (chunk[i] as i64).wrapping_div(sd));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -4,22 +4,23 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
+use crate::_mm_extract_epi64x;
 use crate::shuffle::_mm_shuffle;
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
+#[inline(always)]
 /// Arithmetic shift for i64, shifting with sign bits
 pub unsafe fn _mm256_srai_epi64x<const IMM8: i32>(a: __m256i) -> __m256i {
-    let m = _mm256_set1_epi64x(1 << (64 - 1));
+    let m = _mm256_set1_epi64x(1 << (63 - IMM8));
     let x = _mm256_srli_epi64::<IMM8>(a);
     let result = _mm256_sub_epi64(_mm256_xor_si256(x, m), m); //result = x^m - m
     result
 }
 
-#[inline]
+#[inline(always)]
 /// Select true or false values based on masks for i64
 pub unsafe fn _mm256_select_epi64(
     mask: __m256i,
@@ -33,14 +34,14 @@ pub unsafe fn _mm256_select_epi64(
     ))
 }
 
-#[inline]
+#[inline(always)]
 /// Takes max for epi64
 pub unsafe fn _mm256_max_epi64x(a: __m256i, b: __m256i) -> __m256i {
     let mask = _mm256_cmpgt_epi64(a, b);
     _mm256_blendv_epi8(b, a, mask)
 }
 
-#[inline]
+#[inline(always)]
 /// Takes min for epi64
 pub unsafe fn _mm256_min_epi64x(a: __m256i, b: __m256i) -> __m256i {
     let mut mask = _mm256_cmpgt_epi64(a, b);
@@ -49,7 +50,7 @@ pub unsafe fn _mm256_min_epi64x(a: __m256i, b: __m256i) -> __m256i {
     _mm256_blendv_epi8(b, a, mask)
 }
 
-#[inline]
+#[inline(always)]
 /// Pack 64bytes integers into 32 bytes using unsigned saturation
 pub unsafe fn _mm256_packus_epi64(a: __m256i, b: __m256i) -> __m256i {
     let i32_max = _mm256_set1_epi64x(u32::MAX as i64);
@@ -62,7 +63,7 @@ pub unsafe fn _mm256_packus_epi64(a: __m256i, b: __m256i) -> __m256i {
     return _mm256_castpd_si256(ordered);
 }
 
-#[inline]
+#[inline(always)]
 /// Pack 64bytes integers into 32 bytes using signed saturation
 pub unsafe fn _mm256_packs_epi64(a: __m256i, b: __m256i) -> __m256i {
     let i32_max = _mm256_set1_epi64x(i32::MAX as i64);
@@ -76,7 +77,7 @@ pub unsafe fn _mm256_packs_epi64(a: __m256i, b: __m256i) -> __m256i {
     return _mm256_castpd_si256(ordered);
 }
 
-#[inline]
+#[inline(always)]
 /// Pack 64bytes integers into 32 bytes using truncation
 pub unsafe fn _mm256_packts_epi64(a: __m256i, b: __m256i) -> __m256i {
     const SHUFFLE_1: i32 = _mm_shuffle(2, 0, 2, 0);
@@ -86,7 +87,7 @@ pub unsafe fn _mm256_packts_epi64(a: __m256i, b: __m256i) -> __m256i {
     return _mm256_castpd_si256(ordered);
 }
 
-#[inline]
+#[inline(always)]
 #[allow(dead_code)]
 /// Pack 64bytes integers into 32 bytes
 pub unsafe fn _mm256_cvtepi64_epi32x(v: __m256i) -> __m128i {
@@ -98,7 +99,7 @@ pub unsafe fn _mm256_cvtepi64_epi32x(v: __m256i) -> __m128i {
     return _mm_castps_si128(packed);
 }
 
-#[inline]
+#[inline(always)]
 /// Multiplies unsigned 64 bytes integers, takes only lower half after multiplication, do not care about overflow
 /// Formally it is *_mm256_mullo_epu64*
 pub unsafe fn _mm256_mul_epu64(ab: __m256i, cd: __m256i) -> __m256i {
@@ -125,16 +126,44 @@ pub unsafe fn _mm256_mul_epu64(ab: __m256i, cd: __m256i) -> __m256i {
     return _mm256_add_epi64(high, ac);
 }
 
-#[inline]
+#[inline(always)]
 /// Multiplies unsigned 64 bytes integers, takes only lower half after multiplication, do not care about overflow
 /// Formally it is *_mm_mullo_epi64*
 pub unsafe fn _mm256_mul_epi64(ab: __m256i, cd: __m256i) -> __m256i {
     _mm256_mul_epu64(ab, cd)
 }
 
-#[inline]
+#[inline(always)]
 /// Negates i64
 pub unsafe fn _mm256_neg_epi64(a: __m256i) -> __m256i {
     let k = _mm256_setzero_si256();
     _mm256_sub_epi64(k, a)
 }
+
+#[inline(always)]
+/// Compare *greater than* unsigned 64 bytes integers
+pub unsafe fn _mm256_cmpgt_epu64(a: __m256i, b: __m256i) -> __m256i {
+    let sign = _mm256_set1_epi64x(i64::MIN);
+    _mm256_cmpgt_epi64(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+}
+
+#[inline(always)]
+/// Compare *less than* unsigned 64 bytes integers
+pub unsafe fn _mm256_cmplt_epu64(a: __m256i, b: __m256i) -> __m256i {
+    _mm256_cmpgt_epu64(b, a)
+}
+
+#[inline(always)]
+/// Extracts i64 value
+pub unsafe fn _mm256_extract_epi64x<const IMM: i32>(d: __m256i) -> i64 {
+    let half = if IMM < 2 {
+        _mm256_castsi256_si128(d)
+    } else {
+        _mm256_extracti128_si256::<1>(d)
+    };
+    if IMM % 2 == 0 {
+        _mm_extract_epi64x::<0>(half)
+    } else {
+        _mm_extract_epi64x::<1>(half)
+    }
+}
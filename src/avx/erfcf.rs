@@ -0,0 +1,102 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::erff::{_mm256_erfck_ps, _mm256_erfk_ps};
+use crate::{_mm256_abs_ps, _mm256_select_ps};
+
+/// Computes complementary error function, error bound *ULP 3.5*
+#[inline(always)]
+pub unsafe fn _mm256_erfc_ps(d: __m256) -> __m256 {
+    let a = _mm256_abs_ps(d);
+    let u = _mm256_erfk_ps(d);
+    // Past 0.25 recentering on 0.5 keeps the subtraction from cancelling
+    let mut s = _mm256_sub_ps(
+        _mm256_set1_ps(0.5f32),
+        _mm256_add_ps(_mm256_sub_ps(d, _mm256_set1_ps(0.5f32)), u),
+    );
+    s = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_set1_ps(0.25f32)),
+        _mm256_sub_ps(_mm256_set1_ps(1f32), _mm256_add_ps(d, u)),
+        s,
+    );
+    let e = _mm256_erfck_ps(a);
+    let r = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_setzero_ps()),
+        _mm256_sub_ps(_mm256_set1_ps(2f32), e),
+        e,
+    );
+    _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(a, _mm256_set1_ps(0.84375f32)),
+        s,
+        r,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfcf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.5f32);
+            let comparison = _mm256_erfc_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 0.47950011f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-1.5f32);
+            let comparison = _mm256_erfc_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1.9661051f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(5f32);
+            let comparison = _mm256_erfc_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1.5374598e-12f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(9f32);
+            let comparison = _mm256_erfc_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 4.1370317e-37f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm256_set1_ps(30f32);
+            let comparison = _mm256_erfc_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm256_set1_ps(-30f32);
+            let comparison = _mm256_erfc_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, 2.);
+        }
+    }
+}
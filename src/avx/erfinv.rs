@@ -0,0 +1,222 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::erf::{_mm256_erfck_pd, _mm256_erfk_pd};
+use crate::erfinv::{
+    ERFINV_A_POLY_10_D, ERFINV_A_POLY_11_D, ERFINV_A_POLY_1_D, ERFINV_A_POLY_2_D,
+    ERFINV_A_POLY_3_D, ERFINV_A_POLY_4_D, ERFINV_A_POLY_5_D, ERFINV_A_POLY_6_D, ERFINV_A_POLY_7_D,
+    ERFINV_A_POLY_8_D, ERFINV_A_POLY_9_D, ERFINV_A_SHIFT_D, ERFINV_B_POLY_1_D, ERFINV_B_POLY_2_D,
+    ERFINV_B_POLY_3_D, ERFINV_B_POLY_4_D, ERFINV_B_POLY_5_D, ERFINV_B_POLY_6_D, ERFINV_B_POLY_7_D,
+    ERFINV_B_POLY_8_D, ERFINV_B_POLY_9_D, ERFINV_B_SHIFT_D, ERFINV_C_POLY_1_D, ERFINV_C_POLY_2_D,
+    ERFINV_C_POLY_3_D, ERFINV_C_POLY_4_D, ERFINV_C_POLY_5_D, ERFINV_C_POLY_6_D, ERFINV_C_POLY_7_D,
+    ERFINV_C_POLY_8_D, ERFINV_C_POLY_9_D, ERFINV_C_SHIFT_D, SQRT_PI_2_D,
+};
+use crate::{
+    _mm256_abs_pd, _mm256_copysign_pd, _mm256_exp_pd, _mm256_ln_pd, _mm256_mlaf_pd, _mm256_neg_pd,
+    _mm256_select_pd,
+};
+
+/// Computes inverse error function, error bound *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm256_erfinv_pd(d: __m256d) -> __m256d {
+    let a = _mm256_abs_pd(d);
+    let w = _mm256_neg_pd(_mm256_ln_pd(_mm256_mul_pd(
+        _mm256_sub_pd(_mm256_set1_pd(1f64), a),
+        _mm256_add_pd(_mm256_set1_pd(1f64), a),
+    )));
+    let v = _mm256_sub_pd(w, _mm256_set1_pd(ERFINV_A_SHIFT_D));
+    let mut u = _mm256_set1_pd(ERFINV_A_POLY_11_D);
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(ERFINV_A_POLY_10_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(ERFINV_A_POLY_9_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(ERFINV_A_POLY_8_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(ERFINV_A_POLY_7_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(ERFINV_A_POLY_6_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(ERFINV_A_POLY_5_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(ERFINV_A_POLY_4_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(ERFINV_A_POLY_3_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(ERFINV_A_POLY_2_D));
+    u = _mm256_mlaf_pd(u, v, _mm256_set1_pd(ERFINV_A_POLY_1_D));
+    let s = _mm256_sqrt_pd(w);
+    let mt = _mm256_cmp_pd::<_CMP_LT_OS>(s, _mm256_set1_pd(4f64));
+    let v = _mm256_sub_pd(
+        s,
+        _mm256_select_pd(
+            mt,
+            _mm256_set1_pd(ERFINV_B_SHIFT_D),
+            _mm256_set1_pd(ERFINV_C_SHIFT_D),
+        ),
+    );
+    let mut p = _mm256_select_pd(
+        mt,
+        _mm256_set1_pd(ERFINV_B_POLY_9_D),
+        _mm256_set1_pd(ERFINV_C_POLY_9_D),
+    );
+    p = _mm256_mlaf_pd(
+        p,
+        v,
+        _mm256_select_pd(
+            mt,
+            _mm256_set1_pd(ERFINV_B_POLY_8_D),
+            _mm256_set1_pd(ERFINV_C_POLY_8_D),
+        ),
+    );
+    p = _mm256_mlaf_pd(
+        p,
+        v,
+        _mm256_select_pd(
+            mt,
+            _mm256_set1_pd(ERFINV_B_POLY_7_D),
+            _mm256_set1_pd(ERFINV_C_POLY_7_D),
+        ),
+    );
+    p = _mm256_mlaf_pd(
+        p,
+        v,
+        _mm256_select_pd(
+            mt,
+            _mm256_set1_pd(ERFINV_B_POLY_6_D),
+            _mm256_set1_pd(ERFINV_C_POLY_6_D),
+        ),
+    );
+    p = _mm256_mlaf_pd(
+        p,
+        v,
+        _mm256_select_pd(
+            mt,
+            _mm256_set1_pd(ERFINV_B_POLY_5_D),
+            _mm256_set1_pd(ERFINV_C_POLY_5_D),
+        ),
+    );
+    p = _mm256_mlaf_pd(
+        p,
+        v,
+        _mm256_select_pd(
+            mt,
+            _mm256_set1_pd(ERFINV_B_POLY_4_D),
+            _mm256_set1_pd(ERFINV_C_POLY_4_D),
+        ),
+    );
+    p = _mm256_mlaf_pd(
+        p,
+        v,
+        _mm256_select_pd(
+            mt,
+            _mm256_set1_pd(ERFINV_B_POLY_3_D),
+            _mm256_set1_pd(ERFINV_C_POLY_3_D),
+        ),
+    );
+    p = _mm256_mlaf_pd(
+        p,
+        v,
+        _mm256_select_pd(
+            mt,
+            _mm256_set1_pd(ERFINV_B_POLY_2_D),
+            _mm256_set1_pd(ERFINV_C_POLY_2_D),
+        ),
+    );
+    p = _mm256_mlaf_pd(
+        p,
+        v,
+        _mm256_select_pd(
+            mt,
+            _mm256_set1_pd(ERFINV_B_POLY_1_D),
+            _mm256_set1_pd(ERFINV_C_POLY_1_D),
+        ),
+    );
+    let mut x = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_LT_OS>(w, _mm256_set1_pd(5f64)),
+        _mm256_mul_pd(a, u),
+        p,
+    );
+    // One Halley step on erf(x) = a, in the tail the residual comes from erfc where 1 - a is exact
+    let f0 = _mm256_add_pd(_mm256_sub_pd(x, a), _mm256_erfk_pd(x));
+    let f1 = _mm256_sub_pd(_mm256_sub_pd(_mm256_set1_pd(1f64), a), _mm256_erfck_pd(x));
+    let f = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_LT_OS>(x, _mm256_set1_pd(0.84375f64)),
+        f0,
+        f1,
+    );
+    let e = _mm256_mul_pd(
+        _mm256_mul_pd(f, _mm256_set1_pd(SQRT_PI_2_D)),
+        _mm256_exp_pd(_mm256_mul_pd(x, x)),
+    );
+    x = _mm256_sub_pd(
+        x,
+        _mm256_div_pd(e, _mm256_mlaf_pd(x, e, _mm256_set1_pd(1f64))),
+    );
+    // |d| == 1 -> Inf, |d| > 1 and Nan propagate through ln
+    x = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(a, _mm256_set1_pd(1f64)),
+        _mm256_set1_pd(f64::INFINITY),
+        x,
+    );
+    _mm256_copysign_pd(x, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfinvd() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.5f64);
+            let comparison = _mm256_erfinv_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 0.4769362762044699f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(-0.9f64);
+            let comparison = _mm256_erfinv_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = -1.1630871536766743f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.999999f64);
+            let comparison = _mm256_erfinv_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 3.458910737275499f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_pd(1f64);
+            let comparison = _mm256_erfinv_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_pd(-1f64);
+            let comparison = _mm256_erfinv_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm256_set1_pd(2f64);
+            let comparison = _mm256_erfinv_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
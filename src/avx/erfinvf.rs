@@ -0,0 +1,124 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::generalf::_mm256_copysign_ps;
+use crate::erfinvf::{
+    ERFINV_A_POLY_10_S, ERFINV_A_POLY_11_S, ERFINV_A_POLY_1_S, ERFINV_A_POLY_2_S,
+    ERFINV_A_POLY_3_S, ERFINV_A_POLY_4_S, ERFINV_A_POLY_5_S, ERFINV_A_POLY_6_S, ERFINV_A_POLY_7_S,
+    ERFINV_A_POLY_8_S, ERFINV_A_POLY_9_S, ERFINV_A_SHIFT_S, ERFINV_B_POLY_1_S, ERFINV_B_POLY_2_S,
+    ERFINV_B_POLY_3_S, ERFINV_B_POLY_4_S, ERFINV_B_POLY_5_S, ERFINV_B_POLY_6_S, ERFINV_B_POLY_7_S,
+    ERFINV_B_POLY_8_S, ERFINV_B_POLY_9_S, ERFINV_B_SHIFT_S,
+};
+use crate::{_mm256_abs_ps, _mm256_ln_ps, _mm256_mlaf_ps, _mm256_neg_ps, _mm256_select_ps};
+
+/// Computes inverse error function, error bound *ULP 4.0*
+#[inline(always)]
+pub unsafe fn _mm256_erfinv_ps(d: __m256) -> __m256 {
+    let a = _mm256_abs_ps(d);
+    let w = _mm256_neg_ps(_mm256_ln_ps(_mm256_mul_ps(
+        _mm256_sub_ps(_mm256_set1_ps(1f32), a),
+        _mm256_add_ps(_mm256_set1_ps(1f32), a),
+    )));
+    let v = _mm256_sub_ps(w, _mm256_set1_ps(ERFINV_A_SHIFT_S));
+    let mut u = _mm256_set1_ps(ERFINV_A_POLY_11_S);
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(ERFINV_A_POLY_10_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(ERFINV_A_POLY_9_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(ERFINV_A_POLY_8_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(ERFINV_A_POLY_7_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(ERFINV_A_POLY_6_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(ERFINV_A_POLY_5_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(ERFINV_A_POLY_4_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(ERFINV_A_POLY_3_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(ERFINV_A_POLY_2_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(ERFINV_A_POLY_1_S));
+    let v = _mm256_sub_ps(_mm256_sqrt_ps(w), _mm256_set1_ps(ERFINV_B_SHIFT_S));
+    let mut p = _mm256_set1_ps(ERFINV_B_POLY_9_S);
+    p = _mm256_mlaf_ps(p, v, _mm256_set1_ps(ERFINV_B_POLY_8_S));
+    p = _mm256_mlaf_ps(p, v, _mm256_set1_ps(ERFINV_B_POLY_7_S));
+    p = _mm256_mlaf_ps(p, v, _mm256_set1_ps(ERFINV_B_POLY_6_S));
+    p = _mm256_mlaf_ps(p, v, _mm256_set1_ps(ERFINV_B_POLY_5_S));
+    p = _mm256_mlaf_ps(p, v, _mm256_set1_ps(ERFINV_B_POLY_4_S));
+    p = _mm256_mlaf_ps(p, v, _mm256_set1_ps(ERFINV_B_POLY_3_S));
+    p = _mm256_mlaf_ps(p, v, _mm256_set1_ps(ERFINV_B_POLY_2_S));
+    p = _mm256_mlaf_ps(p, v, _mm256_set1_ps(ERFINV_B_POLY_1_S));
+    let mut x = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(w, _mm256_set1_ps(5f32)),
+        _mm256_mul_ps(a, u),
+        p,
+    );
+    // |d| == 1 -> Inf, |d| > 1 and Nan propagate through ln
+    x = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(a, _mm256_set1_ps(1f32)),
+        _mm256_set1_ps(f32::INFINITY),
+        x,
+    );
+    _mm256_copysign_ps(x, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfinvf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.5f32);
+            let comparison = _mm256_erfinv_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 0.47693628f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-0.9f32);
+            let comparison = _mm256_erfinv_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = -1.1630871f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.9999f32);
+            let comparison = _mm256_erfinv_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 2.7510355f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_ps(1f32);
+            let comparison = _mm256_erfinv_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_ps(-1f32);
+            let comparison = _mm256_erfinv_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm256_set1_ps(2f32);
+            let comparison = _mm256_erfinv_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
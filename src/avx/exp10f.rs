@@ -0,0 +1,84 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::expf::_mm256_expm1k_ps;
+use crate::avx::generalf::_mm256_ldexp2kq_ps;
+use crate::expf::{LG2_L_F, LG2_U_F, LN10_HI_F, LN10_LO_F};
+use crate::{_mm256_mlaf_ps, _mm256_rint_ps, _mm256_select_ps};
+
+/// Computes 10^x *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm256_exp10_ps(d: __m256) -> __m256 {
+    let q = _mm256_rint_ps(_mm256_mul_ps(d, _mm256_set1_ps(std::f32::consts::LOG2_10)));
+    let qf = _mm256_cvtepi32_ps(q);
+    let mut s = _mm256_mlaf_ps(qf, _mm256_set1_ps(-LG2_U_F), d);
+    s = _mm256_mlaf_ps(qf, _mm256_set1_ps(-LG2_L_F), s);
+    let r = _mm256_mlaf_ps(
+        s,
+        _mm256_set1_ps(LN10_HI_F),
+        _mm256_mul_ps(s, _mm256_set1_ps(LN10_LO_F)),
+    );
+    let mut r = _mm256_ldexp2kq_ps(_mm256_add_ps(_mm256_expm1k_ps(r), _mm256_set1_ps(1f32)), q);
+    r = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_set1_ps(-45.16f32)),
+        _mm256_setzero_ps(),
+        r,
+    );
+    r = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_GT_OS>(d, _mm256_set1_ps(38.54f32)),
+        _mm256_set1_ps(f32::INFINITY),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exp10f() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(1.5f32);
+            let comparison = _mm256_exp10_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 10f32.powf(1.5);
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(30f32);
+            let comparison = _mm256_exp10_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1e30;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm256_set1_ps(39f32);
+            let comparison = _mm256_exp10_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        unsafe {
+            // Test underflow
+            let value = _mm256_set1_ps(-50f32);
+            let comparison = _mm256_exp10_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
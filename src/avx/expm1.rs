@@ -0,0 +1,136 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::general::_mm256_ldexp2k_pd;
+use crate::exp::{L2_L, L2_U, R_LN2};
+use crate::expm1::{
+    EXPM1_POLY_10_D, EXPM1_POLY_11_D, EXPM1_POLY_12_D, EXPM1_POLY_13_D, EXPM1_POLY_14_D,
+    EXPM1_POLY_2_D, EXPM1_POLY_3_D, EXPM1_POLY_4_D, EXPM1_POLY_5_D, EXPM1_POLY_6_D, EXPM1_POLY_7_D,
+    EXPM1_POLY_8_D, EXPM1_POLY_9_D,
+};
+use crate::{
+    _mm256_cvtepi64_pdx, _mm256_mlaf_pd, _mm256_pow2i_epi64, _mm256_rint_pd, _mm256_select_pd,
+};
+
+/// Computes `exp(r) - 1 - r` for reduced argument |r| <= ln(2)/2
+#[inline(always)]
+unsafe fn _mm256_expm1_tail_pd(r: __m256d) -> __m256d {
+    let mut u = _mm256_set1_pd(EXPM1_POLY_14_D);
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_13_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_12_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_11_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_10_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_9_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_8_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_7_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_6_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_5_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_4_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_3_D));
+    u = _mm256_mlaf_pd(u, r, _mm256_set1_pd(EXPM1_POLY_2_D));
+    _mm256_mul_pd(_mm256_mul_pd(r, r), u)
+}
+
+/// Computes exp(x) - 1, accurate for x near zero *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm256_expm1_pd(d: __m256d) -> __m256d {
+    let q = _mm256_rint_pd(_mm256_mul_pd(d, _mm256_set1_pd(R_LN2)));
+    let qf = _mm256_cvtepi64_pdx(q);
+    // rh is exact, rl keeps the part of q*ln(2) below it
+    let rh = _mm256_mlaf_pd(qf, _mm256_set1_pd(-L2_U), d);
+    let rl = _mm256_mul_pd(qf, _mm256_set1_pd(-L2_L));
+    let t = _mm256_expm1_tail_pd(_mm256_add_pd(rh, rl));
+    // exp(x) - 1 = 2^q*(rl + t) + (2^q*rh + 2^q - 1), the inner sum is exact where it cancels
+    let i2 = _mm256_castsi256_pd(_mm256_pow2i_epi64(q));
+    let mut u = _mm256_mlaf_pd(
+        i2,
+        _mm256_add_pd(rl, t),
+        _mm256_mlaf_pd(i2, rh, _mm256_sub_pd(i2, _mm256_set1_pd(1f64))),
+    );
+    u = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_GT_OS>(qf, _mm256_set1_pd(53f64)),
+        _mm256_sub_pd(
+            _mm256_ldexp2k_pd(
+                _mm256_add_pd(
+                    _mm256_set1_pd(1f64),
+                    _mm256_add_pd(rh, _mm256_add_pd(rl, t)),
+                ),
+                q,
+            ),
+            _mm256_set1_pd(1f64),
+        ),
+        u,
+    );
+    u = _mm256_select_pd(_mm256_cmp_pd::<_CMP_EQ_OQ>(d, _mm256_setzero_pd()), d, u);
+    u = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_LT_OS>(d, _mm256_set1_pd(-37.5f64)),
+        _mm256_set1_pd(-1f64),
+        u,
+    );
+    u = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_GT_OS>(d, _mm256_set1_pd(709.782712893384f64)),
+        _mm256_set1_pd(f64::INFINITY),
+        u,
+    );
+    u
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expm1d() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(1e-10f64);
+            let comparison = _mm256_expm1_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 1e-10f64.exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.5f64);
+            let comparison = _mm256_expm1_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 0.5f64.exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(-0.3f64);
+            let comparison = _mm256_expm1_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = (-0.3f64).exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm256_set1_pd(-50f64);
+            let comparison = _mm256_expm1_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm256_set1_pd(710f64);
+            let comparison = _mm256_expm1_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
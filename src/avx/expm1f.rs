@@ -0,0 +1,127 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::generalf::_mm256_ldexp2kq_ps;
+use crate::expf::{L2L_F, L2U_F};
+use crate::expm1f::{
+    EXPM1_POLY_2_S, EXPM1_POLY_3_S, EXPM1_POLY_4_S, EXPM1_POLY_5_S, EXPM1_POLY_6_S, EXPM1_POLY_7_S,
+    EXPM1_POLY_8_S,
+};
+use crate::{_mm256_mlaf_ps, _mm256_pow2if_epi32, _mm256_rint_ps, _mm256_select_ps};
+
+/// Computes `exp(r) - 1 - r` for reduced argument |r| <= ln(2)/2
+#[inline(always)]
+unsafe fn _mm256_expm1_tail_ps(r: __m256) -> __m256 {
+    let mut u = _mm256_set1_ps(EXPM1_POLY_8_S);
+    u = _mm256_mlaf_ps(u, r, _mm256_set1_ps(EXPM1_POLY_7_S));
+    u = _mm256_mlaf_ps(u, r, _mm256_set1_ps(EXPM1_POLY_6_S));
+    u = _mm256_mlaf_ps(u, r, _mm256_set1_ps(EXPM1_POLY_5_S));
+    u = _mm256_mlaf_ps(u, r, _mm256_set1_ps(EXPM1_POLY_4_S));
+    u = _mm256_mlaf_ps(u, r, _mm256_set1_ps(EXPM1_POLY_3_S));
+    u = _mm256_mlaf_ps(u, r, _mm256_set1_ps(EXPM1_POLY_2_S));
+    _mm256_mul_ps(_mm256_mul_ps(r, r), u)
+}
+
+/// Computes exp(x) - 1, accurate for x near zero *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm256_expm1_ps(d: __m256) -> __m256 {
+    let q = _mm256_rint_ps(_mm256_mul_ps(d, _mm256_set1_ps(std::f32::consts::LOG2_E)));
+    let qf = _mm256_cvtepi32_ps(q);
+    // rh is exact, rl keeps the part of q*ln(2) below it
+    let rh = _mm256_mlaf_ps(qf, _mm256_set1_ps(-L2U_F), d);
+    let rl = _mm256_mul_ps(qf, _mm256_set1_ps(-L2L_F));
+    let t = _mm256_expm1_tail_ps(_mm256_add_ps(rh, rl));
+    // exp(x) - 1 = 2^q*(rl + t) + (2^q*rh + 2^q - 1), the inner sum is exact where it cancels
+    let i2 = _mm256_castsi256_ps(_mm256_pow2if_epi32(q));
+    let mut u = _mm256_mlaf_ps(
+        i2,
+        _mm256_add_ps(rl, t),
+        _mm256_mlaf_ps(i2, rh, _mm256_sub_ps(i2, _mm256_set1_ps(1f32))),
+    );
+    u = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_GT_OS>(qf, _mm256_set1_ps(24f32)),
+        _mm256_sub_ps(
+            _mm256_ldexp2kq_ps(
+                _mm256_add_ps(
+                    _mm256_set1_ps(1f32),
+                    _mm256_add_ps(rh, _mm256_add_ps(rl, t)),
+                ),
+                q,
+            ),
+            _mm256_set1_ps(1f32),
+        ),
+        u,
+    );
+    u = _mm256_select_ps(_mm256_cmp_ps::<_CMP_EQ_OQ>(d, _mm256_setzero_ps()), d, u);
+    u = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_set1_ps(-17.5f32)),
+        _mm256_set1_ps(-1f32),
+        u,
+    );
+    u = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_GT_OS>(d, _mm256_set1_ps(88.72283f32)),
+        _mm256_set1_ps(f32::INFINITY),
+        u,
+    );
+    u
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expm1f() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(1e-6f32);
+            let comparison = _mm256_expm1_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1e-6f32.exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.5f32);
+            let comparison = _mm256_expm1_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 0.5f32.exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-0.3f32);
+            let comparison = _mm256_expm1_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = (-0.3f32).exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm256_set1_ps(-20f32);
+            let comparison = _mm256_expm1_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm256_set1_ps(89f32);
+            let comparison = _mm256_expm1_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
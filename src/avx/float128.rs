@@ -0,0 +1,272 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm256_extract_pd, _mm256_mlaf_pd};
+
+/// Type represents f128, in low f64 and high f64 part
+/// This is not real f128, this should be considered as `double double` rather than IEEE binary128
+/// Operations are the same as NEON `float128x2_t` ones and produce the same bits
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct __m256dx2(pub(crate) __m256d, pub(crate) __m256d);
+
+#[inline(always)]
+unsafe fn _mm256_upperpart_pd(a: __m256d) -> __m256d {
+    let mask = _mm256_set1_epi64x(0x_ffff_ffff_f800_0000u64 as i64);
+    _mm256_castsi256_pd(_mm256_and_si256(_mm256_castpd_si256(a), mask))
+}
+
+#[inline(always)]
+/// Negates by flipping sign bit, same as NEON `vnegq_f64`
+unsafe fn _mm256_negsign_pd(a: __m256d) -> __m256d {
+    _mm256_xor_pd(a, _mm256_set1_pd(-0.0f64))
+}
+
+#[inline(always)]
+/// Computes `a*b - c`
+unsafe fn _mm256_mlsf_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    _mm256_mlaf_pd(a, b, _mm256_negsign_pd(c))
+}
+
+#[inline(always)]
+/// Computes rounding error of `a*b`, `r` must be the rounded product
+unsafe fn _mm256_mul_err_pd(a: __m256d, b: __m256d, r: __m256d) -> __m256d {
+    let xh = _mm256_upperpart_pd(a);
+    let xl = _mm256_sub_pd(a, xh);
+    let yh = _mm256_upperpart_pd(b);
+    let yl = _mm256_sub_pd(b, yh);
+    _mm256_mlaf_pd(
+        xl,
+        yl,
+        _mm256_mlaf_pd(xl, yh, _mm256_mlaf_pd(xh, yl, _mm256_mlsf_pd(xh, yh, r))),
+    )
+}
+
+#[inline(always)]
+/// Performs multiplication for f128
+pub unsafe fn _mm256_mul_f128(a: __m256dx2, b: __m256dx2) -> __m256dx2 {
+    let r0 = _mm256_mul_pd(a.0, b.0);
+    let e = _mm256_mul_err_pd(a.0, b.0, r0);
+    __m256dx2(r0, _mm256_mlaf_pd(a.0, b.1, _mm256_mlaf_pd(a.1, b.0, e)))
+}
+
+#[inline(always)]
+/// Widens f64 into f128 and performs multiplication
+pub unsafe fn _mm256_mull_pd(a: __m256d, b: __m256d) -> __m256dx2 {
+    let r0 = _mm256_mul_pd(a, b);
+    __m256dx2(r0, _mm256_mul_err_pd(a, b, r0))
+}
+
+#[inline(always)]
+/// Adds f64 with widening to f128
+pub unsafe fn _mm256_addl_pd(a: __m256d, b: __m256d) -> __m256dx2 {
+    let r0 = _mm256_add_pd(a, b);
+    let v = _mm256_sub_pd(r0, a);
+    __m256dx2(
+        r0,
+        _mm256_add_pd(_mm256_sub_pd(a, _mm256_sub_pd(r0, v)), _mm256_sub_pd(b, v)),
+    )
+}
+
+/// Adds f128 to another f128
+#[inline(always)]
+pub unsafe fn _mm256_add_f128(a: __m256dx2, b: __m256dx2) -> __m256dx2 {
+    let r0 = _mm256_add_pd(a.0, b.0);
+    let v = _mm256_sub_pd(r0, a.0);
+    __m256dx2(
+        r0,
+        _mm256_add_pd(
+            _mm256_add_pd(
+                _mm256_sub_pd(a.0, _mm256_sub_pd(r0, v)),
+                _mm256_sub_pd(b.0, v),
+            ),
+            _mm256_add_pd(a.1, b.1),
+        ),
+    )
+}
+
+/// Negates f128
+#[inline(always)]
+pub unsafe fn _mm256_neg_f128(a: __m256dx2) -> __m256dx2 {
+    __m256dx2(_mm256_negsign_pd(a.0), _mm256_negsign_pd(a.1))
+}
+
+/// Adds f64 with widening to f128
+#[inline(always)]
+pub unsafe fn _mm256_addw_pd(a: __m256dx2, b: __m256d) -> __m256dx2 {
+    let r0 = _mm256_add_pd(a.0, b);
+    let v = _mm256_sub_pd(r0, a.0);
+    __m256dx2(
+        r0,
+        _mm256_add_pd(
+            _mm256_add_pd(
+                _mm256_sub_pd(a.0, _mm256_sub_pd(r0, v)),
+                _mm256_sub_pd(b, v),
+            ),
+            a.1,
+        ),
+    )
+}
+
+#[inline(always)]
+/// Performs division for f128
+pub unsafe fn _mm256_div_f128(a: __m256dx2, b: __m256dx2) -> __m256dx2 {
+    let t = _mm256_div_pd(_mm256_set1_pd(1.), b.0);
+    let q0 = _mm256_mul_pd(a.0, t);
+    let u = _mm256_mlaf_pd(
+        q0,
+        _mm256_negsign_pd(_mm256_mul_err_pd(b.0, t, _mm256_set1_pd(1.))),
+        _mm256_mul_err_pd(a.0, t, q0),
+    );
+    let low = _mm256_mlaf_pd(t, _mm256_sub_pd(a.1, _mm256_mul_pd(q0, b.1)), u);
+    __m256dx2(q0, low)
+}
+
+#[inline(always)]
+/// Converts f128 into f64
+pub unsafe fn _mm256_cvtf128_pd(d: __m256dx2) -> __m256d {
+    _mm256_add_pd(d.0, d.1)
+}
+
+#[inline(always)]
+/// Converts f64 into f128
+pub unsafe fn _mm256_cvtpd_f128(d: __m256d) -> __m256dx2 {
+    __m256dx2(d, _mm256_setzero_pd())
+}
+
+#[inline(always)]
+/// Broadcasts f64 into f128
+pub unsafe fn _mm256_set1_f128(d: f64) -> __m256dx2 {
+    __m256dx2(_mm256_set1_pd(d), _mm256_setzero_pd())
+}
+
+/// Computes f128 as f64 and extracts in general register
+#[inline(always)]
+pub unsafe fn _mm256_extract_f128<const IMM: i32>(d: __m256dx2) -> f64 {
+    _mm256_extract_pd::<IMM>(_mm256_add_pd(d.0, d.1))
+}
+
+#[inline(always)]
+/// Fused multiply add for f128
+pub unsafe fn _mm256_mlaf_f128(a: __m256dx2, b: __m256dx2, c: __m256dx2) -> __m256dx2 {
+    _mm256_add_f128(_mm256_mul_f128(a, b), c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scalar port of the NEON `float128x2_t` algorithms, `vfmaq_f64` is always fused
+    fn mla(a: f64, b: f64, c: f64) -> f64 {
+        a.mul_add(b, c)
+    }
+
+    fn upper(a: f64) -> f64 {
+        f64::from_bits(a.to_bits() & 0x_ffff_ffff_f800_0000)
+    }
+
+    fn mul_err(a: f64, b: f64, r: f64) -> f64 {
+        let xh = upper(a);
+        let xl = a - xh;
+        let yh = upper(b);
+        let yl = b - yh;
+        mla(xl, yl, mla(xl, yh, mla(xh, yl, mla(xh, yh, -r))))
+    }
+
+    fn mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+        let r0 = a.0 * b.0;
+        (r0, mla(a.0, b.1, mla(a.1, b.0, mul_err(a.0, b.0, r0))))
+    }
+
+    fn mull(a: f64, b: f64) -> (f64, f64) {
+        let r0 = a * b;
+        (r0, mul_err(a, b, r0))
+    }
+
+    fn div(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+        let t = 1. / b.0;
+        let q0 = a.0 * t;
+        let u = mla(q0, -mul_err(b.0, t, 1.), mul_err(a.0, t, q0));
+        (q0, mla(t, a.1 - q0 * b.1, u))
+    }
+
+    unsafe fn lanes(d: __m256dx2) -> [(f64, f64); 4] {
+        [
+            (_mm256_extract_pd::<0>(d.0), _mm256_extract_pd::<0>(d.1)),
+            (_mm256_extract_pd::<1>(d.0), _mm256_extract_pd::<1>(d.1)),
+            (_mm256_extract_pd::<2>(d.0), _mm256_extract_pd::<2>(d.1)),
+            (_mm256_extract_pd::<3>(d.0), _mm256_extract_pd::<3>(d.1)),
+        ]
+    }
+
+    fn assert_bits(v: (f64, f64), control: (f64, f64)) {
+        assert_eq!(v.0.to_bits(), control.0.to_bits());
+        assert_eq!(v.1.to_bits(), control.1.to_bits());
+    }
+
+    const VALUES: [(f64, f64); 4] = [
+        (std::f64::consts::PI, 1.2246467991473532e-16),
+        (1. / 3., 1.850371707708594e-17),
+        (-7.000000000000001, 0.),
+        (1.2345678901234567e100, -3.2e83),
+    ];
+
+    #[test]
+    fn test_mul_div_f128() {
+        unsafe {
+            for a in VALUES.iter() {
+                for b in VALUES.iter() {
+                    let va = __m256dx2(
+                        _mm256_set_pd(a.0, b.0, b.0, a.0),
+                        _mm256_set_pd(a.1, b.1, b.1, a.1),
+                    );
+                    let vb = __m256dx2(
+                        _mm256_set_pd(b.0, a.0, a.0, b.0),
+                        _mm256_set_pd(b.1, a.1, a.1, b.1),
+                    );
+                    let m = lanes(_mm256_mul_f128(va, vb));
+                    assert_bits(m[0], mul(*a, *b));
+                    assert_bits(m[1], mul(*b, *a));
+                    assert_bits(m[2], mul(*b, *a));
+                    assert_bits(m[3], mul(*a, *b));
+                    let d = lanes(_mm256_div_f128(va, vb));
+                    assert_bits(d[0], div(*a, *b));
+                    assert_bits(d[1], div(*b, *a));
+                    assert_bits(d[2], div(*b, *a));
+                    assert_bits(d[3], div(*a, *b));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_f128() {
+        unsafe {
+            let v = _mm256_mull_pd(_mm256_set1_pd(0.1), _mm256_set1_pd(1. / 3.));
+            assert_bits(lanes(v)[0], mull(0.1, 1. / 3.));
+            assert_eq!(lanes(v)[0].1, 0.1f64.mul_add(1. / 3., -lanes(v)[0].0));
+
+            let v = _mm256_addl_pd(_mm256_set1_pd(1.), _mm256_set1_pd(1e-20));
+            assert_bits(lanes(v)[3], (1., 1e-20));
+            let v = _mm256_addw_pd(v, _mm256_set1_pd(-1.));
+            assert_bits(lanes(v)[0], (0., 1e-20));
+            let v = _mm256_add_f128(_mm256_set1_f128(2.), _mm256_neg_f128(_mm256_set1_f128(0.5)));
+            assert_eq!(_mm256_extract_f128::<2>(v), 1.5);
+
+            let third = _mm256_div_f128(_mm256_set1_f128(1.), _mm256_set1_f128(3.));
+            let one = _mm256_mlaf_f128(third, _mm256_set1_f128(3.), _mm256_set1_f128(-1.));
+            assert!(_mm256_extract_f128::<0>(one).abs() < 1e-31);
+            let r = _mm256_cvtf128_pd(_mm256_cvtpd_f128(_mm256_set1_pd(5.)));
+            assert_eq!(_mm256_extract_pd::<1>(r), 5.);
+        }
+    }
+}
@@ -0,0 +1,430 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{
+    __m256dx2, _mm256_abs_pd, _mm256_add_f128, _mm256_cvtpd_f128, _mm256_ilogb2k_pd,
+    _mm256_isintegral_pd, _mm256_isnotintegral_pd, _mm256_ldexp2k_pd, _mm256_ln_pd,
+    _mm256_mul_f128, _mm256_mull_pd, _mm256_neg_f128, _mm256_pow_pd, _mm256_select_pd,
+    _mm256_set1_f128,
+};
+
+const LN2: [f64; 3] = [
+    std::f64::consts::LN_2,
+    2.3190468138462996e-17,
+    5.707708438416212e-34,
+];
+
+const PI_2: [f64; 3] = [
+    std::f64::consts::FRAC_PI_2,
+    6.123233995736766e-17,
+    -1.4973849048591698e-33,
+];
+
+/// 1/n! for n in 10..=2
+const EXP_POLY: [(f64, f64); 9] = [
+    (2.755731922398589e-07, 2.3767714622250297e-23),
+    (2.7557319223985893e-06, -1.858393274046472e-22),
+    (2.48015873015873e-05, 2.1511947866775882e-23),
+    (0.0001984126984126984, 1.7209558293420705e-22),
+    (0.001388888888888889, -5.300543954373577e-20),
+    (0.008333333333333333, 1.1564823173178714e-19),
+    (0.041666666666666664, 2.3129646346357427e-18),
+    (0.16666666666666666, 9.25185853854297e-18),
+    (0.5, 0.),
+];
+
+/// (-1)^n/(2n+1)! for n in 14..=1
+const SIN_POLY: [(f64, f64); 14] = [
+    (1.1309962886447716e-31, 1.0498015412959506e-47),
+    (-9.183689863795546e-29, -1.4303150396787322e-45),
+    (6.446950284384474e-26, -1.9330404233703465e-42),
+    (-3.868170170630684e-23, 8.843177655482344e-40),
+    (1.9572941063391263e-20, -1.3643503830087908e-36),
+    (-8.22063524662433e-18, -2.2141894119604265e-34),
+    (2.8114572543455206e-15, 1.6508842730861433e-31),
+    (-7.647163731819816e-13, -7.03872877733453e-30),
+    (1.6059043836821613e-10, 1.2585294588752098e-26),
+    (-2.505210838544172e-08, 1.448814070935912e-24),
+    (2.7557319223985893e-06, -1.858393274046472e-22),
+    (-0.0001984126984126984, -1.7209558293420705e-22),
+    (0.008333333333333333, 1.1564823173178714e-19),
+    (-0.16666666666666666, -9.25185853854297e-18),
+];
+
+/// (-1)^n/(2n)! for n in 14..=1
+const COS_POLY: [(f64, f64); 14] = [
+    (3.279889237069838e-30, 1.5117542744029879e-46),
+    (-2.4795962632247976e-27, 1.2953730964765229e-43),
+    (1.6117375710961184e-24, -3.6846573564509766e-41),
+    (-8.896791392450574e-22, 7.911402614872376e-38),
+    (4.110317623312165e-19, 1.4412973378659527e-36),
+    (-1.5619206968586225e-16, -1.1910679660273754e-32),
+    (4.779477332387385e-14, 4.399205485834081e-31),
+    (-1.1470745597729725e-11, -2.0655512752830745e-28),
+    (2.08767569878681e-09, -1.20734505911326e-25),
+    (-2.755731922398589e-07, -2.3767714622250297e-23),
+    (2.48015873015873e-05, 2.1511947866775882e-23),
+    (-0.001388888888888889, 5.300543954373577e-20),
+    (0.041666666666666664, 2.3129646346357427e-18),
+    (-0.5, 0.),
+];
+
+#[inline(always)]
+unsafe fn _mm256_sub_f128(a: __m256dx2, b: __m256dx2) -> __m256dx2 {
+    _mm256_add_f128(a, _mm256_neg_f128(b))
+}
+
+#[inline(always)]
+/// Moves `a` into canonical form where `hi = hi + lo` rounded
+unsafe fn _mm256_renorm_f128(a: __m256dx2) -> __m256dx2 {
+    let r0 = _mm256_add_pd(a.0, a.1);
+    __m256dx2(r0, _mm256_sub_pd(a.1, _mm256_sub_pd(r0, a.0)))
+}
+
+#[inline(always)]
+unsafe fn _mm256_select_f128(
+    mask: __m256d,
+    true_vals: __m256dx2,
+    false_vals: __m256dx2,
+) -> __m256dx2 {
+    __m256dx2(
+        _mm256_select_pd(mask, true_vals.0, false_vals.0),
+        _mm256_select_pd(mask, true_vals.1, false_vals.1),
+    )
+}
+
+#[inline(always)]
+/// Computes `a*2^n` for integral `n` held in f64
+unsafe fn _mm256_ldexp_f128(a: __m256dx2, n: __m256d) -> __m256dx2 {
+    // Integral values below 2^51 are read from mantissa bits
+    let magic = _mm256_set1_pd((1u64 << 52) as f64 + (1u64 << 51) as f64);
+    let k = _mm256_sub_epi64(
+        _mm256_castpd_si256(_mm256_add_pd(n, magic)),
+        _mm256_castpd_si256(magic),
+    );
+    __m256dx2(_mm256_ldexp2k_pd(a.0, k), _mm256_ldexp2k_pd(a.1, k))
+}
+
+#[inline(always)]
+/// Computes `a - n*c` for integral `n` and three part constant `c`
+unsafe fn _mm256_reduce_f128(a: __m256dx2, n: __m256d, c: &[f64; 3]) -> __m256dx2 {
+    let r = _mm256_renorm_f128(_mm256_sub_f128(a, _mm256_mull_pd(n, _mm256_set1_pd(c[0]))));
+    let r = _mm256_sub_f128(r, _mm256_mull_pd(n, _mm256_set1_pd(c[1])));
+    _mm256_sub_f128(r, _mm256_cvtpd_f128(_mm256_mul_pd(n, _mm256_set1_pd(c[2]))))
+}
+
+#[inline(always)]
+unsafe fn _mm256_poly_f128(x: __m256dx2, coeffs: &[(f64, f64)]) -> __m256dx2 {
+    let mut acc = __m256dx2(_mm256_set1_pd(coeffs[0].0), _mm256_set1_pd(coeffs[0].1));
+    for c in coeffs.iter().skip(1) {
+        acc = _mm256_add_f128(
+            _mm256_mul_f128(acc, x),
+            __m256dx2(_mm256_set1_pd(c.0), _mm256_set1_pd(c.1)),
+        );
+    }
+    acc
+}
+
+#[inline(always)]
+/// Multiplies both parts by power of two `s`
+unsafe fn _mm256_scale_f128(a: __m256dx2, s: __m256d) -> __m256dx2 {
+    __m256dx2(_mm256_mul_pd(a.0, s), _mm256_mul_pd(a.1, s))
+}
+
+#[inline(always)]
+/// Computes square root for f128
+pub unsafe fn _mm256_sqrt_f128(d: __m256dx2) -> __m256dx2 {
+    // Tiny values are scaled up so the residual does not fall into subnormals
+    let o = _mm256_cmp_pd::<_CMP_LT_OQ>(d.0, _mm256_set1_pd(1e-250));
+    let a = _mm256_scale_f128(
+        d,
+        _mm256_select_pd(
+            o,
+            _mm256_set1_pd(f64::from_bits(0x5ff0000000000000)),
+            _mm256_set1_pd(1.),
+        ),
+    );
+    let y = _mm256_sqrt_pd(a.0);
+    let e = _mm256_sub_f128(a, _mm256_mull_pd(y, y));
+    let c = _mm256_div_pd(_mm256_add_pd(e.0, e.1), _mm256_add_pd(y, y));
+    let r0 = _mm256_add_pd(y, c);
+    let r = _mm256_scale_f128(
+        __m256dx2(r0, _mm256_sub_pd(c, _mm256_sub_pd(r0, y))),
+        _mm256_select_pd(
+            o,
+            _mm256_set1_pd(f64::from_bits(0x2ff0000000000000)),
+            _mm256_set1_pd(1.),
+        ),
+    );
+    let special = _mm256_or_pd(
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(d.0, _mm256_setzero_pd()),
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(d.0, _mm256_set1_pd(f64::INFINITY)),
+    );
+    _mm256_select_f128(special, _mm256_cvtpd_f128(_mm256_sqrt_pd(d.0)), r)
+}
+
+#[inline(always)]
+/// Computes expm1 for |r| < 0.5
+unsafe fn _mm256_expm1_reduced_f128(r: __m256dx2) -> __m256dx2 {
+    // expm1(r) from expm1(r/256) by repeated (1 + e)^2 - 1 = e*(e + 2)
+    let s = _mm256_scale_f128(r, _mm256_set1_pd(1. / 256.));
+    let p = _mm256_poly_f128(s, &EXP_POLY);
+    let mut e = _mm256_mul_f128(
+        s,
+        _mm256_add_f128(_mm256_mul_f128(p, s), _mm256_set1_f128(1.)),
+    );
+    for _ in 0..8 {
+        e = _mm256_renorm_f128(_mm256_mul_f128(e, _mm256_add_f128(e, _mm256_set1_f128(2.))));
+    }
+    e
+}
+
+#[inline(always)]
+/// Computes exp for f128
+pub unsafe fn _mm256_exp_f128(d: __m256dx2) -> __m256dx2 {
+    let x = _mm256_min_pd(
+        _mm256_max_pd(d.0, _mm256_set1_pd(-750.)),
+        _mm256_set1_pd(710.),
+    );
+    let k = _mm256_round_pd::<0x00>(_mm256_mul_pd(x, _mm256_set1_pd(std::f64::consts::LOG2_E)));
+    let r = _mm256_renorm_f128(_mm256_reduce_f128(d, k, &LN2));
+    let e = _mm256_expm1_reduced_f128(r);
+    let mut v = _mm256_ldexp_f128(
+        _mm256_renorm_f128(_mm256_add_f128(e, _mm256_set1_f128(1.))),
+        k,
+    );
+    v = _mm256_select_f128(
+        _mm256_cmp_pd::<_CMP_GT_OQ>(d.0, _mm256_set1_pd(709.782712893384)),
+        _mm256_set1_f128(f64::INFINITY),
+        v,
+    );
+    v = _mm256_select_f128(
+        _mm256_cmp_pd::<_CMP_LT_OQ>(d.0, _mm256_set1_pd(-745.1332191019412)),
+        _mm256_set1_f128(0.),
+        v,
+    );
+    v
+}
+
+#[inline(always)]
+/// Computes natural logarithm for f128
+pub unsafe fn _mm256_ln_f128(d: __m256dx2) -> __m256dx2 {
+    let o = _mm256_cmp_pd::<_CMP_LT_OQ>(d.0, _mm256_set1_pd(f64::MIN_POSITIVE));
+    let scale = _mm256_select_pd(o, _mm256_set1_pd(18446744073709551616.), _mm256_set1_pd(1.));
+    let a = _mm256_scale_f128(d, scale);
+    // a = m*2^e, m in [0.75, 1.5)
+    let e = _mm256_castsi256_pd(_mm256_add_epi64(
+        _mm256_ilogb2k_pd(_mm256_mul_pd(a.0, _mm256_set1_pd(1. / 0.75))),
+        _mm256_castpd_si256(_mm256_set1_pd((1u64 << 52) as f64 + (1u64 << 51) as f64)),
+    ));
+    let e = _mm256_sub_pd(e, _mm256_set1_pd((1u64 << 52) as f64 + (1u64 << 51) as f64));
+    let m = _mm256_ldexp_f128(a, _mm256_sub_pd(_mm256_setzero_pd(), e));
+    let e = _mm256_sub_pd(
+        e,
+        _mm256_select_pd(o, _mm256_set1_pd(64.), _mm256_setzero_pd()),
+    );
+    // One Newton step on exp(y) = m, m*exp(-y0) - 1 = m*expm1(-y0) + (m - 1)
+    let y0 = _mm256_ln_pd(m.0);
+    let em1 = _mm256_expm1_reduced_f128(_mm256_cvtpd_f128(_mm256_sub_pd(_mm256_setzero_pd(), y0)));
+    let t = _mm256_add_f128(
+        _mm256_mul_f128(m, em1),
+        _mm256_sub_f128(m, _mm256_set1_f128(1.)),
+    );
+    let y = _mm256_add_f128(_mm256_cvtpd_f128(y0), t);
+    let r = _mm256_renorm_f128(_mm256_sub_f128(
+        y,
+        _mm256_reduce_f128(_mm256_set1_f128(0.), e, &LN2),
+    ));
+    let finite = _mm256_and_pd(
+        _mm256_cmp_pd::<_CMP_GT_OQ>(d.0, _mm256_setzero_pd()),
+        _mm256_cmp_pd::<_CMP_LT_OQ>(d.0, _mm256_set1_pd(f64::INFINITY)),
+    );
+    _mm256_select_f128(finite, r, _mm256_cvtpd_f128(_mm256_ln_pd(d.0)))
+}
+
+#[inline(always)]
+/// Computes sin and cos of reduced argument with quadrant `q` in f64
+unsafe fn _mm256_sincos_reduce_f128(d: __m256dx2) -> (__m256dx2, __m256dx2, __m256d) {
+    let k = _mm256_round_pd::<0x00>(_mm256_mul_pd(
+        d.0,
+        _mm256_set1_pd(std::f64::consts::FRAC_2_PI),
+    ));
+    let r = _mm256_renorm_f128(_mm256_reduce_f128(d, k, &PI_2));
+    let r2 = _mm256_mul_f128(r, r);
+    let s = _mm256_renorm_f128(_mm256_add_f128(
+        r,
+        _mm256_mul_f128(_mm256_mul_f128(_mm256_poly_f128(r2, &SIN_POLY), r2), r),
+    ));
+    let c = _mm256_renorm_f128(_mm256_add_f128(
+        _mm256_set1_f128(1.),
+        _mm256_mul_f128(_mm256_poly_f128(r2, &COS_POLY), r2),
+    ));
+    let q = _mm256_sub_pd(
+        k,
+        _mm256_mul_pd(
+            _mm256_floor_pd(_mm256_mul_pd(k, _mm256_set1_pd(0.25))),
+            _mm256_set1_pd(4.),
+        ),
+    );
+    (s, c, q)
+}
+
+#[inline(always)]
+/// Computes sine for f128, accurate for |x| < 2^40
+pub unsafe fn _mm256_sin_f128(d: __m256dx2) -> __m256dx2 {
+    let (s, c, q) = _mm256_sincos_reduce_f128(d);
+    let odd = _mm256_or_pd(
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(q, _mm256_set1_pd(1.)),
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(q, _mm256_set1_pd(3.)),
+    );
+    let v = _mm256_select_f128(odd, c, s);
+    let negate = _mm256_cmp_pd::<_CMP_GE_OQ>(q, _mm256_set1_pd(2.));
+    _mm256_select_f128(negate, _mm256_neg_f128(v), v)
+}
+
+#[inline(always)]
+/// Computes cosine for f128, accurate for |x| < 2^40
+pub unsafe fn _mm256_cos_f128(d: __m256dx2) -> __m256dx2 {
+    let (s, c, q) = _mm256_sincos_reduce_f128(d);
+    let odd = _mm256_or_pd(
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(q, _mm256_set1_pd(1.)),
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(q, _mm256_set1_pd(3.)),
+    );
+    let v = _mm256_select_f128(odd, s, c);
+    let negate = _mm256_or_pd(
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(q, _mm256_set1_pd(1.)),
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(q, _mm256_set1_pd(2.)),
+    );
+    _mm256_select_f128(negate, _mm256_neg_f128(v), v)
+}
+
+#[inline(always)]
+/// Computes pow for f128, error grows with |y*ln(x)| up to 2^-97 near overflow
+pub unsafe fn _mm256_pow_f128(x: __m256dx2, y: __m256dx2) -> __m256dx2 {
+    let neg = _mm256_cmp_pd::<_CMP_LT_OQ>(x.0, _mm256_setzero_pd());
+    let ax = _mm256_select_f128(neg, _mm256_neg_f128(x), x);
+    let mut r = _mm256_exp_f128(_mm256_mul_f128(y, _mm256_ln_f128(ax)));
+    let y_int = _mm256_and_pd(_mm256_isintegral_pd(y.0), _mm256_isintegral_pd(y.1));
+    let y_odd = _mm256_and_pd(
+        y_int,
+        _mm256_xor_pd(
+            _mm256_isnotintegral_pd(_mm256_mul_pd(y.0, _mm256_set1_pd(0.5))),
+            _mm256_isnotintegral_pd(_mm256_mul_pd(y.1, _mm256_set1_pd(0.5))),
+        ),
+    );
+    r = _mm256_select_f128(_mm256_and_pd(neg, y_odd), _mm256_neg_f128(r), r);
+    let inf = _mm256_set1_pd(f64::INFINITY);
+    let regular = _mm256_and_pd(
+        _mm256_and_pd(
+            _mm256_cmp_pd::<_CMP_GT_OQ>(_mm256_abs_pd(x.0), _mm256_setzero_pd()),
+            _mm256_cmp_pd::<_CMP_LT_OQ>(_mm256_abs_pd(x.0), inf),
+        ),
+        _mm256_and_pd(
+            _mm256_cmp_pd::<_CMP_LT_OQ>(_mm256_abs_pd(y.0), inf),
+            _mm256_or_pd(_mm256_cmp_pd::<_CMP_GT_OQ>(x.0, _mm256_setzero_pd()), y_int),
+        ),
+    );
+    _mm256_select_f128(regular, r, _mm256_cvtpd_f128(_mm256_pow_pd(x.0, y.0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm256_extract_pd;
+
+    unsafe fn value(d: __m256dx2) -> [(f64, f64); 2] {
+        [
+            (_mm256_extract_pd::<0>(d.0), _mm256_extract_pd::<0>(d.1)),
+            (_mm256_extract_pd::<1>(d.0), _mm256_extract_pd::<1>(d.1)),
+        ]
+    }
+
+    /// Checks that `hi + lo` is within `1e-30` relative error from the control split in two f64
+    fn assert_dd(v: (f64, f64), control: (f64, f64)) {
+        let diff = (v.0 - control.0) + (v.1 - control.1);
+        assert!(
+            diff.abs() <= control.0.abs() * 1e-30,
+            "({}, {}) against ({}, {})",
+            v.0,
+            v.1,
+            control.0,
+            control.1
+        );
+    }
+
+    #[test]
+    fn test_sqrt_f128() {
+        unsafe {
+            let v = value(_mm256_sqrt_f128(__m256dx2(
+                _mm256_set_pd(0.5, 2., 0.5, 2.),
+                _mm256_setzero_pd(),
+            )));
+            assert_dd(v[0], (1.4142135623730951, -9.667293313452913e-17));
+            assert_dd(v[1], (0.7071067811865476, -4.833646656726457e-17));
+            let v = value(_mm256_sqrt_f128(__m256dx2(
+                _mm256_set_pd(f64::INFINITY, 0., f64::INFINITY, 0.),
+                _mm256_setzero_pd(),
+            )));
+            assert_eq!(v[0].0, 0.);
+            assert_eq!(v[1].0, f64::INFINITY);
+        }
+    }
+
+    #[test]
+    fn test_exp_ln_f128() {
+        unsafe {
+            let v = value(_mm256_exp_f128(__m256dx2(
+                _mm256_set_pd(-20.5, 1., -20.5, 1.),
+                _mm256_setzero_pd(),
+            )));
+            assert_dd(v[0], (2.718281828459045, 1.4456468917292502e-16));
+            assert_dd(v[1], (1.2501528663867426e-9, 6.448235878237776e-26));
+            let v = value(_mm256_ln_f128(__m256dx2(
+                _mm256_set_pd(1e-310, 10., 1e-310, 10.),
+                _mm256_setzero_pd(),
+            )));
+            assert_dd(v[0], (2.302585092994046, -2.1707562233822494e-16));
+            assert_dd(v[1], (-713.8013788281542, -8.592254740270771e-15));
+            let v = value(_mm256_ln_f128(__m256dx2(
+                _mm256_set_pd(-1., 0., -1., 0.),
+                _mm256_setzero_pd(),
+            )));
+            assert_eq!(v[0].0, f64::NEG_INFINITY);
+            assert!(v[1].0.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_sin_cos_f128() {
+        unsafe {
+            let x = __m256dx2(_mm256_set_pd(-1000., 0.5, -1000., 0.5), _mm256_setzero_pd());
+            let v = value(_mm256_sin_f128(x));
+            assert_dd(v[0], (0.479425538604203, -5.103969860556013e-18));
+            assert_dd(v[1], (-0.8268795405320025, -3.867581909641312e-17));
+            let v = value(_mm256_cos_f128(x));
+            assert_dd(v[0], (0.8775825618903728, -4.2623149864279997e-17));
+            assert_dd(v[1], (0.5623790762907029, 5.1611423543848204e-17));
+        }
+    }
+
+    #[test]
+    fn test_pow_f128() {
+        unsafe {
+            let v = value(_mm256_pow_f128(
+                __m256dx2(_mm256_set_pd(-3., 2., -3., 2.), _mm256_setzero_pd()),
+                __m256dx2(_mm256_set_pd(5., 0.5, 5., 0.5), _mm256_setzero_pd()),
+            ));
+            assert_dd(v[0], (1.4142135623730951, -9.667293313452913e-17));
+            assert_dd(v[1], (-243., 0.));
+        }
+    }
+}
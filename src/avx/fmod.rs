@@ -10,14 +10,92 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
-/// Computes fmod for f64
+use crate::avx::general::{
+    _mm256_abs_pd, _mm256_copysign_pd, _mm256_ilogb2k_pd, _mm256_isinf_pd, _mm256_ldexp3k_pd,
+    _mm256_select_pd,
+};
+use crate::fmod::do_fmod;
+
+#[inline(always)]
+/// Computes fmod for f64, result is exact and has the sign of `a`
 pub unsafe fn _mm256_fmod_pd(a: __m256d, b: __m256d) -> __m256d {
-    let dividend_vec = a;
-    let divisor_vec = b;
-    let division = _mm256_mul_pd(dividend_vec, _mm256_div_pd(_mm256_set1_pd(1.), divisor_vec));
-    let int_part = _mm256_floor_pd(division);
-    let product = _mm256_mul_pd(int_part, divisor_vec);
-    let remainder = _mm256_sub_pd(dividend_vec, product);
-    remainder
+    let ax = _mm256_abs_pd(a);
+    let ay = _mm256_abs_pd(b);
+    let nan_mask = _mm256_or_pd(
+        _mm256_or_pd(_mm256_cmp_pd::<_CMP_UNORD_Q>(a, b), _mm256_isinf_pd(a)),
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(b, _mm256_setzero_pd()),
+    );
+    // Subnormal divisors and exponent differences above 64 are left to the scalar routine
+    let fallback = _mm256_andnot_pd(
+        nan_mask,
+        _mm256_or_pd(
+            _mm256_cmp_pd::<_CMP_LT_OQ>(ay, _mm256_set1_pd(f64::MIN_POSITIVE)),
+            _mm256_cmp_pd::<_CMP_GT_OQ>(
+                ax,
+                _mm256_mul_pd(ay, _mm256_set1_pd(18446744073709551616.)),
+            ),
+        ),
+    );
+    if _mm256_movemask_pd(fallback) != 0 {
+        let mut xs = [0f64; 4];
+        let mut ys = [0f64; 4];
+        _mm256_storeu_pd(xs.as_mut_ptr(), a);
+        _mm256_storeu_pd(ys.as_mut_ptr(), b);
+        for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+            *x = do_fmod(*x, y);
+        }
+        return _mm256_loadu_pd(xs.as_ptr());
+    }
+    let mut rem = ax;
+    let mut work = _mm256_andnot_pd(nan_mask, _mm256_cmp_pd::<_CMP_GE_OQ>(rem, ay));
+    while _mm256_movemask_pd(work) != 0 {
+        // Largest |b|*2^k not above the remainder, subtraction is exact by Sterbenz lemma
+        let k = _mm256_sub_epi64(_mm256_ilogb2k_pd(rem), _mm256_ilogb2k_pd(ay));
+        let mut t = _mm256_ldexp3k_pd(ay, k);
+        t = _mm256_select_pd(
+            _mm256_cmp_pd::<_CMP_GT_OQ>(t, rem),
+            _mm256_mul_pd(t, _mm256_set1_pd(0.5)),
+            t,
+        );
+        rem = _mm256_select_pd(work, _mm256_sub_pd(rem, t), rem);
+        work = _mm256_and_pd(work, _mm256_cmp_pd::<_CMP_GE_OQ>(rem, ay));
+    }
+    let ret = _mm256_copysign_pd(rem, a);
+    _mm256_select_pd(nan_mask, _mm256_set1_pd(f64::NAN), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmodd() {
+        let values = [
+            (7., 2.),
+            (5.5, 2.5),
+            (-7., 2.),
+            (7., -2.),
+            (1e300, 3.),
+            (-1e300, 1e-300),
+            (3.5e-310, 1e-315),
+            (0.1, 1e308),
+            (-0., 3.),
+        ];
+        for (x, y) in values {
+            unsafe {
+                let comparison = _mm256_fmod_pd(
+                    _mm256_setr_pd(x, 7., 1e20, 0.5),
+                    _mm256_setr_pd(y, 2., 3., 0.1),
+                );
+                let flag_1 = _mm256_cvtsd_f64(comparison);
+                assert_eq!(flag_1.to_bits(), (x % y).to_bits());
+            }
+        }
+        unsafe {
+            let comparison = _mm256_fmod_pd(_mm256_set1_pd(f64::INFINITY), _mm256_set1_pd(2.));
+            assert!(_mm256_cvtsd_f64(comparison).is_nan());
+            let comparison = _mm256_fmod_pd(_mm256_set1_pd(2.), _mm256_set1_pd(f64::INFINITY));
+            assert_eq!(_mm256_cvtsd_f64(comparison), 2.);
+        }
+    }
 }
@@ -4,19 +4,92 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
+
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
-/// Computes fmod for f32
+use crate::avx::generalf::{
+    _mm256_abs_ps, _mm256_copysign_ps, _mm256_ilogb2kq_ps, _mm256_isinf_ps, _mm256_ldexp3kq_ps,
+    _mm256_select_ps,
+};
+use crate::fmodf::do_fmodf;
+
+#[inline(always)]
+/// Computes fmod for f32, result is exact and has the sign of `a`
 pub unsafe fn _mm256_fmod_ps(a: __m256, b: __m256) -> __m256 {
-    let dividend_vec = a;
-    let divisor_vec = b;
-    let division = _mm256_mul_ps(dividend_vec, _mm256_div_ps(_mm256_set1_ps(1.), divisor_vec));
-    let int_part = _mm256_floor_ps(division);
-    let product = _mm256_mul_ps(int_part, divisor_vec);
-    let remainder = _mm256_sub_ps(dividend_vec, product);
-    remainder
+    let ax = _mm256_abs_ps(a);
+    let ay = _mm256_abs_ps(b);
+    let nan_mask = _mm256_or_ps(
+        _mm256_or_ps(_mm256_cmp_ps::<_CMP_UNORD_Q>(a, b), _mm256_isinf_ps(a)),
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(b, _mm256_setzero_ps()),
+    );
+    // Subnormal divisors and exponent differences above 32 are left to the scalar routine
+    let fallback = _mm256_andnot_ps(
+        nan_mask,
+        _mm256_or_ps(
+            _mm256_cmp_ps::<_CMP_LT_OQ>(ay, _mm256_set1_ps(f32::MIN_POSITIVE)),
+            _mm256_cmp_ps::<_CMP_GT_OQ>(ax, _mm256_mul_ps(ay, _mm256_set1_ps(4294967296.))),
+        ),
+    );
+    if _mm256_movemask_ps(fallback) != 0 {
+        let mut xs = [0f32; 8];
+        let mut ys = [0f32; 8];
+        _mm256_storeu_ps(xs.as_mut_ptr(), a);
+        _mm256_storeu_ps(ys.as_mut_ptr(), b);
+        for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+            *x = do_fmodf(*x, y);
+        }
+        return _mm256_loadu_ps(xs.as_ptr());
+    }
+    let mut rem = ax;
+    let mut work = _mm256_andnot_ps(nan_mask, _mm256_cmp_ps::<_CMP_GE_OQ>(rem, ay));
+    while _mm256_movemask_ps(work) != 0 {
+        // Largest |b|*2^k not above the remainder, subtraction is exact by Sterbenz lemma
+        let k = _mm256_sub_epi32(_mm256_ilogb2kq_ps(rem), _mm256_ilogb2kq_ps(ay));
+        let mut t = _mm256_ldexp3kq_ps(ay, k);
+        t = _mm256_select_ps(
+            _mm256_cmp_ps::<_CMP_GT_OQ>(t, rem),
+            _mm256_mul_ps(t, _mm256_set1_ps(0.5)),
+            t,
+        );
+        rem = _mm256_select_ps(work, _mm256_sub_ps(rem, t), rem);
+        work = _mm256_and_ps(work, _mm256_cmp_ps::<_CMP_GE_OQ>(rem, ay));
+    }
+    let ret = _mm256_copysign_ps(rem, a);
+    _mm256_select_ps(nan_mask, _mm256_set1_ps(f32::NAN), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmodf() {
+        let values = [
+            (7., 2.),
+            (5.5, 2.5),
+            (-7., 2.),
+            (7., -2.),
+            (1e38, 3.),
+            (-1e38, 1e-38),
+            (3.5e-40, 1e-44),
+            (0.1, 1e38),
+            (-0., 3.),
+        ];
+        for (x, y) in values {
+            unsafe {
+                let comparison = _mm256_fmod_ps(_mm256_set1_ps(x), _mm256_set1_ps(y));
+                let flag_1 = _mm256_cvtss_f32(comparison);
+                assert_eq!(flag_1.to_bits(), (x % y).to_bits());
+            }
+        }
+        unsafe {
+            let comparison = _mm256_fmod_ps(_mm256_set1_ps(f32::INFINITY), _mm256_set1_ps(2.));
+            assert!(_mm256_cvtss_f32(comparison).is_nan());
+            let comparison = _mm256_fmod_ps(_mm256_set1_ps(2.), _mm256_set1_ps(f32::INFINITY));
+            assert_eq!(_mm256_cvtss_f32(comparison), 2.);
+        }
+    }
 }
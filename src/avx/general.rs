@@ -1,30 +1,23 @@
+use crate::generalf::PAYNE_HANEK_THRESHOLD;
 use crate::shuffle::_mm_shuffle;
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[cfg(not(target_feature = "fma"))]
-#[inline]
-/// Computes `b*c + a` using fma when available
-pub unsafe fn _mm256_prefer_fma_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
-    _mm256_add_pd(_mm256_mul_pd(b, c), a)
-}
-
-#[cfg(target_feature = "fma")]
-#[inline]
-/// Computes `b*c + a` using fma when available
+#[inline(always)]
+/// Computes `b*c + a` using fma
 pub unsafe fn _mm256_prefer_fma_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
     return _mm256_fmadd_pd(b, c, a);
 }
 
-#[inline]
+#[inline(always)]
 /// Computes `a*b + c`
 pub unsafe fn _mm256_mlaf_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
     _mm256_prefer_fma_pd(c, b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Modulus operator for f64
 pub unsafe fn _mm256_abs_pd(f: __m256d) -> __m256d {
     _mm256_castsi256_pd(_mm256_andnot_si256(
@@ -33,20 +26,20 @@ pub unsafe fn _mm256_abs_pd(f: __m256d) -> __m256d {
     ))
 }
 
-#[inline]
+#[inline(always)]
 /// Negates value
 pub unsafe fn _mm256_neg_pd(f: __m256d) -> __m256d {
     _mm256_sub_pd(_mm256_set1_pd(0.), f)
 }
 
-#[inline]
+#[inline(always)]
 /// Rounds and takes integral part 64 bytes from double
 pub unsafe fn _mm256_rint_pd(f: __m256d) -> __m256i {
     let k = _mm256_round_pd::<0x00>(f);
     _mm256_cvtpd_epi64x(k)
 }
 
-#[inline]
+#[inline(always)]
 // Converts double to integers 64
 pub unsafe fn _mm256_cvtpd_epu64x(v: __m256d) -> __m256i {
     let k_k513ff = _mm256_set1_epi64x(51 + 0x3FF);
@@ -92,7 +85,7 @@ pub unsafe fn _mm256_cvtpd_epu64x(v: __m256d) -> __m256i {
     return fully_bounded;
 }
 
-#[inline]
+#[inline(always)]
 // Converts double to integers 64
 pub unsafe fn _mm256_cvtpd_epi64x(v: __m256d) -> __m256i {
     let k_513ff = _mm256_set1_epi64x(51 + 0x3FF);
@@ -144,19 +137,31 @@ pub unsafe fn _mm256_cvtpd_epi64x(v: __m256d) -> __m256i {
     bounded
 }
 
-#[inline]
+#[inline(always)]
+/// Returns flag value is zero
+pub unsafe fn _mm256_eqzero_pd(d: __m256d) -> __m256d {
+    _mm256_cmp_pd::<_CMP_EQ_OS>(d, _mm256_set1_pd(0.))
+}
+
+#[inline(always)]
+/// Returns flag value is lower than zero
+pub unsafe fn _mm256_ltzero_pd(d: __m256d) -> __m256d {
+    _mm256_cmp_pd::<_CMP_LT_OS>(d, _mm256_set1_pd(0.))
+}
+
+#[inline(always)]
 /// Returns true flag if value is NaN
 pub unsafe fn _mm256_isnan_pd(d: __m256d) -> __m256d {
-    _mm256_cmp_pd::<_CMP_NEQ_OS>(d, d)
+    _mm256_cmp_pd::<_CMP_UNORD_Q>(d, d)
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is Infinity
 pub unsafe fn _mm256_isinf_pd(d: __m256d) -> __m256d {
     _mm256_cmp_pd::<_CMP_EQ_OS>(_mm256_abs_pd(d), _mm256_set1_pd(f64::INFINITY))
 }
 
-#[inline]
+#[inline(always)]
 // Converts integers 64 to double
 pub unsafe fn _mm256_cvtepi64_pdx(v: __m256i) -> __m256d {
     let magic_i_lo = _mm256_set1_epi64x(0x4330000000000000); // 2^52               encoded as floating-point
@@ -171,19 +176,19 @@ pub unsafe fn _mm256_cvtepi64_pdx(v: __m256i) -> __m256d {
     _mm256_add_pd(v_hi_dbl, _mm256_castsi256_pd(v_lo)) // (v_hi - magic_d_all) + v_lo  Do not assume associativity of floating point addition !!
 }
 
-#[inline]
+#[inline(always)]
 /// If mask then `true_vals` otherwise `false_val`
 pub unsafe fn _mm256_select_pd(mask: __m256d, true_vals: __m256d, false_vals: __m256d) -> __m256d {
     _mm256_blendv_pd(false_vals, true_vals, mask)
 }
 
-#[inline]
+#[inline(always)]
 /// If mask then `true_vals` otherwise `false_val`
 pub unsafe fn _mm256_selecti_pd(mask: __m256i, true_vals: __m256d, false_vals: __m256d) -> __m256d {
     _mm256_blendv_pd(false_vals, true_vals, _mm256_castsi256_pd(mask))
 }
 
-#[inline]
+#[inline(always)]
 /// Extracts f64 value
 pub unsafe fn _mm256_extract_pd<const IMM: i32>(d: __m256d) -> f64 {
     #[cfg(target_arch = "x86_64")]
@@ -211,7 +216,7 @@ pub unsafe fn _mm256_extract_pd<const IMM: i32>(d: __m256d) -> f64 {
     }
 }
 
-#[inline]
+#[inline(always)]
 /// Founds n in x=a+𝑛ln(2), |a| <= 1
 pub unsafe fn _mm256_ilogb2k_pd(d: __m256d) -> __m256i {
     _mm256_sub_epi64(
@@ -223,7 +228,7 @@ pub unsafe fn _mm256_ilogb2k_pd(d: __m256d) -> __m256i {
     )
 }
 
-#[inline]
+#[inline(always)]
 /// Founds a in x=a+𝑛ln(2), |a| <= 1
 pub unsafe fn _mm256_ldexp3k_pd(x: __m256d, n: __m256i) -> __m256d {
     _mm256_castsi256_pd(_mm256_add_epi64(
@@ -232,14 +237,26 @@ pub unsafe fn _mm256_ldexp3k_pd(x: __m256d, n: __m256i) -> __m256d {
     ))
 }
 
-#[inline]
+#[inline(always)]
+/// Computes `x*2^n` with two scalings, so `n` may reach subnormal and near overflow exponents
+pub unsafe fn _mm256_ldexp2k_pd(x: __m256d, n: __m256i) -> __m256d {
+    // Shifting 32 bit halves is exact while 64 bit lanes hold values in i32 range
+    let m = _mm256_srai_epi32::<1>(n);
+    let u = _mm256_mul_pd(x, _mm256_castsi256_pd(_mm256_pow2i_epi64(m)));
+    _mm256_mul_pd(
+        u,
+        _mm256_castsi256_pd(_mm256_pow2i_epi64(_mm256_sub_epi64(n, m))),
+    )
+}
+
+#[inline(always)]
 /// Computes 2^n in f64 form for signed 64 bits integers, returns f64 in bits
 pub unsafe fn _mm256_pow2i_epi64(n: __m256i) -> __m256i {
     let j = _mm256_slli_epi64::<52>(_mm256_add_epi64(n, _mm256_set1_epi32(0x3ff)));
     j
 }
 
-#[inline]
+#[inline(always)]
 /// Copies sign from `y` to `x`
 pub unsafe fn _mm256_copysign_pd(x: __m256d, y: __m256d) -> __m256d {
     _mm256_castsi256_pd(_mm256_xor_si256(
@@ -254,14 +271,117 @@ pub unsafe fn _mm256_copysign_pd(x: __m256d, y: __m256d) -> __m256d {
     ))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is Neg Infinity
 pub unsafe fn _mm256_isneginf_pd(d: __m256d) -> __m256d {
     _mm256_cmp_pd::<_CMP_EQ_OS>(d, _mm256_set1_pd(f64::NEG_INFINITY))
 }
 
-#[inline]
+#[inline(always)]
+/// Checks if arguments is integral value
+pub unsafe fn _mm256_isintegral_pd(d: __m256d) -> __m256d {
+    _mm256_cmp_pd::<_CMP_EQ_OS>(d, _mm256_floor_pd(d))
+}
+
+#[inline(always)]
 /// Checks if arguments is not integral value
 pub unsafe fn _mm256_isnotintegral_pd(d: __m256d) -> __m256d {
     _mm256_cmp_pd::<_CMP_NEQ_OS>(d, _mm256_floor_pd(d))
 }
+
+#[inline(always)]
+/// Replaces Cody-Waite reduction with Payne-Hanek `reduce` in lanes above threshold
+pub(crate) unsafe fn _mm256_rempi_pd(
+    d: __m256d,
+    q: __m256i,
+    r: __m256d,
+    reduce: fn(f64) -> (i32, f64),
+) -> (__m256i, __m256d) {
+    if _mm256_movemask_pd(_mm256_cmp_pd::<_CMP_GT_OQ>(
+        _mm256_abs_pd(d),
+        _mm256_set1_pd(PAYNE_HANEK_THRESHOLD),
+    )) == 0
+    {
+        return (q, r);
+    }
+    let mut ds = [0f64; 4];
+    let mut qs = [0i64; 4];
+    let mut rs = [0f64; 4];
+    _mm256_storeu_pd(ds.as_mut_ptr(), d);
+    _mm256_storeu_si256(qs.as_mut_ptr() as *mut __m256i, q);
+    _mm256_storeu_pd(rs.as_mut_ptr(), r);
+    for ((&d, q), r) in ds.iter().zip(qs.iter_mut()).zip(rs.iter_mut()) {
+        if d.abs() > PAYNE_HANEK_THRESHOLD && d.is_finite() {
+            let (lq, lr) = reduce(d);
+            *q = lq as i64;
+            *r = lr;
+        }
+    }
+    (
+        _mm256_loadu_si256(qs.as_ptr() as *const __m256i),
+        _mm256_loadu_pd(rs.as_ptr()),
+    )
+}
+
+#[inline(always)]
+/// Computes a * b as double-float, see `multiply_as_doubled`
+pub(crate) unsafe fn _mm256_multiply_as_doubled_pd(a: __m256d, b: __m256d) -> (__m256d, __m256d) {
+    let xh = _mm256_and_pd(
+        a,
+        _mm256_castsi256_pd(_mm256_set1_epi64x(0xffff_ffff_f800_0000u64 as i64)),
+    );
+    let xl = _mm256_sub_pd(a, xh);
+    let yh = _mm256_and_pd(
+        b,
+        _mm256_castsi256_pd(_mm256_set1_epi64x(0xffff_ffff_f800_0000u64 as i64)),
+    );
+    let yl = _mm256_sub_pd(b, yh);
+    let r0 = _mm256_mul_pd(a, b);
+    let mut d1 = _mm256_sub_pd(_mm256_mul_pd(xh, yh), r0);
+    d1 = _mm256_mlaf_pd(xl, yh, d1);
+    d1 = _mm256_mlaf_pd(xh, yl, d1);
+    (r0, _mm256_mlaf_pd(xl, yl, d1))
+}
+
+#[inline(always)]
+/// Multiplies two double-floats, see `mul_doubled`
+pub(crate) unsafe fn _mm256_mul_doubled_pd(
+    a: (__m256d, __m256d),
+    b: (__m256d, __m256d),
+) -> (__m256d, __m256d) {
+    let m = _mm256_multiply_as_doubled_pd(a.0, b.0);
+    (
+        m.0,
+        _mm256_add_pd(m.1, _mm256_mlaf_pd(a.0, b.1, _mm256_mul_pd(a.1, b.0))),
+    )
+}
+
+#[inline(always)]
+/// Computes a + b as double-float, see `sum_as_doubled`
+pub(crate) unsafe fn _mm256_sum_as_doubled_pd(a: __m256d, b: __m256d) -> (__m256d, __m256d) {
+    let s = _mm256_add_pd(a, b);
+    let v = _mm256_sub_pd(s, a);
+    (
+        s,
+        _mm256_add_pd(_mm256_sub_pd(a, _mm256_sub_pd(s, v)), _mm256_sub_pd(b, v)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isnan_pd() {
+        unsafe {
+            let value = _mm256_setr_pd(f64::NAN, 1., f64::INFINITY, -f64::NAN);
+            let mask = _mm256_movemask_pd(_mm256_isnan_pd(value));
+            assert_eq!(mask, 0b1001);
+        }
+        unsafe {
+            let value = _mm256_setr_ps(f32::NAN, 1., 0., f32::INFINITY, 2., 3., -f32::NAN, 4.);
+            let mask = _mm256_movemask_ps(crate::_mm256_isnan_ps(value));
+            assert_eq!(mask, 0b0100_0001);
+        }
+    }
+}
@@ -5,64 +5,57 @@
  * // license that can be found in the LICENSE file.
  */
 
+use crate::generalf::PAYNE_HANEK_THRESHOLD_F;
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
+#[inline(always)]
 /// Rounds and takes integral part from float
 pub unsafe fn _mm256_rint_ps(f: __m256) -> __m256i {
     let k = _mm256_round_ps::<0x00>(f);
     _mm256_cvtps_epi32(k)
 }
 
-#[cfg(not(target_feature = "fma"))]
-#[inline]
-/// Computes `b*c + a` using fma when available
-pub unsafe fn _mm256_prefer_fma_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
-    _mm256_add_ps(_mm256_mul_ps(b, c), a)
-}
-
-#[cfg(target_feature = "fma")]
-#[inline]
-/// Computes `b*c + a` using fma when available
+#[inline(always)]
+/// Computes `b*c + a` using fma
 pub unsafe fn _mm256_prefer_fma_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
     return _mm256_fmadd_ps(b, c, a);
 }
 
-#[inline]
+#[inline(always)]
 /// Computes `a*b + c`
 pub unsafe fn _mm256_mlaf_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
     _mm256_prefer_fma_ps(c, b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Computes 2^n in f32 form for signed 32 bits integers, returns f32 in bits
 pub unsafe fn _mm256_pow2if_epi32(n: __m256i) -> __m256i {
     let j = _mm256_slli_epi32::<23>(_mm256_add_epi32(n, _mm256_set1_epi32(0x7f)));
     j
 }
 
-#[inline]
+#[inline(always)]
 /// If mask then `true_vals` otherwise `false_val`
 pub unsafe fn _mm256_select_ps(mask: __m256, true_vals: __m256, false_vals: __m256) -> __m256 {
     _mm256_blendv_ps(false_vals, true_vals, mask)
 }
 
-#[inline]
+#[inline(always)]
 /// If mask then `true_vals` otherwise `false_val`
 pub unsafe fn _mm256_extract_ps<const IMM8: i32>(x: __m256) -> f32 {
     f32::from_bits(_mm256_extract_epi32::<IMM8>(_mm256_castps_si256(x)) as u32)
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is zero
 pub unsafe fn _mm256_eqzero_ps(d: __m256) -> __m256 {
     _mm256_cmp_ps::<_CMP_EQ_OS>(d, _mm256_set1_ps(0.))
 }
 
-#[inline]
+#[inline(always)]
 /// Founds n in x=a+𝑛ln(2), |a| <= 1
 pub unsafe fn _mm256_ilogb2kq_ps(d: __m256) -> __m256i {
     _mm256_sub_epi32(
@@ -74,7 +67,7 @@ pub unsafe fn _mm256_ilogb2kq_ps(d: __m256) -> __m256i {
     )
 }
 
-#[inline]
+#[inline(always)]
 /// Founds a in x=a+𝑛ln(2), |a| <= 1
 pub unsafe fn _mm256_ldexp3kq_ps(x: __m256, n: __m256i) -> __m256 {
     _mm256_castsi256_ps(_mm256_add_epi32(
@@ -83,7 +76,18 @@ pub unsafe fn _mm256_ldexp3kq_ps(x: __m256, n: __m256i) -> __m256 {
     ))
 }
 
-#[inline]
+#[inline(always)]
+/// Computes `x*2^n` with two scalings, so `n` may reach subnormal and near overflow exponents
+pub unsafe fn _mm256_ldexp2kq_ps(x: __m256, n: __m256i) -> __m256 {
+    let m = _mm256_srai_epi32::<1>(n);
+    let u = _mm256_mul_ps(x, _mm256_castsi256_ps(_mm256_pow2if_epi32(m)));
+    _mm256_mul_ps(
+        u,
+        _mm256_castsi256_ps(_mm256_pow2if_epi32(_mm256_sub_epi32(n, m))),
+    )
+}
+
+#[inline(always)]
 /// Modulus operator for f32
 pub unsafe fn _mm256_abs_ps(f: __m256) -> __m256 {
     _mm256_castsi256_ps(_mm256_andnot_si256(
@@ -92,37 +96,37 @@ pub unsafe fn _mm256_abs_ps(f: __m256) -> __m256 {
     ))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is Infinity
 pub unsafe fn _mm256_isinf_ps(d: __m256) -> __m256 {
     _mm256_cmp_ps::<_CMP_EQ_OS>(_mm256_abs_ps(d), _mm256_set1_ps(f32::INFINITY))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns true flag if value is NaN
 pub unsafe fn _mm256_isnan_ps(d: __m256) -> __m256 {
-    _mm256_cmp_ps::<_CMP_NEQ_OS>(d, d)
+    _mm256_cmp_ps::<_CMP_UNORD_Q>(d, d)
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is lower than zero
 pub unsafe fn _mm256_ltzero_ps(d: __m256) -> __m256 {
     _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_set1_ps(0.))
 }
 
-#[inline]
+#[inline(always)]
 /// If mask then `true_vals` otherwise `false_val`
 pub unsafe fn _mm256_selecti_ps(mask: __m256i, true_vals: __m256, false_vals: __m256) -> __m256 {
     _mm256_blendv_ps(false_vals, true_vals, _mm256_castsi256_ps(mask))
 }
 
-#[inline]
+#[inline(always)]
 /// Negates value
 pub unsafe fn _mm256_neg_ps(f: __m256) -> __m256 {
     _mm256_sub_ps(_mm256_set1_ps(0.), f)
 }
 
-#[inline]
+#[inline(always)]
 /// Copies sign from `y` to `x`
 pub unsafe fn _mm256_copysign_ps(x: __m256, y: __m256) -> __m256 {
     _mm256_castsi256_ps(_mm256_xor_si256(
@@ -137,14 +141,96 @@ pub unsafe fn _mm256_copysign_ps(x: __m256, y: __m256) -> __m256 {
     ))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is Neg Infinity
 pub unsafe fn _mm256_isneginf_ps(d: __m256) -> __m256 {
     _mm256_cmp_ps::<_CMP_EQ_OS>(d, _mm256_set1_ps(f32::NEG_INFINITY))
 }
 
-#[inline]
+#[inline(always)]
+/// Checks if arguments is integral value
+pub unsafe fn _mm256_isintegral_ps(d: __m256) -> __m256 {
+    _mm256_cmp_ps::<_CMP_EQ_OS>(d, _mm256_floor_ps(d))
+}
+
+#[inline(always)]
 /// Checks if arguments is not integral value
 pub unsafe fn _mm256_isnotintegral_ps(d: __m256) -> __m256 {
     _mm256_cmp_ps::<_CMP_NEQ_OS>(d, _mm256_floor_ps(d))
 }
+
+#[inline(always)]
+/// Replaces Cody-Waite reduction with Payne-Hanek `reduce` in lanes above threshold
+pub(crate) unsafe fn _mm256_rempi_ps(
+    d: __m256,
+    q: __m256i,
+    r: __m256,
+    reduce: fn(f32) -> (i32, f32),
+) -> (__m256i, __m256) {
+    if _mm256_movemask_ps(_mm256_cmp_ps::<_CMP_GT_OQ>(
+        _mm256_abs_ps(d),
+        _mm256_set1_ps(PAYNE_HANEK_THRESHOLD_F),
+    )) == 0
+    {
+        return (q, r);
+    }
+    let mut ds = [0f32; 8];
+    let mut qs = [0i32; 8];
+    let mut rs = [0f32; 8];
+    _mm256_storeu_ps(ds.as_mut_ptr(), d);
+    _mm256_storeu_si256(qs.as_mut_ptr() as *mut __m256i, q);
+    _mm256_storeu_ps(rs.as_mut_ptr(), r);
+    for ((&d, q), r) in ds.iter().zip(qs.iter_mut()).zip(rs.iter_mut()) {
+        if d.abs() > PAYNE_HANEK_THRESHOLD_F && d.is_finite() {
+            (*q, *r) = reduce(d);
+        }
+    }
+    (
+        _mm256_loadu_si256(qs.as_ptr() as *const __m256i),
+        _mm256_loadu_ps(rs.as_ptr()),
+    )
+}
+
+#[inline(always)]
+/// Computes a * b as double-float, see `multiply_as_doubled`
+pub(crate) unsafe fn _mm256_multiply_as_doubled_ps(a: __m256, b: __m256) -> (__m256, __m256) {
+    let xh = _mm256_and_ps(
+        a,
+        _mm256_castsi256_ps(_mm256_set1_epi32(0xffff_f000u32 as i32)),
+    );
+    let xl = _mm256_sub_ps(a, xh);
+    let yh = _mm256_and_ps(
+        b,
+        _mm256_castsi256_ps(_mm256_set1_epi32(0xffff_f000u32 as i32)),
+    );
+    let yl = _mm256_sub_ps(b, yh);
+    let r0 = _mm256_mul_ps(a, b);
+    let mut d1 = _mm256_sub_ps(_mm256_mul_ps(xh, yh), r0);
+    d1 = _mm256_mlaf_ps(xl, yh, d1);
+    d1 = _mm256_mlaf_ps(xh, yl, d1);
+    (r0, _mm256_mlaf_ps(xl, yl, d1))
+}
+
+#[inline(always)]
+/// Multiplies two double-floats, see `mul_doubled`
+pub(crate) unsafe fn _mm256_mul_doubled_ps(
+    a: (__m256, __m256),
+    b: (__m256, __m256),
+) -> (__m256, __m256) {
+    let m = _mm256_multiply_as_doubled_ps(a.0, b.0);
+    (
+        m.0,
+        _mm256_add_ps(m.1, _mm256_mlaf_ps(a.0, b.1, _mm256_mul_ps(a.1, b.0))),
+    )
+}
+
+#[inline(always)]
+/// Computes a + b as double-float, see `sum_as_doubled`
+pub(crate) unsafe fn _mm256_sum_as_doubled_ps(a: __m256, b: __m256) -> (__m256, __m256) {
+    let s = _mm256_add_ps(a, b);
+    let v = _mm256_sub_ps(s, a);
+    (
+        s,
+        _mm256_add_ps(_mm256_sub_ps(a, _mm256_sub_ps(s, v)), _mm256_sub_ps(b, v)),
+    )
+}
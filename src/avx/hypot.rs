@@ -12,7 +12,7 @@ use std::arch::x86_64::*;
 
 use crate::{_mm256_abs_pd, _mm256_isinf_pd, _mm256_isnan_pd, _mm256_mlaf_pd, _mm256_select_pd};
 
-#[inline]
+#[inline(always)]
 /// Method that computes 2D Euclidian distance *ULP 0.6667*
 pub unsafe fn _mm256_hypot_pd(x: __m256d, y: __m256d) -> __m256d {
     let x = _mm256_abs_pd(x);
@@ -27,15 +27,15 @@ pub unsafe fn _mm256_hypot_pd(x: __m256d, y: __m256d) -> __m256d {
     let is_any_infinite = _mm256_or_pd(_mm256_isinf_pd(x), _mm256_isinf_pd(y));
     let mut is_any_nan = _mm256_or_pd(_mm256_isnan_pd(x), _mm256_isnan_pd(y));
     let is_min_zero = _mm256_cmp_pd::<_CMP_EQ_OS>(min, _mm256_setzero_pd());
+    ret = _mm256_select_pd(is_min_zero, max, ret);
     is_any_nan = _mm256_or_pd(_mm256_isnan_pd(ret), is_any_nan);
     ret = _mm256_select_pd(is_any_nan, _mm256_set1_pd(f64::NAN), ret);
     ret = _mm256_select_pd(is_any_infinite, _mm256_set1_pd(f64::INFINITY), ret);
-    ret = _mm256_select_pd(is_min_zero, _mm256_setzero_pd(), ret);
     ret
 }
 
 /// Method that computes 2D Euclidian distance *ULP 0.6667*, skipping Inf, Nan checks
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_hypot_fast_pd(x: __m256d, y: __m256d) -> __m256d {
     let x = _mm256_abs_pd(x);
     let y = _mm256_abs_pd(y);
@@ -47,7 +47,7 @@ pub unsafe fn _mm256_hypot_fast_pd(x: __m256d, y: __m256d) -> __m256d {
         _mm256_sqrt_pd(_mm256_mlaf_pd(r, r, _mm256_set1_pd(1.))),
         max,
     );
-    ret = _mm256_select_pd(is_min_zero, _mm256_setzero_pd(), ret);
+    ret = _mm256_select_pd(is_min_zero, max, ret);
     ret
 }
 
@@ -67,5 +67,13 @@ mod tests {
             let flag_1 = _mm256_extract_pd::<0>(comparison);
             assert_eq!(flag_1, 5.);
         }
+        unsafe {
+            // Test zero operand
+            let vx = _mm256_set1_pd(-5.);
+            let vy = _mm256_set1_pd(0.);
+            let comparison = _mm256_hypot_pd(vx, vy);
+            let flag_1 = _mm256_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 5.);
+        }
     }
 }
@@ -12,7 +12,7 @@ use std::arch::x86_64::*;
 
 use crate::{_mm256_abs_pd, _mm256_isinf_pd, _mm256_isnan_pd, _mm256_mlaf_pd, _mm256_select_pd};
 
-#[inline]
+#[inline(always)]
 /// Method that computes 3D Euclidian distance *ULP 0.66667*
 pub unsafe fn _mm256_hypot3_pd(x: __m256d, y: __m256d, z: __m256d) -> __m256d {
     let x = _mm256_abs_pd(x);
@@ -47,7 +47,7 @@ pub unsafe fn _mm256_hypot3_pd(x: __m256d, y: __m256d, z: __m256d) -> __m256d {
 }
 
 /// Method that computes 3D Euclidian distance *ULP 0.66667*, skipping Inf, Nan checks
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_hypot3_fast_pd(x: __m256d, y: __m256d, z: __m256d) -> __m256d {
     let x = _mm256_abs_pd(x);
     let y = _mm256_abs_pd(y);
@@ -14,7 +14,7 @@ use crate::{
     _mm256_select_ps,
 };
 
-#[inline]
+#[inline(always)]
 /// Method that computes 2D Euclidian distance *ULP 0.6667*
 pub unsafe fn _mm256_hypot_ps(x: __m256, y: __m256) -> __m256 {
     let x = _mm256_abs_ps(x);
@@ -29,15 +29,15 @@ pub unsafe fn _mm256_hypot_ps(x: __m256, y: __m256) -> __m256 {
     let is_any_infinite = _mm256_or_ps(_mm256_isinf_ps(x), _mm256_isinf_ps(y));
     let mut is_any_nan = _mm256_or_ps(_mm256_isnan_ps(x), _mm256_isnan_ps(y));
     let is_min_zero = _mm256_eqzero_ps(min);
+    ret = _mm256_select_ps(is_min_zero, max, ret);
     is_any_nan = _mm256_or_ps(_mm256_isnan_ps(ret), is_any_nan);
     ret = _mm256_select_ps(is_any_nan, _mm256_set1_ps(f32::NAN), ret);
     ret = _mm256_select_ps(is_any_infinite, _mm256_set1_ps(f32::INFINITY), ret);
-    ret = _mm256_select_ps(is_min_zero, _mm256_setzero_ps(), ret);
     ret
 }
 
 /// Method that computes 2D Euclidian distance *ULP 0.6667*, skipping Inf, Nan checks
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_hypot_fast_ps(x: __m256, y: __m256) -> __m256 {
     let x = _mm256_abs_ps(x);
     let y = _mm256_abs_ps(y);
@@ -49,7 +49,7 @@ pub unsafe fn _mm256_hypot_fast_ps(x: __m256, y: __m256) -> __m256 {
         _mm256_sqrt_ps(_mm256_mlaf_ps(r, r, _mm256_set1_ps(1f32))),
         max,
     );
-    ret = _mm256_select_ps(is_min_zero, _mm256_setzero_ps(), ret);
+    ret = _mm256_select_ps(is_min_zero, max, ret);
     ret
 }
 
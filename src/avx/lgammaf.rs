@@ -0,0 +1,225 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::avx::generalf::{_mm256_copysign_ps, _mm256_mul_doubled_ps};
+use crate::avx::lnf::_mm256_lnk_doubled_ps;
+use crate::avx::sinf::_mm256_sinpik_ps;
+use crate::avx::tgammaf::_mm256_stirlingk_ps;
+use crate::digammaf::{DIGAMMA_ROOT_LO_S, DIGAMMA_ROOT_M1_LO_S, DIGAMMA_ROOT_M1_S, DIGAMMA_ROOT_S};
+use crate::lgammaf::{
+    LGAMMA_A_POLY_1_S, LGAMMA_A_POLY_2_S, LGAMMA_A_POLY_3_S, LGAMMA_A_POLY_4_S, LGAMMA_A_POLY_5_S,
+    LGAMMA_A_POLY_6_S, LGAMMA_A_POLY_7_S, LGAMMA_A_POLY_8_S, LGAMMA_A_POLY_9_S, LGAMMA_B_POLY_1_S,
+    LGAMMA_B_POLY_2_S, LGAMMA_B_POLY_3_S, LGAMMA_B_POLY_4_S, LGAMMA_B_POLY_5_S, LGAMMA_B_POLY_6_S,
+    LGAMMA_B_POLY_7_S, LGAMMA_B_POLY_8_S, LGAMMA_B_POLY_9_S, LGAMMA_H_POLY_1_S, LGAMMA_H_POLY_2_S,
+    LGAMMA_H_POLY_3_S, LGAMMA_H_POLY_4_S, LGAMMA_H_POLY_5_S, LGAMMA_H_POLY_6_S, LGAMMA_H_POLY_7_S,
+    LGAMMA_MIN_LO_S, LGAMMA_MIN_S, LN_PI_S, LN_SQRT_2PI_S,
+};
+use crate::{
+    _mm256_abs_ps, _mm256_isnotintegral_ps, _mm256_ln_ps, _mm256_mlaf_ps, _mm256_neg_ps,
+    _mm256_select_ps,
+};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Computes ln|Γ(x)| and the sign of Γ(x), error bound *ULP 2.5* for positive arguments
+#[inline(always)]
+pub unsafe fn _mm256_lgamma_ps(d: __m256) -> (__m256, __m256) {
+    let x = _mm256_abs_ps(d);
+    // lnΓ(x) = (x - 0.5) ln(x) - x + ln(sqrt(2pi)) + 1/x S(1/x^2) for x >= 5
+    let e = _mm256_stirlingk_ps((x, _mm256_setzero_ps()));
+    let mut big = _mm256_add_ps(e.0, _mm256_add_ps(e.1, _mm256_set1_ps(LN_SQRT_2PI_S)));
+    // Everything past the leading terms of Stirling series is below an ulp
+    big = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_GT_OS>(x, _mm256_set1_ps(1073741824f32)),
+        _mm256_mul_ps(x, _mm256_sub_ps(_mm256_ln_ps(x), _mm256_set1_ps(1f32))),
+        big,
+    );
+    // lnΓ(x) = lnΓ(2 + z) + ln((x - 1)...(x - n + 2)), z = x - n in [-0.5, 0.5] for x >= 1.75
+    let mid = _mm256_cmp_ps::<_CMP_GE_OS>(x, _mm256_set1_ps(1.75f32));
+    let n = _mm256_round_ps::<_MM_FROUND_TO_NEAREST_INT>(x);
+    // Below 0.75 lnΓ(x) = lnΓ(x + 1) - ln(x), z is taken against shifted centers so x + 1 is never rounded
+    let shift = _mm256_cmp_ps::<_CMP_LT_OS>(x, _mm256_set1_ps(0.75f32));
+    let near_zero = _mm256_or_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(x, _mm256_set1_ps(0.25f32)),
+        _mm256_and_ps(
+            _mm256_cmp_ps::<_CMP_GE_OS>(x, _mm256_set1_ps(0.75f32)),
+            _mm256_cmp_ps::<_CMP_LT_OS>(x, _mm256_set1_ps(1.25f32)),
+        ),
+    );
+    let z = _mm256_select_ps(
+        mid,
+        _mm256_sub_ps(x, n),
+        _mm256_select_ps(shift, x, _mm256_sub_ps(x, _mm256_set1_ps(1f32))),
+    );
+    let pick = |a: f32, b: f32| _mm256_select_ps(mid, _mm256_set1_ps(b), _mm256_set1_ps(a));
+    let mut u = pick(LGAMMA_A_POLY_9_S, LGAMMA_B_POLY_9_S);
+    u = _mm256_mlaf_ps(u, z, pick(LGAMMA_A_POLY_8_S, LGAMMA_B_POLY_8_S));
+    u = _mm256_mlaf_ps(u, z, pick(LGAMMA_A_POLY_7_S, LGAMMA_B_POLY_7_S));
+    u = _mm256_mlaf_ps(u, z, pick(LGAMMA_A_POLY_6_S, LGAMMA_B_POLY_6_S));
+    u = _mm256_mlaf_ps(u, z, pick(LGAMMA_A_POLY_5_S, LGAMMA_B_POLY_5_S));
+    u = _mm256_mlaf_ps(u, z, pick(LGAMMA_A_POLY_4_S, LGAMMA_B_POLY_4_S));
+    u = _mm256_mlaf_ps(u, z, pick(LGAMMA_A_POLY_3_S, LGAMMA_B_POLY_3_S));
+    u = _mm256_mlaf_ps(u, z, pick(LGAMMA_A_POLY_2_S, LGAMMA_B_POLY_2_S));
+    u = _mm256_mlaf_ps(u, z, pick(LGAMMA_A_POLY_1_S, LGAMMA_B_POLY_1_S));
+    let r = _mm256_mul_ps(z, u);
+    // Around the minimum lnΓ(x0 + z) = lnΓ(x0) + z^2 H(z)
+    let zh = _mm256_select_ps(
+        shift,
+        _mm256_sub_ps(
+            _mm256_sub_ps(x, _mm256_set1_ps(DIGAMMA_ROOT_M1_S)),
+            _mm256_set1_ps(DIGAMMA_ROOT_M1_LO_S),
+        ),
+        _mm256_sub_ps(
+            _mm256_sub_ps(x, _mm256_set1_ps(DIGAMMA_ROOT_S)),
+            _mm256_set1_ps(DIGAMMA_ROOT_LO_S),
+        ),
+    );
+    let mut h = _mm256_set1_ps(LGAMMA_H_POLY_7_S);
+    h = _mm256_mlaf_ps(h, zh, _mm256_set1_ps(LGAMMA_H_POLY_6_S));
+    h = _mm256_mlaf_ps(h, zh, _mm256_set1_ps(LGAMMA_H_POLY_5_S));
+    h = _mm256_mlaf_ps(h, zh, _mm256_set1_ps(LGAMMA_H_POLY_4_S));
+    h = _mm256_mlaf_ps(h, zh, _mm256_set1_ps(LGAMMA_H_POLY_3_S));
+    h = _mm256_mlaf_ps(h, zh, _mm256_set1_ps(LGAMMA_H_POLY_2_S));
+    h = _mm256_mlaf_ps(h, zh, _mm256_set1_ps(LGAMMA_H_POLY_1_S));
+    let m = _mm256_add_ps(
+        _mm256_set1_ps(LGAMMA_MIN_S),
+        _mm256_mlaf_ps(_mm256_mul_ps(zh, zh), h, _mm256_set1_ps(LGAMMA_MIN_LO_S)),
+    );
+    let r = _mm256_select_ps(_mm256_or_ps(mid, near_zero), r, m);
+    // The recurrence product, x itself when shifted and 1 elsewhere
+    let mut q = (
+        _mm256_select_ps(shift, x, _mm256_set1_ps(1f32)),
+        _mm256_setzero_ps(),
+    );
+    let k_max = _mm256_sub_ps(n, _mm256_set1_ps(2f32));
+    for k in 1..4 {
+        let kf = _mm256_set1_ps(k as f32);
+        let f = _mm256_select_ps(
+            _mm256_cmp_ps::<_CMP_LE_OS>(kf, k_max),
+            _mm256_sub_ps(x, kf),
+            _mm256_set1_ps(1f32),
+        );
+        q = _mm256_mul_doubled_ps(q, (f, _mm256_setzero_ps()));
+    }
+    let l = _mm256_lnk_doubled_ps(q);
+    let small = _mm256_select_ps(
+        shift,
+        _mm256_sub_ps(_mm256_sub_ps(r, l.0), l.1),
+        _mm256_add_ps(l.0, _mm256_add_ps(r, l.1)),
+    );
+    let k = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_GE_OS>(x, _mm256_set1_ps(5f32)),
+        big,
+        small,
+    );
+    // |Γ(d)| = pi / (|sin(pi d)| |d| Γ(|d|)) for negative d
+    let sp = _mm256_sinpik_ps(d);
+    let reflect = _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_setzero_ps());
+    let mut ret = _mm256_select_ps(
+        reflect,
+        _mm256_sub_ps(
+            _mm256_sub_ps(
+                _mm256_set1_ps(LN_PI_S),
+                _mm256_ln_ps(_mm256_abs_ps(_mm256_mul_ps(sp, d))),
+            ),
+            k,
+        ),
+        k,
+    );
+    let mut sign = _mm256_select_ps(
+        _mm256_and_ps(
+            reflect,
+            _mm256_cmp_ps::<_CMP_LT_OS>(sp, _mm256_setzero_ps()),
+        ),
+        _mm256_set1_ps(-1f32),
+        _mm256_set1_ps(1f32),
+    );
+    // lnΓ(d) = -ln|d| - γd + O(d^2)
+    let tiny = _mm256_cmp_ps::<_CMP_LT_OS>(x, _mm256_set1_ps(2.9802322e-8f32));
+    ret = _mm256_select_ps(tiny, _mm256_neg_ps(_mm256_ln_ps(x)), ret);
+    sign = _mm256_select_ps(tiny, _mm256_copysign_ps(_mm256_set1_ps(1f32), d), sign);
+    // Poles, this also covers every d <= -2^23 and d == -Inf
+    let pole = _mm256_andnot_ps(_mm256_isnotintegral_ps(d), reflect);
+    ret = _mm256_select_ps(pole, _mm256_set1_ps(f32::INFINITY), ret);
+    sign = _mm256_select_ps(pole, _mm256_set1_ps(1f32), sign);
+    (ret, sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lgammaf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.5f32);
+            let (comparison, sign) = _mm256_lgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 0.5723649f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+            assert_eq!(_mm256_cvtss_f32(sign), 1.);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-0.5f32);
+            let (comparison, sign) = _mm256_lgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1.2655121f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+            assert_eq!(_mm256_cvtss_f32(sign), -1.);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-3.5f32);
+            let (comparison, sign) = _mm256_lgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = -1.3090067f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+            assert_eq!(_mm256_cvtss_f32(sign), 1.);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(100f32);
+            let (comparison, sign) = _mm256_lgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 359.13422f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+            assert_eq!(_mm256_cvtss_f32(sign), 1.);
+        }
+
+        unsafe {
+            // Test exact value
+            let value = _mm256_set1_ps(1f32);
+            let (comparison, sign) = _mm256_lgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, 0.);
+            assert_eq!(_mm256_cvtss_f32(sign), 1.);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_ps(-2f32);
+            let (comparison, _) = _mm256_lgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        unsafe {
+            // Test NaN
+            let value = _mm256_set1_ps(f32::NAN);
+            let (comparison, _) = _mm256_lgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -11,20 +11,28 @@ use std::arch::x86::*;
 use std::arch::x86_64::*;
 
 use crate::avx::general::{_mm256_ilogb2k_pd, _mm256_ldexp3k_pd};
+use crate::avx::general::{
+    _mm256_mul_doubled_pd, _mm256_multiply_as_doubled_pd, _mm256_sum_as_doubled_pd,
+};
+use crate::exp::{L2_L, L2_U};
+use crate::ln::{
+    LN_DOUBLED_POLY_1_D, LN_DOUBLED_POLY_2_D, LN_DOUBLED_POLY_3_D, LN_DOUBLED_POLY_4_D,
+    LN_DOUBLED_POLY_5_D, LN_DOUBLED_POLY_6_D, LN_DOUBLED_POLY_7_D, LN_DOUBLED_POLY_8_D,
+    TWO_THIRDS_D, TWO_THIRDS_LO_D,
+};
 use crate::ln::{
     LN_POLY_1_D, LN_POLY_2_D, LN_POLY_3_D, LN_POLY_4_D, LN_POLY_5_D, LN_POLY_6_D, LN_POLY_7_D,
     LN_POLY_8_D,
 };
+use crate::log2::LOG_SCALE_D;
 use crate::{
     _mm256_cvtepi64_pdx, _mm256_isinf_pd, _mm256_isnan_pd, _mm256_mlaf_pd, _mm256_neg_epi64,
     _mm256_select_pd,
 };
 
-/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
-#[inline]
-pub unsafe fn _mm256_ln_fast_pd(d: __m256d) -> __m256d {
-    let n = _mm256_ilogb2k_pd(_mm256_mul_pd(d, _mm256_set1_pd(1. / 0.75)));
-    let a = _mm256_ldexp3k_pd(d, _mm256_neg_epi64(n));
+/// Computes ln(a) for reduced argument a in [0.75, 1.5)
+#[inline(always)]
+pub(crate) unsafe fn _mm256_lnk_pd(a: __m256d) -> __m256d {
     let ones = _mm256_set1_pd(1.);
     let x = _mm256_div_pd(_mm256_sub_pd(a, ones), _mm256_add_pd(a, ones));
     let x2 = _mm256_mul_pd(x, x);
@@ -35,19 +43,40 @@ pub unsafe fn _mm256_ln_fast_pd(d: __m256d) -> __m256d {
     u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_POLY_4_D));
     u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_POLY_3_D));
     u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_POLY_2_D));
-    u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_POLY_1_D));
+    // Leading 2x term is exact, keeping it apart saves a rounding
+    _mm256_mlaf_pd(
+        _mm256_mul_pd(x, x2),
+        u,
+        _mm256_mul_pd(x, _mm256_set1_pd(LN_POLY_1_D)),
+    )
+}
+
+/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm256_ln_fast_pd(d: __m256d) -> __m256d {
+    let n = _mm256_ilogb2k_pd(_mm256_mul_pd(d, _mm256_set1_pd(1. / 0.75)));
+    let a = _mm256_ldexp3k_pd(d, _mm256_neg_epi64(n));
     let res = _mm256_mlaf_pd(
         _mm256_set1_pd(std::f64::consts::LN_2),
         _mm256_cvtepi64_pdx(n),
-        _mm256_mul_pd(x, u),
+        _mm256_lnk_pd(a),
     );
     res
 }
 
 /// Computes natural logarithm for an argument *ULP 1.5*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_ln_pd(d: __m256d) -> __m256d {
-    let mut res = _mm256_ln_fast_pd(d);
+    // Subnormals are scaled to normal range before the exponent split
+    let o = _mm256_cmp_pd::<_CMP_LT_OS>(d, _mm256_set1_pd(f64::MIN_POSITIVE));
+    let x = _mm256_select_pd(o, _mm256_mul_pd(d, _mm256_set1_pd(LOG_SCALE_D)), d);
+    let n = _mm256_ilogb2k_pd(_mm256_mul_pd(x, _mm256_set1_pd(1. / 0.75)));
+    let a = _mm256_ldexp3k_pd(x, _mm256_neg_epi64(n));
+    let e = _mm256_sub_pd(
+        _mm256_cvtepi64_pdx(n),
+        _mm256_select_pd(o, _mm256_set1_pd(64f64), _mm256_setzero_pd()),
+    );
+    let mut res = _mm256_mlaf_pd(_mm256_set1_pd(std::f64::consts::LN_2), e, _mm256_lnk_pd(a));
     // d == 0 || d == Inf -> Inf
     res = _mm256_select_pd(
         _mm256_cmp_pd::<_CMP_EQ_OS>(d, _mm256_setzero_pd()),
@@ -67,6 +96,61 @@ pub unsafe fn _mm256_ln_pd(d: __m256d) -> __m256d {
     res
 }
 
+/// Computes ln(d.0 + d.1) as double-float for positive normal d
+#[inline(always)]
+pub(crate) unsafe fn _mm256_lnk_doubled_pd(d: (__m256d, __m256d)) -> (__m256d, __m256d) {
+    let n = _mm256_ilogb2k_pd(_mm256_mul_pd(d.0, _mm256_set1_pd(1f64 / 0.75f64)));
+    let a = _mm256_ldexp3k_pd(d.0, _mm256_neg_epi64(n));
+    // x = (a - 1) / (a + 1) split into hi and lo, a - 1 is exact
+    let num = _mm256_sub_pd(a, _mm256_set1_pd(1f64));
+    let den = _mm256_sum_as_doubled_pd(a, _mm256_set1_pd(1f64));
+    let x = _mm256_div_pd(num, den.0);
+    let m = _mm256_multiply_as_doubled_pd(x, den.0);
+    let x_lo = _mm256_div_pd(
+        _mm256_sub_pd(
+            _mm256_sub_pd(_mm256_sub_pd(num, m.0), m.1),
+            _mm256_mul_pd(x, den.1),
+        ),
+        den.0,
+    );
+    // ln(a) = 2x + 2x^3/3 + x^5 R(x^2), the cubic term is too large to be rounded once
+    let x3 = _mm256_mul_doubled_pd(_mm256_multiply_as_doubled_pd(x, x), (x, x_lo));
+    let t = _mm256_mul_doubled_pd(
+        x3,
+        (
+            _mm256_set1_pd(TWO_THIRDS_D),
+            _mm256_set1_pd(TWO_THIRDS_LO_D),
+        ),
+    );
+    let x2 = _mm256_mul_pd(x, x);
+    let mut u = _mm256_set1_pd(LN_DOUBLED_POLY_8_D);
+    u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_DOUBLED_POLY_7_D));
+    u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_DOUBLED_POLY_6_D));
+    u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_DOUBLED_POLY_5_D));
+    u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_DOUBLED_POLY_4_D));
+    u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_DOUBLED_POLY_3_D));
+    u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_DOUBLED_POLY_2_D));
+    u = _mm256_mlaf_pd(u, x2, _mm256_set1_pd(LN_DOUBLED_POLY_1_D));
+    let nf = _mm256_cvtepi64_pdx(n);
+    let b = _mm256_sum_as_doubled_pd(_mm256_add_pd(x, x), t.0);
+    let h = _mm256_sum_as_doubled_pd(_mm256_mul_pd(nf, _mm256_set1_pd(L2_U)), b.0);
+    let tail = _mm256_mlaf_pd(
+        _mm256_mul_pd(_mm256_mul_pd(x2, x2), x),
+        u,
+        _mm256_mlaf_pd(nf, _mm256_set1_pd(L2_L), _mm256_div_pd(d.1, d.0)),
+    );
+    (
+        h.0,
+        _mm256_add_pd(
+            h.1,
+            _mm256_add_pd(
+                _mm256_add_pd(_mm256_add_pd(b.1, t.1), _mm256_add_pd(x_lo, x_lo)),
+                tail,
+            ),
+        ),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::avx::general::_mm256_extract_pd;
@@ -0,0 +1,114 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::ln::_mm256_lnk_pd;
+use crate::log2::LOG_SCALE_D;
+use crate::{
+    _mm256_cvtepi64_pdx, _mm256_ilogb2k_pd, _mm256_isinf_pd, _mm256_isnan_pd, _mm256_ldexp3k_pd,
+    _mm256_mlaf_pd, _mm256_neg_epi64, _mm256_select_pd,
+};
+
+/// Computes log10 for an argument *ULP 3.5*
+#[inline(always)]
+pub unsafe fn _mm256_log10_pd(d: __m256d) -> __m256d {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = _mm256_cmp_pd::<_CMP_LT_OS>(d, _mm256_set1_pd(f64::MIN_POSITIVE));
+    let x = _mm256_select_pd(o, _mm256_mul_pd(d, _mm256_set1_pd(LOG_SCALE_D)), d);
+    let n = _mm256_ilogb2k_pd(_mm256_mul_pd(x, _mm256_set1_pd(1f64 / 0.75f64)));
+    let a = _mm256_ldexp3k_pd(x, _mm256_neg_epi64(n));
+    let e = _mm256_sub_pd(
+        _mm256_cvtepi64_pdx(n),
+        _mm256_select_pd(o, _mm256_set1_pd(64f64), _mm256_setzero_pd()),
+    );
+    let mut res = _mm256_mlaf_pd(
+        _mm256_lnk_pd(a),
+        _mm256_set1_pd(std::f64::consts::LOG10_E),
+        _mm256_mul_pd(e, _mm256_set1_pd(std::f64::consts::LOG10_2)),
+    );
+    // d == 0 -> -Inf
+    res = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(d, _mm256_setzero_pd()),
+        _mm256_set1_pd(f64::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = _mm256_select_pd(_mm256_isinf_pd(d), _mm256_set1_pd(f64::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = _mm256_select_pd(
+        _mm256_or_pd(
+            _mm256_cmp_pd::<_CMP_LT_OS>(d, _mm256_setzero_pd()),
+            _mm256_isnan_pd(d),
+        ),
+        _mm256_set1_pd(f64::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log10d() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(1000f64);
+            let comparison = _mm256_log10_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 3.;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.2f64);
+            let comparison = _mm256_log10_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 0.2f64.log10();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test subnormal
+            let value = _mm256_set1_pd(f64::from_bits(1));
+            let comparison = _mm256_log10_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = f64::from_bits(1).log10();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_pd(0f64);
+            let comparison = _mm256_log10_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm256_set1_pd(-1f64);
+            let comparison = _mm256_log10_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm256_set1_pd(f64::INFINITY);
+            let comparison = _mm256_log10_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::lnf::_mm256_lnk_ps;
+use crate::{
+    _mm256_ilogb2kq_ps, _mm256_isinf_ps, _mm256_isnan_ps, _mm256_ldexp3kq_ps, _mm256_mlaf_ps,
+    _mm256_neg_epi32, _mm256_select_ps,
+};
+
+/// Computes ln(1 + x), accurate for x near zero *ULP 4.5*
+#[inline(always)]
+pub unsafe fn _mm256_log1p_ps(d: __m256) -> __m256 {
+    let dp1 = _mm256_add_ps(d, _mm256_set1_ps(1f32));
+    let n = _mm256_ilogb2kq_ps(_mm256_mul_ps(dp1, _mm256_set1_ps(1f32 / 0.75f32)));
+    let a = _mm256_ldexp3kq_ps(dp1, _mm256_neg_epi32(n));
+    // c is the rounding error of 1 + d relative to it
+    let c = _mm256_div_ps(
+        _mm256_sub_ps(d, _mm256_sub_ps(dp1, _mm256_set1_ps(1f32))),
+        dp1,
+    );
+    let mut res = _mm256_mlaf_ps(
+        _mm256_set1_ps(std::f32::consts::LN_2),
+        _mm256_cvtepi32_ps(n),
+        _mm256_add_ps(_mm256_lnk_ps(a), c),
+    );
+    // d == 0 -> d, keeps the sign of zero
+    res = _mm256_select_ps(_mm256_cmp_ps::<_CMP_EQ_OQ>(d, _mm256_setzero_ps()), d, res);
+    // d == -1 -> -Inf
+    res = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(d, _mm256_set1_ps(-1f32)),
+        _mm256_set1_ps(f32::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = _mm256_select_ps(_mm256_isinf_ps(d), _mm256_set1_ps(f32::INFINITY), res);
+    // d < -1 || d == Nan -> Nan
+    res = _mm256_select_ps(
+        _mm256_or_ps(
+            _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_set1_ps(-1f32)),
+            _mm256_isnan_ps(d),
+        ),
+        _mm256_set1_ps(f32::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log1pf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(1e-6f32);
+            let comparison = _mm256_log1p_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1e-6f32.ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.5f32);
+            let comparison = _mm256_log1p_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 0.5f32.ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-0.3f32);
+            let comparison = _mm256_log1p_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = (-0.3f32).ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_ps(-1f32);
+            let comparison = _mm256_log1p_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm256_set1_ps(-2f32);
+            let comparison = _mm256_log1p_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm256_set1_ps(f32::INFINITY);
+            let comparison = _mm256_log1p_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
@@ -7,19 +7,45 @@
 
 mod acos;
 mod acosf;
+mod acosh;
+mod acoshf;
 mod asin;
 mod asinf;
+mod asinh;
+mod asinhf;
 mod atan;
 mod atan2;
 mod atan2f;
 mod atanf;
+mod atanh;
+mod atanhf;
+mod cbrt;
 mod cbrtf;
 mod cos;
 mod cosf;
+mod cosh;
+mod coshf;
+mod digamma;
+mod digammaf;
+mod divider;
 mod epi32;
 mod epi64;
+mod erf;
+mod erfc;
+mod erfcf;
+mod erff;
+mod erfinv;
+mod erfinvf;
 mod exp;
+mod exp10;
+mod exp10f;
+mod exp2;
+mod exp2f;
 mod expf;
+mod expm1;
+mod expm1f;
+mod float128;
+mod float128_math;
 mod fmod;
 mod fmodf;
 mod general;
@@ -30,27 +56,64 @@ mod hypot3f;
 mod hypot4;
 mod hypot4f;
 mod hypotf;
+mod lgamma;
+mod lgammaf;
 mod ln;
 mod lnf;
+mod log10;
+mod log10f;
+mod log1p;
+mod log1pf;
+mod log2;
+mod log2f;
+mod modular;
+mod poly128;
 mod pow;
 mod powf;
+mod precision;
 mod saturation_epi32;
+mod saturation_epi64;
 mod sin;
+mod sincos;
+mod sincosf;
 mod sinf;
+mod sinh;
+mod sinhf;
 mod tan;
 mod tanf;
+mod tanh;
+mod tanhf;
+mod tgamma;
+mod tgammaf;
 
 pub use acos::_mm256_acos_pd;
 pub use acosf::_mm256_acos_ps;
+pub use acosh::_mm256_acosh_pd;
+pub use acoshf::_mm256_acosh_ps;
+pub use asin::_mm256_asin_pd;
 pub use asinf::_mm256_asin_ps;
+pub use asinh::_mm256_asinh_pd;
+pub use asinhf::_mm256_asinh_ps;
 pub use atan::_mm256_atan_pd;
 pub use atan2::_mm256_atan2_pd;
 pub use atan2f::_mm256_atan2_ps;
 pub use atanf::_mm256_atan_ps;
+pub use atanh::_mm256_atanh_pd;
+pub use atanhf::_mm256_atanh_ps;
+pub use cbrt::_mm256_cbrt_fast_pd;
+pub use cbrt::_mm256_cbrt_pd;
 pub use cbrtf::_mm256_cbrt_fast_ps;
 pub use cbrtf::_mm256_cbrt_ps;
 pub use cos::_mm256_cos_pd;
 pub use cosf::_mm256_cos_ps;
+pub use cosh::_mm256_cosh_pd;
+pub use coshf::_mm256_cosh_ps;
+pub use digamma::_mm256_digamma_pd;
+pub use digammaf::_mm256_digamma_ps;
+pub use divider::_mm256_div_epi32;
+pub use divider::_mm256_div_epi64;
+pub use divider::_mm256_div_epu32;
+pub use divider::_mm256_div_epu64;
 pub use epi32::_mm256_blendv_epi32;
 pub use epi32::_mm256_cmpge_epu16;
 pub use epi32::_mm256_cmpge_epu32;
@@ -61,7 +124,10 @@ pub use epi32::_mm256_cmple_epu32;
 pub use epi32::_mm256_cmplt_epu16;
 pub use epi32::_mm256_cmplt_epu32;
 pub use epi32::_mm256_neg_epi32;
+pub use epi64::_mm256_cmpgt_epu64;
+pub use epi64::_mm256_cmplt_epu64;
 pub use epi64::_mm256_cvtepi64_epi32x;
+pub use epi64::_mm256_extract_epi64x;
 pub use epi64::_mm256_max_epi64x;
 pub use epi64::_mm256_min_epi64x;
 pub use epi64::_mm256_mul_epi64;
@@ -71,22 +137,59 @@ pub use epi64::_mm256_packs_epi64;
 pub use epi64::_mm256_packts_epi64;
 pub use epi64::_mm256_packus_epi64;
 pub use epi64::_mm256_srai_epi64x;
+pub use erf::_mm256_erf_pd;
+pub use erfc::_mm256_erfc_pd;
+pub use erfcf::_mm256_erfc_ps;
+pub use erff::_mm256_erf_ps;
+pub use erfinv::_mm256_erfinv_pd;
+pub use erfinvf::_mm256_erfinv_ps;
 pub use exp::_mm256_exp_pd;
 pub use exp::_mm256_expq_fast_pd;
+pub use exp10::_mm256_exp10_pd;
+pub use exp10f::_mm256_exp10_ps;
+pub use exp2::_mm256_exp2_pd;
+pub use exp2f::_mm256_exp2_ps;
 pub use expf::_mm256_exp_ps;
 pub use expf::_mm256_expq_fast_ps;
+pub use expm1::_mm256_expm1_pd;
+pub use expm1f::_mm256_expm1_ps;
+pub use float128::__m256dx2;
+pub use float128::_mm256_add_f128;
+pub use float128::_mm256_addl_pd;
+pub use float128::_mm256_addw_pd;
+pub use float128::_mm256_cvtf128_pd;
+pub use float128::_mm256_cvtpd_f128;
+pub use float128::_mm256_div_f128;
+pub use float128::_mm256_extract_f128;
+pub use float128::_mm256_mlaf_f128;
+pub use float128::_mm256_mul_f128;
+pub use float128::_mm256_mull_pd;
+pub use float128::_mm256_neg_f128;
+pub use float128::_mm256_set1_f128;
+pub use float128_math::_mm256_cos_f128;
+pub use float128_math::_mm256_exp_f128;
+pub use float128_math::_mm256_ln_f128;
+pub use float128_math::_mm256_pow_f128;
+pub use float128_math::_mm256_sin_f128;
+pub use float128_math::_mm256_sqrt_f128;
 pub use fmod::_mm256_fmod_pd;
 pub use fmodf::_mm256_fmod_ps;
 pub use general::_mm256_abs_pd;
 pub use general::_mm256_copysign_pd;
 pub use general::_mm256_cvtepi64_pdx;
+pub use general::_mm256_cvtpd_epi64x;
 pub use general::_mm256_cvtpd_epu64x;
+pub use general::_mm256_eqzero_pd;
 pub use general::_mm256_extract_pd;
 pub use general::_mm256_ilogb2k_pd;
 pub use general::_mm256_isinf_pd;
+pub use general::_mm256_isintegral_pd;
 pub use general::_mm256_isnan_pd;
 pub use general::_mm256_isneginf_pd;
+pub use general::_mm256_isnotintegral_pd;
+pub use general::_mm256_ldexp2k_pd;
 pub use general::_mm256_ldexp3k_pd;
+pub use general::_mm256_ltzero_pd;
 pub use general::_mm256_mlaf_pd;
 pub use general::_mm256_neg_pd;
 pub use general::_mm256_pow2i_epi64;
@@ -95,13 +198,16 @@ pub use general::_mm256_rint_pd;
 pub use general::_mm256_select_pd;
 pub use general::_mm256_selecti_pd;
 pub use generalf::_mm256_abs_ps;
+pub use generalf::_mm256_copysign_ps;
 pub use generalf::_mm256_eqzero_ps;
 pub use generalf::_mm256_extract_ps;
 pub use generalf::_mm256_ilogb2kq_ps;
 pub use generalf::_mm256_isinf_ps;
+pub use generalf::_mm256_isintegral_ps;
 pub use generalf::_mm256_isnan_ps;
 pub use generalf::_mm256_isneginf_ps;
 pub use generalf::_mm256_isnotintegral_ps;
+pub use generalf::_mm256_ldexp2kq_ps;
 pub use generalf::_mm256_ldexp3kq_ps;
 pub use generalf::_mm256_ltzero_ps;
 pub use generalf::_mm256_mlaf_ps;
@@ -123,18 +229,88 @@ pub use hypot4f::_mm256_hypot4_fast_ps;
 pub use hypot4f::_mm256_hypot4_ps;
 pub use hypotf::_mm256_hypot_fast_ps;
 pub use hypotf::_mm256_hypot_ps;
+pub use lgamma::_mm256_lgamma_pd;
+pub use lgammaf::_mm256_lgamma_ps;
 pub use ln::_mm256_ln_fast_pd;
 pub use ln::_mm256_ln_pd;
 pub use lnf::_mm256_ln_fast_ps;
 pub use lnf::_mm256_ln_ps;
+pub use log10::_mm256_log10_pd;
+pub use log10f::_mm256_log10_ps;
+pub use log1p::_mm256_log1p_pd;
+pub use log1pf::_mm256_log1p_ps;
+pub use log2::_mm256_log2_pd;
+pub use log2f::_mm256_log2_ps;
+pub use modular::_mm256_addmod_epu32;
+pub use modular::_mm256_addmod_epu64;
+pub use modular::_mm256_barrett_reduce_epu32;
+pub use modular::_mm256_barrett_reduce_epu64;
+pub use modular::_mm256_from_montgomery_epu32;
+pub use modular::_mm256_from_montgomery_epu64;
+pub use modular::_mm256_intt_butterfly_epu32;
+pub use modular::_mm256_intt_butterfly_epu64;
+pub use modular::_mm256_montgomery_reduce_epu32;
+pub use modular::_mm256_montgomery_reduce_epu64;
+pub use modular::_mm256_montmul_epu32;
+pub use modular::_mm256_montmul_epu64;
+pub use modular::_mm256_mulmod_epu32;
+pub use modular::_mm256_mulmod_epu64;
+pub use modular::_mm256_ntt_butterfly_epu32;
+pub use modular::_mm256_ntt_butterfly_epu64;
+pub use modular::_mm256_submod_epu32;
+pub use modular::_mm256_submod_epu64;
+pub use modular::_mm256_to_montgomery_epu32;
+pub use modular::_mm256_to_montgomery_epu64;
+pub use poly128::__m256x2i;
+pub use poly128::_mm256_abs_epi128;
+pub use poly128::_mm256_add_epi128;
+pub use poly128::_mm256_add_epu128;
+pub use poly128::_mm256_addw_epi128;
+pub use poly128::_mm256_combine_epi128;
+pub use poly128::_mm256_extract_epi128;
+pub use poly128::_mm256_extract_epu128;
+pub use poly128::_mm256_extract_hi_epi128;
+pub use poly128::_mm256_extract_lo_epi128;
+pub use poly128::_mm256_loadu_epi128;
+pub use poly128::_mm256_movn_epi128;
+pub use poly128::_mm256_mull_epi64;
+pub use poly128::_mm256_mull_epu64;
+pub use poly128::_mm256_set1_epi128;
+pub use poly128::_mm256_set_epi128;
+pub use poly128::_mm256_slli_epi128x;
+pub use poly128::_mm256_srli_epi128x;
+pub use poly128::_mm256_storeu_epi128;
+pub use poly128::_mm256_sub_epi128;
+pub use poly128::_mm256_subw_epi128;
 pub use pow::_mm256_pow_fast_pd;
 pub use pow::_mm256_pow_pd;
 pub use powf::_mm256_pow_fast_ps;
 pub use powf::_mm256_pow_ps;
 pub use saturation_epi32::_mm256_adds_epi32;
 pub use saturation_epi32::_mm256_adds_epu32;
+pub use saturation_epi32::_mm256_muls_epi32;
+pub use saturation_epi32::_mm256_muls_epu32;
+pub use saturation_epi32::_mm256_shls_epi32;
+pub use saturation_epi32::_mm256_shls_epu32;
 pub use saturation_epi32::_mm256_subs_epi32;
+pub use saturation_epi32::_mm256_subs_epu32;
+pub use saturation_epi64::_mm256_adds_epi64;
+pub use saturation_epi64::_mm256_adds_epu64;
+pub use saturation_epi64::_mm256_muls_epi64;
+pub use saturation_epi64::_mm256_muls_epu64;
+pub use saturation_epi64::_mm256_shls_epi64;
+pub use saturation_epi64::_mm256_shls_epu64;
+pub use saturation_epi64::_mm256_subs_epi64;
+pub use saturation_epi64::_mm256_subs_epu64;
 pub use sin::_mm256_sin_pd;
+pub use sincos::_mm256_sincos_pd;
+pub use sincosf::_mm256_sincos_ps;
 pub use sinf::_mm256_sin_ps;
+pub use sinh::_mm256_sinh_pd;
+pub use sinhf::_mm256_sinh_ps;
 pub use tan::_mm256_tan_pd;
 pub use tanf::_mm256_tan_ps;
+pub use tanh::_mm256_tanh_pd;
+pub use tanhf::_mm256_tanh_ps;
+pub use tgamma::_mm256_tgamma_pd;
+pub use tgammaf::_mm256_tgamma_ps;
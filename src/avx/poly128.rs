@@ -0,0 +1,352 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::epi64::_mm256_select_epi64;
+use crate::{_mm256_cmplt_epu64, _mm256_extract_epi64x, _mm256_neg_epi64};
+
+/// Type represents u128, in low u64 and high u64 part
+/// Lower parts, and upper parts are interleaved
+/// Operations are the same as SSE `__m128x2i` and NEON `u128x2_t` ones
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct __m256x2i(pub(crate) __m256i, pub(crate) __m256i);
+
+#[inline(always)]
+unsafe fn _mm256_sign_mask_epi64(a: __m256i) -> __m256i {
+    _mm256_cmpgt_epi64(_mm256_setzero_si256(), a)
+}
+
+#[inline(always)]
+/// Widening multiplication u64 in u128
+pub unsafe fn _mm256_mull_epu64(a: __m256i, b: __m256i) -> __m256x2i {
+    let erase_high = _mm256_set1_epi64x(0xFFFFFFFF);
+    let xh = _mm256_srli_epi64::<32>(a);
+    let yh = _mm256_srli_epi64::<32>(b);
+
+    let hh = _mm256_mul_epu32(xh, yh);
+    let lh = _mm256_mul_epu32(a, yh);
+    let hl = _mm256_mul_epu32(xh, b);
+    let ll = _mm256_mul_epu32(a, b);
+
+    let mid = _mm256_add_epi64(
+        _mm256_add_epi64(
+            _mm256_srli_epi64::<32>(ll),
+            _mm256_and_si256(lh, erase_high),
+        ),
+        _mm256_and_si256(hl, erase_high),
+    );
+    let lo = _mm256_or_si256(
+        _mm256_and_si256(ll, erase_high),
+        _mm256_slli_epi64::<32>(mid),
+    );
+    let hi = _mm256_add_epi64(
+        _mm256_add_epi64(hh, _mm256_srli_epi64::<32>(mid)),
+        _mm256_add_epi64(_mm256_srli_epi64::<32>(lh), _mm256_srli_epi64::<32>(hl)),
+    );
+    __m256x2i(lo, hi)
+}
+
+#[inline(always)]
+/// Widening multiplication i64 in i128
+pub unsafe fn _mm256_mull_epi64(a: __m256i, b: __m256i) -> __m256x2i {
+    let product = _mm256_mull_epu64(a, b);
+    let correction = _mm256_add_epi64(
+        _mm256_and_si256(_mm256_sign_mask_epi64(a), b),
+        _mm256_and_si256(_mm256_sign_mask_epi64(b), a),
+    );
+    __m256x2i(product.0, _mm256_sub_epi64(product.1, correction))
+}
+
+#[inline(always)]
+/// Shifts right u128 immediate
+pub unsafe fn _mm256_srli_epi128x<const IMM: i32>(a: __m256x2i) -> __m256x2i {
+    if IMM <= 0 {
+        a
+    } else if IMM < 64 {
+        let upper_shift = _mm256_set1_epi64x(64i64 - IMM as i64);
+        let new_lower = _mm256_or_si256(
+            _mm256_sllv_epi64(a.1, upper_shift),
+            _mm256_srli_epi64::<IMM>(a.0),
+        );
+        let new_upper = _mm256_srli_epi64::<IMM>(a.1);
+        __m256x2i(new_lower, new_upper)
+    } else {
+        let v_imm = _mm256_set1_epi64x(IMM as i64 - 64i64);
+        __m256x2i(_mm256_srlv_epi64(a.1, v_imm), _mm256_setzero_si256())
+    }
+}
+
+#[inline(always)]
+/// Shifts left u128 immediate
+pub unsafe fn _mm256_slli_epi128x<const IMM: i32>(a: __m256x2i) -> __m256x2i {
+    if IMM <= 0 {
+        a
+    } else if IMM < 64 {
+        let lower_shift = _mm256_set1_epi64x(64i64 - IMM as i64);
+        let new_upper = _mm256_or_si256(
+            _mm256_srlv_epi64(a.0, lower_shift),
+            _mm256_slli_epi64::<IMM>(a.1),
+        );
+        let new_lower = _mm256_slli_epi64::<IMM>(a.0);
+        __m256x2i(new_lower, new_upper)
+    } else {
+        let v_imm = _mm256_set1_epi64x(IMM as i64 - 64i64);
+        __m256x2i(_mm256_setzero_si256(), _mm256_sllv_epi64(a.0, v_imm))
+    }
+}
+
+#[inline(always)]
+/// Adds s128 to s128 using unsigned addition
+pub unsafe fn _mm256_add_epu128(a: __m256x2i, b: __m256x2i) -> __m256x2i {
+    let lo = _mm256_add_epi64(a.0, b.0);
+    let carry = _mm256_cmplt_epu64(lo, a.0);
+    __m256x2i(lo, _mm256_sub_epi64(_mm256_add_epi64(a.1, b.1), carry))
+}
+
+#[inline(always)]
+/// Adds s128 to s128 using signed addition
+pub unsafe fn _mm256_add_epi128(a: __m256x2i, b: __m256x2i) -> __m256x2i {
+    _mm256_add_epu128(a, b)
+}
+
+#[inline(always)]
+/// Subtracts s128 from s128
+pub unsafe fn _mm256_sub_epi128(a: __m256x2i, b: __m256x2i) -> __m256x2i {
+    let lo = _mm256_sub_epi64(a.0, b.0);
+    let borrow = _mm256_cmplt_epu64(a.0, b.0);
+    __m256x2i(lo, _mm256_add_epi64(_mm256_sub_epi64(a.1, b.1), borrow))
+}
+
+#[inline(always)]
+/// Widening add 64 bytes integer to 128 bytes integer
+pub unsafe fn _mm256_addw_epi128(a: __m256x2i, b: __m256i) -> __m256x2i {
+    let lo = _mm256_add_epi64(a.0, b);
+    let carry = _mm256_cmplt_epu64(lo, a.0);
+    let b_hi = _mm256_sign_mask_epi64(b);
+    __m256x2i(lo, _mm256_sub_epi64(_mm256_add_epi64(a.1, b_hi), carry))
+}
+
+#[inline(always)]
+/// Widening substract 64 bytes integer to 128 bytes integer
+pub unsafe fn _mm256_subw_epi128(a: __m256x2i, b: __m256i) -> __m256x2i {
+    let lo = _mm256_sub_epi64(a.0, b);
+    let borrow = _mm256_cmplt_epu64(a.0, b);
+    let b_hi = _mm256_sign_mask_epi64(b);
+    __m256x2i(lo, _mm256_add_epi64(_mm256_sub_epi64(a.1, b_hi), borrow))
+}
+
+#[inline(always)]
+/// Narrows 128-bit integers into 64 bits, keeps only lower half
+pub unsafe fn _mm256_movn_epi128(a: __m256x2i) -> __m256i {
+    a.0
+}
+
+#[inline(always)]
+/// Takes absolute value for i128
+pub unsafe fn _mm256_abs_epi128(a: __m256x2i) -> __m256x2i {
+    let is_neg = _mm256_sign_mask_epi64(a.1);
+    let lo_zero = _mm256_cmpeq_epi64(a.0, _mm256_setzero_si256());
+    let neg_hi = _mm256_sub_epi64(_mm256_xor_si256(a.1, _mm256_set1_epi64x(-1)), lo_zero);
+    __m256x2i(
+        _mm256_select_epi64(is_neg, _mm256_neg_epi64(a.0), a.0),
+        _mm256_select_epi64(is_neg, neg_hi, a.1),
+    )
+}
+
+/// Computes i128 as u64 and extracts lower half in general register
+#[inline(always)]
+pub unsafe fn _mm256_extract_lo_epi128<const IMM: i32>(d: __m256x2i) -> i64 {
+    _mm256_extract_epi64x::<IMM>(d.0)
+}
+
+/// Computes i128 as u64 and extracts upper half in general register
+#[inline(always)]
+pub unsafe fn _mm256_extract_hi_epi128<const IMM: i32>(d: __m256x2i) -> i64 {
+    _mm256_extract_epi64x::<IMM>(d.1)
+}
+
+/// Computes u128 as u128 and extracts in general register
+#[inline(always)]
+pub unsafe fn _mm256_extract_epu128<const IMM: i32>(d: __m256x2i) -> u128 {
+    let lo = (_mm256_extract_epi64x::<IMM>(d.0) as u64) as u128;
+    let hi = (_mm256_extract_epi64x::<IMM>(d.1) as u64) as u128;
+    lo | (hi << 64)
+}
+
+/// Computes i128 as i128 and extracts in general register
+#[inline(always)]
+pub unsafe fn _mm256_extract_epi128<const IMM: i32>(d: __m256x2i) -> i128 {
+    _mm256_extract_epu128::<IMM>(d) as i128
+}
+
+/// Builds 128-bit integers from low and high 64-bit halves
+#[inline(always)]
+pub unsafe fn _mm256_combine_epi128(lo: __m256i, hi: __m256i) -> __m256x2i {
+    __m256x2i(lo, hi)
+}
+
+/// Sets 128-bit integers, `e0` goes to the first lane
+#[inline(always)]
+pub unsafe fn _mm256_set_epi128(e3: i128, e2: i128, e1: i128, e0: i128) -> __m256x2i {
+    __m256x2i(
+        _mm256_set_epi64x(e3 as i64, e2 as i64, e1 as i64, e0 as i64),
+        _mm256_set_epi64x(
+            (e3 >> 64) as i64,
+            (e2 >> 64) as i64,
+            (e1 >> 64) as i64,
+            (e0 >> 64) as i64,
+        ),
+    )
+}
+
+/// Broadcasts 128-bit integer to all lanes
+#[inline(always)]
+pub unsafe fn _mm256_set1_epi128(a: i128) -> __m256x2i {
+    _mm256_set_epi128(a, a, a, a)
+}
+
+/// Loads four 128-bit integers from unaligned memory
+#[inline(always)]
+pub unsafe fn _mm256_loadu_epi128(mem_addr: *const i128) -> __m256x2i {
+    let v0 = _mm256_loadu_si256(mem_addr as *const __m256i);
+    let v1 = _mm256_loadu_si256((mem_addr as *const __m256i).add(1));
+    __m256x2i(
+        _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_unpacklo_epi64(v0, v1)),
+        _mm256_permute4x64_epi64::<0b11_01_10_00>(_mm256_unpackhi_epi64(v0, v1)),
+    )
+}
+
+/// Stores four 128-bit integers into unaligned memory
+#[inline(always)]
+pub unsafe fn _mm256_storeu_epi128(mem_addr: *mut i128, a: __m256x2i) {
+    let lo = _mm256_permute4x64_epi64::<0b11_01_10_00>(a.0);
+    let hi = _mm256_permute4x64_epi64::<0b11_01_10_00>(a.1);
+    _mm256_storeu_si256(mem_addr as *mut __m256i, _mm256_unpacklo_epi64(lo, hi));
+    _mm256_storeu_si256(
+        (mem_addr as *mut __m256i).add(1),
+        _mm256_unpackhi_epi64(lo, hi),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALUES: [u64; 9] = [
+        0,
+        1,
+        27,
+        u64::MAX,
+        1 << 63,
+        0x0123_4567_89ab_cdef,
+        (i64::MIN + 1) as u64,
+        0xffff_0000_ffff_0000,
+        -27i64 as u64,
+    ];
+
+    unsafe fn lanes(d: __m256x2i) -> [u128; 4] {
+        [
+            _mm256_extract_epu128::<0>(d),
+            _mm256_extract_epu128::<1>(d),
+            _mm256_extract_epu128::<2>(d),
+            _mm256_extract_epu128::<3>(d),
+        ]
+    }
+
+    #[test]
+    fn test_against_native() {
+        unsafe {
+            for (k, &a) in VALUES.iter().enumerate() {
+                for &b in VALUES.iter() {
+                    let c = VALUES[(k + 3) % VALUES.len()];
+                    let l = [a, b, c, a ^ b];
+                    let r = [b, c, a, b.wrapping_mul(3)];
+                    let va = _mm256_setr_epi64x(l[0] as i64, l[1] as i64, l[2] as i64, l[3] as i64);
+                    let vb = _mm256_setr_epi64x(r[0] as i64, r[1] as i64, r[2] as i64, r[3] as i64);
+                    let x = __m256x2i(va, vb);
+                    let y = __m256x2i(vb, va);
+
+                    let mu = lanes(_mm256_mull_epu64(va, vb));
+                    let ms = lanes(_mm256_mull_epi64(va, vb));
+                    let add = lanes(_mm256_add_epu128(x, y));
+                    let add_s = lanes(_mm256_add_epi128(x, y));
+                    let sub = lanes(_mm256_sub_epi128(x, y));
+                    let addw = lanes(_mm256_addw_epi128(x, vb));
+                    let subw = lanes(_mm256_subw_epi128(x, vb));
+                    let abs = lanes(_mm256_abs_epi128(x));
+                    let movn = _mm256_movn_epi128(x);
+                    for i in 0..4 {
+                        let xs = l[i] as u128 | (r[i] as u128) << 64;
+                        let ys = r[i] as u128 | (l[i] as u128) << 64;
+                        let w = r[i] as i64 as i128 as u128;
+                        assert_eq!(mu[i], l[i] as u128 * r[i] as u128);
+                        assert_eq!(ms[i], (l[i] as i64 as i128 * r[i] as i64 as i128) as u128);
+                        assert_eq!(add[i], xs.wrapping_add(ys));
+                        assert_eq!(add_s[i], xs.wrapping_add(ys));
+                        assert_eq!(sub[i], xs.wrapping_sub(ys));
+                        assert_eq!(addw[i], xs.wrapping_add(w));
+                        assert_eq!(subw[i], xs.wrapping_sub(w));
+                        assert_eq!(abs[i], (xs as i128).wrapping_abs() as u128);
+                    }
+                    assert_eq!(_mm256_extract_epi64x::<0>(movn), l[0] as i64);
+                    assert_eq!(_mm256_extract_epi64x::<3>(movn), l[3] as i64);
+                    assert_eq!(_mm256_extract_lo_epi128::<2>(x), l[2] as i64);
+                    assert_eq!(_mm256_extract_hi_epi128::<2>(x), r[2] as i64);
+                    assert_eq!(
+                        _mm256_extract_epi128::<1>(x),
+                        (l[1] as u128 | (r[1] as u128) << 64) as i128
+                    );
+
+                    let xs = [0, 1, 2, 3].map(|i| l[i] as u128 | (r[i] as u128) << 64);
+                    macro_rules! check_shifts {
+                        ($($n:literal),*) => {
+                            $(
+                                let v = lanes(_mm256_srli_epi128x::<$n>(x));
+                                assert_eq!(v, xs.map(|x| x >> $n));
+                                let v = lanes(_mm256_slli_epi128x::<$n>(x));
+                                assert_eq!(v, xs.map(|x| x << $n));
+                            )*
+                        };
+                    }
+                    check_shifts!(0, 1, 10, 32, 63, 64, 70, 127);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_store() {
+        unsafe {
+            let values = [
+                0x0123_4567_89ab_cdef_fedc_ba98_7654_3210i128,
+                -27,
+                i128::MIN,
+                i128::MAX,
+            ];
+            let v = _mm256_loadu_epi128(values.as_ptr());
+            assert_eq!(lanes(v), values.map(|x| x as u128));
+            let mut out = [0i128; 4];
+            _mm256_storeu_epi128(out.as_mut_ptr(), v);
+            assert_eq!(out, values);
+
+            let v = _mm256_set_epi128(values[3], values[2], values[1], values[0]);
+            assert_eq!(lanes(v), values.map(|x| x as u128));
+            let v = _mm256_set1_epi128(values[1]);
+            assert_eq!(lanes(v), [values[1] as u128; 4]);
+            let v = _mm256_combine_epi128(
+                _mm256_setr_epi64x(1, 2, 3, 4),
+                _mm256_setr_epi64x(0, -1, 0, 0),
+            );
+            assert_eq!(_mm256_extract_epi128::<1>(v), -(1i128 << 64) + 2);
+            assert_eq!(_mm256_extract_epi128::<3>(v), 4);
+        }
+    }
+}
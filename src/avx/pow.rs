@@ -12,37 +12,47 @@ use std::arch::x86_64::*;
 use crate::avx::general::_mm256_isnotintegral_pd;
 use crate::{
     _mm256_abs_pd, _mm256_copysign_pd, _mm256_exp_pd, _mm256_expq_fast_pd, _mm256_isinf_pd,
-    _mm256_isnan_pd, _mm256_isneginf_pd, _mm256_ln_fast_pd, _mm256_ln_pd, _mm256_select_pd,
+    _mm256_isnan_pd, _mm256_ln_fast_pd, _mm256_ln_pd, _mm256_select_pd,
 };
 
-#[inline]
+#[inline(always)]
 /// Computes pow function *ULP 2.0*
 pub unsafe fn _mm256_pow_pd(d: __m256d, n: __m256d) -> __m256d {
-    let mut c = _mm256_exp_pd(_mm256_mul_pd(n, _mm256_ln_pd(_mm256_abs_pd(d))));
-    c = _mm256_copysign_pd(c, d);
-    let is_infinity = _mm256_or_pd(
-        _mm256_isinf_pd(d),
-        _mm256_or_pd(_mm256_isinf_pd(n), _mm256_isneginf_pd(n)),
-    );
-    let is_power_neg_infinity = _mm256_isneginf_pd(n);
-    // Not integral values do not allowed for negative numbers
-    let inf = _mm256_set1_pd(f64::INFINITY);
-    let is_nan_with_integral = _mm256_and_pd(
-        _mm256_cmp_pd::<_CMP_LT_OS>(d, _mm256_setzero_pd()),
+    let c = _mm256_exp_pd(_mm256_mul_pd(n, _mm256_ln_pd(_mm256_abs_pd(d))));
+    // Sign of the base is kept only for odd integral powers
+    let is_odd = _mm256_andnot_pd(
         _mm256_isnotintegral_pd(n),
+        _mm256_isnotintegral_pd(_mm256_mul_pd(n, _mm256_set1_pd(0.5))),
     );
+    let mut ret = _mm256_select_pd(is_odd, _mm256_copysign_pd(c, d), c);
+    // Not integral powers are not allowed for negative finite numbers
+    let is_neg_finite = _mm256_and_pd(
+        _mm256_cmp_pd::<_CMP_LT_OQ>(d, _mm256_setzero_pd()),
+        _mm256_cmp_pd::<_CMP_GT_OQ>(d, _mm256_set1_pd(f64::NEG_INFINITY)),
+    );
+    let is_nan_with_integral = _mm256_and_pd(is_neg_finite, _mm256_isnotintegral_pd(n));
     let is_any_nan = _mm256_or_pd(
         _mm256_or_pd(_mm256_isnan_pd(d), _mm256_isnan_pd(n)),
         is_nan_with_integral,
     );
-    let mut ret = _mm256_select_pd(is_infinity, inf, c);
-    ret = _mm256_select_pd(is_power_neg_infinity, _mm256_set1_pd(0.), ret);
     ret = _mm256_select_pd(is_any_nan, _mm256_set1_pd(f64::NAN), ret);
-    ret
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN, pow(-1, ±inf) = 1
+    let ones = _mm256_set1_pd(1.);
+    let is_one = _mm256_or_pd(
+        _mm256_or_pd(
+            _mm256_cmp_pd::<_CMP_EQ_OQ>(n, _mm256_setzero_pd()),
+            _mm256_cmp_pd::<_CMP_EQ_OQ>(d, ones),
+        ),
+        _mm256_and_pd(
+            _mm256_cmp_pd::<_CMP_EQ_OQ>(_mm256_abs_pd(d), ones),
+            _mm256_isinf_pd(n),
+        ),
+    );
+    _mm256_select_pd(is_one, ones, ret)
 }
 
 /// Method that computes pow skipping Inf, Nan checks, *ULP 2.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_pow_fast_pd(d: __m256d, n: __m256d) -> __m256d {
     let mut c = _mm256_expq_fast_pd(_mm256_mul_pd(n, _mm256_ln_fast_pd(d)));
     c = _mm256_copysign_pd(c, d);
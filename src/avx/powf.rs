@@ -12,36 +12,47 @@ use std::arch::x86_64::*;
 use crate::avx::generalf::_mm256_copysign_ps;
 use crate::{
     _mm256_abs_ps, _mm256_exp_ps, _mm256_expq_fast_ps, _mm256_isinf_ps, _mm256_isnan_ps,
-    _mm256_isneginf_ps, _mm256_isnotintegral_ps, _mm256_ln_fast_ps, _mm256_ln_ps, _mm256_select_ps,
+    _mm256_isnotintegral_ps, _mm256_ln_fast_ps, _mm256_ln_ps, _mm256_select_ps,
 };
 
-#[inline]
+#[inline(always)]
 /// Computes pow function *ULP 2.0*
 pub unsafe fn _mm256_pow_ps(d: __m256, n: __m256) -> __m256 {
-    let mut c = _mm256_exp_ps(_mm256_mul_ps(n, _mm256_ln_ps(_mm256_abs_ps(d))));
-    c = _mm256_copysign_ps(c, d);
-    let is_infinity = _mm256_or_ps(
-        _mm256_isinf_ps(d),
-        _mm256_or_ps(_mm256_isinf_ps(n), _mm256_isneginf_ps(n)),
-    );
-    let is_power_neg_infinity = _mm256_isneginf_ps(n);
-    let inf = _mm256_set1_ps(f32::INFINITY);
-    let is_nan_with_integral = _mm256_and_ps(
-        _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_setzero_ps()),
+    let c = _mm256_exp_ps(_mm256_mul_ps(n, _mm256_ln_ps(_mm256_abs_ps(d))));
+    // Sign of the base is kept only for odd integral powers
+    let is_odd = _mm256_andnot_ps(
         _mm256_isnotintegral_ps(n),
+        _mm256_isnotintegral_ps(_mm256_mul_ps(n, _mm256_set1_ps(0.5))),
     );
+    let mut ret = _mm256_select_ps(is_odd, _mm256_copysign_ps(c, d), c);
+    // Not integral powers are not allowed for negative finite numbers
+    let is_neg_finite = _mm256_and_ps(
+        _mm256_cmp_ps::<_CMP_LT_OQ>(d, _mm256_setzero_ps()),
+        _mm256_cmp_ps::<_CMP_GT_OQ>(d, _mm256_set1_ps(f32::NEG_INFINITY)),
+    );
+    let is_nan_with_integral = _mm256_and_ps(is_neg_finite, _mm256_isnotintegral_ps(n));
     let is_any_nan = _mm256_or_ps(
         _mm256_or_ps(_mm256_isnan_ps(d), _mm256_isnan_ps(n)),
         is_nan_with_integral,
     );
-    let mut ret = _mm256_select_ps(is_infinity, inf, c);
-    ret = _mm256_select_ps(is_power_neg_infinity, _mm256_set1_ps(0f32), ret);
     ret = _mm256_select_ps(is_any_nan, _mm256_set1_ps(f32::NAN), ret);
-    ret
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN, pow(-1, ±inf) = 1
+    let ones = _mm256_set1_ps(1.);
+    let is_one = _mm256_or_ps(
+        _mm256_or_ps(
+            _mm256_cmp_ps::<_CMP_EQ_OQ>(n, _mm256_setzero_ps()),
+            _mm256_cmp_ps::<_CMP_EQ_OQ>(d, ones),
+        ),
+        _mm256_and_ps(
+            _mm256_cmp_ps::<_CMP_EQ_OQ>(_mm256_abs_ps(d), ones),
+            _mm256_isinf_ps(n),
+        ),
+    );
+    _mm256_select_ps(is_one, ones, ret)
 }
 
 /// Method that computes pow skipping Inf, Nan checks, *ULP 2.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm256_pow_fast_ps(d: __m256, n: __m256) -> __m256 {
     let mut c = _mm256_expq_fast_ps(_mm256_mul_ps(n, _mm256_ln_fast_ps(d)));
     c = _mm256_copysign_ps(c, d);
@@ -0,0 +1,166 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::precision::simd_math_impl;
+
+/// Evaluates f32 lanes with an f64 kernel, so result is rounded only once
+macro_rules! widen_ps {
+    ($f:path, ($x:ident)) => {{
+        let lo = $f(_mm256_cvtps_pd(_mm256_castps256_ps128($x)));
+        let hi = $f(_mm256_cvtps_pd(_mm256_extractf128_ps::<1>($x)));
+        _mm256_insertf128_ps::<1>(
+            _mm256_castps128_ps256(_mm256_cvtpd_ps(lo)),
+            _mm256_cvtpd_ps(hi),
+        )
+    }};
+    ($f:path, ($x:ident, $y:ident)) => {{
+        let lo = $f(
+            _mm256_cvtps_pd(_mm256_castps256_ps128($x)),
+            _mm256_cvtps_pd(_mm256_castps256_ps128($y)),
+        );
+        let hi = $f(
+            _mm256_cvtps_pd(_mm256_extractf128_ps::<1>($x)),
+            _mm256_cvtps_pd(_mm256_extractf128_ps::<1>($y)),
+        );
+        _mm256_insertf128_ps::<1>(
+            _mm256_castps128_ps256(_mm256_cvtpd_ps(lo)),
+            _mm256_cvtpd_ps(hi),
+        )
+    }};
+}
+
+simd_math_impl! {
+    __m256d, f64,
+    exp(x) => crate::_mm256_exp_pd, fast: crate::_mm256_expq_fast_pd;
+    exp2(x) => crate::_mm256_exp2_pd;
+    exp10(x) => crate::_mm256_exp10_pd;
+    expm1(x) => crate::_mm256_expm1_pd;
+    ln(x) => crate::_mm256_ln_pd, fast: crate::_mm256_ln_fast_pd;
+    log2(x) => crate::_mm256_log2_pd;
+    log10(x) => crate::_mm256_log10_pd;
+    log1p(x) => crate::_mm256_log1p_pd;
+    sin(x) => crate::_mm256_sin_pd;
+    cos(x) => crate::_mm256_cos_pd;
+    tan(x) => crate::_mm256_tan_pd;
+    asin(x) => crate::_mm256_asin_pd;
+    acos(x) => crate::_mm256_acos_pd;
+    atan(x) => crate::_mm256_atan_pd;
+    sinh(x) => crate::_mm256_sinh_pd;
+    cosh(x) => crate::_mm256_cosh_pd;
+    tanh(x) => crate::_mm256_tanh_pd;
+    asinh(x) => crate::_mm256_asinh_pd;
+    acosh(x) => crate::_mm256_acosh_pd;
+    atanh(x) => crate::_mm256_atanh_pd;
+    cbrt(x) => crate::_mm256_cbrt_pd, fast: crate::_mm256_cbrt_fast_pd;
+    erf(x) => crate::_mm256_erf_pd;
+    erfc(x) => crate::_mm256_erfc_pd;
+    erfinv(x) => crate::_mm256_erfinv_pd;
+    tgamma(x) => crate::_mm256_tgamma_pd;
+    digamma(x) => crate::_mm256_digamma_pd;
+    pow(x, n) => crate::_mm256_pow_pd, fast: crate::_mm256_pow_fast_pd;
+    atan2(y, x) => crate::_mm256_atan2_pd;
+    hypot(x, y) => crate::_mm256_hypot_pd, fast: crate::_mm256_hypot_fast_pd;
+    fmod(x, y) => crate::_mm256_fmod_pd;
+    hypot3(x, y, z) => crate::_mm256_hypot3_pd, fast: crate::_mm256_hypot3_fast_pd;
+    hypot4(x, y, z, w) => crate::_mm256_hypot4_pd, fast: crate::_mm256_hypot4_fast_pd;
+}
+
+simd_math_impl! {
+    __m256, f32,
+    exp(x) => crate::_mm256_exp_ps, fast: crate::_mm256_expq_fast_ps, high: crate::_mm256_exp_pd, cr: crate::cr::expf;
+    exp2(x) => crate::_mm256_exp2_ps, high: crate::_mm256_exp2_pd, cr: crate::cr::exp2f;
+    exp10(x) => crate::_mm256_exp10_ps, high: crate::_mm256_exp10_pd, cr: crate::cr::exp10f;
+    expm1(x) => crate::_mm256_expm1_ps, high: crate::_mm256_expm1_pd;
+    ln(x) => crate::_mm256_ln_ps, fast: crate::_mm256_ln_fast_ps, high: crate::_mm256_ln_pd, cr: crate::cr::lnf;
+    log2(x) => crate::_mm256_log2_ps, high: crate::_mm256_log2_pd, cr: crate::cr::log2f;
+    log10(x) => crate::_mm256_log10_ps, high: crate::_mm256_log10_pd, cr: crate::cr::log10f;
+    log1p(x) => crate::_mm256_log1p_ps, high: crate::_mm256_log1p_pd;
+    sin(x) => crate::_mm256_sin_ps, high: crate::_mm256_sin_pd, cr: crate::cr::sinf;
+    cos(x) => crate::_mm256_cos_ps, high: crate::_mm256_cos_pd, cr: crate::cr::cosf;
+    tan(x) => crate::_mm256_tan_ps, high: crate::_mm256_tan_pd, cr: crate::cr::tanf;
+    asin(x) => crate::_mm256_asin_ps, high: crate::_mm256_asin_pd, cr: crate::cr::asinf;
+    acos(x) => crate::_mm256_acos_ps, high: crate::_mm256_acos_pd, cr: crate::cr::acosf;
+    atan(x) => crate::_mm256_atan_ps, high: crate::_mm256_atan_pd, cr: crate::cr::atanf;
+    sinh(x) => crate::_mm256_sinh_ps, high: crate::_mm256_sinh_pd;
+    cosh(x) => crate::_mm256_cosh_ps, high: crate::_mm256_cosh_pd;
+    tanh(x) => crate::_mm256_tanh_ps, high: crate::_mm256_tanh_pd;
+    asinh(x) => crate::_mm256_asinh_ps, high: crate::_mm256_asinh_pd;
+    acosh(x) => crate::_mm256_acosh_ps, high: crate::_mm256_acosh_pd;
+    atanh(x) => crate::_mm256_atanh_ps, high: crate::_mm256_atanh_pd;
+    cbrt(x) => crate::_mm256_cbrt_ps, fast: crate::_mm256_cbrt_fast_ps, high: crate::_mm256_cbrt_pd, cr: crate::cr::cbrtf;
+    erf(x) => crate::_mm256_erf_ps, high: crate::_mm256_erf_pd;
+    erfc(x) => crate::_mm256_erfc_ps, high: crate::_mm256_erfc_pd;
+    erfinv(x) => crate::_mm256_erfinv_ps, high: crate::_mm256_erfinv_pd;
+    tgamma(x) => crate::_mm256_tgamma_ps, high: crate::_mm256_tgamma_pd;
+    digamma(x) => crate::_mm256_digamma_ps, high: crate::_mm256_digamma_pd;
+    pow(x, n) => crate::_mm256_pow_ps, fast: crate::_mm256_pow_fast_ps, high: crate::_mm256_pow_pd, cr: crate::cr::powf;
+    atan2(y, x) => crate::_mm256_atan2_ps, high: crate::_mm256_atan2_pd;
+    hypot(x, y) => crate::_mm256_hypot_ps, fast: crate::_mm256_hypot_fast_ps, high: crate::_mm256_hypot_pd;
+    fmod(x, y) => crate::_mm256_fmod_ps;
+    hypot3(x, y, z) => crate::_mm256_hypot3_ps, fast: crate::_mm256_hypot3_fast_ps;
+    hypot4(x, y, z, w) => crate::_mm256_hypot4_ps, fast: crate::_mm256_hypot4_fast_ps;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::precision::{CorrectlyRounded, Fast, HighAccuracy, SimdMath, Standard};
+    use crate::{_mm256_abs_ps, _mm256_exp_ps, _mm256_expq_fast_ps, _mm256_sin_pd};
+
+    #[test]
+    fn test_tiers() {
+        unsafe {
+            let value = _mm256_set1_ps(3.5f32);
+            let fast: f32 = _mm256_cvtss_f32(Fast::exp(value));
+            assert_eq!(fast, _mm256_cvtss_f32(_mm256_expq_fast_ps(value)));
+            let standard: f32 = _mm256_cvtss_f32(Standard::exp(value));
+            assert_eq!(standard, _mm256_cvtss_f32(_mm256_exp_ps(value)));
+            let value = _mm256_set1_pd(0.7f64);
+            let high: f64 = _mm256_cvtsd_f64(HighAccuracy::sin(value));
+            assert_eq!(high, _mm256_cvtsd_f64(_mm256_sin_pd(value)));
+            let value = _mm256_set1_ps(0.7f32);
+            let correct: f32 = _mm256_cvtss_f32(CorrectlyRounded::sin(value));
+            assert_eq!(correct, crate::cr::sinf(0.7f32));
+        }
+    }
+
+    #[test]
+    fn test_high_accuracy_ps() {
+        for i in 0..2000 {
+            let x = i as f32 * 0.0123f32 - 12.3f32;
+            unsafe {
+                let value = _mm256_set1_ps(x);
+                let y = _mm256_set1_ps(0.75f32);
+                let sin = _mm256_cvtss_f32(HighAccuracy::sin(value));
+                let exp = _mm256_cvtss_f32(HighAccuracy::exp(value));
+                let pow = _mm256_cvtss_f32(HighAccuracy::pow(_mm256_abs_ps(value), y));
+                assert!(
+                    ulp(sin, (x as f64).sin() as f32) <= 1,
+                    "sin({}) = {}",
+                    x,
+                    sin
+                );
+                assert!(
+                    ulp(exp, (x as f64).exp() as f32) <= 1,
+                    "exp({}) = {}",
+                    x,
+                    exp
+                );
+                let control = (x.abs() as f64).powf(0.75) as f32;
+                assert!(ulp(pow, control) <= 1, "pow({}) = {}", x, pow);
+            }
+        }
+    }
+
+    fn ulp(a: f32, b: f32) -> i32 {
+        (a.to_bits() as i32).wrapping_sub(b.to_bits() as i32).abs()
+    }
+}
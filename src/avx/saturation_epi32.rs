@@ -12,7 +12,7 @@ use std::arch::x86_64::*;
 
 use crate::{_mm256_blendv_epi32, _mm256_cmplt_epu32};
 
-#[inline]
+#[inline(always)]
 /// Add unsigned 32 bytes integers using saturation
 pub unsafe fn _mm256_adds_epu32(a: __m256i, b: __m256i) -> __m256i {
     let result = _mm256_add_epi32(a, b);
@@ -25,7 +25,7 @@ pub unsafe fn _mm256_adds_epu32(a: __m256i, b: __m256i) -> __m256i {
     )
 }
 
-#[inline]
+#[inline(always)]
 /// Add signed 32 bytes integers using saturation
 pub unsafe fn _mm256_adds_epi32(a: __m256i, b: __m256i) -> __m256i {
     let res = _mm256_add_epi32(a, b);
@@ -41,7 +41,7 @@ pub unsafe fn _mm256_adds_epi32(a: __m256i, b: __m256i) -> __m256i {
     )
 }
 
-#[inline]
+#[inline(always)]
 /// Subtract signed integers 32 using saturation
 pub unsafe fn _mm256_subs_epi32(lhs: __m256i, rhs: __m256i) -> __m256i {
     let res = _mm256_sub_epi32(lhs, rhs);
@@ -59,3 +59,202 @@ pub unsafe fn _mm256_subs_epi32(lhs: __m256i, rhs: __m256i) -> __m256i {
         ),
     )
 }
+
+#[inline(always)]
+/// Subtract unsigned integers 32 using saturation
+pub unsafe fn _mm256_subs_epu32(a: __m256i, b: __m256i) -> __m256i {
+    let result = _mm256_sub_epi32(a, b);
+    _mm256_andnot_si256(_mm256_cmplt_epu32(a, b), result)
+}
+
+#[inline(always)]
+/// Widening u32 multiplication, returns lower and upper halves of the products
+unsafe fn _mm256_mulw_epu32(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    let even = _mm256_mul_epu32(a, b);
+    let odd = _mm256_mul_epu32(_mm256_srli_epi64::<32>(a), _mm256_srli_epi64::<32>(b));
+    let lo_mask = _mm256_set1_epi64x(0xFFFFFFFF);
+    let lo = _mm256_or_si256(
+        _mm256_and_si256(even, lo_mask),
+        _mm256_slli_epi64::<32>(odd),
+    );
+    let hi = _mm256_or_si256(
+        _mm256_srli_epi64::<32>(even),
+        _mm256_andnot_si256(lo_mask, odd),
+    );
+    (lo, hi)
+}
+
+#[inline(always)]
+/// Multiply unsigned integers 32 using saturation
+pub unsafe fn _mm256_muls_epu32(a: __m256i, b: __m256i) -> __m256i {
+    let (lo, hi) = _mm256_mulw_epu32(a, b);
+    let overflow = _mm256_xor_si256(
+        _mm256_cmpeq_epi32(hi, _mm256_setzero_si256()),
+        _mm256_set1_epi32(-1),
+    );
+    _mm256_or_si256(lo, overflow)
+}
+
+#[inline(always)]
+/// Multiply signed integers 32 using saturation
+pub unsafe fn _mm256_muls_epi32(a: __m256i, b: __m256i) -> __m256i {
+    let (lo, hi) = _mm256_mulw_epu32(a, b);
+    let correction = _mm256_add_epi32(
+        _mm256_and_si256(_mm256_srai_epi32::<31>(a), b),
+        _mm256_and_si256(_mm256_srai_epi32::<31>(b), a),
+    );
+    let hi = _mm256_sub_epi32(hi, correction);
+    let fits = _mm256_cmpeq_epi32(hi, _mm256_srai_epi32::<31>(lo));
+    let saturated = _mm256_xor_si256(
+        _mm256_set1_epi32(i32::MAX),
+        _mm256_srai_epi32::<31>(_mm256_xor_si256(a, b)),
+    );
+    _mm256_blendv_epi32(saturated, lo, fits)
+}
+
+#[inline(always)]
+/// Shift unsigned integers 32 left using saturation, same as *vqshlq_u32*.
+/// Shift is the lowest signed byte of `count` lane, negative shift is a truncating right shift
+pub unsafe fn _mm256_shls_epu32(a: __m256i, count: __m256i) -> __m256i {
+    let shift = _mm256_srai_epi32::<24>(_mm256_slli_epi32::<24>(count));
+    let right = _mm256_srlv_epi32(a, _mm256_sub_epi32(_mm256_setzero_si256(), shift));
+    let left = _mm256_sllv_epi32(a, shift);
+    let overflow = _mm256_xor_si256(
+        _mm256_cmpeq_epi32(_mm256_srlv_epi32(left, shift), a),
+        _mm256_set1_epi32(-1),
+    );
+    _mm256_blendv_epi32(_mm256_or_si256(left, overflow), right, shift)
+}
+
+#[inline(always)]
+/// Shift signed integers 32 left using saturation, same as *vqshlq_s32*.
+/// Shift is the lowest signed byte of `count` lane, negative shift is a truncating right shift
+pub unsafe fn _mm256_shls_epi32(a: __m256i, count: __m256i) -> __m256i {
+    let shift = _mm256_srai_epi32::<24>(_mm256_slli_epi32::<24>(count));
+    let right = _mm256_srav_epi32(a, _mm256_sub_epi32(_mm256_setzero_si256(), shift));
+    let left = _mm256_sllv_epi32(a, shift);
+    let fits = _mm256_cmpeq_epi32(_mm256_srav_epi32(left, shift), a);
+    let saturated = _mm256_xor_si256(_mm256_set1_epi32(i32::MAX), _mm256_srai_epi32::<31>(a));
+    _mm256_blendv_epi32(_mm256_blendv_epi32(saturated, left, fits), right, shift)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm256_extract_epi64x;
+
+    const VALUES: [i32; 16] = [
+        0,
+        1,
+        -1,
+        2,
+        7,
+        -9,
+        0xffff,
+        0x10000,
+        0x1234_5678,
+        -0x1234_5678,
+        0x4000_0000,
+        -0x4000_0000,
+        0x7fff_0000,
+        -0x7fff_0000,
+        i32::MAX,
+        i32::MIN,
+    ];
+
+    const SHIFTS: [i32; 16] = [
+        -128,
+        -100,
+        -33,
+        -32,
+        -31,
+        -1,
+        0,
+        1,
+        2,
+        30,
+        31,
+        32,
+        33,
+        127,
+        0x1234_5601,
+        -0xff_ff00,
+    ];
+
+    unsafe fn lanes(v: __m256i) -> [i32; 8] {
+        let l = [
+            _mm256_extract_epi64x::<0>(v) as u64,
+            _mm256_extract_epi64x::<1>(v) as u64,
+            _mm256_extract_epi64x::<2>(v) as u64,
+            _mm256_extract_epi64x::<3>(v) as u64,
+        ];
+        [0, 1, 2, 3, 4, 5, 6, 7].map(|i| (l[i / 2] >> (32 * (i % 2))) as i32)
+    }
+
+    unsafe fn load(v: &[i32]) -> __m256i {
+        _mm256_setr_epi32(v[0], v[1], v[2], v[3], v[4], v[5], v[6], v[7])
+    }
+
+    fn qshl_s32(a: i32, shift: i8) -> i32 {
+        if shift < 0 {
+            return a >> (-(shift as i32)).min(31);
+        }
+        if shift >= 32 {
+            return if a == 0 {
+                0
+            } else if a < 0 {
+                i32::MIN
+            } else {
+                i32::MAX
+            };
+        }
+        ((a as i64) << shift).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    fn qshl_u32(a: u32, shift: i8) -> u32 {
+        if shift < 0 {
+            return a.checked_shr(-(shift as i32) as u32).unwrap_or(0);
+        }
+        if shift >= 32 {
+            return if a == 0 { 0 } else { u32::MAX };
+        }
+        ((a as u64) << shift).min(u32::MAX as u64) as u32
+    }
+
+    #[test]
+    fn test_saturation_32() {
+        unsafe {
+            for chunk in VALUES.chunks(8) {
+                let va = load(chunk);
+                for &b in VALUES.iter() {
+                    let vb = _mm256_set1_epi32(b);
+                    let adds = lanes(_mm256_adds_epi32(va, vb));
+                    let subs = lanes(_mm256_subs_epi32(va, vb));
+                    let muls = lanes(_mm256_muls_epi32(va, vb));
+                    let addu = lanes(_mm256_adds_epu32(va, vb));
+                    let subu = lanes(_mm256_subs_epu32(va, vb));
+                    let mulu = lanes(_mm256_muls_epu32(va, vb));
+                    for i in 0..8 {
+                        let (a, ua, ub) = (chunk[i], chunk[i] as u32, b as u32);
+                        assert_eq!(adds[i], a.saturating_add(b));
+                        assert_eq!(subs[i], a.saturating_sub(b));
+                        assert_eq!(muls[i], a.saturating_mul(b));
+                        assert_eq!(addu[i] as u32, ua.saturating_add(ub));
+                        assert_eq!(subu[i] as u32, ua.saturating_sub(ub));
+                        assert_eq!(mulu[i] as u32, ua.saturating_mul(ub));
+                    }
+                }
+                for r in 0..SHIFTS.len() {
+                    let s = [0, 1, 2, 3, 4, 5, 6, 7].map(|i| SHIFTS[(i + r) % SHIFTS.len()]);
+                    let vs = load(&s);
+                    let shls = lanes(_mm256_shls_epi32(va, vs));
+                    let shlu = lanes(_mm256_shls_epu32(va, vs));
+                    for i in 0..8 {
+                        assert_eq!(shls[i], qshl_s32(chunk[i], s[i] as i8));
+                        assert_eq!(shlu[i] as u32, qshl_u32(chunk[i] as u32, s[i] as i8));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::epi64::_mm256_select_epi64;
+use crate::{_mm256_cmplt_epu64, _mm256_mull_epi64, _mm256_mull_epu64, _mm256_srai_epi64x};
+
+#[inline(always)]
+/// Add unsigned 64 bytes integers using saturation
+pub unsafe fn _mm256_adds_epu64(a: __m256i, b: __m256i) -> __m256i {
+    let result = _mm256_add_epi64(a, b);
+    _mm256_or_si256(_mm256_cmplt_epu64(result, a), result)
+}
+
+#[inline(always)]
+/// Add signed 64 bytes integers using saturation
+pub unsafe fn _mm256_adds_epi64(lhs: __m256i, rhs: __m256i) -> __m256i {
+    let res = _mm256_add_epi64(lhs, rhs);
+    let saturated = _mm256_xor_si256(_mm256_set1_epi64x(i64::MAX), _mm256_srai_epi64x::<63>(lhs));
+    _mm256_select_epi64(
+        _mm256_xor_si256(rhs, _mm256_cmpgt_epi64(lhs, res)),
+        saturated,
+        res,
+    )
+}
+
+#[inline(always)]
+/// Subtract unsigned integers 64 using saturation
+pub unsafe fn _mm256_subs_epu64(a: __m256i, b: __m256i) -> __m256i {
+    let result = _mm256_sub_epi64(a, b);
+    _mm256_andnot_si256(_mm256_cmplt_epu64(a, b), result)
+}
+
+#[inline(always)]
+/// Subtract signed integers 64 using saturation
+pub unsafe fn _mm256_subs_epi64(lhs: __m256i, rhs: __m256i) -> __m256i {
+    let res = _mm256_sub_epi64(lhs, rhs);
+    let saturated = _mm256_xor_si256(_mm256_set1_epi64x(i64::MAX), _mm256_srai_epi64x::<63>(lhs));
+    _mm256_select_epi64(
+        _mm256_xor_si256(
+            _mm256_cmpgt_epi64(rhs, _mm256_setzero_si256()),
+            _mm256_cmpgt_epi64(lhs, res),
+        ),
+        saturated,
+        res,
+    )
+}
+
+#[inline(always)]
+/// Multiply unsigned integers 64 using saturation
+pub unsafe fn _mm256_muls_epu64(a: __m256i, b: __m256i) -> __m256i {
+    let product = _mm256_mull_epu64(a, b);
+    let overflow = _mm256_xor_si256(
+        _mm256_cmpeq_epi64(product.1, _mm256_setzero_si256()),
+        _mm256_set1_epi32(-1),
+    );
+    _mm256_or_si256(product.0, overflow)
+}
+
+#[inline(always)]
+/// Multiply signed integers 64 using saturation
+pub unsafe fn _mm256_muls_epi64(a: __m256i, b: __m256i) -> __m256i {
+    let product = _mm256_mull_epi64(a, b);
+    let fits = _mm256_cmpeq_epi64(product.1, _mm256_srai_epi64x::<63>(product.0));
+    let saturated = _mm256_xor_si256(
+        _mm256_set1_epi64x(i64::MAX),
+        _mm256_srai_epi64x::<63>(_mm256_xor_si256(a, b)),
+    );
+    _mm256_select_epi64(fits, product.0, saturated)
+}
+
+#[inline(always)]
+/// Shifts i64 right by the corresponding lane of `count`, counts greater than 63 fill with sign
+unsafe fn _mm256_srav_epi64x(a: __m256i, count: __m256i) -> __m256i {
+    let sign = _mm256_srai_epi64x::<63>(a);
+    _mm256_xor_si256(_mm256_srlv_epi64(_mm256_xor_si256(a, sign), count), sign)
+}
+
+#[inline(always)]
+/// Shift unsigned integers 64 left using saturation, same as *vqshlq_u64*.
+/// Shift is the lowest signed byte of `count` lane, negative shift is a truncating right shift
+pub unsafe fn _mm256_shls_epu64(a: __m256i, count: __m256i) -> __m256i {
+    let shift = _mm256_srai_epi64x::<56>(_mm256_slli_epi64::<56>(count));
+    let right = _mm256_srlv_epi64(a, _mm256_sub_epi64(_mm256_setzero_si256(), shift));
+    let left = _mm256_sllv_epi64(a, shift);
+    let overflow = _mm256_xor_si256(
+        _mm256_cmpeq_epi64(_mm256_srlv_epi64(left, shift), a),
+        _mm256_set1_epi32(-1),
+    );
+    _mm256_select_epi64(shift, right, _mm256_or_si256(left, overflow))
+}
+
+#[inline(always)]
+/// Shift signed integers 64 left using saturation, same as *vqshlq_s64*.
+/// Shift is the lowest signed byte of `count` lane, negative shift is a truncating right shift
+pub unsafe fn _mm256_shls_epi64(a: __m256i, count: __m256i) -> __m256i {
+    let shift = _mm256_srai_epi64x::<56>(_mm256_slli_epi64::<56>(count));
+    let right = _mm256_srav_epi64x(a, _mm256_sub_epi64(_mm256_setzero_si256(), shift));
+    let left = _mm256_sllv_epi64(a, shift);
+    let fits = _mm256_cmpeq_epi64(_mm256_srav_epi64x(left, shift), a);
+    let saturated = _mm256_xor_si256(_mm256_set1_epi64x(i64::MAX), _mm256_srai_epi64x::<63>(a));
+    _mm256_select_epi64(shift, right, _mm256_select_epi64(fits, left, saturated))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm256_extract_epi64x;
+
+    const VALUES: [i64; 12] = [
+        0,
+        1,
+        -1,
+        3,
+        -9,
+        0x7fff_ffff,
+        0x1_ffff_fff5,
+        0x1234_5678_9abc_def0,
+        -0x1234_5678_9abc_def0,
+        0x4000_0000_0000_0000,
+        i64::MAX,
+        i64::MIN,
+    ];
+
+    const SHIFTS: [i64; 20] = [
+        -128,
+        -100,
+        -65,
+        -64,
+        -63,
+        -33,
+        -7,
+        -1,
+        0,
+        1,
+        2,
+        31,
+        32,
+        62,
+        63,
+        64,
+        65,
+        127,
+        0x1234_5601,
+        -0xff_ff00,
+    ];
+
+    unsafe fn lanes(v: __m256i) -> [i64; 4] {
+        [
+            _mm256_extract_epi64x::<0>(v),
+            _mm256_extract_epi64x::<1>(v),
+            _mm256_extract_epi64x::<2>(v),
+            _mm256_extract_epi64x::<3>(v),
+        ]
+    }
+
+    fn qshl_s64(a: i64, shift: i8) -> i64 {
+        if shift < 0 {
+            return a >> (-(shift as i32)).min(63);
+        }
+        if shift >= 64 {
+            return if a == 0 {
+                0
+            } else if a < 0 {
+                i64::MIN
+            } else {
+                i64::MAX
+            };
+        }
+        ((a as i128) << shift).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    fn qshl_u64(a: u64, shift: i8) -> u64 {
+        if shift < 0 {
+            return a.checked_shr(-(shift as i32) as u32).unwrap_or(0);
+        }
+        if shift >= 64 {
+            return if a == 0 { 0 } else { u64::MAX };
+        }
+        ((a as u128) << shift).min(u64::MAX as u128) as u64
+    }
+
+    #[test]
+    fn test_saturation_64() {
+        unsafe {
+            for chunk in VALUES.chunks(4) {
+                let va = _mm256_setr_epi64x(chunk[0], chunk[1], chunk[2], chunk[3]);
+                for &b in VALUES.iter() {
+                    let vb = _mm256_set1_epi64x(b);
+                    let adds = lanes(_mm256_adds_epi64(va, vb));
+                    let subs = lanes(_mm256_subs_epi64(va, vb));
+                    let muls = lanes(_mm256_muls_epi64(va, vb));
+                    let addu = lanes(_mm256_adds_epu64(va, vb));
+                    let subu = lanes(_mm256_subs_epu64(va, vb));
+                    let mulu = lanes(_mm256_muls_epu64(va, vb));
+                    for i in 0..4 {
+                        let (a, ua, ub) = (chunk[i], chunk[i] as u64, b as u64);
+                        assert_eq!(adds[i], a.saturating_add(b));
+                        assert_eq!(subs[i], a.saturating_sub(b));
+                        assert_eq!(muls[i], a.saturating_mul(b));
+                        assert_eq!(addu[i] as u64, ua.saturating_add(ub));
+                        assert_eq!(subu[i] as u64, ua.saturating_sub(ub));
+                        assert_eq!(mulu[i] as u64, ua.saturating_mul(ub));
+                    }
+                }
+                for r in 0..SHIFTS.len() {
+                    let s = [0, 1, 2, 3].map(|i| SHIFTS[(i + r) % SHIFTS.len()]);
+                    let vs = _mm256_setr_epi64x(s[0], s[1], s[2], s[3]);
+                    let shls = lanes(_mm256_shls_epi64(va, vs));
+                    let shlu = lanes(_mm256_shls_epu64(va, vs));
+                    for i in 0..4 {
+                        assert_eq!(shls[i], qshl_s64(chunk[i], s[i] as i8));
+                        assert_eq!(shlu[i] as u64, qshl_u64(chunk[i] as u64, s[i] as i8));
+                    }
+                }
+            }
+        }
+    }
+}
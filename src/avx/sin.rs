@@ -9,16 +9,20 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use crate::avx::general::_mm256_multiply_as_doubled_pd;
+use crate::avx::general::_mm256_rempi_pd;
+use crate::generalf::rempi;
 use crate::sin::{
     PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
     SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
 };
+use crate::{_mm256_abs_pd, _mm256_copysign_pd, _mm256_select_pd};
 use crate::{
     _mm256_cvtepi64_pdx, _mm256_mlaf_pd, _mm256_neg_pd, _mm256_rint_pd, _mm256_selecti_pd,
 };
 
-#[inline]
-/// Computes sine function with *ULP 1.5* on range [-15; 15]
+#[inline(always)]
+/// Computes sine function with *ULP 2.5*
 pub unsafe fn _mm256_sin_pd(d: __m256d) -> __m256d {
     let q = _mm256_rint_pd(_mm256_mul_pd(
         d,
@@ -29,8 +33,7 @@ pub unsafe fn _mm256_sin_pd(d: __m256d) -> __m256d {
 
     let mut r = _mm256_mlaf_pd(qf, _mm256_set1_pd(-PI_A2), d);
     r = _mm256_mlaf_pd(qf, _mm256_set1_pd(-PI_B2), r);
-
-    let x2 = _mm256_mul_pd(r, r);
+    let (q, mut r) = _mm256_rempi_pd(d, q, r, rempi);
 
     r = _mm256_selecti_pd(
         _mm256_cmpeq_epi64(
@@ -40,6 +43,18 @@ pub unsafe fn _mm256_sin_pd(d: __m256d) -> __m256d {
         r,
         _mm256_neg_pd(r),
     );
+    // sin(±0) = ±0
+    _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(d, _mm256_setzero_pd()),
+        d,
+        _mm256_sink_pd(r),
+    )
+}
+
+/// Evaluates sine polynomial for reduced |r| <= pi/2
+#[inline(always)]
+pub(crate) unsafe fn _mm256_sink_pd(r: __m256d) -> __m256d {
+    let x2 = _mm256_mul_pd(r, r);
     let mut res = _mm256_set1_pd(SIN_POLY_10_D);
     res = _mm256_mlaf_pd(res, x2, _mm256_set1_pd(SIN_POLY_9_D));
     res = _mm256_mlaf_pd(res, x2, _mm256_set1_pd(SIN_POLY_8_D));
@@ -53,6 +68,36 @@ pub unsafe fn _mm256_sin_pd(d: __m256d) -> __m256d {
     res
 }
 
+/// Computes sin(pi*x) for |x| < 2^52, integers give exact zeros
+#[inline(always)]
+pub(crate) unsafe fn _mm256_sinpik_pd(x: __m256d) -> __m256d {
+    // Period is 2 so the reduction is exact, then folding into [-0.5, 0.5] by sin(pi - y) = sin(y)
+    let mut r = _mm256_sub_pd(
+        x,
+        _mm256_mul_pd(
+            _mm256_set1_pd(2f64),
+            _mm256_round_pd::<_MM_FROUND_TO_NEAREST_INT>(_mm256_mul_pd(x, _mm256_set1_pd(0.5f64))),
+        ),
+    );
+    r = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_GT_OS>(_mm256_abs_pd(r), _mm256_set1_pd(0.5f64)),
+        _mm256_sub_pd(_mm256_copysign_pd(_mm256_set1_pd(1f64), r), r),
+        r,
+    );
+    // pi * r is kept as double-float, sin(y + lo) = sin(y) + lo * cos(y) with cos(y) ~ 1 - y^2/2
+    let y = _mm256_multiply_as_doubled_pd(r, _mm256_set1_pd(PI_A2));
+    let lo = _mm256_mlaf_pd(r, _mm256_set1_pd(PI_B2), y.1);
+    _mm256_mlaf_pd(
+        lo,
+        _mm256_mlaf_pd(
+            _mm256_mul_pd(_mm256_set1_pd(-0.5f64), y.0),
+            y.0,
+            _mm256_set1_pd(1f64),
+        ),
+        _mm256_sink_pd(y.0),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::avx::general::_mm256_extract_pd;
@@ -97,4 +142,13 @@ mod tests {
             assert_eq!(flag_1, control);
         }
     }
+
+    #[test]
+    fn test_sind_large() {
+        for x in [1e6f64, -1e22, 3.5e300] {
+            let value = unsafe { _mm256_cvtsd_f64(_mm256_sin_pd(_mm256_setr_pd(x, 2., -0.5, 1.))) };
+            let control = x.sin();
+            assert!((value - control).abs() <= 2. * f64::EPSILON * control.abs());
+        }
+    }
 }
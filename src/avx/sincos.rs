@@ -0,0 +1,93 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::general::_mm256_rempi_pd;
+use crate::generalf::rempi2;
+use crate::sin::{
+    PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
+    SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
+};
+use crate::{
+    _mm256_cvtepi64_pdx, _mm256_mlaf_pd, _mm256_rint_pd, _mm256_select_pd, _mm256_selecti_pd,
+};
+
+#[inline(always)]
+unsafe fn _mm256_sin_poly_pd(r: __m256d) -> __m256d {
+    let x2 = _mm256_mul_pd(r, r);
+    let mut res = _mm256_set1_pd(SIN_POLY_10_D);
+    res = _mm256_mlaf_pd(res, x2, _mm256_set1_pd(SIN_POLY_9_D));
+    res = _mm256_mlaf_pd(res, x2, _mm256_set1_pd(SIN_POLY_8_D));
+    res = _mm256_mlaf_pd(res, x2, _mm256_set1_pd(SIN_POLY_7_D));
+    res = _mm256_mlaf_pd(res, x2, _mm256_set1_pd(SIN_POLY_6_D));
+    res = _mm256_mlaf_pd(res, x2, _mm256_set1_pd(SIN_POLY_5_D));
+    res = _mm256_mlaf_pd(res, x2, _mm256_set1_pd(SIN_POLY_4_D));
+    res = _mm256_mlaf_pd(res, x2, _mm256_set1_pd(SIN_POLY_3_D));
+    res = _mm256_mlaf_pd(res, x2, _mm256_set1_pd(SIN_POLY_2_D));
+    _mm256_mlaf_pd(res, _mm256_mul_pd(x2, r), r)
+}
+
+#[inline(always)]
+/// Computes sine and cosine with a single argument reduction, returns `(sin, cos)`, *ULP 1.5*
+pub unsafe fn _mm256_sincos_pd(d: __m256d) -> (__m256d, __m256d) {
+    let q = _mm256_rint_pd(_mm256_mul_pd(
+        d,
+        _mm256_set1_pd(std::f64::consts::FRAC_2_PI),
+    ));
+    let qf = _mm256_cvtepi64_pdx(q);
+
+    let mut r = _mm256_mlaf_pd(qf, _mm256_set1_pd(-PI_A2 * 0.5), d);
+    r = _mm256_mlaf_pd(qf, _mm256_set1_pd(-PI_B2 * 0.5), r);
+    let (q, r) = _mm256_rempi_pd(d, q, r, rempi2);
+
+    // cos(r) = 1 - 2*sin(r/2)^2, |r| <= π/4
+    let s = _mm256_sin_poly_pd(r);
+    let h = _mm256_sin_poly_pd(_mm256_mul_pd(r, _mm256_set1_pd(0.5)));
+    let c = _mm256_mlaf_pd(_mm256_mul_pd(_mm256_set1_pd(-2.), h), h, _mm256_set1_pd(1.));
+
+    let is_even = _mm256_cmpeq_epi64(
+        _mm256_and_si256(q, _mm256_set1_epi64x(1)),
+        _mm256_setzero_si256(),
+    );
+    let sin = _mm256_selecti_pd(is_even, s, c);
+    let cos = _mm256_selecti_pd(is_even, c, s);
+
+    let sin_sign = _mm256_slli_epi64::<62>(_mm256_and_si256(q, _mm256_set1_epi64x(2)));
+    let cos_sign = _mm256_slli_epi64::<62>(_mm256_and_si256(
+        _mm256_add_epi64(q, _mm256_set1_epi64x(1)),
+        _mm256_set1_epi64x(2),
+    ));
+    // sin(±0) = ±0
+    (
+        _mm256_select_pd(
+            _mm256_cmp_pd::<_CMP_EQ_OQ>(d, _mm256_setzero_pd()),
+            d,
+            _mm256_xor_pd(sin, _mm256_castsi256_pd(sin_sign)),
+        ),
+        _mm256_xor_pd(cos, _mm256_castsi256_pd(cos_sign)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sincosd() {
+        for x in [0f64, -2.70752239, 2., 0.75, -5.5, 14.9, 1e6, -1e22, 3.5e300] {
+            let (sin, cos) = unsafe { _mm256_sincos_pd(_mm256_setr_pd(x, 1., -0.5, 2.)) };
+            let (sin, cos) = unsafe { (_mm256_cvtsd_f64(sin), _mm256_cvtsd_f64(cos)) };
+            let (control_sin, control_cos) = (x.sin(), x.cos());
+            assert!((sin - control_sin).abs() <= 2. * f64::EPSILON * control_sin.abs());
+            assert!((cos - control_cos).abs() <= 2. * f64::EPSILON * control_cos.abs());
+        }
+    }
+}
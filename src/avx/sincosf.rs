@@ -0,0 +1,90 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::generalf::_mm256_rempi_ps;
+use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2f;
+use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+use crate::{
+    _mm256_eqzero_ps, _mm256_mlaf_ps, _mm256_rint_ps, _mm256_select_ps, _mm256_selecti_ps,
+};
+
+#[inline(always)]
+unsafe fn _mm256_sin_poly_ps(r: __m256) -> __m256 {
+    let x2 = _mm256_mul_ps(r, r);
+    let mut res = _mm256_set1_ps(SIN_POLY_5_S);
+    res = _mm256_mlaf_ps(res, x2, _mm256_set1_ps(SIN_POLY_4_S));
+    res = _mm256_mlaf_ps(res, x2, _mm256_set1_ps(SIN_POLY_3_S));
+    res = _mm256_mlaf_ps(res, x2, _mm256_set1_ps(SIN_POLY_2_S));
+    res = _mm256_mlaf_ps(res, x2, _mm256_set1_ps(SIN_POLY_1_S));
+    _mm256_mlaf_ps(res, _mm256_mul_ps(x2, r), r)
+}
+
+#[inline(always)]
+/// Computes sine and cosine with a single argument reduction, returns `(sin, cos)`, *ULP 2.0*
+pub unsafe fn _mm256_sincos_ps(d: __m256) -> (__m256, __m256) {
+    let q = _mm256_rint_ps(_mm256_mul_ps(
+        d,
+        _mm256_set1_ps(std::f32::consts::FRAC_2_PI),
+    ));
+    let qf = _mm256_cvtepi32_ps(q);
+
+    let mut r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_A_F * 0.5), d);
+    r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_B_F * 0.5), r);
+    r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_C_F * 0.5), r);
+    r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_D_F * 0.5), r);
+    let (q, r) = _mm256_rempi_ps(d, q, r, rempi2f);
+
+    // cos(r) = 1 - 2*sin(r/2)^2, |r| <= π/4
+    let s = _mm256_sin_poly_ps(r);
+    let h = _mm256_sin_poly_ps(_mm256_mul_ps(r, _mm256_set1_ps(0.5)));
+    let c = _mm256_mlaf_ps(_mm256_mul_ps(_mm256_set1_ps(-2.), h), h, _mm256_set1_ps(1.));
+
+    let is_even = _mm256_cmpeq_epi32(
+        _mm256_and_si256(q, _mm256_set1_epi32(1)),
+        _mm256_setzero_si256(),
+    );
+    let sin = _mm256_selecti_ps(is_even, s, c);
+    let cos = _mm256_selecti_ps(is_even, c, s);
+
+    let sin_sign = _mm256_slli_epi32::<30>(_mm256_and_si256(q, _mm256_set1_epi32(2)));
+    let cos_sign = _mm256_slli_epi32::<30>(_mm256_and_si256(
+        _mm256_add_epi32(q, _mm256_set1_epi32(1)),
+        _mm256_set1_epi32(2),
+    ));
+    // sin(±0) = ±0
+    (
+        _mm256_select_ps(
+            _mm256_eqzero_ps(d),
+            d,
+            _mm256_xor_ps(sin, _mm256_castsi256_ps(sin_sign)),
+        ),
+        _mm256_xor_ps(cos, _mm256_castsi256_ps(cos_sign)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sincosf() {
+        for x in [0f32, -2.70752239, 2., 0.75, -5.5, 124.9, 1e4, -1e22, 3.0e38] {
+            let (sin, cos) =
+                unsafe { _mm256_sincos_ps(_mm256_setr_ps(x, 1., -0.5, 2., 0.25, 3., -3., 5.)) };
+            let (sin, cos) = unsafe { (_mm256_cvtss_f32(sin), _mm256_cvtss_f32(cos)) };
+            let (control_sin, control_cos) = ((x as f64).sin() as f32, (x as f64).cos() as f32);
+            assert!((sin - control_sin).abs() <= 2. * f32::EPSILON * control_sin.abs());
+            assert!((cos - control_cos).abs() <= 2. * f32::EPSILON * control_cos.abs());
+        }
+    }
+}
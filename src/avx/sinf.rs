@@ -9,12 +9,17 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use crate::avx::generalf::_mm256_rempi_ps;
+use crate::avx::generalf::{_mm256_copysign_ps, _mm256_multiply_as_doubled_ps};
 use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempif;
+use crate::sinf::{PI_LO_S, PI_S};
 use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+use crate::{_mm256_abs_ps, _mm256_eqzero_ps, _mm256_select_ps};
 use crate::{_mm256_mlaf_ps, _mm256_neg_ps, _mm256_rint_ps, _mm256_selecti_ps};
 
-/// Computes sine function with error bound *ULP 1.5*
-#[inline]
+/// Computes sine function with error bound *ULP 2.5*
+#[inline(always)]
 pub unsafe fn _mm256_sin_ps(d: __m256) -> __m256 {
     let q = _mm256_rint_ps(_mm256_mul_ps(
         d,
@@ -26,8 +31,7 @@ pub unsafe fn _mm256_sin_ps(d: __m256) -> __m256 {
     r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_B_F), r);
     r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_C_F), r);
     r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_D_F), r);
-
-    let x2 = _mm256_mul_ps(r, r);
+    let (q, mut r) = _mm256_rempi_ps(d, q, r, rempif);
 
     r = _mm256_selecti_ps(
         _mm256_cmpeq_epi32(
@@ -37,6 +41,14 @@ pub unsafe fn _mm256_sin_ps(d: __m256) -> __m256 {
         r,
         _mm256_neg_ps(r),
     );
+    // sin(±0) = ±0
+    _mm256_select_ps(_mm256_eqzero_ps(d), d, _mm256_sink_ps(r))
+}
+
+/// Evaluates sine polynomial for reduced |r| <= pi/2
+#[inline(always)]
+pub(crate) unsafe fn _mm256_sink_ps(r: __m256) -> __m256 {
+    let x2 = _mm256_mul_ps(r, r);
     let mut res = _mm256_set1_ps(SIN_POLY_5_S);
     res = _mm256_mlaf_ps(res, x2, _mm256_set1_ps(SIN_POLY_4_S));
     res = _mm256_mlaf_ps(res, x2, _mm256_set1_ps(SIN_POLY_3_S));
@@ -46,6 +58,36 @@ pub unsafe fn _mm256_sin_ps(d: __m256) -> __m256 {
     res
 }
 
+/// Computes sin(pi*x) for |x| < 2^23, integers give exact zeros
+#[inline(always)]
+pub(crate) unsafe fn _mm256_sinpik_ps(x: __m256) -> __m256 {
+    // Period is 2 so the reduction is exact, then folding into [-0.5, 0.5] by sin(pi - y) = sin(y)
+    let mut r = _mm256_sub_ps(
+        x,
+        _mm256_mul_ps(
+            _mm256_set1_ps(2f32),
+            _mm256_round_ps::<_MM_FROUND_TO_NEAREST_INT>(_mm256_mul_ps(x, _mm256_set1_ps(0.5f32))),
+        ),
+    );
+    r = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_GT_OS>(_mm256_abs_ps(r), _mm256_set1_ps(0.5f32)),
+        _mm256_sub_ps(_mm256_copysign_ps(_mm256_set1_ps(1f32), r), r),
+        r,
+    );
+    // pi * r is kept as double-float, sin(y + lo) = sin(y) + lo * cos(y) with cos(y) ~ 1 - y^2/2
+    let y = _mm256_multiply_as_doubled_ps(r, _mm256_set1_ps(PI_S));
+    let lo = _mm256_mlaf_ps(r, _mm256_set1_ps(PI_LO_S), y.1);
+    _mm256_mlaf_ps(
+        lo,
+        _mm256_mlaf_ps(
+            _mm256_mul_ps(_mm256_set1_ps(-0.5f32), y.0),
+            y.0,
+            _mm256_set1_ps(1f32),
+        ),
+        _mm256_sink_ps(y.0),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::_mm256_extract_ps;
@@ -70,4 +112,46 @@ mod tests {
             assert_eq!(flag_1, control);
         }
     }
+
+    #[test]
+    fn test_sinf_large() {
+        for x in [1e4f32, -1e22, 3.0e38] {
+            let value = unsafe {
+                _mm256_cvtss_f32(_mm256_sin_ps(_mm256_setr_ps(
+                    x, 2., -0.5, 1., 0.25, 3., -3., 5.,
+                )))
+            };
+            let control = (x as f64).sin() as f32;
+            assert!((value - control).abs() <= 2. * f32::EPSILON * control.abs());
+        }
+    }
+
+    #[test]
+    fn test_sinf_ulp() {
+        // Uniform on [-1e4, 1e4] and log-uniform up to 1e30
+        let mut state = 0x5851_f42d_4c95_7f2du64;
+        for i in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let sign = if i % 4 == 1 { 1. } else { -1. };
+            let x = if i % 2 == 0 {
+                ((u * 2. - 1.) * 1e4) as f32
+            } else {
+                (sign * 10f64.powf(u * 33. - 3.)) as f32
+            };
+            let control = (x as f64).sin();
+            let value = unsafe { _mm256_cvtss_f32(_mm256_sin_ps(_mm256_set1_ps(x))) };
+            assert!(
+                ulp(value, control) <= 2.5,
+                "sin({x}) = {value}, expected {control}"
+            );
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
 }
@@ -10,14 +10,19 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use crate::avx::generalf::_mm256_rempi_ps;
 use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2f;
 use crate::tanf::{
     TAN_POLY_1_S, TAN_POLY_2_S, TAN_POLY_3_S, TAN_POLY_4_S, TAN_POLY_5_S, TAN_POLY_6_S,
     TAN_POLY_7_S, TAN_POLY_8_S, TAN_POLY_9_S,
 };
-use crate::{_mm256_mlaf_ps, _mm256_neg_ps, _mm256_rint_ps, _mm256_selecti_ps};
+use crate::{
+    _mm256_isinf_ps, _mm256_mlaf_ps, _mm256_neg_ps, _mm256_rint_ps, _mm256_select_ps,
+    _mm256_selecti_ps,
+};
 
-#[inline]
+#[inline(always)]
 /// Computes tan function with error bound *ULP 1.5*
 pub unsafe fn _mm256_tan_ps(d: __m256) -> __m256 {
     let q = _mm256_rint_ps(_mm256_mul_ps(
@@ -30,6 +35,7 @@ pub unsafe fn _mm256_tan_ps(d: __m256) -> __m256 {
     r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_B_F * 0.5), r);
     r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_C_F * 0.5), r);
     r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-PI_D_F * 0.5), r);
+    let (q, mut r) = _mm256_rempi_ps(d, q, r, rempi2f);
 
     let is_even = _mm256_cmpeq_epi32(
         _mm256_and_si256(q, _mm256_set1_epi32(1)),
@@ -50,7 +56,8 @@ pub unsafe fn _mm256_tan_ps(d: __m256) -> __m256 {
     res = _mm256_mlaf_ps(res, x2, _mm256_set1_ps(TAN_POLY_1_S));
     res = _mm256_mlaf_ps(res, _mm256_mul_ps(x2, r), r);
     res = _mm256_selecti_ps(is_even, res, _mm256_div_ps(_mm256_set1_ps(1.), res));
-    res
+    // tan(±inf) = NaN
+    _mm256_select_ps(_mm256_isinf_ps(d), _mm256_set1_ps(f32::NAN), res)
 }
 
 #[cfg(test)]
@@ -84,4 +91,17 @@ mod tests {
             assert_eq!(flag_1, control);
         }
     }
+
+    #[test]
+    fn test_tanf_large() {
+        for x in [1e4f32, -1e22, 3.0e38] {
+            let value = unsafe {
+                _mm256_cvtss_f32(_mm256_tan_ps(_mm256_setr_ps(
+                    x, 2., -0.5, 1., 0.25, 3., -3., 5.,
+                )))
+            };
+            let control = (x as f64).tan() as f32;
+            assert!((value - control).abs() <= 4. * f32::EPSILON * control.abs());
+        }
+    }
 }
@@ -0,0 +1,70 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm256_abs_pd, _mm256_copysign_pd, _mm256_expm1_pd, _mm256_select_pd};
+
+/// Computes hyperbolic tangent, error bound *ULP 2.5*
+#[inline(always)]
+pub unsafe fn _mm256_tanh_pd(d: __m256d) -> __m256d {
+    let a = _mm256_abs_pd(d);
+    let e = _mm256_expm1_pd(_mm256_add_pd(a, a));
+    let mut r = _mm256_div_pd(e, _mm256_add_pd(e, _mm256_set1_pd(2f64)));
+    // tanh rounds to 1 long before expm1 overflows
+    r = _mm256_select_pd(
+        _mm256_cmp_pd::<_CMP_GT_OS>(a, _mm256_set1_pd(22f64)),
+        _mm256_set1_pd(1f64),
+        r,
+    );
+    _mm256_copysign_pd(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tanhd() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(0.5f64);
+            let comparison = _mm256_tanh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = 0.5f64.tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_pd(-1e-10f64);
+            let comparison = _mm256_tanh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            let expected = (-1e-10f64).tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm256_set1_pd(30f64);
+            let comparison = _mm256_tanh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, 1.);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm256_set1_pd(-30f64);
+            let comparison = _mm256_tanh_pd(value);
+            let flag_1 = _mm256_cvtsd_f64(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx::generalf::_mm256_copysign_ps;
+use crate::{_mm256_abs_ps, _mm256_expm1_ps, _mm256_select_ps};
+
+/// Computes hyperbolic tangent, error bound *ULP 2.5*
+#[inline(always)]
+pub unsafe fn _mm256_tanh_ps(d: __m256) -> __m256 {
+    let a = _mm256_abs_ps(d);
+    let e = _mm256_expm1_ps(_mm256_add_ps(a, a));
+    let mut r = _mm256_div_ps(e, _mm256_add_ps(e, _mm256_set1_ps(2f32)));
+    // tanh rounds to 1 long before expm1 overflows
+    r = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_GT_OS>(a, _mm256_set1_ps(10f32)),
+        _mm256_set1_ps(1f32),
+        r,
+    );
+    _mm256_copysign_ps(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tanhf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.5f32);
+            let comparison = _mm256_tanh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 0.5f32.tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-1e-10f32);
+            let comparison = _mm256_tanh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = (-1e-10f32).tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm256_set1_ps(30f32);
+            let comparison = _mm256_tanh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, 1.);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm256_set1_ps(-30f32);
+            let comparison = _mm256_tanh_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+    }
+}
@@ -0,0 +1,190 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::avx::expf::_mm256_expm1k_ps;
+use crate::avx::generalf::{_mm256_copysign_ps, _mm256_mul_doubled_ps, _mm256_sum_as_doubled_ps};
+use crate::avx::lnf::_mm256_lnk_doubled_ps;
+use crate::avx::sinf::_mm256_sinpik_ps;
+use crate::expf::{L2L_F, L2U_F, R_LN2_F};
+use crate::tgammaf::{
+    SQRT_2PI_LO_S, SQRT_2PI_S, SQRT_HALF_PI_LO_S, SQRT_HALF_PI_S, STIRLING_POLY_1_S,
+    STIRLING_POLY_2_S, STIRLING_POLY_3_S, STIRLING_POLY_4_S,
+};
+use crate::{
+    _mm256_abs_ps, _mm256_isnotintegral_ps, _mm256_ldexp2kq_ps, _mm256_mlaf_ps, _mm256_neg_ps,
+    _mm256_rint_ps, _mm256_select_ps,
+};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Computes lnΓ(w) - ln(sqrt(2pi)) as double-float for w >= 5
+#[inline(always)]
+pub(crate) unsafe fn _mm256_stirlingk_ps(w: (__m256, __m256)) -> (__m256, __m256) {
+    let l = _mm256_lnk_doubled_ps(w);
+    // (w - 0.5) ln(w) - w, w - 0.5 is exact below 2^23
+    let m = _mm256_mul_doubled_ps((_mm256_sub_ps(w.0, _mm256_set1_ps(0.5f32)), w.1), l);
+    let e = _mm256_sum_as_doubled_ps(m.0, _mm256_neg_ps(w.0));
+    let iw = _mm256_div_ps(_mm256_set1_ps(1f32), w.0);
+    let v = _mm256_mul_ps(iw, iw);
+    let mut u = _mm256_set1_ps(STIRLING_POLY_4_S);
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(STIRLING_POLY_3_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(STIRLING_POLY_2_S));
+    u = _mm256_mlaf_ps(u, v, _mm256_set1_ps(STIRLING_POLY_1_S));
+    (
+        e.0,
+        _mm256_add_ps(e.1, _mm256_mlaf_ps(iw, u, _mm256_sub_ps(m.1, w.1))),
+    )
+}
+
+/// Computes gamma function, error bound *ULP 3.5*, *ULP 2.0* for positive arguments
+#[inline(always)]
+pub unsafe fn _mm256_tgamma_ps(d: __m256) -> __m256 {
+    let reflect = _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_setzero_ps());
+    // Γ(d) = pi / (sin(pi d) Γ(1 - d)) for negative d, 1 - d is kept as double-float
+    let s = _mm256_sum_as_doubled_ps(_mm256_set1_ps(1f32), _mm256_neg_ps(d));
+    let z = (
+        _mm256_select_ps(reflect, s.0, d),
+        _mm256_select_ps(reflect, s.1, _mm256_setzero_ps()),
+    );
+    // Γ(z) = Γ(z + 5) / (z (z + 1) ... (z + 4)) moves the argument into Stirling range
+    let mut w = z;
+    let mut q = (_mm256_set1_ps(1f32), _mm256_setzero_ps());
+    for _ in 0..5 {
+        q = _mm256_mul_doubled_ps(q, w);
+        let s = _mm256_sum_as_doubled_ps(w.0, _mm256_set1_ps(1f32));
+        w = (s.0, _mm256_add_ps(s.1, w.1));
+    }
+    let shift = _mm256_cmp_ps::<_CMP_LT_OS>(z.0, _mm256_set1_ps(5f32));
+    let w = (
+        _mm256_select_ps(shift, w.0, z.0),
+        _mm256_select_ps(shift, w.1, z.1),
+    );
+    let q = (
+        _mm256_select_ps(shift, q.0, _mm256_set1_ps(1f32)),
+        _mm256_select_ps(shift, q.1, _mm256_setzero_ps()),
+    );
+    // ln(Γ(z)) - ln(sqrt(2pi)) = E - ln(q) with every low part kept, so nothing is divided afterwards
+    let e = _mm256_stirlingk_ps(w);
+    let l = _mm256_lnk_doubled_ps(q);
+    let h = _mm256_sum_as_doubled_ps(e.0, _mm256_neg_ps(l.0));
+    let lo = _mm256_add_ps(h.1, _mm256_sub_ps(e.1, l.1));
+    // Reflected lanes take exp of the negated exponent
+    let hi = _mm256_select_ps(reflect, _mm256_neg_ps(h.0), h.0);
+    let lo = _mm256_select_ps(reflect, _mm256_neg_ps(lo), lo);
+    let qi = _mm256_rint_ps(_mm256_mul_ps(hi, _mm256_set1_ps(R_LN2_F)));
+    let qf = _mm256_cvtepi32_ps(qi);
+    let mut r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-L2U_F), hi);
+    r = _mm256_mlaf_ps(qf, _mm256_set1_ps(-L2L_F), r);
+    r = _mm256_add_ps(r, lo);
+    let g = _mm256_select_ps(
+        reflect,
+        _mm256_set1_ps(SQRT_HALF_PI_S),
+        _mm256_set1_ps(SQRT_2PI_S),
+    );
+    let g_lo = _mm256_select_ps(
+        reflect,
+        _mm256_set1_ps(SQRT_HALF_PI_LO_S / SQRT_HALF_PI_S),
+        _mm256_set1_ps(SQRT_2PI_LO_S / SQRT_2PI_S),
+    );
+    let t = _mm256_expm1k_ps(_mm256_add_ps(r, g_lo));
+    let u = _mm256_mlaf_ps(g, t, g);
+    let sp = _mm256_sinpik_ps(d);
+    let mut ret = _mm256_ldexp2kq_ps(_mm256_select_ps(reflect, _mm256_div_ps(u, sp), u), qi);
+    // Γ(d) = 1/d - γ + O(d), past this point γ is below half an ulp of 1/d
+    ret = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(_mm256_abs_ps(d), _mm256_set1_ps(2.9802322e-8f32)),
+        _mm256_div_ps(_mm256_set1_ps(1f32), d),
+        ret,
+    );
+    // d > 35.1 overflows, also covers d == Inf
+    ret = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_GT_OS>(d, _mm256_set1_ps(35.1f32)),
+        _mm256_set1_ps(f32::INFINITY),
+        ret,
+    );
+    // |Γ(d)| is below the smallest subnormal
+    ret = _mm256_select_ps(
+        _mm256_cmp_ps::<_CMP_LT_OS>(d, _mm256_set1_ps(-45f32)),
+        _mm256_copysign_ps(_mm256_setzero_ps(), sp),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    _mm256_select_ps(
+        _mm256_andnot_ps(_mm256_isnotintegral_ps(d), reflect),
+        _mm256_set1_ps(f32::NAN),
+        ret,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tgammaf() {
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(0.5f32);
+            let comparison = _mm256_tgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 1.7724539f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(-1.5f32);
+            let comparison = _mm256_tgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 2.3632717f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm256_set1_ps(5.5f32);
+            let comparison = _mm256_tgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            let expected = 52.342777f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test exact value
+            let value = _mm256_set1_ps(5f32);
+            let comparison = _mm256_tgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, 24.);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm256_set1_ps(-2f32);
+            let comparison = _mm256_tgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm256_set1_ps(40f32);
+            let comparison = _mm256_tgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        unsafe {
+            // Test NaN
+            let value = _mm256_set1_ps(f32::NAN);
+            let comparison = _mm256_tgamma_ps(value);
+            let flag_1 = _mm256_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
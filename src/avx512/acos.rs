@@ -0,0 +1,60 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm512_asin_pd, _mm512_select_pd};
+
+/// Computes arccos, error bound *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm512_acos_pd(x: __m512d) -> __m512d {
+    let gt_zero = _mm512_cmp_pd_mask::<_CMP_GT_OQ>(x, _mm512_setzero_pd());
+    let x_a = _mm512_abs_pd(x);
+    let x_asin = _mm512_asin_pd(x_a);
+    let v_pi = _mm512_set1_pd(std::f64::consts::FRAC_PI_2);
+    _mm512_select_pd(
+        gt_zero,
+        _mm512_sub_pd(v_pi, x_asin),
+        _mm512_add_pd(v_pi, x_asin),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acosd() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_pd(0.3);
+            let comparison = _mm512_acos_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = 1.266103672779499f64;
+            assert!((flag_1 - control).abs() < 1e-15);
+        }
+        unsafe {
+            let value = _mm512_set1_pd(-0.7);
+            let comparison = _mm512_acos_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = 2.346193823405649f64;
+            assert!((flag_1 - control).abs() < 1e-15);
+        }
+
+        unsafe {
+            let value = _mm512_set1_pd(-2.);
+            let comparison = _mm512_acos_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
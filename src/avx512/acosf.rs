@@ -0,0 +1,60 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm512_asin_ps, _mm512_select_ps};
+
+/// Computes arccos, error bound *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm512_acos_ps(x: __m512) -> __m512 {
+    let gt_zero = _mm512_cmp_ps_mask::<_CMP_GT_OQ>(x, _mm512_setzero_ps());
+    let x_a = _mm512_abs_ps(x);
+    let x_asin = _mm512_asin_ps(x_a);
+    let v_pi = _mm512_set1_ps(std::f32::consts::FRAC_PI_2);
+    _mm512_select_ps(
+        gt_zero,
+        _mm512_sub_ps(v_pi, x_asin),
+        _mm512_add_ps(v_pi, x_asin),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acosf() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_ps(0.3);
+            let comparison = _mm512_acos_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = 1.2661036f32;
+            assert!((flag_1 - control).abs() < 1e-6);
+        }
+        unsafe {
+            let value = _mm512_set1_ps(-0.7);
+            let comparison = _mm512_acos_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = 2.3461938f32;
+            assert!((flag_1 - control).abs() < 1e-6);
+        }
+
+        unsafe {
+            let value = _mm512_set1_ps(-2.);
+            let comparison = _mm512_acos_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::asinf::{
+    ASIN_POLY_2_F, ASIN_POLY_3_F, ASIN_POLY_4_F, ASIN_POLY_5_F, ASIN_POLY_6_F, ASIN_POLY_7_F,
+    ASIN_POLY_8_F, ASIN_POLY_9_F,
+};
+use crate::{_mm512_copysign_ps, _mm512_eqzero_ps, _mm512_mlaf_ps, _mm512_select_ps};
+
+/// Computes arcsin, error bound *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm512_asin_ps(d: __m512) -> __m512 {
+    let ones = _mm512_set1_ps(1f32);
+    let ca = _mm512_abs_ps(d);
+    let nan_mask = _mm512_cmp_ps_mask::<_CMP_GT_OQ>(ca, ones);
+    // for more 0.5
+    let reverse_05_mask = _mm512_cmp_ps_mask::<_CMP_GE_OQ>(ca, _mm512_set1_ps(0.5f32));
+    let reversed = _mm512_sqrt_ps(_mm512_div_ps(_mm512_sub_ps(ones, ca), _mm512_set1_ps(2f32)));
+    let x = _mm512_select_ps(reverse_05_mask, reversed, ca);
+    let zeros_is_zeros = _mm512_eqzero_ps(d);
+    let mut u = _mm512_set1_ps(f32::from_bits(ASIN_POLY_9_F));
+    u = _mm512_mlaf_ps(u, x, _mm512_set1_ps(f32::from_bits(ASIN_POLY_8_F)));
+    u = _mm512_mlaf_ps(u, x, _mm512_set1_ps(f32::from_bits(ASIN_POLY_7_F)));
+    u = _mm512_mlaf_ps(u, x, _mm512_set1_ps(f32::from_bits(ASIN_POLY_6_F)));
+    u = _mm512_mlaf_ps(u, x, _mm512_set1_ps(f32::from_bits(ASIN_POLY_5_F)));
+    u = _mm512_mlaf_ps(u, x, _mm512_set1_ps(f32::from_bits(ASIN_POLY_4_F)));
+    u = _mm512_mlaf_ps(u, x, _mm512_set1_ps(f32::from_bits(ASIN_POLY_3_F)));
+    u = _mm512_mlaf_ps(u, x, _mm512_set1_ps(f32::from_bits(ASIN_POLY_2_F)));
+    u = _mm512_mul_ps(u, x);
+    let j = u;
+    let reconstruct_reversed = _mm512_mlaf_ps(
+        _mm512_set1_ps(-2f32),
+        j,
+        _mm512_set1_ps(std::f32::consts::FRAC_PI_2),
+    );
+    let mut ret = _mm512_select_ps(reverse_05_mask, reconstruct_reversed, j);
+    ret = _mm512_select_ps(nan_mask, _mm512_set1_ps(f32::NAN), ret);
+    ret = _mm512_select_ps(zeros_is_zeros, _mm512_set1_ps(0f32), ret);
+    _mm512_copysign_ps(ret, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asinf() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_ps(0.7);
+            let comparison = _mm512_asin_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = 0.7753975f32;
+            assert!((flag_1 - control).abs() < 1e-6);
+        }
+        unsafe {
+            let value = _mm512_set1_ps(-0.3);
+            let comparison = _mm512_asin_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = -0.30469266f32;
+            assert_eq!(flag_1, control);
+        }
+
+        unsafe {
+            let value = _mm512_set1_ps(-2f32);
+            let comparison = _mm512_asin_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
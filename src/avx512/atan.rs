@@ -0,0 +1,84 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::atan::{
+    ATAN_POLY_10_D, ATAN_POLY_11_D, ATAN_POLY_12_D, ATAN_POLY_13_D, ATAN_POLY_14_D, ATAN_POLY_15_D,
+    ATAN_POLY_16_D, ATAN_POLY_17_D, ATAN_POLY_18_D, ATAN_POLY_19_D, ATAN_POLY_1_D, ATAN_POLY_20_D,
+    ATAN_POLY_21_D, ATAN_POLY_2_D, ATAN_POLY_3_D, ATAN_POLY_4_D, ATAN_POLY_5_D, ATAN_POLY_6_D,
+    ATAN_POLY_7_D, ATAN_POLY_8_D, ATAN_POLY_9_D,
+};
+use crate::{_mm512_mlaf_pd, _mm512_select_pd};
+
+/// Computes Atan function with *ULP 2.0* error
+#[inline(always)]
+pub unsafe fn _mm512_atan_pd(x: __m512d) -> __m512d {
+    // Sign bit is restored at the end so that atan(-0) = -0
+    let sign = _mm512_and_si512(_mm512_castpd_si512(x), _mm512_set1_epi64(i64::MIN));
+    let d = _mm512_abs_pd(x);
+    let more_than_one_mask = _mm512_cmp_pd_mask::<_CMP_GE_OQ>(d, _mm512_set1_pd(1.));
+    let x = _mm512_select_pd(more_than_one_mask, _mm512_div_pd(_mm512_set1_pd(1.), d), d);
+    let x2 = _mm512_mul_pd(x, x);
+    let mut u = _mm512_set1_pd(ATAN_POLY_21_D);
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_20_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_19_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_18_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_17_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_16_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_15_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_14_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_13_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_12_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_11_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_10_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_9_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_8_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_7_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_6_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_5_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_4_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_3_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_2_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(ATAN_POLY_1_D));
+    u = _mm512_mul_pd(u, x);
+    u = _mm512_select_pd(
+        more_than_one_mask,
+        _mm512_sub_pd(_mm512_set1_pd(std::f64::consts::FRAC_PI_2), u),
+        u,
+    );
+    _mm512_castsi512_pd(_mm512_xor_si512(_mm512_castpd_si512(u), sign))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atand() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_pd(0.5);
+            let comparison = _mm512_atan_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = 0.4636476090008061f64;
+            assert!((flag_1 - control).abs() < 1e-15);
+        }
+        unsafe {
+            let value = _mm512_set1_pd(-2.);
+            let comparison = _mm512_atan_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = -1.1071487177940904f64;
+            assert!((flag_1 - control).abs() < 1e-15);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{
+    _mm512_atan_pd, _mm512_copysign_pd, _mm512_eqzero_pd, _mm512_isinf_pd, _mm512_select_pd,
+};
+
+/// Computes atan for Y,X
+#[inline(always)]
+pub unsafe fn _mm512_atan2_pd(y: __m512d, x: __m512d) -> __m512d {
+    let ay = _mm512_abs_pd(y);
+    let ax = _mm512_abs_pd(x);
+    let mut r = _mm512_atan_pd(_mm512_div_pd(ay, ax));
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    r = _mm512_select_pd(
+        _mm512_eqzero_pd(ay) & _mm512_eqzero_pd(ax),
+        _mm512_setzero_pd(),
+        r,
+    );
+    r = _mm512_select_pd(
+        _mm512_isinf_pd(ay) & _mm512_isinf_pd(ax),
+        _mm512_set1_pd(std::f64::consts::FRAC_PI_4),
+        r,
+    );
+    // Negative x, including -0, reflects the angle to the left half plane
+    let x_neg = _mm512_cmp_pd_mask::<_CMP_LT_OQ>(
+        _mm512_copysign_pd(_mm512_set1_pd(1.), x),
+        _mm512_setzero_pd(),
+    );
+    r = _mm512_select_pd(
+        x_neg,
+        _mm512_sub_pd(_mm512_set1_pd(std::f64::consts::PI), r),
+        r,
+    );
+    _mm512_copysign_pd(r, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atan2d() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let comparison = _mm512_atan2_pd(_mm512_set1_pd(-1.), _mm512_set1_pd(-2.));
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = (-1f64).atan2(-2.);
+            assert!((flag_1 - control).abs() < 1e-15);
+        }
+        unsafe {
+            let comparison = _mm512_atan2_pd(_mm512_set1_pd(-1.), _mm512_set1_pd(0.));
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            assert_eq!(flag_1, -std::f64::consts::FRAC_PI_2);
+        }
+        unsafe {
+            let comparison = _mm512_atan2_pd(_mm512_set1_pd(0.), _mm512_set1_pd(0.));
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{
+    _mm512_atan_ps, _mm512_copysign_ps, _mm512_eqzero_ps, _mm512_isinf_ps, _mm512_select_ps,
+};
+
+/// Computes atan for Y,X
+#[inline(always)]
+pub unsafe fn _mm512_atan2_ps(y: __m512, x: __m512) -> __m512 {
+    let ay = _mm512_abs_ps(y);
+    let ax = _mm512_abs_ps(x);
+    let mut r = _mm512_atan_ps(_mm512_div_ps(ay, ax));
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    r = _mm512_select_ps(
+        _mm512_eqzero_ps(ay) & _mm512_eqzero_ps(ax),
+        _mm512_setzero_ps(),
+        r,
+    );
+    r = _mm512_select_ps(
+        _mm512_isinf_ps(ay) & _mm512_isinf_ps(ax),
+        _mm512_set1_ps(std::f32::consts::FRAC_PI_4),
+        r,
+    );
+    // Negative x, including -0, reflects the angle to the left half plane
+    let x_neg = _mm512_cmp_ps_mask::<_CMP_LT_OQ>(
+        _mm512_copysign_ps(_mm512_set1_ps(1.), x),
+        _mm512_setzero_ps(),
+    );
+    r = _mm512_select_ps(
+        x_neg,
+        _mm512_sub_ps(_mm512_set1_ps(std::f32::consts::PI), r),
+        r,
+    );
+    _mm512_copysign_ps(r, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atan2f() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let comparison = _mm512_atan2_ps(_mm512_set1_ps(-1.), _mm512_set1_ps(-2.));
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = (-1f32).atan2(-2.);
+            assert!((flag_1 - control).abs() < 1e-6);
+        }
+        unsafe {
+            let comparison = _mm512_atan2_ps(_mm512_set1_ps(-1.), _mm512_set1_ps(0.));
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            assert_eq!(flag_1, -std::f32::consts::FRAC_PI_2);
+        }
+        unsafe {
+            let comparison = _mm512_atan2_ps(_mm512_set1_ps(0.), _mm512_set1_ps(0.));
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::atanf::{
+    ATAN_POLY_1_F, ATAN_POLY_2_F, ATAN_POLY_3_F, ATAN_POLY_4_F, ATAN_POLY_5_F, ATAN_POLY_6_F,
+    ATAN_POLY_7_F, ATAN_POLY_8_F, ATAN_POLY_9_F,
+};
+use crate::{_mm512_mlaf_ps, _mm512_select_ps};
+
+/// Computes Atan function with *ULP 1.0* error
+#[inline(always)]
+pub unsafe fn _mm512_atan_ps(x: __m512) -> __m512 {
+    // Sign bit is restored at the end so that atan(-0) = -0
+    let sign = _mm512_and_si512(_mm512_castps_si512(x), _mm512_set1_epi32(i32::MIN));
+    let d = _mm512_abs_ps(x);
+    let more_than_one_mask = _mm512_cmp_ps_mask::<_CMP_GE_OQ>(d, _mm512_set1_ps(1f32));
+    let x = _mm512_select_ps(
+        more_than_one_mask,
+        _mm512_div_ps(_mm512_set1_ps(1f32), d),
+        d,
+    );
+    let x2 = _mm512_mul_ps(x, x);
+    let mut u = _mm512_set1_ps(ATAN_POLY_9_F);
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(ATAN_POLY_8_F));
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(ATAN_POLY_7_F));
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(ATAN_POLY_6_F));
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(ATAN_POLY_5_F));
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(ATAN_POLY_4_F));
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(ATAN_POLY_3_F));
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(ATAN_POLY_2_F));
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(ATAN_POLY_1_F));
+    u = _mm512_mul_ps(u, x);
+    u = _mm512_select_ps(
+        more_than_one_mask,
+        _mm512_sub_ps(_mm512_set1_ps(std::f32::consts::FRAC_PI_2), u),
+        u,
+    );
+    _mm512_castsi512_ps(_mm512_xor_si512(_mm512_castps_si512(u), sign))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atanf() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_ps(0.5f32);
+            let comparison = _mm512_atan_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = 0.4636476f32;
+            assert!((flag_1 - control).abs() < 1e-7);
+        }
+        unsafe {
+            let value = _mm512_set1_ps(-2f32);
+            let comparison = _mm512_atan_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = -1.1071488f32;
+            assert_eq!(flag_1, control);
+        }
+    }
+}
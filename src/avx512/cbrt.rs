@@ -0,0 +1,135 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::cbrt::{
+    CBRT_HUGE_D, CBRT_HUGE_SCALE_D, CBRT_HUGE_SCALE_R_D, CBRT_SCALE_D, CBRT_SCALE_R_D,
+};
+use crate::{_mm512_eqzero_pd, _mm512_isinf_pd, _mm512_mlaf_pd, _mm512_select_pd};
+
+#[inline(always)]
+unsafe fn halley_cbrt(x: __m512d, a: __m512d) -> __m512d {
+    let tx = _mm512_mul_pd(_mm512_mul_pd(x, x), x);
+    let twos = _mm512_set1_pd(2.);
+    let num = _mm512_mlaf_pd(twos, a, tx);
+    let den = _mm512_mlaf_pd(twos, tx, a);
+    let scale = _mm512_div_pd(num, den);
+    _mm512_mul_pd(x, scale)
+}
+
+/// Newton step from t rounded to 22 bits, t*t is exact so the result is within 0.67 ulp
+#[inline(always)]
+unsafe fn newton_round(t: __m512d, a: __m512d) -> __m512d {
+    let t = _mm512_castsi512_pd(_mm512_and_si512(
+        _mm512_add_epi64(_mm512_castpd_si512(t), _mm512_set1_epi64(0x8000_0000)),
+        _mm512_set1_epi64(0xffff_ffff_c000_0000u64 as i64),
+    ));
+    let s = _mm512_mul_pd(t, t);
+    let r = _mm512_div_pd(a, s);
+    let w = _mm512_add_pd(t, t);
+    let r = _mm512_div_pd(_mm512_sub_pd(r, t), _mm512_add_pd(w, r));
+    _mm512_add_pd(t, _mm512_mul_pd(t, r))
+}
+
+#[inline(always)]
+unsafe fn integer_pow_1_3(hx: __m512i) -> __m512i {
+    // hx / 3 as (hx * 0xAAAAAAAB) >> 33, exact for every 31-bit high word
+    let third = _mm512_srli_epi64::<33>(_mm512_mul_epu32(hx, _mm512_set1_epi64(0xAAAAAAAB)));
+    _mm512_add_epi64(third, _mm512_set1_epi64(715094163))
+}
+
+/// Takes cube root from value *ULP 1.5*, Skipping NaN, Inf checks
+#[inline(always)]
+pub unsafe fn _mm512_cbrt_fast_pd(x: __m512d) -> __m512d {
+    let mut ui = _mm512_castpd_si512(x);
+    let hx = _mm512_and_si512(_mm512_srli_epi64::<32>(ui), _mm512_set1_epi64(0x7fffffff));
+
+    let hx = integer_pow_1_3(hx);
+
+    ui = _mm512_and_si512(ui, _mm512_set1_epi64(i64::MIN));
+    ui = _mm512_or_si512(ui, _mm512_slli_epi64::<32>(hx));
+
+    let t = _mm512_castsi512_pd(ui);
+
+    let c0 = halley_cbrt(t, x);
+    let c1 = halley_cbrt(c0, x);
+    let c2 = halley_cbrt(c1, x);
+    _mm512_select_pd(_mm512_eqzero_pd(x), x, newton_round(c2, x))
+}
+
+/// Takes cube root from value *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm512_cbrt_pd(x: __m512d) -> __m512d {
+    // Subnormals and the top binades are scaled by powers of 8, the initial guess
+    // and x^3 in Halley step are out of range for them
+    let ax = _mm512_abs_pd(x);
+    let tiny = _mm512_cmp_pd_mask::<_CMP_LT_OQ>(ax, _mm512_set1_pd(f64::MIN_POSITIVE));
+    let huge = _mm512_cmp_pd_mask::<_CMP_GT_OQ>(ax, _mm512_set1_pd(CBRT_HUGE_D));
+    let scale_in = _mm512_select_pd(
+        tiny,
+        _mm512_set1_pd(CBRT_SCALE_D),
+        _mm512_select_pd(huge, _mm512_set1_pd(CBRT_HUGE_SCALE_D), _mm512_set1_pd(1.)),
+    );
+    let scale_out = _mm512_select_pd(
+        tiny,
+        _mm512_set1_pd(1. / CBRT_SCALE_R_D),
+        _mm512_select_pd(
+            huge,
+            _mm512_set1_pd(CBRT_HUGE_SCALE_R_D),
+            _mm512_set1_pd(1.),
+        ),
+    );
+    let c1 = _mm512_mul_pd(_mm512_cbrt_fast_pd(_mm512_mul_pd(x, scale_in)), scale_out);
+    _mm512_select_pd(_mm512_isinf_pd(x), x, c1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cbrtd() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_setr_pd(27., -8., 0.5, 1000., 2e-300, 3., 4e200, -5.);
+            let comparison = _mm512_cbrt_pd(value);
+            let mut dst = [0f64; 8];
+            _mm512_storeu_pd(dst.as_mut_ptr(), comparison);
+            let mut src = [0f64; 8];
+            _mm512_storeu_pd(src.as_mut_ptr(), value);
+            for (&v, &c) in dst.iter().zip(src.iter()) {
+                let control = c.cbrt();
+                assert!(((v - control) / control).abs() < 1e-14);
+            }
+        }
+        unsafe {
+            // Top binades, every lane is scaled separately
+            let value = _mm512_setr_pd(1e300, -1e307, f64::MAX, 1e290, 27., -5e301, 1e305, 0.5);
+            let comparison = _mm512_cbrt_pd(value);
+            let mut dst = [0f64; 8];
+            _mm512_storeu_pd(dst.as_mut_ptr(), comparison);
+            let mut src = [0f64; 8];
+            _mm512_storeu_pd(src.as_mut_ptr(), value);
+            for (&v, &c) in dst.iter().zip(src.iter()) {
+                let control = c.cbrt();
+                assert!(((v - control) / control).abs() <= 2. * f64::EPSILON);
+            }
+        }
+        unsafe {
+            let value = _mm512_set1_pd(f64::NEG_INFINITY);
+            let comparison = _mm512_cbrt_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::cbrtf::{CBRT_HUGE_F, CBRT_SCALE_F, CBRT_SCALE_R_F};
+use crate::{_mm512_eqzero_ps, _mm512_isinf_ps, _mm512_mlaf_ps, _mm512_select_ps};
+
+#[inline(always)]
+unsafe fn halley_cbrt(x: __m512, a: __m512) -> __m512 {
+    let tx = _mm512_mul_ps(_mm512_mul_ps(x, x), x);
+    let twos = _mm512_set1_ps(2f32);
+    let num = _mm512_mlaf_ps(twos, a, tx);
+    let den = _mm512_mlaf_ps(twos, tx, a);
+    let scale = _mm512_div_ps(num, den);
+    _mm512_mul_ps(x, scale)
+}
+
+#[inline(always)]
+unsafe fn integer_pow_1_3(hx: __m512i) -> __m512i {
+    let scale = _mm512_set1_epi64(341);
+    // Even and odd lanes are widened separately, products fit into 32 bits after shift
+    let even = _mm512_srli_epi64::<10>(_mm512_mul_epu32(hx, scale));
+    let odd = _mm512_srli_epi64::<10>(_mm512_mul_epu32(_mm512_srli_epi64::<32>(hx), scale));
+    _mm512_or_si512(even, _mm512_slli_epi64::<32>(odd))
+}
+
+/// Takes cube root from value *ULP 1.5*, Skipping NaN, Inf checks
+#[inline(always)]
+pub unsafe fn _mm512_cbrt_fast_ps(x: __m512) -> __m512 {
+    let mut ui = _mm512_castps_si512(x);
+    let hx = _mm512_and_si512(ui, _mm512_set1_epi32(0x7fffffff));
+
+    let hx = _mm512_add_epi32(integer_pow_1_3(hx), _mm512_set1_epi32(709958130));
+
+    ui = _mm512_and_si512(ui, _mm512_set1_epi32(i32::MIN));
+    ui = _mm512_or_si512(ui, hx);
+
+    let t = _mm512_castsi512_ps(ui);
+
+    let c0 = halley_cbrt(t, x);
+    let c1 = halley_cbrt(c0, x);
+    _mm512_select_ps(_mm512_eqzero_ps(x), x, c1)
+}
+
+/// Takes cube root from value *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm512_cbrt_ps(x: __m512) -> __m512 {
+    // Subnormals and the top binades are scaled by powers of 8, the initial guess
+    // and x^3 in Halley step are out of range for them
+    let ax = _mm512_abs_ps(x);
+    let tiny = _mm512_cmp_ps_mask::<_CMP_LT_OQ>(ax, _mm512_set1_ps(f32::MIN_POSITIVE));
+    let huge = _mm512_cmp_ps_mask::<_CMP_GT_OQ>(ax, _mm512_set1_ps(CBRT_HUGE_F));
+    let scale_in = _mm512_select_ps(
+        tiny,
+        _mm512_set1_ps(CBRT_SCALE_F),
+        _mm512_select_ps(huge, _mm512_set1_ps(1. / CBRT_SCALE_F), _mm512_set1_ps(1.)),
+    );
+    let scale_out = _mm512_select_ps(
+        tiny,
+        _mm512_set1_ps(1. / CBRT_SCALE_R_F),
+        _mm512_select_ps(huge, _mm512_set1_ps(CBRT_SCALE_R_F), _mm512_set1_ps(1.)),
+    );
+    let c1 = _mm512_mul_ps(_mm512_cbrt_fast_ps(_mm512_mul_ps(x, scale_in)), scale_out);
+    _mm512_select_ps(_mm512_isinf_ps(x), x, c1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cbrtf() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_setr_ps(
+                27., -8., 0.5, 1000., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11., 12., 13.,
+            );
+            let comparison = _mm512_cbrt_ps(value);
+            let mut dst = [0f32; 16];
+            _mm512_storeu_ps(dst.as_mut_ptr(), comparison);
+            let mut src = [0f32; 16];
+            _mm512_storeu_ps(src.as_mut_ptr(), value);
+            for (&v, &c) in dst.iter().zip(src.iter()) {
+                assert!((v - c.cbrt()).abs() < 1e-5);
+            }
+        }
+        unsafe {
+            let value = _mm512_set1_ps(f32::NEG_INFINITY);
+            let comparison = _mm512_cbrt_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx512::generalf::_mm512_rempi_ps;
+use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2_oddf;
+use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+use crate::{_mm512_eqzero_ps, _mm512_mlaf_ps, _mm512_neg_ps, _mm512_rint_ps, _mm512_select_ps};
+
+#[inline(always)]
+/// Computes cosine function with error bound *ULP 2.5*
+pub unsafe fn _mm512_cos_ps(d: __m512) -> __m512 {
+    let q = _mm512_add_epi32(
+        _mm512_set1_epi32(1),
+        _mm512_slli_epi32::<1>(_mm512_rint_ps(_mm512_sub_ps(
+            _mm512_mul_ps(d, _mm512_set1_ps(std::f32::consts::FRAC_1_PI)),
+            _mm512_set1_ps(0.5f32),
+        ))),
+    );
+    let qf = _mm512_cvtepi32_ps(q);
+
+    let mut r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-PI_A_F * 0.5), d);
+    r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-PI_B_F * 0.5), r);
+    r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-PI_C_F * 0.5), r);
+    r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-PI_D_F * 0.5), r);
+    let (q, mut r) = _mm512_rempi_ps(d, q, r, rempi2_oddf);
+
+    let x2 = _mm512_mul_ps(r, r);
+
+    let is_zero_bit = _mm512_testn_epi32_mask(q, _mm512_set1_epi32(2));
+    r = _mm512_select_ps(is_zero_bit, _mm512_neg_ps(r), r);
+    let mut res = _mm512_set1_ps(SIN_POLY_5_S);
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(SIN_POLY_4_S));
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(SIN_POLY_3_S));
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(SIN_POLY_2_S));
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(SIN_POLY_1_S));
+    res = _mm512_mlaf_ps(res, _mm512_mul_ps(x2, r), r);
+    // cos(±0) = 1
+    _mm512_select_ps(_mm512_eqzero_ps(d), _mm512_set1_ps(1.), res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosf() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_ps(0f32);
+            let comparison = _mm512_cos_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            assert!((flag_1 - 1f32).abs() < 1e-7);
+        }
+
+        unsafe {
+            let value = _mm512_set1_ps(2f32);
+            let comparison = _mm512_cos_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = -0.41614684f32;
+            assert!((flag_1 - control).abs() < 1e-7);
+        }
+    }
+
+    #[test]
+    fn test_cosf_large() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        for x in [1e4f32, -1e22, 3.0e38] {
+            let value = unsafe { _mm512_cvtss_f32(_mm512_cos_ps(_mm512_set1_ps(x))) };
+            let control = (x as f64).cos() as f32;
+            assert!((value - control).abs() <= 2. * f32::EPSILON * control.abs());
+        }
+    }
+}
@@ -0,0 +1,103 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::exp::{
+    EXP_POLY_10_D, EXP_POLY_1_D, EXP_POLY_2_D, EXP_POLY_3_D, EXP_POLY_4_D, EXP_POLY_5_D,
+    EXP_POLY_6_D, EXP_POLY_7_D, EXP_POLY_8_D, EXP_POLY_9_D, L2_L, L2_U, R_LN2,
+};
+use crate::{
+    _mm512_ldexp2k_pd, _mm512_mlaf_pd, _mm512_pow2i_epi64, _mm512_rint_pd, _mm512_select_pd,
+};
+
+/// Computes `exp(r) - 1` for reduced argument |r| <= ln(2)/2
+#[inline(always)]
+pub(crate) unsafe fn _mm512_expm1k_pd(r: __m512d) -> __m512d {
+    let f = _mm512_mul_pd(r, r);
+    let mut u = _mm512_set1_pd(EXP_POLY_10_D);
+    u = _mm512_mlaf_pd(u, f, _mm512_set1_pd(EXP_POLY_9_D));
+    u = _mm512_mlaf_pd(u, f, _mm512_set1_pd(EXP_POLY_8_D));
+    u = _mm512_mlaf_pd(u, f, _mm512_set1_pd(EXP_POLY_7_D));
+    u = _mm512_mlaf_pd(u, f, _mm512_set1_pd(EXP_POLY_6_D));
+    u = _mm512_mlaf_pd(u, f, _mm512_set1_pd(EXP_POLY_5_D));
+    u = _mm512_mlaf_pd(u, f, _mm512_set1_pd(EXP_POLY_4_D));
+    u = _mm512_mlaf_pd(u, f, _mm512_set1_pd(EXP_POLY_3_D));
+    u = _mm512_mlaf_pd(u, f, _mm512_set1_pd(EXP_POLY_2_D));
+    u = _mm512_mlaf_pd(u, f, _mm512_set1_pd(EXP_POLY_1_D));
+    _mm512_div_pd(_mm512_mul_pd(r, _mm512_set1_pd(2.)), _mm512_sub_pd(u, r))
+}
+
+/// Computes exp for an argument *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm512_exp_pd(d: __m512d) -> __m512d {
+    let q = _mm512_rint_pd(_mm512_mul_pd(d, _mm512_set1_pd(R_LN2)));
+    let qf = _mm512_cvtepi64_pd(q);
+    let mut r = _mm512_mlaf_pd(qf, _mm512_set1_pd(-L2_U), d);
+    r = _mm512_mlaf_pd(qf, _mm512_set1_pd(-L2_L), r);
+    // Two step scaling keeps subnormal results and the last binade before overflow
+    r = _mm512_ldexp2k_pd(_mm512_add_pd(_mm512_expm1k_pd(r), _mm512_set1_pd(1.)), q);
+    r = _mm512_select_pd(
+        _mm512_cmp_pd_mask::<_CMP_LT_OQ>(d, _mm512_set1_pd(-746f64)),
+        _mm512_setzero_pd(),
+        r,
+    );
+    r = _mm512_select_pd(
+        _mm512_cmp_pd_mask::<_CMP_GT_OQ>(d, _mm512_set1_pd(710f64)),
+        _mm512_set1_pd(f64::INFINITY),
+        r,
+    );
+    r
+}
+
+/// Method that computes exp skipping Inf, Nan checks error bound *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm512_exp_fast_pd(d: __m512d) -> __m512d {
+    let q = _mm512_rint_pd(_mm512_mul_pd(d, _mm512_set1_pd(R_LN2)));
+    let qf = _mm512_cvtepi64_pd(q);
+    /* exp(x) = 2^i * exp(f); */
+    let mut r = _mm512_mlaf_pd(qf, _mm512_set1_pd(-L2_U), d);
+    r = _mm512_mlaf_pd(qf, _mm512_set1_pd(-L2_L), r);
+    let u = _mm512_add_pd(_mm512_expm1k_pd(r), _mm512_set1_pd(1.));
+    let i2 = _mm512_castsi512_pd(_mm512_pow2i_epi64(q));
+    _mm512_mul_pd(u, i2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expd() {
+        if !std::arch::is_x86_feature_detected!("avx512dq") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_pd(2.);
+            let comparison = _mm512_exp_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = 7.38905609893065f64;
+            assert!((flag_1 - control).abs() < 1e-14);
+        }
+        unsafe {
+            let value = _mm512_set1_pd(-1.5);
+            let comparison = _mm512_exp_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = 0.22313016014842982f64;
+            assert!((flag_1 - control).abs() < 1e-15);
+        }
+        unsafe {
+            let value = _mm512_set1_pd(1000.);
+            let comparison = _mm512_exp_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::expf::{
+    EXP_POLY_1_S, EXP_POLY_2_S, EXP_POLY_3_S, EXP_POLY_4_S, EXP_POLY_5_S, L2L_F, L2U_F,
+};
+use crate::{
+    _mm512_ldexp2k_ps, _mm512_mlaf_ps, _mm512_pow2if_epi32, _mm512_rint_ps, _mm512_select_ps,
+};
+
+/// Computes `exp(r) - 1` for reduced argument |r| <= ln(2)/2
+#[inline(always)]
+pub(crate) unsafe fn _mm512_expm1k_ps(r: __m512) -> __m512 {
+    let f = _mm512_mul_ps(r, r);
+    let mut u = _mm512_set1_ps(EXP_POLY_5_S);
+    u = _mm512_mlaf_ps(u, f, _mm512_set1_ps(EXP_POLY_4_S));
+    u = _mm512_mlaf_ps(u, f, _mm512_set1_ps(EXP_POLY_3_S));
+    u = _mm512_mlaf_ps(u, f, _mm512_set1_ps(EXP_POLY_2_S));
+    u = _mm512_mlaf_ps(u, f, _mm512_set1_ps(EXP_POLY_1_S));
+    _mm512_div_ps(_mm512_mul_ps(r, _mm512_set1_ps(2f32)), _mm512_sub_ps(u, r))
+}
+
+/// Computes exp for an argument *ULP 1.0*
+#[inline(always)]
+pub unsafe fn _mm512_exp_ps(d: __m512) -> __m512 {
+    let q = _mm512_rint_ps(_mm512_mul_ps(d, _mm512_set1_ps(std::f32::consts::LOG2_E)));
+    let qf = _mm512_cvtepi32_ps(q);
+    let mut r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-L2U_F), d);
+    r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-L2L_F), r);
+    // Two step scaling keeps subnormal results and the last binade before overflow
+    r = _mm512_ldexp2k_ps(_mm512_add_ps(_mm512_expm1k_ps(r), _mm512_set1_ps(1f32)), q);
+    r = _mm512_select_ps(
+        _mm512_cmp_ps_mask::<_CMP_LT_OQ>(d, _mm512_set1_ps(-104f32)),
+        _mm512_setzero_ps(),
+        r,
+    );
+    r = _mm512_select_ps(
+        _mm512_cmp_ps_mask::<_CMP_GT_OQ>(d, _mm512_set1_ps(89f32)),
+        _mm512_set1_ps(f32::INFINITY),
+        r,
+    );
+    r
+}
+
+/// Method that computes exp skipping Inf, Nan checks error bound *ULP 1.0*
+#[inline(always)]
+pub unsafe fn _mm512_exp_fast_ps(d: __m512) -> __m512 {
+    let q = _mm512_rint_ps(_mm512_mul_ps(d, _mm512_set1_ps(std::f32::consts::LOG2_E)));
+    let qf = _mm512_cvtepi32_ps(q);
+    /* exp(x) = 2^i * exp(f); */
+    let mut r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-L2U_F), d);
+    r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-L2L_F), r);
+    let u = _mm512_add_ps(_mm512_expm1k_ps(r), _mm512_set1_ps(1f32));
+    let i2 = _mm512_castsi512_ps(_mm512_pow2if_epi32(q));
+    _mm512_mul_ps(u, i2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expf() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_ps(2f32);
+            let comparison = _mm512_exp_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = 7.389056f32;
+            assert!((flag_1 - control).abs() < 1e-5);
+        }
+        unsafe {
+            let value = _mm512_set1_ps(100f32);
+            let comparison = _mm512_exp_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
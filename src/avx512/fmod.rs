@@ -0,0 +1,95 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx512::general::{
+    _mm512_copysign_pd, _mm512_ilogb2k_pd, _mm512_isinf_pd, _mm512_ldexp3k_pd, _mm512_select_pd,
+};
+use crate::fmod::do_fmod;
+
+#[inline(always)]
+/// Computes fmod for f64, result is exact and has the sign of `a`
+pub unsafe fn _mm512_fmod_pd(a: __m512d, b: __m512d) -> __m512d {
+    let ax = _mm512_abs_pd(a);
+    let ay = _mm512_abs_pd(b);
+    let nan_mask: __mmask8 = _mm512_cmp_pd_mask::<_CMP_UNORD_Q>(a, b)
+        | _mm512_isinf_pd(a)
+        | _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(b, _mm512_setzero_pd());
+    // Subnormal divisors and exponent differences above 64 are left to the scalar routine
+    let fallback = !nan_mask
+        & (_mm512_cmp_pd_mask::<_CMP_LT_OQ>(ay, _mm512_set1_pd(f64::MIN_POSITIVE))
+            | _mm512_cmp_pd_mask::<_CMP_GT_OQ>(
+                ax,
+                _mm512_mul_pd(ay, _mm512_set1_pd(18446744073709551616.)),
+            ));
+    if fallback != 0 {
+        let mut xs = [0f64; 8];
+        let mut ys = [0f64; 8];
+        _mm512_storeu_pd(xs.as_mut_ptr(), a);
+        _mm512_storeu_pd(ys.as_mut_ptr(), b);
+        for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+            *x = do_fmod(*x, y);
+        }
+        return _mm512_loadu_pd(xs.as_ptr());
+    }
+    let mut rem = ax;
+    let mut work = !nan_mask & _mm512_cmp_pd_mask::<_CMP_GE_OQ>(rem, ay);
+    while work != 0 {
+        // Largest |b|*2^k not above the remainder, subtraction is exact by Sterbenz lemma
+        let k = _mm512_sub_epi64(_mm512_ilogb2k_pd(rem), _mm512_ilogb2k_pd(ay));
+        let mut t = _mm512_ldexp3k_pd(ay, k);
+        t = _mm512_select_pd(
+            _mm512_cmp_pd_mask::<_CMP_GT_OQ>(t, rem),
+            _mm512_mul_pd(t, _mm512_set1_pd(0.5)),
+            t,
+        );
+        rem = _mm512_select_pd(work, _mm512_sub_pd(rem, t), rem);
+        work &= _mm512_cmp_pd_mask::<_CMP_GE_OQ>(rem, ay);
+    }
+    let ret = _mm512_copysign_pd(rem, a);
+    _mm512_select_pd(nan_mask, _mm512_set1_pd(f64::NAN), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmodd() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        let values = [
+            (7., 2.),
+            (5.5, 2.5),
+            (-7., 2.),
+            (7., -2.),
+            (1e300, 3.),
+            (-1e300, 1e-300),
+            (3.5e-310, 1e-315),
+            (0.1, 1e308),
+            (-0., 3.),
+        ];
+        for (x, y) in values {
+            unsafe {
+                let comparison = _mm512_fmod_pd(_mm512_set1_pd(x), _mm512_set1_pd(y));
+                let flag_1 = _mm512_cvtsd_f64(comparison);
+                assert_eq!(flag_1.to_bits(), (x % y).to_bits());
+            }
+        }
+        unsafe {
+            let comparison = _mm512_fmod_pd(_mm512_set1_pd(f64::INFINITY), _mm512_set1_pd(2.));
+            assert!(_mm512_cvtsd_f64(comparison).is_nan());
+            let comparison = _mm512_fmod_pd(_mm512_set1_pd(2.), _mm512_set1_pd(f64::INFINITY));
+            assert_eq!(_mm512_cvtsd_f64(comparison), 2.);
+        }
+    }
+}
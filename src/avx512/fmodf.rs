@@ -0,0 +1,92 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx512::generalf::{
+    _mm512_copysign_ps, _mm512_ilogb2k_ps, _mm512_isinf_ps, _mm512_ldexp3k_ps, _mm512_select_ps,
+};
+use crate::fmodf::do_fmodf;
+
+#[inline(always)]
+/// Computes fmod for f32, result is exact and has the sign of `a`
+pub unsafe fn _mm512_fmod_ps(a: __m512, b: __m512) -> __m512 {
+    let ax = _mm512_abs_ps(a);
+    let ay = _mm512_abs_ps(b);
+    let nan_mask: __mmask16 = _mm512_cmp_ps_mask::<_CMP_UNORD_Q>(a, b)
+        | _mm512_isinf_ps(a)
+        | _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(b, _mm512_setzero_ps());
+    // Subnormal divisors and exponent differences above 32 are left to the scalar routine
+    let fallback = !nan_mask
+        & (_mm512_cmp_ps_mask::<_CMP_LT_OQ>(ay, _mm512_set1_ps(f32::MIN_POSITIVE))
+            | _mm512_cmp_ps_mask::<_CMP_GT_OQ>(ax, _mm512_mul_ps(ay, _mm512_set1_ps(4294967296.))));
+    if fallback != 0 {
+        let mut xs = [0f32; 16];
+        let mut ys = [0f32; 16];
+        _mm512_storeu_ps(xs.as_mut_ptr(), a);
+        _mm512_storeu_ps(ys.as_mut_ptr(), b);
+        for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+            *x = do_fmodf(*x, y);
+        }
+        return _mm512_loadu_ps(xs.as_ptr());
+    }
+    let mut rem = ax;
+    let mut work = !nan_mask & _mm512_cmp_ps_mask::<_CMP_GE_OQ>(rem, ay);
+    while work != 0 {
+        // Largest |b|*2^k not above the remainder, subtraction is exact by Sterbenz lemma
+        let k = _mm512_sub_epi32(_mm512_ilogb2k_ps(rem), _mm512_ilogb2k_ps(ay));
+        let mut t = _mm512_ldexp3k_ps(ay, k);
+        t = _mm512_select_ps(
+            _mm512_cmp_ps_mask::<_CMP_GT_OQ>(t, rem),
+            _mm512_mul_ps(t, _mm512_set1_ps(0.5)),
+            t,
+        );
+        rem = _mm512_select_ps(work, _mm512_sub_ps(rem, t), rem);
+        work &= _mm512_cmp_ps_mask::<_CMP_GE_OQ>(rem, ay);
+    }
+    let ret = _mm512_copysign_ps(rem, a);
+    _mm512_select_ps(nan_mask, _mm512_set1_ps(f32::NAN), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmodf() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        let values = [
+            (7., 2.),
+            (5.5, 2.5),
+            (-7., 2.),
+            (7., -2.),
+            (1e38, 3.),
+            (-1e38, 1e-38),
+            (3.5e-40, 1e-44),
+            (0.1, 1e38),
+            (-0., 3.),
+        ];
+        for (x, y) in values {
+            unsafe {
+                let comparison = _mm512_fmod_ps(_mm512_set1_ps(x), _mm512_set1_ps(y));
+                let flag_1 = _mm512_cvtss_f32(comparison);
+                assert_eq!(flag_1.to_bits(), (x % y).to_bits());
+            }
+        }
+        unsafe {
+            let comparison = _mm512_fmod_ps(_mm512_set1_ps(f32::INFINITY), _mm512_set1_ps(2.));
+            assert!(_mm512_cvtss_f32(comparison).is_nan());
+            let comparison = _mm512_fmod_ps(_mm512_set1_ps(2.), _mm512_set1_ps(f32::INFINITY));
+            assert_eq!(_mm512_cvtss_f32(comparison), 2.);
+        }
+    }
+}
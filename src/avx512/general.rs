@@ -0,0 +1,178 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::generalf::PAYNE_HANEK_THRESHOLD;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline(always)]
+/// Computes `a*b + c`
+pub unsafe fn _mm512_mlaf_pd(a: __m512d, b: __m512d, c: __m512d) -> __m512d {
+    _mm512_fmadd_pd(a, b, c)
+}
+
+#[inline(always)]
+/// Rounds and takes integral part 64 bytes from double, requires *avx512dq*
+pub unsafe fn _mm512_rint_pd(f: __m512d) -> __m512i {
+    _mm512_cvt_roundpd_epi64::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(f)
+}
+
+#[inline(always)]
+/// Computes 2^n in f64 form for signed 64 bits integers, returns f64 in bits
+pub unsafe fn _mm512_pow2i_epi64(n: __m512i) -> __m512i {
+    _mm512_slli_epi64::<52>(_mm512_add_epi64(n, _mm512_set1_epi64(0x3ff)))
+}
+
+#[inline(always)]
+/// Computes `x*2^n` with two scalings, so `n` may reach subnormal and near overflow exponents
+pub unsafe fn _mm512_ldexp2k_pd(x: __m512d, n: __m512i) -> __m512d {
+    let m = _mm512_srai_epi64::<1>(n);
+    let u = _mm512_mul_pd(x, _mm512_castsi512_pd(_mm512_pow2i_epi64(m)));
+    _mm512_mul_pd(
+        u,
+        _mm512_castsi512_pd(_mm512_pow2i_epi64(_mm512_sub_epi64(n, m))),
+    )
+}
+
+#[inline(always)]
+/// If mask then `true_vals` otherwise `false_val`
+pub unsafe fn _mm512_select_pd(mask: __mmask8, true_vals: __m512d, false_vals: __m512d) -> __m512d {
+    _mm512_mask_blend_pd(mask, false_vals, true_vals)
+}
+
+#[inline(always)]
+/// Returns flag value is zero
+pub unsafe fn _mm512_eqzero_pd(d: __m512d) -> __mmask8 {
+    _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(d, _mm512_setzero_pd())
+}
+
+#[inline(always)]
+/// Returns flag value is lower than zero
+pub unsafe fn _mm512_ltzero_pd(d: __m512d) -> __mmask8 {
+    _mm512_cmp_pd_mask::<_CMP_LT_OQ>(d, _mm512_setzero_pd())
+}
+
+#[inline(always)]
+/// Returns flag value is Infinity
+pub unsafe fn _mm512_isinf_pd(d: __m512d) -> __mmask8 {
+    _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(_mm512_abs_pd(d), _mm512_set1_pd(f64::INFINITY))
+}
+
+#[inline(always)]
+/// Returns flag value is Neg Infinity
+pub unsafe fn _mm512_isneginf_pd(d: __m512d) -> __mmask8 {
+    _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(d, _mm512_set1_pd(f64::NEG_INFINITY))
+}
+
+#[inline(always)]
+/// Returns true flag if value is NaN
+pub unsafe fn _mm512_isnan_pd(d: __m512d) -> __mmask8 {
+    _mm512_cmp_pd_mask::<_CMP_UNORD_Q>(d, d)
+}
+
+#[inline(always)]
+/// Checks if arguments is not integral value
+pub unsafe fn _mm512_isnotintegral_pd(d: __m512d) -> __mmask8 {
+    _mm512_cmp_pd_mask::<_CMP_NEQ_OQ>(
+        d,
+        _mm512_roundscale_pd::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(d),
+    )
+}
+
+#[inline(always)]
+/// Negates value
+pub unsafe fn _mm512_neg_pd(f: __m512d) -> __m512d {
+    _mm512_castsi512_pd(_mm512_xor_si512(
+        _mm512_castpd_si512(f),
+        _mm512_set1_epi64(i64::MIN),
+    ))
+}
+
+#[inline(always)]
+/// Copies sign from `y` to `x`
+pub unsafe fn _mm512_copysign_pd(x: __m512d, y: __m512d) -> __m512d {
+    let sign_mask = _mm512_set1_epi64(i64::MIN);
+    _mm512_castsi512_pd(_mm512_or_si512(
+        _mm512_andnot_si512(sign_mask, _mm512_castpd_si512(x)),
+        _mm512_and_si512(sign_mask, _mm512_castpd_si512(y)),
+    ))
+}
+
+#[inline(always)]
+/// Founds n in x=a+𝑛ln(2), |a| <= 1
+pub unsafe fn _mm512_ilogb2k_pd(d: __m512d) -> __m512i {
+    _mm512_sub_epi64(
+        _mm512_and_si512(
+            _mm512_srli_epi64::<52>(_mm512_castpd_si512(d)),
+            _mm512_set1_epi64(0x7ff),
+        ),
+        _mm512_set1_epi64(0x3ff),
+    )
+}
+
+#[inline(always)]
+/// Founds a in x=a+𝑛ln(2), |a| <= 1
+pub unsafe fn _mm512_ldexp3k_pd(x: __m512d, n: __m512i) -> __m512d {
+    _mm512_castsi512_pd(_mm512_add_epi64(
+        _mm512_castpd_si512(x),
+        _mm512_slli_epi64::<52>(n),
+    ))
+}
+
+#[inline(always)]
+/// Replaces Cody-Waite reduction with Payne-Hanek `reduce` in lanes above threshold
+pub(crate) unsafe fn _mm512_rempi_pd(
+    d: __m512d,
+    q: __m512i,
+    r: __m512d,
+    reduce: fn(f64) -> (i32, f64),
+) -> (__m512i, __m512d) {
+    if _mm512_cmp_pd_mask::<_CMP_GT_OQ>(_mm512_abs_pd(d), _mm512_set1_pd(PAYNE_HANEK_THRESHOLD))
+        == 0
+    {
+        return (q, r);
+    }
+    let mut ds = [0f64; 8];
+    let mut qs = [0i64; 8];
+    let mut rs = [0f64; 8];
+    _mm512_storeu_pd(ds.as_mut_ptr(), d);
+    _mm512_storeu_si512(qs.as_mut_ptr() as *mut _, q);
+    _mm512_storeu_pd(rs.as_mut_ptr(), r);
+    for ((&d, q), r) in ds.iter().zip(qs.iter_mut()).zip(rs.iter_mut()) {
+        if d.abs() > PAYNE_HANEK_THRESHOLD && d.is_finite() {
+            let (lq, lr) = reduce(d);
+            *q = lq as i64;
+            *r = lr;
+        }
+    }
+    (
+        _mm512_loadu_si512(qs.as_ptr() as *const _),
+        _mm512_loadu_pd(rs.as_ptr()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rint_pd() {
+        if !std::arch::is_x86_feature_detected!("avx512dq") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_pd(-2.5);
+            let rounded = _mm512_rint_pd(value);
+            assert_eq!(_mm512_cvtsd_f64(_mm512_cvtepi64_pd(rounded)), -2.);
+            let value = _mm512_set1_pd(3.7);
+            let rounded = _mm512_rint_pd(value);
+            assert_eq!(_mm512_cvtsd_f64(_mm512_cvtepi64_pd(rounded)), 4.);
+        }
+    }
+}
@@ -0,0 +1,177 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::generalf::PAYNE_HANEK_THRESHOLD_F;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline(always)]
+/// Computes `a*b + c`
+pub unsafe fn _mm512_mlaf_ps(a: __m512, b: __m512, c: __m512) -> __m512 {
+    _mm512_fmadd_ps(a, b, c)
+}
+
+#[inline(always)]
+/// Rounds and takes integral part from float
+pub unsafe fn _mm512_rint_ps(f: __m512) -> __m512i {
+    _mm512_cvt_roundps_epi32::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(f)
+}
+
+#[inline(always)]
+/// Computes 2^n in f32 form for signed 32 bits integers, returns f32 in bits
+pub unsafe fn _mm512_pow2if_epi32(n: __m512i) -> __m512i {
+    _mm512_slli_epi32::<23>(_mm512_add_epi32(n, _mm512_set1_epi32(0x7f)))
+}
+
+#[inline(always)]
+/// Computes `x*2^n` with two scalings, so `n` may reach subnormal and near overflow exponents
+pub unsafe fn _mm512_ldexp2k_ps(x: __m512, n: __m512i) -> __m512 {
+    let m = _mm512_srai_epi32::<1>(n);
+    let u = _mm512_mul_ps(x, _mm512_castsi512_ps(_mm512_pow2if_epi32(m)));
+    _mm512_mul_ps(
+        u,
+        _mm512_castsi512_ps(_mm512_pow2if_epi32(_mm512_sub_epi32(n, m))),
+    )
+}
+
+#[inline(always)]
+/// If mask then `true_vals` otherwise `false_val`
+pub unsafe fn _mm512_select_ps(mask: __mmask16, true_vals: __m512, false_vals: __m512) -> __m512 {
+    _mm512_mask_blend_ps(mask, false_vals, true_vals)
+}
+
+#[inline(always)]
+/// Returns flag value is zero
+pub unsafe fn _mm512_eqzero_ps(d: __m512) -> __mmask16 {
+    _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(d, _mm512_setzero_ps())
+}
+
+#[inline(always)]
+/// Returns flag value is lower than zero
+pub unsafe fn _mm512_ltzero_ps(d: __m512) -> __mmask16 {
+    _mm512_cmp_ps_mask::<_CMP_LT_OQ>(d, _mm512_setzero_ps())
+}
+
+#[inline(always)]
+/// Returns flag value is Infinity
+pub unsafe fn _mm512_isinf_ps(d: __m512) -> __mmask16 {
+    _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(_mm512_abs_ps(d), _mm512_set1_ps(f32::INFINITY))
+}
+
+#[inline(always)]
+/// Returns flag value is Neg Infinity
+pub unsafe fn _mm512_isneginf_ps(d: __m512) -> __mmask16 {
+    _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(d, _mm512_set1_ps(f32::NEG_INFINITY))
+}
+
+#[inline(always)]
+/// Returns true flag if value is NaN
+pub unsafe fn _mm512_isnan_ps(d: __m512) -> __mmask16 {
+    _mm512_cmp_ps_mask::<_CMP_UNORD_Q>(d, d)
+}
+
+#[inline(always)]
+/// Checks if arguments is not integral value
+pub unsafe fn _mm512_isnotintegral_ps(d: __m512) -> __mmask16 {
+    _mm512_cmp_ps_mask::<_CMP_NEQ_OQ>(
+        d,
+        _mm512_roundscale_ps::<{ _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC }>(d),
+    )
+}
+
+#[inline(always)]
+/// Negates value
+pub unsafe fn _mm512_neg_ps(f: __m512) -> __m512 {
+    _mm512_castsi512_ps(_mm512_xor_si512(
+        _mm512_castps_si512(f),
+        _mm512_set1_epi32(i32::MIN),
+    ))
+}
+
+#[inline(always)]
+/// Copies sign from `y` to `x`
+pub unsafe fn _mm512_copysign_ps(x: __m512, y: __m512) -> __m512 {
+    let sign_mask = _mm512_set1_epi32(i32::MIN);
+    _mm512_castsi512_ps(_mm512_or_si512(
+        _mm512_andnot_si512(sign_mask, _mm512_castps_si512(x)),
+        _mm512_and_si512(sign_mask, _mm512_castps_si512(y)),
+    ))
+}
+
+#[inline(always)]
+/// Founds n in x=a+𝑛ln(2), |a| <= 1
+pub unsafe fn _mm512_ilogb2k_ps(d: __m512) -> __m512i {
+    _mm512_sub_epi32(
+        _mm512_and_si512(
+            _mm512_srli_epi32::<23>(_mm512_castps_si512(d)),
+            _mm512_set1_epi32(0xff),
+        ),
+        _mm512_set1_epi32(0x7f),
+    )
+}
+
+#[inline(always)]
+/// Founds a in x=a+𝑛ln(2), |a| <= 1
+pub unsafe fn _mm512_ldexp3k_ps(x: __m512, n: __m512i) -> __m512 {
+    _mm512_castsi512_ps(_mm512_add_epi32(
+        _mm512_castps_si512(x),
+        _mm512_slli_epi32::<23>(n),
+    ))
+}
+
+#[inline(always)]
+/// Replaces Cody-Waite reduction with Payne-Hanek `reduce` in lanes above threshold
+pub(crate) unsafe fn _mm512_rempi_ps(
+    d: __m512,
+    q: __m512i,
+    r: __m512,
+    reduce: fn(f32) -> (i32, f32),
+) -> (__m512i, __m512) {
+    if _mm512_cmp_ps_mask::<_CMP_GT_OQ>(_mm512_abs_ps(d), _mm512_set1_ps(PAYNE_HANEK_THRESHOLD_F))
+        == 0
+    {
+        return (q, r);
+    }
+    let mut ds = [0f32; 16];
+    let mut qs = [0i32; 16];
+    let mut rs = [0f32; 16];
+    _mm512_storeu_ps(ds.as_mut_ptr(), d);
+    _mm512_storeu_si512(qs.as_mut_ptr() as *mut _, q);
+    _mm512_storeu_ps(rs.as_mut_ptr(), r);
+    for ((&d, q), r) in ds.iter().zip(qs.iter_mut()).zip(rs.iter_mut()) {
+        if d.abs() > PAYNE_HANEK_THRESHOLD_F && d.is_finite() {
+            (*q, *r) = reduce(d);
+        }
+    }
+    (
+        _mm512_loadu_si512(qs.as_ptr() as *const _),
+        _mm512_loadu_ps(rs.as_ptr()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_ps() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_ps(f32::NAN);
+            let mask = _mm512_isnan_ps(value);
+            assert_eq!(mask, 0xffff);
+            let selected = _mm512_select_ps(mask, _mm512_set1_ps(1.), value);
+            assert_eq!(_mm512_cvtss_f32(selected), 1.);
+            let copied = _mm512_copysign_ps(_mm512_set1_ps(2.), _mm512_set1_ps(-0.));
+            assert_eq!(_mm512_cvtss_f32(copied), -2.);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm512_eqzero_pd, _mm512_isinf_pd, _mm512_isnan_pd, _mm512_mlaf_pd, _mm512_select_pd};
+
+#[inline(always)]
+/// Method that computes 2D Euclidian distance *ULP 0.6667*
+pub unsafe fn _mm512_hypot_pd(x: __m512d, y: __m512d) -> __m512d {
+    let x = _mm512_abs_pd(x);
+    let y = _mm512_abs_pd(y);
+    let max = _mm512_max_pd(x, y);
+    let min = _mm512_min_pd(x, y);
+    let r = _mm512_div_pd(min, max);
+    let mut ret = _mm512_mul_pd(
+        _mm512_sqrt_pd(_mm512_mlaf_pd(r, r, _mm512_set1_pd(1.))),
+        max,
+    );
+    let is_any_infinite = _mm512_isinf_pd(x) | _mm512_isinf_pd(y);
+    let mut is_any_nan = _mm512_isnan_pd(x) | _mm512_isnan_pd(y);
+    let is_min_zero = _mm512_eqzero_pd(min);
+    ret = _mm512_select_pd(is_min_zero, max, ret);
+    is_any_nan |= _mm512_isnan_pd(ret);
+    ret = _mm512_select_pd(is_any_nan, _mm512_set1_pd(f64::NAN), ret);
+    ret = _mm512_select_pd(is_any_infinite, _mm512_set1_pd(f64::INFINITY), ret);
+    ret
+}
+
+/// Method that computes 2D Euclidian distance *ULP 0.6667*, skipping Inf, Nan checks
+#[inline(always)]
+pub unsafe fn _mm512_hypot_fast_pd(x: __m512d, y: __m512d) -> __m512d {
+    let x = _mm512_abs_pd(x);
+    let y = _mm512_abs_pd(y);
+    let max = _mm512_max_pd(x, y);
+    let min = _mm512_min_pd(x, y);
+    let r = _mm512_div_pd(min, max);
+    let is_min_zero = _mm512_eqzero_pd(min);
+    let ret = _mm512_mul_pd(
+        _mm512_sqrt_pd(_mm512_mlaf_pd(r, r, _mm512_set1_pd(1.))),
+        max,
+    );
+    _mm512_select_pd(is_min_zero, max, ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypotd_avx512() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let comparison = _mm512_hypot_pd(_mm512_set1_pd(3.), _mm512_set1_pd(-4.));
+            assert_eq!(_mm512_cvtsd_f64(comparison), 5.);
+            let comparison = _mm512_hypot_fast_pd(_mm512_set1_pd(3.), _mm512_set1_pd(4.));
+            assert_eq!(_mm512_cvtsd_f64(comparison), 5.);
+        }
+        unsafe {
+            let comparison = _mm512_hypot_pd(_mm512_set1_pd(0.), _mm512_set1_pd(-2.));
+            assert_eq!(_mm512_cvtsd_f64(comparison), 2.);
+            let comparison = _mm512_hypot_pd(_mm512_set1_pd(f64::NEG_INFINITY), _mm512_set1_pd(1.));
+            assert_eq!(_mm512_cvtsd_f64(comparison), f64::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,90 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm512_eqzero_ps, _mm512_isinf_ps, _mm512_isnan_ps, _mm512_mlaf_ps, _mm512_select_ps};
+
+#[inline(always)]
+/// Method that computes 3D Euclidian distance *ULP 0.66667*
+pub unsafe fn _mm512_hypot3_ps(x: __m512, y: __m512, z: __m512) -> __m512 {
+    let x = _mm512_abs_ps(x);
+    let y = _mm512_abs_ps(y);
+    let z = _mm512_abs_ps(z);
+    let max = _mm512_max_ps(_mm512_max_ps(x, y), z);
+    let recip_max = _mm512_div_ps(_mm512_set1_ps(1.), max);
+    let norm_x = _mm512_mul_ps(x, recip_max);
+    let norm_y = _mm512_mul_ps(y, recip_max);
+    let norm_z = _mm512_mul_ps(z, recip_max);
+
+    let accumulator = _mm512_mlaf_ps(
+        norm_x,
+        norm_x,
+        _mm512_mlaf_ps(norm_y, norm_y, _mm512_mul_ps(norm_z, norm_z)),
+    );
+    let mut ret = _mm512_mul_ps(_mm512_sqrt_ps(accumulator), max);
+    let is_any_infinite = _mm512_isinf_ps(x) | _mm512_isinf_ps(y) | _mm512_isinf_ps(z);
+    let mut is_any_nan = _mm512_isnan_ps(x) | _mm512_isnan_ps(y) | _mm512_isnan_ps(z);
+    let is_max_zero = _mm512_eqzero_ps(max);
+    is_any_nan |= _mm512_isnan_ps(ret);
+    ret = _mm512_select_ps(is_any_nan, _mm512_set1_ps(f32::NAN), ret);
+    ret = _mm512_select_ps(is_any_infinite, _mm512_set1_ps(f32::INFINITY), ret);
+    ret = _mm512_select_ps(is_max_zero, _mm512_setzero_ps(), ret);
+    ret
+}
+
+/// Method that computes 3D Euclidian distance *ULP 0.66667*, skipping Inf, Nan checks
+#[inline(always)]
+pub unsafe fn _mm512_hypot3_fast_ps(x: __m512, y: __m512, z: __m512) -> __m512 {
+    let x = _mm512_abs_ps(x);
+    let y = _mm512_abs_ps(y);
+    let z = _mm512_abs_ps(z);
+    let max = _mm512_max_ps(_mm512_max_ps(x, y), z);
+    let recip_max = _mm512_div_ps(_mm512_set1_ps(1.), max);
+    let norm_x = _mm512_mul_ps(x, recip_max);
+    let norm_y = _mm512_mul_ps(y, recip_max);
+    let norm_z = _mm512_mul_ps(z, recip_max);
+
+    let is_max_zero = _mm512_eqzero_ps(max);
+
+    let accumulator = _mm512_mlaf_ps(
+        norm_x,
+        norm_x,
+        _mm512_mlaf_ps(norm_y, norm_y, _mm512_mul_ps(norm_z, norm_z)),
+    );
+    let ret = _mm512_mul_ps(_mm512_sqrt_ps(accumulator), max);
+    _mm512_select_ps(is_max_zero, _mm512_setzero_ps(), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypot3f_avx512() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let vx = _mm512_set1_ps(2.);
+            let vy = _mm512_set1_ps(-3.);
+            let vz = _mm512_set1_ps(6.);
+            let comparison = _mm512_hypot3_ps(vx, vy, vz);
+            assert!((_mm512_cvtss_f32(comparison) - 7.).abs() < 1e-6);
+            let comparison = _mm512_hypot3_fast_ps(vx, vy, vz);
+            assert!((_mm512_cvtss_f32(comparison) - 7.).abs() < 1e-6);
+        }
+        unsafe {
+            let zeros = _mm512_setzero_ps();
+            let comparison = _mm512_hypot3_ps(zeros, zeros, zeros);
+            assert_eq!(_mm512_cvtss_f32(comparison), 0.);
+        }
+    }
+}
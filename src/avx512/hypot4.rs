@@ -0,0 +1,105 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm512_eqzero_pd, _mm512_isinf_pd, _mm512_isnan_pd, _mm512_mlaf_pd, _mm512_select_pd};
+
+#[inline(always)]
+/// Method that computes 4D Euclidian distance *ULP 0.6666*
+pub unsafe fn _mm512_hypot4_pd(x: __m512d, y: __m512d, z: __m512d, w: __m512d) -> __m512d {
+    let x = _mm512_abs_pd(x);
+    let y = _mm512_abs_pd(y);
+    let z = _mm512_abs_pd(z);
+    let w = _mm512_abs_pd(w);
+    let max = _mm512_max_pd(_mm512_max_pd(_mm512_max_pd(x, y), z), w);
+    let recip_max = _mm512_div_pd(_mm512_set1_pd(1.), max);
+    let norm_x = _mm512_mul_pd(x, recip_max);
+    let norm_y = _mm512_mul_pd(y, recip_max);
+    let norm_z = _mm512_mul_pd(z, recip_max);
+    let norm_w = _mm512_mul_pd(w, recip_max);
+
+    let accumulator = _mm512_mlaf_pd(
+        norm_x,
+        norm_x,
+        _mm512_mlaf_pd(
+            norm_y,
+            norm_y,
+            _mm512_mlaf_pd(norm_z, norm_z, _mm512_mul_pd(norm_w, norm_w)),
+        ),
+    );
+    let mut ret = _mm512_mul_pd(_mm512_sqrt_pd(accumulator), max);
+    let is_any_infinite =
+        _mm512_isinf_pd(x) | _mm512_isinf_pd(y) | _mm512_isinf_pd(z) | _mm512_isinf_pd(w);
+    let mut is_any_nan =
+        _mm512_isnan_pd(x) | _mm512_isnan_pd(y) | _mm512_isnan_pd(z) | _mm512_isnan_pd(w);
+    let is_max_zero = _mm512_eqzero_pd(max);
+    is_any_nan |= _mm512_isnan_pd(ret);
+    ret = _mm512_select_pd(is_any_nan, _mm512_set1_pd(f64::NAN), ret);
+    ret = _mm512_select_pd(is_any_infinite, _mm512_set1_pd(f64::INFINITY), ret);
+    ret = _mm512_select_pd(is_max_zero, _mm512_setzero_pd(), ret);
+    ret
+}
+
+/// Method that computes 4D Euclidian distance *ULP 0.6666*, skipping Inf, Nan checks
+#[inline(always)]
+pub unsafe fn _mm512_hypot4_fast_pd(x: __m512d, y: __m512d, z: __m512d, w: __m512d) -> __m512d {
+    let x = _mm512_abs_pd(x);
+    let y = _mm512_abs_pd(y);
+    let z = _mm512_abs_pd(z);
+    let w = _mm512_abs_pd(w);
+    let max = _mm512_max_pd(_mm512_max_pd(_mm512_max_pd(x, y), z), w);
+    let recip_max = _mm512_div_pd(_mm512_set1_pd(1.), max);
+    let norm_x = _mm512_mul_pd(x, recip_max);
+    let norm_y = _mm512_mul_pd(y, recip_max);
+    let norm_z = _mm512_mul_pd(z, recip_max);
+    let norm_w = _mm512_mul_pd(w, recip_max);
+
+    let is_max_zero = _mm512_eqzero_pd(max);
+
+    let accumulator = _mm512_mlaf_pd(
+        norm_x,
+        norm_x,
+        _mm512_mlaf_pd(
+            norm_y,
+            norm_y,
+            _mm512_mlaf_pd(norm_z, norm_z, _mm512_mul_pd(norm_w, norm_w)),
+        ),
+    );
+    let ret = _mm512_mul_pd(_mm512_sqrt_pd(accumulator), max);
+    _mm512_select_pd(is_max_zero, _mm512_setzero_pd(), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypot4d_avx512() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let vx = _mm512_set1_pd(1.);
+            let vy = _mm512_set1_pd(-2.);
+            let vz = _mm512_set1_pd(4.);
+            let vw = _mm512_set1_pd(10.);
+            let comparison = _mm512_hypot4_pd(vx, vy, vz, vw);
+            assert_eq!(_mm512_cvtsd_f64(comparison), 11.);
+            let comparison = _mm512_hypot4_fast_pd(vx, vy, vz, vw);
+            assert_eq!(_mm512_cvtsd_f64(comparison), 11.);
+        }
+        unsafe {
+            let zeros = _mm512_setzero_pd();
+            let comparison = _mm512_hypot4_pd(zeros, zeros, zeros, _mm512_set1_pd(f64::NAN));
+            assert!(_mm512_cvtsd_f64(comparison).is_nan());
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm512_eqzero_ps, _mm512_isinf_ps, _mm512_isnan_ps, _mm512_mlaf_ps, _mm512_select_ps};
+
+#[inline(always)]
+/// Method that computes 2D Euclidian distance *ULP 0.6667*
+pub unsafe fn _mm512_hypot_ps(x: __m512, y: __m512) -> __m512 {
+    let x = _mm512_abs_ps(x);
+    let y = _mm512_abs_ps(y);
+    let max = _mm512_max_ps(x, y);
+    let min = _mm512_min_ps(x, y);
+    let r = _mm512_div_ps(min, max);
+    let mut ret = _mm512_mul_ps(
+        _mm512_sqrt_ps(_mm512_mlaf_ps(r, r, _mm512_set1_ps(1.))),
+        max,
+    );
+    let is_any_infinite = _mm512_isinf_ps(x) | _mm512_isinf_ps(y);
+    let mut is_any_nan = _mm512_isnan_ps(x) | _mm512_isnan_ps(y);
+    let is_min_zero = _mm512_eqzero_ps(min);
+    ret = _mm512_select_ps(is_min_zero, max, ret);
+    is_any_nan |= _mm512_isnan_ps(ret);
+    ret = _mm512_select_ps(is_any_nan, _mm512_set1_ps(f32::NAN), ret);
+    ret = _mm512_select_ps(is_any_infinite, _mm512_set1_ps(f32::INFINITY), ret);
+    ret
+}
+
+/// Method that computes 2D Euclidian distance *ULP 0.6667*, skipping Inf, Nan checks
+#[inline(always)]
+pub unsafe fn _mm512_hypot_fast_ps(x: __m512, y: __m512) -> __m512 {
+    let x = _mm512_abs_ps(x);
+    let y = _mm512_abs_ps(y);
+    let max = _mm512_max_ps(x, y);
+    let min = _mm512_min_ps(x, y);
+    let r = _mm512_div_ps(min, max);
+    let is_min_zero = _mm512_eqzero_ps(min);
+    let ret = _mm512_mul_ps(
+        _mm512_sqrt_ps(_mm512_mlaf_ps(r, r, _mm512_set1_ps(1.))),
+        max,
+    );
+    _mm512_select_ps(is_min_zero, max, ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypotf_avx512() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let comparison = _mm512_hypot_ps(_mm512_set1_ps(3.), _mm512_set1_ps(-4.));
+            assert_eq!(_mm512_cvtss_f32(comparison), 5.);
+            let comparison = _mm512_hypot_fast_ps(_mm512_set1_ps(3.), _mm512_set1_ps(4.));
+            assert_eq!(_mm512_cvtss_f32(comparison), 5.);
+        }
+        unsafe {
+            let comparison = _mm512_hypot_ps(_mm512_set1_ps(0.), _mm512_set1_ps(-2.));
+            assert_eq!(_mm512_cvtss_f32(comparison), 2.);
+            let comparison = _mm512_hypot_ps(_mm512_set1_ps(f32::NEG_INFINITY), _mm512_set1_ps(1.));
+            assert_eq!(_mm512_cvtss_f32(comparison), f32::INFINITY);
+        }
+    }
+}
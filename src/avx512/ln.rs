@@ -0,0 +1,98 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::ln::{
+    LN_POLY_1_D, LN_POLY_2_D, LN_POLY_3_D, LN_POLY_4_D, LN_POLY_5_D, LN_POLY_6_D, LN_POLY_7_D,
+    LN_POLY_8_D,
+};
+use crate::log2::LOG_SCALE_D;
+use crate::{
+    _mm512_eqzero_pd, _mm512_ilogb2k_pd, _mm512_isinf_pd, _mm512_isnan_pd, _mm512_ldexp3k_pd,
+    _mm512_ltzero_pd, _mm512_mlaf_pd, _mm512_select_pd,
+};
+
+/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm512_ln_fast_pd(d: __m512d) -> __m512d {
+    let n = _mm512_ilogb2k_pd(_mm512_mul_pd(d, _mm512_set1_pd(1. / 0.75)));
+    let a = _mm512_ldexp3k_pd(d, _mm512_sub_epi64(_mm512_setzero_si512(), n));
+    let ones = _mm512_set1_pd(1.);
+    let x = _mm512_div_pd(_mm512_sub_pd(a, ones), _mm512_add_pd(a, ones));
+    let x2 = _mm512_mul_pd(x, x);
+    let mut u = _mm512_set1_pd(LN_POLY_8_D);
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(LN_POLY_7_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(LN_POLY_6_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(LN_POLY_5_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(LN_POLY_4_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(LN_POLY_3_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(LN_POLY_2_D));
+    u = _mm512_mlaf_pd(u, x2, _mm512_set1_pd(LN_POLY_1_D));
+    _mm512_mlaf_pd(
+        _mm512_set1_pd(std::f64::consts::LN_2),
+        _mm512_cvtepi64_pd(n),
+        _mm512_mul_pd(x, u),
+    )
+}
+
+/// Computes natural logarithm for an argument *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm512_ln_pd(d: __m512d) -> __m512d {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = _mm512_cmp_pd_mask::<_CMP_LT_OQ>(d, _mm512_set1_pd(f64::MIN_POSITIVE));
+    let x = _mm512_select_pd(o, _mm512_mul_pd(d, _mm512_set1_pd(LOG_SCALE_D)), d);
+    let mut res = _mm512_mlaf_pd(
+        _mm512_set1_pd(std::f64::consts::LN_2),
+        _mm512_select_pd(o, _mm512_set1_pd(-64f64), _mm512_setzero_pd()),
+        _mm512_ln_fast_pd(x),
+    );
+    // d == 0 || d == Inf -> Inf
+    res = _mm512_select_pd(_mm512_eqzero_pd(d), _mm512_set1_pd(f64::NEG_INFINITY), res);
+    res = _mm512_select_pd(_mm512_isinf_pd(d), _mm512_set1_pd(f64::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = _mm512_select_pd(
+        _mm512_ltzero_pd(d) | _mm512_isnan_pd(d),
+        _mm512_set1_pd(f64::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lnd() {
+        if !std::arch::is_x86_feature_detected!("avx512dq") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_pd(2.);
+            let comparison = _mm512_ln_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = std::f64::consts::LN_2;
+            assert!((flag_1 - control).abs() < 1e-15);
+        }
+        unsafe {
+            let value = _mm512_set1_pd(0.);
+            let comparison = _mm512_ln_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+        unsafe {
+            let value = _mm512_set1_pd(-1.);
+            let comparison = _mm512_ln_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
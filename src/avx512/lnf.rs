@@ -0,0 +1,86 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::lnf::{LN_POLY_1_F, LN_POLY_2_F, LN_POLY_3_F, LN_POLY_4_F, LN_POLY_5_F};
+use crate::log2f::LOG_SCALE_F;
+use crate::{
+    _mm512_eqzero_ps, _mm512_ilogb2k_ps, _mm512_isinf_ps, _mm512_isnan_ps, _mm512_ldexp3k_ps,
+    _mm512_ltzero_ps, _mm512_mlaf_ps, _mm512_select_ps,
+};
+
+/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm512_ln_fast_ps(d: __m512) -> __m512 {
+    let n = _mm512_ilogb2k_ps(_mm512_mul_ps(d, _mm512_set1_ps(1f32 / 0.75f32)));
+    let a = _mm512_ldexp3k_ps(d, _mm512_sub_epi32(_mm512_setzero_si512(), n));
+    let ones = _mm512_set1_ps(1f32);
+    let x = _mm512_div_ps(_mm512_sub_ps(a, ones), _mm512_add_ps(a, ones));
+    let x2 = _mm512_mul_ps(x, x);
+    let mut u = _mm512_set1_ps(LN_POLY_5_F);
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(LN_POLY_4_F));
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(LN_POLY_3_F));
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(LN_POLY_2_F));
+    u = _mm512_mlaf_ps(u, x2, _mm512_set1_ps(LN_POLY_1_F));
+    _mm512_mlaf_ps(
+        _mm512_set1_ps(std::f32::consts::LN_2),
+        _mm512_cvtepi32_ps(n),
+        _mm512_mul_ps(x, u),
+    )
+}
+
+/// Computes natural logarithm for an argument *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm512_ln_ps(d: __m512) -> __m512 {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = _mm512_cmp_ps_mask::<_CMP_LT_OQ>(d, _mm512_set1_ps(f32::MIN_POSITIVE));
+    let x = _mm512_select_ps(o, _mm512_mul_ps(d, _mm512_set1_ps(LOG_SCALE_F)), d);
+    let mut res = _mm512_mlaf_ps(
+        _mm512_set1_ps(std::f32::consts::LN_2),
+        _mm512_select_ps(o, _mm512_set1_ps(-64f32), _mm512_setzero_ps()),
+        _mm512_ln_fast_ps(x),
+    );
+    // d == 0 || d == Inf -> Inf
+    res = _mm512_select_ps(_mm512_eqzero_ps(d), _mm512_set1_ps(f32::NEG_INFINITY), res);
+    res = _mm512_select_ps(_mm512_isinf_ps(d), _mm512_set1_ps(f32::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = _mm512_select_ps(
+        _mm512_ltzero_ps(d) | _mm512_isnan_ps(d),
+        _mm512_set1_ps(f32::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lnf() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_ps(2f32);
+            let comparison = _mm512_ln_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = std::f32::consts::LN_2;
+            assert!((flag_1 - control).abs() < 1e-6);
+        }
+        unsafe {
+            let value = _mm512_set1_ps(-1f32);
+            let comparison = _mm512_ln_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
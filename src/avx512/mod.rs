@@ -0,0 +1,119 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+mod acos;
+mod acosf;
+mod asin;
+mod asinf;
+mod atan;
+mod atan2;
+mod atan2f;
+mod atanf;
+mod cbrt;
+mod cbrtf;
+mod cos;
+mod cosf;
+mod exp;
+mod expf;
+mod fmod;
+mod fmodf;
+mod general;
+mod generalf;
+mod hypot;
+mod hypot3;
+mod hypot3f;
+mod hypot4;
+mod hypot4f;
+mod hypotf;
+mod ln;
+mod lnf;
+mod pow;
+mod powf;
+mod precision;
+mod sin;
+mod sincos;
+mod sincosf;
+mod sinf;
+mod tan;
+mod tanf;
+
+pub use acos::_mm512_acos_pd;
+pub use acosf::_mm512_acos_ps;
+pub use asin::_mm512_asin_pd;
+pub use asinf::_mm512_asin_ps;
+pub use atan::_mm512_atan_pd;
+pub use atan2::_mm512_atan2_pd;
+pub use atan2f::_mm512_atan2_ps;
+pub use atanf::_mm512_atan_ps;
+pub use cbrt::_mm512_cbrt_fast_pd;
+pub use cbrt::_mm512_cbrt_pd;
+pub use cbrtf::_mm512_cbrt_fast_ps;
+pub use cbrtf::_mm512_cbrt_ps;
+pub use cos::_mm512_cos_pd;
+pub use cosf::_mm512_cos_ps;
+pub use exp::_mm512_exp_fast_pd;
+pub use exp::_mm512_exp_pd;
+pub use expf::_mm512_exp_fast_ps;
+pub use expf::_mm512_exp_ps;
+pub use fmod::_mm512_fmod_pd;
+pub use fmodf::_mm512_fmod_ps;
+pub use general::_mm512_copysign_pd;
+pub use general::_mm512_eqzero_pd;
+pub use general::_mm512_ilogb2k_pd;
+pub use general::_mm512_isinf_pd;
+pub use general::_mm512_isnan_pd;
+pub use general::_mm512_isneginf_pd;
+pub use general::_mm512_isnotintegral_pd;
+pub use general::_mm512_ldexp2k_pd;
+pub use general::_mm512_ldexp3k_pd;
+pub use general::_mm512_ltzero_pd;
+pub use general::_mm512_mlaf_pd;
+pub use general::_mm512_neg_pd;
+pub use general::_mm512_pow2i_epi64;
+pub use general::_mm512_rint_pd;
+pub use general::_mm512_select_pd;
+pub use generalf::_mm512_copysign_ps;
+pub use generalf::_mm512_eqzero_ps;
+pub use generalf::_mm512_ilogb2k_ps;
+pub use generalf::_mm512_isinf_ps;
+pub use generalf::_mm512_isnan_ps;
+pub use generalf::_mm512_isneginf_ps;
+pub use generalf::_mm512_isnotintegral_ps;
+pub use generalf::_mm512_ldexp2k_ps;
+pub use generalf::_mm512_ldexp3k_ps;
+pub use generalf::_mm512_ltzero_ps;
+pub use generalf::_mm512_mlaf_ps;
+pub use generalf::_mm512_neg_ps;
+pub use generalf::_mm512_pow2if_epi32;
+pub use generalf::_mm512_rint_ps;
+pub use generalf::_mm512_select_ps;
+pub use hypot::_mm512_hypot_fast_pd;
+pub use hypot::_mm512_hypot_pd;
+pub use hypot3::_mm512_hypot3_fast_pd;
+pub use hypot3::_mm512_hypot3_pd;
+pub use hypot3f::_mm512_hypot3_fast_ps;
+pub use hypot3f::_mm512_hypot3_ps;
+pub use hypot4::_mm512_hypot4_fast_pd;
+pub use hypot4::_mm512_hypot4_pd;
+pub use hypot4f::_mm512_hypot4_fast_ps;
+pub use hypot4f::_mm512_hypot4_ps;
+pub use hypotf::_mm512_hypot_fast_ps;
+pub use hypotf::_mm512_hypot_ps;
+pub use ln::_mm512_ln_fast_pd;
+pub use ln::_mm512_ln_pd;
+pub use lnf::_mm512_ln_fast_ps;
+pub use lnf::_mm512_ln_ps;
+pub use pow::_mm512_pow_fast_pd;
+pub use pow::_mm512_pow_pd;
+pub use powf::_mm512_pow_fast_ps;
+pub use powf::_mm512_pow_ps;
+pub use sin::_mm512_sin_pd;
+pub use sincos::_mm512_sincos_pd;
+pub use sincosf::_mm512_sincos_ps;
+pub use sinf::_mm512_sin_ps;
+pub use tan::_mm512_tan_pd;
+pub use tanf::_mm512_tan_ps;
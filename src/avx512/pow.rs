@@ -0,0 +1,68 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{
+    _mm512_copysign_pd, _mm512_eqzero_pd, _mm512_exp_fast_pd, _mm512_exp_pd, _mm512_isinf_pd,
+    _mm512_isnan_pd, _mm512_isneginf_pd, _mm512_isnotintegral_pd, _mm512_ln_fast_pd, _mm512_ln_pd,
+    _mm512_ltzero_pd, _mm512_select_pd,
+};
+
+#[inline(always)]
+/// Computes pow function *ULP 2.0*
+pub unsafe fn _mm512_pow_pd(d: __m512d, n: __m512d) -> __m512d {
+    let c = _mm512_exp_pd(_mm512_mul_pd(n, _mm512_ln_pd(_mm512_abs_pd(d))));
+    // Sign of the base is kept only for odd integral powers
+    let is_odd = !_mm512_isnotintegral_pd(n)
+        & _mm512_isnotintegral_pd(_mm512_mul_pd(n, _mm512_set1_pd(0.5)));
+    let mut ret = _mm512_select_pd(is_odd, _mm512_copysign_pd(c, d), c);
+    // Not integral powers are not allowed for negative finite numbers
+    let is_neg_finite = _mm512_ltzero_pd(d) & !_mm512_isneginf_pd(d);
+    let is_nan_with_integral = is_neg_finite & _mm512_isnotintegral_pd(n);
+    let is_any_nan = _mm512_isnan_pd(d) | _mm512_isnan_pd(n) | is_nan_with_integral;
+    ret = _mm512_select_pd(is_any_nan, _mm512_set1_pd(f64::NAN), ret);
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN, pow(-1, ±inf) = 1
+    let ones = _mm512_set1_pd(1.);
+    let is_one = _mm512_eqzero_pd(n)
+        | _mm512_cmp_pd_mask::<_CMP_EQ_OQ>(d, ones)
+        | (_mm512_cmp_pd_mask::<_CMP_EQ_OQ>(_mm512_abs_pd(d), ones) & _mm512_isinf_pd(n));
+    _mm512_select_pd(is_one, ones, ret)
+}
+
+/// Method that computes pow skipping Inf, Nan checks, *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm512_pow_fast_pd(d: __m512d, n: __m512d) -> __m512d {
+    let c = _mm512_exp_fast_pd(_mm512_mul_pd(n, _mm512_ln_fast_pd(d)));
+    _mm512_copysign_pd(c, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powd() {
+        if !std::arch::is_x86_feature_detected!("avx512dq") {
+            return;
+        }
+        unsafe {
+            let comparison = _mm512_pow_pd(_mm512_set1_pd(2.), _mm512_set1_pd(3.5));
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = (2f64).powf(3.5);
+            assert!((flag_1 - control).abs() < 1e-13);
+        }
+        unsafe {
+            let comparison = _mm512_pow_pd(_mm512_set1_pd(-2.), _mm512_set1_pd(0.5));
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,68 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{
+    _mm512_copysign_ps, _mm512_eqzero_ps, _mm512_exp_fast_ps, _mm512_exp_ps, _mm512_isinf_ps,
+    _mm512_isnan_ps, _mm512_isneginf_ps, _mm512_isnotintegral_ps, _mm512_ln_fast_ps, _mm512_ln_ps,
+    _mm512_ltzero_ps, _mm512_select_ps,
+};
+
+#[inline(always)]
+/// Computes pow function *ULP 2.0*
+pub unsafe fn _mm512_pow_ps(d: __m512, n: __m512) -> __m512 {
+    let c = _mm512_exp_ps(_mm512_mul_ps(n, _mm512_ln_ps(_mm512_abs_ps(d))));
+    // Sign of the base is kept only for odd integral powers
+    let is_odd = !_mm512_isnotintegral_ps(n)
+        & _mm512_isnotintegral_ps(_mm512_mul_ps(n, _mm512_set1_ps(0.5)));
+    let mut ret = _mm512_select_ps(is_odd, _mm512_copysign_ps(c, d), c);
+    // Not integral powers are not allowed for negative finite numbers
+    let is_neg_finite = _mm512_ltzero_ps(d) & !_mm512_isneginf_ps(d);
+    let is_nan_with_integral = is_neg_finite & _mm512_isnotintegral_ps(n);
+    let is_any_nan = _mm512_isnan_ps(d) | _mm512_isnan_ps(n) | is_nan_with_integral;
+    ret = _mm512_select_ps(is_any_nan, _mm512_set1_ps(f32::NAN), ret);
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN, pow(-1, ±inf) = 1
+    let ones = _mm512_set1_ps(1.);
+    let is_one = _mm512_eqzero_ps(n)
+        | _mm512_cmp_ps_mask::<_CMP_EQ_OQ>(d, ones)
+        | (_mm512_cmp_ps_mask::<_CMP_EQ_OQ>(_mm512_abs_ps(d), ones) & _mm512_isinf_ps(n));
+    _mm512_select_ps(is_one, ones, ret)
+}
+
+/// Method that computes pow skipping Inf, Nan checks, *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm512_pow_fast_ps(d: __m512, n: __m512) -> __m512 {
+    let c = _mm512_exp_fast_ps(_mm512_mul_ps(n, _mm512_ln_fast_ps(d)));
+    _mm512_copysign_ps(c, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powf() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let comparison = _mm512_pow_ps(_mm512_set1_ps(2.), _mm512_set1_ps(3.5));
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = (2f32).powf(3.5);
+            assert!((flag_1 - control).abs() < 1e-5);
+        }
+        unsafe {
+            let comparison = _mm512_pow_ps(_mm512_set1_ps(-2.), _mm512_set1_ps(0.5));
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,213 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::precision::simd_math_impl;
+
+/// Assembles AVX-512 routines from two AVX halves where no native kernel exists yet
+macro_rules! avx_halves {
+    ($($name_d:ident => $avx_d:path, $name_s:ident => $avx_s:path;)*) => {
+        $(
+            #[inline(always)]
+            unsafe fn $name_d(x: __m512d) -> __m512d {
+                let lo = $avx_d(_mm512_castpd512_pd256(x));
+                let hi = $avx_d(_mm512_extractf64x4_pd::<1>(x));
+                _mm512_insertf64x4::<1>(_mm512_castpd256_pd512(lo), hi)
+            }
+
+            #[inline(always)]
+            unsafe fn $name_s(x: __m512) -> __m512 {
+                let lo = $avx_s(_mm512_castps512_ps256(x));
+                let hi = $avx_s(_mm512_extractf32x8_ps::<1>(x));
+                _mm512_insertf32x8::<1>(_mm512_castps256_ps512(lo), hi)
+            }
+        )*
+    };
+}
+
+avx_halves! {
+    _mm512_exp2_pd => crate::_mm256_exp2_pd, _mm512_exp2_ps => crate::_mm256_exp2_ps;
+    _mm512_exp10_pd => crate::_mm256_exp10_pd, _mm512_exp10_ps => crate::_mm256_exp10_ps;
+    _mm512_expm1_pd => crate::_mm256_expm1_pd, _mm512_expm1_ps => crate::_mm256_expm1_ps;
+    _mm512_log2_pd => crate::_mm256_log2_pd, _mm512_log2_ps => crate::_mm256_log2_ps;
+    _mm512_log10_pd => crate::_mm256_log10_pd, _mm512_log10_ps => crate::_mm256_log10_ps;
+    _mm512_log1p_pd => crate::_mm256_log1p_pd, _mm512_log1p_ps => crate::_mm256_log1p_ps;
+    _mm512_sinh_pd => crate::_mm256_sinh_pd, _mm512_sinh_ps => crate::_mm256_sinh_ps;
+    _mm512_cosh_pd => crate::_mm256_cosh_pd, _mm512_cosh_ps => crate::_mm256_cosh_ps;
+    _mm512_tanh_pd => crate::_mm256_tanh_pd, _mm512_tanh_ps => crate::_mm256_tanh_ps;
+    _mm512_asinh_pd => crate::_mm256_asinh_pd, _mm512_asinh_ps => crate::_mm256_asinh_ps;
+    _mm512_acosh_pd => crate::_mm256_acosh_pd, _mm512_acosh_ps => crate::_mm256_acosh_ps;
+    _mm512_atanh_pd => crate::_mm256_atanh_pd, _mm512_atanh_ps => crate::_mm256_atanh_ps;
+    _mm512_erf_pd => crate::_mm256_erf_pd, _mm512_erf_ps => crate::_mm256_erf_ps;
+    _mm512_erfc_pd => crate::_mm256_erfc_pd, _mm512_erfc_ps => crate::_mm256_erfc_ps;
+    _mm512_erfinv_pd => crate::_mm256_erfinv_pd, _mm512_erfinv_ps => crate::_mm256_erfinv_ps;
+    _mm512_tgamma_pd => crate::_mm256_tgamma_pd, _mm512_tgamma_ps => crate::_mm256_tgamma_ps;
+    _mm512_digamma_pd => crate::_mm256_digamma_pd, _mm512_digamma_ps => crate::_mm256_digamma_ps;
+}
+
+/// Evaluates f32 lanes with an f64 kernel, so result is rounded only once
+macro_rules! widen_ps {
+    ($f:path, ($x:ident)) => {{
+        let lo = $f(_mm512_cvtps_pd(_mm512_castps512_ps256($x)));
+        let hi = $f(_mm512_cvtps_pd(_mm512_extractf32x8_ps::<1>($x)));
+        _mm512_insertf32x8::<1>(
+            _mm512_castps256_ps512(_mm512_cvtpd_ps(lo)),
+            _mm512_cvtpd_ps(hi),
+        )
+    }};
+    ($f:path, ($x:ident, $y:ident)) => {{
+        let lo = $f(
+            _mm512_cvtps_pd(_mm512_castps512_ps256($x)),
+            _mm512_cvtps_pd(_mm512_castps512_ps256($y)),
+        );
+        let hi = $f(
+            _mm512_cvtps_pd(_mm512_extractf32x8_ps::<1>($x)),
+            _mm512_cvtps_pd(_mm512_extractf32x8_ps::<1>($y)),
+        );
+        _mm512_insertf32x8::<1>(
+            _mm512_castps256_ps512(_mm512_cvtpd_ps(lo)),
+            _mm512_cvtpd_ps(hi),
+        )
+    }};
+}
+
+simd_math_impl! {
+    __m512d, f64,
+    exp(x) => crate::_mm512_exp_pd, fast: crate::_mm512_exp_fast_pd;
+    exp2(x) => _mm512_exp2_pd;
+    exp10(x) => _mm512_exp10_pd;
+    expm1(x) => _mm512_expm1_pd;
+    ln(x) => crate::_mm512_ln_pd, fast: crate::_mm512_ln_fast_pd;
+    log2(x) => _mm512_log2_pd;
+    log10(x) => _mm512_log10_pd;
+    log1p(x) => _mm512_log1p_pd;
+    sin(x) => crate::_mm512_sin_pd;
+    cos(x) => crate::_mm512_cos_pd;
+    tan(x) => crate::_mm512_tan_pd;
+    asin(x) => crate::_mm512_asin_pd;
+    acos(x) => crate::_mm512_acos_pd;
+    atan(x) => crate::_mm512_atan_pd;
+    sinh(x) => _mm512_sinh_pd;
+    cosh(x) => _mm512_cosh_pd;
+    tanh(x) => _mm512_tanh_pd;
+    asinh(x) => _mm512_asinh_pd;
+    acosh(x) => _mm512_acosh_pd;
+    atanh(x) => _mm512_atanh_pd;
+    cbrt(x) => crate::_mm512_cbrt_pd, fast: crate::_mm512_cbrt_fast_pd;
+    erf(x) => _mm512_erf_pd;
+    erfc(x) => _mm512_erfc_pd;
+    erfinv(x) => _mm512_erfinv_pd;
+    tgamma(x) => _mm512_tgamma_pd;
+    digamma(x) => _mm512_digamma_pd;
+    pow(x, n) => crate::_mm512_pow_pd, fast: crate::_mm512_pow_fast_pd;
+    atan2(y, x) => crate::_mm512_atan2_pd;
+    hypot(x, y) => crate::_mm512_hypot_pd, fast: crate::_mm512_hypot_fast_pd;
+    fmod(x, y) => crate::_mm512_fmod_pd;
+    hypot3(x, y, z) => crate::_mm512_hypot3_pd, fast: crate::_mm512_hypot3_fast_pd;
+    hypot4(x, y, z, w) => crate::_mm512_hypot4_pd, fast: crate::_mm512_hypot4_fast_pd;
+}
+
+simd_math_impl! {
+    __m512, f32,
+    exp(x) => crate::_mm512_exp_ps, fast: crate::_mm512_exp_fast_ps, high: crate::_mm512_exp_pd, cr: crate::cr::expf;
+    exp2(x) => _mm512_exp2_ps, high: _mm512_exp2_pd, cr: crate::cr::exp2f;
+    exp10(x) => _mm512_exp10_ps, high: _mm512_exp10_pd, cr: crate::cr::exp10f;
+    expm1(x) => _mm512_expm1_ps, high: _mm512_expm1_pd;
+    ln(x) => crate::_mm512_ln_ps, fast: crate::_mm512_ln_fast_ps, high: crate::_mm512_ln_pd, cr: crate::cr::lnf;
+    log2(x) => _mm512_log2_ps, high: _mm512_log2_pd, cr: crate::cr::log2f;
+    log10(x) => _mm512_log10_ps, high: _mm512_log10_pd, cr: crate::cr::log10f;
+    log1p(x) => _mm512_log1p_ps, high: _mm512_log1p_pd;
+    sin(x) => crate::_mm512_sin_ps, high: crate::_mm512_sin_pd, cr: crate::cr::sinf;
+    cos(x) => crate::_mm512_cos_ps, high: crate::_mm512_cos_pd, cr: crate::cr::cosf;
+    tan(x) => crate::_mm512_tan_ps, high: crate::_mm512_tan_pd, cr: crate::cr::tanf;
+    asin(x) => crate::_mm512_asin_ps, high: crate::_mm512_asin_pd, cr: crate::cr::asinf;
+    acos(x) => crate::_mm512_acos_ps, high: crate::_mm512_acos_pd, cr: crate::cr::acosf;
+    atan(x) => crate::_mm512_atan_ps, high: crate::_mm512_atan_pd, cr: crate::cr::atanf;
+    sinh(x) => _mm512_sinh_ps, high: _mm512_sinh_pd;
+    cosh(x) => _mm512_cosh_ps, high: _mm512_cosh_pd;
+    tanh(x) => _mm512_tanh_ps, high: _mm512_tanh_pd;
+    asinh(x) => _mm512_asinh_ps, high: _mm512_asinh_pd;
+    acosh(x) => _mm512_acosh_ps, high: _mm512_acosh_pd;
+    atanh(x) => _mm512_atanh_ps, high: _mm512_atanh_pd;
+    cbrt(x) => crate::_mm512_cbrt_ps, fast: crate::_mm512_cbrt_fast_ps, high: crate::_mm512_cbrt_pd, cr: crate::cr::cbrtf;
+    erf(x) => _mm512_erf_ps, high: _mm512_erf_pd;
+    erfc(x) => _mm512_erfc_ps, high: _mm512_erfc_pd;
+    erfinv(x) => _mm512_erfinv_ps, high: _mm512_erfinv_pd;
+    tgamma(x) => _mm512_tgamma_ps, high: _mm512_tgamma_pd;
+    digamma(x) => _mm512_digamma_ps, high: _mm512_digamma_pd;
+    pow(x, n) => crate::_mm512_pow_ps, fast: crate::_mm512_pow_fast_ps, high: crate::_mm512_pow_pd, cr: crate::cr::powf;
+    atan2(y, x) => crate::_mm512_atan2_ps, high: crate::_mm512_atan2_pd;
+    hypot(x, y) => crate::_mm512_hypot_ps, fast: crate::_mm512_hypot_fast_ps, high: crate::_mm512_hypot_pd;
+    fmod(x, y) => crate::_mm512_fmod_ps;
+    hypot3(x, y, z) => crate::_mm512_hypot3_ps, fast: crate::_mm512_hypot3_fast_ps;
+    hypot4(x, y, z, w) => crate::_mm512_hypot4_ps, fast: crate::_mm512_hypot4_fast_ps;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::precision::{CorrectlyRounded, Fast, HighAccuracy, SimdMath, Standard};
+    use crate::{_mm512_exp_fast_ps, _mm512_exp_ps, _mm512_sin_pd};
+
+    #[test]
+    fn test_tiers() {
+        if !std::arch::is_x86_feature_detected!("avx512dq") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_ps(3.5f32);
+            let fast: f32 = _mm512_cvtss_f32(Fast::exp(value));
+            assert_eq!(fast, _mm512_cvtss_f32(_mm512_exp_fast_ps(value)));
+            let standard: f32 = _mm512_cvtss_f32(Standard::exp(value));
+            assert_eq!(standard, _mm512_cvtss_f32(_mm512_exp_ps(value)));
+            let value = _mm512_set1_pd(0.7f64);
+            let high: f64 = _mm512_cvtsd_f64(HighAccuracy::sin(value));
+            assert_eq!(high, _mm512_cvtsd_f64(_mm512_sin_pd(value)));
+            let value = _mm512_set1_ps(0.7f32);
+            let correct: f32 = _mm512_cvtss_f32(CorrectlyRounded::sin(value));
+            assert_eq!(correct, crate::cr::sinf(0.7f32));
+        }
+    }
+
+    #[test]
+    fn test_high_accuracy_ps() {
+        if !std::arch::is_x86_feature_detected!("avx512dq") {
+            return;
+        }
+        for i in 0..2000 {
+            let x = i as f32 * 0.0123f32 - 12.3f32;
+            unsafe {
+                let value = _mm512_set1_ps(x);
+                let y = _mm512_set1_ps(0.75f32);
+                let sin = _mm512_cvtss_f32(HighAccuracy::sin(value));
+                let exp = _mm512_cvtss_f32(HighAccuracy::exp(value));
+                let pow = _mm512_cvtss_f32(HighAccuracy::pow(_mm512_abs_ps(value), y));
+                assert!(
+                    ulp(sin, (x as f64).sin() as f32) <= 1,
+                    "sin({}) = {}",
+                    x,
+                    sin
+                );
+                assert!(
+                    ulp(exp, (x as f64).exp() as f32) <= 1,
+                    "exp({}) = {}",
+                    x,
+                    exp
+                );
+                let control = (x.abs() as f64).powf(0.75) as f32;
+                assert!(ulp(pow, control) <= 1, "pow({}) = {}", x, pow);
+            }
+        }
+    }
+
+    fn ulp(a: f32, b: f32) -> i32 {
+        (a.to_bits() as i32).wrapping_sub(b.to_bits() as i32).abs()
+    }
+}
@@ -0,0 +1,94 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx512::general::_mm512_rempi_pd;
+use crate::generalf::rempi;
+use crate::sin::{
+    PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
+    SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
+};
+use crate::{_mm512_eqzero_pd, _mm512_mlaf_pd, _mm512_neg_pd, _mm512_rint_pd, _mm512_select_pd};
+
+#[inline(always)]
+/// Computes sine function with *ULP 2.5*
+pub unsafe fn _mm512_sin_pd(d: __m512d) -> __m512d {
+    let q = _mm512_rint_pd(_mm512_mul_pd(
+        d,
+        _mm512_set1_pd(std::f64::consts::FRAC_1_PI),
+    ));
+
+    let qf = _mm512_cvtepi64_pd(q);
+
+    let mut r = _mm512_mlaf_pd(qf, _mm512_set1_pd(-PI_A2), d);
+    r = _mm512_mlaf_pd(qf, _mm512_set1_pd(-PI_B2), r);
+    let (q, mut r) = _mm512_rempi_pd(d, q, r, rempi);
+
+    let x2 = _mm512_mul_pd(r, r);
+
+    let is_odd = _mm512_test_epi64_mask(q, _mm512_set1_epi64(1));
+    r = _mm512_select_pd(is_odd, _mm512_neg_pd(r), r);
+    let mut res = _mm512_set1_pd(SIN_POLY_10_D);
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(SIN_POLY_9_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(SIN_POLY_8_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(SIN_POLY_7_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(SIN_POLY_6_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(SIN_POLY_5_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(SIN_POLY_4_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(SIN_POLY_3_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(SIN_POLY_2_D));
+    res = _mm512_mlaf_pd(res, _mm512_mul_pd(x2, r), r);
+    // sin(±0) = ±0
+    _mm512_select_pd(_mm512_eqzero_pd(d), d, res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sind() {
+        if !std::arch::is_x86_feature_detected!("avx512dq") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_pd(2.);
+            let comparison = _mm512_sin_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = 0.9092974268256817f64;
+            assert_eq!(flag_1, control);
+        }
+
+        unsafe {
+            let value = _mm512_set1_pd(-2.);
+            let comparison = _mm512_sin_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = -0.9092974268256817f64;
+            assert_eq!(flag_1, control);
+        }
+    }
+
+    #[test]
+    fn test_sind_large() {
+        if !std::arch::is_x86_feature_detected!("avx512dq") {
+            return;
+        }
+        for x in [1e6f64, -1e22, 3.5e300] {
+            let value = unsafe {
+                _mm512_cvtsd_f64(_mm512_sin_pd(_mm512_setr_pd(
+                    x, 2., -0.5, 1., 0.25, 3., -3., 5.,
+                )))
+            };
+            let control = x.sin();
+            assert!((value - control).abs() <= 2. * f64::EPSILON * control.abs());
+        }
+    }
+}
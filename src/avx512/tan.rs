@@ -0,0 +1,103 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx512::general::_mm512_rempi_pd;
+use crate::generalf::rempi2;
+use crate::sin::{PI_A2, PI_B2};
+use crate::tan::{
+    TAN_POLY_1_D, TAN_POLY_2_D, TAN_POLY_3_D, TAN_POLY_4_D, TAN_POLY_5_D, TAN_POLY_6_D,
+    TAN_POLY_7_D, TAN_POLY_8_D, TAN_POLY_9_D,
+};
+use crate::{_mm512_mlaf_pd, _mm512_neg_pd, _mm512_rint_pd, _mm512_select_pd};
+
+#[inline(always)]
+/// Computes tan function with error bound *ULP 4.0*
+pub unsafe fn _mm512_tan_pd(d: __m512d) -> __m512d {
+    let q = _mm512_rint_pd(_mm512_mul_pd(
+        d,
+        _mm512_set1_pd(std::f64::consts::FRAC_2_PI),
+    ));
+    let qf = _mm512_cvtepi64_pd(q);
+
+    let mut r = _mm512_mlaf_pd(qf, _mm512_set1_pd(-PI_A2 * 0.5), d);
+    r = _mm512_mlaf_pd(qf, _mm512_set1_pd(-PI_B2 * 0.5), r);
+    let (q, mut r) = _mm512_rempi_pd(d, q, r, rempi2);
+
+    let is_odd = _mm512_test_epi64_mask(q, _mm512_set1_epi64(1));
+    r = _mm512_select_pd(is_odd, _mm512_neg_pd(r), r);
+
+    r = _mm512_mul_pd(r, _mm512_set1_pd(0.5));
+
+    let x2 = _mm512_mul_pd(r, r);
+
+    let mut res = _mm512_set1_pd(TAN_POLY_9_D);
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(TAN_POLY_8_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(TAN_POLY_7_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(TAN_POLY_6_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(TAN_POLY_5_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(TAN_POLY_4_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(TAN_POLY_3_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(TAN_POLY_2_D));
+    res = _mm512_mlaf_pd(res, x2, _mm512_set1_pd(TAN_POLY_1_D));
+    res = _mm512_mlaf_pd(res, _mm512_mul_pd(x2, r), r);
+
+    res = _mm512_div_pd(
+        _mm512_mul_pd(_mm512_set1_pd(2.0), res),
+        _mm512_sub_pd(_mm512_set1_pd(1.0), _mm512_mul_pd(res, res)),
+    );
+
+    res = _mm512_select_pd(is_odd, _mm512_div_pd(_mm512_set1_pd(1.), res), res);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tand() {
+        if !std::arch::is_x86_feature_detected!("avx512dq") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_pd(0.5);
+            let comparison = _mm512_tan_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = 0.5463024898437905f64;
+            assert!((flag_1 - control).abs() < 1e-15);
+        }
+
+        unsafe {
+            let value = _mm512_set1_pd(-1.2);
+            let comparison = _mm512_tan_pd(value);
+            let flag_1 = _mm512_cvtsd_f64(comparison);
+            let control = -2.5721516221263188f64;
+            assert!((flag_1 - control).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_tand_large() {
+        if !std::arch::is_x86_feature_detected!("avx512dq") {
+            return;
+        }
+        for x in [1e6f64, -1e22, 3.5e300] {
+            let value = unsafe {
+                _mm512_cvtsd_f64(_mm512_tan_pd(_mm512_setr_pd(
+                    x, 2., -0.5, 1., 0.25, 3., -3., 5.,
+                )))
+            };
+            let control = x.tan();
+            assert!((value - control).abs() <= 4. * f64::EPSILON * control.abs());
+        }
+    }
+}
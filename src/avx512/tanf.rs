@@ -0,0 +1,94 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::avx512::generalf::_mm512_rempi_ps;
+use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2f;
+use crate::tanf::{
+    TAN_POLY_1_S, TAN_POLY_2_S, TAN_POLY_3_S, TAN_POLY_4_S, TAN_POLY_5_S, TAN_POLY_6_S,
+    TAN_POLY_7_S, TAN_POLY_8_S, TAN_POLY_9_S,
+};
+use crate::{_mm512_isinf_ps, _mm512_mlaf_ps, _mm512_neg_ps, _mm512_rint_ps, _mm512_select_ps};
+
+#[inline(always)]
+/// Computes tan function with error bound *ULP 1.5*
+pub unsafe fn _mm512_tan_ps(d: __m512) -> __m512 {
+    let q = _mm512_rint_ps(_mm512_mul_ps(
+        d,
+        _mm512_set1_ps(std::f32::consts::FRAC_2_PI),
+    ));
+    let qf = _mm512_cvtepi32_ps(q);
+
+    let mut r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-PI_A_F * 0.5), d);
+    r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-PI_B_F * 0.5), r);
+    r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-PI_C_F * 0.5), r);
+    r = _mm512_mlaf_ps(qf, _mm512_set1_ps(-PI_D_F * 0.5), r);
+    let (q, mut r) = _mm512_rempi_ps(d, q, r, rempi2f);
+
+    let is_odd = _mm512_test_epi32_mask(q, _mm512_set1_epi32(1));
+    r = _mm512_select_ps(is_odd, _mm512_neg_ps(r), r);
+
+    let x2 = _mm512_mul_ps(r, r);
+
+    let mut res = _mm512_set1_ps(TAN_POLY_9_S);
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(TAN_POLY_8_S));
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(TAN_POLY_7_S));
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(TAN_POLY_6_S));
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(TAN_POLY_5_S));
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(TAN_POLY_4_S));
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(TAN_POLY_3_S));
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(TAN_POLY_2_S));
+    res = _mm512_mlaf_ps(res, x2, _mm512_set1_ps(TAN_POLY_1_S));
+    res = _mm512_mlaf_ps(res, _mm512_mul_ps(x2, r), r);
+    res = _mm512_select_ps(is_odd, _mm512_div_ps(_mm512_set1_ps(1.), res), res);
+    // tan(±inf) = NaN
+    _mm512_select_ps(_mm512_isinf_ps(d), _mm512_set1_ps(f32::NAN), res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tanf() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        unsafe {
+            let value = _mm512_set1_ps(0.5f32);
+            let comparison = _mm512_tan_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = 0.5463025f32;
+            assert!((flag_1 - control).abs() < 1e-7);
+        }
+
+        unsafe {
+            let value = _mm512_set1_ps(-1.2f32);
+            let comparison = _mm512_tan_ps(value);
+            let flag_1 = _mm512_cvtss_f32(comparison);
+            let control = -2.5721517f32;
+            assert!((flag_1 - control).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_tanf_large() {
+        if !std::arch::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        for x in [1e4f32, -1e22, 3.0e38] {
+            let value = unsafe { _mm512_cvtss_f32(_mm512_tan_ps(_mm512_set1_ps(x))) };
+            let control = (x as f64).tan() as f32;
+            assert!((value - control).abs() <= 4. * f32::EPSILON * control.abs());
+        }
+    }
+}
@@ -0,0 +1,1092 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+//! Slice routines evaluating transcendental functions over whole buffers.
+//!
+//! Each routine picks the widest kernel available on the running CPU (AVX-512, AVX2 with FMA,
+//! SSE 4.1, SSE2 or NEON) and finishes the remainder that doesn't fill a vector with the scalar
+//! function. SSE 4.1 tier runs the same SSE2 kernels compiled with *sse4.1* enabled, native rounding
+//! and 64 bits compares are used only when SSE 4.1 and SSE 4.2 are enabled at compile time.
+//! The CPU is probed once, functions without an AVX-512 kernel use AVX2 on such CPUs.
+//! All slices passed to a routine must have the same length.
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+use std::sync::OnceLock;
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_avx512_available, is_sse41_available};
+
+/// Kernel family used by slice routines, ordered from the narrowest
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum BatchTier {
+    Scalar,
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    Sse,
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    Sse41,
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    Avx2,
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    Avx512,
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    Neon,
+}
+
+fn detect_tier() -> BatchTier {
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    {
+        if is_avx512_available() {
+            return BatchTier::Avx512;
+        }
+        if is_avx2_available() {
+            return BatchTier::Avx2;
+        }
+        #[cfg(target_feature = "sse2")]
+        {
+            if is_sse41_available() {
+                return BatchTier::Sse41;
+            }
+            return BatchTier::Sse;
+        }
+    }
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        return BatchTier::Neon;
+    }
+    #[allow(unreachable_code)]
+    BatchTier::Scalar
+}
+
+#[inline]
+fn batch_tier() -> BatchTier {
+    #[cfg(test)]
+    if let Some(tier) = tests::FORCED_TIER.with(|t| t.get()) {
+        return tier;
+    }
+    static TIER: OnceLock<BatchTier> = OnceLock::new();
+    *TIER.get_or_init(detect_tier)
+}
+
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+/// Declares a routine running `$kernel` over full vectors, returns count of processed items
+macro_rules! batch_runner {
+    (
+        $(#[$attr:meta])*
+        $runner:ident, $t:ty, [$first:ident $(, $rest:ident)*],
+        ($kernel:path, $load:ident, $store:ident, $lanes:expr)
+    ) => {
+        $(#[$attr])*
+        unsafe fn $runner($first: &mut [$t] $(, $rest: &[$t])*) -> usize {
+            let len = $first.len();
+            let mut cx = 0usize;
+            while cx + $lanes <= len {
+                let v = $kernel(
+                    $load($first.as_ptr().add(cx))
+                    $(, $load($rest.as_ptr().add(cx)))*
+                );
+                $store($first.as_mut_ptr().add(cx), v);
+                cx += $lanes;
+            }
+            cx
+        }
+    };
+}
+
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+macro_rules! batch_call {
+    ($runner:ident, [$($arg:ident),+]) => {
+        unsafe { $runner($($arg),+) }
+    };
+}
+
+macro_rules! batch_impl {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $name_in_place:ident, $t:ty, $scalar:path,
+        [$first:ident $(, $rest:ident)*] as $args:tt,
+        sse: $sse:tt,
+        avx: $avx:tt,
+        $(avx512: $avx512:tt,)?
+        neon: $neon:tt
+    ) => {
+        $(#[$meta])*
+        ///
+        /// # Panics
+        /// Panics if slices lengths are not equal
+        pub fn $name($first: &[$t], $($rest: &[$t],)* dst: &mut [$t]) {
+            assert_eq!($first.len(), dst.len(), "Source and destination lengths must match");
+            dst.copy_from_slice($first);
+            $name_in_place(dst $(, $rest)*);
+        }
+
+        $(#[$meta])*
+        /// Result is written back into the first slice.
+        ///
+        /// # Panics
+        /// Panics if slices lengths are not equal
+        pub fn $name_in_place($first: &mut [$t] $(, $rest: &[$t])*) {
+            $(assert_eq!($first.len(), $rest.len(), "Source lengths must match");)*
+
+            let _tier = batch_tier();
+            let mut _processed = 0usize;
+            #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+            {
+                batch_runner!(run_neon, $t, [$first $(, $rest)*], $neon);
+                if _tier == BatchTier::Neon {
+                    _processed = batch_call!(run_neon, [$first $(, $rest)*]);
+                }
+            }
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            {
+                batch_runner!(
+                    #[target_feature(enable = "avx2,fma")]
+                    run_avx, $t, [$first $(, $rest)*], $avx
+                );
+                #[cfg(target_feature = "sse2")]
+                batch_runner!(run_sse, $t, [$first $(, $rest)*], $sse);
+                #[cfg(target_feature = "sse2")]
+                batch_runner!(
+                    #[target_feature(enable = "sse4.1")]
+                    run_sse41, $t, [$first $(, $rest)*], $sse
+                );
+                $(
+                    batch_runner!(
+                        #[target_feature(enable = "avx512f,avx512dq")]
+                        run_avx512, $t, $args, $avx512
+                    );
+                    if _tier == BatchTier::Avx512 {
+                        _processed = batch_call!(run_avx512, $args);
+                    } else
+                )?
+                if _tier >= BatchTier::Avx2 {
+                    _processed = batch_call!(run_avx, [$first $(, $rest)*]);
+                } else if _tier == BatchTier::Sse41 {
+                    #[cfg(target_feature = "sse2")]
+                    {
+                        _processed = batch_call!(run_sse41, [$first $(, $rest)*]);
+                    }
+                } else if _tier == BatchTier::Sse {
+                    #[cfg(target_feature = "sse2")]
+                    {
+                        _processed = batch_call!(run_sse, [$first $(, $rest)*]);
+                    }
+                }
+            }
+            for i in _processed..$first.len() {
+                $first[i] = $scalar($first[i] $(, $rest[i])*);
+            }
+        }
+    };
+}
+
+macro_rules! batch_f32 {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $name_in_place:ident, $scalar:path, [$($arg:ident),+],
+        sse: $sse:path, avx: $avx:path, $(avx512: $avx512:path,)? neon: $neon:path
+    ) => {
+        batch_impl!(
+            $(#[$meta])*
+            $name, $name_in_place, f32, $scalar, [$($arg),+] as [$($arg),+],
+            sse: ($sse, _mm_loadu_ps, _mm_storeu_ps, 4),
+            avx: ($avx, _mm256_loadu_ps, _mm256_storeu_ps, 8),
+            $(avx512: ($avx512, _mm512_loadu_ps, _mm512_storeu_ps, 16),)?
+            neon: ($neon, vld1q_f32, vst1q_f32, 4)
+        );
+    };
+}
+
+macro_rules! batch_f64 {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $name_in_place:ident, $scalar:path, [$($arg:ident),+],
+        sse: $sse:path, avx: $avx:path, $(avx512: $avx512:path,)? neon: $neon:path
+    ) => {
+        batch_impl!(
+            $(#[$meta])*
+            $name, $name_in_place, f64, $scalar, [$($arg),+] as [$($arg),+],
+            sse: ($sse, _mm_loadu_pd, _mm_storeu_pd, 2),
+            avx: ($avx, _mm256_loadu_pd, _mm256_storeu_pd, 4),
+            $(avx512: ($avx512, _mm512_loadu_pd, _mm512_storeu_pd, 8),)?
+            neon: ($neon, vld1q_f64, vst1q_f64, 2)
+        );
+    };
+}
+
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    all(target_arch = "aarch64", target_feature = "neon")
+))]
+/// Declares a routine running `$kernel` returning a pair of vectors over full vectors
+macro_rules! batch_pair_runner {
+    (
+        $(#[$attr:meta])*
+        $runner:ident, $t:ty, ($kernel:path, $load:ident, $store:ident, $lanes:expr)
+    ) => {
+        $(#[$attr])*
+        unsafe fn $runner(src: &[$t], first: &mut [$t], second: &mut [$t]) -> usize {
+            let len = src.len();
+            let mut cx = 0usize;
+            while cx + $lanes <= len {
+                let (a, b) = $kernel($load(src.as_ptr().add(cx)));
+                $store(first.as_mut_ptr().add(cx), a);
+                $store(second.as_mut_ptr().add(cx), b);
+                cx += $lanes;
+            }
+            cx
+        }
+    };
+}
+
+macro_rules! batch_pair_impl {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $t:ty, $scalar:path, [$first:ident, $second:ident],
+        sse: $sse:tt,
+        avx: $avx:tt,
+        $(avx512: $avx512:tt,)?
+        neon: $neon:tt
+    ) => {
+        $(#[$meta])*
+        ///
+        /// # Panics
+        /// Panics if slices lengths are not equal
+        pub fn $name(src: &[$t], $first: &mut [$t], $second: &mut [$t]) {
+            assert_eq!(src.len(), $first.len(), "Source and destination lengths must match");
+            assert_eq!(src.len(), $second.len(), "Source and destination lengths must match");
+
+            let _tier = batch_tier();
+            let mut _processed = 0usize;
+            #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+            {
+                batch_pair_runner!(run_neon, $t, $neon);
+                if _tier == BatchTier::Neon {
+                    _processed = unsafe { run_neon(src, $first, $second) };
+                }
+            }
+            #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+            {
+                batch_pair_runner!(
+                    #[target_feature(enable = "avx2,fma")]
+                    run_avx, $t, $avx
+                );
+                #[cfg(target_feature = "sse2")]
+                batch_pair_runner!(run_sse, $t, $sse);
+                #[cfg(target_feature = "sse2")]
+                batch_pair_runner!(
+                    #[target_feature(enable = "sse4.1")]
+                    run_sse41, $t, $sse
+                );
+                $(
+                    batch_pair_runner!(
+                        #[target_feature(enable = "avx512f,avx512dq")]
+                        run_avx512, $t, $avx512
+                    );
+                    if _tier == BatchTier::Avx512 {
+                        _processed = unsafe { run_avx512(src, $first, $second) };
+                    } else
+                )?
+                if _tier >= BatchTier::Avx2 {
+                    _processed = unsafe { run_avx(src, $first, $second) };
+                } else if _tier == BatchTier::Sse41 {
+                    #[cfg(target_feature = "sse2")]
+                    {
+                        _processed = unsafe { run_sse41(src, $first, $second) };
+                    }
+                } else if _tier == BatchTier::Sse {
+                    #[cfg(target_feature = "sse2")]
+                    {
+                        _processed = unsafe { run_sse(src, $first, $second) };
+                    }
+                }
+            }
+            for i in _processed..src.len() {
+                ($first[i], $second[i]) = $scalar(src[i]);
+            }
+        }
+    };
+}
+
+macro_rules! batch_pair_f32 {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $scalar:path, [$first:ident, $second:ident],
+        sse: $sse:path, avx: $avx:path, $(avx512: $avx512:path,)? neon: $neon:path
+    ) => {
+        batch_pair_impl!(
+            $(#[$meta])*
+            $name, f32, $scalar, [$first, $second],
+            sse: ($sse, _mm_loadu_ps, _mm_storeu_ps, 4),
+            avx: ($avx, _mm256_loadu_ps, _mm256_storeu_ps, 8),
+            $(avx512: ($avx512, _mm512_loadu_ps, _mm512_storeu_ps, 16),)?
+            neon: ($neon, vld1q_f32, vst1q_f32, 4)
+        );
+    };
+}
+
+macro_rules! batch_pair_f64 {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $scalar:path, [$first:ident, $second:ident],
+        sse: $sse:path, avx: $avx:path, $(avx512: $avx512:path,)? neon: $neon:path
+    ) => {
+        batch_pair_impl!(
+            $(#[$meta])*
+            $name, f64, $scalar, [$first, $second],
+            sse: ($sse, _mm_loadu_pd, _mm_storeu_pd, 2),
+            avx: ($avx, _mm256_loadu_pd, _mm256_storeu_pd, 4),
+            $(avx512: ($avx512, _mm512_loadu_pd, _mm512_storeu_pd, 8),)?
+            neon: ($neon, vld1q_f64, vst1q_f64, 2)
+        );
+    };
+}
+
+batch_f32!(
+    /// Computes sine for each value of the slice
+    sin_f32, sin_f32_in_place, crate::esinf, [x],
+    sse: crate::_mm_sin_ps, avx: crate::_mm256_sin_ps,
+    avx512: crate::_mm512_sin_ps, neon: crate::vsinq_f32
+);
+
+batch_f64!(
+    /// Computes sine for each value of the slice
+    sin_f64, sin_f64_in_place, crate::esin, [x],
+    sse: crate::_mm_sin_pd, avx: crate::_mm256_sin_pd,
+    avx512: crate::_mm512_sin_pd, neon: crate::vsinq_f64
+);
+
+batch_f32!(
+    /// Computes cosine for each value of the slice
+    cos_f32, cos_f32_in_place, crate::ecosf, [x],
+    sse: crate::_mm_cos_ps, avx: crate::_mm256_cos_ps,
+    avx512: crate::_mm512_cos_ps, neon: crate::vcosq_f32
+);
+
+batch_f64!(
+    /// Computes cosine for each value of the slice
+    cos_f64, cos_f64_in_place, crate::ecos, [x],
+    sse: crate::_mm_cos_pd, avx: crate::_mm256_cos_pd,
+    avx512: crate::_mm512_cos_pd, neon: crate::vcosq_f64
+);
+
+batch_f32!(
+    /// Computes tangent for each value of the slice
+    tan_f32, tan_f32_in_place, crate::etanf, [x],
+    sse: crate::_mm_tan_ps, avx: crate::_mm256_tan_ps,
+    avx512: crate::_mm512_tan_ps, neon: crate::vtanq_f32
+);
+
+batch_f64!(
+    /// Computes tangent for each value of the slice
+    tan_f64, tan_f64_in_place, crate::etan, [x],
+    sse: crate::_mm_tan_pd, avx: crate::_mm256_tan_pd,
+    avx512: crate::_mm512_tan_pd, neon: crate::vtanq_f64
+);
+
+batch_f32!(
+    /// Computes arcsine for each value of the slice
+    asin_f32, asin_f32_in_place, crate::easinf, [x],
+    sse: crate::_mm_asin_ps, avx: crate::_mm256_asin_ps,
+    avx512: crate::_mm512_asin_ps, neon: crate::vasinq_f32
+);
+
+batch_f64!(
+    /// Computes arcsine for each value of the slice
+    asin_f64, asin_f64_in_place, crate::easin, [x],
+    sse: crate::_mm_asin_pd, avx: crate::_mm256_asin_pd,
+    avx512: crate::_mm512_asin_pd, neon: crate::vasinq_f64
+);
+
+batch_f32!(
+    /// Computes arccosine for each value of the slice
+    acos_f32, acos_f32_in_place, crate::eacosf, [x],
+    sse: crate::_mm_acos_ps, avx: crate::_mm256_acos_ps,
+    avx512: crate::_mm512_acos_ps, neon: crate::vacosq_f32
+);
+
+batch_f64!(
+    /// Computes arccosine for each value of the slice
+    acos_f64, acos_f64_in_place, crate::eacos, [x],
+    sse: crate::_mm_acos_pd, avx: crate::_mm256_acos_pd,
+    avx512: crate::_mm512_acos_pd, neon: crate::vacosq_f64
+);
+
+batch_f32!(
+    /// Computes arctangent for each value of the slice
+    atan_f32, atan_f32_in_place, crate::eatanf, [x],
+    sse: crate::_mm_atan_ps, avx: crate::_mm256_atan_ps,
+    avx512: crate::_mm512_atan_ps, neon: crate::vatanq_f32
+);
+
+batch_f64!(
+    /// Computes arctangent for each value of the slice
+    atan_f64, atan_f64_in_place, crate::eatan, [x],
+    sse: crate::_mm_atan_pd, avx: crate::_mm256_atan_pd,
+    avx512: crate::_mm512_atan_pd, neon: crate::vatanq_f64
+);
+
+batch_f32!(
+    /// Computes arctangent of y/x for each pair of values
+    atan2_f32, atan2_f32_in_place, crate::eatan2f, [y, x],
+    sse: crate::_mm_atan2_ps, avx: crate::_mm256_atan2_ps,
+    avx512: crate::_mm512_atan2_ps, neon: crate::vatan2q_f32
+);
+
+batch_f64!(
+    /// Computes arctangent of y/x for each pair of values
+    atan2_f64, atan2_f64_in_place, crate::eatan2, [y, x],
+    sse: crate::_mm_atan2_pd, avx: crate::_mm256_atan2_pd,
+    avx512: crate::_mm512_atan2_pd, neon: crate::vatan2q_f64
+);
+
+batch_f32!(
+    /// Computes exponent for each value of the slice
+    exp_f32, exp_f32_in_place, crate::eexpf, [x],
+    sse: crate::_mm_exp_ps, avx: crate::_mm256_exp_ps,
+    avx512: crate::_mm512_exp_ps, neon: crate::vexpq_f32
+);
+
+batch_f64!(
+    /// Computes exponent for each value of the slice
+    exp_f64, exp_f64_in_place, crate::eexp, [x],
+    sse: crate::_mm_exp_pd, avx: crate::_mm256_exp_pd,
+    avx512: crate::_mm512_exp_pd, neon: crate::vexpq_f64
+);
+
+batch_f32!(
+    /// Computes natural logarithm for each value of the slice
+    ln_f32, ln_f32_in_place, crate::elnf, [x],
+    sse: crate::_mm_ln_ps, avx: crate::_mm256_ln_ps,
+    avx512: crate::_mm512_ln_ps, neon: crate::vlnq_f32
+);
+
+batch_f64!(
+    /// Computes natural logarithm for each value of the slice
+    ln_f64, ln_f64_in_place, crate::eln, [x],
+    sse: crate::_mm_ln_pd, avx: crate::_mm256_ln_pd,
+    avx512: crate::_mm512_ln_pd, neon: crate::vlnq_f64
+);
+
+batch_f32!(
+    /// Computes power x^n for each pair of values
+    pow_f32, pow_f32_in_place, crate::epowf, [x, n],
+    sse: crate::_mm_pow_ps, avx: crate::_mm256_pow_ps,
+    avx512: crate::_mm512_pow_ps, neon: crate::vpowq_f32
+);
+
+batch_f64!(
+    /// Computes power x^n for each pair of values
+    pow_f64, pow_f64_in_place, crate::epow, [x, n],
+    sse: crate::_mm_pow_pd, avx: crate::_mm256_pow_pd,
+    avx512: crate::_mm512_pow_pd, neon: crate::vpowq_f64
+);
+
+batch_f32!(
+    /// Computes cube root for each value of the slice
+    cbrt_f32, cbrt_f32_in_place, crate::ecbrtf, [x],
+    sse: crate::_mm_cbrt_ps, avx: crate::_mm256_cbrt_ps,
+    avx512: crate::_mm512_cbrt_ps, neon: crate::vcbrtq_f32
+);
+
+batch_f64!(
+    /// Computes cube root for each value of the slice
+    cbrt_f64, cbrt_f64_in_place, crate::ecbrt, [x],
+    sse: crate::_mm_cbrt_pd, avx: crate::_mm256_cbrt_pd,
+    avx512: crate::_mm512_cbrt_pd, neon: crate::vcbrtq_f64
+);
+
+batch_f32!(
+    /// Computes 2D Euclidian distance for each pair of values
+    hypot_f32, hypot_f32_in_place, crate::ehypotf, [x, y],
+    sse: crate::_mm_hypot_ps, avx: crate::_mm256_hypot_ps,
+    avx512: crate::_mm512_hypot_ps, neon: crate::vhypotq_f32
+);
+
+batch_f64!(
+    /// Computes 2D Euclidian distance for each pair of values
+    hypot_f64, hypot_f64_in_place, crate::ehypot, [x, y],
+    sse: crate::_mm_hypot_pd, avx: crate::_mm256_hypot_pd,
+    avx512: crate::_mm512_hypot_pd, neon: crate::vhypotq_f64
+);
+
+batch_f32!(
+    /// Computes 3D Euclidian distance for each triple of values
+    hypot3_f32, hypot3_f32_in_place, crate::ehypot3f, [x, y, z],
+    sse: crate::_mm_hypot3_ps, avx: crate::_mm256_hypot3_ps,
+    avx512: crate::_mm512_hypot3_ps, neon: crate::vhypot3q_f32
+);
+
+batch_f64!(
+    /// Computes 3D Euclidian distance for each triple of values
+    hypot3_f64, hypot3_f64_in_place, crate::hypot3::ehypot3, [x, y, z],
+    sse: crate::_mm_hypot3_pd, avx: crate::_mm256_hypot3_pd,
+    avx512: crate::_mm512_hypot3_pd, neon: crate::vhypot3q_f64
+);
+
+batch_f32!(
+    /// Computes 4D Euclidian distance for each quadruple of values
+    hypot4_f32, hypot4_f32_in_place, crate::ehypot4f, [x, y, z, w],
+    sse: crate::_mm_hypot4_ps, avx: crate::_mm256_hypot4_ps,
+    avx512: crate::_mm512_hypot4_ps, neon: crate::vhypot4q_f32
+);
+
+batch_f64!(
+    /// Computes 4D Euclidian distance for each quadruple of values
+    hypot4_f64, hypot4_f64_in_place, crate::ehypot4, [x, y, z, w],
+    sse: crate::_mm_hypot4_pd, avx: crate::_mm256_hypot4_pd,
+    avx512: crate::_mm512_hypot4_pd, neon: crate::vhypot4q_f64
+);
+
+batch_f32!(
+    /// Computes 2^x for each value of the slice
+    exp2_f32, exp2_f32_in_place, crate::eexp2f, [x],
+    sse: crate::_mm_exp2_ps, avx: crate::_mm256_exp2_ps,
+    neon: crate::vexp2q_f32
+);
+
+batch_f64!(
+    /// Computes 2^x for each value of the slice
+    exp2_f64, exp2_f64_in_place, crate::eexp2, [x],
+    sse: crate::_mm_exp2_pd, avx: crate::_mm256_exp2_pd,
+    neon: crate::vexp2q_f64
+);
+
+batch_f32!(
+    /// Computes 10^x for each value of the slice
+    exp10_f32, exp10_f32_in_place, crate::eexp10f, [x],
+    sse: crate::_mm_exp10_ps, avx: crate::_mm256_exp10_ps,
+    neon: crate::vexp10q_f32
+);
+
+batch_f64!(
+    /// Computes 10^x for each value of the slice
+    exp10_f64, exp10_f64_in_place, crate::eexp10, [x],
+    sse: crate::_mm_exp10_pd, avx: crate::_mm256_exp10_pd,
+    neon: crate::vexp10q_f64
+);
+
+batch_f32!(
+    /// Computes e^x - 1 for each value of the slice
+    expm1_f32, expm1_f32_in_place, crate::eexpm1f, [x],
+    sse: crate::_mm_expm1_ps, avx: crate::_mm256_expm1_ps,
+    neon: crate::vexpm1q_f32
+);
+
+batch_f64!(
+    /// Computes e^x - 1 for each value of the slice
+    expm1_f64, expm1_f64_in_place, crate::eexpm1, [x],
+    sse: crate::_mm_expm1_pd, avx: crate::_mm256_expm1_pd,
+    neon: crate::vexpm1q_f64
+);
+
+batch_f32!(
+    /// Computes base 2 logarithm for each value of the slice
+    log2_f32, log2_f32_in_place, crate::elog2f, [x],
+    sse: crate::_mm_log2_ps, avx: crate::_mm256_log2_ps,
+    neon: crate::vlog2q_f32
+);
+
+batch_f64!(
+    /// Computes base 2 logarithm for each value of the slice
+    log2_f64, log2_f64_in_place, crate::elog2, [x],
+    sse: crate::_mm_log2_pd, avx: crate::_mm256_log2_pd,
+    neon: crate::vlog2q_f64
+);
+
+batch_f32!(
+    /// Computes base 10 logarithm for each value of the slice
+    log10_f32, log10_f32_in_place, crate::elog10f, [x],
+    sse: crate::_mm_log10_ps, avx: crate::_mm256_log10_ps,
+    neon: crate::vlog10q_f32
+);
+
+batch_f64!(
+    /// Computes base 10 logarithm for each value of the slice
+    log10_f64, log10_f64_in_place, crate::elog10, [x],
+    sse: crate::_mm_log10_pd, avx: crate::_mm256_log10_pd,
+    neon: crate::vlog10q_f64
+);
+
+batch_f32!(
+    /// Computes ln(1 + x) for each value of the slice
+    log1p_f32, log1p_f32_in_place, crate::elog1pf, [x],
+    sse: crate::_mm_log1p_ps, avx: crate::_mm256_log1p_ps,
+    neon: crate::vlog1pq_f32
+);
+
+batch_f64!(
+    /// Computes ln(1 + x) for each value of the slice
+    log1p_f64, log1p_f64_in_place, crate::elog1p, [x],
+    sse: crate::_mm_log1p_pd, avx: crate::_mm256_log1p_pd,
+    neon: crate::vlog1pq_f64
+);
+
+batch_f32!(
+    /// Computes hyperbolic sine for each value of the slice
+    sinh_f32, sinh_f32_in_place, crate::esinhf, [x],
+    sse: crate::_mm_sinh_ps, avx: crate::_mm256_sinh_ps,
+    neon: crate::vsinhq_f32
+);
+
+batch_f64!(
+    /// Computes hyperbolic sine for each value of the slice
+    sinh_f64, sinh_f64_in_place, crate::esinh, [x],
+    sse: crate::_mm_sinh_pd, avx: crate::_mm256_sinh_pd,
+    neon: crate::vsinhq_f64
+);
+
+batch_f32!(
+    /// Computes hyperbolic cosine for each value of the slice
+    cosh_f32, cosh_f32_in_place, crate::ecoshf, [x],
+    sse: crate::_mm_cosh_ps, avx: crate::_mm256_cosh_ps,
+    neon: crate::vcoshq_f32
+);
+
+batch_f64!(
+    /// Computes hyperbolic cosine for each value of the slice
+    cosh_f64, cosh_f64_in_place, crate::ecosh, [x],
+    sse: crate::_mm_cosh_pd, avx: crate::_mm256_cosh_pd,
+    neon: crate::vcoshq_f64
+);
+
+batch_f32!(
+    /// Computes hyperbolic tangent for each value of the slice
+    tanh_f32, tanh_f32_in_place, crate::etanhf, [x],
+    sse: crate::_mm_tanh_ps, avx: crate::_mm256_tanh_ps,
+    neon: crate::vtanhq_f32
+);
+
+batch_f64!(
+    /// Computes hyperbolic tangent for each value of the slice
+    tanh_f64, tanh_f64_in_place, crate::etanh, [x],
+    sse: crate::_mm_tanh_pd, avx: crate::_mm256_tanh_pd,
+    neon: crate::vtanhq_f64
+);
+
+batch_f32!(
+    /// Computes inverse hyperbolic sine for each value of the slice
+    asinh_f32, asinh_f32_in_place, crate::easinhf, [x],
+    sse: crate::_mm_asinh_ps, avx: crate::_mm256_asinh_ps,
+    neon: crate::vasinhq_f32
+);
+
+batch_f64!(
+    /// Computes inverse hyperbolic sine for each value of the slice
+    asinh_f64, asinh_f64_in_place, crate::easinh, [x],
+    sse: crate::_mm_asinh_pd, avx: crate::_mm256_asinh_pd,
+    neon: crate::vasinhq_f64
+);
+
+batch_f32!(
+    /// Computes inverse hyperbolic cosine for each value of the slice
+    acosh_f32, acosh_f32_in_place, crate::eacoshf, [x],
+    sse: crate::_mm_acosh_ps, avx: crate::_mm256_acosh_ps,
+    neon: crate::vacoshq_f32
+);
+
+batch_f64!(
+    /// Computes inverse hyperbolic cosine for each value of the slice
+    acosh_f64, acosh_f64_in_place, crate::eacosh, [x],
+    sse: crate::_mm_acosh_pd, avx: crate::_mm256_acosh_pd,
+    neon: crate::vacoshq_f64
+);
+
+batch_f32!(
+    /// Computes inverse hyperbolic tangent for each value of the slice
+    atanh_f32, atanh_f32_in_place, crate::eatanhf, [x],
+    sse: crate::_mm_atanh_ps, avx: crate::_mm256_atanh_ps,
+    neon: crate::vatanhq_f32
+);
+
+batch_f64!(
+    /// Computes inverse hyperbolic tangent for each value of the slice
+    atanh_f64, atanh_f64_in_place, crate::eatanh, [x],
+    sse: crate::_mm_atanh_pd, avx: crate::_mm256_atanh_pd,
+    neon: crate::vatanhq_f64
+);
+
+batch_f32!(
+    /// Computes error function for each value of the slice
+    erf_f32, erf_f32_in_place, crate::eerff, [x],
+    sse: crate::_mm_erf_ps, avx: crate::_mm256_erf_ps,
+    neon: crate::verfq_f32
+);
+
+batch_f64!(
+    /// Computes error function for each value of the slice
+    erf_f64, erf_f64_in_place, crate::eerf, [x],
+    sse: crate::_mm_erf_pd, avx: crate::_mm256_erf_pd,
+    neon: crate::verfq_f64
+);
+
+batch_f32!(
+    /// Computes complementary error function for each value of the slice
+    erfc_f32, erfc_f32_in_place, crate::eerfcf, [x],
+    sse: crate::_mm_erfc_ps, avx: crate::_mm256_erfc_ps,
+    neon: crate::verfcq_f32
+);
+
+batch_f64!(
+    /// Computes complementary error function for each value of the slice
+    erfc_f64, erfc_f64_in_place, crate::eerfc, [x],
+    sse: crate::_mm_erfc_pd, avx: crate::_mm256_erfc_pd,
+    neon: crate::verfcq_f64
+);
+
+batch_f32!(
+    /// Computes gamma function for each value of the slice
+    tgamma_f32, tgamma_f32_in_place, crate::etgammaf, [x],
+    sse: crate::_mm_tgamma_ps, avx: crate::_mm256_tgamma_ps,
+    neon: crate::vtgammaq_f32
+);
+
+batch_f64!(
+    /// Computes gamma function for each value of the slice
+    tgamma_f64, tgamma_f64_in_place, crate::etgamma, [x],
+    sse: crate::_mm_tgamma_pd, avx: crate::_mm256_tgamma_pd,
+    neon: crate::vtgammaq_f64
+);
+
+batch_f32!(
+    /// Computes remainder of x/y truncated towards zero for each pair of values
+    fmod_f32, fmod_f32_in_place, crate::efmodf, [x, y],
+    sse: crate::_mm_fmod_ps, avx: crate::_mm256_fmod_ps,
+    avx512: crate::_mm512_fmod_ps,
+    neon: crate::vfmodq_f32
+);
+
+batch_f64!(
+    /// Computes remainder of x/y truncated towards zero for each pair of values
+    fmod_f64, fmod_f64_in_place, crate::efmod, [x, y],
+    sse: crate::_mm_fmod_pd, avx: crate::_mm256_fmod_pd,
+    avx512: crate::_mm512_fmod_pd,
+    neon: crate::vfmodq_f64
+);
+
+batch_pair_f32!(
+    /// Computes sine and cosine with a single argument reduction for each value of the slice
+    sincos_f32, crate::esincosf, [sin, cos],
+    sse: crate::_mm_sincos_ps, avx: crate::_mm256_sincos_ps,
+    avx512: crate::_mm512_sincos_ps, neon: crate::vsincosq_f32
+);
+
+batch_pair_f64!(
+    /// Computes sine and cosine with a single argument reduction for each value of the slice
+    sincos_f64, crate::esincos, [sin, cos],
+    sse: crate::_mm_sincos_pd, avx: crate::_mm256_sincos_pd,
+    avx512: crate::_mm512_sincos_pd, neon: crate::vsincosq_f64
+);
+
+fn lgammaf_signed(x: f32) -> (f32, f32) {
+    let (value, sign) = crate::elgammaf(x);
+    (value, sign as f32)
+}
+
+fn lgamma_signed(x: f64) -> (f64, f64) {
+    let (value, sign) = crate::elgamma(x);
+    (value, sign as f64)
+}
+
+batch_pair_f32!(
+    /// Computes ln|Γ(x)| and the sign of Γ(x) as ±1 for each value of the slice
+    lgamma_f32, lgammaf_signed, [dst, sign],
+    sse: crate::_mm_lgamma_ps, avx: crate::_mm256_lgamma_ps,
+    neon: crate::vlgammaq_f32
+);
+
+batch_pair_f64!(
+    /// Computes ln|Γ(x)| and the sign of Γ(x) as ±1 for each value of the slice
+    lgamma_f64, lgamma_signed, [dst, sign],
+    sse: crate::_mm_lgamma_pd, avx: crate::_mm256_lgamma_pd,
+    neon: crate::vlgammaq_f64
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        pub(super) static FORCED_TIER: Cell<Option<BatchTier>> = const { Cell::new(None) };
+    }
+
+    fn assert_close_f32(v: f32, e: f32, x: impl std::fmt::Debug) {
+        assert!(
+            v == e || (v.is_nan() && e.is_nan()) || (v - e).abs() <= 2e-5 * e.abs().max(1e-30),
+            "f({:?}) = {}, expected {}",
+            x,
+            v,
+            e
+        );
+    }
+
+    fn assert_close_f64(v: f64, e: f64, x: impl std::fmt::Debug) {
+        assert!(
+            v == e || (v.is_nan() && e.is_nan()) || (v - e).abs() <= 1e-12 * e.abs().max(1e-300),
+            "f({:?}) = {}, expected {}",
+            x,
+            v,
+            e
+        );
+    }
+
+    /// Calls each slice routine and compares every lane with the scalar function
+    macro_rules! check_routines {
+        (@one $assert:ident, [$($src:ident),+], $dst:ident, $batch:ident, $scalar:path) => {
+            $batch($(&$src,)+ &mut $dst);
+            for i in 0..$dst.len() {
+                $assert($dst[i], $scalar($($src[i]),+), ($($src[i]),+));
+            }
+        };
+        ($assert:ident, $src:tt, $dst:ident, $($batch:ident => $scalar:path),+ $(,)?) => {
+            $(check_routines!(@one $assert, $src, $dst, $batch, $scalar);)+
+        };
+    }
+
+    /// Runs slice routines on the forced tier and compares them with scalar functions
+    fn check_tier(tier: BatchTier) {
+        FORCED_TIER.with(|t| t.set(Some(tier)));
+        assert_eq!(batch_tier(), tier);
+
+        // 37 isn't a multiple of any vector width, so the scalar tail is covered too.
+        // Neighbouring lanes get different magnitudes, so kernels mixing lanes are caught
+        const SCALES: [f32; 5] = [1., 0.01, 5., 0.3, 2.5];
+        let x32: Vec<f32> = (0..37)
+            .map(|i| (i as f32 * 0.173f32 - 3.05f32) * SCALES[i % 5])
+            .collect();
+        let y32: Vec<f32> = (0..37)
+            .map(|i| (i as f32 * 0.071f32 + 0.4f32) * SCALES[(i + 2) % 5])
+            .collect();
+        let z32: Vec<f32> = y32.iter().map(|&v| v * 1.7f32 - 0.3f32).collect();
+        let w32: Vec<f32> = x32.iter().map(|&v| v * 0.6f32 + 0.1f32).collect();
+        let unit32: Vec<f32> = x32.iter().map(|&v| v / 16.8f32).collect();
+        let above32: Vec<f32> = y32.iter().map(|&v| v + 1f32).collect();
+        let wide32: Vec<f32> = (0..37)
+            .map(|i| {
+                const MIXED: [f32; 12] =
+                    [1., 1.5, 27., 3., 4., 8., 8., 8., 8., -1e-40, 1e30, -3.4e38];
+                MIXED[i % 12] * (1. + i as f32 * 0.01)
+            })
+            .collect();
+        let [x64, y64, z64, w64, unit64, above64, wide64] =
+            [&x32, &y32, &z32, &w32, &unit32, &above32, &wide32]
+                .map(|v| v.iter().map(|&v| v as f64).collect::<Vec<f64>>());
+
+        let mut dst32 = vec![0f32; x32.len()];
+        let mut aux32 = vec![0f32; x32.len()];
+        let mut dst64 = vec![0f64; x64.len()];
+        let mut aux64 = vec![0f64; x64.len()];
+
+        check_routines!(
+            assert_close_f32, [x32], dst32,
+            sin_f32 => crate::esinf,
+            cos_f32 => crate::ecosf,
+            tan_f32 => crate::etanf,
+            atan_f32 => crate::eatanf,
+            exp_f32 => crate::eexpf,
+            exp2_f32 => crate::eexp2f,
+            exp10_f32 => crate::eexp10f,
+            expm1_f32 => crate::eexpm1f,
+            sinh_f32 => crate::esinhf,
+            cosh_f32 => crate::ecoshf,
+            tanh_f32 => crate::etanhf,
+            asinh_f32 => crate::easinhf,
+            erf_f32 => crate::eerff,
+            erfc_f32 => crate::eerfcf,
+            cbrt_f32 => crate::ecbrtf,
+        );
+        check_routines!(
+            assert_close_f32, [unit32], dst32,
+            asin_f32 => crate::easinf,
+            acos_f32 => crate::eacosf,
+            atanh_f32 => crate::eatanhf,
+        );
+        check_routines!(
+            assert_close_f32, [y32], dst32,
+            ln_f32 => crate::elnf,
+            log2_f32 => crate::elog2f,
+            log10_f32 => crate::elog10f,
+            log1p_f32 => crate::elog1pf,
+            tgamma_f32 => crate::etgammaf,
+        );
+        check_routines!(assert_close_f32, [above32], dst32, acosh_f32 => crate::eacoshf);
+        check_routines!(assert_close_f32, [wide32], dst32, cbrt_f32 => crate::ecbrtf);
+        check_routines!(
+            assert_close_f32, [x32, y32], dst32,
+            atan2_f32 => crate::eatan2f,
+            hypot_f32 => crate::ehypotf,
+        );
+        check_routines!(assert_close_f32, [y32, x32], dst32, pow_f32 => crate::epowf);
+        check_routines!(assert_close_f32, [x32, y32, z32], dst32, hypot3_f32 => crate::ehypot3f);
+        check_routines!(
+            assert_close_f32, [x32, y32, z32, w32], dst32,
+            hypot4_f32 => crate::ehypot4f,
+        );
+        fmod_f32(&x32, &y32, &mut dst32);
+        for ((&x, &y), &v) in x32.iter().zip(y32.iter()).zip(dst32.iter()) {
+            assert_eq!(v, crate::efmodf(x, y), "fmod({}, {})", x, y);
+        }
+        sincos_f32(&x32, &mut dst32, &mut aux32);
+        for ((&x, &s), &c) in x32.iter().zip(dst32.iter()).zip(aux32.iter()) {
+            let (es, ec) = crate::esincosf(x);
+            assert_close_f32(s, es, x);
+            assert_close_f32(c, ec, x);
+        }
+        lgamma_f32(&x32, &mut dst32, &mut aux32);
+        for ((&x, &v), &sign) in x32.iter().zip(dst32.iter()).zip(aux32.iter()) {
+            let (e, es) = crate::elgammaf(x);
+            assert_close_f32(v, e, x);
+            assert_eq!(sign, es as f32, "sign of lgamma({})", x);
+        }
+
+        check_routines!(
+            assert_close_f64, [x64], dst64,
+            sin_f64 => crate::esin,
+            cos_f64 => crate::ecos,
+            tan_f64 => crate::etan,
+            atan_f64 => crate::eatan,
+            exp_f64 => crate::eexp,
+            exp2_f64 => crate::eexp2,
+            exp10_f64 => crate::eexp10,
+            expm1_f64 => crate::eexpm1,
+            sinh_f64 => crate::esinh,
+            cosh_f64 => crate::ecosh,
+            tanh_f64 => crate::etanh,
+            asinh_f64 => crate::easinh,
+            erf_f64 => crate::eerf,
+            erfc_f64 => crate::eerfc,
+            cbrt_f64 => crate::ecbrt,
+        );
+        check_routines!(
+            assert_close_f64, [unit64], dst64,
+            asin_f64 => crate::easin,
+            acos_f64 => crate::eacos,
+            atanh_f64 => crate::eatanh,
+        );
+        check_routines!(
+            assert_close_f64, [y64], dst64,
+            ln_f64 => crate::eln,
+            log2_f64 => crate::elog2,
+            log10_f64 => crate::elog10,
+            log1p_f64 => crate::elog1p,
+            tgamma_f64 => crate::etgamma,
+        );
+        check_routines!(assert_close_f64, [above64], dst64, acosh_f64 => crate::eacosh);
+        check_routines!(assert_close_f64, [wide64], dst64, cbrt_f64 => crate::ecbrt);
+        check_routines!(
+            assert_close_f64, [x64, y64], dst64,
+            atan2_f64 => crate::eatan2,
+            hypot_f64 => crate::ehypot,
+        );
+        check_routines!(assert_close_f64, [y64, x64], dst64, pow_f64 => crate::epow);
+        check_routines!(
+            assert_close_f64, [x64, y64, z64], dst64,
+            hypot3_f64 => crate::hypot3::ehypot3,
+        );
+        check_routines!(
+            assert_close_f64, [x64, y64, z64, w64], dst64,
+            hypot4_f64 => crate::ehypot4,
+        );
+        fmod_f64(&x64, &y64, &mut dst64);
+        for ((&x, &y), &v) in x64.iter().zip(y64.iter()).zip(dst64.iter()) {
+            assert_eq!(v, crate::efmod(x, y), "fmod({}, {})", x, y);
+        }
+        sincos_f64(&x64, &mut dst64, &mut aux64);
+        for ((&x, &s), &c) in x64.iter().zip(dst64.iter()).zip(aux64.iter()) {
+            let (es, ec) = crate::esincos(x);
+            assert_close_f64(s, es, x);
+            assert_close_f64(c, ec, x);
+        }
+        lgamma_f64(&x64, &mut dst64, &mut aux64);
+        for ((&x, &v), &sign) in x64.iter().zip(dst64.iter()).zip(aux64.iter()) {
+            let (e, es) = crate::elgamma(x);
+            assert_close_f64(v, e, x);
+            assert_eq!(sign, es as f64, "sign of lgamma({})", x);
+        }
+
+        FORCED_TIER.with(|t| t.set(None));
+    }
+
+    #[test]
+    fn test_batch_scalar() {
+        check_tier(BatchTier::Scalar);
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    #[test]
+    fn test_batch_sse() {
+        check_tier(BatchTier::Sse);
+    }
+
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    #[test]
+    fn test_batch_sse41() {
+        if is_sse41_available() {
+            check_tier(BatchTier::Sse41);
+        }
+    }
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    #[test]
+    fn test_batch_avx2() {
+        if is_avx2_available() {
+            check_tier(BatchTier::Avx2);
+        }
+    }
+
+    #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+    #[test]
+    fn test_batch_avx512() {
+        if is_avx512_available() {
+            check_tier(BatchTier::Avx512);
+        }
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    #[test]
+    fn test_batch_neon() {
+        check_tier(BatchTier::Neon);
+    }
+
+    #[test]
+    fn test_batch() {
+        let src: Vec<f32> = (0..19).map(|i| i as f32 * 0.37f32 - 3.1f32).collect();
+        let mut dst = vec![0f32; src.len()];
+        sin_f32(&src, &mut dst);
+        for (&s, &d) in src.iter().zip(dst.iter()) {
+            assert!((crate::esinf(s) - d).abs() < 1e-6, "sin({}) = {}", s, d);
+        }
+
+        let mut x: Vec<f64> = (0..11).map(|i| i as f64 * 0.5f64 + 0.25f64).collect();
+        let n: Vec<f64> = (0..11).map(|i| i as f64 * 0.3f64 - 1.2f64).collect();
+        let expected: Vec<f64> = x.iter().zip(n.iter()).map(|(&a, &b)| a.powf(b)).collect();
+        pow_f64_in_place(&mut x, &n);
+        for (&v, &e) in x.iter().zip(expected.iter()) {
+            assert!((v - e).abs() < 1e-12 * e.abs().max(1.), "{} != {}", v, e);
+        }
+    }
+}
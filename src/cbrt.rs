@@ -9,22 +9,47 @@
 use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
 
 use crate::cbrtf::halley_cbrt;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_cbrt;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vcbrtq_f64;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_cbrt_pd, _mm_extract_pd};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 const B1: u32 = 715094163;
 
+/// Subnormals are scaled by 2^54 before the initial guess, 2^18 is taken back from the result
+pub(crate) const CBRT_SCALE_D: f64 = 18_014_398_509_481_984f64;
+pub(crate) const CBRT_SCALE_R_D: f64 = 262_144f64;
+/// Above this x^3 in Halley step may overflow, so the argument is scaled down
+pub(crate) const CBRT_HUGE_D: f64 = 1e300;
+/// Huge values are scaled by 2^-999 into a few binades above one, 2^333 is put back after
+pub(crate) const CBRT_HUGE_SCALE_D: f64 = f64::from_bits(0x0180_0000_0000_0000);
+pub(crate) const CBRT_HUGE_SCALE_R_D: f64 = f64::from_bits(0x54c0_0000_0000_0000);
+
+/// Newton step from t rounded to 22 bits, t*t is exact so the result is within 0.67 ulp
+#[inline(always)]
+fn newton_round(t: f64, a: f64) -> f64 {
+    let t = f64::from_bits((t.to_bits() + 0x8000_0000) & 0xffff_ffff_c000_0000);
+    let s = t * t;
+    let r = a / s;
+    let w = t + t;
+    let r = (r - t) / (w + r);
+    t + t * r
+}
+
 #[inline]
-fn do_ecbrt(x: f64) -> f64 {
+pub(crate) fn do_ecbrt(x: f64) -> f64 {
     if x == 0f64 {
         return x;
     }
@@ -34,6 +59,13 @@ fn do_ecbrt(x: f64) -> f64 {
     if x == f64::NEG_INFINITY {
         return f64::NEG_INFINITY;
     }
+    let (x, scale) = if x.abs() < f64::MIN_POSITIVE {
+        (x * CBRT_SCALE_D, 1. / CBRT_SCALE_R_D)
+    } else if x.abs() > CBRT_HUGE_D {
+        (x * CBRT_HUGE_SCALE_D, CBRT_HUGE_SCALE_R_D)
+    } else {
+        (x, 1.)
+    };
     let mut ui: u64 = x.to_bits();
     let t: f64;
     let mut hx: u32 = (ui >> 32) as u32 & 0x7fffffff;
@@ -45,7 +77,7 @@ fn do_ecbrt(x: f64) -> f64 {
     let c1 = halley_cbrt(t, x);
     let c2 = halley_cbrt(c1, x);
     let c3 = halley_cbrt(c2, x);
-    c3
+    newton_round(c3, x) * scale
 }
 
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
@@ -58,13 +90,36 @@ fn do_cbrt_neon(d: f64) -> f64 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
-fn do_cbrt_sse(d: f64) -> f64 {
-    unsafe {
-        let ld = _mm_set1_pd(d);
-        _mm_extract_pd::<0>(_mm_cbrt_pd(ld))
-    }
+#[inline(always)]
+unsafe fn do_cbrt_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_cbrt_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_cbrt_sse41(d: f64) -> f64 {
+    do_cbrt_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_cbrt_fma(d: f64) -> f64 {
+    do_ecbrt(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_cbrt_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_cbrt(f64x2_splat(d)))
 }
 
 /// Computes Cube Root *ULP 2.0*
@@ -74,12 +129,25 @@ pub fn ecbrt(x: f64) -> f64 {
     {
         _dispatcher = do_cbrt_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_cbrt_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_cbrt_sse;
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_cbrt_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_cbrt_sse41(d) }
+            } else {
+                |d| unsafe { do_cbrt_sse(d) }
+            }
+        });
     }
     _dispatcher(x)
 }
@@ -9,18 +9,23 @@
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
 use std::ops::{Add, Div, Mul};
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_cbrt_ps;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_cbrt;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vcbrtq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_cbrt_ps, _mm_extract_psx};
 use num_traits::AsPrimitive;
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 #[inline]
 pub(crate) fn halley_cbrt<T: Copy + Mul<Output = T> + Div<Output = T> + Add<Output = T> + 'static>(
@@ -31,11 +36,17 @@ where
     f32: AsPrimitive<T>,
 {
     let tx = x * x * x;
-    x * (tx + 2f32.as_() * a) / (2f32.as_() * tx + a)
+    x * ((tx + 2f32.as_() * a) / (2f32.as_() * tx + a))
 }
 
 const B1: u32 = 709958130;
 
+/// Subnormals are scaled by 2^24 before the initial guess, 2^8 is taken back from the result
+pub(crate) const CBRT_SCALE_F: f32 = 16_777_216f32;
+pub(crate) const CBRT_SCALE_R_F: f32 = 256f32;
+/// Above this x^3 in Halley step may overflow, so the argument is scaled down
+pub(crate) const CBRT_HUGE_F: f32 = 1e30;
+
 #[inline]
 fn do_cbrtf(x: f32) -> f32 {
     if x == 0. {
@@ -48,6 +59,13 @@ fn do_cbrtf(x: f32) -> f32 {
         return f32::NEG_INFINITY;
     }
 
+    let (x, scale) = if x.abs() < f32::MIN_POSITIVE {
+        (x * CBRT_SCALE_F, 1. / CBRT_SCALE_R_F)
+    } else if x.abs() > CBRT_HUGE_F {
+        (x * (1. / CBRT_SCALE_F), CBRT_SCALE_R_F)
+    } else {
+        (x, 1.)
+    };
     let mut t: f32;
     let mut ui: u32 = x.to_bits();
     let mut hx: u32 = ui & 0x7fffffff;
@@ -59,7 +77,7 @@ fn do_cbrtf(x: f32) -> f32 {
     t = f32::from_bits(ui);
     t = halley_cbrt(t, x);
     t = halley_cbrt(t, x);
-    t
+    t * scale
 }
 
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
@@ -73,16 +91,37 @@ fn do_cbrtf_neon(d: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_cbrt_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_cbrt_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_cbrt_sse41(d: f32) -> f32 {
+    do_cbrt_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_cbrt_fma(d: f32) -> f32 {
+    do_cbrtf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_cbrt_sse(d: f32) -> f32 {
-    unsafe {
-        let v = _mm_set1_ps(d);
-        let value = _mm_cbrt_ps(v);
-        let ex = f32::from_bits(_mm_extract_ps::<0>(value) as u32);
-        ex
-    }
+fn do_cbrt_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_cbrt(f32x4_splat(d)))
 }
 
 /// Takes cube root from value *ULP 1.5*
@@ -93,12 +132,25 @@ pub fn ecbrtf(x: f32) -> f32 {
     {
         _dispatcher = do_cbrtf_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_cbrt_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_cbrt_sse;
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_cbrt_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_cbrt_sse41(d) }
+            } else {
+                |d| unsafe { do_cbrt_sse(d) }
+            }
+        });
     }
     _dispatcher(x)
 }
@@ -0,0 +1,788 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+//! C99 Annex F special values, checked against every backend.
+//!
+//! Tables are stored in f64 and every entry is exactly representable in f32 as well,
+//! or overflows to infinity on both sides, so the same table drives both precisions. Zeros, infinities and ±1 must match
+//! bit for bit, finite results like π/2 are allowed a few ulp.
+
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+const INF: f64 = f64::INFINITY;
+const NINF: f64 = f64::NEG_INFINITY;
+const NAN: f64 = f64::NAN;
+
+/// `(x, f(x))`
+type Unary = &'static [(f64, f64)];
+/// `(x, y, f(x, y))`
+type Binary = &'static [(f64, f64, f64)];
+
+const EXP: Unary = &[(0., 1.), (-0., 1.), (INF, INF), (NINF, 0.), (NAN, NAN)];
+const EXPM1: Unary = &[(0., 0.), (-0., -0.), (INF, INF), (NINF, -1.), (NAN, NAN)];
+const LOG: Unary = &[
+    (0., NINF),
+    (-0., NINF),
+    (1., 0.),
+    (-1., NAN),
+    (INF, INF),
+    (NINF, NAN),
+    (NAN, NAN),
+];
+const LOG1P: Unary = &[
+    (0., 0.),
+    (-0., -0.),
+    (-1., NINF),
+    (-2., NAN),
+    (INF, INF),
+    (NINF, NAN),
+    (NAN, NAN),
+];
+const SIN: Unary = &[(0., 0.), (-0., -0.), (INF, NAN), (NINF, NAN), (NAN, NAN)];
+const COS: Unary = &[(0., 1.), (-0., 1.), (INF, NAN), (NINF, NAN), (NAN, NAN)];
+const ASIN: Unary = &[
+    (0., 0.),
+    (-0., -0.),
+    (1., FRAC_PI_2),
+    (-1., -FRAC_PI_2),
+    (2., NAN),
+    (-2., NAN),
+    (NAN, NAN),
+];
+const ACOS: Unary = &[
+    (1., 0.),
+    (-1., PI),
+    (0., FRAC_PI_2),
+    (2., NAN),
+    (-2., NAN),
+    (NAN, NAN),
+];
+const ATAN: Unary = &[
+    (0., 0.),
+    (-0., -0.),
+    (INF, FRAC_PI_2),
+    (NINF, -FRAC_PI_2),
+    (NAN, NAN),
+];
+/// Odd functions going to ±inf: sinh, asinh and cbrt
+const ODD_INF: Unary = &[(0., 0.), (-0., -0.), (INF, INF), (NINF, NINF), (NAN, NAN)];
+/// Odd functions going to ±1: tanh and erf
+/// Neighbouring rows differ in sign and magnitude, so a kernel mixing lanes fails `check_lanes`
+const CBRT_LANES: Unary = &[
+    (27., 3.),
+    (-1.9938419936773738e36, -1258626620168.1848),
+    (0.125, 0.5),
+    (7.174648137343064e-43, 8.952277496340409e-15),
+    (-8., -2.),
+    (1.2676506002282294e30, 10822639409.68093),
+    (1.5, 1.1447142425533319),
+    (-7.346839692639297e-40, -9.023330289711564e-14),
+    (-1024., -10.079368399158986),
+    (0., 0.),
+    (3., 1.4422495703074083),
+    (NINF, NINF),
+    (0.25, 0.6299605249474366),
+    (NAN, NAN),
+    (-5., -1.709975946676697),
+    (INF, INF),
+];
+/// Above f32 range, f32 kernels see infinities here
+const CBRT_HUGE: Unary = &[
+    (1e300, 1e100),
+    (-1e300, -1e100),
+    (1e307, 2.1544346900318838e102),
+    (-1e307, -2.1544346900318838e102),
+    (f64::MAX, 5.643803094122362e102),
+    (f64::MIN, -5.643803094122362e102),
+];
+const ODD_ONE: Unary = &[(0., 0.), (-0., -0.), (INF, 1.), (NINF, -1.), (NAN, NAN)];
+const COSH: Unary = &[(0., 1.), (-0., 1.), (INF, INF), (NINF, INF), (NAN, NAN)];
+const ACOSH: Unary = &[
+    (1., 0.),
+    (0.5, NAN),
+    (-1., NAN),
+    (INF, INF),
+    (NINF, NAN),
+    (NAN, NAN),
+];
+const ATANH: Unary = &[
+    (0., 0.),
+    (-0., -0.),
+    (1., INF),
+    (-1., NINF),
+    (2., NAN),
+    (-2., NAN),
+    (NAN, NAN),
+];
+const ERFC: Unary = &[(0., 1.), (INF, 0.), (NINF, 2.), (NAN, NAN)];
+const TGAMMA: Unary = &[
+    (0., INF),
+    (-0., NINF),
+    (-1., NAN),
+    (-2., NAN),
+    (INF, INF),
+    (NINF, NAN),
+    (NAN, NAN),
+];
+const LGAMMA: Unary = &[
+    (1., 0.),
+    (2., 0.),
+    (0., INF),
+    (-0., INF),
+    (-1., INF),
+    (-2., INF),
+    (INF, INF),
+    (NINF, INF),
+    (NAN, NAN),
+];
+
+const POW: Binary = &[
+    (2., 0., 1.),
+    (NAN, 0., 1.),
+    (NAN, -0., 1.),
+    (1., NAN, 1.),
+    (1., INF, 1.),
+    (1., NINF, 1.),
+    (-1., INF, 1.),
+    (-1., NINF, 1.),
+    (0., 3., 0.),
+    (-0., 3., -0.),
+    (0., 2., 0.),
+    (-0., 2., 0.),
+    (0., -3., INF),
+    (-0., -3., NINF),
+    (0., -2., INF),
+    (-0., -2., INF),
+    (0., NINF, INF),
+    (-0., NINF, INF),
+    (0.5, NINF, INF),
+    (-0.5, NINF, INF),
+    (2., NINF, 0.),
+    (-2., NINF, 0.),
+    (0.5, INF, 0.),
+    (-0.5, INF, 0.),
+    (2., INF, INF),
+    (-2., INF, INF),
+    (NINF, -3., -0.),
+    (NINF, -2., 0.),
+    (NINF, 3., NINF),
+    (NINF, 2., INF),
+    (INF, -1., 0.),
+    (INF, 2., INF),
+    (-2., 3., -8.),
+    (-2., 0.5, NAN),
+    (NINF, 0.5, INF),
+    (NAN, 1., NAN),
+    (2., NAN, NAN),
+];
+const ATAN2: Binary = &[
+    (0., 0., 0.),
+    (-0., 0., -0.),
+    (0., -0., PI),
+    (-0., -0., -PI),
+    (0., -1., PI),
+    (-0., -1., -PI),
+    (0., 1., 0.),
+    (-0., 1., -0.),
+    (1., 0., FRAC_PI_2),
+    (1., -0., FRAC_PI_2),
+    (-1., 0., -FRAC_PI_2),
+    (-1., -0., -FRAC_PI_2),
+    (1., NINF, PI),
+    (-1., NINF, -PI),
+    (1., INF, 0.),
+    (-1., INF, -0.),
+    (INF, 1., FRAC_PI_2),
+    (NINF, 1., -FRAC_PI_2),
+    (INF, NINF, 3. * FRAC_PI_4),
+    (NINF, NINF, -3. * FRAC_PI_4),
+    (INF, INF, FRAC_PI_4),
+    (NINF, INF, -FRAC_PI_4),
+    (NAN, 1., NAN),
+    (1., NAN, NAN),
+];
+const HYPOT: Binary = &[
+    (0., 0., 0.),
+    (-0., -0., 0.),
+    (-3., 0., 3.),
+    (-3., -0., 3.),
+    (INF, NAN, INF),
+    (NAN, NINF, INF),
+    (NINF, 1., INF),
+    (NAN, 1., NAN),
+];
+const FMOD: Binary = &[
+    (0., 1., 0.),
+    (-0., 1., -0.),
+    (INF, 1., NAN),
+    (NINF, 1., NAN),
+    (1., 0., NAN),
+    (1., INF, 1.),
+    (-1., NINF, -1.),
+    (NAN, 1., NAN),
+    (1., NAN, NAN),
+];
+const FMAX: Binary = &[
+    (NAN, 1., 1.),
+    (1., NAN, 1.),
+    (0., -0., 0.),
+    (-0., 0., 0.),
+    (NINF, 1., 1.),
+];
+const FMIN: Binary = &[
+    (NAN, 1., 1.),
+    (1., NAN, 1.),
+    (0., -0., -0.),
+    (-0., 0., -0.),
+    (INF, 1., 1.),
+];
+
+fn conforms(got: f64, want: f64, eps: f64) -> bool {
+    if want.is_nan() {
+        got.is_nan()
+    } else if want == 0. || want.is_infinite() || want.abs() == 1. {
+        got.to_bits() == want.to_bits()
+    } else {
+        ((got - want) / want).abs() <= 4. * eps
+    }
+}
+
+fn check(name: &str, table: Unary, f: impl Fn(f64) -> f64) {
+    for &(x, want) in table {
+        let got = f(x);
+        assert!(
+            conforms(got, want, f64::EPSILON),
+            "{name}({x:?}) = {got:?}, expected {want:?}"
+        );
+    }
+}
+
+fn checkf(name: &str, table: Unary, f: impl Fn(f32) -> f32) {
+    for &(x, want) in table {
+        let got = f(x as f32);
+        let want = want as f32;
+        assert!(
+            conforms(got as f64, want as f64, f32::EPSILON as f64),
+            "{name}f({x:?}) = {got:?}, expected {want:?}"
+        );
+    }
+}
+
+fn check2(name: &str, table: Binary, f: impl Fn(f64, f64) -> f64) {
+    for &(x, y, want) in table {
+        let got = f(x, y);
+        assert!(
+            conforms(got, want, f64::EPSILON),
+            "{name}({x:?}, {y:?}) = {got:?}, expected {want:?}"
+        );
+    }
+}
+
+fn check2f(name: &str, table: Binary, f: impl Fn(f32, f32) -> f32) {
+    for &(x, y, want) in table {
+        let got = f(x as f32, y as f32);
+        let want = want as f32;
+        assert!(
+            conforms(got as f64, want as f64, f32::EPSILON as f64),
+            "{name}f({x:?}, {y:?}) = {got:?}, expected {want:?}"
+        );
+    }
+}
+
+/// Passes the whole table at once, every lane gets its own row
+fn check_lanes(name: &str, table: Unary, f: impl Fn(&[f64]) -> Vec<f64>) {
+    let xs = table.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+    for (&(x, want), got) in table.iter().zip(f(&xs)) {
+        assert!(
+            conforms(got, want, f64::EPSILON),
+            "{name}({x:?}) = {got:?}, expected {want:?}"
+        );
+    }
+}
+
+fn checkf_lanes(name: &str, table: Unary, f: impl Fn(&[f32]) -> Vec<f32>) {
+    let xs = table.iter().map(|&(x, _)| x as f32).collect::<Vec<_>>();
+    for (&(x, want), got) in table.iter().zip(f(&xs)) {
+        let want = want as f32;
+        assert!(
+            conforms(got as f64, want as f64, f32::EPSILON as f64),
+            "{name}f({x:?}) = {got:?}, expected {want:?}"
+        );
+    }
+}
+
+/// Runs `f` over `xs` in chunks of `N`, the tail is padded with zeros
+fn lanes<T: Copy + Default, const N: usize>(xs: &[T], f: impl Fn([T; N]) -> [T; N]) -> Vec<T> {
+    xs.chunks(N)
+        .flat_map(|chunk| {
+            let mut v = [T::default(); N];
+            v[..chunk.len()].copy_from_slice(chunk);
+            f(v)[..chunk.len()].to_vec()
+        })
+        .collect()
+}
+
+/// One test per function, checking both precisions against the same table
+macro_rules! conformance {
+    ($($check:ident, $checkf:ident, $name:ident: $table:ident => $d:expr, $s:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                super::$check(stringify!($name), super::$table, $d);
+                super::$checkf(stringify!($name), super::$table, $s);
+            }
+        )*
+    };
+}
+
+mod scalar {
+    use crate::*;
+
+    conformance! {
+        check, checkf, exp: EXP => eexp, eexpf;
+        check, checkf, exp2: EXP => eexp2, eexp2f;
+        check, checkf, exp10: EXP => eexp10, eexp10f;
+        check, checkf, expm1: EXPM1 => eexpm1, eexpm1f;
+        check, checkf, ln: LOG => eln, elnf;
+        check, checkf, log2: LOG => elog2, elog2f;
+        check, checkf, log10: LOG => elog10, elog10f;
+        check, checkf, log1p: LOG1P => elog1p, elog1pf;
+        check, checkf, sin: SIN => esin, esinf;
+        check, checkf, cos: COS => ecos, ecosf;
+        check, checkf, tan: SIN => etan, etanf;
+        check, checkf, sincos_sin: SIN => |x| esincos(x).0, |x| esincosf(x).0;
+        check, checkf, sincos_cos: COS => |x| esincos(x).1, |x| esincosf(x).1;
+        check, checkf, asin: ASIN => easin, easinf;
+        check, checkf, acos: ACOS => eacos, eacosf;
+        check, checkf, atan: ATAN => eatan, eatanf;
+        check, checkf, sinh: ODD_INF => esinh, esinhf;
+        check, checkf, cosh: COSH => ecosh, ecoshf;
+        check, checkf, tanh: ODD_ONE => etanh, etanhf;
+        check, checkf, asinh: ODD_INF => easinh, easinhf;
+        check, checkf, acosh: ACOSH => eacosh, eacoshf;
+        check, checkf, atanh: ATANH => eatanh, eatanhf;
+        check, checkf, cbrt: ODD_INF => ecbrt, ecbrtf;
+        check, checkf, cbrt_huge: CBRT_HUGE => ecbrt, ecbrtf;
+        check, checkf, erf: ODD_ONE => eerf, eerff;
+        check, checkf, erfc: ERFC => eerfc, eerfcf;
+        check, checkf, tgamma: TGAMMA => etgamma, etgammaf;
+        check, checkf, lgamma: LGAMMA => |x| elgamma(x).0, |x| elgammaf(x).0;
+        check2, check2f, pow: POW => epow, epowf;
+        check2, check2f, atan2: ATAN2 => eatan2, eatan2f;
+        check2, check2f, hypot: HYPOT => ehypot, ehypotf;
+        check2, check2f, fmod: FMOD => efmod, efmodf;
+        check2, check2f, fmax: FMAX => efmax, efmaxf;
+        check2, check2f, fmin: FMIN => efmin, efminf;
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod sse {
+    use crate::*;
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    fn d(f: unsafe fn(__m128d) -> __m128d) -> impl Fn(f64) -> f64 {
+        move |x| unsafe { _mm_cvtsd_f64(f(_mm_set1_pd(x))) }
+    }
+
+    fn s(f: unsafe fn(__m128) -> __m128) -> impl Fn(f32) -> f32 {
+        move |x| unsafe { _mm_cvtss_f32(f(_mm_set1_ps(x))) }
+    }
+
+    fn d2(f: unsafe fn(__m128d, __m128d) -> __m128d) -> impl Fn(f64, f64) -> f64 {
+        move |x, y| unsafe { _mm_cvtsd_f64(f(_mm_set1_pd(x), _mm_set1_pd(y))) }
+    }
+
+    fn s2(f: unsafe fn(__m128, __m128) -> __m128) -> impl Fn(f32, f32) -> f32 {
+        move |x, y| unsafe { _mm_cvtss_f32(f(_mm_set1_ps(x), _mm_set1_ps(y))) }
+    }
+
+    fn dl(f: unsafe fn(__m128d) -> __m128d) -> impl Fn(&[f64]) -> Vec<f64> {
+        move |xs| {
+            super::lanes(xs, |v: [f64; 2]| unsafe {
+                let mut out = [0.; 2];
+                _mm_storeu_pd(out.as_mut_ptr(), f(_mm_loadu_pd(v.as_ptr())));
+                out
+            })
+        }
+    }
+
+    fn sl(f: unsafe fn(__m128) -> __m128) -> impl Fn(&[f32]) -> Vec<f32> {
+        move |xs| {
+            super::lanes(xs, |v: [f32; 4]| unsafe {
+                let mut out = [0.; 4];
+                _mm_storeu_ps(out.as_mut_ptr(), f(_mm_loadu_ps(v.as_ptr())));
+                out
+            })
+        }
+    }
+
+    conformance! {
+        check, checkf, exp: EXP => d(_mm_exp_pd), s(_mm_exp_ps);
+        check, checkf, exp2: EXP => d(_mm_exp2_pd), s(_mm_exp2_ps);
+        check, checkf, exp10: EXP => d(_mm_exp10_pd), s(_mm_exp10_ps);
+        check, checkf, expm1: EXPM1 => d(_mm_expm1_pd), s(_mm_expm1_ps);
+        check, checkf, ln: LOG => d(_mm_ln_pd), s(_mm_ln_ps);
+        check, checkf, log2: LOG => d(_mm_log2_pd), s(_mm_log2_ps);
+        check, checkf, log10: LOG => d(_mm_log10_pd), s(_mm_log10_ps);
+        check, checkf, log1p: LOG1P => d(_mm_log1p_pd), s(_mm_log1p_ps);
+        check, checkf, sin: SIN => d(_mm_sin_pd), s(_mm_sin_ps);
+        check, checkf, cos: COS => d(_mm_cos_pd), s(_mm_cos_ps);
+        check, checkf, tan: SIN => d(_mm_tan_pd), s(_mm_tan_ps);
+        check, checkf, sincos_sin: SIN => d(|v| unsafe { _mm_sincos_pd(v).0 }), s(|v| unsafe { _mm_sincos_ps(v).0 });
+        check, checkf, sincos_cos: COS => d(|v| unsafe { _mm_sincos_pd(v).1 }), s(|v| unsafe { _mm_sincos_ps(v).1 });
+        check, checkf, asin: ASIN => d(_mm_asin_pd), s(_mm_asin_ps);
+        check, checkf, acos: ACOS => d(_mm_acos_pd), s(_mm_acos_ps);
+        check, checkf, atan: ATAN => d(_mm_atan_pd), s(_mm_atan_ps);
+        check, checkf, sinh: ODD_INF => d(_mm_sinh_pd), s(_mm_sinh_ps);
+        check, checkf, cosh: COSH => d(_mm_cosh_pd), s(_mm_cosh_ps);
+        check, checkf, tanh: ODD_ONE => d(_mm_tanh_pd), s(_mm_tanh_ps);
+        check, checkf, asinh: ODD_INF => d(_mm_asinh_pd), s(_mm_asinh_ps);
+        check, checkf, acosh: ACOSH => d(_mm_acosh_pd), s(_mm_acosh_ps);
+        check, checkf, atanh: ATANH => d(_mm_atanh_pd), s(_mm_atanh_ps);
+        check, checkf, cbrt: ODD_INF => d(_mm_cbrt_pd), s(_mm_cbrt_ps);
+        check, checkf, cbrt_huge: CBRT_HUGE => d(_mm_cbrt_pd), s(_mm_cbrt_ps);
+        check_lanes, checkf_lanes, cbrt_lanes: CBRT_LANES => dl(_mm_cbrt_pd), sl(_mm_cbrt_ps);
+        check, checkf, erf: ODD_ONE => d(_mm_erf_pd), s(_mm_erf_ps);
+        check, checkf, erfc: ERFC => d(_mm_erfc_pd), s(_mm_erfc_ps);
+        check, checkf, tgamma: TGAMMA => d(_mm_tgamma_pd), s(_mm_tgamma_ps);
+        check, checkf, lgamma: LGAMMA => d(|v| unsafe { _mm_lgamma_pd(v).0 }), s(|v| unsafe { _mm_lgamma_ps(v).0 });
+        check2, check2f, pow: POW => d2(_mm_pow_pd), s2(_mm_pow_ps);
+        check2, check2f, atan2: ATAN2 => d2(_mm_atan2_pd), s2(_mm_atan2_ps);
+        check2, check2f, hypot: HYPOT => d2(_mm_hypot_pd), s2(_mm_hypot_ps);
+        check2, check2f, fmod: FMOD => d2(_mm_fmod_pd), s2(_mm_fmod_ps);
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod avx {
+    use crate::*;
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    fn d(f: unsafe fn(__m256d) -> __m256d) -> impl Fn(f64) -> f64 {
+        move |x| unsafe { _mm256_cvtsd_f64(f(_mm256_set1_pd(x))) }
+    }
+
+    fn s(f: unsafe fn(__m256) -> __m256) -> impl Fn(f32) -> f32 {
+        move |x| unsafe { _mm256_cvtss_f32(f(_mm256_set1_ps(x))) }
+    }
+
+    fn d2(f: unsafe fn(__m256d, __m256d) -> __m256d) -> impl Fn(f64, f64) -> f64 {
+        move |x, y| unsafe { _mm256_cvtsd_f64(f(_mm256_set1_pd(x), _mm256_set1_pd(y))) }
+    }
+
+    fn s2(f: unsafe fn(__m256, __m256) -> __m256) -> impl Fn(f32, f32) -> f32 {
+        move |x, y| unsafe { _mm256_cvtss_f32(f(_mm256_set1_ps(x), _mm256_set1_ps(y))) }
+    }
+
+    fn dl(f: unsafe fn(__m256d) -> __m256d) -> impl Fn(&[f64]) -> Vec<f64> {
+        move |xs| {
+            super::lanes(xs, |v: [f64; 4]| unsafe {
+                let mut out = [0.; 4];
+                _mm256_storeu_pd(out.as_mut_ptr(), f(_mm256_loadu_pd(v.as_ptr())));
+                out
+            })
+        }
+    }
+
+    fn sl(f: unsafe fn(__m256) -> __m256) -> impl Fn(&[f32]) -> Vec<f32> {
+        move |xs| {
+            super::lanes(xs, |v: [f32; 8]| unsafe {
+                let mut out = [0.; 8];
+                _mm256_storeu_ps(out.as_mut_ptr(), f(_mm256_loadu_ps(v.as_ptr())));
+                out
+            })
+        }
+    }
+
+    conformance! {
+        check, checkf, exp: EXP => d(_mm256_exp_pd), s(_mm256_exp_ps);
+        check, checkf, exp2: EXP => d(_mm256_exp2_pd), s(_mm256_exp2_ps);
+        check, checkf, exp10: EXP => d(_mm256_exp10_pd), s(_mm256_exp10_ps);
+        check, checkf, expm1: EXPM1 => d(_mm256_expm1_pd), s(_mm256_expm1_ps);
+        check, checkf, ln: LOG => d(_mm256_ln_pd), s(_mm256_ln_ps);
+        check, checkf, log2: LOG => d(_mm256_log2_pd), s(_mm256_log2_ps);
+        check, checkf, log10: LOG => d(_mm256_log10_pd), s(_mm256_log10_ps);
+        check, checkf, log1p: LOG1P => d(_mm256_log1p_pd), s(_mm256_log1p_ps);
+        check, checkf, sin: SIN => d(_mm256_sin_pd), s(_mm256_sin_ps);
+        check, checkf, cos: COS => d(_mm256_cos_pd), s(_mm256_cos_ps);
+        check, checkf, tan: SIN => d(_mm256_tan_pd), s(_mm256_tan_ps);
+        check, checkf, sincos_sin: SIN => d(|v| unsafe { _mm256_sincos_pd(v).0 }), s(|v| unsafe { _mm256_sincos_ps(v).0 });
+        check, checkf, sincos_cos: COS => d(|v| unsafe { _mm256_sincos_pd(v).1 }), s(|v| unsafe { _mm256_sincos_ps(v).1 });
+        check, checkf, asin: ASIN => d(_mm256_asin_pd), s(_mm256_asin_ps);
+        check, checkf, acos: ACOS => d(_mm256_acos_pd), s(_mm256_acos_ps);
+        check, checkf, atan: ATAN => d(_mm256_atan_pd), s(_mm256_atan_ps);
+        check, checkf, sinh: ODD_INF => d(_mm256_sinh_pd), s(_mm256_sinh_ps);
+        check, checkf, cosh: COSH => d(_mm256_cosh_pd), s(_mm256_cosh_ps);
+        check, checkf, tanh: ODD_ONE => d(_mm256_tanh_pd), s(_mm256_tanh_ps);
+        check, checkf, asinh: ODD_INF => d(_mm256_asinh_pd), s(_mm256_asinh_ps);
+        check, checkf, acosh: ACOSH => d(_mm256_acosh_pd), s(_mm256_acosh_ps);
+        check, checkf, atanh: ATANH => d(_mm256_atanh_pd), s(_mm256_atanh_ps);
+        check, checkf, cbrt: ODD_INF => d(_mm256_cbrt_pd), s(_mm256_cbrt_ps);
+        check, checkf, cbrt_huge: CBRT_HUGE => d(_mm256_cbrt_pd), s(_mm256_cbrt_ps);
+        check_lanes, checkf_lanes, cbrt_lanes: CBRT_LANES => dl(_mm256_cbrt_pd), sl(_mm256_cbrt_ps);
+        check, checkf, erf: ODD_ONE => d(_mm256_erf_pd), s(_mm256_erf_ps);
+        check, checkf, erfc: ERFC => d(_mm256_erfc_pd), s(_mm256_erfc_ps);
+        check, checkf, tgamma: TGAMMA => d(_mm256_tgamma_pd), s(_mm256_tgamma_ps);
+        check, checkf, lgamma: LGAMMA => d(|v| unsafe { _mm256_lgamma_pd(v).0 }), s(|v| unsafe { _mm256_lgamma_ps(v).0 });
+        check2, check2f, pow: POW => d2(_mm256_pow_pd), s2(_mm256_pow_ps);
+        check2, check2f, atan2: ATAN2 => d2(_mm256_atan2_pd), s2(_mm256_atan2_ps);
+        check2, check2f, hypot: HYPOT => d2(_mm256_hypot_pd), s2(_mm256_hypot_ps);
+        check2, check2f, fmod: FMOD => d2(_mm256_fmod_pd), s2(_mm256_fmod_ps);
+    }
+}
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod avx512 {
+    use crate::*;
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    fn available() -> bool {
+        std::arch::is_x86_feature_detected!("avx512f")
+            && std::arch::is_x86_feature_detected!("avx512dq")
+    }
+
+    fn d(f: unsafe fn(__m512d) -> __m512d) -> impl Fn(f64) -> f64 {
+        move |x| unsafe { _mm512_cvtsd_f64(f(_mm512_set1_pd(x))) }
+    }
+
+    fn s(f: unsafe fn(__m512) -> __m512) -> impl Fn(f32) -> f32 {
+        move |x| unsafe { _mm512_cvtss_f32(f(_mm512_set1_ps(x))) }
+    }
+
+    fn d2(f: unsafe fn(__m512d, __m512d) -> __m512d) -> impl Fn(f64, f64) -> f64 {
+        move |x, y| unsafe { _mm512_cvtsd_f64(f(_mm512_set1_pd(x), _mm512_set1_pd(y))) }
+    }
+
+    fn s2(f: unsafe fn(__m512, __m512) -> __m512) -> impl Fn(f32, f32) -> f32 {
+        move |x, y| unsafe { _mm512_cvtss_f32(f(_mm512_set1_ps(x), _mm512_set1_ps(y))) }
+    }
+
+    fn dl(f: unsafe fn(__m512d) -> __m512d) -> impl Fn(&[f64]) -> Vec<f64> {
+        move |xs| {
+            super::lanes(xs, |v: [f64; 8]| unsafe {
+                let mut out = [0.; 8];
+                _mm512_storeu_pd(out.as_mut_ptr(), f(_mm512_loadu_pd(v.as_ptr())));
+                out
+            })
+        }
+    }
+
+    fn sl(f: unsafe fn(__m512) -> __m512) -> impl Fn(&[f32]) -> Vec<f32> {
+        move |xs| {
+            super::lanes(xs, |v: [f32; 16]| unsafe {
+                let mut out = [0.; 16];
+                _mm512_storeu_ps(out.as_mut_ptr(), f(_mm512_loadu_ps(v.as_ptr())));
+                out
+            })
+        }
+    }
+
+    /// Same as `conformance!`, skipping hosts without AVX-512
+    macro_rules! conformance512 {
+        ($($check:ident, $checkf:ident, $name:ident: $table:ident => $d:expr, $s:expr;)*) => {
+            $(
+                #[test]
+                fn $name() {
+                    if !available() {
+                        return;
+                    }
+                    super::$check(stringify!($name), super::$table, $d);
+                    super::$checkf(stringify!($name), super::$table, $s);
+                }
+            )*
+        };
+    }
+
+    conformance512! {
+        check, checkf, exp: EXP => d(_mm512_exp_pd), s(_mm512_exp_ps);
+        check, checkf, ln: LOG => d(_mm512_ln_pd), s(_mm512_ln_ps);
+        check, checkf, sin: SIN => d(_mm512_sin_pd), s(_mm512_sin_ps);
+        check, checkf, cos: COS => d(_mm512_cos_pd), s(_mm512_cos_ps);
+        check, checkf, tan: SIN => d(_mm512_tan_pd), s(_mm512_tan_ps);
+        check, checkf, sincos_sin: SIN => d(|v| unsafe { _mm512_sincos_pd(v).0 }), s(|v| unsafe { _mm512_sincos_ps(v).0 });
+        check, checkf, sincos_cos: COS => d(|v| unsafe { _mm512_sincos_pd(v).1 }), s(|v| unsafe { _mm512_sincos_ps(v).1 });
+        check, checkf, asin: ASIN => d(_mm512_asin_pd), s(_mm512_asin_ps);
+        check, checkf, acos: ACOS => d(_mm512_acos_pd), s(_mm512_acos_ps);
+        check, checkf, atan: ATAN => d(_mm512_atan_pd), s(_mm512_atan_ps);
+        check, checkf, cbrt: ODD_INF => d(_mm512_cbrt_pd), s(_mm512_cbrt_ps);
+        check, checkf, cbrt_huge: CBRT_HUGE => d(_mm512_cbrt_pd), s(_mm512_cbrt_ps);
+        check_lanes, checkf_lanes, cbrt_lanes: CBRT_LANES => dl(_mm512_cbrt_pd), sl(_mm512_cbrt_ps);
+        check2, check2f, pow: POW => d2(_mm512_pow_pd), s2(_mm512_pow_ps);
+        check2, check2f, atan2: ATAN2 => d2(_mm512_atan2_pd), s2(_mm512_atan2_ps);
+        check2, check2f, hypot: HYPOT => d2(_mm512_hypot_pd), s2(_mm512_hypot_ps);
+        check2, check2f, fmod: FMOD => d2(_mm512_fmod_pd), s2(_mm512_fmod_ps);
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod neon {
+    use crate::*;
+    use std::arch::aarch64::*;
+
+    fn d(f: unsafe fn(float64x2_t) -> float64x2_t) -> impl Fn(f64) -> f64 {
+        move |x| unsafe { vgetq_lane_f64::<0>(f(vdupq_n_f64(x))) }
+    }
+
+    fn s(f: unsafe fn(float32x4_t) -> float32x4_t) -> impl Fn(f32) -> f32 {
+        move |x| unsafe { vgetq_lane_f32::<0>(f(vdupq_n_f32(x))) }
+    }
+
+    fn d2(f: unsafe fn(float64x2_t, float64x2_t) -> float64x2_t) -> impl Fn(f64, f64) -> f64 {
+        move |x, y| unsafe { vgetq_lane_f64::<0>(f(vdupq_n_f64(x), vdupq_n_f64(y))) }
+    }
+
+    fn s2(f: unsafe fn(float32x4_t, float32x4_t) -> float32x4_t) -> impl Fn(f32, f32) -> f32 {
+        move |x, y| unsafe { vgetq_lane_f32::<0>(f(vdupq_n_f32(x), vdupq_n_f32(y))) }
+    }
+
+    fn dl(f: unsafe fn(float64x2_t) -> float64x2_t) -> impl Fn(&[f64]) -> Vec<f64> {
+        move |xs| {
+            super::lanes(xs, |v: [f64; 2]| unsafe {
+                let mut out = [0.; 2];
+                vst1q_f64(out.as_mut_ptr(), f(vld1q_f64(v.as_ptr())));
+                out
+            })
+        }
+    }
+
+    fn sl(f: unsafe fn(float32x4_t) -> float32x4_t) -> impl Fn(&[f32]) -> Vec<f32> {
+        move |xs| {
+            super::lanes(xs, |v: [f32; 4]| unsafe {
+                let mut out = [0.; 4];
+                vst1q_f32(out.as_mut_ptr(), f(vld1q_f32(v.as_ptr())));
+                out
+            })
+        }
+    }
+
+    conformance! {
+        check, checkf, exp: EXP => d(vexpq_f64), s(vexpq_f32);
+        check, checkf, exp2: EXP => d(vexp2q_f64), s(vexp2q_f32);
+        check, checkf, exp10: EXP => d(vexp10q_f64), s(vexp10q_f32);
+        check, checkf, expm1: EXPM1 => d(vexpm1q_f64), s(vexpm1q_f32);
+        check, checkf, ln: LOG => d(vlnq_f64), s(vlnq_f32);
+        check, checkf, log2: LOG => d(vlog2q_f64), s(vlog2q_f32);
+        check, checkf, log10: LOG => d(vlog10q_f64), s(vlog10q_f32);
+        check, checkf, log1p: LOG1P => d(vlog1pq_f64), s(vlog1pq_f32);
+        check, checkf, sin: SIN => d(vsinq_f64), s(vsinq_f32);
+        check, checkf, cos: COS => d(vcosq_f64), s(vcosq_f32);
+        check, checkf, tan: SIN => d(vtanq_f64), s(vtanq_f32);
+        check, checkf, sincos_sin: SIN => d(|v| unsafe { vsincosq_f64(v).0 }), s(|v| unsafe { vsincosq_f32(v).0 });
+        check, checkf, sincos_cos: COS => d(|v| unsafe { vsincosq_f64(v).1 }), s(|v| unsafe { vsincosq_f32(v).1 });
+        check, checkf, asin: ASIN => d(vasinq_f64), s(vasinq_f32);
+        check, checkf, acos: ACOS => d(vacosq_f64), s(vacosq_f32);
+        check, checkf, atan: ATAN => d(vatanq_f64), s(vatanq_f32);
+        check, checkf, sinh: ODD_INF => d(vsinhq_f64), s(vsinhq_f32);
+        check, checkf, cosh: COSH => d(vcoshq_f64), s(vcoshq_f32);
+        check, checkf, tanh: ODD_ONE => d(vtanhq_f64), s(vtanhq_f32);
+        check, checkf, asinh: ODD_INF => d(vasinhq_f64), s(vasinhq_f32);
+        check, checkf, acosh: ACOSH => d(vacoshq_f64), s(vacoshq_f32);
+        check, checkf, atanh: ATANH => d(vatanhq_f64), s(vatanhq_f32);
+        check, checkf, cbrt: ODD_INF => d(vcbrtq_f64), s(vcbrtq_f32);
+        check, checkf, cbrt_huge: CBRT_HUGE => d(vcbrtq_f64), s(vcbrtq_f32);
+        check_lanes, checkf_lanes, cbrt_lanes: CBRT_LANES => dl(vcbrtq_f64), sl(vcbrtq_f32);
+        check, checkf, erf: ODD_ONE => d(verfq_f64), s(verfq_f32);
+        check, checkf, erfc: ERFC => d(verfcq_f64), s(verfcq_f32);
+        check, checkf, tgamma: TGAMMA => d(vtgammaq_f64), s(vtgammaq_f32);
+        check, checkf, lgamma: LGAMMA => d(|v| unsafe { vlgammaq_f64(v).0 }), s(|v| unsafe { vlgammaq_f32(v).0 });
+        check2, check2f, pow: POW => d2(vpowq_f64), s2(vpowq_f32);
+        check2, check2f, atan2: ATAN2 => d2(vatan2q_f64), s2(vatan2q_f32);
+        check2, check2f, hypot: HYPOT => d2(vhypotq_f64), s2(vhypotq_f32);
+        check2, check2f, fmod: FMOD => d2(vfmodq_f64), s2(vfmodq_f32);
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod wasm {
+    use crate::*;
+    use std::arch::wasm32::*;
+
+    fn d(f: fn(v128) -> v128) -> impl Fn(f64) -> f64 {
+        move |x| f64x2_extract_lane::<0>(f(f64x2_splat(x)))
+    }
+
+    fn s(f: fn(v128) -> v128) -> impl Fn(f32) -> f32 {
+        move |x| f32x4_extract_lane::<0>(f(f32x4_splat(x)))
+    }
+
+    fn d2(f: fn(v128, v128) -> v128) -> impl Fn(f64, f64) -> f64 {
+        move |x, y| f64x2_extract_lane::<0>(f(f64x2_splat(x), f64x2_splat(y)))
+    }
+
+    fn s2(f: fn(v128, v128) -> v128) -> impl Fn(f32, f32) -> f32 {
+        move |x, y| f32x4_extract_lane::<0>(f(f32x4_splat(x), f32x4_splat(y)))
+    }
+
+    fn dl(f: fn(v128) -> v128) -> impl Fn(&[f64]) -> Vec<f64> {
+        move |xs| {
+            super::lanes(xs, |v: [f64; 2]| {
+                let r = f(f64x2(v[0], v[1]));
+                [f64x2_extract_lane::<0>(r), f64x2_extract_lane::<1>(r)]
+            })
+        }
+    }
+
+    fn sl(f: fn(v128) -> v128) -> impl Fn(&[f32]) -> Vec<f32> {
+        move |xs| {
+            super::lanes(xs, |v: [f32; 4]| {
+                let r = f(f32x4(v[0], v[1], v[2], v[3]));
+                [
+                    f32x4_extract_lane::<0>(r),
+                    f32x4_extract_lane::<1>(r),
+                    f32x4_extract_lane::<2>(r),
+                    f32x4_extract_lane::<3>(r),
+                ]
+            })
+        }
+    }
+
+    conformance! {
+        check, checkf, exp: EXP => d(f64x2_exp), s(f32x4_exp);
+        check, checkf, exp2: EXP => d(f64x2_exp2), s(f32x4_exp2);
+        check, checkf, exp10: EXP => d(f64x2_exp10), s(f32x4_exp10);
+        check, checkf, expm1: EXPM1 => d(f64x2_expm1), s(f32x4_expm1);
+        check, checkf, ln: LOG => d(f64x2_ln), s(f32x4_ln);
+        check, checkf, log2: LOG => d(f64x2_log2), s(f32x4_log2);
+        check, checkf, log10: LOG => d(f64x2_log10), s(f32x4_log10);
+        check, checkf, log1p: LOG1P => d(f64x2_log1p), s(f32x4_log1p);
+        check, checkf, sin: SIN => d(f64x2_sin), s(f32x4_sin);
+        check, checkf, cos: COS => d(f64x2_cos), s(f32x4_cos);
+        check, checkf, tan: SIN => d(f64x2_tan), s(f32x4_tan);
+        check, checkf, sincos_sin: SIN => d(|v| f64x2_sincos(v).0), s(|v| f32x4_sincos(v).0);
+        check, checkf, sincos_cos: COS => d(|v| f64x2_sincos(v).1), s(|v| f32x4_sincos(v).1);
+        check, checkf, asin: ASIN => d(f64x2_asin), s(f32x4_asin);
+        check, checkf, acos: ACOS => d(f64x2_acos), s(f32x4_acos);
+        check, checkf, atan: ATAN => d(f64x2_atan), s(f32x4_atan);
+        check, checkf, sinh: ODD_INF => d(f64x2_sinh), s(f32x4_sinh);
+        check, checkf, cosh: COSH => d(f64x2_cosh), s(f32x4_cosh);
+        check, checkf, tanh: ODD_ONE => d(f64x2_tanh), s(f32x4_tanh);
+        check, checkf, asinh: ODD_INF => d(f64x2_asinh), s(f32x4_asinh);
+        check, checkf, acosh: ACOSH => d(f64x2_acosh), s(f32x4_acosh);
+        check, checkf, atanh: ATANH => d(f64x2_atanh), s(f32x4_atanh);
+        check, checkf, cbrt: ODD_INF => d(f64x2_cbrt), s(f32x4_cbrt);
+        check, checkf, cbrt_huge: CBRT_HUGE => d(f64x2_cbrt), s(f32x4_cbrt);
+        check_lanes, checkf_lanes, cbrt_lanes: CBRT_LANES => dl(f64x2_cbrt), sl(f32x4_cbrt);
+        check, checkf, erf: ODD_ONE => d(f64x2_erf), s(f32x4_erf);
+        check, checkf, erfc: ERFC => d(f64x2_erfc), s(f32x4_erfc);
+        check, checkf, tgamma: TGAMMA => d(f64x2_tgamma), s(f32x4_tgamma);
+        check, checkf, lgamma: LGAMMA => d(|v| f64x2_lgamma(v).0), s(|v| f32x4_lgamma(v).0);
+        check2, check2f, pow: POW => d2(f64x2_pow), s2(f32x4_pow);
+        check2, check2f, atan2: ATAN2 => d2(f64x2_atan2), s2(f32x4_atan2);
+        check2, check2f, hypot: HYPOT => d2(f64x2_hypot), s2(f32x4_hypot);
+        check2, check2f, fmod: FMOD => d2(f64x2_fmod), s2(f32x4_fmod);
+    }
+}
@@ -5,32 +5,41 @@
  * // license that can be found in the LICENSE file.
  */
 
-use crate::generalf::{mlaf, rintk, IsNegZero};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_cos;
+use crate::generalf::{mlaf, rempi2_odd, rintk, IsNegZero, PAYNE_HANEK_THRESHOLD};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vcosq_f64;
 use crate::sin::{
     PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
     SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
 };
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_cos_pd, _mm_extract_pd};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 #[inline]
-fn do_cos(d: f64) -> f64 {
-    let j = rintk(std::f64::consts::FRAC_1_PI * d - 0.5);
-    let qf = 1. + 2. * j;
-    let q = qf as i64;
-    let mut r = mlaf(qf, -PI_A2 * 0.5, d);
-    r = mlaf(qf, -PI_B2 * 0.5, r);
+pub(crate) fn do_cos(d: f64) -> f64 {
+    let (q, mut r) = if d.abs() > PAYNE_HANEK_THRESHOLD && d.is_finite() {
+        let (q, r) = rempi2_odd(d);
+        (q as i64, r)
+    } else {
+        let j = rintk(std::f64::consts::FRAC_1_PI * d - 0.5);
+        let qf = 1. + 2. * j;
+        let r = mlaf(qf, -PI_A2 * 0.5, d);
+        (qf as i64, mlaf(qf, -PI_B2 * 0.5, r))
+    };
 
     let x2 = r * r;
 
@@ -66,31 +75,66 @@ fn do_cos_neon(d: f64) -> f64 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_cos_sse(d: f64) -> f64 {
+    let j = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_cos_pd(j))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_cos_sse41(d: f64) -> f64 {
+    do_cos_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_cos_fma(d: f64) -> f64 {
+    do_cos(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_cos_sse(d: f64) -> f64 {
-    unsafe {
-        let j = _mm_set1_pd(d);
-        _mm_extract_pd::<0>(_mm_cos_pd(j))
-    }
+fn do_cos_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_cos(f64x2_splat(d)))
 }
 
 /// Cosine function
 ///
-/// The error bound of the returned value is `2.0 ULP` on range [-15; 15]
+/// The error bound of the returned value is `2.5 ULP`
 pub fn ecos(d: f64) -> f64 {
     let mut _dispatcher: fn(f64) -> f64 = do_cos;
     #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
     {
         _dispatcher = do_cos_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_cos_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_cos_sse;
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_cos_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_cos_sse41(d) }
+            } else {
+                |d| unsafe { do_cos_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
@@ -8,19 +8,24 @@
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_cos_ps;
-use crate::generalf::{mlaf, rintfk, IsNegZero};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_cos;
+use crate::generalf::{mlaf, rempi2_oddf, rintfk, IsNegZero, PAYNE_HANEK_THRESHOLD_F};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vcosq_f32;
 use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_cos_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub(crate) const PI_A_F: f32 = 3.140_625;
 pub(crate) const PI_B_F: f32 = 0.000_967_025_756_835_937_5;
@@ -29,12 +34,22 @@ pub(crate) const PI_D_F: f32 = 1.215_420_125_655_342_076_2_e-10;
 
 #[inline]
 fn do_cos(d: f32) -> f32 {
-    let q = 1 + 2 * rintfk(std::f32::consts::FRAC_1_PI * d - 0.5) as i32;
-    let qf = q as f32;
-    let mut r = mlaf(qf, -PI_A_F * 0.5, d);
-    r = mlaf(qf, -PI_B_F * 0.5, r);
-    r = mlaf(qf, -PI_C_F * 0.5, r);
-    r = mlaf(qf, -PI_D_F * 0.5, r);
+    // cos(±0) = 1
+    if d == 0. {
+        return 1.;
+    }
+    let (q, mut r) = if d.abs() > PAYNE_HANEK_THRESHOLD_F && d.is_finite() {
+        rempi2_oddf(d)
+    } else {
+        let q = (rintfk(std::f32::consts::FRAC_1_PI * d - 0.5) as i32)
+            .wrapping_mul(2)
+            .wrapping_add(1);
+        let qf = q as f32;
+        let mut r = mlaf(qf, -PI_A_F * 0.5, d);
+        r = mlaf(qf, -PI_B_F * 0.5, r);
+        r = mlaf(qf, -PI_C_F * 0.5, r);
+        (q, mlaf(qf, -PI_D_F * 0.5, r))
+    };
 
     let x2 = r * r;
 
@@ -65,33 +80,67 @@ fn do_cos_neon(d: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_cos_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_cos_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_cos_sse41(d: f32) -> f32 {
+    do_cos_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_cos_fma(d: f32) -> f32 {
+    do_cos(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_cos_sse(d: f32) -> f32 {
-    unsafe {
-        let v = _mm_set1_ps(d);
-        let value = _mm_cos_ps(v);
-        let ex = f32::from_bits(_mm_extract_ps::<0>(value) as u32);
-        ex
-    }
+fn do_cos_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_cos(f32x4_splat(d)))
 }
 
 /// Cosine function
 ///
-/// The error bound of the returned value is `1.5 ULP`.
+/// The error bound of the returned value is `2.5 ULP`.
 pub fn ecosf(d: f32) -> f32 {
     let mut _dispatcher: fn(f32) -> f32 = do_cos;
     #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
     {
         _dispatcher = do_cos_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_cos_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_cos_sse;
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_cos_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_cos_sse41(d) }
+            } else {
+                |d| unsafe { do_cos_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
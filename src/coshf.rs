@@ -0,0 +1,116 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::expf::do_exp;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_cosh;
+use crate::generalf::mlaf;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vcoshq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_cosh_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn do_coshf(d: f32) -> f32 {
+    let a = eabsf(d);
+    if a > 88f32 {
+        // exp(|d|) / 2 overflows before cosh does, split it as exp(|d|/2)^2 / 2
+        let h = do_exp(a * 0.5);
+        h * 0.5 * h
+    } else {
+        let e = do_exp(a);
+        mlaf(0.5, e, 0.5 / e)
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_coshf_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vcoshq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_coshf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_cosh_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_coshf_sse41(d: f32) -> f32 {
+    do_coshf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_coshf_fma(d: f32) -> f32 {
+    do_coshf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_coshf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_cosh(f32x4_splat(d)))
+}
+
+/// Computes hyperbolic cosine, error bound *ULP 3.5*
+#[inline]
+pub fn ecoshf(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_coshf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_coshf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_coshf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_coshf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_coshf_sse41(d) }
+            } else {
+                |d| unsafe { do_coshf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
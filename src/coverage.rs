@@ -0,0 +1,289 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+//! Compile time table of SIMD functions available on each backend.
+//!
+//! Every row names a function in SSE, AVX, AVX-512, NEON and WASM order.
+//! Any entry other than `_` must resolve from the crate root on the target where the backend
+//! is compiled, so a removed or renamed kernel fails the build.
+//! `SimdMath` rows are checked against the [crate::precision::SimdMath] trait, a routine
+//! added to the trait doesn't build until it has a row here.
+//! `_` marks a function that is known to be absent on a backend, it must be listed in the
+//! `absent` section with a reason, and every function listed there must be marked `_`.
+
+/// Position of a backend in a row
+const fn backend_index(backend: &str) -> usize {
+    let backends = ["sse", "avx", "avx512", "neon", "wasm"];
+    let mut i = 0;
+    while !str_eq(backends[i], backend) {
+        i += 1;
+    }
+    i
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Checks that `function` is allowed to be absent on `backend`
+const fn allowed(absent: &[(&str, &str, &str)], backend: &str, function: &str) -> bool {
+    let mut i = 0;
+    while i < absent.len() {
+        if str_eq(absent[i].0, backend) && str_eq(absent[i].1, function) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Checks that some row marks `function` as absent on `backend`
+const fn marked(cells: &[(&str, [bool; 5])], backend: &str, function: &str) -> bool {
+    let index = backend_index(backend);
+    let mut i = 0;
+    while i < cells.len() {
+        if str_eq(cells[i].0, function) && cells[i].1[index] {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+macro_rules! coverage {
+    (
+        absent {
+            $($backend:ident: $($absent:ident),+ => $reason:literal;)+
+        }
+        $($lane:ident {
+            SimdMath {
+                $($name:ident($($arg:ident),+): $($cell:tt),+;)+
+            }
+            other {
+                $($other:ident: $($other_cell:tt),+;)+
+            }
+        })+
+    ) => {
+        /// `(backend, function, reason)` for every function absent on a backend
+        const ABSENT: &[(&str, &str, &str)] = &[
+            $($((stringify!($backend), stringify!($absent), $reason),)+)+
+        ];
+
+        /// Every row with `true` for cells marked absent
+        const CELLS: &[(&str, [bool; 5])] = &[
+            $(
+                $((stringify!($name), [$(coverage!(@absent $cell)),+]),)+
+                $((stringify!($other), [$(coverage!(@absent $other_cell)),+]),)+
+            )+
+        ];
+
+        $(
+            const _: () = {
+                /// Fails to build if the trait has a routine without a row or the other way around
+                #[allow(dead_code)]
+                struct Rows;
+
+                #[allow(unused_variables)]
+                impl crate::precision::SimdMath<Rows, $lane> for Rows {
+                    $(
+                        unsafe fn $name($($arg: Rows),+) -> Rows {
+                            Rows
+                        }
+                    )+
+                }
+            };
+            $(coverage!(@row $name: $($cell),+);)+
+            $(coverage!(@row $other: $($other_cell),+);)+
+        )+
+
+        $(
+            const _: () = assert!(!$reason.is_empty(), "Absent functions need a reason");
+            $(
+                const _: () = assert!(
+                    marked(CELLS, stringify!($backend), stringify!($absent)),
+                    concat!(
+                        stringify!($absent),
+                        " is listed as absent on ",
+                        stringify!($backend),
+                        " but no row marks it with `_`"
+                    )
+                );
+            )+
+        )+
+    };
+    (@absent _) => {
+        true
+    };
+    (@absent $f:ident) => {
+        false
+    };
+    (@row $name:ident: $sse:tt, $avx:tt, $avx512:tt, $neon:tt, $wasm:tt) => {
+        coverage!(@cell sse, $name, $sse);
+        coverage!(@cell avx, $name, $avx);
+        coverage!(@cell avx512, $name, $avx512);
+        coverage!(@cell neon, $name, $neon);
+        coverage!(@cell wasm, $name, $wasm);
+    };
+    (@cell $backend:ident, $name:ident, _) => {
+        const _: () = assert!(
+            allowed(ABSENT, stringify!($backend), stringify!($name)),
+            concat!(
+                stringify!($name),
+                " is marked absent on ",
+                stringify!($backend),
+                " without a reason in the `absent` section"
+            )
+        );
+    };
+    (@cell neon, $name:ident, $f:ident) => {
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        const _: () = {
+            let _ = crate::$f;
+        };
+    };
+    (@cell wasm, $name:ident, $f:ident) => {
+        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+        const _: () = {
+            let _ = crate::$f;
+        };
+    };
+    (@cell $backend:ident, $name:ident, $f:ident) => {
+        #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+        const _: () = {
+            let _ = crate::$f;
+        };
+    };
+}
+
+coverage! {
+    absent {
+        avx512: exp2, exp10, expm1, log2, log10, log1p, sinh, cosh, tanh, asinh, acosh, atanh, erf,
+            erfc, erfinv, tgamma, digamma, lgamma =>
+            "AVX-512 only ports the core routines, batch runs AVX2 kernels for the rest";
+        avx512: isintegral => "AVX-512 comparisons return masks, negated `isnotintegral` is the same";
+        wasm: isintegral => "`v128_not` of `isnotintegral` is the same check";
+    }
+    f64 {
+        SimdMath {
+            exp(x): _mm_exp_pd, _mm256_exp_pd, _mm512_exp_pd, vexpq_f64, f64x2_exp;
+            exp2(x): _mm_exp2_pd, _mm256_exp2_pd, _, vexp2q_f64, f64x2_exp2;
+            exp10(x): _mm_exp10_pd, _mm256_exp10_pd, _, vexp10q_f64, f64x2_exp10;
+            expm1(x): _mm_expm1_pd, _mm256_expm1_pd, _, vexpm1q_f64, f64x2_expm1;
+            ln(x): _mm_ln_pd, _mm256_ln_pd, _mm512_ln_pd, vlnq_f64, f64x2_ln;
+            log2(x): _mm_log2_pd, _mm256_log2_pd, _, vlog2q_f64, f64x2_log2;
+            log10(x): _mm_log10_pd, _mm256_log10_pd, _, vlog10q_f64, f64x2_log10;
+            log1p(x): _mm_log1p_pd, _mm256_log1p_pd, _, vlog1pq_f64, f64x2_log1p;
+            sin(x): _mm_sin_pd, _mm256_sin_pd, _mm512_sin_pd, vsinq_f64, f64x2_sin;
+            cos(x): _mm_cos_pd, _mm256_cos_pd, _mm512_cos_pd, vcosq_f64, f64x2_cos;
+            tan(x): _mm_tan_pd, _mm256_tan_pd, _mm512_tan_pd, vtanq_f64, f64x2_tan;
+            asin(x): _mm_asin_pd, _mm256_asin_pd, _mm512_asin_pd, vasinq_f64, f64x2_asin;
+            acos(x): _mm_acos_pd, _mm256_acos_pd, _mm512_acos_pd, vacosq_f64, f64x2_acos;
+            atan(x): _mm_atan_pd, _mm256_atan_pd, _mm512_atan_pd, vatanq_f64, f64x2_atan;
+            sinh(x): _mm_sinh_pd, _mm256_sinh_pd, _, vsinhq_f64, f64x2_sinh;
+            cosh(x): _mm_cosh_pd, _mm256_cosh_pd, _, vcoshq_f64, f64x2_cosh;
+            tanh(x): _mm_tanh_pd, _mm256_tanh_pd, _, vtanhq_f64, f64x2_tanh;
+            asinh(x): _mm_asinh_pd, _mm256_asinh_pd, _, vasinhq_f64, f64x2_asinh;
+            acosh(x): _mm_acosh_pd, _mm256_acosh_pd, _, vacoshq_f64, f64x2_acosh;
+            atanh(x): _mm_atanh_pd, _mm256_atanh_pd, _, vatanhq_f64, f64x2_atanh;
+            cbrt(x): _mm_cbrt_pd, _mm256_cbrt_pd, _mm512_cbrt_pd, vcbrtq_f64, f64x2_cbrt;
+            erf(x): _mm_erf_pd, _mm256_erf_pd, _, verfq_f64, f64x2_erf;
+            erfc(x): _mm_erfc_pd, _mm256_erfc_pd, _, verfcq_f64, f64x2_erfc;
+            erfinv(x): _mm_erfinv_pd, _mm256_erfinv_pd, _, verfinvq_f64, f64x2_erfinv;
+            tgamma(x): _mm_tgamma_pd, _mm256_tgamma_pd, _, vtgammaq_f64, f64x2_tgamma;
+            digamma(x): _mm_digamma_pd, _mm256_digamma_pd, _, vdigammaq_f64, f64x2_digamma;
+            pow(x, n): _mm_pow_pd, _mm256_pow_pd, _mm512_pow_pd, vpowq_f64, f64x2_pow;
+            atan2(y, x): _mm_atan2_pd, _mm256_atan2_pd, _mm512_atan2_pd, vatan2q_f64, f64x2_atan2;
+            hypot(x, y): _mm_hypot_pd, _mm256_hypot_pd, _mm512_hypot_pd, vhypotq_f64, f64x2_hypot;
+            hypot3(x, y, z): _mm_hypot3_pd, _mm256_hypot3_pd, _mm512_hypot3_pd, vhypot3q_f64, f64x2_hypot3;
+            hypot4(x, y, z, w): _mm_hypot4_pd, _mm256_hypot4_pd, _mm512_hypot4_pd, vhypot4q_f64, f64x2_hypot4;
+            fmod(x, y): _mm_fmod_pd, _mm256_fmod_pd, _mm512_fmod_pd, vfmodq_f64, f64x2_fmod;
+        }
+        other {
+            cbrt_fast: _mm_cbrt_fast_pd, _mm256_cbrt_fast_pd, _mm512_cbrt_fast_pd, vcbrtq_fast_f64, f64x2_cbrt_fast;
+            exp_fast: _mm_exp_fast_pd, _mm256_expq_fast_pd, _mm512_exp_fast_pd, vexpq_fast_f64, f64x2_exp_fast;
+            hypot_fast: _mm_hypot_fast_pd, _mm256_hypot_fast_pd, _mm512_hypot_fast_pd, vhypotq_fast_f64, f64x2_hypot_fast;
+            hypot3_fast: _mm_hypot3_fast_pd, _mm256_hypot3_fast_pd, _mm512_hypot3_fast_pd, vhypot3q_fast_f64, f64x2_hypot3_fast;
+            hypot4_fast: _mm_hypot4_fast_pd, _mm256_hypot4_fast_pd, _mm512_hypot4_fast_pd, vhypot4q_fast_f64, f64x2_hypot4_fast;
+            lgamma: _mm_lgamma_pd, _mm256_lgamma_pd, _, vlgammaq_f64, f64x2_lgamma;
+            ln_fast: _mm_ln_fast_pd, _mm256_ln_fast_pd, _mm512_ln_fast_pd, vlnq_fast_f64, f64x2_ln_fast;
+            pow_fast: _mm_pow_fast_pd, _mm256_pow_fast_pd, _mm512_pow_fast_pd, vpowq_fast_f64, f64x2_pow_fast;
+            sincos: _mm_sincos_pd, _mm256_sincos_pd, _mm512_sincos_pd, vsincosq_f64, f64x2_sincos;
+            copysign: _mm_copysign_pd, _mm256_copysign_pd, _mm512_copysign_pd, vcopysignq_f64, f64x2_copysign;
+            isinf: _mm_isinf_pd, _mm256_isinf_pd, _mm512_isinf_pd, visinfq_f64, f64x2_isinf;
+            isintegral: _mm_isintegral_pd, _mm256_isintegral_pd, _, visintegralq_f64, _;
+            isnan: _mm_isnan_pd, _mm256_isnan_pd, _mm512_isnan_pd, visnanq_f64, f64x2_isnan;
+            isneginf: _mm_isneginf_pd, _mm256_isneginf_pd, _mm512_isneginf_pd, visneginfq_f64, f64x2_isneginf;
+            isnotintegral: _mm_isnotintegral_pd, _mm256_isnotintegral_pd, _mm512_isnotintegral_pd, visnotintegralq_f64, f64x2_isnotintegral;
+            mlaf: _mm_mlaf_pd, _mm256_mlaf_pd, _mm512_mlaf_pd, vmlafq_f64, f64x2_mlaf;
+        }
+    }
+    f32 {
+        SimdMath {
+            exp(x): _mm_exp_ps, _mm256_exp_ps, _mm512_exp_ps, vexpq_f32, f32x4_exp;
+            exp2(x): _mm_exp2_ps, _mm256_exp2_ps, _, vexp2q_f32, f32x4_exp2;
+            exp10(x): _mm_exp10_ps, _mm256_exp10_ps, _, vexp10q_f32, f32x4_exp10;
+            expm1(x): _mm_expm1_ps, _mm256_expm1_ps, _, vexpm1q_f32, f32x4_expm1;
+            ln(x): _mm_ln_ps, _mm256_ln_ps, _mm512_ln_ps, vlnq_f32, f32x4_ln;
+            log2(x): _mm_log2_ps, _mm256_log2_ps, _, vlog2q_f32, f32x4_log2;
+            log10(x): _mm_log10_ps, _mm256_log10_ps, _, vlog10q_f32, f32x4_log10;
+            log1p(x): _mm_log1p_ps, _mm256_log1p_ps, _, vlog1pq_f32, f32x4_log1p;
+            sin(x): _mm_sin_ps, _mm256_sin_ps, _mm512_sin_ps, vsinq_f32, f32x4_sin;
+            cos(x): _mm_cos_ps, _mm256_cos_ps, _mm512_cos_ps, vcosq_f32, f32x4_cos;
+            tan(x): _mm_tan_ps, _mm256_tan_ps, _mm512_tan_ps, vtanq_f32, f32x4_tan;
+            asin(x): _mm_asin_ps, _mm256_asin_ps, _mm512_asin_ps, vasinq_f32, f32x4_asin;
+            acos(x): _mm_acos_ps, _mm256_acos_ps, _mm512_acos_ps, vacosq_f32, f32x4_acos;
+            atan(x): _mm_atan_ps, _mm256_atan_ps, _mm512_atan_ps, vatanq_f32, f32x4_atan;
+            sinh(x): _mm_sinh_ps, _mm256_sinh_ps, _, vsinhq_f32, f32x4_sinh;
+            cosh(x): _mm_cosh_ps, _mm256_cosh_ps, _, vcoshq_f32, f32x4_cosh;
+            tanh(x): _mm_tanh_ps, _mm256_tanh_ps, _, vtanhq_f32, f32x4_tanh;
+            asinh(x): _mm_asinh_ps, _mm256_asinh_ps, _, vasinhq_f32, f32x4_asinh;
+            acosh(x): _mm_acosh_ps, _mm256_acosh_ps, _, vacoshq_f32, f32x4_acosh;
+            atanh(x): _mm_atanh_ps, _mm256_atanh_ps, _, vatanhq_f32, f32x4_atanh;
+            cbrt(x): _mm_cbrt_ps, _mm256_cbrt_ps, _mm512_cbrt_ps, vcbrtq_f32, f32x4_cbrt;
+            erf(x): _mm_erf_ps, _mm256_erf_ps, _, verfq_f32, f32x4_erf;
+            erfc(x): _mm_erfc_ps, _mm256_erfc_ps, _, verfcq_f32, f32x4_erfc;
+            erfinv(x): _mm_erfinv_ps, _mm256_erfinv_ps, _, verfinvq_f32, f32x4_erfinv;
+            tgamma(x): _mm_tgamma_ps, _mm256_tgamma_ps, _, vtgammaq_f32, f32x4_tgamma;
+            digamma(x): _mm_digamma_ps, _mm256_digamma_ps, _, vdigammaq_f32, f32x4_digamma;
+            pow(x, n): _mm_pow_ps, _mm256_pow_ps, _mm512_pow_ps, vpowq_f32, f32x4_pow;
+            atan2(y, x): _mm_atan2_ps, _mm256_atan2_ps, _mm512_atan2_ps, vatan2q_f32, f32x4_atan2;
+            hypot(x, y): _mm_hypot_ps, _mm256_hypot_ps, _mm512_hypot_ps, vhypotq_f32, f32x4_hypot;
+            hypot3(x, y, z): _mm_hypot3_ps, _mm256_hypot3_ps, _mm512_hypot3_ps, vhypot3q_f32, f32x4_hypot3;
+            hypot4(x, y, z, w): _mm_hypot4_ps, _mm256_hypot4_ps, _mm512_hypot4_ps, vhypot4q_f32, f32x4_hypot4;
+            fmod(x, y): _mm_fmod_ps, _mm256_fmod_ps, _mm512_fmod_ps, vfmodq_f32, f32x4_fmod;
+        }
+        other {
+            cbrt_fast: _mm_cbrt_fast_ps, _mm256_cbrt_fast_ps, _mm512_cbrt_fast_ps, vcbrtq_fast_f32, f32x4_cbrt_fast;
+            exp_fast: _mm_exp_fast_ps, _mm256_expq_fast_ps, _mm512_exp_fast_ps, vexpq_fast_f32, f32x4_exp_fast;
+            hypot_fast: _mm_hypot_fast_ps, _mm256_hypot_fast_ps, _mm512_hypot_fast_ps, vhypotq_fast_f32, f32x4_hypot_fast;
+            hypot3_fast: _mm_hypot3_fast_ps, _mm256_hypot3_fast_ps, _mm512_hypot3_fast_ps, vhypot3q_fast_f32, f32x4_hypot3_fast;
+            hypot4_fast: _mm_hypot4_fast_ps, _mm256_hypot4_fast_ps, _mm512_hypot4_fast_ps, vhypot4q_fast_f32, f32x4_hypot4_fast;
+            lgamma: _mm_lgamma_ps, _mm256_lgamma_ps, _, vlgammaq_f32, f32x4_lgamma;
+            ln_fast: _mm_ln_fast_ps, _mm256_ln_fast_ps, _mm512_ln_fast_ps, vlnq_fast_f32, f32x4_ln_fast;
+            pow_fast: _mm_pow_fast_ps, _mm256_pow_fast_ps, _mm512_pow_fast_ps, vpowq_fast_f32, f32x4_pow_fast;
+            sincos: _mm_sincos_ps, _mm256_sincos_ps, _mm512_sincos_ps, vsincosq_f32, f32x4_sincos;
+            copysign: _mm_copysign_ps, _mm256_copysign_ps, _mm512_copysign_ps, vcopysignq_f32, f32x4_copysign;
+            isinf: _mm_isinf_ps, _mm256_isinf_ps, _mm512_isinf_ps, visinfq_f32, f32x4_isinf;
+            isintegral: _mm_isintegral_ps, _mm256_isintegral_ps, _, visintegralq_f32, _;
+            isnan: _mm_isnan_ps, _mm256_isnan_ps, _mm512_isnan_ps, visnanq_f32, f32x4_isnan;
+            isneginf: _mm_isneginf_ps, _mm256_isneginf_ps, _mm512_isneginf_ps, visneginfq_f32, f32x4_isneginf;
+            isnotintegral: _mm_isnotintegral_ps, _mm256_isnotintegral_ps, _mm512_isnotintegral_ps, visnotintegralq_f32, f32x4_isnotintegral;
+            mlaf: _mm_mlaf_ps, _mm256_mlaf_ps, _mm512_mlaf_ps, vmlafq_f32, f32x4_mlaf;
+        }
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::acos::do_acos;
+use crate::asin::do_asin;
+use crate::cr::{hard_case, round_checked};
+
+/// Arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 16] = [
+    0x328885a2, 0x328885a3, 0x328885a4, 0x39826222, 0x3a6a490f, 0x3b7d281b, 0x3bc1dfd8, 0x3c8a2f9b,
+    0x3db6ec75, 0xb3ddde97, 0xba66deed, 0xba9d5f75, 0xbc406ccd, 0xbd56bd25, 0xbe668cd1, 0xbe6dff37,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 16] = [
+    0x3fc90fdb, 0x3fc90fdb, 0x3fc90fda, 0x3fc907b5, 0x3fc8f292, 0x3fc89147, 0x3fc84dfa, 0x3fc6e716,
+    0x3fbd9d2b, 0x3fc90fdb, 0x3fc92cb6, 0x3fc93733, 0x3fca90b6, 0x3fcfc68d, 0x3fe62141, 0x3fe7160b,
+];
+
+/// Computes correctly rounded arccosine *ULP 0.5*
+#[inline]
+pub fn acosf(x: f32) -> f32 {
+    // pi/2 - asin(x) cancels near 1, half angle form keeps the relative error
+    let y = if x > 0.5 {
+        2. * do_asin(((1. - x as f64) * 0.5).sqrt())
+    } else {
+        do_acos(x as f64)
+    };
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    // Hard cases cover every argument failing the rounding test, fallback is not reached
+    hard_case(&HARD_ARGS, &HARD_RESULTS, x.to_bits()).unwrap_or(y as f32)
+}
@@ -0,0 +1,35 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::asin::do_asin;
+use crate::cr::{hard_case, round_checked};
+
+/// Absolute values of arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 7] = [
+    0x39e89768, 0x3d07959c, 0x3d09bf86, 0x3d17817c, 0x3de5fa1e, 0x3eb7f139, 0x3f083a1a,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 7] = [
+    0x39e89769, 0x3d079bf3, 0x3d09c62d, 0x3d178a55, 0x3de6768e, 0x3ebc25fa, 0x3f0fa5b2,
+];
+
+/// Computes correctly rounded arcsine *ULP 0.5*
+#[inline]
+pub fn asinf(x: f32) -> f32 {
+    let y = do_asin(x as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    match hard_case(&HARD_ARGS, &HARD_RESULTS, x.abs().to_bits()) {
+        Some(r) if x < 0. => -r,
+        Some(r) => r,
+        // Hard cases cover every argument failing the rounding test, not reached
+        None => y as f32,
+    }
+}
@@ -0,0 +1,39 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::atan::do_atan;
+use crate::cr::{hard_case, round_checked};
+
+/// Absolute values of arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 24] = [
+    0x3a27ba3b, 0x3aac434b, 0x3ad637fa, 0x3b7c1bc9, 0x3d8d6b23, 0x3e242361, 0x3ee06db1, 0x3f89058c,
+    0x40357f1d, 0x40bf14c5, 0x421ae6b6, 0x4241b6f5, 0x424dd4a2, 0x426bef73, 0x48b90f72, 0x494a1b01,
+    0x4a2367a3, 0x4ae1e272, 0x4c700515, 0x4c700516, 0x4c700517, 0x4c700518, 0x4c700519, 0x4c70051a,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 24] = [
+    0x3a27ba3a, 0x3aac4344, 0x3ad637ee, 0x3b7c1b78, 0x3d8d31c3, 0x3e22c0f5, 0x3ed38381, 0x3f51c5ec,
+    0x3f9dab29, 0x3fb3d2b6, 0x3fc5c1e1, 0x3fc66b54, 0x3fc69324, 0x3fc6e45e, 0x3fc90fc4, 0x3fc90fd1,
+    0x3fc90fd7, 0x3fc90fd9, 0x3fc90fda, 0x3fc90fda, 0x3fc90fda, 0x3fc90fdb, 0x3fc90fdb, 0x3fc90fdb,
+];
+
+/// Computes correctly rounded arctangent *ULP 0.5*
+#[inline]
+pub fn atanf(x: f32) -> f32 {
+    let y = do_atan(x as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    match hard_case(&HARD_ARGS, &HARD_RESULTS, x.abs().to_bits()) {
+        Some(r) if x < 0. => -r,
+        Some(r) => r,
+        // Hard cases cover every argument failing the rounding test, not reached
+        None => y as f32,
+    }
+}
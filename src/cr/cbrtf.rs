@@ -0,0 +1,17 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cbrt::do_ecbrt;
+use crate::cr::round_checked;
+
+/// Computes correctly rounded cube root *ULP 0.5*
+#[inline]
+pub fn cbrtf(x: f32) -> f32 {
+    let y = do_ecbrt(x as f64);
+    // Exhaustive search found no argument failing the rounding test, fallback is not reached
+    round_checked(y).unwrap_or(y as f32)
+}
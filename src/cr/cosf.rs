@@ -0,0 +1,47 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cos::do_cos;
+use crate::cr::{hard_case, round_checked};
+
+/// Absolute values of arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 69] = [
+    0x39800000, 0x3a0f1bbd, 0x3a544395, 0x3b434e12, 0x3c107fe6, 0x3dac4fc0, 0x3e5fa70e, 0x3f8626a5,
+    0x3faa2672, 0x3fdb3c0e, 0x4010a4bf, 0x42378db8, 0x424790ce, 0x46f85a22, 0x474d265c, 0x47a0e238,
+    0x47d7c67e, 0x4986afee, 0x4a01dca4, 0x4b511330, 0x4c46d929, 0x4dd46702, 0x4e5b65ff, 0x4ea2216b,
+    0x504be581, 0x51abf5aa, 0x52d9d3fe, 0x52f88494, 0x543f6e04, 0x55e5235d, 0x5922aa80, 0x59443c0a,
+    0x5956c49c, 0x5a1a3626, 0x5a8c921b, 0x5bae7209, 0x5d173a7d, 0x5da7ab87, 0x5f18b878, 0x6115cb11,
+    0x61703976, 0x647a941f, 0x64933b14, 0x650247d7, 0x6978e912, 0x699f1bf1, 0x69b7b0b9, 0x6aa2e13a,
+    0x6bdb1cfc, 0x6e1ad2be, 0x71510edb, 0x71874a79, 0x72cfc61d, 0x744fd5d8, 0x75da4c95, 0x76d7173f,
+    0x77f7b624, 0x77fb4fbf, 0x7908cd73, 0x797cef4f, 0x7a38ab34, 0x7a4b1a27, 0x7c06dc1b, 0x7c64841e,
+    0x7c69ae1e, 0x7c75f2a4, 0x7d378aef, 0x7db91687, 0x7e82fea4,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 69] = [
+    0x3f800000, 0x3f7ffffd, 0x3f7ffffb, 0x3f7fffb5, 0x3f7ffd74, 0x3f7f182d, 0x3f79eb10, 0x3eff9eb8,
+    0x3e74e5b3, 0xbe10e4b3, 0xbf22cea3, 0xbea87a07, 0x3f6e4c01, 0xbf4232b9, 0xbf7009f2, 0x3f7e19dd,
+    0x3f773488, 0x3f090d16, 0x3f30b318, 0x3f0effc7, 0xbd293d05, 0x3f7932b8, 0xbf62075d, 0xbf7fe8c2,
+    0x3df8dc9c, 0xbcc6b7a4, 0xbf5e187b, 0x3f5c5971, 0x3f0f0187, 0xbe83c11a, 0x3f08aebf, 0x3f425f62,
+    0x3e77bfd8, 0xbf5fdc79, 0x3f5ea913, 0xbe11d727, 0xbf7ff3bf, 0xbf73e8cb, 0x3f7f14bb, 0x3f78142f,
+    0x3edacc56, 0x3f60eed7, 0x3d4bf754, 0x3f25bdc8, 0xbd769ca5, 0xbf6de4e7, 0xbf6b9c0b, 0x3f58445f,
+    0xbee04440, 0x3f7be1d5, 0xbf7e37e8, 0xbf45a449, 0x3eec0d38, 0x3f6cb54a, 0x3f7aeabb, 0x3f266b3e,
+    0x3f67741e, 0xbf63b128, 0x3f798bb5, 0x3f7de018, 0x3f7b3195, 0x3f7c54da, 0xbf2c5ab6, 0xbf7fc6f8,
+    0xbf7ff9fe, 0xbe61ec6f, 0x3d98150f, 0x3edfe26d, 0xbe0d7da7,
+];
+
+/// Computes correctly rounded cosine *ULP 0.5*
+#[inline]
+pub fn cosf(x: f32) -> f32 {
+    let y = do_cos(x as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    // Hard cases cover every argument failing the rounding test, fallback is not reached
+    hard_case(&HARD_ARGS, &HARD_RESULTS, x.abs().to_bits()).unwrap_or(y as f32)
+}
@@ -0,0 +1,35 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cr::{hard_case, round_checked};
+use crate::exp10::do_exp10;
+
+/// Arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 22] = [
+    0x32de5bd8, 0x34d07615, 0x35b1311f, 0x3772c43e, 0x3a01e531, 0x3e34fc89, 0x417d7f60, 0xb25e5bd9,
+    0xb326c4e3, 0xb38af968, 0xb3c2905f, 0xb3fa2756, 0xb54609d8, 0xb7d171cd, 0xb8153d95, 0xb84783d4,
+    0xbaac4a50, 0xbac4c65c, 0xbd6181f7, 0xbdca342d, 0xbf230886, 0xc126f431,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 22] = [
+    0x3f800000, 0x3f800007, 0x3f800019, 0x3f800118, 0x3f802568, 0x3fc04a00, 0x59c64406, 0x3f7fffff,
+    0x3f7ffffe, 0x3f7ffffe, 0x3f7ffffc, 0x3f7ffffb, 0x3f7fffe3, 0x3f7ffc3b, 0x3f7ffaa2, 0x3f7ff8d3,
+    0x3f7f39f2, 0x3f7f1dd8, 0x3f618508, 0x3f4bf144, 0x3e6c4b0c, 0x2e21accb,
+];
+
+/// Computes correctly rounded 10^x *ULP 0.5*
+#[inline]
+pub fn exp10f(x: f32) -> f32 {
+    let y = do_exp10(x as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    // Hard cases cover every argument failing the rounding test, fallback is not reached
+    hard_case(&HARD_ARGS, &HARD_RESULTS, x.to_bits()).unwrap_or(y as f32)
+}
@@ -0,0 +1,35 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cr::{hard_case, round_checked};
+use crate::exp2::do_exp2;
+
+/// Arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 22] = [
+    0x33b8aa3b, 0x36879cf7, 0x3a07857c, 0x3b429d37, 0x3c02a9ad, 0x3dc9abe2, 0xb338aa3b, 0xb338aa3c,
+    0xb466d4cb, 0xb4fdea14, 0xb5160a52, 0xb52d1f9a, 0xb63b8cf0, 0xb8acad70, 0xb8bbd3a2, 0xb8d3d026,
+    0xbae36f38, 0xbaec2b40, 0xbcaf4d02, 0xbcf3a937, 0xbe1f29de, 0xc3160000,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 22] = [
+    0x3f800001, 0x3f800018, 0x3f800bbe, 0x3f804385, 0x3f80b5a3, 0x3f890ab5, 0x3f800000, 0x3f7fffff,
+    0x3f7ffffe, 0x3f7ffffb, 0x3f7ffff9, 0x3f7ffff8, 0x3f7fffdf, 0x3f7ffc42, 0x3f7ffbee, 0x3f7ffb69,
+    0x3f7fb13a, 0x3f7fae34, 0x3f7c3b19, 0x3f7ac6b1, 0x3f65da56, 0x00000000,
+];
+
+/// Computes correctly rounded 2^x *ULP 0.5*
+#[inline]
+pub fn exp2f(x: f32) -> f32 {
+    let y = do_exp2(x as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    // Hard cases cover every argument failing the rounding test, fallback is not reached
+    hard_case(&HARD_ARGS, &HARD_RESULTS, x.to_bits()).unwrap_or(y as f32)
+}
@@ -0,0 +1,35 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cr::{hard_case, round_checked};
+use crate::exp::do_exp;
+
+/// Arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 20] = [
+    0x33800000, 0x36fdffc1, 0x377eff81, 0x383a3ef1, 0x38e69cc1, 0x39c6be5b, 0x39e5bb1d, 0x3d1a274e,
+    0x3fe67199, 0x4001b249, 0x40315b33, 0x41cbf87b, 0x4288942b, 0xb3000000, 0xbae0e25c, 0xbbb70ee8,
+    0xbbf0edf1, 0xbc2a461a, 0xc13d6631, 0xc16912cd,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 20] = [
+    0x3f800001, 0x3f800040, 0x3f800080, 0x3f800175, 0x3f80039a, 0x3f800c6d, 0x3f800e5d, 0x3f84e8ba,
+    0x40c1a7a6, 0x40f2cd14, 0x417fa47d, 0x51dc50be, 0x70b7a4c5, 0x3f800000, 0x3f7f8fa7, 0x3f7e92e8,
+    0x3f7e1fe9, 0x3f7d5a6e, 0x36f28e33, 0x34fd331b,
+];
+
+/// Computes correctly rounded exp *ULP 0.5*
+#[inline]
+pub fn expf(x: f32) -> f32 {
+    let y = do_exp(x as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    // Hard cases cover every argument failing the rounding test, fallback is not reached
+    hard_case(&HARD_ARGS, &HARD_RESULTS, x.to_bits()).unwrap_or(y as f32)
+}
@@ -0,0 +1,53 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cr::{hard_case, round_checked};
+use crate::ln::do_ln;
+
+/// Arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 96] = [
+    0x022ae487, 0x064cb44b, 0x07c060fa, 0x0b265d7b, 0x0dc8bba4, 0x0f61ff63, 0x111c87f8, 0x11b257e3,
+    0x13fcd84a, 0x145cb6d4, 0x14907055, 0x152ed9f5, 0x155d60fc, 0x158772eb, 0x16c5ee7a, 0x18b06f2a,
+    0x1a21db87, 0x1a3de5e8, 0x1a8446cb, 0x1aac80dc, 0x1adfd25d, 0x1b81ab52, 0x1dc9e7c1, 0x1e6bc9fa,
+    0x1f116ab8, 0x22925ad4, 0x22f6d580, 0x2423c085, 0x24a8fb14, 0x25be734f, 0x25f12e61, 0x2758eec4,
+    0x27a51454, 0x28e3fa26, 0x29bd4f07, 0x29e6126b, 0x29fd22f8, 0x2c4c24b7, 0x2d6f4a4e, 0x2e492984,
+    0x2e7a6221, 0x3418949b, 0x3461a322, 0x38dcbe38, 0x39c31348, 0x39c7b1f9, 0x3bf86ef0, 0x3c413d3a,
+    0x3e2b3421, 0x3fc55379, 0x3fd364d7, 0x4056ee45, 0x41178feb, 0x41e3b2af, 0x44bc4360, 0x464d5b2b,
+    0x4665a9a6, 0x46ca6c75, 0x4b77325a, 0x4bf70db3, 0x4c08ff78, 0x4c5d65a5, 0x4d604ebe, 0x4e85f412,
+    0x4fe4ab24, 0x526a304f, 0x54af989d, 0x56210e9a, 0x5b042ba3, 0x5b98e163, 0x5bf9890b, 0x5d800341,
+    0x5d8b2d5b, 0x5ee8984e, 0x5efbcaae, 0x5f64c24a, 0x5f945685, 0x62b467ba, 0x63b134d9, 0x64bc5793,
+    0x64e27fa3, 0x65d890d3, 0x66a8c860, 0x66abbd63, 0x6914cb96, 0x6a2510d0, 0x6be8cf96, 0x6d1f23eb,
+    0x6d6c9d3a, 0x6e7054f2, 0x6f31a8ec, 0x736cc271, 0x7405dee8, 0x74896a73, 0x79e7ec37, 0x7d98b8f4,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 96] = [
+    0xc2a9efaa, 0xc29e7c1a, 0xc29a733a, 0xc291095f, 0xc289bac4, 0xc2855565, 0xc28085df, 0xc27dc05e,
+    0xc27143cc, 0xc26f092f, 0xc26df596, 0xc26a6c24, 0xc2697a8a, 0xc268abd4, 0xc2619bd4, 0xc256fa6f,
+    0xc24f0163, 0xc24e5dc8, 0xc24d0a45, 0xc24bfa60, 0xc24aefb0, 0xc2479316, 0xc23ab685, 0xc23751e0,
+    0xc233b53e, 0xc220462d, 0xc21e2ef4, 0xc21781d1, 0xc2149bd8, 0xc20e95ce, 0xc20da3fb, 0xc205bf23,
+    0xc2041112, 0xc1fa75e0, 0xc1f0db66, 0xc1ef4c02, 0xc1ee8859, 0xc1d48710, 0xc1c82a99, 0xc1be76ee,
+    0xc1bcb6a9, 0xc17c447f, 0xc17601fe, 0xc1128ba4, 0xc0fcaf65, 0xc0fbefa7, 0xc09c399e, 0xc08e158f,
+    0xbfe4f170, 0x3edd9b88, 0x3f006ef3, 0x3f9b103b, 0x400fe5e7, 0x40564f1d, 0x40ea272e, 0x4117bcf1,
+    0x41198725, 0x41229929, 0x4184cde8, 0x418a5849, 0x418b2c45, 0x418f034b, 0x419a352c, 0x41a6b811,
+    0x41b6166f, 0x41d2011d, 0x41eb6d3c, 0x41fb5eee, 0x42189ee2, 0x421bf9b9, 0x421def6c, 0x42265b10,
+    0x4226b0b1, 0x422e4a21, 0x422e9b56, 0x4230fee2, 0x42320918, 0x42437434, 0x4248ed72, 0x424eb76c,
+    0x424f7453, 0x4254d1f9, 0x42595e46, 0x4259700e, 0x4266ba1e, 0x426caff2, 0x4276616f, 0x427d2d35,
+    0x427ec360, 0x42822f73, 0x42845a89, 0x429004b2, 0x4291a68d, 0x429316d3, 0x42a1ffb7, 0x42ac40f2,
+];
+
+/// Computes correctly rounded natural logarithm *ULP 0.5*
+#[inline]
+pub fn lnf(x: f32) -> f32 {
+    let y = do_ln(x as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    // Hard cases cover every argument failing the rounding test, fallback is not reached
+    hard_case(&HARD_ARGS, &HARD_RESULTS, x.to_bits()).unwrap_or(y as f32)
+}
@@ -0,0 +1,51 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cr::{hard_case, round_checked};
+use crate::log10::do_log10;
+
+/// Arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 83] = [
+    0x0225f259, 0x0436b84d, 0x058ecd6a, 0x0a4d4ce8, 0x0ab667dd, 0x0cb01343, 0x0e10c607, 0x0efeee7a,
+    0x10b88aec, 0x116fa037, 0x120b93dc, 0x1266ada7, 0x13ae78d3, 0x13b57a47, 0x15964dc5, 0x1944e975,
+    0x1bade731, 0x1eac122f, 0x214a9d50, 0x22fd44a4, 0x245c3ce7, 0x260b1d0e, 0x262ca79a, 0x2846c871,
+    0x2ae90442, 0x2b1b73f9, 0x2bb4e779, 0x2d43e0a3, 0x2f149212, 0x32a30a9c, 0x33b5ebfc, 0x344bcd43,
+    0x356366fb, 0x3989424b, 0x3c695cc0, 0x3e4e72fb, 0x3f5fde1b, 0x40d6b904, 0x427a28e9, 0x43079cce,
+    0x45bdedc8, 0x499271d9, 0x4d5aad22, 0x4dff4ddc, 0x5038f940, 0x51e73790, 0x539082ba, 0x54fdbb27,
+    0x56f8c876, 0x5735f401, 0x575e626b, 0x57d770b4, 0x5b2d0c6e, 0x5d610fe9, 0x5f3315a3, 0x5fc1d68d,
+    0x604df02c, 0x607be3ab, 0x610567e4, 0x62a6c1dd, 0x6307980c, 0x63bf4495, 0x63ce2f36, 0x64a97e0f,
+    0x65716a23, 0x65903d25, 0x6617abaf, 0x678e7825, 0x681be041, 0x6a581bf5, 0x6bc5af05, 0x6da8a20d,
+    0x6f592c3c, 0x6ff93d5b, 0x707fe458, 0x711016a5, 0x721feeb7, 0x73cb20d6, 0x74bfb209, 0x78bcd7de,
+    0x7cea0c14, 0x7d594696, 0x7f6362e7,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 83] = [
+    0xc213a7de, 0xc20eac07, 0xc20b7ce1, 0xc2000527, 0xc1fe0af5, 0xc1f48857, 0xc1edfce8, 0xc1e99d23,
+    0xc1e11a7d, 0xc1ddc9a8, 0xc1dad957, 0xc1d91a7d, 0xc1d2d957, 0xc1d2b653, 0xc1c9bbed, 0xc1b7f01f,
+    0xc1ac5419, 0xc19dea75, 0xc1914e94, 0xc1894e94, 0xc1829157, 0xc1750fdb, 0xc1738f9c, 0xc15f50dc,
+    0xc146211b, 0xc144201d, 0xc13e4161, 0xc12f40dc, 0xc11de885, 0xc0f71819, 0xc0e24e31, 0xc0d71819,
+    0xc0c24e31, 0xc0653ff8, 0xbfec567c, 0xbf320b6e, 0xbd6e9637, 0x3f53a473, 0x3fe5e87d, 0x40087799,
+    0x407228d0, 0x40c287b1, 0x4105c434, 0x410ba8b4, 0x41218092, 0x41318092, 0x41418092, 0x414f0bf9,
+    0x41622d04, 0x4164d18d, 0x4166367f, 0x416acf15, 0x41858039, 0x41900c01, 0x4198e2cb, 0x419b91b7,
+    0x419e3014, 0x419ee339, 0x41a17eec, 0x41a97eec, 0x41ab2f6e, 0x41aec9ea, 0x41af0cb5, 0x41b32f6e,
+    0x41b6d28a, 0x41b770f4, 0x41ba0625, 0x41c10802, 0x41c3c083, 0x41ce8525, 0x41d56f6a, 0x41de8414,
+    0x41e69e9c, 0x41e9819d, 0x41ec018d, 0x41eed3bd, 0x41f4018d, 0x41fc0fba, 0x4200569c, 0x4209f1fc,
+    0x4213f376, 0x421506a5, 0x4219ebe5,
+];
+
+/// Computes correctly rounded log10 *ULP 0.5*
+#[inline]
+pub fn log10f(x: f32) -> f32 {
+    let y = do_log10(x as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    // Hard cases cover every argument failing the rounding test, fallback is not reached
+    hard_case(&HARD_ARGS, &HARD_RESULTS, x.to_bits()).unwrap_or(y as f32)
+}
@@ -0,0 +1,199 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+//! Correctly rounded single precision routines.
+//!
+//! Every routine evaluates the scalar f64 kernel, which is within 2^-49 relative error, and rounds
+//! the result to f32 when the whole error interval rounds to the same value.
+//! Kernels always use fused multiply-add and never dispatch to SIMD backends, so they return
+//! the same value on every target. Univariate routines were run over all 2^32 arguments, every
+//! argument that fails the test is stored with its correctly rounded result computed in
+//! arbitrary precision.
+//! [powf] switches to double-double evaluation with about 2^-98 relative error instead,
+//! exact midpoints such as `powf(1.000244, 2.)` are rounded to even.
+//! Special values follow C99 Annex F.
+//!
+//! ```rust
+//! let value = erydanos::cr::sinf(0.5f32);
+//! ```
+//!
+//! Exhaustive verification against the platform libm is available as ignored tests:
+//! `cargo test --release -- --ignored exhaustive`. References within 2^-50 of a rounding
+//! boundary are replaced by values computed in arbitrary precision.
+
+mod acosf;
+mod asinf;
+mod atanf;
+mod cbrtf;
+mod cosf;
+mod exp10f;
+mod exp2f;
+mod expf;
+mod lnf;
+mod log10f;
+mod log2f;
+mod powf;
+#[cfg(test)]
+mod reference;
+mod sinf;
+mod tanf;
+
+pub use acosf::acosf;
+pub use asinf::asinf;
+pub use atanf::atanf;
+pub use cbrtf::cbrtf;
+pub use cosf::cosf;
+pub use exp10f::exp10f;
+pub use exp2f::exp2f;
+pub use expf::expf;
+pub use lnf::lnf;
+pub use log10f::log10f;
+pub use log2f::log2f;
+pub use powf::powf;
+pub use sinf::sinf;
+pub use tanf::tanf;
+
+/// Relative error bound of f64 kernels used for evaluation, 2^-49
+const EVAL_ERROR: f64 = f64::from_bits(0x3ce0_0000_0000_0000);
+
+/// Rounds f64 approximation to f32 if every value within the evaluation error rounds the same way
+#[inline]
+pub(crate) fn round_checked(y: f64) -> Option<f32> {
+    if !y.is_finite() || y == 0. {
+        return Some(y as f32);
+    }
+    let e = y.abs() * EVAL_ERROR;
+    let lo = (y - e) as f32;
+    if lo == (y + e) as f32 {
+        Some(lo)
+    } else {
+        None
+    }
+}
+
+/// Looks up correctly rounded result of an argument failing the rounding test
+#[inline]
+pub(crate) fn hard_case(args: &[u32], results: &[u32], x: u32) -> Option<f32> {
+    args.binary_search(&x)
+        .ok()
+        .map(|i| f32::from_bits(results[i]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Compares against f64 reference rounded once, references within 2^-50 of a rounding
+    /// boundary are looked up in values computed in arbitrary precision
+    fn check(
+        f: fn(f32) -> f32,
+        reference: fn(f64) -> f64,
+        exact: &[(u32, u32)],
+        args: impl Iterator<Item = u32>,
+    ) {
+        for bits in args {
+            let x = f32::from_bits(bits);
+            let r = reference(x as f64);
+            let v = f(x);
+            if r.is_nan() {
+                assert!(v.is_nan(), "Invalid result for {:e}: {:e}", x, v);
+                continue;
+            }
+            let e = r.abs() * f64::from_bits(0x3cd0_0000_0000_0000);
+            let (lo, hi) = ((r - e) as f32, (r + e) as f32);
+            let expected = if r == 0. || r.is_infinite() || lo == hi {
+                r as f32
+            } else {
+                match exact.binary_search_by_key(&bits, |&(arg, _)| arg) {
+                    Ok(i) => f32::from_bits(exact[i].1),
+                    Err(_) => panic!("Reference is too close to a rounding boundary for {:e}", x),
+                }
+            };
+            assert_eq!(
+                v.to_bits(),
+                expected.to_bits(),
+                "Invalid result for {:e}: {:e}",
+                x,
+                v
+            );
+        }
+    }
+
+    fn exp10(x: f64) -> f64 {
+        10f64.powf(x)
+    }
+
+    macro_rules! cr_tests {
+        ($($test:ident, $exhaustive:ident => $f:path, $reference:path, $exact:path;)*) => {
+            $(
+                #[test]
+                fn $test() {
+                    check($f, $reference, &$exact, (0..=u32::MAX).step_by(65521));
+                    check($f, $reference, &$exact, 0x3f00_0000..0x3f00_4000);
+                    check($f, $reference, &$exact, $exact.iter().map(|&(arg, _)| arg));
+                }
+
+                #[test]
+                #[ignore]
+                fn $exhaustive() {
+                    check($f, $reference, &$exact, 0..=u32::MAX);
+                }
+            )*
+        };
+    }
+
+    cr_tests! {
+        test_acosf, exhaustive_acosf => acosf, f64::acos, reference::ACOSF;
+        test_asinf, exhaustive_asinf => asinf, f64::asin, reference::ASINF;
+        test_atanf, exhaustive_atanf => atanf, f64::atan, reference::ATANF;
+        test_cbrtf, exhaustive_cbrtf => cbrtf, f64::cbrt, reference::CBRTF;
+        test_cosf, exhaustive_cosf => cosf, f64::cos, reference::COSF;
+        test_exp10f, exhaustive_exp10f => exp10f, exp10, reference::EXP10F;
+        test_exp2f, exhaustive_exp2f => exp2f, f64::exp2, reference::EXP2F;
+        test_expf, exhaustive_expf => expf, f64::exp, reference::EXPF;
+        test_lnf, exhaustive_lnf => lnf, f64::ln, reference::LNF;
+        test_log10f, exhaustive_log10f => log10f, f64::log10, reference::LOG10F;
+        test_log2f, exhaustive_log2f => log2f, f64::log2, reference::LOG2F;
+        test_sinf, exhaustive_sinf => sinf, f64::sin, reference::SINF;
+        test_tanf, exhaustive_tanf => tanf, f64::tan, reference::TANF;
+    }
+
+    #[test]
+    fn test_powf() {
+        // (1 + 2^-12)^2 = 1 + 2^-11 + 2^-24 is a midpoint, ties to even
+        assert_eq!(powf(1.000244140625, 2.), 1.00048828125);
+        // (1 + 2^-12 + 2^-23)^2 is just above the midpoint
+        assert_eq!(
+            powf(f32::from_bits(0x3f80_0801), 2.),
+            f32::from_bits(0x3f80_1003)
+        );
+        assert_eq!(powf(-3., 3.), -27.);
+        assert_eq!(powf(2., -149.), f32::from_bits(1));
+        assert_eq!(powf(2., 0.5), std::f32::consts::SQRT_2);
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        for _ in 0..200000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let x = f32::from_bits((state >> 32) as u32 & 0x7fff_ffff);
+            let n = f32::from_bits(state as u32 & 0xc0ff_ffff);
+            let r = (x as f64).powf(n as f64);
+            let v = powf(x, n);
+            if r.is_nan() {
+                assert!(v.is_nan(), "pow({:e}, {:e}) = {:e}", x, n, v);
+                continue;
+            }
+            if r.is_infinite() || r == 0. {
+                assert_eq!(v.to_bits(), (r as f32).to_bits(), "pow({:e}, {:e})", x, n);
+                continue;
+            }
+            let e = r.abs() * f64::from_bits(0x3cd0_0000_0000_0000);
+            let (lo, hi) = ((r - e) as f32, (r + e) as f32);
+            assert!(v == lo || v == hi, "pow({:e}, {:e}) = {:e}", x, n, v);
+        }
+    }
+}
@@ -0,0 +1,119 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cr::round_checked;
+use crate::double_precision::{add_doubled, div_doubled, mul_doubled};
+use crate::pow::do_pow;
+
+const LN2: (f64, f64) = (std::f64::consts::LN_2, 2.3190468138462996e-17);
+
+/// Multiplies doubled numbers keeping the result normalized
+#[inline]
+fn mul_normalized(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+    let (r0, r1) = mul_doubled(a, b);
+    let s = r0 + r1;
+    (s, r1 - (s - r0))
+}
+
+/// Computes ln(x) for finite positive x as doubled number, relative error is about 2^-100
+#[inline]
+fn ln_doubled(x: f64) -> (f64, f64) {
+    let bits = x.to_bits();
+    let mut e = ((bits >> 52) & 0x7ff) as i64 - 1023;
+    let mut m = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+    if e == -1023 {
+        // Subnormal, f32 subnormals are normal in f64 so this is never reached for f32 arguments
+        return (x.ln(), 0.);
+    }
+    if m > std::f64::consts::SQRT_2 {
+        m *= 0.5;
+        e += 1;
+    }
+    // m - 1 and m + 1 are exact for m with f32 precision
+    let t = div_doubled((m - 1., 0.), (m + 1., 0.));
+    let z = mul_normalized(t, t);
+    // ln(m) = 2 * atanh(t) = 2 * (t + t^3/3 + t^5/5 + ...), |t| <= 0.1716
+    let mut s = (1. / 43., 0.);
+    let mut k = 41f64;
+    while k > 0. {
+        s = add_doubled(mul_normalized(s, z), div_doubled((1., 0.), (k, 0.)));
+        k -= 2.;
+    }
+    let s = mul_normalized(s, t);
+    add_doubled(mul_normalized(LN2, (e as f64, 0.)), (s.0 * 2., s.1 * 2.))
+}
+
+/// Computes exp(x) for doubled x as doubled number, relative error is about 2^-98
+#[inline]
+fn exp_doubled(x: (f64, f64)) -> (f64, f64) {
+    let k = (x.0 / LN2.0).round();
+    let p = mul_normalized(LN2, (k, 0.));
+    let r = add_doubled(x, (-p.0, -p.1));
+    // exp(r) = exp(r / 32)^32 with Taylor series for |r / 32| < 0.011
+    let r = (r.0 / 32., r.1 / 32.);
+    let mut s = (1., 0.);
+    let mut i = 13f64;
+    while i > 0. {
+        s = add_doubled(div_doubled(mul_normalized(s, r), (i, 0.)), (1., 0.));
+        i -= 1.;
+    }
+    for _ in 0..5 {
+        s = mul_normalized(s, s);
+    }
+    // k is in [-160, 130], 2^k is exact in f64 and so are the products
+    let scale = f64::from_bits(((k as i64 + 1023) as u64) << 52);
+    (s.0 * scale, s.1 * scale)
+}
+
+/// Rounds doubled number to f32 correctly through round-to-odd f64
+#[inline]
+fn round_doubled(v: (f64, f64)) -> f32 {
+    let s = v.0 + v.1;
+    let e = v.1 - (s - v.0);
+    if e != 0. && s.to_bits() & 1 == 0 {
+        // Round-to-odd keeps a sticky bit so the second rounding to f32 is correct
+        let bits = if (e > 0.) == (s > 0.) {
+            s.to_bits() + 1
+        } else {
+            s.to_bits() - 1
+        };
+        return f64::from_bits(bits) as f32;
+    }
+    s as f32
+}
+
+/// Computes correctly rounded x raised to the power n
+///
+/// Special values follow C99 Annex F, exact midpoints are rounded to even.
+#[inline]
+pub fn powf(x: f32, n: f32) -> f32 {
+    let y = do_pow(x as f64, n as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    // Arguments are finite here, and base is positive or power is integral
+    let v = exp_doubled(mul_normalized(ln_doubled(x.abs() as f64), (n as f64, 0.)));
+    let err = v.0.abs() * f64::from_bits(0x3a50_0000_0000_0000);
+    let lo = round_doubled((v.0, v.1 - err));
+    let hi = round_doubled((v.0, v.1 + err));
+    let r = if lo == hi {
+        lo
+    } else {
+        // Doubled evaluation cannot tell a midpoint apart, result is treated as exact midpoint
+        if lo.to_bits() & 1 == 0 {
+            lo
+        } else {
+            hi
+        }
+    };
+    let odd = n == n.trunc() && (n * 0.5) != (n * 0.5).trunc();
+    if x < 0. && odd {
+        -r
+    } else {
+        r
+    }
+}
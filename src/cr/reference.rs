@@ -0,0 +1,509 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+//! Correctly rounded results computed in arbitrary precision for arguments whose f64 libm
+//! value is within 2^-50 of a rounding boundary, so it can't serve as a reference.
+
+/// `(x, sinf(x))`
+pub(super) static SINF: [(u32, u32); 66] = [
+    (0x3dcf5597, 0x3dcefaf9),
+    (0x3ef3830f, 0x3eea6f45),
+    (0x42d44528, 0xbf20c9a7),
+    (0x4371ade3, 0x3e62da56),
+    (0x45a8abb3, 0x3e51ade2),
+    (0x46199998, 0xbeb1fa5d),
+    (0x4967cb9b, 0x3ec9df85),
+    (0x4a987933, 0xbf70f319),
+    (0x4aa5a796, 0x3f7ff108),
+    (0x4ecd11c7, 0x3f37516c),
+    (0x4fb56937, 0xbf7ffcb7),
+    (0x55cafb2a, 0xbf7e7a17),
+    (0x58dfb085, 0xbf5585ae),
+    (0x5a935f4c, 0x3f5f0fa8),
+    (0x5cdaa4f7, 0x3f7108db),
+    (0x5dadd689, 0xbf74fc9e),
+    (0x5f208d82, 0x3f2c7688),
+    (0x616d8730, 0x3f67ef01),
+    (0x61dfc847, 0xbf0094df),
+    (0x6446cec0, 0xbf6995bd),
+    (0x653cee8f, 0x3f30df39),
+    (0x67051b8d, 0xbe7ccf49),
+    (0x67a9242b, 0xbf7fab81),
+    (0x6a3f60ff, 0xbf79ee5f),
+    (0x6d734599, 0xbf5b5135),
+    (0x6dcea82e, 0x3f40f5e1),
+    (0x6e649053, 0xbedf21a7),
+    (0x73243f06, 0x3e943a84),
+    (0x79d1f6d3, 0xbf7a88ee),
+    (0x7a5aacdb, 0x3eda83bb),
+    (0x7a817b08, 0x3e1b493e),
+    (0x7c2e964a, 0xbef4a5ec),
+    (0x7f2a2ca7, 0x3efdc03d),
+    (0xbdcf5597, 0xbdcefaf9),
+    (0xbef3830f, 0xbeea6f45),
+    (0xc2d44528, 0x3f20c9a7),
+    (0xc371ade3, 0xbe62da56),
+    (0xc5a8abb3, 0xbe51ade2),
+    (0xc6199998, 0x3eb1fa5d),
+    (0xc967cb9b, 0xbec9df85),
+    (0xca987933, 0x3f70f319),
+    (0xcaa5a796, 0xbf7ff108),
+    (0xcecd11c7, 0xbf37516c),
+    (0xcfb56937, 0x3f7ffcb7),
+    (0xd5cafb2a, 0x3f7e7a17),
+    (0xd8dfb085, 0x3f5585ae),
+    (0xda935f4c, 0xbf5f0fa8),
+    (0xdcdaa4f7, 0xbf7108db),
+    (0xddadd689, 0x3f74fc9e),
+    (0xdf208d82, 0xbf2c7688),
+    (0xe16d8730, 0xbf67ef01),
+    (0xe1dfc847, 0x3f0094df),
+    (0xe446cec0, 0x3f6995bd),
+    (0xe53cee8f, 0xbf30df39),
+    (0xe7051b8d, 0x3e7ccf49),
+    (0xe7a9242b, 0x3f7fab81),
+    (0xea3f60ff, 0x3f79ee5f),
+    (0xed734599, 0x3f5b5135),
+    (0xedcea82e, 0xbf40f5e1),
+    (0xee649053, 0x3edf21a7),
+    (0xf3243f06, 0xbe943a84),
+    (0xf9d1f6d3, 0x3f7a88ee),
+    (0xfa5aacdb, 0xbeda83bb),
+    (0xfa817b08, 0xbe1b493e),
+    (0xfc2e964a, 0x3ef4a5ec),
+    (0xff2a2ca7, 0xbefdc03d),
+];
+
+/// `(x, cosf(x))`
+pub(super) static COSF: [(u32, u32); 66] = [
+    (0x39800000, 0x3f800000),
+    (0x3a0f1bbd, 0x3f7ffffd),
+    (0x3a544395, 0x3f7ffffb),
+    (0x3c107fe6, 0x3f7ffd74),
+    (0x42378db8, 0xbea87a07),
+    (0x424790ce, 0x3f6e4c01),
+    (0x46f85a22, 0xbf4232b9),
+    (0x47a0e238, 0x3f7e19dd),
+    (0x4a01dca4, 0x3f30b318),
+    (0x4e5b65ff, 0xbf62075d),
+    (0x504be581, 0x3df8dc9c),
+    (0x52d9d3fe, 0xbf5e187b),
+    (0x52f88494, 0x3f5c5971),
+    (0x55e5235d, 0xbe83c11a),
+    (0x5922aa80, 0x3f08aebf),
+    (0x59443c0a, 0x3f425f62),
+    (0x5a1a3626, 0xbf5fdc79),
+    (0x5a8c921b, 0x3f5ea913),
+    (0x5f18b878, 0x3f7f14bb),
+    (0x6115cb11, 0x3f78142f),
+    (0x61703976, 0x3edacc56),
+    (0x6978e912, 0xbd769ca5),
+    (0x744fd5d8, 0x3f6cb54a),
+    (0x75da4c95, 0x3f7aeabb),
+    (0x76d7173f, 0x3f266b3e),
+    (0x77f7b624, 0x3f67741e),
+    (0x7908cd73, 0x3f798bb5),
+    (0x797cef4f, 0x3f7de018),
+    (0x7a38ab34, 0x3f7b3195),
+    (0x7a4b1a27, 0x3f7c54da),
+    (0x7c64841e, 0xbf7fc6f8),
+    (0x7c69ae1e, 0xbf7ff9fe),
+    (0x7db91687, 0x3edfe26d),
+    (0xb9800000, 0x3f800000),
+    (0xba0f1bbd, 0x3f7ffffd),
+    (0xba544395, 0x3f7ffffb),
+    (0xbc107fe6, 0x3f7ffd74),
+    (0xc2378db8, 0xbea87a07),
+    (0xc24790ce, 0x3f6e4c01),
+    (0xc6f85a22, 0xbf4232b9),
+    (0xc7a0e238, 0x3f7e19dd),
+    (0xca01dca4, 0x3f30b318),
+    (0xce5b65ff, 0xbf62075d),
+    (0xd04be581, 0x3df8dc9c),
+    (0xd2d9d3fe, 0xbf5e187b),
+    (0xd2f88494, 0x3f5c5971),
+    (0xd5e5235d, 0xbe83c11a),
+    (0xd922aa80, 0x3f08aebf),
+    (0xd9443c0a, 0x3f425f62),
+    (0xda1a3626, 0xbf5fdc79),
+    (0xda8c921b, 0x3f5ea913),
+    (0xdf18b878, 0x3f7f14bb),
+    (0xe115cb11, 0x3f78142f),
+    (0xe1703976, 0x3edacc56),
+    (0xe978e912, 0xbd769ca5),
+    (0xf44fd5d8, 0x3f6cb54a),
+    (0xf5da4c95, 0x3f7aeabb),
+    (0xf6d7173f, 0x3f266b3e),
+    (0xf7f7b624, 0x3f67741e),
+    (0xf908cd73, 0x3f798bb5),
+    (0xf97cef4f, 0x3f7de018),
+    (0xfa38ab34, 0x3f7b3195),
+    (0xfa4b1a27, 0x3f7c54da),
+    (0xfc64841e, 0xbf7fc6f8),
+    (0xfc69ae1e, 0xbf7ff9fe),
+    (0xfdb91687, 0x3edfe26d),
+];
+
+/// `(x, tanf(x))`
+pub(super) static TANF: [(u32, u32); 34] = [
+    (0x3e19205e, 0x3e1a4733),
+    (0x408174dd, 0x3fa29b31),
+    (0x40e67f59, 0x3fa80c88),
+    (0x451e0885, 0xbef714d6),
+    (0x453c5846, 0x3f64fa0e),
+    (0x4ece8446, 0xbfbd4d2f),
+    (0x575852f8, 0xbfc0e1da),
+    (0x5be68bcd, 0xbff16157),
+    (0x5d5873ae, 0x3ea6bfed),
+    (0x5ffd33a4, 0x3fd06c8c),
+    (0x613d28d9, 0x3ec05657),
+    (0x76eb0bdc, 0x4019eb75),
+    (0x7714b423, 0x3fe83698),
+    (0x77cda26b, 0x3f882b34),
+    (0x79c42c65, 0x3fa2e336),
+    (0x7a98d2c2, 0x3f4aef73),
+    (0x7dae7426, 0x3f4ac6db),
+    (0xbe19205e, 0xbe1a4733),
+    (0xc08174dd, 0xbfa29b31),
+    (0xc0e67f59, 0xbfa80c88),
+    (0xc51e0885, 0x3ef714d6),
+    (0xc53c5846, 0xbf64fa0e),
+    (0xcece8446, 0x3fbd4d2f),
+    (0xd75852f8, 0x3fc0e1da),
+    (0xdbe68bcd, 0x3ff16157),
+    (0xdd5873ae, 0xbea6bfed),
+    (0xdffd33a4, 0xbfd06c8c),
+    (0xe13d28d9, 0xbec05657),
+    (0xf6eb0bdc, 0xc019eb75),
+    (0xf714b423, 0xbfe83698),
+    (0xf7cda26b, 0xbf882b34),
+    (0xf9c42c65, 0xbfa2e336),
+    (0xfa98d2c2, 0xbf4aef73),
+    (0xfdae7426, 0xbf4ac6db),
+];
+
+/// `(x, atanf(x))`
+pub(super) static ATANF: [(u32, u32); 20] = [
+    (0x3a27ba3b, 0x3a27ba3a),
+    (0x3aac434b, 0x3aac4344),
+    (0x3ad637fa, 0x3ad637ee),
+    (0x3b7c1bc9, 0x3b7c1b78),
+    (0x3d8d6b23, 0x3d8d31c3),
+    (0x3ee06db1, 0x3ed38381),
+    (0x40357f1d, 0x3f9dab29),
+    (0x4a2367a3, 0x3fc90fd7),
+    (0x4c700517, 0x3fc90fda),
+    (0x4c700518, 0x3fc90fdb),
+    (0xba27ba3b, 0xba27ba3a),
+    (0xbaac434b, 0xbaac4344),
+    (0xbad637fa, 0xbad637ee),
+    (0xbb7c1bc9, 0xbb7c1b78),
+    (0xbd8d6b23, 0xbd8d31c3),
+    (0xbee06db1, 0xbed38381),
+    (0xc0357f1d, 0xbf9dab29),
+    (0xca2367a3, 0xbfc90fd7),
+    (0xcc700517, 0xbfc90fda),
+    (0xcc700518, 0xbfc90fdb),
+];
+
+/// `(x, asinf(x))`
+pub(super) static ASINF: [(u32, u32); 8] = [
+    (0x39e89768, 0x39e89769),
+    (0x3d07959c, 0x3d079bf3),
+    (0x3de5fa1e, 0x3de6768e),
+    (0x3f083a1a, 0x3f0fa5b2),
+    (0xb9e89768, 0xb9e89769),
+    (0xbd07959c, 0xbd079bf3),
+    (0xbde5fa1e, 0xbde6768e),
+    (0xbf083a1a, 0xbf0fa5b2),
+];
+
+/// `(x, acosf(x))`
+pub(super) static ACOSF: [(u32, u32); 10] = [
+    (0x328885a3, 0x3fc90fdb),
+    (0x39826222, 0x3fc907b5),
+    (0x3a6a490f, 0x3fc8f292),
+    (0x3b7d281b, 0x3fc89147),
+    (0x3c8a2f9b, 0x3fc6e716),
+    (0xba66deed, 0x3fc92cb6),
+    (0xba9d5f75, 0x3fc93733),
+    (0xbc406ccd, 0x3fca90b6),
+    (0xbe668cd1, 0x3fe62141),
+    (0xbe6dff37, 0x3fe7160b),
+];
+
+/// `(x, cbrtf(x))`
+pub(super) static CBRTF: [(u32, u32); 0] = [];
+
+/// `(x, expf(x))`
+pub(super) static EXPF: [(u32, u32); 8] = [
+    (0x377eff81, 0x3f800080),
+    (0x39c6be5b, 0x3f800c6d),
+    (0x4001b249, 0x40f2cd14),
+    (0x40315b33, 0x417fa47d),
+    (0xb3000000, 0x3f800000),
+    (0xbae0e25c, 0x3f7f8fa7),
+    (0xbbf0edf1, 0x3f7e1fe9),
+    (0xc16912cd, 0x34fd331b),
+];
+
+/// `(x, exp2f(x))`
+pub(super) static EXP2F: [(u32, u32); 12] = [
+    (0x33b8aa3b, 0x3f800001),
+    (0x36879cf7, 0x3f800018),
+    (0x3a07857c, 0x3f800bbe),
+    (0x3b429d37, 0x3f804385),
+    (0x3c02a9ad, 0x3f80b5a3),
+    (0xb466d4cb, 0x3f7ffffe),
+    (0xb52d1f9a, 0x3f7ffff8),
+    (0xb8d3d026, 0x3f7ffb69),
+    (0xbaec2b40, 0x3f7fae34),
+    (0xbcf3a937, 0x3f7ac6b1),
+    (0xbe1f29de, 0x3f65da56),
+    (0xc3160000, 0x00000000),
+];
+
+/// `(x, exp10f(x))`
+pub(super) static EXP10F: [(u32, u32); 10] = [
+    (0x34d07615, 0x3f800007),
+    (0x35b1311f, 0x3f800019),
+    (0x417d7f60, 0x59c64406),
+    (0xb25e5bd9, 0x3f7fffff),
+    (0xb326c4e3, 0x3f7ffffe),
+    (0xb7d171cd, 0x3f7ffc3b),
+    (0xbaac4a50, 0x3f7f39f2),
+    (0xbac4c65c, 0x3f7f1dd8),
+    (0xbd6181f7, 0x3f618508),
+    (0xbf230886, 0x3e6c4b0c),
+];
+
+/// `(x, lnf(x))`
+pub(super) static LNF: [(u32, u32); 46] = [
+    (0x064cb44b, 0xc29e7c1a),
+    (0x07c060fa, 0xc29a733a),
+    (0x0dc8bba4, 0xc289bac4),
+    (0x0f61ff63, 0xc2855565),
+    (0x111c87f8, 0xc28085df),
+    (0x145cb6d4, 0xc26f092f),
+    (0x14907055, 0xc26df596),
+    (0x16c5ee7a, 0xc2619bd4),
+    (0x18b06f2a, 0xc256fa6f),
+    (0x1a8446cb, 0xc24d0a45),
+    (0x1b81ab52, 0xc2479316),
+    (0x1f116ab8, 0xc233b53e),
+    (0x22925ad4, 0xc220462d),
+    (0x2423c085, 0xc21781d1),
+    (0x28e3fa26, 0xc1fa75e0),
+    (0x29e6126b, 0xc1ef4c02),
+    (0x29fd22f8, 0xc1ee8859),
+    (0x2c4c24b7, 0xc1d48710),
+    (0x2e492984, 0xc1be76ee),
+    (0x3418949b, 0xc17c447f),
+    (0x38dcbe38, 0xc1128ba4),
+    (0x39c31348, 0xc0fcaf65),
+    (0x39c7b1f9, 0xc0fbefa7),
+    (0x3bf86ef0, 0xc09c399e),
+    (0x3c413d3a, 0xc08e158f),
+    (0x41178feb, 0x400fe5e7),
+    (0x464d5b2b, 0x4117bcf1),
+    (0x4665a9a6, 0x41198725),
+    (0x4c5d65a5, 0x418f034b),
+    (0x4d604ebe, 0x419a352c),
+    (0x4e85f412, 0x41a6b811),
+    (0x54af989d, 0x41eb6d3c),
+    (0x5d800341, 0x42265b10),
+    (0x5ee8984e, 0x422e4a21),
+    (0x5f64c24a, 0x4230fee2),
+    (0x62b467ba, 0x42437434),
+    (0x64e27fa3, 0x424f7453),
+    (0x65d890d3, 0x4254d1f9),
+    (0x66a8c860, 0x42595e46),
+    (0x66abbd63, 0x4259700e),
+    (0x6914cb96, 0x4266ba1e),
+    (0x6d1f23eb, 0x427d2d35),
+    (0x6f31a8ec, 0x42845a89),
+    (0x7405dee8, 0x4291a68d),
+    (0x79e7ec37, 0x42a1ffb7),
+    (0x7d98b8f4, 0x42ac40f2),
+];
+
+/// `(x, log2f(x))`
+pub(super) static LOG2F: [(u32, u32); 126] = [
+    (0x002452a4, 0xc2ffa268),
+    (0x0048a548, 0xc2fda268),
+    (0x00914a90, 0xc2fba268),
+    (0x01114a90, 0xc2f9a268),
+    (0x01914a90, 0xc2f7a268),
+    (0x02114a90, 0xc2f5a268),
+    (0x02914a90, 0xc2f3a268),
+    (0x03114a90, 0xc2f1a268),
+    (0x03914a90, 0xc2efa268),
+    (0x04114a90, 0xc2eda268),
+    (0x04914a90, 0xc2eba268),
+    (0x05114a90, 0xc2e9a268),
+    (0x05914a90, 0xc2e7a268),
+    (0x06114a90, 0xc2e5a268),
+    (0x06914a90, 0xc2e3a268),
+    (0x07114a90, 0xc2e1a268),
+    (0x07914a90, 0xc2dfa268),
+    (0x08114a90, 0xc2dda268),
+    (0x08914a90, 0xc2dba268),
+    (0x09114a90, 0xc2d9a268),
+    (0x09914a90, 0xc2d7a268),
+    (0x0a114a90, 0xc2d5a268),
+    (0x0a914a90, 0xc2d3a268),
+    (0x0b114a90, 0xc2d1a268),
+    (0x0b914a90, 0xc2cfa268),
+    (0x0c114a90, 0xc2cda268),
+    (0x0c914a90, 0xc2cba268),
+    (0x0d114a90, 0xc2c9a268),
+    (0x0d914a90, 0xc2c7a268),
+    (0x0e114a90, 0xc2c5a268),
+    (0x0e914a90, 0xc2c3a268),
+    (0x0f114a90, 0xc2c1a268),
+    (0x0f914a90, 0xc2bfa268),
+    (0x10114a90, 0xc2bda268),
+    (0x10914a90, 0xc2bba268),
+    (0x11114a90, 0xc2b9a268),
+    (0x11914a90, 0xc2b7a268),
+    (0x12114a90, 0xc2b5a268),
+    (0x12914a90, 0xc2b3a268),
+    (0x13114a90, 0xc2b1a268),
+    (0x13914a90, 0xc2afa268),
+    (0x14114a90, 0xc2ada268),
+    (0x14914a90, 0xc2aba268),
+    (0x15114a90, 0xc2a9a268),
+    (0x15914a90, 0xc2a7a268),
+    (0x16114a90, 0xc2a5a268),
+    (0x16914a90, 0xc2a3a268),
+    (0x17114a90, 0xc2a1a268),
+    (0x17914a90, 0xc29fa268),
+    (0x18114a90, 0xc29da268),
+    (0x18914a90, 0xc29ba268),
+    (0x19114a90, 0xc299a268),
+    (0x19914a90, 0xc297a268),
+    (0x1a114a90, 0xc295a268),
+    (0x1a914a90, 0xc293a268),
+    (0x1b114a90, 0xc291a268),
+    (0x2fd54996, 0xc1fa1b55),
+    (0x30554996, 0xc1f21b55),
+    (0x30d54996, 0xc1ea1b55),
+    (0x31554996, 0xc1e21b55),
+    (0x31d54996, 0xc1da1b55),
+    (0x32554996, 0xc1d21b55),
+    (0x3ea07ab9, 0xbfd63da2),
+    (0x40207ab9, 0x3fa9c25e),
+    (0x4cd54996, 0x41d5e4ab),
+    (0x4d554996, 0x41dde4ab),
+    (0x4dd54996, 0x41e5e4ab),
+    (0x4e554996, 0x41ede4ab),
+    (0x4ed54996, 0x41f5e4ab),
+    (0x4f554996, 0x41fde4ab),
+    (0x63914a90, 0x42905d98),
+    (0x64114a90, 0x42925d98),
+    (0x64914a90, 0x42945d98),
+    (0x65114a90, 0x42965d98),
+    (0x65914a90, 0x42985d98),
+    (0x66114a90, 0x429a5d98),
+    (0x66914a90, 0x429c5d98),
+    (0x67114a90, 0x429e5d98),
+    (0x67914a90, 0x42a05d98),
+    (0x68114a90, 0x42a25d98),
+    (0x68914a90, 0x42a45d98),
+    (0x69114a90, 0x42a65d98),
+    (0x69914a90, 0x42a85d98),
+    (0x6a114a90, 0x42aa5d98),
+    (0x6a914a90, 0x42ac5d98),
+    (0x6b114a90, 0x42ae5d98),
+    (0x6b914a90, 0x42b05d98),
+    (0x6c114a90, 0x42b25d98),
+    (0x6c914a90, 0x42b45d98),
+    (0x6d114a90, 0x42b65d98),
+    (0x6d914a90, 0x42b85d98),
+    (0x6e114a90, 0x42ba5d98),
+    (0x6e914a90, 0x42bc5d98),
+    (0x6f114a90, 0x42be5d98),
+    (0x6f914a90, 0x42c05d98),
+    (0x70114a90, 0x42c25d98),
+    (0x70914a90, 0x42c45d98),
+    (0x71114a90, 0x42c65d98),
+    (0x71914a90, 0x42c85d98),
+    (0x72114a90, 0x42ca5d98),
+    (0x72914a90, 0x42cc5d98),
+    (0x73114a90, 0x42ce5d98),
+    (0x73914a90, 0x42d05d98),
+    (0x74114a90, 0x42d25d98),
+    (0x74914a90, 0x42d45d98),
+    (0x75114a90, 0x42d65d98),
+    (0x75914a90, 0x42d85d98),
+    (0x76114a90, 0x42da5d98),
+    (0x76914a90, 0x42dc5d98),
+    (0x77114a90, 0x42de5d98),
+    (0x77914a90, 0x42e05d98),
+    (0x78114a90, 0x42e25d98),
+    (0x78914a90, 0x42e45d98),
+    (0x79114a90, 0x42e65d98),
+    (0x79914a90, 0x42e85d98),
+    (0x7a114a90, 0x42ea5d98),
+    (0x7a914a90, 0x42ec5d98),
+    (0x7b114a90, 0x42ee5d98),
+    (0x7b914a90, 0x42f05d98),
+    (0x7c114a90, 0x42f25d98),
+    (0x7c914a90, 0x42f45d98),
+    (0x7d114a90, 0x42f65d98),
+    (0x7d914a90, 0x42f85d98),
+    (0x7e114a90, 0x42fa5d98),
+    (0x7e914a90, 0x42fc5d98),
+    (0x7f114a90, 0x42fe5d98),
+];
+
+/// `(x, log10f(x))`
+pub(super) static LOG10F: [(u32, u32); 43] = [
+    (0x0225f259, 0xc213a7de),
+    (0x058ecd6a, 0xc20b7ce1),
+    (0x0a4d4ce8, 0xc2000527),
+    (0x0ab667dd, 0xc1fe0af5),
+    (0x0e10c607, 0xc1edfce8),
+    (0x0efeee7a, 0xc1e99d23),
+    (0x10b88aec, 0xc1e11a7d),
+    (0x120b93dc, 0xc1dad957),
+    (0x1266ada7, 0xc1d91a7d),
+    (0x13ae78d3, 0xc1d2d957),
+    (0x13b57a47, 0xc1d2b653),
+    (0x15964dc5, 0xc1c9bbed),
+    (0x1944e975, 0xc1b7f01f),
+    (0x1eac122f, 0xc19dea75),
+    (0x262ca79a, 0xc1738f9c),
+    (0x2846c871, 0xc15f50dc),
+    (0x2b1b73f9, 0xc144201d),
+    (0x2bb4e779, 0xc13e4161),
+    (0x2f149212, 0xc11de885),
+    (0x33b5ebfc, 0xc0e24e31),
+    (0x356366fb, 0xc0c24e31),
+    (0x427a28e9, 0x3fe5e87d),
+    (0x43079cce, 0x40087799),
+    (0x45bdedc8, 0x407228d0),
+    (0x499271d9, 0x40c287b1),
+    (0x4dff4ddc, 0x410ba8b4),
+    (0x56f8c876, 0x41622d04),
+    (0x5d610fe9, 0x41900c01),
+    (0x5f3315a3, 0x4198e2cb),
+    (0x604df02c, 0x419e3014),
+    (0x610567e4, 0x41a17eec),
+    (0x62a6c1dd, 0x41a97eec),
+    (0x6307980c, 0x41ab2f6e),
+    (0x63bf4495, 0x41aec9ea),
+    (0x63ce2f36, 0x41af0cb5),
+    (0x64a97e0f, 0x41b32f6e),
+    (0x65716a23, 0x41b6d28a),
+    (0x65903d25, 0x41b770f4),
+    (0x6a581bf5, 0x41ce8525),
+    (0x6f592c3c, 0x41e69e9c),
+    (0x711016a5, 0x41eed3bd),
+    (0x73cb20d6, 0x41fc0fba),
+    (0x7f6362e7, 0x4219ebe5),
+];
@@ -0,0 +1,51 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cr::{hard_case, round_checked};
+use crate::sin::do_sin;
+
+/// Absolute values of arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 65] = [
+    0x3a1285ff, 0x3dcf5597, 0x3ef32001, 0x3ef3830f, 0x3fa0fa4e, 0x401f2100, 0x42d44528, 0x4371ade3,
+    0x45a8abb3, 0x4605b1c7, 0x46199998, 0x47ae93a5, 0x4967cb9b, 0x497d25c7, 0x4a987933, 0x4aa5a796,
+    0x4df947f3, 0x4ecd11c7, 0x4f45dcab, 0x4fb56937, 0x509b1e93, 0x521945ed, 0x545bb734, 0x55cafb2a,
+    0x55da572e, 0x58dfb085, 0x5a935f4c, 0x5af484be, 0x5b258da4, 0x5b895db8, 0x5c8248a5, 0x5cdaa4f7,
+    0x5d937ce2, 0x5dadd689, 0x5de78921, 0x5f208d82, 0x616d8730, 0x61dfc847, 0x62e31fd9, 0x6446cec0,
+    0x64e5a461, 0x6504cef1, 0x653cee8f, 0x6652a0dc, 0x67051b8d, 0x67a9242b, 0x6a3f60ff, 0x6d734599,
+    0x6dcea82e, 0x6e1cdf42, 0x6e649053, 0x6e671317, 0x73243f06, 0x73263d14, 0x742c68ce, 0x7970a79d,
+    0x79d1f6d3, 0x7a5aacdb, 0x7a817b08, 0x7a93d493, 0x7bcebcdd, 0x7c2e964a, 0x7c5d6b82, 0x7f2a2ca7,
+    0x7f58cad9,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 65] = [
+    0x3a1285ff, 0x3dcefaf9, 0x3eea1732, 0x3eea6f45, 0x3f738cb1, 0x3f1bfc6f, 0xbf20c9a7, 0x3e62da56,
+    0x3e51ade2, 0xbf7343e3, 0xbeb1fa5d, 0xbf7704a2, 0x3ec9df85, 0xbeb31ec5, 0xbf70f319, 0x3f7ff108,
+    0xbf1f1f91, 0x3f37516c, 0x3f3539dd, 0xbf7ffcb7, 0xbedfccf9, 0xbd933a00, 0xbc6fce9e, 0xbf7e7a17,
+    0x3d9ed773, 0xbf5585ae, 0x3f5f0fa8, 0xbf60d313, 0xbf67de0e, 0x3f550402, 0xbf683e58, 0x3f7108db,
+    0xbf757e8d, 0xbf74fc9e, 0xbe4a4b3a, 0x3f2c7688, 0x3f67ef01, 0xbf0094df, 0x3eed33b2, 0xbf6995bd,
+    0xbe9d4f71, 0xbf141a50, 0x3f30df39, 0x3f388139, 0xbe7ccf49, 0xbf7fab81, 0xbf79ee5f, 0xbf5b5135,
+    0x3f40f5e1, 0xbf7c9ed7, 0xbedf21a7, 0x3f0eca9e, 0x3e943a84, 0x3f7c42ce, 0xbf787516, 0x3f4207c3,
+    0xbf7a88ee, 0x3eda83bb, 0x3e1b493e, 0x3f406f11, 0xbe26a9af, 0xbef4a5ec, 0x3ea0ddcf, 0x3efdc03d,
+    0xbf315ecf,
+];
+
+/// Computes correctly rounded sine *ULP 0.5*
+#[inline]
+pub fn sinf(x: f32) -> f32 {
+    let y = do_sin(x as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    match hard_case(&HARD_ARGS, &HARD_RESULTS, x.abs().to_bits()) {
+        Some(r) if x < 0. => -r,
+        Some(r) => r,
+        // Hard cases cover every argument failing the rounding test, not reached
+        None => y as f32,
+    }
+}
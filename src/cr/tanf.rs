@@ -0,0 +1,43 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cr::{hard_case, round_checked};
+use crate::tan::do_tan;
+
+/// Absolute values of arguments whose f64 image is too close to a rounding boundary,
+/// found by exhaustive search
+static HARD_ARGS: [u32; 36] = [
+    0x39b89ba2, 0x3e19205e, 0x408174dd, 0x40e67f59, 0x43b055d2, 0x43bf198b, 0x451e0885, 0x453c5846,
+    0x48652066, 0x4872b9fa, 0x490b32df, 0x4c0a82a3, 0x4ece8446, 0x56947c41, 0x56b773f2, 0x575852f8,
+    0x5be68bcd, 0x5c136919, 0x5d5873ae, 0x5dc574a6, 0x5ef23c3f, 0x5ffd33a4, 0x613d28d9, 0x61fe289f,
+    0x624684ef, 0x67a2943d, 0x68058f5d, 0x6a70270c, 0x76eb0bdc, 0x7714b423, 0x77cda26b, 0x79c42c65,
+    0x7a98d2c2, 0x7b97b9ec, 0x7dae7426, 0x7e4f5c9c,
+];
+
+/// Correctly rounded results for [HARD_ARGS]
+static HARD_RESULTS: [u32; 36] = [
+    0x39b89ba2, 0x3e1a4733, 0x3fa29b31, 0x3fa80c88, 0x3f8705f6, 0xbfec8514, 0xbef714d6, 0x3f64fa0e,
+    0xc001908a, 0xc12f32dd, 0xbe97e86c, 0xbffd2655, 0xbfbd4d2f, 0x3e62ee0f, 0xc14a83ce, 0xbfc0e1da,
+    0xbff16157, 0x40aa6cf4, 0x3ea6bfed, 0x3f2e6c11, 0x3ffd27c1, 0x3fd06c8c, 0x3ec05657, 0xbcb3073d,
+    0x3fb4f1df, 0xbfcd73cb, 0x3e9076c9, 0xc18c93aa, 0x4019eb75, 0x3fe83698, 0x3f882b34, 0x3fa2e336,
+    0x3f4aef73, 0x3e2e5b04, 0x3f4ac6db, 0x400411b4,
+];
+
+/// Computes correctly rounded tangent *ULP 0.5*
+#[inline]
+pub fn tanf(x: f32) -> f32 {
+    let y = do_tan(x as f64);
+    if let Some(r) = round_checked(y) {
+        return r;
+    }
+    match hard_case(&HARD_ARGS, &HARD_RESULTS, x.abs().to_bits()) {
+        Some(r) if x < 0. => -r,
+        Some(r) => r,
+        // Hard cases cover every argument failing the rounding test, not reached
+        None => y as f32,
+    }
+}
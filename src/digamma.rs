@@ -0,0 +1,212 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_digamma;
+use crate::floor::efloor;
+use crate::generalf::{mlaf, rintk};
+use crate::ln::do_ln;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vdigammaq_f64;
+use crate::sin::sinpik;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_digamma_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const DIGAMMA_POLY_1_D: f64 = 0.9510558760318328;
+pub(crate) const DIGAMMA_POLY_2_D: f64 = -0.4236274212814606;
+pub(crate) const DIGAMMA_POLY_3_D: f64 = 0.24054248424078697;
+pub(crate) const DIGAMMA_POLY_4_D: f64 = -0.14840492305388092;
+pub(crate) const DIGAMMA_POLY_5_D: f64 = 0.09498872445289039;
+pub(crate) const DIGAMMA_POLY_6_D: f64 = -0.06192213327548831;
+pub(crate) const DIGAMMA_POLY_7_D: f64 = 0.040760833940846085;
+pub(crate) const DIGAMMA_POLY_8_D: f64 = -0.026975796659787055;
+pub(crate) const DIGAMMA_POLY_9_D: f64 = 0.017907248317313294;
+pub(crate) const DIGAMMA_POLY_10_D: f64 = -0.011908220105959716;
+pub(crate) const DIGAMMA_POLY_11_D: f64 = 0.007927021796868154;
+pub(crate) const DIGAMMA_POLY_12_D: f64 = -0.005279937255532708;
+pub(crate) const DIGAMMA_POLY_13_D: f64 = 0.003518106563802472;
+pub(crate) const DIGAMMA_POLY_14_D: f64 = -0.0023454947397380833;
+pub(crate) const DIGAMMA_POLY_15_D: f64 = 0.0015633696313653504;
+pub(crate) const DIGAMMA_POLY_16_D: f64 = -0.0010364584817312839;
+pub(crate) const DIGAMMA_POLY_17_D: f64 = 0.0006909255761282986;
+pub(crate) const DIGAMMA_POLY_18_D: f64 = -0.00048595039220036254;
+pub(crate) const DIGAMMA_POLY_19_D: f64 = 0.00032395945401345196;
+pub(crate) const DIGAMMA_POLY_20_D: f64 = -0.00014480643368061614;
+pub(crate) const DIGAMMA_POLY_21_D: f64 = 9.653646261410062e-5;
+pub(crate) const DIGAMMA_POLY_22_D: f64 = -0.0001782188548855923;
+pub(crate) const DIGAMMA_POLY_23_D: f64 = 0.00011881232908510335;
+pub(crate) const DIGAMMA_ASYMPT_POLY_1_D: f64 = 0.0833333333333333;
+pub(crate) const DIGAMMA_ASYMPT_POLY_2_D: f64 = -0.00833333333308159;
+pub(crate) const DIGAMMA_ASYMPT_POLY_3_D: f64 = 0.003968253673479358;
+pub(crate) const DIGAMMA_ASYMPT_POLY_4_D: f64 = -0.004166539940034663;
+pub(crate) const DIGAMMA_ASYMPT_POLY_5_D: f64 = 0.007550959652543505;
+pub(crate) const DIGAMMA_ASYMPT_POLY_6_D: f64 = -0.018818831275584015;
+/// Positive root of digamma as double-float
+pub(crate) const DIGAMMA_ROOT_D: f64 = 1.4616321449683622;
+pub(crate) const DIGAMMA_ROOT_LO_D: f64 = 9.549995429965697e-17;
+/// Positive root of digamma minus one as double-float
+pub(crate) const DIGAMMA_ROOT_M1_D: f64 = 0.46163214496836236;
+pub(crate) const DIGAMMA_ROOT_M1_LO_D: f64 = -1.5522348162858677e-17;
+
+/// Computes ψ(x) for x >= 0
+#[inline(always)]
+pub(crate) fn digammak(x: f64) -> f64 {
+    if x >= 10. {
+        // ψ(x) = ln(x) - 1/2x - z D(z), z = 1/x^2
+        let ix = 1. / x;
+        let z = ix * ix;
+        let mut u = DIGAMMA_ASYMPT_POLY_6_D;
+        u = mlaf(u, z, DIGAMMA_ASYMPT_POLY_5_D);
+        u = mlaf(u, z, DIGAMMA_ASYMPT_POLY_4_D);
+        u = mlaf(u, z, DIGAMMA_ASYMPT_POLY_3_D);
+        u = mlaf(u, z, DIGAMMA_ASYMPT_POLY_2_D);
+        u = mlaf(u, z, DIGAMMA_ASYMPT_POLY_1_D);
+        return do_ln(x) - mlaf(z, u, 0.5 * ix);
+    }
+    // ψ(1 + t) = (t - t0) G(t) keeps the root t0 apart, so values around it stay accurate
+    let n = x as i32;
+    let t = x - n as f64;
+    let v = t - 0.5;
+    let mut u = DIGAMMA_POLY_23_D;
+    u = mlaf(u, v, DIGAMMA_POLY_22_D);
+    u = mlaf(u, v, DIGAMMA_POLY_21_D);
+    u = mlaf(u, v, DIGAMMA_POLY_20_D);
+    u = mlaf(u, v, DIGAMMA_POLY_19_D);
+    u = mlaf(u, v, DIGAMMA_POLY_18_D);
+    u = mlaf(u, v, DIGAMMA_POLY_17_D);
+    u = mlaf(u, v, DIGAMMA_POLY_16_D);
+    u = mlaf(u, v, DIGAMMA_POLY_15_D);
+    u = mlaf(u, v, DIGAMMA_POLY_14_D);
+    u = mlaf(u, v, DIGAMMA_POLY_13_D);
+    u = mlaf(u, v, DIGAMMA_POLY_12_D);
+    u = mlaf(u, v, DIGAMMA_POLY_11_D);
+    u = mlaf(u, v, DIGAMMA_POLY_10_D);
+    u = mlaf(u, v, DIGAMMA_POLY_9_D);
+    u = mlaf(u, v, DIGAMMA_POLY_8_D);
+    u = mlaf(u, v, DIGAMMA_POLY_7_D);
+    u = mlaf(u, v, DIGAMMA_POLY_6_D);
+    u = mlaf(u, v, DIGAMMA_POLY_5_D);
+    u = mlaf(u, v, DIGAMMA_POLY_4_D);
+    u = mlaf(u, v, DIGAMMA_POLY_3_D);
+    u = mlaf(u, v, DIGAMMA_POLY_2_D);
+    u = mlaf(u, v, DIGAMMA_POLY_1_D);
+    let r = ((t - DIGAMMA_ROOT_M1_D) - DIGAMMA_ROOT_M1_LO_D) * u;
+    if x < 1. {
+        // ψ(x) = ψ(x + 1) - 1/x
+        return r - 1. / x;
+    }
+    // ψ(x) = ψ(1 + t) + 1/(x - 1) + ... + 1/(x - n + 1), summed as a single fraction
+    let mut num = 0.;
+    let mut den = 1.;
+    for k in 1..n {
+        let v = x - k as f64;
+        num = mlaf(num, v, den);
+        den *= v;
+    }
+    r + num / den
+}
+
+#[inline(always)]
+fn do_digamma(d: f64) -> f64 {
+    if d >= 0. || d.is_nan() {
+        return digammak(d);
+    }
+    if d == efloor(d) {
+        return f64::NAN;
+    }
+    // ψ(d) = ψ(1 - d) - pi cot(pi d)
+    let r = d - rintk(d);
+    let c = sinpik(0.5 - eabs(r));
+    digammak(1. - d) - std::f64::consts::PI * c / sinpik(r)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_digamma_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(vdigammaq_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_digamma_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_digamma_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_digamma_sse41(d: f64) -> f64 {
+    do_digamma_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_digamma_fma(d: f64) -> f64 {
+    do_digamma(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_digamma_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_digamma(f64x2_splat(d)))
+}
+
+/// Computes digamma function, error bound *ULP 4.5* for positive arguments
+#[inline]
+pub fn edigamma(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_digamma;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_digamma_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_digamma_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_digamma_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_digamma_sse41(d) }
+            } else {
+                |d| unsafe { do_digamma_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,187 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_digamma;
+use crate::floor::efloorf;
+use crate::generalf::{mlaf, rintfk};
+use crate::lnf::do_ln;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vdigammaq_f32;
+use crate::sinf::sinpikf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_digamma_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const DIGAMMA_POLY_1_S: f32 = 0.9510559;
+pub(crate) const DIGAMMA_POLY_2_S: f32 = -0.4236274;
+pub(crate) const DIGAMMA_POLY_3_S: f32 = 0.24054265;
+pub(crate) const DIGAMMA_POLY_4_S: f32 = -0.14840503;
+pub(crate) const DIGAMMA_POLY_5_S: f32 = 0.09498082;
+pub(crate) const DIGAMMA_POLY_6_S: f32 = -0.06191686;
+pub(crate) const DIGAMMA_POLY_7_S: f32 = 0.040893562;
+pub(crate) const DIGAMMA_POLY_8_S: f32 = -0.027064258;
+pub(crate) const DIGAMMA_POLY_9_S: f32 = 0.016915224;
+pub(crate) const DIGAMMA_POLY_10_S: f32 = -0.011247037;
+pub(crate) const DIGAMMA_POLY_11_S: f32 = 0.011223156;
+pub(crate) const DIGAMMA_POLY_12_S: f32 = -0.007476843;
+pub(crate) const DIGAMMA_ASYMPT_POLY_1_S: f32 = 0.083333336;
+pub(crate) const DIGAMMA_ASYMPT_POLY_2_S: f32 = -0.008333234;
+pub(crate) const DIGAMMA_ASYMPT_POLY_3_S: f32 = 0.0039556315;
+pub(crate) const DIGAMMA_ASYMPT_POLY_4_S: f32 = -0.003639244;
+/// Positive root of digamma as double-float
+pub(crate) const DIGAMMA_ROOT_S: f32 = 1.4616321;
+pub(crate) const DIGAMMA_ROOT_LO_S: f32 = 1.243815e-8;
+/// Positive root of digamma minus one as double-float
+pub(crate) const DIGAMMA_ROOT_M1_S: f32 = 0.46163213;
+pub(crate) const DIGAMMA_ROOT_M1_LO_S: f32 = 1.243815e-8;
+
+/// Computes ψ(x) for x >= 0
+#[inline]
+pub(crate) fn digammakf(x: f32) -> f32 {
+    if x >= 5. {
+        // ψ(x) = ln(x) - 1/2x - z D(z), z = 1/x^2
+        let ix = 1. / x;
+        let z = ix * ix;
+        let mut u = DIGAMMA_ASYMPT_POLY_4_S;
+        u = mlaf(u, z, DIGAMMA_ASYMPT_POLY_3_S);
+        u = mlaf(u, z, DIGAMMA_ASYMPT_POLY_2_S);
+        u = mlaf(u, z, DIGAMMA_ASYMPT_POLY_1_S);
+        return do_ln(x) - mlaf(z, u, 0.5 * ix);
+    }
+    // ψ(1 + t) = (t - t0) G(t) keeps the root t0 apart, so values around it stay accurate
+    let n = x as i32;
+    let t = x - n as f32;
+    let v = t - 0.5;
+    let mut u = DIGAMMA_POLY_12_S;
+    u = mlaf(u, v, DIGAMMA_POLY_11_S);
+    u = mlaf(u, v, DIGAMMA_POLY_10_S);
+    u = mlaf(u, v, DIGAMMA_POLY_9_S);
+    u = mlaf(u, v, DIGAMMA_POLY_8_S);
+    u = mlaf(u, v, DIGAMMA_POLY_7_S);
+    u = mlaf(u, v, DIGAMMA_POLY_6_S);
+    u = mlaf(u, v, DIGAMMA_POLY_5_S);
+    u = mlaf(u, v, DIGAMMA_POLY_4_S);
+    u = mlaf(u, v, DIGAMMA_POLY_3_S);
+    u = mlaf(u, v, DIGAMMA_POLY_2_S);
+    u = mlaf(u, v, DIGAMMA_POLY_1_S);
+    let r = ((t - DIGAMMA_ROOT_M1_S) - DIGAMMA_ROOT_M1_LO_S) * u;
+    if x < 1. {
+        // ψ(x) = ψ(x + 1) - 1/x
+        return r - 1. / x;
+    }
+    // ψ(x) = ψ(1 + t) + 1/(x - 1) + ... + 1/(x - n + 1), summed as a single fraction
+    let mut num = 0.;
+    let mut den = 1.;
+    for k in 1..n {
+        let v = x - k as f32;
+        num = mlaf(num, v, den);
+        den *= v;
+    }
+    r + num / den
+}
+
+#[inline(always)]
+fn do_digammaf(d: f32) -> f32 {
+    if d >= 0. || d.is_nan() {
+        return digammakf(d);
+    }
+    if d == efloorf(d) {
+        return f32::NAN;
+    }
+    // ψ(d) = ψ(1 - d) - pi cot(pi d)
+    let r = d - rintfk(d);
+    let c = sinpikf(0.5 - eabsf(r));
+    digammakf(1. - d) - std::f32::consts::PI * c / sinpikf(r)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_digammaf_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vdigammaq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_digammaf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_digamma_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_digammaf_sse41(d: f32) -> f32 {
+    do_digammaf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_digammaf_fma(d: f32) -> f32 {
+    do_digammaf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_digammaf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_digamma(f32x4_splat(d)))
+}
+
+/// Computes digamma function, error bound *ULP 3.5* for positive arguments
+#[inline]
+pub fn edigammaf(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_digammaf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_digammaf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_digammaf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_digammaf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_digammaf_sse41(d) }
+            } else {
+                |d| unsafe { do_digammaf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,33 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+/// Checks if SSE 4.1 instructions may be used by SSE kernels on the current CPU.
+///
+/// SSE kernels are built on SSE2 sequences from `sse2.rs`, entry points compile them once more
+/// with *sse4.1* enabled and select that copy when this returns true.
+/// Helpers in `sse2.rs` choose the native instruction at compile time, so that copy runs the same
+/// SSE2 sequences and only gains what the compiler folds into SSE 4.1 instructions by itself.
+#[inline]
+pub(crate) fn is_sse41_available() -> bool {
+    std::arch::is_x86_feature_detected!("sse4.1")
+}
+
+/// Checks if AVX2 kernels may be executed on the current CPU, *avx2* and *fma* are required.
+///
+/// Detection result is cached by the standard library, each scalar entry point
+/// additionally caches the selected implementation so detection happens once.
+#[inline]
+pub(crate) fn is_avx2_available() -> bool {
+    std::arch::is_x86_feature_detected!("avx2") && std::arch::is_x86_feature_detected!("fma")
+}
+
+/// Checks if AVX-512 kernels may be executed on the current CPU, *avx512f* and *avx512dq* are required
+#[inline]
+pub(crate) fn is_avx512_available() -> bool {
+    std::arch::is_x86_feature_detected!("avx512f")
+        && std::arch::is_x86_feature_detected!("avx512dq")
+}
@@ -0,0 +1,168 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+//! Precomputed dividers for integer division by runtime-invariant divisors.
+//!
+//! Division is replaced by a high multiplication with a magic number followed by shifts,
+//! the same scheme as libdivide. A divider is computed once and then passed to the vector
+//! routines, `_mm_div_epu32`, `_mm256_div_epi64`, `vdivq_u64` and so on.
+//! Signed division truncates towards zero and wraps like `wrapping_div`.
+
+/// Precomputed divider for u32
+#[derive(Copy, Clone, Debug)]
+pub struct DividerU32 {
+    pub(crate) magic: u32,
+    pub(crate) shift: u32,
+    pub(crate) add: bool,
+}
+
+/// Precomputed divider for i32
+#[derive(Copy, Clone, Debug)]
+pub struct DividerI32 {
+    pub(crate) magic: i32,
+    pub(crate) shift: u32,
+    pub(crate) add: bool,
+    pub(crate) negative: bool,
+}
+
+/// Precomputed divider for u64
+#[derive(Copy, Clone, Debug)]
+pub struct DividerU64 {
+    pub(crate) magic: u64,
+    pub(crate) shift: u32,
+    pub(crate) add: bool,
+}
+
+/// Precomputed divider for i64
+#[derive(Copy, Clone, Debug)]
+pub struct DividerI64 {
+    pub(crate) magic: i64,
+    pub(crate) shift: u32,
+    pub(crate) add: bool,
+    pub(crate) negative: bool,
+}
+
+macro_rules! unsigned_divider {
+    ($name:ident, $t:ty, $wide:ty, $bits:expr) => {
+        impl $name {
+            /// Precomputes divider
+            ///
+            /// # Panics
+            /// Panics if divisor is zero
+            pub fn new(d: $t) -> $name {
+                assert_ne!(d, 0, "Divisor must not be zero");
+                let floor_log_2_d = $bits - 1 - d.leading_zeros();
+                if d.is_power_of_two() {
+                    return $name {
+                        magic: 0,
+                        shift: floor_log_2_d,
+                        add: false,
+                    };
+                }
+                let dividend = 1 as $wide << ($bits + floor_log_2_d);
+                let mut proposed_m = (dividend / d as $wide) as $t;
+                let rem = (dividend % d as $wide) as $t;
+                let e = d - rem;
+                let add = e >= 1 << floor_log_2_d;
+                if add {
+                    proposed_m = proposed_m.wrapping_add(proposed_m);
+                    let twice_rem = rem.wrapping_add(rem);
+                    if twice_rem >= d || twice_rem < rem {
+                        proposed_m = proposed_m.wrapping_add(1);
+                    }
+                }
+                $name {
+                    magic: proposed_m.wrapping_add(1),
+                    shift: floor_log_2_d,
+                    add,
+                }
+            }
+
+            /// Divides scalar value
+            #[inline]
+            pub fn divide(&self, n: $t) -> $t {
+                if self.magic == 0 {
+                    return n >> self.shift;
+                }
+                let q = ((n as $wide * self.magic as $wide) >> $bits) as $t;
+                if self.add {
+                    (((n - q) >> 1) + q) >> self.shift
+                } else {
+                    q >> self.shift
+                }
+            }
+        }
+    };
+}
+
+macro_rules! signed_divider {
+    ($name:ident, $t:ty, $ut:ty, $wide:ty, $uwide:ty, $bits:expr) => {
+        impl $name {
+            /// Precomputes divider
+            ///
+            /// # Panics
+            /// Panics if divisor is zero
+            pub fn new(d: $t) -> $name {
+                assert_ne!(d, 0, "Divisor must not be zero");
+                let abs_d = d.unsigned_abs();
+                let floor_log_2_d = $bits - 1 - abs_d.leading_zeros();
+                if abs_d.is_power_of_two() {
+                    return $name {
+                        magic: 0,
+                        shift: floor_log_2_d,
+                        add: false,
+                        negative: d < 0,
+                    };
+                }
+                let dividend = 1 as $uwide << ($bits - 1 + floor_log_2_d);
+                let mut proposed_m = (dividend / abs_d as $uwide) as $ut;
+                let rem = (dividend % abs_d as $uwide) as $ut;
+                let e = abs_d - rem;
+                let add = e >= 1 << floor_log_2_d;
+                let shift = if add {
+                    proposed_m = proposed_m.wrapping_add(proposed_m);
+                    let twice_rem = rem.wrapping_add(rem);
+                    if twice_rem >= abs_d || twice_rem < rem {
+                        proposed_m = proposed_m.wrapping_add(1);
+                    }
+                    floor_log_2_d
+                } else {
+                    floor_log_2_d - 1
+                };
+                let magic = proposed_m.wrapping_add(1) as $t;
+                $name {
+                    magic: if d < 0 { magic.wrapping_neg() } else { magic },
+                    shift,
+                    add,
+                    negative: d < 0,
+                }
+            }
+
+            /// Divides scalar value
+            #[inline]
+            pub fn divide(&self, n: $t) -> $t {
+                let sign: $t = if self.negative { -1 } else { 0 };
+                if self.magic == 0 {
+                    let mask = ((1 as $ut) << self.shift).wrapping_sub(1) as $t;
+                    let q = n.wrapping_add((n >> ($bits - 1)) & mask) >> self.shift;
+                    return (q ^ sign).wrapping_sub(sign);
+                }
+                let mut q = ((n as $wide * self.magic as $wide) >> $bits) as $t;
+                if self.add {
+                    q = q.wrapping_add((n ^ sign).wrapping_sub(sign));
+                }
+                q >>= self.shift;
+                q.wrapping_add(((q as $ut) >> ($bits - 1)) as $t)
+            }
+        }
+    };
+}
+
+unsigned_divider!(DividerU32, u32, u64, 32);
+unsigned_divider!(DividerU64, u64, u128, 64);
+signed_divider!(DividerI32, i32, u32, i64, u64, 32);
+signed_divider!(DividerI64, i64, u64, i128, u128, 64);
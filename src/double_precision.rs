@@ -5,7 +5,7 @@
  * // license that can be found in the LICENSE file.
  */
 
-use std::ops::{Add, Mul, Sub};
+use std::ops::{Add, Div, Mul, Sub};
 
 pub trait UpperPart {
     fn upper(self) -> Self;
@@ -59,13 +59,9 @@ pub fn multiply_as_doubled<
     let yh = b.upper();
     let yl = b - yh;
     let r0 = a * b;
-    let d0 = r0;
-    let j0 = xh * yh;
-    let j1 = xl * yh;
-    let j2 = xh * yl;
-    let j3 = xl * yl;
-    let d1 = j0 + j1 + j2 + j3 - r0;
-    (d0, d1)
+    // Dekker's order, partial products are exact and each step cancels against r0 first
+    let d1 = ((xh * yh - r0) + xl * yh + xh * yl) + xl * yl;
+    (r0, d1)
 }
 
 #[inline(always)]
@@ -80,6 +76,39 @@ pub fn mul_doubled<T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T>
     let r0 = a.0 * b.0;
     (
         r0,
-        xh * yh + xl * yh + xh * yl + xl * yl + a.0 * b.1 + a.1 * b.0 - r0,
+        (((xh * yh - r0) + xl * yh + xh * yl) + xl * yl) + (a.0 * b.1 + a.1 * b.0),
     )
 }
+
+#[inline(always)]
+pub fn sum_as_doubled<T: Copy + Add<Output = T> + Sub<Output = T>>(a: T, b: T) -> (T, T) {
+    let s = a + b;
+    let v = s - a;
+    (s, (a - (s - v)) + (b - v))
+}
+
+/// Adds two doubled numbers, result is normalized
+#[inline(always)]
+pub fn add_doubled<T: Copy + Add<Output = T> + Sub<Output = T>>(a: (T, T), b: (T, T)) -> (T, T) {
+    let (s, e) = sum_as_doubled(a.0, b.0);
+    let e = e + (a.1 + b.1);
+    let r0 = s + e;
+    (r0, e - (r0 - s))
+}
+
+/// Divides two doubled numbers by long division, result is normalized
+#[inline(always)]
+pub fn div_doubled<
+    T: Copy + Add<Output = T> + Mul<Output = T> + Sub<Output = T> + Div<Output = T> + UpperPart,
+>(
+    a: (T, T),
+    b: (T, T),
+) -> (T, T) {
+    let q0 = a.0 / b.0;
+    // a - q0 * b, leading difference cancels exactly
+    let (p0, p1) = multiply_as_doubled(b.0, q0);
+    let r = (((a.0 - p0) - p1) + a.1) - b.1 * q0;
+    let q1 = r / b.0;
+    let r0 = q0 + q1;
+    (r0, q1 - (r0 - q0))
+}
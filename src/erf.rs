@@ -0,0 +1,390 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::exp::{expm1k, L2_L, L2_U, R_LN2};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_erf;
+use crate::generalf::{copysignk, ldexp2k, mlaf, rintk};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::verfq_f64;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_erf_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const ERF_POLY_1_D: f64 = 0.12837916709551256;
+pub(crate) const ERF_POLY_2_D: f64 = -0.376126389031834;
+pub(crate) const ERF_POLY_3_D: f64 = 0.11283791670935311;
+pub(crate) const ERF_POLY_4_D: f64 = -0.02686617064077985;
+pub(crate) const ERF_POLY_5_D: f64 = 0.005223977576377844;
+pub(crate) const ERF_POLY_6_D: f64 = -0.000854832379116413;
+pub(crate) const ERF_POLY_7_D: f64 = 0.00012055199941321885;
+pub(crate) const ERF_POLY_8_D: f64 = -1.4922121574443055e-5;
+pub(crate) const ERF_POLY_9_D: f64 = 1.640162967934702e-6;
+pub(crate) const ERF_POLY_10_D: f64 = -1.571446067260244e-7;
+pub(crate) const ERF_POLY_11_D: f64 = 1.0726571432176348e-8;
+pub(crate) const ERFC_A_SHIFT_D: f64 = 0.925;
+pub(crate) const ERFC_A_POLY_1_D: f64 = -0.8224424370374097;
+pub(crate) const ERFC_A_POLY_2_D: f64 = -0.36309813053374007;
+pub(crate) const ERFC_A_POLY_3_D: f64 = 0.00919462732472501;
+pub(crate) const ERFC_A_POLY_4_D: f64 = 0.04467716609740224;
+pub(crate) const ERFC_A_POLY_5_D: f64 = -0.04257982884212077;
+pub(crate) const ERFC_A_POLY_6_D: f64 = 0.02905204882169222;
+pub(crate) const ERFC_A_POLY_7_D: f64 = -0.015938845851981624;
+pub(crate) const ERFC_A_POLY_8_D: f64 = 0.006276944206818464;
+pub(crate) const ERFC_A_POLY_9_D: f64 = -0.00025572213647939934;
+pub(crate) const ERFC_A_POLY_10_D: f64 = -0.002837967210648898;
+pub(crate) const ERFC_A_POLY_11_D: f64 = 0.003895449086718036;
+pub(crate) const ERFC_A_POLY_12_D: f64 = -0.003713909791789495;
+pub(crate) const ERFC_A_POLY_13_D: f64 = 0.0029234556386904674;
+pub(crate) const ERFC_A_POLY_14_D: f64 = -0.0019378046276736821;
+pub(crate) const ERFC_A_POLY_15_D: f64 = 0.000862125918885475;
+pub(crate) const ERFC_B_SHIFT_D: f64 = 0.5;
+pub(crate) const ERFC_B_POLY_1_D: f64 = -0.6717940840566923;
+pub(crate) const ERFC_B_POLY_2_D: f64 = -0.32735677602234375;
+pub(crate) const ERFC_B_POLY_3_D: f64 = -0.12529991713574692;
+pub(crate) const ERFC_B_POLY_4_D: f64 = 0.19772766673004222;
+pub(crate) const ERFC_B_POLY_5_D: f64 = -0.14979916213263825;
+pub(crate) const ERFC_B_POLY_6_D: f64 = 0.05021203098052184;
+pub(crate) const ERFC_B_POLY_7_D: f64 = 0.05860342936852157;
+pub(crate) const ERFC_B_POLY_8_D: f64 = -0.14077941948141304;
+pub(crate) const ERFC_B_POLY_9_D: f64 = 0.16736228818170407;
+pub(crate) const ERFC_B_POLY_10_D: f64 = -0.12201769962846895;
+pub(crate) const ERFC_B_POLY_11_D: f64 = 0.0077374523680046;
+pub(crate) const ERFC_B_POLY_12_D: f64 = 0.14882367618509282;
+pub(crate) const ERFC_B_POLY_13_D: f64 = -0.29875324993566676;
+pub(crate) const ERFC_B_POLY_14_D: f64 = 0.36924444143459106;
+pub(crate) const ERFC_B_POLY_15_D: f64 = -0.2554914447656868;
+pub(crate) const ERFC_C_SHIFT_D: f64 = 0.225;
+pub(crate) const ERFC_C_POLY_1_D: f64 = -0.5962460863271387;
+pub(crate) const ERFC_C_POLY_2_D: f64 = -0.20085263745331777;
+pub(crate) const ERFC_C_POLY_3_D: f64 = -0.35474717670613015;
+pub(crate) const ERFC_C_POLY_4_D: f64 = 0.33034758296680794;
+pub(crate) const ERFC_C_POLY_5_D: f64 = 0.028479558372053532;
+pub(crate) const ERFC_C_POLY_6_D: f64 = -0.4797486623618752;
+pub(crate) const ERFC_C_POLY_7_D: f64 = 0.5929239692598409;
+pub(crate) const ERFC_C_POLY_8_D: f64 = 0.08098241109314654;
+pub(crate) const ERFC_C_POLY_9_D: f64 = -1.5016227220779685;
+pub(crate) const ERFC_C_POLY_10_D: f64 = 2.472770174585505;
+pub(crate) const ERFC_C_POLY_11_D: f64 = -0.5254092340011408;
+pub(crate) const ERFC_C_POLY_12_D: f64 = -6.420338128427703;
+pub(crate) const ERFC_C_POLY_13_D: f64 = 15.140556628866474;
+pub(crate) const ERFC_C_POLY_14_D: f64 = -7.947584675128732;
+pub(crate) const ERFC_C_POLY_15_D: f64 = -27.982402048528847;
+pub(crate) const ERFC_D_SHIFT_D: f64 = 0.08;
+pub(crate) const ERFC_D_POLY_1_D: f64 = -0.5755397380740754;
+pub(crate) const ERFC_D_POLY_2_D: f64 = -0.07874941785321445;
+pub(crate) const ERFC_D_POLY_3_D: f64 = -0.4769085445162966;
+pub(crate) const ERFC_D_POLY_4_D: f64 = 0.18516523399659832;
+pub(crate) const ERFC_D_POLY_5_D: f64 = 0.4915202510760829;
+pub(crate) const ERFC_D_POLY_6_D: f64 = -0.6007616181695666;
+pub(crate) const ERFC_D_POLY_7_D: f64 = -0.7420880589546567;
+pub(crate) const ERFC_D_POLY_8_D: f64 = 2.2770439723679043;
+pub(crate) const ERFC_D_POLY_9_D: f64 = 0.4660579369269754;
+pub(crate) const ERFC_D_POLY_10_D: f64 = -9.058867864124172;
+pub(crate) const ERFC_D_POLY_11_D: f64 = 7.750230049683354;
+pub(crate) const ERFC_D_POLY_12_D: f64 = 33.073391910859314;
+pub(crate) const ERFC_D_POLY_13_D: f64 = -80.89779910153864;
+pub(crate) const ERFC_D_POLY_14_D: f64 = -74.09309408778631;
+pub(crate) const ERFC_D_POLY_15_D: f64 = 562.5594305246743;
+
+/// Computes `erf(x) - x` for |x| < 0.84375
+#[inline]
+pub(crate) fn erfk(x: f64) -> f64 {
+    let t = x * x;
+    let mut u = ERF_POLY_11_D;
+    u = mlaf(u, t, ERF_POLY_10_D);
+    u = mlaf(u, t, ERF_POLY_9_D);
+    u = mlaf(u, t, ERF_POLY_8_D);
+    u = mlaf(u, t, ERF_POLY_7_D);
+    u = mlaf(u, t, ERF_POLY_6_D);
+    u = mlaf(u, t, ERF_POLY_5_D);
+    u = mlaf(u, t, ERF_POLY_4_D);
+    u = mlaf(u, t, ERF_POLY_3_D);
+    u = mlaf(u, t, ERF_POLY_2_D);
+    u = mlaf(u, t, ERF_POLY_1_D);
+    x * u
+}
+
+/// Computes erfc(a) for a >= 0.84375 as `exp(-a^2 + P(1/a)) / a`
+#[inline(always)]
+pub(crate) fn erfck(a: f64) -> f64 {
+    // erfc underflows here, also covers a == Inf
+    if a > 27.3 {
+        return 0.;
+    }
+    let t = 1. / a;
+    let pick = |c0: f64, c1: f64, c2: f64, c3: f64| {
+        if a < 1.5 {
+            c0
+        } else if a < 3. {
+            c1
+        } else if a < 8.25 {
+            c2
+        } else {
+            c3
+        }
+    };
+    let v = t - pick(
+        ERFC_A_SHIFT_D,
+        ERFC_B_SHIFT_D,
+        ERFC_C_SHIFT_D,
+        ERFC_D_SHIFT_D,
+    );
+    let mut u = pick(
+        ERFC_A_POLY_15_D,
+        ERFC_B_POLY_15_D,
+        ERFC_C_POLY_15_D,
+        ERFC_D_POLY_15_D,
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_14_D,
+            ERFC_B_POLY_14_D,
+            ERFC_C_POLY_14_D,
+            ERFC_D_POLY_14_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_13_D,
+            ERFC_B_POLY_13_D,
+            ERFC_C_POLY_13_D,
+            ERFC_D_POLY_13_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_12_D,
+            ERFC_B_POLY_12_D,
+            ERFC_C_POLY_12_D,
+            ERFC_D_POLY_12_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_11_D,
+            ERFC_B_POLY_11_D,
+            ERFC_C_POLY_11_D,
+            ERFC_D_POLY_11_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_10_D,
+            ERFC_B_POLY_10_D,
+            ERFC_C_POLY_10_D,
+            ERFC_D_POLY_10_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_9_D,
+            ERFC_B_POLY_9_D,
+            ERFC_C_POLY_9_D,
+            ERFC_D_POLY_9_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_8_D,
+            ERFC_B_POLY_8_D,
+            ERFC_C_POLY_8_D,
+            ERFC_D_POLY_8_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_7_D,
+            ERFC_B_POLY_7_D,
+            ERFC_C_POLY_7_D,
+            ERFC_D_POLY_7_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_6_D,
+            ERFC_B_POLY_6_D,
+            ERFC_C_POLY_6_D,
+            ERFC_D_POLY_6_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_5_D,
+            ERFC_B_POLY_5_D,
+            ERFC_C_POLY_5_D,
+            ERFC_D_POLY_5_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_4_D,
+            ERFC_B_POLY_4_D,
+            ERFC_C_POLY_4_D,
+            ERFC_D_POLY_4_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_3_D,
+            ERFC_B_POLY_3_D,
+            ERFC_C_POLY_3_D,
+            ERFC_D_POLY_3_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_2_D,
+            ERFC_B_POLY_2_D,
+            ERFC_C_POLY_2_D,
+            ERFC_D_POLY_2_D,
+        ),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_1_D,
+            ERFC_B_POLY_1_D,
+            ERFC_C_POLY_1_D,
+            ERFC_D_POLY_1_D,
+        ),
+    );
+    // -a^2 = -z^2 + (z - a)(z + a), z keeps half of the mantissa so z^2 is exact
+    let z = f64::from_bits(a.to_bits() & 0xffff_ffff_0000_0000);
+    let hi = -(z * z);
+    let lo = mlaf(z - a, z + a, u);
+    let qf = rintk((hi + lo) * R_LN2);
+    // hi is reduced exactly, lo joins after the reduction
+    let r = mlaf(qf, -L2_U, hi);
+    let r = mlaf(qf, -L2_L, r + lo);
+    ldexp2k((1. + expm1k(r)) / a, qf as i32)
+}
+
+#[inline(always)]
+fn do_erf(d: f64) -> f64 {
+    let a = eabs(d);
+    if a < 0.84375 {
+        d + erfk(d)
+    } else if a > 6. {
+        copysignk(1., d)
+    } else {
+        copysignk(1. - erfck(a), d)
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_erf_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(verfq_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_erf_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_erf_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_erf_sse41(d: f64) -> f64 {
+    do_erf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_erf_fma(d: f64) -> f64 {
+    do_erf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_erf_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_erf(f64x2_splat(d)))
+}
+
+/// Computes error function, error bound *ULP 1.5*
+#[inline]
+pub fn eerf(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_erf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_erf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_erf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_erf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_erf_sse41(d) }
+            } else {
+                |d| unsafe { do_erf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,122 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::erf::{erfck, erfk};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_erfc;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::verfcq_f64;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_erfc_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline(always)]
+fn do_erfc(d: f64) -> f64 {
+    let a = eabs(d);
+    if a < 0.84375 {
+        let u = erfk(d);
+        if d < 0.25 {
+            1. - (d + u)
+        } else {
+            // Past 0.25 recentering on 0.5 keeps the subtraction from cancelling
+            0.5 - ((d - 0.5) + u)
+        }
+    } else {
+        let r = erfck(a);
+        if d < 0. {
+            2. - r
+        } else {
+            r
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_erfc_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(verfcq_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_erfc_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_erfc_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_erfc_sse41(d: f64) -> f64 {
+    do_erfc_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_erfc_fma(d: f64) -> f64 {
+    do_erfc(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_erfc_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_erfc(f64x2_splat(d)))
+}
+
+/// Computes complementary error function, error bound *ULP 3.5*
+#[inline]
+pub fn eerfc(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_erfc;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_erfc_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_erfc_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_erfc_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_erfc_sse41(d) }
+            } else {
+                |d| unsafe { do_erfc_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
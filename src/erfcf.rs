@@ -0,0 +1,123 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::erff::{erfckf, erfkf};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_erfc;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::verfcq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_erfc_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline(always)]
+fn do_erfcf(d: f32) -> f32 {
+    let a = eabsf(d);
+    if a < 0.84375 {
+        let u = erfkf(d);
+        if d < 0.25 {
+            1. - (d + u)
+        } else {
+            // Past 0.25 recentering on 0.5 keeps the subtraction from cancelling
+            0.5 - ((d - 0.5) + u)
+        }
+    } else {
+        let r = erfckf(a);
+        if d < 0. {
+            2. - r
+        } else {
+            r
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_erfcf_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(verfcq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_erfcf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_erfc_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_erfcf_sse41(d: f32) -> f32 {
+    do_erfcf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_erfcf_fma(d: f32) -> f32 {
+    do_erfcf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_erfcf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_erfc(f32x4_splat(d)))
+}
+
+/// Computes complementary error function, error bound *ULP 3.5*
+#[inline]
+pub fn eerfcf(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_erfcf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_erfcf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_erfcf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_erfcf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_erfcf_sse41(d) }
+            } else {
+                |d| unsafe { do_erfcf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,221 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::expf::{expm1kf, L2L_F, L2U_F, R_LN2_F};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_erf;
+use crate::generalf::{copysignfk, ldexp2kf, mlaf, rintfk};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::verfq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_erf_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const ERF_POLY_1_S: f32 = 0.12837917;
+pub(crate) const ERF_POLY_2_S: f32 = -0.37612638;
+pub(crate) const ERF_POLY_3_S: f32 = 0.112837516;
+pub(crate) const ERF_POLY_4_S: f32 = -0.026862768;
+pub(crate) const ERF_POLY_5_S: f32 = 0.00521012;
+pub(crate) const ERF_POLY_6_S: f32 = -0.0008255121;
+pub(crate) const ERF_POLY_7_S: f32 = 8.889625e-5;
+pub(crate) const ERFC_A_SHIFT_S: f32 = 0.94;
+pub(crate) const ERFC_A_POLY_1_S: f32 = -0.8278867;
+pub(crate) const ERFC_A_POLY_2_S: f32 = -0.36279255;
+pub(crate) const ERFC_A_POLY_3_S: f32 = 0.011148604;
+pub(crate) const ERFC_A_POLY_4_S: f32 = 0.042167906;
+pub(crate) const ERFC_A_POLY_5_S: f32 = -0.04045446;
+pub(crate) const ERFC_A_POLY_6_S: f32 = 0.028279984;
+pub(crate) const ERFC_A_POLY_7_S: f32 = -0.015300527;
+pub(crate) const ERFC_B_SHIFT_S: f32 = 0.54;
+pub(crate) const ERFC_B_POLY_1_S: f32 = -0.68507653;
+pub(crate) const ERFC_B_POLY_2_S: f32 = -0.3364695;
+pub(crate) const ERFC_B_POLY_3_S: f32 = -0.102976084;
+pub(crate) const ERFC_B_POLY_4_S: f32 = 0.17467965;
+pub(crate) const ERFC_B_POLY_5_S: f32 = -0.13874239;
+pub(crate) const ERFC_B_POLY_6_S: f32 = 0.055873267;
+pub(crate) const ERFC_B_POLY_7_S: f32 = 0.032721493;
+pub(crate) const ERFC_C_SHIFT_S: f32 = 0.24;
+pub(crate) const ERFC_C_POLY_1_S: f32 = -0.5993376;
+pub(crate) const ERFC_C_POLY_2_S: f32 = -0.21127181;
+pub(crate) const ERFC_C_POLY_3_S: f32 = -0.33985987;
+pub(crate) const ERFC_C_POLY_4_S: f32 = 0.33101964;
+pub(crate) const ERFC_C_POLY_5_S: f32 = -0.00507752;
+pub(crate) const ERFC_C_POLY_6_S: f32 = -0.42710337;
+pub(crate) const ERFC_C_POLY_7_S: f32 = 0.5508012;
+
+/// Computes `erf(x) - x` for |x| < 0.84375
+#[inline]
+pub(crate) fn erfkf(x: f32) -> f32 {
+    let t = x * x;
+    let mut u = ERF_POLY_7_S;
+    u = mlaf(u, t, ERF_POLY_6_S);
+    u = mlaf(u, t, ERF_POLY_5_S);
+    u = mlaf(u, t, ERF_POLY_4_S);
+    u = mlaf(u, t, ERF_POLY_3_S);
+    u = mlaf(u, t, ERF_POLY_2_S);
+    u = mlaf(u, t, ERF_POLY_1_S);
+    x * u
+}
+
+/// Computes erfc(a) for a >= 0.84375 as `exp(-a^2 + P(1/a)) / a`
+#[inline]
+pub(crate) fn erfckf(a: f32) -> f32 {
+    // erfc underflows here, also covers a == Inf
+    if a > 10.1 {
+        return 0.;
+    }
+    let t = 1. / a;
+    let pick = |c0: f32, c1: f32, c2: f32| {
+        if a < 1.4375 {
+            c0
+        } else if a < 2.625 {
+            c1
+        } else {
+            c2
+        }
+    };
+    let v = t - pick(ERFC_A_SHIFT_S, ERFC_B_SHIFT_S, ERFC_C_SHIFT_S);
+    let mut u = pick(ERFC_A_POLY_7_S, ERFC_B_POLY_7_S, ERFC_C_POLY_7_S);
+    u = mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_6_S, ERFC_B_POLY_6_S, ERFC_C_POLY_6_S),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_5_S, ERFC_B_POLY_5_S, ERFC_C_POLY_5_S),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_4_S, ERFC_B_POLY_4_S, ERFC_C_POLY_4_S),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_3_S, ERFC_B_POLY_3_S, ERFC_C_POLY_3_S),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_2_S, ERFC_B_POLY_2_S, ERFC_C_POLY_2_S),
+    );
+    u = mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_1_S, ERFC_B_POLY_1_S, ERFC_C_POLY_1_S),
+    );
+    // -a^2 = -z^2 + (z - a)(z + a), z keeps half of the mantissa so z^2 is exact
+    let z = f32::from_bits(a.to_bits() & 0xffff_f000);
+    let hi = -(z * z);
+    let lo = mlaf(z - a, z + a, u);
+    let qf = rintfk((hi + lo) * R_LN2_F);
+    // hi is reduced exactly, lo joins after the reduction
+    let r = mlaf(qf, -L2U_F, hi);
+    let r = mlaf(qf, -L2L_F, r + lo);
+    ldexp2kf((1. + expm1kf(r)) / a, qf as i32)
+}
+
+#[inline(always)]
+fn do_erff(d: f32) -> f32 {
+    let a = eabsf(d);
+    if a < 0.84375 {
+        d + erfkf(d)
+    } else if a > 4. {
+        copysignfk(1., d)
+    } else {
+        copysignfk(1. - erfckf(a), d)
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_erff_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(verfq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_erff_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_erf_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_erff_sse41(d: f32) -> f32 {
+    do_erff_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_erff_fma(d: f32) -> f32 {
+    do_erff(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_erff_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_erf(f32x4_splat(d)))
+}
+
+/// Computes error function, error bound *ULP 2.0*
+#[inline]
+pub fn eerff(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_erff;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_erff_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_erff_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_erff_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_erff_sse41(d) }
+            } else {
+                |d| unsafe { do_erff_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,203 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::erf::{erfck, erfk};
+use crate::exp::do_exp;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_erfinv;
+use crate::generalf::{copysignk, mlaf};
+use crate::ln::do_ln;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::verfinvq_f64;
+use crate::sqrt::esqrt;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_erfinv_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const ERFINV_A_SHIFT_D: f64 = 2.5;
+pub(crate) const ERFINV_A_POLY_1_D: f64 = 1.501409351110792;
+pub(crate) const ERFINV_A_POLY_2_D: f64 = 0.24664028942745514;
+pub(crate) const ERFINV_A_POLY_3_D: f64 = -0.004177320040540122;
+pub(crate) const ERFINV_A_POLY_4_D: f64 = -0.0012527485028416628;
+pub(crate) const ERFINV_A_POLY_5_D: f64 = 0.00021825772396383332;
+pub(crate) const ERFINV_A_POLY_6_D: f64 = -4.9596917942159365e-6;
+pub(crate) const ERFINV_A_POLY_7_D: f64 = -3.4234142742220455e-6;
+pub(crate) const ERFINV_A_POLY_8_D: f64 = 4.6541741050231655e-7;
+pub(crate) const ERFINV_A_POLY_9_D: f64 = 1.598430613390006e-8;
+pub(crate) const ERFINV_A_POLY_10_D: f64 = -8.739010702830122e-9;
+pub(crate) const ERFINV_A_POLY_11_D: f64 = 4.671932509038665e-10;
+pub(crate) const ERFINV_B_SHIFT_D: f64 = 3.1;
+pub(crate) const ERFINV_B_POLY_1_D: f64 = 2.933133327479722;
+pub(crate) const ERFINV_B_POLY_2_D: f64 = 1.003932194928294;
+pub(crate) const ERFINV_B_POLY_3_D: f64 = 0.005876417865624054;
+pub(crate) const ERFINV_B_POLY_4_D: f64 = -0.003010177378686657;
+pub(crate) const ERFINV_B_POLY_5_D: f64 = 0.0010287454696046203;
+pub(crate) const ERFINV_B_POLY_6_D: f64 = -0.00023544065307732985;
+pub(crate) const ERFINV_B_POLY_7_D: f64 = -6.264908109402265e-6;
+pub(crate) const ERFINV_B_POLY_8_D: f64 = 4.323532401467591e-5;
+pub(crate) const ERFINV_B_POLY_9_D: f64 = -1.7398036754540107e-5;
+pub(crate) const ERFINV_C_SHIFT_D: f64 = 5.0;
+pub(crate) const ERFINV_C_POLY_1_D: f64 = 4.849906401372349;
+pub(crate) const ERFINV_C_POLY_2_D: f64 = 1.0103004666010154;
+pub(crate) const ERFINV_C_POLY_3_D: f64 = -0.0001387210353301416;
+pub(crate) const ERFINV_C_POLY_4_D: f64 = -0.00021504385833244187;
+pub(crate) const ERFINV_C_POLY_5_D: f64 = 7.598657915449106e-5;
+pub(crate) const ERFINV_C_POLY_6_D: f64 = -1.9594996872618106e-5;
+pub(crate) const ERFINV_C_POLY_7_D: f64 = 4.486899411210995e-6;
+pub(crate) const ERFINV_C_POLY_8_D: f64 = -1.046310160518099e-6;
+pub(crate) const ERFINV_C_POLY_9_D: f64 = 2.087908997447094e-7;
+pub(crate) const SQRT_PI_2_D: f64 = 0.886_226_925_452_758;
+
+#[inline(always)]
+fn do_erfinv(d: f64) -> f64 {
+    let a = eabs(d);
+    if a >= 1. {
+        return if a == 1. {
+            copysignk(f64::INFINITY, d)
+        } else {
+            f64::NAN
+        };
+    }
+    let w = -do_ln((1. - a) * (1. + a));
+    let x = if w < 5. {
+        let v = w - ERFINV_A_SHIFT_D;
+        let mut u = ERFINV_A_POLY_11_D;
+        u = mlaf(u, v, ERFINV_A_POLY_10_D);
+        u = mlaf(u, v, ERFINV_A_POLY_9_D);
+        u = mlaf(u, v, ERFINV_A_POLY_8_D);
+        u = mlaf(u, v, ERFINV_A_POLY_7_D);
+        u = mlaf(u, v, ERFINV_A_POLY_6_D);
+        u = mlaf(u, v, ERFINV_A_POLY_5_D);
+        u = mlaf(u, v, ERFINV_A_POLY_4_D);
+        u = mlaf(u, v, ERFINV_A_POLY_3_D);
+        u = mlaf(u, v, ERFINV_A_POLY_2_D);
+        u = mlaf(u, v, ERFINV_A_POLY_1_D);
+        a * u
+    } else {
+        let v = esqrt(w);
+        if v < 4. {
+            let v = v - ERFINV_B_SHIFT_D;
+            let mut u = ERFINV_B_POLY_9_D;
+            u = mlaf(u, v, ERFINV_B_POLY_8_D);
+            u = mlaf(u, v, ERFINV_B_POLY_7_D);
+            u = mlaf(u, v, ERFINV_B_POLY_6_D);
+            u = mlaf(u, v, ERFINV_B_POLY_5_D);
+            u = mlaf(u, v, ERFINV_B_POLY_4_D);
+            u = mlaf(u, v, ERFINV_B_POLY_3_D);
+            u = mlaf(u, v, ERFINV_B_POLY_2_D);
+            u = mlaf(u, v, ERFINV_B_POLY_1_D);
+            u
+        } else {
+            let v = v - ERFINV_C_SHIFT_D;
+            let mut u = ERFINV_C_POLY_9_D;
+            u = mlaf(u, v, ERFINV_C_POLY_8_D);
+            u = mlaf(u, v, ERFINV_C_POLY_7_D);
+            u = mlaf(u, v, ERFINV_C_POLY_6_D);
+            u = mlaf(u, v, ERFINV_C_POLY_5_D);
+            u = mlaf(u, v, ERFINV_C_POLY_4_D);
+            u = mlaf(u, v, ERFINV_C_POLY_3_D);
+            u = mlaf(u, v, ERFINV_C_POLY_2_D);
+            u = mlaf(u, v, ERFINV_C_POLY_1_D);
+            u
+        }
+    };
+    // One Halley step on erf(x) = a, in the tail the residual comes from erfc where 1 - a is exact
+    let f = if x < 0.84375 {
+        (x - a) + erfk(x)
+    } else {
+        (1. - a) - erfck(x)
+    };
+    let e = f * SQRT_PI_2_D * do_exp(x * x);
+    let x = x - e / mlaf(x, e, 1.);
+    copysignk(x, d)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_erfinv_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(verfinvq_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_erfinv_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_erfinv_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_erfinv_sse41(d: f64) -> f64 {
+    do_erfinv_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_erfinv_fma(d: f64) -> f64 {
+    do_erfinv(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_erfinv_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_erfinv(f64x2_splat(d)))
+}
+
+/// Computes inverse error function, error bound *ULP 1.5*
+#[inline]
+pub fn eerfinv(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_erfinv;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_erfinv_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_erfinv_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_erfinv_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_erfinv_sse41(d) }
+            } else {
+                |d| unsafe { do_erfinv_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
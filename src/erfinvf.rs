@@ -0,0 +1,168 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_erfinv;
+use crate::generalf::{copysignfk, mlaf};
+use crate::lnf::do_ln;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::verfinvq_f32;
+use crate::sqrtf::esqrtf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_erfinv_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const ERFINV_A_SHIFT_S: f32 = 2.5;
+pub(crate) const ERFINV_A_POLY_1_S: f32 = 1.5014093;
+pub(crate) const ERFINV_A_POLY_2_S: f32 = 0.2466403;
+pub(crate) const ERFINV_A_POLY_3_S: f32 = -0.00417732;
+pub(crate) const ERFINV_A_POLY_4_S: f32 = -0.0012527485;
+pub(crate) const ERFINV_A_POLY_5_S: f32 = 0.00021825773;
+pub(crate) const ERFINV_A_POLY_6_S: f32 = -4.959692e-6;
+pub(crate) const ERFINV_A_POLY_7_S: f32 = -3.4234142e-6;
+pub(crate) const ERFINV_A_POLY_8_S: f32 = 4.654174e-7;
+pub(crate) const ERFINV_A_POLY_9_S: f32 = 1.5984305e-8;
+pub(crate) const ERFINV_A_POLY_10_S: f32 = -8.73901e-9;
+pub(crate) const ERFINV_A_POLY_11_S: f32 = 4.6719323e-10;
+pub(crate) const ERFINV_B_SHIFT_S: f32 = 3.1;
+pub(crate) const ERFINV_B_POLY_1_S: f32 = 2.9331334;
+pub(crate) const ERFINV_B_POLY_2_S: f32 = 1.0039322;
+pub(crate) const ERFINV_B_POLY_3_S: f32 = 0.005876425;
+pub(crate) const ERFINV_B_POLY_4_S: f32 = -0.003010193;
+pub(crate) const ERFINV_B_POLY_5_S: f32 = 0.001028691;
+pub(crate) const ERFINV_B_POLY_6_S: f32 = -0.00023539529;
+pub(crate) const ERFINV_B_POLY_7_S: f32 = -6.1357764e-6;
+pub(crate) const ERFINV_B_POLY_8_S: f32 = 4.319822e-5;
+pub(crate) const ERFINV_B_POLY_9_S: f32 = -1.7491315e-5;
+
+#[inline(always)]
+fn do_erfinvf(d: f32) -> f32 {
+    let a = eabsf(d);
+    if a >= 1. {
+        return if a == 1. {
+            copysignfk(f32::INFINITY, d)
+        } else {
+            f32::NAN
+        };
+    }
+    let w = -do_ln((1. - a) * (1. + a));
+    let x = if w < 5. {
+        let v = w - ERFINV_A_SHIFT_S;
+        let mut u = ERFINV_A_POLY_11_S;
+        u = mlaf(u, v, ERFINV_A_POLY_10_S);
+        u = mlaf(u, v, ERFINV_A_POLY_9_S);
+        u = mlaf(u, v, ERFINV_A_POLY_8_S);
+        u = mlaf(u, v, ERFINV_A_POLY_7_S);
+        u = mlaf(u, v, ERFINV_A_POLY_6_S);
+        u = mlaf(u, v, ERFINV_A_POLY_5_S);
+        u = mlaf(u, v, ERFINV_A_POLY_4_S);
+        u = mlaf(u, v, ERFINV_A_POLY_3_S);
+        u = mlaf(u, v, ERFINV_A_POLY_2_S);
+        u = mlaf(u, v, ERFINV_A_POLY_1_S);
+        a * u
+    } else {
+        let v = esqrtf(w) - ERFINV_B_SHIFT_S;
+        let mut u = ERFINV_B_POLY_9_S;
+        u = mlaf(u, v, ERFINV_B_POLY_8_S);
+        u = mlaf(u, v, ERFINV_B_POLY_7_S);
+        u = mlaf(u, v, ERFINV_B_POLY_6_S);
+        u = mlaf(u, v, ERFINV_B_POLY_5_S);
+        u = mlaf(u, v, ERFINV_B_POLY_4_S);
+        u = mlaf(u, v, ERFINV_B_POLY_3_S);
+        u = mlaf(u, v, ERFINV_B_POLY_2_S);
+        u = mlaf(u, v, ERFINV_B_POLY_1_S);
+        u
+    };
+    copysignfk(x, d)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_erfinvf_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(verfinvq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_erfinvf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_erfinv_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_erfinvf_sse41(d: f32) -> f32 {
+    do_erfinvf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_erfinvf_fma(d: f32) -> f32 {
+    do_erfinvf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_erfinvf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_erfinv(f32x4_splat(d)))
+}
+
+/// Computes inverse error function, error bound *ULP 4.0*
+#[inline]
+pub fn eerfinvf(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_erfinvf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_erfinvf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_erfinvf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_erfinvf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_erfinvf_sse41(d) }
+            } else {
+                |d| unsafe { do_erfinvf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
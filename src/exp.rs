@@ -5,20 +5,25 @@
  * // license that can be found in the LICENSE file.
  */
 
-use crate::generalf::{mlaf, pow2i, rintk};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_exp;
+use crate::generalf::{ldexp2k, mlaf, pow2i, rintk};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vexpq_f64;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_exp_pd, _mm_extract_pd};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub(crate) const EXP_POLY_1_D: f64 = 2f64;
 pub(crate) const EXP_POLY_2_D: f64 = 0.16666666666666674f64;
@@ -35,15 +40,15 @@ pub(crate) const L2_U: f64 = 0.693_147_180_559_662_956_511_601_805_686_950_683_5
 pub(crate) const L2_L: f64 = 0.282_352_905_630_315_771_225_884_481_750_134_360_255_254_120_68_e-12;
 pub(crate) const R_LN2: f64 =
     1.442_695_040_888_963_407_359_924_681_001_892_137_426_645_954_152_985_934_135_449_406_931;
+pub(crate) const LN2_LO: f64 = 2.319_046_813_846_299_6e-17;
+pub(crate) const LN10_HI: f64 = std::f64::consts::LN_10;
+pub(crate) const LN10_LO: f64 = -2.170_756_223_382_249_4e-16;
+pub(crate) const LG2_U: f64 = 0.301_029_995_663_611_77;
+pub(crate) const LG2_L: f64 = 3.694_239_077_158_931e-13;
 
+/// Computes `exp(r) - 1` for reduced argument |r| <= ln(2)/2
 #[inline]
-fn do_exp(d: f64) -> f64 {
-    let qf = rintk(d * R_LN2);
-    let q = qf as i32;
-
-    let mut r = mlaf(qf, -L2_U, d);
-    r = mlaf(qf, -L2_L, r);
-
+pub(crate) fn expm1k(r: f64) -> f64 {
     let f = r * r;
     // Poly for u = r*(exp(r)+1)/(exp(r)-1)
     let mut u = EXP_POLY_10_D;
@@ -56,13 +61,23 @@ fn do_exp(d: f64) -> f64 {
     u = mlaf(u, f, EXP_POLY_3_D);
     u = mlaf(u, f, EXP_POLY_2_D);
     u = mlaf(u, f, EXP_POLY_1_D);
-    let u = 1f64 + 2f64 * r / (u - r);
-    let i2 = pow2i(q);
-    let mut r = u * i2;
-    if d < -964f64 {
+    2f64 * r / (u - r)
+}
+
+#[inline]
+pub(crate) fn do_exp(d: f64) -> f64 {
+    let qf = rintk(d * R_LN2);
+    let q = qf as i32;
+
+    let mut r = mlaf(qf, -L2_U, d);
+    r = mlaf(qf, -L2_L, r);
+
+    // Two step scaling keeps subnormal results and the last binade before overflow
+    let mut r = ldexp2k(1f64 + expm1k(r), q);
+    if d < -746f64 {
         r = 0f64;
     }
-    if d > 709f64 {
+    if d > 710f64 {
         r = f64::INFINITY;
     }
     r
@@ -111,14 +126,36 @@ fn do_exp_neon(d: f64) -> f64 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_exp_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_exp_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_exp_sse41(d: f64) -> f64 {
+    do_exp_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_exp_fma(d: f64) -> f64 {
+    do_exp(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_exp_sse(d: f64) -> f64 {
-    unsafe {
-        let ld = _mm_set1_pd(d);
-        _mm_extract_pd::<0>(_mm_exp_pd(ld))
-    }
+fn do_exp_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_exp(f64x2_splat(d)))
 }
 
 #[inline]
@@ -129,12 +166,25 @@ pub fn eexp(d: f64) -> f64 {
     {
         _dispatcher = do_exp_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_exp_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_exp_sse;
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_exp_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_exp_sse41(d) }
+            } else {
+                |d| unsafe { do_exp_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
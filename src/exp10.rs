@@ -0,0 +1,117 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::exp::{expm1k, LG2_L, LG2_U, LN10_HI, LN10_LO};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_exp10;
+use crate::generalf::{ldexp2k, mlaf, rintk};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vexp10q_f64;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_exp10_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+pub(crate) fn do_exp10(d: f64) -> f64 {
+    let qf = rintk(d * std::f64::consts::LOG2_10);
+    let mut s = mlaf(qf, -LG2_U, d);
+    s = mlaf(qf, -LG2_L, s);
+    let r = mlaf(s, LN10_HI, s * LN10_LO);
+    let mut r = ldexp2k(1f64 + expm1k(r), qf as i32);
+    if d < -323.61f64 {
+        r = 0f64;
+    }
+    if d > 308.26f64 {
+        r = f64::INFINITY;
+    }
+    r
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_exp10_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(vexp10q_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_exp10_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_exp10_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_exp10_sse41(d: f64) -> f64 {
+    do_exp10_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_exp10_fma(d: f64) -> f64 {
+    do_exp10(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_exp10_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_exp10(f64x2_splat(d)))
+}
+
+/// Computes 10^x *ULP 2.0*
+#[inline]
+pub fn eexp10(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_exp10;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_exp10_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_exp10_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_exp10_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_exp10_sse41(d) }
+            } else {
+                |d| unsafe { do_exp10_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,117 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::expf::{expm1kf, LN2_LO_F};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_exp2;
+use crate::generalf::{ldexp2kf, mlaf, rintfk};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vexp2q_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_exp2_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn do_exp2f(d: f32) -> f32 {
+    let qf = rintfk(d);
+    let s = d - qf;
+    let r = mlaf(s, std::f32::consts::LN_2, s * LN2_LO_F);
+    let mut r = ldexp2kf(1f32 + expm1kf(r), qf as i32);
+    if d < -150f32 {
+        r = 0f32;
+    }
+    if d >= 128f32 {
+        r = f32::INFINITY;
+    }
+    r
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_exp2f_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vexp2q_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_exp2f_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_exp2_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_exp2f_sse41(d: f32) -> f32 {
+    do_exp2f_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_exp2f_fma(d: f32) -> f32 {
+    do_exp2f(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_exp2f_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_exp2(f32x4_splat(d)))
+}
+
+/// Computes 2^x *ULP 2.0*
+#[inline]
+pub fn eexp2f(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_exp2f;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_exp2f_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_exp2f_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_exp2f_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_exp2f_sse41(d) }
+            } else {
+                |d| unsafe { do_exp2f_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
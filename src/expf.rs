@@ -5,20 +5,25 @@
  * // license that can be found in the LICENSE file.
  */
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_exp_ps;
-use crate::generalf::{mlaf, pow2if, rintfk};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_exp;
+use crate::generalf::{ldexp2kf, mlaf, rintfk};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vexpq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_exp_ps, _mm_extract_psx};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub(crate) const EXP_POLY_1_S: f32 = 2f32;
 pub(crate) const EXP_POLY_2_S: f32 = 0.16666707f32;
@@ -29,14 +34,15 @@ pub(crate) const EXP_POLY_5_S: f32 = 1.6546869e-6f32;
 pub const L2U_F: f32 = 0.693_145_751_953_125;
 pub const L2L_F: f32 = 1.428_606_765_330_187_045_e-6;
 pub const R_LN2_F: f32 = std::f32::consts::LOG2_E;
+pub(crate) const LN2_LO_F: f32 = -1.904_654_2e-9;
+pub(crate) const LN10_HI_F: f32 = std::f32::consts::LN_10;
+pub(crate) const LN10_LO_F: f32 = -3.197_543_6e-8;
+pub(crate) const LG2_U_F: f32 = 0.301_025_4;
+pub(crate) const LG2_L_F: f32 = 4.605_039e-6;
 
+/// Computes `exp(r) - 1` for reduced argument |r| <= ln(2)/2
 #[inline]
-fn do_exp(d: f32) -> f32 {
-    let qf = rintfk(d * R_LN2_F);
-    let q = qf as i32;
-    let r = mlaf(qf, -L2U_F, d);
-    let r = mlaf(qf, -L2L_F, r);
-
+pub(crate) fn expm1kf(r: f32) -> f32 {
     let f = r * r;
     // Poly for u = r*(exp(r)+1)/(exp(r)-1)
     let mut u = EXP_POLY_5_S;
@@ -44,13 +50,22 @@ fn do_exp(d: f32) -> f32 {
     u = mlaf(u, f, EXP_POLY_3_S);
     u = mlaf(u, f, EXP_POLY_2_S);
     u = mlaf(u, f, EXP_POLY_1_S);
-    let u = 1f32 + 2f32 * r / (u - r);
-    let i2 = pow2if(q);
-    let mut r = u * i2;
-    if d < -87f32 {
+    2f32 * r / (u - r)
+}
+
+#[inline]
+pub(crate) fn do_exp(d: f32) -> f32 {
+    let qf = rintfk(d * R_LN2_F);
+    let q = qf as i32;
+    let r = mlaf(qf, -L2U_F, d);
+    let r = mlaf(qf, -L2L_F, r);
+
+    // Two step scaling keeps subnormal results and the last binade before overflow
+    let mut r = ldexp2kf(1f32 + expm1kf(r), q);
+    if d < -104f32 {
         r = 0f32;
     }
-    if d > 88f32 {
+    if d > 89f32 {
         r = f32::INFINITY;
     }
     r
@@ -67,16 +82,37 @@ fn do_exp_neon(d: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
+#[inline(always)]
+unsafe fn do_exp_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_exp_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_exp_sse41(d: f32) -> f32 {
+    do_exp_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_exp_fma(d: f32) -> f32 {
+    do_exp(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_exp_sse(d: f32) -> f32 {
-    unsafe {
-        let v = _mm_set1_ps(d);
-        let value = _mm_exp_ps(v);
-        let ex = f32::from_bits(_mm_extract_ps::<0>(value) as u32);
-        ex
-    }
+fn do_exp_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_exp(f32x4_splat(d)))
 }
 
 /// Computes exp for an argument *ULP 1.0*
@@ -87,12 +123,25 @@ pub fn eexpf(d: f32) -> f32 {
     {
         _dispatcher = do_exp_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_exp_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_exp_sse;
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_exp_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_exp_sse41(d) }
+            } else {
+                |d| unsafe { do_exp_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
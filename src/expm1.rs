@@ -0,0 +1,162 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::exp::{L2_L, L2_U, R_LN2};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_expm1;
+use crate::generalf::{ldexp2k, mlaf, pow2i, rintk};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vexpm1q_f64;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_expm1_pd, _mm_extract_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const EXPM1_POLY_2_D: f64 = 1f64 / 2f64;
+pub(crate) const EXPM1_POLY_3_D: f64 = 1f64 / 6f64;
+pub(crate) const EXPM1_POLY_4_D: f64 = 1f64 / 24f64;
+pub(crate) const EXPM1_POLY_5_D: f64 = 1f64 / 120f64;
+pub(crate) const EXPM1_POLY_6_D: f64 = 1f64 / 720f64;
+pub(crate) const EXPM1_POLY_7_D: f64 = 1f64 / 5_040f64;
+pub(crate) const EXPM1_POLY_8_D: f64 = 1f64 / 40_320f64;
+pub(crate) const EXPM1_POLY_9_D: f64 = 1f64 / 362_880f64;
+pub(crate) const EXPM1_POLY_10_D: f64 = 1f64 / 3_628_800f64;
+pub(crate) const EXPM1_POLY_11_D: f64 = 1f64 / 39_916_800f64;
+pub(crate) const EXPM1_POLY_12_D: f64 = 1f64 / 479_001_600f64;
+pub(crate) const EXPM1_POLY_13_D: f64 = 1f64 / 6_227_020_800f64;
+pub(crate) const EXPM1_POLY_14_D: f64 = 1f64 / 87_178_291_200f64;
+
+/// Computes `exp(r) - 1 - r` for reduced argument |r| <= ln(2)/2
+#[inline]
+fn expm1_tail(r: f64) -> f64 {
+    // Taylor coefficients 1/k!, the truncation error is far below an ulp on the reduced range
+    let mut u = EXPM1_POLY_14_D;
+    u = mlaf(u, r, EXPM1_POLY_13_D);
+    u = mlaf(u, r, EXPM1_POLY_12_D);
+    u = mlaf(u, r, EXPM1_POLY_11_D);
+    u = mlaf(u, r, EXPM1_POLY_10_D);
+    u = mlaf(u, r, EXPM1_POLY_9_D);
+    u = mlaf(u, r, EXPM1_POLY_8_D);
+    u = mlaf(u, r, EXPM1_POLY_7_D);
+    u = mlaf(u, r, EXPM1_POLY_6_D);
+    u = mlaf(u, r, EXPM1_POLY_5_D);
+    u = mlaf(u, r, EXPM1_POLY_4_D);
+    u = mlaf(u, r, EXPM1_POLY_3_D);
+    u = mlaf(u, r, EXPM1_POLY_2_D);
+    r * r * u
+}
+
+#[inline]
+pub(crate) fn do_expm1(d: f64) -> f64 {
+    let qf = rintk(d * R_LN2);
+    let q = qf as i32;
+    // rh is exact, rl keeps the part of q*ln(2) below it
+    let rh = mlaf(qf, -L2_U, d);
+    let rl = qf * -L2_L;
+    let t = expm1_tail(rh + rl);
+    let mut r = if q > 53 {
+        ldexp2k(1f64 + (rh + (rl + t)), q) - 1f64
+    } else {
+        // exp(x) - 1 = 2^q*(rl + t) + (2^q*rh + 2^q - 1), the inner sum is exact where it cancels
+        let i2 = pow2i(q);
+        mlaf(i2, rl + t, mlaf(i2, rh, i2 - 1f64))
+    };
+    if d == 0f64 {
+        r = d;
+    }
+    if d < -37.5f64 {
+        r = -1f64;
+    }
+    if d > 709.782712893384f64 {
+        r = f64::INFINITY;
+    }
+    r
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_expm1_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(vexpm1q_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_expm1_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_expm1_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_expm1_sse41(d: f64) -> f64 {
+    do_expm1_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_expm1_fma(d: f64) -> f64 {
+    do_expm1(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_expm1_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_expm1(f64x2_splat(d)))
+}
+
+/// Computes exp(x) - 1, accurate for x near zero *ULP 1.5*
+#[inline]
+pub fn eexpm1(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_expm1;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_expm1_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_expm1_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_expm1_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_expm1_sse41(d) }
+            } else {
+                |d| unsafe { do_expm1_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,151 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::expf::{L2L_F, L2U_F, R_LN2_F};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_expm1;
+use crate::generalf::{ldexp2kf, mlaf, pow2if, rintfk};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vexpm1q_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_expm1_ps, _mm_extract_psx};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const EXPM1_POLY_2_S: f32 = 1f32 / 2f32;
+pub(crate) const EXPM1_POLY_3_S: f32 = 1f32 / 6f32;
+pub(crate) const EXPM1_POLY_4_S: f32 = 1f32 / 24f32;
+pub(crate) const EXPM1_POLY_5_S: f32 = 1f32 / 120f32;
+pub(crate) const EXPM1_POLY_6_S: f32 = 1f32 / 720f32;
+pub(crate) const EXPM1_POLY_7_S: f32 = 1f32 / 5_040f32;
+pub(crate) const EXPM1_POLY_8_S: f32 = 1f32 / 40_320f32;
+
+/// Computes `exp(r) - 1 - r` for reduced argument |r| <= ln(2)/2
+#[inline]
+fn expm1_tailf(r: f32) -> f32 {
+    // Taylor coefficients 1/k!, the truncation error is far below an ulp on the reduced range
+    let mut u = EXPM1_POLY_8_S;
+    u = mlaf(u, r, EXPM1_POLY_7_S);
+    u = mlaf(u, r, EXPM1_POLY_6_S);
+    u = mlaf(u, r, EXPM1_POLY_5_S);
+    u = mlaf(u, r, EXPM1_POLY_4_S);
+    u = mlaf(u, r, EXPM1_POLY_3_S);
+    u = mlaf(u, r, EXPM1_POLY_2_S);
+    r * r * u
+}
+
+#[inline]
+pub(crate) fn do_expm1f(d: f32) -> f32 {
+    let qf = rintfk(d * R_LN2_F);
+    let q = qf as i32;
+    // rh is exact, rl keeps the part of q*ln(2) below it
+    let rh = mlaf(qf, -L2U_F, d);
+    let rl = qf * -L2L_F;
+    let t = expm1_tailf(rh + rl);
+    let mut r = if q > 24 {
+        ldexp2kf(1f32 + (rh + (rl + t)), q) - 1f32
+    } else {
+        // exp(x) - 1 = 2^q*(rl + t) + (2^q*rh + 2^q - 1), the inner sum is exact where it cancels
+        let i2 = pow2if(q);
+        mlaf(i2, rl + t, mlaf(i2, rh, i2 - 1f32))
+    };
+    if d == 0f32 {
+        r = d;
+    }
+    if d < -17.5f32 {
+        r = -1f32;
+    }
+    if d > 88.72283f32 {
+        r = f32::INFINITY;
+    }
+    r
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_expm1f_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vexpm1q_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_expm1f_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_expm1_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_expm1f_sse41(d: f32) -> f32 {
+    do_expm1f_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_expm1f_fma(d: f32) -> f32 {
+    do_expm1f(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_expm1f_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_expm1(f32x4_splat(d)))
+}
+
+/// Computes exp(x) - 1, accurate for x near zero *ULP 1.5*
+#[inline]
+pub fn eexpm1f(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_expm1f;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_expm1f_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_expm1f_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_expm1f_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_expm1f_sse41(d) }
+            } else {
+                |d| unsafe { do_expm1f_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
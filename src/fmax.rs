@@ -8,7 +8,8 @@
 #[inline]
 /// Find max between a pair of values
 pub fn efmax(x: f64, y: f64) -> f64 {
-    if y.is_nan() || (x > y) {
+    // fmax(+0, -0) = +0
+    if y.is_nan() || (x > y) || (x == y && y.is_sign_negative()) {
         x
     } else {
         y
@@ -8,7 +8,8 @@
 #[inline]
 /// Find max between a pair of values
 pub fn efmaxf(x: f32, y: f32) -> f32 {
-    if y.is_nan() || (x > y) {
+    // fmax(+0, -0) = +0
+    if y.is_nan() || (x > y) || (x == y && y.is_sign_negative()) {
         x
     } else {
         y
@@ -8,7 +8,8 @@
 #[inline]
 /// Find min between a pair of values
 pub fn efmin(x: f64, y: f64) -> f64 {
-    if y.is_nan() || (x < y) {
+    // fmin(-0, +0) = -0
+    if y.is_nan() || (x < y) || (x == y && x.is_sign_negative()) {
         x
     } else {
         y
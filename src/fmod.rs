@@ -0,0 +1,184 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_fmod;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vfmodq_f64;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_pd, _mm_fmod_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::*;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+/// Splits finite non-zero `|x|` bits into `m*2^(e-1075)`, subnormals get `e = 1`
+#[inline]
+fn split_bits(bits: u64) -> (i32, u64) {
+    let e = (bits >> 52) as i32;
+    let m = bits & 0x000f_ffff_ffff_ffff;
+    if e == 0 {
+        (1, m)
+    } else {
+        (e, m | (1 << 52))
+    }
+}
+
+/// Exact fmod on integer mantissas, also used by SIMD kernels for lanes they can't reduce
+#[inline]
+pub(crate) fn do_fmod(x: f64, y: f64) -> f64 {
+    let sign = x.to_bits() & (1 << 63);
+    let ax = x.to_bits() & !(1 << 63);
+    let ay = y.to_bits() & !(1 << 63);
+    if ay == 0 || ax >= 0x7ff0_0000_0000_0000 || ay > 0x7ff0_0000_0000_0000 {
+        return f64::NAN;
+    }
+    if ax < ay {
+        return x;
+    }
+    let (ex, mx) = split_bits(ax);
+    let (ey, my) = split_bits(ay);
+    // mx*2^(ex-ey) mod my, shifting at most 11 bits at once keeps everything in u64
+    let mut m = mx % my;
+    let mut ediff = ex - ey;
+    while ediff > 0 && m != 0 {
+        let shift = ediff.min(11);
+        m = (m << shift) % my;
+        ediff -= shift;
+    }
+    // Result is a multiple of 2^(ey-1075) and below |y|, so the scaling is exact
+    let k = ey - 1075;
+    let scale = if k >= -1022 {
+        f64::from_bits(((k + 0x3ff) as u64) << 52)
+    } else {
+        f64::from_bits(1u64 << (k + 1074))
+    };
+    f64::from_bits((m as f64 * scale).to_bits() | sign)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_fmod_neon(x: f64, y: f64) -> f64 {
+    unsafe {
+        let vx = vdupq_n_f64(x);
+        let vy = vdupq_n_f64(y);
+        vgetq_lane_f64::<0>(vfmodq_f64(vx, vy))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_fmod_sse(x: f64, y: f64) -> f64 {
+    let vx = _mm_set1_pd(x);
+    let vy = _mm_set1_pd(y);
+    _mm_extract_pd::<0>(_mm_fmod_pd(vx, vy))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_fmod_sse41(x: f64, y: f64) -> f64 {
+    do_fmod_sse(x, y)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_fmod_fma(x: f64, y: f64) -> f64 {
+    do_fmod(x, y)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_fmod_wasm(x: f64, y: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_fmod(f64x2_splat(x), f64x2_splat(y)))
+}
+
+/// Computes floating point remainder of `x/y` rounded toward zero, result has the sign of `x`, *ULP 0*
+pub fn efmod(x: f64, y: f64) -> f64 {
+    let mut _dispatcher: fn(f64, f64) -> f64 = do_fmod;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_fmod_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_fmod_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64, f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |x, y| unsafe { do_fmod_fma(x, y) }
+            } else if is_sse41_available() {
+                |x, y| unsafe { do_fmod_sse41(x, y) }
+            } else {
+                |x, y| unsafe { do_fmod_sse(x, y) }
+            }
+        });
+    }
+    _dispatcher(x, y)
+}
+
+/// Computes IEEE remainder of `x/y` with quotient rounded to nearest even and its low 3 bits, returns `(remainder, quotient)`, *ULP 0*
+pub fn eremquo(x: f64, y: f64) -> (f64, i32) {
+    if x.is_nan() || y.is_nan() || x.is_infinite() || y == 0. {
+        return (f64::NAN, 0);
+    }
+    let ay = y.abs();
+    let mut r = if ay <= f64::MAX / 8. {
+        efmod(x.abs(), 8. * ay)
+    } else {
+        x.abs()
+    };
+    // r < 8|y| here, every subtraction below is exact by Sterbenz lemma
+    let mut q = 0i32;
+    for k in [4, 2, 1] {
+        let t = k as f64 * ay;
+        if r >= t {
+            r -= t;
+            q += k;
+        }
+    }
+    let twice = 2. * r;
+    if twice > ay || (twice == ay && q & 1 != 0) {
+        r -= ay;
+        q += 1;
+    }
+    let q = q & 7;
+    let r = if x.is_sign_negative() { -r } else { r };
+    let q = if x.is_sign_negative() != y.is_sign_negative() {
+        -q
+    } else {
+        q
+    };
+    (r, q)
+}
+
+/// Computes IEEE remainder of `x/y` with quotient rounded to nearest even, *ULP 0*
+pub fn eremainder(x: f64, y: f64) -> f64 {
+    eremquo(x, y).0
+}
@@ -0,0 +1,150 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_fmod;
+use crate::fmod::do_fmod;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vfmodq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_fmod_ps};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+/// Exact fmod, also used by SIMD kernels for lanes they can't reduce
+#[inline]
+pub(crate) fn do_fmodf(x: f32, y: f32) -> f32 {
+    // Remainder of two f32 is representable in f32, so computing it in f64 is exact
+    do_fmod(x as f64, y as f64) as f32
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_fmodf_neon(x: f32, y: f32) -> f32 {
+    unsafe {
+        let vx = vdupq_n_f32(x);
+        let vy = vdupq_n_f32(y);
+        vgetq_lane_f32::<0>(vfmodq_f32(vx, vy))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_fmodf_sse(x: f32, y: f32) -> f32 {
+    let vx = _mm_set1_ps(x);
+    let vy = _mm_set1_ps(y);
+    let value = _mm_fmod_ps(vx, vy);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_fmodf_sse41(x: f32, y: f32) -> f32 {
+    do_fmodf_sse(x, y)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_fmodf_fma(x: f32, y: f32) -> f32 {
+    do_fmodf(x, y)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_fmodf_wasm(x: f32, y: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_fmod(f32x4_splat(x), f32x4_splat(y)))
+}
+
+/// Computes floating point remainder of `x/y` rounded toward zero, result has the sign of `x`, *ULP 0*
+#[inline]
+pub fn efmodf(x: f32, y: f32) -> f32 {
+    let mut _dispatcher: fn(f32, f32) -> f32 = do_fmodf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_fmodf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_fmodf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32, f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |x, y| unsafe { do_fmodf_fma(x, y) }
+            } else if is_sse41_available() {
+                |x, y| unsafe { do_fmodf_sse41(x, y) }
+            } else {
+                |x, y| unsafe { do_fmodf_sse(x, y) }
+            }
+        });
+    }
+    _dispatcher(x, y)
+}
+
+/// Computes IEEE remainder of `x/y` with quotient rounded to nearest even and its low 3 bits, returns `(remainder, quotient)`, *ULP 0*
+pub fn eremquof(x: f32, y: f32) -> (f32, i32) {
+    if x.is_nan() || y.is_nan() || x.is_infinite() || y == 0. {
+        return (f32::NAN, 0);
+    }
+    let ay = y.abs();
+    let mut r = if ay <= f32::MAX / 8. {
+        efmodf(x.abs(), 8. * ay)
+    } else {
+        x.abs()
+    };
+    // r < 8|y| here, every subtraction below is exact by Sterbenz lemma
+    let mut q = 0i32;
+    for k in [4, 2, 1] {
+        let t = k as f32 * ay;
+        if r >= t {
+            r -= t;
+            q += k;
+        }
+    }
+    let twice = 2. * r;
+    if twice > ay || (twice == ay && q & 1 != 0) {
+        r -= ay;
+        q += 1;
+    }
+    let q = q & 7;
+    let r = if x.is_sign_negative() { -r } else { r };
+    let q = if x.is_sign_negative() != y.is_sign_negative() {
+        -q
+    } else {
+        q
+    };
+    (r, q)
+}
+
+/// Computes IEEE remainder of `x/y` with quotient rounded to nearest even, *ULP 0*
+pub fn eremainderf(x: f32, y: f32) -> f32 {
+    eremquof(x, y).0
+}
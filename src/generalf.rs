@@ -6,6 +6,7 @@
  */
 
 use crate::floor::efloorf;
+use crate::sin::{PI_A2, PI_B2};
 use num_traits::MulAdd;
 use std::ops::{Add, Mul};
 
@@ -61,16 +62,11 @@ pub fn rintk(x: f64) -> f64 {
     (if x < 0. { x - 0.5 } else { x + 0.5 }) as i64 as f64
 }
 
-/// Computes `x*y + z` using `fma` when available
+/// Computes `x*y + z` as a single fused operation
+///
+/// Always fused so scalar routines give the same results on every target, x86 scalar routines
+/// run from the *fma* dispatch tier, other callers fall back to a `fma` libcall.
 #[inline]
-#[cfg(not(target_feature = "fma"))]
-pub fn mlaf<T: Copy + Add<Output = T> + MulAdd + Mul<Output = T>>(x: T, y: T, z: T) -> T {
-    return x * y + z;
-}
-
-/// Computes `x*y + z` using `fma` when available
-#[inline]
-#[cfg(target_feature = "fma")]
 pub fn mlaf<T: Copy + Add<Output = T> + MulAdd<Output = T> + Mul<Output = T>>(
     x: T,
     y: T,
@@ -130,6 +126,11 @@ pub fn ldexp2kf(d: f32, e: i32) -> f32 {
     d * pow2if(e >> 1) * pow2if(e - (e >> 1))
 }
 
+#[inline]
+pub fn ldexp2k(d: f64, e: i32) -> f64 {
+    d * pow2i(e >> 1) * pow2i(e - (e >> 1))
+}
+
 #[inline]
 // Founds a in x=a+𝑛ln(2)
 pub fn ldexp3kf(d: f32, n: i32) -> f32 {
@@ -141,6 +142,117 @@ pub fn rempif2(x: f32) -> f32 {
     return x - n * std::f32::consts::PI;
 }
 
+/// Arguments above this are reduced with Payne-Hanek in f64 trigonometric kernels
+pub(crate) const PAYNE_HANEK_THRESHOLD: f64 = 15.;
+/// Arguments above this are reduced with Payne-Hanek in f32 trigonometric kernels
+pub(crate) const PAYNE_HANEK_THRESHOLD_F: f32 = 125.;
+
+/// Bits of 1/π, first word is an integer part
+static INV_PI_BITS: [u64; 20] = [
+    0x0000000000000000,
+    0x517cc1b727220a94,
+    0xfe13abe8fa9a6ee0,
+    0x6db14acc9e21c820,
+    0xff28b1d5ef5de2b0,
+    0xdb92371d2126e970,
+    0x0324977504e8c90e,
+    0x7f0ef58e5894d39f,
+    0x74411afa975da242,
+    0x74ce38135a2fbf20,
+    0x9cc8eb1cc1a99cfa,
+    0x4e422fc5defc941d,
+    0x8ffc4bffef02cc07,
+    0xf79788c5ad05368f,
+    0xb69b3f6793e584db,
+    0xa7a31fb34f2ff516,
+    0xba93dd63f5f2f8bd,
+    0x9e839cfbc5294975,
+    0x35fdafd88fc6ae84,
+    0x2b0198237e3db5d5,
+];
+
+const PH_ONE: u128 = 1 << 125;
+const PH_HALF: u128 = 1 << 124;
+const PH_MOD_2: u128 = (1 << 126) - 1;
+const PH_MOD_4: u128 = (1 << 127) - 1;
+
+/// Computes `x/π mod 2` as a fixed point number with 125 fractional bits, |x| must be at least 1
+fn rempi_fixed(x: f64) -> u128 {
+    let bits = x.to_bits();
+    let m = ((bits & 0x000f_ffff_ffff_ffff) | (1 << 52)) as u128;
+    let e = ((bits >> 52) & 0x7ff) as i32 - 1075;
+    debug_assert!(e >= -63);
+    // Bits of 1/π weighted above 2^-e produce only even multiples of m, and are skipped
+    let p = (e + 63) as usize;
+    let (k, s) = (p / 64, p % 64);
+    let word = |i: usize| {
+        if s == 0 {
+            INV_PI_BITS[i]
+        } else {
+            (INV_PI_BITS[i] << s) | (INV_PI_BITS[i + 1] >> (64 - s))
+        }
+    };
+    let p2 = m * word(k + 2) as u128;
+    let p1 = m * word(k + 1) as u128 + (p2 >> 64);
+    let p0 = m * word(k) as u128 + (p1 >> 64);
+    let z = (((p0 as u64 as u128) << 64) | (p1 as u64 as u128)) >> 2;
+    if x < 0. {
+        z.wrapping_neg() & PH_MOD_2
+    } else {
+        z
+    }
+}
+
+/// Rounds fixed point `z` to the nearest integer, and scales the remainder by `c`
+fn rempi_round(z: u128, modulo: u128, c_hi: f64, c_lo: f64) -> (i32, f64) {
+    let z = z.wrapping_add(PH_HALF) & modulo;
+    let q = (z >> 125) as i32;
+    let f = (z & (PH_ONE - 1)) as i128 - PH_HALF as i128;
+    let f_hi = f as f64;
+    let f_lo = (f - f_hi as i128) as f64;
+    let scale = pow2i(-125);
+    let (f_hi, f_lo) = (f_hi * scale, f_lo * scale);
+    let r_hi = f_hi * c_hi;
+    let r_lo = f_hi.mul_add(c_hi, -r_hi);
+    (q, r_hi + mlaf(f_hi, c_lo, mlaf(f_lo, c_hi, r_lo)))
+}
+
+/// Payne-Hanek reduction, finds `q` and `r` such that `x = q*π + r`, only parity of `q` is kept
+pub(crate) fn rempi(x: f64) -> (i32, f64) {
+    rempi_round(rempi_fixed(x), PH_MOD_2, PI_A2, PI_B2)
+}
+
+/// Payne-Hanek reduction, finds `q` and `r` such that `x = q*π/2 + r`, `q` is kept modulo 4
+pub(crate) fn rempi2(x: f64) -> (i32, f64) {
+    let z = (rempi_fixed(x) << 1) & PH_MOD_4;
+    rempi_round(z, PH_MOD_4, PI_A2 * 0.5, PI_B2 * 0.5)
+}
+
+/// Payne-Hanek reduction, finds odd `q` and `r` such that `x = q*π/2 + r`, `q` is kept modulo 4
+pub(crate) fn rempi2_odd(x: f64) -> (i32, f64) {
+    let z = rempi_fixed(x).wrapping_sub(PH_HALF) & PH_MOD_2;
+    let (q, r) = rempi_round(z, PH_MOD_2, PI_A2, PI_B2);
+    ((2 * q + 1) & 3, r)
+}
+
+/// Payne-Hanek reduction, finds `q` and `r` such that `x = q*π + r`, only parity of `q` is kept
+pub(crate) fn rempif(x: f32) -> (i32, f32) {
+    let (q, r) = rempi(x as f64);
+    (q, r as f32)
+}
+
+/// Payne-Hanek reduction, finds `q` and `r` such that `x = q*π/2 + r`, `q` is kept modulo 4
+pub(crate) fn rempi2f(x: f32) -> (i32, f32) {
+    let (q, r) = rempi2(x as f64);
+    (q, r as f32)
+}
+
+/// Payne-Hanek reduction, finds odd `q` and `r` such that `x = q*π/2 + r`, `q` is kept modulo 4
+pub(crate) fn rempi2_oddf(x: f32) -> (i32, f32) {
+    let (q, r) = rempi2_odd(x as f64);
+    (q, r as f32)
+}
+
 /// Checks if values is negative zero
 pub trait IsNegZero {
     fn isnegzero(self) -> bool;
@@ -6,22 +6,27 @@
  */
 
 use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_hypot;
 use crate::fmax::efmax;
 use crate::fmin::efmin;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vhypotq_f64;
 use crate::sqrt::esqrt;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_extract_pd, _mm_hypot_pd};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::*;
-#[cfg(all(target_feature = "sse4.1", target_arch = "x86"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_feature = "sse4.1", target_arch = "x86_64"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 #[inline(always)]
 fn do_hypot(x: f64, y: f64) -> f64 {
@@ -34,7 +39,7 @@ fn do_hypot(x: f64, y: f64) -> f64 {
 
     if (x == f64::INFINITY) || (y == f64::INFINITY) {
         f64::INFINITY
-    } else if x.is_nan() || y.is_nan() || ret.is_nan() {
+    } else if x.is_nan() || y.is_nan() {
         f64::NAN
     } else if min == 0. {
         max
@@ -55,15 +60,37 @@ fn do_hypot_neon(x: f64, y: f64) -> f64 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_hypot_sse(x: f64, y: f64) -> f64 {
+    let vx = _mm_set1_pd(x);
+    let vy = _mm_set1_pd(y);
+    _mm_extract_pd::<0>(_mm_hypot_pd(vx, vy))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_hypot_sse41(x: f64, y: f64) -> f64 {
+    do_hypot_sse(x, y)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_hypot_fma(x: f64, y: f64) -> f64 {
+    do_hypot(x, y)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_hypot_sse(x: f64, y: f64) -> f64 {
-    unsafe {
-        let vx = _mm_set1_pd(x);
-        let vy = _mm_set1_pd(y);
-        _mm_extract_pd::<0>(_mm_hypot_pd(vx, vy))
-    }
+fn do_hypot_wasm(x: f64, y: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_hypot(f64x2_splat(x), f64x2_splat(y)))
 }
 
 /// Computes 2D Euclidian Distance *ULP 0.5*
@@ -73,12 +100,25 @@ pub fn ehypot(x: f64, y: f64) -> f64 {
     {
         _dispatcher = do_hypot_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_hypot_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_hypot_sse;
+        static DISPATCHER: OnceLock<fn(f64, f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |x, y| unsafe { do_hypot_fma(x, y) }
+            } else if is_sse41_available() {
+                |x, y| unsafe { do_hypot_sse41(x, y) }
+            } else {
+                |x, y| unsafe { do_hypot_sse(x, y) }
+            }
+        });
     }
     _dispatcher(x, y)
 }
@@ -4,20 +4,25 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_hypot3;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::vhypot3q_f64;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_extract_pd, _mm_hypot3_pd};
 use crate::{eabs, efmax};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::*;
-#[cfg(all(target_feature = "sse4.1", target_arch = "x86"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_feature = "sse4.1", target_arch = "x86_64"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 #[inline]
 fn do_hypot3(x: f64, y: f64, z: f64) -> f64 {
@@ -62,16 +67,38 @@ fn do_hypot3_neon(x: f64, y: f64, z: f64) -> f64 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_hypot3_sse(x: f64, y: f64, z: f64) -> f64 {
+    let vx = _mm_set1_pd(x);
+    let vy = _mm_set1_pd(y);
+    let vz = _mm_set1_pd(z);
+    _mm_extract_pd::<0>(_mm_hypot3_pd(vx, vy, vz))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_hypot3_sse41(x: f64, y: f64, z: f64) -> f64 {
+    do_hypot3_sse(x, y, z)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_hypot3_fma(x: f64, y: f64, z: f64) -> f64 {
+    do_hypot3(x, y, z)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_hypot3_sse(x: f64, y: f64, z: f64) -> f64 {
-    unsafe {
-        let vx = _mm_set1_pd(x);
-        let vy = _mm_set1_pd(y);
-        let vz = _mm_set1_pd(z);
-        _mm_extract_pd::<0>(_mm_hypot3_pd(vx, vy, vz))
-    }
+fn do_hypot3_wasm(x: f64, y: f64, z: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_hypot3(f64x2_splat(x), f64x2_splat(y), f64x2_splat(z)))
 }
 
 /// Computes 3D Euclidian Distance *ULP 0.6666*
@@ -82,12 +109,25 @@ pub fn ehypot3(x: f64, y: f64, z: f64) -> f64 {
     {
         _dispatcher = do_hypot3_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_hypot3_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_hypot3_sse;
+        static DISPATCHER: OnceLock<fn(f64, f64, f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |x, y, z| unsafe { do_hypot3_fma(x, y, z) }
+            } else if is_sse41_available() {
+                |x, y, z| unsafe { do_hypot3_sse41(x, y, z) }
+            } else {
+                |x, y, z| unsafe { do_hypot3_sse(x, y, z) }
+            }
+        });
     }
     _dispatcher(x, y, z)
 }
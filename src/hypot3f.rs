@@ -10,15 +10,20 @@ use crate::{eabsf, efmaxf};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_hypot3_ps;
-#[cfg(all(target_feature = "sse4.1", target_arch = "x86"))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_hypot3;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_hypot3_ps};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_feature = "sse4.1", target_arch = "x86_64"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 #[inline]
 fn do_hypot3f(x: f32, y: f32, z: f32) -> f32 {
@@ -62,16 +67,38 @@ fn do_hypot3f_neon(x: f32, y: f32, z: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_hypot3f_sse(x: f32, y: f32, z: f32) -> f32 {
+    let vx = _mm_set1_ps(x);
+    let vy = _mm_set1_ps(y);
+    let vz = _mm_set1_ps(z);
+    f32::from_bits(_mm_extract_psx::<0>(_mm_hypot3_ps(vx, vy, vz)) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_hypot3f_sse41(x: f32, y: f32, z: f32) -> f32 {
+    do_hypot3f_sse(x, y, z)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_hypot3f_fma(x: f32, y: f32, z: f32) -> f32 {
+    do_hypot3f(x, y, z)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_hypot3f_sse(x: f32, y: f32, z: f32) -> f32 {
-    unsafe {
-        let vx = _mm_set1_ps(x);
-        let vy = _mm_set1_ps(y);
-        let vz = _mm_set1_ps(z);
-        f32::from_bits(_mm_extract_ps::<0>(_mm_hypot3_ps(vx, vy, vz)) as u32)
-    }
+fn do_hypot3f_wasm(x: f32, y: f32, z: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_hypot3(f32x4_splat(x), f32x4_splat(y), f32x4_splat(z)))
 }
 
 /// Computes 3D Euclidian Distance *ULP 0.6666*
@@ -82,12 +109,25 @@ pub fn ehypot3f(x: f32, y: f32, z: f32) -> f32 {
     {
         _dispatcher = do_hypot3f_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_hypot3f_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_hypot3f_sse;
+        static DISPATCHER: OnceLock<fn(f32, f32, f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |x, y, z| unsafe { do_hypot3f_fma(x, y, z) }
+            } else if is_sse41_available() {
+                |x, y, z| unsafe { do_hypot3f_sse41(x, y, z) }
+            } else {
+                |x, y, z| unsafe { do_hypot3f_sse(x, y, z) }
+            }
+        });
     }
     _dispatcher(x, y, z)
 }
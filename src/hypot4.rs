@@ -4,11 +4,27 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_hypot4;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::vhypot4q_f64;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_pd, _mm_hypot4_pd};
 use crate::{eabs, efmax};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::*;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+type Hypot4Fn = fn(f64, f64, f64, f64) -> f64;
 
 #[inline]
 fn do_hypot4(x: f64, y: f64, z: f64, w: f64) -> f64 {
@@ -53,14 +69,76 @@ fn do_hypot4_neon(x: f64, y: f64, z: f64, w: f64) -> f64 {
     }
 }
 
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_hypot4_sse(x: f64, y: f64, z: f64, w: f64) -> f64 {
+    let vx = _mm_set1_pd(x);
+    let vy = _mm_set1_pd(y);
+    let vz = _mm_set1_pd(z);
+    let vw = _mm_set1_pd(w);
+    _mm_extract_pd::<0>(_mm_hypot4_pd(vx, vy, vz, vw))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_hypot4_sse41(x: f64, y: f64, z: f64, w: f64) -> f64 {
+    do_hypot4_sse(x, y, z, w)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_hypot4_fma(x: f64, y: f64, z: f64, w: f64) -> f64 {
+    do_hypot4(x, y, z, w)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_hypot4_wasm(x: f64, y: f64, z: f64, w: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_hypot4(
+        f64x2_splat(x),
+        f64x2_splat(y),
+        f64x2_splat(z),
+        f64x2_splat(w),
+    ))
+}
+
 /// Computes 4D Euclidian Distance *ULP 0.6666*
 #[inline]
 pub fn ehypot4(x: f64, y: f64, z: f64, w: f64) -> f64 {
-    let mut _dispatcher: fn(f64, f64, f64, f64) -> f64 = do_hypot4;
+    let mut _dispatcher: Hypot4Fn = do_hypot4;
     #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
     {
         _dispatcher = do_hypot4_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_hypot4_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<Hypot4Fn> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |x, y, z, w| unsafe { do_hypot4_fma(x, y, z, w) }
+            } else if is_sse41_available() {
+                |x, y, z, w| unsafe { do_hypot4_sse41(x, y, z, w) }
+            } else {
+                |x, y, z, w| unsafe { do_hypot4_sse(x, y, z, w) }
+            }
+        });
+    }
     _dispatcher(x, y, z, w)
 }
 
@@ -4,20 +4,27 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_hypot4_ps;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_hypot4;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::vhypot4q_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_hypot4_ps};
 use crate::{eabsf, efmaxf};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::*;
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+type Hypot4Fn = fn(f32, f32, f32, f32) -> f32;
 
 #[inline]
 fn do_hypot4f(x: f32, y: f32, z: f32, w: f32) -> f32 {
@@ -64,33 +71,73 @@ fn do_hypot4f_neon(x: f32, y: f32, z: f32, w: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_hypot4f_sse(x: f32, y: f32, z: f32, w: f32) -> f32 {
+    let vx = _mm_set1_ps(x);
+    let vy = _mm_set1_ps(y);
+    let vz = _mm_set1_ps(z);
+    let vw = _mm_set1_ps(w);
+    f32::from_bits(_mm_extract_psx::<0>(_mm_hypot4_ps(vx, vy, vz, vw)) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_hypot4f_sse41(x: f32, y: f32, z: f32, w: f32) -> f32 {
+    do_hypot4f_sse(x, y, z, w)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_hypot4f_fma(x: f32, y: f32, z: f32, w: f32) -> f32 {
+    do_hypot4f(x, y, z, w)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_hypot4f_sse(x: f32, y: f32, z: f32, w: f32) -> f32 {
-    unsafe {
-        let vx = _mm_set1_ps(x);
-        let vy = _mm_set1_ps(y);
-        let vz = _mm_set1_ps(z);
-        let vw = _mm_set1_ps(w);
-        f32::from_bits(_mm_extract_ps::<0>(_mm_hypot4_ps(vx, vy, vz, vw)) as u32)
-    }
+fn do_hypot4f_wasm(x: f32, y: f32, z: f32, w: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_hypot4(
+        f32x4_splat(x),
+        f32x4_splat(y),
+        f32x4_splat(z),
+        f32x4_splat(w),
+    ))
 }
 
 /// Computes 4D Euclidian Distance *ULP 0.6666*
 #[inline]
 pub fn ehypot4f(x: f32, y: f32, z: f32, w: f32) -> f32 {
-    let mut _dispatcher: fn(f32, f32, f32, f32) -> f32 = do_hypot4f;
+    let mut _dispatcher: Hypot4Fn = do_hypot4f;
     #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
     {
         _dispatcher = do_hypot4f_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_hypot4f_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_hypot4f_sse;
+        static DISPATCHER: OnceLock<Hypot4Fn> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |x, y, z, w| unsafe { do_hypot4f_fma(x, y, z, w) }
+            } else if is_sse41_available() {
+                |x, y, z, w| unsafe { do_hypot4f_sse41(x, y, z, w) }
+            } else {
+                |x, y, z, w| unsafe { do_hypot4f_sse(x, y, z, w) }
+            }
+        });
     }
     _dispatcher(x, y, z, w)
 }
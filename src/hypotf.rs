@@ -5,23 +5,28 @@
  * // license that can be found in the LICENSE file.
  */
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_hypot_ps;
 use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_hypot;
 use crate::fmaxf::efmaxf;
 use crate::fminf::efminf;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vhypotq_f32;
 use crate::sqrtf::esqrtf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_hypot_ps};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 #[inline]
 fn do_hypotf(x: f32, y: f32) -> f32 {
@@ -34,7 +39,7 @@ fn do_hypotf(x: f32, y: f32) -> f32 {
 
     if (x == f32::INFINITY) || (y == f32::INFINITY) {
         f32::INFINITY
-    } else if x.is_nan() || y.is_nan() || ret.is_nan() {
+    } else if x.is_nan() || y.is_nan() {
         f32::NAN
     } else if min == 0. {
         max
@@ -55,17 +60,38 @@ fn do_hypotf_neon(x: f32, y: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_hypot_sse(x: f32, y: f32) -> f32 {
+    let vx = _mm_set1_ps(x);
+    let vy = _mm_set1_ps(y);
+    let value = _mm_hypot_ps(vx, vy);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_hypot_sse41(x: f32, y: f32) -> f32 {
+    do_hypot_sse(x, y)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_hypot_fma(x: f32, y: f32) -> f32 {
+    do_hypotf(x, y)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_hypot_sse(x: f32, y: f32) -> f32 {
-    unsafe {
-        let vx = _mm_set1_ps(x);
-        let vy = _mm_set1_ps(y);
-        let value = _mm_hypot_ps(vx, vy);
-        let ex = f32::from_bits(_mm_extract_ps::<0>(value) as u32);
-        ex
-    }
+fn do_hypot_wasm(x: f32, y: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_hypot(f32x4_splat(x), f32x4_splat(y)))
 }
 
 /// Computes 2D Euclidian Distance *ULP 0.5*
@@ -76,12 +102,25 @@ pub fn ehypotf(x: f32, y: f32) -> f32 {
     {
         _dispatcher = do_hypotf_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_hypot_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_hypot_sse;
+        static DISPATCHER: OnceLock<fn(f32, f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |x, y| unsafe { do_hypot_fma(x, y) }
+            } else if is_sse41_available() {
+                |x, y| unsafe { do_hypot_sse41(x, y) }
+            } else {
+                |x, y| unsafe { do_hypot_sse(x, y) }
+            }
+        });
     }
     _dispatcher(x, y)
 }
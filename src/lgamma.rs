@@ -0,0 +1,299 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabs;
+use crate::digamma::{DIGAMMA_ROOT_D, DIGAMMA_ROOT_LO_D, DIGAMMA_ROOT_M1_D, DIGAMMA_ROOT_M1_LO_D};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::double_precision::mul_doubled;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_lgamma;
+use crate::floor::efloor;
+use crate::generalf::{mlaf, rintk};
+use crate::ln::{do_ln, lnk_doubled};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vlgammaq_f64;
+use crate::sin::sinpik;
+use crate::tgamma::stirlingk;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_pd, _mm_lgamma_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const LGAMMA_A_POLY_1_D: f64 = -0.5772156649015329;
+pub(crate) const LGAMMA_A_POLY_2_D: f64 = 0.8224670334241133;
+pub(crate) const LGAMMA_A_POLY_3_D: f64 = -0.4006856343865315;
+pub(crate) const LGAMMA_A_POLY_4_D: f64 = 0.27058080842771787;
+pub(crate) const LGAMMA_A_POLY_5_D: f64 = -0.20738555102861042;
+pub(crate) const LGAMMA_A_POLY_6_D: f64 = 0.16955717701613895;
+pub(crate) const LGAMMA_A_POLY_7_D: f64 = -0.14404989678670604;
+pub(crate) const LGAMMA_A_POLY_8_D: f64 = 0.12550966713649744;
+pub(crate) const LGAMMA_A_POLY_9_D: f64 = -0.11133426359238932;
+pub(crate) const LGAMMA_A_POLY_10_D: f64 = 0.10009962210510019;
+pub(crate) const LGAMMA_A_POLY_11_D: f64 = -0.09095417407951806;
+pub(crate) const LGAMMA_A_POLY_12_D: f64 = 0.08334719848486703;
+pub(crate) const LGAMMA_A_POLY_13_D: f64 = -0.07692618365330123;
+pub(crate) const LGAMMA_A_POLY_14_D: f64 = 0.07159408341567752;
+pub(crate) const LGAMMA_A_POLY_15_D: f64 = -0.06682232999260808;
+pub(crate) const LGAMMA_A_POLY_16_D: f64 = 0.06020201298139458;
+pub(crate) const LGAMMA_A_POLY_17_D: f64 = -0.05663245272474891;
+pub(crate) const LGAMMA_A_POLY_18_D: f64 = 0.07302784334409541;
+pub(crate) const LGAMMA_A_POLY_19_D: f64 = -0.06928380249485716;
+pub(crate) const LGAMMA_B_POLY_1_D: f64 = 0.42278433509846713;
+pub(crate) const LGAMMA_B_POLY_2_D: f64 = 0.32246703342411326;
+pub(crate) const LGAMMA_B_POLY_3_D: f64 = -0.0673523010531981;
+pub(crate) const LGAMMA_B_POLY_4_D: f64 = 0.02058080842778038;
+pub(crate) const LGAMMA_B_POLY_5_D: f64 = -0.0073855510286719986;
+pub(crate) const LGAMMA_B_POLY_6_D: f64 = 0.002890510331034267;
+pub(crate) const LGAMMA_B_POLY_7_D: f64 = -0.0011927539118428156;
+pub(crate) const LGAMMA_B_POLY_8_D: f64 = 0.0005096695154115458;
+pub(crate) const LGAMMA_B_POLY_9_D: f64 = -0.00022315475400520507;
+pub(crate) const LGAMMA_B_POLY_10_D: f64 = 9.945767355819498e-5;
+pub(crate) const LGAMMA_B_POLY_11_D: f64 = -4.49263133791557e-5;
+pub(crate) const LGAMMA_B_POLY_12_D: f64 = 2.0505590755833454e-5;
+pub(crate) const LGAMMA_B_POLY_13_D: f64 = -9.438715099211532e-6;
+pub(crate) const LGAMMA_B_POLY_14_D: f64 = 4.38470440459836e-6;
+pub(crate) const LGAMMA_B_POLY_15_D: f64 = -2.0439048107029907e-6;
+pub(crate) const LGAMMA_B_POLY_16_D: f64 = 9.200527894968101e-7;
+pub(crate) const LGAMMA_B_POLY_17_D: f64 = -4.3252393445604814e-7;
+pub(crate) const LGAMMA_B_POLY_18_D: f64 = 2.78740805211359e-7;
+pub(crate) const LGAMMA_B_POLY_19_D: f64 = -1.3219945666117874e-7;
+pub(crate) const LGAMMA_H_POLY_1_D: f64 = 0.4838361227238106;
+pub(crate) const LGAMMA_H_POLY_2_D: f64 = -0.14758772299453055;
+pub(crate) const LGAMMA_H_POLY_3_D: f64 = 0.06462494023890714;
+pub(crate) const LGAMMA_H_POLY_4_D: f64 = -0.03278854108857478;
+pub(crate) const LGAMMA_H_POLY_5_D: f64 = 0.01797067511701078;
+pub(crate) const LGAMMA_H_POLY_6_D: f64 = -0.010314223022146999;
+pub(crate) const LGAMMA_H_POLY_7_D: f64 = 0.006100535797768679;
+pub(crate) const LGAMMA_H_POLY_8_D: f64 = -0.003684570474943473;
+pub(crate) const LGAMMA_H_POLY_9_D: f64 = 0.0022597782161759656;
+pub(crate) const LGAMMA_H_POLY_10_D: f64 = -0.00140223306820108;
+pub(crate) const LGAMMA_H_POLY_11_D: f64 = 0.0008778157119471028;
+pub(crate) const LGAMMA_H_POLY_12_D: f64 = -0.0005540655841519184;
+pub(crate) const LGAMMA_H_POLY_13_D: f64 = 0.00035826827189652354;
+pub(crate) const LGAMMA_H_POLY_14_D: f64 = -0.0002343914920697584;
+pub(crate) const LGAMMA_H_POLY_15_D: f64 = 0.00010973226486910339;
+/// Minimum of lnΓ on the positive axis, reached at the digamma root
+pub(crate) const LGAMMA_MIN_D: f64 = -0.12148629053584961;
+pub(crate) const LGAMMA_MIN_LO_D: f64 = 3.364991468473138e-18;
+pub(crate) const LN_SQRT_2PI_D: f64 = 0.9189385332046728;
+pub(crate) const LN_PI_D: f64 = 1.1447298858494002;
+
+/// Computes lnΓ(x) for finite x > 0
+#[inline]
+pub(crate) fn lgammak(x: f64) -> f64 {
+    if x >= 10. {
+        if x > 1_152_921_504_606_846_976. {
+            // Everything past the leading terms of Stirling series is below an ulp
+            return x * (do_ln(x) - 1.);
+        }
+        let e = stirlingk((x, 0.));
+        return e.0 + (e.1 + LN_SQRT_2PI_D);
+    }
+    if x >= 1.75 {
+        // lnΓ(x) = lnΓ(2 + z) + ln((x - 1)...(x - n + 2)), z = x - n in [-0.5, 0.5]
+        let n = rintk(x);
+        let z = x - n;
+        let mut u = LGAMMA_B_POLY_19_D;
+        u = mlaf(u, z, LGAMMA_B_POLY_18_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_17_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_16_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_15_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_14_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_13_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_12_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_11_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_10_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_9_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_8_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_7_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_6_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_5_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_4_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_3_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_2_D);
+        u = mlaf(u, z, LGAMMA_B_POLY_1_D);
+        let r = z * u;
+        if n < 3. {
+            return r;
+        }
+        let mut q = (x - 1., 0.);
+        for k in 2..(n as i32 - 1) {
+            q = mul_doubled(q, (x - k as f64, 0.));
+        }
+        let l = lnk_doubled(q);
+        return l.0 + (r + l.1);
+    }
+    // Below 0.75 lnΓ(x) = lnΓ(x + 1) - ln(x), z is taken against shifted centers so x + 1 is never rounded
+    let shift = x < 0.75;
+    let r = if x < 0.25 || (0.75..1.25).contains(&x) {
+        let z = if shift { x } else { x - 1. };
+        let mut u = LGAMMA_A_POLY_19_D;
+        u = mlaf(u, z, LGAMMA_A_POLY_18_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_17_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_16_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_15_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_14_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_13_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_12_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_11_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_10_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_9_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_8_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_7_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_6_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_5_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_4_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_3_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_2_D);
+        u = mlaf(u, z, LGAMMA_A_POLY_1_D);
+        z * u
+    } else {
+        // Around the minimum lnΓ(x0 + z) = lnΓ(x0) + z^2 H(z)
+        let z = if shift {
+            (x - DIGAMMA_ROOT_M1_D) - DIGAMMA_ROOT_M1_LO_D
+        } else {
+            (x - DIGAMMA_ROOT_D) - DIGAMMA_ROOT_LO_D
+        };
+        let mut u = LGAMMA_H_POLY_15_D;
+        u = mlaf(u, z, LGAMMA_H_POLY_14_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_13_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_12_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_11_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_10_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_9_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_8_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_7_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_6_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_5_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_4_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_3_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_2_D);
+        u = mlaf(u, z, LGAMMA_H_POLY_1_D);
+        LGAMMA_MIN_D + mlaf(z * z, u, LGAMMA_MIN_LO_D)
+    };
+    if shift {
+        let l = lnk_doubled((x, 0.));
+        (r - l.0) - l.1
+    } else {
+        r
+    }
+}
+
+#[inline]
+fn do_lgamma(d: f64) -> (f64, i32) {
+    let a = eabs(d);
+    if !a.is_finite() {
+        return (a, 1);
+    }
+    let sign = if d.is_sign_negative() { -1 } else { 1 };
+    if a < 5.551115123125783e-17 {
+        // lnΓ(d) = -ln|d| - γd + O(d^2)
+        return (-do_ln(a), sign);
+    }
+    if d == 1. || d == 2. {
+        // lnΓ(1) = lnΓ(2) = +0
+        return (0., 1);
+    }
+    if d > 0. {
+        return (lgammak(d), 1);
+    }
+    if d == efloor(d) {
+        // Poles, this also covers every d <= -2^52
+        return (f64::INFINITY, 1);
+    }
+    // |Γ(d)| = pi / (|sin(pi d)| |d| Γ(|d|))
+    let s = sinpik(d);
+    let r = LN_PI_D - do_ln(eabs(s * d)) - lgammak(a);
+    (r, if s < 0. { -1 } else { 1 })
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_lgamma_neon(d: f64) -> (f64, i32) {
+    unsafe {
+        let (r, s) = vlgammaq_f64(vdupq_n_f64(d));
+        (vgetq_lane_f64::<0>(r), vgetq_lane_f64::<0>(s) as i32)
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_lgamma_sse(d: f64) -> (f64, i32) {
+    let (r, s) = _mm_lgamma_pd(_mm_set1_pd(d));
+    (_mm_extract_pd::<0>(r), _mm_extract_pd::<0>(s) as i32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_lgamma_sse41(d: f64) -> (f64, i32) {
+    do_lgamma_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_lgamma_fma(d: f64) -> (f64, i32) {
+    do_lgamma(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_lgamma_wasm(d: f64) -> (f64, i32) {
+    let (r, s) = f64x2_lgamma(f64x2_splat(d));
+    (
+        f64x2_extract_lane::<0>(r),
+        f64x2_extract_lane::<0>(s) as i32,
+    )
+}
+
+/// Computes ln|Γ(x)| and the sign of Γ(x), error bound *ULP 2.5* for positive arguments
+#[inline]
+pub fn elgamma(d: f64) -> (f64, i32) {
+    let mut _dispatcher: fn(f64) -> (f64, i32) = do_lgamma;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_lgamma_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_lgamma_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> (f64, i32)> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_lgamma_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_lgamma_sse41(d) }
+            } else {
+                |d| unsafe { do_lgamma_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,246 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+use crate::digammaf::{DIGAMMA_ROOT_LO_S, DIGAMMA_ROOT_M1_LO_S, DIGAMMA_ROOT_M1_S, DIGAMMA_ROOT_S};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::double_precision::mul_doubled;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_lgamma;
+use crate::floor::efloorf;
+use crate::generalf::{mlaf, rintfk};
+use crate::lnf::{do_ln, lnkf_doubled};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vlgammaq_f32;
+use crate::sinf::sinpikf;
+use crate::tgammaf::stirlingkf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_lgamma_ps};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const LGAMMA_A_POLY_1_S: f32 = -0.5772157;
+pub(crate) const LGAMMA_A_POLY_2_S: f32 = 0.82246697;
+pub(crate) const LGAMMA_A_POLY_3_S: f32 = -0.40068558;
+pub(crate) const LGAMMA_A_POLY_4_S: f32 = 0.27059367;
+pub(crate) const LGAMMA_A_POLY_5_S: f32 = -0.20739725;
+pub(crate) const LGAMMA_A_POLY_6_S: f32 = 0.16882415;
+pub(crate) const LGAMMA_A_POLY_7_S: f32 = -0.14338274;
+pub(crate) const LGAMMA_A_POLY_8_S: f32 = 0.14076445;
+pub(crate) const LGAMMA_A_POLY_9_S: f32 = -0.12521306;
+pub(crate) const LGAMMA_B_POLY_1_S: f32 = 0.42278433;
+pub(crate) const LGAMMA_B_POLY_2_S: f32 = 0.32246703;
+pub(crate) const LGAMMA_B_POLY_3_S: f32 = -0.067352295;
+pub(crate) const LGAMMA_B_POLY_4_S: f32 = 0.020581625;
+pub(crate) const LGAMMA_B_POLY_5_S: f32 = -0.0073859207;
+pub(crate) const LGAMMA_B_POLY_6_S: f32 = 0.0028788687;
+pub(crate) const LGAMMA_B_POLY_7_S: f32 = -0.0011874848;
+pub(crate) const LGAMMA_B_POLY_8_S: f32 = 0.00057025126;
+pub(crate) const LGAMMA_B_POLY_9_S: f32 = -0.00025056204;
+pub(crate) const LGAMMA_H_POLY_1_S: f32 = 0.4838361;
+pub(crate) const LGAMMA_H_POLY_2_S: f32 = -0.14758776;
+pub(crate) const LGAMMA_H_POLY_3_S: f32 = 0.06462388;
+pub(crate) const LGAMMA_H_POLY_4_S: f32 = -0.032781754;
+pub(crate) const LGAMMA_H_POLY_5_S: f32 = 0.018028453;
+pub(crate) const LGAMMA_H_POLY_6_S: f32 = -0.010605304;
+pub(crate) const LGAMMA_H_POLY_7_S: f32 = 0.005403786;
+/// Minimum of lnΓ on the positive axis, reached at the digamma root
+pub(crate) const LGAMMA_MIN_S: f32 = -0.12148629;
+pub(crate) const LGAMMA_MIN_LO_S: f32 = 7.5347995e-10;
+pub(crate) const LN_SQRT_2PI_S: f32 = 0.9189385;
+pub(crate) const LN_PI_S: f32 = 1.1447299;
+
+/// Computes lnΓ(x) for finite x > 0
+#[inline]
+pub(crate) fn lgammakf(x: f32) -> f32 {
+    if x >= 5. {
+        if x > 1_073_741_824. {
+            // Everything past the leading terms of Stirling series is below an ulp
+            return x * (do_ln(x) - 1.);
+        }
+        let e = stirlingkf((x, 0.));
+        return e.0 + (e.1 + LN_SQRT_2PI_S);
+    }
+    if x >= 1.75 {
+        // lnΓ(x) = lnΓ(2 + z) + ln((x - 1)...(x - n + 2)), z = x - n in [-0.5, 0.5]
+        let n = rintfk(x);
+        let z = x - n;
+        let mut u = LGAMMA_B_POLY_9_S;
+        u = mlaf(u, z, LGAMMA_B_POLY_8_S);
+        u = mlaf(u, z, LGAMMA_B_POLY_7_S);
+        u = mlaf(u, z, LGAMMA_B_POLY_6_S);
+        u = mlaf(u, z, LGAMMA_B_POLY_5_S);
+        u = mlaf(u, z, LGAMMA_B_POLY_4_S);
+        u = mlaf(u, z, LGAMMA_B_POLY_3_S);
+        u = mlaf(u, z, LGAMMA_B_POLY_2_S);
+        u = mlaf(u, z, LGAMMA_B_POLY_1_S);
+        let r = z * u;
+        if n < 3. {
+            return r;
+        }
+        let mut q = (x - 1., 0.);
+        for k in 2..(n as i32 - 1) {
+            q = mul_doubled(q, (x - k as f32, 0.));
+        }
+        let l = lnkf_doubled(q);
+        return l.0 + (r + l.1);
+    }
+    // Below 0.75 lnΓ(x) = lnΓ(x + 1) - ln(x), z is taken against shifted centers so x + 1 is never rounded
+    let shift = x < 0.75;
+    let r = if x < 0.25 || (0.75..1.25).contains(&x) {
+        let z = if shift { x } else { x - 1. };
+        let mut u = LGAMMA_A_POLY_9_S;
+        u = mlaf(u, z, LGAMMA_A_POLY_8_S);
+        u = mlaf(u, z, LGAMMA_A_POLY_7_S);
+        u = mlaf(u, z, LGAMMA_A_POLY_6_S);
+        u = mlaf(u, z, LGAMMA_A_POLY_5_S);
+        u = mlaf(u, z, LGAMMA_A_POLY_4_S);
+        u = mlaf(u, z, LGAMMA_A_POLY_3_S);
+        u = mlaf(u, z, LGAMMA_A_POLY_2_S);
+        u = mlaf(u, z, LGAMMA_A_POLY_1_S);
+        z * u
+    } else {
+        // Around the minimum lnΓ(x0 + z) = lnΓ(x0) + z^2 H(z)
+        let z = if shift {
+            (x - DIGAMMA_ROOT_M1_S) - DIGAMMA_ROOT_M1_LO_S
+        } else {
+            (x - DIGAMMA_ROOT_S) - DIGAMMA_ROOT_LO_S
+        };
+        let mut u = LGAMMA_H_POLY_7_S;
+        u = mlaf(u, z, LGAMMA_H_POLY_6_S);
+        u = mlaf(u, z, LGAMMA_H_POLY_5_S);
+        u = mlaf(u, z, LGAMMA_H_POLY_4_S);
+        u = mlaf(u, z, LGAMMA_H_POLY_3_S);
+        u = mlaf(u, z, LGAMMA_H_POLY_2_S);
+        u = mlaf(u, z, LGAMMA_H_POLY_1_S);
+        LGAMMA_MIN_S + mlaf(z * z, u, LGAMMA_MIN_LO_S)
+    };
+    if shift {
+        let l = lnkf_doubled((x, 0.));
+        (r - l.0) - l.1
+    } else {
+        r
+    }
+}
+
+#[inline]
+fn do_lgammaf(d: f32) -> (f32, i32) {
+    let a = eabsf(d);
+    if !a.is_finite() {
+        return (a, 1);
+    }
+    let sign = if d.is_sign_negative() { -1 } else { 1 };
+    if a < 2.980_232_2e-8 {
+        // lnΓ(d) = -ln|d| - γd + O(d^2)
+        return (-do_ln(a), sign);
+    }
+    if d == 1. || d == 2. {
+        // lnΓ(1) = lnΓ(2) = +0
+        return (0., 1);
+    }
+    if d > 0. {
+        return (lgammakf(d), 1);
+    }
+    if d == efloorf(d) {
+        // Poles, this also covers every d <= -2^23
+        return (f32::INFINITY, 1);
+    }
+    // |Γ(d)| = pi / (|sin(pi d)| |d| Γ(|d|))
+    let s = sinpikf(d);
+    let r = LN_PI_S - do_ln(eabsf(s * d)) - lgammakf(a);
+    (r, if s < 0. { -1 } else { 1 })
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_lgammaf_neon(d: f32) -> (f32, i32) {
+    unsafe {
+        let (r, s) = vlgammaq_f32(vdupq_n_f32(d));
+        (vgetq_lane_f32::<0>(r), vgetq_lane_f32::<0>(s) as i32)
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_lgammaf_sse(d: f32) -> (f32, i32) {
+    let (r, s) = _mm_lgamma_ps(_mm_set1_ps(d));
+    (
+        f32::from_bits(_mm_extract_psx::<0>(r) as u32),
+        f32::from_bits(_mm_extract_psx::<0>(s) as u32) as i32,
+    )
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_lgammaf_sse41(d: f32) -> (f32, i32) {
+    do_lgammaf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_lgammaf_fma(d: f32) -> (f32, i32) {
+    do_lgammaf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_lgammaf_wasm(d: f32) -> (f32, i32) {
+    let (r, s) = f32x4_lgamma(f32x4_splat(d));
+    (
+        f32x4_extract_lane::<0>(r),
+        f32x4_extract_lane::<0>(s) as i32,
+    )
+}
+
+/// Computes ln|Γ(x)| and the sign of Γ(x), error bound *ULP 2.5* for positive arguments
+#[inline]
+pub fn elgammaf(d: f32) -> (f32, i32) {
+    let mut _dispatcher: fn(f32) -> (f32, i32) = do_lgammaf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_lgammaf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_lgammaf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> (f32, i32)> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_lgammaf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_lgammaf_sse41(d) }
+            } else {
+                |d| unsafe { do_lgammaf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
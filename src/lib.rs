@@ -8,28 +8,62 @@
 mod abs;
 pub mod acos;
 pub mod acosf;
+mod acosh;
+mod acoshf;
 pub mod asin;
 pub mod asinf;
+mod asinh;
+mod asinhf;
 pub mod atan;
 pub mod atan2;
 pub mod atan2f;
 pub mod atanf;
+mod atanh;
+mod atanhf;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 mod avx;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod avx512;
+pub mod batch;
 pub mod cbrt;
 pub mod cbrtf;
 pub mod ceil;
 pub mod ceilf;
+#[cfg(test)]
+mod conformance;
 pub mod cos;
 pub mod cosf;
+mod cosh;
+mod coshf;
+mod coverage;
+pub mod cr;
+mod digamma;
+mod digammaf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+mod dispatch;
+mod divider;
 pub mod double_precision;
+mod erf;
+mod erfc;
+mod erfcf;
+mod erff;
+mod erfinv;
+mod erfinvf;
 pub mod exp;
+mod exp10;
+mod exp10f;
+mod exp2;
+mod exp2f;
 pub mod expf;
+mod expm1;
+mod expm1f;
 pub mod floor;
 mod fmax;
 mod fmaxf;
 mod fmin;
 mod fminf;
+mod fmod;
+mod fmodf;
 mod generalf;
 mod hypot;
 mod hypot3;
@@ -37,59 +71,124 @@ mod hypot3f;
 mod hypot4;
 mod hypot4f;
 mod hypotf;
+mod lgamma;
+mod lgammaf;
 mod ln;
 mod lnf;
+mod log10;
+mod log10f;
+mod log1p;
+mod log1pf;
+mod log2;
+mod log2f;
+mod modular;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 mod neon;
 mod pow;
 mod powf;
+pub mod precision;
 mod shuffle;
 mod sin;
+mod sincos;
+mod sincosf;
 mod sinf;
+mod sinh;
+mod sinhf;
 mod sqrt;
 mod sqrtf;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 mod sse;
 mod tan;
 mod tanf;
+mod tanh;
+mod tanhf;
+mod tgamma;
+mod tgammaf;
 mod vector;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod wasm;
 
 pub use abs::{eabs, eabsf};
 pub use acos::eacos;
 pub use acosf::eacosf;
+pub use acosh::eacosh;
+pub use acoshf::eacoshf;
 pub use asin::easin;
 pub use asinf::easinf;
+pub use asinh::easinh;
+pub use asinhf::easinhf;
 pub use atan::eatan;
 pub use atan2::eatan2;
 pub use atan2f::eatan2f;
 pub use atanf::eatanf;
+pub use atanh::eatanh;
+pub use atanhf::eatanhf;
 pub use cbrt::ecbrt;
 pub use cbrtf::ecbrtf;
 pub use cos::ecos;
 pub use cosf::ecosf;
+pub use cosh::ecosh;
+pub use coshf::ecoshf;
+pub use digamma::edigamma;
+pub use digammaf::edigammaf;
+pub use divider::{DividerI32, DividerI64, DividerU32, DividerU64};
+pub use erf::eerf;
+pub use erfc::eerfc;
+pub use erfcf::eerfcf;
+pub use erff::eerff;
+pub use erfinv::eerfinv;
+pub use erfinvf::eerfinvf;
 pub use exp::eexp;
+pub use exp10::eexp10;
+pub use exp10f::eexp10f;
+pub use exp2::eexp2;
+pub use exp2f::eexp2f;
 pub use expf::eexpf;
+pub use expm1::eexpm1;
+pub use expm1f::eexpm1f;
 pub use floor::{efloor, efloorf};
 pub use fmax::efmax;
 pub use fmaxf::efmaxf;
 pub use fmin::efmin;
 pub use fminf::efminf;
+pub use fmod::{efmod, eremainder, eremquo};
+pub use fmodf::{efmodf, eremainderf, eremquof};
 pub use generalf::*;
 pub use hypot::ehypot;
 pub use hypot3f::ehypot3f;
 pub use hypot4::ehypot4;
 pub use hypot4f::ehypot4f;
 pub use hypotf::ehypotf;
+pub use lgamma::elgamma;
+pub use lgammaf::elgammaf;
 pub use ln::eln;
 pub use lnf::elnf;
+pub use log10::elog10;
+pub use log10f::elog10f;
+pub use log1p::elog1p;
+pub use log1pf::elog1pf;
+pub use log2::elog2;
+pub use log2f::elog2f;
+pub use modular::{ModulusU32, ModulusU64};
 pub use pow::epow;
 pub use powf::epowf;
+pub use precision::{
+    Accuracy, CorrectlyRounded, Fast, HighAccuracy, Precision, ScalarMath, SimdMath, Standard,
+};
 pub use sin::esin;
+pub use sincos::esincos;
+pub use sincosf::esincosf;
 pub use sinf::esinf;
+pub use sinh::esinh;
+pub use sinhf::esinhf;
 pub use sqrt::esqrt;
 pub use sqrtf::esqrtf;
 pub use tan::etan;
 pub use tanf::etanf;
+pub use tanh::etanh;
+pub use tanhf::etanhf;
+pub use tgamma::etgamma;
+pub use tgammaf::etgammaf;
 
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 pub use neon::*;
@@ -101,6 +200,12 @@ pub use sse::*;
 #[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 pub use avx::*;
 
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+pub use avx512::*;
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+pub use wasm::*;
+
 pub trait Sqrtf {
     /// Computes square root
     fn esqrt(self) -> Self;
@@ -143,18 +248,48 @@ impl Sine for f64 {
 pub trait Exponential {
     /// Computes exponent
     fn eexp(self) -> Self;
+    /// Computes 2 raised to the value
+    fn eexp2(self) -> Self;
+    /// Computes 10 raised to the value
+    fn eexp10(self) -> Self;
+    /// Computes exponent minus one
+    fn eexpm1(self) -> Self;
 }
 
 impl Exponential for f32 {
     fn eexp(self) -> Self {
         eexpf(self)
     }
+
+    fn eexp2(self) -> Self {
+        eexp2f(self)
+    }
+
+    fn eexp10(self) -> Self {
+        eexp10f(self)
+    }
+
+    fn eexpm1(self) -> Self {
+        eexpm1f(self)
+    }
 }
 
 impl Exponential for f64 {
     fn eexp(self) -> Self {
         eexp(self)
     }
+
+    fn eexp2(self) -> Self {
+        eexp2(self)
+    }
+
+    fn eexp10(self) -> Self {
+        eexp10(self)
+    }
+
+    fn eexpm1(self) -> Self {
+        eexpm1(self)
+    }
 }
 
 impl Sqrtf for f32 {
@@ -206,18 +341,48 @@ impl Signed for f64 {
 pub trait Logarithmic {
     /// Computes natural logarithm for value
     fn eln(self) -> Self;
+    /// Computes base 2 logarithm for value
+    fn elog2(self) -> Self;
+    /// Computes base 10 logarithm for value
+    fn elog10(self) -> Self;
+    /// Computes natural logarithm of one plus value
+    fn elog1p(self) -> Self;
 }
 
 impl Logarithmic for f32 {
     fn eln(self) -> Self {
         elnf(self)
     }
+
+    fn elog2(self) -> Self {
+        elog2f(self)
+    }
+
+    fn elog10(self) -> Self {
+        elog10f(self)
+    }
+
+    fn elog1p(self) -> Self {
+        elog1pf(self)
+    }
 }
 
 impl Logarithmic for f64 {
     fn eln(self) -> Self {
         eln(self)
     }
+
+    fn elog2(self) -> Self {
+        elog2(self)
+    }
+
+    fn elog10(self) -> Self {
+        elog10(self)
+    }
+
+    fn elog1p(self) -> Self {
+        elog1p(self)
+    }
 }
 
 pub trait Tangent {
@@ -254,6 +419,100 @@ impl Power for f64 {
     }
 }
 
+pub trait Modulo {
+    /// Computes floating point remainder rounded toward zero
+    fn efmod(self, y: Self) -> Self;
+    /// Computes IEEE remainder rounded to nearest
+    fn eremainder(self, y: Self) -> Self;
+}
+
+impl Modulo for f32 {
+    fn efmod(self, y: Self) -> Self {
+        efmodf(self, y)
+    }
+
+    fn eremainder(self, y: Self) -> Self {
+        eremainderf(self, y)
+    }
+}
+
+impl Modulo for f64 {
+    fn efmod(self, y: Self) -> Self {
+        efmod(self, y)
+    }
+
+    fn eremainder(self, y: Self) -> Self {
+        eremainder(self, y)
+    }
+}
+
+pub trait Hyperbolic {
+    /// Computes hyperbolic sine
+    fn esinh(self) -> Self;
+    /// Computes hyperbolic cosine
+    fn ecosh(self) -> Self;
+    /// Computes hyperbolic tangent
+    fn etanh(self) -> Self;
+    /// Computes inverse hyperbolic sine
+    fn easinh(self) -> Self;
+    /// Computes inverse hyperbolic cosine
+    fn eacosh(self) -> Self;
+    /// Computes inverse hyperbolic tangent
+    fn eatanh(self) -> Self;
+}
+
+impl Hyperbolic for f32 {
+    fn esinh(self) -> Self {
+        esinhf(self)
+    }
+
+    fn ecosh(self) -> Self {
+        ecoshf(self)
+    }
+
+    fn etanh(self) -> Self {
+        etanhf(self)
+    }
+
+    fn easinh(self) -> Self {
+        easinhf(self)
+    }
+
+    fn eacosh(self) -> Self {
+        eacoshf(self)
+    }
+
+    fn eatanh(self) -> Self {
+        eatanhf(self)
+    }
+}
+
+impl Hyperbolic for f64 {
+    fn esinh(self) -> Self {
+        esinh(self)
+    }
+
+    fn ecosh(self) -> Self {
+        ecosh(self)
+    }
+
+    fn etanh(self) -> Self {
+        etanh(self)
+    }
+
+    fn easinh(self) -> Self {
+        easinh(self)
+    }
+
+    fn eacosh(self) -> Self {
+        eacosh(self)
+    }
+
+    fn eatanh(self) -> Self {
+        eatanh(self)
+    }
+}
+
 pub trait ArcTan {
     /// Computes arc tangent
     fn eatan(self) -> Self;
@@ -8,18 +8,26 @@
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
 
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::double_precision::{mul_doubled, multiply_as_doubled, sum_as_doubled};
+use crate::exp::{L2_L, L2_U};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_ln;
 use crate::generalf::{ilogb2k, ldexp3k, mlaf};
+use crate::log2::LOG_SCALE_D;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vlnq_f64;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_extract_pd, _mm_ln_pd};
-#[cfg(all(target_feature = "sse4.1", target_arch = "x86"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_feature = "sse4.1", target_arch = "x86_64"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub(crate) const LN_POLY_1_D: f64 = 2.;
 pub(crate) const LN_POLY_2_D: f64 = 0.666_666_666_666_777_874_006_3;
@@ -30,13 +38,20 @@ pub(crate) const LN_POLY_6_D: f64 = 0.181_863_266_251_982_985_677_316;
 pub(crate) const LN_POLY_7_D: f64 = 0.152_519_917_006_351_951_593_857;
 pub(crate) const LN_POLY_8_D: f64 = 0.153_487_338_491_425_068_243_146;
 
-// Absolute error 1.136351756823757474514312*10^-18
-#[inline]
-fn do_ln(d: f64) -> f64 {
-    // ln(𝑥)=ln(𝑎)+𝑛ln(2)
-    let n = ilogb2k(d * (1. / 0.75));
-    let a = ldexp3k(d, -n);
+pub(crate) const LN_DOUBLED_POLY_1_D: f64 = 0.39999999999999997;
+pub(crate) const LN_DOUBLED_POLY_2_D: f64 = 0.28571428571435636;
+pub(crate) const LN_DOUBLED_POLY_3_D: f64 = 0.22222222218517385;
+pub(crate) const LN_DOUBLED_POLY_4_D: f64 = 0.18181818920691084;
+pub(crate) const LN_DOUBLED_POLY_5_D: f64 = 0.15384543159505473;
+pub(crate) const LN_DOUBLED_POLY_6_D: f64 = 0.13337155343891088;
+pub(crate) const LN_DOUBLED_POLY_7_D: f64 = 0.11653325572378419;
+pub(crate) const LN_DOUBLED_POLY_8_D: f64 = 0.1219181334216037;
+pub(crate) const TWO_THIRDS_D: f64 = 0.6666666666666666;
+pub(crate) const TWO_THIRDS_LO_D: f64 = 3.700743415417188e-17;
 
+/// Computes ln(a) for reduced argument a in [0.75, 1.5)
+#[inline]
+pub(crate) fn lnk(a: f64) -> f64 {
     let x = (a - 1.) / (a + 1.);
     let x2 = x * x;
     let mut u = LN_POLY_8_D;
@@ -46,7 +61,52 @@ fn do_ln(d: f64) -> f64 {
     u = mlaf(u, x2, LN_POLY_4_D);
     u = mlaf(u, x2, LN_POLY_3_D);
     u = mlaf(u, x2, LN_POLY_2_D);
-    u = mlaf(u, x2, LN_POLY_1_D);
+    // Leading 2x term is exact, keeping it apart saves a rounding
+    mlaf(x * x2, u, LN_POLY_1_D * x)
+}
+
+/// Computes ln(d.0 + d.1) as double-float for positive normal d
+#[inline(always)]
+pub(crate) fn lnk_doubled(d: (f64, f64)) -> (f64, f64) {
+    let n = ilogb2k(d.0 * (1. / 0.75));
+    let a = ldexp3k(d.0, -n);
+    // x = (a - 1) / (a + 1) split into hi and lo, a - 1 is exact
+    let num = a - 1.;
+    let den = sum_as_doubled(a, 1.);
+    let x = num / den.0;
+    let m = multiply_as_doubled(x, den.0);
+    let x_lo = ((num - m.0) - m.1 - x * den.1) / den.0;
+    // ln(a) = 2x + 2x^3/3 + x^5 R(x^2), the cubic term is too large to be rounded once
+    let x3 = mul_doubled(multiply_as_doubled(x, x), (x, x_lo));
+    let t = mul_doubled(x3, (TWO_THIRDS_D, TWO_THIRDS_LO_D));
+    let x2 = x * x;
+    let mut u = LN_DOUBLED_POLY_8_D;
+    u = mlaf(u, x2, LN_DOUBLED_POLY_7_D);
+    u = mlaf(u, x2, LN_DOUBLED_POLY_6_D);
+    u = mlaf(u, x2, LN_DOUBLED_POLY_5_D);
+    u = mlaf(u, x2, LN_DOUBLED_POLY_4_D);
+    u = mlaf(u, x2, LN_DOUBLED_POLY_3_D);
+    u = mlaf(u, x2, LN_DOUBLED_POLY_2_D);
+    u = mlaf(u, x2, LN_DOUBLED_POLY_1_D);
+    let nf = n as f64;
+    let b = sum_as_doubled(2. * x, t.0);
+    let h = sum_as_doubled(nf * L2_U, b.0);
+    let tail = mlaf(x2 * x2 * x, u, mlaf(nf, L2_L, d.1 / d.0));
+    (h.0, h.1 + (b.1 + t.1 + 2. * x_lo + tail))
+}
+
+// Absolute error 1.136351756823757474514312*10^-18
+#[inline]
+pub(crate) fn do_ln(d: f64) -> f64 {
+    // ln(𝑥)=ln(𝑎)+𝑛ln(2)
+    // Subnormals are scaled to normal range before the exponent split
+    let o = d < f64::MIN_POSITIVE;
+    let x = if o { d * LOG_SCALE_D } else { d };
+    let mut n = ilogb2k(x * (1. / 0.75));
+    let a = ldexp3k(x, -n);
+    if o {
+        n -= 64;
+    }
 
     return if d == 0f64 {
         f64::NEG_INFINITY
@@ -55,7 +115,7 @@ fn do_ln(d: f64) -> f64 {
     } else if d.is_infinite() {
         f64::INFINITY
     } else {
-        x * u + std::f64::consts::LN_2 * (n as f64)
+        lnk(a) + std::f64::consts::LN_2 * (n as f64)
     };
 }
 
@@ -70,14 +130,36 @@ fn do_ln_neon(x: f64) -> f64 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
+#[inline(always)]
+unsafe fn do_ln_sse(x: f64) -> f64 {
+    let vx = _mm_set1_pd(x);
+    _mm_extract_pd::<0>(_mm_ln_pd(vx))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_ln_sse41(x: f64) -> f64 {
+    do_ln_sse(x)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_ln_fma(x: f64) -> f64 {
+    do_ln(x)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_ln_sse(x: f64) -> f64 {
-    unsafe {
-        let vx = _mm_set1_pd(x);
-        _mm_extract_pd::<0>(_mm_ln_pd(vx))
-    }
+fn do_ln_wasm(x: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_ln(f64x2_splat(x)))
 }
 
 /// Computes natural logarithm *ULP 3.5*
@@ -87,12 +169,25 @@ pub fn eln(d: f64) -> f64 {
     {
         _dispatcher = do_ln_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_ln_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_ln_sse;
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |x| unsafe { do_ln_fma(x) }
+            } else if is_sse41_available() {
+                |x| unsafe { do_ln_sse41(x) }
+            } else {
+                |x| unsafe { do_ln_sse(x) }
+            }
+        });
     }
     _dispatcher(d)
 }
@@ -5,20 +5,28 @@
  * // license that can be found in the LICENSE file.
  */
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_ln_ps;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::double_precision::{mul_doubled, multiply_as_doubled, sum_as_doubled};
+use crate::expf::{L2L_F, L2U_F};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_ln;
 use crate::generalf::{ilogb2kf, ldexp3kf, mlaf};
+use crate::log2f::LOG_SCALE_F;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vlnq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_ln_ps};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub const LN_POLY_1_F: f32 = 2f32;
 pub const LN_POLY_2_F: f32 = 0.6666677f32;
@@ -26,19 +34,64 @@ pub const LN_POLY_3_F: f32 = 0.40017125f32;
 pub const LN_POLY_4_F: f32 = 0.28523374f32;
 pub const LN_POLY_5_F: f32 = 0.23616748f32;
 
-#[inline]
-fn do_ln(d: f32) -> f32 {
-    // ln(𝑥)=ln(𝑎)+𝑛ln(2)
-    let n = ilogb2kf(d * (1. / 0.75));
-    let a = ldexp3kf(d, -n);
+pub(crate) const LN_DOUBLED_POLY_1_S: f32 = 0.40;
+pub(crate) const LN_DOUBLED_POLY_2_S: f32 = 0.28571692;
+pub(crate) const LN_DOUBLED_POLY_3_S: f32 = 0.2218944;
+pub(crate) const LN_DOUBLED_POLY_4_S: f32 = 0.194738;
+pub(crate) const TWO_THIRDS_S: f32 = 0.6666667;
+pub(crate) const TWO_THIRDS_LO_S: f32 = -1.9868216e-8;
 
+/// Computes ln(a) for reduced argument a in [0.75, 1.5)
+#[inline]
+pub(crate) fn lnkf(a: f32) -> f32 {
     let x = (a - 1.) / (a + 1.);
     let x2 = x * x;
     let mut u = LN_POLY_5_F;
     u = mlaf(u, x2, LN_POLY_4_F);
     u = mlaf(u, x2, LN_POLY_3_F);
     u = mlaf(u, x2, LN_POLY_2_F);
-    u = mlaf(u, x2, LN_POLY_1_F);
+    // Leading 2x term is exact, keeping it apart saves a rounding
+    mlaf(x * x2, u, LN_POLY_1_F * x)
+}
+
+/// Computes ln(d.0 + d.1) as double-float for positive normal d
+#[inline]
+pub(crate) fn lnkf_doubled(d: (f32, f32)) -> (f32, f32) {
+    let n = ilogb2kf(d.0 * (1. / 0.75));
+    let a = ldexp3kf(d.0, -n);
+    // x = (a - 1) / (a + 1) split into hi and lo, a - 1 is exact
+    let num = a - 1.;
+    let den = sum_as_doubled(a, 1.);
+    let x = num / den.0;
+    let m = multiply_as_doubled(x, den.0);
+    let x_lo = ((num - m.0) - m.1 - x * den.1) / den.0;
+    // ln(a) = 2x + 2x^3/3 + x^5 R(x^2), the cubic term is too large to be rounded once
+    let x3 = mul_doubled(multiply_as_doubled(x, x), (x, x_lo));
+    let t = mul_doubled(x3, (TWO_THIRDS_S, TWO_THIRDS_LO_S));
+    let x2 = x * x;
+    let mut u = LN_DOUBLED_POLY_4_S;
+    u = mlaf(u, x2, LN_DOUBLED_POLY_3_S);
+    u = mlaf(u, x2, LN_DOUBLED_POLY_2_S);
+    u = mlaf(u, x2, LN_DOUBLED_POLY_1_S);
+    let nf = n as f32;
+    let b = sum_as_doubled(2. * x, t.0);
+    let h = sum_as_doubled(nf * L2U_F, b.0);
+    let tail = mlaf(x2 * x2 * x, u, mlaf(nf, L2L_F, d.1 / d.0));
+    (h.0, h.1 + (b.1 + t.1 + 2. * x_lo + tail))
+}
+
+#[inline]
+pub(crate) fn do_ln(d: f32) -> f32 {
+    // ln(𝑥)=ln(𝑎)+𝑛ln(2)
+    // Subnormals are scaled to normal range before the exponent split
+    let o = d < f32::MIN_POSITIVE;
+    let x = if o { d * LOG_SCALE_F } else { d };
+    let mut n = ilogb2kf(x * (1. / 0.75));
+    let a = ldexp3kf(x, -n);
+    if o {
+        n -= 64;
+    }
+
     return if d == 0f32 {
         f32::NEG_INFINITY
     } else if (d < 0.) || d.is_nan() {
@@ -46,7 +99,7 @@ fn do_ln(d: f32) -> f32 {
     } else if d.is_infinite() {
         f32::INFINITY
     } else {
-        x * u + std::f32::consts::LN_2 * (n as f32)
+        lnkf(a) + std::f32::consts::LN_2 * (n as f32)
     };
 }
 
@@ -61,16 +114,37 @@ fn do_lnf_neon(x: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
+#[inline(always)]
+unsafe fn do_ln_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_ln_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_ln_sse41(d: f32) -> f32 {
+    do_ln_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_ln_fma(d: f32) -> f32 {
+    do_ln(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_ln_sse(d: f32) -> f32 {
-    unsafe {
-        let v = _mm_set1_ps(d);
-        let value = _mm_ln_ps(v);
-        let ex = f32::from_bits(_mm_extract_ps::<0>(value) as u32);
-        ex
-    }
+fn do_ln_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_ln(f32x4_splat(d)))
 }
 
 /// Computes natural logarithm for an argument *ULP 1.0*
@@ -80,12 +154,25 @@ pub fn elnf(d: f32) -> f32 {
     {
         _dispatcher = do_lnf_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_ln_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_ln_sse;
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_ln_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_ln_sse41(d) }
+            } else {
+                |d| unsafe { do_ln_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
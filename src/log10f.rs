@@ -0,0 +1,129 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_log10;
+use crate::generalf::{ilogb2kf, ldexp3kf, mlaf};
+use crate::lnf::lnkf;
+use crate::log2f::LOG_SCALE_F;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vlog10q_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_log10_ps};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn do_log10f(d: f32) -> f32 {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = d < f32::MIN_POSITIVE;
+    let x = if o { d * LOG_SCALE_F } else { d };
+    let mut n = ilogb2kf(x * (1. / 0.75));
+    let a = ldexp3kf(x, -n);
+    if o {
+        n -= 64;
+    }
+
+    if d == 0f32 {
+        f32::NEG_INFINITY
+    } else if (d < 0.) || d.is_nan() {
+        f32::NAN
+    } else if d.is_infinite() {
+        f32::INFINITY
+    } else {
+        mlaf(
+            lnkf(a),
+            std::f32::consts::LOG10_E,
+            n as f32 * std::f32::consts::LOG10_2,
+        )
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_log10f_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vlog10q_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_log10f_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_log10_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_log10f_sse41(d: f32) -> f32 {
+    do_log10f_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_log10f_fma(d: f32) -> f32 {
+    do_log10f(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_log10f_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_log10(f32x4_splat(d)))
+}
+
+/// Computes log10 for an argument *ULP 4.5*
+#[inline]
+pub fn elog10f(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_log10f;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_log10f_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_log10f_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_log10f_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_log10f_sse41(d) }
+            } else {
+                |d| unsafe { do_log10f_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
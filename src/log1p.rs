@@ -0,0 +1,122 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_log1p;
+use crate::generalf::{ilogb2k, ldexp3k, mlaf};
+use crate::ln::lnk;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vlog1pq_f64;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_pd, _mm_log1p_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+pub(crate) fn do_log1p(d: f64) -> f64 {
+    let dp1 = d + 1.;
+    let n = ilogb2k(dp1 * (1. / 0.75));
+    let a = ldexp3k(dp1, -n);
+    // c is the rounding error of 1 + d relative to it
+    let c = (d - (dp1 - 1.)) / dp1;
+
+    if d == 0f64 {
+        d
+    } else if d == -1f64 {
+        f64::NEG_INFINITY
+    } else if (d < -1.) || d.is_nan() {
+        f64::NAN
+    } else if d.is_infinite() {
+        f64::INFINITY
+    } else {
+        mlaf(std::f64::consts::LN_2, n as f64, lnk(a) + c)
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_log1p_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(vlog1pq_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_log1p_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_log1p_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_log1p_sse41(d: f64) -> f64 {
+    do_log1p_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_log1p_fma(d: f64) -> f64 {
+    do_log1p(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_log1p_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_log1p(f64x2_splat(d)))
+}
+
+/// Computes ln(1 + x), accurate for x near zero *ULP 2.5*
+#[inline]
+pub fn elog1p(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_log1p;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_log1p_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_log1p_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_log1p_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_log1p_sse41(d) }
+            } else {
+                |d| unsafe { do_log1p_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
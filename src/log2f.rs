@@ -0,0 +1,126 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_log2;
+use crate::generalf::{ilogb2kf, ldexp3kf, mlaf};
+use crate::lnf::lnkf;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vlog2q_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_log2_ps};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const LOG_SCALE_F: f32 = 18_446_744_073_709_551_616f32;
+
+#[inline]
+fn do_log2f(d: f32) -> f32 {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = d < f32::MIN_POSITIVE;
+    let x = if o { d * LOG_SCALE_F } else { d };
+    let mut n = ilogb2kf(x * (1. / 0.75));
+    let a = ldexp3kf(x, -n);
+    if o {
+        n -= 64;
+    }
+
+    if d == 0f32 {
+        f32::NEG_INFINITY
+    } else if (d < 0.) || d.is_nan() {
+        f32::NAN
+    } else if d.is_infinite() {
+        f32::INFINITY
+    } else {
+        mlaf(lnkf(a), std::f32::consts::LOG2_E, n as f32)
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_log2f_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vlog2q_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_log2f_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_log2_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_log2f_sse41(d: f32) -> f32 {
+    do_log2f_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_log2f_fma(d: f32) -> f32 {
+    do_log2f(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_log2f_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_log2(f32x4_splat(d)))
+}
+
+/// Computes log2 for an argument *ULP 5.5*
+#[inline]
+pub fn elog2f(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_log2f;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_log2f_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_log2f_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_log2f_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_log2f_sse41(d) }
+            } else {
+                |d| unsafe { do_log2f_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,132 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+//! Precomputed constants for modular arithmetic on vector lanes.
+//!
+//! A modulus is computed once and then passed to the vector routines `_mm_mulmod_epu64`,
+//! `_mm256_montmul_epu32`, `vntt_butterflyq_u64` and so on. Montgomery routines use `R = 2^32`
+//! for u32 lanes and `R = 2^64` for u64 lanes. Inputs of every routine must already be
+//! reduced, i.e. less than the modulus, and results are always fully reduced.
+//!
+//! Moduli are limited to odd values below 2^31 for [`ModulusU32`] and below 2^62 for
+//! [`ModulusU64`], so sums and Barrett estimates never overflow a lane. NTT-friendly primes
+//! such as 998244353 fit, while the Goldilocks prime 2^64 - 2^32 + 1 is not supported.
+//! [`ModulusU32::mul_mod`] and [`ModulusU32::montmul`] (and the same for u64) are scalar
+//! counterparts of the vector routines and give identical results.
+
+/// Precomputed modulus for u32 lanes
+#[derive(Copy, Clone, Debug)]
+pub struct ModulusU32 {
+    pub(crate) n: u32,
+    pub(crate) n_inv: u32,
+    pub(crate) r2: u32,
+    pub(crate) mu: u32,
+    pub(crate) bits: u32,
+}
+
+/// Precomputed modulus for u64 lanes
+#[derive(Copy, Clone, Debug)]
+pub struct ModulusU64 {
+    pub(crate) n: u64,
+    pub(crate) n_inv: u64,
+    pub(crate) r2: u64,
+    pub(crate) mu: u64,
+    pub(crate) bits: u32,
+}
+
+macro_rules! modulus_impl {
+    ($name:ident, $t:ty, $wide:ty, $bits:expr, $max_bits:expr) => {
+        impl $name {
+            /// Precomputes Montgomery and Barrett constants
+            ///
+            /// # Panics
+            /// Panics if modulus is even, less than 3, or doesn't fit into 31 bits for u32
+            /// and 62 bits for u64
+            pub fn new(n: $t) -> $name {
+                assert!(
+                    n % 2 == 1 && n > 1 && n >> $max_bits == 0,
+                    "Modulus must be odd and in range [3, 2^{})",
+                    $max_bits
+                );
+                // Newton iterations double correct bits of the inverse, n is correct to 3 bits
+                let mut n_inv = n;
+                for _ in 0..5 {
+                    n_inv = n_inv.wrapping_mul((2 as $t).wrapping_sub(n.wrapping_mul(n_inv)));
+                }
+                let r1 = (((1 as $wide) << $bits) % n as $wide) as $t;
+                let r2 = ((r1 as $wide * r1 as $wide) % n as $wide) as $t;
+                let bits = $bits - n.leading_zeros();
+                let mu = (((1 as $wide) << (2 * bits)) / n as $wide) as $t;
+                $name {
+                    n,
+                    n_inv,
+                    r2,
+                    mu,
+                    bits,
+                }
+            }
+
+            /// Returns modulus
+            #[inline]
+            pub fn modulus(&self) -> $t {
+                self.n
+            }
+
+            /// Montgomery reduction, computes `x * R^-1 mod n` for `x < n * R`
+            #[inline]
+            fn montgomery_reduce(&self, x: $wide) -> $t {
+                let q = (x as $t).wrapping_mul(self.n_inv);
+                let qn = ((q as $wide * self.n as $wide) >> $bits) as $t;
+                let hi = (x >> $bits) as $t;
+                // Low halves of x and q * n are equal, so the difference lies in (-n, n)
+                if hi >= qn {
+                    hi - qn
+                } else {
+                    hi.wrapping_sub(qn).wrapping_add(self.n)
+                }
+            }
+
+            /// Montgomery multiplication of reduced values, computes `a * b * R^-1 mod n`
+            #[inline]
+            pub fn montmul(&self, a: $t, b: $t) -> $t {
+                self.montgomery_reduce(a as $wide * b as $wide)
+            }
+
+            /// Barrett modular multiplication of reduced values, computes `a * b mod n`
+            #[inline]
+            pub fn mul_mod(&self, a: $t, b: $t) -> $t {
+                let x = a as $wide * b as $wide;
+                let x1 = (x >> (self.bits - 1)) as $t;
+                let q = ((x1 as $wide * self.mu as $wide) >> (self.bits + 1)) as $t;
+                // Estimated quotient is at most 2 less than the exact one
+                let mut r = x - q as $wide * self.n as $wide;
+                if r >= self.n as $wide {
+                    r -= self.n as $wide;
+                }
+                if r >= self.n as $wide {
+                    r -= self.n as $wide;
+                }
+                r as $t
+            }
+
+            /// Converts reduced value into Montgomery form, `a * R mod n`
+            #[inline]
+            pub fn to_montgomery(&self, a: $t) -> $t {
+                self.montmul(a, self.r2)
+            }
+
+            /// Converts reduced value from Montgomery form, `a * R^-1 mod n`
+            #[inline]
+            pub fn from_montgomery(&self, a: $t) -> $t {
+                self.montgomery_reduce(a as $wide)
+            }
+        }
+    };
+}
+
+modulus_impl!(ModulusU32, u32, u64, 32, 31);
+modulus_impl!(ModulusU64, u64, u128, 64, 62);
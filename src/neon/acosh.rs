@@ -0,0 +1,40 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::vlog1pq_f64;
+
+/// Computes inverse hyperbolic cosine, error bound *ULP 4.0*
+#[inline]
+pub unsafe fn vacoshq_f64(d: float64x2_t) -> float64x2_t {
+    let t = vsubq_f64(d, vdupq_n_f64(1f64));
+    // d < 2: acosh(d) = log1p(t + sqrt(t * (t + 2))), t = d - 1 is exact there
+    let small = vaddq_f64(t, vsqrtq_f64(vmulq_f64(t, vaddq_f64(t, vdupq_n_f64(2f64)))));
+    // d >= 2: acosh(d) = log1p(2d - 1 - 1 / (d + sqrt(d^2 - 1)))
+    let mid = vsubq_f64(
+        vsubq_f64(vaddq_f64(d, d), vdupq_n_f64(1f64)),
+        vdivq_f64(
+            vdupq_n_f64(1f64),
+            vaddq_f64(d, vsqrtq_f64(vsubq_f64(vmulq_f64(d, d), vdupq_n_f64(1f64)))),
+        ),
+    );
+    // Large d has sqrt(d^2 - 1) == d, so acosh(d) = ln(d) + ln(2)
+    let big = vcgtq_f64(d, vdupq_n_f64(268_435_456f64));
+    let u = vbslq_f64(
+        vcltq_f64(d, vdupq_n_f64(2f64)),
+        small,
+        vbslq_f64(big, t, mid),
+    );
+    let mut r = vaddq_f64(
+        vlog1pq_f64(u),
+        vbslq_f64(big, vdupq_n_f64(std::f64::consts::LN_2), vdupq_n_f64(0f64)),
+    );
+    // d < 1 -> Nan
+    r = vbslq_f64(vcltq_f64(d, vdupq_n_f64(1f64)), vdupq_n_f64(f64::NAN), r);
+    r
+}
@@ -0,0 +1,40 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::vlog1pq_f32;
+
+/// Computes inverse hyperbolic cosine, error bound *ULP 4.5*
+#[inline]
+pub unsafe fn vacoshq_f32(d: float32x4_t) -> float32x4_t {
+    let t = vsubq_f32(d, vdupq_n_f32(1f32));
+    // d < 2: acosh(d) = log1p(t + sqrt(t * (t + 2))), t = d - 1 is exact there
+    let small = vaddq_f32(t, vsqrtq_f32(vmulq_f32(t, vaddq_f32(t, vdupq_n_f32(2f32)))));
+    // d >= 2: acosh(d) = log1p(2d - 1 - 1 / (d + sqrt(d^2 - 1)))
+    let mid = vsubq_f32(
+        vsubq_f32(vaddq_f32(d, d), vdupq_n_f32(1f32)),
+        vdivq_f32(
+            vdupq_n_f32(1f32),
+            vaddq_f32(d, vsqrtq_f32(vsubq_f32(vmulq_f32(d, d), vdupq_n_f32(1f32)))),
+        ),
+    );
+    // Large d has sqrt(d^2 - 1) == d, so acosh(d) = ln(d) + ln(2)
+    let big = vcgtq_f32(d, vdupq_n_f32(4096f32));
+    let u = vbslq_f32(
+        vcltq_f32(d, vdupq_n_f32(2f32)),
+        small,
+        vbslq_f32(big, t, mid),
+    );
+    let mut r = vaddq_f32(
+        vlog1pq_f32(u),
+        vbslq_f32(big, vdupq_n_f32(std::f32::consts::LN_2), vdupq_n_f32(0f32)),
+    );
+    // d < 1 -> Nan
+    r = vbslq_f32(vcltq_f32(d, vdupq_n_f32(1f32)), vdupq_n_f32(f32::NAN), r);
+    r
+}
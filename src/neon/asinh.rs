@@ -0,0 +1,48 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::{vcopysignq_f64, vlog1pq_f64};
+
+/// Computes inverse hyperbolic sine, error bound *ULP 3.5*
+#[inline]
+pub unsafe fn vasinhq_f64(d: float64x2_t) -> float64x2_t {
+    let a = vabsq_f64(d);
+    let a2 = vmulq_f64(a, a);
+    // a < 2: asinh(a) = log1p(a + a^2 / (1 + sqrt(1 + a^2)))
+    let small = vaddq_f64(
+        a,
+        vdivq_f64(
+            a2,
+            vaddq_f64(
+                vdupq_n_f64(1f64),
+                vsqrtq_f64(vaddq_f64(a2, vdupq_n_f64(1f64))),
+            ),
+        ),
+    );
+    // a >= 2: asinh(a) = log1p(2a - 1 + 1 / (a + sqrt(a^2 + 1))), 2a - 1 is exact there
+    let mid = vaddq_f64(
+        vsubq_f64(vaddq_f64(a, a), vdupq_n_f64(1f64)),
+        vdivq_f64(
+            vdupq_n_f64(1f64),
+            vaddq_f64(a, vsqrtq_f64(vaddq_f64(a2, vdupq_n_f64(1f64)))),
+        ),
+    );
+    // Large a has sqrt(a^2 + 1) == a, so asinh(a) = ln(a) + ln(2)
+    let big = vcgtq_f64(a, vdupq_n_f64(268_435_456f64));
+    let u = vbslq_f64(
+        vcltq_f64(a, vdupq_n_f64(2f64)),
+        small,
+        vbslq_f64(big, vsubq_f64(a, vdupq_n_f64(1f64)), mid),
+    );
+    let r = vaddq_f64(
+        vlog1pq_f64(u),
+        vbslq_f64(big, vdupq_n_f64(std::f64::consts::LN_2), vdupq_n_f64(0f64)),
+    );
+    vcopysignq_f64(r, d)
+}
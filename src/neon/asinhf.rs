@@ -0,0 +1,48 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::{vcopysignq_f32, vlog1pq_f32};
+
+/// Computes inverse hyperbolic sine, error bound *ULP 4.5*
+#[inline]
+pub unsafe fn vasinhq_f32(d: float32x4_t) -> float32x4_t {
+    let a = vabsq_f32(d);
+    let a2 = vmulq_f32(a, a);
+    // a < 2: asinh(a) = log1p(a + a^2 / (1 + sqrt(1 + a^2)))
+    let small = vaddq_f32(
+        a,
+        vdivq_f32(
+            a2,
+            vaddq_f32(
+                vdupq_n_f32(1f32),
+                vsqrtq_f32(vaddq_f32(a2, vdupq_n_f32(1f32))),
+            ),
+        ),
+    );
+    // a >= 2: asinh(a) = log1p(2a - 1 + 1 / (a + sqrt(a^2 + 1))), 2a - 1 is exact there
+    let mid = vaddq_f32(
+        vsubq_f32(vaddq_f32(a, a), vdupq_n_f32(1f32)),
+        vdivq_f32(
+            vdupq_n_f32(1f32),
+            vaddq_f32(a, vsqrtq_f32(vaddq_f32(a2, vdupq_n_f32(1f32)))),
+        ),
+    );
+    // Large a has sqrt(a^2 + 1) == a, so asinh(a) = ln(a) + ln(2)
+    let big = vcgtq_f32(a, vdupq_n_f32(4096f32));
+    let u = vbslq_f32(
+        vcltq_f32(a, vdupq_n_f32(2f32)),
+        small,
+        vbslq_f32(big, vsubq_f32(a, vdupq_n_f32(1f32)), mid),
+    );
+    let r = vaddq_f32(
+        vlog1pq_f32(u),
+        vbslq_f32(big, vdupq_n_f32(std::f32::consts::LN_2), vdupq_n_f32(0f32)),
+    );
+    vcopysignq_f32(r, d)
+}
@@ -0,0 +1,55 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::atan::{
+    ATAN_POLY_10_D, ATAN_POLY_11_D, ATAN_POLY_12_D, ATAN_POLY_13_D, ATAN_POLY_14_D, ATAN_POLY_15_D,
+    ATAN_POLY_16_D, ATAN_POLY_17_D, ATAN_POLY_18_D, ATAN_POLY_19_D, ATAN_POLY_1_D, ATAN_POLY_20_D,
+    ATAN_POLY_21_D, ATAN_POLY_2_D, ATAN_POLY_3_D, ATAN_POLY_4_D, ATAN_POLY_5_D, ATAN_POLY_6_D,
+    ATAN_POLY_7_D, ATAN_POLY_8_D, ATAN_POLY_9_D,
+};
+use crate::neon::general::vmlafq_f64;
+use std::arch::aarch64::*;
+
+/// Computes Atan function with *ULP 2.0* error
+#[inline]
+pub unsafe fn vatanq_f64(x: float64x2_t) -> float64x2_t {
+    // Sign bit is restored at the end so that atan(-0) = -0
+    let sign = vandq_u64(vreinterpretq_u64_f64(x), vdupq_n_u64(0x8000_0000_0000_0000));
+    let d = vabsq_f64(x);
+    let more_than_one_mask = vcgeq_f64(d, vdupq_n_f64(1.));
+    let x = vbslq_f64(more_than_one_mask, vdivq_f64(vdupq_n_f64(1.), d), d);
+    let x2 = vmulq_f64(x, x);
+    let mut u = vdupq_n_f64(ATAN_POLY_21_D);
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_20_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_19_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_18_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_17_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_16_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_15_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_14_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_13_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_12_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_11_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_10_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_9_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_8_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_7_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_6_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_5_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_4_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_3_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_2_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(ATAN_POLY_1_D));
+    u = vmulq_f64(u, x);
+    u = vbslq_f64(
+        more_than_one_mask,
+        vsubq_f64(vdupq_n_f64(std::f64::consts::FRAC_PI_2), u),
+        u,
+    );
+    u = vreinterpretq_f64_u64(veorq_u64(vreinterpretq_u64_f64(u), sign));
+    u
+}
@@ -0,0 +1,33 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::neon::atan::vatanq_f64;
+use crate::neon::general::{vcopysignq_f64, visinfq_f64};
+use std::arch::aarch64::*;
+
+/// Computes atan for Y,X
+#[inline]
+pub unsafe fn vatan2q_f64(y: float64x2_t, x: float64x2_t) -> float64x2_t {
+    let ay = vabsq_f64(y);
+    let ax = vabsq_f64(x);
+    let mut r = vatanq_f64(vdivq_f64(ay, ax));
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    r = vbslq_f64(
+        vandq_u64(vceqzq_f64(ay), vceqzq_f64(ax)),
+        vdupq_n_f64(0.),
+        r,
+    );
+    r = vbslq_f64(
+        vandq_u64(visinfq_f64(ay), visinfq_f64(ax)),
+        vdupq_n_f64(std::f64::consts::FRAC_PI_4),
+        r,
+    );
+    // Negative x, including -0, reflects the angle to the left half plane
+    let x_neg = vcltq_f64(vcopysignq_f64(vdupq_n_f64(1.), x), vdupq_n_f64(0.));
+    r = vbslq_f64(x_neg, vsubq_f64(vdupq_n_f64(std::f64::consts::PI), r), r);
+    vcopysignq_f64(r, y)
+}
@@ -6,39 +6,28 @@
  */
 
 use crate::neon::atanf::vatanq_f32;
+use crate::neon::general::{vcopysignq_f32, visinfq_f32};
 use std::arch::aarch64::*;
 
 /// Computes atan for Y,X
 #[inline]
 pub unsafe fn vatan2q_f32(y: float32x4_t, x: float32x4_t) -> float32x4_t {
-    let zero_x_mask = vceqzq_f32(x);
-    let yx = vatanq_f32(vdivq_f32(y, x));
-    let mut rad = yx;
-    rad = vbslq_f32(
-        vandq_u32(zero_x_mask, vcgezq_f32(y)),
-        vdupq_n_f32(std::f32::consts::FRAC_PI_2),
-        rad,
-    ); // x == 0 && y > 0.
-    rad = vbslq_f32(
-        vandq_u32(zero_x_mask, vclezq_f32(y)),
-        vdupq_n_f32(-std::f32::consts::FRAC_PI_2),
-        rad,
-    ); // x == 0 && y < 0.
-    rad = vbslq_f32(
-        vandq_u32(zero_x_mask, vclezq_f32(y)),
-        vdupq_n_f32(0f32),
-        rad,
-    ); // x == 0 && y == 0.
-    let x_lower_than_0 = vcltzq_f32(x);
-    rad = vbslq_f32(
-        vandq_u32(x_lower_than_0, vcgezq_f32(y)),
-        vaddq_f32(yx, vdupq_n_f32(std::f32::consts::PI)),
-        rad,
-    ); // x < 0 && y >= 0
-    rad = vbslq_f32(
-        vandq_u32(x_lower_than_0, vcltzq_f32(y)),
-        vaddq_f32(yx, vdupq_n_f32(-std::f32::consts::PI)),
-        rad,
-    ); // x < 0 && y < 0
-    rad
+    let ay = vabsq_f32(y);
+    let ax = vabsq_f32(x);
+    let mut r = vatanq_f32(vdivq_f32(ay, ax));
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    r = vbslq_f32(
+        vandq_u32(vceqzq_f32(ay), vceqzq_f32(ax)),
+        vdupq_n_f32(0.),
+        r,
+    );
+    r = vbslq_f32(
+        vandq_u32(visinfq_f32(ay), visinfq_f32(ax)),
+        vdupq_n_f32(std::f32::consts::FRAC_PI_4),
+        r,
+    );
+    // Negative x, including -0, reflects the angle to the left half plane
+    let x_neg = vcltq_f32(vcopysignq_f32(vdupq_n_f32(1.), x), vdupq_n_f32(0.));
+    r = vbslq_f32(x_neg, vsubq_f32(vdupq_n_f32(std::f32::consts::PI), r), r);
+    vcopysignq_f32(r, y)
 }
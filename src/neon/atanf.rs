@@ -15,7 +15,8 @@ use std::arch::aarch64::*;
 /// Computes Atan function with *ULP 1.0* error
 #[inline]
 pub unsafe fn vatanq_f32(x: float32x4_t) -> float32x4_t {
-    let negative_mask = vcltzq_f32(x);
+    // Sign bit is restored at the end so that atan(-0) = -0
+    let sign = vandq_u32(vreinterpretq_u32_f32(x), vdupq_n_u32(0x8000_0000));
     let d = vabsq_f32(x);
     let more_than_one_mask = vcgeq_f32(d, vdupq_n_f32(1f32));
     let x = vbslq_f32(more_than_one_mask, vdivq_f32(vdupq_n_f32(1f32), d), d);
@@ -35,6 +36,6 @@ pub unsafe fn vatanq_f32(x: float32x4_t) -> float32x4_t {
         vsubq_f32(vdupq_n_f32(std::f32::consts::FRAC_PI_2), u),
         u,
     );
-    u = vbslq_f32(negative_mask, vnegq_f32(u), u);
+    u = vreinterpretq_f32_u32(veorq_u32(vreinterpretq_u32_f32(u), sign));
     u
 }
@@ -0,0 +1,26 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::{vcopysignq_f64, vlog1pq_f64};
+
+/// Computes inverse hyperbolic tangent, error bound *ULP 3.0*
+#[inline]
+pub unsafe fn vatanhq_f64(d: float64x2_t) -> float64x2_t {
+    let a = vabsq_f64(d);
+    let s = vaddq_f64(a, a);
+    // atanh(a) = log1p(2a + 2a^2 / (1 - a)) / 2, keeping 2a apart holds precision near zero
+    let u = vaddq_f64(
+        s,
+        vdivq_f64(vmulq_f64(s, a), vsubq_f64(vdupq_n_f64(1f64), a)),
+    );
+    let mut r = vmulq_f64(vdupq_n_f64(0.5f64), vlog1pq_f64(u));
+    // |d| > 1 -> Nan
+    r = vbslq_f64(vcgtq_f64(a, vdupq_n_f64(1f64)), vdupq_n_f64(f64::NAN), r);
+    vcopysignq_f64(r, d)
+}
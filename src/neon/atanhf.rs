@@ -0,0 +1,26 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::{vcopysignq_f32, vlog1pq_f32};
+
+/// Computes inverse hyperbolic tangent, error bound *ULP 4.0*
+#[inline]
+pub unsafe fn vatanhq_f32(d: float32x4_t) -> float32x4_t {
+    let a = vabsq_f32(d);
+    let s = vaddq_f32(a, a);
+    // atanh(a) = log1p(2a + 2a^2 / (1 - a)) / 2, keeping 2a apart holds precision near zero
+    let u = vaddq_f32(
+        s,
+        vdivq_f32(vmulq_f32(s, a), vsubq_f32(vdupq_n_f32(1f32), a)),
+    );
+    let mut r = vmulq_f32(vdupq_n_f32(0.5f32), vlog1pq_f32(u));
+    // |d| > 1 -> Nan
+    r = vbslq_f32(vcgtq_f32(a, vdupq_n_f32(1f32)), vdupq_n_f32(f32::NAN), r);
+    vcopysignq_f32(r, d)
+}
@@ -7,8 +7,10 @@
 
 use std::arch::aarch64::*;
 
+use crate::cbrt::{
+    CBRT_HUGE_D, CBRT_HUGE_SCALE_D, CBRT_HUGE_SCALE_R_D, CBRT_SCALE_D, CBRT_SCALE_R_D,
+};
 use crate::neon::general::{visinfq_f64, visneginfq_f64, vmlafq_f64};
-use crate::neon::poly128::{vaddwq_u64, vmovnq_u128, vmullq_u64, vshrq_n_u128};
 
 #[inline(always)]
 unsafe fn halley_cbrt(x: float64x2_t, a: float64x2_t) -> float64x2_t {
@@ -20,19 +22,45 @@ unsafe fn halley_cbrt(x: float64x2_t, a: float64x2_t) -> float64x2_t {
     vmulq_f64(x, scale)
 }
 
+/// Newton step from t rounded to 22 bits, t*t is exact so the result is within 0.67 ulp
+#[inline(always)]
+unsafe fn newton_round(t: float64x2_t, a: float64x2_t) -> float64x2_t {
+    let t = vreinterpretq_f64_u64(vandq_u64(
+        vaddq_u64(vreinterpretq_u64_f64(t), vdupq_n_u64(0x8000_0000)),
+        vdupq_n_u64(0xffff_ffff_c000_0000),
+    ));
+    let s = vmulq_f64(t, t);
+    let r = vdivq_f64(a, s);
+    let w = vaddq_f64(t, t);
+    let r = vdivq_f64(vsubq_f64(r, t), vaddq_f64(w, r));
+    vaddq_f64(t, vmulq_f64(t, r))
+}
+
 #[inline(always)]
 unsafe fn integer_pow_1_3(hx: uint64x2_t) -> uint64x2_t {
-    let scale = vdupq_n_u64(341);
-    let wide = vmullq_u64(hx, scale);
-    let shifted = vshrq_n_u128::<10>(wide);
-    let addiction = vdupq_n_u64(715094163);
-    let product = vaddwq_u64(shifted, addiction);
-    vmovnq_u128(product)
+    // hx / 3 as (hx * 0xAAAAAAAB) >> 33, exact for every 31-bit high word
+    let wide = vmull_u32(vmovn_u64(hx), vdup_n_u32(0xAAAAAAAB));
+    vaddq_u64(vshrq_n_u64::<33>(wide), vdupq_n_u64(715094163))
 }
 
 #[inline]
 pub unsafe fn vcbrtq_f64(x: float64x2_t) -> float64x2_t {
-    let mut v = vcbrtq_fast_f64(x);
+    // Subnormals and the top binades are scaled by powers of 8, the initial guess
+    // and x^3 in Halley step are out of range for them
+    let ax = vabsq_f64(x);
+    let tiny = vcltq_f64(ax, vdupq_n_f64(f64::MIN_POSITIVE));
+    let huge = vcgtq_f64(ax, vdupq_n_f64(CBRT_HUGE_D));
+    let scale_in = vbslq_f64(
+        tiny,
+        vdupq_n_f64(CBRT_SCALE_D),
+        vbslq_f64(huge, vdupq_n_f64(CBRT_HUGE_SCALE_D), vdupq_n_f64(1.)),
+    );
+    let scale_out = vbslq_f64(
+        tiny,
+        vdupq_n_f64(1. / CBRT_SCALE_R_D),
+        vbslq_f64(huge, vdupq_n_f64(CBRT_HUGE_SCALE_R_D), vdupq_n_f64(1.)),
+    );
+    let mut v = vmulq_f64(vcbrtq_fast_f64(vmulq_f64(x, scale_in)), scale_out);
     v = vbslq_f64(visinfq_f64(x), vdupq_n_f64(f64::INFINITY), v);
     v = vbslq_f64(visneginfq_f64(x), vdupq_n_f64(f64::NEG_INFINITY), v);
     v
@@ -53,8 +81,9 @@ pub unsafe fn vcbrtq_fast_f64(x: float64x2_t) -> float64x2_t {
     let c0 = halley_cbrt(t, x);
     let c1 = halley_cbrt(c0, x);
     let c2 = halley_cbrt(c1, x);
+    let c2 = newton_round(c2, x);
 
-    let v = vbslq_f64(vceqzq_f64(x), vdupq_n_f64(0f64), c2);
+    let v = vbslq_f64(vceqzq_f64(x), x, c2);
     v
 }
 
@@ -5,6 +5,7 @@
  * // license that can be found in the LICENSE file.
  */
 
+use crate::cbrtf::{CBRT_HUGE_F, CBRT_SCALE_F, CBRT_SCALE_R_F};
 use crate::neon::general::{visinfq_f32, visneginfq_f32, vmlafq_f32};
 use std::arch::aarch64::*;
 
@@ -41,14 +42,29 @@ pub unsafe fn vcbrtq_fast_f32(x: float32x4_t) -> float32x4_t {
 
     let c0 = halley_cbrt(t, x);
     let c1 = halley_cbrt(c0, x);
-    let v = vbslq_f32(vceqzq_f32(x), vdupq_n_f32(0f32), c1);
+    let v = vbslq_f32(vceqzq_f32(x), x, c1);
     v
 }
 
 /// Takes cube root from value *ULP 1.5*
 #[inline]
 pub unsafe fn vcbrtq_f32(x: float32x4_t) -> float32x4_t {
-    let c1 = vcbrtq_fast_f32(x);
+    // Subnormals and the top binades are scaled by powers of 8, the initial guess
+    // and x^3 in Halley step are out of range for them
+    let ax = vabsq_f32(x);
+    let tiny = vcltq_f32(ax, vdupq_n_f32(f32::MIN_POSITIVE));
+    let huge = vcgtq_f32(ax, vdupq_n_f32(CBRT_HUGE_F));
+    let scale_in = vbslq_f32(
+        tiny,
+        vdupq_n_f32(CBRT_SCALE_F),
+        vbslq_f32(huge, vdupq_n_f32(1. / CBRT_SCALE_F), vdupq_n_f32(1.)),
+    );
+    let scale_out = vbslq_f32(
+        tiny,
+        vdupq_n_f32(1. / CBRT_SCALE_R_F),
+        vbslq_f32(huge, vdupq_n_f32(CBRT_SCALE_R_F), vdupq_n_f32(1.)),
+    );
+    let c1 = vmulq_f32(vcbrtq_fast_f32(vmulq_f32(x, scale_in)), scale_out);
     let mut v = vbslq_f32(visinfq_f32(x), vdupq_n_f32(f32::INFINITY), c1);
     v = vbslq_f32(visneginfq_f32(x), vdupq_n_f32(f32::NEG_INFINITY), v);
     v
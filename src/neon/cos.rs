@@ -7,7 +7,8 @@
 
 use std::arch::aarch64::*;
 
-use crate::neon::general::{vmlafq_f64, vmulq_s64};
+use crate::generalf::rempi2_odd;
+use crate::neon::general::{vmlafq_f64, vmulq_s64, vrempiq_f64};
 use crate::sin::{
     PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
     SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
@@ -26,6 +27,7 @@ pub unsafe fn vcosq_f64(d: float64x2_t) -> float64x2_t {
 
     let mut r = vmlafq_f64(qf, vdupq_n_f64(-PI_A2 * 0.5), d);
     r = vmlafq_f64(qf, vdupq_n_f64(-PI_B2 * 0.5), r);
+    let (q, mut r) = vrempiq_f64(d, q, r, rempi2_odd);
 
     let x2 = vmulq_f64(r, r);
 
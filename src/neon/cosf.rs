@@ -6,12 +6,13 @@
  */
 
 use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
-use crate::neon::general::vmlafq_f32;
+use crate::generalf::rempi2_oddf;
+use crate::neon::general::{vmlafq_f32, vrempiq_f32};
 use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
 use std::arch::aarch64::*;
 
 #[inline]
-/// Computes cosine function with error bound *ULP 1.5*
+/// Computes cosine function with error bound *ULP 2.5*
 pub unsafe fn vcosq_f32(d: float32x4_t) -> float32x4_t {
     let half_1 = vdupq_n_f32(0.5f32);
     let q = vmlaq_s32(
@@ -28,6 +29,7 @@ pub unsafe fn vcosq_f32(d: float32x4_t) -> float32x4_t {
     r = vmlafq_f32(qf, vdupq_n_f32(-PI_B_F * 0.5), r);
     r = vmlafq_f32(qf, vdupq_n_f32(-PI_C_F * 0.5), r);
     r = vmlafq_f32(qf, vdupq_n_f32(-PI_D_F * 0.5), r);
+    let (q, mut r) = vrempiq_f32(d, q, r, rempi2_oddf);
 
     let x2 = vmulq_f32(r, r);
 
@@ -45,5 +47,6 @@ pub unsafe fn vcosq_f32(d: float32x4_t) -> float32x4_t {
     res = vmlafq_f32(res, x2, vdupq_n_f32(SIN_POLY_2_S));
     res = vmlafq_f32(res, x2, vdupq_n_f32(SIN_POLY_1_S));
     res = vmlafq_f32(res, vmulq_f32(x2, r), r);
-    res
+    // cos(±0) = 1
+    vbslq_f32(vceqzq_f32(d), vdupq_n_f32(1.), res)
 }
@@ -0,0 +1,26 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::{vexpq_f32, vmlafq_f32};
+
+/// Computes hyperbolic cosine, error bound *ULP 3.5*
+#[inline]
+pub unsafe fn vcoshq_f32(d: float32x4_t) -> float32x4_t {
+    let a = vabsq_f32(d);
+    let e = vexpq_f32(a);
+    let mut r = vmlafq_f32(vdupq_n_f32(0.5f32), e, vdivq_f32(vdupq_n_f32(0.5f32), e));
+    // exp(|d|) / 2 overflows before cosh does, split it as exp(|d|/2)^2 / 2
+    let h = vexpq_f32(vmulq_f32(a, vdupq_n_f32(0.5f32)));
+    r = vbslq_f32(
+        vcgtq_f32(a, vdupq_n_f32(88f32)),
+        vmulq_f32(vmulq_f32(h, vdupq_n_f32(0.5f32)), h),
+        r,
+    );
+    r
+}
@@ -0,0 +1,109 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::digamma::{
+    DIGAMMA_ASYMPT_POLY_1_D, DIGAMMA_ASYMPT_POLY_2_D, DIGAMMA_ASYMPT_POLY_3_D,
+    DIGAMMA_ASYMPT_POLY_4_D, DIGAMMA_ASYMPT_POLY_5_D, DIGAMMA_ASYMPT_POLY_6_D, DIGAMMA_POLY_10_D,
+    DIGAMMA_POLY_11_D, DIGAMMA_POLY_12_D, DIGAMMA_POLY_13_D, DIGAMMA_POLY_14_D, DIGAMMA_POLY_15_D,
+    DIGAMMA_POLY_16_D, DIGAMMA_POLY_17_D, DIGAMMA_POLY_18_D, DIGAMMA_POLY_19_D, DIGAMMA_POLY_1_D,
+    DIGAMMA_POLY_20_D, DIGAMMA_POLY_21_D, DIGAMMA_POLY_22_D, DIGAMMA_POLY_23_D, DIGAMMA_POLY_2_D,
+    DIGAMMA_POLY_3_D, DIGAMMA_POLY_4_D, DIGAMMA_POLY_5_D, DIGAMMA_POLY_6_D, DIGAMMA_POLY_7_D,
+    DIGAMMA_POLY_8_D, DIGAMMA_POLY_9_D, DIGAMMA_ROOT_M1_D, DIGAMMA_ROOT_M1_LO_D,
+};
+use crate::neon::sin::vsinpikq_f64;
+use crate::{visnotintegralq_f64, vlnq_f64, vmlafq_f64};
+use std::arch::aarch64::*;
+
+/// Computes digamma function, error bound *ULP 4.5* for positive arguments
+#[inline]
+pub unsafe fn vdigammaq_f64(d: float64x2_t) -> float64x2_t {
+    let reflect = vcltq_f64(d, vdupq_n_f64(0f64));
+    // ψ(d) = ψ(1 - d) - pi cot(pi d)
+    let x = vbslq_f64(reflect, vsubq_f64(vdupq_n_f64(1f64), d), d);
+    // ψ(x) = ln(x) - 1/2x - z D(z), z = 1/x^2 for x >= 10
+    let ix = vdivq_f64(vdupq_n_f64(1f64), x);
+    let z = vmulq_f64(ix, ix);
+    let mut p = vdupq_n_f64(DIGAMMA_ASYMPT_POLY_6_D);
+    p = vmlafq_f64(p, z, vdupq_n_f64(DIGAMMA_ASYMPT_POLY_5_D));
+    p = vmlafq_f64(p, z, vdupq_n_f64(DIGAMMA_ASYMPT_POLY_4_D));
+    p = vmlafq_f64(p, z, vdupq_n_f64(DIGAMMA_ASYMPT_POLY_3_D));
+    p = vmlafq_f64(p, z, vdupq_n_f64(DIGAMMA_ASYMPT_POLY_2_D));
+    p = vmlafq_f64(p, z, vdupq_n_f64(DIGAMMA_ASYMPT_POLY_1_D));
+    let big = vsubq_f64(
+        vlnq_f64(x),
+        vmlafq_f64(z, p, vmulq_f64(vdupq_n_f64(0.5f64), ix)),
+    );
+    // ψ(1 + t) = (t - t0) G(t) keeps the root t0 apart, so values around it stay accurate
+    let n = vrndmq_f64(x);
+    let t = vsubq_f64(x, n);
+    let v = vsubq_f64(t, vdupq_n_f64(0.5f64));
+    let mut u = vdupq_n_f64(DIGAMMA_POLY_23_D);
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_22_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_21_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_20_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_19_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_18_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_17_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_16_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_15_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_14_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_13_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_12_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_11_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_10_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_9_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_8_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_7_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_6_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_5_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_4_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_3_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_2_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(DIGAMMA_POLY_1_D));
+    let r = vmulq_f64(
+        vsubq_f64(
+            vsubq_f64(t, vdupq_n_f64(DIGAMMA_ROOT_M1_D)),
+            vdupq_n_f64(DIGAMMA_ROOT_M1_LO_D),
+        ),
+        u,
+    );
+    // ψ(x) = ψ(1 + t) + 1/(x - 1) + ... + 1/(x - n + 1) summed as a single fraction, below 1 it is -1/x
+    let below = vcltq_f64(x, vdupq_n_f64(1f64));
+    let mut num = vbslq_f64(below, vdupq_n_f64(-1f64), vdupq_n_f64(0f64));
+    let mut den = vbslq_f64(below, x, vdupq_n_f64(1f64));
+    for k in 1..10 {
+        let kf = vdupq_n_f64(k as f64);
+        let v = vsubq_f64(x, kf);
+        let m = vcltq_f64(kf, n);
+        num = vbslq_f64(m, vmlafq_f64(num, v, den), num);
+        den = vbslq_f64(m, vmulq_f64(den, v), den);
+    }
+    let mut ret = vbslq_f64(
+        vcltq_f64(x, vdupq_n_f64(10f64)),
+        vaddq_f64(r, vdivq_f64(num, den)),
+        big,
+    );
+    let rr = vsubq_f64(d, vrndnq_f64(d));
+    let cs = vsinpikq_f64(vsubq_f64(vdupq_n_f64(0.5f64), vabsq_f64(rr)));
+    ret = vbslq_f64(
+        reflect,
+        vsubq_f64(
+            ret,
+            vdivq_f64(
+                vmulq_f64(vdupq_n_f64(std::f64::consts::PI), cs),
+                vsinpikq_f64(rr),
+            ),
+        ),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    vbslq_f64(
+        vbicq_u64(reflect, visnotintegralq_f64(d)),
+        vdupq_n_f64(f64::NAN),
+        ret,
+    )
+}
@@ -0,0 +1,94 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::digammaf::{
+    DIGAMMA_ASYMPT_POLY_1_S, DIGAMMA_ASYMPT_POLY_2_S, DIGAMMA_ASYMPT_POLY_3_S,
+    DIGAMMA_ASYMPT_POLY_4_S, DIGAMMA_POLY_10_S, DIGAMMA_POLY_11_S, DIGAMMA_POLY_12_S,
+    DIGAMMA_POLY_1_S, DIGAMMA_POLY_2_S, DIGAMMA_POLY_3_S, DIGAMMA_POLY_4_S, DIGAMMA_POLY_5_S,
+    DIGAMMA_POLY_6_S, DIGAMMA_POLY_7_S, DIGAMMA_POLY_8_S, DIGAMMA_POLY_9_S, DIGAMMA_ROOT_M1_LO_S,
+    DIGAMMA_ROOT_M1_S,
+};
+use crate::neon::sinf::vsinpikq_f32;
+use crate::{visnotintegralq_f32, vlnq_f32, vmlafq_f32};
+use std::arch::aarch64::*;
+
+/// Computes digamma function, error bound *ULP 3.5* for positive arguments
+#[inline]
+pub unsafe fn vdigammaq_f32(d: float32x4_t) -> float32x4_t {
+    let reflect = vcltq_f32(d, vdupq_n_f32(0f32));
+    // ψ(d) = ψ(1 - d) - pi cot(pi d)
+    let x = vbslq_f32(reflect, vsubq_f32(vdupq_n_f32(1f32), d), d);
+    // ψ(x) = ln(x) - 1/2x - z D(z), z = 1/x^2 for x >= 5
+    let ix = vdivq_f32(vdupq_n_f32(1f32), x);
+    let z = vmulq_f32(ix, ix);
+    let mut p = vdupq_n_f32(DIGAMMA_ASYMPT_POLY_4_S);
+    p = vmlafq_f32(p, z, vdupq_n_f32(DIGAMMA_ASYMPT_POLY_3_S));
+    p = vmlafq_f32(p, z, vdupq_n_f32(DIGAMMA_ASYMPT_POLY_2_S));
+    p = vmlafq_f32(p, z, vdupq_n_f32(DIGAMMA_ASYMPT_POLY_1_S));
+    let big = vsubq_f32(
+        vlnq_f32(x),
+        vmlafq_f32(z, p, vmulq_f32(vdupq_n_f32(0.5f32), ix)),
+    );
+    // ψ(1 + t) = (t - t0) G(t) keeps the root t0 apart, so values around it stay accurate
+    let n = vrndmq_f32(x);
+    let t = vsubq_f32(x, n);
+    let v = vsubq_f32(t, vdupq_n_f32(0.5f32));
+    let mut u = vdupq_n_f32(DIGAMMA_POLY_12_S);
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_11_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_10_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_9_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_8_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_7_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_6_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_5_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_4_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_3_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_2_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(DIGAMMA_POLY_1_S));
+    let r = vmulq_f32(
+        vsubq_f32(
+            vsubq_f32(t, vdupq_n_f32(DIGAMMA_ROOT_M1_S)),
+            vdupq_n_f32(DIGAMMA_ROOT_M1_LO_S),
+        ),
+        u,
+    );
+    // ψ(x) = ψ(1 + t) + 1/(x - 1) + ... + 1/(x - n + 1) summed as a single fraction, below 1 it is -1/x
+    let below = vcltq_f32(x, vdupq_n_f32(1f32));
+    let mut num = vbslq_f32(below, vdupq_n_f32(-1f32), vdupq_n_f32(0f32));
+    let mut den = vbslq_f32(below, x, vdupq_n_f32(1f32));
+    for k in 1..5 {
+        let kf = vdupq_n_f32(k as f32);
+        let v = vsubq_f32(x, kf);
+        let m = vcltq_f32(kf, n);
+        num = vbslq_f32(m, vmlafq_f32(num, v, den), num);
+        den = vbslq_f32(m, vmulq_f32(den, v), den);
+    }
+    let mut ret = vbslq_f32(
+        vcltq_f32(x, vdupq_n_f32(5f32)),
+        vaddq_f32(r, vdivq_f32(num, den)),
+        big,
+    );
+    let rr = vsubq_f32(d, vrndnq_f32(d));
+    let cs = vsinpikq_f32(vsubq_f32(vdupq_n_f32(0.5f32), vabsq_f32(rr)));
+    ret = vbslq_f32(
+        reflect,
+        vsubq_f32(
+            ret,
+            vdivq_f32(
+                vmulq_f32(vdupq_n_f32(std::f32::consts::PI), cs),
+                vsinpikq_f32(rr),
+            ),
+        ),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    vbslq_f32(
+        vbicq_u32(reflect, visnotintegralq_f32(d)),
+        vdupq_n_f32(f32::NAN),
+        ret,
+    )
+}
@@ -0,0 +1,165 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::divider::{DividerI32, DividerI64, DividerU32, DividerU64};
+use crate::neon::poly128::{vmullq_s64, vmullq_u64};
+
+#[inline]
+/// Takes upper half of u32 multiplication
+unsafe fn vmulhiq_u32(a: uint32x4_t, b: uint32x4_t) -> uint32x4_t {
+    let lo = vmull_u32(vget_low_u32(a), vget_low_u32(b));
+    let hi = vmull_high_u32(a, b);
+    vuzp2q_u32(vreinterpretq_u32_u64(lo), vreinterpretq_u32_u64(hi))
+}
+
+#[inline]
+/// Takes upper half of i32 multiplication
+unsafe fn vmulhiq_s32(a: int32x4_t, b: int32x4_t) -> int32x4_t {
+    let lo = vmull_s32(vget_low_s32(a), vget_low_s32(b));
+    let hi = vmull_high_s32(a, b);
+    vuzp2q_s32(vreinterpretq_s32_s64(lo), vreinterpretq_s32_s64(hi))
+}
+
+#[inline]
+/// Divides u32 by precomputed divider
+pub unsafe fn vdivq_u32(a: uint32x4_t, divider: DividerU32) -> uint32x4_t {
+    let shift = vdupq_n_s32(-(divider.shift as i32));
+    if divider.magic == 0 {
+        return vshlq_u32(a, shift);
+    }
+    let q = vmulhiq_u32(a, vdupq_n_u32(divider.magic));
+    if divider.add {
+        let t = vaddq_u32(vshrq_n_u32::<1>(vsubq_u32(a, q)), q);
+        vshlq_u32(t, shift)
+    } else {
+        vshlq_u32(q, shift)
+    }
+}
+
+#[inline]
+/// Divides i32 by precomputed divider, rounds towards zero
+pub unsafe fn vdivq_s32(a: int32x4_t, divider: DividerI32) -> int32x4_t {
+    let shift = vdupq_n_s32(-(divider.shift as i32));
+    let sign = vdupq_n_s32(if divider.negative { -1 } else { 0 });
+    if divider.magic == 0 {
+        let mask = vdupq_n_s32(((1u32 << divider.shift) - 1) as i32);
+        let rounding = vandq_s32(vshrq_n_s32::<31>(a), mask);
+        let q = vshlq_s32(vaddq_s32(a, rounding), shift);
+        return vsubq_s32(veorq_s32(q, sign), sign);
+    }
+    let mut q = vmulhiq_s32(a, vdupq_n_s32(divider.magic));
+    if divider.add {
+        q = vaddq_s32(q, vsubq_s32(veorq_s32(a, sign), sign));
+    }
+    q = vshlq_s32(q, shift);
+    vaddq_s32(
+        q,
+        vreinterpretq_s32_u32(vshrq_n_u32::<31>(vreinterpretq_u32_s32(q))),
+    )
+}
+
+#[inline]
+/// Divides u64 by precomputed divider
+pub unsafe fn vdivq_u64(a: uint64x2_t, divider: DividerU64) -> uint64x2_t {
+    let shift = vdupq_n_s64(-(divider.shift as i64));
+    if divider.magic == 0 {
+        return vshlq_u64(a, shift);
+    }
+    let q = vmullq_u64(a, vdupq_n_u64(divider.magic)).1;
+    if divider.add {
+        let t = vaddq_u64(vshrq_n_u64::<1>(vsubq_u64(a, q)), q);
+        vshlq_u64(t, shift)
+    } else {
+        vshlq_u64(q, shift)
+    }
+}
+
+#[inline]
+/// Divides i64 by precomputed divider, rounds towards zero
+pub unsafe fn vdivq_s64(a: int64x2_t, divider: DividerI64) -> int64x2_t {
+    let shift = vdupq_n_s64(-(divider.shift as i64));
+    let sign = vdupq_n_s64(if divider.negative { -1 } else { 0 });
+    if divider.magic == 0 {
+        let mask = vdupq_n_s64(((1u64 << divider.shift) - 1) as i64);
+        let rounding = vandq_s64(vshrq_n_s64::<63>(a), mask);
+        let q = vshlq_s64(vaddq_s64(a, rounding), shift);
+        return vsubq_s64(veorq_s64(q, sign), sign);
+    }
+    let mut q = vmullq_s64(a, vdupq_n_s64(divider.magic)).1;
+    if divider.add {
+        q = vaddq_s64(q, vsubq_s64(veorq_s64(a, sign), sign));
+    }
+    q = vshlq_s64(q, shift);
+    vaddq_s64(
+        q,
+        vreinterpretq_s64_u64(vshrq_n_u64::<63>(vreinterpretq_u64_s64(q))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIVISORS: [u64; 10] = [
+        1,
+        3,
+        7,
+        64,
+        641,
+        0x8000_0001,
+        0x1_0000_0003,
+        i64::MAX as u64,
+        1 << 63,
+        u64::MAX,
+    ];
+
+    const NUMERATORS: [u64; 8] = [
+        0,
+        6,
+        0x7fff_ffff,
+        0xffff_ffff,
+        0x1234_5678_9abc_def0,
+        i64::MIN as u64,
+        -7i64 as u64,
+        u64::MAX,
+    ];
+
+    #[test]
+    fn test_div() {
+        unsafe {
+            for &d in DIVISORS.iter() {
+                for chunk in NUMERATORS.chunks(2) {
+                    let v = vcombine_u64(vdup_n_u64(chunk[0]), vdup_n_u64(chunk[1]));
+                    let q = vdivq_u64(v, DividerU64::new(d));
+                    assert_eq!(vgetq_lane_u64::<0>(q), chunk[0] / d);
+                    assert_eq!(vgetq_lane_u64::<1>(q), chunk[1] / d);
+                    for sd in [d as i64, (d as i64).wrapping_neg()] {
+                        let q = vdivq_s64(vreinterpretq_s64_u64(v), DividerI64::new(sd));
+                        let control = (chunk[0] as i64).wrapping_div(sd);
+                        assert_eq!(vgetq_lane_s64::<0>(q), control);
+                        let control = (chunk[1] as i64).wrapping_div(sd);
+                        assert_eq!(vgetq_lane_s64::<1>(q), control);
+                    }
+
+                    let d = (d as u32).max(1);
+                    let v = vreinterpretq_u32_u64(v);
+                    let n = [chunk[0] as u32, (chunk[0] >> 32) as u32];
+                    let q = vdivq_u32(v, DividerU32::new(d));
+                    assert_eq!(vgetq_lane_u32::<0>(q), n[0] / d);
+                    assert_eq!(vgetq_lane_u32::<1>(q), n[1] / d);
+                    for sd in [d as i32, (d as i32).wrapping_neg()] {
+                        let q = vdivq_s32(vreinterpretq_s32_u32(v), DividerI32::new(sd));
+                        assert_eq!(vgetq_lane_s32::<0>(q), (n[0] as i32).wrapping_div(sd));
+                        assert_eq!(vgetq_lane_s32::<1>(q), (n[1] as i32).wrapping_div(sd));
+                    }
+                }
+            }
+        }
+    }
+}
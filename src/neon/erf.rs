@@ -0,0 +1,258 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::erf::{
+    ERFC_A_POLY_10_D, ERFC_A_POLY_11_D, ERFC_A_POLY_12_D, ERFC_A_POLY_13_D, ERFC_A_POLY_14_D,
+    ERFC_A_POLY_15_D, ERFC_A_POLY_1_D, ERFC_A_POLY_2_D, ERFC_A_POLY_3_D, ERFC_A_POLY_4_D,
+    ERFC_A_POLY_5_D, ERFC_A_POLY_6_D, ERFC_A_POLY_7_D, ERFC_A_POLY_8_D, ERFC_A_POLY_9_D,
+    ERFC_A_SHIFT_D, ERFC_B_POLY_10_D, ERFC_B_POLY_11_D, ERFC_B_POLY_12_D, ERFC_B_POLY_13_D,
+    ERFC_B_POLY_14_D, ERFC_B_POLY_15_D, ERFC_B_POLY_1_D, ERFC_B_POLY_2_D, ERFC_B_POLY_3_D,
+    ERFC_B_POLY_4_D, ERFC_B_POLY_5_D, ERFC_B_POLY_6_D, ERFC_B_POLY_7_D, ERFC_B_POLY_8_D,
+    ERFC_B_POLY_9_D, ERFC_B_SHIFT_D, ERFC_C_POLY_10_D, ERFC_C_POLY_11_D, ERFC_C_POLY_12_D,
+    ERFC_C_POLY_13_D, ERFC_C_POLY_14_D, ERFC_C_POLY_15_D, ERFC_C_POLY_1_D, ERFC_C_POLY_2_D,
+    ERFC_C_POLY_3_D, ERFC_C_POLY_4_D, ERFC_C_POLY_5_D, ERFC_C_POLY_6_D, ERFC_C_POLY_7_D,
+    ERFC_C_POLY_8_D, ERFC_C_POLY_9_D, ERFC_C_SHIFT_D, ERFC_D_POLY_10_D, ERFC_D_POLY_11_D,
+    ERFC_D_POLY_12_D, ERFC_D_POLY_13_D, ERFC_D_POLY_14_D, ERFC_D_POLY_15_D, ERFC_D_POLY_1_D,
+    ERFC_D_POLY_2_D, ERFC_D_POLY_3_D, ERFC_D_POLY_4_D, ERFC_D_POLY_5_D, ERFC_D_POLY_6_D,
+    ERFC_D_POLY_7_D, ERFC_D_POLY_8_D, ERFC_D_POLY_9_D, ERFC_D_SHIFT_D, ERF_POLY_10_D,
+    ERF_POLY_11_D, ERF_POLY_1_D, ERF_POLY_2_D, ERF_POLY_3_D, ERF_POLY_4_D, ERF_POLY_5_D,
+    ERF_POLY_6_D, ERF_POLY_7_D, ERF_POLY_8_D, ERF_POLY_9_D,
+};
+use crate::exp::{L2_L, L2_U, R_LN2};
+use crate::neon::exp::vexpm1kq_f64;
+use crate::{vcopysignq_f64, vldexp2kq_f64, vmlafq_f64};
+
+/// Computes `erf(x) - x` for |x| < 0.84375
+#[inline]
+pub(crate) unsafe fn verfkq_f64(x: float64x2_t) -> float64x2_t {
+    let t = vmulq_f64(x, x);
+    let mut u = vdupq_n_f64(ERF_POLY_11_D);
+    u = vmlafq_f64(u, t, vdupq_n_f64(ERF_POLY_10_D));
+    u = vmlafq_f64(u, t, vdupq_n_f64(ERF_POLY_9_D));
+    u = vmlafq_f64(u, t, vdupq_n_f64(ERF_POLY_8_D));
+    u = vmlafq_f64(u, t, vdupq_n_f64(ERF_POLY_7_D));
+    u = vmlafq_f64(u, t, vdupq_n_f64(ERF_POLY_6_D));
+    u = vmlafq_f64(u, t, vdupq_n_f64(ERF_POLY_5_D));
+    u = vmlafq_f64(u, t, vdupq_n_f64(ERF_POLY_4_D));
+    u = vmlafq_f64(u, t, vdupq_n_f64(ERF_POLY_3_D));
+    u = vmlafq_f64(u, t, vdupq_n_f64(ERF_POLY_2_D));
+    u = vmlafq_f64(u, t, vdupq_n_f64(ERF_POLY_1_D));
+    vmulq_f64(x, u)
+}
+
+/// Computes erfc(a) for a >= 0.84375 as `exp(-a^2 + P(1/a)) / a`
+#[inline]
+pub(crate) unsafe fn verfckq_f64(a: float64x2_t) -> float64x2_t {
+    let t = vdivq_f64(vdupq_n_f64(1f64), a);
+    let m0 = vcltq_f64(a, vdupq_n_f64(1.5f64));
+    let m1 = vcltq_f64(a, vdupq_n_f64(3.0f64));
+    let m2 = vcltq_f64(a, vdupq_n_f64(8.25f64));
+    let pick = |c0: f64, c1: f64, c2: f64, c3: f64| {
+        vbslq_f64(
+            m0,
+            vdupq_n_f64(c0),
+            vbslq_f64(
+                m1,
+                vdupq_n_f64(c1),
+                vbslq_f64(m2, vdupq_n_f64(c2), vdupq_n_f64(c3)),
+            ),
+        )
+    };
+    let v = vsubq_f64(
+        t,
+        pick(
+            ERFC_A_SHIFT_D,
+            ERFC_B_SHIFT_D,
+            ERFC_C_SHIFT_D,
+            ERFC_D_SHIFT_D,
+        ),
+    );
+    let mut u = pick(
+        ERFC_A_POLY_15_D,
+        ERFC_B_POLY_15_D,
+        ERFC_C_POLY_15_D,
+        ERFC_D_POLY_15_D,
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_14_D,
+            ERFC_B_POLY_14_D,
+            ERFC_C_POLY_14_D,
+            ERFC_D_POLY_14_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_13_D,
+            ERFC_B_POLY_13_D,
+            ERFC_C_POLY_13_D,
+            ERFC_D_POLY_13_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_12_D,
+            ERFC_B_POLY_12_D,
+            ERFC_C_POLY_12_D,
+            ERFC_D_POLY_12_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_11_D,
+            ERFC_B_POLY_11_D,
+            ERFC_C_POLY_11_D,
+            ERFC_D_POLY_11_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_10_D,
+            ERFC_B_POLY_10_D,
+            ERFC_C_POLY_10_D,
+            ERFC_D_POLY_10_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_9_D,
+            ERFC_B_POLY_9_D,
+            ERFC_C_POLY_9_D,
+            ERFC_D_POLY_9_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_8_D,
+            ERFC_B_POLY_8_D,
+            ERFC_C_POLY_8_D,
+            ERFC_D_POLY_8_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_7_D,
+            ERFC_B_POLY_7_D,
+            ERFC_C_POLY_7_D,
+            ERFC_D_POLY_7_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_6_D,
+            ERFC_B_POLY_6_D,
+            ERFC_C_POLY_6_D,
+            ERFC_D_POLY_6_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_5_D,
+            ERFC_B_POLY_5_D,
+            ERFC_C_POLY_5_D,
+            ERFC_D_POLY_5_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_4_D,
+            ERFC_B_POLY_4_D,
+            ERFC_C_POLY_4_D,
+            ERFC_D_POLY_4_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_3_D,
+            ERFC_B_POLY_3_D,
+            ERFC_C_POLY_3_D,
+            ERFC_D_POLY_3_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_2_D,
+            ERFC_B_POLY_2_D,
+            ERFC_C_POLY_2_D,
+            ERFC_D_POLY_2_D,
+        ),
+    );
+    u = vmlafq_f64(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_1_D,
+            ERFC_B_POLY_1_D,
+            ERFC_C_POLY_1_D,
+            ERFC_D_POLY_1_D,
+        ),
+    );
+    // -a^2 = -z^2 + (z - a)(z + a), z keeps half of the mantissa so z^2 is exact
+    let z = vreinterpretq_f64_u64(vandq_u64(
+        vreinterpretq_u64_f64(a),
+        vdupq_n_u64(0xffff_ffff_0000_0000),
+    ));
+    let hi = vnegq_f64(vmulq_f64(z, z));
+    let lo = vmlafq_f64(vsubq_f64(z, a), vaddq_f64(z, a), u);
+    let q = vcvtaq_s64_f64(vmulq_f64(vaddq_f64(hi, lo), vdupq_n_f64(R_LN2)));
+    let qf = vcvtq_f64_s64(q);
+    // hi is reduced exactly, lo joins after the reduction
+    let mut r = vmlafq_f64(qf, vdupq_n_f64(-L2_U), hi);
+    r = vmlafq_f64(qf, vdupq_n_f64(-L2_L), vaddq_f64(r, lo));
+    let e = vldexp2kq_f64(
+        vdivq_f64(vaddq_f64(vexpm1kq_f64(r), vdupq_n_f64(1f64)), a),
+        q,
+    );
+    // erfc underflows here, also covers a == Inf
+    vbslq_f64(vcgtq_f64(a, vdupq_n_f64(27.3f64)), vdupq_n_f64(0f64), e)
+}
+
+/// Computes error function, error bound *ULP 1.5*
+#[inline]
+pub unsafe fn verfq_f64(d: float64x2_t) -> float64x2_t {
+    let a = vabsq_f64(d);
+    let mut r = vcopysignq_f64(vsubq_f64(vdupq_n_f64(1f64), verfckq_f64(a)), d);
+    r = vbslq_f64(
+        vcltq_f64(a, vdupq_n_f64(0.84375f64)),
+        vaddq_f64(d, verfkq_f64(d)),
+        r,
+    );
+    r = vbslq_f64(
+        vcgtq_f64(a, vdupq_n_f64(6f64)),
+        vcopysignq_f64(vdupq_n_f64(1f64), d),
+        r,
+    );
+    r
+}
@@ -0,0 +1,34 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::neon::erf::{verfckq_f64, verfkq_f64};
+
+/// Computes complementary error function, error bound *ULP 3.5*
+#[inline]
+pub unsafe fn verfcq_f64(d: float64x2_t) -> float64x2_t {
+    let a = vabsq_f64(d);
+    let u = verfkq_f64(d);
+    // Past 0.25 recentering on 0.5 keeps the subtraction from cancelling
+    let mut s = vsubq_f64(
+        vdupq_n_f64(0.5f64),
+        vaddq_f64(vsubq_f64(d, vdupq_n_f64(0.5f64)), u),
+    );
+    s = vbslq_f64(
+        vcltq_f64(d, vdupq_n_f64(0.25f64)),
+        vsubq_f64(vdupq_n_f64(1f64), vaddq_f64(d, u)),
+        s,
+    );
+    let e = verfckq_f64(a);
+    let r = vbslq_f64(
+        vcltq_f64(d, vdupq_n_f64(0f64)),
+        vsubq_f64(vdupq_n_f64(2f64), e),
+        e,
+    );
+    vbslq_f64(vcltq_f64(a, vdupq_n_f64(0.84375f64)), s, r)
+}
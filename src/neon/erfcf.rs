@@ -0,0 +1,34 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::neon::erff::{verfckq_f32, verfkq_f32};
+
+/// Computes complementary error function, error bound *ULP 3.5*
+#[inline]
+pub unsafe fn verfcq_f32(d: float32x4_t) -> float32x4_t {
+    let a = vabsq_f32(d);
+    let u = verfkq_f32(d);
+    // Past 0.25 recentering on 0.5 keeps the subtraction from cancelling
+    let mut s = vsubq_f32(
+        vdupq_n_f32(0.5f32),
+        vaddq_f32(vsubq_f32(d, vdupq_n_f32(0.5f32)), u),
+    );
+    s = vbslq_f32(
+        vcltq_f32(d, vdupq_n_f32(0.25f32)),
+        vsubq_f32(vdupq_n_f32(1f32), vaddq_f32(d, u)),
+        s,
+    );
+    let e = verfckq_f32(a);
+    let r = vbslq_f32(
+        vcltq_f32(d, vdupq_n_f32(0f32)),
+        vsubq_f32(vdupq_n_f32(2f32), e),
+        e,
+    );
+    vbslq_f32(vcltq_f32(a, vdupq_n_f32(0.84375f32)), s, r)
+}
@@ -0,0 +1,117 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::erff::{
+    ERFC_A_POLY_1_S, ERFC_A_POLY_2_S, ERFC_A_POLY_3_S, ERFC_A_POLY_4_S, ERFC_A_POLY_5_S,
+    ERFC_A_POLY_6_S, ERFC_A_POLY_7_S, ERFC_A_SHIFT_S, ERFC_B_POLY_1_S, ERFC_B_POLY_2_S,
+    ERFC_B_POLY_3_S, ERFC_B_POLY_4_S, ERFC_B_POLY_5_S, ERFC_B_POLY_6_S, ERFC_B_POLY_7_S,
+    ERFC_B_SHIFT_S, ERFC_C_POLY_1_S, ERFC_C_POLY_2_S, ERFC_C_POLY_3_S, ERFC_C_POLY_4_S,
+    ERFC_C_POLY_5_S, ERFC_C_POLY_6_S, ERFC_C_POLY_7_S, ERFC_C_SHIFT_S, ERF_POLY_1_S, ERF_POLY_2_S,
+    ERF_POLY_3_S, ERF_POLY_4_S, ERF_POLY_5_S, ERF_POLY_6_S, ERF_POLY_7_S,
+};
+use crate::expf::{L2L_F, L2U_F, R_LN2_F};
+use crate::neon::expf::vexpm1kq_f32;
+use crate::{vcopysignq_f32, vldexp2kq_f32, vmlafq_f32};
+
+/// Computes `erf(x) - x` for |x| < 0.84375
+#[inline]
+pub(crate) unsafe fn verfkq_f32(x: float32x4_t) -> float32x4_t {
+    let t = vmulq_f32(x, x);
+    let mut u = vdupq_n_f32(ERF_POLY_7_S);
+    u = vmlafq_f32(u, t, vdupq_n_f32(ERF_POLY_6_S));
+    u = vmlafq_f32(u, t, vdupq_n_f32(ERF_POLY_5_S));
+    u = vmlafq_f32(u, t, vdupq_n_f32(ERF_POLY_4_S));
+    u = vmlafq_f32(u, t, vdupq_n_f32(ERF_POLY_3_S));
+    u = vmlafq_f32(u, t, vdupq_n_f32(ERF_POLY_2_S));
+    u = vmlafq_f32(u, t, vdupq_n_f32(ERF_POLY_1_S));
+    vmulq_f32(x, u)
+}
+
+/// Computes erfc(a) for a >= 0.84375 as `exp(-a^2 + P(1/a)) / a`
+#[inline]
+pub(crate) unsafe fn verfckq_f32(a: float32x4_t) -> float32x4_t {
+    let t = vdivq_f32(vdupq_n_f32(1f32), a);
+    let m0 = vcltq_f32(a, vdupq_n_f32(1.4375f32));
+    let m1 = vcltq_f32(a, vdupq_n_f32(2.625f32));
+    let pick = |c0: f32, c1: f32, c2: f32| {
+        vbslq_f32(
+            m0,
+            vdupq_n_f32(c0),
+            vbslq_f32(m1, vdupq_n_f32(c1), vdupq_n_f32(c2)),
+        )
+    };
+    let v = vsubq_f32(t, pick(ERFC_A_SHIFT_S, ERFC_B_SHIFT_S, ERFC_C_SHIFT_S));
+    let mut u = pick(ERFC_A_POLY_7_S, ERFC_B_POLY_7_S, ERFC_C_POLY_7_S);
+    u = vmlafq_f32(
+        u,
+        v,
+        pick(ERFC_A_POLY_6_S, ERFC_B_POLY_6_S, ERFC_C_POLY_6_S),
+    );
+    u = vmlafq_f32(
+        u,
+        v,
+        pick(ERFC_A_POLY_5_S, ERFC_B_POLY_5_S, ERFC_C_POLY_5_S),
+    );
+    u = vmlafq_f32(
+        u,
+        v,
+        pick(ERFC_A_POLY_4_S, ERFC_B_POLY_4_S, ERFC_C_POLY_4_S),
+    );
+    u = vmlafq_f32(
+        u,
+        v,
+        pick(ERFC_A_POLY_3_S, ERFC_B_POLY_3_S, ERFC_C_POLY_3_S),
+    );
+    u = vmlafq_f32(
+        u,
+        v,
+        pick(ERFC_A_POLY_2_S, ERFC_B_POLY_2_S, ERFC_C_POLY_2_S),
+    );
+    u = vmlafq_f32(
+        u,
+        v,
+        pick(ERFC_A_POLY_1_S, ERFC_B_POLY_1_S, ERFC_C_POLY_1_S),
+    );
+    // -a^2 = -z^2 + (z - a)(z + a), z keeps half of the mantissa so z^2 is exact
+    let z = vreinterpretq_f32_u32(vandq_u32(
+        vreinterpretq_u32_f32(a),
+        vdupq_n_u32(0xffff_f000),
+    ));
+    let hi = vnegq_f32(vmulq_f32(z, z));
+    let lo = vmlafq_f32(vsubq_f32(z, a), vaddq_f32(z, a), u);
+    let q = vcvtaq_s32_f32(vmulq_f32(vaddq_f32(hi, lo), vdupq_n_f32(R_LN2_F)));
+    let qf = vcvtq_f32_s32(q);
+    // hi is reduced exactly, lo joins after the reduction
+    let mut r = vmlafq_f32(qf, vdupq_n_f32(-L2U_F), hi);
+    r = vmlafq_f32(qf, vdupq_n_f32(-L2L_F), vaddq_f32(r, lo));
+    let e = vldexp2kq_f32(
+        vdivq_f32(vaddq_f32(vexpm1kq_f32(r), vdupq_n_f32(1f32)), a),
+        q,
+    );
+    // erfc underflows here, also covers a == Inf
+    vbslq_f32(vcgtq_f32(a, vdupq_n_f32(10.1f32)), vdupq_n_f32(0f32), e)
+}
+
+/// Computes error function, error bound *ULP 2.0*
+#[inline]
+pub unsafe fn verfq_f32(d: float32x4_t) -> float32x4_t {
+    let a = vabsq_f32(d);
+    let mut r = vcopysignq_f32(vsubq_f32(vdupq_n_f32(1f32), verfckq_f32(a)), d);
+    r = vbslq_f32(
+        vcltq_f32(a, vdupq_n_f32(0.84375f32)),
+        vaddq_f32(d, verfkq_f32(d)),
+        r,
+    );
+    r = vbslq_f32(
+        vcgtq_f32(a, vdupq_n_f32(4f32)),
+        vcopysignq_f32(vdupq_n_f32(1f32), d),
+        r,
+    );
+    r
+}
@@ -0,0 +1,146 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::erfinv::{
+    ERFINV_A_POLY_10_D, ERFINV_A_POLY_11_D, ERFINV_A_POLY_1_D, ERFINV_A_POLY_2_D,
+    ERFINV_A_POLY_3_D, ERFINV_A_POLY_4_D, ERFINV_A_POLY_5_D, ERFINV_A_POLY_6_D, ERFINV_A_POLY_7_D,
+    ERFINV_A_POLY_8_D, ERFINV_A_POLY_9_D, ERFINV_A_SHIFT_D, ERFINV_B_POLY_1_D, ERFINV_B_POLY_2_D,
+    ERFINV_B_POLY_3_D, ERFINV_B_POLY_4_D, ERFINV_B_POLY_5_D, ERFINV_B_POLY_6_D, ERFINV_B_POLY_7_D,
+    ERFINV_B_POLY_8_D, ERFINV_B_POLY_9_D, ERFINV_B_SHIFT_D, ERFINV_C_POLY_1_D, ERFINV_C_POLY_2_D,
+    ERFINV_C_POLY_3_D, ERFINV_C_POLY_4_D, ERFINV_C_POLY_5_D, ERFINV_C_POLY_6_D, ERFINV_C_POLY_7_D,
+    ERFINV_C_POLY_8_D, ERFINV_C_POLY_9_D, ERFINV_C_SHIFT_D, SQRT_PI_2_D,
+};
+use crate::neon::erf::{verfckq_f64, verfkq_f64};
+use crate::{vcopysignq_f64, vexpq_f64, vlnq_f64, vmlafq_f64};
+
+/// Computes inverse error function, error bound *ULP 1.5*
+#[inline]
+pub unsafe fn verfinvq_f64(d: float64x2_t) -> float64x2_t {
+    let a = vabsq_f64(d);
+    let w = vnegq_f64(vlnq_f64(vmulq_f64(
+        vsubq_f64(vdupq_n_f64(1f64), a),
+        vaddq_f64(vdupq_n_f64(1f64), a),
+    )));
+    let v = vsubq_f64(w, vdupq_n_f64(ERFINV_A_SHIFT_D));
+    let mut u = vdupq_n_f64(ERFINV_A_POLY_11_D);
+    u = vmlafq_f64(u, v, vdupq_n_f64(ERFINV_A_POLY_10_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(ERFINV_A_POLY_9_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(ERFINV_A_POLY_8_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(ERFINV_A_POLY_7_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(ERFINV_A_POLY_6_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(ERFINV_A_POLY_5_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(ERFINV_A_POLY_4_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(ERFINV_A_POLY_3_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(ERFINV_A_POLY_2_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(ERFINV_A_POLY_1_D));
+    let s = vsqrtq_f64(w);
+    let mt = vcltq_f64(s, vdupq_n_f64(4f64));
+    let v = vsubq_f64(
+        s,
+        vbslq_f64(
+            mt,
+            vdupq_n_f64(ERFINV_B_SHIFT_D),
+            vdupq_n_f64(ERFINV_C_SHIFT_D),
+        ),
+    );
+    let mut p = vbslq_f64(
+        mt,
+        vdupq_n_f64(ERFINV_B_POLY_9_D),
+        vdupq_n_f64(ERFINV_C_POLY_9_D),
+    );
+    p = vmlafq_f64(
+        p,
+        v,
+        vbslq_f64(
+            mt,
+            vdupq_n_f64(ERFINV_B_POLY_8_D),
+            vdupq_n_f64(ERFINV_C_POLY_8_D),
+        ),
+    );
+    p = vmlafq_f64(
+        p,
+        v,
+        vbslq_f64(
+            mt,
+            vdupq_n_f64(ERFINV_B_POLY_7_D),
+            vdupq_n_f64(ERFINV_C_POLY_7_D),
+        ),
+    );
+    p = vmlafq_f64(
+        p,
+        v,
+        vbslq_f64(
+            mt,
+            vdupq_n_f64(ERFINV_B_POLY_6_D),
+            vdupq_n_f64(ERFINV_C_POLY_6_D),
+        ),
+    );
+    p = vmlafq_f64(
+        p,
+        v,
+        vbslq_f64(
+            mt,
+            vdupq_n_f64(ERFINV_B_POLY_5_D),
+            vdupq_n_f64(ERFINV_C_POLY_5_D),
+        ),
+    );
+    p = vmlafq_f64(
+        p,
+        v,
+        vbslq_f64(
+            mt,
+            vdupq_n_f64(ERFINV_B_POLY_4_D),
+            vdupq_n_f64(ERFINV_C_POLY_4_D),
+        ),
+    );
+    p = vmlafq_f64(
+        p,
+        v,
+        vbslq_f64(
+            mt,
+            vdupq_n_f64(ERFINV_B_POLY_3_D),
+            vdupq_n_f64(ERFINV_C_POLY_3_D),
+        ),
+    );
+    p = vmlafq_f64(
+        p,
+        v,
+        vbslq_f64(
+            mt,
+            vdupq_n_f64(ERFINV_B_POLY_2_D),
+            vdupq_n_f64(ERFINV_C_POLY_2_D),
+        ),
+    );
+    p = vmlafq_f64(
+        p,
+        v,
+        vbslq_f64(
+            mt,
+            vdupq_n_f64(ERFINV_B_POLY_1_D),
+            vdupq_n_f64(ERFINV_C_POLY_1_D),
+        ),
+    );
+    let mut x = vbslq_f64(vcltq_f64(w, vdupq_n_f64(5f64)), vmulq_f64(a, u), p);
+    // One Halley step on erf(x) = a, in the tail the residual comes from erfc where 1 - a is exact
+    let f0 = vaddq_f64(vsubq_f64(x, a), verfkq_f64(x));
+    let f1 = vsubq_f64(vsubq_f64(vdupq_n_f64(1f64), a), verfckq_f64(x));
+    let f = vbslq_f64(vcltq_f64(x, vdupq_n_f64(0.84375f64)), f0, f1);
+    let e = vmulq_f64(
+        vmulq_f64(f, vdupq_n_f64(SQRT_PI_2_D)),
+        vexpq_f64(vmulq_f64(x, x)),
+    );
+    x = vsubq_f64(x, vdivq_f64(e, vmlafq_f64(x, e, vdupq_n_f64(1f64))));
+    // |d| == 1 -> Inf, |d| > 1 and Nan propagate through ln
+    x = vbslq_f64(
+        vceqq_f64(a, vdupq_n_f64(1f64)),
+        vdupq_n_f64(f64::INFINITY),
+        x,
+    );
+    vcopysignq_f64(x, d)
+}
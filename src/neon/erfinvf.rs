@@ -0,0 +1,57 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::erfinvf::{
+    ERFINV_A_POLY_10_S, ERFINV_A_POLY_11_S, ERFINV_A_POLY_1_S, ERFINV_A_POLY_2_S,
+    ERFINV_A_POLY_3_S, ERFINV_A_POLY_4_S, ERFINV_A_POLY_5_S, ERFINV_A_POLY_6_S, ERFINV_A_POLY_7_S,
+    ERFINV_A_POLY_8_S, ERFINV_A_POLY_9_S, ERFINV_A_SHIFT_S, ERFINV_B_POLY_1_S, ERFINV_B_POLY_2_S,
+    ERFINV_B_POLY_3_S, ERFINV_B_POLY_4_S, ERFINV_B_POLY_5_S, ERFINV_B_POLY_6_S, ERFINV_B_POLY_7_S,
+    ERFINV_B_POLY_8_S, ERFINV_B_POLY_9_S, ERFINV_B_SHIFT_S,
+};
+use crate::{vcopysignq_f32, vlnq_f32, vmlafq_f32};
+
+/// Computes inverse error function, error bound *ULP 4.0*
+#[inline]
+pub unsafe fn verfinvq_f32(d: float32x4_t) -> float32x4_t {
+    let a = vabsq_f32(d);
+    let w = vnegq_f32(vlnq_f32(vmulq_f32(
+        vsubq_f32(vdupq_n_f32(1f32), a),
+        vaddq_f32(vdupq_n_f32(1f32), a),
+    )));
+    let v = vsubq_f32(w, vdupq_n_f32(ERFINV_A_SHIFT_S));
+    let mut u = vdupq_n_f32(ERFINV_A_POLY_11_S);
+    u = vmlafq_f32(u, v, vdupq_n_f32(ERFINV_A_POLY_10_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(ERFINV_A_POLY_9_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(ERFINV_A_POLY_8_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(ERFINV_A_POLY_7_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(ERFINV_A_POLY_6_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(ERFINV_A_POLY_5_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(ERFINV_A_POLY_4_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(ERFINV_A_POLY_3_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(ERFINV_A_POLY_2_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(ERFINV_A_POLY_1_S));
+    let v = vsubq_f32(vsqrtq_f32(w), vdupq_n_f32(ERFINV_B_SHIFT_S));
+    let mut p = vdupq_n_f32(ERFINV_B_POLY_9_S);
+    p = vmlafq_f32(p, v, vdupq_n_f32(ERFINV_B_POLY_8_S));
+    p = vmlafq_f32(p, v, vdupq_n_f32(ERFINV_B_POLY_7_S));
+    p = vmlafq_f32(p, v, vdupq_n_f32(ERFINV_B_POLY_6_S));
+    p = vmlafq_f32(p, v, vdupq_n_f32(ERFINV_B_POLY_5_S));
+    p = vmlafq_f32(p, v, vdupq_n_f32(ERFINV_B_POLY_4_S));
+    p = vmlafq_f32(p, v, vdupq_n_f32(ERFINV_B_POLY_3_S));
+    p = vmlafq_f32(p, v, vdupq_n_f32(ERFINV_B_POLY_2_S));
+    p = vmlafq_f32(p, v, vdupq_n_f32(ERFINV_B_POLY_1_S));
+    let mut x = vbslq_f32(vcltq_f32(w, vdupq_n_f32(5f32)), vmulq_f32(a, u), p);
+    // |d| == 1 -> Inf, |d| > 1 and Nan propagate through ln
+    x = vbslq_f32(
+        vceqq_f32(a, vdupq_n_f32(1f32)),
+        vdupq_n_f32(f32::INFINITY),
+        x,
+    );
+    vcopysignq_f32(x, d)
+}
@@ -0,0 +1,30 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::exp::{LG2_L, LG2_U, LN10_HI, LN10_LO};
+use crate::neon::exp::vexpm1kq_f64;
+use crate::neon::general::{vldexp2kq_f64, vmlafq_f64};
+
+/// Computes 10^x *ULP 2.0*
+#[inline]
+pub unsafe fn vexp10q_f64(d: float64x2_t) -> float64x2_t {
+    let q = vcvtaq_s64_f64(vmulq_f64(d, vdupq_n_f64(std::f64::consts::LOG2_10)));
+    let qf = vcvtq_f64_s64(q);
+    let mut s = vmlafq_f64(qf, vdupq_n_f64(-LG2_U), d);
+    s = vmlafq_f64(qf, vdupq_n_f64(-LG2_L), s);
+    let r = vmlafq_f64(s, vdupq_n_f64(LN10_HI), vmulq_f64(s, vdupq_n_f64(LN10_LO)));
+    let mut r = vldexp2kq_f64(vaddq_f64(vexpm1kq_f64(r), vdupq_n_f64(1f64)), q);
+    r = vbslq_f64(vcltq_f64(d, vdupq_n_f64(-323.61f64)), vdupq_n_f64(0f64), r);
+    r = vbslq_f64(
+        vcgtq_f64(d, vdupq_n_f64(308.26f64)),
+        vdupq_n_f64(f64::INFINITY),
+        r,
+    );
+    r
+}
@@ -0,0 +1,34 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::expf::{LG2_L_F, LG2_U_F, LN10_HI_F, LN10_LO_F};
+use crate::neon::expf::vexpm1kq_f32;
+use crate::neon::general::{vldexp2kq_f32, vmlafq_f32};
+
+/// Computes 10^x *ULP 2.0*
+#[inline]
+pub unsafe fn vexp10q_f32(d: float32x4_t) -> float32x4_t {
+    let q = vcvtaq_s32_f32(vmulq_f32(d, vdupq_n_f32(std::f32::consts::LOG2_10)));
+    let qf = vcvtq_f32_s32(q);
+    let mut s = vmlafq_f32(qf, vdupq_n_f32(-LG2_U_F), d);
+    s = vmlafq_f32(qf, vdupq_n_f32(-LG2_L_F), s);
+    let r = vmlafq_f32(
+        s,
+        vdupq_n_f32(LN10_HI_F),
+        vmulq_f32(s, vdupq_n_f32(LN10_LO_F)),
+    );
+    let mut r = vldexp2kq_f32(vaddq_f32(vexpm1kq_f32(r), vdupq_n_f32(1f32)), q);
+    r = vbslq_f32(vcltq_f32(d, vdupq_n_f32(-45.16f32)), vdupq_n_f32(0f32), r);
+    r = vbslq_f32(
+        vcgtq_f32(d, vdupq_n_f32(38.54f32)),
+        vdupq_n_f32(f32::INFINITY),
+        r,
+    );
+    r
+}
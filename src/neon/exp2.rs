@@ -0,0 +1,33 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::exp::LN2_LO;
+use crate::neon::exp::vexpm1kq_f64;
+use crate::neon::general::{vldexp2kq_f64, vmlafq_f64};
+
+/// Computes 2^x *ULP 1.5*
+#[inline]
+pub unsafe fn vexp2q_f64(d: float64x2_t) -> float64x2_t {
+    let q = vcvtaq_s64_f64(d);
+    let qf = vcvtq_f64_s64(q);
+    let s = vsubq_f64(d, qf);
+    let r = vmlafq_f64(
+        s,
+        vdupq_n_f64(std::f64::consts::LN_2),
+        vmulq_f64(s, vdupq_n_f64(LN2_LO)),
+    );
+    let mut r = vldexp2kq_f64(vaddq_f64(vexpm1kq_f64(r), vdupq_n_f64(1f64)), q);
+    r = vbslq_f64(vcltq_f64(d, vdupq_n_f64(-1075f64)), vdupq_n_f64(0f64), r);
+    r = vbslq_f64(
+        vcgeq_f64(d, vdupq_n_f64(1024f64)),
+        vdupq_n_f64(f64::INFINITY),
+        r,
+    );
+    r
+}
@@ -0,0 +1,33 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::expf::LN2_LO_F;
+use crate::neon::expf::vexpm1kq_f32;
+use crate::neon::general::{vldexp2kq_f32, vmlafq_f32};
+
+/// Computes 2^x *ULP 2.0*
+#[inline]
+pub unsafe fn vexp2q_f32(d: float32x4_t) -> float32x4_t {
+    let q = vcvtaq_s32_f32(d);
+    let qf = vcvtq_f32_s32(q);
+    let s = vsubq_f32(d, qf);
+    let r = vmlafq_f32(
+        s,
+        vdupq_n_f32(std::f32::consts::LN_2),
+        vmulq_f32(s, vdupq_n_f32(LN2_LO_F)),
+    );
+    let mut r = vldexp2kq_f32(vaddq_f32(vexpm1kq_f32(r), vdupq_n_f32(1f32)), q);
+    r = vbslq_f32(vcltq_f32(d, vdupq_n_f32(-150f32)), vdupq_n_f32(0f32), r);
+    r = vbslq_f32(
+        vcgeq_f32(d, vdupq_n_f32(128f32)),
+        vdupq_n_f32(f32::INFINITY),
+        r,
+    );
+    r
+}
@@ -8,16 +8,33 @@
 use crate::expf::{
     EXP_POLY_1_S, EXP_POLY_2_S, EXP_POLY_3_S, EXP_POLY_4_S, EXP_POLY_5_S, L2L_F, L2U_F,
 };
-use crate::neon::general::{vmlafq_f32, vpow2ifq_s32};
+use crate::neon::general::{vldexp2kq_f32, vmlafq_f32, vpow2ifq_s32};
 use std::arch::aarch64::*;
 
+/// Computes `exp(r) - 1` for reduced argument |r| <= ln(2)/2
+#[inline]
+pub(crate) unsafe fn vexpm1kq_f32(r: float32x4_t) -> float32x4_t {
+    let f = vmulq_f32(r, r);
+    let mut u = vdupq_n_f32(EXP_POLY_5_S);
+    u = vmlafq_f32(u, f, vdupq_n_f32(EXP_POLY_4_S));
+    u = vmlafq_f32(u, f, vdupq_n_f32(EXP_POLY_3_S));
+    u = vmlafq_f32(u, f, vdupq_n_f32(EXP_POLY_2_S));
+    u = vmlafq_f32(u, f, vdupq_n_f32(EXP_POLY_1_S));
+    vdivq_f32(vmulq_n_f32(r, 2f32), vsubq_f32(u, r))
+}
+
 /// Computes exp for an argument *ULP 1.0*
 #[inline]
 pub unsafe fn vexpq_f32(d: float32x4_t) -> float32x4_t {
-    let mut r = vexpq_fast_f32(d);
-    r = vbslq_f32(vcltq_f32(d, vdupq_n_f32(-87f32)), vdupq_n_f32(0f32), r);
+    let q = vcvtaq_s32_f32(vmulq_n_f32(d, std::f32::consts::LOG2_E));
+    let qf = vcvtq_f32_s32(q);
+    let mut r = vmlafq_f32(qf, vdupq_n_f32(-L2U_F), d);
+    r = vmlafq_f32(qf, vdupq_n_f32(-L2L_F), r);
+    // Two step scaling keeps subnormal results and the last binade before overflow
+    r = vldexp2kq_f32(vaddq_f32(vexpm1kq_f32(r), vdupq_n_f32(1f32)), q);
+    r = vbslq_f32(vcltq_f32(d, vdupq_n_f32(-104f32)), vdupq_n_f32(0f32), r);
     r = vbslq_f32(
-        vcgtq_f32(d, vdupq_n_f32(88f32)),
+        vcgtq_f32(d, vdupq_n_f32(89f32)),
         vdupq_n_f32(f32::INFINITY),
         r,
     );
@@ -32,16 +49,7 @@ pub unsafe fn vexpq_fast_f32(d: float32x4_t) -> float32x4_t {
     /* exp(x) = 2^i * exp(f); */
     let mut r = vmlafq_f32(qf, vdupq_n_f32(-L2U_F), d);
     r = vmlafq_f32(qf, vdupq_n_f32(-L2L_F), r);
-    let f = vmulq_f32(r, r);
-    let mut u = vdupq_n_f32(EXP_POLY_5_S);
-    u = vmlafq_f32(u, f, vdupq_n_f32(EXP_POLY_4_S));
-    u = vmlafq_f32(u, f, vdupq_n_f32(EXP_POLY_3_S));
-    u = vmlafq_f32(u, f, vdupq_n_f32(EXP_POLY_2_S));
-    u = vmlafq_f32(u, f, vdupq_n_f32(EXP_POLY_1_S));
-    let u = vaddq_f32(
-        vdivq_f32(vmulq_n_f32(r, 2f32), vsubq_f32(u, r)),
-        vdupq_n_f32(1f32),
-    );
+    let u = vaddq_f32(vexpm1kq_f32(r), vdupq_n_f32(1f32));
     let i2 = vreinterpretq_f32_s32(vpow2ifq_s32(q));
     let r = vmulq_f32(u, i2);
     r
@@ -0,0 +1,72 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::exp::{L2_L, L2_U, R_LN2};
+use crate::expm1::{
+    EXPM1_POLY_10_D, EXPM1_POLY_11_D, EXPM1_POLY_12_D, EXPM1_POLY_13_D, EXPM1_POLY_14_D,
+    EXPM1_POLY_2_D, EXPM1_POLY_3_D, EXPM1_POLY_4_D, EXPM1_POLY_5_D, EXPM1_POLY_6_D, EXPM1_POLY_7_D,
+    EXPM1_POLY_8_D, EXPM1_POLY_9_D,
+};
+use crate::neon::general::{vldexp2kq_f64, vmlafq_f64, vpow2ifq_s64};
+
+/// Computes `exp(r) - 1 - r` for reduced argument |r| <= ln(2)/2
+#[inline]
+unsafe fn vexpm1_tailq_f64(r: float64x2_t) -> float64x2_t {
+    let mut u = vdupq_n_f64(EXPM1_POLY_14_D);
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_13_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_12_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_11_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_10_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_9_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_8_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_7_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_6_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_5_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_4_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_3_D));
+    u = vmlafq_f64(u, r, vdupq_n_f64(EXPM1_POLY_2_D));
+    vmulq_f64(vmulq_f64(r, r), u)
+}
+
+/// Computes exp(x) - 1, accurate for x near zero *ULP 1.5*
+#[inline]
+pub unsafe fn vexpm1q_f64(d: float64x2_t) -> float64x2_t {
+    let q = vcvtaq_s64_f64(vmulq_f64(d, vdupq_n_f64(R_LN2)));
+    let qf = vcvtq_f64_s64(q);
+    // rh is exact, rl keeps the part of q*ln(2) below it
+    let rh = vmlafq_f64(qf, vdupq_n_f64(-L2_U), d);
+    let rl = vmulq_f64(qf, vdupq_n_f64(-L2_L));
+    let t = vexpm1_tailq_f64(vaddq_f64(rh, rl));
+    // exp(x) - 1 = 2^q*(rl + t) + (2^q*rh + 2^q - 1), the inner sum is exact where it cancels
+    let i2 = vreinterpretq_f64_s64(vpow2ifq_s64(q));
+    let mut u = vmlafq_f64(
+        i2,
+        vaddq_f64(rl, t),
+        vmlafq_f64(i2, rh, vsubq_f64(i2, vdupq_n_f64(1f64))),
+    );
+    u = vbslq_f64(
+        vcgtq_f64(qf, vdupq_n_f64(53f64)),
+        vsubq_f64(
+            vldexp2kq_f64(
+                vaddq_f64(vdupq_n_f64(1f64), vaddq_f64(rh, vaddq_f64(rl, t))),
+                q,
+            ),
+            vdupq_n_f64(1f64),
+        ),
+        u,
+    );
+    u = vbslq_f64(vceqq_f64(d, vdupq_n_f64(0f64)), d, u);
+    u = vbslq_f64(vcltq_f64(d, vdupq_n_f64(-37.5f64)), vdupq_n_f64(-1f64), u);
+    u = vbslq_f64(
+        vcgtq_f64(d, vdupq_n_f64(709.782712893384f64)),
+        vdupq_n_f64(f64::INFINITY),
+        u,
+    );
+    u
+}
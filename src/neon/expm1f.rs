@@ -0,0 +1,65 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::expf::{L2L_F, L2U_F};
+use crate::expm1f::{
+    EXPM1_POLY_2_S, EXPM1_POLY_3_S, EXPM1_POLY_4_S, EXPM1_POLY_5_S, EXPM1_POLY_6_S, EXPM1_POLY_7_S,
+    EXPM1_POLY_8_S,
+};
+use crate::neon::general::{vldexp2kq_f32, vmlafq_f32, vpow2ifq_s32};
+
+/// Computes `exp(r) - 1 - r` for reduced argument |r| <= ln(2)/2
+#[inline]
+unsafe fn vexpm1_tailq_f32(r: float32x4_t) -> float32x4_t {
+    let mut u = vdupq_n_f32(EXPM1_POLY_8_S);
+    u = vmlafq_f32(u, r, vdupq_n_f32(EXPM1_POLY_7_S));
+    u = vmlafq_f32(u, r, vdupq_n_f32(EXPM1_POLY_6_S));
+    u = vmlafq_f32(u, r, vdupq_n_f32(EXPM1_POLY_5_S));
+    u = vmlafq_f32(u, r, vdupq_n_f32(EXPM1_POLY_4_S));
+    u = vmlafq_f32(u, r, vdupq_n_f32(EXPM1_POLY_3_S));
+    u = vmlafq_f32(u, r, vdupq_n_f32(EXPM1_POLY_2_S));
+    vmulq_f32(vmulq_f32(r, r), u)
+}
+
+/// Computes exp(x) - 1, accurate for x near zero *ULP 1.5*
+#[inline]
+pub unsafe fn vexpm1q_f32(d: float32x4_t) -> float32x4_t {
+    let q = vcvtaq_s32_f32(vmulq_f32(d, vdupq_n_f32(std::f32::consts::LOG2_E)));
+    let qf = vcvtq_f32_s32(q);
+    // rh is exact, rl keeps the part of q*ln(2) below it
+    let rh = vmlafq_f32(qf, vdupq_n_f32(-L2U_F), d);
+    let rl = vmulq_f32(qf, vdupq_n_f32(-L2L_F));
+    let t = vexpm1_tailq_f32(vaddq_f32(rh, rl));
+    // exp(x) - 1 = 2^q*(rl + t) + (2^q*rh + 2^q - 1), the inner sum is exact where it cancels
+    let i2 = vreinterpretq_f32_s32(vpow2ifq_s32(q));
+    let mut u = vmlafq_f32(
+        i2,
+        vaddq_f32(rl, t),
+        vmlafq_f32(i2, rh, vsubq_f32(i2, vdupq_n_f32(1f32))),
+    );
+    u = vbslq_f32(
+        vcgtq_f32(qf, vdupq_n_f32(24f32)),
+        vsubq_f32(
+            vldexp2kq_f32(
+                vaddq_f32(vdupq_n_f32(1f32), vaddq_f32(rh, vaddq_f32(rl, t))),
+                q,
+            ),
+            vdupq_n_f32(1f32),
+        ),
+        u,
+    );
+    u = vbslq_f32(vceqq_f32(d, vdupq_n_f32(0f32)), d, u);
+    u = vbslq_f32(vcltq_f32(d, vdupq_n_f32(-17.5f32)), vdupq_n_f32(-1f32), u);
+    u = vbslq_f32(
+        vcgtq_f32(d, vdupq_n_f32(88.72283f32)),
+        vdupq_n_f32(f32::INFINITY),
+        u,
+    );
+    u
+}
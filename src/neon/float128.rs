@@ -15,7 +15,7 @@ use crate::neon::general::{vmlafq_f64, vmlsfq_f64};
 ///
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
-pub struct float128x2_t(float64x2_t, float64x2_t);
+pub struct float128x2_t(pub(crate) float64x2_t, pub(crate) float64x2_t);
 
 #[inline]
 unsafe fn vupperpartq_f64(a: float64x2_t) -> float64x2_t {
@@ -23,43 +23,40 @@ unsafe fn vupperpartq_f64(a: float64x2_t) -> float64x2_t {
     vreinterpretq_f64_u64(vandq_u64(vreinterpretq_u64_f64(a), mask))
 }
 
+#[inline]
+/// Computes rounding error of `a*b`, `r` must be the rounded product
+unsafe fn vmul_errq_f64(a: float64x2_t, b: float64x2_t, r: float64x2_t) -> float64x2_t {
+    let xh = vupperpartq_f64(a);
+    let xl = vsubq_f64(a, xh);
+    let yh = vupperpartq_f64(b);
+    let yl = vsubq_f64(b, yh);
+    vmlafq_f64(
+        xl,
+        yl,
+        vmlafq_f64(xl, yh, vmlafq_f64(xh, yl, vmlsfq_f64(xh, yh, r))),
+    )
+}
+
 #[inline]
 /// Performs multiplication for f128
 pub unsafe fn vmulq_f128(a: float128x2_t, b: float128x2_t) -> float128x2_t {
-    let xh = vupperpartq_f64(a.0);
-    let xl = vsubq_f64(a.0, xh);
-    let yh = vupperpartq_f64(b.0);
-    let yl = vsubq_f64(b.0, yh);
     let r0 = vmulq_f64(a.0, b.0);
-    let v0 = vmlsfq_f64(xl, yl, r0);
-    let j0 = vmlafq_f64(xh, yh, vmlafq_f64(xl, yh, vmlafq_f64(xh, yl, v0)));
-    let j2 = vmlafq_f64(a.0, b.1, vmlafq_f64(a.1, b.0, j0));
-    float128x2_t(r0, j2)
+    let e = vmul_errq_f64(a.0, b.0, r0);
+    float128x2_t(r0, vmlafq_f64(a.0, b.1, vmlafq_f64(a.1, b.0, e)))
 }
 
 #[inline]
 /// Widens f64 into f128 and performs multiplication
 pub unsafe fn vmullq_f64(a: float64x2_t, b: float64x2_t) -> float128x2_t {
-    let xh = vupperpartq_f64(a);
-    let xl = vsubq_f64(a, xh);
-    let yh = vupperpartq_f64(b);
-    let yl = vsubq_f64(b, yh);
     let r0 = vmulq_f64(a, b);
-    let d0 = r0;
-    let j0 = vmlafq_f64(
-        xh,
-        yh,
-        vmlafq_f64(xl, yh, vmlafq_f64(xh, yl, vmulq_f64(xl, yl))),
-    );
-    let d1 = vsubq_f64(j0, r0);
-    float128x2_t(d0, d1)
+    float128x2_t(r0, vmul_errq_f64(a, b, r0))
 }
 
 #[inline]
 /// Adds f64 with widening to f128
 pub unsafe fn vaddl_f64(a: float64x2_t, b: float64x2_t) -> float128x2_t {
     let r0 = vaddq_f64(a, b);
-    let v = vsubq_f64(r0, b);
+    let v = vsubq_f64(r0, a);
     float128x2_t(
         r0,
         vaddq_f64(vsubq_f64(a, vsubq_f64(r0, v)), vsubq_f64(b, v)),
@@ -70,9 +67,13 @@ pub unsafe fn vaddl_f64(a: float64x2_t, b: float64x2_t) -> float128x2_t {
 #[inline]
 pub unsafe fn vaddq_f128(a: float128x2_t, b: float128x2_t) -> float128x2_t {
     let r0 = vaddq_f64(a.0, b.0);
+    let v = vsubq_f64(r0, a.0);
     float128x2_t(
         r0,
-        vsubq_f64(vaddq_f64(vaddq_f64(vaddq_f64(b.0, a.1), r0), b.1), r0),
+        vaddq_f64(
+            vaddq_f64(vsubq_f64(a.0, vsubq_f64(r0, v)), vsubq_f64(b.0, v)),
+            vaddq_f64(a.1, b.1),
+        ),
     )
 }
 
@@ -97,30 +98,16 @@ pub unsafe fn vaddw_f64(a: float128x2_t, b: float64x2_t) -> float128x2_t {
 }
 
 #[inline]
+/// Performs division for f128
 pub unsafe fn vdivq_f128(a: float128x2_t, b: float128x2_t) -> float128x2_t {
     let t = vdivq_f64(vdupq_n_f64(1.), b.0);
-    let dh = vupperpartq_f64(b.0);
-    let dl = vsubq_f64(b.0, dh);
-    let th = vupperpartq_f64(t);
-    let tl = vsubq_f64(t, th);
-    let nhh = vupperpartq_f64(a.0);
-    let nhl = vsubq_f64(a.0, nhh);
-
     let q0 = vmulq_f64(a.0, t);
-
-    let j0 = vmlafq_f64(
-        nhh,
-        th,
-        vmlafq_f64(nhh, tl, vmlafq_f64(nhl, th, vmulq_f64(nhl, tl))),
-    );
-    let j1 = vmlafq_f64(
-        dh,
-        th,
-        vmlafq_f64(dh, tl, vmlafq_f64(dl, th, vmulq_f64(dl, tl))),
+    let u = vmlafq_f64(
+        q0,
+        vnegq_f64(vmul_errq_f64(b.0, t, vdupq_n_f64(1.))),
+        vmul_errq_f64(a.0, t, q0),
     );
-    let j2 = vsubq_f64(vdupq_n_f64(1f64), j1);
-    let u = vsubq_f64(vmlafq_f64(q0, j2, j0), q0);
-    let low = vmlafq_f64(vsubq_f64(a.1, vmulq_f64(q0, b.1)), t, u);
+    let low = vmlafq_f64(t, vsubq_f64(a.1, vmulq_f64(q0, b.1)), u);
     float128x2_t(q0, low)
 }
 
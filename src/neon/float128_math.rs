@@ -0,0 +1,291 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::neon::float128::{
+    float128x2_t, vaddq_f128, vcvtq_f128_f64, vdupq_n_f128, vmullq_f64, vmulq_f128, vnegq_f128,
+};
+use crate::neon::general::{vilogb2kq_f64, visintegralq_f64, visnotintegralq_f64, vldexp2kq_f64};
+use crate::neon::ln::vlnq_f64;
+use crate::neon::pow::vpowq_f64;
+use std::arch::aarch64::*;
+
+const LN2: [f64; 3] = [
+    std::f64::consts::LN_2,
+    2.3190468138462996e-17,
+    5.707708438416212e-34,
+];
+
+const PI_2: [f64; 3] = [
+    std::f64::consts::FRAC_PI_2,
+    6.123233995736766e-17,
+    -1.4973849048591698e-33,
+];
+
+/// 1/n! for n in 10..=2
+const EXP_POLY: [(f64, f64); 9] = [
+    (2.755731922398589e-07, 2.3767714622250297e-23),
+    (2.7557319223985893e-06, -1.858393274046472e-22),
+    (2.48015873015873e-05, 2.1511947866775882e-23),
+    (0.0001984126984126984, 1.7209558293420705e-22),
+    (0.001388888888888889, -5.300543954373577e-20),
+    (0.008333333333333333, 1.1564823173178714e-19),
+    (0.041666666666666664, 2.3129646346357427e-18),
+    (0.16666666666666666, 9.25185853854297e-18),
+    (0.5, 0.),
+];
+
+/// (-1)^n/(2n+1)! for n in 14..=1
+const SIN_POLY: [(f64, f64); 14] = [
+    (1.1309962886447716e-31, 1.0498015412959506e-47),
+    (-9.183689863795546e-29, -1.4303150396787322e-45),
+    (6.446950284384474e-26, -1.9330404233703465e-42),
+    (-3.868170170630684e-23, 8.843177655482344e-40),
+    (1.9572941063391263e-20, -1.3643503830087908e-36),
+    (-8.22063524662433e-18, -2.2141894119604265e-34),
+    (2.8114572543455206e-15, 1.6508842730861433e-31),
+    (-7.647163731819816e-13, -7.03872877733453e-30),
+    (1.6059043836821613e-10, 1.2585294588752098e-26),
+    (-2.505210838544172e-08, 1.448814070935912e-24),
+    (2.7557319223985893e-06, -1.858393274046472e-22),
+    (-0.0001984126984126984, -1.7209558293420705e-22),
+    (0.008333333333333333, 1.1564823173178714e-19),
+    (-0.16666666666666666, -9.25185853854297e-18),
+];
+
+/// (-1)^n/(2n)! for n in 14..=1
+const COS_POLY: [(f64, f64); 14] = [
+    (3.279889237069838e-30, 1.5117542744029879e-46),
+    (-2.4795962632247976e-27, 1.2953730964765229e-43),
+    (1.6117375710961184e-24, -3.6846573564509766e-41),
+    (-8.896791392450574e-22, 7.911402614872376e-38),
+    (4.110317623312165e-19, 1.4412973378659527e-36),
+    (-1.5619206968586225e-16, -1.1910679660273754e-32),
+    (4.779477332387385e-14, 4.399205485834081e-31),
+    (-1.1470745597729725e-11, -2.0655512752830745e-28),
+    (2.08767569878681e-09, -1.20734505911326e-25),
+    (-2.755731922398589e-07, -2.3767714622250297e-23),
+    (2.48015873015873e-05, 2.1511947866775882e-23),
+    (-0.001388888888888889, 5.300543954373577e-20),
+    (0.041666666666666664, 2.3129646346357427e-18),
+    (-0.5, 0.),
+];
+
+#[inline]
+unsafe fn vsubq_f128(a: float128x2_t, b: float128x2_t) -> float128x2_t {
+    vaddq_f128(a, vnegq_f128(b))
+}
+
+#[inline]
+/// Moves `a` into canonical form where `hi = hi + lo` rounded
+unsafe fn vrenormq_f128(a: float128x2_t) -> float128x2_t {
+    let r0 = vaddq_f64(a.0, a.1);
+    float128x2_t(r0, vsubq_f64(a.1, vsubq_f64(r0, a.0)))
+}
+
+#[inline]
+unsafe fn vselectq_f128(
+    mask: uint64x2_t,
+    true_vals: float128x2_t,
+    false_vals: float128x2_t,
+) -> float128x2_t {
+    float128x2_t(
+        vbslq_f64(mask, true_vals.0, false_vals.0),
+        vbslq_f64(mask, true_vals.1, false_vals.1),
+    )
+}
+
+#[inline]
+/// Computes `a*2^n` for integral `n` held in f64
+unsafe fn vldexpq_f128(a: float128x2_t, n: float64x2_t) -> float128x2_t {
+    let k = vcvtq_s64_f64(n);
+    float128x2_t(vldexp2kq_f64(a.0, k), vldexp2kq_f64(a.1, k))
+}
+
+#[inline]
+/// Computes `a - n*c` for integral `n` and three part constant `c`
+unsafe fn vreduceq_f128(a: float128x2_t, n: float64x2_t, c: &[f64; 3]) -> float128x2_t {
+    let r = vrenormq_f128(vsubq_f128(a, vmullq_f64(n, vdupq_n_f64(c[0]))));
+    let r = vsubq_f128(r, vmullq_f64(n, vdupq_n_f64(c[1])));
+    vsubq_f128(r, vcvtq_f128_f64(vmulq_f64(n, vdupq_n_f64(c[2]))))
+}
+
+#[inline]
+unsafe fn vpolyq_f128(x: float128x2_t, coeffs: &[(f64, f64)]) -> float128x2_t {
+    let mut acc = float128x2_t(vdupq_n_f64(coeffs[0].0), vdupq_n_f64(coeffs[0].1));
+    for c in coeffs.iter().skip(1) {
+        acc = vaddq_f128(
+            vmulq_f128(acc, x),
+            float128x2_t(vdupq_n_f64(c.0), vdupq_n_f64(c.1)),
+        );
+    }
+    acc
+}
+
+#[inline]
+/// Multiplies both parts by power of two `s`
+unsafe fn vscaleq_f128(a: float128x2_t, s: float64x2_t) -> float128x2_t {
+    float128x2_t(vmulq_f64(a.0, s), vmulq_f64(a.1, s))
+}
+
+#[inline]
+/// Computes square root for f128
+pub unsafe fn vsqrtq_f128(d: float128x2_t) -> float128x2_t {
+    // Tiny values are scaled up so the residual does not fall into subnormals
+    let o = vcltq_f64(d.0, vdupq_n_f64(1e-250));
+    let a = vscaleq_f128(
+        d,
+        vbslq_f64(
+            o,
+            vdupq_n_f64(f64::from_bits(0x5ff0000000000000)),
+            vdupq_n_f64(1.),
+        ),
+    );
+    let y = vsqrtq_f64(a.0);
+    let e = vsubq_f128(a, vmullq_f64(y, y));
+    let c = vdivq_f64(vaddq_f64(e.0, e.1), vaddq_f64(y, y));
+    let r0 = vaddq_f64(y, c);
+    let r = vscaleq_f128(
+        float128x2_t(r0, vsubq_f64(c, vsubq_f64(r0, y))),
+        vbslq_f64(
+            o,
+            vdupq_n_f64(f64::from_bits(0x2ff0000000000000)),
+            vdupq_n_f64(1.),
+        ),
+    );
+    let special = vorrq_u64(
+        vceqq_f64(d.0, vdupq_n_f64(0.)),
+        vceqq_f64(d.0, vdupq_n_f64(f64::INFINITY)),
+    );
+    vselectq_f128(special, vcvtq_f128_f64(vsqrtq_f64(d.0)), r)
+}
+
+#[inline]
+/// Computes expm1 for |r| < 0.5
+unsafe fn vexpm1_reducedq_f128(r: float128x2_t) -> float128x2_t {
+    // expm1(r) from expm1(r/256) by repeated (1 + e)^2 - 1 = e*(e + 2)
+    let s = vscaleq_f128(r, vdupq_n_f64(1. / 256.));
+    let p = vpolyq_f128(s, &EXP_POLY);
+    let mut e = vmulq_f128(s, vaddq_f128(vmulq_f128(p, s), vdupq_n_f128(1.)));
+    for _ in 0..8 {
+        e = vrenormq_f128(vmulq_f128(e, vaddq_f128(e, vdupq_n_f128(2.))));
+    }
+    e
+}
+
+#[inline]
+/// Computes exp for f128
+pub unsafe fn vexpq_f128(d: float128x2_t) -> float128x2_t {
+    let x = vminq_f64(vmaxq_f64(d.0, vdupq_n_f64(-750.)), vdupq_n_f64(710.));
+    let k = vrndnq_f64(vmulq_f64(x, vdupq_n_f64(std::f64::consts::LOG2_E)));
+    let r = vrenormq_f128(vreduceq_f128(d, k, &LN2));
+    let e = vexpm1_reducedq_f128(r);
+    let mut v = vldexpq_f128(vrenormq_f128(vaddq_f128(e, vdupq_n_f128(1.))), k);
+    v = vselectq_f128(
+        vcgtq_f64(d.0, vdupq_n_f64(709.782712893384)),
+        vdupq_n_f128(f64::INFINITY),
+        v,
+    );
+    v = vselectq_f128(
+        vcltq_f64(d.0, vdupq_n_f64(-745.1332191019412)),
+        vdupq_n_f128(0.),
+        v,
+    );
+    v
+}
+
+#[inline]
+/// Computes natural logarithm for f128
+pub unsafe fn vlnq_f128(d: float128x2_t) -> float128x2_t {
+    let o = vcltq_f64(d.0, vdupq_n_f64(f64::MIN_POSITIVE));
+    let scale = vbslq_f64(o, vdupq_n_f64(18446744073709551616.), vdupq_n_f64(1.));
+    let a = vscaleq_f128(d, scale);
+    // a = m*2^e, m in [0.75, 1.5)
+    let e = vcvtq_f64_s64(vilogb2kq_f64(vmulq_f64(a.0, vdupq_n_f64(1. / 0.75))));
+    let m = vldexpq_f128(a, vsubq_f64(vdupq_n_f64(0.), e));
+    let e = vsubq_f64(e, vbslq_f64(o, vdupq_n_f64(64.), vdupq_n_f64(0.)));
+    // One Newton step on exp(y) = m, m*exp(-y0) - 1 = m*expm1(-y0) + (m - 1)
+    let y0 = vlnq_f64(m.0);
+    let em1 = vexpm1_reducedq_f128(vcvtq_f128_f64(vsubq_f64(vdupq_n_f64(0.), y0)));
+    let t = vaddq_f128(vmulq_f128(m, em1), vsubq_f128(m, vdupq_n_f128(1.)));
+    let y = vaddq_f128(vcvtq_f128_f64(y0), t);
+    let r = vrenormq_f128(vsubq_f128(y, vreduceq_f128(vdupq_n_f128(0.), e, &LN2)));
+    let finite = vandq_u64(
+        vcgtq_f64(d.0, vdupq_n_f64(0.)),
+        vcltq_f64(d.0, vdupq_n_f64(f64::INFINITY)),
+    );
+    vselectq_f128(finite, r, vcvtq_f128_f64(vlnq_f64(d.0)))
+}
+
+#[inline]
+/// Computes sin and cos of reduced argument with quadrant `q` in f64
+unsafe fn vsincos_reduceq_f128(d: float128x2_t) -> (float128x2_t, float128x2_t, float64x2_t) {
+    let k = vrndnq_f64(vmulq_f64(d.0, vdupq_n_f64(std::f64::consts::FRAC_2_PI)));
+    let r = vrenormq_f128(vreduceq_f128(d, k, &PI_2));
+    let r2 = vmulq_f128(r, r);
+    let s = vrenormq_f128(vaddq_f128(
+        r,
+        vmulq_f128(vmulq_f128(vpolyq_f128(r2, &SIN_POLY), r2), r),
+    ));
+    let c = vrenormq_f128(vaddq_f128(
+        vdupq_n_f128(1.),
+        vmulq_f128(vpolyq_f128(r2, &COS_POLY), r2),
+    ));
+    let q = vsubq_f64(
+        k,
+        vmulq_f64(vrndmq_f64(vmulq_f64(k, vdupq_n_f64(0.25))), vdupq_n_f64(4.)),
+    );
+    (s, c, q)
+}
+
+#[inline]
+/// Computes sine for f128, accurate for |x| < 2^40
+pub unsafe fn vsinq_f128(d: float128x2_t) -> float128x2_t {
+    let (s, c, q) = vsincos_reduceq_f128(d);
+    let odd = vorrq_u64(vceqq_f64(q, vdupq_n_f64(1.)), vceqq_f64(q, vdupq_n_f64(3.)));
+    let v = vselectq_f128(odd, c, s);
+    let negate = vcgeq_f64(q, vdupq_n_f64(2.));
+    vselectq_f128(negate, vnegq_f128(v), v)
+}
+
+#[inline]
+/// Computes cosine for f128, accurate for |x| < 2^40
+pub unsafe fn vcosq_f128(d: float128x2_t) -> float128x2_t {
+    let (s, c, q) = vsincos_reduceq_f128(d);
+    let odd = vorrq_u64(vceqq_f64(q, vdupq_n_f64(1.)), vceqq_f64(q, vdupq_n_f64(3.)));
+    let v = vselectq_f128(odd, s, c);
+    let negate = vorrq_u64(vceqq_f64(q, vdupq_n_f64(1.)), vceqq_f64(q, vdupq_n_f64(2.)));
+    vselectq_f128(negate, vnegq_f128(v), v)
+}
+
+#[inline]
+/// Computes pow for f128, error grows with |y*ln(x)| up to 2^-97 near overflow
+pub unsafe fn vpowq_f128(x: float128x2_t, y: float128x2_t) -> float128x2_t {
+    let neg = vcltq_f64(x.0, vdupq_n_f64(0.));
+    let ax = vselectq_f128(neg, vnegq_f128(x), x);
+    let mut r = vexpq_f128(vmulq_f128(y, vlnq_f128(ax)));
+    let y_int = vandq_u64(visintegralq_f64(y.0), visintegralq_f64(y.1));
+    let y_odd = vandq_u64(
+        y_int,
+        veorq_u64(
+            visnotintegralq_f64(vmulq_f64(y.0, vdupq_n_f64(0.5))),
+            visnotintegralq_f64(vmulq_f64(y.1, vdupq_n_f64(0.5))),
+        ),
+    );
+    r = vselectq_f128(vandq_u64(neg, y_odd), vnegq_f128(r), r);
+    let inf = vdupq_n_f64(f64::INFINITY);
+    let regular = vandq_u64(
+        vandq_u64(
+            vcgtq_f64(vabsq_f64(x.0), vdupq_n_f64(0.)),
+            vcltq_f64(vabsq_f64(x.0), inf),
+        ),
+        vandq_u64(
+            vcltq_f64(vabsq_f64(y.0), inf),
+            vorrq_u64(vcgtq_f64(x.0, vdupq_n_f64(0.)), y_int),
+        ),
+    );
+    vselectq_f128(regular, r, vcvtq_f128_f64(vpowq_f64(x.0, y.0)))
+}
@@ -7,14 +7,48 @@
 
 use std::arch::aarch64::*;
 
+use crate::fmod::do_fmod;
+use crate::neon::general::{
+    vcopysignq_f64, vilogb2kq_f64, visinfq_f64, visnanq_f64, vldexp3kq_f64,
+};
+
 #[inline]
-/// Compute fmod for f64
+/// Compute fmod for f64, result is exact and has the sign of `a`
 pub unsafe fn vfmodq_f64(a: float64x2_t, b: float64x2_t) -> float64x2_t {
-    let dividend_vec = a;
-    let divisor_vec = b;
-    let division = vmulq_f64(dividend_vec, vdivq_f64(vdupq_n_f64(1.), divisor_vec));
-    let int_part = vcvtq_f64_s64(vcvtq_s64_f64(division));
-    let product = vmulq_f64(int_part, divisor_vec);
-    let remainder = vsubq_f64(dividend_vec, product);
-    remainder
+    let ax = vabsq_f64(a);
+    let ay = vabsq_f64(b);
+    let nan_mask = vorrq_u64(
+        vorrq_u64(visnanq_f64(a), visnanq_f64(b)),
+        vorrq_u64(visinfq_f64(a), vceqzq_f64(b)),
+    );
+    // Subnormal divisors and exponent differences above 64 are left to the scalar routine
+    let fallback = vbicq_u64(
+        vorrq_u64(
+            vcltq_f64(ay, vdupq_n_f64(f64::MIN_POSITIVE)),
+            vcgtq_f64(ax, vmulq_n_f64(ay, 18446744073709551616.)),
+        ),
+        nan_mask,
+    );
+    if vmaxvq_u32(vreinterpretq_u32_u64(fallback)) != 0 {
+        let mut xs = [0f64; 2];
+        let mut ys = [0f64; 2];
+        vst1q_f64(xs.as_mut_ptr(), a);
+        vst1q_f64(ys.as_mut_ptr(), b);
+        for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+            *x = do_fmod(*x, y);
+        }
+        return vld1q_f64(xs.as_ptr());
+    }
+    let mut rem = ax;
+    let mut work = vbicq_u64(vcgeq_f64(rem, ay), nan_mask);
+    while vmaxvq_u32(vreinterpretq_u32_u64(work)) != 0 {
+        // Largest |b|*2^k not above the remainder, subtraction is exact by Sterbenz lemma
+        let k = vsubq_s64(vilogb2kq_f64(rem), vilogb2kq_f64(ay));
+        let mut t = vldexp3kq_f64(ay, k);
+        t = vbslq_f64(vcgtq_f64(t, rem), vmulq_n_f64(t, 0.5), t);
+        rem = vbslq_f64(work, vsubq_f64(rem, t), rem);
+        work = vandq_u64(work, vcgeq_f64(rem, ay));
+    }
+    let ret = vcopysignq_f64(rem, a);
+    vbslq_f64(nan_mask, vdupq_n_f64(f64::NAN), ret)
 }
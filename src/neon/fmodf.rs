@@ -4,16 +4,51 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
+
 use std::arch::aarch64::*;
 
+use crate::fmodf::do_fmodf;
+use crate::neon::general::{
+    vcopysignq_f32, vilogb2kq_f32, visinfq_f32, visnanq_f32, vldexp3kq_f32,
+};
+
 #[inline]
-/// Compute fmod for f32
+/// Compute fmod for f32, result is exact and has the sign of `a`
 pub unsafe fn vfmodq_f32(a: float32x4_t, b: float32x4_t) -> float32x4_t {
-    let dividend_vec = a;
-    let divisor_vec = b;
-    let division = vmulq_f32(dividend_vec, vdivq_f32(vdupq_n_f32(1.), divisor_vec));
-    let int_part = vcvtq_f32_s32(vcvtq_s32_f32(division));
-    let product = vmulq_f32(int_part, divisor_vec);
-    let remainder = vsubq_f32(dividend_vec, product);
-    remainder
+    let ax = vabsq_f32(a);
+    let ay = vabsq_f32(b);
+    let nan_mask = vorrq_u32(
+        vorrq_u32(visnanq_f32(a), visnanq_f32(b)),
+        vorrq_u32(visinfq_f32(a), vceqzq_f32(b)),
+    );
+    // Subnormal divisors and exponent differences above 32 are left to the scalar routine
+    let fallback = vbicq_u32(
+        vorrq_u32(
+            vcltq_f32(ay, vdupq_n_f32(f32::MIN_POSITIVE)),
+            vcgtq_f32(ax, vmulq_n_f32(ay, 4294967296.)),
+        ),
+        nan_mask,
+    );
+    if vmaxvq_u32(fallback) != 0 {
+        let mut xs = [0f32; 4];
+        let mut ys = [0f32; 4];
+        vst1q_f32(xs.as_mut_ptr(), a);
+        vst1q_f32(ys.as_mut_ptr(), b);
+        for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+            *x = do_fmodf(*x, y);
+        }
+        return vld1q_f32(xs.as_ptr());
+    }
+    let mut rem = ax;
+    let mut work = vbicq_u32(vcgeq_f32(rem, ay), nan_mask);
+    while vmaxvq_u32(work) != 0 {
+        // Largest |b|*2^k not above the remainder, subtraction is exact by Sterbenz lemma
+        let k = vsubq_s32(vilogb2kq_f32(rem), vilogb2kq_f32(ay));
+        let mut t = vldexp3kq_f32(ay, k);
+        t = vbslq_f32(vcgtq_f32(t, rem), vmulq_n_f32(t, 0.5), t);
+        rem = vbslq_f32(work, vsubq_f32(rem, t), rem);
+        work = vandq_u32(work, vcgeq_f32(rem, ay));
+    }
+    let ret = vcopysignq_f32(rem, a);
+    vbslq_f32(nan_mask, vdupq_n_f32(f32::NAN), ret)
 }
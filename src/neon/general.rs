@@ -5,6 +5,7 @@
  * // license that can be found in the LICENSE file.
  */
 
+use crate::generalf::{PAYNE_HANEK_THRESHOLD, PAYNE_HANEK_THRESHOLD_F};
 use std::arch::aarch64::*;
 
 #[inline]
@@ -59,9 +60,9 @@ pub unsafe fn vpow2ifq_s64(q: int64x2_t) -> int64x2_t {
 }
 
 #[inline]
-/// Returns true flag if value is Infinity
+/// Returns true flag if value is Infinity of any sign
 pub unsafe fn visinfq_f32(d: float32x4_t) -> uint32x4_t {
-    return vceqq_f32(d, vdupq_n_f32(f32::INFINITY));
+    return vceqq_f32(vabsq_f32(d), vdupq_n_f32(f32::INFINITY));
 }
 
 #[inline]
@@ -71,9 +72,9 @@ pub unsafe fn visneginfq_f32(d: float32x4_t) -> uint32x4_t {
 }
 
 #[inline]
-/// Returns true flag if value is Infinity
+/// Returns true flag if value is Infinity of any sign
 pub unsafe fn visinfq_f64(d: float64x2_t) -> uint64x2_t {
-    return vceqq_f64(d, vdupq_n_f64(f64::INFINITY));
+    return vceqq_f64(vabsq_f64(d), vdupq_n_f64(f64::INFINITY));
 }
 
 #[inline]
@@ -148,6 +149,14 @@ pub unsafe fn vldexp3kq_f32(x: float32x4_t, n: int32x4_t) -> float32x4_t {
     vreinterpretq_f32_s32(vaddq_s32(vreinterpretq_s32_f32(x), vshlq_n_s32::<23>(n)))
 }
 
+#[inline]
+/// Computes `x*2^n` with two scalings, so `n` may reach subnormal and near overflow exponents
+pub unsafe fn vldexp2kq_f32(x: float32x4_t, n: int32x4_t) -> float32x4_t {
+    let m = vshrq_n_s32::<1>(n);
+    let u = vmulq_f32(x, vreinterpretq_f32_s32(vpow2ifq_s32(m)));
+    vmulq_f32(u, vreinterpretq_f32_s32(vpow2ifq_s32(vsubq_s32(n, m))))
+}
+
 #[inline]
 /// Founds n in x=a+𝑛ln(2), |a| <= 1
 pub unsafe fn vilogb2kq_f64(d: float64x2_t) -> int64x2_t {
@@ -166,6 +175,14 @@ pub unsafe fn vldexp3kq_f64(x: float64x2_t, n: int64x2_t) -> float64x2_t {
     vreinterpretq_f64_s64(vaddq_s64(vreinterpretq_s64_f64(x), vshlq_n_s64::<52>(n)))
 }
 
+#[inline]
+/// Computes `x*2^n` with two scalings, so `n` may reach subnormal and near overflow exponents
+pub unsafe fn vldexp2kq_f64(x: float64x2_t, n: int64x2_t) -> float64x2_t {
+    let m = vshrq_n_s64::<1>(n);
+    let u = vmulq_f64(x, vreinterpretq_f64_s64(vpow2ifq_s64(m)));
+    vmulq_f64(u, vreinterpretq_f64_s64(vpow2ifq_s64(vsubq_s64(n, m))))
+}
+
 #[inline]
 pub unsafe fn vcopysignq_f32(x: float32x4_t, y: float32x4_t) -> float32x4_t {
     let mag = vdupq_n_u32(1 << 31);
@@ -260,10 +277,22 @@ pub unsafe fn vmulq_s64(ab: int64x2_t, cd: int64x2_t) -> int64x2_t {
     ))
 }
 
+#[inline]
+/// Returns true flag if value is an integral value
+pub unsafe fn visintegralq_f32(d: float32x4_t) -> uint32x4_t {
+    vceqq_f32(d, vrndmq_f32(d))
+}
+
+#[inline]
+/// Returns true flag if value is an integral value
+pub unsafe fn visintegralq_f64(d: float64x2_t) -> uint64x2_t {
+    vceqq_f64(d, vrndmq_f64(d))
+}
+
 #[inline]
 /// Returns true flag if value is not an integral value
 pub unsafe fn visnotintegralq_f32(d: float32x4_t) -> uint32x4_t {
-    return vmvnq_u32(vceqq_f32(d, vcvtq_f32_s32(vcvtq_s32_f32(d))));
+    return vmvnq_u32(vceqq_f32(d, vrndmq_f32(d)));
 }
 
 #[inline]
@@ -271,10 +300,160 @@ pub unsafe fn visnotintegralq_f32(d: float32x4_t) -> uint32x4_t {
 pub unsafe fn visnotintegralq_f64(d: float64x2_t) -> uint64x2_t {
     return vreinterpretq_u64_u32(vmvnq_u32(vreinterpretq_u32_u64(vceqq_f64(
         d,
-        vcvtq_f64_s64(vcvtq_s64_f64(d)),
+        vrndmq_f64(d),
     ))));
 }
 
+#[inline]
+/// Replaces Cody-Waite reduction with Payne-Hanek `reduce` in lanes above threshold
+pub(crate) unsafe fn vrempiq_f64(
+    d: float64x2_t,
+    q: int64x2_t,
+    r: float64x2_t,
+    reduce: fn(f64) -> (i32, f64),
+) -> (int64x2_t, float64x2_t) {
+    if vmaxvq_u32(vreinterpretq_u32_u64(vcagtq_f64(
+        d,
+        vdupq_n_f64(PAYNE_HANEK_THRESHOLD),
+    ))) == 0
+    {
+        return (q, r);
+    }
+    let mut ds = [0f64; 2];
+    let mut qs = [0i64; 2];
+    let mut rs = [0f64; 2];
+    vst1q_f64(ds.as_mut_ptr(), d);
+    vst1q_s64(qs.as_mut_ptr(), q);
+    vst1q_f64(rs.as_mut_ptr(), r);
+    for ((&d, q), r) in ds.iter().zip(qs.iter_mut()).zip(rs.iter_mut()) {
+        if d.abs() > PAYNE_HANEK_THRESHOLD && d.is_finite() {
+            let (lq, lr) = reduce(d);
+            *q = lq as i64;
+            *r = lr;
+        }
+    }
+    (vld1q_s64(qs.as_ptr()), vld1q_f64(rs.as_ptr()))
+}
+
+#[inline]
+/// Replaces Cody-Waite reduction with Payne-Hanek `reduce` in lanes above threshold
+pub(crate) unsafe fn vrempiq_f32(
+    d: float32x4_t,
+    q: int32x4_t,
+    r: float32x4_t,
+    reduce: fn(f32) -> (i32, f32),
+) -> (int32x4_t, float32x4_t) {
+    if vmaxvq_u32(vcagtq_f32(d, vdupq_n_f32(PAYNE_HANEK_THRESHOLD_F))) == 0 {
+        return (q, r);
+    }
+    let mut ds = [0f32; 4];
+    let mut qs = [0i32; 4];
+    let mut rs = [0f32; 4];
+    vst1q_f32(ds.as_mut_ptr(), d);
+    vst1q_s32(qs.as_mut_ptr(), q);
+    vst1q_f32(rs.as_mut_ptr(), r);
+    for ((&d, q), r) in ds.iter().zip(qs.iter_mut()).zip(rs.iter_mut()) {
+        if d.abs() > PAYNE_HANEK_THRESHOLD_F && d.is_finite() {
+            (*q, *r) = reduce(d);
+        }
+    }
+    (vld1q_s32(qs.as_ptr()), vld1q_f32(rs.as_ptr()))
+}
+
+#[inline]
+/// Computes a * b as double-float, see `multiply_as_doubled`
+pub(crate) unsafe fn vmultiply_as_doubledq_f64(
+    a: float64x2_t,
+    b: float64x2_t,
+) -> (float64x2_t, float64x2_t) {
+    let xh = vreinterpretq_f64_u64(vandq_u64(
+        vreinterpretq_u64_f64(a),
+        vdupq_n_u64(0xffff_ffff_f800_0000),
+    ));
+    let xl = vsubq_f64(a, xh);
+    let yh = vreinterpretq_f64_u64(vandq_u64(
+        vreinterpretq_u64_f64(b),
+        vdupq_n_u64(0xffff_ffff_f800_0000),
+    ));
+    let yl = vsubq_f64(b, yh);
+    let r0 = vmulq_f64(a, b);
+    let mut d1 = vsubq_f64(vmulq_f64(xh, yh), r0);
+    d1 = vmlafq_f64(xl, yh, d1);
+    d1 = vmlafq_f64(xh, yl, d1);
+    (r0, vmlafq_f64(xl, yl, d1))
+}
+
+#[inline]
+/// Multiplies two double-floats, see `mul_doubled`
+pub(crate) unsafe fn vmul_doubledq_f64(
+    a: (float64x2_t, float64x2_t),
+    b: (float64x2_t, float64x2_t),
+) -> (float64x2_t, float64x2_t) {
+    let m = vmultiply_as_doubledq_f64(a.0, b.0);
+    (
+        m.0,
+        vaddq_f64(m.1, vmlafq_f64(a.0, b.1, vmulq_f64(a.1, b.0))),
+    )
+}
+
+#[inline]
+/// Computes a + b as double-float, see `sum_as_doubled`
+pub(crate) unsafe fn vsum_as_doubledq_f64(
+    a: float64x2_t,
+    b: float64x2_t,
+) -> (float64x2_t, float64x2_t) {
+    let s = vaddq_f64(a, b);
+    let v = vsubq_f64(s, a);
+    (s, vaddq_f64(vsubq_f64(a, vsubq_f64(s, v)), vsubq_f64(b, v)))
+}
+
+#[inline]
+/// Computes a * b as double-float, see `multiply_as_doubled`
+pub(crate) unsafe fn vmultiply_as_doubledq_f32(
+    a: float32x4_t,
+    b: float32x4_t,
+) -> (float32x4_t, float32x4_t) {
+    let xh = vreinterpretq_f32_u32(vandq_u32(
+        vreinterpretq_u32_f32(a),
+        vdupq_n_u32(0xffff_f000),
+    ));
+    let xl = vsubq_f32(a, xh);
+    let yh = vreinterpretq_f32_u32(vandq_u32(
+        vreinterpretq_u32_f32(b),
+        vdupq_n_u32(0xffff_f000),
+    ));
+    let yl = vsubq_f32(b, yh);
+    let r0 = vmulq_f32(a, b);
+    let mut d1 = vsubq_f32(vmulq_f32(xh, yh), r0);
+    d1 = vmlafq_f32(xl, yh, d1);
+    d1 = vmlafq_f32(xh, yl, d1);
+    (r0, vmlafq_f32(xl, yl, d1))
+}
+
+#[inline]
+/// Multiplies two double-floats, see `mul_doubled`
+pub(crate) unsafe fn vmul_doubledq_f32(
+    a: (float32x4_t, float32x4_t),
+    b: (float32x4_t, float32x4_t),
+) -> (float32x4_t, float32x4_t) {
+    let m = vmultiply_as_doubledq_f32(a.0, b.0);
+    (
+        m.0,
+        vaddq_f32(m.1, vmlafq_f32(a.0, b.1, vmulq_f32(a.1, b.0))),
+    )
+}
+
+#[inline]
+/// Computes a + b as double-float, see `sum_as_doubled`
+pub(crate) unsafe fn vsum_as_doubledq_f32(
+    a: float32x4_t,
+    b: float32x4_t,
+) -> (float32x4_t, float32x4_t) {
+    let s = vaddq_f32(a, b);
+    let v = vsubq_f32(s, a);
+    (s, vaddq_f32(vsubq_f32(a, vsubq_f32(s, v)), vsubq_f32(b, v)))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -20,10 +20,10 @@ pub unsafe fn vhypotq_f32(x: float32x4_t, y: float32x4_t) -> float32x4_t {
     let is_any_infinite = vorrq_u32(visinfq_f32(x), visinfq_f32(y));
     let mut is_any_nan = vorrq_u32(visnanq_f32(x), visnanq_f32(y));
     let is_min_zero = vceqzq_f32(min);
+    ret = vbslq_f32(is_min_zero, max, ret);
     is_any_nan = vorrq_u32(visnanq_f32(ret), is_any_nan);
     ret = vbslq_f32(is_any_nan, vdupq_n_f32(f32::NAN), ret);
     ret = vbslq_f32(is_any_infinite, vdupq_n_f32(f32::INFINITY), ret);
-    ret = vbslq_f32(is_min_zero, vdupq_n_f32(0f32), ret);
     ret
 }
 
@@ -37,6 +37,6 @@ pub unsafe fn vhypotq_fast_f32(x: float32x4_t, y: float32x4_t) -> float32x4_t {
     let is_min_zero = vceqzq_f32(min);
     let r = vdivq_f32(min, max);
     let mut ret = vmulq_f32(vsqrtq_f32(vmlafq_f32(r, r, vdupq_n_f32(1f32))), max);
-    ret = vbslq_f32(is_min_zero, vdupq_n_f32(0f32), ret);
+    ret = vbslq_f32(is_min_zero, max, ret);
     ret
 }
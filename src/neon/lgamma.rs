@@ -0,0 +1,154 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::digamma::{DIGAMMA_ROOT_D, DIGAMMA_ROOT_LO_D, DIGAMMA_ROOT_M1_D, DIGAMMA_ROOT_M1_LO_D};
+use crate::lgamma::{
+    LGAMMA_A_POLY_10_D, LGAMMA_A_POLY_11_D, LGAMMA_A_POLY_12_D, LGAMMA_A_POLY_13_D,
+    LGAMMA_A_POLY_14_D, LGAMMA_A_POLY_15_D, LGAMMA_A_POLY_16_D, LGAMMA_A_POLY_17_D,
+    LGAMMA_A_POLY_18_D, LGAMMA_A_POLY_19_D, LGAMMA_A_POLY_1_D, LGAMMA_A_POLY_2_D,
+    LGAMMA_A_POLY_3_D, LGAMMA_A_POLY_4_D, LGAMMA_A_POLY_5_D, LGAMMA_A_POLY_6_D, LGAMMA_A_POLY_7_D,
+    LGAMMA_A_POLY_8_D, LGAMMA_A_POLY_9_D, LGAMMA_B_POLY_10_D, LGAMMA_B_POLY_11_D,
+    LGAMMA_B_POLY_12_D, LGAMMA_B_POLY_13_D, LGAMMA_B_POLY_14_D, LGAMMA_B_POLY_15_D,
+    LGAMMA_B_POLY_16_D, LGAMMA_B_POLY_17_D, LGAMMA_B_POLY_18_D, LGAMMA_B_POLY_19_D,
+    LGAMMA_B_POLY_1_D, LGAMMA_B_POLY_2_D, LGAMMA_B_POLY_3_D, LGAMMA_B_POLY_4_D, LGAMMA_B_POLY_5_D,
+    LGAMMA_B_POLY_6_D, LGAMMA_B_POLY_7_D, LGAMMA_B_POLY_8_D, LGAMMA_B_POLY_9_D, LGAMMA_H_POLY_10_D,
+    LGAMMA_H_POLY_11_D, LGAMMA_H_POLY_12_D, LGAMMA_H_POLY_13_D, LGAMMA_H_POLY_14_D,
+    LGAMMA_H_POLY_15_D, LGAMMA_H_POLY_1_D, LGAMMA_H_POLY_2_D, LGAMMA_H_POLY_3_D, LGAMMA_H_POLY_4_D,
+    LGAMMA_H_POLY_5_D, LGAMMA_H_POLY_6_D, LGAMMA_H_POLY_7_D, LGAMMA_H_POLY_8_D, LGAMMA_H_POLY_9_D,
+    LGAMMA_MIN_D, LGAMMA_MIN_LO_D, LN_PI_D, LN_SQRT_2PI_D,
+};
+use crate::neon::general::vmul_doubledq_f64;
+use crate::neon::ln::vlnk_doubledq_f64;
+use crate::neon::sin::vsinpikq_f64;
+use crate::neon::tgamma::vstirlingkq_f64;
+use crate::{vcopysignq_f64, visnotintegralq_f64, vlnq_f64, vmlafq_f64};
+use std::arch::aarch64::*;
+
+/// Computes ln|Γ(x)| and the sign of Γ(x), error bound *ULP 2.5* for positive arguments
+#[inline]
+pub unsafe fn vlgammaq_f64(d: float64x2_t) -> (float64x2_t, float64x2_t) {
+    let x = vabsq_f64(d);
+    // lnΓ(x) = (x - 0.5) ln(x) - x + ln(sqrt(2pi)) + 1/x S(1/x^2) for x >= 10
+    let e = vstirlingkq_f64((x, vdupq_n_f64(0f64)));
+    let mut big = vaddq_f64(e.0, vaddq_f64(e.1, vdupq_n_f64(LN_SQRT_2PI_D)));
+    // Everything past the leading terms of Stirling series is below an ulp
+    big = vbslq_f64(
+        vcgtq_f64(x, vdupq_n_f64(1152921504606846976f64)),
+        vmulq_f64(x, vsubq_f64(vlnq_f64(x), vdupq_n_f64(1f64))),
+        big,
+    );
+    // lnΓ(x) = lnΓ(2 + z) + ln((x - 1)...(x - n + 2)), z = x - n in [-0.5, 0.5] for x >= 1.75
+    let mid = vcgeq_f64(x, vdupq_n_f64(1.75f64));
+    let n = vrndnq_f64(x);
+    // Below 0.75 lnΓ(x) = lnΓ(x + 1) - ln(x), z is taken against shifted centers so x + 1 is never rounded
+    let shift = vcltq_f64(x, vdupq_n_f64(0.75f64));
+    let near_zero = vorrq_u64(
+        vcltq_f64(x, vdupq_n_f64(0.25f64)),
+        vandq_u64(
+            vcgeq_f64(x, vdupq_n_f64(0.75f64)),
+            vcltq_f64(x, vdupq_n_f64(1.25f64)),
+        ),
+    );
+    let z = vbslq_f64(
+        mid,
+        vsubq_f64(x, n),
+        vbslq_f64(shift, x, vsubq_f64(x, vdupq_n_f64(1f64))),
+    );
+    let pick = |a: f64, b: f64| vbslq_f64(mid, vdupq_n_f64(b), vdupq_n_f64(a));
+    let mut u = pick(LGAMMA_A_POLY_19_D, LGAMMA_B_POLY_19_D);
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_18_D, LGAMMA_B_POLY_18_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_17_D, LGAMMA_B_POLY_17_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_16_D, LGAMMA_B_POLY_16_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_15_D, LGAMMA_B_POLY_15_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_14_D, LGAMMA_B_POLY_14_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_13_D, LGAMMA_B_POLY_13_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_12_D, LGAMMA_B_POLY_12_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_11_D, LGAMMA_B_POLY_11_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_10_D, LGAMMA_B_POLY_10_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_9_D, LGAMMA_B_POLY_9_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_8_D, LGAMMA_B_POLY_8_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_7_D, LGAMMA_B_POLY_7_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_6_D, LGAMMA_B_POLY_6_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_5_D, LGAMMA_B_POLY_5_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_4_D, LGAMMA_B_POLY_4_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_3_D, LGAMMA_B_POLY_3_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_2_D, LGAMMA_B_POLY_2_D));
+    u = vmlafq_f64(u, z, pick(LGAMMA_A_POLY_1_D, LGAMMA_B_POLY_1_D));
+    let r = vmulq_f64(z, u);
+    // Around the minimum lnΓ(x0 + z) = lnΓ(x0) + z^2 H(z)
+    let zh = vbslq_f64(
+        shift,
+        vsubq_f64(
+            vsubq_f64(x, vdupq_n_f64(DIGAMMA_ROOT_M1_D)),
+            vdupq_n_f64(DIGAMMA_ROOT_M1_LO_D),
+        ),
+        vsubq_f64(
+            vsubq_f64(x, vdupq_n_f64(DIGAMMA_ROOT_D)),
+            vdupq_n_f64(DIGAMMA_ROOT_LO_D),
+        ),
+    );
+    let mut h = vdupq_n_f64(LGAMMA_H_POLY_15_D);
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_14_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_13_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_12_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_11_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_10_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_9_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_8_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_7_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_6_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_5_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_4_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_3_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_2_D));
+    h = vmlafq_f64(h, zh, vdupq_n_f64(LGAMMA_H_POLY_1_D));
+    let m = vaddq_f64(
+        vdupq_n_f64(LGAMMA_MIN_D),
+        vmlafq_f64(vmulq_f64(zh, zh), h, vdupq_n_f64(LGAMMA_MIN_LO_D)),
+    );
+    let r = vbslq_f64(vorrq_u64(mid, near_zero), r, m);
+    // The recurrence product, x itself when shifted and 1 elsewhere
+    let mut q = (vbslq_f64(shift, x, vdupq_n_f64(1f64)), vdupq_n_f64(0f64));
+    let k_max = vsubq_f64(n, vdupq_n_f64(2f64));
+    for k in 1..9 {
+        let kf = vdupq_n_f64(k as f64);
+        let f = vbslq_f64(vcleq_f64(kf, k_max), vsubq_f64(x, kf), vdupq_n_f64(1f64));
+        q = vmul_doubledq_f64(q, (f, vdupq_n_f64(0f64)));
+    }
+    let l = vlnk_doubledq_f64(q);
+    let small = vbslq_f64(
+        shift,
+        vsubq_f64(vsubq_f64(r, l.0), l.1),
+        vaddq_f64(l.0, vaddq_f64(r, l.1)),
+    );
+    let k = vbslq_f64(vcgeq_f64(x, vdupq_n_f64(10f64)), big, small);
+    // |Γ(d)| = pi / (|sin(pi d)| |d| Γ(|d|)) for negative d
+    let sp = vsinpikq_f64(d);
+    let reflect = vcltq_f64(d, vdupq_n_f64(0f64));
+    let mut ret = vbslq_f64(
+        reflect,
+        vsubq_f64(
+            vsubq_f64(vdupq_n_f64(LN_PI_D), vlnq_f64(vabsq_f64(vmulq_f64(sp, d)))),
+            k,
+        ),
+        k,
+    );
+    let mut sign = vbslq_f64(
+        vandq_u64(reflect, vcltq_f64(sp, vdupq_n_f64(0f64))),
+        vdupq_n_f64(-1f64),
+        vdupq_n_f64(1f64),
+    );
+    // lnΓ(d) = -ln|d| - γd + O(d^2)
+    let tiny = vcltq_f64(x, vdupq_n_f64(5.551115123125783e-17f64));
+    ret = vbslq_f64(tiny, vnegq_f64(vlnq_f64(x)), ret);
+    sign = vbslq_f64(tiny, vcopysignq_f64(vdupq_n_f64(1f64), d), sign);
+    // Poles, this also covers every d <= -2^52 and d == -Inf
+    let pole = vbicq_u64(reflect, visnotintegralq_f64(d));
+    ret = vbslq_f64(pole, vdupq_n_f64(f64::INFINITY), ret);
+    sign = vbslq_f64(pole, vdupq_n_f64(1f64), sign);
+    (ret, sign)
+}
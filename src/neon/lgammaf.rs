@@ -0,0 +1,129 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::digammaf::{DIGAMMA_ROOT_LO_S, DIGAMMA_ROOT_M1_LO_S, DIGAMMA_ROOT_M1_S, DIGAMMA_ROOT_S};
+use crate::lgammaf::{
+    LGAMMA_A_POLY_1_S, LGAMMA_A_POLY_2_S, LGAMMA_A_POLY_3_S, LGAMMA_A_POLY_4_S, LGAMMA_A_POLY_5_S,
+    LGAMMA_A_POLY_6_S, LGAMMA_A_POLY_7_S, LGAMMA_A_POLY_8_S, LGAMMA_A_POLY_9_S, LGAMMA_B_POLY_1_S,
+    LGAMMA_B_POLY_2_S, LGAMMA_B_POLY_3_S, LGAMMA_B_POLY_4_S, LGAMMA_B_POLY_5_S, LGAMMA_B_POLY_6_S,
+    LGAMMA_B_POLY_7_S, LGAMMA_B_POLY_8_S, LGAMMA_B_POLY_9_S, LGAMMA_H_POLY_1_S, LGAMMA_H_POLY_2_S,
+    LGAMMA_H_POLY_3_S, LGAMMA_H_POLY_4_S, LGAMMA_H_POLY_5_S, LGAMMA_H_POLY_6_S, LGAMMA_H_POLY_7_S,
+    LGAMMA_MIN_LO_S, LGAMMA_MIN_S, LN_PI_S, LN_SQRT_2PI_S,
+};
+use crate::neon::general::vmul_doubledq_f32;
+use crate::neon::lnf::vlnk_doubledq_f32;
+use crate::neon::sinf::vsinpikq_f32;
+use crate::neon::tgammaf::vstirlingkq_f32;
+use crate::{vcopysignq_f32, visnotintegralq_f32, vlnq_f32, vmlafq_f32};
+use std::arch::aarch64::*;
+
+/// Computes ln|Γ(x)| and the sign of Γ(x), error bound *ULP 2.5* for positive arguments
+#[inline]
+pub unsafe fn vlgammaq_f32(d: float32x4_t) -> (float32x4_t, float32x4_t) {
+    let x = vabsq_f32(d);
+    // lnΓ(x) = (x - 0.5) ln(x) - x + ln(sqrt(2pi)) + 1/x S(1/x^2) for x >= 5
+    let e = vstirlingkq_f32((x, vdupq_n_f32(0f32)));
+    let mut big = vaddq_f32(e.0, vaddq_f32(e.1, vdupq_n_f32(LN_SQRT_2PI_S)));
+    // Everything past the leading terms of Stirling series is below an ulp
+    big = vbslq_f32(
+        vcgtq_f32(x, vdupq_n_f32(1073741824f32)),
+        vmulq_f32(x, vsubq_f32(vlnq_f32(x), vdupq_n_f32(1f32))),
+        big,
+    );
+    // lnΓ(x) = lnΓ(2 + z) + ln((x - 1)...(x - n + 2)), z = x - n in [-0.5, 0.5] for x >= 1.75
+    let mid = vcgeq_f32(x, vdupq_n_f32(1.75f32));
+    let n = vrndnq_f32(x);
+    // Below 0.75 lnΓ(x) = lnΓ(x + 1) - ln(x), z is taken against shifted centers so x + 1 is never rounded
+    let shift = vcltq_f32(x, vdupq_n_f32(0.75f32));
+    let near_zero = vorrq_u32(
+        vcltq_f32(x, vdupq_n_f32(0.25f32)),
+        vandq_u32(
+            vcgeq_f32(x, vdupq_n_f32(0.75f32)),
+            vcltq_f32(x, vdupq_n_f32(1.25f32)),
+        ),
+    );
+    let z = vbslq_f32(
+        mid,
+        vsubq_f32(x, n),
+        vbslq_f32(shift, x, vsubq_f32(x, vdupq_n_f32(1f32))),
+    );
+    let pick = |a: f32, b: f32| vbslq_f32(mid, vdupq_n_f32(b), vdupq_n_f32(a));
+    let mut u = pick(LGAMMA_A_POLY_9_S, LGAMMA_B_POLY_9_S);
+    u = vmlafq_f32(u, z, pick(LGAMMA_A_POLY_8_S, LGAMMA_B_POLY_8_S));
+    u = vmlafq_f32(u, z, pick(LGAMMA_A_POLY_7_S, LGAMMA_B_POLY_7_S));
+    u = vmlafq_f32(u, z, pick(LGAMMA_A_POLY_6_S, LGAMMA_B_POLY_6_S));
+    u = vmlafq_f32(u, z, pick(LGAMMA_A_POLY_5_S, LGAMMA_B_POLY_5_S));
+    u = vmlafq_f32(u, z, pick(LGAMMA_A_POLY_4_S, LGAMMA_B_POLY_4_S));
+    u = vmlafq_f32(u, z, pick(LGAMMA_A_POLY_3_S, LGAMMA_B_POLY_3_S));
+    u = vmlafq_f32(u, z, pick(LGAMMA_A_POLY_2_S, LGAMMA_B_POLY_2_S));
+    u = vmlafq_f32(u, z, pick(LGAMMA_A_POLY_1_S, LGAMMA_B_POLY_1_S));
+    let r = vmulq_f32(z, u);
+    // Around the minimum lnΓ(x0 + z) = lnΓ(x0) + z^2 H(z)
+    let zh = vbslq_f32(
+        shift,
+        vsubq_f32(
+            vsubq_f32(x, vdupq_n_f32(DIGAMMA_ROOT_M1_S)),
+            vdupq_n_f32(DIGAMMA_ROOT_M1_LO_S),
+        ),
+        vsubq_f32(
+            vsubq_f32(x, vdupq_n_f32(DIGAMMA_ROOT_S)),
+            vdupq_n_f32(DIGAMMA_ROOT_LO_S),
+        ),
+    );
+    let mut h = vdupq_n_f32(LGAMMA_H_POLY_7_S);
+    h = vmlafq_f32(h, zh, vdupq_n_f32(LGAMMA_H_POLY_6_S));
+    h = vmlafq_f32(h, zh, vdupq_n_f32(LGAMMA_H_POLY_5_S));
+    h = vmlafq_f32(h, zh, vdupq_n_f32(LGAMMA_H_POLY_4_S));
+    h = vmlafq_f32(h, zh, vdupq_n_f32(LGAMMA_H_POLY_3_S));
+    h = vmlafq_f32(h, zh, vdupq_n_f32(LGAMMA_H_POLY_2_S));
+    h = vmlafq_f32(h, zh, vdupq_n_f32(LGAMMA_H_POLY_1_S));
+    let m = vaddq_f32(
+        vdupq_n_f32(LGAMMA_MIN_S),
+        vmlafq_f32(vmulq_f32(zh, zh), h, vdupq_n_f32(LGAMMA_MIN_LO_S)),
+    );
+    let r = vbslq_f32(vorrq_u32(mid, near_zero), r, m);
+    // The recurrence product, x itself when shifted and 1 elsewhere
+    let mut q = (vbslq_f32(shift, x, vdupq_n_f32(1f32)), vdupq_n_f32(0f32));
+    let k_max = vsubq_f32(n, vdupq_n_f32(2f32));
+    for k in 1..4 {
+        let kf = vdupq_n_f32(k as f32);
+        let f = vbslq_f32(vcleq_f32(kf, k_max), vsubq_f32(x, kf), vdupq_n_f32(1f32));
+        q = vmul_doubledq_f32(q, (f, vdupq_n_f32(0f32)));
+    }
+    let l = vlnk_doubledq_f32(q);
+    let small = vbslq_f32(
+        shift,
+        vsubq_f32(vsubq_f32(r, l.0), l.1),
+        vaddq_f32(l.0, vaddq_f32(r, l.1)),
+    );
+    let k = vbslq_f32(vcgeq_f32(x, vdupq_n_f32(5f32)), big, small);
+    // |Γ(d)| = pi / (|sin(pi d)| |d| Γ(|d|)) for negative d
+    let sp = vsinpikq_f32(d);
+    let reflect = vcltq_f32(d, vdupq_n_f32(0f32));
+    let mut ret = vbslq_f32(
+        reflect,
+        vsubq_f32(
+            vsubq_f32(vdupq_n_f32(LN_PI_S), vlnq_f32(vabsq_f32(vmulq_f32(sp, d)))),
+            k,
+        ),
+        k,
+    );
+    let mut sign = vbslq_f32(
+        vandq_u32(reflect, vcltq_f32(sp, vdupq_n_f32(0f32))),
+        vdupq_n_f32(-1f32),
+        vdupq_n_f32(1f32),
+    );
+    // lnΓ(d) = -ln|d| - γd + O(d^2)
+    let tiny = vcltq_f32(x, vdupq_n_f32(2.9802322e-8f32));
+    ret = vbslq_f32(tiny, vnegq_f32(vlnq_f32(x)), ret);
+    sign = vbslq_f32(tiny, vcopysignq_f32(vdupq_n_f32(1f32), d), sign);
+    // Poles, this also covers every d <= -2^23 and d == -Inf
+    let pole = vbicq_u32(reflect, visnotintegralq_f32(d));
+    ret = vbslq_f32(pole, vdupq_n_f32(f32::INFINITY), ret);
+    sign = vbslq_f32(pole, vdupq_n_f32(1f32), sign);
+    (ret, sign)
+}
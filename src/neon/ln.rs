@@ -5,17 +5,24 @@
  * // license that can be found in the LICENSE file.
  */
 
+use crate::exp::{L2_L, L2_U};
+use crate::ln::{
+    LN_DOUBLED_POLY_1_D, LN_DOUBLED_POLY_2_D, LN_DOUBLED_POLY_3_D, LN_DOUBLED_POLY_4_D,
+    LN_DOUBLED_POLY_5_D, LN_DOUBLED_POLY_6_D, LN_DOUBLED_POLY_7_D, LN_DOUBLED_POLY_8_D,
+    TWO_THIRDS_D, TWO_THIRDS_LO_D,
+};
 use crate::ln::{
     LN_POLY_1_D, LN_POLY_2_D, LN_POLY_3_D, LN_POLY_4_D, LN_POLY_5_D, LN_POLY_6_D, LN_POLY_7_D,
     LN_POLY_8_D,
 };
+use crate::log2::LOG_SCALE_D;
 use crate::neon::general::{vilogb2kq_f64, visinfq_f64, visnanq_f64, vldexp3kq_f64, vmlafq_f64};
+use crate::neon::general::{vmul_doubledq_f64, vmultiply_as_doubledq_f64, vsum_as_doubledq_f64};
 use std::arch::aarch64::*;
 
+/// Computes ln(a) for reduced argument a in [0.75, 1.5)
 #[inline]
-pub unsafe fn vlnq_f64(d: float64x2_t) -> float64x2_t {
-    let n = vilogb2kq_f64(vmulq_n_f64(d, 1f64 / 0.75f64));
-    let a = vldexp3kq_f64(d, vnegq_s64(n));
+pub(crate) unsafe fn vlnkq_f64(a: float64x2_t) -> float64x2_t {
     let ones = vdupq_n_f64(1f64);
     let x = vdivq_f64(vsubq_f64(a, ones), vaddq_f64(a, ones));
     let x2 = vmulq_f64(x, x);
@@ -26,12 +33,22 @@ pub unsafe fn vlnq_f64(d: float64x2_t) -> float64x2_t {
     u = vmlafq_f64(u, x2, vdupq_n_f64(LN_POLY_4_D));
     u = vmlafq_f64(u, x2, vdupq_n_f64(LN_POLY_3_D));
     u = vmlafq_f64(u, x2, vdupq_n_f64(LN_POLY_2_D));
-    u = vmlafq_f64(u, x2, vdupq_n_f64(LN_POLY_1_D));
-    let mut res = vmlafq_f64(
-        vdupq_n_f64(std::f64::consts::LN_2),
+    // Leading 2x term is exact, keeping it apart saves a rounding
+    vmlafq_f64(vmulq_f64(x, x2), u, vmulq_f64(x, vdupq_n_f64(LN_POLY_1_D)))
+}
+
+#[inline]
+pub unsafe fn vlnq_f64(d: float64x2_t) -> float64x2_t {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = vcltq_f64(d, vdupq_n_f64(f64::MIN_POSITIVE));
+    let x = vbslq_f64(o, vmulq_f64(d, vdupq_n_f64(LOG_SCALE_D)), d);
+    let n = vilogb2kq_f64(vmulq_n_f64(x, 1f64 / 0.75f64));
+    let a = vldexp3kq_f64(x, vnegq_s64(n));
+    let e = vsubq_f64(
         vcvtq_f64_s64(n),
-        vmulq_f64(x, u),
+        vbslq_f64(o, vdupq_n_f64(64f64), vdupq_n_f64(0f64)),
     );
+    let mut res = vmlafq_f64(vdupq_n_f64(std::f64::consts::LN_2), e, vlnkq_f64(a));
     // d == 0 -> -Inf
     res = vbslq_f64(vceqzq_f64(d), vdupq_n_f64(f64::NEG_INFINITY), res);
     // d == Inf -> Inf
@@ -44,3 +61,64 @@ pub unsafe fn vlnq_f64(d: float64x2_t) -> float64x2_t {
     );
     res
 }
+
+/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
+#[inline]
+pub unsafe fn vlnq_fast_f64(d: float64x2_t) -> float64x2_t {
+    let n = vilogb2kq_f64(vmulq_n_f64(d, 1f64 / 0.75f64));
+    let a = vldexp3kq_f64(d, vnegq_s64(n));
+    let res = vmlafq_f64(
+        vdupq_n_f64(std::f64::consts::LN_2),
+        vcvtq_f64_s64(n),
+        vlnkq_f64(a),
+    );
+    res
+}
+
+/// Computes ln(d.0 + d.1) as double-float for positive normal d
+#[inline]
+pub(crate) unsafe fn vlnk_doubledq_f64(
+    d: (float64x2_t, float64x2_t),
+) -> (float64x2_t, float64x2_t) {
+    let n = vilogb2kq_f64(vmulq_f64(d.0, vdupq_n_f64(1f64 / 0.75f64)));
+    let a = vldexp3kq_f64(d.0, vnegq_s64(n));
+    // x = (a - 1) / (a + 1) split into hi and lo, a - 1 is exact
+    let num = vsubq_f64(a, vdupq_n_f64(1f64));
+    let den = vsum_as_doubledq_f64(a, vdupq_n_f64(1f64));
+    let x = vdivq_f64(num, den.0);
+    let m = vmultiply_as_doubledq_f64(x, den.0);
+    let x_lo = vdivq_f64(
+        vsubq_f64(vsubq_f64(vsubq_f64(num, m.0), m.1), vmulq_f64(x, den.1)),
+        den.0,
+    );
+    // ln(a) = 2x + 2x^3/3 + x^5 R(x^2), the cubic term is too large to be rounded once
+    let x3 = vmul_doubledq_f64(vmultiply_as_doubledq_f64(x, x), (x, x_lo));
+    let t = vmul_doubledq_f64(
+        x3,
+        (vdupq_n_f64(TWO_THIRDS_D), vdupq_n_f64(TWO_THIRDS_LO_D)),
+    );
+    let x2 = vmulq_f64(x, x);
+    let mut u = vdupq_n_f64(LN_DOUBLED_POLY_8_D);
+    u = vmlafq_f64(u, x2, vdupq_n_f64(LN_DOUBLED_POLY_7_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(LN_DOUBLED_POLY_6_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(LN_DOUBLED_POLY_5_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(LN_DOUBLED_POLY_4_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(LN_DOUBLED_POLY_3_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(LN_DOUBLED_POLY_2_D));
+    u = vmlafq_f64(u, x2, vdupq_n_f64(LN_DOUBLED_POLY_1_D));
+    let nf = vcvtq_f64_s64(n);
+    let b = vsum_as_doubledq_f64(vaddq_f64(x, x), t.0);
+    let h = vsum_as_doubledq_f64(vmulq_f64(nf, vdupq_n_f64(L2_U)), b.0);
+    let tail = vmlafq_f64(
+        vmulq_f64(vmulq_f64(x2, x2), x),
+        u,
+        vmlafq_f64(nf, vdupq_n_f64(L2_L), vdivq_f64(d.1, d.0)),
+    );
+    (
+        h.0,
+        vaddq_f64(
+            h.1,
+            vaddq_f64(vaddq_f64(vaddq_f64(b.1, t.1), vaddq_f64(x_lo, x_lo)), tail),
+        ),
+    )
+}
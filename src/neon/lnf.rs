@@ -5,14 +5,30 @@
  * // license that can be found in the LICENSE file.
  */
 
+use crate::expf::{L2L_F, L2U_F};
+use crate::lnf::{
+    LN_DOUBLED_POLY_1_S, LN_DOUBLED_POLY_2_S, LN_DOUBLED_POLY_3_S, LN_DOUBLED_POLY_4_S,
+    TWO_THIRDS_LO_S, TWO_THIRDS_S,
+};
 use crate::lnf::{LN_POLY_1_F, LN_POLY_2_F, LN_POLY_3_F, LN_POLY_4_F, LN_POLY_5_F};
+use crate::log2f::LOG_SCALE_F;
 use crate::neon::general::{vilogb2kq_f32, visinfq_f32, visnanq_f32, vldexp3kq_f32, vmlafq_f32};
+use crate::neon::general::{vmul_doubledq_f32, vmultiply_as_doubledq_f32, vsum_as_doubledq_f32};
 use std::arch::aarch64::*;
 
 /// Computes natural logarithm for an argument *ULP 1.5*
 #[inline]
 pub unsafe fn vlnq_f32(d: float32x4_t) -> float32x4_t {
-    let mut res = vlnq_fast_f32(d);
+    // Subnormals are scaled to normal range before the exponent split
+    let o = vcltq_f32(d, vdupq_n_f32(f32::MIN_POSITIVE));
+    let x = vbslq_f32(o, vmulq_f32(d, vdupq_n_f32(LOG_SCALE_F)), d);
+    let n = vilogb2kq_f32(vmulq_n_f32(x, 1f32 / 0.75f32));
+    let a = vldexp3kq_f32(x, vnegq_s32(n));
+    let e = vsubq_f32(
+        vcvtq_f32_s32(n),
+        vbslq_f32(o, vdupq_n_f32(64f32), vdupq_n_f32(0f32)),
+    );
+    let mut res = vmlafq_f32(vdupq_n_f32(std::f32::consts::LN_2), e, vlnkq_f32(a));
     // d == 0 -> -Inf
     res = vbslq_f32(vceqzq_f32(d), vdupq_n_f32(f32::NEG_INFINITY), res);
     // d == Inf -> Inf
@@ -26,11 +42,9 @@ pub unsafe fn vlnq_f32(d: float32x4_t) -> float32x4_t {
     res
 }
 
-/// Method that computes ln skipping Inf, Nan checks
+/// Computes ln(a) for reduced argument a in [0.75, 1.5)
 #[inline]
-pub unsafe fn vlnq_fast_f32(d: float32x4_t) -> float32x4_t {
-    let n = vilogb2kq_f32(vmulq_n_f32(d, 1f32 / 0.75f32));
-    let a = vldexp3kq_f32(d, vnegq_s32(n));
+pub(crate) unsafe fn vlnkq_f32(a: float32x4_t) -> float32x4_t {
     let ones = vdupq_n_f32(1f32);
     let x = vdivq_f32(vsubq_f32(a, ones), vaddq_f32(a, ones));
     let x2 = vmulq_f32(x, x);
@@ -38,11 +52,63 @@ pub unsafe fn vlnq_fast_f32(d: float32x4_t) -> float32x4_t {
     u = vmlafq_f32(u, x2, vdupq_n_f32(LN_POLY_4_F));
     u = vmlafq_f32(u, x2, vdupq_n_f32(LN_POLY_3_F));
     u = vmlafq_f32(u, x2, vdupq_n_f32(LN_POLY_2_F));
-    u = vmlafq_f32(u, x2, vdupq_n_f32(LN_POLY_1_F));
+    // Leading 2x term is exact, keeping it apart saves a rounding
+    vmlafq_f32(vmulq_f32(x, x2), u, vmulq_f32(x, vdupq_n_f32(LN_POLY_1_F)))
+}
+
+/// Method that computes ln skipping Inf, Nan checks
+#[inline]
+pub unsafe fn vlnq_fast_f32(d: float32x4_t) -> float32x4_t {
+    let n = vilogb2kq_f32(vmulq_n_f32(d, 1f32 / 0.75f32));
+    let a = vldexp3kq_f32(d, vnegq_s32(n));
     let res = vmlafq_f32(
         vdupq_n_f32(std::f32::consts::LN_2),
         vcvtq_f32_s32(n),
-        vmulq_f32(x, u),
+        vlnkq_f32(a),
     );
     res
 }
+
+/// Computes ln(d.0 + d.1) as double-float for positive normal d
+#[inline]
+pub(crate) unsafe fn vlnk_doubledq_f32(
+    d: (float32x4_t, float32x4_t),
+) -> (float32x4_t, float32x4_t) {
+    let n = vilogb2kq_f32(vmulq_f32(d.0, vdupq_n_f32(1f32 / 0.75f32)));
+    let a = vldexp3kq_f32(d.0, vnegq_s32(n));
+    // x = (a - 1) / (a + 1) split into hi and lo, a - 1 is exact
+    let num = vsubq_f32(a, vdupq_n_f32(1f32));
+    let den = vsum_as_doubledq_f32(a, vdupq_n_f32(1f32));
+    let x = vdivq_f32(num, den.0);
+    let m = vmultiply_as_doubledq_f32(x, den.0);
+    let x_lo = vdivq_f32(
+        vsubq_f32(vsubq_f32(vsubq_f32(num, m.0), m.1), vmulq_f32(x, den.1)),
+        den.0,
+    );
+    // ln(a) = 2x + 2x^3/3 + x^5 R(x^2), the cubic term is too large to be rounded once
+    let x3 = vmul_doubledq_f32(vmultiply_as_doubledq_f32(x, x), (x, x_lo));
+    let t = vmul_doubledq_f32(
+        x3,
+        (vdupq_n_f32(TWO_THIRDS_S), vdupq_n_f32(TWO_THIRDS_LO_S)),
+    );
+    let x2 = vmulq_f32(x, x);
+    let mut u = vdupq_n_f32(LN_DOUBLED_POLY_4_S);
+    u = vmlafq_f32(u, x2, vdupq_n_f32(LN_DOUBLED_POLY_3_S));
+    u = vmlafq_f32(u, x2, vdupq_n_f32(LN_DOUBLED_POLY_2_S));
+    u = vmlafq_f32(u, x2, vdupq_n_f32(LN_DOUBLED_POLY_1_S));
+    let nf = vcvtq_f32_s32(n);
+    let b = vsum_as_doubledq_f32(vaddq_f32(x, x), t.0);
+    let h = vsum_as_doubledq_f32(vmulq_f32(nf, vdupq_n_f32(L2U_F)), b.0);
+    let tail = vmlafq_f32(
+        vmulq_f32(vmulq_f32(x2, x2), x),
+        u,
+        vmlafq_f32(nf, vdupq_n_f32(L2L_F), vdivq_f32(d.1, d.0)),
+    );
+    (
+        h.0,
+        vaddq_f32(
+            h.1,
+            vaddq_f32(vaddq_f32(vaddq_f32(b.1, t.1), vaddq_f32(x_lo, x_lo)), tail),
+        ),
+    )
+}
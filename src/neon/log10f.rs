@@ -0,0 +1,47 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::log2f::LOG_SCALE_F;
+use crate::neon::general::vldexp3kq_f32;
+use crate::neon::lnf::vlnkq_f32;
+use crate::{vilogb2kq_f32, visinfq_f32, visnanq_f32, vmlafq_f32};
+
+/// Computes log10 for an argument *ULP 4.5*
+#[inline]
+pub unsafe fn vlog10q_f32(d: float32x4_t) -> float32x4_t {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = vcltq_f32(d, vdupq_n_f32(f32::MIN_POSITIVE));
+    let x = vbslq_f32(o, vmulq_f32(d, vdupq_n_f32(LOG_SCALE_F)), d);
+    let n = vilogb2kq_f32(vmulq_f32(x, vdupq_n_f32(1f32 / 0.75f32)));
+    let a = vldexp3kq_f32(x, vnegq_s32(n));
+    let e = vsubq_f32(
+        vcvtq_f32_s32(n),
+        vbslq_f32(o, vdupq_n_f32(64f32), vdupq_n_f32(0f32)),
+    );
+    let mut res = vmlafq_f32(
+        vlnkq_f32(a),
+        vdupq_n_f32(std::f32::consts::LOG10_E),
+        vmulq_f32(e, vdupq_n_f32(std::f32::consts::LOG10_2)),
+    );
+    // d == 0 -> -Inf
+    res = vbslq_f32(
+        vceqq_f32(d, vdupq_n_f32(0f32)),
+        vdupq_n_f32(f32::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = vbslq_f32(visinfq_f32(d), vdupq_n_f32(f32::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = vbslq_f32(
+        vorrq_u32(vcltq_f32(d, vdupq_n_f32(0f32)), visnanq_f32(d)),
+        vdupq_n_f32(f32::NAN),
+        res,
+    );
+    res
+}
@@ -0,0 +1,44 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::neon::general::vldexp3kq_f64;
+use crate::neon::ln::vlnkq_f64;
+use crate::{vilogb2kq_f64, visinfq_f64, visnanq_f64, vmlafq_f64};
+
+/// Computes ln(1 + x), accurate for x near zero *ULP 2.5*
+#[inline]
+pub unsafe fn vlog1pq_f64(d: float64x2_t) -> float64x2_t {
+    let dp1 = vaddq_f64(d, vdupq_n_f64(1f64));
+    let n = vilogb2kq_f64(vmulq_f64(dp1, vdupq_n_f64(1f64 / 0.75f64)));
+    let a = vldexp3kq_f64(dp1, vnegq_s64(n));
+    // c is the rounding error of 1 + d relative to it
+    let c = vdivq_f64(vsubq_f64(d, vsubq_f64(dp1, vdupq_n_f64(1f64))), dp1);
+    let mut res = vmlafq_f64(
+        vdupq_n_f64(std::f64::consts::LN_2),
+        vcvtq_f64_s64(n),
+        vaddq_f64(vlnkq_f64(a), c),
+    );
+    // d == 0 -> d, keeps the sign of zero
+    res = vbslq_f64(vceqq_f64(d, vdupq_n_f64(0f64)), d, res);
+    // d == -1 -> -Inf
+    res = vbslq_f64(
+        vceqq_f64(d, vdupq_n_f64(-1f64)),
+        vdupq_n_f64(f64::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = vbslq_f64(visinfq_f64(d), vdupq_n_f64(f64::INFINITY), res);
+    // d < -1 || d == Nan -> Nan
+    res = vbslq_f64(
+        vorrq_u64(vcltq_f64(d, vdupq_n_f64(-1f64)), visnanq_f64(d)),
+        vdupq_n_f64(f64::NAN),
+        res,
+    );
+    res
+}
@@ -0,0 +1,44 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::neon::general::vldexp3kq_f32;
+use crate::neon::lnf::vlnkq_f32;
+use crate::{vilogb2kq_f32, visinfq_f32, visnanq_f32, vmlafq_f32};
+
+/// Computes ln(1 + x), accurate for x near zero *ULP 4.5*
+#[inline]
+pub unsafe fn vlog1pq_f32(d: float32x4_t) -> float32x4_t {
+    let dp1 = vaddq_f32(d, vdupq_n_f32(1f32));
+    let n = vilogb2kq_f32(vmulq_f32(dp1, vdupq_n_f32(1f32 / 0.75f32)));
+    let a = vldexp3kq_f32(dp1, vnegq_s32(n));
+    // c is the rounding error of 1 + d relative to it
+    let c = vdivq_f32(vsubq_f32(d, vsubq_f32(dp1, vdupq_n_f32(1f32))), dp1);
+    let mut res = vmlafq_f32(
+        vdupq_n_f32(std::f32::consts::LN_2),
+        vcvtq_f32_s32(n),
+        vaddq_f32(vlnkq_f32(a), c),
+    );
+    // d == 0 -> d, keeps the sign of zero
+    res = vbslq_f32(vceqq_f32(d, vdupq_n_f32(0f32)), d, res);
+    // d == -1 -> -Inf
+    res = vbslq_f32(
+        vceqq_f32(d, vdupq_n_f32(-1f32)),
+        vdupq_n_f32(f32::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = vbslq_f32(visinfq_f32(d), vdupq_n_f32(f32::INFINITY), res);
+    // d < -1 || d == Nan -> Nan
+    res = vbslq_f32(
+        vorrq_u32(vcltq_f32(d, vdupq_n_f32(-1f32)), visnanq_f32(d)),
+        vdupq_n_f32(f32::NAN),
+        res,
+    );
+    res
+}
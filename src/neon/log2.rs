@@ -0,0 +1,43 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::log2::LOG_SCALE_D;
+use crate::neon::general::vldexp3kq_f64;
+use crate::neon::ln::vlnkq_f64;
+use crate::{vilogb2kq_f64, visinfq_f64, visnanq_f64, vmlafq_f64};
+
+/// Computes log2 for an argument *ULP 3.5*
+#[inline]
+pub unsafe fn vlog2q_f64(d: float64x2_t) -> float64x2_t {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = vcltq_f64(d, vdupq_n_f64(f64::MIN_POSITIVE));
+    let x = vbslq_f64(o, vmulq_f64(d, vdupq_n_f64(LOG_SCALE_D)), d);
+    let n = vilogb2kq_f64(vmulq_f64(x, vdupq_n_f64(1f64 / 0.75f64)));
+    let a = vldexp3kq_f64(x, vnegq_s64(n));
+    let e = vsubq_f64(
+        vcvtq_f64_s64(n),
+        vbslq_f64(o, vdupq_n_f64(64f64), vdupq_n_f64(0f64)),
+    );
+    let mut res = vmlafq_f64(vlnkq_f64(a), vdupq_n_f64(std::f64::consts::LOG2_E), e);
+    // d == 0 -> -Inf
+    res = vbslq_f64(
+        vceqq_f64(d, vdupq_n_f64(0f64)),
+        vdupq_n_f64(f64::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = vbslq_f64(visinfq_f64(d), vdupq_n_f64(f64::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = vbslq_f64(
+        vorrq_u64(vcltq_f64(d, vdupq_n_f64(0f64)), visnanq_f64(d)),
+        vdupq_n_f64(f64::NAN),
+        res,
+    );
+    res
+}
@@ -7,19 +7,45 @@
 
 mod acos;
 mod acosf;
+mod acosh;
+mod acoshf;
 mod asin;
 mod asinf;
+mod asinh;
+mod asinhf;
+mod atan;
+mod atan2;
 mod atan2f;
 mod atanf;
+mod atanh;
+mod atanhf;
 mod cbrt;
 mod cbrtf;
 mod ceil;
 mod ceilf;
 mod cos;
 mod cosf;
+mod cosh;
+mod coshf;
+mod digamma;
+mod digammaf;
+mod divider;
+mod erf;
+mod erfc;
+mod erfcf;
+mod erff;
+mod erfinv;
+mod erfinvf;
 mod exp;
+mod exp10;
+mod exp10f;
+mod exp2;
+mod exp2f;
 mod expf;
+mod expm1;
+mod expm1f;
 mod float128;
+mod float128_math;
 mod floor;
 mod floorf;
 mod fmod;
@@ -31,22 +57,48 @@ mod hypot3f;
 mod hypot4;
 mod hypot4f;
 mod hypotf;
+mod lgamma;
+mod lgammaf;
 mod ln;
 mod lnf;
+mod log10;
+mod log10f;
+mod log1p;
+mod log1pf;
+mod log2;
+mod log2f;
+mod modular;
 mod poly128;
 mod pow;
 mod powf;
+mod precision;
 mod sin;
+mod sincos;
+mod sincosf;
 mod sinf;
+mod sinh;
+mod sinhf;
 mod tan;
 mod tanf;
+mod tanh;
+mod tanhf;
+mod tgamma;
+mod tgammaf;
 
 pub use acos::vacosq_f64;
 pub use acosf::vacosq_f32;
+pub use acosh::vacoshq_f64;
+pub use acoshf::vacoshq_f32;
 pub use asin::vasinq_f64;
 pub use asinf::vasinq_f32;
+pub use asinh::vasinhq_f64;
+pub use asinhf::vasinhq_f32;
+pub use atan::vatanq_f64;
+pub use atan2::vatan2q_f64;
 pub use atan2f::vatan2q_f32;
 pub use atanf::vatanq_f32;
+pub use atanh::vatanhq_f64;
+pub use atanhf::vatanhq_f32;
 pub use cbrt::vcbrtq_f64;
 pub use cbrt::vcbrtq_fast_f64;
 pub use cbrtf::vcbrtq_f32;
@@ -57,10 +109,31 @@ pub use ceilf::vceilq_f32;
 pub use ceilf::vceilq_ie_f32;
 pub use cos::vcosq_f64;
 pub use cosf::vcosq_f32;
-pub use exp::vexpq_f64;
+pub use cosh::vcoshq_f64;
+pub use coshf::vcoshq_f32;
+pub use digamma::vdigammaq_f64;
+pub use digammaf::vdigammaq_f32;
+pub use divider::vdivq_s32;
+pub use divider::vdivq_s64;
+pub use divider::vdivq_u32;
+pub use divider::vdivq_u64;
+pub use erf::verfq_f64;
+pub use erfc::verfcq_f64;
+pub use erfcf::verfcq_f32;
+pub use erff::verfq_f32;
+pub use erfinv::verfinvq_f64;
+pub use erfinvf::verfinvq_f32;
+pub use exp::{vexpq_f64, vexpq_fast_f64};
+pub use exp10::vexp10q_f64;
+pub use exp10f::vexp10q_f32;
+pub use exp2::vexp2q_f64;
+pub use exp2f::vexp2q_f32;
 pub use expf::vexpq_f32;
 pub use expf::vexpq_fast_f32;
+pub use expm1::vexpm1q_f64;
+pub use expm1f::vexpm1q_f32;
 pub use float128::*;
+pub use float128_math::{vcosq_f128, vexpq_f128, vlnq_f128, vpowq_f128, vsinq_f128, vsqrtq_f128};
 pub use floor::vfloorq_f64;
 pub use floor::vfloorq_ie_f64;
 pub use floorf::vfloorq_f32;
@@ -73,12 +146,16 @@ pub use general::vilogb2kq_f32;
 pub use general::vilogb2kq_f64;
 pub use general::visinfq_f32;
 pub use general::visinfq_f64;
+pub use general::visintegralq_f32;
+pub use general::visintegralq_f64;
 pub use general::visnanq_f32;
 pub use general::visnanq_f64;
 pub use general::visneginfq_f32;
 pub use general::visneginfq_f64;
 pub use general::visnotintegralq_f32;
 pub use general::visnotintegralq_f64;
+pub use general::vldexp2kq_f32;
+pub use general::vldexp2kq_f64;
 pub use general::vmlafq_f32;
 pub use general::vmlafq_f64;
 pub use general::vmlsfq_f32;
@@ -94,7 +171,7 @@ pub use general::vqshrn_n_u128;
 pub use general::vsignbitq_f32;
 pub use general::vsignbitq_f64;
 pub use general::*;
-pub use hypot::vhypotq_f64;
+pub use hypot::{vhypotq_f64, vhypotq_fast_f64};
 pub use hypot3::vhypot3q_f64;
 pub use hypot3::vhypot3q_fast_f64;
 pub use hypot3f::vhypot3q_f32;
@@ -105,18 +182,54 @@ pub use hypot4f::vhypot4q_f32;
 pub use hypot4f::vhypot4q_fast_f32;
 pub use hypotf::vhypotq_f32;
 pub use hypotf::vhypotq_fast_f32;
-pub use ln::vlnq_f64;
+pub use lgamma::vlgammaq_f64;
+pub use lgammaf::vlgammaq_f32;
+pub use ln::{vlnq_f64, vlnq_fast_f64};
 pub use lnf::vlnq_f32;
 pub use lnf::vlnq_fast_f32;
+pub use log10::vlog10q_f64;
+pub use log10f::vlog10q_f32;
+pub use log1p::vlog1pq_f64;
+pub use log1pf::vlog1pq_f32;
+pub use log2::vlog2q_f64;
+pub use log2f::vlog2q_f32;
+pub use modular::vaddmodq_u32;
+pub use modular::vaddmodq_u64;
+pub use modular::vbarrett_reduceq_u32;
+pub use modular::vbarrett_reduceq_u64;
+pub use modular::vfrom_montgomeryq_u32;
+pub use modular::vfrom_montgomeryq_u64;
+pub use modular::vintt_butterflyq_u32;
+pub use modular::vintt_butterflyq_u64;
+pub use modular::vmontgomery_reduceq_u32;
+pub use modular::vmontgomery_reduceq_u64;
+pub use modular::vmontmulq_u32;
+pub use modular::vmontmulq_u64;
+pub use modular::vmulmodq_u32;
+pub use modular::vmulmodq_u64;
+pub use modular::vntt_butterflyq_u32;
+pub use modular::vntt_butterflyq_u64;
+pub use modular::vsubmodq_u32;
+pub use modular::vsubmodq_u64;
+pub use modular::vto_montgomeryq_u32;
+pub use modular::vto_montgomeryq_u64;
 pub use poly128::vshl_n_s128;
 pub use poly128::vshl_n_u128;
 pub use poly128::vshlq_n_s128;
 pub use poly128::vshlq_n_u128;
 pub use poly128::*;
-pub use pow::vpowq_f64;
+pub use pow::{vpowq_f64, vpowq_fast_f64};
 pub use powf::vpowq_f32;
 pub use powf::vpowq_fast_f32;
 pub use sin::vsinq_f64;
+pub use sincos::vsincosq_f64;
+pub use sincosf::vsincosq_f32;
 pub use sinf::vsinq_f32;
+pub use sinh::vsinhq_f64;
+pub use sinhf::vsinhq_f32;
 pub use tan::vtanq_f64;
 pub use tanf::vtanq_f32;
+pub use tanh::vtanhq_f64;
+pub use tanhf::vtanhq_f32;
+pub use tgamma::vtgammaq_f64;
+pub use tgammaf::vtgammaq_f32;
@@ -0,0 +1,255 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::modular::{ModulusU32, ModulusU64};
+use crate::neon::general::vmulq_u64;
+use crate::neon::poly128::{u128x2_t, vmullq_u64};
+
+#[inline]
+/// Adds modulus to lanes holding negative i64
+unsafe fn vfixmodq_u64(a: uint64x2_t, n: uint64x2_t) -> uint64x2_t {
+    let sign = vreinterpretq_u64_s64(vshrq_n_s64::<63>(vreinterpretq_s64_u64(a)));
+    vaddq_u64(a, vandq_u64(sign, n))
+}
+
+#[inline]
+/// Adds modulus to lanes holding negative i32
+unsafe fn vfixmodq_u32(a: uint32x4_t, n: uint32x4_t) -> uint32x4_t {
+    let sign = vreinterpretq_u32_s32(vshrq_n_s32::<31>(vreinterpretq_s32_u32(a)));
+    vaddq_u32(a, vandq_u32(sign, n))
+}
+
+#[inline]
+/// Modular addition for u32
+pub unsafe fn vaddmodq_u32(a: uint32x4_t, b: uint32x4_t, m: ModulusU32) -> uint32x4_t {
+    let n = vdupq_n_u32(m.n);
+    vfixmodq_u32(vsubq_u32(vaddq_u32(a, b), n), n)
+}
+
+#[inline]
+/// Modular subtraction for u32
+pub unsafe fn vsubmodq_u32(a: uint32x4_t, b: uint32x4_t, m: ModulusU32) -> uint32x4_t {
+    vfixmodq_u32(vsubq_u32(a, b), vdupq_n_u32(m.n))
+}
+
+#[inline]
+/// Montgomery reduction, computes `x * R^-1 mod n` for u64 lanes holding `x < n * 2^32`,
+/// result is stored in u64 lanes
+pub unsafe fn vmontgomery_reduceq_u32(x: uint64x2_t, m: ModulusU32) -> uint64x2_t {
+    let q = vmul_u32(vmovn_u64(x), vdup_n_u32(m.n_inv));
+    let qn = vmull_u32(q, vdup_n_u32(m.n));
+    let t = vsubq_u64(vshrq_n_u64::<32>(x), vshrq_n_u64::<32>(qn));
+    vfixmodq_u64(t, vdupq_n_u64(m.n as u64))
+}
+
+#[inline]
+/// Barrett reduction, computes `x mod n` for u64 lanes holding `x < n^2`,
+/// result is stored in u64 lanes
+pub unsafe fn vbarrett_reduceq_u32(x: uint64x2_t, m: ModulusU32) -> uint64x2_t {
+    let n = vdupq_n_u64(m.n as u64);
+    let x1 = vshlq_u64(x, vdupq_n_s64(1 - m.bits as i64));
+    let p = vmull_u32(vmovn_u64(x1), vdup_n_u32(m.mu));
+    let q = vshlq_u64(p, vdupq_n_s64(-(m.bits as i64 + 1)));
+    let r = vsubq_u64(x, vmull_u32(vmovn_u64(q), vdup_n_u32(m.n)));
+    let r = vfixmodq_u64(vsubq_u64(r, n), n);
+    vfixmodq_u64(vsubq_u64(r, n), n)
+}
+
+#[inline]
+/// Montgomery multiplication for u32, computes `a * b * R^-1 mod n`
+pub unsafe fn vmontmulq_u32(a: uint32x4_t, b: uint32x4_t, m: ModulusU32) -> uint32x4_t {
+    let lo = vmontgomery_reduceq_u32(vmull_u32(vget_low_u32(a), vget_low_u32(b)), m);
+    let hi = vmontgomery_reduceq_u32(vmull_high_u32(a, b), m);
+    vuzp1q_u32(vreinterpretq_u32_u64(lo), vreinterpretq_u32_u64(hi))
+}
+
+#[inline]
+/// Modular multiplication for u32
+pub unsafe fn vmulmodq_u32(a: uint32x4_t, b: uint32x4_t, m: ModulusU32) -> uint32x4_t {
+    let lo = vbarrett_reduceq_u32(vmull_u32(vget_low_u32(a), vget_low_u32(b)), m);
+    let hi = vbarrett_reduceq_u32(vmull_high_u32(a, b), m);
+    vuzp1q_u32(vreinterpretq_u32_u64(lo), vreinterpretq_u32_u64(hi))
+}
+
+#[inline]
+/// Converts u32 into Montgomery form
+pub unsafe fn vto_montgomeryq_u32(a: uint32x4_t, m: ModulusU32) -> uint32x4_t {
+    vmontmulq_u32(a, vdupq_n_u32(m.r2), m)
+}
+
+#[inline]
+/// Converts u32 from Montgomery form
+pub unsafe fn vfrom_montgomeryq_u32(a: uint32x4_t, m: ModulusU32) -> uint32x4_t {
+    let lo = vmontgomery_reduceq_u32(vmovl_u32(vget_low_u32(a)), m);
+    let hi = vmontgomery_reduceq_u32(vmovl_high_u32(a), m);
+    vuzp1q_u32(vreinterpretq_u32_u64(lo), vreinterpretq_u32_u64(hi))
+}
+
+#[inline]
+/// Cooley-Tukey NTT butterfly for u32, returns `(a + w * b, a - w * b)`,
+/// twiddle `w` must be in Montgomery form
+pub unsafe fn vntt_butterflyq_u32(
+    a: uint32x4_t,
+    b: uint32x4_t,
+    w: uint32x4_t,
+    m: ModulusU32,
+) -> (uint32x4_t, uint32x4_t) {
+    let t = vmontmulq_u32(b, w, m);
+    (vaddmodq_u32(a, t, m), vsubmodq_u32(a, t, m))
+}
+
+#[inline]
+/// Gentleman-Sande inverse NTT butterfly for u32, returns `(a + b, (a - b) * w)`,
+/// twiddle `w` must be in Montgomery form
+pub unsafe fn vintt_butterflyq_u32(
+    a: uint32x4_t,
+    b: uint32x4_t,
+    w: uint32x4_t,
+    m: ModulusU32,
+) -> (uint32x4_t, uint32x4_t) {
+    let t = vsubmodq_u32(a, b, m);
+    (vaddmodq_u32(a, b, m), vmontmulq_u32(t, w, m))
+}
+
+#[inline]
+/// Modular addition for u64
+pub unsafe fn vaddmodq_u64(a: uint64x2_t, b: uint64x2_t, m: ModulusU64) -> uint64x2_t {
+    let n = vdupq_n_u64(m.n);
+    vfixmodq_u64(vsubq_u64(vaddq_u64(a, b), n), n)
+}
+
+#[inline]
+/// Modular subtraction for u64
+pub unsafe fn vsubmodq_u64(a: uint64x2_t, b: uint64x2_t, m: ModulusU64) -> uint64x2_t {
+    vfixmodq_u64(vsubq_u64(a, b), vdupq_n_u64(m.n))
+}
+
+#[inline]
+/// Montgomery reduction, computes `x * R^-1 mod n` for u128 holding `x < n * 2^64`
+pub unsafe fn vmontgomery_reduceq_u64(x: u128x2_t, m: ModulusU64) -> uint64x2_t {
+    let n = vdupq_n_u64(m.n);
+    let q = vmulq_u64(x.0, vdupq_n_u64(m.n_inv));
+    let qn = vmullq_u64(q, n);
+    vfixmodq_u64(vsubq_u64(x.1, qn.1), n)
+}
+
+#[inline]
+/// Barrett reduction, computes `x mod n` for u128 holding `x < n^2`
+pub unsafe fn vbarrett_reduceq_u64(x: u128x2_t, m: ModulusU64) -> uint64x2_t {
+    let n = vdupq_n_u64(m.n);
+    let bits = m.bits as i64;
+    let x1 = vorrq_u64(
+        vshlq_u64(x.0, vdupq_n_s64(1 - bits)),
+        vshlq_u64(x.1, vdupq_n_s64(65 - bits)),
+    );
+    let p = vmullq_u64(x1, vdupq_n_u64(m.mu));
+    let q = vorrq_u64(
+        vshlq_u64(p.0, vdupq_n_s64(-(bits + 1))),
+        vshlq_u64(p.1, vdupq_n_s64(63 - bits)),
+    );
+    let r = vsubq_u64(x.0, vmulq_u64(q, n));
+    let r = vfixmodq_u64(vsubq_u64(r, n), n);
+    vfixmodq_u64(vsubq_u64(r, n), n)
+}
+
+#[inline]
+/// Montgomery multiplication for u64, computes `a * b * R^-1 mod n`
+pub unsafe fn vmontmulq_u64(a: uint64x2_t, b: uint64x2_t, m: ModulusU64) -> uint64x2_t {
+    vmontgomery_reduceq_u64(vmullq_u64(a, b), m)
+}
+
+#[inline]
+/// Modular multiplication for u64
+pub unsafe fn vmulmodq_u64(a: uint64x2_t, b: uint64x2_t, m: ModulusU64) -> uint64x2_t {
+    vbarrett_reduceq_u64(vmullq_u64(a, b), m)
+}
+
+#[inline]
+/// Converts u64 into Montgomery form
+pub unsafe fn vto_montgomeryq_u64(a: uint64x2_t, m: ModulusU64) -> uint64x2_t {
+    vmontmulq_u64(a, vdupq_n_u64(m.r2), m)
+}
+
+#[inline]
+/// Converts u64 from Montgomery form
+pub unsafe fn vfrom_montgomeryq_u64(a: uint64x2_t, m: ModulusU64) -> uint64x2_t {
+    vmontgomery_reduceq_u64(u128x2_t(a, vdupq_n_u64(0)), m)
+}
+
+#[inline]
+/// Cooley-Tukey NTT butterfly for u64, returns `(a + w * b, a - w * b)`,
+/// twiddle `w` must be in Montgomery form
+pub unsafe fn vntt_butterflyq_u64(
+    a: uint64x2_t,
+    b: uint64x2_t,
+    w: uint64x2_t,
+    m: ModulusU64,
+) -> (uint64x2_t, uint64x2_t) {
+    let t = vmontmulq_u64(b, w, m);
+    (vaddmodq_u64(a, t, m), vsubmodq_u64(a, t, m))
+}
+
+#[inline]
+/// Gentleman-Sande inverse NTT butterfly for u64, returns `(a + b, (a - b) * w)`,
+/// twiddle `w` must be in Montgomery form
+pub unsafe fn vintt_butterflyq_u64(
+    a: uint64x2_t,
+    b: uint64x2_t,
+    w: uint64x2_t,
+    m: ModulusU64,
+) -> (uint64x2_t, uint64x2_t) {
+    let t = vsubmodq_u64(a, b, m);
+    (vaddmodq_u64(a, b, m), vmontmulq_u64(t, w, m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MODULI: [u64; 4] = [3, 998244353, 0x7fff_ffff, 4611686018326724609];
+
+    #[test]
+    fn test_modular() {
+        unsafe {
+            for &n in MODULI.iter() {
+                let vals = [0, 1, n / 3, n - 1, 0x9e37_79b9_7f4a_7c15 % n];
+                let w = vals[4];
+                for &a in vals.iter() {
+                    for &b in vals.iter() {
+                        let m = ModulusU64::new(n);
+                        let (va, vb) = (vdupq_n_u64(a), vdupq_n_u64(b));
+                        let vw = vdupq_n_u64(m.to_montgomery(w));
+                        let (a, b, w, n) = (a as u128, b as u128, w as u128, n as u128);
+                        let r = vmulmodq_u64(va, vb, m);
+                        assert_eq!(vgetq_lane_u64::<1>(r) as u128, a * b % n);
+                        let r = vmontmulq_u64(va, vb, m);
+                        assert_eq!(((vgetq_lane_u64::<0>(r) as u128) << 64) % n, a * b % n);
+                        let (x, y) = vntt_butterflyq_u64(va, vb, vw, m);
+                        assert_eq!(vgetq_lane_u64::<0>(x) as u128, (a + b * w % n) % n);
+                        assert_eq!(vgetq_lane_u64::<1>(y) as u128, (a + n - b * w % n) % n);
+
+                        if n >> 31 != 0 {
+                            continue;
+                        }
+                        let m = ModulusU32::new(n as u32);
+                        let (va, vb) = (vdupq_n_u32(a as u32), vdupq_n_u32(b as u32));
+                        let vw = vdupq_n_u32(m.to_montgomery(w as u32));
+                        let r = vmulmodq_u32(va, vb, m);
+                        assert_eq!(vgetq_lane_u32::<3>(r) as u128, a * b % n);
+                        let r = vmontmulq_u32(va, vb, m);
+                        assert_eq!(((vgetq_lane_u32::<2>(r) as u128) << 32) % n, a * b % n);
+                        let (x, y) = vintt_butterflyq_u32(va, vb, vw, m);
+                        assert_eq!(vgetq_lane_u32::<0>(x) as u128, (a + b) % n);
+                        assert_eq!(vgetq_lane_u32::<1>(y) as u128, (a + n - b) % n * w % n);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -5,6 +5,7 @@
  * // license that can be found in the LICENSE file.
  */
 
+use crate::neon::general::vmulq_u64;
 use std::arch::aarch64::*;
 
 /// Type represents u128, in low u64 and high u64 part
@@ -25,27 +26,25 @@ pub struct s128x1_t(int64x1_t, int64x1_t);
 ///
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
-pub struct u128x2_t(uint64x2_t, uint64x2_t);
+pub struct u128x2_t(pub(crate) uint64x2_t, pub(crate) uint64x2_t);
 
 /// Type represents i128, in low i64 and high i64 part
 /// Low parts and half parts are interleaved
 ///
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
-pub struct s128x2_t(int64x2_t, int64x2_t);
+pub struct s128x2_t(pub(crate) int64x2_t, pub(crate) int64x2_t);
 
-/// Computes i128 as i64 and extracts in general register
+/// Computes i128 as i64 and extracts lower half in general register
 #[inline]
 pub unsafe fn vextract_s128(d: s128x1_t) -> i64 {
-    let product = vadd_s64(d.0, d.1);
-    vget_lane_s64::<0>(product)
+    vget_lane_s64::<0>(d.0)
 }
 
-/// Computes u128 as u64 and extracts in general register
+/// Computes u128 as u64 and extracts lower half in general register
 #[inline]
 pub unsafe fn vextract_u128(d: u128x1_t) -> u64 {
-    let product = vadd_u64(d.0, d.1);
-    vget_lane_u64::<0>(product)
+    vget_lane_u64::<0>(d.0)
 }
 
 /// Computes i128 as i64 and extracts in general register
@@ -71,9 +70,9 @@ pub unsafe fn vextractq_q_u128<const IMM: i32>(d: u128x2_t) -> u128 {
 #[inline]
 /// Computes i128 as u128 and extracts in general register
 pub unsafe fn vextractq_q_s128<const IMM: i32>(d: s128x2_t) -> i128 {
-    let v0 = vgetq_lane_s64::<IMM>(d.0) as i128;
-    let v1 = vgetq_lane_s64::<IMM>(d.1) as i128;
-    v0 | (v1 << 64)
+    let v0 = vgetq_lane_s64::<IMM>(d.0) as u64 as u128;
+    let v1 = vgetq_lane_s64::<IMM>(d.1) as u64 as u128;
+    (v0 | (v1 << 64)) as i128
 }
 
 /// Casts uint128 to int128
@@ -88,16 +87,22 @@ pub unsafe fn vreinterpretq_u128_s128(d: u128x2_t) -> s128x2_t {
     s128x2_t(vreinterpretq_s64_u64(d.0), vreinterpretq_s64_u64(d.1))
 }
 
+#[inline]
+/// Casts int128 to uint128
+pub unsafe fn vreinterpretq_s128_u128(d: s128x2_t) -> u128x2_t {
+    u128x2_t(vreinterpretq_u64_s64(d.0), vreinterpretq_u64_s64(d.1))
+}
+
 /// Broadcasts u64 into u128
 #[inline]
 pub unsafe fn vmovl_u64(v: uint64x1_t) -> u128x1_t {
     u128x1_t(v, vdup_n_u64(0u64))
 }
 
-/// Broadcasts u64 into u128
+/// Broadcasts i64 into i128
 #[inline]
 pub unsafe fn vmovl_s64(v: int64x1_t) -> s128x1_t {
-    s128x1_t(v, vdup_n_s64(0i64))
+    s128x1_t(v, vshr_n_s64::<63>(v))
 }
 
 /// Convert u64x2 low part as a low part of u128, and high as high of u128
@@ -107,14 +112,15 @@ pub unsafe fn vcvtq_u128_u64(v: uint64x2_t) -> u128x1_t {
 }
 
 #[inline]
+/// Narrows u128 into u64, keeps only lower half
 pub unsafe fn vmovnq_u128(v: u128x2_t) -> uint64x2_t {
-    vaddq_u64(v.0, v.1)
+    v.0
 }
 
 #[inline]
+/// Narrows u128 into u64, keeps only lower half
 pub unsafe fn vmovn_u128(p: u128x1_t) -> uint64x1_t {
-    let v0 = vadd_u64(p.0, p.1);
-    v0
+    p.0
 }
 
 /// Convert u64x2 low part as a low part of u128, and high as high of u128
@@ -124,61 +130,31 @@ pub unsafe fn vcreate_u128(low: uint64x1_t, high: uint64x1_t) -> u128x1_t {
 }
 
 #[inline]
-/// Widening multiplication u64 in u128
+/// Widening multiplication i64 in i128
 pub unsafe fn vmull_s64(a: int64x1_t, b: int64x1_t) -> s128x1_t {
-    let sign_ab = vshr_n_u64::<63>(vreinterpret_u64_s64(a));
-    let sign_cd = vshr_n_u64::<63>(vreinterpret_u64_s64(b));
-    let sign = veor_u64(sign_ab, sign_cd);
-    let uab = vreinterpret_u64_s64(vabs_s64(a));
-    let ucd = vreinterpret_u64_s64(vabs_s64(b));
-    let product = vmull_u64(uab, ucd);
-    let is_not_negative = vceqz_u64(sign);
-    let lo = vbsl_u64(
-        is_not_negative,
-        product.0,
-        vreinterpret_u64_u32(vmvn_u32(vreinterpret_u32_u64(product.0))),
+    let product = vmull_u64(vreinterpret_u64_s64(a), vreinterpret_u64_s64(b));
+    let correction = vadd_s64(
+        vand_s64(vshr_n_s64::<63>(a), b),
+        vand_s64(vshr_n_s64::<63>(b), a),
     );
-    let hi = vbsl_u64(
-        is_not_negative,
-        product.1,
-        vreinterpret_u64_u32(vmvn_u32(vreinterpret_u32_u64(product.1))),
-    );
-    let v0 = vbsl_s64(
-        is_not_negative,
-        vreinterpret_s64_u64(lo),
-        vadd_s64(vreinterpret_s64_u64(lo), vdup_n_s64(1)),
-    );
-    let v1 = vreinterpret_s64_u64(hi);
-    s128x1_t(v0, v1)
+    s128x1_t(
+        vreinterpret_s64_u64(product.0),
+        vsub_s64(vreinterpret_s64_u64(product.1), correction),
+    )
 }
 
 #[inline]
-/// Widening multiplication u64 in u128
+/// Widening multiplication i64 in i128
 pub unsafe fn vmullq_s64(a: int64x2_t, b: int64x2_t) -> s128x2_t {
-    let sign_ab = vshrq_n_u64::<63>(vreinterpretq_u64_s64(a));
-    let sign_cd = vshrq_n_u64::<63>(vreinterpretq_u64_s64(b));
-    let sign = veorq_u64(sign_ab, sign_cd);
-    let uab = vreinterpretq_u64_s64(vabsq_s64(a));
-    let ucd = vreinterpretq_u64_s64(vabsq_s64(b));
-    let product = vmullq_u64(uab, ucd);
-    let is_not_negative = vceqzq_u64(sign);
-    let lo = vbslq_u64(
-        is_not_negative,
-        product.0,
-        vreinterpretq_u64_u32(vmvnq_u32(vreinterpretq_u32_u64(product.0))),
-    );
-    let hi = vbslq_u64(
-        is_not_negative,
-        product.1,
-        vreinterpretq_u64_u32(vmvnq_u32(vreinterpretq_u32_u64(product.1))),
-    );
-    let v0 = vbslq_s64(
-        is_not_negative,
-        vreinterpretq_s64_u64(lo),
-        vaddq_s64(vreinterpretq_s64_u64(lo), vdupq_n_s64(1)),
+    let product = vmullq_u64(vreinterpretq_u64_s64(a), vreinterpretq_u64_s64(b));
+    let correction = vaddq_s64(
+        vandq_s64(vshrq_n_s64::<63>(a), b),
+        vandq_s64(vshrq_n_s64::<63>(b), a),
     );
-    let v1 = vreinterpretq_s64_u64(hi);
-    s128x2_t(v0, v1)
+    s128x2_t(
+        vreinterpretq_s64_u64(product.0),
+        vsubq_s64(vreinterpretq_s64_u64(product.1), correction),
+    )
 }
 
 #[inline]
@@ -219,78 +195,54 @@ pub unsafe fn vmullq_u64(a: uint64x2_t, b: uint64x2_t) -> u128x2_t {
 #[inline]
 /// Widening add u64 to u128
 pub unsafe fn vaddw_u64(a: u128x1_t, b: uint64x1_t) -> u128x1_t {
-    let r0 = vadd_u64(a.0, b);
-    let v = vsub_u64(r0, a.0);
-    u128x1_t(
-        r0,
-        vadd_u64(
-            vsub_u64(a.0, vsub_u64(r0, v)),
-            vadd_u64(vsub_u64(b, v), a.1),
-        ),
-    )
+    let j0 = vadd_u64(a.0, b);
+    let overflow = vclt_u64(j0, a.0);
+    u128x1_t(j0, vsub_u64(a.1, overflow))
 }
 
 #[inline]
 /// Widening add i64 to i128
 pub unsafe fn vaddw_s64(a: s128x1_t, b: int64x1_t) -> s128x1_t {
     let j0 = vadd_s64(a.0, b);
-    //   let overflow = ((!(a ^ b) & (a ^ sum)) < 0);
-    let overflow = vcltz_s64(vand_s64(
-        vreinterpret_s64_s32(vmvn_s32(vreinterpret_s32_s64(veor_s64(a.0, b)))),
-        veor_s64(a.0, j0),
-    ));
-    let mut carry = vdup_n_s64(1);
-    carry = vbsl_s64(vcltz_s64(b), vdup_n_s64(-1), carry);
-    let hi = vbsl_s64(overflow, vadd_s64(a.1, carry), a.1);
-    s128x1_t(j0, hi)
+    let overflow = vclt_u64(vreinterpret_u64_s64(j0), vreinterpret_u64_s64(a.0));
+    let hi = vadd_s64(a.1, vshr_n_s64::<63>(b));
+    s128x1_t(j0, vsub_s64(hi, vreinterpret_s64_u64(overflow)))
 }
 
 #[inline]
-
 /// Widening add u64 to u128
 pub unsafe fn vaddwq_u64(a: u128x2_t, b: uint64x2_t) -> u128x2_t {
     let j0 = vaddq_u64(a.0, b);
     let overflow = vcltq_u64(j0, a.0);
-    let hi = vbslq_u64(overflow, vaddq_u64(a.1, vdupq_n_u64(1)), a.1);
-    u128x2_t(j0, hi)
+    u128x2_t(j0, vsubq_u64(a.1, overflow))
 }
 
 #[inline]
 /// Adds u128 to u128
 pub unsafe fn vaddq_u128(a: u128x2_t, b: u128x2_t) -> u128x2_t {
     let lo = vaddq_u64(a.0, b.0);
-    let carry = vbslq_u64(vcltq_u64(lo, a.0), vdupq_n_u64(1), vdupq_n_u64(0));
-    u128x2_t(lo, vaddq_u64(vaddq_u64(a.1, b.1), carry))
+    let carry = vcltq_u64(lo, a.0);
+    u128x2_t(lo, vsubq_u64(vaddq_u64(a.1, b.1), carry))
 }
 
 #[inline]
 /// Adds s128 to s128
 pub unsafe fn vaddq_s128(a: s128x2_t, b: s128x2_t) -> s128x2_t {
     let lo = vaddq_s64(a.0, b.0);
-
-    let overflow = vcltzq_s64(vandq_s64(
-        vreinterpretq_s64_s32(vmvnq_s32(vreinterpretq_s32_s64(veorq_s64(a.0, b.0)))),
-        veorq_s64(a.0, lo),
-    ));
-
-    let mut carry = vbslq_s64(overflow, vdupq_n_s64(1), vdupq_n_s64(0));
-    carry = vbslq_s64(vcltzq_s64(b.1), vdupq_n_s64(-1), carry);
-    s128x2_t(lo, vaddq_s64(vaddq_s64(a.1, b.1), carry))
+    let carry = vcltq_u64(vreinterpretq_u64_s64(lo), vreinterpretq_u64_s64(a.0));
+    s128x2_t(
+        lo,
+        vsubq_s64(vaddq_s64(a.1, b.1), vreinterpretq_s64_u64(carry)),
+    )
 }
 
 #[inline]
 /// Widening add i64 to i128
 pub unsafe fn vaddwq_s64(a: s128x2_t, b: int64x2_t) -> s128x2_t {
     let j0 = vaddq_s64(a.0, b);
-    //   let overflow = ((!(a ^ b) & (a ^ sum)) < 0);
-    let overflow = vcltzq_s64(vandq_s64(
-        vreinterpretq_s64_s32(vmvnq_s32(vreinterpretq_s32_s64(veorq_s64(a.0, b)))),
-        veorq_s64(a.0, j0),
-    ));
-    let mut carry = vdupq_n_s64(1);
-    carry = vbslq_s64(vcltzq_s64(b), vdupq_n_s64(-1), carry);
-    let hi = vbslq_s64(overflow, vaddq_s64(a.1, carry), a.1);
-    s128x2_t(j0, hi)
+    let overflow = vcltq_u64(vreinterpretq_u64_s64(j0), vreinterpretq_u64_s64(a.0));
+    let hi = vaddq_s64(a.1, vshrq_n_s64::<63>(b));
+    s128x2_t(j0, vsubq_s64(hi, vreinterpretq_s64_u64(overflow)))
 }
 
 #[inline]
@@ -304,7 +256,7 @@ pub unsafe fn vshr_n_u128<const IMM: i32>(a: u128x1_t) -> u128x1_t {
         let new_upper = vshr_n_u64::<IMM>(a.1);
         u128x1_t(new_lower, new_upper)
     } else {
-        let shr_value = vdup_n_s64(-(64i64 - IMM as i64));
+        let shr_value = vdup_n_s64(64i64 - IMM as i64);
         u128x1_t(vshl_u64(a.1, shr_value), vdup_n_u64(0))
     };
 }
@@ -320,7 +272,7 @@ pub unsafe fn vshrq_n_u128<const IMM: i32>(a: u128x2_t) -> u128x2_t {
         let new_upper = vshrq_n_u64::<IMM>(a.1);
         u128x2_t(new_lower, new_upper)
     } else {
-        let shr_value = vdupq_n_s64(-(64i64 - IMM as i64));
+        let shr_value = vdupq_n_s64(64i64 - IMM as i64);
         u128x2_t(vshlq_u64(a.1, shr_value), vdupq_n_u64(0))
     };
 }
@@ -332,12 +284,15 @@ pub unsafe fn vshr_n_s128<const IMM: i32>(a: s128x1_t) -> s128x1_t {
         a
     } else if IMM < 64 {
         let upper_shift = vdup_n_s64(64i64 - IMM as i64);
-        let new_lower = vorr_s64(vshl_s64(a.1, upper_shift), vshr_n_s64::<IMM>(a.0));
+        let new_lower = vorr_u64(
+            vshl_u64(vreinterpret_u64_s64(a.1), upper_shift),
+            vshr_n_u64::<IMM>(vreinterpret_u64_s64(a.0)),
+        );
         let new_upper = vshr_n_s64::<IMM>(a.1);
-        s128x1_t(new_lower, new_upper)
+        s128x1_t(vreinterpret_s64_u64(new_lower), new_upper)
     } else {
-        let shr_value = vdup_n_s64(-(64i64 - IMM as i64));
-        s128x1_t(vshl_s64(a.1, shr_value), vdup_n_s64(0))
+        let shr_value = vdup_n_s64(64i64 - IMM as i64);
+        s128x1_t(vshl_s64(a.1, shr_value), vshr_n_s64::<63>(a.1))
     };
 }
 
@@ -348,12 +303,15 @@ pub unsafe fn vshrq_n_s128<const IMM: i32>(a: s128x2_t) -> s128x2_t {
         a
     } else if IMM < 64 {
         let upper_shift = vdupq_n_s64(64i64 - IMM as i64);
-        let new_lower = vorrq_s64(vshlq_s64(a.1, upper_shift), vshrq_n_s64::<IMM>(a.0));
+        let new_lower = vorrq_u64(
+            vshlq_u64(vreinterpretq_u64_s64(a.1), upper_shift),
+            vshrq_n_u64::<IMM>(vreinterpretq_u64_s64(a.0)),
+        );
         let new_upper = vshrq_n_s64::<IMM>(a.1);
-        s128x2_t(new_lower, new_upper)
+        s128x2_t(vreinterpretq_s64_u64(new_lower), new_upper)
     } else {
-        let shr_value = vdupq_n_s64(-(64i64 - IMM as i64));
-        s128x2_t(vshlq_s64(a.1, shr_value), vdupq_n_s64(0))
+        let shr_value = vdupq_n_s64(64i64 - IMM as i64);
+        s128x2_t(vshlq_s64(a.1, shr_value), vshrq_n_s64::<63>(a.1))
     };
 }
 
@@ -362,7 +320,7 @@ pub unsafe fn vshrq_n_s128<const IMM: i32>(a: s128x2_t) -> s128x2_t {
 pub unsafe fn vshl_n_u128<const IMM: i32>(a: u128x1_t) -> u128x1_t {
     if IMM >= 64 {
         let v_imm = vdup_n_s64(IMM as i64 - 64i64);
-        u128x1_t(vdup_n_u64(0), vshl_u64(a.1, v_imm))
+        u128x1_t(vdup_n_u64(0), vshl_u64(a.0, v_imm))
     } else {
         let v_imm = vdup_n_s64(IMM as i64);
         let lo = vshl_u64(a.0, v_imm);
@@ -378,7 +336,7 @@ pub unsafe fn vshl_n_u128<const IMM: i32>(a: u128x1_t) -> u128x1_t {
 pub unsafe fn vshlq_n_u128<const IMM: i32>(a: u128x2_t) -> u128x2_t {
     if IMM >= 64 {
         let v_imm = vdupq_n_s64(IMM as i64 - 64i64);
-        u128x2_t(vdupq_n_u64(0), vshlq_u64(a.1, v_imm))
+        u128x2_t(vdupq_n_u64(0), vshlq_u64(a.0, v_imm))
     } else {
         let v_imm = vdupq_n_s64(IMM as i64);
         let lo = vshlq_u64(a.0, v_imm);
@@ -390,33 +348,38 @@ pub unsafe fn vshlq_n_u128<const IMM: i32>(a: u128x2_t) -> u128x2_t {
 }
 
 #[inline]
-
 /// Shifts left s128 immediate
 pub unsafe fn vshl_n_s128<const IMM: i32>(a: s128x1_t) -> s128x1_t {
     if IMM >= 64 {
         let v_imm = vdup_n_s64(IMM as i64 - 64i64);
-        s128x1_t(vdup_n_s64(0), vshl_s64(a.1, v_imm))
+        s128x1_t(vdup_n_s64(0), vshl_s64(a.0, v_imm))
     } else {
         let v_imm = vdup_n_s64(IMM as i64);
         let lo = vshl_s64(a.0, v_imm);
         let mut hi = vshl_s64(a.1, v_imm);
-        let overflow = vshl_s64(a.0, vdup_n_s64(-(64i64 - IMM as i64)));
+        let overflow = vreinterpret_s64_u64(vshl_u64(
+            vreinterpret_u64_s64(a.0),
+            vdup_n_s64(-(64i64 - IMM as i64)),
+        ));
         hi = vorr_s64(hi, overflow);
         s128x1_t(lo, hi)
     }
 }
 
 #[inline]
-/// Shifts right s128 immediate
+/// Shifts left s128 immediate
 pub unsafe fn vshlq_n_s128<const IMM: i32>(a: s128x2_t) -> s128x2_t {
     if IMM >= 64 {
         let v_imm = vdupq_n_s64(IMM as i64 - 64i64);
-        s128x2_t(vdupq_n_s64(0), vshlq_s64(a.1, v_imm))
+        s128x2_t(vdupq_n_s64(0), vshlq_s64(a.0, v_imm))
     } else {
         let v_imm = vdupq_n_s64(IMM as i64);
         let lo = vshlq_s64(a.0, v_imm);
         let mut hi = vshlq_s64(a.1, v_imm);
-        let overflow = vshlq_s64(a.0, vdupq_n_s64(-(64i64 - IMM as i64)));
+        let overflow = vreinterpretq_s64_u64(vshlq_u64(
+            vreinterpretq_u64_s64(a.0),
+            vdupq_n_s64(-(64i64 - IMM as i64)),
+        ));
         hi = vorrq_s64(hi, overflow);
         s128x2_t(lo, hi)
     }
@@ -426,16 +389,322 @@ pub unsafe fn vshlq_n_s128<const IMM: i32>(a: s128x2_t) -> s128x2_t {
 /// Absolute value for i128
 pub unsafe fn vabsq_s128(a: s128x2_t) -> s128x2_t {
     let is_neg = vcltzq_s64(a.1);
+    let lo_zero = vreinterpretq_s64_u64(vceqzq_s64(a.0));
+    let neg_hi = vsubq_s64(
+        vreinterpretq_s64_s32(vmvnq_s32(vreinterpretq_s32_s64(a.1))),
+        lo_zero,
+    );
     s128x2_t(
         vbslq_s64(is_neg, vnegq_s64(a.0), a.0),
-        vbslq_s64(is_neg, vnegq_s64(a.1), a.1),
+        vbslq_s64(is_neg, neg_hi, a.1),
+    )
+}
+
+#[inline]
+/// Subtracts u128 from u128
+pub unsafe fn vsubq_u128(a: u128x2_t, b: u128x2_t) -> u128x2_t {
+    let lo = vsubq_u64(a.0, b.0);
+    let borrow = vcltq_u64(a.0, b.0);
+    u128x2_t(lo, vaddq_u64(vsubq_u64(a.1, b.1), borrow))
+}
+
+#[inline]
+/// Subtracts s128 from s128
+pub unsafe fn vsubq_s128(a: s128x2_t, b: s128x2_t) -> s128x2_t {
+    let v = vsubq_u128(vreinterpretq_s128_u128(a), vreinterpretq_s128_u128(b));
+    vreinterpretq_u128_s128(v)
+}
+
+#[inline]
+/// Multiplies u128, takes only lower 128 bits
+pub unsafe fn vmulq_u128(a: u128x2_t, b: u128x2_t) -> u128x2_t {
+    let product = vmullq_u64(a.0, b.0);
+    let cross = vaddq_u64(vmulq_u64(a.0, b.1), vmulq_u64(a.1, b.0));
+    u128x2_t(product.0, vaddq_u64(product.1, cross))
+}
+
+#[inline]
+/// Multiplies s128, takes only lower 128 bits
+pub unsafe fn vmulq_s128(a: s128x2_t, b: s128x2_t) -> s128x2_t {
+    let v = vmulq_u128(vreinterpretq_s128_u128(a), vreinterpretq_s128_u128(b));
+    vreinterpretq_u128_s128(v)
+}
+
+#[inline]
+/// Compare *equal to* u128
+pub unsafe fn vceqq_u128(a: u128x2_t, b: u128x2_t) -> uint64x2_t {
+    vandq_u64(vceqq_u64(a.0, b.0), vceqq_u64(a.1, b.1))
+}
+
+#[inline]
+/// Compare *greater than* u128
+pub unsafe fn vcgtq_u128(a: u128x2_t, b: u128x2_t) -> uint64x2_t {
+    let lo_gt = vandq_u64(vceqq_u64(a.1, b.1), vcgtq_u64(a.0, b.0));
+    vorrq_u64(vcgtq_u64(a.1, b.1), lo_gt)
+}
+
+#[inline]
+/// Compare *greater than or equal to* u128
+pub unsafe fn vcgeq_u128(a: u128x2_t, b: u128x2_t) -> uint64x2_t {
+    let lo_ge = vandq_u64(vceqq_u64(a.1, b.1), vcgeq_u64(a.0, b.0));
+    vorrq_u64(vcgtq_u64(a.1, b.1), lo_ge)
+}
+
+#[inline]
+/// Compare *less than* u128
+pub unsafe fn vcltq_u128(a: u128x2_t, b: u128x2_t) -> uint64x2_t {
+    vcgtq_u128(b, a)
+}
+
+#[inline]
+/// Compare *less than or equal to* u128
+pub unsafe fn vcleq_u128(a: u128x2_t, b: u128x2_t) -> uint64x2_t {
+    vcgeq_u128(b, a)
+}
+
+#[inline]
+/// Compare *equal to* s128
+pub unsafe fn vceqq_s128(a: s128x2_t, b: s128x2_t) -> uint64x2_t {
+    vandq_u64(vceqq_s64(a.0, b.0), vceqq_s64(a.1, b.1))
+}
+
+#[inline]
+/// Compare *greater than* s128
+pub unsafe fn vcgtq_s128(a: s128x2_t, b: s128x2_t) -> uint64x2_t {
+    let lo_gt = vcgtq_u64(vreinterpretq_u64_s64(a.0), vreinterpretq_u64_s64(b.0));
+    vorrq_u64(vcgtq_s64(a.1, b.1), vandq_u64(vceqq_s64(a.1, b.1), lo_gt))
+}
+
+#[inline]
+/// Compare *greater than or equal to* s128
+pub unsafe fn vcgeq_s128(a: s128x2_t, b: s128x2_t) -> uint64x2_t {
+    let lo_ge = vcgeq_u64(vreinterpretq_u64_s64(a.0), vreinterpretq_u64_s64(b.0));
+    vorrq_u64(vcgtq_s64(a.1, b.1), vandq_u64(vceqq_s64(a.1, b.1), lo_ge))
+}
+
+#[inline]
+/// Compare *less than* s128
+pub unsafe fn vcltq_s128(a: s128x2_t, b: s128x2_t) -> uint64x2_t {
+    vcgtq_s128(b, a)
+}
+
+#[inline]
+/// Compare *less than or equal to* s128
+pub unsafe fn vcleq_s128(a: s128x2_t, b: s128x2_t) -> uint64x2_t {
+    vcgeq_s128(b, a)
+}
+
+#[inline]
+/// Bitwise select u128 by 64-bit lane mask
+pub unsafe fn vbslq_u128(mask: uint64x2_t, true_vals: u128x2_t, false_vals: u128x2_t) -> u128x2_t {
+    u128x2_t(
+        vbslq_u64(mask, true_vals.0, false_vals.0),
+        vbslq_u64(mask, true_vals.1, false_vals.1),
     )
 }
 
+#[inline]
+/// Bitwise select s128 by 64-bit lane mask
+pub unsafe fn vbslq_s128(mask: uint64x2_t, true_vals: s128x2_t, false_vals: s128x2_t) -> s128x2_t {
+    s128x2_t(
+        vbslq_s64(mask, true_vals.0, false_vals.0),
+        vbslq_s64(mask, true_vals.1, false_vals.1),
+    )
+}
+
+#[inline]
+/// Takes minimum of u128
+pub unsafe fn vminq_u128(a: u128x2_t, b: u128x2_t) -> u128x2_t {
+    vbslq_u128(vcltq_u128(a, b), a, b)
+}
+
+#[inline]
+/// Takes maximum of u128
+pub unsafe fn vmaxq_u128(a: u128x2_t, b: u128x2_t) -> u128x2_t {
+    vbslq_u128(vcgtq_u128(a, b), a, b)
+}
+
+#[inline]
+/// Takes minimum of s128
+pub unsafe fn vminq_s128(a: s128x2_t, b: s128x2_t) -> s128x2_t {
+    vbslq_s128(vcltq_s128(a, b), a, b)
+}
+
+#[inline]
+/// Takes maximum of s128
+pub unsafe fn vmaxq_s128(a: s128x2_t, b: s128x2_t) -> s128x2_t {
+    vbslq_s128(vcgtq_s128(a, b), a, b)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const VALUES: [u64; 9] = [
+        0,
+        1,
+        27,
+        u64::MAX,
+        1 << 63,
+        0x0123_4567_89ab_cdef,
+        (i64::MIN + 1) as u64,
+        0xffff_0000_ffff_0000,
+        -27i64 as u64,
+    ];
+
+    unsafe fn lanes(d: u128x2_t) -> [u128; 2] {
+        [vextractq_q_u128::<0>(d), vextractq_q_u128::<1>(d)]
+    }
+
+    unsafe fn lanes_s(d: s128x2_t) -> [u128; 2] {
+        [
+            vextractq_q_s128::<0>(d) as u128,
+            vextractq_q_s128::<1>(d) as u128,
+        ]
+    }
+
+    #[test]
+    fn test_against_native() {
+        unsafe {
+            for &a in VALUES.iter() {
+                for &b in VALUES.iter() {
+                    let va = vcombine_u64(vdup_n_u64(a), vdup_n_u64(b));
+                    let vb = vcombine_u64(vdup_n_u64(b), vdup_n_u64(a));
+                    let x = u128x2_t(va, vb);
+                    let y = u128x2_t(vb, va);
+                    let xs_signed = vreinterpretq_u128_s128(x);
+                    let ys_signed = vreinterpretq_u128_s128(y);
+                    let xs = [a as u128 | (b as u128) << 64, b as u128 | (a as u128) << 64];
+                    let ys = [xs[1], xs[0]];
+                    let bs = [b, a];
+
+                    let m = lanes(vmullq_u64(va, vb));
+                    assert_eq!(m, [a as u128 * b as u128; 2]);
+                    let m = lanes_s(vmullq_s64(
+                        vreinterpretq_s64_u64(va),
+                        vreinterpretq_s64_u64(vb),
+                    ));
+                    assert_eq!(m, [(a as i64 as i128 * b as i64 as i128) as u128; 2]);
+
+                    let add = lanes(vaddq_u128(x, y));
+                    let add_s = lanes_s(vaddq_s128(xs_signed, ys_signed));
+                    let addw = lanes(vaddwq_u64(x, vb));
+                    let addw_s = lanes_s(vaddwq_s64(xs_signed, vreinterpretq_s64_u64(vb)));
+                    let abs = lanes_s(vabsq_s128(xs_signed));
+                    for i in 0..2 {
+                        assert_eq!(add[i], xs[i].wrapping_add(ys[i]));
+                        assert_eq!(add_s[i], xs[i].wrapping_add(ys[i]));
+                        assert_eq!(addw[i], xs[i].wrapping_add(bs[i] as u128));
+                        let w = bs[i] as i64 as i128 as u128;
+                        assert_eq!(addw_s[i], xs[i].wrapping_add(w));
+                        assert_eq!(abs[i], (xs[i] as i128).wrapping_abs() as u128);
+                    }
+                    let movn = vmovnq_u128(x);
+                    assert_eq!(vgetq_lane_u64::<0>(movn), a);
+                    assert_eq!(vgetq_lane_u64::<1>(movn), b);
+
+                    let x1 = vcreate_u128(vdup_n_u64(a), vdup_n_u64(b));
+                    let m1 = vmull_u64(vdup_n_u64(a), vdup_n_u64(b));
+                    assert_eq!(vextract_u128(m1), a.wrapping_mul(b));
+                    let w1 = vaddw_u64(x1, vdup_n_u64(b));
+                    assert_eq!(
+                        vget_lane_u64::<0>(w1.1),
+                        (xs[0].wrapping_add(b as u128) >> 64) as u64
+                    );
+                    let w1 = vaddw_s64(vreinterpret_u128_s128(x1), vdup_n_s64(b as i64));
+                    let control = xs[0].wrapping_add(b as i64 as i128 as u128);
+                    assert_eq!(vget_lane_s64::<0>(w1.1), (control >> 64) as i64);
+                    let m1 = vmull_s64(vdup_n_s64(a as i64), vdup_n_s64(b as i64));
+                    let control = a as i64 as i128 * b as i64 as i128;
+                    assert_eq!(vget_lane_s64::<0>(m1.1), (control >> 64) as i64);
+
+                    macro_rules! check_shifts {
+                        ($($n:literal),*) => {
+                            $(
+                                let v = lanes(vshrq_n_u128::<$n>(x));
+                                assert_eq!(v, [xs[0] >> $n, xs[1] >> $n]);
+                                let v = lanes(vshlq_n_u128::<$n>(x));
+                                assert_eq!(v, [xs[0] << $n, xs[1] << $n]);
+                                let v = lanes_s(vshrq_n_s128::<$n>(xs_signed));
+                                let control = xs.map(|x| ((x as i128) >> $n) as u128);
+                                assert_eq!(v, control);
+                                let v = lanes_s(vshlq_n_s128::<$n>(xs_signed));
+                                assert_eq!(v, [xs[0] << $n, xs[1] << $n]);
+                            )*
+                        };
+                    }
+                    check_shifts!(0, 1, 10, 32, 63, 64, 70, 127);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_compare_against_native() {
+        unsafe {
+            for &a in VALUES.iter() {
+                for &b in VALUES.iter() {
+                    let va = vcombine_u64(vdup_n_u64(a), vdup_n_u64(b));
+                    let vb = vcombine_u64(vdup_n_u64(b), vdup_n_u64(a));
+                    let x = u128x2_t(va, vb);
+                    let xs = lanes(x);
+                    let x_signed = vreinterpretq_u128_s128(x);
+                    for y in [u128x2_t(vb, va), u128x2_t(vb, vb), u128x2_t(va, va)] {
+                        let ys = lanes(y);
+                        let y_signed = vreinterpretq_u128_s128(y);
+                        let sub = lanes(vsubq_u128(x, y));
+                        let sub_s = lanes_s(vsubq_s128(x_signed, y_signed));
+                        let mul = lanes(vmulq_u128(x, y));
+                        let mul_s = lanes_s(vmulq_s128(x_signed, y_signed));
+                        let min_u = lanes(vminq_u128(x, y));
+                        let max_u = lanes(vmaxq_u128(x, y));
+                        let min_s = lanes_s(vminq_s128(x_signed, y_signed));
+                        let max_s = lanes_s(vmaxq_s128(x_signed, y_signed));
+                        let masks = [
+                            vceqq_u128(x, y),
+                            vcgtq_u128(x, y),
+                            vcgeq_u128(x, y),
+                            vcltq_u128(x, y),
+                            vcleq_u128(x, y),
+                            vceqq_s128(x_signed, y_signed),
+                            vcgtq_s128(x_signed, y_signed),
+                            vcgeq_s128(x_signed, y_signed),
+                            vcltq_s128(x_signed, y_signed),
+                            vcleq_s128(x_signed, y_signed),
+                        ]
+                        .map(|m| [vgetq_lane_u64::<0>(m), vgetq_lane_u64::<1>(m)]);
+                        for i in 0..2 {
+                            assert_eq!(sub[i], xs[i].wrapping_sub(ys[i]));
+                            assert_eq!(sub_s[i], xs[i].wrapping_sub(ys[i]));
+                            assert_eq!(mul[i], xs[i].wrapping_mul(ys[i]));
+                            assert_eq!(mul_s[i], xs[i].wrapping_mul(ys[i]));
+                            assert_eq!(min_u[i], xs[i].min(ys[i]));
+                            assert_eq!(max_u[i], xs[i].max(ys[i]));
+                            let (sx, sy) = (xs[i] as i128, ys[i] as i128);
+                            assert_eq!(min_s[i], sx.min(sy) as u128);
+                            assert_eq!(max_s[i], sx.max(sy) as u128);
+                            let control = [
+                                xs[i] == ys[i],
+                                xs[i] > ys[i],
+                                xs[i] >= ys[i],
+                                xs[i] < ys[i],
+                                xs[i] <= ys[i],
+                                sx == sy,
+                                sx > sy,
+                                sx >= sy,
+                                sx < sy,
+                                sx <= sy,
+                            ];
+                            for (mask, flag) in masks.iter().zip(control.iter()) {
+                                assert_eq!(mask[i], if *flag { u64::MAX } else { 0 });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_add() {
         unsafe {
@@ -5,27 +5,46 @@
  * // license that can be found in the LICENSE file.
  */
 
-use crate::neon::exp::vexpq_f64;
-use crate::neon::general::{vcopysignq_f64, visinfq_f64, visnanq_f64, visneginfq_f64};
-use crate::neon::ln::vlnq_f64;
+use crate::neon::exp::{vexpq_f64, vexpq_fast_f64};
+use crate::neon::general::{vcopysignq_f64, visinfq_f64, visnanq_f64};
+use crate::neon::ln::{vlnq_f64, vlnq_fast_f64};
 use crate::visnotintegralq_f64;
 use std::arch::aarch64::*;
 
 #[inline]
 /// Method computes pow for NEON, with Inf, Nan checks
 pub unsafe fn vpowq_f64(d: float64x2_t, n: float64x2_t) -> float64x2_t {
-    let value = vabsq_f64(d);
-    let mut c = vexpq_f64(vmulq_f64(n, vlnq_f64(value)));
-    c = vcopysignq_f64(c, d);
-    let is_infinity = vorrq_u64(visinfq_f64(d), vorrq_u64(visinfq_f64(n), visneginfq_f64(n)));
-    let is_power_neg_infinity = visneginfq_f64(n);
-    let is_not_neg_integral = vandq_u64(vcltzq_f64(d), visnotintegralq_f64(n));
+    let c = vexpq_f64(vmulq_f64(n, vlnq_f64(vabsq_f64(d))));
+    // Sign of the base is kept only for odd integral powers
+    let is_odd = vbicq_u64(
+        visnotintegralq_f64(vmulq_f64(n, vdupq_n_f64(0.5))),
+        visnotintegralq_f64(n),
+    );
+    let mut ret = vbslq_f64(is_odd, vcopysignq_f64(c, d), c);
+    // Not integral powers are not allowed for negative finite numbers
+    let is_neg_finite = vandq_u64(
+        vcltq_f64(d, vdupq_n_f64(0.)),
+        vcgtq_f64(d, vdupq_n_f64(f64::NEG_INFINITY)),
+    );
+    let is_nan_with_integral = vandq_u64(is_neg_finite, visnotintegralq_f64(n));
     let is_any_nan = vorrq_u64(
         vorrq_u64(visnanq_f64(d), visnanq_f64(n)),
-        is_not_neg_integral,
+        is_nan_with_integral,
     );
-    let mut ret = vbslq_f64(is_infinity, vdupq_n_f64(f64::INFINITY), c);
-    ret = vbslq_f64(is_power_neg_infinity, vdupq_n_f64(0f64), ret);
     ret = vbslq_f64(is_any_nan, vdupq_n_f64(f64::NAN), ret);
-    ret
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN, pow(-1, ±inf) = 1
+    let ones = vdupq_n_f64(1.);
+    let is_one = vorrq_u64(
+        vorrq_u64(vceqq_f64(n, vdupq_n_f64(0.)), vceqq_f64(d, ones)),
+        vandq_u64(vceqq_f64(vabsq_f64(d), ones), visinfq_f64(n)),
+    );
+    vbslq_f64(is_one, ones, ret)
+}
+
+/// Method that computes pow skipping Inf, Nan checks, *ULP 2.0*
+#[inline]
+pub unsafe fn vpowq_fast_f64(d: float64x2_t, n: float64x2_t) -> float64x2_t {
+    let mut c = vexpq_fast_f64(vmulq_f64(n, vlnq_fast_f64(d)));
+    c = vcopysignq_f64(c, d);
+    c
 }
@@ -6,7 +6,7 @@
  */
 
 use crate::neon::expf::{vexpq_f32, vexpq_fast_f32};
-use crate::neon::general::{vcopysignq_f32, visinfq_f32, visnanq_f32, visneginfq_f32};
+use crate::neon::general::{vcopysignq_f32, visinfq_f32, visnanq_f32};
 use crate::neon::lnf::{vlnq_f32, vlnq_fast_f32};
 use crate::visnotintegralq_f32;
 use std::arch::aarch64::*;
@@ -14,19 +14,31 @@ use std::arch::aarch64::*;
 #[inline]
 /// Computes pow function *ULP 2.0*
 pub unsafe fn vpowq_f32(d: float32x4_t, n: float32x4_t) -> float32x4_t {
-    let mut c = vexpq_f32(vmulq_f32(n, vlnq_f32(vabsq_f32(d))));
-    c = vcopysignq_f32(c, d);
-    let is_infinity = vorrq_u32(visinfq_f32(d), vorrq_u32(visinfq_f32(n), visneginfq_f32(n)));
-    let is_power_neg_infinity = visneginfq_f32(n);
-    let is_not_neg_integral = vandq_u32(vcltzq_f32(d), visnotintegralq_f32(n));
+    let c = vexpq_f32(vmulq_f32(n, vlnq_f32(vabsq_f32(d))));
+    // Sign of the base is kept only for odd integral powers
+    let is_odd = vbicq_u32(
+        visnotintegralq_f32(vmulq_f32(n, vdupq_n_f32(0.5))),
+        visnotintegralq_f32(n),
+    );
+    let mut ret = vbslq_f32(is_odd, vcopysignq_f32(c, d), c);
+    // Not integral powers are not allowed for negative finite numbers
+    let is_neg_finite = vandq_u32(
+        vcltq_f32(d, vdupq_n_f32(0.)),
+        vcgtq_f32(d, vdupq_n_f32(f32::NEG_INFINITY)),
+    );
+    let is_nan_with_integral = vandq_u32(is_neg_finite, visnotintegralq_f32(n));
     let is_any_nan = vorrq_u32(
         vorrq_u32(visnanq_f32(d), visnanq_f32(n)),
-        is_not_neg_integral,
+        is_nan_with_integral,
     );
-    let mut ret = vbslq_f32(is_infinity, vdupq_n_f32(f32::INFINITY), c);
-    ret = vbslq_f32(is_power_neg_infinity, vdupq_n_f32(0f32), ret);
     ret = vbslq_f32(is_any_nan, vdupq_n_f32(f32::NAN), ret);
-    ret
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN, pow(-1, ±inf) = 1
+    let ones = vdupq_n_f32(1.);
+    let is_one = vorrq_u32(
+        vorrq_u32(vceqq_f32(n, vdupq_n_f32(0.)), vceqq_f32(d, ones)),
+        vandq_u32(vceqq_f32(vabsq_f32(d), ones), visinfq_f32(n)),
+    );
+    vbslq_f32(is_one, ones, ret)
 }
 
 /// Method that computes pow skipping Inf, Nan checks, *ULP 2.0*
@@ -0,0 +1,98 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+use std::arch::aarch64::*;
+
+use crate::precision::simd_math_impl;
+
+/// Evaluates f32 lanes with an f64 kernel, so result is rounded only once
+macro_rules! widen_ps {
+    ($f:path, ($x:ident)) => {{
+        let lo = $f(vcvt_f64_f32(vget_low_f32($x)));
+        let hi = $f(vcvt_high_f64_f32($x));
+        vcvt_high_f32_f64(vcvt_f32_f64(lo), hi)
+    }};
+    ($f:path, ($x:ident, $y:ident)) => {{
+        let lo = $f(
+            vcvt_f64_f32(vget_low_f32($x)),
+            vcvt_f64_f32(vget_low_f32($y)),
+        );
+        let hi = $f(vcvt_high_f64_f32($x), vcvt_high_f64_f32($y));
+        vcvt_high_f32_f64(vcvt_f32_f64(lo), hi)
+    }};
+}
+
+simd_math_impl! {
+    float64x2_t, f64,
+    exp(x) => crate::vexpq_f64, fast: crate::vexpq_fast_f64;
+    exp2(x) => crate::vexp2q_f64;
+    exp10(x) => crate::vexp10q_f64;
+    expm1(x) => crate::vexpm1q_f64;
+    ln(x) => crate::vlnq_f64, fast: crate::vlnq_fast_f64;
+    log2(x) => crate::vlog2q_f64;
+    log10(x) => crate::vlog10q_f64;
+    log1p(x) => crate::vlog1pq_f64;
+    sin(x) => crate::vsinq_f64;
+    cos(x) => crate::vcosq_f64;
+    tan(x) => crate::vtanq_f64;
+    asin(x) => crate::vasinq_f64;
+    acos(x) => crate::vacosq_f64;
+    atan(x) => crate::vatanq_f64;
+    sinh(x) => crate::vsinhq_f64;
+    cosh(x) => crate::vcoshq_f64;
+    tanh(x) => crate::vtanhq_f64;
+    asinh(x) => crate::vasinhq_f64;
+    acosh(x) => crate::vacoshq_f64;
+    atanh(x) => crate::vatanhq_f64;
+    cbrt(x) => crate::vcbrtq_f64, fast: crate::vcbrtq_fast_f64;
+    erf(x) => crate::verfq_f64;
+    erfc(x) => crate::verfcq_f64;
+    erfinv(x) => crate::verfinvq_f64;
+    tgamma(x) => crate::vtgammaq_f64;
+    digamma(x) => crate::vdigammaq_f64;
+    pow(x, n) => crate::vpowq_f64, fast: crate::vpowq_fast_f64;
+    atan2(y, x) => crate::vatan2q_f64;
+    hypot(x, y) => crate::vhypotq_f64, fast: crate::vhypotq_fast_f64;
+    fmod(x, y) => crate::vfmodq_f64;
+    hypot3(x, y, z) => crate::vhypot3q_f64, fast: crate::vhypot3q_fast_f64;
+    hypot4(x, y, z, w) => crate::vhypot4q_f64, fast: crate::vhypot4q_fast_f64;
+}
+
+simd_math_impl! {
+    float32x4_t, f32,
+    exp(x) => crate::vexpq_f32, fast: crate::vexpq_fast_f32, high: crate::vexpq_f64, cr: crate::cr::expf;
+    exp2(x) => crate::vexp2q_f32, high: crate::vexp2q_f64, cr: crate::cr::exp2f;
+    exp10(x) => crate::vexp10q_f32, high: crate::vexp10q_f64, cr: crate::cr::exp10f;
+    expm1(x) => crate::vexpm1q_f32, high: crate::vexpm1q_f64;
+    ln(x) => crate::vlnq_f32, fast: crate::vlnq_fast_f32, high: crate::vlnq_f64, cr: crate::cr::lnf;
+    log2(x) => crate::vlog2q_f32, high: crate::vlog2q_f64, cr: crate::cr::log2f;
+    log10(x) => crate::vlog10q_f32, high: crate::vlog10q_f64, cr: crate::cr::log10f;
+    log1p(x) => crate::vlog1pq_f32, high: crate::vlog1pq_f64;
+    sin(x) => crate::vsinq_f32, high: crate::vsinq_f64, cr: crate::cr::sinf;
+    cos(x) => crate::vcosq_f32, high: crate::vcosq_f64, cr: crate::cr::cosf;
+    tan(x) => crate::vtanq_f32, high: crate::vtanq_f64, cr: crate::cr::tanf;
+    asin(x) => crate::vasinq_f32, high: crate::vasinq_f64, cr: crate::cr::asinf;
+    acos(x) => crate::vacosq_f32, high: crate::vacosq_f64, cr: crate::cr::acosf;
+    atan(x) => crate::vatanq_f32, high: crate::vatanq_f64, cr: crate::cr::atanf;
+    sinh(x) => crate::vsinhq_f32, high: crate::vsinhq_f64;
+    cosh(x) => crate::vcoshq_f32, high: crate::vcoshq_f64;
+    tanh(x) => crate::vtanhq_f32, high: crate::vtanhq_f64;
+    asinh(x) => crate::vasinhq_f32, high: crate::vasinhq_f64;
+    acosh(x) => crate::vacoshq_f32, high: crate::vacoshq_f64;
+    atanh(x) => crate::vatanhq_f32, high: crate::vatanhq_f64;
+    cbrt(x) => crate::vcbrtq_f32, fast: crate::vcbrtq_fast_f32, high: crate::vcbrtq_f64, cr: crate::cr::cbrtf;
+    erf(x) => crate::verfq_f32, high: crate::verfq_f64;
+    erfc(x) => crate::verfcq_f32, high: crate::verfcq_f64;
+    erfinv(x) => crate::verfinvq_f32, high: crate::verfinvq_f64;
+    tgamma(x) => crate::vtgammaq_f32, high: crate::vtgammaq_f64;
+    digamma(x) => crate::vdigammaq_f32, high: crate::vdigammaq_f64;
+    pow(x, n) => crate::vpowq_f32, fast: crate::vpowq_fast_f32, high: crate::vpowq_f64, cr: crate::cr::powf;
+    atan2(y, x) => crate::vatan2q_f32, high: crate::vatan2q_f64;
+    hypot(x, y) => crate::vhypotq_f32, fast: crate::vhypotq_fast_f32, high: crate::vhypotq_f64;
+    fmod(x, y) => crate::vfmodq_f32;
+    hypot3(x, y, z) => crate::vhypot3q_f32, fast: crate::vhypot3q_fast_f32;
+    hypot4(x, y, z, w) => crate::vhypot4q_f32, fast: crate::vhypot4q_fast_f32;
+}
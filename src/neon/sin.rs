@@ -7,22 +7,24 @@
 
 use std::arch::aarch64::*;
 
-use crate::neon::general::vmlafq_f64;
+use crate::generalf::rempi;
+use crate::neon::general::vmultiply_as_doubledq_f64;
+use crate::neon::general::{vmlafq_f64, vrempiq_f64};
 use crate::sin::{
     PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
     SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
 };
+use crate::vcopysignq_f64;
 
 #[inline]
-/// Computes sine function with *ULP 1.5* on range [-15; 15]
+/// Computes sine function with *ULP 2.5*
 pub unsafe fn vsinq_f64(d: float64x2_t) -> float64x2_t {
     let q = vcvtaq_s64_f64(vmulq_n_f64(d, std::f64::consts::FRAC_1_PI));
     let qf = vcvtq_f64_s64(q);
 
     let mut r = vmlafq_f64(qf, vdupq_n_f64(-PI_A2), d);
     r = vmlafq_f64(qf, vdupq_n_f64(-PI_B2), r);
-
-    let x2 = vmulq_f64(r, r);
+    let (q, mut r) = vrempiq_f64(d, q, r, rempi);
 
     r = vbslq_f64(
         vceqq_u64(
@@ -32,6 +34,14 @@ pub unsafe fn vsinq_f64(d: float64x2_t) -> float64x2_t {
         r,
         vnegq_f64(r),
     );
+    // sin(±0) = ±0
+    vbslq_f64(vceqzq_f64(d), d, vsinkq_f64(r))
+}
+
+/// Evaluates sine polynomial for reduced |r| <= pi/2
+#[inline]
+pub(crate) unsafe fn vsinkq_f64(r: float64x2_t) -> float64x2_t {
+    let x2 = vmulq_f64(r, r);
     let mut res = vdupq_n_f64(SIN_POLY_10_D);
     res = vmlafq_f64(res, x2, vdupq_n_f64(SIN_POLY_9_D));
     res = vmlafq_f64(res, x2, vdupq_n_f64(SIN_POLY_8_D));
@@ -44,3 +54,29 @@ pub unsafe fn vsinq_f64(d: float64x2_t) -> float64x2_t {
     res = vmlafq_f64(res, vmulq_f64(x2, r), r);
     res
 }
+
+/// Computes sin(pi*x) for |x| < 2^52, integers give exact zeros
+#[inline]
+pub(crate) unsafe fn vsinpikq_f64(x: float64x2_t) -> float64x2_t {
+    // Period is 2 so the reduction is exact, then folding into [-0.5, 0.5] by sin(pi - y) = sin(y)
+    let mut r = vsubq_f64(
+        x,
+        vmulq_f64(
+            vdupq_n_f64(2f64),
+            vrndnq_f64(vmulq_f64(x, vdupq_n_f64(0.5f64))),
+        ),
+    );
+    r = vbslq_f64(
+        vcgtq_f64(vabsq_f64(r), vdupq_n_f64(0.5f64)),
+        vsubq_f64(vcopysignq_f64(vdupq_n_f64(1f64), r), r),
+        r,
+    );
+    // pi * r is kept as double-float, sin(y + lo) = sin(y) + lo * cos(y) with cos(y) ~ 1 - y^2/2
+    let y = vmultiply_as_doubledq_f64(r, vdupq_n_f64(PI_A2));
+    let lo = vmlafq_f64(r, vdupq_n_f64(PI_B2), y.1);
+    vmlafq_f64(
+        lo,
+        vmlafq_f64(vmulq_f64(vdupq_n_f64(-0.5f64), y.0), y.0, vdupq_n_f64(1f64)),
+        vsinkq_f64(y.0),
+    )
+}
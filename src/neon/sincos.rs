@@ -0,0 +1,65 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::generalf::rempi2;
+use crate::neon::general::{vmlafq_f64, vrempiq_f64};
+use crate::sin::{
+    PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
+    SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
+};
+
+#[inline]
+unsafe fn vsinpolyq_f64(r: float64x2_t) -> float64x2_t {
+    let x2 = vmulq_f64(r, r);
+    let mut res = vdupq_n_f64(SIN_POLY_10_D);
+    res = vmlafq_f64(res, x2, vdupq_n_f64(SIN_POLY_9_D));
+    res = vmlafq_f64(res, x2, vdupq_n_f64(SIN_POLY_8_D));
+    res = vmlafq_f64(res, x2, vdupq_n_f64(SIN_POLY_7_D));
+    res = vmlafq_f64(res, x2, vdupq_n_f64(SIN_POLY_6_D));
+    res = vmlafq_f64(res, x2, vdupq_n_f64(SIN_POLY_5_D));
+    res = vmlafq_f64(res, x2, vdupq_n_f64(SIN_POLY_4_D));
+    res = vmlafq_f64(res, x2, vdupq_n_f64(SIN_POLY_3_D));
+    res = vmlafq_f64(res, x2, vdupq_n_f64(SIN_POLY_2_D));
+    vmlafq_f64(res, vmulq_f64(x2, r), r)
+}
+
+#[inline]
+/// Computes sine and cosine with a single argument reduction, returns `(sin, cos)`, *ULP 1.5*
+pub unsafe fn vsincosq_f64(d: float64x2_t) -> (float64x2_t, float64x2_t) {
+    let q = vcvtaq_s64_f64(vmulq_n_f64(d, std::f64::consts::FRAC_2_PI));
+    let qf = vcvtq_f64_s64(q);
+
+    let mut r = vmlafq_f64(qf, vdupq_n_f64(-PI_A2 * 0.5), d);
+    r = vmlafq_f64(qf, vdupq_n_f64(-PI_B2 * 0.5), r);
+    let (q, r) = vrempiq_f64(d, q, r, rempi2);
+
+    // cos(r) = 1 - 2*sin(r/2)^2, |r| <= π/4
+    let s = vsinpolyq_f64(r);
+    let h = vsinpolyq_f64(vmulq_n_f64(r, 0.5));
+    let c = vmlafq_f64(vmulq_n_f64(h, -2.), h, vdupq_n_f64(1.));
+
+    let is_odd = vtstq_s64(q, vdupq_n_s64(1));
+    let sin = vbslq_f64(is_odd, c, s);
+    let cos = vbslq_f64(is_odd, s, c);
+
+    let sin_sign = vshlq_n_u64::<62>(vandq_u64(vreinterpretq_u64_s64(q), vdupq_n_u64(2)));
+    let cos_sign = vshlq_n_u64::<62>(vandq_u64(
+        vreinterpretq_u64_s64(vaddq_s64(q, vdupq_n_s64(1))),
+        vdupq_n_u64(2),
+    ));
+    // sin(±0) = ±0
+    (
+        vbslq_f64(
+            vceqzq_f64(d),
+            d,
+            vreinterpretq_f64_u64(veorq_u64(vreinterpretq_u64_f64(sin), sin_sign)),
+        ),
+        vreinterpretq_f64_u64(veorq_u64(vreinterpretq_u64_f64(cos), cos_sign)),
+    )
+}
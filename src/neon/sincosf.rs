@@ -0,0 +1,61 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2f;
+use crate::neon::general::{vmlafq_f32, vrempiq_f32};
+use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+
+#[inline]
+unsafe fn vsinpolyq_f32(r: float32x4_t) -> float32x4_t {
+    let x2 = vmulq_f32(r, r);
+    let mut res = vdupq_n_f32(SIN_POLY_5_S);
+    res = vmlafq_f32(res, x2, vdupq_n_f32(SIN_POLY_4_S));
+    res = vmlafq_f32(res, x2, vdupq_n_f32(SIN_POLY_3_S));
+    res = vmlafq_f32(res, x2, vdupq_n_f32(SIN_POLY_2_S));
+    res = vmlafq_f32(res, x2, vdupq_n_f32(SIN_POLY_1_S));
+    vmlafq_f32(res, vmulq_f32(x2, r), r)
+}
+
+#[inline]
+/// Computes sine and cosine with a single argument reduction, returns `(sin, cos)`, *ULP 2.0*
+pub unsafe fn vsincosq_f32(d: float32x4_t) -> (float32x4_t, float32x4_t) {
+    let q = vcvtaq_s32_f32(vmulq_n_f32(d, std::f32::consts::FRAC_2_PI));
+    let qf = vcvtq_f32_s32(q);
+
+    let mut r = vmlafq_f32(qf, vdupq_n_f32(-PI_A_F * 0.5), d);
+    r = vmlafq_f32(qf, vdupq_n_f32(-PI_B_F * 0.5), r);
+    r = vmlafq_f32(qf, vdupq_n_f32(-PI_C_F * 0.5), r);
+    r = vmlafq_f32(qf, vdupq_n_f32(-PI_D_F * 0.5), r);
+    let (q, r) = vrempiq_f32(d, q, r, rempi2f);
+
+    // cos(r) = 1 - 2*sin(r/2)^2, |r| <= π/4
+    let s = vsinpolyq_f32(r);
+    let h = vsinpolyq_f32(vmulq_n_f32(r, 0.5));
+    let c = vmlafq_f32(vmulq_n_f32(h, -2.), h, vdupq_n_f32(1.));
+
+    let is_odd = vtstq_s32(q, vdupq_n_s32(1));
+    let sin = vbslq_f32(is_odd, c, s);
+    let cos = vbslq_f32(is_odd, s, c);
+
+    let sin_sign = vshlq_n_u32::<30>(vandq_u32(vreinterpretq_u32_s32(q), vdupq_n_u32(2)));
+    let cos_sign = vshlq_n_u32::<30>(vandq_u32(
+        vreinterpretq_u32_s32(vaddq_s32(q, vdupq_n_s32(1))),
+        vdupq_n_u32(2),
+    ));
+    // sin(±0) = ±0
+    (
+        vbslq_f32(
+            vceqzq_f32(d),
+            d,
+            vreinterpretq_f32_u32(veorq_u32(vreinterpretq_u32_f32(sin), sin_sign)),
+        ),
+        vreinterpretq_f32_u32(veorq_u32(vreinterpretq_u32_f32(cos), cos_sign)),
+    )
+}
@@ -8,10 +8,14 @@
 use std::arch::aarch64::*;
 
 use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
-use crate::neon::general::vmlafq_f32;
+use crate::generalf::rempif;
+use crate::neon::general::vmultiply_as_doubledq_f32;
+use crate::neon::general::{vmlafq_f32, vrempiq_f32};
+use crate::sinf::{PI_LO_S, PI_S};
 use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+use crate::vcopysignq_f32;
 
-/// Computes sine function with error bound *ULP 1.5*
+/// Computes sine function with error bound *ULP 2.5*
 #[inline]
 pub unsafe fn vsinq_f32(d: float32x4_t) -> float32x4_t {
     let q = vcvtaq_s32_f32(vmulq_n_f32(d, std::f32::consts::FRAC_1_PI));
@@ -21,8 +25,7 @@ pub unsafe fn vsinq_f32(d: float32x4_t) -> float32x4_t {
     r = vmlafq_f32(qf, vdupq_n_f32(-PI_B_F), r);
     r = vmlafq_f32(qf, vdupq_n_f32(-PI_C_F), r);
     r = vmlafq_f32(qf, vdupq_n_f32(-PI_D_F), r);
-
-    let x2 = vmulq_f32(r, r);
+    let (q, mut r) = vrempiq_f32(d, q, r, rempif);
 
     r = vbslq_f32(
         vceqq_u32(
@@ -32,6 +35,14 @@ pub unsafe fn vsinq_f32(d: float32x4_t) -> float32x4_t {
         r,
         vnegq_f32(r),
     );
+    // sin(±0) = ±0
+    vbslq_f32(vceqzq_f32(d), d, vsinkq_f32(r))
+}
+
+/// Evaluates sine polynomial for reduced |r| <= pi/2
+#[inline]
+pub(crate) unsafe fn vsinkq_f32(r: float32x4_t) -> float32x4_t {
+    let x2 = vmulq_f32(r, r);
     let mut res = vdupq_n_f32(SIN_POLY_5_S);
     res = vmlafq_f32(res, x2, vdupq_n_f32(SIN_POLY_4_S));
     res = vmlafq_f32(res, x2, vdupq_n_f32(SIN_POLY_3_S));
@@ -40,3 +51,29 @@ pub unsafe fn vsinq_f32(d: float32x4_t) -> float32x4_t {
     res = vmlafq_f32(res, vmulq_f32(x2, r), r);
     res
 }
+
+/// Computes sin(pi*x) for |x| < 2^23, integers give exact zeros
+#[inline]
+pub(crate) unsafe fn vsinpikq_f32(x: float32x4_t) -> float32x4_t {
+    // Period is 2 so the reduction is exact, then folding into [-0.5, 0.5] by sin(pi - y) = sin(y)
+    let mut r = vsubq_f32(
+        x,
+        vmulq_f32(
+            vdupq_n_f32(2f32),
+            vrndnq_f32(vmulq_f32(x, vdupq_n_f32(0.5f32))),
+        ),
+    );
+    r = vbslq_f32(
+        vcgtq_f32(vabsq_f32(r), vdupq_n_f32(0.5f32)),
+        vsubq_f32(vcopysignq_f32(vdupq_n_f32(1f32), r), r),
+        r,
+    );
+    // pi * r is kept as double-float, sin(y + lo) = sin(y) + lo * cos(y) with cos(y) ~ 1 - y^2/2
+    let y = vmultiply_as_doubledq_f32(r, vdupq_n_f32(PI_S));
+    let lo = vmlafq_f32(r, vdupq_n_f32(PI_LO_S), y.1);
+    vmlafq_f32(
+        lo,
+        vmlafq_f32(vmulq_f32(vdupq_n_f32(-0.5f32), y.0), y.0, vdupq_n_f32(1f32)),
+        vsinkq_f32(y.0),
+    )
+}
@@ -0,0 +1,29 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::{vcopysignq_f64, vexpm1q_f64, vexpq_f64};
+
+/// Computes hyperbolic sine, error bound *ULP 3.5*
+#[inline]
+pub unsafe fn vsinhq_f64(d: float64x2_t) -> float64x2_t {
+    let a = vabsq_f64(d);
+    let e = vexpm1q_f64(a);
+    let mut r = vmulq_f64(
+        vdupq_n_f64(0.5f64),
+        vaddq_f64(e, vdivq_f64(e, vaddq_f64(e, vdupq_n_f64(1f64)))),
+    );
+    // exp(|d|) / 2 overflows before sinh does, split it as exp(|d|/2)^2 / 2
+    let h = vexpq_f64(vmulq_f64(a, vdupq_n_f64(0.5f64)));
+    r = vbslq_f64(
+        vcgtq_f64(a, vdupq_n_f64(709f64)),
+        vmulq_f64(vmulq_f64(h, vdupq_n_f64(0.5f64)), h),
+        r,
+    );
+    vcopysignq_f64(r, d)
+}
@@ -0,0 +1,29 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::{vcopysignq_f32, vexpm1q_f32, vexpq_f32};
+
+/// Computes hyperbolic sine, error bound *ULP 3.5*
+#[inline]
+pub unsafe fn vsinhq_f32(d: float32x4_t) -> float32x4_t {
+    let a = vabsq_f32(d);
+    let e = vexpm1q_f32(a);
+    let mut r = vmulq_f32(
+        vdupq_n_f32(0.5f32),
+        vaddq_f32(e, vdivq_f32(e, vaddq_f32(e, vdupq_n_f32(1f32)))),
+    );
+    // exp(|d|) / 2 overflows before sinh does, split it as exp(|d|/2)^2 / 2
+    let h = vexpq_f32(vmulq_f32(a, vdupq_n_f32(0.5f32)));
+    r = vbslq_f32(
+        vcgtq_f32(a, vdupq_n_f32(88f32)),
+        vmulq_f32(vmulq_f32(h, vdupq_n_f32(0.5f32)), h),
+        r,
+    );
+    vcopysignq_f32(r, d)
+}
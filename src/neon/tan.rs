@@ -4,7 +4,8 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
-use crate::neon::general::vmlafq_f64;
+use crate::generalf::rempi2;
+use crate::neon::general::{vmlafq_f64, vrempiq_f64};
 use crate::sin::{PI_A2, PI_B2};
 use crate::tan::{
     TAN_POLY_1_D, TAN_POLY_2_D, TAN_POLY_3_D, TAN_POLY_4_D, TAN_POLY_5_D, TAN_POLY_6_D,
@@ -13,13 +14,14 @@ use crate::tan::{
 use std::arch::aarch64::*;
 
 #[inline]
-/// Computes tan with error bound *ULP 2.0*
+/// Computes tan with error bound *ULP 4.0*
 pub unsafe fn vtanq_f64(d: float64x2_t) -> float64x2_t {
     let q = vcvtaq_s64_f64(vmulq_n_f64(d, std::f64::consts::FRAC_2_PI));
     let qf = vcvtq_f64_s64(q);
 
     let mut x = vmlafq_f64(qf, vdupq_n_f64(-PI_A2 * 0.5), d);
     x = vmlafq_f64(qf, vdupq_n_f64(-PI_B2 * 0.5), x);
+    let (q, mut x) = vrempiq_f64(d, q, x, rempi2);
 
     let even = vceqzq_s64(vandq_s64(q, vdupq_n_s64(1)));
     x = vbslq_f64(even, x, vnegq_f64(x));
@@ -7,7 +7,8 @@
 use std::arch::aarch64::*;
 
 use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
-use crate::neon::general::vmlafq_f32;
+use crate::generalf::rempi2f;
+use crate::neon::general::{visinfq_f32, vmlafq_f32, vrempiq_f32};
 use crate::tanf::{
     TAN_POLY_1_S, TAN_POLY_2_S, TAN_POLY_3_S, TAN_POLY_4_S, TAN_POLY_5_S, TAN_POLY_6_S,
     TAN_POLY_7_S, TAN_POLY_8_S, TAN_POLY_9_S,
@@ -23,6 +24,7 @@ pub unsafe fn vtanq_f32(d: float32x4_t) -> float32x4_t {
     x = vmlafq_f32(qf, vdupq_n_f32(-PI_B_F * 0.5), x);
     x = vmlafq_f32(qf, vdupq_n_f32(-PI_C_F * 0.5), x);
     x = vmlafq_f32(qf, vdupq_n_f32(-PI_D_F * 0.5), x);
+    let (q, mut x) = vrempiq_f32(d, q, x, rempi2f);
 
     let even = vceqzq_s32(vandq_s32(q, vdupq_n_s32(1)));
     x = vbslq_f32(even, x, vnegq_f32(x));
@@ -38,5 +40,6 @@ pub unsafe fn vtanq_f32(d: float32x4_t) -> float32x4_t {
     u = vmlafq_f32(u, x2, vdupq_n_f32(TAN_POLY_1_S));
     u = vmlafq_f32(u, vmulq_f32(x2, x), x);
     u = vbslq_f32(even, u, vdivq_f32(vdupq_n_f32(1.), u));
-    u
+    // tan(±inf) = NaN
+    vbslq_f32(visinfq_f32(d), vdupq_n_f32(f32::NAN), u)
 }
@@ -0,0 +1,21 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::{vcopysignq_f64, vexpm1q_f64};
+
+/// Computes hyperbolic tangent, error bound *ULP 2.5*
+#[inline]
+pub unsafe fn vtanhq_f64(d: float64x2_t) -> float64x2_t {
+    let a = vabsq_f64(d);
+    let e = vexpm1q_f64(vaddq_f64(a, a));
+    let mut r = vdivq_f64(e, vaddq_f64(e, vdupq_n_f64(2f64)));
+    // tanh rounds to 1 long before expm1 overflows
+    r = vbslq_f64(vcgtq_f64(a, vdupq_n_f64(22f64)), vdupq_n_f64(1f64), r);
+    vcopysignq_f64(r, d)
+}
@@ -0,0 +1,21 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::aarch64::*;
+
+use crate::{vcopysignq_f32, vexpm1q_f32};
+
+/// Computes hyperbolic tangent, error bound *ULP 2.5*
+#[inline]
+pub unsafe fn vtanhq_f32(d: float32x4_t) -> float32x4_t {
+    let a = vabsq_f32(d);
+    let e = vexpm1q_f32(vaddq_f32(a, a));
+    let mut r = vdivq_f32(e, vaddq_f32(e, vdupq_n_f32(2f32)));
+    // tanh rounds to 1 long before expm1 overflows
+    r = vbslq_f32(vcgtq_f32(a, vdupq_n_f32(10f32)), vdupq_n_f32(1f32), r);
+    vcopysignq_f32(r, d)
+}
@@ -0,0 +1,113 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::exp::{L2_L, L2_U, R_LN2};
+use crate::neon::exp::vexpm1kq_f64;
+use crate::neon::general::{vmul_doubledq_f64, vsum_as_doubledq_f64};
+use crate::neon::ln::vlnk_doubledq_f64;
+use crate::neon::sin::vsinpikq_f64;
+use crate::tgamma::{
+    SQRT_2PI_D, SQRT_2PI_LO_D, SQRT_HALF_PI_D, SQRT_HALF_PI_LO_D, STIRLING_POLY_1_D,
+    STIRLING_POLY_2_D, STIRLING_POLY_3_D, STIRLING_POLY_4_D, STIRLING_POLY_5_D, STIRLING_POLY_6_D,
+};
+use crate::{vcopysignq_f64, visnotintegralq_f64, vldexp2kq_f64, vmlafq_f64};
+use std::arch::aarch64::*;
+
+/// Computes lnΓ(w) - ln(sqrt(2pi)) as double-float for w >= 10
+#[inline]
+pub(crate) unsafe fn vstirlingkq_f64(w: (float64x2_t, float64x2_t)) -> (float64x2_t, float64x2_t) {
+    let l = vlnk_doubledq_f64(w);
+    // (w - 0.5) ln(w) - w, w - 0.5 is exact below 2^52
+    let m = vmul_doubledq_f64((vsubq_f64(w.0, vdupq_n_f64(0.5f64)), w.1), l);
+    let e = vsum_as_doubledq_f64(m.0, vnegq_f64(w.0));
+    let iw = vdivq_f64(vdupq_n_f64(1f64), w.0);
+    let v = vmulq_f64(iw, iw);
+    let mut u = vdupq_n_f64(STIRLING_POLY_6_D);
+    u = vmlafq_f64(u, v, vdupq_n_f64(STIRLING_POLY_5_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(STIRLING_POLY_4_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(STIRLING_POLY_3_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(STIRLING_POLY_2_D));
+    u = vmlafq_f64(u, v, vdupq_n_f64(STIRLING_POLY_1_D));
+    (e.0, vaddq_f64(e.1, vmlafq_f64(iw, u, vsubq_f64(m.1, w.1))))
+}
+
+/// Computes gamma function, error bound *ULP 3.5*, *ULP 2.5* for positive arguments
+#[inline]
+pub unsafe fn vtgammaq_f64(d: float64x2_t) -> float64x2_t {
+    let reflect = vcltq_f64(d, vdupq_n_f64(0f64));
+    // Γ(d) = pi / (sin(pi d) Γ(1 - d)) for negative d, 1 - d is kept as double-float
+    let s = vsum_as_doubledq_f64(vdupq_n_f64(1f64), vnegq_f64(d));
+    let z = (
+        vbslq_f64(reflect, s.0, d),
+        vbslq_f64(reflect, s.1, vdupq_n_f64(0f64)),
+    );
+    // Γ(z) = Γ(z + 10) / (z (z + 1) ... (z + 9)) moves the argument into Stirling range
+    let mut w = z;
+    let mut q = (vdupq_n_f64(1f64), vdupq_n_f64(0f64));
+    for _ in 0..10 {
+        q = vmul_doubledq_f64(q, w);
+        let s = vsum_as_doubledq_f64(w.0, vdupq_n_f64(1f64));
+        w = (s.0, vaddq_f64(s.1, w.1));
+    }
+    let shift = vcltq_f64(z.0, vdupq_n_f64(10f64));
+    let w = (vbslq_f64(shift, w.0, z.0), vbslq_f64(shift, w.1, z.1));
+    let q = (
+        vbslq_f64(shift, q.0, vdupq_n_f64(1f64)),
+        vbslq_f64(shift, q.1, vdupq_n_f64(0f64)),
+    );
+    // ln(Γ(z)) - ln(sqrt(2pi)) = E - ln(q) with every low part kept, so nothing is divided afterwards
+    let e = vstirlingkq_f64(w);
+    let l = vlnk_doubledq_f64(q);
+    let h = vsum_as_doubledq_f64(e.0, vnegq_f64(l.0));
+    let lo = vaddq_f64(h.1, vsubq_f64(e.1, l.1));
+    // Reflected lanes take exp of the negated exponent
+    let hi = vbslq_f64(reflect, vnegq_f64(h.0), h.0);
+    let lo = vbslq_f64(reflect, vnegq_f64(lo), lo);
+    let qi = vcvtaq_s64_f64(vmulq_f64(hi, vdupq_n_f64(R_LN2)));
+    let qf = vcvtq_f64_s64(qi);
+    let mut r = vmlafq_f64(qf, vdupq_n_f64(-L2_U), hi);
+    r = vmlafq_f64(qf, vdupq_n_f64(-L2_L), r);
+    r = vaddq_f64(r, lo);
+    let g = vbslq_f64(
+        reflect,
+        vdupq_n_f64(SQRT_HALF_PI_D),
+        vdupq_n_f64(SQRT_2PI_D),
+    );
+    let g_lo = vbslq_f64(
+        reflect,
+        vdupq_n_f64(SQRT_HALF_PI_LO_D / SQRT_HALF_PI_D),
+        vdupq_n_f64(SQRT_2PI_LO_D / SQRT_2PI_D),
+    );
+    let t = vexpm1kq_f64(vaddq_f64(r, g_lo));
+    let u = vmlafq_f64(g, t, g);
+    let sp = vsinpikq_f64(d);
+    let mut ret = vldexp2kq_f64(vbslq_f64(reflect, vdivq_f64(u, sp), u), qi);
+    // Γ(d) = 1/d - γ + O(d), past this point γ is below half an ulp of 1/d
+    ret = vbslq_f64(
+        vcltq_f64(vabsq_f64(d), vdupq_n_f64(5.551115123125783e-17f64)),
+        vdivq_f64(vdupq_n_f64(1f64), d),
+        ret,
+    );
+    // d > 171.7 overflows, also covers d == Inf
+    ret = vbslq_f64(
+        vcgtq_f64(d, vdupq_n_f64(171.7f64)),
+        vdupq_n_f64(f64::INFINITY),
+        ret,
+    );
+    // |Γ(d)| is below the smallest subnormal
+    ret = vbslq_f64(
+        vcltq_f64(d, vdupq_n_f64(-190f64)),
+        vcopysignq_f64(vdupq_n_f64(0f64), sp),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    vbslq_f64(
+        vbicq_u64(reflect, visnotintegralq_f64(d)),
+        vdupq_n_f64(f64::NAN),
+        ret,
+    )
+}
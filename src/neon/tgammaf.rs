@@ -0,0 +1,111 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::expf::{L2L_F, L2U_F, R_LN2_F};
+use crate::neon::expf::vexpm1kq_f32;
+use crate::neon::general::{vmul_doubledq_f32, vsum_as_doubledq_f32};
+use crate::neon::lnf::vlnk_doubledq_f32;
+use crate::neon::sinf::vsinpikq_f32;
+use crate::tgammaf::{
+    SQRT_2PI_LO_S, SQRT_2PI_S, SQRT_HALF_PI_LO_S, SQRT_HALF_PI_S, STIRLING_POLY_1_S,
+    STIRLING_POLY_2_S, STIRLING_POLY_3_S, STIRLING_POLY_4_S,
+};
+use crate::{vcopysignq_f32, visnotintegralq_f32, vldexp2kq_f32, vmlafq_f32};
+use std::arch::aarch64::*;
+
+/// Computes lnΓ(w) - ln(sqrt(2pi)) as double-float for w >= 5
+#[inline]
+pub(crate) unsafe fn vstirlingkq_f32(w: (float32x4_t, float32x4_t)) -> (float32x4_t, float32x4_t) {
+    let l = vlnk_doubledq_f32(w);
+    // (w - 0.5) ln(w) - w, w - 0.5 is exact below 2^23
+    let m = vmul_doubledq_f32((vsubq_f32(w.0, vdupq_n_f32(0.5f32)), w.1), l);
+    let e = vsum_as_doubledq_f32(m.0, vnegq_f32(w.0));
+    let iw = vdivq_f32(vdupq_n_f32(1f32), w.0);
+    let v = vmulq_f32(iw, iw);
+    let mut u = vdupq_n_f32(STIRLING_POLY_4_S);
+    u = vmlafq_f32(u, v, vdupq_n_f32(STIRLING_POLY_3_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(STIRLING_POLY_2_S));
+    u = vmlafq_f32(u, v, vdupq_n_f32(STIRLING_POLY_1_S));
+    (e.0, vaddq_f32(e.1, vmlafq_f32(iw, u, vsubq_f32(m.1, w.1))))
+}
+
+/// Computes gamma function, error bound *ULP 3.5*, *ULP 2.0* for positive arguments
+#[inline]
+pub unsafe fn vtgammaq_f32(d: float32x4_t) -> float32x4_t {
+    let reflect = vcltq_f32(d, vdupq_n_f32(0f32));
+    // Γ(d) = pi / (sin(pi d) Γ(1 - d)) for negative d, 1 - d is kept as double-float
+    let s = vsum_as_doubledq_f32(vdupq_n_f32(1f32), vnegq_f32(d));
+    let z = (
+        vbslq_f32(reflect, s.0, d),
+        vbslq_f32(reflect, s.1, vdupq_n_f32(0f32)),
+    );
+    // Γ(z) = Γ(z + 5) / (z (z + 1) ... (z + 4)) moves the argument into Stirling range
+    let mut w = z;
+    let mut q = (vdupq_n_f32(1f32), vdupq_n_f32(0f32));
+    for _ in 0..5 {
+        q = vmul_doubledq_f32(q, w);
+        let s = vsum_as_doubledq_f32(w.0, vdupq_n_f32(1f32));
+        w = (s.0, vaddq_f32(s.1, w.1));
+    }
+    let shift = vcltq_f32(z.0, vdupq_n_f32(5f32));
+    let w = (vbslq_f32(shift, w.0, z.0), vbslq_f32(shift, w.1, z.1));
+    let q = (
+        vbslq_f32(shift, q.0, vdupq_n_f32(1f32)),
+        vbslq_f32(shift, q.1, vdupq_n_f32(0f32)),
+    );
+    // ln(Γ(z)) - ln(sqrt(2pi)) = E - ln(q) with every low part kept, so nothing is divided afterwards
+    let e = vstirlingkq_f32(w);
+    let l = vlnk_doubledq_f32(q);
+    let h = vsum_as_doubledq_f32(e.0, vnegq_f32(l.0));
+    let lo = vaddq_f32(h.1, vsubq_f32(e.1, l.1));
+    // Reflected lanes take exp of the negated exponent
+    let hi = vbslq_f32(reflect, vnegq_f32(h.0), h.0);
+    let lo = vbslq_f32(reflect, vnegq_f32(lo), lo);
+    let qi = vcvtaq_s32_f32(vmulq_f32(hi, vdupq_n_f32(R_LN2_F)));
+    let qf = vcvtq_f32_s32(qi);
+    let mut r = vmlafq_f32(qf, vdupq_n_f32(-L2U_F), hi);
+    r = vmlafq_f32(qf, vdupq_n_f32(-L2L_F), r);
+    r = vaddq_f32(r, lo);
+    let g = vbslq_f32(
+        reflect,
+        vdupq_n_f32(SQRT_HALF_PI_S),
+        vdupq_n_f32(SQRT_2PI_S),
+    );
+    let g_lo = vbslq_f32(
+        reflect,
+        vdupq_n_f32(SQRT_HALF_PI_LO_S / SQRT_HALF_PI_S),
+        vdupq_n_f32(SQRT_2PI_LO_S / SQRT_2PI_S),
+    );
+    let t = vexpm1kq_f32(vaddq_f32(r, g_lo));
+    let u = vmlafq_f32(g, t, g);
+    let sp = vsinpikq_f32(d);
+    let mut ret = vldexp2kq_f32(vbslq_f32(reflect, vdivq_f32(u, sp), u), qi);
+    // Γ(d) = 1/d - γ + O(d), past this point γ is below half an ulp of 1/d
+    ret = vbslq_f32(
+        vcltq_f32(vabsq_f32(d), vdupq_n_f32(2.9802322e-8f32)),
+        vdivq_f32(vdupq_n_f32(1f32), d),
+        ret,
+    );
+    // d > 35.1 overflows, also covers d == Inf
+    ret = vbslq_f32(
+        vcgtq_f32(d, vdupq_n_f32(35.1f32)),
+        vdupq_n_f32(f32::INFINITY),
+        ret,
+    );
+    // |Γ(d)| is below the smallest subnormal
+    ret = vbslq_f32(
+        vcltq_f32(d, vdupq_n_f32(-45f32)),
+        vcopysignq_f32(vdupq_n_f32(0f32), sp),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    vbslq_f32(
+        vbicq_u32(reflect, visnotintegralq_f32(d)),
+        vdupq_n_f32(f32::NAN),
+        ret,
+    )
+}
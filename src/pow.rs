@@ -6,38 +6,52 @@
  */
 
 use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
 use crate::efloor;
 use crate::exp::eexp;
-use crate::generalf::{copysignk, is_neg_infinite, is_pos_infinite};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_pow;
+use crate::generalf::copysignk;
 use crate::ln::eln;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vpowq_f64;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_extract_pd, _mm_pow_pd};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 #[inline]
-fn do_pow(d: f64, n: f64) -> f64 {
-    let value = eabs(d);
-    let mut c = eexp(n * eln(value));
-    c = copysignk(c, d);
-    if d < 0. && efloor(n) != n {
+pub(crate) fn do_pow(d: f64, n: f64) -> f64 {
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN
+    if n == 0. || d == 1. {
+        return 1.;
+    }
+    if d.is_nan() || n.is_nan() {
+        return f64::NAN;
+    }
+    // pow(-1, ±inf) = 1
+    if n.is_infinite() && eabs(d) == 1. {
+        return 1.;
+    }
+    let is_integral = efloor(n) == n;
+    // Not integral powers are not allowed for negative finite numbers
+    if d < 0. && d.is_finite() && !is_integral {
         return f64::NAN;
     }
-    if is_pos_infinite(n) || d.is_infinite() {
-        f64::INFINITY
-    } else if is_neg_infinite(n) {
-        0f64
-    } else if n.is_nan() || d.is_nan() {
-        f64::NAN
+    let c = eexp(n * eln(eabs(d)));
+    // Sign of the base is kept only for odd integral powers
+    let half = n * 0.5;
+    if is_integral && efloor(half) != half {
+        copysignk(c, d)
     } else {
         c
     }
@@ -55,15 +69,37 @@ fn do_pow_neon(d: f64, n: f64) -> f64 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_pow_sse(d: f64, n: f64) -> f64 {
+    let val = _mm_set1_pd(d);
+    let power = _mm_set1_pd(n);
+    _mm_extract_pd::<0>(_mm_pow_pd(val, power))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_pow_sse41(d: f64, n: f64) -> f64 {
+    do_pow_sse(d, n)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_pow_fma(d: f64, n: f64) -> f64 {
+    do_pow(d, n)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_pow_sse(d: f64, n: f64) -> f64 {
-    unsafe {
-        let val = _mm_set1_pd(d);
-        let power = _mm_set1_pd(n);
-        _mm_extract_pd::<0>(_mm_pow_pd(val, power))
-    }
+fn do_pow_wasm(d: f64, n: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_pow(f64x2_splat(d), f64x2_splat(n)))
 }
 
 /// Computes power function, error bound *ULP 2.0*
@@ -74,12 +110,25 @@ pub fn epow(d: f64, n: f64) -> f64 {
     {
         _dispatcher = do_pow_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_pow_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_pow_sse;
+        static DISPATCHER: OnceLock<fn(f64, f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d, n| unsafe { do_pow_fma(d, n) }
+            } else if is_sse41_available() {
+                |d, n| unsafe { do_pow_sse41(d, n) }
+            } else {
+                |d, n| unsafe { do_pow_sse(d, n) }
+            }
+        });
     }
     _dispatcher(d, n)
 }
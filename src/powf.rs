@@ -5,39 +5,53 @@
  * // license that can be found in the LICENSE file.
  */
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_pow_ps;
 use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
 use crate::efloorf;
 use crate::expf::eexpf;
-use crate::generalf::{copysignfk, is_neg_infinitef, is_pos_infinitef};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_pow;
+use crate::generalf::copysignfk;
 use crate::lnf::elnf;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vpowq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_pow_ps};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 #[inline]
 fn do_pow(d: f32, n: f32) -> f32 {
-    let value = eabsf(d);
-    let mut c = eexpf(n * elnf(value));
-    c = copysignfk(c, d);
-    if d < 0. && efloorf(n) != n {
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN
+    if n == 0. || d == 1. {
+        return 1.;
+    }
+    if d.is_nan() || n.is_nan() {
+        return f32::NAN;
+    }
+    // pow(-1, ±inf) = 1
+    if n.is_infinite() && eabsf(d) == 1. {
+        return 1.;
+    }
+    let is_integral = efloorf(n) == n;
+    // Not integral powers are not allowed for negative finite numbers
+    if d < 0. && d.is_finite() && !is_integral {
         return f32::NAN;
     }
-    if is_pos_infinitef(n) || d.is_infinite() {
-        f32::INFINITY
-    } else if is_neg_infinitef(n) {
-        0f32
-    } else if n.is_nan() || d.is_nan() {
-        f32::NAN
+    let c = eexpf(n * elnf(eabsf(d)));
+    // Sign of the base is kept only for odd integral powers
+    let half = n * 0.5;
+    if is_integral && efloorf(half) != half {
+        copysignfk(c, d)
     } else {
         c
     }
@@ -55,16 +69,38 @@ pub fn do_pow_neon(d: f32, n: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_pow_sse(d: f32, n: f32) -> f32 {
+    let val = _mm_set1_ps(d);
+    let power = _mm_set1_ps(n);
+    let gt = _mm_extract_psx::<0>(_mm_pow_ps(val, power)) as u32;
+    f32::from_bits(gt)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_pow_sse41(d: f32, n: f32) -> f32 {
+    do_pow_sse(d, n)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_pow_fma(d: f32, n: f32) -> f32 {
+    do_pow(d, n)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-pub fn do_pow_sse(d: f32, n: f32) -> f32 {
-    unsafe {
-        let val = _mm_set1_ps(d);
-        let power = _mm_set1_ps(n);
-        let gt = _mm_extract_ps::<0>(_mm_pow_ps(val, power)) as u32;
-        f32::from_bits(gt)
-    }
+fn do_pow_wasm(d: f32, n: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_pow(f32x4_splat(d), f32x4_splat(n)))
 }
 
 /// Computes power function, error bound *ULP 2.0*
@@ -75,12 +111,25 @@ pub fn epowf(d: f32, n: f32) -> f32 {
     {
         _dispatcher = do_pow_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_pow_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_pow_sse;
+        static DISPATCHER: OnceLock<fn(f32, f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d, n| unsafe { do_pow_fma(d, n) }
+            } else if is_sse41_available() {
+                |d, n| unsafe { do_pow_sse41(d, n) }
+            } else {
+                |d, n| unsafe { do_pow_sse(d, n) }
+            }
+        });
     }
     _dispatcher(d, n)
 }
@@ -0,0 +1,444 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+//! Accuracy tiers selectable per call site.
+//!
+//! Every routine is available through a [Precision] marker type:
+//!
+//! ```rust
+//! use erydanos::{Fast, HighAccuracy, ScalarMath, Standard};
+//!
+//! let a = Standard::esin(0.5f64);
+//! let b = HighAccuracy::eexp(0.5f32);
+//! let c = Fast::epow(2f32, 0.5f32);
+//! ```
+//!
+//! SIMD types use [SimdMath] the same way, e.g. `Fast::exp(v)` for `__m256` or `float32x4_t`.
+//! On `wasm32` both lane types share `v128`, so the lane type is spelled out:
+//! `<Fast as SimdMath<v128, f32>>::exp(v)`.
+//!
+//! | Tier               | Error bound                                            | Special values           |
+//! |--------------------|--------------------------------------------------------|--------------------------|
+//! | [Fast]             | Same as [Standard] for finite arguments in range       | Not checked, unspecified |
+//! | [Standard]         | Bound documented on each routine, *ULP 0.5 - 5.5*      | C99 Annex F              |
+//! | [HighAccuracy]     | f32: *ULP 1.0*, f64: same as [Standard]                | C99 Annex F              |
+//! | [CorrectlyRounded] | f32: *ULP 0.5* in [crate::cr], f64: same as [Standard] | C99 Annex F              |
+//!
+//! [Fast] uses `_fast` kernels where a backend has them (exp, ln, pow, cbrt, hypot),
+//! other routines have nothing to skip and fall back to [Standard].
+//! [HighAccuracy] for f32 evaluates the f64 routine and rounds once, so the result is within
+//! one ulp and most of the time correctly rounded. For f64 there is no more accurate kernel yet
+//! and it is the same as [Standard].
+//! [CorrectlyRounded] uses [crate::cr] routines, SIMD types evaluate them lane by lane.
+//! Routines [crate::cr] does not have yet are the same as [HighAccuracy].
+//!
+//! Routines returning a pair have no tiers and are called directly with [Standard] accuracy:
+//! [crate::esincosf], [crate::elgammaf], [crate::eremquof] and their f64 and SIMD counterparts
+//! (e.g. `_mm_sincos_ps`, `vlgammaq_f32`). [SimdMath] has no `remainder`, `sqrt`, `fmax` and `fmin`:
+//! they are exact, so there is nothing to select. Use native instructions (e.g. `_mm_sqrt_ps`,
+//! `vsqrtq_f32`, `vmaxnmq_f32`, `f32x4_sqrt`) or [crate::eremainderf] per lane.
+//! SIMD `fmod`, `hypot3` and `hypot4` are within one ulp already, so [HighAccuracy] uses
+//! the standard kernel for them.
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Fast {}
+    impl Sealed for super::Standard {}
+    impl Sealed for super::HighAccuracy {}
+    impl Sealed for super::CorrectlyRounded {}
+}
+
+/// Accuracy tier of a [Precision] marker
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Accuracy {
+    Fast,
+    Standard,
+    High,
+    CorrectlyRounded,
+}
+
+/// Marker type selecting accuracy tier of a routine
+pub trait Precision: private::Sealed + Copy + Default + 'static {
+    const ACCURACY: Accuracy;
+}
+
+/// Skips special values checks, see [module docs](crate::precision)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fast;
+
+/// Documented error bound with C99 Annex F special values, see [module docs](crate::precision)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Standard;
+
+/// Tightest available error bound with C99 Annex F special values, see [module docs](crate::precision)
+///
+/// For f64 it is the same as [Standard].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HighAccuracy;
+
+/// Correctly rounded f32 with C99 Annex F special values, see [module docs](crate::precision)
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CorrectlyRounded;
+
+impl Precision for Fast {
+    const ACCURACY: Accuracy = Accuracy::Fast;
+}
+
+impl Precision for Standard {
+    const ACCURACY: Accuracy = Accuracy::Standard;
+}
+
+impl Precision for HighAccuracy {
+    const ACCURACY: Accuracy = Accuracy::High;
+}
+
+impl Precision for CorrectlyRounded {
+    const ACCURACY: Accuracy = Accuracy::CorrectlyRounded;
+}
+
+/// Scalar routines with accuracy selected by the implementing [Precision] marker
+pub trait ScalarMath<T> {
+    /// Computes exp
+    fn eexp(x: T) -> T;
+    /// Computes 2^x
+    fn eexp2(x: T) -> T;
+    /// Computes 10^x
+    fn eexp10(x: T) -> T;
+    /// Computes exp(x) - 1
+    fn eexpm1(x: T) -> T;
+    /// Computes natural logarithm
+    fn eln(x: T) -> T;
+    /// Computes log2
+    fn elog2(x: T) -> T;
+    /// Computes log10
+    fn elog10(x: T) -> T;
+    /// Computes ln(1 + x)
+    fn elog1p(x: T) -> T;
+    /// Computes sine
+    fn esin(x: T) -> T;
+    /// Computes cosine
+    fn ecos(x: T) -> T;
+    /// Computes tangent
+    fn etan(x: T) -> T;
+    /// Computes arcsine
+    fn easin(x: T) -> T;
+    /// Computes arccosine
+    fn eacos(x: T) -> T;
+    /// Computes arctangent
+    fn eatan(x: T) -> T;
+    /// Computes hyperbolic sine
+    fn esinh(x: T) -> T;
+    /// Computes hyperbolic cosine
+    fn ecosh(x: T) -> T;
+    /// Computes hyperbolic tangent
+    fn etanh(x: T) -> T;
+    /// Computes inverse hyperbolic sine
+    fn easinh(x: T) -> T;
+    /// Computes inverse hyperbolic cosine
+    fn eacosh(x: T) -> T;
+    /// Computes inverse hyperbolic tangent
+    fn eatanh(x: T) -> T;
+    /// Computes cube root
+    fn ecbrt(x: T) -> T;
+    /// Computes error function
+    fn eerf(x: T) -> T;
+    /// Computes complementary error function
+    fn eerfc(x: T) -> T;
+    /// Computes inverse error function
+    fn eerfinv(x: T) -> T;
+    /// Computes gamma function
+    fn etgamma(x: T) -> T;
+    /// Computes digamma function
+    fn edigamma(x: T) -> T;
+    /// Computes x raised to the power n
+    fn epow(x: T, n: T) -> T;
+    /// Computes arctangent of y/x
+    fn eatan2(y: T, x: T) -> T;
+    /// Computes 2D Euclidian distance
+    fn ehypot(x: T, y: T) -> T;
+    /// Computes 3D Euclidian distance
+    fn ehypot3(x: T, y: T, z: T) -> T;
+    /// Computes 4D Euclidian distance
+    fn ehypot4(x: T, y: T, z: T, w: T) -> T;
+    /// Computes remainder of x/y truncated towards zero, exact in every tier
+    fn efmod(x: T, y: T) -> T;
+    /// Computes IEEE remainder of x/y rounded to nearest, exact in every tier
+    fn eremainder(x: T, y: T) -> T;
+    /// Computes square root, correctly rounded in every tier
+    fn esqrt(x: T) -> T;
+    /// Computes maximum of two values, exact in every tier
+    fn efmax(x: T, y: T) -> T;
+    /// Computes minimum of two values, exact in every tier
+    fn efmin(x: T, y: T) -> T;
+}
+
+/// SIMD routines with accuracy selected by the implementing [Precision] marker,
+/// `V` is a vector type and `L` is its lane type
+pub trait SimdMath<V, L> {
+    /// Computes exp
+    unsafe fn exp(x: V) -> V;
+    /// Computes 2^x
+    unsafe fn exp2(x: V) -> V;
+    /// Computes 10^x
+    unsafe fn exp10(x: V) -> V;
+    /// Computes exp(x) - 1
+    unsafe fn expm1(x: V) -> V;
+    /// Computes natural logarithm
+    unsafe fn ln(x: V) -> V;
+    /// Computes log2
+    unsafe fn log2(x: V) -> V;
+    /// Computes log10
+    unsafe fn log10(x: V) -> V;
+    /// Computes ln(1 + x)
+    unsafe fn log1p(x: V) -> V;
+    /// Computes sine
+    unsafe fn sin(x: V) -> V;
+    /// Computes cosine
+    unsafe fn cos(x: V) -> V;
+    /// Computes tangent
+    unsafe fn tan(x: V) -> V;
+    /// Computes arcsine
+    unsafe fn asin(x: V) -> V;
+    /// Computes arccosine
+    unsafe fn acos(x: V) -> V;
+    /// Computes arctangent
+    unsafe fn atan(x: V) -> V;
+    /// Computes hyperbolic sine
+    unsafe fn sinh(x: V) -> V;
+    /// Computes hyperbolic cosine
+    unsafe fn cosh(x: V) -> V;
+    /// Computes hyperbolic tangent
+    unsafe fn tanh(x: V) -> V;
+    /// Computes inverse hyperbolic sine
+    unsafe fn asinh(x: V) -> V;
+    /// Computes inverse hyperbolic cosine
+    unsafe fn acosh(x: V) -> V;
+    /// Computes inverse hyperbolic tangent
+    unsafe fn atanh(x: V) -> V;
+    /// Computes cube root
+    unsafe fn cbrt(x: V) -> V;
+    /// Computes error function
+    unsafe fn erf(x: V) -> V;
+    /// Computes complementary error function
+    unsafe fn erfc(x: V) -> V;
+    /// Computes inverse error function
+    unsafe fn erfinv(x: V) -> V;
+    /// Computes gamma function
+    unsafe fn tgamma(x: V) -> V;
+    /// Computes digamma function
+    unsafe fn digamma(x: V) -> V;
+    /// Computes x raised to the power n
+    unsafe fn pow(x: V, n: V) -> V;
+    /// Computes arctangent of y/x
+    unsafe fn atan2(y: V, x: V) -> V;
+    /// Computes 2D Euclidian distance
+    unsafe fn hypot(x: V, y: V) -> V;
+    /// Computes 3D Euclidian distance
+    unsafe fn hypot3(x: V, y: V, z: V) -> V;
+    /// Computes 4D Euclidian distance
+    unsafe fn hypot4(x: V, y: V, z: V, w: V) -> V;
+    /// Computes remainder of x/y truncated towards zero, exact in every tier
+    unsafe fn fmod(x: V, y: V) -> V;
+}
+
+macro_rules! scalar_math_f32 {
+    ($($name:ident($($arg:ident),+) => $f32:path, $f64:path $(, cr: $cr:path)?;)*) => {
+        impl<P: Precision> ScalarMath<f32> for P {
+            $(
+                #[inline]
+                fn $name($($arg: f32),+) -> f32 {
+                    scalar_math_f32!(@dispatch ($($arg),+), $f32, $f64, [$($cr)?])
+                }
+            )*
+        }
+    };
+    (@dispatch ($($arg:ident),+), $f32:path, $f64:path, [$cr:path]) => {
+        match P::ACCURACY {
+            Accuracy::CorrectlyRounded => $cr($($arg),+),
+            Accuracy::High => $f64($($arg as f64),+) as f32,
+            _ => $f32($($arg),+),
+        }
+    };
+    (@dispatch ($($arg:ident),+), $f32:path, $f64:path, []) => {
+        match P::ACCURACY {
+            Accuracy::High | Accuracy::CorrectlyRounded => $f64($($arg as f64),+) as f32,
+            _ => $f32($($arg),+),
+        }
+    };
+}
+
+macro_rules! scalar_math_f64 {
+    ($($name:ident($($arg:ident),+) => $f64:path;)*) => {
+        impl<P: Precision> ScalarMath<f64> for P {
+            $(
+                #[inline]
+                fn $name($($arg: f64),+) -> f64 {
+                    $f64($($arg),+)
+                }
+            )*
+        }
+    };
+}
+
+scalar_math_f32! {
+    eexp(x) => crate::eexpf, crate::eexp, cr: crate::cr::expf;
+    eexp2(x) => crate::eexp2f, crate::eexp2, cr: crate::cr::exp2f;
+    eexp10(x) => crate::eexp10f, crate::eexp10, cr: crate::cr::exp10f;
+    eexpm1(x) => crate::eexpm1f, crate::eexpm1;
+    eln(x) => crate::elnf, crate::eln, cr: crate::cr::lnf;
+    elog2(x) => crate::elog2f, crate::elog2, cr: crate::cr::log2f;
+    elog10(x) => crate::elog10f, crate::elog10, cr: crate::cr::log10f;
+    elog1p(x) => crate::elog1pf, crate::elog1p;
+    esin(x) => crate::esinf, crate::esin, cr: crate::cr::sinf;
+    ecos(x) => crate::ecosf, crate::ecos, cr: crate::cr::cosf;
+    etan(x) => crate::etanf, crate::etan, cr: crate::cr::tanf;
+    easin(x) => crate::easinf, crate::easin, cr: crate::cr::asinf;
+    eacos(x) => crate::eacosf, crate::eacos, cr: crate::cr::acosf;
+    eatan(x) => crate::eatanf, crate::eatan, cr: crate::cr::atanf;
+    esinh(x) => crate::esinhf, crate::esinh;
+    ecosh(x) => crate::ecoshf, crate::ecosh;
+    etanh(x) => crate::etanhf, crate::etanh;
+    easinh(x) => crate::easinhf, crate::easinh;
+    eacosh(x) => crate::eacoshf, crate::eacosh;
+    eatanh(x) => crate::eatanhf, crate::eatanh;
+    ecbrt(x) => crate::ecbrtf, crate::ecbrt, cr: crate::cr::cbrtf;
+    eerf(x) => crate::eerff, crate::eerf;
+    eerfc(x) => crate::eerfcf, crate::eerfc;
+    eerfinv(x) => crate::eerfinvf, crate::eerfinv;
+    etgamma(x) => crate::etgammaf, crate::etgamma;
+    edigamma(x) => crate::edigammaf, crate::edigamma;
+    epow(x, n) => crate::epowf, crate::epow, cr: crate::cr::powf;
+    eatan2(y, x) => crate::eatan2f, crate::eatan2;
+    ehypot(x, y) => crate::ehypotf, crate::ehypot;
+    ehypot3(x, y, z) => crate::ehypot3f, crate::hypot3::ehypot3;
+    ehypot4(x, y, z, w) => crate::ehypot4f, crate::ehypot4;
+    efmod(x, y) => crate::efmodf, crate::efmod;
+    eremainder(x, y) => crate::eremainderf, crate::eremainder;
+    esqrt(x) => crate::esqrtf, crate::esqrt;
+    efmax(x, y) => crate::efmaxf, crate::efmax;
+    efmin(x, y) => crate::efminf, crate::efmin;
+}
+
+scalar_math_f64! {
+    eexp(x) => crate::eexp;
+    eexp2(x) => crate::eexp2;
+    eexp10(x) => crate::eexp10;
+    eexpm1(x) => crate::eexpm1;
+    eln(x) => crate::eln;
+    elog2(x) => crate::elog2;
+    elog10(x) => crate::elog10;
+    elog1p(x) => crate::elog1p;
+    esin(x) => crate::esin;
+    ecos(x) => crate::ecos;
+    etan(x) => crate::etan;
+    easin(x) => crate::easin;
+    eacos(x) => crate::eacos;
+    eatan(x) => crate::eatan;
+    esinh(x) => crate::esinh;
+    ecosh(x) => crate::ecosh;
+    etanh(x) => crate::etanh;
+    easinh(x) => crate::easinh;
+    eacosh(x) => crate::eacosh;
+    eatanh(x) => crate::eatanh;
+    ecbrt(x) => crate::ecbrt;
+    eerf(x) => crate::eerf;
+    eerfc(x) => crate::eerfc;
+    eerfinv(x) => crate::eerfinv;
+    etgamma(x) => crate::etgamma;
+    edigamma(x) => crate::edigamma;
+    epow(x, n) => crate::epow;
+    eatan2(y, x) => crate::eatan2;
+    ehypot(x, y) => crate::ehypot;
+    ehypot3(x, y, z) => crate::hypot3::ehypot3;
+    ehypot4(x, y, z, w) => crate::ehypot4;
+    efmod(x, y) => crate::efmod;
+    eremainder(x, y) => crate::eremainder;
+    esqrt(x) => crate::esqrt;
+    efmax(x, y) => crate::efmax;
+    efmin(x, y) => crate::efmin;
+}
+
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128")
+))]
+/// Implements [SimdMath] for a vector type.
+///
+/// Each routine lists the standard kernel, optionally the `fast:` kernel, and for f32 vectors
+/// the f64 kernel used by [HighAccuracy] and the [crate::cr] routine used by [CorrectlyRounded].
+/// Lanes are widened by `widen_ps!` of the calling backend.
+macro_rules! simd_math_impl {
+    (
+        $v:ty, $lane:ty,
+        $(
+            $name:ident($($arg:ident),+) => $std:path
+            $(, fast: $fast:path)? $(, high: $high:path)? $(, cr: $cr:path)?;
+        )*
+    ) => {
+        impl<P: $crate::precision::Precision> $crate::precision::SimdMath<$v, $lane> for P {
+            $(
+                #[inline]
+                unsafe fn $name($($arg: $v),+) -> $v {
+                    $crate::precision::simd_math_impl!(
+                        @dispatch $v, ($($arg),+), $std, [$($fast)?], [$($high)?], [$($cr)?]
+                    )
+                }
+            )*
+        }
+    };
+    (@dispatch $v:ty, $args:tt, $std:path, [$($fast:path)?], [$high:path], [$cr:path]) => {
+        match P::ACCURACY {
+            $($crate::precision::Accuracy::Fast => $fast $args,)?
+            $crate::precision::Accuracy::High => widen_ps!($high, $args),
+            $crate::precision::Accuracy::CorrectlyRounded => {
+                $crate::precision::simd_math_impl!(@lanes $v, $cr, $args)
+            }
+            _ => $std $args,
+        }
+    };
+    (@dispatch $v:ty, $args:tt, $std:path, [$($fast:path)?], [$high:path], []) => {
+        match P::ACCURACY {
+            $($crate::precision::Accuracy::Fast => $fast $args,)?
+            $crate::precision::Accuracy::High | $crate::precision::Accuracy::CorrectlyRounded => {
+                widen_ps!($high, $args)
+            }
+            _ => $std $args,
+        }
+    };
+    (@dispatch $v:ty, $args:tt, $std:path, [$($fast:path)?], [], []) => {
+        match P::ACCURACY {
+            $($crate::precision::Accuracy::Fast => $fast $args,)?
+            _ => $std $args,
+        }
+    };
+    (@lanes $v:ty, $f:path, ($x:ident)) => {{
+        let mut lanes: [f32; std::mem::size_of::<$v>() / 4] = std::mem::transmute($x);
+        for lane in lanes.iter_mut() {
+            *lane = $f(*lane);
+        }
+        std::mem::transmute::<[f32; std::mem::size_of::<$v>() / 4], $v>(lanes)
+    }};
+    (@lanes $v:ty, $f:path, ($x:ident, $y:ident)) => {{
+        let mut lanes: [f32; std::mem::size_of::<$v>() / 4] = std::mem::transmute($x);
+        let other: [f32; std::mem::size_of::<$v>() / 4] = std::mem::transmute($y);
+        for (lane, other) in lanes.iter_mut().zip(other) {
+            *lane = $f(*lane, other);
+        }
+        std::mem::transmute::<[f32; std::mem::size_of::<$v>() / 4], $v>(lanes)
+    }};
+}
+
+#[cfg(any(
+    target_arch = "x86_64",
+    target_arch = "x86",
+    all(target_arch = "aarch64", target_feature = "neon"),
+    all(target_arch = "wasm32", target_feature = "simd128")
+))]
+pub(crate) use simd_math_impl;
@@ -5,20 +5,27 @@
  * // license that can be found in the LICENSE file.
  */
 
-use crate::generalf::{mlaf, rintk, IsNegZero};
+use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::double_precision::multiply_as_doubled;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_sin;
+use crate::generalf::{copysignk, mlaf, rempi, rintk, PAYNE_HANEK_THRESHOLD};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vsinq_f64;
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_extract_pd, _mm_sin_pd};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::*;
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub(crate) const SIN_POLY_2_D: f64 = -0.1666666666666666666666f64;
 pub(crate) const SIN_POLY_3_D: f64 = 0.008333333333333333332719f64;
@@ -33,18 +40,10 @@ pub(crate) const SIN_POLY_10_D: f64 = -8.118486649859753485496e-18;
 pub(crate) const PI_A2: f64 = 3.141_592_653_589_793_116;
 pub(crate) const PI_B2: f64 = 1.224_646_799_147_353_207_2_e-16;
 
+/// Evaluates sine polynomial for reduced |r| <= pi/2
 #[inline]
-fn do_sin(d: f64) -> f64 {
-    let qf = rintk(std::f64::consts::FRAC_1_PI * d);
-    let q = qf as i64;
-    let mut r = mlaf(qf, -PI_A2, d);
-    r = mlaf(qf, -PI_B2, r);
-
+pub(crate) fn sink(r: f64) -> f64 {
     let x2 = r * r;
-
-    if (q & 1) != 0 {
-        r = -r;
-    }
     let mut u = SIN_POLY_10_D;
     u = mlaf(u, x2, SIN_POLY_9_D);
     u = mlaf(u, x2, SIN_POLY_8_D);
@@ -54,11 +53,41 @@ fn do_sin(d: f64) -> f64 {
     u = mlaf(u, x2, SIN_POLY_4_D);
     u = mlaf(u, x2, SIN_POLY_3_D);
     u = mlaf(u, x2, SIN_POLY_2_D);
-    u = u * x2 * r + r;
-    if u.isnegzero() {
-        return 0.;
+    u * x2 * r + r
+}
+
+/// Computes sin(pi*x) for |x| < 2^52, integers give exact zeros
+#[inline(always)]
+pub(crate) fn sinpik(x: f64) -> f64 {
+    // Period is 2 so the reduction is exact, then folding into [-0.5, 0.5] by sin(pi - y) = sin(y)
+    let mut r = x - 2. * rintk(0.5 * x);
+    if eabs(r) > 0.5 {
+        r = copysignk(1., r) - r;
+    }
+    // pi * r is kept as double-float, sin(y + lo) = sin(y) + lo * cos(y) with cos(y) ~ 1 - y^2/2
+    let y = multiply_as_doubled(r, PI_A2);
+    let lo = mlaf(r, PI_B2, y.1);
+    mlaf(lo, mlaf(-0.5 * y.0, y.0, 1.), sink(y.0))
+}
+
+#[inline]
+pub(crate) fn do_sin(d: f64) -> f64 {
+    // sin(±0) = ±0
+    if d == 0. {
+        return d;
+    }
+    let (q, mut r) = if d.abs() > PAYNE_HANEK_THRESHOLD && d.is_finite() {
+        rempi(d)
+    } else {
+        let qf = rintk(std::f64::consts::FRAC_1_PI * d);
+        let r = mlaf(qf, -PI_A2, d);
+        (qf as i32, mlaf(qf, -PI_B2, r))
+    };
+
+    if (q & 1) != 0 {
+        r = -r;
     }
-    u
+    sink(r)
 }
 
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
@@ -71,17 +100,39 @@ fn do_sin_neon(d: f64) -> f64 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
+#[inline(always)]
+unsafe fn do_sin_sse(d: f64) -> f64 {
+    let j = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_sin_pd(j))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_sin_sse41(d: f64) -> f64 {
+    do_sin_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_sin_fma(d: f64) -> f64 {
+    do_sin(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_sin_sse(d: f64) -> f64 {
-    unsafe {
-        let j = _mm_set1_pd(d);
-        _mm_extract_pd::<0>(_mm_sin_pd(j))
-    }
+fn do_sin_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_sin(f64x2_splat(d)))
 }
 
-/// Computes sine function with *ULP 1.5* on range [-15; 15]
+/// Computes sine function with *ULP 2.5*
 #[inline]
 pub fn esin(d: f64) -> f64 {
     let mut _dispatcher: fn(f64) -> f64 = do_sin;
@@ -89,12 +140,25 @@ pub fn esin(d: f64) -> f64 {
     {
         _dispatcher = do_sin_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_sin_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_sin_sse;
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_sin_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_sin_sse41(d) }
+            } else {
+                |d| unsafe { do_sin_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
@@ -0,0 +1,149 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_sincos;
+use crate::generalf::{mlaf, rempi2, rintk, PAYNE_HANEK_THRESHOLD};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vsincosq_f64;
+use crate::sin::{
+    PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
+    SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
+};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_pd, _mm_sincos_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::*;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn sin_poly(r: f64) -> f64 {
+    let x2 = r * r;
+    let mut u = SIN_POLY_10_D;
+    u = mlaf(u, x2, SIN_POLY_9_D);
+    u = mlaf(u, x2, SIN_POLY_8_D);
+    u = mlaf(u, x2, SIN_POLY_7_D);
+    u = mlaf(u, x2, SIN_POLY_6_D);
+    u = mlaf(u, x2, SIN_POLY_5_D);
+    u = mlaf(u, x2, SIN_POLY_4_D);
+    u = mlaf(u, x2, SIN_POLY_3_D);
+    u = mlaf(u, x2, SIN_POLY_2_D);
+    mlaf(u, x2 * r, r)
+}
+
+#[inline]
+fn do_sincos(d: f64) -> (f64, f64) {
+    let (q, r) = if d.abs() > PAYNE_HANEK_THRESHOLD && d.is_finite() {
+        rempi2(d)
+    } else {
+        let qf = rintk(d * std::f64::consts::FRAC_2_PI);
+        let r = mlaf(qf, -PI_A2 * 0.5, d);
+        (qf as i32, mlaf(qf, -PI_B2 * 0.5, r))
+    };
+
+    // cos(r) = 1 - 2*sin(r/2)^2, |r| <= π/4
+    let s = sin_poly(r);
+    let h = sin_poly(r * 0.5);
+    let c = mlaf(-2. * h, h, 1.);
+
+    let (mut sin, mut cos) = if q & 1 != 0 { (c, s) } else { (s, c) };
+    if q & 2 != 0 {
+        sin = -sin;
+    }
+    if q.wrapping_add(1) & 2 != 0 {
+        cos = -cos;
+    }
+    // sin(±0) = ±0
+    if d == 0. {
+        sin = d;
+    }
+    (sin, cos)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_sincos_neon(d: f64) -> (f64, f64) {
+    unsafe {
+        let (s, c) = vsincosq_f64(vdupq_n_f64(d));
+        (vgetq_lane_f64::<0>(s), vgetq_lane_f64::<0>(c))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_sincos_sse(d: f64) -> (f64, f64) {
+    let (s, c) = _mm_sincos_pd(_mm_set1_pd(d));
+    (_mm_extract_pd::<0>(s), _mm_extract_pd::<0>(c))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_sincos_sse41(d: f64) -> (f64, f64) {
+    do_sincos_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_sincos_fma(d: f64) -> (f64, f64) {
+    do_sincos(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_sincos_wasm(d: f64) -> (f64, f64) {
+    let (s, c) = f64x2_sincos(f64x2_splat(d));
+    (f64x2_extract_lane::<0>(s), f64x2_extract_lane::<0>(c))
+}
+
+/// Computes sine and cosine with a single argument reduction, returns `(sin, cos)`, *ULP 1.5*
+#[inline]
+pub fn esincos(d: f64) -> (f64, f64) {
+    let mut _dispatcher: fn(f64) -> (f64, f64) = do_sincos;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_sincos_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_sincos_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> (f64, f64)> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_sincos_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_sincos_sse41(d) }
+            } else {
+                |d| unsafe { do_sincos_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
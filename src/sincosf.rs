@@ -0,0 +1,148 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_sincos;
+use crate::generalf::{mlaf, rempi2f, rintfk, PAYNE_HANEK_THRESHOLD_F};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vsincosq_f32;
+use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_sincos_ps};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::*;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn sin_poly(r: f32) -> f32 {
+    let x2 = r * r;
+    let mut u = SIN_POLY_5_S;
+    u = mlaf(u, x2, SIN_POLY_4_S);
+    u = mlaf(u, x2, SIN_POLY_3_S);
+    u = mlaf(u, x2, SIN_POLY_2_S);
+    u = mlaf(u, x2, SIN_POLY_1_S);
+    mlaf(u, x2 * r, r)
+}
+
+#[inline]
+fn do_sincosf(d: f32) -> (f32, f32) {
+    let (q, r) = if d.abs() > PAYNE_HANEK_THRESHOLD_F && d.is_finite() {
+        rempi2f(d)
+    } else {
+        let qf = rintfk(d * std::f32::consts::FRAC_2_PI);
+        let mut r = mlaf(qf, -PI_A_F * 0.5, d);
+        r = mlaf(qf, -PI_B_F * 0.5, r);
+        r = mlaf(qf, -PI_C_F * 0.5, r);
+        (qf as i32, mlaf(qf, -PI_D_F * 0.5, r))
+    };
+
+    // cos(r) = 1 - 2*sin(r/2)^2, |r| <= π/4
+    let s = sin_poly(r);
+    let h = sin_poly(r * 0.5);
+    let c = mlaf(-2. * h, h, 1.);
+
+    let (mut sin, mut cos) = if q & 1 != 0 { (c, s) } else { (s, c) };
+    if q & 2 != 0 {
+        sin = -sin;
+    }
+    if q.wrapping_add(1) & 2 != 0 {
+        cos = -cos;
+    }
+    // sin(±0) = ±0
+    if d == 0. {
+        sin = d;
+    }
+    (sin, cos)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_sincosf_neon(d: f32) -> (f32, f32) {
+    unsafe {
+        let (s, c) = vsincosq_f32(vdupq_n_f32(d));
+        (vgetq_lane_f32::<0>(s), vgetq_lane_f32::<0>(c))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_sincosf_sse(d: f32) -> (f32, f32) {
+    let (s, c) = _mm_sincos_ps(_mm_set1_ps(d));
+    (
+        f32::from_bits(_mm_extract_psx::<0>(s) as u32),
+        f32::from_bits(_mm_extract_psx::<0>(c) as u32),
+    )
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_sincosf_sse41(d: f32) -> (f32, f32) {
+    do_sincosf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_sincosf_fma(d: f32) -> (f32, f32) {
+    do_sincosf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_sincosf_wasm(d: f32) -> (f32, f32) {
+    let (s, c) = f32x4_sincos(f32x4_splat(d));
+    (f32x4_extract_lane::<0>(s), f32x4_extract_lane::<0>(c))
+}
+
+/// Computes sine and cosine with a single argument reduction, returns `(sin, cos)`, *ULP 2.0*
+#[inline]
+pub fn esincosf(d: f32) -> (f32, f32) {
+    let mut _dispatcher: fn(f32) -> (f32, f32) = do_sincosf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_sincosf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_sincosf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> (f32, f32)> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_sincosf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_sincosf_sse41(d) }
+            } else {
+                |d| unsafe { do_sincosf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
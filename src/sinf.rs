@@ -8,19 +8,26 @@
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
 
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_sin_ps;
+use crate::abs::eabsf;
 use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
-use crate::generalf::{mlaf, rintfk, IsNegZero};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::double_precision::multiply_as_doubled;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_sin;
+use crate::generalf::{copysignfk, mlaf, rempif, rintfk, PAYNE_HANEK_THRESHOLD_F};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vsinq_f32;
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_sin_ps};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub const SIN_POLY_1_S: f32 = -0.16666667f32;
 pub const SIN_POLY_2_S: f32 = 0.0083333375f32;
@@ -28,31 +35,55 @@ pub const SIN_POLY_3_S: f32 = -0.00019841341f32;
 pub const SIN_POLY_4_S: f32 = 2.7551241e-6f32;
 pub const SIN_POLY_5_S: f32 = -2.4535176e-8f32;
 
-#[inline]
-fn do_sin(d: f32) -> f32 {
-    let qf = rintfk(std::f32::consts::FRAC_1_PI * d);
-    let q = qf as i32;
-    let mut r = mlaf(qf, -PI_A_F, d);
-    r = mlaf(qf, -PI_B_F, r);
-    r = mlaf(qf, -PI_C_F, r);
-    r = mlaf(qf, -PI_D_F, r);
+pub(crate) const PI_S: f32 = std::f32::consts::PI;
+pub(crate) const PI_LO_S: f32 = -8.742278e-8;
 
+/// Evaluates sine polynomial for reduced |r| <= pi/2
+#[inline]
+pub(crate) fn sinkf(r: f32) -> f32 {
     let x2 = r * r;
-
-    if (q & 1) != 0 {
-        r = -r;
-    }
-
     let mut u = SIN_POLY_5_S;
     u = mlaf(u, x2, SIN_POLY_4_S);
     u = mlaf(u, x2, SIN_POLY_3_S);
     u = mlaf(u, x2, SIN_POLY_2_S);
     u = mlaf(u, x2, SIN_POLY_1_S);
-    u = mlaf(u, x2 * r, r);
-    if u.isnegzero() {
-        return 0f32;
+    mlaf(u, x2 * r, r)
+}
+
+/// Computes sin(pi*x) for |x| < 2^23, integers give exact zeros
+#[inline(always)]
+pub(crate) fn sinpikf(x: f32) -> f32 {
+    // Period is 2 so the reduction is exact, then folding into [-0.5, 0.5] by sin(pi - y) = sin(y)
+    let mut r = x - 2. * rintfk(0.5 * x);
+    if eabsf(r) > 0.5 {
+        r = copysignfk(1., r) - r;
+    }
+    // pi * r is kept as double-float, sin(y + lo) = sin(y) + lo * cos(y) with cos(y) ~ 1 - y^2/2
+    let y = multiply_as_doubled(r, PI_S);
+    let lo = mlaf(r, PI_LO_S, y.1);
+    mlaf(lo, mlaf(-0.5 * y.0, y.0, 1.), sinkf(y.0))
+}
+
+#[inline]
+fn do_sin(d: f32) -> f32 {
+    // sin(±0) = ±0
+    if d == 0. {
+        return d;
+    }
+    let (q, mut r) = if d.abs() > PAYNE_HANEK_THRESHOLD_F && d.is_finite() {
+        rempif(d)
+    } else {
+        let qf = rintfk(std::f32::consts::FRAC_1_PI * d);
+        let mut r = mlaf(qf, -PI_A_F, d);
+        r = mlaf(qf, -PI_B_F, r);
+        r = mlaf(qf, -PI_C_F, r);
+        (qf as i32, mlaf(qf, -PI_D_F, r))
+    };
+
+    if (q & 1) != 0 {
+        r = -r;
     }
-    u
+    sinkf(r)
 }
 
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
@@ -66,19 +97,40 @@ fn do_sin_neon(d: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_sin_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_sin_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
 ))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_sin_sse41(d: f32) -> f32 {
+    do_sin_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_sin_fma(d: f32) -> f32 {
+    do_sin(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-fn do_sin_sse(d: f32) -> f32 {
-    unsafe {
-        let v = _mm_set1_ps(d);
-        let value = _mm_sin_ps(v);
-        let ex = f32::from_bits(_mm_extract_ps::<0>(value) as u32);
-        ex
-    }
+fn do_sin_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_sin(f32x4_splat(d)))
 }
 
-/// Computes sine function with error bound *ULP 1.2*
+/// Computes sine function with error bound *ULP 2.5*
 #[inline]
 pub fn esinf(d: f32) -> f32 {
     let mut _dispatcher: fn(f32) -> f32 = do_sin;
@@ -86,12 +138,25 @@ pub fn esinf(d: f32) -> f32 {
     {
         _dispatcher = do_sin_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_sin_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_sin_sse;
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_sin_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_sin_sse41(d) }
+            } else {
+                |d| unsafe { do_sin_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
@@ -0,0 +1,118 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::expf::do_exp;
+use crate::expm1f::do_expm1f;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_sinh;
+use crate::generalf::copysignfk;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vsinhq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_sinh_ps};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline(always)]
+fn do_sinhf(d: f32) -> f32 {
+    let a = eabsf(d);
+    let r = if a > 88f32 {
+        // exp(|d|) / 2 overflows before sinh does, split it as exp(|d|/2)^2 / 2
+        let h = do_exp(a * 0.5);
+        h * 0.5 * h
+    } else {
+        let e = do_expm1f(a);
+        0.5 * (e + e / (e + 1.))
+    };
+    copysignfk(r, d)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_sinhf_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vsinhq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_sinhf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_sinh_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_sinhf_sse41(d: f32) -> f32 {
+    do_sinhf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_sinhf_fma(d: f32) -> f32 {
+    do_sinhf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_sinhf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_sinh(f32x4_splat(d)))
+}
+
+/// Computes hyperbolic sine, error bound *ULP 3.5*
+#[inline]
+pub fn esinhf(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_sinhf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_sinhf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_sinhf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_sinhf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_sinhf_sse41(d) }
+            } else {
+                |d| unsafe { do_sinhf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -24,7 +24,7 @@ pub fn esqrt(d: f64) -> f64 {
     }
 
     // http://en.wikipedia.org/wiki/Fast_inverse_square_root
-    let mut x = f64::from_bits(0x5fe6ec85e7de30da - ((d + 1e-320).to_bits() >> 1));
+    let mut x = f64::from_bits(0x5fe6ec85e7de30dau64.wrapping_sub((d + 1e-320).to_bits() >> 1));
 
     x = x * (1.5 - 0.5 * d * x * x);
     x = x * (1.5 - 0.5 * d * x * x);
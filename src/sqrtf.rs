@@ -24,7 +24,7 @@ pub fn esqrtf(d: f32) -> f32 {
     }
 
     // http://en.wikipedia.org/wiki/Fast_inverse_square_root
-    let mut x = f32::from_bits(0x5f375a86 - ((d + 1e-45f32).to_bits() >> 1));
+    let mut x = f32::from_bits(0x5f375a86u32.wrapping_sub((d + 1e-45f32).to_bits() >> 1));
 
     x = x * (1.5f32 - 0.5f32 * d * x * x);
     x = x * (1.5f32 - 0.5f32 * d * x * x);
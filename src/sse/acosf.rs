@@ -12,7 +12,7 @@ use std::arch::x86::*;
 use std::arch::x86_64::*;
 
 /// Computes arccos, error bound *ULP 2.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_acos_ps(x: __m128) -> __m128 {
     let gt_zero = _mm_cmpgt_ps(x, _mm_setzero_ps());
     let x_a = _mm_abs_ps(x);
@@ -24,20 +24,21 @@ pub unsafe fn _mm_acos_ps(x: __m128) -> __m128 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_acosf() {
         unsafe {
             let value = _mm_set1_ps(0.3);
             let comparison = _mm_acos_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<1>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<1>(comparison) as u32);
             let control = 1.2661037;
             assert_eq!(flag_1, control);
         }
         unsafe {
             let value = _mm_set1_ps(-0.3);
             let comparison = _mm_acos_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<1>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<1>(comparison) as u32);
             let control = 1.87548898081029412720f32;
             assert_eq!(flag_1, control);
         }
@@ -45,7 +46,7 @@ mod tests {
         unsafe {
             let value = _mm_set1_ps(-2f32);
             let comparison = _mm_acos_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<1>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<1>(comparison) as u32);
             assert!(flag_1.is_nan());
         }
     }
@@ -19,7 +19,7 @@ use crate::asin::{
 use crate::{_mm_abs_pd, _mm_copysign_pd, _mm_mlaf_pd, _mm_select_pd};
 
 /// Computes arcsin, error bound *ULP 2.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_asin_pd(d: __m128d) -> __m128d {
     let ones = _mm_set1_pd(1.);
     let ca = _mm_abs_pd(d);
@@ -50,7 +50,7 @@ pub unsafe fn _mm_asin_pd(d: __m128d) -> __m128d {
     u = _mm_mlaf_pd(u, x, _mm_set1_pd(ASIN_POLY_1_D));
     u = _mm_mul_pd(u, x);
     let j = u;
-    let reconstruct_reversed = _mm_select_pd(
+    let reconstruct_reversed = _mm_mlaf_pd(
         _mm_set1_pd(-2f64),
         j,
         _mm_set1_pd(std::f64::consts::FRAC_PI_2),
@@ -68,6 +68,13 @@ mod tests {
 
     #[test]
     fn test_asind() {
+        unsafe {
+            let value = _mm_set1_pd(0.7);
+            let comparison = _mm_asin_pd(value);
+            let flag_1 = _mm_extract_pd::<1>(comparison);
+            let control = 0.775397496610753f64;
+            assert!((flag_1 - control).abs() < 1e-15);
+        }
         unsafe {
             let value = _mm_set1_pd(0.3);
             let comparison = _mm_asin_pd(value);
@@ -15,7 +15,7 @@ use std::arch::x86::*;
 use std::arch::x86_64::*;
 
 /// Computes arcsin, error bound *ULP 2.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_asin_ps(d: __m128) -> __m128 {
     let ones = _mm_set1_ps(1f32);
     let ca = _mm_abs_ps(d);
@@ -35,7 +35,7 @@ pub unsafe fn _mm_asin_ps(d: __m128) -> __m128 {
     u = _mm_mlaf_ps(u, x, _mm_castsi128_ps(_mm_set1_epi32(ASIN_POLY_2_F as i32)));
     u = _mm_mul_ps(u, x);
     let j = u;
-    let reconstruct_reversed = _mm_select_ps(
+    let reconstruct_reversed = _mm_mlaf_ps(
         _mm_set1_ps(-2f32),
         j,
         _mm_set1_ps(std::f32::consts::FRAC_PI_2),
@@ -49,20 +49,28 @@ pub unsafe fn _mm_asin_ps(d: __m128) -> __m128 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_asinf() {
+        unsafe {
+            let value = _mm_set1_ps(0.7);
+            let comparison = _mm_asin_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<1>(comparison) as u32);
+            let control = 0.775397496610753f32;
+            assert!((flag_1 - control).abs() < 1e-6);
+        }
         unsafe {
             let value = _mm_set1_ps(0.3);
             let comparison = _mm_asin_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<1>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<1>(comparison) as u32);
             let control = 0.304692654015397507972f32;
             assert_eq!(flag_1, control);
         }
         unsafe {
             let value = _mm_set1_ps(-0.3);
             let comparison = _mm_asin_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             let control = -0.304692654015397507972f32;
             assert_eq!(flag_1, control);
         }
@@ -70,7 +78,7 @@ mod tests {
         unsafe {
             let value = _mm_set1_ps(-2f32);
             let comparison = _mm_asin_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert!(flag_1.is_nan());
         }
     }
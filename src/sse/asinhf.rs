@@ -0,0 +1,131 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm_abs_ps, _mm_copysign_ps, _mm_log1p_ps, _mm_select_ps};
+
+/// Computes inverse hyperbolic sine, error bound *ULP 4.5*
+#[inline(always)]
+pub unsafe fn _mm_asinh_ps(d: __m128) -> __m128 {
+    let a = _mm_abs_ps(d);
+    let a2 = _mm_mul_ps(a, a);
+    // a < 2: asinh(a) = log1p(a + a^2 / (1 + sqrt(1 + a^2)))
+    let small = _mm_add_ps(
+        a,
+        _mm_div_ps(
+            a2,
+            _mm_add_ps(
+                _mm_set1_ps(1f32),
+                _mm_sqrt_ps(_mm_add_ps(a2, _mm_set1_ps(1f32))),
+            ),
+        ),
+    );
+    // a >= 2: asinh(a) = log1p(2a - 1 + 1 / (a + sqrt(a^2 + 1))), 2a - 1 is exact there
+    let mid = _mm_add_ps(
+        _mm_sub_ps(_mm_add_ps(a, a), _mm_set1_ps(1f32)),
+        _mm_div_ps(
+            _mm_set1_ps(1f32),
+            _mm_add_ps(a, _mm_sqrt_ps(_mm_add_ps(a2, _mm_set1_ps(1f32)))),
+        ),
+    );
+    // Large a has sqrt(a^2 + 1) == a, so asinh(a) = ln(a) + ln(2)
+    let big = _mm_cmpgt_ps(a, _mm_set1_ps(4096f32));
+    let u = _mm_select_ps(
+        _mm_cmplt_ps(a, _mm_set1_ps(2f32)),
+        small,
+        _mm_select_ps(big, _mm_sub_ps(a, _mm_set1_ps(1f32)), mid),
+    );
+    let r = _mm_add_ps(
+        _mm_log1p_ps(u),
+        _mm_select_ps(big, _mm_set1_ps(std::f32::consts::LN_2), _mm_setzero_ps()),
+    );
+    _mm_copysign_ps(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_asinhf() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(0.5f32);
+            let comparison = _mm_asinh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 0.5f32.asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(-1e-10f32);
+            let comparison = _mm_asinh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = (-1e-10f32).asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(-3f32);
+            let comparison = _mm_asinh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = (-3f32).asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(1e30f32);
+            let comparison = _mm_asinh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 1e30f32.asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm_set1_ps(f32::NEG_INFINITY);
+            let comparison = _mm_asinh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+    }
+
+    #[test]
+    fn test_asinhf_ulp() {
+        // Log-uniform magnitudes from 1e-6 to 1e30
+        let mut state = 0x1405_7b7e_f767_814fu64;
+        for i in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let sign = if i % 2 == 0 { 1. } else { -1. };
+            let x = (sign * 10f64.powf(u * 36. - 6.)) as f32;
+            let control = (x as f64).asinh();
+            let value = unsafe { _mm_cvtss_f32(_mm_asinh_ps(_mm_set1_ps(x))) };
+            for value in [value, crate::easinhf(x)] {
+                assert!(
+                    ulp(value, control) <= 4.5,
+                    "asinh({x}) = {value}, expected {control}"
+                );
+            }
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
+}
@@ -16,12 +16,13 @@ use crate::atan::{
     ATAN_POLY_21_D, ATAN_POLY_2_D, ATAN_POLY_3_D, ATAN_POLY_4_D, ATAN_POLY_5_D, ATAN_POLY_6_D,
     ATAN_POLY_7_D, ATAN_POLY_8_D, ATAN_POLY_9_D,
 };
-use crate::{_mm_abs_pd, _mm_mlaf_pd, _mm_neg_pd, _mm_select_pd};
+use crate::{_mm_abs_pd, _mm_mlaf_pd, _mm_select_pd};
 
 /// Computes Atan function with *ULP 2.0* error
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_atan_pd(x: __m128d) -> __m128d {
-    let negative_mask = _mm_cmplt_pd(x, _mm_setzero_pd());
+    // Sign bit is restored at the end so that atan(-0) = -0
+    let sign = _mm_and_pd(x, _mm_set1_pd(-0f64));
     let d = _mm_abs_pd(x);
     let more_than_one_mask = _mm_cmpge_pd(d, _mm_set1_pd(1.));
     let x = _mm_select_pd(more_than_one_mask, _mm_div_pd(_mm_set1_pd(1.), d), d);
@@ -53,7 +54,7 @@ pub unsafe fn _mm_atan_pd(x: __m128d) -> __m128d {
         _mm_sub_pd(_mm_set1_pd(std::f64::consts::FRAC_PI_2), u),
         u,
     );
-    u = _mm_select_pd(negative_mask, _mm_neg_pd(u), u);
+    u = _mm_xor_pd(u, sign);
     u
 }
 
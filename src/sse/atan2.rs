@@ -9,40 +9,52 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-use crate::sse::general::_mm_ltzero_pd;
-use crate::{_mm_atan_pd, _mm_eqzero_pd, _mm_select_pd};
+use crate::{_mm_abs_pd, _mm_atan_pd, _mm_copysign_pd, _mm_eqzero_pd, _mm_isinf_pd, _mm_select_pd};
 
 /// Computes atan for Y,X
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_atan2_pd(y: __m128d, x: __m128d) -> __m128d {
-    let zero_x_mask = _mm_eqzero_pd(x);
-    let yx = _mm_atan_pd(_mm_div_pd(y, x));
-    let mut rad = yx;
-    rad = _mm_select_pd(
-        _mm_and_pd(zero_x_mask, _mm_cmpge_pd(y, _mm_setzero_pd())),
-        _mm_set1_pd(std::f64::consts::FRAC_PI_2),
-        rad,
-    ); // x == 0 && y > 0.
-    rad = _mm_select_pd(
-        _mm_and_pd(zero_x_mask, _mm_cmple_pd(y, _mm_setzero_pd())),
-        _mm_set1_pd(-std::f64::consts::FRAC_PI_2),
-        rad,
-    ); // x == 0 && y < 0.
-    rad = _mm_select_pd(
-        _mm_and_pd(zero_x_mask, _mm_cmple_pd(y, _mm_setzero_pd())),
-        _mm_set1_pd(0.),
-        rad,
-    ); // x == 0 && y == 0.
-    let x_lower_than_0 = _mm_ltzero_pd(x);
-    rad = _mm_select_pd(
-        _mm_and_pd(x_lower_than_0, _mm_cmpge_pd(y, _mm_setzero_pd())),
-        _mm_add_pd(yx, _mm_set1_pd(std::f64::consts::PI)),
-        rad,
-    ); // x < 0 && y >= 0
-    rad = _mm_select_pd(
-        _mm_and_pd(x_lower_than_0, _mm_ltzero_pd(y)),
-        _mm_add_pd(yx, _mm_set1_pd(-std::f64::consts::PI)),
-        rad,
-    ); // x < 0 && y < 0
-    rad
+    let ay = _mm_abs_pd(y);
+    let ax = _mm_abs_pd(x);
+    let mut r = _mm_atan_pd(_mm_div_pd(ay, ax));
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    r = _mm_select_pd(
+        _mm_and_pd(_mm_eqzero_pd(ay), _mm_eqzero_pd(ax)),
+        _mm_setzero_pd(),
+        r,
+    );
+    r = _mm_select_pd(
+        _mm_and_pd(_mm_isinf_pd(ay), _mm_isinf_pd(ax)),
+        _mm_set1_pd(std::f64::consts::FRAC_PI_4),
+        r,
+    );
+    // Negative x, including -0, reflects the angle to the left half plane
+    let x_neg = _mm_cmplt_pd(_mm_copysign_pd(_mm_set1_pd(1.), x), _mm_setzero_pd());
+    r = _mm_select_pd(x_neg, _mm_sub_pd(_mm_set1_pd(std::f64::consts::PI), r), r);
+    _mm_copysign_pd(r, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_atan2d() {
+        unsafe {
+            let value = _mm_atan2_pd(_mm_set1_pd(-1.), _mm_set1_pd(0.));
+            let flag_1 = _mm_extract_pd::<0>(value);
+            assert_eq!(flag_1, -std::f64::consts::FRAC_PI_2);
+        }
+        unsafe {
+            let value = _mm_atan2_pd(_mm_set1_pd(1.), _mm_set1_pd(0.));
+            let flag_1 = _mm_extract_pd::<0>(value);
+            assert_eq!(flag_1, std::f64::consts::FRAC_PI_2);
+        }
+        unsafe {
+            let value = _mm_atan2_pd(_mm_set1_pd(0.), _mm_set1_pd(0.));
+            let flag_1 = _mm_extract_pd::<0>(value);
+            assert_eq!(flag_1, 0.);
+        }
+    }
 }
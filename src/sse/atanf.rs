@@ -8,16 +8,17 @@ use crate::atanf::{
     ATAN_POLY_1_F, ATAN_POLY_2_F, ATAN_POLY_3_F, ATAN_POLY_4_F, ATAN_POLY_5_F, ATAN_POLY_6_F,
     ATAN_POLY_7_F, ATAN_POLY_8_F, ATAN_POLY_9_F,
 };
-use crate::{_mm_abs_ps, _mm_ltzero_ps, _mm_mlaf_ps, _mm_neg_ps, _mm_select_ps};
+use crate::{_mm_abs_ps, _mm_mlaf_ps, _mm_select_ps};
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
 /// Computes Atan function with *ULP 1.0* error
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_atan_ps(x: __m128) -> __m128 {
-    let negative_mask = _mm_ltzero_ps(x);
+    // Sign bit is restored at the end so that atan(-0) = -0
+    let sign = _mm_and_ps(x, _mm_set1_ps(-0f32));
     let d = _mm_abs_ps(x);
     let more_than_one_mask = _mm_cmpge_ps(d, _mm_set1_ps(1f32));
     let x = _mm_select_ps(more_than_one_mask, _mm_div_ps(_mm_set1_ps(1f32), d), d);
@@ -37,7 +38,7 @@ pub unsafe fn _mm_atan_ps(x: __m128) -> __m128 {
         _mm_sub_ps(_mm_set1_ps(std::f32::consts::FRAC_PI_2), u),
         u,
     );
-    u = _mm_select_ps(negative_mask, _mm_neg_ps(u), u);
+    u = _mm_xor_ps(u, sign);
     u
 }
 
@@ -45,20 +46,21 @@ pub unsafe fn _mm_atan_ps(x: __m128) -> __m128 {
 mod tests {
     use super::*;
     use crate::_mm_atan_ps;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_atanf() {
         unsafe {
             let value = _mm_set1_ps(-2.70752239);
             let comparison = _mm_atan_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<1>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<1>(comparison) as u32);
             let control = -1.216996f32;
             assert_eq!(flag_1, control);
         }
         unsafe {
             let value = _mm_set1_ps(2f32);
             let comparison = _mm_atan_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             let control = 1.107148717794090503017065460f32;
             assert_eq!(flag_1, control);
         }
@@ -66,7 +68,7 @@ mod tests {
         unsafe {
             let value = _mm_set1_ps(-2f32);
             let comparison = _mm_atan_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             let control = -1.107148717794090503017065460f32;
             assert_eq!(flag_1, control);
         }
@@ -0,0 +1,107 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm_abs_ps, _mm_copysign_ps, _mm_log1p_ps, _mm_select_ps};
+
+/// Computes inverse hyperbolic tangent, error bound *ULP 4.0*
+#[inline(always)]
+pub unsafe fn _mm_atanh_ps(d: __m128) -> __m128 {
+    let a = _mm_abs_ps(d);
+    let s = _mm_add_ps(a, a);
+    // atanh(a) = log1p(2a + 2a^2 / (1 - a)) / 2, keeping 2a apart holds precision near zero
+    let u = _mm_add_ps(
+        s,
+        _mm_div_ps(_mm_mul_ps(s, a), _mm_sub_ps(_mm_set1_ps(1f32), a)),
+    );
+    let mut r = _mm_mul_ps(_mm_set1_ps(0.5f32), _mm_log1p_ps(u));
+    // |d| > 1 -> Nan
+    r = _mm_select_ps(_mm_cmpgt_ps(a, _mm_set1_ps(1f32)), _mm_set1_ps(f32::NAN), r);
+    _mm_copysign_ps(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_atanhf() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(0.5f32);
+            let comparison = _mm_atanh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 0.5f32.atanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(-1e-10f32);
+            let comparison = _mm_atanh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = (-1e-10f32).atanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm_set1_ps(1f32);
+            let comparison = _mm_atanh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm_set1_ps(-1f32);
+            let comparison = _mm_atanh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm_set1_ps(2f32);
+            let comparison = _mm_atanh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert!(flag_1.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_atanhf_ulp() {
+        // Distance to the poles is log-uniform from 1e-7 to 1
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        for i in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let sign = if i % 2 == 0 { 1. } else { -1. };
+            let x = (sign * (1. - 10f64.powf(-u * 7.))) as f32;
+            let control = (x as f64).atanh();
+            let value = unsafe { _mm_cvtss_f32(_mm_atanh_ps(_mm_set1_ps(x))) };
+            for value in [value, crate::eatanhf(x)] {
+                assert!(
+                    ulp(value, control) <= 4.0,
+                    "atanh({x}) = {value}, expected {control}"
+                );
+            }
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
+}
@@ -9,8 +9,10 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-use crate::sse::poly128::{_mm_addw_epi128, _mm_movn_epi128, _mm_mull_epu64, _mm_srli_epi128x};
-use crate::{_mm_eqzero_pd, _mm_isinf_pd, _mm_isneginf_pd, _mm_mlaf_pd, _mm_select_pd};
+use crate::cbrt::{
+    CBRT_HUGE_D, CBRT_HUGE_SCALE_D, CBRT_HUGE_SCALE_R_D, CBRT_SCALE_D, CBRT_SCALE_R_D,
+};
+use crate::{_mm_abs_pd, _mm_eqzero_pd, _mm_isinf_pd, _mm_isneginf_pd, _mm_mlaf_pd, _mm_select_pd};
 
 #[inline(always)]
 unsafe fn halley_cbrt(x: __m128d, a: __m128d) -> __m128d {
@@ -22,18 +24,30 @@ unsafe fn halley_cbrt(x: __m128d, a: __m128d) -> __m128d {
     _mm_mul_pd(x, scale)
 }
 
+/// Newton step from t rounded to 22 bits, t*t is exact so the result is within 0.67 ulp
+#[inline(always)]
+unsafe fn newton_round(t: __m128d, a: __m128d) -> __m128d {
+    #[allow(overflowing_literals)]
+    let t = _mm_castsi128_pd(_mm_and_si128(
+        _mm_add_epi64(_mm_castpd_si128(t), _mm_set1_epi64x(0x8000_0000)),
+        _mm_set1_epi64x(0xffff_ffff_c000_0000),
+    ));
+    let s = _mm_mul_pd(t, t);
+    let r = _mm_div_pd(a, s);
+    let w = _mm_add_pd(t, t);
+    let r = _mm_div_pd(_mm_sub_pd(r, t), _mm_add_pd(w, r));
+    _mm_add_pd(t, _mm_mul_pd(t, r))
+}
+
 #[inline(always)]
 unsafe fn integer_pow_1_3(hx: __m128i) -> __m128i {
-    let scale = _mm_set1_epi64x(341);
-    let wide = _mm_mull_epu64(hx, scale);
-    let shifted = _mm_srli_epi128x::<10>(wide);
-    let addiction = _mm_set1_epi64x(715094163);
-    let product = _mm_addw_epi128(shifted, addiction);
-    _mm_movn_epi128(product)
+    // hx / 3 as (hx * 0xAAAAAAAB) >> 33, exact for every 31-bit high word
+    let third = _mm_srli_epi64::<33>(_mm_mul_epu32(hx, _mm_set1_epi64x(0xAAAAAAAB)));
+    _mm_add_epi64(third, _mm_set1_epi64x(715094163))
 }
 
 /// Takes cube root from value *ULP 1.5*, Skipping NaN, Inf checks
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_cbrt_fast_pd(x: __m128d) -> __m128d {
     let mut ui = _mm_castpd_si128(x);
     let hx = _mm_and_si128(_mm_srli_epi64::<32>(ui), _mm_set1_epi64x(0x7fffffff));
@@ -50,14 +64,30 @@ pub unsafe fn _mm_cbrt_fast_pd(x: __m128d) -> __m128d {
     let c0 = halley_cbrt(t, x);
     let c1 = halley_cbrt(c0, x);
     let c2 = halley_cbrt(c1, x);
-    let v = _mm_select_pd(_mm_eqzero_pd(x), _mm_set1_pd(0.), c2);
+    let c2 = newton_round(c2, x);
+    let v = _mm_select_pd(_mm_eqzero_pd(x), x, c2);
     v
 }
 
 /// Takes cube root from value *ULP 1.5*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_cbrt_pd(x: __m128d) -> __m128d {
-    let c1 = _mm_cbrt_fast_pd(x);
+    // Subnormals and the top binades are scaled by powers of 8, the initial guess
+    // and x^3 in Halley step are out of range for them
+    let ax = _mm_abs_pd(x);
+    let tiny = _mm_cmplt_pd(ax, _mm_set1_pd(f64::MIN_POSITIVE));
+    let huge = _mm_cmpgt_pd(ax, _mm_set1_pd(CBRT_HUGE_D));
+    let scale_in = _mm_select_pd(
+        tiny,
+        _mm_set1_pd(CBRT_SCALE_D),
+        _mm_select_pd(huge, _mm_set1_pd(CBRT_HUGE_SCALE_D), _mm_set1_pd(1.)),
+    );
+    let scale_out = _mm_select_pd(
+        tiny,
+        _mm_set1_pd(1. / CBRT_SCALE_R_D),
+        _mm_select_pd(huge, _mm_set1_pd(CBRT_HUGE_SCALE_R_D), _mm_set1_pd(1.)),
+    );
+    let c1 = _mm_mul_pd(_mm_cbrt_fast_pd(_mm_mul_pd(x, scale_in)), scale_out);
     let mut v = _mm_select_pd(_mm_isinf_pd(x), _mm_set1_pd(f64::INFINITY), c1);
     v = _mm_select_pd(_mm_isneginf_pd(x), _mm_set1_pd(f64::NEG_INFINITY), v);
     v
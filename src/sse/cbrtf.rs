@@ -4,9 +4,10 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
+use crate::cbrtf::{CBRT_HUGE_F, CBRT_SCALE_F, CBRT_SCALE_R_F};
 use crate::{
-    _mm_eqzero_ps, _mm_isinf_ps, _mm_isneginf_ps, _mm_mlaf_ps, _mm_mul_epu64, _mm_packts_epi64,
-    _mm_select_ps,
+    _mm_abs_ps, _mm_eqzero_ps, _mm_isinf_ps, _mm_isneginf_ps, _mm_mlaf_ps, _mm_mul_epu64,
+    _mm_packts_epi64, _mm_select_ps,
 };
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
@@ -38,7 +39,7 @@ unsafe fn integer_pow_1_3(hx: __m128i) -> __m128i {
 }
 
 /// Takes cube root from value *ULP 1.5*, Skipping NaN, Inf checks
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_cbrt_fast_ps(x: __m128) -> __m128 {
     let mut ui = _mm_castps_si128(x);
     let hx = _mm_and_si128(ui, _mm_set1_epi32(0x7fffffff));
@@ -54,14 +55,29 @@ pub unsafe fn _mm_cbrt_fast_ps(x: __m128) -> __m128 {
 
     let c0 = halley_cbrt(t, x);
     let c1 = halley_cbrt(c0, x);
-    let v = _mm_select_ps(_mm_eqzero_ps(x), _mm_set1_ps(0f32), c1);
+    let v = _mm_select_ps(_mm_eqzero_ps(x), x, c1);
     v
 }
 
 /// Takes cube root from value *ULP 1.5*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_cbrt_ps(x: __m128) -> __m128 {
-    let c1 = _mm_cbrt_fast_ps(x);
+    // Subnormals and the top binades are scaled by powers of 8, the initial guess
+    // and x^3 in Halley step are out of range for them
+    let ax = _mm_abs_ps(x);
+    let tiny = _mm_cmplt_ps(ax, _mm_set1_ps(f32::MIN_POSITIVE));
+    let huge = _mm_cmpgt_ps(ax, _mm_set1_ps(CBRT_HUGE_F));
+    let scale_in = _mm_select_ps(
+        tiny,
+        _mm_set1_ps(CBRT_SCALE_F),
+        _mm_select_ps(huge, _mm_set1_ps(1. / CBRT_SCALE_F), _mm_set1_ps(1.)),
+    );
+    let scale_out = _mm_select_ps(
+        tiny,
+        _mm_set1_ps(1. / CBRT_SCALE_R_F),
+        _mm_select_ps(huge, _mm_set1_ps(CBRT_SCALE_R_F), _mm_set1_ps(1.)),
+    );
+    let c1 = _mm_mul_ps(_mm_cbrt_fast_ps(_mm_mul_ps(x, scale_in)), scale_out);
     let mut v = _mm_select_ps(_mm_isinf_ps(x), _mm_set1_ps(f32::INFINITY), c1);
     v = _mm_select_ps(_mm_isneginf_ps(x), _mm_set1_ps(f32::NEG_INFINITY), v);
     v
@@ -70,6 +86,7 @@ pub unsafe fn _mm_cbrt_ps(x: __m128) -> __m128 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_cbrtf() {
@@ -77,7 +94,7 @@ mod tests {
             // Test regular
             let value = _mm_set1_ps(0.0201934222);
             let comparison = _mm_cbrt_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<3>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<3>(comparison) as u32);
             assert_eq!(flag_1, 0.272313982f32);
         }
 
@@ -85,7 +102,7 @@ mod tests {
             // Test regular
             let value = _mm_set1_ps(0.0201934222);
             let comparison = _mm_cbrt_fast_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<3>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<3>(comparison) as u32);
             assert_eq!(flag_1, 0.272313982f32);
         }
 
@@ -93,7 +110,7 @@ mod tests {
             // Test regular
             let value = _mm_set1_ps(27f32);
             let comparison = _mm_cbrt_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, 3f32);
         }
 
@@ -101,7 +118,7 @@ mod tests {
             // Test regular
             let value = _mm_set1_ps(0.5f32);
             let comparison = _mm_cbrt_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, 0.7937005f32);
         }
 
@@ -109,7 +126,7 @@ mod tests {
             // Test Infinity
             let value = _mm_set1_ps(f32::INFINITY);
             let comparison = _mm_cbrt_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, f32::INFINITY);
         }
 
@@ -117,14 +134,14 @@ mod tests {
             // Test Neg Infinity
             let value = _mm_set1_ps(-27f32);
             let comparison = _mm_cbrt_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, -3f32);
         }
 
         unsafe {
             let value = _mm_set1_ps(f32::NEG_INFINITY);
             let comparison = _mm_cbrt_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, f32::NEG_INFINITY);
         }
     }
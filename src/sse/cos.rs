@@ -10,15 +10,19 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use crate::generalf::rempi2_odd;
 use crate::sin::{
     PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
     SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
 };
+use crate::sse::general::_mm_rempi_pd;
 use crate::sse::general::_mm_rint_pd;
-use crate::{_mm_cvtepi64_pd, _mm_mlaf_pd, _mm_mul_epi64, _mm_neg_pd, _mm_selecti_pd};
+use crate::{
+    _mm_cmpeq_epi64x, _mm_cvtepi64_pd, _mm_mlaf_pd, _mm_mul_epi64, _mm_neg_pd, _mm_selecti_pd,
+};
 
-#[inline]
-/// Computes cosine function with error bound *ULP 2.0*
+#[inline(always)]
+/// Computes cosine function with error bound *ULP 2.5*
 pub unsafe fn _mm_cos_pd(d: __m128d) -> __m128d {
     let j = _mm_rint_pd(_mm_sub_pd(
         _mm_mul_pd(d, _mm_set1_pd(std::f64::consts::FRAC_1_PI)),
@@ -32,11 +36,12 @@ pub unsafe fn _mm_cos_pd(d: __m128d) -> __m128d {
 
     let mut r = _mm_mlaf_pd(qf, _mm_set1_pd(-PI_A2 * 0.5), d);
     r = _mm_mlaf_pd(qf, _mm_set1_pd(-PI_B2 * 0.5), r);
+    let (q, mut r) = _mm_rempi_pd(d, q, r, rempi2_odd);
 
     let x2 = _mm_mul_pd(r, r);
 
     r = _mm_selecti_pd(
-        _mm_cmpeq_epi64(_mm_and_si128(q, _mm_set1_epi64x(2)), _mm_setzero_si128()),
+        _mm_cmpeq_epi64x(_mm_and_si128(q, _mm_set1_epi64x(2)), _mm_setzero_si128()),
         _mm_neg_pd(r),
         r,
     );
@@ -84,4 +89,13 @@ mod tests {
             assert_eq!(flag_1, control);
         }
     }
+
+    #[test]
+    fn test_cosd_large() {
+        for x in [1e6f64, -1e22, 3.5e300] {
+            let value = unsafe { _mm_cvtsd_f64(_mm_cos_pd(_mm_setr_pd(x, 2.))) };
+            let control = x.cos();
+            assert!((value - control).abs() <= 2. * f64::EPSILON * control.abs());
+        }
+    }
 }
@@ -5,25 +5,24 @@
  * // license that can be found in the LICENSE file.
  */
 use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2_oddf;
 use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
-use crate::{_mm_mlaf_ps, _mm_neg_ps, _mm_rint_ps, _mm_selecti_ps};
+use crate::sse::generalf::_mm_rempi_ps;
+use crate::{_mm_eqzero_ps, _mm_mlaf_ps, _mm_neg_ps, _mm_rint_ps, _mm_select_ps, _mm_selecti_ps};
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
-/// Computes cosine function with error bound *ULP 1.5*
+#[inline(always)]
+/// Computes cosine function with error bound *ULP 2.5*
 pub unsafe fn _mm_cos_ps(d: __m128) -> __m128 {
     let q = _mm_add_epi32(
         _mm_set1_epi32(1),
-        _mm_mullo_epi32(
-            _mm_rint_ps(_mm_sub_ps(
-                _mm_mul_ps(d, _mm_set1_ps(std::f32::consts::FRAC_1_PI)),
-                _mm_set1_ps(0.5f32),
-            )),
-            _mm_set1_epi32(2),
-        ),
+        _mm_slli_epi32::<1>(_mm_rint_ps(_mm_sub_ps(
+            _mm_mul_ps(d, _mm_set1_ps(std::f32::consts::FRAC_1_PI)),
+            _mm_set1_ps(0.5f32),
+        ))),
     );
     let qf = _mm_cvtepi32_ps(q);
 
@@ -31,6 +30,7 @@ pub unsafe fn _mm_cos_ps(d: __m128) -> __m128 {
     r = _mm_mlaf_ps(qf, _mm_set1_ps(-PI_B_F * 0.5), r);
     r = _mm_mlaf_ps(qf, _mm_set1_ps(-PI_C_F * 0.5), r);
     r = _mm_mlaf_ps(qf, _mm_set1_ps(-PI_D_F * 0.5), r);
+    let (q, mut r) = _mm_rempi_ps(d, q, r, rempi2_oddf);
 
     let x2 = _mm_mul_ps(r, r);
 
@@ -45,26 +45,28 @@ pub unsafe fn _mm_cos_ps(d: __m128) -> __m128 {
     res = _mm_mlaf_ps(res, x2, _mm_set1_ps(SIN_POLY_2_S));
     res = _mm_mlaf_ps(res, x2, _mm_set1_ps(SIN_POLY_1_S));
     res = _mm_mlaf_ps(res, _mm_mul_ps(x2, r), r);
-    res
+    // cos(±0) = 1
+    _mm_select_ps(_mm_eqzero_ps(d), _mm_set1_ps(1.), res)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_cosf() {
         unsafe {
             let value = _mm_set1_ps(-2.70752239);
             let comparison = _mm_cos_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<1>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<1>(comparison) as u32);
             let control = -0.907261451f32;
             assert_eq!(flag_1, control);
         }
         unsafe {
             let value = _mm_set1_ps(2f32);
             let comparison = _mm_cos_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             let control = -0.4161468;
             assert_eq!(flag_1, control);
         }
@@ -72,9 +74,49 @@ mod tests {
         unsafe {
             let value = _mm_set1_ps(-2f32);
             let comparison = _mm_cos_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             let control = -0.4161468;
             assert_eq!(flag_1, control);
         }
     }
+
+    #[test]
+    fn test_cosf_large() {
+        for x in [1e4f32, -1e22, 3.0e38] {
+            let value = unsafe { _mm_cvtss_f32(_mm_cos_ps(_mm_setr_ps(x, 2., -0.5, 1.))) };
+            let control = (x as f64).cos() as f32;
+            assert!((value - control).abs() <= 2. * f32::EPSILON * control.abs());
+        }
+    }
+
+    #[test]
+    fn test_cosf_ulp() {
+        // Uniform on [-1e4, 1e4] and log-uniform up to 1e30, scalar cosine dispatches to this kernel
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        for i in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let sign = if i % 4 == 1 { 1. } else { -1. };
+            let x = if i % 2 == 0 {
+                ((u * 2. - 1.) * 1e4) as f32
+            } else {
+                (sign * 10f64.powf(u * 33. - 3.)) as f32
+            };
+            let control = (x as f64).cos();
+            let value = unsafe { _mm_cvtss_f32(_mm_cos_ps(_mm_set1_ps(x))) };
+            for value in [value, crate::ecosf(x)] {
+                assert!(
+                    ulp(value, control) <= 2.5,
+                    "cos({x}) = {value}, expected {control}"
+                );
+            }
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
 }
@@ -0,0 +1,100 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm_abs_ps, _mm_exp_ps, _mm_mlaf_ps, _mm_select_ps};
+
+/// Computes hyperbolic cosine, error bound *ULP 3.5*
+#[inline(always)]
+pub unsafe fn _mm_cosh_ps(d: __m128) -> __m128 {
+    let a = _mm_abs_ps(d);
+    let e = _mm_exp_ps(a);
+    let mut r = _mm_mlaf_ps(_mm_set1_ps(0.5f32), e, _mm_div_ps(_mm_set1_ps(0.5f32), e));
+    // exp(|d|) / 2 overflows before cosh does, split it as exp(|d|/2)^2 / 2
+    let h = _mm_exp_ps(_mm_mul_ps(a, _mm_set1_ps(0.5f32)));
+    r = _mm_select_ps(
+        _mm_cmpgt_ps(a, _mm_set1_ps(88f32)),
+        _mm_mul_ps(_mm_mul_ps(h, _mm_set1_ps(0.5f32)), h),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_coshf() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(1.5f32);
+            let comparison = _mm_cosh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 1.5f32.cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(-0.3f32);
+            let comparison = _mm_cosh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = (-0.3f32).cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(89f32);
+            let comparison = _mm_cosh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 89f32.cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm_set1_ps(-100f32);
+            let comparison = _mm_cosh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+
+    #[test]
+    fn test_coshf_ulp() {
+        // Whole finite range, the worst case is next to overflow
+        let mut state = 0x9e37_79b9_7f4a_7c15u64;
+        for i in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let sign = if i % 2 == 0 { 1. } else { -1. };
+            let x = (sign * u * 89.4) as f32;
+            let control = (x as f64).cosh();
+            let value = unsafe { _mm_cvtss_f32(_mm_cosh_ps(_mm_set1_ps(x))) };
+            for value in [value, crate::ecoshf(x)] {
+                assert!(
+                    ulp(value, control) <= 3.5,
+                    "cosh({x}) = {value}, expected {control}"
+                );
+            }
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
+}
@@ -0,0 +1,193 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::digammaf::{
+    DIGAMMA_ASYMPT_POLY_1_S, DIGAMMA_ASYMPT_POLY_2_S, DIGAMMA_ASYMPT_POLY_3_S,
+    DIGAMMA_ASYMPT_POLY_4_S, DIGAMMA_POLY_10_S, DIGAMMA_POLY_11_S, DIGAMMA_POLY_12_S,
+    DIGAMMA_POLY_1_S, DIGAMMA_POLY_2_S, DIGAMMA_POLY_3_S, DIGAMMA_POLY_4_S, DIGAMMA_POLY_5_S,
+    DIGAMMA_POLY_6_S, DIGAMMA_POLY_7_S, DIGAMMA_POLY_8_S, DIGAMMA_POLY_9_S, DIGAMMA_ROOT_M1_LO_S,
+    DIGAMMA_ROOT_M1_S,
+};
+use crate::sse::sinf::_mm_sinpik_ps;
+use crate::{
+    _mm_abs_ps, _mm_floor_psx, _mm_isnotintegral_ps, _mm_ln_ps, _mm_mlaf_ps, _mm_round_psx,
+    _mm_select_ps,
+};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Computes digamma function, error bound *ULP 3.5* for positive arguments
+#[inline(always)]
+pub unsafe fn _mm_digamma_ps(d: __m128) -> __m128 {
+    let reflect = _mm_cmplt_ps(d, _mm_setzero_ps());
+    // ψ(d) = ψ(1 - d) - pi cot(pi d)
+    let x = _mm_select_ps(reflect, _mm_sub_ps(_mm_set1_ps(1f32), d), d);
+    // ψ(x) = ln(x) - 1/2x - z D(z), z = 1/x^2 for x >= 5
+    let ix = _mm_div_ps(_mm_set1_ps(1f32), x);
+    let z = _mm_mul_ps(ix, ix);
+    let mut p = _mm_set1_ps(DIGAMMA_ASYMPT_POLY_4_S);
+    p = _mm_mlaf_ps(p, z, _mm_set1_ps(DIGAMMA_ASYMPT_POLY_3_S));
+    p = _mm_mlaf_ps(p, z, _mm_set1_ps(DIGAMMA_ASYMPT_POLY_2_S));
+    p = _mm_mlaf_ps(p, z, _mm_set1_ps(DIGAMMA_ASYMPT_POLY_1_S));
+    let big = _mm_sub_ps(
+        _mm_ln_ps(x),
+        _mm_mlaf_ps(z, p, _mm_mul_ps(_mm_set1_ps(0.5f32), ix)),
+    );
+    // ψ(1 + t) = (t - t0) G(t) keeps the root t0 apart, so values around it stay accurate
+    let n = _mm_floor_psx(x);
+    let t = _mm_sub_ps(x, n);
+    let v = _mm_sub_ps(t, _mm_set1_ps(0.5f32));
+    let mut u = _mm_set1_ps(DIGAMMA_POLY_12_S);
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_11_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_10_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_9_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_8_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_7_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_6_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_5_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_4_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_3_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_2_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(DIGAMMA_POLY_1_S));
+    let r = _mm_mul_ps(
+        _mm_sub_ps(
+            _mm_sub_ps(t, _mm_set1_ps(DIGAMMA_ROOT_M1_S)),
+            _mm_set1_ps(DIGAMMA_ROOT_M1_LO_S),
+        ),
+        u,
+    );
+    // ψ(x) = ψ(1 + t) + 1/(x - 1) + ... + 1/(x - n + 1) summed as a single fraction, below 1 it is -1/x
+    let below = _mm_cmplt_ps(x, _mm_set1_ps(1f32));
+    let mut num = _mm_select_ps(below, _mm_set1_ps(-1f32), _mm_setzero_ps());
+    let mut den = _mm_select_ps(below, x, _mm_set1_ps(1f32));
+    for k in 1..5 {
+        let kf = _mm_set1_ps(k as f32);
+        let v = _mm_sub_ps(x, kf);
+        let m = _mm_cmplt_ps(kf, n);
+        num = _mm_select_ps(m, _mm_mlaf_ps(num, v, den), num);
+        den = _mm_select_ps(m, _mm_mul_ps(den, v), den);
+    }
+    let mut ret = _mm_select_ps(
+        _mm_cmplt_ps(x, _mm_set1_ps(5f32)),
+        _mm_add_ps(r, _mm_div_ps(num, den)),
+        big,
+    );
+    let rr = _mm_sub_ps(d, _mm_round_psx::<_MM_FROUND_TO_NEAREST_INT>(d));
+    let cs = _mm_sinpik_ps(_mm_sub_ps(_mm_set1_ps(0.5f32), _mm_abs_ps(rr)));
+    ret = _mm_select_ps(
+        reflect,
+        _mm_sub_ps(
+            ret,
+            _mm_div_ps(
+                _mm_mul_ps(_mm_set1_ps(std::f32::consts::PI), cs),
+                _mm_sinpik_ps(rr),
+            ),
+        ),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    _mm_select_ps(
+        _mm_andnot_ps(_mm_isnotintegral_ps(d), reflect),
+        _mm_set1_ps(f32::NAN),
+        ret,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_digammaf() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(0.5f32);
+            let comparison = _mm_digamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = -1.96351f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(3.7f32);
+            let comparison = _mm_digamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 1.1671536f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(-1.5f32);
+            let comparison = _mm_digamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 0.70315665f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm_set1_ps(-1f32);
+            let comparison = _mm_digamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm_set1_ps(f32::INFINITY);
+            let comparison = _mm_digamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        unsafe {
+            // Test NaN
+            let value = _mm_set1_ps(f32::NAN);
+            let comparison = _mm_digamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert!(flag_1.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_digammaf_ulp() {
+        // Uniform on (0, 12) and log-uniform from 1e-6 to 1e30
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        for i in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let x = if i % 2 == 0 {
+                (u * 12.) as f32
+            } else {
+                10f64.powf(u * 36. - 6.) as f32
+            };
+            if x == 0. {
+                continue;
+            }
+            let control = crate::edigamma(x as f64);
+            let value = unsafe { _mm_cvtss_f32(_mm_digamma_ps(_mm_set1_ps(x))) };
+            for value in [value, crate::edigammaf(x)] {
+                assert!(
+                    ulp(value, control) <= 3.5,
+                    "digamma({x}) = {value}, expected {control}"
+                );
+            }
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
+}
@@ -0,0 +1,221 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::divider::{DividerI32, DividerI64, DividerU32, DividerU64};
+use crate::{_mm_cmplt_epi64, _mm_mull_epi64, _mm_mull_epu64};
+
+#[inline(always)]
+/// Takes upper half of u32 multiplication
+unsafe fn _mm_mulhi_epu32(a: __m128i, b: __m128i) -> __m128i {
+    let even = _mm_srli_epi64::<32>(_mm_mul_epu32(a, b));
+    let odd = _mm_mul_epu32(_mm_srli_epi64::<32>(a), _mm_srli_epi64::<32>(b));
+    _mm_or_si128(
+        even,
+        _mm_and_si128(odd, _mm_set1_epi64x(0xFFFFFFFF00000000u64 as i64)),
+    )
+}
+
+#[inline(always)]
+/// Takes upper half of i32 multiplication
+unsafe fn _mm_mulhi_epi32(a: __m128i, b: __m128i) -> __m128i {
+    let product = _mm_mulhi_epu32(a, b);
+    let correction = _mm_add_epi32(
+        _mm_and_si128(_mm_srai_epi32::<31>(a), b),
+        _mm_and_si128(_mm_srai_epi32::<31>(b), a),
+    );
+    _mm_sub_epi32(product, correction)
+}
+
+#[inline(always)]
+/// Arithmetic shift i64 by the same runtime amount
+unsafe fn _mm_sra_epi64x(a: __m128i, shift: u32) -> __m128i {
+    let sign = _mm_cmplt_epi64(a, _mm_setzero_si128());
+    _mm_or_si128(
+        _mm_srl_epi64(a, _mm_cvtsi32_si128(shift as i32)),
+        _mm_sll_epi64(sign, _mm_cvtsi32_si128(64 - shift as i32)),
+    )
+}
+
+#[inline(always)]
+/// Divides u32 by precomputed divider
+pub unsafe fn _mm_div_epu32(a: __m128i, divider: DividerU32) -> __m128i {
+    let shift = _mm_cvtsi32_si128(divider.shift as i32);
+    if divider.magic == 0 {
+        return _mm_srl_epi32(a, shift);
+    }
+    let q = _mm_mulhi_epu32(a, _mm_set1_epi32(divider.magic as i32));
+    if divider.add {
+        let t = _mm_add_epi32(_mm_srli_epi32::<1>(_mm_sub_epi32(a, q)), q);
+        _mm_srl_epi32(t, shift)
+    } else {
+        _mm_srl_epi32(q, shift)
+    }
+}
+
+#[inline(always)]
+/// Divides i32 by precomputed divider, rounds towards zero
+pub unsafe fn _mm_div_epi32(a: __m128i, divider: DividerI32) -> __m128i {
+    let shift = _mm_cvtsi32_si128(divider.shift as i32);
+    let sign = _mm_set1_epi32(if divider.negative { -1 } else { 0 });
+    if divider.magic == 0 {
+        let mask = _mm_set1_epi32(((1u32 << divider.shift) - 1) as i32);
+        let rounding = _mm_and_si128(_mm_srai_epi32::<31>(a), mask);
+        let q = _mm_sra_epi32(_mm_add_epi32(a, rounding), shift);
+        return _mm_sub_epi32(_mm_xor_si128(q, sign), sign);
+    }
+    let mut q = _mm_mulhi_epi32(a, _mm_set1_epi32(divider.magic));
+    if divider.add {
+        q = _mm_add_epi32(q, _mm_sub_epi32(_mm_xor_si128(a, sign), sign));
+    }
+    q = _mm_sra_epi32(q, shift);
+    _mm_add_epi32(q, _mm_srli_epi32::<31>(q))
+}
+
+#[inline(always)]
+/// Divides u64 by precomputed divider
+pub unsafe fn _mm_div_epu64(a: __m128i, divider: DividerU64) -> __m128i {
+    let shift = _mm_cvtsi32_si128(divider.shift as i32);
+    if divider.magic == 0 {
+        return _mm_srl_epi64(a, shift);
+    }
+    let q = _mm_mull_epu64(a, _mm_set1_epi64x(divider.magic as i64)).1;
+    if divider.add {
+        let t = _mm_add_epi64(_mm_srli_epi64::<1>(_mm_sub_epi64(a, q)), q);
+        _mm_srl_epi64(t, shift)
+    } else {
+        _mm_srl_epi64(q, shift)
+    }
+}
+
+#[inline(always)]
+/// Divides i64 by precomputed divider, rounds towards zero
+pub unsafe fn _mm_div_epi64(a: __m128i, divider: DividerI64) -> __m128i {
+    let sign = _mm_set1_epi64x(if divider.negative { -1 } else { 0 });
+    if divider.magic == 0 {
+        let mask = _mm_set1_epi64x(((1u64 << divider.shift) - 1) as i64);
+        let rounding = _mm_and_si128(_mm_cmplt_epi64(a, _mm_setzero_si128()), mask);
+        let q = _mm_sra_epi64x(_mm_add_epi64(a, rounding), divider.shift);
+        return _mm_sub_epi64(_mm_xor_si128(q, sign), sign);
+    }
+    let mut q = _mm_mull_epi64(a, _mm_set1_epi64x(divider.magic)).1;
+    if divider.add {
+        q = _mm_add_epi64(q, _mm_sub_epi64(_mm_xor_si128(a, sign), sign));
+    }
+    q = _mm_sra_epi64x(q, divider.shift);
+    _mm_add_epi64(q, _mm_srli_epi64::<63>(q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_epi64x;
+
+    const DIVISORS: [u64; 14] = [
+        1,
+        2,
+        3,
+        7,
+        10,
+        64,
+        641,
+        0x7fff_ffff,
+        0x8000_0001,
+        0xffff_ffff,
+        0x1_0000_0003,
+        i64::MAX as u64,
+        1 << 63,
+        u64::MAX,
+    ];
+
+    const NUMERATORS: [u64; 12] = [
+        0,
+        1,
+        6,
+        100,
+        0x7fff_ffff,
+        0x8000_0000,
+        0xffff_ffff,
+        0x1234_5678_9abc_def0,
+        i64::MAX as u64,
+        i64::MIN as u64,
+        -7i64 as u64,
+        u64::MAX,
+    ];
+
+    unsafe fn lanes32(v: __m128i) -> [u32; 4] {
+        let lo = _mm_extract_epi64x::<0>(v) as u64;
+        let hi = _mm_extract_epi64x::<1>(v) as u64;
+        [lo as u32, (lo >> 32) as u32, hi as u32, (hi >> 32) as u32]
+    }
+
+    #[test]
+    fn test_div_32() {
+        unsafe {
+            for &d in DIVISORS.iter() {
+                for d in [d as u32, (d >> 32) as u32] {
+                    if d == 0 {
+                        continue;
+                    }
+                    let n = NUMERATORS.map(|x| x as u32);
+                    for chunk in n.chunks(4) {
+                        let v = _mm_setr_epi32(
+                            chunk[0] as i32,
+                            chunk[1] as i32,
+                            chunk[2] as i32,
+                            chunk[3] as i32,
+                        );
+                        let q = lanes32(_mm_div_epu32(v, DividerU32::new(d)));
+                        assert_eq!(DividerU32::new(d).divide(chunk[0]), chunk[0] / d);
+                        for i in 0..4 {
+                            assert_eq!(q[i], chunk[i] / d);
+                        }
+                        for sd in [d as i32, (d as i32).wrapping_neg()] {
+                            let q = lanes32(_mm_div_epi32(v, DividerI32::new(sd)));
+                            let control = (chunk[0] as i32).wrapping_div(sd);
+                            assert_eq!(DividerI32::new(sd).divide(chunk[0] as i32), control);
+                            for i in 0..4 {
+                                assert_eq!(q[i] as i32, (chunk[i] as i32).wrapping_div(sd));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_div_64() {
+        unsafe {
+            for &d in DIVISORS.iter() {
+                for chunk in NUMERATORS.chunks(2) {
+                    let v = _mm_set_epi64x(chunk[1] as i64, chunk[0] as i64);
+                    let q = _mm_div_epu64(v, DividerU64::new(d));
+                    assert_eq!(_mm_extract_epi64x::<0>(q) as u64, chunk[0] / d);
+                    assert_eq!(_mm_extract_epi64x::<1>(q) as u64, chunk[1] / d);
+                    assert_eq!(DividerU64::new(d).divide(chunk[1]), chunk[1] / d);
+                    for sd in [d as i64, (d as i64).wrapping_neg()] {
+                        let q = _mm_div_epi64(v, DividerI64::new(sd));
+                        let control = (chunk[0] as i64).wrapping_div(sd);
+                        assert_eq!(DividerI64::new(sd).divide(chunk[0] as i64), control);
+                        assert_eq!(
+                            _mm_extract_epi64x::<0>(q),
+                            (chunk[0] as i64).wrapping_div(sd)
+                        );
+                        assert_eq!(
+                            _mm_extract_epi64x::<1>(q),
+                            (chunk[1] as i64).wrapping_div(sd)
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
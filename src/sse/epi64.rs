@@ -11,32 +11,32 @@ use std::arch::x86::*;
 use std::arch::x86_64::*;
 
 use crate::shuffle::_mm_shuffle;
-use crate::{_mm_cmplt_epi64, _mm_max_epi64x, _mm_min_epi64x};
+use crate::{_mm_blendv_pdx, _mm_cmpgt_epi64x, _mm_cmplt_epi64, _mm_max_epi64x, _mm_min_epi64x};
 
-#[inline]
+#[inline(always)]
 /// Mod function for i64
 pub unsafe fn _mm_abs_epi64(a: __m128i) -> __m128i {
     _mm_select_epi64(_mm_cmplt_epi64(a, _mm_setzero_si128()), _mm_neg_epi64(a), a)
 }
 
-#[inline]
+#[inline(always)]
 /// Negates i64
 pub unsafe fn _mm_neg_epi64(a: __m128i) -> __m128i {
     let k = _mm_setzero_si128();
     _mm_sub_epi64(k, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Select true or false values based on masks for i64
 pub unsafe fn _mm_select_epi64(mask: __m128i, true_vals: __m128i, false_vals: __m128i) -> __m128i {
-    _mm_castpd_si128(_mm_blendv_pd(
+    _mm_castpd_si128(_mm_blendv_pdx(
         _mm_castsi128_pd(false_vals),
         _mm_castsi128_pd(true_vals),
         _mm_castsi128_pd(mask),
     ))
 }
 
-#[inline]
+#[inline(always)]
 /// Multiplies unsigned 64 bytes integers, takes only lower half after multiplication, do not care about overflow
 /// Formally it is *_mm_mullo_epu64*
 pub unsafe fn _mm_mul_epu64(ab: __m128i, cd: __m128i) -> __m128i {
@@ -63,28 +63,28 @@ pub unsafe fn _mm_mul_epu64(ab: __m128i, cd: __m128i) -> __m128i {
     return _mm_add_epi64(high, ac);
 }
 
-#[inline]
+#[inline(always)]
 /// Multiplies unsigned 64 bytes integers, takes only lower half after multiplication, do not care about overflow
 /// Formally it is *_mm_mullo_epi64*
 pub unsafe fn _mm_mul_epi64(ab: __m128i, cd: __m128i) -> __m128i {
     _mm_mul_epu64(ab, cd)
 }
 
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_blendv_epi64(xmm0: __m128i, xmm1: __m128i, mask: __m128i) -> __m128i {
-    _mm_castpd_si128(_mm_blendv_pd(
+    _mm_castpd_si128(_mm_blendv_pdx(
         _mm_castsi128_pd(xmm0),
         _mm_castsi128_pd(xmm1),
         _mm_castsi128_pd(mask),
     ))
 }
 
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_setr_epi64x(a: i64, b: i64) -> __m128i {
     _mm_set_epi64x(b, a)
 }
 
-#[inline]
+#[inline(always)]
 #[rustfmt::skip]
 /// Converts signed 64-bit integers into double
 pub unsafe fn _mm_cvtepi64_pd(v: __m128i) -> __m128d {
@@ -93,14 +93,14 @@ pub unsafe fn _mm_cvtepi64_pd(v: __m128i) -> __m128d {
     let magic_i_all = _mm_set1_epi64x(0x4530000080100000); // 2^84 + 2^63 + 2^52 encoded as floating-point
     let magic_d_all = _mm_castsi128_pd(magic_i_all);
 
-    let v_lo = _mm_blend_epi16::<0b00110011>(magic_i_lo, v);      // Blend the 32 lowest significant bits of v with magic_int_lo
+    let v_lo = _mm_or_si128(_mm_and_si128(v, _mm_set1_epi64x(0xffff_ffff)), magic_i_lo); // Blend the 32 lowest significant bits of v with magic_int_lo
     let mut v_hi = _mm_srli_epi64::<32>(v);                           // Extract the 32 most significant bits of v
     v_hi = _mm_xor_si128(v_hi, magic_i_hi32);               // Flip the msb of v_hi and blend with 0x45300000
     let v_hi_dbl = _mm_sub_pd(_mm_castsi128_pd(v_hi), magic_d_all); // Compute in double precision:
     _mm_add_pd(v_hi_dbl, _mm_castsi128_pd(v_lo))     // (v_hi - magic_d_all) + v_lo  Do not assume associativity of floating point addition !!
 }
 
-#[inline]
+#[inline(always)]
 /// Shifts packed 64-bit integers in a right by the amount specified by the corresponding element in count while shifting in zeros,
 pub unsafe fn _mm_srlv_epi64x(a: __m128i, count: __m128i) -> __m128i {
     let shift_low = _mm_srl_epi64(a, count); // high 64 is garbage
@@ -114,7 +114,7 @@ pub unsafe fn _mm_srlv_epi64x(a: __m128i, count: __m128i) -> __m128i {
     ))
 }
 
-#[inline]
+#[inline(always)]
 /// Shifts packed 64-bit integers in a left by the amount specified by the corresponding element in count while shifting in zeros, and returns the result.
 pub unsafe fn _mm_sllv_epi64x(a: __m128i, count: __m128i) -> __m128i {
     let shift_low = _mm_sll_epi64(a, count); // high 64 is garbage
@@ -128,7 +128,7 @@ pub unsafe fn _mm_sllv_epi64x(a: __m128i, count: __m128i) -> __m128i {
     ))
 }
 
-#[inline]
+#[inline(always)]
 /// Extracts i64 value
 pub unsafe fn _mm_extract_epi64x<const IMM: i32>(d: __m128i) -> i64 {
     #[cfg(target_arch = "x86_64")]
@@ -136,7 +136,7 @@ pub unsafe fn _mm_extract_epi64x<const IMM: i32>(d: __m128i) -> i64 {
         return if IMM == 0 {
             _mm_cvtsi128_si64(d)
         } else {
-            _mm_extract_epi64::<IMM>(d)
+            _mm_cvtsi128_si64(_mm_unpackhi_epi64(d, d))
         };
     }
     #[cfg(target_arch = "x86")]
@@ -149,11 +149,11 @@ pub unsafe fn _mm_extract_epi64x<const IMM: i32>(d: __m128i) -> i64 {
             low = _mm_cvtsi128_si32(_mm_srli_si128::<8>(d));
             high = _mm_cvtsi128_si32(_mm_srli_si128::<12>(d));
         }
-        return ((high as i64) << 32) | low as i64;
+        return ((high as i64) << 32) | (low as u32 as i64);
     }
 }
 
-#[inline]
+#[inline(always)]
 /// Bitwise not epi64
 pub unsafe fn _mm_not_epi64(a: __m128i) -> __m128i {
     #[allow(overflowing_literals)]
@@ -161,20 +161,20 @@ pub unsafe fn _mm_not_epi64(a: __m128i) -> __m128i {
     return _mm_xor_si128(a, all_ones);
 }
 
-#[inline]
+#[inline(always)]
 /// Arithmetic shift for i64, shifting with sign bits
 pub unsafe fn _mm_srai_epi64x<const IMM8: i32>(a: __m128i) -> __m128i {
-    let m = _mm_set1_epi64x(1 << (64 - 1));
+    let m = _mm_set1_epi64x(1 << (63 - IMM8));
     let x = _mm_srli_epi64::<IMM8>(a);
     _mm_sub_epi64(_mm_xor_si128(x, m), m)
 }
 
-#[inline]
+#[inline(always)]
 /// Packs integers 64 bits use unsigned saturation
 pub unsafe fn _mm_packus_epi64(a: __m128i, b: __m128i) -> __m128i {
     let i32_max = _mm_set1_epi64x(u32::MAX as i64);
-    let a = _mm_select_epi64(_mm_cmpgt_epi64(a, i32_max), i32_max, a);
-    let b = _mm_select_epi64(_mm_cmpgt_epi64(b, i32_max), i32_max, b);
+    let a = _mm_select_epi64(_mm_cmpgt_epi64x(a, i32_max), i32_max, a);
+    let b = _mm_select_epi64(_mm_cmpgt_epi64x(b, i32_max), i32_max, b);
     const SHUFFLE_MASK: i32 = _mm_shuffle(3, 1, 2, 0);
     let a = _mm_shuffle_epi32::<SHUFFLE_MASK>(a);
     let b1 = _mm_shuffle_epi32::<SHUFFLE_MASK>(b);
@@ -182,7 +182,7 @@ pub unsafe fn _mm_packus_epi64(a: __m128i, b: __m128i) -> __m128i {
     moved
 }
 
-#[inline]
+#[inline(always)]
 /// Packs integers 64 bits use signed saturation
 pub unsafe fn _mm_packs_epi64(a: __m128i, b: __m128i) -> __m128i {
     let i32_max = _mm_set1_epi64x(i32::MAX as i64);
@@ -196,7 +196,7 @@ pub unsafe fn _mm_packs_epi64(a: __m128i, b: __m128i) -> __m128i {
     moved
 }
 
-#[inline]
+#[inline(always)]
 /// Packs integers 64 bits use truncating, only lower half of i64 will be used
 pub unsafe fn _mm_packts_epi64(a: __m128i, b: __m128i) -> __m128i {
     const SHUFFLE_MASK: i32 = _mm_shuffle(3, 1, 2, 0);
@@ -257,6 +257,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_srai_epi64x() {
+        unsafe {
+            let value = _mm_set_epi64x(-1000, 1000);
+            let shifted = _mm_srai_epi64x::<3>(value);
+            assert_eq!(_mm_extract_epi64x::<0>(shifted), 1000 >> 3);
+            assert_eq!(_mm_extract_epi64x::<1>(shifted), -1000 >> 3);
+            let shifted = _mm_srai_epi64x::<63>(value);
+            assert_eq!(_mm_extract_epi64x::<0>(shifted), 0);
+            assert_eq!(_mm_extract_epi64x::<1>(shifted), -1);
+        }
+    }
+
     #[test]
     fn test_cvtepi64_pd() {
         unsafe {
@@ -0,0 +1,325 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::erf::{
+    ERFC_A_POLY_10_D, ERFC_A_POLY_11_D, ERFC_A_POLY_12_D, ERFC_A_POLY_13_D, ERFC_A_POLY_14_D,
+    ERFC_A_POLY_15_D, ERFC_A_POLY_1_D, ERFC_A_POLY_2_D, ERFC_A_POLY_3_D, ERFC_A_POLY_4_D,
+    ERFC_A_POLY_5_D, ERFC_A_POLY_6_D, ERFC_A_POLY_7_D, ERFC_A_POLY_8_D, ERFC_A_POLY_9_D,
+    ERFC_A_SHIFT_D, ERFC_B_POLY_10_D, ERFC_B_POLY_11_D, ERFC_B_POLY_12_D, ERFC_B_POLY_13_D,
+    ERFC_B_POLY_14_D, ERFC_B_POLY_15_D, ERFC_B_POLY_1_D, ERFC_B_POLY_2_D, ERFC_B_POLY_3_D,
+    ERFC_B_POLY_4_D, ERFC_B_POLY_5_D, ERFC_B_POLY_6_D, ERFC_B_POLY_7_D, ERFC_B_POLY_8_D,
+    ERFC_B_POLY_9_D, ERFC_B_SHIFT_D, ERFC_C_POLY_10_D, ERFC_C_POLY_11_D, ERFC_C_POLY_12_D,
+    ERFC_C_POLY_13_D, ERFC_C_POLY_14_D, ERFC_C_POLY_15_D, ERFC_C_POLY_1_D, ERFC_C_POLY_2_D,
+    ERFC_C_POLY_3_D, ERFC_C_POLY_4_D, ERFC_C_POLY_5_D, ERFC_C_POLY_6_D, ERFC_C_POLY_7_D,
+    ERFC_C_POLY_8_D, ERFC_C_POLY_9_D, ERFC_C_SHIFT_D, ERFC_D_POLY_10_D, ERFC_D_POLY_11_D,
+    ERFC_D_POLY_12_D, ERFC_D_POLY_13_D, ERFC_D_POLY_14_D, ERFC_D_POLY_15_D, ERFC_D_POLY_1_D,
+    ERFC_D_POLY_2_D, ERFC_D_POLY_3_D, ERFC_D_POLY_4_D, ERFC_D_POLY_5_D, ERFC_D_POLY_6_D,
+    ERFC_D_POLY_7_D, ERFC_D_POLY_8_D, ERFC_D_POLY_9_D, ERFC_D_SHIFT_D, ERF_POLY_10_D,
+    ERF_POLY_11_D, ERF_POLY_1_D, ERF_POLY_2_D, ERF_POLY_3_D, ERF_POLY_4_D, ERF_POLY_5_D,
+    ERF_POLY_6_D, ERF_POLY_7_D, ERF_POLY_8_D, ERF_POLY_9_D,
+};
+use crate::exp::{L2_L, L2_U, R_LN2};
+use crate::sse::exp::_mm_expm1k_pd;
+use crate::sse::general::_mm_rint_pd;
+use crate::{
+    _mm_abs_pd, _mm_copysign_pd, _mm_cvtepi64_pd, _mm_ldexp2k_pd, _mm_mlaf_pd, _mm_neg_pd,
+    _mm_select_pd,
+};
+
+/// Computes `erf(x) - x` for |x| < 0.84375
+#[inline(always)]
+pub(crate) unsafe fn _mm_erfk_pd(x: __m128d) -> __m128d {
+    let t = _mm_mul_pd(x, x);
+    let mut u = _mm_set1_pd(ERF_POLY_11_D);
+    u = _mm_mlaf_pd(u, t, _mm_set1_pd(ERF_POLY_10_D));
+    u = _mm_mlaf_pd(u, t, _mm_set1_pd(ERF_POLY_9_D));
+    u = _mm_mlaf_pd(u, t, _mm_set1_pd(ERF_POLY_8_D));
+    u = _mm_mlaf_pd(u, t, _mm_set1_pd(ERF_POLY_7_D));
+    u = _mm_mlaf_pd(u, t, _mm_set1_pd(ERF_POLY_6_D));
+    u = _mm_mlaf_pd(u, t, _mm_set1_pd(ERF_POLY_5_D));
+    u = _mm_mlaf_pd(u, t, _mm_set1_pd(ERF_POLY_4_D));
+    u = _mm_mlaf_pd(u, t, _mm_set1_pd(ERF_POLY_3_D));
+    u = _mm_mlaf_pd(u, t, _mm_set1_pd(ERF_POLY_2_D));
+    u = _mm_mlaf_pd(u, t, _mm_set1_pd(ERF_POLY_1_D));
+    _mm_mul_pd(x, u)
+}
+
+/// Computes erfc(a) for a >= 0.84375 as `exp(-a^2 + P(1/a)) / a`
+#[inline(always)]
+pub(crate) unsafe fn _mm_erfck_pd(a: __m128d) -> __m128d {
+    let t = _mm_div_pd(_mm_set1_pd(1f64), a);
+    let m0 = _mm_cmplt_pd(a, _mm_set1_pd(1.5f64));
+    let m1 = _mm_cmplt_pd(a, _mm_set1_pd(3.0f64));
+    let m2 = _mm_cmplt_pd(a, _mm_set1_pd(8.25f64));
+    let pick = |c0: f64, c1: f64, c2: f64, c3: f64| {
+        _mm_select_pd(
+            m0,
+            _mm_set1_pd(c0),
+            _mm_select_pd(
+                m1,
+                _mm_set1_pd(c1),
+                _mm_select_pd(m2, _mm_set1_pd(c2), _mm_set1_pd(c3)),
+            ),
+        )
+    };
+    let v = _mm_sub_pd(
+        t,
+        pick(
+            ERFC_A_SHIFT_D,
+            ERFC_B_SHIFT_D,
+            ERFC_C_SHIFT_D,
+            ERFC_D_SHIFT_D,
+        ),
+    );
+    let mut u = pick(
+        ERFC_A_POLY_15_D,
+        ERFC_B_POLY_15_D,
+        ERFC_C_POLY_15_D,
+        ERFC_D_POLY_15_D,
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_14_D,
+            ERFC_B_POLY_14_D,
+            ERFC_C_POLY_14_D,
+            ERFC_D_POLY_14_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_13_D,
+            ERFC_B_POLY_13_D,
+            ERFC_C_POLY_13_D,
+            ERFC_D_POLY_13_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_12_D,
+            ERFC_B_POLY_12_D,
+            ERFC_C_POLY_12_D,
+            ERFC_D_POLY_12_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_11_D,
+            ERFC_B_POLY_11_D,
+            ERFC_C_POLY_11_D,
+            ERFC_D_POLY_11_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_10_D,
+            ERFC_B_POLY_10_D,
+            ERFC_C_POLY_10_D,
+            ERFC_D_POLY_10_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_9_D,
+            ERFC_B_POLY_9_D,
+            ERFC_C_POLY_9_D,
+            ERFC_D_POLY_9_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_8_D,
+            ERFC_B_POLY_8_D,
+            ERFC_C_POLY_8_D,
+            ERFC_D_POLY_8_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_7_D,
+            ERFC_B_POLY_7_D,
+            ERFC_C_POLY_7_D,
+            ERFC_D_POLY_7_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_6_D,
+            ERFC_B_POLY_6_D,
+            ERFC_C_POLY_6_D,
+            ERFC_D_POLY_6_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_5_D,
+            ERFC_B_POLY_5_D,
+            ERFC_C_POLY_5_D,
+            ERFC_D_POLY_5_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_4_D,
+            ERFC_B_POLY_4_D,
+            ERFC_C_POLY_4_D,
+            ERFC_D_POLY_4_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_3_D,
+            ERFC_B_POLY_3_D,
+            ERFC_C_POLY_3_D,
+            ERFC_D_POLY_3_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_2_D,
+            ERFC_B_POLY_2_D,
+            ERFC_C_POLY_2_D,
+            ERFC_D_POLY_2_D,
+        ),
+    );
+    u = _mm_mlaf_pd(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_1_D,
+            ERFC_B_POLY_1_D,
+            ERFC_C_POLY_1_D,
+            ERFC_D_POLY_1_D,
+        ),
+    );
+    // -a^2 = -z^2 + (z - a)(z + a), z keeps half of the mantissa so z^2 is exact
+    let z = _mm_and_pd(
+        a,
+        _mm_castsi128_pd(_mm_set1_epi64x(0xffff_ffff_0000_0000u64 as i64)),
+    );
+    let hi = _mm_neg_pd(_mm_mul_pd(z, z));
+    let lo = _mm_mlaf_pd(_mm_sub_pd(z, a), _mm_add_pd(z, a), u);
+    let q = _mm_rint_pd(_mm_mul_pd(_mm_add_pd(hi, lo), _mm_set1_pd(R_LN2)));
+    let qf = _mm_cvtepi64_pd(q);
+    // hi is reduced exactly, lo joins after the reduction
+    let mut r = _mm_mlaf_pd(qf, _mm_set1_pd(-L2_U), hi);
+    r = _mm_mlaf_pd(qf, _mm_set1_pd(-L2_L), _mm_add_pd(r, lo));
+    let e = _mm_ldexp2k_pd(
+        _mm_div_pd(_mm_add_pd(_mm_expm1k_pd(r), _mm_set1_pd(1f64)), a),
+        q,
+    );
+    // erfc underflows here, also covers a == Inf
+    _mm_select_pd(_mm_cmpgt_pd(a, _mm_set1_pd(27.3f64)), _mm_setzero_pd(), e)
+}
+
+/// Computes error function, error bound *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm_erf_pd(d: __m128d) -> __m128d {
+    let a = _mm_abs_pd(d);
+    let mut r = _mm_copysign_pd(_mm_sub_pd(_mm_set1_pd(1f64), _mm_erfck_pd(a)), d);
+    r = _mm_select_pd(
+        _mm_cmplt_pd(a, _mm_set1_pd(0.84375f64)),
+        _mm_add_pd(d, _mm_erfk_pd(d)),
+        r,
+    );
+    r = _mm_select_pd(
+        _mm_cmpgt_pd(a, _mm_set1_pd(6f64)),
+        _mm_copysign_pd(_mm_set1_pd(1f64), d),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_erfd() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(0.5f64);
+            let comparison = _mm_erf_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 0.5204998778130465f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(-1.5f64);
+            let comparison = _mm_erf_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = -0.9661051464753108f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(3f64);
+            let comparison = _mm_erf_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 0.9999779095030014f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm_set1_pd(10f64);
+            let comparison = _mm_erf_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 1.);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm_set1_pd(-10f64);
+            let comparison = _mm_erf_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+
+        unsafe {
+            // Test Nan
+            let value = _mm_set1_pd(f64::NAN);
+            let comparison = _mm_erf_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
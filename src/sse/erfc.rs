@@ -0,0 +1,99 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::sse::erf::{_mm_erfck_pd, _mm_erfk_pd};
+use crate::{_mm_abs_pd, _mm_select_pd};
+
+/// Computes complementary error function, error bound *ULP 3.5*
+#[inline(always)]
+pub unsafe fn _mm_erfc_pd(d: __m128d) -> __m128d {
+    let a = _mm_abs_pd(d);
+    let u = _mm_erfk_pd(d);
+    // Past 0.25 recentering on 0.5 keeps the subtraction from cancelling
+    let mut s = _mm_sub_pd(
+        _mm_set1_pd(0.5f64),
+        _mm_add_pd(_mm_sub_pd(d, _mm_set1_pd(0.5f64)), u),
+    );
+    s = _mm_select_pd(
+        _mm_cmplt_pd(d, _mm_set1_pd(0.25f64)),
+        _mm_sub_pd(_mm_set1_pd(1f64), _mm_add_pd(d, u)),
+        s,
+    );
+    let e = _mm_erfck_pd(a);
+    let r = _mm_select_pd(
+        _mm_cmplt_pd(d, _mm_setzero_pd()),
+        _mm_sub_pd(_mm_set1_pd(2f64), e),
+        e,
+    );
+    _mm_select_pd(_mm_cmplt_pd(a, _mm_set1_pd(0.84375f64)), s, r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_erfcd() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(0.5f64);
+            let comparison = _mm_erfc_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 0.4795001221869535f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(-1.5f64);
+            let comparison = _mm_erfc_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 1.9661051464753108f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(5f64);
+            let comparison = _mm_erfc_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 1.537459794428035e-12f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(26f64);
+            let comparison = _mm_erfc_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 5.663192408856143e-296f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm_set1_pd(30f64);
+            let comparison = _mm_erfc_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm_set1_pd(-30f64);
+            let comparison = _mm_erfc_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 2.);
+        }
+    }
+}
@@ -0,0 +1,180 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::erff::{
+    ERFC_A_POLY_1_S, ERFC_A_POLY_2_S, ERFC_A_POLY_3_S, ERFC_A_POLY_4_S, ERFC_A_POLY_5_S,
+    ERFC_A_POLY_6_S, ERFC_A_POLY_7_S, ERFC_A_SHIFT_S, ERFC_B_POLY_1_S, ERFC_B_POLY_2_S,
+    ERFC_B_POLY_3_S, ERFC_B_POLY_4_S, ERFC_B_POLY_5_S, ERFC_B_POLY_6_S, ERFC_B_POLY_7_S,
+    ERFC_B_SHIFT_S, ERFC_C_POLY_1_S, ERFC_C_POLY_2_S, ERFC_C_POLY_3_S, ERFC_C_POLY_4_S,
+    ERFC_C_POLY_5_S, ERFC_C_POLY_6_S, ERFC_C_POLY_7_S, ERFC_C_SHIFT_S, ERF_POLY_1_S, ERF_POLY_2_S,
+    ERF_POLY_3_S, ERF_POLY_4_S, ERF_POLY_5_S, ERF_POLY_6_S, ERF_POLY_7_S,
+};
+use crate::expf::{L2L_F, L2U_F, R_LN2_F};
+use crate::sse::expf::_mm_expm1k_ps;
+use crate::{
+    _mm_abs_ps, _mm_copysign_ps, _mm_ldexp2kq_ps, _mm_mlaf_ps, _mm_neg_ps, _mm_rint_ps,
+    _mm_select_ps,
+};
+
+/// Computes `erf(x) - x` for |x| < 0.84375
+#[inline(always)]
+pub(crate) unsafe fn _mm_erfk_ps(x: __m128) -> __m128 {
+    let t = _mm_mul_ps(x, x);
+    let mut u = _mm_set1_ps(ERF_POLY_7_S);
+    u = _mm_mlaf_ps(u, t, _mm_set1_ps(ERF_POLY_6_S));
+    u = _mm_mlaf_ps(u, t, _mm_set1_ps(ERF_POLY_5_S));
+    u = _mm_mlaf_ps(u, t, _mm_set1_ps(ERF_POLY_4_S));
+    u = _mm_mlaf_ps(u, t, _mm_set1_ps(ERF_POLY_3_S));
+    u = _mm_mlaf_ps(u, t, _mm_set1_ps(ERF_POLY_2_S));
+    u = _mm_mlaf_ps(u, t, _mm_set1_ps(ERF_POLY_1_S));
+    _mm_mul_ps(x, u)
+}
+
+/// Computes erfc(a) for a >= 0.84375 as `exp(-a^2 + P(1/a)) / a`
+#[inline(always)]
+pub(crate) unsafe fn _mm_erfck_ps(a: __m128) -> __m128 {
+    let t = _mm_div_ps(_mm_set1_ps(1f32), a);
+    let m0 = _mm_cmplt_ps(a, _mm_set1_ps(1.4375f32));
+    let m1 = _mm_cmplt_ps(a, _mm_set1_ps(2.625f32));
+    let pick = |c0: f32, c1: f32, c2: f32| {
+        _mm_select_ps(
+            m0,
+            _mm_set1_ps(c0),
+            _mm_select_ps(m1, _mm_set1_ps(c1), _mm_set1_ps(c2)),
+        )
+    };
+    let v = _mm_sub_ps(t, pick(ERFC_A_SHIFT_S, ERFC_B_SHIFT_S, ERFC_C_SHIFT_S));
+    let mut u = pick(ERFC_A_POLY_7_S, ERFC_B_POLY_7_S, ERFC_C_POLY_7_S);
+    u = _mm_mlaf_ps(
+        u,
+        v,
+        pick(ERFC_A_POLY_6_S, ERFC_B_POLY_6_S, ERFC_C_POLY_6_S),
+    );
+    u = _mm_mlaf_ps(
+        u,
+        v,
+        pick(ERFC_A_POLY_5_S, ERFC_B_POLY_5_S, ERFC_C_POLY_5_S),
+    );
+    u = _mm_mlaf_ps(
+        u,
+        v,
+        pick(ERFC_A_POLY_4_S, ERFC_B_POLY_4_S, ERFC_C_POLY_4_S),
+    );
+    u = _mm_mlaf_ps(
+        u,
+        v,
+        pick(ERFC_A_POLY_3_S, ERFC_B_POLY_3_S, ERFC_C_POLY_3_S),
+    );
+    u = _mm_mlaf_ps(
+        u,
+        v,
+        pick(ERFC_A_POLY_2_S, ERFC_B_POLY_2_S, ERFC_C_POLY_2_S),
+    );
+    u = _mm_mlaf_ps(
+        u,
+        v,
+        pick(ERFC_A_POLY_1_S, ERFC_B_POLY_1_S, ERFC_C_POLY_1_S),
+    );
+    // -a^2 = -z^2 + (z - a)(z + a), z keeps half of the mantissa so z^2 is exact
+    let z = _mm_and_ps(a, _mm_castsi128_ps(_mm_set1_epi32(0xffff_f000u32 as i32)));
+    let hi = _mm_neg_ps(_mm_mul_ps(z, z));
+    let lo = _mm_mlaf_ps(_mm_sub_ps(z, a), _mm_add_ps(z, a), u);
+    let q = _mm_rint_ps(_mm_mul_ps(_mm_add_ps(hi, lo), _mm_set1_ps(R_LN2_F)));
+    let qf = _mm_cvtepi32_ps(q);
+    // hi is reduced exactly, lo joins after the reduction
+    let mut r = _mm_mlaf_ps(qf, _mm_set1_ps(-L2U_F), hi);
+    r = _mm_mlaf_ps(qf, _mm_set1_ps(-L2L_F), _mm_add_ps(r, lo));
+    let e = _mm_ldexp2kq_ps(
+        _mm_div_ps(_mm_add_ps(_mm_expm1k_ps(r), _mm_set1_ps(1f32)), a),
+        q,
+    );
+    // erfc underflows here, also covers a == Inf
+    _mm_select_ps(_mm_cmpgt_ps(a, _mm_set1_ps(10.1f32)), _mm_setzero_ps(), e)
+}
+
+/// Computes error function, error bound *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm_erf_ps(d: __m128) -> __m128 {
+    let a = _mm_abs_ps(d);
+    let mut r = _mm_copysign_ps(_mm_sub_ps(_mm_set1_ps(1f32), _mm_erfck_ps(a)), d);
+    r = _mm_select_ps(
+        _mm_cmplt_ps(a, _mm_set1_ps(0.84375f32)),
+        _mm_add_ps(d, _mm_erfk_ps(d)),
+        r,
+    );
+    r = _mm_select_ps(
+        _mm_cmpgt_ps(a, _mm_set1_ps(4f32)),
+        _mm_copysign_ps(_mm_set1_ps(1f32), d),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_erff() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(0.5f32);
+            let comparison = _mm_erf_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 0.5204999f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(-1.5f32);
+            let comparison = _mm_erf_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = -0.96610516f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(3f32);
+            let comparison = _mm_erf_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 0.9999779f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm_set1_ps(10f32);
+            let comparison = _mm_erf_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, 1.);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm_set1_ps(-10f32);
+            let comparison = _mm_erf_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, -1.);
+        }
+
+        unsafe {
+            // Test Nan
+            let value = _mm_set1_ps(f32::NAN);
+            let comparison = _mm_erf_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
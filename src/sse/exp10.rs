@@ -0,0 +1,81 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::exp::{LG2_L, LG2_U, LN10_HI, LN10_LO};
+use crate::sse::exp::_mm_expm1k_pd;
+use crate::sse::general::{_mm_ldexp2k_pd, _mm_rint_pd};
+use crate::{_mm_cvtepi64_pd, _mm_mlaf_pd, _mm_select_pd};
+
+/// Computes 10^x *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm_exp10_pd(d: __m128d) -> __m128d {
+    let q = _mm_rint_pd(_mm_mul_pd(d, _mm_set1_pd(std::f64::consts::LOG2_10)));
+    let qf = _mm_cvtepi64_pd(q);
+    let mut s = _mm_mlaf_pd(qf, _mm_set1_pd(-LG2_U), d);
+    s = _mm_mlaf_pd(qf, _mm_set1_pd(-LG2_L), s);
+    let r = _mm_mlaf_pd(s, _mm_set1_pd(LN10_HI), _mm_mul_pd(s, _mm_set1_pd(LN10_LO)));
+    let mut r = _mm_ldexp2k_pd(_mm_add_pd(_mm_expm1k_pd(r), _mm_set1_pd(1f64)), q);
+    r = _mm_select_pd(
+        _mm_cmplt_pd(d, _mm_set1_pd(-323.61f64)),
+        _mm_setzero_pd(),
+        r,
+    );
+    r = _mm_select_pd(
+        _mm_cmpgt_pd(d, _mm_set1_pd(308.26f64)),
+        _mm_set1_pd(f64::INFINITY),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_exp10d() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(2.5f64);
+            let comparison = _mm_exp10_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 10f64.powf(2.5);
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(300f64);
+            let comparison = _mm_exp10_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 1e300;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm_set1_pd(400f64);
+            let comparison = _mm_exp10_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        unsafe {
+            // Test underflow
+            let value = _mm_set1_pd(-400f64);
+            let comparison = _mm_exp10_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
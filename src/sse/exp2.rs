@@ -0,0 +1,79 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::exp::LN2_LO;
+use crate::sse::exp::_mm_expm1k_pd;
+use crate::sse::general::{_mm_ldexp2k_pd, _mm_rint_pd};
+use crate::{_mm_cvtepi64_pd, _mm_mlaf_pd, _mm_select_pd};
+
+/// Computes 2^x *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm_exp2_pd(d: __m128d) -> __m128d {
+    let q = _mm_rint_pd(d);
+    let qf = _mm_cvtepi64_pd(q);
+    let s = _mm_sub_pd(d, qf);
+    let r = _mm_mlaf_pd(
+        s,
+        _mm_set1_pd(std::f64::consts::LN_2),
+        _mm_mul_pd(s, _mm_set1_pd(LN2_LO)),
+    );
+    let mut r = _mm_ldexp2k_pd(_mm_add_pd(_mm_expm1k_pd(r), _mm_set1_pd(1f64)), q);
+    r = _mm_select_pd(_mm_cmplt_pd(d, _mm_set1_pd(-1075f64)), _mm_setzero_pd(), r);
+    r = _mm_select_pd(
+        _mm_cmpge_pd(d, _mm_set1_pd(1024f64)),
+        _mm_set1_pd(f64::INFINITY),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_exp2d() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(10.5f64);
+            let comparison = _mm_exp2_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 10.5f64.exp2();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test subnormal result
+            let value = _mm_set1_pd(-1070f64);
+            let comparison = _mm_exp2_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::from_bits(16));
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm_set1_pd(1024f64);
+            let comparison = _mm_exp2_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        unsafe {
+            // Test underflow
+            let value = _mm_set1_pd(-1100f64);
+            let comparison = _mm_exp2_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::expf::LN2_LO_F;
+use crate::sse::expf::_mm_expm1k_ps;
+use crate::sse::generalf::{_mm_ldexp2kq_ps, _mm_rint_ps};
+use crate::{_mm_mlaf_ps, _mm_select_ps};
+
+/// Computes 2^x *ULP 2.0*
+#[inline(always)]
+pub unsafe fn _mm_exp2_ps(d: __m128) -> __m128 {
+    let q = _mm_rint_ps(d);
+    let qf = _mm_cvtepi32_ps(q);
+    let s = _mm_sub_ps(d, qf);
+    let r = _mm_mlaf_ps(
+        s,
+        _mm_set1_ps(std::f32::consts::LN_2),
+        _mm_mul_ps(s, _mm_set1_ps(LN2_LO_F)),
+    );
+    let mut r = _mm_ldexp2kq_ps(_mm_add_ps(_mm_expm1k_ps(r), _mm_set1_ps(1f32)), q);
+    r = _mm_select_ps(_mm_cmplt_ps(d, _mm_set1_ps(-150f32)), _mm_setzero_ps(), r);
+    r = _mm_select_ps(
+        _mm_cmpge_ps(d, _mm_set1_ps(128f32)),
+        _mm_set1_ps(f32::INFINITY),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_exp2f() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(3.25f32);
+            let comparison = _mm_exp2_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 3.25f32.exp2();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test subnormal result
+            let value = _mm_set1_ps(-140f32);
+            let comparison = _mm_exp2_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::from_bits(1 << 9));
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm_set1_ps(128f32);
+            let comparison = _mm_exp2_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        unsafe {
+            // Test underflow
+            let value = _mm_set1_ps(-160f32);
+            let comparison = _mm_exp2_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
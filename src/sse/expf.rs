@@ -8,20 +8,37 @@
 use crate::expf::{
     EXP_POLY_1_S, EXP_POLY_2_S, EXP_POLY_3_S, EXP_POLY_4_S, EXP_POLY_5_S, L2L_F, L2U_F,
 };
-use crate::sse::generalf::_mm_rint_ps;
+use crate::sse::generalf::{_mm_ldexp2kq_ps, _mm_rint_ps};
 use crate::{_mm_mlaf_ps, _mm_pow2if_epi32, _mm_select_ps};
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+/// Computes `exp(r) - 1` for reduced argument |r| <= ln(2)/2
+#[inline(always)]
+pub(crate) unsafe fn _mm_expm1k_ps(r: __m128) -> __m128 {
+    let f = _mm_mul_ps(r, r);
+    let mut u = _mm_set1_ps(EXP_POLY_5_S);
+    u = _mm_mlaf_ps(u, f, _mm_set1_ps(EXP_POLY_4_S));
+    u = _mm_mlaf_ps(u, f, _mm_set1_ps(EXP_POLY_3_S));
+    u = _mm_mlaf_ps(u, f, _mm_set1_ps(EXP_POLY_2_S));
+    u = _mm_mlaf_ps(u, f, _mm_set1_ps(EXP_POLY_1_S));
+    _mm_div_ps(_mm_mul_ps(r, _mm_set1_ps(2f32)), _mm_sub_ps(u, r))
+}
+
 /// Computes exp for an argument *ULP 1.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_exp_ps(d: __m128) -> __m128 {
-    let mut r = _mm_exp_fast_ps(d);
-    r = _mm_select_ps(_mm_cmplt_ps(d, _mm_set1_ps(-87f32)), _mm_setzero_ps(), r);
+    let q = _mm_rint_ps(_mm_mul_ps(d, _mm_set1_ps(std::f32::consts::LOG2_E)));
+    let qf = _mm_cvtepi32_ps(q);
+    let mut r = _mm_mlaf_ps(qf, _mm_set1_ps(-L2U_F), d);
+    r = _mm_mlaf_ps(qf, _mm_set1_ps(-L2L_F), r);
+    // Two step scaling keeps subnormal results and the last binade before overflow
+    r = _mm_ldexp2kq_ps(_mm_add_ps(_mm_expm1k_ps(r), _mm_set1_ps(1f32)), q);
+    r = _mm_select_ps(_mm_cmplt_ps(d, _mm_set1_ps(-104f32)), _mm_setzero_ps(), r);
     r = _mm_select_ps(
-        _mm_cmpgt_ps(d, _mm_set1_ps(88f32)),
+        _mm_cmpgt_ps(d, _mm_set1_ps(89f32)),
         _mm_set1_ps(f32::INFINITY),
         r,
     );
@@ -29,23 +46,14 @@ pub unsafe fn _mm_exp_ps(d: __m128) -> __m128 {
 }
 
 /// Method that computes exp skipping Inf, Nan checks error bound *ULP 1.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_exp_fast_ps(d: __m128) -> __m128 {
     let q = _mm_rint_ps(_mm_mul_ps(d, _mm_set1_ps(std::f32::consts::LOG2_E)));
     let qf = _mm_cvtepi32_ps(q);
     /* exp(x) = 2^i * exp(f); */
     let mut r = _mm_mlaf_ps(qf, _mm_set1_ps(-L2U_F), d);
     r = _mm_mlaf_ps(qf, _mm_set1_ps(-L2L_F), r);
-    let f = _mm_mul_ps(r, r);
-    let mut u = _mm_set1_ps(EXP_POLY_5_S);
-    u = _mm_mlaf_ps(u, f, _mm_set1_ps(EXP_POLY_4_S));
-    u = _mm_mlaf_ps(u, f, _mm_set1_ps(EXP_POLY_3_S));
-    u = _mm_mlaf_ps(u, f, _mm_set1_ps(EXP_POLY_2_S));
-    u = _mm_mlaf_ps(u, f, _mm_set1_ps(EXP_POLY_1_S));
-    let u = _mm_add_ps(
-        _mm_div_ps(_mm_mul_ps(r, _mm_set1_ps(2f32)), _mm_sub_ps(u, r)),
-        _mm_set1_ps(1f32),
-    );
+    let u = _mm_add_ps(_mm_expm1k_ps(r), _mm_set1_ps(1f32));
     let i2 = _mm_castsi128_ps(_mm_pow2if_epi32(q));
     let r = _mm_mul_ps(u, i2);
     r
@@ -54,6 +62,7 @@ pub unsafe fn _mm_exp_fast_ps(d: __m128) -> __m128 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_expf() {
@@ -61,7 +70,7 @@ mod tests {
             // Test regular
             let value = _mm_set1_ps(23f32);
             let comparison = _mm_exp_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, 23f32.exp());
         }
 
@@ -69,7 +78,7 @@ mod tests {
             // Test Infinity
             let value = _mm_set1_ps(f32::INFINITY);
             let comparison = _mm_exp_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, f32::INFINITY);
         }
 
@@ -77,15 +86,15 @@ mod tests {
             // Test Neg Infinity
             let value = _mm_set1_ps(-15f32);
             let comparison = _mm_exp_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, (-15f32).exp());
         }
 
         unsafe {
             // Test underflow
-            let value = _mm_set1_ps(-89f32);
+            let value = _mm_set1_ps(-105f32);
             let comparison = _mm_exp_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, 0f32);
         }
 
@@ -93,7 +102,7 @@ mod tests {
             // Test overflow
             let value = _mm_set1_ps(89f32);
             let comparison = _mm_exp_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1.is_infinite(), true);
         }
     }
@@ -0,0 +1,337 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::_mm_extract_pd;
+
+/// Type represents f128, in low f64 and high f64 part
+/// This is not real f128, this should be considered as `double double` rather than IEEE binary128
+/// Operations are the same as NEON `float128x2_t` ones and produce the same bits,
+/// without `fma` target feature products are made exact by Dekker's TwoProd.
+/// Exactness holds while products neither overflow nor fall into subnormal range
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct __m128dx2(pub(crate) __m128d, pub(crate) __m128d);
+
+#[cfg(target_feature = "fma")]
+#[inline(always)]
+unsafe fn _mm_upperpart_pd(a: __m128d) -> __m128d {
+    let mask = _mm_set1_epi64x(0x_ffff_ffff_f800_0000u64 as i64);
+    _mm_castsi128_pd(_mm_and_si128(_mm_castpd_si128(a), mask))
+}
+
+#[inline(always)]
+/// Negates by flipping sign bit, same as NEON `vnegq_f64`
+unsafe fn _mm_negsign_pd(a: __m128d) -> __m128d {
+    _mm_xor_pd(a, _mm_set1_pd(-0.0f64))
+}
+
+#[cfg(target_feature = "fma")]
+#[inline(always)]
+/// Computes `a*b + c` with a single rounding, same as NEON `vfmaq_f64`
+unsafe fn _mm_fmaf_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    _mm_fmadd_pd(a, b, c)
+}
+
+#[cfg(target_feature = "fma")]
+#[inline(always)]
+/// Computes rounding error of `a*b`, `r` must be the rounded product
+unsafe fn _mm_mul_err_pd(a: __m128d, b: __m128d, r: __m128d) -> __m128d {
+    let xh = _mm_upperpart_pd(a);
+    let xl = _mm_sub_pd(a, xh);
+    let yh = _mm_upperpart_pd(b);
+    let yl = _mm_sub_pd(b, yh);
+    _mm_fmaf_pd(
+        xl,
+        yl,
+        _mm_fmaf_pd(xl, yh, _mm_fmaf_pd(xh, yl, _mm_fmsub_pd(xh, yh, r))),
+    )
+}
+
+#[cfg(not(target_feature = "fma"))]
+#[inline(always)]
+/// Splits into high and low halves of 26 bits each, so their products are exact
+unsafe fn _mm_split_pd(a: __m128d) -> (__m128d, __m128d) {
+    let c = _mm_mul_pd(_mm_set1_pd(134217729f64), a);
+    let h = _mm_sub_pd(c, _mm_sub_pd(c, a));
+    (h, _mm_sub_pd(a, h))
+}
+
+#[cfg(not(target_feature = "fma"))]
+#[inline(always)]
+/// Computes rounding error of `a*b`, `r` must be the rounded product.
+/// Dekker's TwoProd gives the exact error, as NEON fused sequence does
+unsafe fn _mm_mul_err_pd(a: __m128d, b: __m128d, r: __m128d) -> __m128d {
+    let (xh, xl) = _mm_split_pd(a);
+    let (yh, yl) = _mm_split_pd(b);
+    let mut e = _mm_sub_pd(_mm_mul_pd(xh, yh), r);
+    e = _mm_add_pd(e, _mm_mul_pd(xh, yl));
+    e = _mm_add_pd(e, _mm_mul_pd(xl, yh));
+    _mm_add_pd(e, _mm_mul_pd(xl, yl))
+}
+
+#[cfg(not(target_feature = "fma"))]
+#[inline(always)]
+/// Adds with rounding to odd, inexact sums get the neighbour with odd last bit
+unsafe fn _mm_add_odd_pd(a: __m128d, b: __m128d) -> __m128d {
+    let s = _mm_add_pd(a, b);
+    let v = _mm_sub_pd(s, a);
+    let e = _mm_add_pd(_mm_sub_pd(a, _mm_sub_pd(s, v)), _mm_sub_pd(b, v));
+    let bits = _mm_castpd_si128(s);
+    let inexact = _mm_castpd_si128(_mm_cmpneq_pd(e, _mm_setzero_pd()));
+    let lsb = _mm_and_si128(bits, _mm_set1_epi64x(1));
+    let even = _mm_shuffle_epi32::<0b10100000>(_mm_cmpeq_epi32(lsb, _mm_setzero_si128()));
+    // Steps one ulp away from zero when error has the same sign, otherwise towards zero
+    let sign = _mm_srai_epi32::<31>(_mm_castpd_si128(_mm_xor_pd(s, e)));
+    let step = _mm_or_si128(_mm_shuffle_epi32::<0b11110101>(sign), _mm_set1_epi64x(1));
+    let step = _mm_and_si128(step, _mm_and_si128(inexact, even));
+    _mm_castsi128_pd(_mm_add_epi64(bits, step))
+}
+
+#[cfg(not(target_feature = "fma"))]
+#[inline(always)]
+/// Computes `a*b + c` with a single rounding, same as NEON `vfmaq_f64`.
+/// Exact product is added with rounding to odd, then rounded once to nearest (Boldo-Melquiond)
+unsafe fn _mm_fmaf_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    let p = _mm_mul_pd(a, b);
+    let pl = _mm_mul_err_pd(a, b, p);
+    let s = _mm_add_pd(c, p);
+    let v = _mm_sub_pd(s, c);
+    let sl = _mm_add_pd(_mm_sub_pd(c, _mm_sub_pd(s, v)), _mm_sub_pd(p, v));
+    _mm_add_pd(s, _mm_add_odd_pd(sl, pl))
+}
+
+#[inline(always)]
+/// Performs multiplication for f128
+pub unsafe fn _mm_mul_f128(a: __m128dx2, b: __m128dx2) -> __m128dx2 {
+    let r0 = _mm_mul_pd(a.0, b.0);
+    let e = _mm_mul_err_pd(a.0, b.0, r0);
+    __m128dx2(r0, _mm_fmaf_pd(a.0, b.1, _mm_fmaf_pd(a.1, b.0, e)))
+}
+
+#[inline(always)]
+/// Widens f64 into f128 and performs multiplication
+pub unsafe fn _mm_mull_pd(a: __m128d, b: __m128d) -> __m128dx2 {
+    let r0 = _mm_mul_pd(a, b);
+    __m128dx2(r0, _mm_mul_err_pd(a, b, r0))
+}
+
+#[inline(always)]
+/// Adds f64 with widening to f128
+pub unsafe fn _mm_addl_pd(a: __m128d, b: __m128d) -> __m128dx2 {
+    let r0 = _mm_add_pd(a, b);
+    let v = _mm_sub_pd(r0, a);
+    __m128dx2(
+        r0,
+        _mm_add_pd(_mm_sub_pd(a, _mm_sub_pd(r0, v)), _mm_sub_pd(b, v)),
+    )
+}
+
+/// Adds f128 to another f128
+#[inline(always)]
+pub unsafe fn _mm_add_f128(a: __m128dx2, b: __m128dx2) -> __m128dx2 {
+    let r0 = _mm_add_pd(a.0, b.0);
+    let v = _mm_sub_pd(r0, a.0);
+    __m128dx2(
+        r0,
+        _mm_add_pd(
+            _mm_add_pd(_mm_sub_pd(a.0, _mm_sub_pd(r0, v)), _mm_sub_pd(b.0, v)),
+            _mm_add_pd(a.1, b.1),
+        ),
+    )
+}
+
+/// Negates f128
+#[inline(always)]
+pub unsafe fn _mm_neg_f128(a: __m128dx2) -> __m128dx2 {
+    __m128dx2(_mm_negsign_pd(a.0), _mm_negsign_pd(a.1))
+}
+
+/// Adds f64 with widening to f128
+#[inline(always)]
+pub unsafe fn _mm_addw_pd(a: __m128dx2, b: __m128d) -> __m128dx2 {
+    let r0 = _mm_add_pd(a.0, b);
+    let v = _mm_sub_pd(r0, a.0);
+    __m128dx2(
+        r0,
+        _mm_add_pd(
+            _mm_add_pd(_mm_sub_pd(a.0, _mm_sub_pd(r0, v)), _mm_sub_pd(b, v)),
+            a.1,
+        ),
+    )
+}
+
+#[inline(always)]
+/// Performs division for f128
+pub unsafe fn _mm_div_f128(a: __m128dx2, b: __m128dx2) -> __m128dx2 {
+    let t = _mm_div_pd(_mm_set1_pd(1.), b.0);
+    let q0 = _mm_mul_pd(a.0, t);
+    let u = _mm_fmaf_pd(
+        q0,
+        _mm_negsign_pd(_mm_mul_err_pd(b.0, t, _mm_set1_pd(1.))),
+        _mm_mul_err_pd(a.0, t, q0),
+    );
+    let low = _mm_fmaf_pd(t, _mm_sub_pd(a.1, _mm_mul_pd(q0, b.1)), u);
+    __m128dx2(q0, low)
+}
+
+#[inline(always)]
+/// Converts f128 into f64
+pub unsafe fn _mm_cvtf128_pd(d: __m128dx2) -> __m128d {
+    _mm_add_pd(d.0, d.1)
+}
+
+#[inline(always)]
+/// Converts f64 into f128
+pub unsafe fn _mm_cvtpd_f128(d: __m128d) -> __m128dx2 {
+    __m128dx2(d, _mm_setzero_pd())
+}
+
+#[inline(always)]
+/// Broadcasts f64 into f128
+pub unsafe fn _mm_set1_f128(d: f64) -> __m128dx2 {
+    __m128dx2(_mm_set1_pd(d), _mm_setzero_pd())
+}
+
+/// Computes f128 as f64 and extracts in general register
+#[inline(always)]
+pub unsafe fn _mm_extract_f128<const IMM: i32>(d: __m128dx2) -> f64 {
+    _mm_extract_pd::<IMM>(_mm_add_pd(d.0, d.1))
+}
+
+#[inline(always)]
+/// Fused multiply add for f128
+pub unsafe fn _mm_mlaf_f128(a: __m128dx2, b: __m128dx2, c: __m128dx2) -> __m128dx2 {
+    _mm_add_f128(_mm_mul_f128(a, b), c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scalar port of the NEON `float128x2_t` algorithms, `vfmaq_f64` is always fused
+    fn mla(a: f64, b: f64, c: f64) -> f64 {
+        a.mul_add(b, c)
+    }
+
+    fn upper(a: f64) -> f64 {
+        f64::from_bits(a.to_bits() & 0x_ffff_ffff_f800_0000)
+    }
+
+    fn mul_err(a: f64, b: f64, r: f64) -> f64 {
+        let xh = upper(a);
+        let xl = a - xh;
+        let yh = upper(b);
+        let yl = b - yh;
+        mla(xl, yl, mla(xl, yh, mla(xh, yl, mla(xh, yh, -r))))
+    }
+
+    fn mul(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+        let r0 = a.0 * b.0;
+        (r0, mla(a.0, b.1, mla(a.1, b.0, mul_err(a.0, b.0, r0))))
+    }
+
+    fn mull(a: f64, b: f64) -> (f64, f64) {
+        let r0 = a * b;
+        (r0, mul_err(a, b, r0))
+    }
+
+    fn div(a: (f64, f64), b: (f64, f64)) -> (f64, f64) {
+        let t = 1. / b.0;
+        let q0 = a.0 * t;
+        let u = mla(q0, -mul_err(b.0, t, 1.), mul_err(a.0, t, q0));
+        (q0, mla(t, a.1 - q0 * b.1, u))
+    }
+
+    unsafe fn lanes(d: __m128dx2) -> [(f64, f64); 2] {
+        [
+            (_mm_extract_pd::<0>(d.0), _mm_extract_pd::<0>(d.1)),
+            (_mm_extract_pd::<1>(d.0), _mm_extract_pd::<1>(d.1)),
+        ]
+    }
+
+    fn assert_bits(v: (f64, f64), control: (f64, f64)) {
+        assert_eq!(v.0.to_bits(), control.0.to_bits());
+        assert_eq!(v.1.to_bits(), control.1.to_bits());
+    }
+
+    const VALUES: [(f64, f64); 4] = [
+        (std::f64::consts::PI, 1.2246467991473532e-16),
+        (1. / 3., 1.850371707708594e-17),
+        (-7.000000000000001, 0.),
+        (1.2345678901234567e100, -3.2e83),
+    ];
+
+    #[test]
+    fn test_mul_div_f128() {
+        unsafe {
+            for a in VALUES.iter() {
+                for b in VALUES.iter() {
+                    let va = __m128dx2(_mm_set_pd(b.0, a.0), _mm_set_pd(b.1, a.1));
+                    let vb = __m128dx2(_mm_set_pd(a.0, b.0), _mm_set_pd(a.1, b.1));
+                    let m = lanes(_mm_mul_f128(va, vb));
+                    assert_bits(m[0], mul(*a, *b));
+                    assert_bits(m[1], mul(*b, *a));
+                    let d = lanes(_mm_div_f128(va, vb));
+                    assert_bits(d[0], div(*a, *b));
+                    assert_bits(d[1], div(*b, *a));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fused_parts_exact() {
+        unsafe {
+            let mut state = 0x2545_f491_4f6c_dd1du64;
+            let mut next = || {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                let exponent = (state >> 52) % 200 + 923;
+                f64::from_bits((state & 0x800f_ffff_ffff_ffff) | (exponent << 52))
+            };
+            for _ in 0..10000 {
+                let (a, b) = ((next(), next()), (next(), next()));
+                let m = lanes(_mm_mull_pd(_mm_set_pd(b.0, a.0), _mm_set_pd(a.0, b.0)));
+                let r = a.0 * b.0;
+                assert_bits(m[0], (r, a.0.mul_add(b.0, -r)));
+                assert_bits(m[1], (r, b.0.mul_add(a.0, -r)));
+                let va = __m128dx2(_mm_set1_pd(a.0), _mm_set1_pd(a.1));
+                let vb = __m128dx2(_mm_set1_pd(b.0), _mm_set1_pd(b.1));
+                assert_bits(lanes(_mm_mul_f128(va, vb))[0], mul(a, b));
+                assert_bits(lanes(_mm_div_f128(va, vb))[1], div(a, b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_f128() {
+        unsafe {
+            let v = _mm_mull_pd(_mm_set1_pd(0.1), _mm_set1_pd(1. / 3.));
+            assert_bits(lanes(v)[0], mull(0.1, 1. / 3.));
+            assert_eq!(lanes(v)[0].1, 0.1f64.mul_add(1. / 3., -lanes(v)[0].0));
+
+            let v = _mm_addl_pd(_mm_set1_pd(1.), _mm_set1_pd(1e-20));
+            assert_bits(lanes(v)[1], (1., 1e-20));
+            let v = _mm_addw_pd(v, _mm_set1_pd(-1.));
+            assert_bits(lanes(v)[0], (0., 1e-20));
+            let v = _mm_add_f128(_mm_set1_f128(2.), _mm_neg_f128(_mm_set1_f128(0.5)));
+            assert_eq!(_mm_extract_f128::<0>(v), 1.5);
+
+            let third = _mm_div_f128(_mm_set1_f128(1.), _mm_set1_f128(3.));
+            let one = _mm_mlaf_f128(third, _mm_set1_f128(3.), _mm_set1_f128(-1.));
+            assert!(_mm_extract_f128::<0>(one).abs() < 1e-31);
+            let r = _mm_cvtf128_pd(_mm_cvtpd_f128(_mm_set1_pd(5.)));
+            assert_eq!(_mm_extract_pd::<1>(r), 5.);
+        }
+    }
+}
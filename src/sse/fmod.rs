@@ -5,19 +5,88 @@
  * // license that can be found in the LICENSE file.
  */
 
+use crate::fmod::do_fmod;
+use crate::sse::general::{
+    _mm_abs_pd, _mm_copysign_pd, _mm_ilogb2k_pd, _mm_isinf_pd, _mm_ldexp3k_pd, _mm_select_pd,
+};
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
-/// Computes fmod for f64
+#[inline(always)]
+/// Computes fmod for f64, result is exact and has the sign of `a`
 pub unsafe fn _mm_fmod_pd(a: __m128d, b: __m128d) -> __m128d {
-    let dividend_vec = a;
-    let divisor_vec = b;
-    let division = _mm_mul_pd(dividend_vec, _mm_div_pd(_mm_set1_pd(1.), divisor_vec));
-    let int_part = _mm_floor_pd(division);
-    let product = _mm_mul_pd(int_part, divisor_vec);
-    let remainder = _mm_sub_pd(dividend_vec, product);
-    remainder
+    let ax = _mm_abs_pd(a);
+    let ay = _mm_abs_pd(b);
+    let nan_mask = _mm_or_pd(
+        _mm_or_pd(_mm_cmpunord_pd(a, b), _mm_isinf_pd(a)),
+        _mm_cmpeq_pd(b, _mm_setzero_pd()),
+    );
+    // Subnormal divisors and exponent differences above 64 are left to the scalar routine
+    let fallback = _mm_andnot_pd(
+        nan_mask,
+        _mm_or_pd(
+            _mm_cmplt_pd(ay, _mm_set1_pd(f64::MIN_POSITIVE)),
+            _mm_cmpgt_pd(ax, _mm_mul_pd(ay, _mm_set1_pd(18446744073709551616.))),
+        ),
+    );
+    if _mm_movemask_pd(fallback) != 0 {
+        let mut xs = [0f64; 2];
+        let mut ys = [0f64; 2];
+        _mm_storeu_pd(xs.as_mut_ptr(), a);
+        _mm_storeu_pd(ys.as_mut_ptr(), b);
+        for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+            *x = do_fmod(*x, y);
+        }
+        return _mm_loadu_pd(xs.as_ptr());
+    }
+    let mut rem = ax;
+    let mut work = _mm_andnot_pd(nan_mask, _mm_cmpge_pd(rem, ay));
+    while _mm_movemask_pd(work) != 0 {
+        // Largest |b|*2^k not above the remainder, subtraction is exact by Sterbenz lemma
+        let k = _mm_sub_epi64(_mm_ilogb2k_pd(rem), _mm_ilogb2k_pd(ay));
+        let mut t = _mm_ldexp3k_pd(ay, k);
+        t = _mm_select_pd(_mm_cmpgt_pd(t, rem), _mm_mul_pd(t, _mm_set1_pd(0.5)), t);
+        rem = _mm_select_pd(work, _mm_sub_pd(rem, t), rem);
+        work = _mm_and_pd(work, _mm_cmpge_pd(rem, ay));
+    }
+    let ret = _mm_copysign_pd(rem, a);
+    _mm_select_pd(nan_mask, _mm_set1_pd(f64::NAN), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_fmodd() {
+        let values = [
+            (7., 2.),
+            (5.5, 2.5),
+            (-7., 2.),
+            (7., -2.),
+            (1e300, 3.),
+            (-1e300, 1e-300),
+            (3.5e-310, 1e-315),
+            (0.1, 1e308),
+            (-0., 3.),
+        ];
+        for (x, y) in values {
+            unsafe {
+                let comparison = _mm_fmod_pd(_mm_set1_pd(x), _mm_set1_pd(y));
+                let flag_1 = _mm_extract_pd::<0>(comparison);
+                assert_eq!(flag_1.to_bits(), (x % y).to_bits());
+            }
+        }
+        unsafe {
+            let comparison = _mm_fmod_pd(_mm_set1_pd(f64::INFINITY), _mm_set1_pd(2.));
+            assert!(_mm_extract_pd::<0>(comparison).is_nan());
+            let comparison = _mm_fmod_pd(_mm_set1_pd(2.), _mm_set1_pd(0.));
+            assert!(_mm_extract_pd::<0>(comparison).is_nan());
+            let comparison = _mm_fmod_pd(_mm_set1_pd(2.), _mm_set1_pd(f64::INFINITY));
+            assert_eq!(_mm_extract_pd::<0>(comparison), 2.);
+        }
+    }
 }
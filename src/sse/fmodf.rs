@@ -4,20 +4,86 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
+use crate::fmodf::do_fmodf;
+use crate::sse::generalf::{
+    _mm_abs_ps, _mm_copysign_ps, _mm_ilogb2kq_ps, _mm_isinf_ps, _mm_ldexp3kq_ps, _mm_select_ps,
+};
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
-
-/// Computes fmod for f32
+#[inline(always)]
+/// Computes fmod for f32, result is exact and has the sign of `a`
 pub unsafe fn _mm_fmod_ps(a: __m128, b: __m128) -> __m128 {
-    let dividend_vec = a;
-    let divisor_vec = b;
-    let division = _mm_mul_ps(dividend_vec, _mm_div_ps(_mm_set1_ps(1.), divisor_vec));
-    let int_part = _mm_floor_ps(division);
-    let product = _mm_mul_ps(int_part, divisor_vec);
-    let remainder = _mm_sub_ps(dividend_vec, product);
-    remainder
+    let ax = _mm_abs_ps(a);
+    let ay = _mm_abs_ps(b);
+    let nan_mask = _mm_or_ps(
+        _mm_or_ps(_mm_cmpunord_ps(a, b), _mm_isinf_ps(a)),
+        _mm_cmpeq_ps(b, _mm_setzero_ps()),
+    );
+    // Subnormal divisors and exponent differences above 32 are left to the scalar routine
+    let fallback = _mm_andnot_ps(
+        nan_mask,
+        _mm_or_ps(
+            _mm_cmplt_ps(ay, _mm_set1_ps(f32::MIN_POSITIVE)),
+            _mm_cmpgt_ps(ax, _mm_mul_ps(ay, _mm_set1_ps(4294967296.))),
+        ),
+    );
+    if _mm_movemask_ps(fallback) != 0 {
+        let mut xs = [0f32; 4];
+        let mut ys = [0f32; 4];
+        _mm_storeu_ps(xs.as_mut_ptr(), a);
+        _mm_storeu_ps(ys.as_mut_ptr(), b);
+        for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+            *x = do_fmodf(*x, y);
+        }
+        return _mm_loadu_ps(xs.as_ptr());
+    }
+    let mut rem = ax;
+    let mut work = _mm_andnot_ps(nan_mask, _mm_cmpge_ps(rem, ay));
+    while _mm_movemask_ps(work) != 0 {
+        // Largest |b|*2^k not above the remainder, subtraction is exact by Sterbenz lemma
+        let k = _mm_sub_epi32(_mm_ilogb2kq_ps(rem), _mm_ilogb2kq_ps(ay));
+        let mut t = _mm_ldexp3kq_ps(ay, k);
+        t = _mm_select_ps(_mm_cmpgt_ps(t, rem), _mm_mul_ps(t, _mm_set1_ps(0.5)), t);
+        rem = _mm_select_ps(work, _mm_sub_ps(rem, t), rem);
+        work = _mm_and_ps(work, _mm_cmpge_ps(rem, ay));
+    }
+    let ret = _mm_copysign_ps(rem, a);
+    _mm_select_ps(nan_mask, _mm_set1_ps(f32::NAN), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_fmodf() {
+        let values = [
+            (7., 2.),
+            (5.5, 2.5),
+            (-7., 2.),
+            (7., -2.),
+            (1e38, 3.),
+            (-1e38, 1e-38),
+            (3.5e-40, 1e-44),
+            (0.1, 1e38),
+            (-0., 3.),
+        ];
+        for (x, y) in values {
+            unsafe {
+                let comparison = _mm_fmod_ps(_mm_set1_ps(x), _mm_set1_ps(y));
+                let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+                assert_eq!(flag_1.to_bits(), (x % y).to_bits());
+            }
+        }
+        unsafe {
+            let comparison = _mm_fmod_ps(_mm_set1_ps(f32::INFINITY), _mm_set1_ps(2.));
+            assert!(f32::from_bits(_mm_extract_psx::<0>(comparison) as u32).is_nan());
+            let comparison = _mm_fmod_ps(_mm_set1_ps(2.), _mm_set1_ps(f32::INFINITY));
+            assert_eq!(f32::from_bits(_mm_extract_psx::<0>(comparison) as u32), 2.);
+        }
+    }
 }
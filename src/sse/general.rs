@@ -4,6 +4,7 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
+use crate::generalf::PAYNE_HANEK_THRESHOLD;
 use crate::shuffle::_mm_shuffle;
 use crate::sse::epi64::{_mm_sllv_epi64x, _mm_srlv_epi64x};
 #[cfg(target_arch = "x86")]
@@ -11,6 +12,8 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use crate::{_mm_blendv_pdx, _mm_floor_pdx, _mm_round_pdx};
+
 #[inline(always)]
 /// Founds n in x=a+𝑛ln(2), |a| <= 1
 pub unsafe fn _mm_ilogb2k_pd(d: __m128d) -> __m128i {
@@ -29,6 +32,15 @@ pub unsafe fn _mm_ldexp3k_pd(x: __m128d, n: __m128i) -> __m128d {
     _mm_castsi128_pd(_mm_add_epi64(_mm_castpd_si128(x), _mm_slli_epi64::<52>(n)))
 }
 
+#[inline(always)]
+/// Computes `x*2^n` with two scalings, so `n` may reach subnormal and near overflow exponents
+pub unsafe fn _mm_ldexp2k_pd(x: __m128d, n: __m128i) -> __m128d {
+    // Shifting 32 bit halves is exact while 64 bit lanes hold values in i32 range
+    let m = _mm_srai_epi32::<1>(n);
+    let u = _mm_mul_pd(x, _mm_castsi128_pd(_mm_pow2i_epi64(m)));
+    _mm_mul_pd(u, _mm_castsi128_pd(_mm_pow2i_epi64(_mm_sub_epi64(n, m))))
+}
+
 #[inline(always)]
 /// Computes `a*b + c`
 pub unsafe fn _mm_mlaf_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
@@ -36,14 +48,14 @@ pub unsafe fn _mm_mlaf_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
 }
 
 #[cfg(not(target_feature = "fma"))]
-#[inline]
+#[inline(always)]
 /// Computes `b*c + a` using fma when available
 pub unsafe fn _mm_prefer_fma_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
     return _mm_add_pd(_mm_mul_pd(b, c), a);
 }
 
 #[cfg(target_feature = "fma")]
-#[inline]
+#[inline(always)]
 /// Computes `b*c + a` using fma when available
 pub unsafe fn _mm_prefer_fma_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
     return _mm_fmadd_pd(b, c, a);
@@ -64,13 +76,13 @@ pub unsafe fn _mm_neg_pd(f: __m128d) -> __m128d {
     _mm_sub_pd(_mm_set1_pd(0.), f)
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is Infinity
 pub unsafe fn _mm_isinf_pd(d: __m128d) -> __m128d {
     _mm_cmpeq_pd(_mm_abs_pd(d), _mm_set1_pd(f64::INFINITY))
 }
 
-#[inline]
+#[inline(always)]
 /// Extracts f64 value
 pub unsafe fn _mm_extract_pd<const IMM: i32>(d: __m128d) -> f64 {
     #[cfg(target_arch = "x86_64")]
@@ -78,7 +90,7 @@ pub unsafe fn _mm_extract_pd<const IMM: i32>(d: __m128d) -> f64 {
         return if IMM == 0 {
             f64::from_bits(_mm_cvtsi128_si64(_mm_castpd_si128(d)) as u64)
         } else {
-            f64::from_bits(_mm_extract_epi64::<IMM>(_mm_castpd_si128(d)) as u64)
+            _mm_cvtsd_f64(_mm_unpackhi_pd(d, d))
         };
     }
     #[cfg(target_arch = "x86")]
@@ -96,44 +108,44 @@ pub unsafe fn _mm_extract_pd<const IMM: i32>(d: __m128d) -> f64 {
     }
 }
 
-#[inline]
+#[inline(always)]
 /// Returns true flag if value is NaN
 pub unsafe fn _mm_isnan_pd(d: __m128d) -> __m128d {
     return _mm_cmpneq_pd(d, d);
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is zero
 pub unsafe fn _mm_eqzero_pd(d: __m128d) -> __m128d {
     return _mm_cmpeq_pd(d, _mm_set1_pd(0.));
 }
 
-#[inline]
+#[inline(always)]
 /// If mask then `true_vals` otherwise `false_val`
 pub unsafe fn _mm_select_pd(mask: __m128d, true_vals: __m128d, false_vals: __m128d) -> __m128d {
-    _mm_blendv_pd(false_vals, true_vals, mask)
+    _mm_blendv_pdx(false_vals, true_vals, mask)
 }
 
-#[inline]
+#[inline(always)]
 /// If mask then `true_vals` otherwise `false_val`
 pub unsafe fn _mm_selecti_pd(mask: __m128i, true_vals: __m128d, false_vals: __m128d) -> __m128d {
-    _mm_blendv_pd(false_vals, true_vals, _mm_castsi128_pd(mask))
+    _mm_blendv_pdx(false_vals, true_vals, _mm_castsi128_pd(mask))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is lower than zero
 pub unsafe fn _mm_ltzero_pd(d: __m128d) -> __m128d {
     return _mm_cmplt_pd(d, _mm_set1_pd(0.));
 }
 
-#[inline]
+#[inline(always)]
 /// Computes 2^n in f64 form for signed 64 bits integers, returns f64 in bits
 pub unsafe fn _mm_pow2i_epi64(n: __m128i) -> __m128i {
     let j = _mm_slli_epi64::<52>(_mm_add_epi64(n, _mm_set1_epi32(0x3ff)));
     j
 }
 
-#[inline]
+#[inline(always)]
 /// Converts double into signed 64 bytes int with truncation
 pub unsafe fn _mm_cvtpd_epi64(v: __m128d) -> __m128i {
     let k51 = _mm_set1_epi64x(51 + 0x3FF);
@@ -180,7 +192,7 @@ pub unsafe fn _mm_cvtpd_epi64(v: __m128d) -> __m128i {
     )
 }
 
-#[inline]
+#[inline(always)]
 /// Converts double into unsigned int 64 bytes with truncation
 pub unsafe fn _mm_cvtpd_epu64(v: __m128d) -> __m128i {
     let k51 = _mm_set1_epi64x(51 + 0x3FF);
@@ -227,16 +239,16 @@ pub unsafe fn _mm_cvtpd_epu64(v: __m128d) -> __m128i {
     return fully_bounded;
 }
 
-#[inline]
+#[inline(always)]
 
 /// Rounds and takes integral part 64 bytes from double
 pub unsafe fn _mm_rint_pd(f: __m128d) -> __m128i {
     const ROUNDING_FLAG: i32 = _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC;
-    let k = _mm_round_pd::<ROUNDING_FLAG>(f);
+    let k = _mm_round_pdx::<ROUNDING_FLAG>(f);
     _mm_cvtpd_epi64(k)
 }
 
-#[inline]
+#[inline(always)]
 /// Copies sign from `y` to `x`
 pub unsafe fn _mm_copysign_pd(x: __m128d, y: __m128d) -> __m128d {
     _mm_castsi128_pd(_mm_xor_si128(
@@ -245,22 +257,97 @@ pub unsafe fn _mm_copysign_pd(x: __m128d, y: __m128d) -> __m128d {
     ))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is Neg Infinity
 pub unsafe fn _mm_isneginf_pd(d: __m128d) -> __m128d {
     return _mm_cmpeq_pd(d, _mm_set1_pd(f64::NEG_INFINITY));
 }
 
-#[inline]
+#[inline(always)]
 /// Checks if arguments is integral value
 pub unsafe fn _mm_isintegral_pd(d: __m128d) -> __m128d {
-    return _mm_cmpeq_pd(d, _mm_floor_pd(d));
+    return _mm_cmpeq_pd(d, _mm_floor_pdx(d));
 }
 
-#[inline]
+#[inline(always)]
 /// Checks if arguments is not integral value
 pub unsafe fn _mm_isnotintegral_pd(d: __m128d) -> __m128d {
-    return _mm_cmpneq_pd(d, _mm_floor_pd(d));
+    return _mm_cmpneq_pd(d, _mm_floor_pdx(d));
+}
+
+#[inline(always)]
+/// Replaces Cody-Waite reduction with Payne-Hanek `reduce` in lanes above threshold
+pub(crate) unsafe fn _mm_rempi_pd(
+    d: __m128d,
+    q: __m128i,
+    r: __m128d,
+    reduce: fn(f64) -> (i32, f64),
+) -> (__m128i, __m128d) {
+    let threshold = _mm_set1_pd(PAYNE_HANEK_THRESHOLD);
+    if _mm_movemask_pd(_mm_cmpgt_pd(_mm_abs_pd(d), threshold)) == 0 {
+        return (q, r);
+    }
+    let mut ds = [0f64; 2];
+    let mut qs = [0i64; 2];
+    let mut rs = [0f64; 2];
+    _mm_storeu_pd(ds.as_mut_ptr(), d);
+    _mm_storeu_si128(qs.as_mut_ptr() as *mut __m128i, q);
+    _mm_storeu_pd(rs.as_mut_ptr(), r);
+    for ((&d, q), r) in ds.iter().zip(qs.iter_mut()).zip(rs.iter_mut()) {
+        if d.abs() > PAYNE_HANEK_THRESHOLD && d.is_finite() {
+            let (lq, lr) = reduce(d);
+            *q = lq as i64;
+            *r = lr;
+        }
+    }
+    (
+        _mm_loadu_si128(qs.as_ptr() as *const __m128i),
+        _mm_loadu_pd(rs.as_ptr()),
+    )
+}
+
+#[inline(always)]
+/// Computes a * b as double-float, see `multiply_as_doubled`
+pub(crate) unsafe fn _mm_multiply_as_doubled_pd(a: __m128d, b: __m128d) -> (__m128d, __m128d) {
+    let xh = _mm_and_pd(
+        a,
+        _mm_castsi128_pd(_mm_set1_epi64x(0xffff_ffff_f800_0000u64 as i64)),
+    );
+    let xl = _mm_sub_pd(a, xh);
+    let yh = _mm_and_pd(
+        b,
+        _mm_castsi128_pd(_mm_set1_epi64x(0xffff_ffff_f800_0000u64 as i64)),
+    );
+    let yl = _mm_sub_pd(b, yh);
+    let r0 = _mm_mul_pd(a, b);
+    let mut d1 = _mm_sub_pd(_mm_mul_pd(xh, yh), r0);
+    d1 = _mm_mlaf_pd(xl, yh, d1);
+    d1 = _mm_mlaf_pd(xh, yl, d1);
+    (r0, _mm_mlaf_pd(xl, yl, d1))
+}
+
+#[inline(always)]
+/// Multiplies two double-floats, see `mul_doubled`
+pub(crate) unsafe fn _mm_mul_doubled_pd(
+    a: (__m128d, __m128d),
+    b: (__m128d, __m128d),
+) -> (__m128d, __m128d) {
+    let m = _mm_multiply_as_doubled_pd(a.0, b.0);
+    (
+        m.0,
+        _mm_add_pd(m.1, _mm_mlaf_pd(a.0, b.1, _mm_mul_pd(a.1, b.0))),
+    )
+}
+
+#[inline(always)]
+/// Computes a + b as double-float, see `sum_as_doubled`
+pub(crate) unsafe fn _mm_sum_as_doubled_pd(a: __m128d, b: __m128d) -> (__m128d, __m128d) {
+    let s = _mm_add_pd(a, b);
+    let v = _mm_sub_pd(s, a);
+    (
+        s,
+        _mm_add_pd(_mm_sub_pd(a, _mm_sub_pd(s, v)), _mm_sub_pd(b, v)),
+    )
 }
 
 #[cfg(test)]
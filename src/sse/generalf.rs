@@ -5,12 +5,15 @@
  * // license that can be found in the LICENSE file.
  */
 
+use crate::generalf::PAYNE_HANEK_THRESHOLD_F;
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
+use crate::{_mm_blendv_psx, _mm_floor_psx, _mm_round_psx};
+
+#[inline(always)]
 /// Founds n in x=a+𝑛ln(2), |a| <= 1
 pub unsafe fn _mm_ilogb2kq_ps(d: __m128) -> __m128i {
     _mm_sub_epi32(
@@ -22,27 +25,35 @@ pub unsafe fn _mm_ilogb2kq_ps(d: __m128) -> __m128i {
     )
 }
 
-#[inline]
+#[inline(always)]
 /// Founds a in x=a+𝑛ln(2), |a| <= 1
 pub unsafe fn _mm_ldexp3kq_ps(x: __m128, n: __m128i) -> __m128 {
     _mm_castsi128_ps(_mm_add_epi32(_mm_castps_si128(x), _mm_slli_epi32::<23>(n)))
 }
 
+#[inline(always)]
+/// Computes `x*2^n` with two scalings, so `n` may reach subnormal and near overflow exponents
+pub unsafe fn _mm_ldexp2kq_ps(x: __m128, n: __m128i) -> __m128 {
+    let m = _mm_srai_epi32::<1>(n);
+    let u = _mm_mul_ps(x, _mm_castsi128_ps(_mm_pow2if_epi32(m)));
+    _mm_mul_ps(u, _mm_castsi128_ps(_mm_pow2if_epi32(_mm_sub_epi32(n, m))))
+}
+
 #[cfg(not(target_feature = "fma"))]
-#[inline]
+#[inline(always)]
 /// Computes `b*c + a` using fma when available
 pub unsafe fn _mm_prefer_fma_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
     return _mm_add_ps(_mm_mul_ps(b, c), a);
 }
 
 #[cfg(target_feature = "fma")]
-#[inline]
+#[inline(always)]
 /// Computes `b*c + a` using fma when available
 pub unsafe fn _mm_prefer_fma_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
     return _mm_fmadd_ps(b, c, a);
 }
 
-#[inline]
+#[inline(always)]
 /// Computes `a*b + c`
 pub unsafe fn _mm_mlaf_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
     _mm_prefer_fma_ps(c, b, a)
@@ -51,46 +62,46 @@ pub unsafe fn _mm_mlaf_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
 #[inline(always)]
 /// If mask then `true_vals` otherwise `false_val`
 pub unsafe fn _mm_select_ps(mask: __m128, true_vals: __m128, false_vals: __m128) -> __m128 {
-    _mm_blendv_ps(false_vals, true_vals, mask)
+    _mm_blendv_psx(false_vals, true_vals, mask)
 }
 
-#[inline]
+#[inline(always)]
 /// If mask then `true_vals` otherwise `false_val`
 pub unsafe fn _mm_selecti_ps(mask: __m128i, true_vals: __m128, false_vals: __m128) -> __m128 {
-    _mm_blendv_ps(false_vals, true_vals, _mm_castsi128_ps(mask))
+    _mm_blendv_psx(false_vals, true_vals, _mm_castsi128_ps(mask))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is Infinity
 pub unsafe fn _mm_isinf_ps(d: __m128) -> __m128 {
     _mm_cmpeq_ps(_mm_abs_ps(d), _mm_set1_ps(f32::INFINITY))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is Neg Infinity
 pub unsafe fn _mm_isneginf_ps(d: __m128) -> __m128 {
     _mm_cmpeq_ps(d, _mm_set1_ps(f32::NEG_INFINITY))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is zero
 pub unsafe fn _mm_eqzero_ps(d: __m128) -> __m128 {
     _mm_cmpeq_ps(d, _mm_set1_ps(0.))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns flag value is lower than zero
 pub unsafe fn _mm_ltzero_ps(d: __m128) -> __m128 {
     _mm_cmplt_ps(d, _mm_set1_ps(0.))
 }
 
-#[inline]
+#[inline(always)]
 /// Returns true flag if value is NaN
 pub unsafe fn _mm_isnan_ps(d: __m128) -> __m128 {
     _mm_cmpneq_ps(d, d)
 }
 
-#[inline]
+#[inline(always)]
 /// Modulus operator for f32
 pub unsafe fn _mm_abs_ps(f: __m128) -> __m128 {
     _mm_castsi128_ps(_mm_andnot_si128(
@@ -99,28 +110,28 @@ pub unsafe fn _mm_abs_ps(f: __m128) -> __m128 {
     ))
 }
 
-#[inline]
+#[inline(always)]
 /// Negates value
 pub unsafe fn _mm_neg_ps(f: __m128) -> __m128 {
     _mm_sub_ps(_mm_set1_ps(0.), f)
 }
 
-#[inline]
+#[inline(always)]
 /// Rounds and takes integral part from float
 pub unsafe fn _mm_rint_ps(f: __m128) -> __m128i {
     const ROUNDING_FLAG: i32 = _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC;
-    let k = _mm_round_ps::<ROUNDING_FLAG>(f);
+    let k = _mm_round_psx::<ROUNDING_FLAG>(f);
     _mm_cvtps_epi32(k)
 }
 
-#[inline]
+#[inline(always)]
 /// Computes 2^n in f32 form for signed 32 bits integers, returns f32 in bits
 pub unsafe fn _mm_pow2if_epi32(n: __m128i) -> __m128i {
     let j = _mm_slli_epi32::<23>(_mm_add_epi32(n, _mm_set1_epi32(0x7f)));
     j
 }
 
-#[inline]
+#[inline(always)]
 /// Copies sign from `y` to `x`
 pub unsafe fn _mm_copysign_ps(x: __m128, y: __m128) -> __m128 {
     _mm_castsi128_ps(_mm_xor_si128(
@@ -129,22 +140,90 @@ pub unsafe fn _mm_copysign_ps(x: __m128, y: __m128) -> __m128 {
     ))
 }
 
-#[inline]
+#[inline(always)]
 
 /// Checks if arguments is integral value
 pub unsafe fn _mm_isintegral_ps(d: __m128) -> __m128 {
-    _mm_cmpeq_ps(d, _mm_floor_ps(d))
+    _mm_cmpeq_ps(d, _mm_floor_psx(d))
 }
 
-#[inline]
+#[inline(always)]
 /// Checks if arguments is not integral value
 pub unsafe fn _mm_isnotintegral_ps(d: __m128) -> __m128 {
-    _mm_cmpneq_ps(d, _mm_floor_ps(d))
+    _mm_cmpneq_ps(d, _mm_floor_psx(d))
+}
+
+#[inline(always)]
+/// Replaces Cody-Waite reduction with Payne-Hanek `reduce` in lanes above threshold
+pub(crate) unsafe fn _mm_rempi_ps(
+    d: __m128,
+    q: __m128i,
+    r: __m128,
+    reduce: fn(f32) -> (i32, f32),
+) -> (__m128i, __m128) {
+    let threshold = _mm_set1_ps(PAYNE_HANEK_THRESHOLD_F);
+    if _mm_movemask_ps(_mm_cmpgt_ps(_mm_abs_ps(d), threshold)) == 0 {
+        return (q, r);
+    }
+    let mut ds = [0f32; 4];
+    let mut qs = [0i32; 4];
+    let mut rs = [0f32; 4];
+    _mm_storeu_ps(ds.as_mut_ptr(), d);
+    _mm_storeu_si128(qs.as_mut_ptr() as *mut __m128i, q);
+    _mm_storeu_ps(rs.as_mut_ptr(), r);
+    for ((&d, q), r) in ds.iter().zip(qs.iter_mut()).zip(rs.iter_mut()) {
+        if d.abs() > PAYNE_HANEK_THRESHOLD_F && d.is_finite() {
+            (*q, *r) = reduce(d);
+        }
+    }
+    (
+        _mm_loadu_si128(qs.as_ptr() as *const __m128i),
+        _mm_loadu_ps(rs.as_ptr()),
+    )
+}
+
+#[inline(always)]
+/// Computes a * b as double-float, see `multiply_as_doubled`
+pub(crate) unsafe fn _mm_multiply_as_doubled_ps(a: __m128, b: __m128) -> (__m128, __m128) {
+    let xh = _mm_and_ps(a, _mm_castsi128_ps(_mm_set1_epi32(0xffff_f000u32 as i32)));
+    let xl = _mm_sub_ps(a, xh);
+    let yh = _mm_and_ps(b, _mm_castsi128_ps(_mm_set1_epi32(0xffff_f000u32 as i32)));
+    let yl = _mm_sub_ps(b, yh);
+    let r0 = _mm_mul_ps(a, b);
+    let mut d1 = _mm_sub_ps(_mm_mul_ps(xh, yh), r0);
+    d1 = _mm_mlaf_ps(xl, yh, d1);
+    d1 = _mm_mlaf_ps(xh, yl, d1);
+    (r0, _mm_mlaf_ps(xl, yl, d1))
+}
+
+#[inline(always)]
+/// Multiplies two double-floats, see `mul_doubled`
+pub(crate) unsafe fn _mm_mul_doubled_ps(
+    a: (__m128, __m128),
+    b: (__m128, __m128),
+) -> (__m128, __m128) {
+    let m = _mm_multiply_as_doubled_ps(a.0, b.0);
+    (
+        m.0,
+        _mm_add_ps(m.1, _mm_mlaf_ps(a.0, b.1, _mm_mul_ps(a.1, b.0))),
+    )
+}
+
+#[inline(always)]
+/// Computes a + b as double-float, see `sum_as_doubled`
+pub(crate) unsafe fn _mm_sum_as_doubled_ps(a: __m128, b: __m128) -> (__m128, __m128) {
+    let s = _mm_add_ps(a, b);
+    let v = _mm_sub_ps(s, a);
+    (
+        s,
+        _mm_add_ps(_mm_sub_ps(a, _mm_sub_ps(s, v)), _mm_sub_ps(b, v)),
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_if_inf() {
@@ -152,7 +231,7 @@ mod tests {
             // Test regular
             let value = _mm_set1_ps(23f32);
             let comparison = _mm_isinf_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_eq!(flag, 0);
         }
 
@@ -160,7 +239,7 @@ mod tests {
             // Test negative regular
             let value = _mm_set1_ps(-23f32);
             let comparison = _mm_isinf_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_eq!(flag, 0);
         }
 
@@ -168,7 +247,7 @@ mod tests {
             // Test Infinity
             let value = _mm_set1_ps(f32::INFINITY);
             let comparison = _mm_isinf_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_ne!(flag, 0);
         }
     }
@@ -179,7 +258,7 @@ mod tests {
             // Test regular
             let value = _mm_set1_ps(23f32);
             let comparison = _mm_isneginf_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_eq!(flag, 0);
         }
 
@@ -187,7 +266,7 @@ mod tests {
             // Test negative regular
             let value = _mm_set1_ps(-23f32);
             let comparison = _mm_isneginf_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_eq!(flag, 0);
         }
 
@@ -195,7 +274,7 @@ mod tests {
             // Test Infinity
             let value = _mm_set1_ps(f32::INFINITY);
             let comparison = _mm_isneginf_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_eq!(flag, 0);
         }
 
@@ -203,7 +282,7 @@ mod tests {
             // Test Infinity
             let value = _mm_set1_ps(f32::NEG_INFINITY);
             let comparison = _mm_isneginf_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_ne!(flag, 0);
         }
     }
@@ -214,7 +293,7 @@ mod tests {
             let value = _mm_set1_ps(23f32);
             let other = _mm_set1_ps(-2f32);
             let comparison = _mm_copysign_ps(value, other);
-            let flag = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag, -23f32);
         }
 
@@ -222,7 +301,7 @@ mod tests {
             let value = _mm_set1_ps(23f32);
             let other = _mm_set1_ps(2f32);
             let comparison = _mm_copysign_ps(value, other);
-            let flag = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag, 23f32);
         }
     }
@@ -233,7 +312,7 @@ mod tests {
             // Test regular
             let value = _mm_set1_ps(23f32);
             let comparison = _mm_abs_ps(value);
-            let flag = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag, 23f32);
         }
 
@@ -241,7 +320,7 @@ mod tests {
             // Test negative regular
             let value = _mm_set1_ps(-23f32);
             let comparison = _mm_abs_ps(value);
-            let flag = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag, 23f32);
         }
 
@@ -249,7 +328,7 @@ mod tests {
             // Test Infinity
             let value = _mm_set1_ps(f32::NEG_INFINITY);
             let comparison = _mm_abs_ps(value);
-            let flag = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag.is_infinite(), true);
         }
 
@@ -257,7 +336,7 @@ mod tests {
             // Test Infinity
             let value = _mm_set1_ps(f32::INFINITY);
             let comparison = _mm_abs_ps(value);
-            let flag = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag.is_infinite(), true);
         }
 
@@ -265,7 +344,7 @@ mod tests {
             // Test NaN
             let value = _mm_set1_ps(f32::NAN);
             let comparison = _mm_abs_ps(value);
-            let flag = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag.is_nan(), true);
         }
     }
@@ -276,7 +355,7 @@ mod tests {
             // Test regular
             let value = _mm_set1_ps(23f32);
             let comparison = _mm_isnan_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_eq!(flag, 0);
         }
 
@@ -284,7 +363,7 @@ mod tests {
             // Test negative regular
             let value = _mm_set1_ps(-23f32);
             let comparison = _mm_isnan_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_eq!(flag, 0);
         }
 
@@ -292,7 +371,7 @@ mod tests {
             // Test Infinity
             let value = _mm_set1_ps(f32::NEG_INFINITY);
             let comparison = _mm_isnan_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_eq!(flag, 0);
         }
 
@@ -300,7 +379,7 @@ mod tests {
             // Test Infinity
             let value = _mm_set1_ps(f32::INFINITY);
             let comparison = _mm_isnan_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_eq!(flag, 0);
         }
 
@@ -308,7 +387,7 @@ mod tests {
             // Test NaN
             let value = _mm_set1_ps(f32::NAN);
             let comparison = _mm_isnan_ps(value);
-            let flag = _mm_extract_ps::<0>(comparison) as u32;
+            let flag = _mm_extract_psx::<0>(comparison) as u32;
             assert_ne!(flag, 0);
         }
     }
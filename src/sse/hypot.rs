@@ -12,7 +12,7 @@ use std::arch::x86_64::*;
 
 use crate::{_mm_abs_pd, _mm_eqzero_pd, _mm_isinf_pd, _mm_isnan_pd, _mm_mlaf_pd, _mm_select_pd};
 
-#[inline]
+#[inline(always)]
 /// Method that computes 2D Euclidian distance *ULP 0.6667*
 pub unsafe fn _mm_hypot_pd(x: __m128d, y: __m128d) -> __m128d {
     let x = _mm_abs_pd(x);
@@ -24,14 +24,14 @@ pub unsafe fn _mm_hypot_pd(x: __m128d, y: __m128d) -> __m128d {
     let is_any_infinite = _mm_or_pd(_mm_isinf_pd(x), _mm_isinf_pd(y));
     let mut is_any_nan = _mm_or_pd(_mm_isnan_pd(x), _mm_isnan_pd(y));
     let is_min_zero = _mm_eqzero_pd(min);
+    ret = _mm_select_pd(is_min_zero, max, ret);
     is_any_nan = _mm_or_pd(_mm_isnan_pd(ret), is_any_nan);
     ret = _mm_select_pd(is_any_nan, _mm_set1_pd(f64::NAN), ret);
     ret = _mm_select_pd(is_any_infinite, _mm_set1_pd(f64::INFINITY), ret);
-    ret = _mm_select_pd(is_min_zero, _mm_setzero_pd(), ret);
     ret
 }
 
-#[inline]
+#[inline(always)]
 /// Method that computes 2D Euclidian distance *ULP 0.6667*, skipping Inf, Nan checks
 pub unsafe fn _mm_hypot_fast_pd(x: __m128d, y: __m128d) -> __m128d {
     let x = _mm_abs_pd(x);
@@ -41,7 +41,7 @@ pub unsafe fn _mm_hypot_fast_pd(x: __m128d, y: __m128d) -> __m128d {
     let r = _mm_div_pd(min, max);
     let is_min_zero = _mm_eqzero_pd(min);
     let mut ret = _mm_mul_pd(_mm_sqrt_pd(_mm_mlaf_pd(r, r, _mm_set1_pd(1.))), max);
-    ret = _mm_select_pd(is_min_zero, _mm_setzero_pd(), ret);
+    ret = _mm_select_pd(is_min_zero, max, ret);
     ret
 }
 
@@ -61,5 +61,13 @@ mod tests {
             let flag_1 = _mm_extract_pd::<0>(comparison);
             assert_eq!(flag_1, 5.);
         }
+        unsafe {
+            // Test zero operand
+            let vx = _mm_set1_pd(-5.);
+            let vy = _mm_set1_pd(0.);
+            let comparison = _mm_hypot_pd(vx, vy);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 5.);
+        }
     }
 }
@@ -12,7 +12,7 @@ use std::arch::x86_64::*;
 
 use crate::{_mm_abs_pd, _mm_eqzero_pd, _mm_isinf_pd, _mm_isnan_pd, _mm_mlaf_pd, _mm_select_pd};
 
-#[inline]
+#[inline(always)]
 /// Method that computes 3D Euclidian distance *ULP 0.66667*
 pub unsafe fn _mm_hypot3_pd(x: __m128d, y: __m128d, z: __m128d) -> __m128d {
     let x = _mm_abs_pd(x);
@@ -40,7 +40,7 @@ pub unsafe fn _mm_hypot3_pd(x: __m128d, y: __m128d, z: __m128d) -> __m128d {
     ret
 }
 
-#[inline]
+#[inline(always)]
 /// Method that computes 3D Euclidian distance *ULP 0.66667*, skipping Inf, Nan checks
 pub unsafe fn _mm_hypot3_fast_pd(x: __m128d, y: __m128d, z: __m128d) -> __m128d {
     let x = _mm_abs_pd(x);
@@ -11,7 +11,7 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
+#[inline(always)]
 /// Method that computes 3D Euclidian distance *ULP 0.6667*
 pub unsafe fn _mm_hypot3_ps(x: __m128, y: __m128, z: __m128) -> __m128 {
     let x = _mm_abs_ps(x);
@@ -40,7 +40,7 @@ pub unsafe fn _mm_hypot3_ps(x: __m128, y: __m128, z: __m128) -> __m128 {
 }
 
 /// Method that computes 3D Euclidian distance *ULP 0.6666*, skipping Inf, Nan checks
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_hypot3_fast_ps(x: __m128, y: __m128, z: __m128) -> __m128 {
     let x = _mm_abs_ps(x);
     let y = _mm_abs_ps(y);
@@ -66,6 +66,7 @@ pub unsafe fn _mm_hypot3_fast_ps(x: __m128, y: __m128, z: __m128) -> __m128 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_hypot3f_sse() {
@@ -75,18 +76,18 @@ mod tests {
             let vy = _mm_set1_ps(4.);
             let vz = _mm_set1_ps(5.);
             let comparison = _mm_hypot3_ps(vx, vy, vz);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(
                 flag_1,
-                f32::from_bits(_mm_extract_ps::<1>(comparison) as u32)
+                f32::from_bits(_mm_extract_psx::<1>(comparison) as u32)
             );
             assert_eq!(
                 flag_1,
-                f32::from_bits(_mm_extract_ps::<2>(comparison) as u32)
+                f32::from_bits(_mm_extract_psx::<2>(comparison) as u32)
             );
             assert_eq!(
                 flag_1,
-                f32::from_bits(_mm_extract_ps::<3>(comparison) as u32)
+                f32::from_bits(_mm_extract_psx::<3>(comparison) as u32)
             );
             assert_eq!(flag_1, 7.0710678118654752440f32);
         }
@@ -0,0 +1,241 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::digamma::{DIGAMMA_ROOT_D, DIGAMMA_ROOT_LO_D, DIGAMMA_ROOT_M1_D, DIGAMMA_ROOT_M1_LO_D};
+use crate::lgamma::{
+    LGAMMA_A_POLY_10_D, LGAMMA_A_POLY_11_D, LGAMMA_A_POLY_12_D, LGAMMA_A_POLY_13_D,
+    LGAMMA_A_POLY_14_D, LGAMMA_A_POLY_15_D, LGAMMA_A_POLY_16_D, LGAMMA_A_POLY_17_D,
+    LGAMMA_A_POLY_18_D, LGAMMA_A_POLY_19_D, LGAMMA_A_POLY_1_D, LGAMMA_A_POLY_2_D,
+    LGAMMA_A_POLY_3_D, LGAMMA_A_POLY_4_D, LGAMMA_A_POLY_5_D, LGAMMA_A_POLY_6_D, LGAMMA_A_POLY_7_D,
+    LGAMMA_A_POLY_8_D, LGAMMA_A_POLY_9_D, LGAMMA_B_POLY_10_D, LGAMMA_B_POLY_11_D,
+    LGAMMA_B_POLY_12_D, LGAMMA_B_POLY_13_D, LGAMMA_B_POLY_14_D, LGAMMA_B_POLY_15_D,
+    LGAMMA_B_POLY_16_D, LGAMMA_B_POLY_17_D, LGAMMA_B_POLY_18_D, LGAMMA_B_POLY_19_D,
+    LGAMMA_B_POLY_1_D, LGAMMA_B_POLY_2_D, LGAMMA_B_POLY_3_D, LGAMMA_B_POLY_4_D, LGAMMA_B_POLY_5_D,
+    LGAMMA_B_POLY_6_D, LGAMMA_B_POLY_7_D, LGAMMA_B_POLY_8_D, LGAMMA_B_POLY_9_D, LGAMMA_H_POLY_10_D,
+    LGAMMA_H_POLY_11_D, LGAMMA_H_POLY_12_D, LGAMMA_H_POLY_13_D, LGAMMA_H_POLY_14_D,
+    LGAMMA_H_POLY_15_D, LGAMMA_H_POLY_1_D, LGAMMA_H_POLY_2_D, LGAMMA_H_POLY_3_D, LGAMMA_H_POLY_4_D,
+    LGAMMA_H_POLY_5_D, LGAMMA_H_POLY_6_D, LGAMMA_H_POLY_7_D, LGAMMA_H_POLY_8_D, LGAMMA_H_POLY_9_D,
+    LGAMMA_MIN_D, LGAMMA_MIN_LO_D, LN_PI_D, LN_SQRT_2PI_D,
+};
+use crate::sse::general::_mm_mul_doubled_pd;
+use crate::sse::ln::_mm_lnk_doubled_pd;
+use crate::sse::sin::_mm_sinpik_pd;
+use crate::sse::tgamma::_mm_stirlingk_pd;
+use crate::{
+    _mm_abs_pd, _mm_copysign_pd, _mm_isnotintegral_pd, _mm_ln_pd, _mm_mlaf_pd, _mm_neg_pd,
+    _mm_round_pdx, _mm_select_pd,
+};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Computes ln|Γ(x)| and the sign of Γ(x), error bound *ULP 2.5* for positive arguments
+#[inline(always)]
+pub unsafe fn _mm_lgamma_pd(d: __m128d) -> (__m128d, __m128d) {
+    let x = _mm_abs_pd(d);
+    // lnΓ(x) = (x - 0.5) ln(x) - x + ln(sqrt(2pi)) + 1/x S(1/x^2) for x >= 10
+    let e = _mm_stirlingk_pd((x, _mm_setzero_pd()));
+    let mut big = _mm_add_pd(e.0, _mm_add_pd(e.1, _mm_set1_pd(LN_SQRT_2PI_D)));
+    // Everything past the leading terms of Stirling series is below an ulp
+    big = _mm_select_pd(
+        _mm_cmpgt_pd(x, _mm_set1_pd(1152921504606846976f64)),
+        _mm_mul_pd(x, _mm_sub_pd(_mm_ln_pd(x), _mm_set1_pd(1f64))),
+        big,
+    );
+    // lnΓ(x) = lnΓ(2 + z) + ln((x - 1)...(x - n + 2)), z = x - n in [-0.5, 0.5] for x >= 1.75
+    let mid = _mm_cmpge_pd(x, _mm_set1_pd(1.75f64));
+    let n = _mm_round_pdx::<_MM_FROUND_TO_NEAREST_INT>(x);
+    // Below 0.75 lnΓ(x) = lnΓ(x + 1) - ln(x), z is taken against shifted centers so x + 1 is never rounded
+    let shift = _mm_cmplt_pd(x, _mm_set1_pd(0.75f64));
+    let near_zero = _mm_or_pd(
+        _mm_cmplt_pd(x, _mm_set1_pd(0.25f64)),
+        _mm_and_pd(
+            _mm_cmpge_pd(x, _mm_set1_pd(0.75f64)),
+            _mm_cmplt_pd(x, _mm_set1_pd(1.25f64)),
+        ),
+    );
+    let z = _mm_select_pd(
+        mid,
+        _mm_sub_pd(x, n),
+        _mm_select_pd(shift, x, _mm_sub_pd(x, _mm_set1_pd(1f64))),
+    );
+    let pick = |a: f64, b: f64| _mm_select_pd(mid, _mm_set1_pd(b), _mm_set1_pd(a));
+    let mut u = pick(LGAMMA_A_POLY_19_D, LGAMMA_B_POLY_19_D);
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_18_D, LGAMMA_B_POLY_18_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_17_D, LGAMMA_B_POLY_17_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_16_D, LGAMMA_B_POLY_16_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_15_D, LGAMMA_B_POLY_15_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_14_D, LGAMMA_B_POLY_14_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_13_D, LGAMMA_B_POLY_13_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_12_D, LGAMMA_B_POLY_12_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_11_D, LGAMMA_B_POLY_11_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_10_D, LGAMMA_B_POLY_10_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_9_D, LGAMMA_B_POLY_9_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_8_D, LGAMMA_B_POLY_8_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_7_D, LGAMMA_B_POLY_7_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_6_D, LGAMMA_B_POLY_6_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_5_D, LGAMMA_B_POLY_5_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_4_D, LGAMMA_B_POLY_4_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_3_D, LGAMMA_B_POLY_3_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_2_D, LGAMMA_B_POLY_2_D));
+    u = _mm_mlaf_pd(u, z, pick(LGAMMA_A_POLY_1_D, LGAMMA_B_POLY_1_D));
+    let r = _mm_mul_pd(z, u);
+    // Around the minimum lnΓ(x0 + z) = lnΓ(x0) + z^2 H(z)
+    let zh = _mm_select_pd(
+        shift,
+        _mm_sub_pd(
+            _mm_sub_pd(x, _mm_set1_pd(DIGAMMA_ROOT_M1_D)),
+            _mm_set1_pd(DIGAMMA_ROOT_M1_LO_D),
+        ),
+        _mm_sub_pd(
+            _mm_sub_pd(x, _mm_set1_pd(DIGAMMA_ROOT_D)),
+            _mm_set1_pd(DIGAMMA_ROOT_LO_D),
+        ),
+    );
+    let mut h = _mm_set1_pd(LGAMMA_H_POLY_15_D);
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_14_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_13_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_12_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_11_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_10_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_9_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_8_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_7_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_6_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_5_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_4_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_3_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_2_D));
+    h = _mm_mlaf_pd(h, zh, _mm_set1_pd(LGAMMA_H_POLY_1_D));
+    let m = _mm_add_pd(
+        _mm_set1_pd(LGAMMA_MIN_D),
+        _mm_mlaf_pd(_mm_mul_pd(zh, zh), h, _mm_set1_pd(LGAMMA_MIN_LO_D)),
+    );
+    let r = _mm_select_pd(_mm_or_pd(mid, near_zero), r, m);
+    // The recurrence product, x itself when shifted and 1 elsewhere
+    let mut q = (_mm_select_pd(shift, x, _mm_set1_pd(1f64)), _mm_setzero_pd());
+    let k_max = _mm_sub_pd(n, _mm_set1_pd(2f64));
+    for k in 1..9 {
+        let kf = _mm_set1_pd(k as f64);
+        let f = _mm_select_pd(
+            _mm_cmple_pd(kf, k_max),
+            _mm_sub_pd(x, kf),
+            _mm_set1_pd(1f64),
+        );
+        q = _mm_mul_doubled_pd(q, (f, _mm_setzero_pd()));
+    }
+    let l = _mm_lnk_doubled_pd(q);
+    let small = _mm_select_pd(
+        shift,
+        _mm_sub_pd(_mm_sub_pd(r, l.0), l.1),
+        _mm_add_pd(l.0, _mm_add_pd(r, l.1)),
+    );
+    let k = _mm_select_pd(_mm_cmpge_pd(x, _mm_set1_pd(10f64)), big, small);
+    // |Γ(d)| = pi / (|sin(pi d)| |d| Γ(|d|)) for negative d
+    let sp = _mm_sinpik_pd(d);
+    let reflect = _mm_cmplt_pd(d, _mm_setzero_pd());
+    let mut ret = _mm_select_pd(
+        reflect,
+        _mm_sub_pd(
+            _mm_sub_pd(
+                _mm_set1_pd(LN_PI_D),
+                _mm_ln_pd(_mm_abs_pd(_mm_mul_pd(sp, d))),
+            ),
+            k,
+        ),
+        k,
+    );
+    let mut sign = _mm_select_pd(
+        _mm_and_pd(reflect, _mm_cmplt_pd(sp, _mm_setzero_pd())),
+        _mm_set1_pd(-1f64),
+        _mm_set1_pd(1f64),
+    );
+    // lnΓ(d) = -ln|d| - γd + O(d^2)
+    let tiny = _mm_cmplt_pd(x, _mm_set1_pd(5.551115123125783e-17f64));
+    ret = _mm_select_pd(tiny, _mm_neg_pd(_mm_ln_pd(x)), ret);
+    sign = _mm_select_pd(tiny, _mm_copysign_pd(_mm_set1_pd(1f64), d), sign);
+    // Poles, this also covers every d <= -2^52 and d == -Inf
+    let pole = _mm_andnot_pd(_mm_isnotintegral_pd(d), reflect);
+    ret = _mm_select_pd(pole, _mm_set1_pd(f64::INFINITY), ret);
+    sign = _mm_select_pd(pole, _mm_set1_pd(1f64), sign);
+    (ret, sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_lgammad() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(0.5f64);
+            let (comparison, sign) = _mm_lgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 0.5723649429247001f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+            assert_eq!(_mm_extract_pd::<0>(sign), 1.);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(-0.5f64);
+            let (comparison, sign) = _mm_lgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 1.2655121234846454f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+            assert_eq!(_mm_extract_pd::<0>(sign), -1.);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(-3.5f64);
+            let (comparison, sign) = _mm_lgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = -1.309006684993042f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+            assert_eq!(_mm_extract_pd::<0>(sign), 1.);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(100f64);
+            let (comparison, sign) = _mm_lgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 359.1342053695754f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+            assert_eq!(_mm_extract_pd::<0>(sign), 1.);
+        }
+
+        unsafe {
+            // Test exact value
+            let value = _mm_set1_pd(1f64);
+            let (comparison, sign) = _mm_lgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+            assert_eq!(_mm_extract_pd::<0>(sign), 1.);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm_set1_pd(-2f64);
+            let (comparison, _) = _mm_lgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        unsafe {
+            // Test NaN
+            let value = _mm_set1_pd(f64::NAN);
+            let (comparison, _) = _mm_lgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -10,21 +10,27 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+use crate::exp::{L2_L, L2_U};
+use crate::ln::{
+    LN_DOUBLED_POLY_1_D, LN_DOUBLED_POLY_2_D, LN_DOUBLED_POLY_3_D, LN_DOUBLED_POLY_4_D,
+    LN_DOUBLED_POLY_5_D, LN_DOUBLED_POLY_6_D, LN_DOUBLED_POLY_7_D, LN_DOUBLED_POLY_8_D,
+    TWO_THIRDS_D, TWO_THIRDS_LO_D,
+};
 use crate::ln::{
     LN_POLY_1_D, LN_POLY_2_D, LN_POLY_3_D, LN_POLY_4_D, LN_POLY_5_D, LN_POLY_6_D, LN_POLY_7_D,
     LN_POLY_8_D,
 };
+use crate::log2::LOG_SCALE_D;
 use crate::sse::general::{_mm_ilogb2k_pd, _mm_ldexp3k_pd, _mm_ltzero_pd};
+use crate::sse::general::{_mm_mul_doubled_pd, _mm_multiply_as_doubled_pd, _mm_sum_as_doubled_pd};
 use crate::{
     _mm_cvtepi64_pd, _mm_eqzero_pd, _mm_isinf_pd, _mm_isnan_pd, _mm_mlaf_pd, _mm_neg_epi64,
     _mm_select_pd,
 };
 
-/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
-#[inline]
-pub unsafe fn _mm_ln_fast_pd(d: __m128d) -> __m128d {
-    let n = _mm_ilogb2k_pd(_mm_mul_pd(d, _mm_set1_pd(1. / 0.75)));
-    let a = _mm_ldexp3k_pd(d, _mm_neg_epi64(n));
+/// Computes ln(a) for reduced argument a in [0.75, 1.5)
+#[inline(always)]
+pub(crate) unsafe fn _mm_lnk_pd(a: __m128d) -> __m128d {
     let ones = _mm_set1_pd(1.);
     let x = _mm_div_pd(_mm_sub_pd(a, ones), _mm_add_pd(a, ones));
     let x2 = _mm_mul_pd(x, x);
@@ -35,19 +41,40 @@ pub unsafe fn _mm_ln_fast_pd(d: __m128d) -> __m128d {
     u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_POLY_4_D));
     u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_POLY_3_D));
     u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_POLY_2_D));
-    u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_POLY_1_D));
+    // Leading 2x term is exact, keeping it apart saves a rounding
+    _mm_mlaf_pd(
+        _mm_mul_pd(x, x2),
+        u,
+        _mm_mul_pd(x, _mm_set1_pd(LN_POLY_1_D)),
+    )
+}
+
+/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm_ln_fast_pd(d: __m128d) -> __m128d {
+    let n = _mm_ilogb2k_pd(_mm_mul_pd(d, _mm_set1_pd(1. / 0.75)));
+    let a = _mm_ldexp3k_pd(d, _mm_neg_epi64(n));
     let res = _mm_mlaf_pd(
         _mm_set1_pd(std::f64::consts::LN_2),
         _mm_cvtepi64_pd(n),
-        _mm_mul_pd(x, u),
+        _mm_lnk_pd(a),
     );
     res
 }
 
 /// Computes natural logarithm for an argument *ULP 1.5*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_ln_pd(d: __m128d) -> __m128d {
-    let mut res = _mm_ln_fast_pd(d);
+    // Subnormals are scaled to normal range before the exponent split
+    let o = _mm_cmplt_pd(d, _mm_set1_pd(f64::MIN_POSITIVE));
+    let x = _mm_select_pd(o, _mm_mul_pd(d, _mm_set1_pd(LOG_SCALE_D)), d);
+    let n = _mm_ilogb2k_pd(_mm_mul_pd(x, _mm_set1_pd(1. / 0.75)));
+    let a = _mm_ldexp3k_pd(x, _mm_neg_epi64(n));
+    let e = _mm_sub_pd(
+        _mm_cvtepi64_pd(n),
+        _mm_select_pd(o, _mm_set1_pd(64f64), _mm_setzero_pd()),
+    );
+    let mut res = _mm_mlaf_pd(_mm_set1_pd(std::f64::consts::LN_2), e, _mm_lnk_pd(a));
     // d == 0 || d == Inf -> Inf
     res = _mm_select_pd(_mm_eqzero_pd(d), _mm_set1_pd(f64::NEG_INFINITY), res);
     res = _mm_select_pd(_mm_isinf_pd(d), _mm_set1_pd(f64::INFINITY), res);
@@ -60,6 +87,55 @@ pub unsafe fn _mm_ln_pd(d: __m128d) -> __m128d {
     res
 }
 
+/// Computes ln(d.0 + d.1) as double-float for positive normal d
+#[inline(always)]
+pub(crate) unsafe fn _mm_lnk_doubled_pd(d: (__m128d, __m128d)) -> (__m128d, __m128d) {
+    let n = _mm_ilogb2k_pd(_mm_mul_pd(d.0, _mm_set1_pd(1f64 / 0.75f64)));
+    let a = _mm_ldexp3k_pd(d.0, _mm_neg_epi64(n));
+    // x = (a - 1) / (a + 1) split into hi and lo, a - 1 is exact
+    let num = _mm_sub_pd(a, _mm_set1_pd(1f64));
+    let den = _mm_sum_as_doubled_pd(a, _mm_set1_pd(1f64));
+    let x = _mm_div_pd(num, den.0);
+    let m = _mm_multiply_as_doubled_pd(x, den.0);
+    let x_lo = _mm_div_pd(
+        _mm_sub_pd(_mm_sub_pd(_mm_sub_pd(num, m.0), m.1), _mm_mul_pd(x, den.1)),
+        den.0,
+    );
+    // ln(a) = 2x + 2x^3/3 + x^5 R(x^2), the cubic term is too large to be rounded once
+    let x3 = _mm_mul_doubled_pd(_mm_multiply_as_doubled_pd(x, x), (x, x_lo));
+    let t = _mm_mul_doubled_pd(
+        x3,
+        (_mm_set1_pd(TWO_THIRDS_D), _mm_set1_pd(TWO_THIRDS_LO_D)),
+    );
+    let x2 = _mm_mul_pd(x, x);
+    let mut u = _mm_set1_pd(LN_DOUBLED_POLY_8_D);
+    u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_DOUBLED_POLY_7_D));
+    u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_DOUBLED_POLY_6_D));
+    u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_DOUBLED_POLY_5_D));
+    u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_DOUBLED_POLY_4_D));
+    u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_DOUBLED_POLY_3_D));
+    u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_DOUBLED_POLY_2_D));
+    u = _mm_mlaf_pd(u, x2, _mm_set1_pd(LN_DOUBLED_POLY_1_D));
+    let nf = _mm_cvtepi64_pd(n);
+    let b = _mm_sum_as_doubled_pd(_mm_add_pd(x, x), t.0);
+    let h = _mm_sum_as_doubled_pd(_mm_mul_pd(nf, _mm_set1_pd(L2_U)), b.0);
+    let tail = _mm_mlaf_pd(
+        _mm_mul_pd(_mm_mul_pd(x2, x2), x),
+        u,
+        _mm_mlaf_pd(nf, _mm_set1_pd(L2_L), _mm_div_pd(d.1, d.0)),
+    );
+    (
+        h.0,
+        _mm_add_pd(
+            h.1,
+            _mm_add_pd(
+                _mm_add_pd(_mm_add_pd(b.1, t.1), _mm_add_pd(x_lo, x_lo)),
+                tail,
+            ),
+        ),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,7 +5,14 @@
  * // license that can be found in the LICENSE file.
  */
 
+use crate::expf::{L2L_F, L2U_F};
+use crate::lnf::{
+    LN_DOUBLED_POLY_1_S, LN_DOUBLED_POLY_2_S, LN_DOUBLED_POLY_3_S, LN_DOUBLED_POLY_4_S,
+    TWO_THIRDS_LO_S, TWO_THIRDS_S,
+};
 use crate::lnf::{LN_POLY_1_F, LN_POLY_2_F, LN_POLY_3_F, LN_POLY_4_F, LN_POLY_5_F};
+use crate::log2f::LOG_SCALE_F;
+use crate::sse::generalf::{_mm_mul_doubled_ps, _mm_multiply_as_doubled_ps, _mm_sum_as_doubled_ps};
 use crate::{
     _mm_eqzero_ps, _mm_ilogb2kq_ps, _mm_isinf_ps, _mm_isnan_ps, _mm_ldexp3kq_ps, _mm_ltzero_ps,
     _mm_mlaf_ps, _mm_neg_epi32, _mm_select_ps,
@@ -15,11 +22,9 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
-#[inline]
-pub unsafe fn _mm_ln_fast_ps(d: __m128) -> __m128 {
-    let n = _mm_ilogb2kq_ps(_mm_mul_ps(d, _mm_set1_ps(1f32 / 0.75f32)));
-    let a = _mm_ldexp3kq_ps(d, _mm_neg_epi32(n));
+/// Computes ln(a) for reduced argument a in [0.75, 1.5)
+#[inline(always)]
+pub(crate) unsafe fn _mm_lnk_ps(a: __m128) -> __m128 {
     let ones = _mm_set1_ps(1f32);
     let x = _mm_div_ps(_mm_sub_ps(a, ones), _mm_add_ps(a, ones));
     let x2 = _mm_mul_ps(x, x);
@@ -27,19 +32,40 @@ pub unsafe fn _mm_ln_fast_ps(d: __m128) -> __m128 {
     u = _mm_mlaf_ps(u, x2, _mm_set1_ps(LN_POLY_4_F));
     u = _mm_mlaf_ps(u, x2, _mm_set1_ps(LN_POLY_3_F));
     u = _mm_mlaf_ps(u, x2, _mm_set1_ps(LN_POLY_2_F));
-    u = _mm_mlaf_ps(u, x2, _mm_set1_ps(LN_POLY_1_F));
+    // Leading 2x term is exact, keeping it apart saves a rounding
+    _mm_mlaf_ps(
+        _mm_mul_ps(x, x2),
+        u,
+        _mm_mul_ps(x, _mm_set1_ps(LN_POLY_1_F)),
+    )
+}
+
+/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
+#[inline(always)]
+pub unsafe fn _mm_ln_fast_ps(d: __m128) -> __m128 {
+    let n = _mm_ilogb2kq_ps(_mm_mul_ps(d, _mm_set1_ps(1f32 / 0.75f32)));
+    let a = _mm_ldexp3kq_ps(d, _mm_neg_epi32(n));
     let res = _mm_mlaf_ps(
         _mm_set1_ps(std::f32::consts::LN_2),
         _mm_cvtepi32_ps(n),
-        _mm_mul_ps(x, u),
+        _mm_lnk_ps(a),
     );
     res
 }
 
 /// Computes natural logarithm for an argument *ULP 1.5*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_ln_ps(d: __m128) -> __m128 {
-    let mut res = _mm_ln_fast_ps(d);
+    // Subnormals are scaled to normal range before the exponent split
+    let o = _mm_cmplt_ps(d, _mm_set1_ps(f32::MIN_POSITIVE));
+    let x = _mm_select_ps(o, _mm_mul_ps(d, _mm_set1_ps(LOG_SCALE_F)), d);
+    let n = _mm_ilogb2kq_ps(_mm_mul_ps(x, _mm_set1_ps(1f32 / 0.75f32)));
+    let a = _mm_ldexp3kq_ps(x, _mm_neg_epi32(n));
+    let e = _mm_sub_ps(
+        _mm_cvtepi32_ps(n),
+        _mm_select_ps(o, _mm_set1_ps(64f32), _mm_setzero_ps()),
+    );
+    let mut res = _mm_mlaf_ps(_mm_set1_ps(std::f32::consts::LN_2), e, _mm_lnk_ps(a));
     // d == 0 || d == Inf -> Inf
     res = _mm_select_ps(_mm_eqzero_ps(d), _mm_set1_ps(f32::NEG_INFINITY), res);
     res = _mm_select_ps(_mm_isinf_ps(d), _mm_set1_ps(f32::INFINITY), res);
@@ -52,9 +78,55 @@ pub unsafe fn _mm_ln_ps(d: __m128) -> __m128 {
     res
 }
 
+/// Computes ln(d.0 + d.1) as double-float for positive normal d
+#[inline(always)]
+pub(crate) unsafe fn _mm_lnk_doubled_ps(d: (__m128, __m128)) -> (__m128, __m128) {
+    let n = _mm_ilogb2kq_ps(_mm_mul_ps(d.0, _mm_set1_ps(1f32 / 0.75f32)));
+    let a = _mm_ldexp3kq_ps(d.0, _mm_neg_epi32(n));
+    // x = (a - 1) / (a + 1) split into hi and lo, a - 1 is exact
+    let num = _mm_sub_ps(a, _mm_set1_ps(1f32));
+    let den = _mm_sum_as_doubled_ps(a, _mm_set1_ps(1f32));
+    let x = _mm_div_ps(num, den.0);
+    let m = _mm_multiply_as_doubled_ps(x, den.0);
+    let x_lo = _mm_div_ps(
+        _mm_sub_ps(_mm_sub_ps(_mm_sub_ps(num, m.0), m.1), _mm_mul_ps(x, den.1)),
+        den.0,
+    );
+    // ln(a) = 2x + 2x^3/3 + x^5 R(x^2), the cubic term is too large to be rounded once
+    let x3 = _mm_mul_doubled_ps(_mm_multiply_as_doubled_ps(x, x), (x, x_lo));
+    let t = _mm_mul_doubled_ps(
+        x3,
+        (_mm_set1_ps(TWO_THIRDS_S), _mm_set1_ps(TWO_THIRDS_LO_S)),
+    );
+    let x2 = _mm_mul_ps(x, x);
+    let mut u = _mm_set1_ps(LN_DOUBLED_POLY_4_S);
+    u = _mm_mlaf_ps(u, x2, _mm_set1_ps(LN_DOUBLED_POLY_3_S));
+    u = _mm_mlaf_ps(u, x2, _mm_set1_ps(LN_DOUBLED_POLY_2_S));
+    u = _mm_mlaf_ps(u, x2, _mm_set1_ps(LN_DOUBLED_POLY_1_S));
+    let nf = _mm_cvtepi32_ps(n);
+    let b = _mm_sum_as_doubled_ps(_mm_add_ps(x, x), t.0);
+    let h = _mm_sum_as_doubled_ps(_mm_mul_ps(nf, _mm_set1_ps(L2U_F)), b.0);
+    let tail = _mm_mlaf_ps(
+        _mm_mul_ps(_mm_mul_ps(x2, x2), x),
+        u,
+        _mm_mlaf_ps(nf, _mm_set1_ps(L2L_F), _mm_div_ps(d.1, d.0)),
+    );
+    (
+        h.0,
+        _mm_add_ps(
+            h.1,
+            _mm_add_ps(
+                _mm_add_ps(_mm_add_ps(b.1, t.1), _mm_add_ps(x_lo, x_lo)),
+                tail,
+            ),
+        ),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_ln() {
@@ -62,7 +134,7 @@ mod tests {
             // Test regular
             let value = _mm_set1_ps(23f32);
             let comparison = _mm_ln_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, 23f32.ln());
         }
 
@@ -70,7 +142,7 @@ mod tests {
             // Test Infinity
             let value = _mm_set1_ps(f32::INFINITY);
             let comparison = _mm_ln_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, f32::INFINITY);
         }
 
@@ -78,7 +150,7 @@ mod tests {
             // Test Neg Infinity
             let value = _mm_set1_ps(0f32);
             let comparison = _mm_ln_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, f32::NEG_INFINITY);
         }
 
@@ -86,7 +158,7 @@ mod tests {
             // Test Infinity, from negatives
             let value = _mm_set1_ps(-53f32);
             let comparison = _mm_ln_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1.is_nan(), true);
         }
     }
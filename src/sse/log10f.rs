@@ -0,0 +1,112 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::log2f::LOG_SCALE_F;
+use crate::sse::lnf::_mm_lnk_ps;
+use crate::{
+    _mm_ilogb2kq_ps, _mm_isinf_ps, _mm_isnan_ps, _mm_ldexp3kq_ps, _mm_mlaf_ps, _mm_neg_epi32,
+    _mm_select_ps,
+};
+
+/// Computes log10 for an argument *ULP 4.5*
+#[inline(always)]
+pub unsafe fn _mm_log10_ps(d: __m128) -> __m128 {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = _mm_cmplt_ps(d, _mm_set1_ps(f32::MIN_POSITIVE));
+    let x = _mm_select_ps(o, _mm_mul_ps(d, _mm_set1_ps(LOG_SCALE_F)), d);
+    let n = _mm_ilogb2kq_ps(_mm_mul_ps(x, _mm_set1_ps(1f32 / 0.75f32)));
+    let a = _mm_ldexp3kq_ps(x, _mm_neg_epi32(n));
+    let e = _mm_sub_ps(
+        _mm_cvtepi32_ps(n),
+        _mm_select_ps(o, _mm_set1_ps(64f32), _mm_setzero_ps()),
+    );
+    let mut res = _mm_mlaf_ps(
+        _mm_lnk_ps(a),
+        _mm_set1_ps(std::f32::consts::LOG10_E),
+        _mm_mul_ps(e, _mm_set1_ps(std::f32::consts::LOG10_2)),
+    );
+    // d == 0 -> -Inf
+    res = _mm_select_ps(
+        _mm_cmpeq_ps(d, _mm_setzero_ps()),
+        _mm_set1_ps(f32::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = _mm_select_ps(_mm_isinf_ps(d), _mm_set1_ps(f32::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = _mm_select_ps(
+        _mm_or_ps(_mm_cmplt_ps(d, _mm_setzero_ps()), _mm_isnan_ps(d)),
+        _mm_set1_ps(f32::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_log10f() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(1000f32);
+            let comparison = _mm_log10_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 3.;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(0.2f32);
+            let comparison = _mm_log10_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 0.2f32.log10();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test subnormal
+            let value = _mm_set1_ps(f32::from_bits(1));
+            let comparison = _mm_log10_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = f32::from_bits(1).log10();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm_set1_ps(0f32);
+            let comparison = _mm_log10_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm_set1_ps(-1f32);
+            let comparison = _mm_log10_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm_set1_ps(f32::INFINITY);
+            let comparison = _mm_log10_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::sse::general::{_mm_ilogb2k_pd, _mm_ldexp3k_pd};
+use crate::sse::ln::_mm_lnk_pd;
+use crate::{
+    _mm_cvtepi64_pd, _mm_isinf_pd, _mm_isnan_pd, _mm_mlaf_pd, _mm_neg_epi64, _mm_select_pd,
+};
+
+/// Computes ln(1 + x), accurate for x near zero *ULP 2.5*
+#[inline(always)]
+pub unsafe fn _mm_log1p_pd(d: __m128d) -> __m128d {
+    let dp1 = _mm_add_pd(d, _mm_set1_pd(1f64));
+    let n = _mm_ilogb2k_pd(_mm_mul_pd(dp1, _mm_set1_pd(1f64 / 0.75f64)));
+    let a = _mm_ldexp3k_pd(dp1, _mm_neg_epi64(n));
+    // c is the rounding error of 1 + d relative to it
+    let c = _mm_div_pd(_mm_sub_pd(d, _mm_sub_pd(dp1, _mm_set1_pd(1f64))), dp1);
+    let mut res = _mm_mlaf_pd(
+        _mm_set1_pd(std::f64::consts::LN_2),
+        _mm_cvtepi64_pd(n),
+        _mm_add_pd(_mm_lnk_pd(a), c),
+    );
+    // d == 0 -> d, keeps the sign of zero
+    res = _mm_select_pd(_mm_cmpeq_pd(d, _mm_setzero_pd()), d, res);
+    // d == -1 -> -Inf
+    res = _mm_select_pd(
+        _mm_cmpeq_pd(d, _mm_set1_pd(-1f64)),
+        _mm_set1_pd(f64::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = _mm_select_pd(_mm_isinf_pd(d), _mm_set1_pd(f64::INFINITY), res);
+    // d < -1 || d == Nan -> Nan
+    res = _mm_select_pd(
+        _mm_or_pd(_mm_cmplt_pd(d, _mm_set1_pd(-1f64)), _mm_isnan_pd(d)),
+        _mm_set1_pd(f64::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_log1pd() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(1e-10f64);
+            let comparison = _mm_log1p_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 1e-10f64.ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(0.5f64);
+            let comparison = _mm_log1p_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 0.5f64.ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(-0.3f64);
+            let comparison = _mm_log1p_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = (-0.3f64).ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm_set1_pd(-1f64);
+            let comparison = _mm_log1p_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm_set1_pd(-2f64);
+            let comparison = _mm_log1p_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm_set1_pd(f64::INFINITY);
+            let comparison = _mm_log1p_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
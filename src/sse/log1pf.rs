@@ -0,0 +1,109 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::sse::lnf::_mm_lnk_ps;
+use crate::{
+    _mm_ilogb2kq_ps, _mm_isinf_ps, _mm_isnan_ps, _mm_ldexp3kq_ps, _mm_mlaf_ps, _mm_neg_epi32,
+    _mm_select_ps,
+};
+
+/// Computes ln(1 + x), accurate for x near zero *ULP 4.5*
+#[inline(always)]
+pub unsafe fn _mm_log1p_ps(d: __m128) -> __m128 {
+    let dp1 = _mm_add_ps(d, _mm_set1_ps(1f32));
+    let n = _mm_ilogb2kq_ps(_mm_mul_ps(dp1, _mm_set1_ps(1f32 / 0.75f32)));
+    let a = _mm_ldexp3kq_ps(dp1, _mm_neg_epi32(n));
+    // c is the rounding error of 1 + d relative to it
+    let c = _mm_div_ps(_mm_sub_ps(d, _mm_sub_ps(dp1, _mm_set1_ps(1f32))), dp1);
+    let mut res = _mm_mlaf_ps(
+        _mm_set1_ps(std::f32::consts::LN_2),
+        _mm_cvtepi32_ps(n),
+        _mm_add_ps(_mm_lnk_ps(a), c),
+    );
+    // d == 0 -> d, keeps the sign of zero
+    res = _mm_select_ps(_mm_cmpeq_ps(d, _mm_setzero_ps()), d, res);
+    // d == -1 -> -Inf
+    res = _mm_select_ps(
+        _mm_cmpeq_ps(d, _mm_set1_ps(-1f32)),
+        _mm_set1_ps(f32::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = _mm_select_ps(_mm_isinf_ps(d), _mm_set1_ps(f32::INFINITY), res);
+    // d < -1 || d == Nan -> Nan
+    res = _mm_select_ps(
+        _mm_or_ps(_mm_cmplt_ps(d, _mm_set1_ps(-1f32)), _mm_isnan_ps(d)),
+        _mm_set1_ps(f32::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_log1pf() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(1e-6f32);
+            let comparison = _mm_log1p_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 1e-6f32.ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(0.5f32);
+            let comparison = _mm_log1p_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 0.5f32.ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(-0.3f32);
+            let comparison = _mm_log1p_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = (-0.3f32).ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm_set1_ps(-1f32);
+            let comparison = _mm_log1p_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm_set1_ps(-2f32);
+            let comparison = _mm_log1p_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm_set1_ps(f32::INFINITY);
+            let comparison = _mm_log1p_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::log2::LOG_SCALE_D;
+use crate::sse::general::{_mm_ilogb2k_pd, _mm_ldexp3k_pd};
+use crate::sse::ln::_mm_lnk_pd;
+use crate::{
+    _mm_cvtepi64_pd, _mm_isinf_pd, _mm_isnan_pd, _mm_mlaf_pd, _mm_neg_epi64, _mm_select_pd,
+};
+
+/// Computes log2 for an argument *ULP 3.5*
+#[inline(always)]
+pub unsafe fn _mm_log2_pd(d: __m128d) -> __m128d {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = _mm_cmplt_pd(d, _mm_set1_pd(f64::MIN_POSITIVE));
+    let x = _mm_select_pd(o, _mm_mul_pd(d, _mm_set1_pd(LOG_SCALE_D)), d);
+    let n = _mm_ilogb2k_pd(_mm_mul_pd(x, _mm_set1_pd(1f64 / 0.75f64)));
+    let a = _mm_ldexp3k_pd(x, _mm_neg_epi64(n));
+    let e = _mm_sub_pd(
+        _mm_cvtepi64_pd(n),
+        _mm_select_pd(o, _mm_set1_pd(64f64), _mm_setzero_pd()),
+    );
+    let mut res = _mm_mlaf_pd(_mm_lnk_pd(a), _mm_set1_pd(std::f64::consts::LOG2_E), e);
+    // d == 0 -> -Inf
+    res = _mm_select_pd(
+        _mm_cmpeq_pd(d, _mm_setzero_pd()),
+        _mm_set1_pd(f64::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = _mm_select_pd(_mm_isinf_pd(d), _mm_set1_pd(f64::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = _mm_select_pd(
+        _mm_or_pd(_mm_cmplt_pd(d, _mm_setzero_pd()), _mm_isnan_pd(d)),
+        _mm_set1_pd(f64::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_log2d() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(10f64);
+            let comparison = _mm_log2_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 10f64.log2();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(8f64);
+            let comparison = _mm_log2_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 3.);
+        }
+
+        unsafe {
+            // Test subnormal
+            let value = _mm_set1_pd(f64::from_bits(1));
+            let comparison = _mm_log2_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, -1074.);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm_set1_pd(0f64);
+            let comparison = _mm_log2_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+
+        unsafe {
+            // Test domain error
+            let value = _mm_set1_pd(-1f64);
+            let comparison = _mm_log2_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test infinity
+            let value = _mm_set1_pd(f64::INFINITY);
+            let comparison = _mm_log2_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
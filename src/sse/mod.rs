@@ -6,20 +6,45 @@
  */
 mod acos;
 mod acosf;
+mod acosh;
+mod acoshf;
 mod asin;
 mod asinf;
+mod asinh;
+mod asinhf;
 mod atan;
 mod atan2;
 mod atan2f;
 mod atanf;
+mod atanh;
+mod atanhf;
 mod cbrt;
 mod cbrtf;
 mod cos;
 mod cosf;
+mod cosh;
+mod coshf;
+mod digamma;
+mod digammaf;
+mod divider;
 mod epi32;
 mod epi64;
+mod erf;
+mod erfc;
+mod erfcf;
+mod erff;
+mod erfinv;
+mod erfinvf;
 mod exp;
+mod exp10;
+mod exp10f;
+mod exp2;
+mod exp2f;
 mod expf;
+mod expm1;
+mod expm1f;
+mod float128;
+mod float128_math;
 mod fmod;
 mod fmodf;
 mod general;
@@ -30,33 +55,66 @@ mod hypot3f;
 mod hypot4;
 mod hypot4f;
 mod hypotf;
+mod lgamma;
+mod lgammaf;
 mod ln;
 mod lnf;
+mod log10;
+mod log10f;
+mod log1p;
+mod log1pf;
+mod log2;
+mod log2f;
+mod modular;
 mod poly128;
 mod pow;
 mod powf;
+mod precision;
 mod saturation_epi32;
 mod saturation_epi64;
 mod sin;
+mod sincos;
+mod sincosf;
 mod sinf;
+mod sinh;
+mod sinhf;
+mod sse2;
 mod tan;
 mod tanf;
+mod tanh;
+mod tanhf;
+mod tgamma;
+mod tgammaf;
 mod unsigned;
 
 pub use acos::_mm_acos_pd;
 pub use acosf::_mm_acos_ps;
+pub use acosh::_mm_acosh_pd;
+pub use acoshf::_mm_acosh_ps;
 pub use asin::_mm_asin_pd;
 pub use asinf::_mm_asin_ps;
+pub use asinh::_mm_asinh_pd;
+pub use asinhf::_mm_asinh_ps;
 pub use atan::_mm_atan_pd;
 pub use atan2::_mm_atan2_pd;
 pub use atan2f::_mm_atan2_ps;
 pub use atanf::_mm_atan_ps;
+pub use atanh::_mm_atanh_pd;
+pub use atanhf::_mm_atanh_ps;
 pub use cbrt::_mm_cbrt_fast_pd;
 pub use cbrt::_mm_cbrt_pd;
 pub use cbrtf::_mm_cbrt_fast_ps;
 pub use cbrtf::_mm_cbrt_ps;
 pub use cos::_mm_cos_pd;
 pub use cosf::_mm_cos_ps;
+pub use cosh::_mm_cosh_pd;
+pub use coshf::_mm_cosh_ps;
+pub use digamma::_mm_digamma_pd;
+pub use digammaf::_mm_digamma_ps;
+pub use divider::_mm_div_epi32;
+pub use divider::_mm_div_epi64;
+pub use divider::_mm_div_epu32;
+pub use divider::_mm_div_epu64;
 pub use epi32::_mm_blendv_epi32;
 pub use epi32::_mm_neg_epi32;
 pub use epi32::_mm_select_epi32;
@@ -76,10 +134,41 @@ pub use epi64::_mm_setr_epi64x;
 pub use epi64::_mm_sllv_epi64x;
 pub use epi64::_mm_srai_epi64x;
 pub use epi64::_mm_srlv_epi64x;
+pub use erf::_mm_erf_pd;
+pub use erfc::_mm_erfc_pd;
+pub use erfcf::_mm_erfc_ps;
+pub use erff::_mm_erf_ps;
+pub use erfinv::_mm_erfinv_pd;
+pub use erfinvf::_mm_erfinv_ps;
 pub use exp::_mm_exp_fast_pd;
 pub use exp::_mm_exp_pd;
+pub use exp10::_mm_exp10_pd;
+pub use exp10f::_mm_exp10_ps;
+pub use exp2::_mm_exp2_pd;
+pub use exp2f::_mm_exp2_ps;
 pub use expf::_mm_exp_fast_ps;
 pub use expf::_mm_exp_ps;
+pub use expm1::_mm_expm1_pd;
+pub use expm1f::_mm_expm1_ps;
+pub use float128::__m128dx2;
+pub use float128::_mm_add_f128;
+pub use float128::_mm_addl_pd;
+pub use float128::_mm_addw_pd;
+pub use float128::_mm_cvtf128_pd;
+pub use float128::_mm_cvtpd_f128;
+pub use float128::_mm_div_f128;
+pub use float128::_mm_extract_f128;
+pub use float128::_mm_mlaf_f128;
+pub use float128::_mm_mul_f128;
+pub use float128::_mm_mull_pd;
+pub use float128::_mm_neg_f128;
+pub use float128::_mm_set1_f128;
+pub use float128_math::_mm_cos_f128;
+pub use float128_math::_mm_exp_f128;
+pub use float128_math::_mm_ln_f128;
+pub use float128_math::_mm_pow_f128;
+pub use float128_math::_mm_sin_f128;
+pub use float128_math::_mm_sqrt_f128;
 pub use fmod::_mm_fmod_pd;
 pub use fmodf::_mm_fmod_ps;
 pub use general::_mm_abs_pd;
@@ -93,6 +182,7 @@ pub use general::_mm_isintegral_pd;
 pub use general::_mm_isnan_pd;
 pub use general::_mm_isneginf_pd;
 pub use general::_mm_isnotintegral_pd;
+pub use general::_mm_ldexp2k_pd;
 pub use general::_mm_mlaf_pd;
 pub use general::_mm_neg_pd;
 pub use general::_mm_prefer_fma_pd;
@@ -107,6 +197,7 @@ pub use generalf::_mm_isintegral_ps;
 pub use generalf::_mm_isnan_ps;
 pub use generalf::_mm_isneginf_ps;
 pub use generalf::_mm_isnotintegral_ps;
+pub use generalf::_mm_ldexp2kq_ps;
 pub use generalf::_mm_ldexp3kq_ps;
 pub use generalf::_mm_ltzero_ps;
 pub use generalf::_mm_mlaf_ps;
@@ -128,24 +219,73 @@ pub use hypot4f::_mm_hypot4_fast_ps;
 pub use hypot4f::_mm_hypot4_ps;
 pub use hypotf::_mm_hypot_fast_ps;
 pub use hypotf::_mm_hypot_ps;
+pub use lgamma::_mm_lgamma_pd;
+pub use lgammaf::_mm_lgamma_ps;
 pub use ln::_mm_ln_fast_pd;
 pub use ln::_mm_ln_pd;
 pub use lnf::_mm_ln_fast_ps;
 pub use lnf::_mm_ln_ps;
+pub use log10::_mm_log10_pd;
+pub use log10f::_mm_log10_ps;
+pub use log1p::_mm_log1p_pd;
+pub use log1pf::_mm_log1p_ps;
+pub use log2::_mm_log2_pd;
+pub use log2f::_mm_log2_ps;
+pub use modular::_mm_addmod_epu32;
+pub use modular::_mm_addmod_epu64;
+pub use modular::_mm_barrett_reduce_epu32;
+pub use modular::_mm_barrett_reduce_epu64;
+pub use modular::_mm_from_montgomery_epu32;
+pub use modular::_mm_from_montgomery_epu64;
+pub use modular::_mm_intt_butterfly_epu32;
+pub use modular::_mm_intt_butterfly_epu64;
+pub use modular::_mm_montgomery_reduce_epu32;
+pub use modular::_mm_montgomery_reduce_epu64;
+pub use modular::_mm_montmul_epu32;
+pub use modular::_mm_montmul_epu64;
+pub use modular::_mm_mulmod_epu32;
+pub use modular::_mm_mulmod_epu64;
+pub use modular::_mm_ntt_butterfly_epu32;
+pub use modular::_mm_ntt_butterfly_epu64;
+pub use modular::_mm_submod_epu32;
+pub use modular::_mm_submod_epu64;
+pub use modular::_mm_to_montgomery_epu32;
+pub use modular::_mm_to_montgomery_epu64;
 pub use poly128::__m128x2i;
 pub use poly128::_mm_abs_epi128;
 pub use poly128::_mm_add_epi128;
 pub use poly128::_mm_add_epu128;
 pub use poly128::_mm_addw_epi128;
+pub use poly128::_mm_cmpeq_epi128;
+pub use poly128::_mm_cmpge_epi128;
+pub use poly128::_mm_cmpge_epu128;
+pub use poly128::_mm_cmpgt_epi128;
+pub use poly128::_mm_cmpgt_epu128;
+pub use poly128::_mm_cmple_epi128;
+pub use poly128::_mm_cmple_epu128;
+pub use poly128::_mm_cmplt_epi128;
+pub use poly128::_mm_cmplt_epu128;
+pub use poly128::_mm_combine_epi128;
 pub use poly128::_mm_extract_epi128;
 pub use poly128::_mm_extract_epu128;
 pub use poly128::_mm_extract_hi_epi128;
 pub use poly128::_mm_extract_lo_epi128;
+pub use poly128::_mm_loadu_epi128;
+pub use poly128::_mm_max_epi128;
+pub use poly128::_mm_max_epu128;
+pub use poly128::_mm_min_epi128;
+pub use poly128::_mm_min_epu128;
 pub use poly128::_mm_movn_epi128;
+pub use poly128::_mm_mul_epi128;
 pub use poly128::_mm_mull_epi64;
 pub use poly128::_mm_mull_epu64;
+pub use poly128::_mm_select_epi128;
+pub use poly128::_mm_set1_epi128;
+pub use poly128::_mm_set_epi128;
 pub use poly128::_mm_slli_epi128x;
 pub use poly128::_mm_srli_epi128x;
+pub use poly128::_mm_storeu_epi128;
+pub use poly128::_mm_sub_epi128;
 pub use poly128::_mm_subw_epi128;
 pub use pow::_mm_pow_fast_pd;
 pub use pow::_mm_pow_pd;
@@ -153,14 +293,44 @@ pub use powf::_mm_pow_fast_ps;
 pub use powf::_mm_pow_ps;
 pub use saturation_epi32::_mm_adds_epi32;
 pub use saturation_epi32::_mm_adds_epu32;
+pub use saturation_epi32::_mm_muls_epi32;
+pub use saturation_epi32::_mm_muls_epu32;
+pub use saturation_epi32::_mm_shls_epi32;
+pub use saturation_epi32::_mm_shls_epu32;
 pub use saturation_epi32::_mm_subs_epi32;
+pub use saturation_epi32::_mm_subs_epu32;
 pub use saturation_epi64::_mm_adds_epi64;
 pub use saturation_epi64::_mm_adds_epu64;
+pub use saturation_epi64::_mm_muls_epi64;
+pub use saturation_epi64::_mm_muls_epu64;
+pub use saturation_epi64::_mm_shls_epi64;
+pub use saturation_epi64::_mm_shls_epu64;
 pub use saturation_epi64::_mm_subs_epi64;
+pub use saturation_epi64::_mm_subs_epu64;
 pub use sin::_mm_sin_pd;
+pub use sincos::_mm_sincos_pd;
+pub use sincosf::_mm_sincos_ps;
 pub use sinf::_mm_sin_ps;
+pub use sinh::_mm_sinh_pd;
+pub use sinhf::_mm_sinh_ps;
+pub use sse2::_mm_blendv_epi8x;
+pub use sse2::_mm_blendv_pdx;
+pub use sse2::_mm_blendv_psx;
+pub use sse2::_mm_ceil_pdx;
+pub use sse2::_mm_ceil_psx;
+pub use sse2::_mm_cmpeq_epi64x;
+pub use sse2::_mm_cmpgt_epi64x;
+pub use sse2::_mm_extract_psx;
+pub use sse2::_mm_floor_pdx;
+pub use sse2::_mm_floor_psx;
+pub use sse2::_mm_round_pdx;
+pub use sse2::_mm_round_psx;
 pub use tan::_mm_tan_pd;
 pub use tanf::_mm_tan_ps;
+pub use tanh::_mm_tanh_pd;
+pub use tanhf::_mm_tanh_ps;
+pub use tgamma::_mm_tgamma_pd;
+pub use tgammaf::_mm_tgamma_ps;
 pub use unsigned::_mm_cmpge_epi64;
 pub use unsigned::_mm_cmpge_epu16;
 pub use unsigned::_mm_cmpge_epu32;
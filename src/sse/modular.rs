@@ -0,0 +1,356 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::modular::{ModulusU32, ModulusU64};
+use crate::{__m128x2i, _mm_mul_epu64, _mm_mull_epu64, _mm_srai_epi64x};
+
+#[inline(always)]
+/// Adds modulus to lanes holding negative i64
+unsafe fn _mm_fixmod_epi64(a: __m128i, n: __m128i) -> __m128i {
+    _mm_add_epi64(a, _mm_and_si128(_mm_srai_epi64x::<63>(a), n))
+}
+
+#[inline(always)]
+/// Adds modulus to lanes holding negative i32
+unsafe fn _mm_fixmod_epi32(a: __m128i, n: __m128i) -> __m128i {
+    _mm_add_epi32(a, _mm_and_si128(_mm_srai_epi32::<31>(a), n))
+}
+
+#[inline(always)]
+/// Modular addition for u32
+pub unsafe fn _mm_addmod_epu32(a: __m128i, b: __m128i, m: ModulusU32) -> __m128i {
+    let n = _mm_set1_epi32(m.n as i32);
+    _mm_fixmod_epi32(_mm_sub_epi32(_mm_add_epi32(a, b), n), n)
+}
+
+#[inline(always)]
+/// Modular subtraction for u32
+pub unsafe fn _mm_submod_epu32(a: __m128i, b: __m128i, m: ModulusU32) -> __m128i {
+    _mm_fixmod_epi32(_mm_sub_epi32(a, b), _mm_set1_epi32(m.n as i32))
+}
+
+#[inline(always)]
+/// Montgomery reduction, computes `x * R^-1 mod n` for u64 lanes holding `x < n * 2^32`,
+/// result is stored in u64 lanes
+pub unsafe fn _mm_montgomery_reduce_epu32(x: __m128i, m: ModulusU32) -> __m128i {
+    let n = _mm_set1_epi64x(m.n as i64);
+    let q = _mm_mul_epu32(x, _mm_set1_epi64x(m.n_inv as i64));
+    let qn = _mm_mul_epu32(q, n);
+    let t = _mm_sub_epi64(_mm_srli_epi64::<32>(x), _mm_srli_epi64::<32>(qn));
+    _mm_fixmod_epi64(t, n)
+}
+
+#[inline(always)]
+/// Barrett reduction, computes `x mod n` for u64 lanes holding `x < n^2`,
+/// result is stored in u64 lanes
+pub unsafe fn _mm_barrett_reduce_epu32(x: __m128i, m: ModulusU32) -> __m128i {
+    let n = _mm_set1_epi64x(m.n as i64);
+    let x1 = _mm_srl_epi64(x, _mm_cvtsi32_si128(m.bits as i32 - 1));
+    let q = _mm_srl_epi64(
+        _mm_mul_epu32(x1, _mm_set1_epi64x(m.mu as i64)),
+        _mm_cvtsi32_si128(m.bits as i32 + 1),
+    );
+    let r = _mm_sub_epi64(x, _mm_mul_epu32(q, n));
+    let r = _mm_fixmod_epi64(_mm_sub_epi64(r, n), n);
+    _mm_fixmod_epi64(_mm_sub_epi64(r, n), n)
+}
+
+#[inline(always)]
+/// Montgomery multiplication for u32, computes `a * b * R^-1 mod n`
+pub unsafe fn _mm_montmul_epu32(a: __m128i, b: __m128i, m: ModulusU32) -> __m128i {
+    let even = _mm_mul_epu32(a, b);
+    let odd = _mm_mul_epu32(_mm_srli_epi64::<32>(a), _mm_srli_epi64::<32>(b));
+    _mm_or_si128(
+        _mm_montgomery_reduce_epu32(even, m),
+        _mm_slli_epi64::<32>(_mm_montgomery_reduce_epu32(odd, m)),
+    )
+}
+
+#[inline(always)]
+/// Modular multiplication for u32
+pub unsafe fn _mm_mulmod_epu32(a: __m128i, b: __m128i, m: ModulusU32) -> __m128i {
+    let even = _mm_mul_epu32(a, b);
+    let odd = _mm_mul_epu32(_mm_srli_epi64::<32>(a), _mm_srli_epi64::<32>(b));
+    _mm_or_si128(
+        _mm_barrett_reduce_epu32(even, m),
+        _mm_slli_epi64::<32>(_mm_barrett_reduce_epu32(odd, m)),
+    )
+}
+
+#[inline(always)]
+/// Converts u32 into Montgomery form
+pub unsafe fn _mm_to_montgomery_epu32(a: __m128i, m: ModulusU32) -> __m128i {
+    _mm_montmul_epu32(a, _mm_set1_epi32(m.r2 as i32), m)
+}
+
+#[inline(always)]
+/// Converts u32 from Montgomery form
+pub unsafe fn _mm_from_montgomery_epu32(a: __m128i, m: ModulusU32) -> __m128i {
+    let even = _mm_and_si128(a, _mm_set1_epi64x(0xFFFFFFFF));
+    let odd = _mm_srli_epi64::<32>(a);
+    _mm_or_si128(
+        _mm_montgomery_reduce_epu32(even, m),
+        _mm_slli_epi64::<32>(_mm_montgomery_reduce_epu32(odd, m)),
+    )
+}
+
+#[inline(always)]
+/// Cooley-Tukey NTT butterfly for u32, returns `(a + w * b, a - w * b)`,
+/// twiddle `w` must be in Montgomery form
+pub unsafe fn _mm_ntt_butterfly_epu32(
+    a: __m128i,
+    b: __m128i,
+    w: __m128i,
+    m: ModulusU32,
+) -> (__m128i, __m128i) {
+    let t = _mm_montmul_epu32(b, w, m);
+    (_mm_addmod_epu32(a, t, m), _mm_submod_epu32(a, t, m))
+}
+
+#[inline(always)]
+/// Gentleman-Sande inverse NTT butterfly for u32, returns `(a + b, (a - b) * w)`,
+/// twiddle `w` must be in Montgomery form
+pub unsafe fn _mm_intt_butterfly_epu32(
+    a: __m128i,
+    b: __m128i,
+    w: __m128i,
+    m: ModulusU32,
+) -> (__m128i, __m128i) {
+    let t = _mm_submod_epu32(a, b, m);
+    (_mm_addmod_epu32(a, b, m), _mm_montmul_epu32(t, w, m))
+}
+
+#[inline(always)]
+/// Modular addition for u64
+pub unsafe fn _mm_addmod_epu64(a: __m128i, b: __m128i, m: ModulusU64) -> __m128i {
+    let n = _mm_set1_epi64x(m.n as i64);
+    _mm_fixmod_epi64(_mm_sub_epi64(_mm_add_epi64(a, b), n), n)
+}
+
+#[inline(always)]
+/// Modular subtraction for u64
+pub unsafe fn _mm_submod_epu64(a: __m128i, b: __m128i, m: ModulusU64) -> __m128i {
+    _mm_fixmod_epi64(_mm_sub_epi64(a, b), _mm_set1_epi64x(m.n as i64))
+}
+
+#[inline(always)]
+/// Montgomery reduction, computes `x * R^-1 mod n` for u128 holding `x < n * 2^64`
+pub unsafe fn _mm_montgomery_reduce_epu64(x: __m128x2i, m: ModulusU64) -> __m128i {
+    let n = _mm_set1_epi64x(m.n as i64);
+    let q = _mm_mul_epu64(x.0, _mm_set1_epi64x(m.n_inv as i64));
+    let qn = _mm_mull_epu64(q, n);
+    _mm_fixmod_epi64(_mm_sub_epi64(x.1, qn.1), n)
+}
+
+#[inline(always)]
+/// Barrett reduction, computes `x mod n` for u128 holding `x < n^2`
+pub unsafe fn _mm_barrett_reduce_epu64(x: __m128x2i, m: ModulusU64) -> __m128i {
+    let n = _mm_set1_epi64x(m.n as i64);
+    let bits = m.bits as i32;
+    let x1 = _mm_or_si128(
+        _mm_srl_epi64(x.0, _mm_cvtsi32_si128(bits - 1)),
+        _mm_sll_epi64(x.1, _mm_cvtsi32_si128(65 - bits)),
+    );
+    let p = _mm_mull_epu64(x1, _mm_set1_epi64x(m.mu as i64));
+    let q = _mm_or_si128(
+        _mm_srl_epi64(p.0, _mm_cvtsi32_si128(bits + 1)),
+        _mm_sll_epi64(p.1, _mm_cvtsi32_si128(63 - bits)),
+    );
+    let r = _mm_sub_epi64(x.0, _mm_mul_epu64(q, n));
+    let r = _mm_fixmod_epi64(_mm_sub_epi64(r, n), n);
+    _mm_fixmod_epi64(_mm_sub_epi64(r, n), n)
+}
+
+#[inline(always)]
+/// Montgomery multiplication for u64, computes `a * b * R^-1 mod n`
+pub unsafe fn _mm_montmul_epu64(a: __m128i, b: __m128i, m: ModulusU64) -> __m128i {
+    _mm_montgomery_reduce_epu64(_mm_mull_epu64(a, b), m)
+}
+
+#[inline(always)]
+/// Modular multiplication for u64
+pub unsafe fn _mm_mulmod_epu64(a: __m128i, b: __m128i, m: ModulusU64) -> __m128i {
+    _mm_barrett_reduce_epu64(_mm_mull_epu64(a, b), m)
+}
+
+#[inline(always)]
+/// Converts u64 into Montgomery form
+pub unsafe fn _mm_to_montgomery_epu64(a: __m128i, m: ModulusU64) -> __m128i {
+    _mm_montmul_epu64(a, _mm_set1_epi64x(m.r2 as i64), m)
+}
+
+#[inline(always)]
+/// Converts u64 from Montgomery form
+pub unsafe fn _mm_from_montgomery_epu64(a: __m128i, m: ModulusU64) -> __m128i {
+    _mm_montgomery_reduce_epu64(__m128x2i(a, _mm_setzero_si128()), m)
+}
+
+#[inline(always)]
+/// Cooley-Tukey NTT butterfly for u64, returns `(a + w * b, a - w * b)`,
+/// twiddle `w` must be in Montgomery form
+pub unsafe fn _mm_ntt_butterfly_epu64(
+    a: __m128i,
+    b: __m128i,
+    w: __m128i,
+    m: ModulusU64,
+) -> (__m128i, __m128i) {
+    let t = _mm_montmul_epu64(b, w, m);
+    (_mm_addmod_epu64(a, t, m), _mm_submod_epu64(a, t, m))
+}
+
+#[inline(always)]
+/// Gentleman-Sande inverse NTT butterfly for u64, returns `(a + b, (a - b) * w)`,
+/// twiddle `w` must be in Montgomery form
+pub unsafe fn _mm_intt_butterfly_epu64(
+    a: __m128i,
+    b: __m128i,
+    w: __m128i,
+    m: ModulusU64,
+) -> (__m128i, __m128i) {
+    let t = _mm_submod_epu64(a, b, m);
+    (_mm_addmod_epu64(a, b, m), _mm_montmul_epu64(t, w, m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_epi64x;
+
+    const MODULI_32: [u32; 5] = [3, 17, 998244353, 0x7fff_ffff, 0x4000_0001];
+    const MODULI_64: [u64; 5] = [
+        3,
+        998244353,
+        0x1fff_ffff_ffff_ffff,
+        4611686018326724609,
+        0x3fff_ffff_ffff_ffff,
+    ];
+
+    fn values(n: u64) -> [u64; 8] {
+        [
+            0,
+            1,
+            2,
+            n / 3,
+            n / 2 + 1,
+            n - 2,
+            n - 1,
+            0x9e37_79b9_7f4a_7c15 % n,
+        ]
+    }
+
+    unsafe fn lanes32(v: __m128i) -> [u32; 4] {
+        let lo = _mm_extract_epi64x::<0>(v) as u64;
+        let hi = _mm_extract_epi64x::<1>(v) as u64;
+        [lo as u32, (lo >> 32) as u32, hi as u32, (hi >> 32) as u32]
+    }
+
+    unsafe fn lanes64(v: __m128i) -> [u64; 2] {
+        [
+            _mm_extract_epi64x::<0>(v) as u64,
+            _mm_extract_epi64x::<1>(v) as u64,
+        ]
+    }
+
+    #[test]
+    fn test_modular_u32() {
+        unsafe {
+            for &n in MODULI_32.iter() {
+                let m = ModulusU32::new(n);
+                let vals = values(n as u64).map(|x| x as u32);
+                for &b in vals.iter() {
+                    let w = vals[7];
+                    let a = [vals[0], vals[3], vals[5], vals[6]];
+                    let va = _mm_setr_epi32(a[0] as i32, a[1] as i32, a[2] as i32, a[3] as i32);
+                    let vb = _mm_set1_epi32(b as i32);
+                    let vw = _mm_set1_epi32(m.to_montgomery(w) as i32);
+                    let add = lanes32(_mm_addmod_epu32(va, vb, m));
+                    let sub = lanes32(_mm_submod_epu32(va, vb, m));
+                    let mul = lanes32(_mm_mulmod_epu32(va, vb, m));
+                    let mont = lanes32(_mm_montmul_epu32(va, vb, m));
+                    let to = lanes32(_mm_to_montgomery_epu32(va, m));
+                    let from = lanes32(_mm_from_montgomery_epu32(va, m));
+                    let (x, y) = _mm_ntt_butterfly_epu32(va, vb, vw, m);
+                    let (x, y) = (lanes32(x), lanes32(y));
+                    let (ix, iy) = _mm_intt_butterfly_epu32(va, vb, vw, m);
+                    let (ix, iy) = (lanes32(ix), lanes32(iy));
+                    for i in 0..4 {
+                        let (a, b, w, n) = (a[i] as u64, b as u64, w as u64, n as u64);
+                        assert_eq!(add[i] as u64, (a + b) % n);
+                        assert_eq!(sub[i] as u64, (a + n - b) % n);
+                        assert_eq!(mul[i] as u64, a * b % n);
+                        assert_eq!(mul[i], m.mul_mod(a as u32, b as u32));
+                        assert_eq!(((mont[i] as u64) << 32) % n, a * b % n);
+                        assert_eq!(mont[i], m.montmul(a as u32, b as u32));
+                        assert_eq!(to[i] as u64, (a << 32) % n);
+                        assert_eq!(to[i], m.to_montgomery(a as u32));
+                        assert_eq!(from[i], m.from_montgomery(a as u32));
+                        assert_eq!(((from[i] as u64) << 32) % n, a);
+                        assert_eq!(x[i] as u64, (a + b * w % n) % n);
+                        assert_eq!(y[i] as u64, (a + n - b * w % n) % n);
+                        assert_eq!(ix[i] as u64, (a + b) % n);
+                        assert_eq!(iy[i] as u64, (a + n - b) % n * w % n);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_modular_u64() {
+        unsafe {
+            for &n in MODULI_64.iter() {
+                let m = ModulusU64::new(n);
+                let vals = values(n);
+                for &a in vals.iter() {
+                    for &b in vals.iter() {
+                        let w = vals[7];
+                        let va = _mm_set_epi64x(b as i64, a as i64);
+                        let vb = _mm_set_epi64x(a as i64, b as i64);
+                        let vw = _mm_set1_epi64x(m.to_montgomery(w) as i64);
+                        let add = lanes64(_mm_addmod_epu64(va, vb, m));
+                        let sub = lanes64(_mm_submod_epu64(va, vb, m));
+                        let mul = lanes64(_mm_mulmod_epu64(va, vb, m));
+                        let mont = lanes64(_mm_montmul_epu64(va, vb, m));
+                        let to = lanes64(_mm_to_montgomery_epu64(va, m));
+                        let from = lanes64(_mm_from_montgomery_epu64(va, m));
+                        let (x, y) = _mm_ntt_butterfly_epu64(va, vb, vw, m);
+                        let (x, y) = (lanes64(x), lanes64(y));
+                        let (ix, iy) = _mm_intt_butterfly_epu64(va, vb, vw, m);
+                        let (ix, iy) = (lanes64(ix), lanes64(iy));
+                        let (a, b, w, n) = (
+                            [a as u128, b as u128],
+                            [b as u128, a as u128],
+                            w as u128,
+                            n as u128,
+                        );
+                        for i in 0..2 {
+                            let (a, b) = (a[i], b[i]);
+                            assert_eq!(add[i] as u128, (a + b) % n);
+                            assert_eq!(sub[i] as u128, (a + n - b) % n);
+                            assert_eq!(mul[i] as u128, a * b % n);
+                            assert_eq!(mul[i], m.mul_mod(a as u64, b as u64));
+                            assert_eq!(((mont[i] as u128) << 64) % n, a * b % n);
+                            assert_eq!(mont[i], m.montmul(a as u64, b as u64));
+                            assert_eq!(to[i] as u128, (a << 64) % n);
+                            assert_eq!(to[i], m.to_montgomery(a as u64));
+                            assert_eq!(from[i], m.from_montgomery(a as u64));
+                            assert_eq!(((from[i] as u128) << 64) % n, a);
+                            assert_eq!(x[i] as u128, (a + b * w % n) % n);
+                            assert_eq!(y[i] as u128, (a + n - b * w % n) % n);
+                            assert_eq!(ix[i] as u128, (a + b) % n);
+                            assert_eq!(iy[i] as u128, (a + n - b) % n * w % n);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -11,65 +11,51 @@ use std::arch::x86_64::*;
 
 use crate::sse::epi64::_mm_not_epi64;
 use crate::{
-    _mm_abs_epi64, _mm_cmplt_epi64, _mm_cmplt_epu64, _mm_extract_epi64x, _mm_neg_epi64,
-    _mm_packus_epi64, _mm_select_epi64, _mm_sllv_epi64x,
+    _mm_cmpeq_epi64x, _mm_cmpgt_epi64x, _mm_cmpgt_epu64, _mm_cmplt_epi64, _mm_cmplt_epu64,
+    _mm_extract_epi64x, _mm_mul_epu64, _mm_neg_epi64, _mm_select_epi64, _mm_sllv_epi64x,
 };
 
 /// Type represents u128, in low u64 and high u64 part
 /// Lower parts, and upper parts are interleaved
 #[allow(non_camel_case_types)]
 #[derive(Copy, Clone)]
-pub struct __m128x2i(__m128i, __m128i);
+pub struct __m128x2i(pub(crate) __m128i, pub(crate) __m128i);
 
-#[inline]
+#[inline(always)]
 /// Widening multiplication u64 in u128
 pub unsafe fn _mm_mull_epu64(a: __m128i, b: __m128i) -> __m128x2i {
     let erase_high = _mm_set1_epi64x(0xFFFFFFFF);
     let xh = _mm_srli_epi64::<32>(a);
-    let xl = _mm_and_si128(a, erase_high);
     let yh = _mm_srli_epi64::<32>(b);
-    let yl = _mm_and_si128(b, erase_high);
-
-    let xh = _mm_packus_epi64(xh, xh);
-    let xl = _mm_packus_epi64(xl, xl);
-    let yh = _mm_packus_epi64(yh, yh);
-    let yl = _mm_packus_epi64(yl, yl);
 
     let hh = _mm_mul_epu32(xh, yh);
-    let lh = _mm_mul_epu32(xl, yh);
-    let hl = _mm_mul_epu32(xh, yl);
-    let ll = _mm_mul_epu32(xl, yl);
-
-    let lo = ll;
-    let hi = hh;
-    let mut rs = __m128x2i(lo, hi);
-
-    let carry1 = _mm_slli_epi128x::<32>(__m128x2i(lh, _mm_setzero_si128()));
-    let carry2 = _mm_slli_epi128x::<32>(__m128x2i(hl, _mm_setzero_si128()));
+    let lh = _mm_mul_epu32(a, yh);
+    let hl = _mm_mul_epu32(xh, b);
+    let ll = _mm_mul_epu32(a, b);
 
-    rs = _mm_add_epu128(rs, carry1);
-    rs = _mm_add_epu128(rs, carry2);
-    rs
+    let mid = _mm_add_epi64(
+        _mm_add_epi64(_mm_srli_epi64::<32>(ll), _mm_and_si128(lh, erase_high)),
+        _mm_and_si128(hl, erase_high),
+    );
+    let lo = _mm_or_si128(_mm_and_si128(ll, erase_high), _mm_slli_epi64::<32>(mid));
+    let hi = _mm_add_epi64(
+        _mm_add_epi64(hh, _mm_srli_epi64::<32>(mid)),
+        _mm_add_epi64(_mm_srli_epi64::<32>(lh), _mm_srli_epi64::<32>(hl)),
+    );
+    __m128x2i(lo, hi)
 }
 
-#[inline]
+#[inline(always)]
 /// Widening multiplication i64 in i128
 pub unsafe fn _mm_mull_epi64(a: __m128i, b: __m128i) -> __m128x2i {
-    let sign_ab = _mm_srli_epi64::<63>(a);
-    let sign_cd = _mm_srli_epi64::<63>(b);
-    let sign = _mm_xor_si128(sign_ab, sign_cd);
-    let uab = _mm_abs_epi64(a);
-    let ucd = _mm_abs_epi64(b);
-    let product = _mm_mull_epu64(uab, ucd);
-    let is_not_negative = _mm_cmpeq_epi64(sign, _mm_setzero_si128());
-    let lo = _mm_select_epi64(is_not_negative, product.0, _mm_not_epi64(product.0));
-    let hi = _mm_select_epi64(is_not_negative, product.1, _mm_not_epi64(product.1));
-    let v0 = _mm_select_epi64(is_not_negative, lo, _mm_add_epi64(lo, _mm_set1_epi64x(1)));
-    let v1 = hi;
-    __m128x2i(v0, v1)
-}
-
-#[inline]
+    let product = _mm_mull_epu64(a, b);
+    let a_neg = _mm_cmplt_epi64(a, _mm_setzero_si128());
+    let b_neg = _mm_cmplt_epi64(b, _mm_setzero_si128());
+    let correction = _mm_add_epi64(_mm_and_si128(a_neg, b), _mm_and_si128(b_neg, a));
+    __m128x2i(product.0, _mm_sub_epi64(product.1, correction))
+}
+
+#[inline(always)]
 /// Shifts right u128 immediate
 pub unsafe fn _mm_srli_epi128x<const IMM: i32>(a: __m128x2i) -> __m128x2i {
     if IMM <= 0 {
@@ -83,17 +69,17 @@ pub unsafe fn _mm_srli_epi128x<const IMM: i32>(a: __m128x2i) -> __m128x2i {
         let new_upper = _mm_srli_epi64::<IMM>(a.1);
         __m128x2i(new_lower, new_upper)
     } else {
-        let shr_value = _mm_set1_epi64x(64i64 - IMM as i64);
-        __m128x2i(_mm_sllv_epi64x(a.1, shr_value), _mm_set1_epi64x(0))
+        let v_imm = _mm_set1_epi64x(IMM as i64 - 64i64);
+        __m128x2i(_mm_srl_epi64(a.1, v_imm), _mm_set1_epi64x(0))
     }
 }
 
-#[inline]
+#[inline(always)]
 /// Shifts left u128 immediate
 pub unsafe fn _mm_slli_epi128x<const IMM: i32>(a: __m128x2i) -> __m128x2i {
     if IMM >= 64 {
         let v_imm = _mm_set1_epi64x(IMM as i64 - 64i64);
-        __m128x2i(_mm_set1_epi64x(0), _mm_sll_epi64(a.1, v_imm))
+        __m128x2i(_mm_set1_epi64x(0), _mm_sll_epi64(a.0, v_imm))
     } else {
         let v_imm = _mm_set1_epi64x(IMM as i64);
         let lo = _mm_sll_epi64(a.0, v_imm);
@@ -104,56 +90,55 @@ pub unsafe fn _mm_slli_epi128x<const IMM: i32>(a: __m128x2i) -> __m128x2i {
     }
 }
 
-#[inline]
+#[inline(always)]
 /// Widening add 64 bytes integer to 128 bytes integer
 pub unsafe fn _mm_addw_epi128(a: __m128x2i, b: __m128i) -> __m128x2i {
-    let r0 = _mm_add_epi64(a.0, b);
-    let v = _mm_sub_epi64(r0, a.0);
-    __m128x2i(
-        r0,
-        _mm_add_epi64(
-            _mm_sub_epi64(a.0, _mm_sub_epi64(r0, v)),
-            _mm_add_epi64(_mm_sub_epi64(b, v), a.1),
-        ),
-    )
+    let lo = _mm_add_epi64(a.0, b);
+    let carry = _mm_cmplt_epu64(lo, a.0);
+    let b_hi = _mm_cmplt_epi64(b, _mm_setzero_si128());
+    __m128x2i(lo, _mm_sub_epi64(_mm_add_epi64(a.1, b_hi), carry))
 }
 
-#[inline]
+#[inline(always)]
 /// Widening substract 64 bytes integer to 128 bytes integer
 pub unsafe fn _mm_subw_epi128(a: __m128x2i, b: __m128i) -> __m128x2i {
-    _mm_addw_epi128(a, _mm_neg_epi64(b))
+    let lo = _mm_sub_epi64(a.0, b);
+    let borrow = _mm_cmplt_epu64(a.0, b);
+    let b_hi = _mm_cmplt_epi64(b, _mm_setzero_si128());
+    __m128x2i(lo, _mm_add_epi64(_mm_sub_epi64(a.1, b_hi), borrow))
 }
 
-#[inline]
-/// Saturates 128-bit integers presentation into 64 bits
+#[inline(always)]
+/// Narrows 128-bit integers into 64 bits, keeps only lower half
 pub unsafe fn _mm_movn_epi128(a: __m128x2i) -> __m128i {
-    _mm_add_epi64(a.0, a.1)
+    a.0
 }
 
-#[inline]
+#[inline(always)]
 /// Takes absolute value for i128
 pub unsafe fn _mm_abs_epi128(a: __m128x2i) -> __m128x2i {
     let is_neg = _mm_cmplt_epi64(a.1, _mm_setzero_si128());
+    let lo_zero = _mm_cmpeq_epi64x(a.0, _mm_setzero_si128());
     __m128x2i(
         _mm_select_epi64(is_neg, _mm_neg_epi64(a.0), a.0),
-        _mm_select_epi64(is_neg, _mm_neg_epi64(a.1), a.1),
+        _mm_select_epi64(is_neg, _mm_sub_epi64(_mm_not_epi64(a.1), lo_zero), a.1),
     )
 }
 
 /// Computes i128 as u64 and extracts lower half in general register
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_extract_lo_epi128<const IMM: i32>(d: __m128x2i) -> i64 {
     _mm_extract_epi64x::<IMM>(d.0)
 }
 
 /// Computes i128 as u64 and extracts upper half in general register
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_extract_hi_epi128<const IMM: i32>(d: __m128x2i) -> i64 {
     _mm_extract_epi64x::<IMM>(d.1)
 }
 
 /// Computes u128 as u128 and extracts in general register
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_extract_epu128<const IMM: i32>(d: __m128x2i) -> u128 {
     let lo = (_mm_extract_epi64x::<IMM>(d.0) as u64) as u128;
     let hi = (_mm_extract_epi64x::<IMM>(d.1) as u64) as u128;
@@ -161,51 +146,304 @@ pub unsafe fn _mm_extract_epu128<const IMM: i32>(d: __m128x2i) -> u128 {
 }
 
 /// Computes u128 as u128 and extracts in general register
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_extract_epi128<const IMM: i32>(d: __m128x2i) -> i128 {
     let lo = (_mm_extract_epi64x::<IMM>(d.0) as u64) as u128;
     let hi = (_mm_extract_epi64x::<IMM>(d.1) as u64) as u128;
     (lo | (hi << 64)) as i128
 }
 
-#[inline]
-/// Adds s128 to s128 using signed addition
-pub unsafe fn _mm_add_epi128(a: __m128x2i, b: __m128x2i) -> __m128x2i {
-    let lo = _mm_add_epi64(a.0, b.0);
+/// Builds 128-bit integers from low and high 64-bit halves
+#[inline(always)]
+pub unsafe fn _mm_combine_epi128(lo: __m128i, hi: __m128i) -> __m128x2i {
+    __m128x2i(lo, hi)
+}
 
-    let overflow = _mm_cmplt_epi64(
-        _mm_and_si128(
-            _mm_not_epi64(_mm_xor_si128(a.0, b.0)),
-            _mm_xor_si128(a.0, lo),
-        ),
-        _mm_setzero_si128(),
-    );
+/// Sets 128-bit integers, `e0` goes to the first lane
+#[inline(always)]
+pub unsafe fn _mm_set_epi128(e1: i128, e0: i128) -> __m128x2i {
+    __m128x2i(
+        _mm_set_epi64x(e1 as i64, e0 as i64),
+        _mm_set_epi64x((e1 >> 64) as i64, (e0 >> 64) as i64),
+    )
+}
+
+/// Broadcasts 128-bit integer to all lanes
+#[inline(always)]
+pub unsafe fn _mm_set1_epi128(a: i128) -> __m128x2i {
+    _mm_set_epi128(a, a)
+}
 
-    let mut carry = _mm_select_epi64(overflow, _mm_set1_epi64x(1), _mm_set1_epi64x(0));
-    carry = _mm_select_epi64(
-        _mm_cmplt_epi64(b.1, _mm_setzero_si128()),
-        _mm_set1_epi64x(-1),
-        carry,
+/// Loads two 128-bit integers from unaligned memory
+#[inline(always)]
+pub unsafe fn _mm_loadu_epi128(mem_addr: *const i128) -> __m128x2i {
+    let v0 = _mm_loadu_si128(mem_addr as *const __m128i);
+    let v1 = _mm_loadu_si128((mem_addr as *const __m128i).add(1));
+    __m128x2i(_mm_unpacklo_epi64(v0, v1), _mm_unpackhi_epi64(v0, v1))
+}
+
+/// Stores two 128-bit integers into unaligned memory
+#[inline(always)]
+pub unsafe fn _mm_storeu_epi128(mem_addr: *mut i128, a: __m128x2i) {
+    _mm_storeu_si128(mem_addr as *mut __m128i, _mm_unpacklo_epi64(a.0, a.1));
+    _mm_storeu_si128(
+        (mem_addr as *mut __m128i).add(1),
+        _mm_unpackhi_epi64(a.0, a.1),
     );
-    __m128x2i(lo, _mm_add_epi64(_mm_add_epi64(a.1, b.1), carry))
 }
 
-#[inline]
+#[inline(always)]
+/// Adds s128 to s128 using signed addition
+pub unsafe fn _mm_add_epi128(a: __m128x2i, b: __m128x2i) -> __m128x2i {
+    _mm_add_epu128(a, b)
+}
+
+#[inline(always)]
 /// Adds s128 to s128 using unsigned addition
 pub unsafe fn _mm_add_epu128(a: __m128x2i, b: __m128x2i) -> __m128x2i {
     let lo = _mm_add_epi64(a.0, b.0);
-    let carry = _mm_select_epi64(
-        _mm_cmplt_epu64(lo, a.0),
-        _mm_set1_epi64x(1),
-        _mm_setzero_si128(),
-    );
-    __m128x2i(lo, _mm_add_epi64(_mm_add_epi64(a.1, b.1), carry))
+    let carry = _mm_cmplt_epu64(lo, a.0);
+    __m128x2i(lo, _mm_sub_epi64(_mm_add_epi64(a.1, b.1), carry))
+}
+
+#[inline(always)]
+/// Subtracts s128 from s128
+pub unsafe fn _mm_sub_epi128(a: __m128x2i, b: __m128x2i) -> __m128x2i {
+    let lo = _mm_sub_epi64(a.0, b.0);
+    let borrow = _mm_cmplt_epu64(a.0, b.0);
+    __m128x2i(lo, _mm_add_epi64(_mm_sub_epi64(a.1, b.1), borrow))
+}
+
+#[inline(always)]
+/// Multiplies 128-bit integers, takes only lower 128 bits, same for signed and unsigned
+pub unsafe fn _mm_mul_epi128(a: __m128x2i, b: __m128x2i) -> __m128x2i {
+    let product = _mm_mull_epu64(a.0, b.0);
+    let cross = _mm_add_epi64(_mm_mul_epu64(a.0, b.1), _mm_mul_epu64(a.1, b.0));
+    __m128x2i(product.0, _mm_add_epi64(product.1, cross))
+}
+
+#[inline(always)]
+/// Compare *equal to* 128-bit integers
+pub unsafe fn _mm_cmpeq_epi128(a: __m128x2i, b: __m128x2i) -> __m128i {
+    _mm_and_si128(_mm_cmpeq_epi64x(a.0, b.0), _mm_cmpeq_epi64x(a.1, b.1))
+}
+
+#[inline(always)]
+/// Compare *greater than* unsigned 128-bit integers
+pub unsafe fn _mm_cmpgt_epu128(a: __m128x2i, b: __m128x2i) -> __m128i {
+    let lo_gt = _mm_and_si128(_mm_cmpeq_epi64x(a.1, b.1), _mm_cmpgt_epu64(a.0, b.0));
+    _mm_or_si128(_mm_cmpgt_epu64(a.1, b.1), lo_gt)
+}
+
+#[inline(always)]
+/// Compare *greater than or equal to* unsigned 128-bit integers
+pub unsafe fn _mm_cmpge_epu128(a: __m128x2i, b: __m128x2i) -> __m128i {
+    _mm_not_epi64(_mm_cmpgt_epu128(b, a))
+}
+
+#[inline(always)]
+/// Compare *less than* unsigned 128-bit integers
+pub unsafe fn _mm_cmplt_epu128(a: __m128x2i, b: __m128x2i) -> __m128i {
+    _mm_cmpgt_epu128(b, a)
+}
+
+#[inline(always)]
+/// Compare *less than or equal to* unsigned 128-bit integers
+pub unsafe fn _mm_cmple_epu128(a: __m128x2i, b: __m128x2i) -> __m128i {
+    _mm_cmpge_epu128(b, a)
+}
+
+#[inline(always)]
+/// Compare *greater than* signed 128-bit integers
+pub unsafe fn _mm_cmpgt_epi128(a: __m128x2i, b: __m128x2i) -> __m128i {
+    let lo_gt = _mm_and_si128(_mm_cmpeq_epi64x(a.1, b.1), _mm_cmpgt_epu64(a.0, b.0));
+    _mm_or_si128(_mm_cmpgt_epi64x(a.1, b.1), lo_gt)
+}
+
+#[inline(always)]
+/// Compare *greater than or equal to* signed 128-bit integers
+pub unsafe fn _mm_cmpge_epi128(a: __m128x2i, b: __m128x2i) -> __m128i {
+    _mm_not_epi64(_mm_cmpgt_epi128(b, a))
+}
+
+#[inline(always)]
+/// Compare *less than* signed 128-bit integers
+pub unsafe fn _mm_cmplt_epi128(a: __m128x2i, b: __m128x2i) -> __m128i {
+    _mm_cmpgt_epi128(b, a)
+}
+
+#[inline(always)]
+/// Compare *less than or equal to* signed 128-bit integers
+pub unsafe fn _mm_cmple_epi128(a: __m128x2i, b: __m128x2i) -> __m128i {
+    _mm_cmpge_epi128(b, a)
+}
+
+#[inline(always)]
+/// Selects 128-bit integers by 64-bit lane mask, mask must be all ones or all zeros per lane
+pub unsafe fn _mm_select_epi128(
+    mask: __m128i,
+    true_vals: __m128x2i,
+    false_vals: __m128x2i,
+) -> __m128x2i {
+    __m128x2i(
+        _mm_select_epi64(mask, true_vals.0, false_vals.0),
+        _mm_select_epi64(mask, true_vals.1, false_vals.1),
+    )
+}
+
+#[inline(always)]
+/// Takes minimum of unsigned 128-bit integers
+pub unsafe fn _mm_min_epu128(a: __m128x2i, b: __m128x2i) -> __m128x2i {
+    _mm_select_epi128(_mm_cmplt_epu128(a, b), a, b)
+}
+
+#[inline(always)]
+/// Takes maximum of unsigned 128-bit integers
+pub unsafe fn _mm_max_epu128(a: __m128x2i, b: __m128x2i) -> __m128x2i {
+    _mm_select_epi128(_mm_cmpgt_epu128(a, b), a, b)
+}
+
+#[inline(always)]
+/// Takes minimum of signed 128-bit integers
+pub unsafe fn _mm_min_epi128(a: __m128x2i, b: __m128x2i) -> __m128x2i {
+    _mm_select_epi128(_mm_cmplt_epi128(a, b), a, b)
+}
+
+#[inline(always)]
+/// Takes maximum of signed 128-bit integers
+pub unsafe fn _mm_max_epi128(a: __m128x2i, b: __m128x2i) -> __m128x2i {
+    _mm_select_epi128(_mm_cmpgt_epi128(a, b), a, b)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const VALUES: [u64; 9] = [
+        0,
+        1,
+        27,
+        u64::MAX,
+        1 << 63,
+        0x0123_4567_89ab_cdef,
+        (i64::MIN + 1) as u64,
+        0xffff_0000_ffff_0000,
+        -27i64 as u64,
+    ];
+
+    unsafe fn lanes(d: __m128x2i) -> [u128; 2] {
+        [_mm_extract_epu128::<0>(d), _mm_extract_epu128::<1>(d)]
+    }
+
+    #[test]
+    fn test_against_native() {
+        unsafe {
+            for &a in VALUES.iter() {
+                for &b in VALUES.iter() {
+                    let va = _mm_set_epi64x(b as i64, a as i64);
+                    let vb = _mm_set_epi64x(a as i64, b as i64);
+                    let x = __m128x2i(va, vb);
+                    let y = __m128x2i(vb, va);
+                    let xs = [a as u128 | (b as u128) << 64, b as u128 | (a as u128) << 64];
+                    let ys = [xs[1], xs[0]];
+                    let bs = [b, a];
+
+                    let m = lanes(_mm_mull_epu64(va, vb));
+                    assert_eq!(m, [a as u128 * b as u128; 2]);
+                    let m = lanes(_mm_mull_epi64(va, vb));
+                    assert_eq!(m, [(a as i64 as i128 * b as i64 as i128) as u128; 2]);
+
+                    let add = lanes(_mm_add_epu128(x, y));
+                    let add_s = lanes(_mm_add_epi128(x, y));
+                    let addw = lanes(_mm_addw_epi128(x, vb));
+                    let subw = lanes(_mm_subw_epi128(x, vb));
+                    let abs = lanes(_mm_abs_epi128(x));
+                    let movn = _mm_movn_epi128(x);
+                    assert_eq!(_mm_extract_epi64x::<0>(movn), a as i64);
+                    assert_eq!(_mm_extract_epi64x::<1>(movn), b as i64);
+                    for i in 0..2 {
+                        assert_eq!(add[i], xs[i].wrapping_add(ys[i]));
+                        assert_eq!(add_s[i], xs[i].wrapping_add(ys[i]));
+                        let w = bs[i] as i64 as i128 as u128;
+                        assert_eq!(addw[i], xs[i].wrapping_add(w));
+                        assert_eq!(subw[i], xs[i].wrapping_sub(w));
+                        assert_eq!(abs[i], (xs[i] as i128).wrapping_abs() as u128);
+                    }
+
+                    macro_rules! check_shifts {
+                        ($($n:literal),*) => {
+                            $(
+                                let v = lanes(_mm_srli_epi128x::<$n>(x));
+                                assert_eq!(v, [xs[0] >> $n, xs[1] >> $n]);
+                                let v = lanes(_mm_slli_epi128x::<$n>(x));
+                                assert_eq!(v, [xs[0] << $n, xs[1] << $n]);
+                            )*
+                        };
+                    }
+                    check_shifts!(0, 1, 10, 32, 63, 64, 70, 127);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_compare_against_native() {
+        unsafe {
+            for &a in VALUES.iter() {
+                for &b in VALUES.iter() {
+                    let va = _mm_set_epi64x(b as i64, a as i64);
+                    let vb = _mm_set_epi64x(a as i64, b as i64);
+                    let x = __m128x2i(va, vb);
+                    let xs = lanes(x);
+                    for y in [__m128x2i(vb, va), __m128x2i(vb, vb), __m128x2i(va, va)] {
+                        let ys = lanes(y);
+                        let sub = lanes(_mm_sub_epi128(x, y));
+                        let mul = lanes(_mm_mul_epi128(x, y));
+                        let min_u = lanes(_mm_min_epu128(x, y));
+                        let max_u = lanes(_mm_max_epu128(x, y));
+                        let min_s = lanes(_mm_min_epi128(x, y));
+                        let max_s = lanes(_mm_max_epi128(x, y));
+                        let masks = [
+                            _mm_cmpeq_epi128(x, y),
+                            _mm_cmpgt_epu128(x, y),
+                            _mm_cmpge_epu128(x, y),
+                            _mm_cmplt_epu128(x, y),
+                            _mm_cmple_epu128(x, y),
+                            _mm_cmpgt_epi128(x, y),
+                            _mm_cmpge_epi128(x, y),
+                            _mm_cmplt_epi128(x, y),
+                            _mm_cmple_epi128(x, y),
+                        ]
+                        .map(|m| [_mm_extract_epi64x::<0>(m), _mm_extract_epi64x::<1>(m)]);
+                        for i in 0..2 {
+                            assert_eq!(sub[i], xs[i].wrapping_sub(ys[i]));
+                            assert_eq!(mul[i], xs[i].wrapping_mul(ys[i]));
+                            assert_eq!(min_u[i], xs[i].min(ys[i]));
+                            assert_eq!(max_u[i], xs[i].max(ys[i]));
+                            let (sx, sy) = (xs[i] as i128, ys[i] as i128);
+                            assert_eq!(min_s[i], sx.min(sy) as u128);
+                            assert_eq!(max_s[i], sx.max(sy) as u128);
+                            let control = [
+                                xs[i] == ys[i],
+                                xs[i] > ys[i],
+                                xs[i] >= ys[i],
+                                xs[i] < ys[i],
+                                xs[i] <= ys[i],
+                                sx > sy,
+                                sx >= sy,
+                                sx < sy,
+                                sx <= sy,
+                            ];
+                            for (mask, flag) in masks.iter().zip(control.iter()) {
+                                assert_eq!(mask[i], if *flag { -1 } else { 0 });
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_addition() {
         unsafe {
@@ -354,4 +592,31 @@ mod tests {
             assert_eq!(flag_1, control);
         }
     }
+
+    #[test]
+    fn test_load_store() {
+        unsafe {
+            let values = [
+                0x0123_4567_89ab_cdef_fedc_ba98_7654_3210i128,
+                -27,
+                i128::MIN,
+                i128::MAX,
+            ];
+            let v = _mm_loadu_epi128(values.as_ptr());
+            assert_eq!(_mm_extract_epi128::<0>(v), values[0]);
+            assert_eq!(_mm_extract_epi128::<1>(v), values[1]);
+            let mut out = [0i128; 2];
+            _mm_storeu_epi128(out.as_mut_ptr(), v);
+            assert_eq!(out, [values[0], values[1]]);
+
+            let v = _mm_set_epi128(values[3], values[2]);
+            assert_eq!(_mm_extract_epi128::<0>(v), values[2]);
+            assert_eq!(_mm_extract_epi128::<1>(v), values[3]);
+            let v = _mm_set1_epi128(values[0]);
+            assert_eq!(lanes(v), [values[0] as u128; 2]);
+            let v = _mm_combine_epi128(_mm_set_epi64x(2, 1), _mm_set_epi64x(-1, 0));
+            assert_eq!(_mm_extract_epi128::<0>(v), 1);
+            assert_eq!(_mm_extract_epi128::<1>(v), -(1i128 << 64) + 2);
+        }
+    }
 }
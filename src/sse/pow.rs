@@ -11,35 +11,41 @@ use std::arch::x86_64::*;
 
 use crate::{
     _mm_abs_pd, _mm_copysign_pd, _mm_exp_fast_pd, _mm_exp_pd, _mm_isinf_pd, _mm_isnan_pd,
-    _mm_isneginf_pd, _mm_isnotintegral_pd, _mm_ln_fast_pd, _mm_ln_pd, _mm_select_pd,
+    _mm_isnotintegral_pd, _mm_ln_fast_pd, _mm_ln_pd, _mm_select_pd,
 };
 
-#[inline]
+#[inline(always)]
 /// Computes pow function *ULP 2.0*
 pub unsafe fn _mm_pow_pd(d: __m128d, n: __m128d) -> __m128d {
-    let mut c = _mm_exp_pd(_mm_mul_pd(n, _mm_ln_pd(_mm_abs_pd(d))));
-    c = _mm_copysign_pd(c, d);
-    let is_infinity = _mm_or_pd(
-        _mm_isinf_pd(d),
-        _mm_or_pd(_mm_isinf_pd(n), _mm_isneginf_pd(n)),
+    let c = _mm_exp_pd(_mm_mul_pd(n, _mm_ln_pd(_mm_abs_pd(d))));
+    // Sign of the base is kept only for odd integral powers
+    let is_odd = _mm_andnot_pd(
+        _mm_isnotintegral_pd(n),
+        _mm_isnotintegral_pd(_mm_mul_pd(n, _mm_set1_pd(0.5))),
+    );
+    let mut ret = _mm_select_pd(is_odd, _mm_copysign_pd(c, d), c);
+    // Not integral powers are not allowed for negative finite numbers
+    let is_neg_finite = _mm_and_pd(
+        _mm_cmplt_pd(d, _mm_setzero_pd()),
+        _mm_cmpgt_pd(d, _mm_set1_pd(f64::NEG_INFINITY)),
     );
-    let is_power_neg_infinity = _mm_isneginf_pd(n);
-    // Not integral values do not allowed for negative numbers
-    let inf = _mm_set1_pd(f64::INFINITY);
-    let is_nan_with_integral =
-        _mm_and_pd(_mm_cmplt_pd(d, _mm_setzero_pd()), _mm_isnotintegral_pd(n));
+    let is_nan_with_integral = _mm_and_pd(is_neg_finite, _mm_isnotintegral_pd(n));
     let is_any_nan = _mm_or_pd(
         _mm_or_pd(_mm_isnan_pd(d), _mm_isnan_pd(n)),
         is_nan_with_integral,
     );
-    let mut ret = _mm_select_pd(is_infinity, inf, c);
-    ret = _mm_select_pd(is_power_neg_infinity, _mm_set1_pd(0.), ret);
     ret = _mm_select_pd(is_any_nan, _mm_set1_pd(f64::NAN), ret);
-    ret
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN, pow(-1, ±inf) = 1
+    let ones = _mm_set1_pd(1.);
+    let is_one = _mm_or_pd(
+        _mm_or_pd(_mm_cmpeq_pd(n, _mm_setzero_pd()), _mm_cmpeq_pd(d, ones)),
+        _mm_and_pd(_mm_cmpeq_pd(_mm_abs_pd(d), ones), _mm_isinf_pd(n)),
+    );
+    _mm_select_pd(is_one, ones, ret)
 }
 
 /// Method that computes pow skipping Inf, Nan checks, *ULP 2.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_pow_fast_pd(d: __m128d, n: __m128d) -> __m128d {
     let mut c = _mm_exp_fast_pd(_mm_mul_pd(n, _mm_ln_fast_pd(d)));
     c = _mm_copysign_pd(c, d);
@@ -11,34 +11,41 @@ use std::arch::x86_64::*;
 
 use crate::{
     _mm_abs_ps, _mm_copysign_ps, _mm_exp_fast_ps, _mm_exp_ps, _mm_isinf_ps, _mm_isnan_ps,
-    _mm_isneginf_ps, _mm_isnotintegral_ps, _mm_ln_fast_ps, _mm_ln_ps, _mm_select_ps,
+    _mm_isnotintegral_ps, _mm_ln_fast_ps, _mm_ln_ps, _mm_select_ps,
 };
 
-#[inline]
+#[inline(always)]
 /// Computes pow function *ULP 2.0*
 pub unsafe fn _mm_pow_ps(d: __m128, n: __m128) -> __m128 {
-    let mut c = _mm_exp_ps(_mm_mul_ps(n, _mm_ln_ps(_mm_abs_ps(d))));
-    c = _mm_copysign_ps(c, d);
-    let is_infinity = _mm_or_ps(
-        _mm_isinf_ps(d),
-        _mm_or_ps(_mm_isinf_ps(n), _mm_isneginf_ps(n)),
+    let c = _mm_exp_ps(_mm_mul_ps(n, _mm_ln_ps(_mm_abs_ps(d))));
+    // Sign of the base is kept only for odd integral powers
+    let is_odd = _mm_andnot_ps(
+        _mm_isnotintegral_ps(n),
+        _mm_isnotintegral_ps(_mm_mul_ps(n, _mm_set1_ps(0.5))),
+    );
+    let mut ret = _mm_select_ps(is_odd, _mm_copysign_ps(c, d), c);
+    // Not integral powers are not allowed for negative finite numbers
+    let is_neg_finite = _mm_and_ps(
+        _mm_cmplt_ps(d, _mm_setzero_ps()),
+        _mm_cmpgt_ps(d, _mm_set1_ps(f32::NEG_INFINITY)),
     );
-    let is_power_neg_infinity = _mm_isneginf_ps(n);
-    let inf = _mm_set1_ps(f32::INFINITY);
-    let is_nan_with_integral =
-        _mm_and_ps(_mm_cmplt_ps(d, _mm_setzero_ps()), _mm_isnotintegral_ps(n));
+    let is_nan_with_integral = _mm_and_ps(is_neg_finite, _mm_isnotintegral_ps(n));
     let is_any_nan = _mm_or_ps(
         _mm_or_ps(_mm_isnan_ps(d), _mm_isnan_ps(n)),
         is_nan_with_integral,
     );
-    let mut ret = _mm_select_ps(is_infinity, inf, c);
-    ret = _mm_select_ps(is_power_neg_infinity, _mm_set1_ps(0f32), ret);
     ret = _mm_select_ps(is_any_nan, _mm_set1_ps(f32::NAN), ret);
-    ret
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN, pow(-1, ±inf) = 1
+    let ones = _mm_set1_ps(1.);
+    let is_one = _mm_or_ps(
+        _mm_or_ps(_mm_cmpeq_ps(n, _mm_setzero_ps()), _mm_cmpeq_ps(d, ones)),
+        _mm_and_ps(_mm_cmpeq_ps(_mm_abs_ps(d), ones), _mm_isinf_ps(n)),
+    );
+    _mm_select_ps(is_one, ones, ret)
 }
 
 /// Method that computes pow skipping Inf, Nan checks, *ULP 2.0*
-#[inline]
+#[inline(always)]
 pub unsafe fn _mm_pow_fast_ps(d: __m128, n: __m128) -> __m128 {
     let mut c = _mm_exp_fast_ps(_mm_mul_ps(n, _mm_ln_fast_ps(d)));
     c = _mm_copysign_ps(c, d);
@@ -48,6 +55,7 @@ pub unsafe fn _mm_pow_fast_ps(d: __m128, n: __m128) -> __m128 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_powf() {
@@ -56,7 +64,7 @@ mod tests {
             let value = _mm_set1_ps(15f32);
             let power = _mm_set1_ps(1. / 5.);
             let comparison = _mm_pow_ps(value, power);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert_eq!(flag_1, 15f32.powf(1. / 5.));
         }
 
@@ -64,7 +72,7 @@ mod tests {
             let value = _mm_set1_ps(15f32);
             let power = _mm_set1_ps(-1. / 5.);
             let comparison = _mm_pow_ps(value, power);
-            let rs = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let rs = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             let flag_1 = rs.to_bits();
             let origin = 0.5818107f32.to_bits();
             let diff = flag_1.max(origin) - flag_1.min(origin);
@@ -75,7 +83,7 @@ mod tests {
             let value = _mm_set1_ps(-15f32);
             let power = _mm_set1_ps(1. / 5.);
             let comparison = _mm_pow_ps(value, power);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert!(flag_1.is_nan());
         }
 
@@ -83,7 +91,7 @@ mod tests {
             let value = _mm_set1_ps(-15f32);
             let power = _mm_set1_ps(-1. / 5.);
             let comparison = _mm_pow_ps(value, power);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             assert!(flag_1.is_nan());
         }
     }
@@ -0,0 +1,197 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::precision::simd_math_impl;
+
+/// Evaluates f32 lanes with an f64 kernel, so result is rounded only once
+macro_rules! widen_ps {
+    ($f:path, ($x:ident)) => {{
+        let lo = $f(_mm_cvtps_pd($x));
+        let hi = $f(_mm_cvtps_pd(_mm_movehl_ps($x, $x)));
+        _mm_movelh_ps(_mm_cvtpd_ps(lo), _mm_cvtpd_ps(hi))
+    }};
+    ($f:path, ($x:ident, $y:ident)) => {{
+        let lo = $f(_mm_cvtps_pd($x), _mm_cvtps_pd($y));
+        let hi = $f(
+            _mm_cvtps_pd(_mm_movehl_ps($x, $x)),
+            _mm_cvtps_pd(_mm_movehl_ps($y, $y)),
+        );
+        _mm_movelh_ps(_mm_cvtpd_ps(lo), _mm_cvtpd_ps(hi))
+    }};
+}
+
+simd_math_impl! {
+    __m128d, f64,
+    exp(x) => crate::_mm_exp_pd, fast: crate::_mm_exp_fast_pd;
+    exp2(x) => crate::_mm_exp2_pd;
+    exp10(x) => crate::_mm_exp10_pd;
+    expm1(x) => crate::_mm_expm1_pd;
+    ln(x) => crate::_mm_ln_pd, fast: crate::_mm_ln_fast_pd;
+    log2(x) => crate::_mm_log2_pd;
+    log10(x) => crate::_mm_log10_pd;
+    log1p(x) => crate::_mm_log1p_pd;
+    sin(x) => crate::_mm_sin_pd;
+    cos(x) => crate::_mm_cos_pd;
+    tan(x) => crate::_mm_tan_pd;
+    asin(x) => crate::_mm_asin_pd;
+    acos(x) => crate::_mm_acos_pd;
+    atan(x) => crate::_mm_atan_pd;
+    sinh(x) => crate::_mm_sinh_pd;
+    cosh(x) => crate::_mm_cosh_pd;
+    tanh(x) => crate::_mm_tanh_pd;
+    asinh(x) => crate::_mm_asinh_pd;
+    acosh(x) => crate::_mm_acosh_pd;
+    atanh(x) => crate::_mm_atanh_pd;
+    cbrt(x) => crate::_mm_cbrt_pd, fast: crate::_mm_cbrt_fast_pd;
+    erf(x) => crate::_mm_erf_pd;
+    erfc(x) => crate::_mm_erfc_pd;
+    erfinv(x) => crate::_mm_erfinv_pd;
+    tgamma(x) => crate::_mm_tgamma_pd;
+    digamma(x) => crate::_mm_digamma_pd;
+    pow(x, n) => crate::_mm_pow_pd, fast: crate::_mm_pow_fast_pd;
+    atan2(y, x) => crate::_mm_atan2_pd;
+    hypot(x, y) => crate::_mm_hypot_pd, fast: crate::_mm_hypot_fast_pd;
+    fmod(x, y) => crate::_mm_fmod_pd;
+    hypot3(x, y, z) => crate::_mm_hypot3_pd, fast: crate::_mm_hypot3_fast_pd;
+    hypot4(x, y, z, w) => crate::_mm_hypot4_pd, fast: crate::_mm_hypot4_fast_pd;
+}
+
+simd_math_impl! {
+    __m128, f32,
+    exp(x) => crate::_mm_exp_ps, fast: crate::_mm_exp_fast_ps, high: crate::_mm_exp_pd, cr: crate::cr::expf;
+    exp2(x) => crate::_mm_exp2_ps, high: crate::_mm_exp2_pd, cr: crate::cr::exp2f;
+    exp10(x) => crate::_mm_exp10_ps, high: crate::_mm_exp10_pd, cr: crate::cr::exp10f;
+    expm1(x) => crate::_mm_expm1_ps, high: crate::_mm_expm1_pd;
+    ln(x) => crate::_mm_ln_ps, fast: crate::_mm_ln_fast_ps, high: crate::_mm_ln_pd, cr: crate::cr::lnf;
+    log2(x) => crate::_mm_log2_ps, high: crate::_mm_log2_pd, cr: crate::cr::log2f;
+    log10(x) => crate::_mm_log10_ps, high: crate::_mm_log10_pd, cr: crate::cr::log10f;
+    log1p(x) => crate::_mm_log1p_ps, high: crate::_mm_log1p_pd;
+    sin(x) => crate::_mm_sin_ps, high: crate::_mm_sin_pd, cr: crate::cr::sinf;
+    cos(x) => crate::_mm_cos_ps, high: crate::_mm_cos_pd, cr: crate::cr::cosf;
+    tan(x) => crate::_mm_tan_ps, high: crate::_mm_tan_pd, cr: crate::cr::tanf;
+    asin(x) => crate::_mm_asin_ps, high: crate::_mm_asin_pd, cr: crate::cr::asinf;
+    acos(x) => crate::_mm_acos_ps, high: crate::_mm_acos_pd, cr: crate::cr::acosf;
+    atan(x) => crate::_mm_atan_ps, high: crate::_mm_atan_pd, cr: crate::cr::atanf;
+    sinh(x) => crate::_mm_sinh_ps, high: crate::_mm_sinh_pd;
+    cosh(x) => crate::_mm_cosh_ps, high: crate::_mm_cosh_pd;
+    tanh(x) => crate::_mm_tanh_ps, high: crate::_mm_tanh_pd;
+    asinh(x) => crate::_mm_asinh_ps, high: crate::_mm_asinh_pd;
+    acosh(x) => crate::_mm_acosh_ps, high: crate::_mm_acosh_pd;
+    atanh(x) => crate::_mm_atanh_ps, high: crate::_mm_atanh_pd;
+    cbrt(x) => crate::_mm_cbrt_ps, fast: crate::_mm_cbrt_fast_ps, high: crate::_mm_cbrt_pd, cr: crate::cr::cbrtf;
+    erf(x) => crate::_mm_erf_ps, high: crate::_mm_erf_pd;
+    erfc(x) => crate::_mm_erfc_ps, high: crate::_mm_erfc_pd;
+    erfinv(x) => crate::_mm_erfinv_ps, high: crate::_mm_erfinv_pd;
+    tgamma(x) => crate::_mm_tgamma_ps, high: crate::_mm_tgamma_pd;
+    digamma(x) => crate::_mm_digamma_ps, high: crate::_mm_digamma_pd;
+    pow(x, n) => crate::_mm_pow_ps, fast: crate::_mm_pow_fast_ps, high: crate::_mm_pow_pd, cr: crate::cr::powf;
+    atan2(y, x) => crate::_mm_atan2_ps, high: crate::_mm_atan2_pd;
+    hypot(x, y) => crate::_mm_hypot_ps, fast: crate::_mm_hypot_fast_ps, high: crate::_mm_hypot_pd;
+    fmod(x, y) => crate::_mm_fmod_ps;
+    hypot3(x, y, z) => crate::_mm_hypot3_ps, fast: crate::_mm_hypot3_fast_ps;
+    hypot4(x, y, z, w) => crate::_mm_hypot4_ps, fast: crate::_mm_hypot4_fast_ps;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::precision::{CorrectlyRounded, Fast, HighAccuracy, ScalarMath, SimdMath, Standard};
+    use crate::{_mm_abs_ps, _mm_exp_fast_ps, _mm_exp_ps, _mm_sin_pd};
+
+    #[test]
+    fn test_tiers() {
+        unsafe {
+            let value = _mm_set1_ps(3.5f32);
+            let fast: f32 = _mm_cvtss_f32(Fast::exp(value));
+            assert_eq!(fast, _mm_cvtss_f32(_mm_exp_fast_ps(value)));
+            let standard: f32 = _mm_cvtss_f32(Standard::exp(value));
+            assert_eq!(standard, _mm_cvtss_f32(_mm_exp_ps(value)));
+            let value = _mm_set1_pd(0.7f64);
+            let high: f64 = _mm_cvtsd_f64(HighAccuracy::sin(value));
+            assert_eq!(high, _mm_cvtsd_f64(_mm_sin_pd(value)));
+            let value = _mm_set1_ps(0.7f32);
+            let correct: f32 = _mm_cvtss_f32(CorrectlyRounded::sin(value));
+            assert_eq!(correct, crate::cr::sinf(0.7f32));
+        }
+    }
+
+    #[test]
+    fn test_high_accuracy_ps() {
+        for i in 0..2000 {
+            let x = i as f32 * 0.0123f32 - 12.3f32;
+            unsafe {
+                let value = _mm_set1_ps(x);
+                let y = _mm_set1_ps(0.75f32);
+                let sin = _mm_cvtss_f32(HighAccuracy::sin(value));
+                let exp = _mm_cvtss_f32(HighAccuracy::exp(value));
+                let pow = _mm_cvtss_f32(HighAccuracy::pow(_mm_abs_ps(value), y));
+                assert!(
+                    ulp(sin, (x as f64).sin() as f32) <= 1,
+                    "sin({}) = {}",
+                    x,
+                    sin
+                );
+                assert!(
+                    ulp(exp, (x as f64).exp() as f32) <= 1,
+                    "exp({}) = {}",
+                    x,
+                    exp
+                );
+                let control = (x.abs() as f64).powf(0.75) as f32;
+                assert!(ulp(pow, control) <= 1, "pow({}) = {}", x, pow);
+            }
+        }
+    }
+
+    #[test]
+    fn test_extra_routines() {
+        unsafe {
+            let x = _mm_set1_ps(7.5f32);
+            let y = _mm_set1_ps(2f32);
+            let z = _mm_set1_ps(-3f32);
+            let fmod = _mm_cvtss_f32(HighAccuracy::fmod(x, y));
+            assert_eq!(fmod, 1.5f32);
+            let hypot3 = _mm_cvtss_f32(Standard::hypot3(x, y, z));
+            assert!(
+                ulp(hypot3, 69.25f64.sqrt() as f32) <= 1,
+                "hypot3 = {}",
+                hypot3
+            );
+            let hypot4 = _mm_cvtss_f32(Fast::hypot4(x, y, z, y));
+            assert!(
+                ulp(hypot4, 73.25f64.sqrt() as f32) <= 1,
+                "hypot4 = {}",
+                hypot4
+            );
+        }
+        assert_eq!(
+            <HighAccuracy as ScalarMath<f32>>::efmod(7.5f32, 2f32),
+            1.5f32
+        );
+        assert_eq!(<HighAccuracy as ScalarMath<f32>>::esqrt(2f32), 2f32.sqrt());
+        assert_eq!(
+            <Standard as ScalarMath<f64>>::eremainder(7.5f64, 2f64),
+            -0.5f64
+        );
+        assert_eq!(<Fast as ScalarMath<f64>>::efmax(f64::NAN, 1f64), 1f64);
+        assert_eq!(<Standard as ScalarMath<f64>>::efmin(-0.5f64, 1f64), -0.5f64);
+        let hypot4 = <CorrectlyRounded as ScalarMath<f32>>::ehypot4(7.5f32, 2f32, -3f32, 2f32);
+        assert!(
+            ulp(hypot4, 73.25f64.sqrt() as f32) <= 1,
+            "hypot4 = {}",
+            hypot4
+        );
+    }
+
+    fn ulp(a: f32, b: f32) -> i32 {
+        (a.to_bits() as i32).wrapping_sub(b.to_bits() as i32).abs()
+    }
+}
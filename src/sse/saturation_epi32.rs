@@ -12,7 +12,7 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
+#[inline(always)]
 /// Add unsigned 32 bytes integers using saturation
 pub unsafe fn _mm_adds_epu32(a: __m128i, b: __m128i) -> __m128i {
     let result = _mm_add_epi32(a, b);
@@ -25,7 +25,7 @@ pub unsafe fn _mm_adds_epu32(a: __m128i, b: __m128i) -> __m128i {
     )
 }
 
-#[inline]
+#[inline(always)]
 /// Add signed 32 bytes integers using saturation
 pub unsafe fn _mm_adds_epi32(a: __m128i, b: __m128i) -> __m128i {
     let res = _mm_add_epi32(a, b);
@@ -37,7 +37,7 @@ pub unsafe fn _mm_adds_epi32(a: __m128i, b: __m128i) -> __m128i {
     )
 }
 
-#[inline]
+#[inline(always)]
 /// Subtract signed integers 32 using saturation
 pub unsafe fn _mm_subs_epi32(lhs: __m128i, rhs: __m128i) -> __m128i {
     let res = _mm_sub_epi32(lhs, rhs);
@@ -51,3 +51,256 @@ pub unsafe fn _mm_subs_epi32(lhs: __m128i, rhs: __m128i) -> __m128i {
         ),
     )
 }
+
+#[inline(always)]
+/// Subtract unsigned integers 32 using saturation
+pub unsafe fn _mm_subs_epu32(a: __m128i, b: __m128i) -> __m128i {
+    let result = _mm_sub_epi32(a, b);
+    _mm_andnot_si128(_mm_cmplt_epu32(a, b), result)
+}
+
+#[inline(always)]
+/// Widening u32 multiplication, returns lower and upper halves of the products
+unsafe fn _mm_mulw_epu32(a: __m128i, b: __m128i) -> (__m128i, __m128i) {
+    let even = _mm_mul_epu32(a, b);
+    let odd = _mm_mul_epu32(_mm_srli_epi64::<32>(a), _mm_srli_epi64::<32>(b));
+    let lo_mask = _mm_set1_epi64x(0xFFFFFFFF);
+    let lo = _mm_or_si128(_mm_and_si128(even, lo_mask), _mm_slli_epi64::<32>(odd));
+    let hi = _mm_or_si128(_mm_srli_epi64::<32>(even), _mm_andnot_si128(lo_mask, odd));
+    (lo, hi)
+}
+
+#[inline(always)]
+/// Multiply unsigned integers 32 using saturation
+pub unsafe fn _mm_muls_epu32(a: __m128i, b: __m128i) -> __m128i {
+    let (lo, hi) = _mm_mulw_epu32(a, b);
+    let overflow = _mm_xor_si128(_mm_cmpeq_epi32(hi, _mm_setzero_si128()), _mm_set1_epi32(-1));
+    _mm_or_si128(lo, overflow)
+}
+
+#[inline(always)]
+/// Multiply signed integers 32 using saturation
+pub unsafe fn _mm_muls_epi32(a: __m128i, b: __m128i) -> __m128i {
+    let (lo, hi) = _mm_mulw_epu32(a, b);
+    let correction = _mm_add_epi32(
+        _mm_and_si128(_mm_srai_epi32::<31>(a), b),
+        _mm_and_si128(_mm_srai_epi32::<31>(b), a),
+    );
+    let hi = _mm_sub_epi32(hi, correction);
+    let fits = _mm_cmpeq_epi32(hi, _mm_srai_epi32::<31>(lo));
+    let saturated = _mm_xor_si128(
+        _mm_set1_epi32(i32::MAX),
+        _mm_srai_epi32::<31>(_mm_xor_si128(a, b)),
+    );
+    _mm_blendv_epi32(saturated, lo, fits)
+}
+
+#[inline(always)]
+/// Shifts u32 left by the corresponding lane of `count`, counts greater than 31 give zero
+unsafe fn _mm_sllv_epi32x(a: __m128i, count: __m128i) -> __m128i {
+    #[cfg(target_feature = "avx2")]
+    {
+        _mm_sllv_epi32(a, count)
+    }
+    #[cfg(not(target_feature = "avx2"))]
+    {
+        let lane = _mm_set_epi32(0, 0, 0, -1);
+        let r0 = _mm_sll_epi32(a, _mm_and_si128(count, lane));
+        let r1 = _mm_sll_epi32(a, _mm_and_si128(_mm_srli_si128::<4>(count), lane));
+        let r2 = _mm_sll_epi32(a, _mm_and_si128(_mm_srli_si128::<8>(count), lane));
+        let r3 = _mm_sll_epi32(a, _mm_srli_si128::<12>(count));
+        _mm_or_si128(
+            _mm_or_si128(
+                _mm_and_si128(r0, lane),
+                _mm_and_si128(r1, _mm_slli_si128::<4>(lane)),
+            ),
+            _mm_or_si128(
+                _mm_and_si128(r2, _mm_slli_si128::<8>(lane)),
+                _mm_and_si128(r3, _mm_slli_si128::<12>(lane)),
+            ),
+        )
+    }
+}
+
+#[inline(always)]
+/// Shifts u32 right by the corresponding lane of `count`, counts greater than 31 give zero
+unsafe fn _mm_srlv_epi32x(a: __m128i, count: __m128i) -> __m128i {
+    #[cfg(target_feature = "avx2")]
+    {
+        _mm_srlv_epi32(a, count)
+    }
+    #[cfg(not(target_feature = "avx2"))]
+    {
+        let lane = _mm_set_epi32(0, 0, 0, -1);
+        let r0 = _mm_srl_epi32(a, _mm_and_si128(count, lane));
+        let r1 = _mm_srl_epi32(a, _mm_and_si128(_mm_srli_si128::<4>(count), lane));
+        let r2 = _mm_srl_epi32(a, _mm_and_si128(_mm_srli_si128::<8>(count), lane));
+        let r3 = _mm_srl_epi32(a, _mm_srli_si128::<12>(count));
+        _mm_or_si128(
+            _mm_or_si128(
+                _mm_and_si128(r0, lane),
+                _mm_and_si128(r1, _mm_slli_si128::<4>(lane)),
+            ),
+            _mm_or_si128(
+                _mm_and_si128(r2, _mm_slli_si128::<8>(lane)),
+                _mm_and_si128(r3, _mm_slli_si128::<12>(lane)),
+            ),
+        )
+    }
+}
+
+#[inline(always)]
+/// Shifts i32 right by the corresponding lane of `count`, counts greater than 31 fill with sign
+unsafe fn _mm_srav_epi32x(a: __m128i, count: __m128i) -> __m128i {
+    #[cfg(target_feature = "avx2")]
+    {
+        _mm_srav_epi32(a, count)
+    }
+    #[cfg(not(target_feature = "avx2"))]
+    {
+        let sign = _mm_srai_epi32::<31>(a);
+        _mm_xor_si128(_mm_srlv_epi32x(_mm_xor_si128(a, sign), count), sign)
+    }
+}
+
+#[inline(always)]
+/// Shift unsigned integers 32 left using saturation, same as *vqshlq_u32*.
+/// Shift is the lowest signed byte of `count` lane, negative shift is a truncating right shift
+pub unsafe fn _mm_shls_epu32(a: __m128i, count: __m128i) -> __m128i {
+    let shift = _mm_srai_epi32::<24>(_mm_slli_epi32::<24>(count));
+    let right = _mm_srlv_epi32x(a, _mm_sub_epi32(_mm_setzero_si128(), shift));
+    let left = _mm_sllv_epi32x(a, shift);
+    let overflow = _mm_xor_si128(
+        _mm_cmpeq_epi32(_mm_srlv_epi32x(left, shift), a),
+        _mm_set1_epi32(-1),
+    );
+    _mm_blendv_epi32(_mm_or_si128(left, overflow), right, shift)
+}
+
+#[inline(always)]
+/// Shift signed integers 32 left using saturation, same as *vqshlq_s32*.
+/// Shift is the lowest signed byte of `count` lane, negative shift is a truncating right shift
+pub unsafe fn _mm_shls_epi32(a: __m128i, count: __m128i) -> __m128i {
+    let shift = _mm_srai_epi32::<24>(_mm_slli_epi32::<24>(count));
+    let right = _mm_srav_epi32x(a, _mm_sub_epi32(_mm_setzero_si128(), shift));
+    let left = _mm_sllv_epi32x(a, shift);
+    let fits = _mm_cmpeq_epi32(_mm_srav_epi32x(left, shift), a);
+    let saturated = _mm_xor_si128(_mm_set1_epi32(i32::MAX), _mm_srai_epi32::<31>(a));
+    _mm_blendv_epi32(_mm_blendv_epi32(saturated, left, fits), right, shift)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_epi64x;
+
+    const VALUES: [i32; 16] = [
+        0,
+        1,
+        -1,
+        2,
+        7,
+        -9,
+        0xffff,
+        0x10000,
+        0x7fff_0000,
+        -0x7fff_0000,
+        0x1234_5678,
+        -0x1234_5678,
+        0x4000_0000,
+        -0x4000_0000,
+        i32::MAX,
+        i32::MIN,
+    ];
+
+    const SHIFTS: [i32; 19] = [
+        -128,
+        -100,
+        -64,
+        -33,
+        -32,
+        -31,
+        -7,
+        -1,
+        0,
+        1,
+        2,
+        7,
+        30,
+        31,
+        32,
+        33,
+        64,
+        127,
+        0x1234_5601,
+    ];
+
+    unsafe fn lanes(v: __m128i) -> [i32; 4] {
+        let lo = _mm_extract_epi64x::<0>(v) as u64;
+        let hi = _mm_extract_epi64x::<1>(v) as u64;
+        [lo as i32, (lo >> 32) as i32, hi as i32, (hi >> 32) as i32]
+    }
+
+    fn qshl_s32(a: i32, shift: i8) -> i32 {
+        if shift < 0 {
+            return a >> (-(shift as i32)).min(31);
+        }
+        if shift >= 32 {
+            return if a == 0 {
+                0
+            } else if a < 0 {
+                i32::MIN
+            } else {
+                i32::MAX
+            };
+        }
+        ((a as i64) << shift).clamp(i32::MIN as i64, i32::MAX as i64) as i32
+    }
+
+    fn qshl_u32(a: u32, shift: i8) -> u32 {
+        if shift < 0 {
+            return a.checked_shr(-(shift as i32) as u32).unwrap_or(0);
+        }
+        if shift >= 32 {
+            return if a == 0 { 0 } else { u32::MAX };
+        }
+        ((a as u64) << shift).min(u32::MAX as u64) as u32
+    }
+
+    #[test]
+    fn test_saturation_32() {
+        unsafe {
+            for chunk in VALUES.chunks(4) {
+                let va = _mm_setr_epi32(chunk[0], chunk[1], chunk[2], chunk[3]);
+                for &b in VALUES.iter() {
+                    let vb = _mm_set1_epi32(b);
+                    let adds = lanes(_mm_adds_epi32(va, vb));
+                    let subs = lanes(_mm_subs_epi32(va, vb));
+                    let muls = lanes(_mm_muls_epi32(va, vb));
+                    let addu = lanes(_mm_adds_epu32(va, vb));
+                    let subu = lanes(_mm_subs_epu32(va, vb));
+                    let mulu = lanes(_mm_muls_epu32(va, vb));
+                    for i in 0..4 {
+                        let (a, ua, ub) = (chunk[i], chunk[i] as u32, b as u32);
+                        assert_eq!(adds[i], a.saturating_add(b));
+                        assert_eq!(subs[i], a.saturating_sub(b));
+                        assert_eq!(muls[i], a.saturating_mul(b));
+                        assert_eq!(addu[i] as u32, ua.saturating_add(ub));
+                        assert_eq!(subu[i] as u32, ua.saturating_sub(ub));
+                        assert_eq!(mulu[i] as u32, ua.saturating_mul(ub));
+                    }
+                }
+                for (shifts, r) in SHIFTS.chunks(4).flat_map(|c| (0..4).map(move |r| (c, r))) {
+                    let s = [0, 1, 2, 3].map(|i| shifts[(i + r) % shifts.len()]);
+                    let vs = _mm_setr_epi32(s[0], s[1], s[2], s[3]);
+                    let shls = lanes(_mm_shls_epi32(va, vs));
+                    let shlu = lanes(_mm_shls_epu32(va, vs));
+                    for i in 0..4 {
+                        assert_eq!(shls[i], qshl_s32(chunk[i], s[i] as i8));
+                        assert_eq!(shlu[i] as u32, qshl_u32(chunk[i] as u32, s[i] as i8));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -6,17 +6,22 @@
  */
 
 use crate::sse::epi64::_mm_blendv_epi64;
-use crate::sse::unsigned::_mm_cmplt_epu32;
+use crate::sse::unsigned::_mm_cmplt_epu64;
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
-/// Add unsigned 32 bytes integers using saturation
+use crate::{
+    _mm_cmpeq_epi64x, _mm_cmpgt_epi64x, _mm_mull_epi64, _mm_mull_epu64, _mm_sllv_epi64x,
+    _mm_srai_epi64x, _mm_srlv_epi64x,
+};
+
+#[inline(always)]
+/// Add unsigned 64 bytes integers using saturation
 pub unsafe fn _mm_adds_epu64(a: __m128i, b: __m128i) -> __m128i {
     let result = _mm_add_epi64(a, b);
-    let mask = _mm_cmplt_epu32(result, a);
+    let mask = _mm_cmplt_epu64(result, a);
     #[allow(overflowing_literals)]
     let saturated = _mm_set1_epi64x(0xFFFFFFFFFFFFFFFF);
     _mm_or_si128(
@@ -25,7 +30,7 @@ pub unsafe fn _mm_adds_epu64(a: __m128i, b: __m128i) -> __m128i {
     )
 }
 
-#[inline]
+#[inline(always)]
 /// Add signed 64 bytes integers usign saturation
 pub unsafe fn _mm_adds_epi64(lhs: __m128i, rhs: __m128i) -> __m128i {
     let res = _mm_add_epi64(lhs, rhs);
@@ -33,11 +38,11 @@ pub unsafe fn _mm_adds_epi64(lhs: __m128i, rhs: __m128i) -> __m128i {
     _mm_blendv_epi64(
         res,
         _mm_blendv_epi64(_mm_set1_epi64x(i64::MIN), _mm_set1_epi64x(i64::MAX), res),
-        _mm_xor_si128(rhs, _mm_cmpgt_epi64(lhs, res)),
+        _mm_xor_si128(rhs, _mm_cmpgt_epi64x(lhs, res)),
     )
 }
 
-#[inline]
+#[inline(always)]
 /// subtract signed integers 64 using saturation
 pub unsafe fn _mm_subs_epi64(lhs: __m128i, rhs: __m128i) -> __m128i {
     let res = _mm_sub_epi64(lhs, rhs);
@@ -46,17 +51,202 @@ pub unsafe fn _mm_subs_epi64(lhs: __m128i, rhs: __m128i) -> __m128i {
         res,
         _mm_blendv_epi64(_mm_set1_epi64x(i64::MIN), _mm_set1_epi64x(i64::MAX), res),
         _mm_xor_si128(
-            _mm_cmpgt_epi64(rhs, _mm_setzero_si128()),
-            _mm_cmpgt_epi64(lhs, res),
+            _mm_cmpgt_epi64x(rhs, _mm_setzero_si128()),
+            _mm_cmpgt_epi64x(lhs, res),
         ),
     )
 }
 
+#[inline(always)]
+/// Subtract unsigned integers 64 using saturation
+pub unsafe fn _mm_subs_epu64(a: __m128i, b: __m128i) -> __m128i {
+    let result = _mm_sub_epi64(a, b);
+    _mm_andnot_si128(_mm_cmplt_epu64(a, b), result)
+}
+
+#[inline(always)]
+/// Multiply unsigned integers 64 using saturation
+pub unsafe fn _mm_muls_epu64(a: __m128i, b: __m128i) -> __m128i {
+    let product = _mm_mull_epu64(a, b);
+    let overflow = _mm_xor_si128(
+        _mm_cmpeq_epi64x(product.1, _mm_setzero_si128()),
+        _mm_set1_epi32(-1),
+    );
+    _mm_or_si128(product.0, overflow)
+}
+
+#[inline(always)]
+/// Multiply signed integers 64 using saturation
+pub unsafe fn _mm_muls_epi64(a: __m128i, b: __m128i) -> __m128i {
+    let product = _mm_mull_epi64(a, b);
+    let fits = _mm_cmpeq_epi64x(product.1, _mm_srai_epi64x::<63>(product.0));
+    let saturated = _mm_xor_si128(
+        _mm_set1_epi64x(i64::MAX),
+        _mm_srai_epi64x::<63>(_mm_xor_si128(a, b)),
+    );
+    _mm_blendv_epi64(saturated, product.0, fits)
+}
+
+#[inline(always)]
+/// Shifts i64 right by the corresponding lane of `count`, counts greater than 63 fill with sign
+unsafe fn _mm_srav_epi64x(a: __m128i, count: __m128i) -> __m128i {
+    let sign = _mm_srai_epi64x::<63>(a);
+    _mm_xor_si128(_mm_srlv_epi64x(_mm_xor_si128(a, sign), count), sign)
+}
+
+#[inline(always)]
+/// Shift unsigned integers 64 left using saturation, same as *vqshlq_u64*.
+/// Shift is the lowest signed byte of `count` lane, negative shift is a truncating right shift
+pub unsafe fn _mm_shls_epu64(a: __m128i, count: __m128i) -> __m128i {
+    let shift = _mm_srai_epi64x::<56>(_mm_slli_epi64::<56>(count));
+    let right = _mm_srlv_epi64x(a, _mm_sub_epi64(_mm_setzero_si128(), shift));
+    let left = _mm_sllv_epi64x(a, shift);
+    let overflow = _mm_xor_si128(
+        _mm_cmpeq_epi64x(_mm_srlv_epi64x(left, shift), a),
+        _mm_set1_epi32(-1),
+    );
+    _mm_blendv_epi64(_mm_or_si128(left, overflow), right, shift)
+}
+
+#[inline(always)]
+/// Shift signed integers 64 left using saturation, same as *vqshlq_s64*.
+/// Shift is the lowest signed byte of `count` lane, negative shift is a truncating right shift
+pub unsafe fn _mm_shls_epi64(a: __m128i, count: __m128i) -> __m128i {
+    let shift = _mm_srai_epi64x::<56>(_mm_slli_epi64::<56>(count));
+    let right = _mm_srav_epi64x(a, _mm_sub_epi64(_mm_setzero_si128(), shift));
+    let left = _mm_sllv_epi64x(a, shift);
+    let fits = _mm_cmpeq_epi64x(_mm_srav_epi64x(left, shift), a);
+    let saturated = _mm_xor_si128(_mm_set1_epi64x(i64::MAX), _mm_srai_epi64x::<63>(a));
+    _mm_blendv_epi64(_mm_blendv_epi64(saturated, left, fits), right, shift)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::_mm_extract_epi64x;
 
+    const VALUES: [i64; 12] = [
+        0,
+        1,
+        -1,
+        3,
+        -9,
+        0x7fff_ffff,
+        0x1_ffff_fff5,
+        0x1234_5678_9abc_def0,
+        -0x1234_5678_9abc_def0,
+        0x4000_0000_0000_0000,
+        i64::MAX,
+        i64::MIN,
+    ];
+
+    const SHIFTS: [i64; 20] = [
+        -128,
+        -100,
+        -65,
+        -64,
+        -63,
+        -33,
+        -7,
+        -1,
+        0,
+        1,
+        2,
+        31,
+        32,
+        62,
+        63,
+        64,
+        65,
+        127,
+        0x1234_5601,
+        -0xff_ff00,
+    ];
+
+    fn qshl_s64(a: i64, shift: i8) -> i64 {
+        if shift < 0 {
+            return a >> (-(shift as i32)).min(63);
+        }
+        if shift >= 64 {
+            return if a == 0 {
+                0
+            } else if a < 0 {
+                i64::MIN
+            } else {
+                i64::MAX
+            };
+        }
+        ((a as i128) << shift).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    fn qshl_u64(a: u64, shift: i8) -> u64 {
+        if shift < 0 {
+            return a.checked_shr(-(shift as i32) as u32).unwrap_or(0);
+        }
+        if shift >= 64 {
+            return if a == 0 { 0 } else { u64::MAX };
+        }
+        ((a as u128) << shift).min(u64::MAX as u128) as u64
+    }
+
+    #[test]
+    fn test_saturation_64() {
+        unsafe {
+            for &a in VALUES.iter() {
+                for &b in VALUES.iter() {
+                    let va = _mm_set_epi64x(b, a);
+                    let vb = _mm_set_epi64x(a, b);
+                    let lanes = [(a, b), (b, a)];
+                    let adds = _mm_adds_epi64(va, vb);
+                    let subs = _mm_subs_epi64(va, vb);
+                    let muls = _mm_muls_epi64(va, vb);
+                    let addu = _mm_adds_epu64(va, vb);
+                    let subu = _mm_subs_epu64(va, vb);
+                    let mulu = _mm_muls_epu64(va, vb);
+                    let r = [
+                        [
+                            _mm_extract_epi64x::<0>(adds),
+                            _mm_extract_epi64x::<0>(subs),
+                            _mm_extract_epi64x::<0>(muls),
+                            _mm_extract_epi64x::<0>(addu),
+                            _mm_extract_epi64x::<0>(subu),
+                            _mm_extract_epi64x::<0>(mulu),
+                        ],
+                        [
+                            _mm_extract_epi64x::<1>(adds),
+                            _mm_extract_epi64x::<1>(subs),
+                            _mm_extract_epi64x::<1>(muls),
+                            _mm_extract_epi64x::<1>(addu),
+                            _mm_extract_epi64x::<1>(subu),
+                            _mm_extract_epi64x::<1>(mulu),
+                        ],
+                    ];
+                    for (i, &(a, b)) in lanes.iter().enumerate() {
+                        let (ua, ub) = (a as u64, b as u64);
+                        assert_eq!(r[i][0], a.saturating_add(b));
+                        assert_eq!(r[i][1], a.saturating_sub(b));
+                        assert_eq!(r[i][2], a.saturating_mul(b));
+                        assert_eq!(r[i][3] as u64, ua.saturating_add(ub));
+                        assert_eq!(r[i][4] as u64, ua.saturating_sub(ub));
+                        assert_eq!(r[i][5] as u64, ua.saturating_mul(ub));
+                    }
+                }
+                for &s0 in SHIFTS.iter() {
+                    let s1 = SHIFTS[(s0 as usize) % SHIFTS.len()];
+                    let va = _mm_set1_epi64x(a);
+                    let vs = _mm_set_epi64x(s1, s0);
+                    let shls = _mm_shls_epi64(va, vs);
+                    let shlu = _mm_shls_epu64(va, vs);
+                    assert_eq!(_mm_extract_epi64x::<0>(shls), qshl_s64(a, s0 as i8));
+                    assert_eq!(_mm_extract_epi64x::<1>(shls), qshl_s64(a, s1 as i8));
+                    let ua = a as u64;
+                    assert_eq!(_mm_extract_epi64x::<0>(shlu) as u64, qshl_u64(ua, s0 as i8));
+                    assert_eq!(_mm_extract_epi64x::<1>(shlu) as u64, qshl_u64(ua, s1 as i8));
+                }
+            }
+        }
+    }
+
     #[test]
     fn saturation_add_epu64() {
         unsafe {
@@ -4,19 +4,25 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
+use crate::generalf::rempi;
 use crate::sin::{
     PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
     SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
 };
+use crate::sse::general::_mm_multiply_as_doubled_pd;
+use crate::sse::general::_mm_rempi_pd;
 use crate::sse::general::_mm_rint_pd;
+use crate::{
+    _mm_abs_pd, _mm_cmpeq_epi64x, _mm_copysign_pd, _mm_eqzero_pd, _mm_round_pdx, _mm_select_pd,
+};
 use crate::{_mm_cvtepi64_pd, _mm_mlaf_pd, _mm_neg_pd, _mm_selecti_pd};
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
-/// Computes sine function with *ULP 1.5* on range [-15; 15]
+#[inline(always)]
+/// Computes sine function with *ULP 2.5*
 pub unsafe fn _mm_sin_pd(d: __m128d) -> __m128d {
     let q = _mm_rint_pd(_mm_mul_pd(d, _mm_set1_pd(std::f64::consts::FRAC_1_PI)));
 
@@ -24,14 +30,21 @@ pub unsafe fn _mm_sin_pd(d: __m128d) -> __m128d {
 
     let mut r = _mm_mlaf_pd(qf, _mm_set1_pd(-PI_A2), d);
     r = _mm_mlaf_pd(qf, _mm_set1_pd(-PI_B2), r);
-
-    let x2 = _mm_mul_pd(r, r);
+    let (q, mut r) = _mm_rempi_pd(d, q, r, rempi);
 
     r = _mm_selecti_pd(
-        _mm_cmpeq_epi64(_mm_and_si128(q, _mm_set1_epi64x(1)), _mm_setzero_si128()),
+        _mm_cmpeq_epi64x(_mm_and_si128(q, _mm_set1_epi64x(1)), _mm_setzero_si128()),
         r,
         _mm_neg_pd(r),
     );
+    // sin(±0) = ±0
+    _mm_select_pd(_mm_eqzero_pd(d), d, _mm_sink_pd(r))
+}
+
+/// Evaluates sine polynomial for reduced |r| <= pi/2
+#[inline(always)]
+pub(crate) unsafe fn _mm_sink_pd(r: __m128d) -> __m128d {
+    let x2 = _mm_mul_pd(r, r);
     let mut res = _mm_set1_pd(SIN_POLY_10_D);
     res = _mm_mlaf_pd(res, x2, _mm_set1_pd(SIN_POLY_9_D));
     res = _mm_mlaf_pd(res, x2, _mm_set1_pd(SIN_POLY_8_D));
@@ -45,6 +58,36 @@ pub unsafe fn _mm_sin_pd(d: __m128d) -> __m128d {
     res
 }
 
+/// Computes sin(pi*x) for |x| < 2^52, integers give exact zeros
+#[inline(always)]
+pub(crate) unsafe fn _mm_sinpik_pd(x: __m128d) -> __m128d {
+    // Period is 2 so the reduction is exact, then folding into [-0.5, 0.5] by sin(pi - y) = sin(y)
+    let mut r = _mm_sub_pd(
+        x,
+        _mm_mul_pd(
+            _mm_set1_pd(2f64),
+            _mm_round_pdx::<_MM_FROUND_TO_NEAREST_INT>(_mm_mul_pd(x, _mm_set1_pd(0.5f64))),
+        ),
+    );
+    r = _mm_select_pd(
+        _mm_cmpgt_pd(_mm_abs_pd(r), _mm_set1_pd(0.5f64)),
+        _mm_sub_pd(_mm_copysign_pd(_mm_set1_pd(1f64), r), r),
+        r,
+    );
+    // pi * r is kept as double-float, sin(y + lo) = sin(y) + lo * cos(y) with cos(y) ~ 1 - y^2/2
+    let y = _mm_multiply_as_doubled_pd(r, _mm_set1_pd(PI_A2));
+    let lo = _mm_mlaf_pd(r, _mm_set1_pd(PI_B2), y.1);
+    _mm_mlaf_pd(
+        lo,
+        _mm_mlaf_pd(
+            _mm_mul_pd(_mm_set1_pd(-0.5f64), y.0),
+            y.0,
+            _mm_set1_pd(1f64),
+        ),
+        _mm_sink_pd(y.0),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use crate::_mm_extract_pd;
@@ -89,4 +132,13 @@ mod tests {
             assert_eq!(flag_1, control);
         }
     }
+
+    #[test]
+    fn test_sind_large() {
+        for x in [1e6f64, -1e22, 3.5e300] {
+            let value = unsafe { _mm_cvtsd_f64(_mm_sin_pd(_mm_setr_pd(x, 2.))) };
+            let control = x.sin();
+            assert!((value - control).abs() <= 2. * f64::EPSILON * control.abs());
+        }
+    }
 }
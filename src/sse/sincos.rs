@@ -0,0 +1,87 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::generalf::rempi2;
+use crate::sin::{
+    PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
+    SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
+};
+use crate::sse::general::{_mm_rempi_pd, _mm_rint_pd};
+use crate::{
+    _mm_cmpeq_epi64x, _mm_cvtepi64_pd, _mm_eqzero_pd, _mm_mlaf_pd, _mm_select_pd, _mm_selecti_pd,
+};
+
+#[inline(always)]
+unsafe fn _mm_sin_poly_pd(r: __m128d) -> __m128d {
+    let x2 = _mm_mul_pd(r, r);
+    let mut res = _mm_set1_pd(SIN_POLY_10_D);
+    res = _mm_mlaf_pd(res, x2, _mm_set1_pd(SIN_POLY_9_D));
+    res = _mm_mlaf_pd(res, x2, _mm_set1_pd(SIN_POLY_8_D));
+    res = _mm_mlaf_pd(res, x2, _mm_set1_pd(SIN_POLY_7_D));
+    res = _mm_mlaf_pd(res, x2, _mm_set1_pd(SIN_POLY_6_D));
+    res = _mm_mlaf_pd(res, x2, _mm_set1_pd(SIN_POLY_5_D));
+    res = _mm_mlaf_pd(res, x2, _mm_set1_pd(SIN_POLY_4_D));
+    res = _mm_mlaf_pd(res, x2, _mm_set1_pd(SIN_POLY_3_D));
+    res = _mm_mlaf_pd(res, x2, _mm_set1_pd(SIN_POLY_2_D));
+    _mm_mlaf_pd(res, _mm_mul_pd(x2, r), r)
+}
+
+#[inline(always)]
+/// Computes sine and cosine with a single argument reduction, returns `(sin, cos)`, *ULP 1.5*
+pub unsafe fn _mm_sincos_pd(d: __m128d) -> (__m128d, __m128d) {
+    let q = _mm_rint_pd(_mm_mul_pd(d, _mm_set1_pd(std::f64::consts::FRAC_2_PI)));
+    let qf = _mm_cvtepi64_pd(q);
+
+    let mut r = _mm_mlaf_pd(qf, _mm_set1_pd(-PI_A2 * 0.5), d);
+    r = _mm_mlaf_pd(qf, _mm_set1_pd(-PI_B2 * 0.5), r);
+    let (q, r) = _mm_rempi_pd(d, q, r, rempi2);
+
+    // cos(r) = 1 - 2*sin(r/2)^2, |r| <= π/4
+    let s = _mm_sin_poly_pd(r);
+    let h = _mm_sin_poly_pd(_mm_mul_pd(r, _mm_set1_pd(0.5)));
+    let c = _mm_mlaf_pd(_mm_mul_pd(_mm_set1_pd(-2.), h), h, _mm_set1_pd(1.));
+
+    let is_even = _mm_cmpeq_epi64x(_mm_and_si128(q, _mm_set1_epi64x(1)), _mm_setzero_si128());
+    let sin = _mm_selecti_pd(is_even, s, c);
+    let cos = _mm_selecti_pd(is_even, c, s);
+
+    let sin_sign = _mm_slli_epi64::<62>(_mm_and_si128(q, _mm_set1_epi64x(2)));
+    let cos_sign = _mm_slli_epi64::<62>(_mm_and_si128(
+        _mm_add_epi64(q, _mm_set1_epi64x(1)),
+        _mm_set1_epi64x(2),
+    ));
+    // sin(±0) = ±0
+    (
+        _mm_select_pd(
+            _mm_eqzero_pd(d),
+            d,
+            _mm_xor_pd(sin, _mm_castsi128_pd(sin_sign)),
+        ),
+        _mm_xor_pd(cos, _mm_castsi128_pd(cos_sign)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sincosd() {
+        for x in [0f64, -2.70752239, 2., 0.75, -5.5, 14.9, 1e6, -1e22, 3.5e300] {
+            let (sin, cos) = unsafe { _mm_sincos_pd(_mm_setr_pd(x, 1.)) };
+            let (sin, cos) = unsafe { (_mm_cvtsd_f64(sin), _mm_cvtsd_f64(cos)) };
+            let (control_sin, control_cos) = (x.sin(), x.cos());
+            assert!((sin - control_sin).abs() <= 2. * f64::EPSILON * control_sin.abs());
+            assert!((cos - control_cos).abs() <= 2. * f64::EPSILON * control_cos.abs());
+        }
+    }
+}
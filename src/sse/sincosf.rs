@@ -0,0 +1,81 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2f;
+use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+use crate::sse::generalf::_mm_rempi_ps;
+use crate::{_mm_eqzero_ps, _mm_mlaf_ps, _mm_rint_ps, _mm_select_ps, _mm_selecti_ps};
+
+#[inline(always)]
+unsafe fn _mm_sin_poly_ps(r: __m128) -> __m128 {
+    let x2 = _mm_mul_ps(r, r);
+    let mut res = _mm_set1_ps(SIN_POLY_5_S);
+    res = _mm_mlaf_ps(res, x2, _mm_set1_ps(SIN_POLY_4_S));
+    res = _mm_mlaf_ps(res, x2, _mm_set1_ps(SIN_POLY_3_S));
+    res = _mm_mlaf_ps(res, x2, _mm_set1_ps(SIN_POLY_2_S));
+    res = _mm_mlaf_ps(res, x2, _mm_set1_ps(SIN_POLY_1_S));
+    _mm_mlaf_ps(res, _mm_mul_ps(x2, r), r)
+}
+
+#[inline(always)]
+/// Computes sine and cosine with a single argument reduction, returns `(sin, cos)`, *ULP 2.0*
+pub unsafe fn _mm_sincos_ps(d: __m128) -> (__m128, __m128) {
+    let q = _mm_rint_ps(_mm_mul_ps(d, _mm_set1_ps(std::f32::consts::FRAC_2_PI)));
+    let qf = _mm_cvtepi32_ps(q);
+
+    let mut r = _mm_mlaf_ps(qf, _mm_set1_ps(-PI_A_F * 0.5), d);
+    r = _mm_mlaf_ps(qf, _mm_set1_ps(-PI_B_F * 0.5), r);
+    r = _mm_mlaf_ps(qf, _mm_set1_ps(-PI_C_F * 0.5), r);
+    r = _mm_mlaf_ps(qf, _mm_set1_ps(-PI_D_F * 0.5), r);
+    let (q, r) = _mm_rempi_ps(d, q, r, rempi2f);
+
+    // cos(r) = 1 - 2*sin(r/2)^2, |r| <= π/4
+    let s = _mm_sin_poly_ps(r);
+    let h = _mm_sin_poly_ps(_mm_mul_ps(r, _mm_set1_ps(0.5)));
+    let c = _mm_mlaf_ps(_mm_mul_ps(_mm_set1_ps(-2.), h), h, _mm_set1_ps(1.));
+
+    let is_even = _mm_cmpeq_epi32(_mm_and_si128(q, _mm_set1_epi32(1)), _mm_setzero_si128());
+    let sin = _mm_selecti_ps(is_even, s, c);
+    let cos = _mm_selecti_ps(is_even, c, s);
+
+    let sin_sign = _mm_slli_epi32::<30>(_mm_and_si128(q, _mm_set1_epi32(2)));
+    let cos_sign = _mm_slli_epi32::<30>(_mm_and_si128(
+        _mm_add_epi32(q, _mm_set1_epi32(1)),
+        _mm_set1_epi32(2),
+    ));
+    // sin(±0) = ±0
+    (
+        _mm_select_ps(
+            _mm_eqzero_ps(d),
+            d,
+            _mm_xor_ps(sin, _mm_castsi128_ps(sin_sign)),
+        ),
+        _mm_xor_ps(cos, _mm_castsi128_ps(cos_sign)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sincosf() {
+        for x in [0f32, -2.70752239, 2., 0.75, -5.5, 124.9, 1e4, -1e22, 3.0e38] {
+            let (sin, cos) = unsafe { _mm_sincos_ps(_mm_setr_ps(x, 1., -0.5, 2.)) };
+            let (sin, cos) = unsafe { (_mm_cvtss_f32(sin), _mm_cvtss_f32(cos)) };
+            let (control_sin, control_cos) = ((x as f64).sin() as f32, (x as f64).cos() as f32);
+            assert!((sin - control_sin).abs() <= 2. * f32::EPSILON * control_sin.abs());
+            assert!((cos - control_cos).abs() <= 2. * f32::EPSILON * control_cos.abs());
+        }
+    }
+}
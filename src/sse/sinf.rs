@@ -5,15 +5,20 @@
  * // license that can be found in the LICENSE file.
  */
 use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempif;
+use crate::sinf::{PI_LO_S, PI_S};
 use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+use crate::sse::generalf::_mm_multiply_as_doubled_ps;
+use crate::sse::generalf::_mm_rempi_ps;
+use crate::{_mm_abs_ps, _mm_copysign_ps, _mm_eqzero_ps, _mm_round_psx, _mm_select_ps};
 use crate::{_mm_mlaf_ps, _mm_neg_ps, _mm_rint_ps, _mm_selecti_ps};
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-/// Computes sine function with error bound *ULP 1.5*
-#[inline]
+/// Computes sine function with error bound *ULP 2.5*
+#[inline(always)]
 pub unsafe fn _mm_sin_ps(d: __m128) -> __m128 {
     let q = _mm_rint_ps(_mm_mul_ps(d, _mm_set1_ps(std::f32::consts::FRAC_1_PI)));
     let qf = _mm_cvtepi32_ps(q);
@@ -22,14 +27,21 @@ pub unsafe fn _mm_sin_ps(d: __m128) -> __m128 {
     r = _mm_mlaf_ps(qf, _mm_set1_ps(-PI_B_F), r);
     r = _mm_mlaf_ps(qf, _mm_set1_ps(-PI_C_F), r);
     r = _mm_mlaf_ps(qf, _mm_set1_ps(-PI_D_F), r);
-
-    let x2 = _mm_mul_ps(r, r);
+    let (q, mut r) = _mm_rempi_ps(d, q, r, rempif);
 
     r = _mm_selecti_ps(
         _mm_cmpeq_epi32(_mm_and_si128(q, _mm_set1_epi32(1)), _mm_setzero_si128()),
         r,
         _mm_neg_ps(r),
     );
+    // sin(±0) = ±0
+    _mm_select_ps(_mm_eqzero_ps(d), d, _mm_sink_ps(r))
+}
+
+/// Evaluates sine polynomial for reduced |r| <= pi/2
+#[inline(always)]
+pub(crate) unsafe fn _mm_sink_ps(r: __m128) -> __m128 {
+    let x2 = _mm_mul_ps(r, r);
     let mut res = _mm_set1_ps(SIN_POLY_5_S);
     res = _mm_mlaf_ps(res, x2, _mm_set1_ps(SIN_POLY_4_S));
     res = _mm_mlaf_ps(res, x2, _mm_set1_ps(SIN_POLY_3_S));
@@ -39,16 +51,47 @@ pub unsafe fn _mm_sin_ps(d: __m128) -> __m128 {
     res
 }
 
+/// Computes sin(pi*x) for |x| < 2^23, integers give exact zeros
+#[inline(always)]
+pub(crate) unsafe fn _mm_sinpik_ps(x: __m128) -> __m128 {
+    // Period is 2 so the reduction is exact, then folding into [-0.5, 0.5] by sin(pi - y) = sin(y)
+    let mut r = _mm_sub_ps(
+        x,
+        _mm_mul_ps(
+            _mm_set1_ps(2f32),
+            _mm_round_psx::<_MM_FROUND_TO_NEAREST_INT>(_mm_mul_ps(x, _mm_set1_ps(0.5f32))),
+        ),
+    );
+    r = _mm_select_ps(
+        _mm_cmpgt_ps(_mm_abs_ps(r), _mm_set1_ps(0.5f32)),
+        _mm_sub_ps(_mm_copysign_ps(_mm_set1_ps(1f32), r), r),
+        r,
+    );
+    // pi * r is kept as double-float, sin(y + lo) = sin(y) + lo * cos(y) with cos(y) ~ 1 - y^2/2
+    let y = _mm_multiply_as_doubled_ps(r, _mm_set1_ps(PI_S));
+    let lo = _mm_mlaf_ps(r, _mm_set1_ps(PI_LO_S), y.1);
+    _mm_mlaf_ps(
+        lo,
+        _mm_mlaf_ps(
+            _mm_mul_ps(_mm_set1_ps(-0.5f32), y.0),
+            y.0,
+            _mm_set1_ps(1f32),
+        ),
+        _mm_sink_ps(y.0),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_psx;
 
     #[test]
     fn test_sinf() {
         unsafe {
             let value = _mm_set1_ps(2f32);
             let comparison = _mm_sin_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             let control = 0.9092974268256f32;
             assert_eq!(flag_1, control);
         }
@@ -56,9 +99,49 @@ mod tests {
         unsafe {
             let value = _mm_set1_ps(-2f32);
             let comparison = _mm_sin_ps(value);
-            let flag_1 = f32::from_bits(_mm_extract_ps::<0>(comparison) as u32);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
             let control = -0.9092974268256f32;
             assert_eq!(flag_1, control);
         }
     }
+
+    #[test]
+    fn test_sinf_large() {
+        for x in [1e4f32, -1e22, 3.0e38] {
+            let value = unsafe { _mm_cvtss_f32(_mm_sin_ps(_mm_setr_ps(x, 2., -0.5, 1.))) };
+            let control = (x as f64).sin() as f32;
+            assert!((value - control).abs() <= 2. * f32::EPSILON * control.abs());
+        }
+    }
+
+    #[test]
+    fn test_sinf_ulp() {
+        // Uniform on [-1e4, 1e4] and log-uniform up to 1e30, scalar sine dispatches to this kernel
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        for i in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let sign = if i % 4 == 1 { 1. } else { -1. };
+            let x = if i % 2 == 0 {
+                ((u * 2. - 1.) * 1e4) as f32
+            } else {
+                (sign * 10f64.powf(u * 33. - 3.)) as f32
+            };
+            let control = (x as f64).sin();
+            let value = unsafe { _mm_cvtss_f32(_mm_sin_ps(_mm_set1_ps(x))) };
+            for value in [value, crate::esinf(x)] {
+                assert!(
+                    ulp(value, control) <= 2.5,
+                    "sin({x}) = {value}, expected {control}"
+                );
+            }
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
 }
@@ -0,0 +1,357 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+//! SSE 4.1 and SSE 4.2 instructions with SSE2 fallbacks.
+//!
+//! When crate is compiled with `sse4.1` or `sse4.2` target features native instruction is used,
+//! otherwise an equivalent SSE2 sequence is, so kernels run on baseline x86_64 targets.
+//! The choice is made at compile time, runtime *sse4.1* dispatch still gets the SSE2 sequence.
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline(always)]
+/// Blends f32 by sign bit of `mask`, same as *_mm_blendv_ps*
+pub unsafe fn _mm_blendv_psx(a: __m128, b: __m128, mask: __m128) -> __m128 {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_blendv_ps(a, b, mask)
+    }
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        let mask = _mm_castsi128_ps(_mm_srai_epi32::<31>(_mm_castps_si128(mask)));
+        _mm_or_ps(_mm_and_ps(mask, b), _mm_andnot_ps(mask, a))
+    }
+}
+
+#[inline(always)]
+/// Blends f64 by sign bit of `mask`, same as *_mm_blendv_pd*
+pub unsafe fn _mm_blendv_pdx(a: __m128d, b: __m128d, mask: __m128d) -> __m128d {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_blendv_pd(a, b, mask)
+    }
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        // Sign of the high half is broadcast to the whole 64 bits lane
+        let sign = _mm_srai_epi32::<31>(_mm_castpd_si128(mask));
+        let mask = _mm_castsi128_pd(_mm_shuffle_epi32::<0b11110101>(sign));
+        _mm_or_pd(_mm_and_pd(mask, b), _mm_andnot_pd(mask, a))
+    }
+}
+
+#[inline(always)]
+/// Blends bytes by sign bit of `mask`, same as *_mm_blendv_epi8*
+pub unsafe fn _mm_blendv_epi8x(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_blendv_epi8(a, b, mask)
+    }
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        let mask = _mm_cmplt_epi8(mask, _mm_setzero_si128());
+        _mm_or_si128(_mm_and_si128(mask, b), _mm_andnot_si128(mask, a))
+    }
+}
+
+#[inline(always)]
+/// Rounds f32 with rounding mode from `IMM`, same as *_mm_round_ps*.
+/// Fallback treats `_MM_FROUND_CUR_DIRECTION` as round to nearest
+pub unsafe fn _mm_round_psx<const IMM: i32>(a: __m128) -> __m128 {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_round_ps::<IMM>(a)
+    }
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        let sign = _mm_and_ps(a, _mm_set1_ps(-0.0f32));
+        let ones = _mm_set1_ps(1f32);
+        let mut v = if IMM & 0x03 == _MM_FROUND_TO_ZERO {
+            _mm_andnot_ps(_mm_set1_ps(-0.0f32), a)
+        } else {
+            a
+        };
+        // Adding and subtracting 2^23 drops fraction bits with rounding to nearest
+        let magic = _mm_or_ps(_mm_set1_ps(8388608f32), _mm_and_ps(v, _mm_set1_ps(-0.0f32)));
+        let n = _mm_sub_ps(_mm_add_ps(v, magic), magic);
+        v = match IMM & 0x03 {
+            _MM_FROUND_TO_NEG_INF | _MM_FROUND_TO_ZERO => {
+                _mm_sub_ps(n, _mm_and_ps(_mm_cmpgt_ps(n, v), ones))
+            }
+            _MM_FROUND_TO_POS_INF => _mm_add_ps(n, _mm_and_ps(_mm_cmplt_ps(n, v), ones)),
+            _ => n,
+        };
+        // Result keeps sign of the argument, so -0.3 rounds to -0
+        v = _mm_or_ps(v, sign);
+        let is_fractional = _mm_cmplt_ps(
+            _mm_andnot_ps(_mm_set1_ps(-0.0f32), a),
+            _mm_set1_ps(8388608f32),
+        );
+        _mm_blendv_psx(a, v, is_fractional)
+    }
+}
+
+#[inline(always)]
+/// Rounds f64 with rounding mode from `IMM`, same as *_mm_round_pd*.
+/// Fallback treats `_MM_FROUND_CUR_DIRECTION` as round to nearest
+pub unsafe fn _mm_round_pdx<const IMM: i32>(a: __m128d) -> __m128d {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_round_pd::<IMM>(a)
+    }
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        let sign = _mm_and_pd(a, _mm_set1_pd(-0.0f64));
+        let ones = _mm_set1_pd(1f64);
+        let mut v = if IMM & 0x03 == _MM_FROUND_TO_ZERO {
+            _mm_andnot_pd(_mm_set1_pd(-0.0f64), a)
+        } else {
+            a
+        };
+        // Adding and subtracting 2^52 drops fraction bits with rounding to nearest
+        let magic = _mm_or_pd(
+            _mm_set1_pd(4503599627370496f64),
+            _mm_and_pd(v, _mm_set1_pd(-0.0f64)),
+        );
+        let n = _mm_sub_pd(_mm_add_pd(v, magic), magic);
+        v = match IMM & 0x03 {
+            _MM_FROUND_TO_NEG_INF | _MM_FROUND_TO_ZERO => {
+                _mm_sub_pd(n, _mm_and_pd(_mm_cmpgt_pd(n, v), ones))
+            }
+            _MM_FROUND_TO_POS_INF => _mm_add_pd(n, _mm_and_pd(_mm_cmplt_pd(n, v), ones)),
+            _ => n,
+        };
+        // Result keeps sign of the argument, so -0.3 rounds to -0
+        v = _mm_or_pd(v, sign);
+        let is_fractional = _mm_cmplt_pd(
+            _mm_andnot_pd(_mm_set1_pd(-0.0f64), a),
+            _mm_set1_pd(4503599627370496f64),
+        );
+        _mm_blendv_pdx(a, v, is_fractional)
+    }
+}
+
+#[inline(always)]
+/// Rounds f32 towards minus infinity, same as *_mm_floor_ps*
+pub unsafe fn _mm_floor_psx(a: __m128) -> __m128 {
+    _mm_round_psx::<_MM_FROUND_FLOOR>(a)
+}
+
+#[inline(always)]
+/// Rounds f64 towards minus infinity, same as *_mm_floor_pd*
+pub unsafe fn _mm_floor_pdx(a: __m128d) -> __m128d {
+    _mm_round_pdx::<_MM_FROUND_FLOOR>(a)
+}
+
+#[inline(always)]
+/// Rounds f32 towards plus infinity, same as *_mm_ceil_ps*
+pub unsafe fn _mm_ceil_psx(a: __m128) -> __m128 {
+    _mm_round_psx::<_MM_FROUND_CEIL>(a)
+}
+
+#[inline(always)]
+/// Rounds f64 towards plus infinity, same as *_mm_ceil_pd*
+pub unsafe fn _mm_ceil_pdx(a: __m128d) -> __m128d {
+    _mm_round_pdx::<_MM_FROUND_CEIL>(a)
+}
+
+#[inline(always)]
+/// Extracts f32 lane as bits, same as *_mm_extract_ps*
+pub unsafe fn _mm_extract_psx<const IMM: i32>(a: __m128) -> i32 {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_extract_ps::<IMM>(a)
+    }
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        _mm_cvtsi128_si32(_mm_shuffle_epi32::<IMM>(_mm_castps_si128(a)))
+    }
+}
+
+#[inline(always)]
+/// Compares 64 bits integers for equality, same as *_mm_cmpeq_epi64*
+pub unsafe fn _mm_cmpeq_epi64x(a: __m128i, b: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.1")]
+    {
+        _mm_cmpeq_epi64(a, b)
+    }
+    #[cfg(not(target_feature = "sse4.1"))]
+    {
+        let eq = _mm_cmpeq_epi32(a, b);
+        _mm_and_si128(eq, _mm_shuffle_epi32::<0b10110001>(eq))
+    }
+}
+
+#[inline(always)]
+/// Compares signed 64 bits integers *greater than*, same as *_mm_cmpgt_epi64*
+pub unsafe fn _mm_cmpgt_epi64x(a: __m128i, b: __m128i) -> __m128i {
+    #[cfg(target_feature = "sse4.2")]
+    {
+        _mm_cmpgt_epi64(a, b)
+    }
+    #[cfg(not(target_feature = "sse4.2"))]
+    {
+        // High halves decide, when they are equal borrow of `b - a` is the answer
+        let mut r = _mm_and_si128(_mm_cmpeq_epi32(a, b), _mm_sub_epi64(b, a));
+        r = _mm_or_si128(r, _mm_cmpgt_epi32(a, b));
+        _mm_shuffle_epi32::<0b11110101>(_mm_srai_epi32::<31>(r))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_round_ps() {
+        let values = [
+            -2.5f32,
+            -1.5,
+            -0.7,
+            -0.5,
+            -0.3,
+            -0.0,
+            0.0,
+            0.3,
+            0.5,
+            0.7,
+            1.5,
+            2.5,
+            8388607.5,
+            -8388607.5,
+            8388609.,
+            1e20,
+            -1e20,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        ];
+        for v in values {
+            unsafe {
+                let x = _mm_set1_ps(v);
+                let check = |r: __m128, control: f32| {
+                    let r = f32::from_bits(_mm_extract_psx::<1>(r) as u32);
+                    assert_eq!(r.to_bits(), control.to_bits(), "{} {} {}", v, r, control);
+                };
+                check(
+                    _mm_round_psx::<_MM_FROUND_TO_NEAREST_INT>(x),
+                    v.round_ties_even(),
+                );
+                check(_mm_floor_psx(x), v.floor());
+                check(_mm_ceil_psx(x), v.ceil());
+                check(_mm_round_psx::<_MM_FROUND_TO_ZERO>(x), v.trunc());
+            }
+        }
+        unsafe {
+            let r = _mm_floor_psx(_mm_set1_ps(f32::NAN));
+            assert!(f32::from_bits(_mm_extract_psx::<0>(r) as u32).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_round_pd() {
+        let values = [
+            -2.5f64,
+            -1.5,
+            -0.7,
+            -0.5,
+            -0.3,
+            -0.0,
+            0.0,
+            0.3,
+            0.5,
+            0.7,
+            1.5,
+            2.5,
+            4503599627370495.5,
+            -4503599627370495.5,
+            4503599627370497.,
+            1e300,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ];
+        for v in values {
+            unsafe {
+                let x = _mm_set1_pd(v);
+                let check = |r: __m128d, control: f64| {
+                    let r = _mm_extract_pd::<1>(r);
+                    assert_eq!(r.to_bits(), control.to_bits(), "{} {} {}", v, r, control);
+                };
+                check(
+                    _mm_round_pdx::<_MM_FROUND_TO_NEAREST_INT>(x),
+                    v.round_ties_even(),
+                );
+                check(_mm_floor_pdx(x), v.floor());
+                check(_mm_ceil_pdx(x), v.ceil());
+                check(_mm_round_pdx::<_MM_FROUND_TO_ZERO>(x), v.trunc());
+            }
+        }
+    }
+
+    #[test]
+    fn test_blendv() {
+        unsafe {
+            let a = _mm_setr_ps(1., 2., 3., 4.);
+            let b = _mm_setr_ps(5., 6., 7., 8.);
+            let mask = _mm_setr_ps(-0.0, 0.0, -1., 1.);
+            let r = _mm_blendv_psx(a, b, mask);
+            assert_eq!(f32::from_bits(_mm_extract_psx::<0>(r) as u32), 5.);
+            assert_eq!(f32::from_bits(_mm_extract_psx::<1>(r) as u32), 2.);
+            assert_eq!(f32::from_bits(_mm_extract_psx::<2>(r) as u32), 7.);
+            assert_eq!(f32::from_bits(_mm_extract_psx::<3>(r) as u32), 4.);
+
+            let r = _mm_blendv_pdx(
+                _mm_setr_pd(1., 2.),
+                _mm_setr_pd(3., 4.),
+                _mm_setr_pd(0., -0.0),
+            );
+            assert_eq!(_mm_extract_pd::<0>(r), 1.);
+            assert_eq!(_mm_extract_pd::<1>(r), 4.);
+
+            let r = _mm_blendv_epi8x(
+                _mm_set1_epi8(1),
+                _mm_set1_epi8(2),
+                _mm_setr_epi8(-1, 0, -128, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0),
+            );
+            assert_eq!(_mm_cvtsi128_si32(r), 0x01020102);
+        }
+    }
+
+    #[test]
+    fn test_cmp_epi64() {
+        let values = [
+            i64::MIN,
+            i64::MIN + 1,
+            -4294967296,
+            -4294967295,
+            -1,
+            0,
+            1,
+            4294967295,
+            4294967296,
+            i64::MAX - 1,
+            i64::MAX,
+        ];
+        for a in values {
+            for b in values {
+                unsafe {
+                    let va = _mm_set1_epi64x(a);
+                    let vb = _mm_set1_epi64x(b);
+                    let gt = _mm_cvtsi128_si32(_mm_cmpgt_epi64x(va, vb));
+                    assert_eq!(gt, if a > b { -1 } else { 0 }, "{} > {}", a, b);
+                    let gt = _mm_cvtsi128_si32(_mm_srli_si128::<4>(_mm_cmpgt_epi64x(va, vb)));
+                    assert_eq!(gt, if a > b { -1 } else { 0 }, "{} > {}", a, b);
+                    let eq = _mm_cvtsi128_si32(_mm_cmpeq_epi64x(va, vb));
+                    assert_eq!(eq, if a == b { -1 } else { 0 }, "{} == {}", a, b);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm_abs_pd, _mm_copysign_pd, _mm_expm1_pd, _mm_select_pd};
+
+/// Computes hyperbolic tangent, error bound *ULP 2.5*
+#[inline(always)]
+pub unsafe fn _mm_tanh_pd(d: __m128d) -> __m128d {
+    let a = _mm_abs_pd(d);
+    let e = _mm_expm1_pd(_mm_add_pd(a, a));
+    let mut r = _mm_div_pd(e, _mm_add_pd(e, _mm_set1_pd(2f64)));
+    // tanh rounds to 1 long before expm1 overflows
+    r = _mm_select_pd(_mm_cmpgt_pd(a, _mm_set1_pd(22f64)), _mm_set1_pd(1f64), r);
+    _mm_copysign_pd(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_tanhd() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(0.5f64);
+            let comparison = _mm_tanh_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 0.5f64.tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(-1e-10f64);
+            let comparison = _mm_tanh_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = (-1e-10f64).tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm_set1_pd(30f64);
+            let comparison = _mm_tanh_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 1.);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm_set1_pd(-30f64);
+            let comparison = _mm_tanh_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::{_mm_abs_ps, _mm_copysign_ps, _mm_expm1_ps, _mm_select_ps};
+
+/// Computes hyperbolic tangent, error bound *ULP 2.5*
+#[inline(always)]
+pub unsafe fn _mm_tanh_ps(d: __m128) -> __m128 {
+    let a = _mm_abs_ps(d);
+    let e = _mm_expm1_ps(_mm_add_ps(a, a));
+    let mut r = _mm_div_ps(e, _mm_add_ps(e, _mm_set1_ps(2f32)));
+    // tanh rounds to 1 long before expm1 overflows
+    r = _mm_select_ps(_mm_cmpgt_ps(a, _mm_set1_ps(10f32)), _mm_set1_ps(1f32), r);
+    _mm_copysign_ps(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_tanhf() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(0.5f32);
+            let comparison = _mm_tanh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 0.5f32.tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(-1e-10f32);
+            let comparison = _mm_tanh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = (-1e-10f32).tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm_set1_ps(30f32);
+            let comparison = _mm_tanh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, 1.);
+        }
+
+        unsafe {
+            // Test saturation
+            let value = _mm_set1_ps(-30f32);
+            let comparison = _mm_tanh_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, -1.);
+        }
+    }
+
+    #[test]
+    fn test_tanhf_ulp() {
+        // Log-uniform magnitudes from 1e-6 to 12, past that tanh rounds to one
+        let mut state = 0x5851_f42d_4c95_7f2du64;
+        for i in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let sign = if i % 2 == 0 { 1. } else { -1. };
+            let x = (sign * 10f64.powf(u * 7.08 - 6.)) as f32;
+            let control = (x as f64).tanh();
+            let value = unsafe { _mm_cvtss_f32(_mm_tanh_ps(_mm_set1_ps(x))) };
+            for value in [value, crate::etanhf(x)] {
+                assert!(
+                    ulp(value, control) <= 2.5,
+                    "tanh({x}) = {value}, expected {control}"
+                );
+            }
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
+}
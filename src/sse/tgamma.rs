@@ -0,0 +1,193 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::exp::{L2_L, L2_U, R_LN2};
+use crate::sse::exp::_mm_expm1k_pd;
+use crate::sse::general::{_mm_mul_doubled_pd, _mm_rint_pd, _mm_sum_as_doubled_pd};
+use crate::sse::ln::_mm_lnk_doubled_pd;
+use crate::sse::sin::_mm_sinpik_pd;
+use crate::tgamma::{
+    SQRT_2PI_D, SQRT_2PI_LO_D, SQRT_HALF_PI_D, SQRT_HALF_PI_LO_D, STIRLING_POLY_1_D,
+    STIRLING_POLY_2_D, STIRLING_POLY_3_D, STIRLING_POLY_4_D, STIRLING_POLY_5_D, STIRLING_POLY_6_D,
+};
+use crate::{
+    _mm_abs_pd, _mm_copysign_pd, _mm_cvtepi64_pd, _mm_isnotintegral_pd, _mm_ldexp2k_pd,
+    _mm_mlaf_pd, _mm_neg_pd, _mm_select_pd,
+};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Computes lnΓ(w) - ln(sqrt(2pi)) as double-float for w >= 10
+#[inline(always)]
+pub(crate) unsafe fn _mm_stirlingk_pd(w: (__m128d, __m128d)) -> (__m128d, __m128d) {
+    let l = _mm_lnk_doubled_pd(w);
+    // (w - 0.5) ln(w) - w, w - 0.5 is exact below 2^52
+    let m = _mm_mul_doubled_pd((_mm_sub_pd(w.0, _mm_set1_pd(0.5f64)), w.1), l);
+    let e = _mm_sum_as_doubled_pd(m.0, _mm_neg_pd(w.0));
+    let iw = _mm_div_pd(_mm_set1_pd(1f64), w.0);
+    let v = _mm_mul_pd(iw, iw);
+    let mut u = _mm_set1_pd(STIRLING_POLY_6_D);
+    u = _mm_mlaf_pd(u, v, _mm_set1_pd(STIRLING_POLY_5_D));
+    u = _mm_mlaf_pd(u, v, _mm_set1_pd(STIRLING_POLY_4_D));
+    u = _mm_mlaf_pd(u, v, _mm_set1_pd(STIRLING_POLY_3_D));
+    u = _mm_mlaf_pd(u, v, _mm_set1_pd(STIRLING_POLY_2_D));
+    u = _mm_mlaf_pd(u, v, _mm_set1_pd(STIRLING_POLY_1_D));
+    (
+        e.0,
+        _mm_add_pd(e.1, _mm_mlaf_pd(iw, u, _mm_sub_pd(m.1, w.1))),
+    )
+}
+
+/// Computes gamma function, error bound *ULP 3.5*, *ULP 2.5* for positive arguments
+#[inline(always)]
+pub unsafe fn _mm_tgamma_pd(d: __m128d) -> __m128d {
+    let reflect = _mm_cmplt_pd(d, _mm_setzero_pd());
+    // Γ(d) = pi / (sin(pi d) Γ(1 - d)) for negative d, 1 - d is kept as double-float
+    let s = _mm_sum_as_doubled_pd(_mm_set1_pd(1f64), _mm_neg_pd(d));
+    let z = (
+        _mm_select_pd(reflect, s.0, d),
+        _mm_select_pd(reflect, s.1, _mm_setzero_pd()),
+    );
+    // Γ(z) = Γ(z + 10) / (z (z + 1) ... (z + 9)) moves the argument into Stirling range
+    let mut w = z;
+    let mut q = (_mm_set1_pd(1f64), _mm_setzero_pd());
+    for _ in 0..10 {
+        q = _mm_mul_doubled_pd(q, w);
+        let s = _mm_sum_as_doubled_pd(w.0, _mm_set1_pd(1f64));
+        w = (s.0, _mm_add_pd(s.1, w.1));
+    }
+    let shift = _mm_cmplt_pd(z.0, _mm_set1_pd(10f64));
+    let w = (
+        _mm_select_pd(shift, w.0, z.0),
+        _mm_select_pd(shift, w.1, z.1),
+    );
+    let q = (
+        _mm_select_pd(shift, q.0, _mm_set1_pd(1f64)),
+        _mm_select_pd(shift, q.1, _mm_setzero_pd()),
+    );
+    // ln(Γ(z)) - ln(sqrt(2pi)) = E - ln(q) with every low part kept, so nothing is divided afterwards
+    let e = _mm_stirlingk_pd(w);
+    let l = _mm_lnk_doubled_pd(q);
+    let h = _mm_sum_as_doubled_pd(e.0, _mm_neg_pd(l.0));
+    let lo = _mm_add_pd(h.1, _mm_sub_pd(e.1, l.1));
+    // Reflected lanes take exp of the negated exponent
+    let hi = _mm_select_pd(reflect, _mm_neg_pd(h.0), h.0);
+    let lo = _mm_select_pd(reflect, _mm_neg_pd(lo), lo);
+    let qi = _mm_rint_pd(_mm_mul_pd(hi, _mm_set1_pd(R_LN2)));
+    let qf = _mm_cvtepi64_pd(qi);
+    let mut r = _mm_mlaf_pd(qf, _mm_set1_pd(-L2_U), hi);
+    r = _mm_mlaf_pd(qf, _mm_set1_pd(-L2_L), r);
+    r = _mm_add_pd(r, lo);
+    let g = _mm_select_pd(
+        reflect,
+        _mm_set1_pd(SQRT_HALF_PI_D),
+        _mm_set1_pd(SQRT_2PI_D),
+    );
+    let g_lo = _mm_select_pd(
+        reflect,
+        _mm_set1_pd(SQRT_HALF_PI_LO_D / SQRT_HALF_PI_D),
+        _mm_set1_pd(SQRT_2PI_LO_D / SQRT_2PI_D),
+    );
+    let t = _mm_expm1k_pd(_mm_add_pd(r, g_lo));
+    let u = _mm_mlaf_pd(g, t, g);
+    let sp = _mm_sinpik_pd(d);
+    let mut ret = _mm_ldexp2k_pd(_mm_select_pd(reflect, _mm_div_pd(u, sp), u), qi);
+    // Γ(d) = 1/d - γ + O(d), past this point γ is below half an ulp of 1/d
+    ret = _mm_select_pd(
+        _mm_cmplt_pd(_mm_abs_pd(d), _mm_set1_pd(5.551115123125783e-17f64)),
+        _mm_div_pd(_mm_set1_pd(1f64), d),
+        ret,
+    );
+    // d > 171.7 overflows, also covers d == Inf
+    ret = _mm_select_pd(
+        _mm_cmpgt_pd(d, _mm_set1_pd(171.7f64)),
+        _mm_set1_pd(f64::INFINITY),
+        ret,
+    );
+    // |Γ(d)| is below the smallest subnormal
+    ret = _mm_select_pd(
+        _mm_cmplt_pd(d, _mm_set1_pd(-190f64)),
+        _mm_copysign_pd(_mm_setzero_pd(), sp),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    _mm_select_pd(
+        _mm_andnot_pd(_mm_isnotintegral_pd(d), reflect),
+        _mm_set1_pd(f64::NAN),
+        ret,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_pd;
+
+    #[test]
+    fn test_tgammad() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(0.5f64);
+            let comparison = _mm_tgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 1.772453850905516f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(-1.5f64);
+            let comparison = _mm_tgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 2.363271801207355f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_pd(5.5f64);
+            let comparison = _mm_tgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            let expected = 52.34277778455352f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        unsafe {
+            // Test exact value
+            let value = _mm_set1_pd(5f64);
+            let comparison = _mm_tgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, 24.);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm_set1_pd(-2f64);
+            let comparison = _mm_tgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm_set1_pd(200f64);
+            let comparison = _mm_tgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        unsafe {
+            // Test NaN
+            let value = _mm_set1_pd(f64::NAN);
+            let comparison = _mm_tgamma_pd(value);
+            let flag_1 = _mm_extract_pd::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
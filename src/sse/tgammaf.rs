@@ -0,0 +1,220 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::expf::{L2L_F, L2U_F, R_LN2_F};
+use crate::sse::expf::_mm_expm1k_ps;
+use crate::sse::generalf::{_mm_mul_doubled_ps, _mm_sum_as_doubled_ps};
+use crate::sse::lnf::_mm_lnk_doubled_ps;
+use crate::sse::sinf::_mm_sinpik_ps;
+use crate::tgammaf::{
+    SQRT_2PI_LO_S, SQRT_2PI_S, SQRT_HALF_PI_LO_S, SQRT_HALF_PI_S, STIRLING_POLY_1_S,
+    STIRLING_POLY_2_S, STIRLING_POLY_3_S, STIRLING_POLY_4_S,
+};
+use crate::{
+    _mm_abs_ps, _mm_copysign_ps, _mm_isnotintegral_ps, _mm_ldexp2kq_ps, _mm_mlaf_ps, _mm_neg_ps,
+    _mm_rint_ps, _mm_select_ps,
+};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Computes lnΓ(w) - ln(sqrt(2pi)) as double-float for w >= 5
+#[inline(always)]
+pub(crate) unsafe fn _mm_stirlingk_ps(w: (__m128, __m128)) -> (__m128, __m128) {
+    let l = _mm_lnk_doubled_ps(w);
+    // (w - 0.5) ln(w) - w, w - 0.5 is exact below 2^23
+    let m = _mm_mul_doubled_ps((_mm_sub_ps(w.0, _mm_set1_ps(0.5f32)), w.1), l);
+    let e = _mm_sum_as_doubled_ps(m.0, _mm_neg_ps(w.0));
+    let iw = _mm_div_ps(_mm_set1_ps(1f32), w.0);
+    let v = _mm_mul_ps(iw, iw);
+    let mut u = _mm_set1_ps(STIRLING_POLY_4_S);
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(STIRLING_POLY_3_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(STIRLING_POLY_2_S));
+    u = _mm_mlaf_ps(u, v, _mm_set1_ps(STIRLING_POLY_1_S));
+    (
+        e.0,
+        _mm_add_ps(e.1, _mm_mlaf_ps(iw, u, _mm_sub_ps(m.1, w.1))),
+    )
+}
+
+/// Computes gamma function, error bound *ULP 3.5*, *ULP 2.0* for positive arguments
+#[inline(always)]
+pub unsafe fn _mm_tgamma_ps(d: __m128) -> __m128 {
+    let reflect = _mm_cmplt_ps(d, _mm_setzero_ps());
+    // Γ(d) = pi / (sin(pi d) Γ(1 - d)) for negative d, 1 - d is kept as double-float
+    let s = _mm_sum_as_doubled_ps(_mm_set1_ps(1f32), _mm_neg_ps(d));
+    let z = (
+        _mm_select_ps(reflect, s.0, d),
+        _mm_select_ps(reflect, s.1, _mm_setzero_ps()),
+    );
+    // Γ(z) = Γ(z + 5) / (z (z + 1) ... (z + 4)) moves the argument into Stirling range
+    let mut w = z;
+    let mut q = (_mm_set1_ps(1f32), _mm_setzero_ps());
+    for _ in 0..5 {
+        q = _mm_mul_doubled_ps(q, w);
+        let s = _mm_sum_as_doubled_ps(w.0, _mm_set1_ps(1f32));
+        w = (s.0, _mm_add_ps(s.1, w.1));
+    }
+    let shift = _mm_cmplt_ps(z.0, _mm_set1_ps(5f32));
+    let w = (
+        _mm_select_ps(shift, w.0, z.0),
+        _mm_select_ps(shift, w.1, z.1),
+    );
+    let q = (
+        _mm_select_ps(shift, q.0, _mm_set1_ps(1f32)),
+        _mm_select_ps(shift, q.1, _mm_setzero_ps()),
+    );
+    // ln(Γ(z)) - ln(sqrt(2pi)) = E - ln(q) with every low part kept, so nothing is divided afterwards
+    let e = _mm_stirlingk_ps(w);
+    let l = _mm_lnk_doubled_ps(q);
+    let h = _mm_sum_as_doubled_ps(e.0, _mm_neg_ps(l.0));
+    let lo = _mm_add_ps(h.1, _mm_sub_ps(e.1, l.1));
+    // Reflected lanes take exp of the negated exponent
+    let hi = _mm_select_ps(reflect, _mm_neg_ps(h.0), h.0);
+    let lo = _mm_select_ps(reflect, _mm_neg_ps(lo), lo);
+    let qi = _mm_rint_ps(_mm_mul_ps(hi, _mm_set1_ps(R_LN2_F)));
+    let qf = _mm_cvtepi32_ps(qi);
+    let mut r = _mm_mlaf_ps(qf, _mm_set1_ps(-L2U_F), hi);
+    r = _mm_mlaf_ps(qf, _mm_set1_ps(-L2L_F), r);
+    r = _mm_add_ps(r, lo);
+    let g = _mm_select_ps(
+        reflect,
+        _mm_set1_ps(SQRT_HALF_PI_S),
+        _mm_set1_ps(SQRT_2PI_S),
+    );
+    let g_lo = _mm_select_ps(
+        reflect,
+        _mm_set1_ps(SQRT_HALF_PI_LO_S / SQRT_HALF_PI_S),
+        _mm_set1_ps(SQRT_2PI_LO_S / SQRT_2PI_S),
+    );
+    let t = _mm_expm1k_ps(_mm_add_ps(r, g_lo));
+    let u = _mm_mlaf_ps(g, t, g);
+    let sp = _mm_sinpik_ps(d);
+    let mut ret = _mm_ldexp2kq_ps(_mm_select_ps(reflect, _mm_div_ps(u, sp), u), qi);
+    // Γ(d) = 1/d - γ + O(d), past this point γ is below half an ulp of 1/d
+    ret = _mm_select_ps(
+        _mm_cmplt_ps(_mm_abs_ps(d), _mm_set1_ps(2.9802322e-8f32)),
+        _mm_div_ps(_mm_set1_ps(1f32), d),
+        ret,
+    );
+    // d > 35.1 overflows, also covers d == Inf
+    ret = _mm_select_ps(
+        _mm_cmpgt_ps(d, _mm_set1_ps(35.1f32)),
+        _mm_set1_ps(f32::INFINITY),
+        ret,
+    );
+    // |Γ(d)| is below the smallest subnormal
+    ret = _mm_select_ps(
+        _mm_cmplt_ps(d, _mm_set1_ps(-45f32)),
+        _mm_copysign_ps(_mm_setzero_ps(), sp),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    _mm_select_ps(
+        _mm_andnot_ps(_mm_isnotintegral_ps(d), reflect),
+        _mm_set1_ps(f32::NAN),
+        ret,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::_mm_extract_psx;
+
+    #[test]
+    fn test_tgammaf() {
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(0.5f32);
+            let comparison = _mm_tgamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 1.7724539f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(-1.5f32);
+            let comparison = _mm_tgamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 2.3632717f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test regular
+            let value = _mm_set1_ps(5.5f32);
+            let comparison = _mm_tgamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            let expected = 52.342777f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        unsafe {
+            // Test exact value
+            let value = _mm_set1_ps(5f32);
+            let comparison = _mm_tgamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, 24.);
+        }
+
+        unsafe {
+            // Test pole
+            let value = _mm_set1_ps(-2f32);
+            let comparison = _mm_tgamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert!(flag_1.is_nan());
+        }
+
+        unsafe {
+            // Test overflow
+            let value = _mm_set1_ps(40f32);
+            let comparison = _mm_tgamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        unsafe {
+            // Test NaN
+            let value = _mm_set1_ps(f32::NAN);
+            let comparison = _mm_tgamma_ps(value);
+            let flag_1 = f32::from_bits(_mm_extract_psx::<0>(comparison) as u32);
+            assert!(flag_1.is_nan());
+        }
+    }
+
+    #[test]
+    fn test_tgammaf_ulp() {
+        // Uniform on (-30, 35), poles are skipped
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        for _ in 0..100_000 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let u = (state >> 40) as f64 / (1u64 << 24) as f64;
+            let x = (u * 65. - 30.) as f32;
+            if x <= 0. && x == x.floor() {
+                continue;
+            }
+            let control = crate::etgamma(x as f64);
+            let value = unsafe { _mm_cvtss_f32(_mm_tgamma_ps(_mm_set1_ps(x))) };
+            for value in [value, crate::etgammaf(x)] {
+                assert!(
+                    ulp(value, control) <= 3.5,
+                    "tgamma({x}) = {value}, expected {control}"
+                );
+            }
+        }
+    }
+
+    fn ulp(v: f32, e: f64) -> f64 {
+        let exponent = (e.abs().log2().floor() as i32).max(-126);
+        (v as f64 - e).abs() / 2f64.powi(exponent - 23)
+    }
+}
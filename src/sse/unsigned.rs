@@ -5,112 +5,116 @@
  * // license that can be found in the LICENSE file.
  */
 use crate::shuffle::_mm_shuffle;
+use crate::{_mm_blendv_epi8x, _mm_cmpeq_epi64x, _mm_cmpgt_epi64x};
 #[cfg(target_arch = "x86")]
 use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
-#[inline]
+#[inline(always)]
 /// Compare *greater than or equal to* unsigned 8,
 pub unsafe fn _mm_cmpge_epu8(a: __m128i, b: __m128i) -> __m128i {
     _mm_cmpeq_epi8(_mm_max_epu8(a, b), a)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *less than or equal to* unsigned 8,
 pub unsafe fn _mm_cmple_epu8(a: __m128i, b: __m128i) -> __m128i {
     _mm_cmpge_epu8(b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *greater than* unsigned 8,
 pub unsafe fn _mm_cmpgt_epu8(a: __m128i, b: __m128i) -> __m128i {
     _mm_xor_si128(_mm_cmple_epu8(a, b), _mm_set1_epi8(-1))
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *less than* unsigned 8,
 pub unsafe fn _mm_cmplt_epu8(a: __m128i, b: __m128i) -> __m128i {
     _mm_cmpgt_epu8(b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *greater than or equal to* unsigned 16,
 pub unsafe fn _mm_cmpge_epu16(a: __m128i, b: __m128i) -> __m128i {
-    _mm_cmpeq_epi16(_mm_max_epu16(a, b), a)
+    _mm_cmpeq_epi16(_mm_subs_epu16(b, a), _mm_setzero_si128())
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *less than or equal to* unsigned 16,
 pub unsafe fn _mm_cmple_epu16(a: __m128i, b: __m128i) -> __m128i {
     _mm_cmpge_epu16(b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *greater than* unsigned 16,
 pub unsafe fn _mm_cmpgt_epu16(a: __m128i, b: __m128i) -> __m128i {
     _mm_xor_si128(_mm_cmple_epu16(a, b), _mm_set1_epi16(-1))
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *less than* unsigned 16,
 pub unsafe fn _mm_cmplt_epu16(a: __m128i, b: __m128i) -> __m128i {
     _mm_cmpgt_epu16(b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *greater than or equal to* unsigned 32,
 pub unsafe fn _mm_cmpge_epu32(a: __m128i, b: __m128i) -> __m128i {
-    _mm_cmpeq_epi32(_mm_max_epu32(a, b), a)
+    // Flipping sign bit maps unsigned order onto signed order
+    let flip = _mm_set1_epi32(i32::MIN);
+    let lt = _mm_cmpgt_epi32(_mm_xor_si128(b, flip), _mm_xor_si128(a, flip));
+    _mm_xor_si128(lt, _mm_set1_epi32(-1))
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *less than or equal to* unsigned 32,
 pub unsafe fn _mm_cmple_epu32(a: __m128i, b: __m128i) -> __m128i {
-    _mm_cmpge_epu16(b, a)
+    _mm_cmpge_epu32(b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *greater than* unsigned 32,
 pub unsafe fn _mm_cmpgt_epu32(a: __m128i, b: __m128i) -> __m128i {
     _mm_xor_si128(_mm_cmple_epu32(a, b), _mm_set1_epi32(-1))
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *less than* unsigned 16,
 pub unsafe fn _mm_cmplt_epu32(a: __m128i, b: __m128i) -> __m128i {
     _mm_cmpgt_epu32(b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Takes max for epi64
 pub unsafe fn _mm_max_epi64x(a: __m128i, b: __m128i) -> __m128i {
-    let mask = _mm_cmpgt_epi64(a, b);
-    _mm_blendv_epi8(b, a, mask)
+    let mask = _mm_cmpgt_epi64x(a, b);
+    _mm_blendv_epi8x(b, a, mask)
 }
 
-#[inline]
+#[inline(always)]
 /// Takes min for epi64
 pub unsafe fn _mm_min_epi64x(a: __m128i, b: __m128i) -> __m128i {
-    let mut mask = _mm_cmpgt_epi64(a, b);
+    let mut mask = _mm_cmpgt_epi64x(a, b);
     let ffs = _mm_cmpeq_epi32(_mm_setzero_si128(), _mm_setzero_si128());
     mask = _mm_andnot_si128(mask, ffs);
-    _mm_blendv_epi8(b, a, mask)
+    _mm_blendv_epi8x(b, a, mask)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *greater than or equal to* unsigned 64 bytes integers,
 pub unsafe fn _mm_cmpge_epu64(a: __m128i, b: __m128i) -> __m128i {
-    _mm_cmpeq_epi64(_mm_max_epi64x(a, b), a)
+    _mm_xor_si128(_mm_cmpgt_epu64(b, a), _mm_set1_epi32(-1))
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *less than or equal to* unsigned 64 bytes integers,
 pub unsafe fn _mm_cmple_epu64(a: __m128i, b: __m128i) -> __m128i {
     _mm_cmpge_epu64(b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *greater than* unsigned 64 bytes integers,
 pub unsafe fn _mm_cmpgt_epu64(a: __m128i, b: __m128i) -> __m128i {
     let b = _mm_xor_si128(b, a);
@@ -120,33 +124,34 @@ pub unsafe fn _mm_cmpgt_epu64(a: __m128i, b: __m128i) -> __m128i {
     _mm_shuffle_epi32::<SHUFFLE_FLAG>(_mm_srai_epi32::<31>(b))
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *less than* unsigned 64 bytes integers,
 pub unsafe fn _mm_cmplt_epu64(a: __m128i, b: __m128i) -> __m128i {
     _mm_cmpgt_epu64(b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *greater than or equal to* signed 64 bytes integers,
 pub unsafe fn _mm_cmpge_epi64(a: __m128i, b: __m128i) -> __m128i {
-    _mm_cmpeq_epi64(_mm_max_epi64x(a, b), a)
+    _mm_cmpeq_epi64x(_mm_max_epi64x(a, b), a)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *less than or equal to* signed 64 bytes integers,
 pub unsafe fn _mm_cmple_epi64(a: __m128i, b: __m128i) -> __m128i {
     _mm_cmpge_epi64(b, a)
 }
 
-#[inline]
+#[inline(always)]
 /// Compare *less than* signed 64 bytes integers,
 pub unsafe fn _mm_cmplt_epi64(a: __m128i, b: __m128i) -> __m128i {
-    _mm_cmpgt_epi64(b, a)
+    _mm_cmpgt_epi64x(b, a)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::_mm_extract_epi64x;
     use crate::sse::epi64::_mm_setr_epi64x;
 
     #[test]
@@ -154,9 +159,9 @@ mod tests {
         unsafe {
             let top = _mm_setr_epi64x(23, 0);
             let low = _mm_setr_epi64x(6, 15);
-            let comparison = _mm_cmpgt_epi64(top, low);
-            let flag_1 = _mm_extract_epi64::<0>(comparison);
-            let flag_2 = _mm_extract_epi64::<1>(comparison);
+            let comparison = _mm_cmpgt_epi64x(top, low);
+            let flag_1 = _mm_extract_epi64x::<0>(comparison);
+            let flag_2 = _mm_extract_epi64x::<1>(comparison);
             assert_ne!(flag_1, 0);
             assert_eq!(flag_2, 0);
         }
@@ -164,9 +169,9 @@ mod tests {
         unsafe {
             let top = _mm_setr_epi64x(23, -5);
             let low = _mm_setr_epi64x(-6, 15);
-            let comparison = _mm_cmpgt_epi64(top, low);
-            let flag_1 = _mm_extract_epi64::<0>(comparison);
-            let flag_2 = _mm_extract_epi64::<1>(comparison);
+            let comparison = _mm_cmpgt_epi64x(top, low);
+            let flag_1 = _mm_extract_epi64x::<0>(comparison);
+            let flag_2 = _mm_extract_epi64x::<1>(comparison);
             assert_ne!(flag_1, 0);
             assert_eq!(flag_2, 0);
         }
@@ -178,8 +183,8 @@ mod tests {
             let top = _mm_setr_epi64x(23, 0);
             let low = _mm_setr_epi64x(6, 15);
             let comparison = _mm_cmpgt_epu64(top, low);
-            let flag_1 = _mm_extract_epi64::<0>(comparison);
-            let flag_2 = _mm_extract_epi64::<1>(comparison);
+            let flag_1 = _mm_extract_epi64x::<0>(comparison);
+            let flag_2 = _mm_extract_epi64x::<1>(comparison);
             assert_ne!(flag_1, 0);
             assert_eq!(flag_2, 0);
         }
@@ -188,10 +193,59 @@ mod tests {
             let top = _mm_setr_epi64x(23, -5);
             let low = _mm_setr_epi64x(-6, 15);
             let comparison = _mm_cmpgt_epu64(top, low);
-            let flag_1 = _mm_extract_epi64::<0>(comparison);
-            let flag_2 = _mm_extract_epi64::<1>(comparison);
+            let flag_1 = _mm_extract_epi64x::<0>(comparison);
+            let flag_2 = _mm_extract_epi64x::<1>(comparison);
             assert_eq!(flag_1, 0);
             assert_ne!(flag_2, 0);
         }
     }
+
+    #[test]
+    fn test_cmp_unsigned() {
+        let values = [
+            0u64,
+            1,
+            0x7f,
+            0x80,
+            0xff,
+            0x7fff,
+            0x8000,
+            0xffff,
+            0x7fff_ffff,
+            0x8000_0000,
+        ];
+        let values = values
+            .iter()
+            .flat_map(|&v| [v, u64::MAX - v, v << 32 | v])
+            .collect::<Vec<_>>();
+        for &a in values.iter() {
+            for &b in values.iter() {
+                unsafe {
+                    let va = _mm_set1_epi64x(a as i64);
+                    let vb = _mm_set1_epi64x(b as i64);
+                    let lane = |v: __m128i| _mm_cvtsi128_si32(v) as u32;
+                    assert_eq!(
+                        lane(_mm_cmpge_epu8(va, vb)) & 0xff,
+                        if a as u8 >= b as u8 { 0xff } else { 0 }
+                    );
+                    assert_eq!(
+                        lane(_mm_cmpge_epu16(va, vb)) & 0xffff,
+                        if a as u16 >= b as u16 { 0xffff } else { 0 }
+                    );
+                    assert_eq!(
+                        lane(_mm_cmple_epu32(va, vb)),
+                        if a as u32 <= b as u32 { u32::MAX } else { 0 }
+                    );
+                    assert_eq!(
+                        _mm_extract_epi64x::<1>(_mm_cmpge_epu64(va, vb)),
+                        if a >= b { -1 } else { 0 }
+                    );
+                    assert_eq!(
+                        _mm_extract_epi64x::<1>(_mm_cmple_epi64(va, vb)),
+                        if a as i64 <= b as i64 { -1 } else { 0 }
+                    );
+                }
+            }
+        }
+    }
 }
@@ -4,21 +4,26 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
-use crate::generalf::{mlaf, rintk};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_tan;
+use crate::generalf::{mlaf, rempi2, rintk, PAYNE_HANEK_THRESHOLD};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vtanq_f64;
 use crate::sin::{PI_A2, PI_B2};
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
 use crate::{_mm_extract_pd, _mm_tan_pd};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub(crate) const TAN_POLY_1_D: f64 = 0.333_333_333_333_334_369_5;
 pub(crate) const TAN_POLY_2_D: f64 = 0.133_333_333_333_050_058_1;
@@ -31,11 +36,15 @@ pub(crate) const TAN_POLY_8_D: f64 = 0.561_921_973_811_432_373_5_e-3;
 pub(crate) const TAN_POLY_9_D: f64 = 0.324_509_882_663_927_631_6_e-3;
 
 #[inline]
-fn do_tan(d: f64) -> f64 {
-    let qlf = rintk(d * std::f64::consts::FRAC_2_PI);
-    let q = qlf as isize;
-    let mut x = mlaf(qlf, -PI_A2 * 0.5, d);
-    x = mlaf(qlf, -PI_B2 * 0.5, x);
+pub(crate) fn do_tan(d: f64) -> f64 {
+    let (q, mut x) = if d.abs() > PAYNE_HANEK_THRESHOLD && d.is_finite() {
+        let (q, x) = rempi2(d);
+        (q as isize, x)
+    } else {
+        let qlf = rintk(d * std::f64::consts::FRAC_2_PI);
+        let x = mlaf(qlf, -PI_A2 * 0.5, d);
+        (qlf as isize, mlaf(qlf, -PI_B2 * 0.5, x))
+    };
 
     if (q & 1) != 0 {
         x = -x;
@@ -75,30 +84,65 @@ fn do_tan_neon(d: f64) -> f64 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
 #[inline(always)]
-fn do_tan_sse(d: f64) -> f64 {
-    unsafe {
-        let ld = _mm_set1_pd(d);
-        _mm_extract_pd::<0>(_mm_tan_pd(ld))
-    }
+unsafe fn do_tan_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_tan_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_tan_sse41(d: f64) -> f64 {
+    do_tan_sse(d)
 }
 
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_tan_fma(d: f64) -> f64 {
+    do_tan(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 #[inline]
-/// Computes tan with error bound *ULP 2.0*
+fn do_tan_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_tan(f64x2_splat(d)))
+}
+
+#[inline]
+/// Computes tan with error bound *ULP 4.0*
 pub fn etan(d: f64) -> f64 {
     let mut _dispatcher: fn(f64) -> f64 = do_tan;
     #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
     {
         _dispatcher = do_tan_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_tan_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_tan_sse;
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_tan_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_tan_sse41(d) }
+            } else {
+                |d| unsafe { do_tan_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
@@ -4,22 +4,27 @@
  * // Use of this source code is governed by a BSD-style
  * // license that can be found in the LICENSE file.
  */
-#[cfg(all(
-    any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
-))]
-use crate::_mm_tan_ps;
 use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
-use crate::generalf::{mlaf, rintfk, IsNegZero};
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_tan;
+use crate::generalf::{mlaf, rempi2f, rintfk, PAYNE_HANEK_THRESHOLD_F};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use crate::neon::vtanq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_tan_ps};
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
-#[cfg(all(target_arch = "x86", target_feature = "sse4.1"))]
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
 use std::arch::x86::*;
-#[cfg(all(target_arch = "x86_64", target_feature = "sse4.1"))]
+#[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 use std::f32::consts::FRAC_2_PI;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
 
 pub(crate) const TAN_POLY_1_S: f32 = 0.3333353561669567628359f32;
 pub(crate) const TAN_POLY_2_S: f32 = 0.1332909226735641872812f32;
@@ -33,12 +38,23 @@ pub(crate) const TAN_POLY_9_S: f32 = 0.003119367819237227984603f32;
 
 #[inline]
 fn do_tanf(d: f32) -> f32 {
-    let qf = rintfk(d * FRAC_2_PI);
-    let q = qf as i32;
-    let mut x = mlaf(qf, -PI_A_F * 0.5, d);
-    x = mlaf(qf, -PI_B_F * 0.5, x);
-    x = mlaf(qf, -PI_C_F * 0.5, x);
-    x = mlaf(qf, -PI_D_F * 0.5, x);
+    // tan(±0) = ±0
+    if d == 0. {
+        return d;
+    }
+    // tan(±inf) = NaN
+    if d.is_infinite() {
+        return f32::NAN;
+    }
+    let (q, mut x) = if d.abs() > PAYNE_HANEK_THRESHOLD_F && d.is_finite() {
+        rempi2f(d)
+    } else {
+        let qf = rintfk(d * FRAC_2_PI);
+        let mut x = mlaf(qf, -PI_A_F * 0.5, d);
+        x = mlaf(qf, -PI_B_F * 0.5, x);
+        x = mlaf(qf, -PI_C_F * 0.5, x);
+        (qf as i32, mlaf(qf, -PI_D_F * 0.5, x))
+    };
 
     let x2 = x * x;
 
@@ -61,11 +77,7 @@ fn do_tanf(d: f32) -> f32 {
         u = 1. / u;
     }
 
-    let c = u;
-    if c.isnegzero() {
-        return 0f32;
-    }
-    c
+    u
 }
 
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
@@ -79,14 +91,36 @@ fn do_tanf_neon(d: f32) -> f32 {
 
 #[cfg(all(
     any(target_arch = "x86_64", target_arch = "x86"),
-    target_feature = "sse4.1"
+    target_feature = "sse2"
 ))]
 #[inline(always)]
-fn do_tanf_sse(d: f32) -> f32 {
-    unsafe {
-        let ld = _mm_set1_ps(d);
-        f32::from_bits(_mm_extract_ps::<0>(_mm_tan_ps(ld)) as u32)
-    }
+unsafe fn do_tanf_sse(d: f32) -> f32 {
+    let ld = _mm_set1_ps(d);
+    f32::from_bits(_mm_extract_psx::<0>(_mm_tan_ps(ld)) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_tanf_sse41(d: f32) -> f32 {
+    do_tanf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_tanf_fma(d: f32) -> f32 {
+    do_tanf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_tanf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_tan(f32x4_splat(d)))
 }
 
 /// Computes tan *ULP 2.0*
@@ -97,12 +131,25 @@ pub fn etanf(d: f32) -> f32 {
     {
         _dispatcher = do_tanf_neon;
     }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_tanf_wasm;
+    }
     #[cfg(all(
         any(target_arch = "x86_64", target_arch = "x86"),
-        target_feature = "sse4.1"
+        target_feature = "sse2"
     ))]
     {
-        _dispatcher = do_tanf_sse;
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_tanf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_tanf_sse41(d) }
+            } else {
+                |d| unsafe { do_tanf_sse(d) }
+            }
+        });
     }
     _dispatcher(d)
 }
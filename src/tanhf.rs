@@ -0,0 +1,116 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::expm1f::do_expm1f;
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_tanh;
+use crate::generalf::copysignfk;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vtanhq_f32;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_tanh_ps};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+#[inline]
+fn do_tanhf(d: f32) -> f32 {
+    let a = eabsf(d);
+    // tanh rounds to 1 long before expm1 overflows
+    let r = if a > 10f32 {
+        1.
+    } else {
+        let e = do_expm1f(a + a);
+        e / (e + 2.)
+    };
+    copysignfk(r, d)
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_tanhf_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vtanhq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_tanhf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_tanh_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_tanhf_sse41(d: f32) -> f32 {
+    do_tanhf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_tanhf_fma(d: f32) -> f32 {
+    do_tanhf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_tanhf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_tanh(f32x4_splat(d)))
+}
+
+/// Computes hyperbolic tangent, error bound *ULP 2.5*
+#[inline]
+pub fn etanhf(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_tanhf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_tanhf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_tanhf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_tanhf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_tanhf_sse41(d) }
+            } else {
+                |d| unsafe { do_tanhf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
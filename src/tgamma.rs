@@ -0,0 +1,191 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabs;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::double_precision::{mul_doubled, sum_as_doubled};
+use crate::exp::{expm1k, L2_L, L2_U, R_LN2};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f64x2_tgamma;
+use crate::floor::efloor;
+use crate::generalf::{copysignk, ldexp2k, mlaf, rintk};
+use crate::ln::lnk_doubled;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vtgammaq_f64;
+use crate::sin::sinpik;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_pd, _mm_tgamma_pd};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f64, vgetq_lane_f64};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const STIRLING_POLY_1_D: f64 = 0.08333333333333333;
+pub(crate) const STIRLING_POLY_2_D: f64 = -0.002777777777758028;
+pub(crate) const STIRLING_POLY_3_D: f64 = 0.0007936507705360397;
+pub(crate) const STIRLING_POLY_4_D: f64 = -0.0005952281678273074;
+pub(crate) const STIRLING_POLY_5_D: f64 = 0.0008398119452648516;
+pub(crate) const STIRLING_POLY_6_D: f64 = -0.0017404560390011845;
+/// sqrt(2pi) as double-float
+pub(crate) const SQRT_2PI_D: f64 = 2.5066282746310007;
+pub(crate) const SQRT_2PI_LO_D: f64 = -1.8328579980459167e-16;
+/// sqrt(pi/2) as double-float
+pub(crate) const SQRT_HALF_PI_D: f64 = 1.2533141373155003;
+pub(crate) const SQRT_HALF_PI_LO_D: f64 = -9.164289990229583e-17;
+
+/// Computes lnΓ(w) - ln(sqrt(2pi)) as double-float for w >= 10
+#[inline(always)]
+pub(crate) fn stirlingk(w: (f64, f64)) -> (f64, f64) {
+    let l = lnk_doubled(w);
+    // (w - 0.5) ln(w) - w, w - 0.5 is exact below 2^52
+    let m = mul_doubled((w.0 - 0.5, w.1), l);
+    let e = sum_as_doubled(m.0, -w.0);
+    let iw = 1. / w.0;
+    let v = iw * iw;
+    let mut u = STIRLING_POLY_6_D;
+    u = mlaf(u, v, STIRLING_POLY_5_D);
+    u = mlaf(u, v, STIRLING_POLY_4_D);
+    u = mlaf(u, v, STIRLING_POLY_3_D);
+    u = mlaf(u, v, STIRLING_POLY_2_D);
+    u = mlaf(u, v, STIRLING_POLY_1_D);
+    (e.0, e.1 + ((m.1 - w.1) + iw * u))
+}
+
+#[inline(always)]
+fn do_tgamma(d: f64) -> f64 {
+    if eabs(d) < 5.551115123125783e-17 {
+        // Γ(d) = 1/d - γ + O(d), past this point γ is below half an ulp of 1/d
+        return 1. / d;
+    }
+    if d > 171.7 {
+        return f64::INFINITY;
+    }
+    let reflect = d < 0.;
+    if reflect {
+        if d == efloor(d) {
+            return f64::NAN;
+        }
+        if d < -190. {
+            // |Γ(d)| is below the smallest subnormal
+            return copysignk(0., sinpik(d));
+        }
+    }
+    // Γ(d) = pi / (sin(pi d) Γ(1 - d)) for negative d, 1 - d is kept as double-float
+    let z = if reflect {
+        sum_as_doubled(1., -d)
+    } else {
+        (d, 0.)
+    };
+    // Γ(z) = Γ(z + 10) / (z (z + 1) ... (z + 9)) moves the argument into Stirling range
+    let mut w = z;
+    let mut q = (1., 0.);
+    if z.0 < 10. {
+        for _ in 0..10 {
+            q = mul_doubled(q, w);
+            let s = sum_as_doubled(w.0, 1.);
+            w = (s.0, s.1 + w.1);
+        }
+    }
+    // ln(Γ(z)) - ln(sqrt(2pi)) = E - ln(q) with every low part kept, so nothing is divided afterwards
+    let e = stirlingk(w);
+    let l = lnk_doubled(q);
+    let h = sum_as_doubled(e.0, -l.0);
+    let qf = rintk(h.0 * R_LN2);
+    let mut r = mlaf(qf, -L2_U, h.0);
+    r = mlaf(qf, -L2_L, r);
+    r += h.1 + (e.1 - l.1);
+    let n = qf as i32;
+    if reflect {
+        let t = expm1k(SQRT_HALF_PI_LO_D / SQRT_HALF_PI_D - r);
+        let g = mlaf(SQRT_HALF_PI_D, t, SQRT_HALF_PI_D);
+        ldexp2k(g / sinpik(d), -n)
+    } else {
+        let t = expm1k(r + SQRT_2PI_LO_D / SQRT_2PI_D);
+        ldexp2k(mlaf(SQRT_2PI_D, t, SQRT_2PI_D), n)
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_tgamma_neon(d: f64) -> f64 {
+    unsafe {
+        let ld = vdupq_n_f64(d);
+        vgetq_lane_f64::<0>(vtgammaq_f64(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_tgamma_sse(d: f64) -> f64 {
+    let ld = _mm_set1_pd(d);
+    _mm_extract_pd::<0>(_mm_tgamma_pd(ld))
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_tgamma_sse41(d: f64) -> f64 {
+    do_tgamma_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_tgamma_fma(d: f64) -> f64 {
+    do_tgamma(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_tgamma_wasm(d: f64) -> f64 {
+    f64x2_extract_lane::<0>(f64x2_tgamma(f64x2_splat(d)))
+}
+
+/// Computes gamma function, error bound *ULP 3.5*, *ULP 2.5* for positive arguments
+#[inline]
+pub fn etgamma(d: f64) -> f64 {
+    let mut _dispatcher: fn(f64) -> f64 = do_tgamma;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_tgamma_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_tgamma_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f64) -> f64> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_tgamma_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_tgamma_sse41(d) }
+            } else {
+                |d| unsafe { do_tgamma_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
@@ -0,0 +1,188 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::abs::eabsf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::dispatch::{is_avx2_available, is_sse41_available};
+use crate::double_precision::{mul_doubled, sum_as_doubled};
+use crate::expf::{expm1kf, L2L_F, L2U_F, R_LN2_F};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use crate::f32x4_tgamma;
+use crate::floor::efloorf;
+use crate::generalf::{copysignfk, ldexp2kf, mlaf, rintfk};
+use crate::lnf::lnkf_doubled;
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::neon::vtgammaq_f32;
+use crate::sinf::sinpikf;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use crate::{_mm_extract_psx, _mm_tgamma_ps};
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use std::arch::aarch64::{vdupq_n_f32, vgetq_lane_f32};
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+use std::arch::wasm32::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+#[cfg(any(target_arch = "x86_64", target_arch = "x86"))]
+use std::sync::OnceLock;
+
+pub(crate) const STIRLING_POLY_1_S: f32 = 0.083333336;
+pub(crate) const STIRLING_POLY_2_S: f32 = -0.0027777664;
+pub(crate) const STIRLING_POLY_3_S: f32 = 0.0007922033;
+pub(crate) const STIRLING_POLY_4_S: f32 = -0.0005352181;
+/// sqrt(2pi) as double-float
+pub(crate) const SQRT_2PI_S: f32 = 2.5066283;
+pub(crate) const SQRT_2PI_LO_S: f32 = -2.8660205e-10;
+/// sqrt(pi/2) as double-float
+pub(crate) const SQRT_HALF_PI_S: f32 = 1.2533141;
+pub(crate) const SQRT_HALF_PI_LO_S: f32 = -1.4330102e-10;
+
+/// Computes lnΓ(w) - ln(sqrt(2pi)) as double-float for w >= 5
+#[inline(always)]
+pub(crate) fn stirlingkf(w: (f32, f32)) -> (f32, f32) {
+    let l = lnkf_doubled(w);
+    // (w - 0.5) ln(w) - w, w - 0.5 is exact below 2^23
+    let m = mul_doubled((w.0 - 0.5, w.1), l);
+    let e = sum_as_doubled(m.0, -w.0);
+    let iw = 1. / w.0;
+    let v = iw * iw;
+    let mut u = STIRLING_POLY_4_S;
+    u = mlaf(u, v, STIRLING_POLY_3_S);
+    u = mlaf(u, v, STIRLING_POLY_2_S);
+    u = mlaf(u, v, STIRLING_POLY_1_S);
+    (e.0, e.1 + ((m.1 - w.1) + iw * u))
+}
+
+#[inline(always)]
+fn do_tgammaf(d: f32) -> f32 {
+    if eabsf(d) < 2.980_232_2e-8 {
+        // Γ(d) = 1/d - γ + O(d), past this point γ is below half an ulp of 1/d
+        return 1. / d;
+    }
+    if d > 35.1 {
+        return f32::INFINITY;
+    }
+    let reflect = d < 0.;
+    if reflect {
+        if d == efloorf(d) {
+            return f32::NAN;
+        }
+        if d < -45. {
+            // |Γ(d)| is below the smallest subnormal
+            return copysignfk(0., sinpikf(d));
+        }
+    }
+    // Γ(d) = pi / (sin(pi d) Γ(1 - d)) for negative d, 1 - d is kept as double-float
+    let z = if reflect {
+        sum_as_doubled(1., -d)
+    } else {
+        (d, 0.)
+    };
+    // Γ(z) = Γ(z + 5) / (z (z + 1) ... (z + 4)) moves the argument into Stirling range
+    let mut w = z;
+    let mut q = (1., 0.);
+    if z.0 < 5. {
+        for _ in 0..5 {
+            q = mul_doubled(q, w);
+            let s = sum_as_doubled(w.0, 1.);
+            w = (s.0, s.1 + w.1);
+        }
+    }
+    // ln(Γ(z)) - ln(sqrt(2pi)) = E - ln(q) with every low part kept, so nothing is divided afterwards
+    let e = stirlingkf(w);
+    let l = lnkf_doubled(q);
+    let h = sum_as_doubled(e.0, -l.0);
+    let qf = rintfk(h.0 * R_LN2_F);
+    let mut r = mlaf(qf, -L2U_F, h.0);
+    r = mlaf(qf, -L2L_F, r);
+    r += h.1 + (e.1 - l.1);
+    let n = qf as i32;
+    if reflect {
+        let t = expm1kf(SQRT_HALF_PI_LO_S / SQRT_HALF_PI_S - r);
+        let g = mlaf(SQRT_HALF_PI_S, t, SQRT_HALF_PI_S);
+        ldexp2kf(g / sinpikf(d), -n)
+    } else {
+        let t = expm1kf(r + SQRT_2PI_LO_S / SQRT_2PI_S);
+        ldexp2kf(mlaf(SQRT_2PI_S, t, SQRT_2PI_S), n)
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline]
+fn do_tgammaf_neon(d: f32) -> f32 {
+    unsafe {
+        let ld = vdupq_n_f32(d);
+        vgetq_lane_f32::<0>(vtgammaq_f32(ld))
+    }
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[inline(always)]
+unsafe fn do_tgammaf_sse(d: f32) -> f32 {
+    let v = _mm_set1_ps(d);
+    let value = _mm_tgamma_ps(v);
+    f32::from_bits(_mm_extract_psx::<0>(value) as u32)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "sse4.1")]
+unsafe fn do_tgammaf_sse41(d: f32) -> f32 {
+    do_tgammaf_sse(d)
+}
+
+#[cfg(all(
+    any(target_arch = "x86_64", target_arch = "x86"),
+    target_feature = "sse2"
+))]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn do_tgammaf_fma(d: f32) -> f32 {
+    do_tgammaf(d)
+}
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[inline]
+fn do_tgammaf_wasm(d: f32) -> f32 {
+    f32x4_extract_lane::<0>(f32x4_tgamma(f32x4_splat(d)))
+}
+
+/// Computes gamma function, error bound *ULP 3.5*, *ULP 2.0* for positive arguments
+#[inline]
+pub fn etgammaf(d: f32) -> f32 {
+    let mut _dispatcher: fn(f32) -> f32 = do_tgammaf;
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        _dispatcher = do_tgammaf_neon;
+    }
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+    {
+        _dispatcher = do_tgammaf_wasm;
+    }
+    #[cfg(all(
+        any(target_arch = "x86_64", target_arch = "x86"),
+        target_feature = "sse2"
+    ))]
+    {
+        static DISPATCHER: OnceLock<fn(f32) -> f32> = OnceLock::new();
+        _dispatcher = *DISPATCHER.get_or_init(|| {
+            if is_avx2_available() {
+                |d| unsafe { do_tgammaf_fma(d) }
+            } else if is_sse41_available() {
+                |d| unsafe { do_tgammaf_sse41(d) }
+            } else {
+                |d| unsafe { do_tgammaf_sse(d) }
+            }
+        });
+    }
+    _dispatcher(d)
+}
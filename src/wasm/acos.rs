@@ -0,0 +1,44 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f64x2_asin, f64x2_select};
+
+/// Computes arccos, error bound *ULP 2.0*
+#[inline]
+pub fn f64x2_acos(x: v128) -> v128 {
+    let gt_zero = f64x2_gt(x, f64x2_splat(0.));
+    let x_a = f64x2_abs(x);
+    let x_asin = f64x2_asin(x_a);
+    let v_pi = f64x2_splat(std::f64::consts::FRAC_PI_2);
+    f64x2_select(gt_zero, f64x2_sub(v_pi, x_asin), f64x2_add(v_pi, x_asin))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acosd() {
+        {
+            let comparison = f64x2_acos(f64x2_splat(0.3));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 1.266103672779499f64).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_acos(f64x2_splat(-0.7));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 2.346193823405649f64).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_acos(f64x2_splat(-2.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_asin, f32x4_select};
+
+/// Computes arccos, error bound *ULP 2.0*
+#[inline]
+pub fn f32x4_acos(x: v128) -> v128 {
+    let gt_zero = f32x4_gt(x, f32x4_splat(0.));
+    let x_a = f32x4_abs(x);
+    let x_asin = f32x4_asin(x_a);
+    let v_pi = f32x4_splat(std::f32::consts::FRAC_PI_2);
+    f32x4_select(gt_zero, f32x4_sub(v_pi, x_asin), f32x4_add(v_pi, x_asin))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acosf() {
+        {
+            let comparison = f32x4_acos(f32x4_splat(0.3));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 1.2661036f32).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_acos(f32x4_splat(-0.7));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 2.3461938f32).abs() < 1e-5);
+        }
+        {
+            let comparison = f32x4_acos(f32x4_splat(-2.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f64x2_log1p, f64x2_select};
+
+/// Computes inverse hyperbolic cosine, error bound *ULP 4.0*
+#[inline]
+pub fn f64x2_acosh(d: v128) -> v128 {
+    let t = f64x2_sub(d, f64x2_splat(1f64));
+    // d < 2: acosh(d) = log1p(t + sqrt(t * (t + 2))), t = d - 1 is exact there
+    let small = f64x2_add(t, f64x2_sqrt(f64x2_mul(t, f64x2_add(t, f64x2_splat(2f64)))));
+    // d >= 2: acosh(d) = log1p(2d - 1 - 1 / (d + sqrt(d^2 - 1)))
+    let mid = f64x2_sub(
+        f64x2_sub(f64x2_add(d, d), f64x2_splat(1f64)),
+        f64x2_div(
+            f64x2_splat(1f64),
+            f64x2_add(d, f64x2_sqrt(f64x2_sub(f64x2_mul(d, d), f64x2_splat(1f64)))),
+        ),
+    );
+    // Large d has sqrt(d^2 - 1) == d, so acosh(d) = ln(d) + ln(2)
+    let big = f64x2_gt(d, f64x2_splat(268_435_456f64));
+    let u = f64x2_select(
+        f64x2_lt(d, f64x2_splat(2f64)),
+        small,
+        f64x2_select(big, t, mid),
+    );
+    let mut r = f64x2_add(
+        f64x2_log1p(u),
+        f64x2_select(big, f64x2_splat(std::f64::consts::LN_2), f64x2_splat(0f64)),
+    );
+    // d < 1 -> Nan
+    r = f64x2_select(f64x2_lt(d, f64x2_splat(1f64)), f64x2_splat(f64::NAN), r);
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acoshd() {
+        {
+            // Test regular
+            let value = f64x2_splat(1.5f64);
+            let comparison = f64x2_acosh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1.5f64.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(3f64);
+            let comparison = f64x2_acosh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 3f64.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(1e300f64);
+            let comparison = f64x2_acosh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1e300f64.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test exact value
+            let value = f64x2_splat(1f64);
+            let comparison = f64x2_acosh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+
+        {
+            // Test domain error
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_acosh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        {
+            // Test infinity
+            let value = f64x2_splat(f64::INFINITY);
+            let comparison = f64x2_acosh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_log1p, f32x4_select};
+
+/// Computes inverse hyperbolic cosine, error bound *ULP 4.5*
+#[inline]
+pub fn f32x4_acosh(d: v128) -> v128 {
+    let t = f32x4_sub(d, f32x4_splat(1f32));
+    // d < 2: acosh(d) = log1p(t + sqrt(t * (t + 2))), t = d - 1 is exact there
+    let small = f32x4_add(t, f32x4_sqrt(f32x4_mul(t, f32x4_add(t, f32x4_splat(2f32)))));
+    // d >= 2: acosh(d) = log1p(2d - 1 - 1 / (d + sqrt(d^2 - 1)))
+    let mid = f32x4_sub(
+        f32x4_sub(f32x4_add(d, d), f32x4_splat(1f32)),
+        f32x4_div(
+            f32x4_splat(1f32),
+            f32x4_add(d, f32x4_sqrt(f32x4_sub(f32x4_mul(d, d), f32x4_splat(1f32)))),
+        ),
+    );
+    // Large d has sqrt(d^2 - 1) == d, so acosh(d) = ln(d) + ln(2)
+    let big = f32x4_gt(d, f32x4_splat(4096f32));
+    let u = f32x4_select(
+        f32x4_lt(d, f32x4_splat(2f32)),
+        small,
+        f32x4_select(big, t, mid),
+    );
+    let mut r = f32x4_add(
+        f32x4_log1p(u),
+        f32x4_select(big, f32x4_splat(std::f32::consts::LN_2), f32x4_splat(0f32)),
+    );
+    // d < 1 -> Nan
+    r = f32x4_select(f32x4_lt(d, f32x4_splat(1f32)), f32x4_splat(f32::NAN), r);
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acoshf() {
+        {
+            // Test regular
+            let value = f32x4_splat(1.5f32);
+            let comparison = f32x4_acosh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1.5f32.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(3f32);
+            let comparison = f32x4_acosh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 3f32.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(1e30f32);
+            let comparison = f32x4_acosh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1e30f32.acosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test exact value
+            let value = f32x4_splat(1f32);
+            let comparison = f32x4_acosh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+
+        {
+            // Test domain error
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_acosh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        {
+            // Test infinity
+            let value = f32x4_splat(f32::INFINITY);
+            let comparison = f32x4_acosh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
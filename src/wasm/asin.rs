@@ -0,0 +1,83 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::asin::{
+    ASIN_POLY_10_D, ASIN_POLY_11_D, ASIN_POLY_12_D, ASIN_POLY_13_D, ASIN_POLY_14_D, ASIN_POLY_15_D,
+    ASIN_POLY_16_D, ASIN_POLY_17_D, ASIN_POLY_18_D, ASIN_POLY_19_D, ASIN_POLY_1_D, ASIN_POLY_2_D,
+    ASIN_POLY_3_D, ASIN_POLY_4_D, ASIN_POLY_5_D, ASIN_POLY_6_D, ASIN_POLY_7_D, ASIN_POLY_8_D,
+    ASIN_POLY_9_D,
+};
+use crate::{f64x2_copysign, f64x2_eqzero, f64x2_mlaf, f64x2_select};
+
+/// Computes arcsin, error bound *ULP 2.0*
+#[inline]
+pub fn f64x2_asin(d: v128) -> v128 {
+    let ones = f64x2_splat(1.);
+    let ca = f64x2_abs(d);
+    let nan_mask = f64x2_gt(ca, ones);
+    // for more 0.5
+    let reverse_05_mask = f64x2_ge(ca, f64x2_splat(0.5f64));
+    let reversed = f64x2_sqrt(f64x2_div(f64x2_sub(ones, ca), f64x2_splat(2.)));
+    let x = f64x2_select(reverse_05_mask, reversed, ca);
+    let zeros_is_zeros = f64x2_eqzero(d);
+    let mut u = f64x2_splat(ASIN_POLY_19_D);
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_18_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_17_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_16_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_15_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_14_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_13_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_12_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_11_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_10_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_9_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_8_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_7_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_6_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_5_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_4_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_3_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_2_D));
+    u = f64x2_mlaf(u, x, f64x2_splat(ASIN_POLY_1_D));
+    u = f64x2_mul(u, x);
+    let j = u;
+    let reconstruct_reversed = f64x2_mlaf(
+        f64x2_splat(-2f64),
+        j,
+        f64x2_splat(std::f64::consts::FRAC_PI_2),
+    );
+    let mut ret = f64x2_select(reverse_05_mask, reconstruct_reversed, j);
+    ret = f64x2_select(nan_mask, f64x2_splat(f64::NAN), ret);
+    ret = f64x2_select(zeros_is_zeros, f64x2_splat(0.), ret);
+    f64x2_copysign(ret, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asind() {
+        {
+            let comparison = f64x2_asin(f64x2_splat(0.7));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 0.775397496610753f64).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_asin(f64x2_splat(-0.3));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 + 0.30469265401539747f64).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_asin(f64x2_splat(-2.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
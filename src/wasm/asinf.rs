@@ -0,0 +1,70 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::asinf::{
+    ASIN_POLY_2_F, ASIN_POLY_3_F, ASIN_POLY_4_F, ASIN_POLY_5_F, ASIN_POLY_6_F, ASIN_POLY_7_F,
+    ASIN_POLY_8_F, ASIN_POLY_9_F,
+};
+use crate::{f32x4_copysign, f32x4_eqzero, f32x4_mlaf, f32x4_select};
+
+/// Computes arcsin, error bound *ULP 2.0*
+#[inline]
+pub fn f32x4_asin(d: v128) -> v128 {
+    let ones = f32x4_splat(1f32);
+    let ca = f32x4_abs(d);
+    let nan_mask = f32x4_gt(ca, ones);
+    // for more 0.5
+    let reverse_05_mask = f32x4_ge(ca, f32x4_splat(0.5f32));
+    let reversed = f32x4_sqrt(f32x4_div(f32x4_sub(ones, ca), f32x4_splat(2f32)));
+    let x = f32x4_select(reverse_05_mask, reversed, ca);
+    let zeros_is_zeros = f32x4_eqzero(d);
+    let mut u = f32x4_splat(f32::from_bits(ASIN_POLY_9_F));
+    u = f32x4_mlaf(u, x, f32x4_splat(f32::from_bits(ASIN_POLY_8_F)));
+    u = f32x4_mlaf(u, x, f32x4_splat(f32::from_bits(ASIN_POLY_7_F)));
+    u = f32x4_mlaf(u, x, f32x4_splat(f32::from_bits(ASIN_POLY_6_F)));
+    u = f32x4_mlaf(u, x, f32x4_splat(f32::from_bits(ASIN_POLY_5_F)));
+    u = f32x4_mlaf(u, x, f32x4_splat(f32::from_bits(ASIN_POLY_4_F)));
+    u = f32x4_mlaf(u, x, f32x4_splat(f32::from_bits(ASIN_POLY_3_F)));
+    u = f32x4_mlaf(u, x, f32x4_splat(f32::from_bits(ASIN_POLY_2_F)));
+    u = f32x4_mul(u, x);
+    let j = u;
+    let reconstruct_reversed = f32x4_mlaf(
+        f32x4_splat(-2f32),
+        j,
+        f32x4_splat(std::f32::consts::FRAC_PI_2),
+    );
+    let mut ret = f32x4_select(reverse_05_mask, reconstruct_reversed, j);
+    ret = f32x4_select(nan_mask, f32x4_splat(f32::NAN), ret);
+    ret = f32x4_select(zeros_is_zeros, f32x4_splat(0f32), ret);
+    f32x4_copysign(ret, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asinf() {
+        {
+            let comparison = f32x4_asin(f32x4_splat(0.7));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 0.7753975f32).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_asin(f32x4_splat(-0.3));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 + 0.30469266f32).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_asin(f32x4_splat(-2f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,100 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f64x2_copysign, f64x2_log1p, f64x2_select};
+
+/// Computes inverse hyperbolic sine, error bound *ULP 3.5*
+#[inline]
+pub fn f64x2_asinh(d: v128) -> v128 {
+    let a = f64x2_abs(d);
+    let a2 = f64x2_mul(a, a);
+    // a < 2: asinh(a) = log1p(a + a^2 / (1 + sqrt(1 + a^2)))
+    let small = f64x2_add(
+        a,
+        f64x2_div(
+            a2,
+            f64x2_add(
+                f64x2_splat(1f64),
+                f64x2_sqrt(f64x2_add(a2, f64x2_splat(1f64))),
+            ),
+        ),
+    );
+    // a >= 2: asinh(a) = log1p(2a - 1 + 1 / (a + sqrt(a^2 + 1))), 2a - 1 is exact there
+    let mid = f64x2_add(
+        f64x2_sub(f64x2_add(a, a), f64x2_splat(1f64)),
+        f64x2_div(
+            f64x2_splat(1f64),
+            f64x2_add(a, f64x2_sqrt(f64x2_add(a2, f64x2_splat(1f64)))),
+        ),
+    );
+    // Large a has sqrt(a^2 + 1) == a, so asinh(a) = ln(a) + ln(2)
+    let big = f64x2_gt(a, f64x2_splat(268_435_456f64));
+    let u = f64x2_select(
+        f64x2_lt(a, f64x2_splat(2f64)),
+        small,
+        f64x2_select(big, f64x2_sub(a, f64x2_splat(1f64)), mid),
+    );
+    let r = f64x2_add(
+        f64x2_log1p(u),
+        f64x2_select(big, f64x2_splat(std::f64::consts::LN_2), f64x2_splat(0f64)),
+    );
+    f64x2_copysign(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asinhd() {
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_asinh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.5f64.asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-1e-10f64);
+            let comparison = f64x2_asinh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = (-1e-10f64).asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-3f64);
+            let comparison = f64x2_asinh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = (-3f64).asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(1e300f64);
+            let comparison = f64x2_asinh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1e300f64.asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test infinity
+            let value = f64x2_splat(f64::NEG_INFINITY);
+            let comparison = f64x2_asinh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+    }
+}
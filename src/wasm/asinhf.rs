@@ -0,0 +1,100 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_copysign, f32x4_log1p, f32x4_select};
+
+/// Computes inverse hyperbolic sine, error bound *ULP 4.5*
+#[inline]
+pub fn f32x4_asinh(d: v128) -> v128 {
+    let a = f32x4_abs(d);
+    let a2 = f32x4_mul(a, a);
+    // a < 2: asinh(a) = log1p(a + a^2 / (1 + sqrt(1 + a^2)))
+    let small = f32x4_add(
+        a,
+        f32x4_div(
+            a2,
+            f32x4_add(
+                f32x4_splat(1f32),
+                f32x4_sqrt(f32x4_add(a2, f32x4_splat(1f32))),
+            ),
+        ),
+    );
+    // a >= 2: asinh(a) = log1p(2a - 1 + 1 / (a + sqrt(a^2 + 1))), 2a - 1 is exact there
+    let mid = f32x4_add(
+        f32x4_sub(f32x4_add(a, a), f32x4_splat(1f32)),
+        f32x4_div(
+            f32x4_splat(1f32),
+            f32x4_add(a, f32x4_sqrt(f32x4_add(a2, f32x4_splat(1f32)))),
+        ),
+    );
+    // Large a has sqrt(a^2 + 1) == a, so asinh(a) = ln(a) + ln(2)
+    let big = f32x4_gt(a, f32x4_splat(4096f32));
+    let u = f32x4_select(
+        f32x4_lt(a, f32x4_splat(2f32)),
+        small,
+        f32x4_select(big, f32x4_sub(a, f32x4_splat(1f32)), mid),
+    );
+    let r = f32x4_add(
+        f32x4_log1p(u),
+        f32x4_select(big, f32x4_splat(std::f32::consts::LN_2), f32x4_splat(0f32)),
+    );
+    f32x4_copysign(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_asinhf() {
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_asinh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.5f32.asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-1e-10f32);
+            let comparison = f32x4_asinh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = (-1e-10f32).asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-3f32);
+            let comparison = f32x4_asinh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = (-3f32).asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(1e30f32);
+            let comparison = f32x4_asinh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1e30f32.asinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test infinity
+            let value = f32x4_splat(f32::NEG_INFINITY);
+            let comparison = f32x4_asinh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::atan::{
+    ATAN_POLY_10_D, ATAN_POLY_11_D, ATAN_POLY_12_D, ATAN_POLY_13_D, ATAN_POLY_14_D, ATAN_POLY_15_D,
+    ATAN_POLY_16_D, ATAN_POLY_17_D, ATAN_POLY_18_D, ATAN_POLY_19_D, ATAN_POLY_1_D, ATAN_POLY_20_D,
+    ATAN_POLY_21_D, ATAN_POLY_2_D, ATAN_POLY_3_D, ATAN_POLY_4_D, ATAN_POLY_5_D, ATAN_POLY_6_D,
+    ATAN_POLY_7_D, ATAN_POLY_8_D, ATAN_POLY_9_D,
+};
+use crate::{f64x2_mlaf, f64x2_select};
+
+/// Computes Atan function with *ULP 2.0* error
+#[inline]
+pub fn f64x2_atan(x: v128) -> v128 {
+    // Sign bit is restored at the end so that atan(-0) = -0
+    let sign = v128_and(x, f64x2_splat(-0f64));
+    let d = f64x2_abs(x);
+    let more_than_one_mask = f64x2_ge(d, f64x2_splat(1.));
+    let x = f64x2_select(more_than_one_mask, f64x2_div(f64x2_splat(1.), d), d);
+    let x2 = f64x2_mul(x, x);
+    let mut u = f64x2_splat(ATAN_POLY_21_D);
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_20_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_19_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_18_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_17_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_16_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_15_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_14_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_13_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_12_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_11_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_10_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_9_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_8_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_7_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_6_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_5_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_4_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_3_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_2_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(ATAN_POLY_1_D));
+    u = f64x2_mul(u, x);
+    u = f64x2_select(
+        more_than_one_mask,
+        f64x2_sub(f64x2_splat(std::f64::consts::FRAC_PI_2), u),
+        u,
+    );
+    u = v128_xor(u, sign);
+    u
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atand() {
+        {
+            let comparison = f64x2_atan(f64x2_splat(0.5));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 0.4636476090008061f64).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_atan(f64x2_splat(-2.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 + 1.1071487177940904f64).abs() < 1e-15);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f64x2_atan, f64x2_copysign, f64x2_eqzero, f64x2_isinf, f64x2_select};
+
+/// Computes atan for Y,X
+#[inline]
+pub fn f64x2_atan2(y: v128, x: v128) -> v128 {
+    let ay = f64x2_abs(y);
+    let ax = f64x2_abs(x);
+    let mut r = f64x2_atan(f64x2_div(ay, ax));
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    r = f64x2_select(
+        v128_and(f64x2_eqzero(ay), f64x2_eqzero(ax)),
+        f64x2_splat(0.),
+        r,
+    );
+    r = f64x2_select(
+        v128_and(f64x2_isinf(ay), f64x2_isinf(ax)),
+        f64x2_splat(std::f64::consts::FRAC_PI_4),
+        r,
+    );
+    // Negative x, including -0, reflects the angle to the left half plane
+    let x_neg = f64x2_lt(f64x2_copysign(f64x2_splat(1.), x), f64x2_splat(0.));
+    r = f64x2_select(x_neg, f64x2_sub(f64x2_splat(std::f64::consts::PI), r), r);
+    f64x2_copysign(r, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atan2d() {
+        {
+            let comparison = f64x2_atan2(f64x2_splat(-1.), f64x2_splat(-2.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - (-1f64).atan2(-2.)).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_atan2(f64x2_splat(-1.), f64x2_splat(0.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, -std::f64::consts::FRAC_PI_2);
+        }
+    }
+}
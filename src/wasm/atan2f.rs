@@ -0,0 +1,52 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_atan, f32x4_copysign, f32x4_eqzero, f32x4_isinf, f32x4_select};
+
+/// Computes atan for Y,X
+#[inline]
+pub fn f32x4_atan2(y: v128, x: v128) -> v128 {
+    let ay = f32x4_abs(y);
+    let ax = f32x4_abs(x);
+    let mut r = f32x4_atan(f32x4_div(ay, ax));
+    // atan2(±0, ±0) and atan2(±inf, ±inf) would be 0/0 and inf/inf
+    r = f32x4_select(
+        v128_and(f32x4_eqzero(ay), f32x4_eqzero(ax)),
+        f32x4_splat(0.),
+        r,
+    );
+    r = f32x4_select(
+        v128_and(f32x4_isinf(ay), f32x4_isinf(ax)),
+        f32x4_splat(std::f32::consts::FRAC_PI_4),
+        r,
+    );
+    // Negative x, including -0, reflects the angle to the left half plane
+    let x_neg = f32x4_lt(f32x4_copysign(f32x4_splat(1.), x), f32x4_splat(0.));
+    r = f32x4_select(x_neg, f32x4_sub(f32x4_splat(std::f32::consts::PI), r), r);
+    f32x4_copysign(r, y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atan2f() {
+        {
+            let comparison = f32x4_atan2(f32x4_splat(-1.), f32x4_splat(-2.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - (-1f32).atan2(-2.)).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_atan2(f32x4_splat(-1.), f32x4_splat(0.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, -std::f32::consts::FRAC_PI_2);
+        }
+    }
+}
@@ -0,0 +1,61 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::atanf::{
+    ATAN_POLY_1_F, ATAN_POLY_2_F, ATAN_POLY_3_F, ATAN_POLY_4_F, ATAN_POLY_5_F, ATAN_POLY_6_F,
+    ATAN_POLY_7_F, ATAN_POLY_8_F, ATAN_POLY_9_F,
+};
+use crate::{f32x4_mlaf, f32x4_select};
+
+/// Computes Atan function with *ULP 1.0* error
+#[inline]
+pub fn f32x4_atan(x: v128) -> v128 {
+    // Sign bit is restored at the end so that atan(-0) = -0
+    let sign = v128_and(x, f32x4_splat(-0f32));
+    let d = f32x4_abs(x);
+    let more_than_one_mask = f32x4_ge(d, f32x4_splat(1f32));
+    let x = f32x4_select(more_than_one_mask, f32x4_div(f32x4_splat(1f32), d), d);
+    let x2 = f32x4_mul(x, x);
+    let mut u = f32x4_splat(ATAN_POLY_9_F);
+    u = f32x4_mlaf(u, x2, f32x4_splat(ATAN_POLY_8_F));
+    u = f32x4_mlaf(u, x2, f32x4_splat(ATAN_POLY_7_F));
+    u = f32x4_mlaf(u, x2, f32x4_splat(ATAN_POLY_6_F));
+    u = f32x4_mlaf(u, x2, f32x4_splat(ATAN_POLY_5_F));
+    u = f32x4_mlaf(u, x2, f32x4_splat(ATAN_POLY_4_F));
+    u = f32x4_mlaf(u, x2, f32x4_splat(ATAN_POLY_3_F));
+    u = f32x4_mlaf(u, x2, f32x4_splat(ATAN_POLY_2_F));
+    u = f32x4_mlaf(u, x2, f32x4_splat(ATAN_POLY_1_F));
+    u = f32x4_mul(u, x);
+    u = f32x4_select(
+        more_than_one_mask,
+        f32x4_sub(f32x4_splat(std::f32::consts::FRAC_PI_2), u),
+        u,
+    );
+    u = v128_xor(u, sign);
+    u
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atanf() {
+        {
+            let comparison = f32x4_atan(f32x4_splat(0.5f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 0.4636476f32).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_atan(f32x4_splat(-2f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 + 1.1071488f32).abs() < 1e-6);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f64x2_copysign, f64x2_log1p, f64x2_select};
+
+/// Computes inverse hyperbolic tangent, error bound *ULP 3.0*
+#[inline]
+pub fn f64x2_atanh(d: v128) -> v128 {
+    let a = f64x2_abs(d);
+    let s = f64x2_add(a, a);
+    // atanh(a) = log1p(2a + 2a^2 / (1 - a)) / 2, keeping 2a apart holds precision near zero
+    let u = f64x2_add(
+        s,
+        f64x2_div(f64x2_mul(s, a), f64x2_sub(f64x2_splat(1f64), a)),
+    );
+    let mut r = f64x2_mul(f64x2_splat(0.5f64), f64x2_log1p(u));
+    // |d| > 1 -> Nan
+    r = f64x2_select(f64x2_gt(a, f64x2_splat(1f64)), f64x2_splat(f64::NAN), r);
+    f64x2_copysign(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atanhd() {
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_atanh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.5f64.atanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-1e-10f64);
+            let comparison = f64x2_atanh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = (-1e-10f64).atanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test pole
+            let value = f64x2_splat(1f64);
+            let comparison = f64x2_atanh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        {
+            // Test pole
+            let value = f64x2_splat(-1f64);
+            let comparison = f64x2_atanh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+
+        {
+            // Test domain error
+            let value = f64x2_splat(2f64);
+            let comparison = f64x2_atanh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
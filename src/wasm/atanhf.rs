@@ -0,0 +1,76 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_copysign, f32x4_log1p, f32x4_select};
+
+/// Computes inverse hyperbolic tangent, error bound *ULP 4.0*
+#[inline]
+pub fn f32x4_atanh(d: v128) -> v128 {
+    let a = f32x4_abs(d);
+    let s = f32x4_add(a, a);
+    // atanh(a) = log1p(2a + 2a^2 / (1 - a)) / 2, keeping 2a apart holds precision near zero
+    let u = f32x4_add(
+        s,
+        f32x4_div(f32x4_mul(s, a), f32x4_sub(f32x4_splat(1f32), a)),
+    );
+    let mut r = f32x4_mul(f32x4_splat(0.5f32), f32x4_log1p(u));
+    // |d| > 1 -> Nan
+    r = f32x4_select(f32x4_gt(a, f32x4_splat(1f32)), f32x4_splat(f32::NAN), r);
+    f32x4_copysign(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_atanhf() {
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_atanh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.5f32.atanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-1e-10f32);
+            let comparison = f32x4_atanh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = (-1e-10f32).atanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test pole
+            let value = f32x4_splat(1f32);
+            let comparison = f32x4_atanh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        {
+            // Test pole
+            let value = f32x4_splat(-1f32);
+            let comparison = f32x4_atanh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+
+        {
+            // Test domain error
+            let value = f32x4_splat(2f32);
+            let comparison = f32x4_atanh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
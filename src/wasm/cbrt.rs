@@ -0,0 +1,108 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::cbrt::{
+    CBRT_HUGE_D, CBRT_HUGE_SCALE_D, CBRT_HUGE_SCALE_R_D, CBRT_SCALE_D, CBRT_SCALE_R_D,
+};
+use crate::{f64x2_eqzero, f64x2_isinf, f64x2_mlaf, f64x2_select};
+
+#[inline(always)]
+fn halley_cbrt(x: v128, a: v128) -> v128 {
+    let tx = f64x2_mul(f64x2_mul(x, x), x);
+    let twos = f64x2_splat(2.);
+    let num = f64x2_mlaf(twos, a, tx);
+    let den = f64x2_mlaf(twos, tx, a);
+    let scale = f64x2_div(num, den);
+    f64x2_mul(x, scale)
+}
+
+/// Newton step from t rounded to 22 bits, t*t is exact so the result is within 0.67 ulp
+#[inline(always)]
+fn newton_round(t: v128, a: v128) -> v128 {
+    let t = v128_and(
+        i64x2_add(t, u64x2_splat(0x8000_0000)),
+        u64x2_splat(0xffff_ffff_c000_0000),
+    );
+    let s = f64x2_mul(t, t);
+    let r = f64x2_div(a, s);
+    let w = f64x2_add(t, t);
+    let r = f64x2_div(f64x2_sub(r, t), f64x2_add(w, r));
+    f64x2_add(t, f64x2_mul(t, r))
+}
+
+#[inline(always)]
+fn integer_pow_1_3(hx: v128) -> v128 {
+    // hx / 3 as (hx * 0xAAAAAAAB) >> 33, exact for every 31-bit high word
+    let third = u64x2_shr(i64x2_mul(hx, i64x2_splat(0xAAAAAAAB)), 33);
+    i64x2_add(third, i64x2_splat(715094163))
+}
+
+/// Takes cube root from value *ULP 1.5*, Skipping NaN, Inf checks
+#[inline]
+pub fn f64x2_cbrt_fast(x: v128) -> v128 {
+    let hx = v128_and(u64x2_shr(x, 32), u64x2_splat(0x7fffffff));
+
+    let hx = integer_pow_1_3(hx);
+
+    let ui = v128_or(
+        v128_and(x, u64x2_splat(0x8000_0000_0000_0000)),
+        i64x2_shl(hx, 32),
+    );
+
+    let c0 = halley_cbrt(ui, x);
+    let c1 = halley_cbrt(c0, x);
+    let c2 = halley_cbrt(c1, x);
+    f64x2_select(f64x2_eqzero(x), x, newton_round(c2, x))
+}
+
+/// Takes cube root from value *ULP 1.5*
+#[inline]
+pub fn f64x2_cbrt(x: v128) -> v128 {
+    // Subnormals and the top binades are scaled by powers of 8, the initial guess
+    // and x^3 in Halley step are out of range for them
+    let ax = f64x2_abs(x);
+    let tiny = f64x2_lt(ax, f64x2_splat(f64::MIN_POSITIVE));
+    let huge = f64x2_gt(ax, f64x2_splat(CBRT_HUGE_D));
+    let scale_in = f64x2_select(
+        tiny,
+        f64x2_splat(CBRT_SCALE_D),
+        f64x2_select(huge, f64x2_splat(CBRT_HUGE_SCALE_D), f64x2_splat(1.)),
+    );
+    let scale_out = f64x2_select(
+        tiny,
+        f64x2_splat(1. / CBRT_SCALE_R_D),
+        f64x2_select(huge, f64x2_splat(CBRT_HUGE_SCALE_R_D), f64x2_splat(1.)),
+    );
+    let c1 = f64x2_mul(f64x2_cbrt_fast(f64x2_mul(x, scale_in)), scale_out);
+    f64x2_select(f64x2_isinf(x), x, c1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cbrtd() {
+        {
+            let comparison = f64x2_cbrt(f64x2_splat(27.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 3.).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_cbrt(f64x2_splat(-0.5));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 + 0.5f64.cbrt()).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_cbrt(f64x2_splat(f64::NEG_INFINITY));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+    }
+}
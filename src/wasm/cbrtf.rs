@@ -0,0 +1,89 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::cbrtf::{CBRT_HUGE_F, CBRT_SCALE_F, CBRT_SCALE_R_F};
+use crate::{f32x4_eqzero, f32x4_isinf, f32x4_mlaf, f32x4_select};
+
+#[inline(always)]
+fn halley_cbrt(x: v128, a: v128) -> v128 {
+    let tx = f32x4_mul(f32x4_mul(x, x), x);
+    let twos = f32x4_splat(2f32);
+    let num = f32x4_mlaf(twos, a, tx);
+    let den = f32x4_mlaf(twos, tx, a);
+    let scale = f32x4_div(num, den);
+    f32x4_mul(x, scale)
+}
+
+#[inline(always)]
+fn integer_pow_1_3(hx: v128) -> v128 {
+    let scale = u32x4_splat(341);
+    let lo = u64x2_shr(u64x2_extmul_low_u32x4(hx, scale), 10);
+    let hi = u64x2_shr(u64x2_extmul_high_u32x4(hx, scale), 10);
+    i32x4_shuffle::<0, 2, 4, 6>(lo, hi)
+}
+
+/// Takes cube root from value *ULP 1.5*, Skipping NaN, Inf checks
+#[inline]
+pub fn f32x4_cbrt_fast(x: v128) -> v128 {
+    let hx = v128_and(x, u32x4_splat(0x7fffffff));
+
+    let hx = i32x4_add(integer_pow_1_3(hx), i32x4_splat(709958130));
+
+    let ui = v128_or(v128_and(x, u32x4_splat(0x80000000)), hx);
+
+    let c0 = halley_cbrt(ui, x);
+    let c1 = halley_cbrt(c0, x);
+    f32x4_select(f32x4_eqzero(x), x, c1)
+}
+
+/// Takes cube root from value *ULP 1.5*
+#[inline]
+pub fn f32x4_cbrt(x: v128) -> v128 {
+    // Subnormals and the top binades are scaled by powers of 8, the initial guess
+    // and x^3 in Halley step are out of range for them
+    let ax = f32x4_abs(x);
+    let tiny = f32x4_lt(ax, f32x4_splat(f32::MIN_POSITIVE));
+    let huge = f32x4_gt(ax, f32x4_splat(CBRT_HUGE_F));
+    let scale_in = f32x4_select(
+        tiny,
+        f32x4_splat(CBRT_SCALE_F),
+        f32x4_select(huge, f32x4_splat(1. / CBRT_SCALE_F), f32x4_splat(1.)),
+    );
+    let scale_out = f32x4_select(
+        tiny,
+        f32x4_splat(1. / CBRT_SCALE_R_F),
+        f32x4_select(huge, f32x4_splat(CBRT_SCALE_R_F), f32x4_splat(1.)),
+    );
+    let c1 = f32x4_mul(f32x4_cbrt_fast(f32x4_mul(x, scale_in)), scale_out);
+    f32x4_select(f32x4_isinf(x), x, c1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cbrtf() {
+        {
+            let comparison = f32x4_cbrt(f32x4_splat(27.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 3.).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_cbrt(f32x4_splat(-0.5));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 + 0.5f32.cbrt()).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_cbrt(f32x4_splat(f32::NEG_INFINITY));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::generalf::rempi2_odd;
+use crate::sin::{
+    PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
+    SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
+};
+use crate::wasm::general::f64x2_rempi;
+use crate::{f64x2_convert_i64x2, f64x2_mlaf, f64x2_rint, f64x2_select};
+
+#[inline]
+/// Computes cosine function with error bound *ULP 2.5*
+pub fn f64x2_cos(d: v128) -> v128 {
+    let j = f64x2_rint(f64x2_sub(
+        f64x2_mul(d, f64x2_splat(std::f64::consts::FRAC_1_PI)),
+        f64x2_splat(0.5),
+    ));
+
+    let q = i64x2_add(i64x2_splat(1), i64x2_shl(j, 1));
+
+    let qf = f64x2_convert_i64x2(q);
+
+    let mut r = f64x2_mlaf(qf, f64x2_splat(-PI_A2 * 0.5), d);
+    r = f64x2_mlaf(qf, f64x2_splat(-PI_B2 * 0.5), r);
+    let (q, mut r) = f64x2_rempi(d, q, r, rempi2_odd);
+
+    let x2 = f64x2_mul(r, r);
+
+    let is_zero_bit = i64x2_eq(v128_and(q, i64x2_splat(2)), i64x2_splat(0));
+    r = f64x2_select(is_zero_bit, f64x2_neg(r), r);
+    let mut res = f64x2_splat(SIN_POLY_10_D);
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_9_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_8_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_7_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_6_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_5_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_4_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_3_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_2_D));
+    res = f64x2_mlaf(res, f64x2_mul(x2, r), r);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosd() {
+        {
+            let comparison = f64x2_cos(f64x2_splat(0.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 1.).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_cos(f64x2_splat(2.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 + 0.4161468365471424f64).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_cosd_large() {
+        for x in [1e6f64, -1e22, 3.5e300] {
+            let value = f64x2_extract_lane::<0>(f64x2_cos(f64x2(x, 2.)));
+            let control = x.cos();
+            assert!((value - control).abs() <= 2. * f64::EPSILON * control.abs());
+        }
+    }
+}
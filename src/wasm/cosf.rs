@@ -0,0 +1,77 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2_oddf;
+use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+use crate::wasm::generalf::f32x4_rempi;
+use crate::{f32x4_eqzero, f32x4_mlaf, f32x4_rint, f32x4_select};
+
+#[inline]
+/// Computes cosine function with error bound *ULP 2.5*
+pub fn f32x4_cos(d: v128) -> v128 {
+    let q = i32x4_add(
+        i32x4_splat(1),
+        i32x4_shl(
+            f32x4_rint(f32x4_sub(
+                f32x4_mul(d, f32x4_splat(std::f32::consts::FRAC_1_PI)),
+                f32x4_splat(0.5f32),
+            )),
+            1,
+        ),
+    );
+    let qf = f32x4_convert_i32x4(q);
+
+    let mut r = f32x4_mlaf(qf, f32x4_splat(-PI_A_F * 0.5), d);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_B_F * 0.5), r);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_C_F * 0.5), r);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_D_F * 0.5), r);
+    let (q, mut r) = f32x4_rempi(d, q, r, rempi2_oddf);
+
+    let x2 = f32x4_mul(r, r);
+
+    let is_zero_bit = i32x4_eq(v128_and(q, i32x4_splat(2)), i32x4_splat(0));
+    r = f32x4_select(is_zero_bit, f32x4_neg(r), r);
+    let mut res = f32x4_splat(SIN_POLY_5_S);
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_4_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_3_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_2_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_1_S));
+    res = f32x4_mlaf(res, f32x4_mul(x2, r), r);
+    // cos(±0) = 1
+    f32x4_select(f32x4_eqzero(d), f32x4_splat(1.), res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cosf() {
+        {
+            let comparison = f32x4_cos(f32x4_splat(0f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 1f32).abs() < 1e-7);
+        }
+        {
+            let comparison = f32x4_cos(f32x4_splat(2f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 + 0.41614684f32).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_cosf_large() {
+        for x in [1e4f32, -1e22, 3.0e38] {
+            let value = f32x4_extract_lane::<0>(f32x4_cos(f32x4(x, 2., -0.5, 1.)));
+            let control = (x as f64).cos() as f32;
+            assert!((value - control).abs() <= 2. * f32::EPSILON * control.abs());
+        }
+    }
+}
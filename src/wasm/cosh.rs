@@ -0,0 +1,69 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f64x2_exp, f64x2_mlaf, f64x2_select};
+
+/// Computes hyperbolic cosine, error bound *ULP 3.5*
+#[inline]
+pub fn f64x2_cosh(d: v128) -> v128 {
+    let a = f64x2_abs(d);
+    let e = f64x2_exp(a);
+    let mut r = f64x2_mlaf(f64x2_splat(0.5f64), e, f64x2_div(f64x2_splat(0.5f64), e));
+    // exp(|d|) / 2 overflows before cosh does, split it as exp(|d|/2)^2 / 2
+    let h = f64x2_exp(f64x2_mul(a, f64x2_splat(0.5f64)));
+    r = f64x2_select(
+        f64x2_gt(a, f64x2_splat(709f64)),
+        f64x2_mul(f64x2_mul(h, f64x2_splat(0.5f64)), h),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coshd() {
+        {
+            // Test regular
+            let value = f64x2_splat(1.5f64);
+            let comparison = f64x2_cosh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1.5f64.cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-0.3f64);
+            let comparison = f64x2_cosh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = (-0.3f64).cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(710f64);
+            let comparison = f64x2_cosh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 710f64.cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test overflow
+            let value = f64x2_splat(-720f64);
+            let comparison = f64x2_cosh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,69 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_exp, f32x4_mlaf, f32x4_select};
+
+/// Computes hyperbolic cosine, error bound *ULP 3.5*
+#[inline]
+pub fn f32x4_cosh(d: v128) -> v128 {
+    let a = f32x4_abs(d);
+    let e = f32x4_exp(a);
+    let mut r = f32x4_mlaf(f32x4_splat(0.5f32), e, f32x4_div(f32x4_splat(0.5f32), e));
+    // exp(|d|) / 2 overflows before cosh does, split it as exp(|d|/2)^2 / 2
+    let h = f32x4_exp(f32x4_mul(a, f32x4_splat(0.5f32)));
+    r = f32x4_select(
+        f32x4_gt(a, f32x4_splat(88f32)),
+        f32x4_mul(f32x4_mul(h, f32x4_splat(0.5f32)), h),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coshf() {
+        {
+            // Test regular
+            let value = f32x4_splat(1.5f32);
+            let comparison = f32x4_cosh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1.5f32.cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-0.3f32);
+            let comparison = f32x4_cosh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = (-0.3f32).cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(89f32);
+            let comparison = f32x4_cosh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 89f32.cosh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test overflow
+            let value = f32x4_splat(-100f32);
+            let comparison = f32x4_cosh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
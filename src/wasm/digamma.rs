@@ -0,0 +1,168 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::digamma::{
+    DIGAMMA_ASYMPT_POLY_1_D, DIGAMMA_ASYMPT_POLY_2_D, DIGAMMA_ASYMPT_POLY_3_D,
+    DIGAMMA_ASYMPT_POLY_4_D, DIGAMMA_ASYMPT_POLY_5_D, DIGAMMA_ASYMPT_POLY_6_D, DIGAMMA_POLY_10_D,
+    DIGAMMA_POLY_11_D, DIGAMMA_POLY_12_D, DIGAMMA_POLY_13_D, DIGAMMA_POLY_14_D, DIGAMMA_POLY_15_D,
+    DIGAMMA_POLY_16_D, DIGAMMA_POLY_17_D, DIGAMMA_POLY_18_D, DIGAMMA_POLY_19_D, DIGAMMA_POLY_1_D,
+    DIGAMMA_POLY_20_D, DIGAMMA_POLY_21_D, DIGAMMA_POLY_22_D, DIGAMMA_POLY_23_D, DIGAMMA_POLY_2_D,
+    DIGAMMA_POLY_3_D, DIGAMMA_POLY_4_D, DIGAMMA_POLY_5_D, DIGAMMA_POLY_6_D, DIGAMMA_POLY_7_D,
+    DIGAMMA_POLY_8_D, DIGAMMA_POLY_9_D, DIGAMMA_ROOT_M1_D, DIGAMMA_ROOT_M1_LO_D,
+};
+use crate::wasm::sin::f64x2_sinpik;
+use crate::{f64x2_isnotintegral, f64x2_ln, f64x2_mlaf, f64x2_select};
+use std::arch::wasm32::*;
+
+/// Computes digamma function, error bound *ULP 4.5* for positive arguments
+#[inline]
+pub fn f64x2_digamma(d: v128) -> v128 {
+    let reflect = f64x2_lt(d, f64x2_splat(0f64));
+    // ψ(d) = ψ(1 - d) - pi cot(pi d)
+    let x = f64x2_select(reflect, f64x2_sub(f64x2_splat(1f64), d), d);
+    // ψ(x) = ln(x) - 1/2x - z D(z), z = 1/x^2 for x >= 10
+    let ix = f64x2_div(f64x2_splat(1f64), x);
+    let z = f64x2_mul(ix, ix);
+    let mut p = f64x2_splat(DIGAMMA_ASYMPT_POLY_6_D);
+    p = f64x2_mlaf(p, z, f64x2_splat(DIGAMMA_ASYMPT_POLY_5_D));
+    p = f64x2_mlaf(p, z, f64x2_splat(DIGAMMA_ASYMPT_POLY_4_D));
+    p = f64x2_mlaf(p, z, f64x2_splat(DIGAMMA_ASYMPT_POLY_3_D));
+    p = f64x2_mlaf(p, z, f64x2_splat(DIGAMMA_ASYMPT_POLY_2_D));
+    p = f64x2_mlaf(p, z, f64x2_splat(DIGAMMA_ASYMPT_POLY_1_D));
+    let big = f64x2_sub(
+        f64x2_ln(x),
+        f64x2_mlaf(z, p, f64x2_mul(f64x2_splat(0.5f64), ix)),
+    );
+    // ψ(1 + t) = (t - t0) G(t) keeps the root t0 apart, so values around it stay accurate
+    let n = f64x2_floor(x);
+    let t = f64x2_sub(x, n);
+    let v = f64x2_sub(t, f64x2_splat(0.5f64));
+    let mut u = f64x2_splat(DIGAMMA_POLY_23_D);
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_22_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_21_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_20_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_19_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_18_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_17_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_16_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_15_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_14_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_13_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_12_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_11_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_10_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_9_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_8_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_7_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_6_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_5_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_4_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_3_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_2_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(DIGAMMA_POLY_1_D));
+    let r = f64x2_mul(
+        f64x2_sub(
+            f64x2_sub(t, f64x2_splat(DIGAMMA_ROOT_M1_D)),
+            f64x2_splat(DIGAMMA_ROOT_M1_LO_D),
+        ),
+        u,
+    );
+    // ψ(x) = ψ(1 + t) + 1/(x - 1) + ... + 1/(x - n + 1) summed as a single fraction, below 1 it is -1/x
+    let below = f64x2_lt(x, f64x2_splat(1f64));
+    let mut num = f64x2_select(below, f64x2_splat(-1f64), f64x2_splat(0f64));
+    let mut den = f64x2_select(below, x, f64x2_splat(1f64));
+    for k in 1..10 {
+        let kf = f64x2_splat(k as f64);
+        let v = f64x2_sub(x, kf);
+        let m = f64x2_lt(kf, n);
+        num = f64x2_select(m, f64x2_mlaf(num, v, den), num);
+        den = f64x2_select(m, f64x2_mul(den, v), den);
+    }
+    let mut ret = f64x2_select(
+        f64x2_lt(x, f64x2_splat(10f64)),
+        f64x2_add(r, f64x2_div(num, den)),
+        big,
+    );
+    let rr = f64x2_sub(d, f64x2_nearest(d));
+    let cs = f64x2_sinpik(f64x2_sub(f64x2_splat(0.5f64), f64x2_abs(rr)));
+    ret = f64x2_select(
+        reflect,
+        f64x2_sub(
+            ret,
+            f64x2_div(
+                f64x2_mul(f64x2_splat(std::f64::consts::PI), cs),
+                f64x2_sinpik(rr),
+            ),
+        ),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    f64x2_select(
+        v128_andnot(reflect, f64x2_isnotintegral(d)),
+        f64x2_splat(f64::NAN),
+        ret,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digammad() {
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_digamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = -1.9635100260214235f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(3.7f64);
+            let comparison = f64x2_digamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1.1671535393615113f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-1.5f64);
+            let comparison = f64x2_digamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.7031566406452432f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test pole
+            let value = f64x2_splat(-1f64);
+            let comparison = f64x2_digamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        {
+            // Test infinity
+            let value = f64x2_splat(f64::INFINITY);
+            let comparison = f64x2_digamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        {
+            // Test NaN
+            let value = f64x2_splat(f64::NAN);
+            let comparison = f64x2_digamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,153 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::digammaf::{
+    DIGAMMA_ASYMPT_POLY_1_S, DIGAMMA_ASYMPT_POLY_2_S, DIGAMMA_ASYMPT_POLY_3_S,
+    DIGAMMA_ASYMPT_POLY_4_S, DIGAMMA_POLY_10_S, DIGAMMA_POLY_11_S, DIGAMMA_POLY_12_S,
+    DIGAMMA_POLY_1_S, DIGAMMA_POLY_2_S, DIGAMMA_POLY_3_S, DIGAMMA_POLY_4_S, DIGAMMA_POLY_5_S,
+    DIGAMMA_POLY_6_S, DIGAMMA_POLY_7_S, DIGAMMA_POLY_8_S, DIGAMMA_POLY_9_S, DIGAMMA_ROOT_M1_LO_S,
+    DIGAMMA_ROOT_M1_S,
+};
+use crate::wasm::sinf::f32x4_sinpik;
+use crate::{f32x4_isnotintegral, f32x4_ln, f32x4_mlaf, f32x4_select};
+use std::arch::wasm32::*;
+
+/// Computes digamma function, error bound *ULP 3.5* for positive arguments
+#[inline]
+pub fn f32x4_digamma(d: v128) -> v128 {
+    let reflect = f32x4_lt(d, f32x4_splat(0f32));
+    // ψ(d) = ψ(1 - d) - pi cot(pi d)
+    let x = f32x4_select(reflect, f32x4_sub(f32x4_splat(1f32), d), d);
+    // ψ(x) = ln(x) - 1/2x - z D(z), z = 1/x^2 for x >= 5
+    let ix = f32x4_div(f32x4_splat(1f32), x);
+    let z = f32x4_mul(ix, ix);
+    let mut p = f32x4_splat(DIGAMMA_ASYMPT_POLY_4_S);
+    p = f32x4_mlaf(p, z, f32x4_splat(DIGAMMA_ASYMPT_POLY_3_S));
+    p = f32x4_mlaf(p, z, f32x4_splat(DIGAMMA_ASYMPT_POLY_2_S));
+    p = f32x4_mlaf(p, z, f32x4_splat(DIGAMMA_ASYMPT_POLY_1_S));
+    let big = f32x4_sub(
+        f32x4_ln(x),
+        f32x4_mlaf(z, p, f32x4_mul(f32x4_splat(0.5f32), ix)),
+    );
+    // ψ(1 + t) = (t - t0) G(t) keeps the root t0 apart, so values around it stay accurate
+    let n = f32x4_floor(x);
+    let t = f32x4_sub(x, n);
+    let v = f32x4_sub(t, f32x4_splat(0.5f32));
+    let mut u = f32x4_splat(DIGAMMA_POLY_12_S);
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_11_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_10_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_9_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_8_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_7_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_6_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_5_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_4_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_3_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_2_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(DIGAMMA_POLY_1_S));
+    let r = f32x4_mul(
+        f32x4_sub(
+            f32x4_sub(t, f32x4_splat(DIGAMMA_ROOT_M1_S)),
+            f32x4_splat(DIGAMMA_ROOT_M1_LO_S),
+        ),
+        u,
+    );
+    // ψ(x) = ψ(1 + t) + 1/(x - 1) + ... + 1/(x - n + 1) summed as a single fraction, below 1 it is -1/x
+    let below = f32x4_lt(x, f32x4_splat(1f32));
+    let mut num = f32x4_select(below, f32x4_splat(-1f32), f32x4_splat(0f32));
+    let mut den = f32x4_select(below, x, f32x4_splat(1f32));
+    for k in 1..5 {
+        let kf = f32x4_splat(k as f32);
+        let v = f32x4_sub(x, kf);
+        let m = f32x4_lt(kf, n);
+        num = f32x4_select(m, f32x4_mlaf(num, v, den), num);
+        den = f32x4_select(m, f32x4_mul(den, v), den);
+    }
+    let mut ret = f32x4_select(
+        f32x4_lt(x, f32x4_splat(5f32)),
+        f32x4_add(r, f32x4_div(num, den)),
+        big,
+    );
+    let rr = f32x4_sub(d, f32x4_nearest(d));
+    let cs = f32x4_sinpik(f32x4_sub(f32x4_splat(0.5f32), f32x4_abs(rr)));
+    ret = f32x4_select(
+        reflect,
+        f32x4_sub(
+            ret,
+            f32x4_div(
+                f32x4_mul(f32x4_splat(std::f32::consts::PI), cs),
+                f32x4_sinpik(rr),
+            ),
+        ),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    f32x4_select(
+        v128_andnot(reflect, f32x4_isnotintegral(d)),
+        f32x4_splat(f32::NAN),
+        ret,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digammaf() {
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_digamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = -1.96351f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(3.7f32);
+            let comparison = f32x4_digamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1.1671536f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-1.5f32);
+            let comparison = f32x4_digamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.70315665f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test pole
+            let value = f32x4_splat(-1f32);
+            let comparison = f32x4_digamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        {
+            // Test infinity
+            let value = f32x4_splat(f32::INFINITY);
+            let comparison = f32x4_digamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        {
+            // Test NaN
+            let value = f32x4_splat(f32::NAN);
+            let comparison = f32x4_digamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
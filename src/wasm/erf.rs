@@ -0,0 +1,316 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::erf::{
+    ERFC_A_POLY_10_D, ERFC_A_POLY_11_D, ERFC_A_POLY_12_D, ERFC_A_POLY_13_D, ERFC_A_POLY_14_D,
+    ERFC_A_POLY_15_D, ERFC_A_POLY_1_D, ERFC_A_POLY_2_D, ERFC_A_POLY_3_D, ERFC_A_POLY_4_D,
+    ERFC_A_POLY_5_D, ERFC_A_POLY_6_D, ERFC_A_POLY_7_D, ERFC_A_POLY_8_D, ERFC_A_POLY_9_D,
+    ERFC_A_SHIFT_D, ERFC_B_POLY_10_D, ERFC_B_POLY_11_D, ERFC_B_POLY_12_D, ERFC_B_POLY_13_D,
+    ERFC_B_POLY_14_D, ERFC_B_POLY_15_D, ERFC_B_POLY_1_D, ERFC_B_POLY_2_D, ERFC_B_POLY_3_D,
+    ERFC_B_POLY_4_D, ERFC_B_POLY_5_D, ERFC_B_POLY_6_D, ERFC_B_POLY_7_D, ERFC_B_POLY_8_D,
+    ERFC_B_POLY_9_D, ERFC_B_SHIFT_D, ERFC_C_POLY_10_D, ERFC_C_POLY_11_D, ERFC_C_POLY_12_D,
+    ERFC_C_POLY_13_D, ERFC_C_POLY_14_D, ERFC_C_POLY_15_D, ERFC_C_POLY_1_D, ERFC_C_POLY_2_D,
+    ERFC_C_POLY_3_D, ERFC_C_POLY_4_D, ERFC_C_POLY_5_D, ERFC_C_POLY_6_D, ERFC_C_POLY_7_D,
+    ERFC_C_POLY_8_D, ERFC_C_POLY_9_D, ERFC_C_SHIFT_D, ERFC_D_POLY_10_D, ERFC_D_POLY_11_D,
+    ERFC_D_POLY_12_D, ERFC_D_POLY_13_D, ERFC_D_POLY_14_D, ERFC_D_POLY_15_D, ERFC_D_POLY_1_D,
+    ERFC_D_POLY_2_D, ERFC_D_POLY_3_D, ERFC_D_POLY_4_D, ERFC_D_POLY_5_D, ERFC_D_POLY_6_D,
+    ERFC_D_POLY_7_D, ERFC_D_POLY_8_D, ERFC_D_POLY_9_D, ERFC_D_SHIFT_D, ERF_POLY_10_D,
+    ERF_POLY_11_D, ERF_POLY_1_D, ERF_POLY_2_D, ERF_POLY_3_D, ERF_POLY_4_D, ERF_POLY_5_D,
+    ERF_POLY_6_D, ERF_POLY_7_D, ERF_POLY_8_D, ERF_POLY_9_D,
+};
+use crate::exp::{L2_L, L2_U, R_LN2};
+use crate::wasm::exp::f64x2_expm1k;
+use crate::{
+    f64x2_convert_i64x2, f64x2_copysign, f64x2_ldexp2k, f64x2_mlaf, f64x2_rint, f64x2_select,
+};
+
+/// Computes `erf(x) - x` for |x| < 0.84375
+#[inline]
+pub(crate) fn f64x2_erfk(x: v128) -> v128 {
+    let t = f64x2_mul(x, x);
+    let mut u = f64x2_splat(ERF_POLY_11_D);
+    u = f64x2_mlaf(u, t, f64x2_splat(ERF_POLY_10_D));
+    u = f64x2_mlaf(u, t, f64x2_splat(ERF_POLY_9_D));
+    u = f64x2_mlaf(u, t, f64x2_splat(ERF_POLY_8_D));
+    u = f64x2_mlaf(u, t, f64x2_splat(ERF_POLY_7_D));
+    u = f64x2_mlaf(u, t, f64x2_splat(ERF_POLY_6_D));
+    u = f64x2_mlaf(u, t, f64x2_splat(ERF_POLY_5_D));
+    u = f64x2_mlaf(u, t, f64x2_splat(ERF_POLY_4_D));
+    u = f64x2_mlaf(u, t, f64x2_splat(ERF_POLY_3_D));
+    u = f64x2_mlaf(u, t, f64x2_splat(ERF_POLY_2_D));
+    u = f64x2_mlaf(u, t, f64x2_splat(ERF_POLY_1_D));
+    f64x2_mul(x, u)
+}
+
+/// Computes erfc(a) for a >= 0.84375 as `exp(-a^2 + P(1/a)) / a`
+#[inline]
+pub(crate) fn f64x2_erfck(a: v128) -> v128 {
+    let t = f64x2_div(f64x2_splat(1f64), a);
+    let m0 = f64x2_lt(a, f64x2_splat(1.5f64));
+    let m1 = f64x2_lt(a, f64x2_splat(3.0f64));
+    let m2 = f64x2_lt(a, f64x2_splat(8.25f64));
+    let pick = |c0: f64, c1: f64, c2: f64, c3: f64| {
+        f64x2_select(
+            m0,
+            f64x2_splat(c0),
+            f64x2_select(
+                m1,
+                f64x2_splat(c1),
+                f64x2_select(m2, f64x2_splat(c2), f64x2_splat(c3)),
+            ),
+        )
+    };
+    let v = f64x2_sub(
+        t,
+        pick(
+            ERFC_A_SHIFT_D,
+            ERFC_B_SHIFT_D,
+            ERFC_C_SHIFT_D,
+            ERFC_D_SHIFT_D,
+        ),
+    );
+    let mut u = pick(
+        ERFC_A_POLY_15_D,
+        ERFC_B_POLY_15_D,
+        ERFC_C_POLY_15_D,
+        ERFC_D_POLY_15_D,
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_14_D,
+            ERFC_B_POLY_14_D,
+            ERFC_C_POLY_14_D,
+            ERFC_D_POLY_14_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_13_D,
+            ERFC_B_POLY_13_D,
+            ERFC_C_POLY_13_D,
+            ERFC_D_POLY_13_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_12_D,
+            ERFC_B_POLY_12_D,
+            ERFC_C_POLY_12_D,
+            ERFC_D_POLY_12_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_11_D,
+            ERFC_B_POLY_11_D,
+            ERFC_C_POLY_11_D,
+            ERFC_D_POLY_11_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_10_D,
+            ERFC_B_POLY_10_D,
+            ERFC_C_POLY_10_D,
+            ERFC_D_POLY_10_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_9_D,
+            ERFC_B_POLY_9_D,
+            ERFC_C_POLY_9_D,
+            ERFC_D_POLY_9_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_8_D,
+            ERFC_B_POLY_8_D,
+            ERFC_C_POLY_8_D,
+            ERFC_D_POLY_8_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_7_D,
+            ERFC_B_POLY_7_D,
+            ERFC_C_POLY_7_D,
+            ERFC_D_POLY_7_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_6_D,
+            ERFC_B_POLY_6_D,
+            ERFC_C_POLY_6_D,
+            ERFC_D_POLY_6_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_5_D,
+            ERFC_B_POLY_5_D,
+            ERFC_C_POLY_5_D,
+            ERFC_D_POLY_5_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_4_D,
+            ERFC_B_POLY_4_D,
+            ERFC_C_POLY_4_D,
+            ERFC_D_POLY_4_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_3_D,
+            ERFC_B_POLY_3_D,
+            ERFC_C_POLY_3_D,
+            ERFC_D_POLY_3_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_2_D,
+            ERFC_B_POLY_2_D,
+            ERFC_C_POLY_2_D,
+            ERFC_D_POLY_2_D,
+        ),
+    );
+    u = f64x2_mlaf(
+        u,
+        v,
+        pick(
+            ERFC_A_POLY_1_D,
+            ERFC_B_POLY_1_D,
+            ERFC_C_POLY_1_D,
+            ERFC_D_POLY_1_D,
+        ),
+    );
+    // -a^2 = -z^2 + (z - a)(z + a), z keeps half of the mantissa so z^2 is exact
+    let z = v128_and(a, u64x2_splat(0xffff_ffff_0000_0000));
+    let hi = f64x2_neg(f64x2_mul(z, z));
+    let lo = f64x2_mlaf(f64x2_sub(z, a), f64x2_add(z, a), u);
+    let q = f64x2_rint(f64x2_mul(f64x2_add(hi, lo), f64x2_splat(R_LN2)));
+    let qf = f64x2_convert_i64x2(q);
+    // hi is reduced exactly, lo joins after the reduction
+    let mut r = f64x2_mlaf(qf, f64x2_splat(-L2_U), hi);
+    r = f64x2_mlaf(qf, f64x2_splat(-L2_L), f64x2_add(r, lo));
+    let e = f64x2_ldexp2k(
+        f64x2_div(f64x2_add(f64x2_expm1k(r), f64x2_splat(1f64)), a),
+        q,
+    );
+    // erfc underflows here, also covers a == Inf
+    f64x2_select(f64x2_gt(a, f64x2_splat(27.3f64)), f64x2_splat(0f64), e)
+}
+
+/// Computes error function, error bound *ULP 1.5*
+#[inline]
+pub fn f64x2_erf(d: v128) -> v128 {
+    let a = f64x2_abs(d);
+    let mut r = f64x2_copysign(f64x2_sub(f64x2_splat(1f64), f64x2_erfck(a)), d);
+    r = f64x2_select(
+        f64x2_lt(a, f64x2_splat(0.84375f64)),
+        f64x2_add(d, f64x2_erfk(d)),
+        r,
+    );
+    r = f64x2_select(
+        f64x2_gt(a, f64x2_splat(6f64)),
+        f64x2_copysign(f64x2_splat(1f64), d),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfd() {
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_erf(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.5204998778130465f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-1.5f64);
+            let comparison = f64x2_erf(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = -0.9661051464753108f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(3f64);
+            let comparison = f64x2_erf(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.9999779095030014f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test saturation
+            let value = f64x2_splat(10f64);
+            let comparison = f64x2_erf(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 1.);
+        }
+
+        {
+            // Test saturation
+            let value = f64x2_splat(-10f64);
+            let comparison = f64x2_erf(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+
+        {
+            // Test Nan
+            let value = f64x2_splat(f64::NAN);
+            let comparison = f64x2_erf(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
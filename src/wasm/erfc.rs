@@ -0,0 +1,95 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::f64x2_select;
+use crate::wasm::erf::{f64x2_erfck, f64x2_erfk};
+
+/// Computes complementary error function, error bound *ULP 3.5*
+#[inline]
+pub fn f64x2_erfc(d: v128) -> v128 {
+    let a = f64x2_abs(d);
+    let u = f64x2_erfk(d);
+    // Past 0.25 recentering on 0.5 keeps the subtraction from cancelling
+    let mut s = f64x2_sub(
+        f64x2_splat(0.5f64),
+        f64x2_add(f64x2_sub(d, f64x2_splat(0.5f64)), u),
+    );
+    s = f64x2_select(
+        f64x2_lt(d, f64x2_splat(0.25f64)),
+        f64x2_sub(f64x2_splat(1f64), f64x2_add(d, u)),
+        s,
+    );
+    let e = f64x2_erfck(a);
+    let r = f64x2_select(
+        f64x2_lt(d, f64x2_splat(0f64)),
+        f64x2_sub(f64x2_splat(2f64), e),
+        e,
+    );
+    f64x2_select(f64x2_lt(a, f64x2_splat(0.84375f64)), s, r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfcd() {
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_erfc(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.4795001221869535f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-1.5f64);
+            let comparison = f64x2_erfc(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1.9661051464753108f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(5f64);
+            let comparison = f64x2_erfc(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1.537459794428035e-12f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(26f64);
+            let comparison = f64x2_erfc(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 5.663192408856143e-296f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test saturation
+            let value = f64x2_splat(30f64);
+            let comparison = f64x2_erfc(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+
+        {
+            // Test saturation
+            let value = f64x2_splat(-30f64);
+            let comparison = f64x2_erfc(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 2.);
+        }
+    }
+}
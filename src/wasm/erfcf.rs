@@ -0,0 +1,95 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::f32x4_select;
+use crate::wasm::erff::{f32x4_erfck, f32x4_erfk};
+
+/// Computes complementary error function, error bound *ULP 3.5*
+#[inline]
+pub fn f32x4_erfc(d: v128) -> v128 {
+    let a = f32x4_abs(d);
+    let u = f32x4_erfk(d);
+    // Past 0.25 recentering on 0.5 keeps the subtraction from cancelling
+    let mut s = f32x4_sub(
+        f32x4_splat(0.5f32),
+        f32x4_add(f32x4_sub(d, f32x4_splat(0.5f32)), u),
+    );
+    s = f32x4_select(
+        f32x4_lt(d, f32x4_splat(0.25f32)),
+        f32x4_sub(f32x4_splat(1f32), f32x4_add(d, u)),
+        s,
+    );
+    let e = f32x4_erfck(a);
+    let r = f32x4_select(
+        f32x4_lt(d, f32x4_splat(0f32)),
+        f32x4_sub(f32x4_splat(2f32), e),
+        e,
+    );
+    f32x4_select(f32x4_lt(a, f32x4_splat(0.84375f32)), s, r)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfcf() {
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_erfc(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.47950011f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-1.5f32);
+            let comparison = f32x4_erfc(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1.9661051f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(5f32);
+            let comparison = f32x4_erfc(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1.5374598e-12f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(9f32);
+            let comparison = f32x4_erfc(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 4.1370317e-37f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test saturation
+            let value = f32x4_splat(30f32);
+            let comparison = f32x4_erfc(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+
+        {
+            // Test saturation
+            let value = f32x4_splat(-30f32);
+            let comparison = f32x4_erfc(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 2.);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::erff::{
+    ERFC_A_POLY_1_S, ERFC_A_POLY_2_S, ERFC_A_POLY_3_S, ERFC_A_POLY_4_S, ERFC_A_POLY_5_S,
+    ERFC_A_POLY_6_S, ERFC_A_POLY_7_S, ERFC_A_SHIFT_S, ERFC_B_POLY_1_S, ERFC_B_POLY_2_S,
+    ERFC_B_POLY_3_S, ERFC_B_POLY_4_S, ERFC_B_POLY_5_S, ERFC_B_POLY_6_S, ERFC_B_POLY_7_S,
+    ERFC_B_SHIFT_S, ERFC_C_POLY_1_S, ERFC_C_POLY_2_S, ERFC_C_POLY_3_S, ERFC_C_POLY_4_S,
+    ERFC_C_POLY_5_S, ERFC_C_POLY_6_S, ERFC_C_POLY_7_S, ERFC_C_SHIFT_S, ERF_POLY_1_S, ERF_POLY_2_S,
+    ERF_POLY_3_S, ERF_POLY_4_S, ERF_POLY_5_S, ERF_POLY_6_S, ERF_POLY_7_S,
+};
+use crate::expf::{L2L_F, L2U_F, R_LN2_F};
+use crate::wasm::expf::f32x4_expm1k;
+use crate::{f32x4_copysign, f32x4_ldexp2k, f32x4_mlaf, f32x4_rint, f32x4_select};
+
+/// Computes `erf(x) - x` for |x| < 0.84375
+#[inline]
+pub(crate) fn f32x4_erfk(x: v128) -> v128 {
+    let t = f32x4_mul(x, x);
+    let mut u = f32x4_splat(ERF_POLY_7_S);
+    u = f32x4_mlaf(u, t, f32x4_splat(ERF_POLY_6_S));
+    u = f32x4_mlaf(u, t, f32x4_splat(ERF_POLY_5_S));
+    u = f32x4_mlaf(u, t, f32x4_splat(ERF_POLY_4_S));
+    u = f32x4_mlaf(u, t, f32x4_splat(ERF_POLY_3_S));
+    u = f32x4_mlaf(u, t, f32x4_splat(ERF_POLY_2_S));
+    u = f32x4_mlaf(u, t, f32x4_splat(ERF_POLY_1_S));
+    f32x4_mul(x, u)
+}
+
+/// Computes erfc(a) for a >= 0.84375 as `exp(-a^2 + P(1/a)) / a`
+#[inline]
+pub(crate) fn f32x4_erfck(a: v128) -> v128 {
+    let t = f32x4_div(f32x4_splat(1f32), a);
+    let m0 = f32x4_lt(a, f32x4_splat(1.4375f32));
+    let m1 = f32x4_lt(a, f32x4_splat(2.625f32));
+    let pick = |c0: f32, c1: f32, c2: f32| {
+        f32x4_select(
+            m0,
+            f32x4_splat(c0),
+            f32x4_select(m1, f32x4_splat(c1), f32x4_splat(c2)),
+        )
+    };
+    let v = f32x4_sub(t, pick(ERFC_A_SHIFT_S, ERFC_B_SHIFT_S, ERFC_C_SHIFT_S));
+    let mut u = pick(ERFC_A_POLY_7_S, ERFC_B_POLY_7_S, ERFC_C_POLY_7_S);
+    u = f32x4_mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_6_S, ERFC_B_POLY_6_S, ERFC_C_POLY_6_S),
+    );
+    u = f32x4_mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_5_S, ERFC_B_POLY_5_S, ERFC_C_POLY_5_S),
+    );
+    u = f32x4_mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_4_S, ERFC_B_POLY_4_S, ERFC_C_POLY_4_S),
+    );
+    u = f32x4_mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_3_S, ERFC_B_POLY_3_S, ERFC_C_POLY_3_S),
+    );
+    u = f32x4_mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_2_S, ERFC_B_POLY_2_S, ERFC_C_POLY_2_S),
+    );
+    u = f32x4_mlaf(
+        u,
+        v,
+        pick(ERFC_A_POLY_1_S, ERFC_B_POLY_1_S, ERFC_C_POLY_1_S),
+    );
+    // -a^2 = -z^2 + (z - a)(z + a), z keeps half of the mantissa so z^2 is exact
+    let z = v128_and(a, u32x4_splat(0xffff_f000));
+    let hi = f32x4_neg(f32x4_mul(z, z));
+    let lo = f32x4_mlaf(f32x4_sub(z, a), f32x4_add(z, a), u);
+    let q = f32x4_rint(f32x4_mul(f32x4_add(hi, lo), f32x4_splat(R_LN2_F)));
+    let qf = f32x4_convert_i32x4(q);
+    // hi is reduced exactly, lo joins after the reduction
+    let mut r = f32x4_mlaf(qf, f32x4_splat(-L2U_F), hi);
+    r = f32x4_mlaf(qf, f32x4_splat(-L2L_F), f32x4_add(r, lo));
+    let e = f32x4_ldexp2k(
+        f32x4_div(f32x4_add(f32x4_expm1k(r), f32x4_splat(1f32)), a),
+        q,
+    );
+    // erfc underflows here, also covers a == Inf
+    f32x4_select(f32x4_gt(a, f32x4_splat(10.1f32)), f32x4_splat(0f32), e)
+}
+
+/// Computes error function, error bound *ULP 2.0*
+#[inline]
+pub fn f32x4_erf(d: v128) -> v128 {
+    let a = f32x4_abs(d);
+    let mut r = f32x4_copysign(f32x4_sub(f32x4_splat(1f32), f32x4_erfck(a)), d);
+    r = f32x4_select(
+        f32x4_lt(a, f32x4_splat(0.84375f32)),
+        f32x4_add(d, f32x4_erfk(d)),
+        r,
+    );
+    r = f32x4_select(
+        f32x4_gt(a, f32x4_splat(4f32)),
+        f32x4_copysign(f32x4_splat(1f32), d),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erff() {
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_erf(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.5204999f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-1.5f32);
+            let comparison = f32x4_erf(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = -0.96610516f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(3f32);
+            let comparison = f32x4_erf(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.9999779f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test saturation
+            let value = f32x4_splat(10f32);
+            let comparison = f32x4_erf(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 1.);
+        }
+
+        {
+            // Test saturation
+            let value = f32x4_splat(-10f32);
+            let comparison = f32x4_erf(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+
+        {
+            // Test Nan
+            let value = f32x4_splat(f32::NAN);
+            let comparison = f32x4_erf(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
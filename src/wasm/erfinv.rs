@@ -0,0 +1,205 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::erfinv::{
+    ERFINV_A_POLY_10_D, ERFINV_A_POLY_11_D, ERFINV_A_POLY_1_D, ERFINV_A_POLY_2_D,
+    ERFINV_A_POLY_3_D, ERFINV_A_POLY_4_D, ERFINV_A_POLY_5_D, ERFINV_A_POLY_6_D, ERFINV_A_POLY_7_D,
+    ERFINV_A_POLY_8_D, ERFINV_A_POLY_9_D, ERFINV_A_SHIFT_D, ERFINV_B_POLY_1_D, ERFINV_B_POLY_2_D,
+    ERFINV_B_POLY_3_D, ERFINV_B_POLY_4_D, ERFINV_B_POLY_5_D, ERFINV_B_POLY_6_D, ERFINV_B_POLY_7_D,
+    ERFINV_B_POLY_8_D, ERFINV_B_POLY_9_D, ERFINV_B_SHIFT_D, ERFINV_C_POLY_1_D, ERFINV_C_POLY_2_D,
+    ERFINV_C_POLY_3_D, ERFINV_C_POLY_4_D, ERFINV_C_POLY_5_D, ERFINV_C_POLY_6_D, ERFINV_C_POLY_7_D,
+    ERFINV_C_POLY_8_D, ERFINV_C_POLY_9_D, ERFINV_C_SHIFT_D, SQRT_PI_2_D,
+};
+use crate::wasm::erf::{f64x2_erfck, f64x2_erfk};
+use crate::{f64x2_copysign, f64x2_exp, f64x2_ln, f64x2_mlaf, f64x2_select};
+
+/// Computes inverse error function, error bound *ULP 1.5*
+#[inline]
+pub fn f64x2_erfinv(d: v128) -> v128 {
+    let a = f64x2_abs(d);
+    let w = f64x2_neg(f64x2_ln(f64x2_mul(
+        f64x2_sub(f64x2_splat(1f64), a),
+        f64x2_add(f64x2_splat(1f64), a),
+    )));
+    let v = f64x2_sub(w, f64x2_splat(ERFINV_A_SHIFT_D));
+    let mut u = f64x2_splat(ERFINV_A_POLY_11_D);
+    u = f64x2_mlaf(u, v, f64x2_splat(ERFINV_A_POLY_10_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(ERFINV_A_POLY_9_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(ERFINV_A_POLY_8_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(ERFINV_A_POLY_7_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(ERFINV_A_POLY_6_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(ERFINV_A_POLY_5_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(ERFINV_A_POLY_4_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(ERFINV_A_POLY_3_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(ERFINV_A_POLY_2_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(ERFINV_A_POLY_1_D));
+    let s = f64x2_sqrt(w);
+    let mt = f64x2_lt(s, f64x2_splat(4f64));
+    let v = f64x2_sub(
+        s,
+        f64x2_select(
+            mt,
+            f64x2_splat(ERFINV_B_SHIFT_D),
+            f64x2_splat(ERFINV_C_SHIFT_D),
+        ),
+    );
+    let mut p = f64x2_select(
+        mt,
+        f64x2_splat(ERFINV_B_POLY_9_D),
+        f64x2_splat(ERFINV_C_POLY_9_D),
+    );
+    p = f64x2_mlaf(
+        p,
+        v,
+        f64x2_select(
+            mt,
+            f64x2_splat(ERFINV_B_POLY_8_D),
+            f64x2_splat(ERFINV_C_POLY_8_D),
+        ),
+    );
+    p = f64x2_mlaf(
+        p,
+        v,
+        f64x2_select(
+            mt,
+            f64x2_splat(ERFINV_B_POLY_7_D),
+            f64x2_splat(ERFINV_C_POLY_7_D),
+        ),
+    );
+    p = f64x2_mlaf(
+        p,
+        v,
+        f64x2_select(
+            mt,
+            f64x2_splat(ERFINV_B_POLY_6_D),
+            f64x2_splat(ERFINV_C_POLY_6_D),
+        ),
+    );
+    p = f64x2_mlaf(
+        p,
+        v,
+        f64x2_select(
+            mt,
+            f64x2_splat(ERFINV_B_POLY_5_D),
+            f64x2_splat(ERFINV_C_POLY_5_D),
+        ),
+    );
+    p = f64x2_mlaf(
+        p,
+        v,
+        f64x2_select(
+            mt,
+            f64x2_splat(ERFINV_B_POLY_4_D),
+            f64x2_splat(ERFINV_C_POLY_4_D),
+        ),
+    );
+    p = f64x2_mlaf(
+        p,
+        v,
+        f64x2_select(
+            mt,
+            f64x2_splat(ERFINV_B_POLY_3_D),
+            f64x2_splat(ERFINV_C_POLY_3_D),
+        ),
+    );
+    p = f64x2_mlaf(
+        p,
+        v,
+        f64x2_select(
+            mt,
+            f64x2_splat(ERFINV_B_POLY_2_D),
+            f64x2_splat(ERFINV_C_POLY_2_D),
+        ),
+    );
+    p = f64x2_mlaf(
+        p,
+        v,
+        f64x2_select(
+            mt,
+            f64x2_splat(ERFINV_B_POLY_1_D),
+            f64x2_splat(ERFINV_C_POLY_1_D),
+        ),
+    );
+    let mut x = f64x2_select(f64x2_lt(w, f64x2_splat(5f64)), f64x2_mul(a, u), p);
+    // One Halley step on erf(x) = a, in the tail the residual comes from erfc where 1 - a is exact
+    let f0 = f64x2_add(f64x2_sub(x, a), f64x2_erfk(x));
+    let f1 = f64x2_sub(f64x2_sub(f64x2_splat(1f64), a), f64x2_erfck(x));
+    let f = f64x2_select(f64x2_lt(x, f64x2_splat(0.84375f64)), f0, f1);
+    let e = f64x2_mul(
+        f64x2_mul(f, f64x2_splat(SQRT_PI_2_D)),
+        f64x2_exp(f64x2_mul(x, x)),
+    );
+    x = f64x2_sub(x, f64x2_div(e, f64x2_mlaf(x, e, f64x2_splat(1f64))));
+    // |d| == 1 -> Inf, |d| > 1 and Nan propagate through ln
+    x = f64x2_select(
+        f64x2_eq(a, f64x2_splat(1f64)),
+        f64x2_splat(f64::INFINITY),
+        x,
+    );
+    f64x2_copysign(x, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfinvd() {
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_erfinv(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.4769362762044699f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-0.9f64);
+            let comparison = f64x2_erfinv(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = -1.1630871536766743f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(0.999999f64);
+            let comparison = f64x2_erfinv(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 3.458910737275499f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test pole
+            let value = f64x2_splat(1f64);
+            let comparison = f64x2_erfinv(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        {
+            // Test pole
+            let value = f64x2_splat(-1f64);
+            let comparison = f64x2_erfinv(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+
+        {
+            // Test domain error
+            let value = f64x2_splat(2f64);
+            let comparison = f64x2_erfinv(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,116 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::erfinvf::{
+    ERFINV_A_POLY_10_S, ERFINV_A_POLY_11_S, ERFINV_A_POLY_1_S, ERFINV_A_POLY_2_S,
+    ERFINV_A_POLY_3_S, ERFINV_A_POLY_4_S, ERFINV_A_POLY_5_S, ERFINV_A_POLY_6_S, ERFINV_A_POLY_7_S,
+    ERFINV_A_POLY_8_S, ERFINV_A_POLY_9_S, ERFINV_A_SHIFT_S, ERFINV_B_POLY_1_S, ERFINV_B_POLY_2_S,
+    ERFINV_B_POLY_3_S, ERFINV_B_POLY_4_S, ERFINV_B_POLY_5_S, ERFINV_B_POLY_6_S, ERFINV_B_POLY_7_S,
+    ERFINV_B_POLY_8_S, ERFINV_B_POLY_9_S, ERFINV_B_SHIFT_S,
+};
+use crate::{f32x4_copysign, f32x4_ln, f32x4_mlaf, f32x4_select};
+
+/// Computes inverse error function, error bound *ULP 4.0*
+#[inline]
+pub fn f32x4_erfinv(d: v128) -> v128 {
+    let a = f32x4_abs(d);
+    let w = f32x4_neg(f32x4_ln(f32x4_mul(
+        f32x4_sub(f32x4_splat(1f32), a),
+        f32x4_add(f32x4_splat(1f32), a),
+    )));
+    let v = f32x4_sub(w, f32x4_splat(ERFINV_A_SHIFT_S));
+    let mut u = f32x4_splat(ERFINV_A_POLY_11_S);
+    u = f32x4_mlaf(u, v, f32x4_splat(ERFINV_A_POLY_10_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(ERFINV_A_POLY_9_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(ERFINV_A_POLY_8_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(ERFINV_A_POLY_7_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(ERFINV_A_POLY_6_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(ERFINV_A_POLY_5_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(ERFINV_A_POLY_4_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(ERFINV_A_POLY_3_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(ERFINV_A_POLY_2_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(ERFINV_A_POLY_1_S));
+    let v = f32x4_sub(f32x4_sqrt(w), f32x4_splat(ERFINV_B_SHIFT_S));
+    let mut p = f32x4_splat(ERFINV_B_POLY_9_S);
+    p = f32x4_mlaf(p, v, f32x4_splat(ERFINV_B_POLY_8_S));
+    p = f32x4_mlaf(p, v, f32x4_splat(ERFINV_B_POLY_7_S));
+    p = f32x4_mlaf(p, v, f32x4_splat(ERFINV_B_POLY_6_S));
+    p = f32x4_mlaf(p, v, f32x4_splat(ERFINV_B_POLY_5_S));
+    p = f32x4_mlaf(p, v, f32x4_splat(ERFINV_B_POLY_4_S));
+    p = f32x4_mlaf(p, v, f32x4_splat(ERFINV_B_POLY_3_S));
+    p = f32x4_mlaf(p, v, f32x4_splat(ERFINV_B_POLY_2_S));
+    p = f32x4_mlaf(p, v, f32x4_splat(ERFINV_B_POLY_1_S));
+    let mut x = f32x4_select(f32x4_lt(w, f32x4_splat(5f32)), f32x4_mul(a, u), p);
+    // |d| == 1 -> Inf, |d| > 1 and Nan propagate through ln
+    x = f32x4_select(
+        f32x4_eq(a, f32x4_splat(1f32)),
+        f32x4_splat(f32::INFINITY),
+        x,
+    );
+    f32x4_copysign(x, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_erfinvf() {
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_erfinv(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.47693628f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-0.9f32);
+            let comparison = f32x4_erfinv(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = -1.1630871f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(0.9999f32);
+            let comparison = f32x4_erfinv(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 2.7510355f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test pole
+            let value = f32x4_splat(1f32);
+            let comparison = f32x4_erfinv(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        {
+            // Test pole
+            let value = f32x4_splat(-1f32);
+            let comparison = f32x4_erfinv(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+
+        {
+            // Test domain error
+            let value = f32x4_splat(2f32);
+            let comparison = f32x4_erfinv(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
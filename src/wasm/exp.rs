@@ -0,0 +1,86 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::exp::{
+    EXP_POLY_10_D, EXP_POLY_1_D, EXP_POLY_2_D, EXP_POLY_3_D, EXP_POLY_4_D, EXP_POLY_5_D,
+    EXP_POLY_6_D, EXP_POLY_7_D, EXP_POLY_8_D, EXP_POLY_9_D, L2_L, L2_U, R_LN2,
+};
+use crate::wasm::general::f64x2_ldexp2k;
+use crate::{f64x2_convert_i64x2, f64x2_mlaf, f64x2_rint, f64x2_select, i64x2_pow2i};
+
+/// Computes `exp(r) - 1` for reduced argument |r| <= ln(2)/2
+#[inline]
+pub(crate) fn f64x2_expm1k(r: v128) -> v128 {
+    let f = f64x2_mul(r, r);
+    let mut u = f64x2_splat(EXP_POLY_10_D);
+    u = f64x2_mlaf(u, f, f64x2_splat(EXP_POLY_9_D));
+    u = f64x2_mlaf(u, f, f64x2_splat(EXP_POLY_8_D));
+    u = f64x2_mlaf(u, f, f64x2_splat(EXP_POLY_7_D));
+    u = f64x2_mlaf(u, f, f64x2_splat(EXP_POLY_6_D));
+    u = f64x2_mlaf(u, f, f64x2_splat(EXP_POLY_5_D));
+    u = f64x2_mlaf(u, f, f64x2_splat(EXP_POLY_4_D));
+    u = f64x2_mlaf(u, f, f64x2_splat(EXP_POLY_3_D));
+    u = f64x2_mlaf(u, f, f64x2_splat(EXP_POLY_2_D));
+    u = f64x2_mlaf(u, f, f64x2_splat(EXP_POLY_1_D));
+    f64x2_div(f64x2_mul(r, f64x2_splat(2.)), f64x2_sub(u, r))
+}
+
+/// Computes exp for an argument *ULP 2.0*
+#[inline]
+pub fn f64x2_exp(d: v128) -> v128 {
+    let q = f64x2_rint(f64x2_mul(d, f64x2_splat(R_LN2)));
+    let qf = f64x2_convert_i64x2(q);
+    let mut r = f64x2_mlaf(qf, f64x2_splat(-L2_U), d);
+    r = f64x2_mlaf(qf, f64x2_splat(-L2_L), r);
+    // Two step scaling keeps subnormal results and the last binade before overflow
+    r = f64x2_ldexp2k(f64x2_add(f64x2_expm1k(r), f64x2_splat(1.)), q);
+    r = f64x2_select(f64x2_lt(d, f64x2_splat(-746f64)), f64x2_splat(0.), r);
+    r = f64x2_select(
+        f64x2_gt(d, f64x2_splat(710f64)),
+        f64x2_splat(f64::INFINITY),
+        r,
+    );
+    r
+}
+
+/// Method that computes exp skipping Inf, Nan checks error bound *ULP 2.0*
+#[inline]
+pub fn f64x2_exp_fast(d: v128) -> v128 {
+    let q = f64x2_rint(f64x2_mul(d, f64x2_splat(R_LN2)));
+    let qf = f64x2_convert_i64x2(q);
+    /* exp(x) = 2^i * exp(f); */
+    let mut r = f64x2_mlaf(qf, f64x2_splat(-L2_U), d);
+    r = f64x2_mlaf(qf, f64x2_splat(-L2_L), r);
+    let u = f64x2_add(f64x2_expm1k(r), f64x2_splat(1.));
+    f64x2_mul(u, i64x2_pow2i(q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expd() {
+        {
+            let comparison = f64x2_exp(f64x2_splat(2.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 7.38905609893065f64).abs() < 1e-14);
+        }
+        {
+            let comparison = f64x2_exp(f64x2_splat(-1.5));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 0.22313016014842982f64).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_exp(f64x2_splat(1000.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
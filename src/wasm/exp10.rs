@@ -0,0 +1,73 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::exp::{LG2_L, LG2_U, LN10_HI, LN10_LO};
+use crate::wasm::exp::f64x2_expm1k;
+use crate::wasm::general::f64x2_ldexp2k;
+use crate::{f64x2_convert_i64x2, f64x2_mlaf, f64x2_rint, f64x2_select};
+
+/// Computes 10^x *ULP 2.0*
+#[inline]
+pub fn f64x2_exp10(d: v128) -> v128 {
+    let q = f64x2_rint(f64x2_mul(d, f64x2_splat(std::f64::consts::LOG2_10)));
+    let qf = f64x2_convert_i64x2(q);
+    let mut s = f64x2_mlaf(qf, f64x2_splat(-LG2_U), d);
+    s = f64x2_mlaf(qf, f64x2_splat(-LG2_L), s);
+    let r = f64x2_mlaf(s, f64x2_splat(LN10_HI), f64x2_mul(s, f64x2_splat(LN10_LO)));
+    let mut r = f64x2_ldexp2k(f64x2_add(f64x2_expm1k(r), f64x2_splat(1f64)), q);
+    r = f64x2_select(f64x2_lt(d, f64x2_splat(-323.61f64)), f64x2_splat(0f64), r);
+    r = f64x2_select(
+        f64x2_gt(d, f64x2_splat(308.26f64)),
+        f64x2_splat(f64::INFINITY),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exp10d() {
+        {
+            // Test regular
+            let value = f64x2_splat(2.5f64);
+            let comparison = f64x2_exp10(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 10f64.powf(2.5);
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(300f64);
+            let comparison = f64x2_exp10(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1e300;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        {
+            // Test overflow
+            let value = f64x2_splat(400f64);
+            let comparison = f64x2_exp10(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        {
+            // Test underflow
+            let value = f64x2_splat(-400f64);
+            let comparison = f64x2_exp10(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::expf::{LG2_L_F, LG2_U_F, LN10_HI_F, LN10_LO_F};
+use crate::wasm::expf::f32x4_expm1k;
+use crate::wasm::generalf::f32x4_ldexp2k;
+use crate::{f32x4_mlaf, f32x4_rint, f32x4_select};
+
+/// Computes 10^x *ULP 2.0*
+#[inline]
+pub fn f32x4_exp10(d: v128) -> v128 {
+    let q = f32x4_rint(f32x4_mul(d, f32x4_splat(std::f32::consts::LOG2_10)));
+    let qf = f32x4_convert_i32x4(q);
+    let mut s = f32x4_mlaf(qf, f32x4_splat(-LG2_U_F), d);
+    s = f32x4_mlaf(qf, f32x4_splat(-LG2_L_F), s);
+    let r = f32x4_mlaf(
+        s,
+        f32x4_splat(LN10_HI_F),
+        f32x4_mul(s, f32x4_splat(LN10_LO_F)),
+    );
+    let mut r = f32x4_ldexp2k(f32x4_add(f32x4_expm1k(r), f32x4_splat(1f32)), q);
+    r = f32x4_select(f32x4_lt(d, f32x4_splat(-45.16f32)), f32x4_splat(0f32), r);
+    r = f32x4_select(
+        f32x4_gt(d, f32x4_splat(38.54f32)),
+        f32x4_splat(f32::INFINITY),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exp10f() {
+        {
+            // Test regular
+            let value = f32x4_splat(1.5f32);
+            let comparison = f32x4_exp10(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 10f32.powf(1.5);
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(30f32);
+            let comparison = f32x4_exp10(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1e30;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        {
+            // Test overflow
+            let value = f32x4_splat(39f32);
+            let comparison = f32x4_exp10(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        {
+            // Test underflow
+            let value = f32x4_splat(-50f32);
+            let comparison = f32x4_exp10(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::exp::LN2_LO;
+use crate::wasm::exp::f64x2_expm1k;
+use crate::wasm::general::f64x2_ldexp2k;
+use crate::{f64x2_convert_i64x2, f64x2_mlaf, f64x2_rint, f64x2_select};
+
+/// Computes 2^x *ULP 1.5*
+#[inline]
+pub fn f64x2_exp2(d: v128) -> v128 {
+    let q = f64x2_rint(d);
+    let qf = f64x2_convert_i64x2(q);
+    let s = f64x2_sub(d, qf);
+    let r = f64x2_mlaf(
+        s,
+        f64x2_splat(std::f64::consts::LN_2),
+        f64x2_mul(s, f64x2_splat(LN2_LO)),
+    );
+    let mut r = f64x2_ldexp2k(f64x2_add(f64x2_expm1k(r), f64x2_splat(1f64)), q);
+    r = f64x2_select(f64x2_lt(d, f64x2_splat(-1075f64)), f64x2_splat(0f64), r);
+    r = f64x2_select(
+        f64x2_ge(d, f64x2_splat(1024f64)),
+        f64x2_splat(f64::INFINITY),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exp2d() {
+        {
+            // Test regular
+            let value = f64x2_splat(10.5f64);
+            let comparison = f64x2_exp2(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 10.5f64.exp2();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        {
+            // Test subnormal result
+            let value = f64x2_splat(-1070f64);
+            let comparison = f64x2_exp2(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::from_bits(16));
+        }
+
+        {
+            // Test overflow
+            let value = f64x2_splat(1024f64);
+            let comparison = f64x2_exp2(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        {
+            // Test underflow
+            let value = f64x2_splat(-1100f64);
+            let comparison = f64x2_exp2(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
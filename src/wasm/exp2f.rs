@@ -0,0 +1,75 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::expf::LN2_LO_F;
+use crate::wasm::expf::f32x4_expm1k;
+use crate::wasm::generalf::f32x4_ldexp2k;
+use crate::{f32x4_mlaf, f32x4_rint, f32x4_select};
+
+/// Computes 2^x *ULP 2.0*
+#[inline]
+pub fn f32x4_exp2(d: v128) -> v128 {
+    let q = f32x4_rint(d);
+    let qf = f32x4_convert_i32x4(q);
+    let s = f32x4_sub(d, qf);
+    let r = f32x4_mlaf(
+        s,
+        f32x4_splat(std::f32::consts::LN_2),
+        f32x4_mul(s, f32x4_splat(LN2_LO_F)),
+    );
+    let mut r = f32x4_ldexp2k(f32x4_add(f32x4_expm1k(r), f32x4_splat(1f32)), q);
+    r = f32x4_select(f32x4_lt(d, f32x4_splat(-150f32)), f32x4_splat(0f32), r);
+    r = f32x4_select(
+        f32x4_ge(d, f32x4_splat(128f32)),
+        f32x4_splat(f32::INFINITY),
+        r,
+    );
+    r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exp2f() {
+        {
+            // Test regular
+            let value = f32x4_splat(3.25f32);
+            let comparison = f32x4_exp2(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 3.25f32.exp2();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        {
+            // Test subnormal result
+            let value = f32x4_splat(-140f32);
+            let comparison = f32x4_exp2(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::from_bits(1 << 9));
+        }
+
+        {
+            // Test overflow
+            let value = f32x4_splat(128f32);
+            let comparison = f32x4_exp2(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        {
+            // Test underflow
+            let value = f32x4_splat(-160f32);
+            let comparison = f32x4_exp2(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
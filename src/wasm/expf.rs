@@ -0,0 +1,75 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::expf::{
+    EXP_POLY_1_S, EXP_POLY_2_S, EXP_POLY_3_S, EXP_POLY_4_S, EXP_POLY_5_S, L2L_F, L2U_F,
+};
+use crate::wasm::generalf::f32x4_ldexp2k;
+use crate::{f32x4_mlaf, f32x4_rint, f32x4_select, i32x4_pow2if};
+
+/// Computes `exp(r) - 1` for reduced argument |r| <= ln(2)/2
+#[inline]
+pub(crate) fn f32x4_expm1k(r: v128) -> v128 {
+    let f = f32x4_mul(r, r);
+    let mut u = f32x4_splat(EXP_POLY_5_S);
+    u = f32x4_mlaf(u, f, f32x4_splat(EXP_POLY_4_S));
+    u = f32x4_mlaf(u, f, f32x4_splat(EXP_POLY_3_S));
+    u = f32x4_mlaf(u, f, f32x4_splat(EXP_POLY_2_S));
+    u = f32x4_mlaf(u, f, f32x4_splat(EXP_POLY_1_S));
+    f32x4_div(f32x4_mul(r, f32x4_splat(2f32)), f32x4_sub(u, r))
+}
+
+/// Computes exp for an argument *ULP 1.0*
+#[inline]
+pub fn f32x4_exp(d: v128) -> v128 {
+    let q = f32x4_rint(f32x4_mul(d, f32x4_splat(std::f32::consts::LOG2_E)));
+    let qf = f32x4_convert_i32x4(q);
+    let mut r = f32x4_mlaf(qf, f32x4_splat(-L2U_F), d);
+    r = f32x4_mlaf(qf, f32x4_splat(-L2L_F), r);
+    // Two step scaling keeps subnormal results and the last binade before overflow
+    r = f32x4_ldexp2k(f32x4_add(f32x4_expm1k(r), f32x4_splat(1f32)), q);
+    r = f32x4_select(f32x4_lt(d, f32x4_splat(-104f32)), f32x4_splat(0f32), r);
+    r = f32x4_select(
+        f32x4_gt(d, f32x4_splat(89f32)),
+        f32x4_splat(f32::INFINITY),
+        r,
+    );
+    r
+}
+
+/// Method that computes exp skipping Inf, Nan checks error bound *ULP 1.0*
+#[inline]
+pub fn f32x4_exp_fast(d: v128) -> v128 {
+    let q = f32x4_rint(f32x4_mul(d, f32x4_splat(std::f32::consts::LOG2_E)));
+    let qf = f32x4_convert_i32x4(q);
+    /* exp(x) = 2^i * exp(f); */
+    let mut r = f32x4_mlaf(qf, f32x4_splat(-L2U_F), d);
+    r = f32x4_mlaf(qf, f32x4_splat(-L2L_F), r);
+    let u = f32x4_add(f32x4_expm1k(r), f32x4_splat(1f32));
+    f32x4_mul(u, i32x4_pow2if(q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expf() {
+        {
+            let comparison = f32x4_exp(f32x4_splat(2f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 7.389056f32).abs() < 1e-5);
+        }
+        {
+            let comparison = f32x4_exp(f32x4_splat(100f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
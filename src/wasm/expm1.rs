@@ -0,0 +1,124 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::exp::{L2_L, L2_U, R_LN2};
+use crate::expm1::{
+    EXPM1_POLY_10_D, EXPM1_POLY_11_D, EXPM1_POLY_12_D, EXPM1_POLY_13_D, EXPM1_POLY_14_D,
+    EXPM1_POLY_2_D, EXPM1_POLY_3_D, EXPM1_POLY_4_D, EXPM1_POLY_5_D, EXPM1_POLY_6_D, EXPM1_POLY_7_D,
+    EXPM1_POLY_8_D, EXPM1_POLY_9_D,
+};
+use crate::wasm::general::f64x2_ldexp2k;
+use crate::{f64x2_convert_i64x2, f64x2_mlaf, f64x2_rint, f64x2_select, i64x2_pow2i};
+
+/// Computes `exp(r) - 1 - r` for reduced argument |r| <= ln(2)/2
+#[inline]
+fn f64x2_expm1_tail(r: v128) -> v128 {
+    let mut u = f64x2_splat(EXPM1_POLY_14_D);
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_13_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_12_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_11_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_10_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_9_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_8_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_7_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_6_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_5_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_4_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_3_D));
+    u = f64x2_mlaf(u, r, f64x2_splat(EXPM1_POLY_2_D));
+    f64x2_mul(f64x2_mul(r, r), u)
+}
+
+/// Computes exp(x) - 1, accurate for x near zero *ULP 1.5*
+#[inline]
+pub fn f64x2_expm1(d: v128) -> v128 {
+    let q = f64x2_rint(f64x2_mul(d, f64x2_splat(R_LN2)));
+    let qf = f64x2_convert_i64x2(q);
+    // rh is exact, rl keeps the part of q*ln(2) below it
+    let rh = f64x2_mlaf(qf, f64x2_splat(-L2_U), d);
+    let rl = f64x2_mul(qf, f64x2_splat(-L2_L));
+    let t = f64x2_expm1_tail(f64x2_add(rh, rl));
+    // exp(x) - 1 = 2^q*(rl + t) + (2^q*rh + 2^q - 1), the inner sum is exact where it cancels
+    let i2 = i64x2_pow2i(q);
+    let mut u = f64x2_mlaf(
+        i2,
+        f64x2_add(rl, t),
+        f64x2_mlaf(i2, rh, f64x2_sub(i2, f64x2_splat(1f64))),
+    );
+    u = f64x2_select(
+        f64x2_gt(qf, f64x2_splat(53f64)),
+        f64x2_sub(
+            f64x2_ldexp2k(
+                f64x2_add(f64x2_splat(1f64), f64x2_add(rh, f64x2_add(rl, t))),
+                q,
+            ),
+            f64x2_splat(1f64),
+        ),
+        u,
+    );
+    u = f64x2_select(f64x2_eq(d, f64x2_splat(0f64)), d, u);
+    u = f64x2_select(f64x2_lt(d, f64x2_splat(-37.5f64)), f64x2_splat(-1f64), u);
+    u = f64x2_select(
+        f64x2_gt(d, f64x2_splat(709.782712893384f64)),
+        f64x2_splat(f64::INFINITY),
+        u,
+    );
+    u
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expm1d() {
+        {
+            // Test regular
+            let value = f64x2_splat(1e-10f64);
+            let comparison = f64x2_expm1(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1e-10f64.exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_expm1(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.5f64.exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-0.3f64);
+            let comparison = f64x2_expm1(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = (-0.3f64).exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f64::EPSILON);
+        }
+
+        {
+            // Test saturation
+            let value = f64x2_splat(-50f64);
+            let comparison = f64x2_expm1(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+
+        {
+            // Test overflow
+            let value = f64x2_splat(710f64);
+            let comparison = f64x2_expm1(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
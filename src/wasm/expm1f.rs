@@ -0,0 +1,117 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::expf::{L2L_F, L2U_F};
+use crate::expm1f::{
+    EXPM1_POLY_2_S, EXPM1_POLY_3_S, EXPM1_POLY_4_S, EXPM1_POLY_5_S, EXPM1_POLY_6_S, EXPM1_POLY_7_S,
+    EXPM1_POLY_8_S,
+};
+use crate::wasm::generalf::f32x4_ldexp2k;
+use crate::{f32x4_mlaf, f32x4_rint, f32x4_select, i32x4_pow2if};
+
+/// Computes `exp(r) - 1 - r` for reduced argument |r| <= ln(2)/2
+#[inline]
+fn f32x4_expm1_tail(r: v128) -> v128 {
+    let mut u = f32x4_splat(EXPM1_POLY_8_S);
+    u = f32x4_mlaf(u, r, f32x4_splat(EXPM1_POLY_7_S));
+    u = f32x4_mlaf(u, r, f32x4_splat(EXPM1_POLY_6_S));
+    u = f32x4_mlaf(u, r, f32x4_splat(EXPM1_POLY_5_S));
+    u = f32x4_mlaf(u, r, f32x4_splat(EXPM1_POLY_4_S));
+    u = f32x4_mlaf(u, r, f32x4_splat(EXPM1_POLY_3_S));
+    u = f32x4_mlaf(u, r, f32x4_splat(EXPM1_POLY_2_S));
+    f32x4_mul(f32x4_mul(r, r), u)
+}
+
+/// Computes exp(x) - 1, accurate for x near zero *ULP 1.5*
+#[inline]
+pub fn f32x4_expm1(d: v128) -> v128 {
+    let q = f32x4_rint(f32x4_mul(d, f32x4_splat(std::f32::consts::LOG2_E)));
+    let qf = f32x4_convert_i32x4(q);
+    // rh is exact, rl keeps the part of q*ln(2) below it
+    let rh = f32x4_mlaf(qf, f32x4_splat(-L2U_F), d);
+    let rl = f32x4_mul(qf, f32x4_splat(-L2L_F));
+    let t = f32x4_expm1_tail(f32x4_add(rh, rl));
+    // exp(x) - 1 = 2^q*(rl + t) + (2^q*rh + 2^q - 1), the inner sum is exact where it cancels
+    let i2 = i32x4_pow2if(q);
+    let mut u = f32x4_mlaf(
+        i2,
+        f32x4_add(rl, t),
+        f32x4_mlaf(i2, rh, f32x4_sub(i2, f32x4_splat(1f32))),
+    );
+    u = f32x4_select(
+        f32x4_gt(qf, f32x4_splat(24f32)),
+        f32x4_sub(
+            f32x4_ldexp2k(
+                f32x4_add(f32x4_splat(1f32), f32x4_add(rh, f32x4_add(rl, t))),
+                q,
+            ),
+            f32x4_splat(1f32),
+        ),
+        u,
+    );
+    u = f32x4_select(f32x4_eq(d, f32x4_splat(0f32)), d, u);
+    u = f32x4_select(f32x4_lt(d, f32x4_splat(-17.5f32)), f32x4_splat(-1f32), u);
+    u = f32x4_select(
+        f32x4_gt(d, f32x4_splat(88.72283f32)),
+        f32x4_splat(f32::INFINITY),
+        u,
+    );
+    u
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expm1f() {
+        {
+            // Test regular
+            let value = f32x4_splat(1e-6f32);
+            let comparison = f32x4_expm1(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1e-6f32.exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_expm1(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.5f32.exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-0.3f32);
+            let comparison = f32x4_expm1(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = (-0.3f32).exp_m1();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 2. * f32::EPSILON);
+        }
+
+        {
+            // Test saturation
+            let value = f32x4_splat(-20f32);
+            let comparison = f32x4_expm1(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+
+        {
+            // Test overflow
+            let value = f32x4_splat(89f32);
+            let comparison = f32x4_expm1(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::fmod::do_fmod;
+use crate::wasm::general::{
+    f64x2_copysign, f64x2_ilogb2k, f64x2_isinf, f64x2_isnan, f64x2_ldexp3k, f64x2_select,
+};
+
+#[inline]
+/// Computes fmod for f64, result is exact and has the sign of `a`
+pub fn f64x2_fmod(a: v128, b: v128) -> v128 {
+    let ax = f64x2_abs(a);
+    let ay = f64x2_abs(b);
+    let nan_mask = v128_or(
+        v128_or(f64x2_isnan(a), f64x2_isnan(b)),
+        v128_or(f64x2_isinf(a), f64x2_eq(b, f64x2_splat(0.))),
+    );
+    // Subnormal divisors and exponent differences above 64 are left to the scalar routine
+    let fallback = v128_andnot(
+        v128_or(
+            f64x2_lt(ay, f64x2_splat(f64::MIN_POSITIVE)),
+            f64x2_gt(ax, f64x2_mul(ay, f64x2_splat(18446744073709551616.))),
+        ),
+        nan_mask,
+    );
+    if v128_any_true(fallback) {
+        let mut xs = [0f64; 2];
+        let mut ys = [0f64; 2];
+        unsafe {
+            v128_store(xs.as_mut_ptr() as *mut v128, a);
+            v128_store(ys.as_mut_ptr() as *mut v128, b);
+        }
+        for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+            *x = do_fmod(*x, y);
+        }
+        return unsafe { v128_load(xs.as_ptr() as *const v128) };
+    }
+    let mut rem = ax;
+    let mut work = v128_andnot(f64x2_ge(rem, ay), nan_mask);
+    while v128_any_true(work) {
+        // Largest |b|*2^k not above the remainder, subtraction is exact by Sterbenz lemma
+        let k = i64x2_sub(f64x2_ilogb2k(rem), f64x2_ilogb2k(ay));
+        let mut t = f64x2_ldexp3k(ay, k);
+        t = f64x2_select(f64x2_gt(t, rem), f64x2_mul(t, f64x2_splat(0.5)), t);
+        rem = f64x2_select(work, f64x2_sub(rem, t), rem);
+        work = v128_and(work, f64x2_ge(rem, ay));
+    }
+    let ret = f64x2_copysign(rem, a);
+    f64x2_select(nan_mask, f64x2_splat(f64::NAN), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmodd() {
+        let values = [
+            (7., 2.),
+            (5.5, 2.5),
+            (-7., 2.),
+            (7., -2.),
+            (1e300, 3.),
+            (-1e300, 1e-300),
+            (3.5e-310, 1e-315),
+            (0.1, 1e308),
+            (-0., 3.),
+        ];
+        for (x, y) in values {
+            let comparison = f64x2_fmod(f64x2_splat(x), f64x2_splat(y));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1.to_bits(), (x % y).to_bits());
+        }
+        let comparison = f64x2_fmod(f64x2_splat(f64::INFINITY), f64x2_splat(2.));
+        assert!(f64x2_extract_lane::<0>(comparison).is_nan());
+        let comparison = f64x2_fmod(f64x2_splat(2.), f64x2_splat(f64::INFINITY));
+        assert_eq!(f64x2_extract_lane::<0>(comparison), 2.);
+    }
+}
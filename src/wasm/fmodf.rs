@@ -0,0 +1,85 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::fmodf::do_fmodf;
+use crate::wasm::generalf::{
+    f32x4_copysign, f32x4_ilogb2k, f32x4_isinf, f32x4_isnan, f32x4_ldexp3k, f32x4_select,
+};
+
+#[inline]
+/// Computes fmod for f32, result is exact and has the sign of `a`
+pub fn f32x4_fmod(a: v128, b: v128) -> v128 {
+    let ax = f32x4_abs(a);
+    let ay = f32x4_abs(b);
+    let nan_mask = v128_or(
+        v128_or(f32x4_isnan(a), f32x4_isnan(b)),
+        v128_or(f32x4_isinf(a), f32x4_eq(b, f32x4_splat(0.))),
+    );
+    // Subnormal divisors and exponent differences above 32 are left to the scalar routine
+    let fallback = v128_andnot(
+        v128_or(
+            f32x4_lt(ay, f32x4_splat(f32::MIN_POSITIVE)),
+            f32x4_gt(ax, f32x4_mul(ay, f32x4_splat(4294967296.))),
+        ),
+        nan_mask,
+    );
+    if v128_any_true(fallback) {
+        let mut xs = [0f32; 4];
+        let mut ys = [0f32; 4];
+        unsafe {
+            v128_store(xs.as_mut_ptr() as *mut v128, a);
+            v128_store(ys.as_mut_ptr() as *mut v128, b);
+        }
+        for (x, &y) in xs.iter_mut().zip(ys.iter()) {
+            *x = do_fmodf(*x, y);
+        }
+        return unsafe { v128_load(xs.as_ptr() as *const v128) };
+    }
+    let mut rem = ax;
+    let mut work = v128_andnot(f32x4_ge(rem, ay), nan_mask);
+    while v128_any_true(work) {
+        // Largest |b|*2^k not above the remainder, subtraction is exact by Sterbenz lemma
+        let k = i32x4_sub(f32x4_ilogb2k(rem), f32x4_ilogb2k(ay));
+        let mut t = f32x4_ldexp3k(ay, k);
+        t = f32x4_select(f32x4_gt(t, rem), f32x4_mul(t, f32x4_splat(0.5)), t);
+        rem = f32x4_select(work, f32x4_sub(rem, t), rem);
+        work = v128_and(work, f32x4_ge(rem, ay));
+    }
+    let ret = f32x4_copysign(rem, a);
+    f32x4_select(nan_mask, f32x4_splat(f32::NAN), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmodf() {
+        let values = [
+            (7., 2.),
+            (5.5, 2.5),
+            (-7., 2.),
+            (7., -2.),
+            (1e38, 3.),
+            (-1e38, 1e-38),
+            (3.5e-40, 1e-44),
+            (0.1, 1e38),
+            (-0., 3.),
+        ];
+        for (x, y) in values {
+            let comparison = f32x4_fmod(f32x4_splat(x), f32x4_splat(y));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1.to_bits(), (x % y).to_bits());
+        }
+        let comparison = f32x4_fmod(f32x4_splat(f32::INFINITY), f32x4_splat(2.));
+        assert!(f32x4_extract_lane::<0>(comparison).is_nan());
+        let comparison = f32x4_fmod(f32x4_splat(2.), f32x4_splat(f32::INFINITY));
+        assert_eq!(f32x4_extract_lane::<0>(comparison), 2.);
+    }
+}
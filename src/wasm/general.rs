@@ -0,0 +1,171 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::generalf::PAYNE_HANEK_THRESHOLD;
+use std::arch::wasm32::*;
+
+#[inline]
+/// Computes `a*b + c`
+pub fn f64x2_mlaf(a: v128, b: v128, c: v128) -> v128 {
+    f64x2_add(f64x2_mul(a, b), c)
+}
+
+#[inline]
+/// Rounds and takes integral part 64 bytes from double
+pub fn f64x2_rint(f: v128) -> v128 {
+    i64x2_extend_low_i32x4(i32x4_trunc_sat_f64x2_zero(f64x2_nearest(f)))
+}
+
+#[inline]
+/// Computes 2^n in f64 form for signed 64 bits integers, returns f64 in bits
+pub fn i64x2_pow2i(n: v128) -> v128 {
+    i64x2_shl(i64x2_add(n, i64x2_splat(0x3ff)), 52)
+}
+
+#[inline]
+/// Converts signed 64 bits integers to f64, values must be in i32 range
+pub fn f64x2_convert_i64x2(v: v128) -> v128 {
+    f64x2_convert_low_i32x4(i32x4_shuffle::<0, 2, 0, 2>(v, v))
+}
+
+#[inline]
+/// If mask then `true_vals` otherwise `false_val`
+pub fn f64x2_select(mask: v128, true_vals: v128, false_vals: v128) -> v128 {
+    v128_bitselect(true_vals, false_vals, mask)
+}
+
+#[inline]
+/// Returns flag value is zero
+pub fn f64x2_eqzero(d: v128) -> v128 {
+    f64x2_eq(d, f64x2_splat(0.))
+}
+
+#[inline]
+/// Returns flag value is lower than zero
+pub fn f64x2_ltzero(d: v128) -> v128 {
+    f64x2_lt(d, f64x2_splat(0.))
+}
+
+#[inline]
+/// Returns flag value is Infinity
+pub fn f64x2_isinf(d: v128) -> v128 {
+    f64x2_eq(f64x2_abs(d), f64x2_splat(f64::INFINITY))
+}
+
+#[inline]
+/// Returns flag value is Neg Infinity
+pub fn f64x2_isneginf(d: v128) -> v128 {
+    f64x2_eq(d, f64x2_splat(f64::NEG_INFINITY))
+}
+
+#[inline]
+/// Returns true flag if value is NaN
+pub fn f64x2_isnan(d: v128) -> v128 {
+    f64x2_ne(d, d)
+}
+
+#[inline]
+/// Checks if arguments is not integral value
+pub fn f64x2_isnotintegral(d: v128) -> v128 {
+    f64x2_ne(d, f64x2_floor(d))
+}
+
+#[inline]
+/// Copies sign from `y` to `x`
+pub fn f64x2_copysign(x: v128, y: v128) -> v128 {
+    let sign_mask = u64x2_splat(0x8000_0000_0000_0000);
+    v128_or(v128_andnot(x, sign_mask), v128_and(y, sign_mask))
+}
+
+#[inline]
+/// Founds n in x=a+𝑛ln(2), |a| <= 1
+pub fn f64x2_ilogb2k(d: v128) -> v128 {
+    i64x2_sub(
+        v128_and(u64x2_shr(d, 52), i64x2_splat(0x7ff)),
+        i64x2_splat(0x3ff),
+    )
+}
+
+#[inline]
+/// Founds a in x=a+𝑛ln(2), |a| <= 1
+pub fn f64x2_ldexp3k(x: v128, n: v128) -> v128 {
+    i64x2_add(x, i64x2_shl(n, 52))
+}
+
+#[inline]
+/// Computes `x*2^n` with two scalings, so `n` may reach subnormal and near overflow exponents
+pub fn f64x2_ldexp2k(x: v128, n: v128) -> v128 {
+    let m = i64x2_shr(n, 1);
+    let u = f64x2_mul(x, i64x2_pow2i(m));
+    f64x2_mul(u, i64x2_pow2i(i64x2_sub(n, m)))
+}
+
+#[inline]
+/// Replaces Cody-Waite reduction with Payne-Hanek `reduce` in lanes above threshold
+pub(crate) fn f64x2_rempi(
+    d: v128,
+    q: v128,
+    r: v128,
+    reduce: fn(f64) -> (i32, f64),
+) -> (v128, v128) {
+    if !v128_any_true(f64x2_gt(f64x2_abs(d), f64x2_splat(PAYNE_HANEK_THRESHOLD))) {
+        return (q, r);
+    }
+    let mut ds = [0f64; 2];
+    let mut qs = [0i64; 2];
+    let mut rs = [0f64; 2];
+    unsafe {
+        v128_store(ds.as_mut_ptr() as *mut v128, d);
+        v128_store(qs.as_mut_ptr() as *mut v128, q);
+        v128_store(rs.as_mut_ptr() as *mut v128, r);
+    }
+    for ((&d, q), r) in ds.iter().zip(qs.iter_mut()).zip(rs.iter_mut()) {
+        if d.abs() > PAYNE_HANEK_THRESHOLD && d.is_finite() {
+            let (lq, lr) = reduce(d);
+            *q = lq as i64;
+            *r = lr;
+        }
+    }
+    unsafe {
+        (
+            v128_load(qs.as_ptr() as *const v128),
+            v128_load(rs.as_ptr() as *const v128),
+        )
+    }
+}
+
+#[inline]
+/// Computes a * b as double-float, see `multiply_as_doubled`
+pub(crate) fn f64x2_multiply_as_doubled(a: v128, b: v128) -> (v128, v128) {
+    let xh = v128_and(a, u64x2_splat(0xffff_ffff_f800_0000));
+    let xl = f64x2_sub(a, xh);
+    let yh = v128_and(b, u64x2_splat(0xffff_ffff_f800_0000));
+    let yl = f64x2_sub(b, yh);
+    let r0 = f64x2_mul(a, b);
+    let mut d1 = f64x2_sub(f64x2_mul(xh, yh), r0);
+    d1 = f64x2_mlaf(xl, yh, d1);
+    d1 = f64x2_mlaf(xh, yl, d1);
+    (r0, f64x2_mlaf(xl, yl, d1))
+}
+
+#[inline]
+/// Multiplies two double-floats, see `mul_doubled`
+pub(crate) fn f64x2_mul_doubled(a: (v128, v128), b: (v128, v128)) -> (v128, v128) {
+    let m = f64x2_multiply_as_doubled(a.0, b.0);
+    (
+        m.0,
+        f64x2_add(m.1, f64x2_mlaf(a.0, b.1, f64x2_mul(a.1, b.0))),
+    )
+}
+
+#[inline]
+/// Computes a + b as double-float, see `sum_as_doubled`
+pub(crate) fn f64x2_sum_as_doubled(a: v128, b: v128) -> (v128, v128) {
+    let s = f64x2_add(a, b);
+    let v = f64x2_sub(s, a);
+    (s, f64x2_add(f64x2_sub(a, f64x2_sub(s, v)), f64x2_sub(b, v)))
+}
@@ -0,0 +1,163 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::generalf::PAYNE_HANEK_THRESHOLD_F;
+use std::arch::wasm32::*;
+
+#[inline]
+/// Computes `a*b + c`
+pub fn f32x4_mlaf(a: v128, b: v128, c: v128) -> v128 {
+    f32x4_add(f32x4_mul(a, b), c)
+}
+
+#[inline]
+/// Rounds and takes integral part from float
+pub fn f32x4_rint(f: v128) -> v128 {
+    i32x4_trunc_sat_f32x4(f32x4_nearest(f))
+}
+
+#[inline]
+/// Computes 2^n in f32 form for signed 32 bits integers, returns f32 in bits
+pub fn i32x4_pow2if(n: v128) -> v128 {
+    i32x4_shl(i32x4_add(n, i32x4_splat(0x7f)), 23)
+}
+
+#[inline]
+/// If mask then `true_vals` otherwise `false_val`
+pub fn f32x4_select(mask: v128, true_vals: v128, false_vals: v128) -> v128 {
+    v128_bitselect(true_vals, false_vals, mask)
+}
+
+#[inline]
+/// Returns flag value is zero
+pub fn f32x4_eqzero(d: v128) -> v128 {
+    f32x4_eq(d, f32x4_splat(0f32))
+}
+
+#[inline]
+/// Returns flag value is lower than zero
+pub fn f32x4_ltzero(d: v128) -> v128 {
+    f32x4_lt(d, f32x4_splat(0f32))
+}
+
+#[inline]
+/// Returns flag value is Infinity
+pub fn f32x4_isinf(d: v128) -> v128 {
+    f32x4_eq(f32x4_abs(d), f32x4_splat(f32::INFINITY))
+}
+
+#[inline]
+/// Returns flag value is Neg Infinity
+pub fn f32x4_isneginf(d: v128) -> v128 {
+    f32x4_eq(d, f32x4_splat(f32::NEG_INFINITY))
+}
+
+#[inline]
+/// Returns true flag if value is NaN
+pub fn f32x4_isnan(d: v128) -> v128 {
+    f32x4_ne(d, d)
+}
+
+#[inline]
+/// Checks if arguments is not integral value
+pub fn f32x4_isnotintegral(d: v128) -> v128 {
+    f32x4_ne(d, f32x4_floor(d))
+}
+
+#[inline]
+/// Copies sign from `y` to `x`
+pub fn f32x4_copysign(x: v128, y: v128) -> v128 {
+    let sign_mask = u32x4_splat(0x8000_0000);
+    v128_or(v128_andnot(x, sign_mask), v128_and(y, sign_mask))
+}
+
+#[inline]
+/// Founds n in x=a+𝑛ln(2), |a| <= 1
+pub fn f32x4_ilogb2k(d: v128) -> v128 {
+    i32x4_sub(
+        v128_and(u32x4_shr(d, 23), i32x4_splat(0xff)),
+        i32x4_splat(0x7f),
+    )
+}
+
+#[inline]
+/// Founds a in x=a+𝑛ln(2), |a| <= 1
+pub fn f32x4_ldexp3k(x: v128, n: v128) -> v128 {
+    i32x4_add(x, i32x4_shl(n, 23))
+}
+
+#[inline]
+/// Computes `x*2^n` with two scalings, so `n` may reach subnormal and near overflow exponents
+pub fn f32x4_ldexp2k(x: v128, n: v128) -> v128 {
+    let m = i32x4_shr(n, 1);
+    let u = f32x4_mul(x, i32x4_pow2if(m));
+    f32x4_mul(u, i32x4_pow2if(i32x4_sub(n, m)))
+}
+
+#[inline]
+/// Replaces Cody-Waite reduction with Payne-Hanek `reduce` in lanes above threshold
+pub(crate) fn f32x4_rempi(
+    d: v128,
+    q: v128,
+    r: v128,
+    reduce: fn(f32) -> (i32, f32),
+) -> (v128, v128) {
+    if !v128_any_true(f32x4_gt(f32x4_abs(d), f32x4_splat(PAYNE_HANEK_THRESHOLD_F))) {
+        return (q, r);
+    }
+    let mut ds = [0f32; 4];
+    let mut qs = [0i32; 4];
+    let mut rs = [0f32; 4];
+    unsafe {
+        v128_store(ds.as_mut_ptr() as *mut v128, d);
+        v128_store(qs.as_mut_ptr() as *mut v128, q);
+        v128_store(rs.as_mut_ptr() as *mut v128, r);
+    }
+    for ((&d, q), r) in ds.iter().zip(qs.iter_mut()).zip(rs.iter_mut()) {
+        if d.abs() > PAYNE_HANEK_THRESHOLD_F && d.is_finite() {
+            (*q, *r) = reduce(d);
+        }
+    }
+    unsafe {
+        (
+            v128_load(qs.as_ptr() as *const v128),
+            v128_load(rs.as_ptr() as *const v128),
+        )
+    }
+}
+
+#[inline]
+/// Computes a * b as double-float, see `multiply_as_doubled`
+pub(crate) fn f32x4_multiply_as_doubled(a: v128, b: v128) -> (v128, v128) {
+    let xh = v128_and(a, u32x4_splat(0xffff_f000));
+    let xl = f32x4_sub(a, xh);
+    let yh = v128_and(b, u32x4_splat(0xffff_f000));
+    let yl = f32x4_sub(b, yh);
+    let r0 = f32x4_mul(a, b);
+    let mut d1 = f32x4_sub(f32x4_mul(xh, yh), r0);
+    d1 = f32x4_mlaf(xl, yh, d1);
+    d1 = f32x4_mlaf(xh, yl, d1);
+    (r0, f32x4_mlaf(xl, yl, d1))
+}
+
+#[inline]
+/// Multiplies two double-floats, see `mul_doubled`
+pub(crate) fn f32x4_mul_doubled(a: (v128, v128), b: (v128, v128)) -> (v128, v128) {
+    let m = f32x4_multiply_as_doubled(a.0, b.0);
+    (
+        m.0,
+        f32x4_add(m.1, f32x4_mlaf(a.0, b.1, f32x4_mul(a.1, b.0))),
+    )
+}
+
+#[inline]
+/// Computes a + b as double-float, see `sum_as_doubled`
+pub(crate) fn f32x4_sum_as_doubled(a: v128, b: v128) -> (v128, v128) {
+    let s = f32x4_add(a, b);
+    let v = f32x4_sub(s, a);
+    (s, f32x4_add(f32x4_sub(a, f32x4_sub(s, v)), f32x4_sub(b, v)))
+}
@@ -0,0 +1,66 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f64x2_eqzero, f64x2_isinf, f64x2_isnan, f64x2_mlaf, f64x2_select};
+
+#[inline]
+/// Method that computes 2D Euclidian distance *ULP 0.6667*
+pub fn f64x2_hypot(x: v128, y: v128) -> v128 {
+    let x = f64x2_abs(x);
+    let y = f64x2_abs(y);
+    let max = f64x2_max(x, y);
+    let min = f64x2_min(x, y);
+    let r = f64x2_div(min, max);
+    let mut ret = f64x2_mul(f64x2_sqrt(f64x2_mlaf(r, r, f64x2_splat(1.))), max);
+    let is_any_infinite = v128_or(f64x2_isinf(x), f64x2_isinf(y));
+    let mut is_any_nan = v128_or(f64x2_isnan(x), f64x2_isnan(y));
+    let is_min_zero = f64x2_eqzero(min);
+    ret = f64x2_select(is_min_zero, max, ret);
+    is_any_nan = v128_or(f64x2_isnan(ret), is_any_nan);
+    ret = f64x2_select(is_any_nan, f64x2_splat(f64::NAN), ret);
+    ret = f64x2_select(is_any_infinite, f64x2_splat(f64::INFINITY), ret);
+    ret
+}
+
+/// Method that computes 2D Euclidian distance *ULP 0.6667*, skipping Inf, Nan checks
+#[inline]
+pub fn f64x2_hypot_fast(x: v128, y: v128) -> v128 {
+    let x = f64x2_abs(x);
+    let y = f64x2_abs(y);
+    let max = f64x2_max(x, y);
+    let min = f64x2_min(x, y);
+    let r = f64x2_div(min, max);
+    let is_min_zero = f64x2_eqzero(min);
+    let ret = f64x2_mul(f64x2_sqrt(f64x2_mlaf(r, r, f64x2_splat(1.))), max);
+    f64x2_select(is_min_zero, max, ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypotd() {
+        {
+            let comparison = f64x2_hypot(f64x2_splat(3.), f64x2_splat(-4.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 5.);
+        }
+        {
+            let comparison = f64x2_hypot(f64x2_splat(0.), f64x2_splat(-2.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 2.);
+        }
+        {
+            let comparison = f64x2_hypot_fast(f64x2_splat(3.), f64x2_splat(4.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 5.);
+        }
+    }
+}
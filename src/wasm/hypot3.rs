@@ -0,0 +1,80 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f64x2_eqzero, f64x2_isinf, f64x2_isnan, f64x2_mlaf, f64x2_select};
+
+#[inline]
+/// Method that computes 3D Euclidian distance *ULP 0.66667*
+pub fn f64x2_hypot3(x: v128, y: v128, z: v128) -> v128 {
+    let x = f64x2_abs(x);
+    let y = f64x2_abs(y);
+    let z = f64x2_abs(z);
+    let max = f64x2_max(f64x2_max(x, y), z);
+    let recip_max = f64x2_div(f64x2_splat(1.), max);
+    let norm_x = f64x2_mul(x, recip_max);
+    let norm_y = f64x2_mul(y, recip_max);
+    let norm_z = f64x2_mul(z, recip_max);
+
+    let accumulator = f64x2_mlaf(
+        norm_x,
+        norm_x,
+        f64x2_mlaf(norm_y, norm_y, f64x2_mul(norm_z, norm_z)),
+    );
+    let mut ret = f64x2_mul(f64x2_sqrt(accumulator), max);
+    let is_any_infinite = v128_or(v128_or(f64x2_isinf(x), f64x2_isinf(y)), f64x2_isinf(z));
+    let mut is_any_nan = v128_or(v128_or(f64x2_isnan(x), f64x2_isnan(y)), f64x2_isnan(z));
+    let is_max_zero = f64x2_eqzero(max);
+    is_any_nan = v128_or(f64x2_isnan(ret), is_any_nan);
+    ret = f64x2_select(is_any_nan, f64x2_splat(f64::NAN), ret);
+    ret = f64x2_select(is_any_infinite, f64x2_splat(f64::INFINITY), ret);
+    ret = f64x2_select(is_max_zero, f64x2_splat(0.), ret);
+    ret
+}
+
+/// Method that computes 3D Euclidian distance *ULP 0.66667*, skipping Inf, Nan checks
+#[inline]
+pub fn f64x2_hypot3_fast(x: v128, y: v128, z: v128) -> v128 {
+    let x = f64x2_abs(x);
+    let y = f64x2_abs(y);
+    let z = f64x2_abs(z);
+    let max = f64x2_max(f64x2_max(x, y), z);
+    let recip_max = f64x2_div(f64x2_splat(1.), max);
+    let norm_x = f64x2_mul(x, recip_max);
+    let norm_y = f64x2_mul(y, recip_max);
+    let norm_z = f64x2_mul(z, recip_max);
+
+    let is_max_zero = f64x2_eqzero(max);
+
+    let accumulator = f64x2_mlaf(
+        norm_x,
+        norm_x,
+        f64x2_mlaf(norm_y, norm_y, f64x2_mul(norm_z, norm_z)),
+    );
+    let ret = f64x2_mul(f64x2_sqrt(accumulator), max);
+    f64x2_select(is_max_zero, f64x2_splat(0.), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypot3d() {
+        {
+            let comparison = f64x2_hypot3(f64x2_splat(2.), f64x2_splat(-3.), f64x2_splat(6.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 7.).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_hypot3(f64x2_splat(0.), f64x2_splat(0.), f64x2_splat(0.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
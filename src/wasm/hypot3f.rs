@@ -0,0 +1,80 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_eqzero, f32x4_isinf, f32x4_isnan, f32x4_mlaf, f32x4_select};
+
+#[inline]
+/// Method that computes 3D Euclidian distance *ULP 0.66667*
+pub fn f32x4_hypot3(x: v128, y: v128, z: v128) -> v128 {
+    let x = f32x4_abs(x);
+    let y = f32x4_abs(y);
+    let z = f32x4_abs(z);
+    let max = f32x4_max(f32x4_max(x, y), z);
+    let recip_max = f32x4_div(f32x4_splat(1.), max);
+    let norm_x = f32x4_mul(x, recip_max);
+    let norm_y = f32x4_mul(y, recip_max);
+    let norm_z = f32x4_mul(z, recip_max);
+
+    let accumulator = f32x4_mlaf(
+        norm_x,
+        norm_x,
+        f32x4_mlaf(norm_y, norm_y, f32x4_mul(norm_z, norm_z)),
+    );
+    let mut ret = f32x4_mul(f32x4_sqrt(accumulator), max);
+    let is_any_infinite = v128_or(v128_or(f32x4_isinf(x), f32x4_isinf(y)), f32x4_isinf(z));
+    let mut is_any_nan = v128_or(v128_or(f32x4_isnan(x), f32x4_isnan(y)), f32x4_isnan(z));
+    let is_max_zero = f32x4_eqzero(max);
+    is_any_nan = v128_or(f32x4_isnan(ret), is_any_nan);
+    ret = f32x4_select(is_any_nan, f32x4_splat(f32::NAN), ret);
+    ret = f32x4_select(is_any_infinite, f32x4_splat(f32::INFINITY), ret);
+    ret = f32x4_select(is_max_zero, f32x4_splat(0.), ret);
+    ret
+}
+
+/// Method that computes 3D Euclidian distance *ULP 0.66667*, skipping Inf, Nan checks
+#[inline]
+pub fn f32x4_hypot3_fast(x: v128, y: v128, z: v128) -> v128 {
+    let x = f32x4_abs(x);
+    let y = f32x4_abs(y);
+    let z = f32x4_abs(z);
+    let max = f32x4_max(f32x4_max(x, y), z);
+    let recip_max = f32x4_div(f32x4_splat(1.), max);
+    let norm_x = f32x4_mul(x, recip_max);
+    let norm_y = f32x4_mul(y, recip_max);
+    let norm_z = f32x4_mul(z, recip_max);
+
+    let is_max_zero = f32x4_eqzero(max);
+
+    let accumulator = f32x4_mlaf(
+        norm_x,
+        norm_x,
+        f32x4_mlaf(norm_y, norm_y, f32x4_mul(norm_z, norm_z)),
+    );
+    let ret = f32x4_mul(f32x4_sqrt(accumulator), max);
+    f32x4_select(is_max_zero, f32x4_splat(0.), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypot3f() {
+        {
+            let comparison = f32x4_hypot3(f32x4_splat(2.), f32x4_splat(-3.), f32x4_splat(6.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 7.).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_hypot3(f32x4_splat(0.), f32x4_splat(0.), f32x4_splat(0.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_eqzero, f32x4_isinf, f32x4_isnan, f32x4_mlaf, f32x4_select};
+
+#[inline]
+/// Method that computes 4D Euclidian distance *ULP 0.6666*
+pub fn f32x4_hypot4(x: v128, y: v128, z: v128, w: v128) -> v128 {
+    let x = f32x4_abs(x);
+    let y = f32x4_abs(y);
+    let z = f32x4_abs(z);
+    let w = f32x4_abs(w);
+    let max = f32x4_max(f32x4_max(f32x4_max(x, y), z), w);
+    let recip_max = f32x4_div(f32x4_splat(1.), max);
+    let norm_x = f32x4_mul(x, recip_max);
+    let norm_y = f32x4_mul(y, recip_max);
+    let norm_z = f32x4_mul(z, recip_max);
+    let norm_w = f32x4_mul(w, recip_max);
+
+    let accumulator = f32x4_mlaf(
+        norm_x,
+        norm_x,
+        f32x4_mlaf(
+            norm_y,
+            norm_y,
+            f32x4_mlaf(norm_z, norm_z, f32x4_mul(norm_w, norm_w)),
+        ),
+    );
+    let mut ret = f32x4_mul(f32x4_sqrt(accumulator), max);
+    let is_any_infinite = v128_or(
+        v128_or(v128_or(f32x4_isinf(x), f32x4_isinf(y)), f32x4_isinf(z)),
+        f32x4_isinf(w),
+    );
+    let mut is_any_nan = v128_or(
+        v128_or(v128_or(f32x4_isnan(x), f32x4_isnan(y)), f32x4_isnan(z)),
+        f32x4_isnan(w),
+    );
+    let is_max_zero = f32x4_eqzero(max);
+    is_any_nan = v128_or(f32x4_isnan(ret), is_any_nan);
+    ret = f32x4_select(is_any_nan, f32x4_splat(f32::NAN), ret);
+    ret = f32x4_select(is_any_infinite, f32x4_splat(f32::INFINITY), ret);
+    ret = f32x4_select(is_max_zero, f32x4_splat(0.), ret);
+    ret
+}
+
+/// Method that computes 4D Euclidian distance *ULP 0.6666*, skipping Inf, Nan checks
+#[inline]
+pub fn f32x4_hypot4_fast(x: v128, y: v128, z: v128, w: v128) -> v128 {
+    let x = f32x4_abs(x);
+    let y = f32x4_abs(y);
+    let z = f32x4_abs(z);
+    let w = f32x4_abs(w);
+    let max = f32x4_max(f32x4_max(f32x4_max(x, y), z), w);
+    let recip_max = f32x4_div(f32x4_splat(1.), max);
+    let norm_x = f32x4_mul(x, recip_max);
+    let norm_y = f32x4_mul(y, recip_max);
+    let norm_z = f32x4_mul(z, recip_max);
+    let norm_w = f32x4_mul(w, recip_max);
+
+    let is_max_zero = f32x4_eqzero(max);
+
+    let accumulator = f32x4_mlaf(
+        norm_x,
+        norm_x,
+        f32x4_mlaf(
+            norm_y,
+            norm_y,
+            f32x4_mlaf(norm_z, norm_z, f32x4_mul(norm_w, norm_w)),
+        ),
+    );
+    let ret = f32x4_mul(f32x4_sqrt(accumulator), max);
+    f32x4_select(is_max_zero, f32x4_splat(0.), ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypot4f() {
+        {
+            let comparison = f32x4_hypot4(
+                f32x4_splat(1.),
+                f32x4_splat(-2.),
+                f32x4_splat(4.),
+                f32x4_splat(10.),
+            );
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 11.).abs() < 1e-5);
+        }
+        {
+            let comparison = f32x4_hypot4(
+                f32x4_splat(0.),
+                f32x4_splat(0.),
+                f32x4_splat(0.),
+                f32x4_splat(f32::NAN),
+            );
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,66 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_eqzero, f32x4_isinf, f32x4_isnan, f32x4_mlaf, f32x4_select};
+
+#[inline]
+/// Method that computes 2D Euclidian distance *ULP 0.6667*
+pub fn f32x4_hypot(x: v128, y: v128) -> v128 {
+    let x = f32x4_abs(x);
+    let y = f32x4_abs(y);
+    let max = f32x4_max(x, y);
+    let min = f32x4_min(x, y);
+    let r = f32x4_div(min, max);
+    let mut ret = f32x4_mul(f32x4_sqrt(f32x4_mlaf(r, r, f32x4_splat(1.))), max);
+    let is_any_infinite = v128_or(f32x4_isinf(x), f32x4_isinf(y));
+    let mut is_any_nan = v128_or(f32x4_isnan(x), f32x4_isnan(y));
+    let is_min_zero = f32x4_eqzero(min);
+    ret = f32x4_select(is_min_zero, max, ret);
+    is_any_nan = v128_or(f32x4_isnan(ret), is_any_nan);
+    ret = f32x4_select(is_any_nan, f32x4_splat(f32::NAN), ret);
+    ret = f32x4_select(is_any_infinite, f32x4_splat(f32::INFINITY), ret);
+    ret
+}
+
+/// Method that computes 2D Euclidian distance *ULP 0.6667*, skipping Inf, Nan checks
+#[inline]
+pub fn f32x4_hypot_fast(x: v128, y: v128) -> v128 {
+    let x = f32x4_abs(x);
+    let y = f32x4_abs(y);
+    let max = f32x4_max(x, y);
+    let min = f32x4_min(x, y);
+    let r = f32x4_div(min, max);
+    let is_min_zero = f32x4_eqzero(min);
+    let ret = f32x4_mul(f32x4_sqrt(f32x4_mlaf(r, r, f32x4_splat(1.))), max);
+    f32x4_select(is_min_zero, max, ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hypotf() {
+        {
+            let comparison = f32x4_hypot(f32x4_splat(3.), f32x4_splat(-4.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 5.);
+        }
+        {
+            let comparison = f32x4_hypot(f32x4_splat(0.), f32x4_splat(-2.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 2.);
+        }
+        {
+            let comparison = f32x4_hypot_fast(f32x4_splat(3.), f32x4_splat(4.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 5.);
+        }
+    }
+}
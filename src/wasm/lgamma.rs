@@ -0,0 +1,227 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::digamma::{DIGAMMA_ROOT_D, DIGAMMA_ROOT_LO_D, DIGAMMA_ROOT_M1_D, DIGAMMA_ROOT_M1_LO_D};
+use crate::lgamma::{
+    LGAMMA_A_POLY_10_D, LGAMMA_A_POLY_11_D, LGAMMA_A_POLY_12_D, LGAMMA_A_POLY_13_D,
+    LGAMMA_A_POLY_14_D, LGAMMA_A_POLY_15_D, LGAMMA_A_POLY_16_D, LGAMMA_A_POLY_17_D,
+    LGAMMA_A_POLY_18_D, LGAMMA_A_POLY_19_D, LGAMMA_A_POLY_1_D, LGAMMA_A_POLY_2_D,
+    LGAMMA_A_POLY_3_D, LGAMMA_A_POLY_4_D, LGAMMA_A_POLY_5_D, LGAMMA_A_POLY_6_D, LGAMMA_A_POLY_7_D,
+    LGAMMA_A_POLY_8_D, LGAMMA_A_POLY_9_D, LGAMMA_B_POLY_10_D, LGAMMA_B_POLY_11_D,
+    LGAMMA_B_POLY_12_D, LGAMMA_B_POLY_13_D, LGAMMA_B_POLY_14_D, LGAMMA_B_POLY_15_D,
+    LGAMMA_B_POLY_16_D, LGAMMA_B_POLY_17_D, LGAMMA_B_POLY_18_D, LGAMMA_B_POLY_19_D,
+    LGAMMA_B_POLY_1_D, LGAMMA_B_POLY_2_D, LGAMMA_B_POLY_3_D, LGAMMA_B_POLY_4_D, LGAMMA_B_POLY_5_D,
+    LGAMMA_B_POLY_6_D, LGAMMA_B_POLY_7_D, LGAMMA_B_POLY_8_D, LGAMMA_B_POLY_9_D, LGAMMA_H_POLY_10_D,
+    LGAMMA_H_POLY_11_D, LGAMMA_H_POLY_12_D, LGAMMA_H_POLY_13_D, LGAMMA_H_POLY_14_D,
+    LGAMMA_H_POLY_15_D, LGAMMA_H_POLY_1_D, LGAMMA_H_POLY_2_D, LGAMMA_H_POLY_3_D, LGAMMA_H_POLY_4_D,
+    LGAMMA_H_POLY_5_D, LGAMMA_H_POLY_6_D, LGAMMA_H_POLY_7_D, LGAMMA_H_POLY_8_D, LGAMMA_H_POLY_9_D,
+    LGAMMA_MIN_D, LGAMMA_MIN_LO_D, LN_PI_D, LN_SQRT_2PI_D,
+};
+use crate::wasm::general::f64x2_mul_doubled;
+use crate::wasm::ln::f64x2_lnk_doubled;
+use crate::wasm::sin::f64x2_sinpik;
+use crate::wasm::tgamma::f64x2_stirlingk;
+use crate::{f64x2_copysign, f64x2_isnotintegral, f64x2_ln, f64x2_mlaf, f64x2_select};
+use std::arch::wasm32::*;
+
+/// Computes ln|Γ(x)| and the sign of Γ(x), error bound *ULP 2.5* for positive arguments
+#[inline]
+pub fn f64x2_lgamma(d: v128) -> (v128, v128) {
+    let x = f64x2_abs(d);
+    // lnΓ(x) = (x - 0.5) ln(x) - x + ln(sqrt(2pi)) + 1/x S(1/x^2) for x >= 10
+    let e = f64x2_stirlingk((x, f64x2_splat(0f64)));
+    let mut big = f64x2_add(e.0, f64x2_add(e.1, f64x2_splat(LN_SQRT_2PI_D)));
+    // Everything past the leading terms of Stirling series is below an ulp
+    big = f64x2_select(
+        f64x2_gt(x, f64x2_splat(1152921504606846976f64)),
+        f64x2_mul(x, f64x2_sub(f64x2_ln(x), f64x2_splat(1f64))),
+        big,
+    );
+    // lnΓ(x) = lnΓ(2 + z) + ln((x - 1)...(x - n + 2)), z = x - n in [-0.5, 0.5] for x >= 1.75
+    let mid = f64x2_ge(x, f64x2_splat(1.75f64));
+    let n = f64x2_nearest(x);
+    // Below 0.75 lnΓ(x) = lnΓ(x + 1) - ln(x), z is taken against shifted centers so x + 1 is never rounded
+    let shift = f64x2_lt(x, f64x2_splat(0.75f64));
+    let near_zero = v128_or(
+        f64x2_lt(x, f64x2_splat(0.25f64)),
+        v128_and(
+            f64x2_ge(x, f64x2_splat(0.75f64)),
+            f64x2_lt(x, f64x2_splat(1.25f64)),
+        ),
+    );
+    let z = f64x2_select(
+        mid,
+        f64x2_sub(x, n),
+        f64x2_select(shift, x, f64x2_sub(x, f64x2_splat(1f64))),
+    );
+    let pick = |a: f64, b: f64| f64x2_select(mid, f64x2_splat(b), f64x2_splat(a));
+    let mut u = pick(LGAMMA_A_POLY_19_D, LGAMMA_B_POLY_19_D);
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_18_D, LGAMMA_B_POLY_18_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_17_D, LGAMMA_B_POLY_17_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_16_D, LGAMMA_B_POLY_16_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_15_D, LGAMMA_B_POLY_15_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_14_D, LGAMMA_B_POLY_14_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_13_D, LGAMMA_B_POLY_13_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_12_D, LGAMMA_B_POLY_12_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_11_D, LGAMMA_B_POLY_11_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_10_D, LGAMMA_B_POLY_10_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_9_D, LGAMMA_B_POLY_9_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_8_D, LGAMMA_B_POLY_8_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_7_D, LGAMMA_B_POLY_7_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_6_D, LGAMMA_B_POLY_6_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_5_D, LGAMMA_B_POLY_5_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_4_D, LGAMMA_B_POLY_4_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_3_D, LGAMMA_B_POLY_3_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_2_D, LGAMMA_B_POLY_2_D));
+    u = f64x2_mlaf(u, z, pick(LGAMMA_A_POLY_1_D, LGAMMA_B_POLY_1_D));
+    let r = f64x2_mul(z, u);
+    // Around the minimum lnΓ(x0 + z) = lnΓ(x0) + z^2 H(z)
+    let zh = f64x2_select(
+        shift,
+        f64x2_sub(
+            f64x2_sub(x, f64x2_splat(DIGAMMA_ROOT_M1_D)),
+            f64x2_splat(DIGAMMA_ROOT_M1_LO_D),
+        ),
+        f64x2_sub(
+            f64x2_sub(x, f64x2_splat(DIGAMMA_ROOT_D)),
+            f64x2_splat(DIGAMMA_ROOT_LO_D),
+        ),
+    );
+    let mut h = f64x2_splat(LGAMMA_H_POLY_15_D);
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_14_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_13_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_12_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_11_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_10_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_9_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_8_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_7_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_6_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_5_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_4_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_3_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_2_D));
+    h = f64x2_mlaf(h, zh, f64x2_splat(LGAMMA_H_POLY_1_D));
+    let m = f64x2_add(
+        f64x2_splat(LGAMMA_MIN_D),
+        f64x2_mlaf(f64x2_mul(zh, zh), h, f64x2_splat(LGAMMA_MIN_LO_D)),
+    );
+    let r = f64x2_select(v128_or(mid, near_zero), r, m);
+    // The recurrence product, x itself when shifted and 1 elsewhere
+    let mut q = (f64x2_select(shift, x, f64x2_splat(1f64)), f64x2_splat(0f64));
+    let k_max = f64x2_sub(n, f64x2_splat(2f64));
+    for k in 1..9 {
+        let kf = f64x2_splat(k as f64);
+        let f = f64x2_select(f64x2_le(kf, k_max), f64x2_sub(x, kf), f64x2_splat(1f64));
+        q = f64x2_mul_doubled(q, (f, f64x2_splat(0f64)));
+    }
+    let l = f64x2_lnk_doubled(q);
+    let small = f64x2_select(
+        shift,
+        f64x2_sub(f64x2_sub(r, l.0), l.1),
+        f64x2_add(l.0, f64x2_add(r, l.1)),
+    );
+    let k = f64x2_select(f64x2_ge(x, f64x2_splat(10f64)), big, small);
+    // |Γ(d)| = pi / (|sin(pi d)| |d| Γ(|d|)) for negative d
+    let sp = f64x2_sinpik(d);
+    let reflect = f64x2_lt(d, f64x2_splat(0f64));
+    let mut ret = f64x2_select(
+        reflect,
+        f64x2_sub(
+            f64x2_sub(f64x2_splat(LN_PI_D), f64x2_ln(f64x2_abs(f64x2_mul(sp, d)))),
+            k,
+        ),
+        k,
+    );
+    let mut sign = f64x2_select(
+        v128_and(reflect, f64x2_lt(sp, f64x2_splat(0f64))),
+        f64x2_splat(-1f64),
+        f64x2_splat(1f64),
+    );
+    // lnΓ(d) = -ln|d| - γd + O(d^2)
+    let tiny = f64x2_lt(x, f64x2_splat(5.551115123125783e-17f64));
+    ret = f64x2_select(tiny, f64x2_neg(f64x2_ln(x)), ret);
+    sign = f64x2_select(tiny, f64x2_copysign(f64x2_splat(1f64), d), sign);
+    // Poles, this also covers every d <= -2^52 and d == -Inf
+    let pole = v128_andnot(reflect, f64x2_isnotintegral(d));
+    ret = f64x2_select(pole, f64x2_splat(f64::INFINITY), ret);
+    sign = f64x2_select(pole, f64x2_splat(1f64), sign);
+    (ret, sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lgammad() {
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let (comparison, sign) = f64x2_lgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.5723649429247001f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+            assert_eq!(f64x2_extract_lane::<0>(sign), 1.);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-0.5f64);
+            let (comparison, sign) = f64x2_lgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1.2655121234846454f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+            assert_eq!(f64x2_extract_lane::<0>(sign), -1.);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-3.5f64);
+            let (comparison, sign) = f64x2_lgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = -1.309006684993042f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+            assert_eq!(f64x2_extract_lane::<0>(sign), 1.);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(100f64);
+            let (comparison, sign) = f64x2_lgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 359.1342053695754f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+            assert_eq!(f64x2_extract_lane::<0>(sign), 1.);
+        }
+
+        {
+            // Test exact value
+            let value = f64x2_splat(1f64);
+            let (comparison, sign) = f64x2_lgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+            assert_eq!(f64x2_extract_lane::<0>(sign), 1.);
+        }
+
+        {
+            // Test pole
+            let value = f64x2_splat(-2f64);
+            let (comparison, _) = f64x2_lgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        {
+            // Test NaN
+            let value = f64x2_splat(f64::NAN);
+            let (comparison, _) = f64x2_lgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,202 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::digammaf::{DIGAMMA_ROOT_LO_S, DIGAMMA_ROOT_M1_LO_S, DIGAMMA_ROOT_M1_S, DIGAMMA_ROOT_S};
+use crate::lgammaf::{
+    LGAMMA_A_POLY_1_S, LGAMMA_A_POLY_2_S, LGAMMA_A_POLY_3_S, LGAMMA_A_POLY_4_S, LGAMMA_A_POLY_5_S,
+    LGAMMA_A_POLY_6_S, LGAMMA_A_POLY_7_S, LGAMMA_A_POLY_8_S, LGAMMA_A_POLY_9_S, LGAMMA_B_POLY_1_S,
+    LGAMMA_B_POLY_2_S, LGAMMA_B_POLY_3_S, LGAMMA_B_POLY_4_S, LGAMMA_B_POLY_5_S, LGAMMA_B_POLY_6_S,
+    LGAMMA_B_POLY_7_S, LGAMMA_B_POLY_8_S, LGAMMA_B_POLY_9_S, LGAMMA_H_POLY_1_S, LGAMMA_H_POLY_2_S,
+    LGAMMA_H_POLY_3_S, LGAMMA_H_POLY_4_S, LGAMMA_H_POLY_5_S, LGAMMA_H_POLY_6_S, LGAMMA_H_POLY_7_S,
+    LGAMMA_MIN_LO_S, LGAMMA_MIN_S, LN_PI_S, LN_SQRT_2PI_S,
+};
+use crate::wasm::generalf::f32x4_mul_doubled;
+use crate::wasm::lnf::f32x4_lnk_doubled;
+use crate::wasm::sinf::f32x4_sinpik;
+use crate::wasm::tgammaf::f32x4_stirlingk;
+use crate::{f32x4_copysign, f32x4_isnotintegral, f32x4_ln, f32x4_mlaf, f32x4_select};
+use std::arch::wasm32::*;
+
+/// Computes ln|Γ(x)| and the sign of Γ(x), error bound *ULP 2.5* for positive arguments
+#[inline]
+pub fn f32x4_lgamma(d: v128) -> (v128, v128) {
+    let x = f32x4_abs(d);
+    // lnΓ(x) = (x - 0.5) ln(x) - x + ln(sqrt(2pi)) + 1/x S(1/x^2) for x >= 5
+    let e = f32x4_stirlingk((x, f32x4_splat(0f32)));
+    let mut big = f32x4_add(e.0, f32x4_add(e.1, f32x4_splat(LN_SQRT_2PI_S)));
+    // Everything past the leading terms of Stirling series is below an ulp
+    big = f32x4_select(
+        f32x4_gt(x, f32x4_splat(1073741824f32)),
+        f32x4_mul(x, f32x4_sub(f32x4_ln(x), f32x4_splat(1f32))),
+        big,
+    );
+    // lnΓ(x) = lnΓ(2 + z) + ln((x - 1)...(x - n + 2)), z = x - n in [-0.5, 0.5] for x >= 1.75
+    let mid = f32x4_ge(x, f32x4_splat(1.75f32));
+    let n = f32x4_nearest(x);
+    // Below 0.75 lnΓ(x) = lnΓ(x + 1) - ln(x), z is taken against shifted centers so x + 1 is never rounded
+    let shift = f32x4_lt(x, f32x4_splat(0.75f32));
+    let near_zero = v128_or(
+        f32x4_lt(x, f32x4_splat(0.25f32)),
+        v128_and(
+            f32x4_ge(x, f32x4_splat(0.75f32)),
+            f32x4_lt(x, f32x4_splat(1.25f32)),
+        ),
+    );
+    let z = f32x4_select(
+        mid,
+        f32x4_sub(x, n),
+        f32x4_select(shift, x, f32x4_sub(x, f32x4_splat(1f32))),
+    );
+    let pick = |a: f32, b: f32| f32x4_select(mid, f32x4_splat(b), f32x4_splat(a));
+    let mut u = pick(LGAMMA_A_POLY_9_S, LGAMMA_B_POLY_9_S);
+    u = f32x4_mlaf(u, z, pick(LGAMMA_A_POLY_8_S, LGAMMA_B_POLY_8_S));
+    u = f32x4_mlaf(u, z, pick(LGAMMA_A_POLY_7_S, LGAMMA_B_POLY_7_S));
+    u = f32x4_mlaf(u, z, pick(LGAMMA_A_POLY_6_S, LGAMMA_B_POLY_6_S));
+    u = f32x4_mlaf(u, z, pick(LGAMMA_A_POLY_5_S, LGAMMA_B_POLY_5_S));
+    u = f32x4_mlaf(u, z, pick(LGAMMA_A_POLY_4_S, LGAMMA_B_POLY_4_S));
+    u = f32x4_mlaf(u, z, pick(LGAMMA_A_POLY_3_S, LGAMMA_B_POLY_3_S));
+    u = f32x4_mlaf(u, z, pick(LGAMMA_A_POLY_2_S, LGAMMA_B_POLY_2_S));
+    u = f32x4_mlaf(u, z, pick(LGAMMA_A_POLY_1_S, LGAMMA_B_POLY_1_S));
+    let r = f32x4_mul(z, u);
+    // Around the minimum lnΓ(x0 + z) = lnΓ(x0) + z^2 H(z)
+    let zh = f32x4_select(
+        shift,
+        f32x4_sub(
+            f32x4_sub(x, f32x4_splat(DIGAMMA_ROOT_M1_S)),
+            f32x4_splat(DIGAMMA_ROOT_M1_LO_S),
+        ),
+        f32x4_sub(
+            f32x4_sub(x, f32x4_splat(DIGAMMA_ROOT_S)),
+            f32x4_splat(DIGAMMA_ROOT_LO_S),
+        ),
+    );
+    let mut h = f32x4_splat(LGAMMA_H_POLY_7_S);
+    h = f32x4_mlaf(h, zh, f32x4_splat(LGAMMA_H_POLY_6_S));
+    h = f32x4_mlaf(h, zh, f32x4_splat(LGAMMA_H_POLY_5_S));
+    h = f32x4_mlaf(h, zh, f32x4_splat(LGAMMA_H_POLY_4_S));
+    h = f32x4_mlaf(h, zh, f32x4_splat(LGAMMA_H_POLY_3_S));
+    h = f32x4_mlaf(h, zh, f32x4_splat(LGAMMA_H_POLY_2_S));
+    h = f32x4_mlaf(h, zh, f32x4_splat(LGAMMA_H_POLY_1_S));
+    let m = f32x4_add(
+        f32x4_splat(LGAMMA_MIN_S),
+        f32x4_mlaf(f32x4_mul(zh, zh), h, f32x4_splat(LGAMMA_MIN_LO_S)),
+    );
+    let r = f32x4_select(v128_or(mid, near_zero), r, m);
+    // The recurrence product, x itself when shifted and 1 elsewhere
+    let mut q = (f32x4_select(shift, x, f32x4_splat(1f32)), f32x4_splat(0f32));
+    let k_max = f32x4_sub(n, f32x4_splat(2f32));
+    for k in 1..4 {
+        let kf = f32x4_splat(k as f32);
+        let f = f32x4_select(f32x4_le(kf, k_max), f32x4_sub(x, kf), f32x4_splat(1f32));
+        q = f32x4_mul_doubled(q, (f, f32x4_splat(0f32)));
+    }
+    let l = f32x4_lnk_doubled(q);
+    let small = f32x4_select(
+        shift,
+        f32x4_sub(f32x4_sub(r, l.0), l.1),
+        f32x4_add(l.0, f32x4_add(r, l.1)),
+    );
+    let k = f32x4_select(f32x4_ge(x, f32x4_splat(5f32)), big, small);
+    // |Γ(d)| = pi / (|sin(pi d)| |d| Γ(|d|)) for negative d
+    let sp = f32x4_sinpik(d);
+    let reflect = f32x4_lt(d, f32x4_splat(0f32));
+    let mut ret = f32x4_select(
+        reflect,
+        f32x4_sub(
+            f32x4_sub(f32x4_splat(LN_PI_S), f32x4_ln(f32x4_abs(f32x4_mul(sp, d)))),
+            k,
+        ),
+        k,
+    );
+    let mut sign = f32x4_select(
+        v128_and(reflect, f32x4_lt(sp, f32x4_splat(0f32))),
+        f32x4_splat(-1f32),
+        f32x4_splat(1f32),
+    );
+    // lnΓ(d) = -ln|d| - γd + O(d^2)
+    let tiny = f32x4_lt(x, f32x4_splat(2.9802322e-8f32));
+    ret = f32x4_select(tiny, f32x4_neg(f32x4_ln(x)), ret);
+    sign = f32x4_select(tiny, f32x4_copysign(f32x4_splat(1f32), d), sign);
+    // Poles, this also covers every d <= -2^23 and d == -Inf
+    let pole = v128_andnot(reflect, f32x4_isnotintegral(d));
+    ret = f32x4_select(pole, f32x4_splat(f32::INFINITY), ret);
+    sign = f32x4_select(pole, f32x4_splat(1f32), sign);
+    (ret, sign)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lgammaf() {
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let (comparison, sign) = f32x4_lgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.5723649f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+            assert_eq!(f32x4_extract_lane::<0>(sign), 1.);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-0.5f32);
+            let (comparison, sign) = f32x4_lgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1.2655121f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+            assert_eq!(f32x4_extract_lane::<0>(sign), -1.);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-3.5f32);
+            let (comparison, sign) = f32x4_lgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = -1.3090067f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+            assert_eq!(f32x4_extract_lane::<0>(sign), 1.);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(100f32);
+            let (comparison, sign) = f32x4_lgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 359.13422f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+            assert_eq!(f32x4_extract_lane::<0>(sign), 1.);
+        }
+
+        {
+            // Test exact value
+            let value = f32x4_splat(1f32);
+            let (comparison, sign) = f32x4_lgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 0.);
+            assert_eq!(f32x4_extract_lane::<0>(sign), 1.);
+        }
+
+        {
+            // Test pole
+            let value = f32x4_splat(-2f32);
+            let (comparison, _) = f32x4_lgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        {
+            // Test NaN
+            let value = f32x4_splat(f32::NAN);
+            let (comparison, _) = f32x4_lgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
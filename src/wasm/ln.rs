@@ -0,0 +1,149 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::exp::{L2_L, L2_U};
+use crate::ln::{
+    LN_DOUBLED_POLY_1_D, LN_DOUBLED_POLY_2_D, LN_DOUBLED_POLY_3_D, LN_DOUBLED_POLY_4_D,
+    LN_DOUBLED_POLY_5_D, LN_DOUBLED_POLY_6_D, LN_DOUBLED_POLY_7_D, LN_DOUBLED_POLY_8_D,
+    TWO_THIRDS_D, TWO_THIRDS_LO_D,
+};
+use crate::ln::{
+    LN_POLY_1_D, LN_POLY_2_D, LN_POLY_3_D, LN_POLY_4_D, LN_POLY_5_D, LN_POLY_6_D, LN_POLY_7_D,
+    LN_POLY_8_D,
+};
+use crate::log2::LOG_SCALE_D;
+use crate::wasm::general::{f64x2_mul_doubled, f64x2_multiply_as_doubled, f64x2_sum_as_doubled};
+use crate::{
+    f64x2_convert_i64x2, f64x2_eqzero, f64x2_ilogb2k, f64x2_isinf, f64x2_isnan, f64x2_ldexp3k,
+    f64x2_ltzero, f64x2_mlaf, f64x2_select,
+};
+
+/// Computes ln(a) for reduced argument a in [0.75, 1.5)
+#[inline]
+pub(crate) fn f64x2_lnk(a: v128) -> v128 {
+    let ones = f64x2_splat(1.);
+    let x = f64x2_div(f64x2_sub(a, ones), f64x2_add(a, ones));
+    let x2 = f64x2_mul(x, x);
+    let mut u = f64x2_splat(LN_POLY_8_D);
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_POLY_7_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_POLY_6_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_POLY_5_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_POLY_4_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_POLY_3_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_POLY_2_D));
+    // Leading 2x term is exact, keeping it apart saves a rounding
+    f64x2_mlaf(f64x2_mul(x, x2), u, f64x2_mul(x, f64x2_splat(LN_POLY_1_D)))
+}
+
+/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
+#[inline]
+pub fn f64x2_ln_fast(d: v128) -> v128 {
+    let n = f64x2_ilogb2k(f64x2_mul(d, f64x2_splat(1. / 0.75)));
+    let a = f64x2_ldexp3k(d, i64x2_neg(n));
+    f64x2_mlaf(
+        f64x2_splat(std::f64::consts::LN_2),
+        f64x2_convert_i64x2(n),
+        f64x2_lnk(a),
+    )
+}
+
+/// Computes natural logarithm for an argument *ULP 1.5*
+#[inline]
+pub fn f64x2_ln(d: v128) -> v128 {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = f64x2_lt(d, f64x2_splat(f64::MIN_POSITIVE));
+    let x = f64x2_select(o, f64x2_mul(d, f64x2_splat(LOG_SCALE_D)), d);
+    let n = f64x2_ilogb2k(f64x2_mul(x, f64x2_splat(1. / 0.75)));
+    let a = f64x2_ldexp3k(x, i64x2_neg(n));
+    let e = f64x2_sub(
+        f64x2_convert_i64x2(n),
+        f64x2_select(o, f64x2_splat(64f64), f64x2_splat(0f64)),
+    );
+    let mut res = f64x2_mlaf(f64x2_splat(std::f64::consts::LN_2), e, f64x2_lnk(a));
+    // d == 0 || d == Inf -> Inf
+    res = f64x2_select(f64x2_eqzero(d), f64x2_splat(f64::NEG_INFINITY), res);
+    res = f64x2_select(f64x2_isinf(d), f64x2_splat(f64::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = f64x2_select(
+        v128_or(f64x2_ltzero(d), f64x2_isnan(d)),
+        f64x2_splat(f64::NAN),
+        res,
+    );
+    res
+}
+
+/// Computes ln(d.0 + d.1) as double-float for positive normal d
+#[inline]
+pub(crate) fn f64x2_lnk_doubled(d: (v128, v128)) -> (v128, v128) {
+    let n = f64x2_ilogb2k(f64x2_mul(d.0, f64x2_splat(1f64 / 0.75f64)));
+    let a = f64x2_ldexp3k(d.0, i64x2_neg(n));
+    // x = (a - 1) / (a + 1) split into hi and lo, a - 1 is exact
+    let num = f64x2_sub(a, f64x2_splat(1f64));
+    let den = f64x2_sum_as_doubled(a, f64x2_splat(1f64));
+    let x = f64x2_div(num, den.0);
+    let m = f64x2_multiply_as_doubled(x, den.0);
+    let x_lo = f64x2_div(
+        f64x2_sub(f64x2_sub(f64x2_sub(num, m.0), m.1), f64x2_mul(x, den.1)),
+        den.0,
+    );
+    // ln(a) = 2x + 2x^3/3 + x^5 R(x^2), the cubic term is too large to be rounded once
+    let x3 = f64x2_mul_doubled(f64x2_multiply_as_doubled(x, x), (x, x_lo));
+    let t = f64x2_mul_doubled(
+        x3,
+        (f64x2_splat(TWO_THIRDS_D), f64x2_splat(TWO_THIRDS_LO_D)),
+    );
+    let x2 = f64x2_mul(x, x);
+    let mut u = f64x2_splat(LN_DOUBLED_POLY_8_D);
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_DOUBLED_POLY_7_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_DOUBLED_POLY_6_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_DOUBLED_POLY_5_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_DOUBLED_POLY_4_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_DOUBLED_POLY_3_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_DOUBLED_POLY_2_D));
+    u = f64x2_mlaf(u, x2, f64x2_splat(LN_DOUBLED_POLY_1_D));
+    let nf = f64x2_convert_i64x2(n);
+    let b = f64x2_sum_as_doubled(f64x2_add(x, x), t.0);
+    let h = f64x2_sum_as_doubled(f64x2_mul(nf, f64x2_splat(L2_U)), b.0);
+    let tail = f64x2_mlaf(
+        f64x2_mul(f64x2_mul(x2, x2), x),
+        u,
+        f64x2_mlaf(nf, f64x2_splat(L2_L), f64x2_div(d.1, d.0)),
+    );
+    (
+        h.0,
+        f64x2_add(
+            h.1,
+            f64x2_add(f64x2_add(f64x2_add(b.1, t.1), f64x2_add(x_lo, x_lo)), tail),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lnd() {
+        {
+            let comparison = f64x2_ln(f64x2_splat(2.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - std::f64::consts::LN_2).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_ln(f64x2_splat(0.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+        {
+            let comparison = f64x2_ln(f64x2_splat(-1.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
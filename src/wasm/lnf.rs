@@ -0,0 +1,138 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::expf::{L2L_F, L2U_F};
+use crate::lnf::{
+    LN_DOUBLED_POLY_1_S, LN_DOUBLED_POLY_2_S, LN_DOUBLED_POLY_3_S, LN_DOUBLED_POLY_4_S,
+    TWO_THIRDS_LO_S, TWO_THIRDS_S,
+};
+use crate::lnf::{LN_POLY_1_F, LN_POLY_2_F, LN_POLY_3_F, LN_POLY_4_F, LN_POLY_5_F};
+use crate::log2f::LOG_SCALE_F;
+use crate::wasm::generalf::{f32x4_mul_doubled, f32x4_multiply_as_doubled, f32x4_sum_as_doubled};
+use crate::{
+    f32x4_eqzero, f32x4_ilogb2k, f32x4_isinf, f32x4_isnan, f32x4_ldexp3k, f32x4_ltzero, f32x4_mlaf,
+    f32x4_select,
+};
+
+/// Computes ln(a) for reduced argument a in [0.75, 1.5)
+#[inline]
+pub(crate) fn f32x4_lnk(a: v128) -> v128 {
+    let ones = f32x4_splat(1.);
+    let x = f32x4_div(f32x4_sub(a, ones), f32x4_add(a, ones));
+    let x2 = f32x4_mul(x, x);
+    let mut u = f32x4_splat(LN_POLY_5_F);
+    u = f32x4_mlaf(u, x2, f32x4_splat(LN_POLY_4_F));
+    u = f32x4_mlaf(u, x2, f32x4_splat(LN_POLY_3_F));
+    u = f32x4_mlaf(u, x2, f32x4_splat(LN_POLY_2_F));
+    // Leading 2x term is exact, keeping it apart saves a rounding
+    f32x4_mlaf(f32x4_mul(x, x2), u, f32x4_mul(x, f32x4_splat(LN_POLY_1_F)))
+}
+
+/// Method that computes ln skipping Inf, Nan checks, error bound *ULP 1.5*
+#[inline]
+pub fn f32x4_ln_fast(d: v128) -> v128 {
+    let n = f32x4_ilogb2k(f32x4_mul(d, f32x4_splat(1. / 0.75)));
+    let a = f32x4_ldexp3k(d, i32x4_neg(n));
+    f32x4_mlaf(
+        f32x4_splat(std::f32::consts::LN_2),
+        f32x4_convert_i32x4(n),
+        f32x4_lnk(a),
+    )
+}
+
+/// Computes natural logarithm for an argument *ULP 1.5*
+#[inline]
+pub fn f32x4_ln(d: v128) -> v128 {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = f32x4_lt(d, f32x4_splat(f32::MIN_POSITIVE));
+    let x = f32x4_select(o, f32x4_mul(d, f32x4_splat(LOG_SCALE_F)), d);
+    let n = f32x4_ilogb2k(f32x4_mul(x, f32x4_splat(1. / 0.75)));
+    let a = f32x4_ldexp3k(x, i32x4_neg(n));
+    let e = f32x4_sub(
+        f32x4_convert_i32x4(n),
+        f32x4_select(o, f32x4_splat(64f32), f32x4_splat(0f32)),
+    );
+    let mut res = f32x4_mlaf(f32x4_splat(std::f32::consts::LN_2), e, f32x4_lnk(a));
+    // d == 0 || d == Inf -> Inf
+    res = f32x4_select(f32x4_eqzero(d), f32x4_splat(f32::NEG_INFINITY), res);
+    res = f32x4_select(f32x4_isinf(d), f32x4_splat(f32::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = f32x4_select(
+        v128_or(f32x4_ltzero(d), f32x4_isnan(d)),
+        f32x4_splat(f32::NAN),
+        res,
+    );
+    res
+}
+
+/// Computes ln(d.0 + d.1) as double-float for positive normal d
+#[inline]
+pub(crate) fn f32x4_lnk_doubled(d: (v128, v128)) -> (v128, v128) {
+    let n = f32x4_ilogb2k(f32x4_mul(d.0, f32x4_splat(1f32 / 0.75f32)));
+    let a = f32x4_ldexp3k(d.0, i32x4_neg(n));
+    // x = (a - 1) / (a + 1) split into hi and lo, a - 1 is exact
+    let num = f32x4_sub(a, f32x4_splat(1f32));
+    let den = f32x4_sum_as_doubled(a, f32x4_splat(1f32));
+    let x = f32x4_div(num, den.0);
+    let m = f32x4_multiply_as_doubled(x, den.0);
+    let x_lo = f32x4_div(
+        f32x4_sub(f32x4_sub(f32x4_sub(num, m.0), m.1), f32x4_mul(x, den.1)),
+        den.0,
+    );
+    // ln(a) = 2x + 2x^3/3 + x^5 R(x^2), the cubic term is too large to be rounded once
+    let x3 = f32x4_mul_doubled(f32x4_multiply_as_doubled(x, x), (x, x_lo));
+    let t = f32x4_mul_doubled(
+        x3,
+        (f32x4_splat(TWO_THIRDS_S), f32x4_splat(TWO_THIRDS_LO_S)),
+    );
+    let x2 = f32x4_mul(x, x);
+    let mut u = f32x4_splat(LN_DOUBLED_POLY_4_S);
+    u = f32x4_mlaf(u, x2, f32x4_splat(LN_DOUBLED_POLY_3_S));
+    u = f32x4_mlaf(u, x2, f32x4_splat(LN_DOUBLED_POLY_2_S));
+    u = f32x4_mlaf(u, x2, f32x4_splat(LN_DOUBLED_POLY_1_S));
+    let nf = f32x4_convert_i32x4(n);
+    let b = f32x4_sum_as_doubled(f32x4_add(x, x), t.0);
+    let h = f32x4_sum_as_doubled(f32x4_mul(nf, f32x4_splat(L2U_F)), b.0);
+    let tail = f32x4_mlaf(
+        f32x4_mul(f32x4_mul(x2, x2), x),
+        u,
+        f32x4_mlaf(nf, f32x4_splat(L2L_F), f32x4_div(d.1, d.0)),
+    );
+    (
+        h.0,
+        f32x4_add(
+            h.1,
+            f32x4_add(f32x4_add(f32x4_add(b.1, t.1), f32x4_add(x_lo, x_lo)), tail),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lnf() {
+        {
+            let comparison = f32x4_ln(f32x4_splat(2.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - std::f32::consts::LN_2).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_ln(f32x4_splat(0.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+        {
+            let comparison = f32x4_ln(f32x4_splat(-1.));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
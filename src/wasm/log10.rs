@@ -0,0 +1,108 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::log2::LOG_SCALE_D;
+use crate::wasm::ln::f64x2_lnk;
+use crate::{
+    f64x2_convert_i64x2, f64x2_ilogb2k, f64x2_isinf, f64x2_isnan, f64x2_ldexp3k, f64x2_mlaf,
+    f64x2_select,
+};
+
+/// Computes log10 for an argument *ULP 3.5*
+#[inline]
+pub fn f64x2_log10(d: v128) -> v128 {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = f64x2_lt(d, f64x2_splat(f64::MIN_POSITIVE));
+    let x = f64x2_select(o, f64x2_mul(d, f64x2_splat(LOG_SCALE_D)), d);
+    let n = f64x2_ilogb2k(f64x2_mul(x, f64x2_splat(1f64 / 0.75f64)));
+    let a = f64x2_ldexp3k(x, i64x2_neg(n));
+    let e = f64x2_sub(
+        f64x2_convert_i64x2(n),
+        f64x2_select(o, f64x2_splat(64f64), f64x2_splat(0f64)),
+    );
+    let mut res = f64x2_mlaf(
+        f64x2_lnk(a),
+        f64x2_splat(std::f64::consts::LOG10_E),
+        f64x2_mul(e, f64x2_splat(std::f64::consts::LOG10_2)),
+    );
+    // d == 0 -> -Inf
+    res = f64x2_select(
+        f64x2_eq(d, f64x2_splat(0f64)),
+        f64x2_splat(f64::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = f64x2_select(f64x2_isinf(d), f64x2_splat(f64::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = f64x2_select(
+        v128_or(f64x2_lt(d, f64x2_splat(0f64)), f64x2_isnan(d)),
+        f64x2_splat(f64::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log10d() {
+        {
+            // Test regular
+            let value = f64x2_splat(1000f64);
+            let comparison = f64x2_log10(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 3.;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(0.2f64);
+            let comparison = f64x2_log10(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.2f64.log10();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test subnormal
+            let value = f64x2_splat(f64::from_bits(1));
+            let comparison = f64x2_log10(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = f64::from_bits(1).log10();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test pole
+            let value = f64x2_splat(0f64);
+            let comparison = f64x2_log10(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+
+        {
+            // Test domain error
+            let value = f64x2_splat(-1f64);
+            let comparison = f64x2_log10(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        {
+            // Test infinity
+            let value = f64x2_splat(f64::INFINITY);
+            let comparison = f64x2_log10(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::wasm::ln::f64x2_lnk;
+use crate::{
+    f64x2_convert_i64x2, f64x2_ilogb2k, f64x2_isinf, f64x2_isnan, f64x2_ldexp3k, f64x2_mlaf,
+    f64x2_select,
+};
+
+/// Computes ln(1 + x), accurate for x near zero *ULP 2.5*
+#[inline]
+pub fn f64x2_log1p(d: v128) -> v128 {
+    let dp1 = f64x2_add(d, f64x2_splat(1f64));
+    let n = f64x2_ilogb2k(f64x2_mul(dp1, f64x2_splat(1f64 / 0.75f64)));
+    let a = f64x2_ldexp3k(dp1, i64x2_neg(n));
+    // c is the rounding error of 1 + d relative to it
+    let c = f64x2_div(f64x2_sub(d, f64x2_sub(dp1, f64x2_splat(1f64))), dp1);
+    let mut res = f64x2_mlaf(
+        f64x2_splat(std::f64::consts::LN_2),
+        f64x2_convert_i64x2(n),
+        f64x2_add(f64x2_lnk(a), c),
+    );
+    // d == 0 -> d, keeps the sign of zero
+    res = f64x2_select(f64x2_eq(d, f64x2_splat(0f64)), d, res);
+    // d == -1 -> -Inf
+    res = f64x2_select(
+        f64x2_eq(d, f64x2_splat(-1f64)),
+        f64x2_splat(f64::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = f64x2_select(f64x2_isinf(d), f64x2_splat(f64::INFINITY), res);
+    // d < -1 || d == Nan -> Nan
+    res = f64x2_select(
+        v128_or(f64x2_lt(d, f64x2_splat(-1f64)), f64x2_isnan(d)),
+        f64x2_splat(f64::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log1pd() {
+        {
+            // Test regular
+            let value = f64x2_splat(1e-10f64);
+            let comparison = f64x2_log1p(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1e-10f64.ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_log1p(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.5f64.ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-0.3f64);
+            let comparison = f64x2_log1p(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = (-0.3f64).ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test pole
+            let value = f64x2_splat(-1f64);
+            let comparison = f64x2_log1p(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::NEG_INFINITY);
+        }
+
+        {
+            // Test domain error
+            let value = f64x2_splat(-2f64);
+            let comparison = f64x2_log1p(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        {
+            // Test infinity
+            let value = f64x2_splat(f64::INFINITY);
+            let comparison = f64x2_log1p(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::wasm::lnf::f32x4_lnk;
+use crate::{f32x4_ilogb2k, f32x4_isinf, f32x4_isnan, f32x4_ldexp3k, f32x4_mlaf, f32x4_select};
+
+/// Computes ln(1 + x), accurate for x near zero *ULP 4.5*
+#[inline]
+pub fn f32x4_log1p(d: v128) -> v128 {
+    let dp1 = f32x4_add(d, f32x4_splat(1f32));
+    let n = f32x4_ilogb2k(f32x4_mul(dp1, f32x4_splat(1f32 / 0.75f32)));
+    let a = f32x4_ldexp3k(dp1, i32x4_neg(n));
+    // c is the rounding error of 1 + d relative to it
+    let c = f32x4_div(f32x4_sub(d, f32x4_sub(dp1, f32x4_splat(1f32))), dp1);
+    let mut res = f32x4_mlaf(
+        f32x4_splat(std::f32::consts::LN_2),
+        f32x4_convert_i32x4(n),
+        f32x4_add(f32x4_lnk(a), c),
+    );
+    // d == 0 -> d, keeps the sign of zero
+    res = f32x4_select(f32x4_eq(d, f32x4_splat(0f32)), d, res);
+    // d == -1 -> -Inf
+    res = f32x4_select(
+        f32x4_eq(d, f32x4_splat(-1f32)),
+        f32x4_splat(f32::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = f32x4_select(f32x4_isinf(d), f32x4_splat(f32::INFINITY), res);
+    // d < -1 || d == Nan -> Nan
+    res = f32x4_select(
+        v128_or(f32x4_lt(d, f32x4_splat(-1f32)), f32x4_isnan(d)),
+        f32x4_splat(f32::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log1pf() {
+        {
+            // Test regular
+            let value = f32x4_splat(1e-6f32);
+            let comparison = f32x4_log1p(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1e-6f32.ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_log1p(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.5f32.ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-0.3f32);
+            let comparison = f32x4_log1p(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = (-0.3f32).ln_1p();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test pole
+            let value = f32x4_splat(-1f32);
+            let comparison = f32x4_log1p(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+
+        {
+            // Test domain error
+            let value = f32x4_splat(-2f32);
+            let comparison = f32x4_log1p(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        {
+            // Test infinity
+            let value = f32x4_splat(f32::INFINITY);
+            let comparison = f32x4_log1p(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::log2f::LOG_SCALE_F;
+use crate::wasm::lnf::f32x4_lnk;
+use crate::{f32x4_ilogb2k, f32x4_isinf, f32x4_isnan, f32x4_ldexp3k, f32x4_mlaf, f32x4_select};
+
+/// Computes log2 for an argument *ULP 5.5*
+#[inline]
+pub fn f32x4_log2(d: v128) -> v128 {
+    // Subnormals are scaled to normal range before the exponent split
+    let o = f32x4_lt(d, f32x4_splat(f32::MIN_POSITIVE));
+    let x = f32x4_select(o, f32x4_mul(d, f32x4_splat(LOG_SCALE_F)), d);
+    let n = f32x4_ilogb2k(f32x4_mul(x, f32x4_splat(1f32 / 0.75f32)));
+    let a = f32x4_ldexp3k(x, i32x4_neg(n));
+    let e = f32x4_sub(
+        f32x4_convert_i32x4(n),
+        f32x4_select(o, f32x4_splat(64f32), f32x4_splat(0f32)),
+    );
+    let mut res = f32x4_mlaf(f32x4_lnk(a), f32x4_splat(std::f32::consts::LOG2_E), e);
+    // d == 0 -> -Inf
+    res = f32x4_select(
+        f32x4_eq(d, f32x4_splat(0f32)),
+        f32x4_splat(f32::NEG_INFINITY),
+        res,
+    );
+    // d == Inf -> Inf
+    res = f32x4_select(f32x4_isinf(d), f32x4_splat(f32::INFINITY), res);
+    // d < 0 || d == Nan -> Nan
+    res = f32x4_select(
+        v128_or(f32x4_lt(d, f32x4_splat(0f32)), f32x4_isnan(d)),
+        f32x4_splat(f32::NAN),
+        res,
+    );
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log2f() {
+        {
+            // Test regular
+            let value = f32x4_splat(10f32);
+            let comparison = f32x4_log2(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 10f32.log2();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(8f32);
+            let comparison = f32x4_log2(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 3.);
+        }
+
+        {
+            // Test subnormal
+            let value = f32x4_splat(f32::from_bits(1));
+            let comparison = f32x4_log2(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, -149.);
+        }
+
+        {
+            // Test pole
+            let value = f32x4_splat(0f32);
+            let comparison = f32x4_log2(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+
+        {
+            // Test domain error
+            let value = f32x4_splat(-1f32);
+            let comparison = f32x4_log2(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        {
+            // Test infinity
+            let value = f32x4_splat(f32::INFINITY);
+            let comparison = f32x4_log2(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+    }
+}
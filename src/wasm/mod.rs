@@ -0,0 +1,190 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+mod acos;
+mod acosf;
+mod acosh;
+mod acoshf;
+mod asin;
+mod asinf;
+mod asinh;
+mod asinhf;
+mod atan;
+mod atan2;
+mod atan2f;
+mod atanf;
+mod atanh;
+mod atanhf;
+mod cbrt;
+mod cbrtf;
+mod cos;
+mod cosf;
+mod cosh;
+mod coshf;
+mod digamma;
+mod digammaf;
+mod erf;
+mod erfc;
+mod erfcf;
+mod erff;
+mod erfinv;
+mod erfinvf;
+mod exp;
+mod exp10;
+mod exp10f;
+mod exp2;
+mod exp2f;
+mod expf;
+mod expm1;
+mod expm1f;
+mod fmod;
+mod fmodf;
+mod general;
+mod generalf;
+mod hypot;
+mod hypot3;
+mod hypot3f;
+mod hypot4;
+mod hypot4f;
+mod hypotf;
+mod lgamma;
+mod lgammaf;
+mod ln;
+mod lnf;
+mod log10;
+mod log10f;
+mod log1p;
+mod log1pf;
+mod log2;
+mod log2f;
+mod pow;
+mod powf;
+mod precision;
+mod sin;
+mod sincos;
+mod sincosf;
+mod sinf;
+mod sinh;
+mod sinhf;
+mod tan;
+mod tanf;
+mod tanh;
+mod tanhf;
+mod tgamma;
+mod tgammaf;
+
+pub use acos::f64x2_acos;
+pub use acosf::f32x4_acos;
+pub use acosh::f64x2_acosh;
+pub use acoshf::f32x4_acosh;
+pub use asin::f64x2_asin;
+pub use asinf::f32x4_asin;
+pub use asinh::f64x2_asinh;
+pub use asinhf::f32x4_asinh;
+pub use atan::f64x2_atan;
+pub use atan2::f64x2_atan2;
+pub use atan2f::f32x4_atan2;
+pub use atanf::f32x4_atan;
+pub use atanh::f64x2_atanh;
+pub use atanhf::f32x4_atanh;
+pub use cbrt::f64x2_cbrt;
+pub use cbrt::f64x2_cbrt_fast;
+pub use cbrtf::f32x4_cbrt;
+pub use cbrtf::f32x4_cbrt_fast;
+pub use cos::f64x2_cos;
+pub use cosf::f32x4_cos;
+pub use cosh::f64x2_cosh;
+pub use coshf::f32x4_cosh;
+pub use digamma::f64x2_digamma;
+pub use digammaf::f32x4_digamma;
+pub use erf::f64x2_erf;
+pub use erfc::f64x2_erfc;
+pub use erfcf::f32x4_erfc;
+pub use erff::f32x4_erf;
+pub use erfinv::f64x2_erfinv;
+pub use erfinvf::f32x4_erfinv;
+pub use exp::f64x2_exp;
+pub use exp::f64x2_exp_fast;
+pub use exp10::f64x2_exp10;
+pub use exp10f::f32x4_exp10;
+pub use exp2::f64x2_exp2;
+pub use exp2f::f32x4_exp2;
+pub use expf::f32x4_exp;
+pub use expf::f32x4_exp_fast;
+pub use expm1::f64x2_expm1;
+pub use expm1f::f32x4_expm1;
+pub use fmod::f64x2_fmod;
+pub use fmodf::f32x4_fmod;
+pub use general::f64x2_convert_i64x2;
+pub use general::f64x2_copysign;
+pub use general::f64x2_eqzero;
+pub use general::f64x2_ilogb2k;
+pub use general::f64x2_isinf;
+pub use general::f64x2_isnan;
+pub use general::f64x2_isneginf;
+pub use general::f64x2_isnotintegral;
+pub use general::f64x2_ldexp2k;
+pub use general::f64x2_ldexp3k;
+pub use general::f64x2_ltzero;
+pub use general::f64x2_mlaf;
+pub use general::f64x2_rint;
+pub use general::f64x2_select;
+pub use general::i64x2_pow2i;
+pub use generalf::f32x4_copysign;
+pub use generalf::f32x4_eqzero;
+pub use generalf::f32x4_ilogb2k;
+pub use generalf::f32x4_isinf;
+pub use generalf::f32x4_isnan;
+pub use generalf::f32x4_isneginf;
+pub use generalf::f32x4_isnotintegral;
+pub use generalf::f32x4_ldexp2k;
+pub use generalf::f32x4_ldexp3k;
+pub use generalf::f32x4_ltzero;
+pub use generalf::f32x4_mlaf;
+pub use generalf::f32x4_rint;
+pub use generalf::f32x4_select;
+pub use generalf::i32x4_pow2if;
+pub use hypot::f64x2_hypot;
+pub use hypot::f64x2_hypot_fast;
+pub use hypot3::f64x2_hypot3;
+pub use hypot3::f64x2_hypot3_fast;
+pub use hypot3f::f32x4_hypot3;
+pub use hypot3f::f32x4_hypot3_fast;
+pub use hypot4::f64x2_hypot4;
+pub use hypot4::f64x2_hypot4_fast;
+pub use hypot4f::f32x4_hypot4;
+pub use hypot4f::f32x4_hypot4_fast;
+pub use hypotf::f32x4_hypot;
+pub use hypotf::f32x4_hypot_fast;
+pub use lgamma::f64x2_lgamma;
+pub use lgammaf::f32x4_lgamma;
+pub use ln::f64x2_ln;
+pub use ln::f64x2_ln_fast;
+pub use lnf::f32x4_ln;
+pub use lnf::f32x4_ln_fast;
+pub use log10::f64x2_log10;
+pub use log10f::f32x4_log10;
+pub use log1p::f64x2_log1p;
+pub use log1pf::f32x4_log1p;
+pub use log2::f64x2_log2;
+pub use log2f::f32x4_log2;
+pub use pow::f64x2_pow;
+pub use pow::f64x2_pow_fast;
+pub use powf::f32x4_pow;
+pub use powf::f32x4_pow_fast;
+pub use sin::f64x2_sin;
+pub use sincos::f64x2_sincos;
+pub use sincosf::f32x4_sincos;
+pub use sinf::f32x4_sin;
+pub use sinh::f64x2_sinh;
+pub use sinhf::f32x4_sinh;
+pub use tan::f64x2_tan;
+pub use tanf::f32x4_tan;
+pub use tanh::f64x2_tanh;
+pub use tanhf::f32x4_tanh;
+pub use tgamma::f64x2_tgamma;
+pub use tgammaf::f32x4_tgamma;
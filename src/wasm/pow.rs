@@ -0,0 +1,69 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{
+    f64x2_copysign, f64x2_exp, f64x2_exp_fast, f64x2_isinf, f64x2_isnan, f64x2_isnotintegral,
+    f64x2_ln, f64x2_ln_fast, f64x2_select,
+};
+
+#[inline]
+/// Computes pow function *ULP 2.0*
+pub fn f64x2_pow(d: v128, n: v128) -> v128 {
+    let c = f64x2_exp(f64x2_mul(n, f64x2_ln(f64x2_abs(d))));
+    // Sign of the base is kept only for odd integral powers
+    let is_odd = v128_andnot(
+        f64x2_isnotintegral(f64x2_mul(n, f64x2_splat(0.5))),
+        f64x2_isnotintegral(n),
+    );
+    let mut ret = f64x2_select(is_odd, f64x2_copysign(c, d), c);
+    // Not integral powers are not allowed for negative finite numbers
+    let is_neg_finite = v128_and(
+        f64x2_lt(d, f64x2_splat(0.)),
+        f64x2_gt(d, f64x2_splat(f64::NEG_INFINITY)),
+    );
+    let is_nan_with_integral = v128_and(is_neg_finite, f64x2_isnotintegral(n));
+    let is_any_nan = v128_or(
+        v128_or(f64x2_isnan(d), f64x2_isnan(n)),
+        is_nan_with_integral,
+    );
+    ret = f64x2_select(is_any_nan, f64x2_splat(f64::NAN), ret);
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN, pow(-1, ±inf) = 1
+    let ones = f64x2_splat(1.);
+    let is_one = v128_or(
+        v128_or(f64x2_eq(n, f64x2_splat(0.)), f64x2_eq(d, ones)),
+        v128_and(f64x2_eq(f64x2_abs(d), ones), f64x2_isinf(n)),
+    );
+    f64x2_select(is_one, ones, ret)
+}
+
+/// Method that computes pow skipping Inf, Nan checks, *ULP 2.0*
+#[inline]
+pub fn f64x2_pow_fast(d: v128, n: v128) -> v128 {
+    let c = f64x2_exp_fast(f64x2_mul(n, f64x2_ln_fast(d)));
+    f64x2_copysign(c, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powd() {
+        {
+            let comparison = f64x2_pow(f64x2_splat(2.), f64x2_splat(3.5));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - (2f64).powf(3.5)).abs() < 1e-13);
+        }
+        {
+            let comparison = f64x2_pow(f64x2_splat(-2.), f64x2_splat(0.5));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,69 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{
+    f32x4_copysign, f32x4_exp, f32x4_exp_fast, f32x4_isinf, f32x4_isnan, f32x4_isnotintegral,
+    f32x4_ln, f32x4_ln_fast, f32x4_select,
+};
+
+#[inline]
+/// Computes pow function *ULP 2.0*
+pub fn f32x4_pow(d: v128, n: v128) -> v128 {
+    let c = f32x4_exp(f32x4_mul(n, f32x4_ln(f32x4_abs(d))));
+    // Sign of the base is kept only for odd integral powers
+    let is_odd = v128_andnot(
+        f32x4_isnotintegral(f32x4_mul(n, f32x4_splat(0.5))),
+        f32x4_isnotintegral(n),
+    );
+    let mut ret = f32x4_select(is_odd, f32x4_copysign(c, d), c);
+    // Not integral powers are not allowed for negative finite numbers
+    let is_neg_finite = v128_and(
+        f32x4_lt(d, f32x4_splat(0.)),
+        f32x4_gt(d, f32x4_splat(f32::NEG_INFINITY)),
+    );
+    let is_nan_with_integral = v128_and(is_neg_finite, f32x4_isnotintegral(n));
+    let is_any_nan = v128_or(
+        v128_or(f32x4_isnan(d), f32x4_isnan(n)),
+        is_nan_with_integral,
+    );
+    ret = f32x4_select(is_any_nan, f32x4_splat(f32::NAN), ret);
+    // pow(x, ±0) = 1 and pow(1, y) = 1 even for NaN, pow(-1, ±inf) = 1
+    let ones = f32x4_splat(1.);
+    let is_one = v128_or(
+        v128_or(f32x4_eq(n, f32x4_splat(0.)), f32x4_eq(d, ones)),
+        v128_and(f32x4_eq(f32x4_abs(d), ones), f32x4_isinf(n)),
+    );
+    f32x4_select(is_one, ones, ret)
+}
+
+/// Method that computes pow skipping Inf, Nan checks, *ULP 2.0*
+#[inline]
+pub fn f32x4_pow_fast(d: v128, n: v128) -> v128 {
+    let c = f32x4_exp_fast(f32x4_mul(n, f32x4_ln_fast(d)));
+    f32x4_copysign(c, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_powf() {
+        {
+            let comparison = f32x4_pow(f32x4_splat(2.), f32x4_splat(3.5));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - (2f32).powf(3.5)).abs() < 1e-5);
+        }
+        {
+            let comparison = f32x4_pow(f32x4_splat(-2.), f32x4_splat(0.5));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
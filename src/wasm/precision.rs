@@ -0,0 +1,147 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+use std::arch::wasm32::*;
+
+use crate::precision::simd_math_impl;
+
+/// Evaluates f32 lanes with an f64 kernel, so result is rounded only once
+macro_rules! widen_ps {
+    ($f:path, ($x:ident)) => {{
+        let lo = $f(f64x2_promote_low_f32x4($x));
+        let hi = $f(f64x2_promote_low_f32x4(i64x2_shuffle::<1, 1>($x, $x)));
+        i64x2_shuffle::<0, 2>(f32x4_demote_f64x2_zero(lo), f32x4_demote_f64x2_zero(hi))
+    }};
+    ($f:path, ($x:ident, $y:ident)) => {{
+        let lo = $f(f64x2_promote_low_f32x4($x), f64x2_promote_low_f32x4($y));
+        let hi = $f(
+            f64x2_promote_low_f32x4(i64x2_shuffle::<1, 1>($x, $x)),
+            f64x2_promote_low_f32x4(i64x2_shuffle::<1, 1>($y, $y)),
+        );
+        i64x2_shuffle::<0, 2>(f32x4_demote_f64x2_zero(lo), f32x4_demote_f64x2_zero(hi))
+    }};
+}
+
+simd_math_impl! {
+    v128, f64,
+    exp(x) => crate::f64x2_exp, fast: crate::f64x2_exp_fast;
+    exp2(x) => crate::f64x2_exp2;
+    exp10(x) => crate::f64x2_exp10;
+    expm1(x) => crate::f64x2_expm1;
+    ln(x) => crate::f64x2_ln, fast: crate::f64x2_ln_fast;
+    log2(x) => crate::f64x2_log2;
+    log10(x) => crate::f64x2_log10;
+    log1p(x) => crate::f64x2_log1p;
+    sin(x) => crate::f64x2_sin;
+    cos(x) => crate::f64x2_cos;
+    tan(x) => crate::f64x2_tan;
+    asin(x) => crate::f64x2_asin;
+    acos(x) => crate::f64x2_acos;
+    atan(x) => crate::f64x2_atan;
+    sinh(x) => crate::f64x2_sinh;
+    cosh(x) => crate::f64x2_cosh;
+    tanh(x) => crate::f64x2_tanh;
+    asinh(x) => crate::f64x2_asinh;
+    acosh(x) => crate::f64x2_acosh;
+    atanh(x) => crate::f64x2_atanh;
+    cbrt(x) => crate::f64x2_cbrt, fast: crate::f64x2_cbrt_fast;
+    erf(x) => crate::f64x2_erf;
+    erfc(x) => crate::f64x2_erfc;
+    erfinv(x) => crate::f64x2_erfinv;
+    tgamma(x) => crate::f64x2_tgamma;
+    digamma(x) => crate::f64x2_digamma;
+    pow(x, n) => crate::f64x2_pow, fast: crate::f64x2_pow_fast;
+    atan2(y, x) => crate::f64x2_atan2;
+    hypot(x, y) => crate::f64x2_hypot, fast: crate::f64x2_hypot_fast;
+    fmod(x, y) => crate::f64x2_fmod;
+    hypot3(x, y, z) => crate::f64x2_hypot3, fast: crate::f64x2_hypot3_fast;
+    hypot4(x, y, z, w) => crate::f64x2_hypot4, fast: crate::f64x2_hypot4_fast;
+}
+
+simd_math_impl! {
+    v128, f32,
+    exp(x) => crate::f32x4_exp, fast: crate::f32x4_exp_fast, high: crate::f64x2_exp, cr: crate::cr::expf;
+    exp2(x) => crate::f32x4_exp2, high: crate::f64x2_exp2, cr: crate::cr::exp2f;
+    exp10(x) => crate::f32x4_exp10, high: crate::f64x2_exp10, cr: crate::cr::exp10f;
+    expm1(x) => crate::f32x4_expm1, high: crate::f64x2_expm1;
+    ln(x) => crate::f32x4_ln, fast: crate::f32x4_ln_fast, high: crate::f64x2_ln, cr: crate::cr::lnf;
+    log2(x) => crate::f32x4_log2, high: crate::f64x2_log2, cr: crate::cr::log2f;
+    log10(x) => crate::f32x4_log10, high: crate::f64x2_log10, cr: crate::cr::log10f;
+    log1p(x) => crate::f32x4_log1p, high: crate::f64x2_log1p;
+    sin(x) => crate::f32x4_sin, high: crate::f64x2_sin, cr: crate::cr::sinf;
+    cos(x) => crate::f32x4_cos, high: crate::f64x2_cos, cr: crate::cr::cosf;
+    tan(x) => crate::f32x4_tan, high: crate::f64x2_tan, cr: crate::cr::tanf;
+    asin(x) => crate::f32x4_asin, high: crate::f64x2_asin, cr: crate::cr::asinf;
+    acos(x) => crate::f32x4_acos, high: crate::f64x2_acos, cr: crate::cr::acosf;
+    atan(x) => crate::f32x4_atan, high: crate::f64x2_atan, cr: crate::cr::atanf;
+    sinh(x) => crate::f32x4_sinh, high: crate::f64x2_sinh;
+    cosh(x) => crate::f32x4_cosh, high: crate::f64x2_cosh;
+    tanh(x) => crate::f32x4_tanh, high: crate::f64x2_tanh;
+    asinh(x) => crate::f32x4_asinh, high: crate::f64x2_asinh;
+    acosh(x) => crate::f32x4_acosh, high: crate::f64x2_acosh;
+    atanh(x) => crate::f32x4_atanh, high: crate::f64x2_atanh;
+    cbrt(x) => crate::f32x4_cbrt, fast: crate::f32x4_cbrt_fast, high: crate::f64x2_cbrt, cr: crate::cr::cbrtf;
+    erf(x) => crate::f32x4_erf, high: crate::f64x2_erf;
+    erfc(x) => crate::f32x4_erfc, high: crate::f64x2_erfc;
+    erfinv(x) => crate::f32x4_erfinv, high: crate::f64x2_erfinv;
+    tgamma(x) => crate::f32x4_tgamma, high: crate::f64x2_tgamma;
+    digamma(x) => crate::f32x4_digamma, high: crate::f64x2_digamma;
+    pow(x, n) => crate::f32x4_pow, fast: crate::f32x4_pow_fast, high: crate::f64x2_pow, cr: crate::cr::powf;
+    atan2(y, x) => crate::f32x4_atan2, high: crate::f64x2_atan2;
+    hypot(x, y) => crate::f32x4_hypot, fast: crate::f32x4_hypot_fast, high: crate::f64x2_hypot;
+    fmod(x, y) => crate::f32x4_fmod;
+    hypot3(x, y, z) => crate::f32x4_hypot3, fast: crate::f32x4_hypot3_fast;
+    hypot4(x, y, z, w) => crate::f32x4_hypot4, fast: crate::f32x4_hypot4_fast;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::precision::{CorrectlyRounded, Fast, HighAccuracy, SimdMath, Standard};
+    use crate::{f32x4_exp, f32x4_exp_fast, f64x2_sin};
+
+    #[test]
+    fn test_tiers() {
+        unsafe {
+            let value = f32x4_splat(3.5f32);
+            let fast = f32x4_extract_lane::<0>(<Fast as SimdMath<v128, f32>>::exp(value));
+            assert_eq!(fast, f32x4_extract_lane::<0>(f32x4_exp_fast(value)));
+            let standard = f32x4_extract_lane::<0>(<Standard as SimdMath<v128, f32>>::exp(value));
+            assert_eq!(standard, f32x4_extract_lane::<0>(f32x4_exp(value)));
+            let value = f64x2_splat(0.7f64);
+            let high = f64x2_extract_lane::<0>(<HighAccuracy as SimdMath<v128, f64>>::sin(value));
+            assert_eq!(high, f64x2_extract_lane::<0>(f64x2_sin(value)));
+            let value = f32x4_splat(0.7f32);
+            let correct = <CorrectlyRounded as SimdMath<v128, f32>>::sin(value);
+            assert_eq!(f32x4_extract_lane::<3>(correct), crate::cr::sinf(0.7f32));
+        }
+    }
+
+    #[test]
+    fn test_high_accuracy_ps() {
+        for i in 0..2000 {
+            let x = i as f32 * 0.0123f32 - 12.3f32;
+            let value = f32x4_replace_lane::<3>(f32x4_splat(1f32), x);
+            let value = f32x4_replace_lane::<1>(value, x);
+            unsafe {
+                let sin = <HighAccuracy as SimdMath<v128, f32>>::sin(value);
+                let exp = <HighAccuracy as SimdMath<v128, f32>>::exp(value);
+                for (result, control) in [
+                    (f32x4_extract_lane::<1>(sin), (x as f64).sin() as f32),
+                    (f32x4_extract_lane::<3>(sin), (x as f64).sin() as f32),
+                    (f32x4_extract_lane::<1>(exp), (x as f64).exp() as f32),
+                    (f32x4_extract_lane::<3>(exp), (x as f64).exp() as f32),
+                ] {
+                    assert!(ulp(result, control) <= 1, "{} = {}", x, result);
+                }
+            }
+        }
+    }
+
+    fn ulp(a: f32, b: f32) -> i32 {
+        (a.to_bits() as i32).wrapping_sub(b.to_bits() as i32).abs()
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::f64x2_copysign;
+use crate::generalf::rempi;
+use crate::sin::{
+    PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
+    SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
+};
+use crate::wasm::general::f64x2_multiply_as_doubled;
+use crate::wasm::general::f64x2_rempi;
+use crate::{f64x2_convert_i64x2, f64x2_eqzero, f64x2_mlaf, f64x2_rint, f64x2_select};
+
+#[inline]
+/// Computes sine function with *ULP 2.5*
+pub fn f64x2_sin(d: v128) -> v128 {
+    let q = f64x2_rint(f64x2_mul(d, f64x2_splat(std::f64::consts::FRAC_1_PI)));
+    let qf = f64x2_convert_i64x2(q);
+
+    let mut r = f64x2_mlaf(qf, f64x2_splat(-PI_A2), d);
+    r = f64x2_mlaf(qf, f64x2_splat(-PI_B2), r);
+    let (q, mut r) = f64x2_rempi(d, q, r, rempi);
+
+    let ones = i64x2_splat(1);
+    let is_odd = i64x2_eq(v128_and(q, ones), ones);
+    r = f64x2_select(is_odd, f64x2_neg(r), r);
+    // sin(±0) = ±0
+    f64x2_select(f64x2_eqzero(d), d, f64x2_sink(r))
+}
+
+/// Evaluates sine polynomial for reduced |r| <= pi/2
+#[inline]
+pub(crate) fn f64x2_sink(r: v128) -> v128 {
+    let x2 = f64x2_mul(r, r);
+    let mut res = f64x2_splat(SIN_POLY_10_D);
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_9_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_8_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_7_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_6_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_5_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_4_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_3_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_2_D));
+    res = f64x2_mlaf(res, f64x2_mul(x2, r), r);
+    res
+}
+
+/// Computes sin(pi*x) for |x| < 2^52, integers give exact zeros
+#[inline]
+pub(crate) fn f64x2_sinpik(x: v128) -> v128 {
+    // Period is 2 so the reduction is exact, then folding into [-0.5, 0.5] by sin(pi - y) = sin(y)
+    let mut r = f64x2_sub(
+        x,
+        f64x2_mul(
+            f64x2_splat(2f64),
+            f64x2_nearest(f64x2_mul(x, f64x2_splat(0.5f64))),
+        ),
+    );
+    r = f64x2_select(
+        f64x2_gt(f64x2_abs(r), f64x2_splat(0.5f64)),
+        f64x2_sub(f64x2_copysign(f64x2_splat(1f64), r), r),
+        r,
+    );
+    // pi * r is kept as double-float, sin(y + lo) = sin(y) + lo * cos(y) with cos(y) ~ 1 - y^2/2
+    let y = f64x2_multiply_as_doubled(r, f64x2_splat(PI_A2));
+    let lo = f64x2_mlaf(r, f64x2_splat(PI_B2), y.1);
+    f64x2_mlaf(
+        lo,
+        f64x2_mlaf(f64x2_mul(f64x2_splat(-0.5f64), y.0), y.0, f64x2_splat(1f64)),
+        f64x2_sink(y.0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sind() {
+        {
+            let comparison = f64x2_sin(f64x2_splat(2.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 0.9092974268256817f64).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_sin(f64x2_splat(-2.));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 + 0.9092974268256817f64).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_sind_large() {
+        for x in [1e6f64, -1e22, 3.5e300] {
+            let value = f64x2_extract_lane::<0>(f64x2_sin(f64x2(x, 2.)));
+            let control = x.sin();
+            assert!((value - control).abs() <= 2. * f64::EPSILON * control.abs());
+        }
+    }
+}
@@ -0,0 +1,73 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::generalf::rempi2;
+use crate::sin::{
+    PI_A2, PI_B2, SIN_POLY_10_D, SIN_POLY_2_D, SIN_POLY_3_D, SIN_POLY_4_D, SIN_POLY_5_D,
+    SIN_POLY_6_D, SIN_POLY_7_D, SIN_POLY_8_D, SIN_POLY_9_D,
+};
+use crate::wasm::general::f64x2_rempi;
+use crate::{f64x2_convert_i64x2, f64x2_eqzero, f64x2_mlaf, f64x2_rint, f64x2_select};
+
+#[inline]
+fn f64x2_sin_poly(r: v128) -> v128 {
+    let x2 = f64x2_mul(r, r);
+    let mut res = f64x2_splat(SIN_POLY_10_D);
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_9_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_8_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_7_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_6_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_5_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_4_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_3_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(SIN_POLY_2_D));
+    f64x2_mlaf(res, f64x2_mul(x2, r), r)
+}
+
+#[inline]
+/// Computes sine and cosine with a single argument reduction, returns `(sin, cos)`, *ULP 1.5*
+pub fn f64x2_sincos(d: v128) -> (v128, v128) {
+    let q = f64x2_rint(f64x2_mul(d, f64x2_splat(std::f64::consts::FRAC_2_PI)));
+    let qf = f64x2_convert_i64x2(q);
+
+    let mut r = f64x2_mlaf(qf, f64x2_splat(-PI_A2 * 0.5), d);
+    r = f64x2_mlaf(qf, f64x2_splat(-PI_B2 * 0.5), r);
+    let (q, r) = f64x2_rempi(d, q, r, rempi2);
+
+    // cos(r) = 1 - 2*sin(r/2)^2, |r| <= π/4
+    let s = f64x2_sin_poly(r);
+    let h = f64x2_sin_poly(f64x2_mul(r, f64x2_splat(0.5)));
+    let c = f64x2_mlaf(f64x2_mul(h, f64x2_splat(-2.)), h, f64x2_splat(1.));
+
+    let is_odd = i64x2_ne(v128_and(q, i64x2_splat(1)), i64x2_splat(0));
+    let sin = f64x2_select(is_odd, c, s);
+    let cos = f64x2_select(is_odd, s, c);
+
+    let sin_sign = i64x2_shl(v128_and(q, i64x2_splat(2)), 62);
+    let cos_sign = i64x2_shl(v128_and(i64x2_add(q, i64x2_splat(1)), i64x2_splat(2)), 62);
+    // sin(±0) = ±0
+    let sin = f64x2_select(f64x2_eqzero(d), d, v128_xor(sin, sin_sign));
+    (sin, v128_xor(cos, cos_sign))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sincosd() {
+        for x in [0f64, -2.70752239, 2., 0.75, -5.5, 14.9, 1e6, -1e22, 3.5e300] {
+            let (sin, cos) = f64x2_sincos(f64x2(x, 1.));
+            let (sin, cos) = (f64x2_extract_lane::<0>(sin), f64x2_extract_lane::<0>(cos));
+            let (control_sin, control_cos) = (x.sin(), x.cos());
+            assert!((sin - control_sin).abs() <= 2. * f64::EPSILON * control_sin.abs());
+            assert!((cos - control_cos).abs() <= 2. * f64::EPSILON * control_cos.abs());
+        }
+    }
+}
@@ -0,0 +1,69 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2f;
+use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+use crate::wasm::generalf::f32x4_rempi;
+use crate::{f32x4_eqzero, f32x4_mlaf, f32x4_rint, f32x4_select};
+
+#[inline]
+fn f32x4_sin_poly(r: v128) -> v128 {
+    let x2 = f32x4_mul(r, r);
+    let mut res = f32x4_splat(SIN_POLY_5_S);
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_4_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_3_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_2_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_1_S));
+    f32x4_mlaf(res, f32x4_mul(x2, r), r)
+}
+
+#[inline]
+/// Computes sine and cosine with a single argument reduction, returns `(sin, cos)`, *ULP 2.0*
+pub fn f32x4_sincos(d: v128) -> (v128, v128) {
+    let q = f32x4_rint(f32x4_mul(d, f32x4_splat(std::f32::consts::FRAC_2_PI)));
+    let qf = f32x4_convert_i32x4(q);
+
+    let mut r = f32x4_mlaf(qf, f32x4_splat(-PI_A_F * 0.5), d);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_B_F * 0.5), r);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_C_F * 0.5), r);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_D_F * 0.5), r);
+    let (q, r) = f32x4_rempi(d, q, r, rempi2f);
+
+    // cos(r) = 1 - 2*sin(r/2)^2, |r| <= π/4
+    let s = f32x4_sin_poly(r);
+    let h = f32x4_sin_poly(f32x4_mul(r, f32x4_splat(0.5)));
+    let c = f32x4_mlaf(f32x4_mul(h, f32x4_splat(-2.)), h, f32x4_splat(1.));
+
+    let is_odd = i32x4_ne(v128_and(q, i32x4_splat(1)), i32x4_splat(0));
+    let sin = f32x4_select(is_odd, c, s);
+    let cos = f32x4_select(is_odd, s, c);
+
+    let sin_sign = i32x4_shl(v128_and(q, i32x4_splat(2)), 30);
+    let cos_sign = i32x4_shl(v128_and(i32x4_add(q, i32x4_splat(1)), i32x4_splat(2)), 30);
+    // sin(±0) = ±0
+    let sin = f32x4_select(f32x4_eqzero(d), d, v128_xor(sin, sin_sign));
+    (sin, v128_xor(cos, cos_sign))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sincosf() {
+        for x in [0f32, -2.70752239, 2., 0.75, -5.5, 124.9, 1e4, -1e22, 3.0e38] {
+            let (sin, cos) = f32x4_sincos(f32x4(x, 1., -0.5, 2.));
+            let (sin, cos) = (f32x4_extract_lane::<0>(sin), f32x4_extract_lane::<0>(cos));
+            let (control_sin, control_cos) = ((x as f64).sin() as f32, (x as f64).cos() as f32);
+            assert!((sin - control_sin).abs() <= 2. * f32::EPSILON * control_sin.abs());
+            assert!((cos - control_cos).abs() <= 2. * f32::EPSILON * control_cos.abs());
+        }
+    }
+}
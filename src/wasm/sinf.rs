@@ -0,0 +1,103 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::f32x4_copysign;
+use crate::generalf::rempif;
+use crate::sinf::{PI_LO_S, PI_S};
+use crate::sinf::{SIN_POLY_1_S, SIN_POLY_2_S, SIN_POLY_3_S, SIN_POLY_4_S, SIN_POLY_5_S};
+use crate::wasm::generalf::f32x4_multiply_as_doubled;
+use crate::wasm::generalf::f32x4_rempi;
+use crate::{f32x4_eqzero, f32x4_mlaf, f32x4_rint, f32x4_select};
+
+/// Computes sine function with error bound *ULP 2.5*
+#[inline]
+pub fn f32x4_sin(d: v128) -> v128 {
+    let q = f32x4_rint(f32x4_mul(d, f32x4_splat(std::f32::consts::FRAC_1_PI)));
+    let qf = f32x4_convert_i32x4(q);
+
+    let mut r = f32x4_mlaf(qf, f32x4_splat(-PI_A_F), d);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_B_F), r);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_C_F), r);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_D_F), r);
+    let (q, mut r) = f32x4_rempi(d, q, r, rempif);
+
+    let ones = i32x4_splat(1);
+    let is_odd = i32x4_eq(v128_and(q, ones), ones);
+    r = f32x4_select(is_odd, f32x4_neg(r), r);
+    // sin(±0) = ±0
+    f32x4_select(f32x4_eqzero(d), d, f32x4_sink(r))
+}
+
+/// Evaluates sine polynomial for reduced |r| <= pi/2
+#[inline]
+pub(crate) fn f32x4_sink(r: v128) -> v128 {
+    let x2 = f32x4_mul(r, r);
+    let mut res = f32x4_splat(SIN_POLY_5_S);
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_4_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_3_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_2_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(SIN_POLY_1_S));
+    res = f32x4_mlaf(res, f32x4_mul(x2, r), r);
+    res
+}
+
+/// Computes sin(pi*x) for |x| < 2^23, integers give exact zeros
+#[inline]
+pub(crate) fn f32x4_sinpik(x: v128) -> v128 {
+    // Period is 2 so the reduction is exact, then folding into [-0.5, 0.5] by sin(pi - y) = sin(y)
+    let mut r = f32x4_sub(
+        x,
+        f32x4_mul(
+            f32x4_splat(2f32),
+            f32x4_nearest(f32x4_mul(x, f32x4_splat(0.5f32))),
+        ),
+    );
+    r = f32x4_select(
+        f32x4_gt(f32x4_abs(r), f32x4_splat(0.5f32)),
+        f32x4_sub(f32x4_copysign(f32x4_splat(1f32), r), r),
+        r,
+    );
+    // pi * r is kept as double-float, sin(y + lo) = sin(y) + lo * cos(y) with cos(y) ~ 1 - y^2/2
+    let y = f32x4_multiply_as_doubled(r, f32x4_splat(PI_S));
+    let lo = f32x4_mlaf(r, f32x4_splat(PI_LO_S), y.1);
+    f32x4_mlaf(
+        lo,
+        f32x4_mlaf(f32x4_mul(f32x4_splat(-0.5f32), y.0), y.0, f32x4_splat(1f32)),
+        f32x4_sink(y.0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sinf() {
+        {
+            let comparison = f32x4_sin(f32x4_splat(2f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 0.9092974f32).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_sin(f32x4_splat(-2f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 + 0.9092974f32).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_sinf_large() {
+        for x in [1e4f32, -1e22, 3.0e38] {
+            let value = f32x4_extract_lane::<0>(f32x4_sin(f32x4(x, 2., -0.5, 1.)));
+            let control = (x as f64).sin() as f32;
+            assert!((value - control).abs() <= 2. * f32::EPSILON * control.abs());
+        }
+    }
+}
@@ -0,0 +1,80 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_copysign, f32x4_exp, f32x4_expm1, f32x4_select};
+
+/// Computes hyperbolic sine, error bound *ULP 3.5*
+#[inline]
+pub fn f32x4_sinh(d: v128) -> v128 {
+    let a = f32x4_abs(d);
+    let e = f32x4_expm1(a);
+    let mut r = f32x4_mul(
+        f32x4_splat(0.5f32),
+        f32x4_add(e, f32x4_div(e, f32x4_add(e, f32x4_splat(1f32)))),
+    );
+    // exp(|d|) / 2 overflows before sinh does, split it as exp(|d|/2)^2 / 2
+    let h = f32x4_exp(f32x4_mul(a, f32x4_splat(0.5f32)));
+    r = f32x4_select(
+        f32x4_gt(a, f32x4_splat(88f32)),
+        f32x4_mul(f32x4_mul(h, f32x4_splat(0.5f32)), h),
+        r,
+    );
+    f32x4_copysign(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sinhf() {
+        {
+            // Test regular
+            let value = f32x4_splat(1.5f32);
+            let comparison = f32x4_sinh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1.5f32.sinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-1e-10f32);
+            let comparison = f32x4_sinh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = (-1e-10f32).sinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(89f32);
+            let comparison = f32x4_sinh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 89f32.sinh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test overflow
+            let value = f32x4_splat(100f32);
+            let comparison = f32x4_sinh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        {
+            // Test overflow
+            let value = f32x4_splat(-100f32);
+            let comparison = f32x4_sinh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::NEG_INFINITY);
+        }
+    }
+}
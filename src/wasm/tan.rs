@@ -0,0 +1,83 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::generalf::rempi2;
+use crate::sin::{PI_A2, PI_B2};
+use crate::tan::{
+    TAN_POLY_1_D, TAN_POLY_2_D, TAN_POLY_3_D, TAN_POLY_4_D, TAN_POLY_5_D, TAN_POLY_6_D,
+    TAN_POLY_7_D, TAN_POLY_8_D, TAN_POLY_9_D,
+};
+use crate::wasm::general::f64x2_rempi;
+use crate::{f64x2_convert_i64x2, f64x2_mlaf, f64x2_rint, f64x2_select};
+
+#[inline]
+/// Computes tan function with error bound *ULP 4.0*
+pub fn f64x2_tan(d: v128) -> v128 {
+    let q = f64x2_rint(f64x2_mul(d, f64x2_splat(std::f64::consts::FRAC_2_PI)));
+    let qf = f64x2_convert_i64x2(q);
+
+    let mut r = f64x2_mlaf(qf, f64x2_splat(-PI_A2 * 0.5), d);
+    r = f64x2_mlaf(qf, f64x2_splat(-PI_B2 * 0.5), r);
+    let (q, mut r) = f64x2_rempi(d, q, r, rempi2);
+
+    let ones = i64x2_splat(1);
+    let is_odd = i64x2_eq(v128_and(q, ones), ones);
+    r = f64x2_select(is_odd, f64x2_neg(r), r);
+
+    r = f64x2_mul(r, f64x2_splat(0.5));
+
+    let x2 = f64x2_mul(r, r);
+
+    let mut res = f64x2_splat(TAN_POLY_9_D);
+    res = f64x2_mlaf(res, x2, f64x2_splat(TAN_POLY_8_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(TAN_POLY_7_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(TAN_POLY_6_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(TAN_POLY_5_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(TAN_POLY_4_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(TAN_POLY_3_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(TAN_POLY_2_D));
+    res = f64x2_mlaf(res, x2, f64x2_splat(TAN_POLY_1_D));
+    res = f64x2_mlaf(res, f64x2_mul(x2, r), r);
+
+    res = f64x2_div(
+        f64x2_mul(f64x2_splat(2.0), res),
+        f64x2_sub(f64x2_splat(1.0), f64x2_mul(res, res)),
+    );
+
+    res = f64x2_select(is_odd, f64x2_div(f64x2_splat(1.), res), res);
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tand() {
+        {
+            let comparison = f64x2_tan(f64x2_splat(0.5));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 - 0.5463024898437905f64).abs() < 1e-15);
+        }
+        {
+            let comparison = f64x2_tan(f64x2_splat(-1.2));
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!((flag_1 + 2.5721516221263188f64).abs() < 1e-14);
+        }
+    }
+
+    #[test]
+    fn test_tand_large() {
+        for x in [1e6f64, -1e22, 3.5e300] {
+            let value = f64x2_extract_lane::<0>(f64x2_tan(f64x2(x, 2.)));
+            let control = x.tan();
+            assert!((value - control).abs() <= 4. * f64::EPSILON * control.abs());
+        }
+    }
+}
@@ -0,0 +1,78 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::cosf::{PI_A_F, PI_B_F, PI_C_F, PI_D_F};
+use crate::generalf::rempi2f;
+use crate::tanf::{
+    TAN_POLY_1_S, TAN_POLY_2_S, TAN_POLY_3_S, TAN_POLY_4_S, TAN_POLY_5_S, TAN_POLY_6_S,
+    TAN_POLY_7_S, TAN_POLY_8_S, TAN_POLY_9_S,
+};
+use crate::wasm::generalf::f32x4_rempi;
+use crate::{f32x4_isinf, f32x4_mlaf, f32x4_rint, f32x4_select};
+
+#[inline]
+/// Computes tan function with error bound *ULP 1.5*
+pub fn f32x4_tan(d: v128) -> v128 {
+    let q = f32x4_rint(f32x4_mul(d, f32x4_splat(std::f32::consts::FRAC_2_PI)));
+    let qf = f32x4_convert_i32x4(q);
+
+    let mut r = f32x4_mlaf(qf, f32x4_splat(-PI_A_F * 0.5), d);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_B_F * 0.5), r);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_C_F * 0.5), r);
+    r = f32x4_mlaf(qf, f32x4_splat(-PI_D_F * 0.5), r);
+    let (q, mut r) = f32x4_rempi(d, q, r, rempi2f);
+
+    let ones = i32x4_splat(1);
+    let is_odd = i32x4_eq(v128_and(q, ones), ones);
+    r = f32x4_select(is_odd, f32x4_neg(r), r);
+
+    let x2 = f32x4_mul(r, r);
+
+    let mut res = f32x4_splat(TAN_POLY_9_S);
+    res = f32x4_mlaf(res, x2, f32x4_splat(TAN_POLY_8_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(TAN_POLY_7_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(TAN_POLY_6_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(TAN_POLY_5_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(TAN_POLY_4_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(TAN_POLY_3_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(TAN_POLY_2_S));
+    res = f32x4_mlaf(res, x2, f32x4_splat(TAN_POLY_1_S));
+    res = f32x4_mlaf(res, f32x4_mul(x2, r), r);
+    res = f32x4_select(is_odd, f32x4_div(f32x4_splat(1.), res), res);
+    // tan(±inf) = NaN
+    f32x4_select(f32x4_isinf(d), f32x4_splat(f32::NAN), res)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tanf() {
+        {
+            let comparison = f32x4_tan(f32x4_splat(0.5f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 - 0.5463025f32).abs() < 1e-6);
+        }
+        {
+            let comparison = f32x4_tan(f32x4_splat(-1.2f32));
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!((flag_1 + 2.5721517f32).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_tanf_large() {
+        for x in [1e4f32, -1e22, 3.0e38] {
+            let value = f32x4_extract_lane::<0>(f32x4_tan(f32x4(x, 2., -0.5, 1.)));
+            let control = (x as f64).tan() as f32;
+            assert!((value - control).abs() <= 4. * f32::EPSILON * control.abs());
+        }
+    }
+}
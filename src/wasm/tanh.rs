@@ -0,0 +1,63 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f64x2_copysign, f64x2_expm1, f64x2_select};
+
+/// Computes hyperbolic tangent, error bound *ULP 2.5*
+#[inline]
+pub fn f64x2_tanh(d: v128) -> v128 {
+    let a = f64x2_abs(d);
+    let e = f64x2_expm1(f64x2_add(a, a));
+    let mut r = f64x2_div(e, f64x2_add(e, f64x2_splat(2f64)));
+    // tanh rounds to 1 long before expm1 overflows
+    r = f64x2_select(f64x2_gt(a, f64x2_splat(22f64)), f64x2_splat(1f64), r);
+    f64x2_copysign(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tanhd() {
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_tanh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 0.5f64.tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-1e-10f64);
+            let comparison = f64x2_tanh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = (-1e-10f64).tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test saturation
+            let value = f64x2_splat(30f64);
+            let comparison = f64x2_tanh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 1.);
+        }
+
+        {
+            // Test saturation
+            let value = f64x2_splat(-30f64);
+            let comparison = f64x2_tanh(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+    }
+}
@@ -0,0 +1,63 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use std::arch::wasm32::*;
+
+use crate::{f32x4_copysign, f32x4_expm1, f32x4_select};
+
+/// Computes hyperbolic tangent, error bound *ULP 2.5*
+#[inline]
+pub fn f32x4_tanh(d: v128) -> v128 {
+    let a = f32x4_abs(d);
+    let e = f32x4_expm1(f32x4_add(a, a));
+    let mut r = f32x4_div(e, f32x4_add(e, f32x4_splat(2f32)));
+    // tanh rounds to 1 long before expm1 overflows
+    r = f32x4_select(f32x4_gt(a, f32x4_splat(10f32)), f32x4_splat(1f32), r);
+    f32x4_copysign(r, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tanhf() {
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_tanh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 0.5f32.tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-1e-10f32);
+            let comparison = f32x4_tanh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = (-1e-10f32).tanh();
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test saturation
+            let value = f32x4_splat(30f32);
+            let comparison = f32x4_tanh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 1.);
+        }
+
+        {
+            // Test saturation
+            let value = f32x4_splat(-30f32);
+            let comparison = f32x4_tanh(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, -1.);
+        }
+    }
+}
@@ -0,0 +1,183 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::exp::{L2_L, L2_U, R_LN2};
+use crate::tgamma::{
+    SQRT_2PI_D, SQRT_2PI_LO_D, SQRT_HALF_PI_D, SQRT_HALF_PI_LO_D, STIRLING_POLY_1_D,
+    STIRLING_POLY_2_D, STIRLING_POLY_3_D, STIRLING_POLY_4_D, STIRLING_POLY_5_D, STIRLING_POLY_6_D,
+};
+use crate::wasm::exp::f64x2_expm1k;
+use crate::wasm::general::{f64x2_mul_doubled, f64x2_sum_as_doubled};
+use crate::wasm::ln::f64x2_lnk_doubled;
+use crate::wasm::sin::f64x2_sinpik;
+use crate::{
+    f64x2_convert_i64x2, f64x2_copysign, f64x2_isnotintegral, f64x2_ldexp2k, f64x2_mlaf,
+    f64x2_rint, f64x2_select,
+};
+use std::arch::wasm32::*;
+
+/// Computes lnΓ(w) - ln(sqrt(2pi)) as double-float for w >= 10
+#[inline]
+pub(crate) fn f64x2_stirlingk(w: (v128, v128)) -> (v128, v128) {
+    let l = f64x2_lnk_doubled(w);
+    // (w - 0.5) ln(w) - w, w - 0.5 is exact below 2^52
+    let m = f64x2_mul_doubled((f64x2_sub(w.0, f64x2_splat(0.5f64)), w.1), l);
+    let e = f64x2_sum_as_doubled(m.0, f64x2_neg(w.0));
+    let iw = f64x2_div(f64x2_splat(1f64), w.0);
+    let v = f64x2_mul(iw, iw);
+    let mut u = f64x2_splat(STIRLING_POLY_6_D);
+    u = f64x2_mlaf(u, v, f64x2_splat(STIRLING_POLY_5_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(STIRLING_POLY_4_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(STIRLING_POLY_3_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(STIRLING_POLY_2_D));
+    u = f64x2_mlaf(u, v, f64x2_splat(STIRLING_POLY_1_D));
+    (e.0, f64x2_add(e.1, f64x2_mlaf(iw, u, f64x2_sub(m.1, w.1))))
+}
+
+/// Computes gamma function, error bound *ULP 3.5*, *ULP 2.5* for positive arguments
+#[inline]
+pub fn f64x2_tgamma(d: v128) -> v128 {
+    let reflect = f64x2_lt(d, f64x2_splat(0f64));
+    // Γ(d) = pi / (sin(pi d) Γ(1 - d)) for negative d, 1 - d is kept as double-float
+    let s = f64x2_sum_as_doubled(f64x2_splat(1f64), f64x2_neg(d));
+    let z = (
+        f64x2_select(reflect, s.0, d),
+        f64x2_select(reflect, s.1, f64x2_splat(0f64)),
+    );
+    // Γ(z) = Γ(z + 10) / (z (z + 1) ... (z + 9)) moves the argument into Stirling range
+    let mut w = z;
+    let mut q = (f64x2_splat(1f64), f64x2_splat(0f64));
+    for _ in 0..10 {
+        q = f64x2_mul_doubled(q, w);
+        let s = f64x2_sum_as_doubled(w.0, f64x2_splat(1f64));
+        w = (s.0, f64x2_add(s.1, w.1));
+    }
+    let shift = f64x2_lt(z.0, f64x2_splat(10f64));
+    let w = (f64x2_select(shift, w.0, z.0), f64x2_select(shift, w.1, z.1));
+    let q = (
+        f64x2_select(shift, q.0, f64x2_splat(1f64)),
+        f64x2_select(shift, q.1, f64x2_splat(0f64)),
+    );
+    // ln(Γ(z)) - ln(sqrt(2pi)) = E - ln(q) with every low part kept, so nothing is divided afterwards
+    let e = f64x2_stirlingk(w);
+    let l = f64x2_lnk_doubled(q);
+    let h = f64x2_sum_as_doubled(e.0, f64x2_neg(l.0));
+    let lo = f64x2_add(h.1, f64x2_sub(e.1, l.1));
+    // Reflected lanes take exp of the negated exponent
+    let hi = f64x2_select(reflect, f64x2_neg(h.0), h.0);
+    let lo = f64x2_select(reflect, f64x2_neg(lo), lo);
+    let qi = f64x2_rint(f64x2_mul(hi, f64x2_splat(R_LN2)));
+    let qf = f64x2_convert_i64x2(qi);
+    let mut r = f64x2_mlaf(qf, f64x2_splat(-L2_U), hi);
+    r = f64x2_mlaf(qf, f64x2_splat(-L2_L), r);
+    r = f64x2_add(r, lo);
+    let g = f64x2_select(
+        reflect,
+        f64x2_splat(SQRT_HALF_PI_D),
+        f64x2_splat(SQRT_2PI_D),
+    );
+    let g_lo = f64x2_select(
+        reflect,
+        f64x2_splat(SQRT_HALF_PI_LO_D / SQRT_HALF_PI_D),
+        f64x2_splat(SQRT_2PI_LO_D / SQRT_2PI_D),
+    );
+    let t = f64x2_expm1k(f64x2_add(r, g_lo));
+    let u = f64x2_mlaf(g, t, g);
+    let sp = f64x2_sinpik(d);
+    let mut ret = f64x2_ldexp2k(f64x2_select(reflect, f64x2_div(u, sp), u), qi);
+    // Γ(d) = 1/d - γ + O(d), past this point γ is below half an ulp of 1/d
+    ret = f64x2_select(
+        f64x2_lt(f64x2_abs(d), f64x2_splat(5.551115123125783e-17f64)),
+        f64x2_div(f64x2_splat(1f64), d),
+        ret,
+    );
+    // d > 171.7 overflows, also covers d == Inf
+    ret = f64x2_select(
+        f64x2_gt(d, f64x2_splat(171.7f64)),
+        f64x2_splat(f64::INFINITY),
+        ret,
+    );
+    // |Γ(d)| is below the smallest subnormal
+    ret = f64x2_select(
+        f64x2_lt(d, f64x2_splat(-190f64)),
+        f64x2_copysign(f64x2_splat(0f64), sp),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    f64x2_select(
+        v128_andnot(reflect, f64x2_isnotintegral(d)),
+        f64x2_splat(f64::NAN),
+        ret,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tgammad() {
+        {
+            // Test regular
+            let value = f64x2_splat(0.5f64);
+            let comparison = f64x2_tgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 1.772453850905516f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(-1.5f64);
+            let comparison = f64x2_tgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 2.363271801207355f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f64x2_splat(5.5f64);
+            let comparison = f64x2_tgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            let expected = 52.34277778455352f64;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f64::EPSILON);
+        }
+
+        {
+            // Test exact value
+            let value = f64x2_splat(5f64);
+            let comparison = f64x2_tgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 24.);
+        }
+
+        {
+            // Test pole
+            let value = f64x2_splat(-2f64);
+            let comparison = f64x2_tgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        {
+            // Test overflow
+            let value = f64x2_splat(200f64);
+            let comparison = f64x2_tgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f64::INFINITY);
+        }
+
+        {
+            // Test NaN
+            let value = f64x2_splat(f64::NAN);
+            let comparison = f64x2_tgamma(value);
+            let flag_1 = f64x2_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}
@@ -0,0 +1,180 @@
+/*
+ * // Copyright 2024 (c) the Radzivon Bartoshyk. All rights reserved.
+ * //
+ * // Use of this source code is governed by a BSD-style
+ * // license that can be found in the LICENSE file.
+ */
+
+use crate::expf::{L2L_F, L2U_F, R_LN2_F};
+use crate::tgammaf::{
+    SQRT_2PI_LO_S, SQRT_2PI_S, SQRT_HALF_PI_LO_S, SQRT_HALF_PI_S, STIRLING_POLY_1_S,
+    STIRLING_POLY_2_S, STIRLING_POLY_3_S, STIRLING_POLY_4_S,
+};
+use crate::wasm::expf::f32x4_expm1k;
+use crate::wasm::generalf::{f32x4_mul_doubled, f32x4_sum_as_doubled};
+use crate::wasm::lnf::f32x4_lnk_doubled;
+use crate::wasm::sinf::f32x4_sinpik;
+use crate::{
+    f32x4_copysign, f32x4_isnotintegral, f32x4_ldexp2k, f32x4_mlaf, f32x4_rint, f32x4_select,
+};
+use std::arch::wasm32::*;
+
+/// Computes lnΓ(w) - ln(sqrt(2pi)) as double-float for w >= 5
+#[inline]
+pub(crate) fn f32x4_stirlingk(w: (v128, v128)) -> (v128, v128) {
+    let l = f32x4_lnk_doubled(w);
+    // (w - 0.5) ln(w) - w, w - 0.5 is exact below 2^23
+    let m = f32x4_mul_doubled((f32x4_sub(w.0, f32x4_splat(0.5f32)), w.1), l);
+    let e = f32x4_sum_as_doubled(m.0, f32x4_neg(w.0));
+    let iw = f32x4_div(f32x4_splat(1f32), w.0);
+    let v = f32x4_mul(iw, iw);
+    let mut u = f32x4_splat(STIRLING_POLY_4_S);
+    u = f32x4_mlaf(u, v, f32x4_splat(STIRLING_POLY_3_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(STIRLING_POLY_2_S));
+    u = f32x4_mlaf(u, v, f32x4_splat(STIRLING_POLY_1_S));
+    (e.0, f32x4_add(e.1, f32x4_mlaf(iw, u, f32x4_sub(m.1, w.1))))
+}
+
+/// Computes gamma function, error bound *ULP 3.5*, *ULP 2.0* for positive arguments
+#[inline]
+pub fn f32x4_tgamma(d: v128) -> v128 {
+    let reflect = f32x4_lt(d, f32x4_splat(0f32));
+    // Γ(d) = pi / (sin(pi d) Γ(1 - d)) for negative d, 1 - d is kept as double-float
+    let s = f32x4_sum_as_doubled(f32x4_splat(1f32), f32x4_neg(d));
+    let z = (
+        f32x4_select(reflect, s.0, d),
+        f32x4_select(reflect, s.1, f32x4_splat(0f32)),
+    );
+    // Γ(z) = Γ(z + 5) / (z (z + 1) ... (z + 4)) moves the argument into Stirling range
+    let mut w = z;
+    let mut q = (f32x4_splat(1f32), f32x4_splat(0f32));
+    for _ in 0..5 {
+        q = f32x4_mul_doubled(q, w);
+        let s = f32x4_sum_as_doubled(w.0, f32x4_splat(1f32));
+        w = (s.0, f32x4_add(s.1, w.1));
+    }
+    let shift = f32x4_lt(z.0, f32x4_splat(5f32));
+    let w = (f32x4_select(shift, w.0, z.0), f32x4_select(shift, w.1, z.1));
+    let q = (
+        f32x4_select(shift, q.0, f32x4_splat(1f32)),
+        f32x4_select(shift, q.1, f32x4_splat(0f32)),
+    );
+    // ln(Γ(z)) - ln(sqrt(2pi)) = E - ln(q) with every low part kept, so nothing is divided afterwards
+    let e = f32x4_stirlingk(w);
+    let l = f32x4_lnk_doubled(q);
+    let h = f32x4_sum_as_doubled(e.0, f32x4_neg(l.0));
+    let lo = f32x4_add(h.1, f32x4_sub(e.1, l.1));
+    // Reflected lanes take exp of the negated exponent
+    let hi = f32x4_select(reflect, f32x4_neg(h.0), h.0);
+    let lo = f32x4_select(reflect, f32x4_neg(lo), lo);
+    let qi = f32x4_rint(f32x4_mul(hi, f32x4_splat(R_LN2_F)));
+    let qf = f32x4_convert_i32x4(qi);
+    let mut r = f32x4_mlaf(qf, f32x4_splat(-L2U_F), hi);
+    r = f32x4_mlaf(qf, f32x4_splat(-L2L_F), r);
+    r = f32x4_add(r, lo);
+    let g = f32x4_select(
+        reflect,
+        f32x4_splat(SQRT_HALF_PI_S),
+        f32x4_splat(SQRT_2PI_S),
+    );
+    let g_lo = f32x4_select(
+        reflect,
+        f32x4_splat(SQRT_HALF_PI_LO_S / SQRT_HALF_PI_S),
+        f32x4_splat(SQRT_2PI_LO_S / SQRT_2PI_S),
+    );
+    let t = f32x4_expm1k(f32x4_add(r, g_lo));
+    let u = f32x4_mlaf(g, t, g);
+    let sp = f32x4_sinpik(d);
+    let mut ret = f32x4_ldexp2k(f32x4_select(reflect, f32x4_div(u, sp), u), qi);
+    // Γ(d) = 1/d - γ + O(d), past this point γ is below half an ulp of 1/d
+    ret = f32x4_select(
+        f32x4_lt(f32x4_abs(d), f32x4_splat(2.9802322e-8f32)),
+        f32x4_div(f32x4_splat(1f32), d),
+        ret,
+    );
+    // d > 35.1 overflows, also covers d == Inf
+    ret = f32x4_select(
+        f32x4_gt(d, f32x4_splat(35.1f32)),
+        f32x4_splat(f32::INFINITY),
+        ret,
+    );
+    // |Γ(d)| is below the smallest subnormal
+    ret = f32x4_select(
+        f32x4_lt(d, f32x4_splat(-45f32)),
+        f32x4_copysign(f32x4_splat(0f32), sp),
+        ret,
+    );
+    // Poles and d == -Inf -> Nan
+    f32x4_select(
+        v128_andnot(reflect, f32x4_isnotintegral(d)),
+        f32x4_splat(f32::NAN),
+        ret,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tgammaf() {
+        {
+            // Test regular
+            let value = f32x4_splat(0.5f32);
+            let comparison = f32x4_tgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 1.7724539f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(-1.5f32);
+            let comparison = f32x4_tgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 2.3632717f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test regular
+            let value = f32x4_splat(5.5f32);
+            let comparison = f32x4_tgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            let expected = 52.342777f32;
+            assert!((flag_1 - expected).abs() <= expected.abs() * 4. * f32::EPSILON);
+        }
+
+        {
+            // Test exact value
+            let value = f32x4_splat(5f32);
+            let comparison = f32x4_tgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, 24.);
+        }
+
+        {
+            // Test pole
+            let value = f32x4_splat(-2f32);
+            let comparison = f32x4_tgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+
+        {
+            // Test overflow
+            let value = f32x4_splat(40f32);
+            let comparison = f32x4_tgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert_eq!(flag_1, f32::INFINITY);
+        }
+
+        {
+            // Test NaN
+            let value = f32x4_splat(f32::NAN);
+            let comparison = f32x4_tgamma(value);
+            let flag_1 = f32x4_extract_lane::<0>(comparison);
+            assert!(flag_1.is_nan());
+        }
+    }
+}